@@ -47,13 +47,17 @@ fn correct_disconnect_shared(_method: &mut Method) {}
 
 fn correct_get_conversation_prefs(_method: &mut Method) {}
 
-fn correct_get_teams(_method: &mut Method) {}
+fn correct_get_teams(method: &mut Method) {
+    paginate_by_cursor(method, "team_ids", &["response_metadata", "next_cursor"]);
+}
 
 fn correct_invite(_method: &mut Method) {}
 
 fn correct_rename(_method: &mut Method) {}
 
-fn correct_search(_method: &mut Method) {}
+fn correct_search(method: &mut Method) {
+    paginate_by_cursor(method, "channels", &["next_cursor"]);
+}
 
 fn correct_set_conversation_prefs(_method: &mut Method) {}
 
@@ -1,7 +1,7 @@
 #![allow(unused_imports)]
 #![allow(clippy::single_match)]
 use crate::adapt::utils::*;
-use crate::rust::{Method, Module};
+use crate::rust::{Method, Module, ResponseType};
 
 pub fn correct(module: &mut Module) {
     for mut method in &mut module.methods {
@@ -12,4 +12,6 @@ pub fn correct(module: &mut Module) {
     }
 }
 
-fn correct_list(_method: &mut Method) {}
+fn correct_list(method: &mut Method) {
+    paginate_missing_by_cursor(method, "approved_requests", ResponseType::RawJson);
+}
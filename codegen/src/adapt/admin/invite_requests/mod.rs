@@ -1,7 +1,7 @@
 #![allow(unused_imports)]
 #![allow(clippy::single_match)]
 use crate::adapt::utils::*;
-use crate::rust::{Method, Module};
+use crate::rust::{Method, Module, ResponseType};
 
 mod approved;
 mod denied;
@@ -29,4 +29,6 @@ fn correct_approve(_method: &mut Method) {}
 
 fn correct_deny(_method: &mut Method) {}
 
-fn correct_list(_method: &mut Method) {}
+fn correct_list(method: &mut Method) {
+    paginate_missing_by_cursor(method, "invite_requests", ResponseType::RawJson);
+}
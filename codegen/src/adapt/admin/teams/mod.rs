@@ -1,7 +1,7 @@
 #![allow(unused_imports)]
 #![allow(clippy::single_match)]
 use crate::adapt::utils::*;
-use crate::rust::{Method, Module};
+use crate::rust::{Method, Module, ResponseType};
 
 mod admins;
 mod owners;
@@ -28,4 +28,6 @@ pub fn correct(module: &mut Module) {
 
 fn correct_create(_method: &mut Method) {}
 
-fn correct_list(_method: &mut Method) {}
+fn correct_list(method: &mut Method) {
+    paginate_missing_by_cursor(method, "teams", ResponseType::RawJson);
+}
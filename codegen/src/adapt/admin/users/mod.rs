@@ -1,7 +1,7 @@
 #![allow(unused_imports)]
 #![allow(clippy::single_match)]
 use crate::adapt::utils::*;
-use crate::rust::{Method, Module};
+use crate::rust::{Method, Module, ResponseType};
 
 mod session;
 
@@ -32,7 +32,9 @@ fn correct_assign(_method: &mut Method) {}
 
 fn correct_invite(_method: &mut Method) {}
 
-fn correct_list(_method: &mut Method) {}
+fn correct_list(method: &mut Method) {
+    paginate_missing_by_cursor(method, "users", ResponseType::RawJson);
+}
 
 fn correct_remove(_method: &mut Method) {}
 
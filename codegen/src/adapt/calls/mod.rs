@@ -24,10 +24,18 @@ pub fn correct(module: &mut Module) {
     }
 }
 
-fn correct_add(_method: &mut Method) {}
+fn correct_add(method: &mut Method) {
+    send_as_json(method, &["users"]);
+}
 
-fn correct_end(_method: &mut Method) {}
+fn correct_end(method: &mut Method) {
+    send_as_json(method, &[]);
+}
 
-fn correct_info(_method: &mut Method) {}
+fn correct_info(method: &mut Method) {
+    send_as_json(method, &[]);
+}
 
-fn correct_update(_method: &mut Method) {}
+fn correct_update(method: &mut Method) {
+    send_as_json(method, &[]);
+}
@@ -13,6 +13,10 @@ pub fn correct(module: &mut Module) {
     }
 }
 
-fn correct_add(_method: &mut Method) {}
+fn correct_add(method: &mut Method) {
+    send_as_json(method, &["users"]);
+}
 
-fn correct_remove(_method: &mut Method) {}
+fn correct_remove(method: &mut Method) {
+    send_as_json(method, &["users"]);
+}
@@ -31,26 +31,38 @@ pub fn correct(module: &mut Module) {
 
 fn correct_delete(method: &mut Method) {
     set_parameters_required(method, &["channel", "token", "ts"]);
+    send_as_json(method, &[]);
 }
 
-fn correct_delete_scheduled_message(_method: &mut Method) {}
+fn correct_delete_scheduled_message(method: &mut Method) {
+    send_as_json(method, &[]);
+}
 
 fn correct_get_permalink(_method: &mut Method) {}
 
 fn correct_me_message(method: &mut Method) {
     set_parameters_required(method, &["channel", "token", "text"]);
+    send_as_json(method, &[]);
 }
 
-fn correct_post_ephemeral(_method: &mut Method) {}
+fn correct_post_ephemeral(method: &mut Method) {
+    send_as_json(method, &["attachments", "blocks"]);
+}
 
 fn correct_post_message(method: &mut Method) {
     set_parameters_required(method, &["text"]);
+    send_as_json(method, &["attachments", "blocks"]);
 }
 
-fn correct_schedule_message(_method: &mut Method) {}
+fn correct_schedule_message(method: &mut Method) {
+    send_as_json(method, &["attachments", "blocks"]);
+}
 
 fn correct_unfurl(method: &mut Method) {
     set_parameters_required(method, &["unfurls"]);
+    send_as_json(method, &["unfurls"]);
 }
 
-fn correct_update(_method: &mut Method) {}
+fn correct_update(method: &mut Method) {
+    send_as_json(method, &["attachments", "blocks"]);
+}
@@ -12,4 +12,11 @@ pub fn correct(module: &mut Module) {
     }
 }
 
-fn correct_list(_method: &mut Method) {}
+fn correct_list(method: &mut Method) {
+    send_as_json(method, &[]);
+    paginate_by_cursor(
+        method,
+        "scheduled_messages",
+        &["response_metadata", "next_cursor"],
+    );
+}
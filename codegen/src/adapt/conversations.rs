@@ -37,6 +37,8 @@ fn correct_create(_method: &mut Method) {}
 
 fn correct_history(method: &mut Method) {
     set_parameters_required(method, &["channel", "token"]);
+    add_response_metadata(method);
+    paginate_by_cursor(method, "messages", &["response_metadata", "next_cursor"]);
     let mut root = ResponseTypeModifier::from(method);
 
     // messages.attachments.id is not required
@@ -84,6 +86,7 @@ fn correct_leave(_method: &mut Method) {}
 
 fn correct_list(method: &mut Method) {
     set_parameters_required(method, &["token"]);
+    paginate_by_cursor(method, "channels", &["response_metadata", "next_cursor"]);
     let mut root = ResponseTypeModifier::from(method);
 
     // channels is defined as Vec<Vec<_>> but should return Vec<_>
@@ -95,13 +98,18 @@ fn correct_list(method: &mut Method) {
 
 fn correct_mark(_method: &mut Method) {}
 
-fn correct_members(_method: &mut Method) {}
+fn correct_members(method: &mut Method) {
+    paginate_by_cursor(method, "members", &["response_metadata", "next_cursor"]);
+}
 
 fn correct_open(_method: &mut Method) {}
 
 fn correct_rename(_method: &mut Method) {}
 
-fn correct_replies(_method: &mut Method) {}
+fn correct_replies(method: &mut Method) {
+    add_response_metadata(method);
+    paginate_by_cursor(method, "messages", &["response_metadata", "next_cursor"]);
+}
 
 fn correct_set_purpose(_method: &mut Method) {}
 
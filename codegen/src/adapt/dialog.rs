@@ -12,4 +12,6 @@ pub fn correct(module: &mut Module) {
     }
 }
 
-fn correct_open(_method: &mut Method) {}
+fn correct_open(method: &mut Method) {
+    send_as_json(method, &["dialog"]);
+}
@@ -1,7 +1,9 @@
 #![allow(unused_imports)]
 #![allow(clippy::single_match)]
 use crate::adapt::utils::*;
-use crate::rust::{Method, Module};
+use crate::rust::{
+    HttpMethod, Member, Method, Module, Parameter, ParameterDataType, Response, ResponseType,
+};
 
 mod comments;
 mod remote;
@@ -26,16 +28,205 @@ pub fn correct(module: &mut Module) {
             _ => {}
         }
     }
+
+    add_methods(
+        module,
+        vec![get_upload_url_external(), complete_upload_external()],
+    );
+    module.extended = true;
 }
 
 fn correct_delete(_method: &mut Method) {}
 
 fn correct_info(_method: &mut Method) {}
 
-fn correct_list(_method: &mut Method) {}
+fn correct_list(method: &mut Method) {
+    paginate_by_page(method, &["files"], &["paging"], None);
+}
 
 fn correct_revoke_public_url(_method: &mut Method) {}
 
 fn correct_shared_public_url(_method: &mut Method) {}
 
-fn correct_upload(_method: &mut Method) {}
+fn correct_upload(method: &mut Method) {
+    set_parameters_type(method, &["file"], ParameterDataType::File);
+}
+
+/// The schema predates the external upload flow that replaces `files.upload`.
+fn get_upload_url_external() -> Method {
+    Method {
+        name: "getUploadURLExternal".into(),
+        full_name: "/files.getUploadURLExternal".into(),
+        description: "Gets a URL for an edge external file upload".into(),
+        documentation_url: "https://api.slack.com/methods/files.getUploadURLExternal".into(),
+        parameters: vec![
+            parameter(
+                "alt_txt",
+                "Description of image for screen-reader.",
+                false,
+                ParameterDataType::String,
+            ),
+            parameter(
+                "filename",
+                "Name of the file being uploaded.",
+                true,
+                ParameterDataType::String,
+            ),
+            parameter(
+                "length",
+                "Size in bytes of the file being uploaded.",
+                true,
+                ParameterDataType::Int,
+            ),
+            parameter(
+                "snippet_type",
+                "Syntax type of the snippet being uploaded.",
+                false,
+                ParameterDataType::String,
+            ),
+            parameter(
+                "token",
+                "Authentication token. Requires scope: `files:write`",
+                true,
+                ParameterDataType::String,
+            ),
+        ],
+        response: object(vec![
+            member("error", ResponseType::String, false),
+            member("file_id", ResponseType::String, false),
+            member("ok", ResponseType::Bool, true),
+            member("upload_url", ResponseType::String, false),
+        ]),
+        http_method: HttpMethod::Post,
+        errors: common_errors(&[]),
+        pagination: None,
+    }
+}
+
+fn complete_upload_external() -> Method {
+    Method {
+        name: "completeUploadExternal".into(),
+        full_name: "/files.completeUploadExternal".into(),
+        description: "Finishes an upload started with files.getUploadURLExternal".into(),
+        documentation_url: "https://api.slack.com/methods/files.completeUploadExternal".into(),
+        parameters: vec![
+            parameter(
+                "channel_id",
+                "Channel ID where the file will be shared. If not specified the file will be private.",
+                false,
+                ParameterDataType::String,
+            ),
+            parameter(
+                "files",
+                "Array of file ids and their corresponding (optional) titles, as JSON.",
+                true,
+                ParameterDataType::String,
+            ),
+            parameter(
+                "initial_comment",
+                "The message text introducing the file in specified channel.",
+                false,
+                ParameterDataType::String,
+            ),
+            parameter(
+                "thread_ts",
+                "Provide another message's `ts` value to upload this file as a reply. Never use a reply's `ts` value; use its parent instead.",
+                false,
+                ParameterDataType::String,
+            ),
+            parameter(
+                "token",
+                "Authentication token. Requires scope: `files:write`",
+                true,
+                ParameterDataType::String,
+            ),
+        ],
+        response: object(vec![
+            member("error", ResponseType::String, false),
+            member(
+                "files",
+                ResponseType::Vec(Box::new(Response {
+                    description: None,
+                    r#type: ResponseType::Object(vec![
+                        member("id", ResponseType::String, true),
+                        member("title", ResponseType::String, false),
+                    ]),
+                    required: true,
+                })),
+                false,
+            ),
+            member("ok", ResponseType::Bool, true),
+        ]),
+        http_method: HttpMethod::Post,
+        errors: common_errors(&[
+            "channel_not_found",
+            "file_not_found",
+            "invalid_channel",
+            "not_in_channel",
+        ]),
+        pagination: None,
+    }
+}
+
+fn parameter(
+    name: &str,
+    description: &str,
+    required: bool,
+    param_type: ParameterDataType,
+) -> Parameter {
+    Parameter {
+        description: Some(description.into()),
+        name: name.into(),
+        required,
+        param_type,
+    }
+}
+
+fn object(members: Vec<Member>) -> Response {
+    Response {
+        description: None,
+        r#type: ResponseType::Object(members),
+        required: true,
+    }
+}
+
+fn member(name: &str, r#type: ResponseType, required: bool) -> Member {
+    Member {
+        name: name.into(),
+        r#type: Response {
+            description: None,
+            r#type,
+            required,
+        },
+    }
+}
+
+fn common_errors(specific: &[&str]) -> Vec<String> {
+    let mut errors: Vec<String> = [
+        "access_denied",
+        "account_inactive",
+        "fatal_error",
+        "invalid_arg_name",
+        "invalid_arguments",
+        "invalid_auth",
+        "invalid_charset",
+        "invalid_form_data",
+        "invalid_post_type",
+        "missing_post_type",
+        "missing_scope",
+        "not_authed",
+        "org_login_required",
+        "ratelimited",
+        "request_timeout",
+        "service_unavailable",
+        "team_added_to_org",
+        "token_expired",
+        "token_revoked",
+    ]
+    .iter()
+    .chain(specific)
+    .map(|e| e.to_string())
+    .collect();
+    errors.sort_unstable();
+    errors
+}
@@ -1,7 +1,7 @@
 #![allow(unused_imports)]
 #![allow(clippy::single_match)]
 use crate::adapt::utils::*;
-use crate::rust::{Method, Module};
+use crate::rust::{Member, Method, Module, ParameterDataType, Response, ResponseType};
 
 pub fn correct(module: &mut Module) {
     for mut method in &mut module.methods {
@@ -17,14 +17,46 @@ pub fn correct(module: &mut Module) {
     }
 }
 
-fn correct_add(_method: &mut Method) {}
+fn correct_add(method: &mut Method) {
+    set_parameters_type(
+        method,
+        &["indexable_file_contents", "preview_image"],
+        ParameterDataType::File,
+    );
+}
 
 fn correct_info(_method: &mut Method) {}
 
-fn correct_list(_method: &mut Method) {}
+fn correct_list(method: &mut Method) {
+    // The spec has no response members besides ok
+    let file = Response {
+        description: None,
+        r#type: ResponseType::RawJson,
+        required: true,
+    };
+    add_response_member(
+        method,
+        Member {
+            name: "files".into(),
+            r#type: Response {
+                description: None,
+                r#type: ResponseType::Vec(Box::new(file)),
+                required: true,
+            },
+        },
+    );
+    add_response_metadata(method);
+    paginate_by_cursor(method, "files", &["response_metadata", "next_cursor"]);
+}
 
 fn correct_remove(_method: &mut Method) {}
 
 fn correct_share(_method: &mut Method) {}
 
-fn correct_update(_method: &mut Method) {}
+fn correct_update(method: &mut Method) {
+    set_parameters_type(
+        method,
+        &["indexable_file_contents", "preview_image"],
+        ParameterDataType::File,
+    );
+}
@@ -17,6 +17,7 @@ mod emoji;
 mod files;
 mod migration;
 mod oauth;
+mod openid;
 mod pins;
 mod reactions;
 mod reminders;
@@ -24,6 +25,7 @@ mod rtm;
 mod search;
 mod stars;
 mod team;
+mod tooling;
 mod usergroups;
 mod users;
 mod utils;
@@ -31,7 +33,7 @@ mod views;
 mod workflows;
 
 pub fn correct(modules: &mut Vec<Module>) {
-    for mut module in modules {
+    for mut module in modules.iter_mut() {
         match module.name.as_str() {
             "bots" => bots::correct(&mut module),
             "migration" => migration::correct(&mut module),
@@ -61,4 +63,27 @@ pub fn correct(modules: &mut Vec<Module>) {
             _ => {}
         }
     }
+    for missing in vec![openid::module(), tooling::module()] {
+        if !modules.iter().any(|module| module.name == missing.name) {
+            modules.push(missing);
+        }
+    }
+    modules.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+    for module in modules {
+        correct_shared_types(module);
+    }
+}
+
+/// Applies the corrections shared by every method, such as typed timestamps, blocks and IDs, and
+/// the members kept on errors.
+fn correct_shared_types(module: &mut Module) {
+    for method in &mut module.methods {
+        use_timestamps(method);
+        use_blocks(method);
+        use_ids(method);
+        add_error_members(method);
+    }
+    for submodule in &mut module.submodules {
+        correct_shared_types(submodule);
+    }
 }
@@ -1,7 +1,7 @@
 #![allow(unused_imports)]
 #![allow(clippy::single_match)]
 use crate::adapt::utils::*;
-use crate::rust::{Method, Module};
+use crate::rust::{Method, Module, ResponseType};
 
 mod v_2;
 
@@ -22,6 +22,38 @@ pub fn correct(module: &mut Module) {
     }
 }
 
-fn correct_access(_method: &mut Method) {}
+fn correct_access(method: &mut Method) {
+    send_with_client_auth(method);
+    let string = |name: &str, required: bool| response_member(name, ResponseType::String, required);
+    let bot = ResponseType::Object(vec![
+        string("bot_access_token", true),
+        string("bot_user_id", true),
+    ]);
+    let members = vec![
+        string("access_token", true),
+        response_member("bot", bot, false),
+        string("enterprise_id", false),
+        response_member("incoming_webhook", incoming_webhook(), false),
+        string("scope", true),
+        string("team_id", false),
+        string("team_name", false),
+        string("user_id", false),
+    ];
+    for member in members {
+        add_response_member(method, member);
+    }
+}
+
+/// The incoming webhook that `oauth.access` and `oauth.v2.access` return for installs with the
+/// `incoming-webhook` scope.
+pub(super) fn incoming_webhook() -> ResponseType {
+    let string = |name: &str| response_member(name, ResponseType::String, true);
+    ResponseType::Object(vec![
+        string("channel"),
+        string("channel_id"),
+        string("configuration_url"),
+        string("url"),
+    ])
+}
 
 fn correct_token(_method: &mut Method) {}
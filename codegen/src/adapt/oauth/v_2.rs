@@ -1,7 +1,8 @@
 #![allow(unused_imports)]
 #![allow(clippy::single_match)]
+use crate::adapt::oauth::incoming_webhook;
 use crate::adapt::utils::*;
-use crate::rust::{Method, Module};
+use crate::rust::{Method, Module, Parameter, ParameterDataType, ResponseType};
 
 pub fn correct(module: &mut Module) {
     for mut method in &mut module.methods {
@@ -10,6 +11,64 @@ pub fn correct(module: &mut Module) {
             _ => {}
         }
     }
+    module.extended = true;
 }
 
-fn correct_access(_method: &mut Method) {}
+fn correct_access(method: &mut Method) {
+    send_with_client_auth(method);
+    // Apps with token rotation call it again with `grant_type=refresh_token` instead of a `code`.
+    for param in &mut method.parameters {
+        if param.name == "code" {
+            param.required = false;
+        }
+    }
+    add_parameters(
+        method,
+        vec![
+            Parameter {
+                description: Some(
+                    "The `grant_type` param as described in the OAuth spec, `refresh_token` to refresh an expiring token."
+                        .into(),
+                ),
+                name: "grant_type".into(),
+                required: false,
+                param_type: ParameterDataType::String,
+            },
+            Parameter {
+                description: Some(
+                    "The `refresh_token` param as described in the OAuth spec.".into(),
+                ),
+                name: "refresh_token".into(),
+                required: false,
+                param_type: ParameterDataType::String,
+            },
+        ],
+    );
+    let string = |name: &str, required: bool| response_member(name, ResponseType::String, required);
+    let authed_user = ResponseType::Object(vec![
+        string("access_token", false),
+        response_member("expires_in", ResponseType::Int, false),
+        string("id", true),
+        string("refresh_token", false),
+        string("scope", false),
+        string("token_type", false),
+    ]);
+    let named = || ResponseType::Object(vec![string("id", true), string("name", true)]);
+    let members = vec![
+        string("access_token", false),
+        string("app_id", true),
+        response_member("authed_user", authed_user, true),
+        string("bot_user_id", false),
+        response_member("enterprise", named(), false),
+        response_member("expires_in", ResponseType::Int, false),
+        response_member("incoming_webhook", incoming_webhook(), false),
+        response_member("is_enterprise_install", ResponseType::Bool, false),
+        string("refresh_token", false),
+        string("scope", false),
+        response_member("team", named(), false),
+        string("token_type", false),
+    ];
+    for member in members {
+        add_response_member(method, member);
+    }
+}
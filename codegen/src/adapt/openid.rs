@@ -0,0 +1,178 @@
+use crate::adapt::utils::*;
+use crate::rust::{
+    HttpMethod, Member, Method, Module, Parameter, ParameterDataType, Response, ResponseType,
+};
+
+/// The schema has no `openid.*` methods, which Sign in with Slack uses.
+pub fn module() -> Module {
+    Module {
+        name: "openid".into(),
+        submodules: vec![Module {
+            name: "connect".into(),
+            submodules: Vec::new(),
+            methods: vec![token(), user_info()],
+            extended: true,
+        }],
+        methods: Vec::new(),
+        extended: false,
+    }
+}
+
+fn token() -> Method {
+    let mut method = Method {
+        name: "token".into(),
+        full_name: "/openid.connect.token".into(),
+        description:
+            "Exchanges a temporary OAuth verifier code for an access token for Sign in with Slack."
+                .into(),
+        documentation_url: "https://api.slack.com/methods/openid.connect.token".into(),
+        parameters: vec![
+            parameter(
+                "client_id",
+                "Issued when you created your application.",
+                true,
+            ),
+            parameter(
+                "client_secret",
+                "Issued when you created your application.",
+                true,
+            ),
+            parameter(
+                "code",
+                "The `code` param returned via the OAuth callback.",
+                false,
+            ),
+            parameter(
+                "grant_type",
+                "The `grant_type` param as described in the OAuth spec.",
+                false,
+            ),
+            parameter(
+                "redirect_uri",
+                "This must match the originally submitted URI (if one was sent).",
+                false,
+            ),
+            parameter(
+                "refresh_token",
+                "The `refresh_token` param as described in the OAuth spec.",
+                false,
+            ),
+        ],
+        response: object(vec![
+            response_member("access_token", ResponseType::String, false),
+            response_member("error", ResponseType::String, false),
+            response_member("expires_in", ResponseType::Int, false),
+            response_member("id_token", ResponseType::String, false),
+            response_member("ok", ResponseType::Bool, true),
+            response_member("refresh_token", ResponseType::String, false),
+            response_member("token_type", ResponseType::String, false),
+        ]),
+        http_method: HttpMethod::Post,
+        errors: errors(&[
+            "invalid_code",
+            "invalid_grant_type",
+            "invalid_refresh_token",
+            "bad_client_secret",
+            "bad_redirect_uri",
+            "invalid_client_id",
+            "oauth_authorization_url_mismatch",
+        ]),
+        pagination: None,
+    };
+    send_with_client_auth(&mut method);
+    method
+}
+
+fn user_info() -> Method {
+    let string = |name: &str, required: bool| response_member(name, ResponseType::String, required);
+    let slack = |name: &str| format!("https://slack.com/{}", name);
+    let mut members = vec![
+        response_member("date_email_verified", ResponseType::Int, false),
+        string("email", false),
+        response_member("email_verified", ResponseType::Bool, false),
+        string("error", false),
+        string("family_name", false),
+        string("given_name", false),
+        string("locale", false),
+        string("name", false),
+        response_member("ok", ResponseType::Bool, true),
+        string("picture", false),
+        string("sub", false),
+        response_member(
+            &slack("enterprise_id"),
+            ResponseType::Typed("crate::EnterpriseId".into()),
+            false,
+        ),
+        string(&slack("team_domain"), false),
+        response_member(
+            &slack("team_id"),
+            ResponseType::Typed("crate::TeamId".into()),
+            false,
+        ),
+        response_member(&slack("team_image_default"), ResponseType::Bool, false),
+        string(&slack("team_name"), false),
+        response_member(
+            &slack("user_id"),
+            ResponseType::Typed("crate::UserId".into()),
+            false,
+        ),
+    ];
+    for size in &["34", "44", "68", "88", "102", "132", "230"] {
+        members.push(string(&slack(&format!("team_image_{}", size)), false));
+    }
+    for size in &["24", "32", "48", "72", "192", "512", "1024"] {
+        members.push(string(&slack(&format!("user_image_{}", size)), false));
+    }
+    members.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+    Method {
+        name: "userInfo".into(),
+        full_name: "/openid.connect.userInfo".into(),
+        description: "Get the identity of a user who has authorized Sign in with Slack.".into(),
+        documentation_url: "https://api.slack.com/methods/openid.connect.userInfo".into(),
+        parameters: vec![parameter(
+            "token",
+            "Authentication token. Requires scope: `openid`",
+            true,
+        )],
+        response: object(members),
+        http_method: HttpMethod::Post,
+        errors: errors(&[]),
+        pagination: None,
+    }
+}
+
+fn errors(specific: &[&str]) -> Vec<String> {
+    let mut errors: Vec<String> = [
+        "invalid_arguments",
+        "invalid_auth",
+        "not_authed",
+        "ratelimited",
+        "request_timeout",
+        "service_unavailable",
+        "token_expired",
+        "token_revoked",
+    ]
+    .iter()
+    .chain(specific)
+    .map(|e| e.to_string())
+    .collect();
+    errors.sort_unstable();
+    errors
+}
+
+fn parameter(name: &str, description: &str, required: bool) -> Parameter {
+    Parameter {
+        description: Some(description.into()),
+        name: name.into(),
+        required,
+        param_type: ParameterDataType::String,
+    }
+}
+
+fn object(members: Vec<Member>) -> Response {
+    Response {
+        description: None,
+        r#type: ResponseType::Object(members),
+        required: true,
+    }
+}
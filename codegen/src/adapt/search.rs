@@ -1,7 +1,7 @@
 #![allow(unused_imports)]
 #![allow(clippy::single_match)]
 use crate::adapt::utils::*;
-use crate::rust::{Method, Module};
+use crate::rust::{Member, Method, Module, Response, ResponseType};
 
 pub fn correct(module: &mut Module) {
     for mut method in &mut module.methods {
@@ -12,4 +12,60 @@ pub fn correct(module: &mut Module) {
     }
 }
 
-fn correct_messages(_method: &mut Method) {}
+fn correct_messages(method: &mut Method) {
+    // The spec has no response members besides ok
+    let int = |name: &str, required: bool| Member {
+        name: name.into(),
+        r#type: Response {
+            description: None,
+            r#type: ResponseType::Int,
+            required,
+        },
+    };
+    let paging = Response {
+        description: None,
+        r#type: ResponseType::Object(vec![
+            int("count", false),
+            int("page", true),
+            int("pages", false),
+            int("total", true),
+        ]),
+        required: true,
+    };
+    let matches = Response {
+        description: None,
+        r#type: ResponseType::Vec(Box::new(Response {
+            description: None,
+            r#type: ResponseType::RawJson,
+            required: true,
+        })),
+        required: true,
+    };
+    add_response_member(
+        method,
+        Member {
+            name: "messages".into(),
+            r#type: Response {
+                description: None,
+                r#type: ResponseType::Object(vec![
+                    Member {
+                        name: "matches".into(),
+                        r#type: matches,
+                    },
+                    Member {
+                        name: "paging".into(),
+                        r#type: paging,
+                    },
+                    int("total", true),
+                ]),
+                required: true,
+            },
+        },
+    );
+    paginate_by_page(
+        method,
+        &["messages", "matches"],
+        &["messages", "paging"],
+        None,
+    );
+}
@@ -16,6 +16,15 @@ pub fn correct(module: &mut Module) {
 
 fn correct_add(_method: &mut Method) {}
 
-fn correct_list(_method: &mut Method) {}
+fn correct_list(method: &mut Method) {
+    paginate_by_page(method, &["items"], &["paging"], None);
+    let mut root = ResponseTypeModifier::from(method);
+
+    // items is defined as Vec<Vec<_>> but should return Vec<_>
+    root.split()
+        .member_type("items")
+        .vec_type()
+        .set_to_inner(|inner| inner.vec_type());
+}
 
 fn correct_remove(_method: &mut Method) {}
@@ -1,7 +1,7 @@
 #![allow(unused_imports)]
 #![allow(clippy::single_match)]
 use crate::adapt::utils::*;
-use crate::rust::{Method, Module};
+use crate::rust::{Method, Module, ParameterDataType};
 
 mod profile;
 
@@ -24,10 +24,16 @@ pub fn correct(module: &mut Module) {
     }
 }
 
-fn correct_access_logs(_method: &mut Method) {}
+fn correct_access_logs(method: &mut Method) {
+    // Past page 100, older logins are listed with `before`
+    set_parameters_type(method, &["before"], ParameterDataType::Timestamp);
+    paginate_by_page(method, &["logins"], &["paging"], Some("date_first"));
+}
 
 fn correct_billable_info(_method: &mut Method) {}
 
 fn correct_info(_method: &mut Method) {}
 
-fn correct_integration_logs(_method: &mut Method) {}
+fn correct_integration_logs(method: &mut Method) {
+    paginate_by_page(method, &["logs"], &["paging"], None);
+}
@@ -0,0 +1,65 @@
+use crate::rust::{
+    HttpMethod, Member, Method, Module, Parameter, ParameterDataType, Response, ResponseType,
+};
+
+/// The schema has no `tooling.*` methods; `tooling.tokens.rotate` exchanges the refresh token of
+/// an app configuration token for a new pair.
+pub fn module() -> Module {
+    Module {
+        name: "tooling".into(),
+        submodules: vec![Module {
+            name: "tokens".into(),
+            submodules: Vec::new(),
+            methods: vec![rotate()],
+            extended: false,
+        }],
+        methods: Vec::new(),
+        extended: false,
+    }
+}
+
+fn rotate() -> Method {
+    let member = |name: &str, r#type: ResponseType, required: bool| Member {
+        name: name.into(),
+        r#type: Response {
+            description: None,
+            r#type,
+            required,
+        },
+    };
+    Method {
+        name: "rotate".into(),
+        full_name: "/tooling.tokens.rotate".into(),
+        description: "Exchanges a refresh token for a new app configuration token.".into(),
+        documentation_url: "https://api.slack.com/methods/tooling.tokens.rotate".into(),
+        parameters: vec![Parameter {
+            description: Some("The `xoxe` refresh token that was issued along with the old app configuration token.".into()),
+            name: "refresh_token".into(),
+            required: true,
+            param_type: ParameterDataType::String,
+        }],
+        response: Response {
+            description: None,
+            r#type: ResponseType::Object(vec![
+                member("error", ResponseType::String, false),
+                member("exp", ResponseType::Int, false),
+                member("iat", ResponseType::Int, false),
+                member("ok", ResponseType::Bool, true),
+                member("refresh_token", ResponseType::String, false),
+                member("team_id", ResponseType::String, false),
+                member("token", ResponseType::String, false),
+                member("user_id", ResponseType::String, false),
+            ]),
+            required: true,
+        },
+        http_method: HttpMethod::Post,
+        errors: vec![
+            "fatal_error".into(),
+            "invalid_arguments".into(),
+            "invalid_refresh_token".into(),
+            "ratelimited".into(),
+            "service_unavailable".into(),
+        ],
+        pagination: None,
+    }
+}
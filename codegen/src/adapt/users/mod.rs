@@ -1,7 +1,7 @@
 #![allow(unused_imports)]
 #![allow(clippy::single_match)]
 use crate::adapt::utils::*;
-use crate::rust::{Method, Module};
+use crate::rust::{Method, Module, ParameterDataType};
 
 mod profile;
 
@@ -30,7 +30,16 @@ pub fn correct(module: &mut Module) {
     }
 }
 
-fn correct_conversations(_method: &mut Method) {}
+fn correct_conversations(method: &mut Method) {
+    paginate_by_cursor(method, "channels", &["response_metadata", "next_cursor"]);
+    let mut root = ResponseTypeModifier::from(method);
+
+    // channels is defined as Vec<Vec<_>> but should return Vec<_>
+    root.split()
+        .member_type("channels")
+        .vec_type()
+        .set_to_inner(|inner| inner.vec_type());
+}
 
 fn correct_delete_photo(_method: &mut Method) {}
 
@@ -40,12 +49,28 @@ fn correct_identity(_method: &mut Method) {}
 
 fn correct_info(_method: &mut Method) {}
 
-fn correct_list(_method: &mut Method) {}
+fn correct_list(method: &mut Method) {
+    paginate_by_cursor(method, "members", &["response_metadata", "next_cursor"]);
+    let mut root = ResponseTypeModifier::from(method);
+
+    // members is defined as Vec<Vec<_>> but should return Vec<_>
+    root.split()
+        .member_type("members")
+        .vec_type()
+        .set_to_inner(|inner| inner.vec_type());
+
+    // response_metadata is defined as Vec<_> but should return a single _
+    root.split()
+        .member_type("response_metadata")
+        .set_to_inner(|inner| inner.vec_type());
+}
 
 fn correct_lookup_by_email(_method: &mut Method) {}
 
 fn correct_set_active(_method: &mut Method) {}
 
-fn correct_set_photo(_method: &mut Method) {}
+fn correct_set_photo(method: &mut Method) {
+    set_parameters_type(method, &["image"], ParameterDataType::File);
+}
 
 fn correct_set_presence(_method: &mut Method) {}
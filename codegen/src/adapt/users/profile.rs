@@ -15,4 +15,6 @@ pub fn correct(module: &mut Module) {
 
 fn correct_get(_method: &mut Method) {}
 
-fn correct_set(_method: &mut Method) {}
+fn correct_set(method: &mut Method) {
+    send_as_json(method, &["profile"]);
+}
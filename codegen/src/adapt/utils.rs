@@ -1,4 +1,7 @@
-use crate::rust::{Member, Method, Module, Parameter, Response, ResponseType};
+use crate::rust::{
+    HttpMethod, Member, Method, Module, Pagination, Parameter, ParameterDataType, Response,
+    ResponseType,
+};
 
 pub fn set_parameters_required(method: &mut Method, params: &[&str]) {
     for mut param in &mut method.parameters {
@@ -8,6 +11,378 @@ pub fn set_parameters_required(method: &mut Method, params: &[&str]) {
     }
 }
 
+pub fn set_parameters_type(method: &mut Method, params: &[&str], param_type: ParameterDataType) {
+    for mut param in &mut method.parameters {
+        if params.contains(&param.name.as_str()) {
+            param.param_type = param_type.clone();
+        }
+    }
+}
+
+/// Parameters holding a message `ts`.
+const TIMESTAMP_PARAMS: &[&str] = &[
+    "latest",
+    "message_ts",
+    "oldest",
+    "thread_ts",
+    "timestamp",
+    "ts",
+    "ts_from",
+    "ts_to",
+];
+
+/// Response members holding a message `ts`, including the last read and latest reply of a
+/// conversation.
+const TIMESTAMP_MEMBERS: &[&str] = &[
+    "last_read",
+    "latest_reply",
+    "message_ts",
+    "thread_ts",
+    "ts",
+];
+
+/// Types the message `ts` parameters and response members as `crate::Timestamp`, so they keep
+/// their microseconds instead of going through `f64` or untyped strings.
+pub fn use_timestamps(method: &mut Method) {
+    for param in &mut method.parameters {
+        if TIMESTAMP_PARAMS.contains(&param.name.as_str())
+            && matches!(
+                param.param_type,
+                ParameterDataType::String | ParameterDataType::Decimal
+            )
+        {
+            param.param_type = ParameterDataType::Timestamp;
+        }
+    }
+    use_timestamp_members(&mut method.response.r#type);
+}
+
+fn use_timestamp_members(r#type: &mut ResponseType) {
+    match r#type {
+        ResponseType::Object(members) => {
+            for member in members {
+                if TIMESTAMP_MEMBERS.contains(&member.name.as_str())
+                    && matches!(member.r#type.r#type, ResponseType::String)
+                {
+                    member.r#type.r#type = ResponseType::Timestamp;
+                } else {
+                    use_timestamp_members(&mut member.r#type.r#type);
+                }
+            }
+        }
+        ResponseType::Vec(item) => use_timestamp_members(&mut item.r#type),
+        _ => {}
+    }
+}
+
+/// Types the `blocks` of JSON bodies and the `blocks` response members as
+/// `crate::blocks::Block`s instead of untyped JSON.
+pub fn use_blocks(method: &mut Method) {
+    for param in &mut method.parameters {
+        if param.name == "blocks" && param.param_type == ParameterDataType::Json {
+            param.param_type = ParameterDataType::Typed("Vec<crate::blocks::Block>".into());
+        }
+    }
+    use_block_members(&mut method.response.r#type);
+}
+
+fn use_block_members(r#type: &mut ResponseType) {
+    match r#type {
+        ResponseType::Object(members) => {
+            for member in members {
+                if member.name == "blocks"
+                    && matches!(
+                        member.r#type.r#type,
+                        ResponseType::Vec(_) | ResponseType::RawJson
+                    )
+                {
+                    member.r#type.r#type = ResponseType::Typed("Vec<crate::blocks::Block>".into());
+                } else {
+                    use_block_members(&mut member.r#type.r#type);
+                }
+            }
+        }
+        ResponseType::Vec(item) => use_block_members(&mut item.r#type),
+        _ => {}
+    }
+}
+
+/// The ID newtypes of parameters and response members, by name.
+const ID_NAMES: &[(&str, &str)] = &[
+    ("app_id", "crate::AppId"),
+    ("bot", "crate::BotId"),
+    ("bot_id", "crate::BotId"),
+    ("bot_user_id", "crate::UserId"),
+    ("channel", "crate::ChannelId"),
+    ("channel_id", "crate::ChannelId"),
+    ("enterprise_id", "crate::EnterpriseId"),
+    ("file", "crate::FileId"),
+    ("file_id", "crate::FileId"),
+    ("team", "crate::TeamId"),
+    ("team_id", "crate::TeamId"),
+    ("user", "crate::UserId"),
+    ("user_id", "crate::UserId"),
+    ("usergroup", "crate::UsergroupId"),
+    ("usergroup_id", "crate::UsergroupId"),
+];
+
+/// The ID newtypes of the `id` members of objects, by the name of the member holding them.
+const ID_OBJECTS: &[(&str, &str)] = &[
+    ("authed_user", "crate::UserId"),
+    ("bot", "crate::BotId"),
+    ("bot_profile", "crate::BotId"),
+    ("channel", "crate::ChannelId"),
+    ("channels", "crate::ChannelId"),
+    ("enterprise", "crate::EnterpriseId"),
+    ("enterprise_user", "crate::UserId"),
+    ("file", "crate::FileId"),
+    ("files", "crate::FileId"),
+    ("members", "crate::UserId"),
+    ("primary_owner", "crate::UserId"),
+    ("self", "crate::UserId"),
+    ("team", "crate::TeamId"),
+    ("user", "crate::UserId"),
+    ("usergroup", "crate::UsergroupId"),
+    ("usergroups", "crate::UsergroupId"),
+];
+
+/// Response members that hold a name rather than an ID, by method and path.
+const NAME_MEMBERS: &[(&str, &str)] = &[
+    ("auth.test", "team"),
+    ("auth.test", "user"),
+    ("oauth.access", "incoming_webhook.channel"),
+    ("oauth.v2.access", "incoming_webhook.channel"),
+];
+
+fn id_type(ids: &[(&str, &str)], name: &str) -> Option<String> {
+    ids.iter()
+        .find(|(n, _)| *n == name)
+        .map(|(_, id)| id.to_string())
+}
+
+/// Types the string parameters and response members holding user, channel, team and other IDs
+/// as the ID newtypes such as `crate::ChannelId`.
+pub fn use_ids(method: &mut Method) {
+    for param in &mut method.parameters {
+        if param.param_type == ParameterDataType::String {
+            if let Some(id) = id_type(ID_NAMES, &param.name) {
+                if let Some(description) = &mut param.description {
+                    if description.contains("or a name") {
+                        description.push_str(&format!(
+                            " Pass a name with `{}::new_unchecked`.",
+                            id
+                        ));
+                    }
+                }
+                param.param_type = ParameterDataType::Id(id);
+                // IDs have no empty value, so requests can only derive `Default` with them optional
+                param.required = false;
+            }
+        }
+    }
+    let names = NAME_MEMBERS
+        .iter()
+        .filter(|(m, _)| *m == method.full_name.trim_start_matches('/'))
+        .map(|(_, name)| *name)
+        .collect::<Vec<_>>();
+    use_id_members(&mut method.response.r#type, None, "", &names);
+}
+
+fn use_id_members(r#type: &mut ResponseType, parent: Option<&str>, path: &str, names: &[&str]) {
+    match r#type {
+        ResponseType::Object(members) => {
+            for member in members {
+                let member_path = if path.is_empty() {
+                    member.name.clone()
+                } else {
+                    format!("{}.{}", path, member.name)
+                };
+                let id = match member.name.as_str() {
+                    _ if names.contains(&member_path.as_str()) => None,
+                    "id" => parent.and_then(|parent| id_type(ID_OBJECTS, parent)),
+                    name => id_type(ID_NAMES, name),
+                };
+                match id {
+                    Some(id) if matches!(member.r#type.r#type, ResponseType::String) => {
+                        member.r#type.r#type = ResponseType::Typed(id);
+                    }
+                    _ => use_id_members(
+                        &mut member.r#type.r#type,
+                        Some(&member.name),
+                        &member_path,
+                        names,
+                    ),
+                }
+            }
+        }
+        ResponseType::Vec(item) => use_id_members(&mut item.r#type, parent, path, names),
+        _ => {}
+    }
+}
+
+/// Adds the members Slack sends along with errors, such as the `needed` scopes of `missing_scope`
+/// errors, so that the method's error keeps them.
+pub fn add_error_members(method: &mut Method) {
+    let members = match &mut method.response.r#type {
+        ResponseType::Object(members) => members,
+        _ => return,
+    };
+    let optional = |name: &str, r#type: ResponseType| Member {
+        name: name.into(),
+        r#type: Response {
+            description: None,
+            r#type,
+            required: false,
+        },
+    };
+    for name in &["error", "needed", "provided", "warning"] {
+        if !members.iter().any(|m| m.name == *name) {
+            members.push(optional(name, ResponseType::String));
+        }
+    }
+    let strings = || {
+        ResponseType::Vec(Box::new(Response {
+            description: None,
+            r#type: ResponseType::String,
+            required: true,
+        }))
+    };
+    match members.iter_mut().find(|m| m.name == "response_metadata") {
+        Some(Member {
+            r#type:
+                Response {
+                    r#type: ResponseType::Object(metadata),
+                    ..
+                },
+            ..
+        }) => {
+            for name in &["messages", "warnings"] {
+                if !metadata.iter().any(|m| m.name == *name) {
+                    metadata.push(optional(name, strings()));
+                }
+            }
+            metadata.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+        }
+        Some(metadata) => {
+            metadata.r#type.r#type = ResponseType::Typed("crate::ResponseMetadata".into());
+        }
+        None => members.push(optional(
+            "response_metadata",
+            ResponseType::Typed("crate::ResponseMetadata".into()),
+        )),
+    }
+    members.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+}
+
+/// Sends `client_id` and `client_secret` with HTTP Basic auth instead of as form parameters, as
+/// Slack prefers for the `oauth.*` methods.
+pub fn send_with_client_auth(method: &mut Method) {
+    set_parameters_required(method, &["client_id", "client_secret"]);
+    method.http_method = HttpMethod::PostClientAuth;
+}
+
+/// Sends the method as a JSON body, with `json_params` embedded as JSON instead of strings.
+pub fn send_as_json(method: &mut Method, json_params: &[&str]) {
+    method.http_method = HttpMethod::PostJson;
+    set_parameters_type(method, json_params, ParameterDataType::Json);
+    for param in &mut method.parameters {
+        if json_params.contains(&param.name.as_str()) {
+            param.description = param.description.as_deref().map(drop_encoding_notes);
+        }
+    }
+}
+
+/// Drops what the spec says about encoding a parameter as a string, which no longer holds once
+/// it is embedded as JSON.
+fn drop_encoding_notes(description: &str) -> String {
+    description
+        .replace(", presented as a URL-encoded string", "")
+        .replace("URL-encoded JSON", "JSON")
+        .replace(" This must be a JSON-encoded string.", "")
+}
+
+/// Generates `*_stream`/`*_iter` functions that follow `next_cursor` across the pages of `items`.
+pub fn paginate_by_cursor(method: &mut Method, items: &str, next_cursor: &[&str]) {
+    method.pagination = Some(Pagination::Cursor {
+        items: items.into(),
+        next_cursor: next_cursor.iter().map(|s| s.to_string()).collect(),
+    });
+}
+
+/// Adds `items`, a list of `item`s the spec is missing, and generates `*_stream`/`*_iter`
+/// functions that follow `response_metadata.next_cursor` across its pages.
+pub fn paginate_missing_by_cursor(method: &mut Method, items: &str, item: ResponseType) {
+    let item = Response {
+        description: None,
+        r#type: item,
+        required: true,
+    };
+    add_response_member(
+        method,
+        response_member(items, ResponseType::Vec(Box::new(item)), false),
+    );
+    paginate_by_cursor(method, items, &["response_metadata", "next_cursor"]);
+}
+
+/// Generates `*_stream`/`*_iter` functions that follow the `page` numbers in `paging` across the
+/// pages of `items`, continuing past page 100 with the oldest `before` member of the items if set.
+pub fn paginate_by_page(
+    method: &mut Method,
+    items: &[&str],
+    paging: &[&str],
+    before: Option<&str>,
+) {
+    method.pagination = Some(Pagination::Pages {
+        items: items.iter().map(|s| s.to_string()).collect(),
+        paging: paging.iter().map(|s| s.to_string()).collect(),
+        before: before.map(Into::into),
+    });
+}
+
+/// A response member for the members the spec is missing.
+pub fn response_member(name: &str, r#type: ResponseType, required: bool) -> Member {
+    Member {
+        name: name.into(),
+        r#type: Response {
+            description: None,
+            r#type,
+            required,
+        },
+    }
+}
+
+/// Adds `response_metadata.next_cursor` to responses that are missing it in the spec.
+pub fn add_response_metadata(method: &mut Method) {
+    let next_cursor = Member {
+        name: "next_cursor".into(),
+        r#type: Response {
+            description: None,
+            r#type: ResponseType::String,
+            required: false,
+        },
+    };
+    add_response_member(
+        method,
+        Member {
+            name: "response_metadata".into(),
+            r#type: Response {
+                description: None,
+                r#type: ResponseType::Object(vec![next_cursor]),
+                required: false,
+            },
+        },
+    );
+}
+
+pub fn add_response_member(method: &mut Method, member: Member) {
+    if let ResponseType::Object(members) = &mut method.response.r#type {
+        if !members.iter().any(|m| m.name == member.name) {
+            members.push(member);
+            members.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+        }
+    }
+}
+
 pub fn add_parameters(method: &mut Method, params: Vec<Parameter>) {
     for param in params {
         if !method.parameters.iter().any(|p| p.name == param.name) {
@@ -19,6 +394,15 @@ pub fn add_parameters(method: &mut Method, params: Vec<Parameter>) {
         .sort_unstable_by(|a, b| a.name.cmp(&b.name));
 }
 
+pub fn add_methods(module: &mut Module, methods: Vec<Method>) {
+    for method in methods {
+        if !module.methods.iter().any(|m| m.name == method.name) {
+            module.methods.push(method);
+        }
+    }
+    module.methods.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+}
+
 pub struct ResponseTypeModifier<'a>(Option<&'a mut ResponseType>);
 
 impl<'a> ResponseTypeModifier<'a> {
@@ -1,7 +1,7 @@
 #![allow(unused_imports)]
 #![allow(clippy::single_match)]
 use crate::adapt::utils::*;
-use crate::rust::{Method, Module};
+use crate::rust::{Member, Method, Module, ParameterDataType, Response, ResponseType};
 
 pub fn correct(module: &mut Module) {
     for mut method in &mut module.methods {
@@ -15,10 +15,47 @@ pub fn correct(module: &mut Module) {
     }
 }
 
-fn correct_open(_method: &mut Method) {}
+fn correct_open(method: &mut Method) {
+    send_as_json(method, &["view"]);
+    use_views(method, "crate::blocks::ModalView");
+}
+
+fn correct_publish(method: &mut Method) {
+    send_as_json(method, &["view"]);
+    use_views(method, "crate::blocks::HomeView");
+}
 
-fn correct_publish(_method: &mut Method) {}
+fn correct_push(method: &mut Method) {
+    send_as_json(method, &["view"]);
+    use_views(method, "crate::blocks::ModalView");
+}
 
-fn correct_push(_method: &mut Method) {}
+fn correct_update(method: &mut Method) {
+    send_as_json(method, &["view"]);
+    // Home tabs can be updated as well as modals
+    set_parameters_required(method, &["view"]);
+    use_views(method, "crate::blocks::UpdatedView");
+}
 
-fn correct_update(_method: &mut Method) {}
+/// Types the `view` argument as `view_type`, and adds the `view` and `error` the spec is
+/// missing from the response.
+fn use_views(method: &mut Method, view_type: &str) {
+    set_parameters_type(
+        method,
+        &["view"],
+        ParameterDataType::Typed(view_type.into()),
+    );
+    let member = |name: &str, r#type: ResponseType| Member {
+        name: name.into(),
+        r#type: Response {
+            description: None,
+            r#type,
+            required: false,
+        },
+    };
+    add_response_member(method, member("error", ResponseType::String));
+    add_response_member(
+        method,
+        member("view", ResponseType::Typed("crate::blocks::View".into())),
+    );
+}
@@ -205,6 +205,7 @@ fn create_method(
         response,
         http_method,
         errors,
+        pagination: None,
     };
     for parameter in &op.parameters {
         let parameter = match parameter.location.as_ref() {
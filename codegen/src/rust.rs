@@ -59,6 +59,7 @@ impl ModuleBuilder<'_> {
             name: self.name,
             submodules,
             methods,
+            extended: false,
         }
     }
 }
@@ -68,6 +69,8 @@ pub struct Module {
     pub name: String,
     pub submodules: Vec<Module>,
     pub methods: Vec<Method>,
+    /// Re-export handwritten helpers from `async_impl::ext` and `sync::ext`; set by the adapt step.
+    pub extended: bool,
 }
 
 impl Module {
@@ -174,9 +177,18 @@ impl Module {
                 format!(
                     "use std::borrow::Cow;
                     {import}
-                    pub use crate::mod_types::{path}::*;",
+                    pub use crate::mod_types::{path}::*;{ext}",
                     import = gen_mode.import(),
                     path = path,
+                    ext = if self.extended {
+                        format!(
+                            "\npub use {ext_mod}::{path}::*;",
+                            ext_mod = gen_mode.ext_mod(),
+                            path = path.trim_end_matches("_types"),
+                        )
+                    } else {
+                        String::new()
+                    },
                 ),
             )
         };
@@ -257,6 +269,14 @@ impl GenMode {
         }
     }
 
+    fn ext_mod(self) -> &'static str {
+        match self {
+            GenMode::Async => "crate::async_impl::ext",
+            GenMode::Sync => "crate::sync::ext",
+            _ => unreachable!(),
+        }
+    }
+
     fn dot_await(self) -> &'static str {
         match self {
             GenMode::Async => ".await",
@@ -283,18 +303,42 @@ pub struct Method {
     pub response: Response,
     pub http_method: HttpMethod,
     pub errors: Vec<String>,
+    /// Set by the adapt step for list methods to also generate `*_stream`/`*_iter` functions.
+    pub pagination: Option<Pagination>,
+}
+
+/// How a list method pages through its results.
+#[derive(Clone, Debug)]
+pub enum Pagination {
+    Cursor {
+        /// The response member holding the items of a page.
+        items: String,
+        /// The path to the next cursor in the response, e.g. `response_metadata.next_cursor`.
+        next_cursor: Vec<String>,
+    },
+    Pages {
+        /// The path to the items of a page in the response, e.g. `messages.matches`.
+        items: Vec<String>,
+        /// The path to the `paging` object holding `page` and `pages`.
+        paging: Vec<String>,
+        /// The item member whose oldest value is sent as `before` to continue past page 100.
+        before: Option<String>,
+    },
 }
 
 impl Method {
+    /// Whether the request type borrows some of its parameters, and so has a lifetime.
+    fn borrows_parameters(&self) -> bool {
+        self.parameters
+            .iter()
+            .filter(|p| p.name != "token")
+            .any(|p| p.param_type.is_borrowed())
+    }
+
     fn build_request_type(&self) -> Result<String> {
         let type_prefix = self.name.to_pascal_case();
         let struct_name = format!("{}Request", type_prefix);
-        let lifetime = if self
-            .parameters
-            .iter()
-            .filter(|p| p.name != "token")
-            .any(|p| p.param_type == ParameterDataType::String)
-        {
+        let lifetime = if self.borrows_parameters() {
             "<'a>"
         } else {
             ""
@@ -315,7 +359,74 @@ impl Method {
             parameters = parameters,
             lifetime = lifetime,
         );
-        Ok(out)
+        match &self.pagination {
+            Some(Pagination::Cursor { .. }) => Ok(format!(
+                "{out}
+
+                impl crate::pagination::CursorRequest for {name}{anonymous} {{
+                    fn set_cursor(&mut self, cursor: String) {{
+                        self.cursor = Some(cursor.into());
+                    }}
+                }}",
+                out = out,
+                name = struct_name,
+                anonymous = lifetime.replace('a', "_"),
+            )),
+            Some(Pagination::Pages { before, .. }) => {
+                let set_before = match before {
+                    Some(_) => format!(
+                        "
+
+                        fn set_before(&mut self, before: crate::Timestamp) {{
+                            self.before = {};
+                        }}",
+                        self.set_request_param("before", "before")?
+                    ),
+                    None => String::new(),
+                };
+                Ok(format!(
+                    "{out}
+
+                    impl crate::pagination::PageRequest for {name}{anonymous} {{
+                        fn set_page(&mut self, page: u64) {{
+                            self.page = {set_page};
+                        }}{set_before}
+                    }}",
+                    out = out,
+                    name = struct_name,
+                    anonymous = lifetime.replace('a', "_"),
+                    set_page = self.set_request_param("page", "page")?,
+                    set_before = set_before,
+                ))
+            }
+            None => Ok(out),
+        }
+    }
+
+    /// The value to assign to the optional request parameter `name` from the variable `var`,
+    /// which is a `u64` for `page` and a `crate::Timestamp` for `before`.
+    fn set_request_param(&self, name: &str, var: &str) -> Result<String> {
+        let param = self
+            .parameters
+            .iter()
+            .find(|p| p.name == name)
+            .with_context(|| format!("Request of {} has no parameter {}", self.name, name))?;
+        if param.required {
+            bail!("Parameter {} of {} is required", name, self.name);
+        }
+        let value = match (&param.param_type, var) {
+            (ParameterDataType::String, _) => format!("Some({}.to_string().into())", var),
+            (ParameterDataType::Int, "page") | (ParameterDataType::Timestamp, "before") => {
+                format!("Some({})", var)
+            }
+            (t, _) => bail!(
+                "Parameter {} of {} has unsupported type {:?}",
+                name,
+                self.name,
+                t
+            ),
+        };
+        Ok(value)
     }
 
     fn build_response_type(&self) -> Result<String> {
@@ -329,66 +440,292 @@ impl Method {
             true,
             &mut HashSet::new(),
         )?;
-        let out = r#types.join("\n\n");
+        let mut out = r#types.join("\n\n");
+        if let Some(Pagination::Cursor { items, next_cursor }) = &self.pagination {
+            out = format!(
+                "{out}
+
+                impl crate::pagination::CursorPage for {name} {{
+                    type Item = {item};
+
+                    fn next_cursor(&self) -> Option<&str> {{
+                        {next_cursor}
+                    }}
+
+                    fn into_items(self) -> Vec<Self::Item> {{
+                        {items}
+                    }}
+                }}",
+                out = out,
+                name = struct_name,
+                item = self.page_item_type(&[items])?,
+                next_cursor = self.next_cursor_expr(next_cursor)?,
+                items = if self.response_member(&[items.as_str()])?.required {
+                    format!("self.{}", items)
+                } else {
+                    format!("self.{}.unwrap_or_default()", items)
+                },
+            );
+        }
+        if let Some(Pagination::Pages {
+            items,
+            paging,
+            before,
+        }) = &self.pagination
+        {
+            let continue_before = match before {
+                Some(before) => format!(
+                    "
+
+                    fn continue_before(&self) -> Option<crate::Timestamp> {{
+                        {}
+                    }}",
+                    self.continue_before_expr(items, before)?
+                ),
+                None => String::new(),
+            };
+            let paging_member = |name: &str| {
+                let mut path = paging.clone();
+                path.push(name.into());
+                self.path_expr(&path, true).map(|(expr, optional)| {
+                    if optional {
+                        expr
+                    } else {
+                        format!("Some({})", expr)
+                    }
+                })
+            };
+            let (items_expr, items_optional) = self.path_expr(items, false)?;
+            out = format!(
+                "{out}
+
+                impl crate::pagination::NumberedPage for {name} {{
+                    type Item = {item};
+
+                    fn page(&self) -> Option<u64> {{
+                        {page}
+                    }}
+
+                    fn pages(&self) -> Option<u64> {{
+                        {pages}
+                    }}
+
+                    fn into_items(self) -> Vec<Self::Item> {{
+                        {items}
+                    }}{continue_before}
+                }}",
+                out = out,
+                name = struct_name,
+                item = self.page_item_type(items)?,
+                page = paging_member("page")?,
+                pages = paging_member("pages")?,
+                items = if items_optional {
+                    format!("{}.unwrap_or_default()", items_expr)
+                } else {
+                    items_expr
+                },
+                continue_before = continue_before,
+            );
+        }
         Ok(out)
     }
 
+    /// The expression reaching the response member at `path` from `self`, and whether it is
+    /// wrapped in an `Option` because a member on the way is optional.
+    ///
+    /// Members before the last one are borrowed if `by_ref` is set, else moved; the last one is
+    /// always moved, or copied.
+    fn path_expr(&self, path: &[String], by_ref: bool) -> Result<(String, bool)> {
+        let mut expr = "self".to_string();
+        let mut optional = false;
+        for (i, name) in path.iter().enumerate() {
+            let required = self.response_member(&path[..=i])?.required;
+            let borrow = by_ref && i + 1 < path.len();
+            expr = match (optional, required, borrow) {
+                (false, true, _) | (false, false, false) => format!("{}.{}", expr, name),
+                (false, false, true) => format!("{}.{}.as_ref()", expr, name),
+                (true, true, true) => format!("{}.map(|{p}| &{p}.{})", expr, name, p = path[i - 1]),
+                (true, false, true) => format!(
+                    "{}.and_then(|{p}| {p}.{}.as_ref())",
+                    expr,
+                    name,
+                    p = path[i - 1]
+                ),
+                (true, true, false) => format!("{}.map(|{p}| {p}.{})", expr, name, p = path[i - 1]),
+                (true, false, false) => {
+                    format!("{}.and_then(|{p}| {p}.{})", expr, name, p = path[i - 1])
+                }
+            };
+            optional |= !required;
+        }
+        Ok((expr, optional))
+    }
+
+    /// The oldest value of the `before` member among the items, as a `crate::Timestamp`.
+    fn continue_before_expr(&self, items: &[String], before: &str) -> Result<String> {
+        if items.iter().enumerate().any(|(i, _)| {
+            !self
+                .response_member(&items[..=i])
+                .map_or(false, |m| m.required)
+        }) {
+            bail!("Items {:?} of {} must be required", items, self.name);
+        }
+        let member = match &self.response_member(items)?.r#type {
+            ResponseType::Vec(item) => match &item.r#type {
+                ResponseType::Object(members) => members.iter().find(|m| m.name == before),
+                _ => None,
+            },
+            _ => None,
+        }
+        .with_context(|| format!("Items of {} have no member {}", self.name, before))?;
+        let values = if member.r#type.required {
+            format!("map(|item| item.{})", before)
+        } else {
+            format!("filter_map(|item| item.{})", before)
+        };
+        Ok(format!(
+            "self.{}.iter().{}.min().map(crate::Timestamp::from)",
+            items.join("."),
+            values
+        ))
+    }
+
+    /// The response member at `path`, for the adapt step's pagination settings.
+    fn response_member<S: AsRef<str>>(&self, path: &[S]) -> Result<&Response> {
+        let mut response = &self.response;
+        for name in path {
+            response = match &response.r#type {
+                ResponseType::Object(members) => members
+                    .iter()
+                    .find(|m| m.name == name.as_ref())
+                    .map(|m| &m.r#type),
+                _ => None,
+            }
+            .with_context(|| {
+                format!("Response of {} has no member {}", self.name, name.as_ref())
+            })?;
+        }
+        Ok(response)
+    }
+
+    /// The Rust type of one item of the `items` member, named the way `Member::to_rust` names it.
+    fn page_item_type<S: AsRef<str>>(&self, items: &[S]) -> Result<String> {
+        match &self.response_member(items)?.r#type {
+            ResponseType::Vec(item) => {
+                let last = items.last().map_or("", AsRef::as_ref);
+                let name = format!("{}{}", self.name.to_pascal_case(), last.to_pascal_case());
+                let (r#type, _) = item.to_rust(
+                    &self.name.to_pascal_case(),
+                    Some(&name),
+                    "",
+                    false,
+                    &mut HashSet::new(),
+                )?;
+                Ok(r#type)
+            }
+            t => bail!(
+                "Items {} of {} are not a list but {:?}",
+                items
+                    .iter()
+                    .map(AsRef::as_ref)
+                    .collect::<Vec<_>>()
+                    .join("."),
+                self.name,
+                t
+            ),
+        }
+    }
+
+    fn next_cursor_expr(&self, path: &[String]) -> Result<String> {
+        let parent = &path[..path.len().saturating_sub(1)];
+        let cursor_required = match &self.response_member(parent)?.r#type {
+            // The shared `crate::ResponseMetadata`, whose `next_cursor` is optional
+            ResponseType::Typed(_) => false,
+            _ => self.response_member(path)?.required,
+        };
+        let expr = match path {
+            [cursor] if cursor_required => format!("Some(self.{}.as_str())", cursor),
+            [cursor] => format!("self.{}.as_deref()", cursor),
+            [parent, cursor] => match (self.response_member(&path[..1])?.required, cursor_required)
+            {
+                (true, true) => format!("Some(self.{}.{}.as_str())", parent, cursor),
+                (true, false) => format!("self.{}.{}.as_deref()", parent, cursor),
+                (false, true) => format!(
+                    "self.{parent}.as_ref().map(|{parent}| {parent}.{cursor}.as_str())",
+                    parent = parent,
+                    cursor = cursor
+                ),
+                (false, false) => format!(
+                    "self.{parent}.as_ref().and_then(|{parent}| {parent}.{cursor}.as_deref())",
+                    parent = parent,
+                    cursor = cursor
+                ),
+            },
+            _ => bail!("Unsupported cursor path {:?} for {}", path, self.name),
+        };
+        Ok(expr)
+    }
+
     fn build_error_type(&self) -> Result<String> {
         let type_prefix = self.name.to_pascal_case();
         let struct_name = format!("{}Error", type_prefix);
-        let mut custom_errors = self
-            .errors
-            .iter()
-            .map(|e| e.to_pascal_case())
-            .collect::<Vec<_>>()
-            .join(",\n");
-        if !custom_errors.is_empty() {
-            custom_errors.push(',');
-            custom_errors.push('\n');
-        }
-        let mut custom_errors_from = self
-            .errors
-            .iter()
-            .map(|e| format!("\"{}\" => {}::{}", e, struct_name, e.to_pascal_case()))
-            .collect::<Vec<_>>()
-            .join(",\n");
-        if !custom_errors_from.is_empty() {
-            custom_errors_from.push(',');
-            custom_errors_from.push('\n');
-        }
-        let mut custom_errors_dis = self
+        let custom = |f: &dyn Fn(&str, String) -> String| {
+            let mut out = self
+                .errors
+                .iter()
+                .map(|e| f(e, e.to_pascal_case()))
+                .collect::<Vec<_>>()
+                .join(",\n");
+            if !out.is_empty() {
+                out.push(',');
+                out.push('\n');
+            }
+            out
+        };
+        let custom_errors = custom(&|_, variant| format!("{}(crate::ErrorContext)", variant));
+        let custom_errors_new =
+            custom(&|e, variant| format!("\"{}\" => {}::{}(context)", e, struct_name, variant));
+        let custom_errors_dis = custom(&|e, variant| {
+            format!(
+                "{}::{}(_) => write!(f, \"Server returned error {}\")",
+                struct_name, variant, e
+            )
+        });
+        let custom_errors_code =
+            custom(&|e, variant| format!("{}::{}(_) => Some(\"{}\")", struct_name, variant, e));
+        let custom_errors_context = self
             .errors
             .iter()
-            .map(|e| {
-                format!(
-                    "{}::{} => write!(f, \"Server returned error {}\")",
-                    struct_name,
-                    e.to_pascal_case(),
-                    e
-                )
-            })
+            .map(|e| format!("{}::{}(ref context)", struct_name, e.to_pascal_case()))
+            .chain(std::iter::once(format!(
+                "{}::Unknown(_, ref context)",
+                struct_name
+            )))
             .collect::<Vec<_>>()
-            .join(",\n");
-        if !custom_errors_dis.is_empty() {
-            custom_errors_dis.push(',');
-            custom_errors_dis.push('\n');
-        }
+            .join(" | ");
         let out = format!(
             "#[derive(Debug)]
             pub enum {name}<E: Error> {{
                 {custom_errors}/// The response was not parseable as the expected object
                 MalformedResponse(String, serde_json::error::Error),
                 /// The response returned an error that was unknown to the library
-                Unknown(String),
+                Unknown(String, crate::ErrorContext),
                 /// The client had an error sending the request to Slack
                 Client(E),
             }}
+
+            impl<E: Error> {name}<E> {{
+                /// The error for an error string Slack returned, with what else Slack said about it.
+                pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {{
+                    match code {{
+                        {custom_errors_new}_ => {name}::Unknown(code.to_owned(), context),
+                    }}
+                }}
+            }}
             
             impl<'a, E: Error> From<&'a str> for {name}<E> {{
                 fn from(s: &'a str) -> Self {{
-                    match s {{
-                        {custom_errors_from}_ => {name}::Unknown(s.to_owned()),
-                    }}
+                    Self::new(s, crate::ErrorContext::default())
                 }}
             }}
 
@@ -396,7 +733,7 @@ impl Method {
                 fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {{
                     match *self {{
                         {custom_errors_dis}{name}::MalformedResponse(_, ref e) => write!(f, \"{{}}\", e),
-                        {name}::Unknown(ref s) => write!(f, \"{{}}\", s),
+                        {name}::Unknown(ref s, _) => write!(f, \"{{}}\", s),
                         {name}::Client(ref inner) => write!(f, \"{{}}\", inner),
                     }}
                 }}
@@ -410,23 +747,44 @@ impl Method {
                         _ => None,
                     }}
                 }}
+            }}
+
+            impl<E: Error + 'static> crate::MethodError for {name}<E> {{
+                fn code(&self) -> Option<&str> {{
+                    match *self {{
+                        {custom_errors_code}{name}::Unknown(ref s, _) => Some(s),
+                        {name}::MalformedResponse(..) | {name}::Client(_) => None,
+                    }}
+                }}
+
+                fn kind(&self) -> crate::ErrorKind {{
+                    match *self {{
+                        {name}::MalformedResponse(..) => crate::ErrorKind::Malformed,
+                        {name}::Client(_) => crate::ErrorKind::Client,
+                        _ => self.code().map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+                    }}
+                }}
+
+                fn context(&self) -> Option<&crate::ErrorContext> {{
+                    match *self {{
+                        {custom_errors_context} => Some(context),
+                        {name}::MalformedResponse(..) | {name}::Client(_) => None,
+                    }}
+                }}
             }}",
             name = struct_name,
             custom_errors = custom_errors,
-            custom_errors_from = custom_errors_from,
+            custom_errors_new = custom_errors_new,
             custom_errors_dis = custom_errors_dis,
+            custom_errors_code = custom_errors_code,
+            custom_errors_context = custom_errors_context,
         );
         Ok(out)
     }
 
     fn build_call_method(&self, gen_mode: GenMode) -> Result<String> {
         let type_prefix = self.name.to_pascal_case();
-        let req_lt = if self
-            .parameters
-            .iter()
-            .filter(|p| p.name != "token")
-            .any(|p| p.param_type == ParameterDataType::String)
-        {
+        let req_lt = if self.borrows_parameters() {
             "<'_>"
         } else {
             ""
@@ -435,49 +793,16 @@ impl Method {
         let response_type = format!("{}Response", type_prefix);
         let error_type = format!("{}Error", type_prefix);
         let fn_name = self.name.to_snake_case();
-        let parameter_converts = self
-            .parameters
-            .iter()
-            .filter(|p| p.name != "token")
-            .filter(|p| p.param_type != ParameterDataType::String)
-            .map(Parameter::to_rust_fn_convert)
-            .collect::<Vec<_>>()
-            .join("\n");
-        let parameters = self
-            .parameters
-            .iter()
-            .filter(|p| p.name != "token")
-            .map(Parameter::to_rust_fn)
-            .collect::<Vec<_>>()
-            .join("\n");
         let token = self.parameters.iter().find(|p| p.name == "token");
-        let headers = match self.http_method {
-            HttpMethod::Get => "",
-            HttpMethod::Post => {
-                if let Some(token) = token {
-                    if token.required {
-                        ", &[(\"token\", token)]"
-                    } else {
-                        ", &token.map_or(vec![], |t| vec![(\"token\", t)])"
-                    }
-                } else {
-                    ", &[]"
-                }
-            }
+        let token_arg = match token {
+            Some(token) if token.required => "Some(token)",
+            Some(_) => "token",
+            None => "None",
         };
-        let params = match self.http_method {
-            HttpMethod::Post => "",
-            HttpMethod::Get => {
-                if let Some(token) = token {
-                    if token.required {
-                        "Some((\"token\", token)),"
-                    } else {
-                        "token.map(|token| (\"token\", token)),"
-                    }
-                } else {
-                    ""
-                }
-            }
+        let dot_await = gen_mode.dot_await();
+        let (has_params, build_params, send) = match self.http_method {
+            HttpMethod::PostJson => self.build_json_body(token_arg, dot_await),
+            _ => self.build_form_params(token_arg, dot_await),
         };
         let token_param = if let Some(token) = token {
             if token.required {
@@ -488,7 +813,7 @@ impl Method {
         } else {
             ""
         };
-        let empty_param = if parameters.is_empty() { "_" } else { "" };
+        let empty_param = if has_params { "" } else { "_" };
         let out = format!(
             "/// {description}
             ///
@@ -501,20 +826,29 @@ impl Method {
             where
                 R: SlackWebRequestSender,
             {{
-                {parameter_converts}
-                let params: Vec<Option<(&str, &str)>> = vec![
-                    {params}{parameters}
-                ];
-                let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-                let url = crate::get_slack_url_for_method(\"{full_name}\");
-                client
-                    .{method}(&url, &params[..]{headers}){dot_await}
+                {build_params}
+                let url = crate::get_slack_url_for_method(client.base_url(), \"{full_name}\");
+                {send}
                     .map_err({error_type}::Client)
                     .and_then(|result| {{
-                        serde_json::from_str::<{response_type}>(&result)
-                            .map_err(|e| {error_type}::MalformedResponse(result, e))
+                        let (http, body) = result.into_parts();
+                        serde_json::from_str::<{response_type}>(&body)
+                            .map(|mut response| {{
+                                response.http = http;
+                                response
+                            }})
+                            .map_err(|e| match crate::error::read_error(&body) {{
+                                Some((code, context)) => {error_type}::new(&code, context),
+                                None => {error_type}::MalformedResponse(body, e),
+                            }})
                     }})
                     .and_then(|o| o.into())
+                    .inspect(|response| {{
+                        let warnings = crate::SlackResponse::warnings(response);
+                        if !warnings.is_empty() {{
+                            client.on_warnings(\"{full_name}\", &warnings);
+                        }}
+                    }})
             }}",
             description = self.description,
             doc_url = self.documentation_url,
@@ -522,19 +856,240 @@ impl Method {
             response_type = response_type,
             error_type = error_type,
             fn_name = fn_name,
-            full_name = self.full_name,
+            full_name = self.full_name.trim_start_matches('/'),
             fn_type = gen_mode.fn_type(),
-            dot_await = gen_mode.dot_await(),
-            parameters = parameters,
-            method=self.http_method.method(),
-            headers=headers,
-            token_param=token_param,
-            params = params,
+            build_params = build_params,
+            send = send,
+            token_param = token_param,
             empty_param = empty_param,
-            parameter_converts = parameter_converts,
+        );
+        match &self.pagination {
+            Some(pagination) => Ok(format!(
+                "{}\n{}",
+                out,
+                self.build_paginated_fn(gen_mode, pagination)?
+            )),
+            None => Ok(out),
+        }
+    }
+
+    /// Builds `{fn_name}_stream` or `{fn_name}_iter`, which follow the cursor or the page
+    /// numbers across pages.
+    fn build_paginated_fn(&self, gen_mode: GenMode, pagination: &Pagination) -> Result<String> {
+        let type_prefix = self.name.to_pascal_case();
+        let fn_name = self.name.to_snake_case();
+        let token_param = match self.parameters.iter().find(|p| p.name == "token") {
+            Some(token) if token.required => "token: &'a str,",
+            Some(_) => "token: Option<&'a str>,",
+            None => "",
+        };
+        let token_arg = if token_param.is_empty() {
+            ""
+        } else {
+            " token,"
+        };
+        let (kind, items, item, follows) = match pagination {
+            Pagination::Cursor { items, next_cursor } => (
+                "cursor",
+                items.clone(),
+                self.page_item_type(&[items])?,
+                format!("Follows `{}` until it is empty", next_cursor.join(".")),
+            ),
+            Pagination::Pages {
+                items,
+                paging,
+                before,
+            } => (
+                "page",
+                items.join("."),
+                self.page_item_type(items)?,
+                match before {
+                    Some(before) => format!(
+                        "Follows `{paging}.page` up to `{paging}.pages`, continuing past page 100 with
+                        /// `before` set to the oldest `{before}` seen",
+                        paging = paging.join("."),
+                        before = before,
+                    ),
+                    None => format!(
+                        "Follows `{paging}.page` up to `{paging}.pages`",
+                        paging = paging.join(".")
+                    ),
+                },
+            ),
+        };
+        let (suffix, noun, return_type, body) = match gen_mode {
+            GenMode::Async => (
+                "stream",
+                "stream",
+                "futures_util::Stream",
+                format!(
+                    "crate::async_impl::pagination::{kind}_stream(request.clone(), max_items, move |request| async move {{
+                        {fn_name}(client,{token_arg} &request).await
+                    }})",
+                    kind = kind,
+                    fn_name = fn_name,
+                    token_arg = token_arg,
+                ),
+            ),
+            GenMode::Sync => (
+                "iter",
+                "iteration",
+                "Iterator",
+                format!(
+                    "crate::sync::pagination::{kind}_iter(request.clone(), max_items, move |request| {{
+                        {fn_name}(client,{token_arg} request)
+                    }})",
+                    kind = kind,
+                    fn_name = fn_name,
+                    token_arg = token_arg,
+                ),
+            ),
+            GenMode::Types => unreachable!(),
+        };
+        let out = format!(
+            "/// Calls [`{fn_name}`] page by page and yields the `{items}` of every page.
+            ///
+            /// {follows}, and stops early once
+            /// `max_items` items were yielded. An error is yielded once and ends the {noun}.
+            pub fn {fn_name}_{suffix}<'a, R>(
+                client: &'a R,{token_param}
+                request: &{type_prefix}Request{lifetime},
+                max_items: Option<usize>,
+            ) -> impl {return_type}<Item = Result<{item}, {type_prefix}Error<R::Error>>> + 'a
+            where
+                R: SlackWebRequestSender,
+            {{
+                {body}
+            }}",
+            fn_name = fn_name,
+            items = items,
+            follows = follows,
+            suffix = suffix,
+            noun = noun,
+            token_param = token_param,
+            type_prefix = type_prefix,
+            lifetime = if self.borrows_parameters() {
+                "<'a>"
+            } else {
+                ""
+            },
+            return_type = return_type,
+            item = item,
+            body = body,
         );
         Ok(out)
     }
+
+    /// Builds the form parameters, and file parts if any, along with the call sending them.
+    fn build_form_params(&self, token_arg: &str, dot_await: &str) -> (bool, String, String) {
+        let client_auth = matches!(self.http_method, HttpMethod::PostClientAuth);
+        let is_form_param = |p: &&Parameter| {
+            p.name != "token"
+                && !(client_auth && (p.name == "client_id" || p.name == "client_secret"))
+        };
+        let parameter_converts = self
+            .parameters
+            .iter()
+            .filter(is_form_param)
+            .filter(|p| !p.param_type.is_str() && p.param_type != ParameterDataType::File)
+            .map(Parameter::to_rust_fn_convert)
+            .collect::<Vec<_>>()
+            .join("\n");
+        let parameters = self
+            .parameters
+            .iter()
+            .filter(is_form_param)
+            .filter(|p| p.param_type != ParameterDataType::File)
+            .map(Parameter::to_rust_fn)
+            .collect::<Vec<_>>()
+            .join("\n");
+        let files = self
+            .parameters
+            .iter()
+            .filter(|p| p.param_type == ParameterDataType::File)
+            .map(Parameter::to_rust_fn_file)
+            .collect::<Vec<_>>()
+            .join("\n");
+        let (files, send) = if client_auth {
+            (
+                String::new(),
+                format!(
+                    "client.post_client_auth(&url, request.client_id.as_ref(), request.client_secret.as_ref(), &params[..]){dot_await}",
+                    dot_await = dot_await,
+                ),
+            )
+        } else if files.is_empty() {
+            (
+                String::new(),
+                format!(
+                    "client.{method}_response(&url, {token_arg}, &params[..]){dot_await}",
+                    method = self.http_method.method(),
+                    token_arg = token_arg,
+                    dot_await = dot_await,
+                ),
+            )
+        } else {
+            (
+                format!(
+                    "\nlet files: Vec<Option<(&str, &crate::FileUpload<'_>)>> = vec![
+                        {files}
+                    ];
+                    let files: Vec<(&str, &crate::FileUpload<'_>)> = files.into_iter().filter_map(|x| x).collect::<Vec<_>>();",
+                    files = files,
+                ),
+                // Without file parts there is nothing to send as `multipart/form-data`
+                format!(
+                    "let response = if files.is_empty() {{
+                        client.post_response(&url, {token_arg}, &params[..]){dot_await}
+                    }} else {{
+                        client.post_multipart(&url, {token_arg}, &params[..], &files[..]){dot_await}
+                    }};
+                    response",
+                    token_arg = token_arg,
+                    dot_await = dot_await,
+                ),
+            )
+        };
+        let build_params = format!(
+            "{parameter_converts}
+            let params: Vec<Option<(&str, &str)>> = vec![
+                {parameters}
+            ];
+            let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();{files}",
+            parameter_converts = parameter_converts,
+            parameters = parameters,
+            files = files,
+        );
+        (!parameters.is_empty(), build_params, send)
+    }
+
+    /// Builds the JSON body along with the call sending it.
+    fn build_json_body(&self, token_arg: &str, dot_await: &str) -> (bool, String, String) {
+        let members = self
+            .parameters
+            .iter()
+            .filter(|p| p.name != "token")
+            .map(Parameter::to_rust_fn_json)
+            .collect::<Vec<_>>()
+            .join("\n");
+        let build_params = format!(
+            "let body: Vec<Option<(&str, serde_json::Value)>> = vec![
+                {members}
+            ];
+            let body: serde_json::Map<String, serde_json::Value> = body
+                .into_iter()
+                .filter_map(|x| x)
+                .map(|(name, value)| (name.to_owned(), value))
+                .collect();",
+            members = members,
+        );
+        let send = format!(
+            "client.post_json(&url, {token_arg}, &serde_json::Value::Object(body)){dot_await}",
+            token_arg = token_arg,
+            dot_await = dot_await,
+        );
+        (!members.is_empty(), build_params, send)
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -553,23 +1108,30 @@ impl Parameter {
     }
 
     fn to_rust_fn_convert(&self) -> String {
-        if self.param_type == ParameterDataType::String {
-            panic!("Not required for string types");
-        } else if self.required {
+        let to_string = match self.param_type {
+            ParameterDataType::String | ParameterDataType::Id(_) => {
+                panic!("Not required for string types")
+            }
+            ParameterDataType::Timestamp => "to_param_value",
+            _ => "to_string",
+        };
+        if self.required {
             format!(
-                "let {name}: Option<Cow<'_, str>> = Some(request.{name}.to_string().into());",
-                name = self.name
+                "let {name}: Option<Cow<'_, str>> = Some(request.{name}.{to_string}().into());",
+                name = self.name,
+                to_string = to_string,
             )
         } else {
             format!(
-                    "let {name}: Option<Cow<'_, str>> = request.{name}.as_ref().map(|{name}| {name}.to_string().into());",
-                    name = self.name
+                    "let {name}: Option<Cow<'_, str>> = request.{name}.as_ref().map(|{name}| {name}.{to_string}().into());",
+                    name = self.name,
+                    to_string = to_string,
                 )
         }
     }
 
     fn to_rust_fn(&self) -> String {
-        if self.param_type == ParameterDataType::String {
+        if self.param_type.is_str() {
             if self.required {
                 format!(
                     "Some((\"{name}\", request.{name}.as_ref())),",
@@ -589,6 +1151,64 @@ impl Parameter {
         }
     }
 
+    fn to_rust_fn_json(&self) -> String {
+        match (&self.param_type, self.required) {
+            (ParameterDataType::String | ParameterDataType::Id(_), true) => format!(
+                "Some((\"{name}\", request.{name}.as_ref().into())),",
+                name = self.name
+            ),
+            (ParameterDataType::String | ParameterDataType::Id(_), false) => format!(
+                "request.{name}.as_ref().map(|{name}| (\"{name}\", {name}.as_ref().into())),",
+                name = self.name
+            ),
+            (ParameterDataType::Json, true) => {
+                format!(
+                    "Some((\"{name}\", request.{name}.clone())),",
+                    name = self.name
+                )
+            }
+            (ParameterDataType::Json, false) => format!(
+                "request.{name}.clone().map(|{name}| (\"{name}\", {name})),",
+                name = self.name
+            ),
+            (ParameterDataType::Timestamp, true) => format!(
+                "Some((\"{name}\", request.{name}.to_param_value().into())),",
+                name = self.name
+            ),
+            (ParameterDataType::Timestamp, false) => format!(
+                "request.{name}.map(|{name}| (\"{name}\", {name}.to_param_value().into())),",
+                name = self.name
+            ),
+            (ParameterDataType::Typed(_), true) => format!(
+                "Some((\"{name}\", crate::blocks::to_json(&request.{name}))),",
+                name = self.name
+            ),
+            (ParameterDataType::Typed(_), false) => format!(
+                "request.{name}.as_ref().map(|{name}| (\"{name}\", crate::blocks::to_json({name}))),",
+                name = self.name
+            ),
+            (_, true) => format!(
+                "Some((\"{name}\", request.{name}.into())),",
+                name = self.name
+            ),
+            (_, false) => format!(
+                "request.{name}.map(|{name}| (\"{name}\", {name}.into())),",
+                name = self.name
+            ),
+        }
+    }
+
+    fn to_rust_fn_file(&self) -> String {
+        if self.required {
+            format!("Some((\"{name}\", &request.{name})),", name = self.name)
+        } else {
+            format!(
+                "request.{name}.as_ref().map(|{name}| (\"{name}\", {name})),",
+                name = self.name
+            )
+        }
+    }
+
     fn description_to_rust(&self) -> String {
         self.description
             .as_ref()
@@ -619,15 +1239,41 @@ pub enum ParameterDataType {
     Decimal,
     Int,
     String,
+    /// Sent as a `multipart/form-data` part; only set by the adapt step.
+    File,
+    /// Embedded as is in a JSON body; only set by the adapt step.
+    Json,
+    /// A message `ts`, sent as its `to_param_value`; only set by the adapt step.
+    Timestamp,
+    /// A hand-written Rust type such as `Vec<crate::blocks::Block>`, serialized into a JSON
+    /// body; only set by the adapt step.
+    Typed(String),
+    /// An ID newtype such as `crate::ChannelId`, passed to the sender as a string; only set by
+    /// the adapt step.
+    Id(String),
 }
 
 impl ParameterDataType {
+    /// Whether the Rust type borrows from the request, and is passed to the sender as is.
+    pub fn is_borrowed(&self) -> bool {
+        matches!(self, Self::String | Self::File)
+    }
+
+    /// Whether the Rust type is passed to the sender as a `str`.
+    pub fn is_str(&self) -> bool {
+        matches!(self, Self::String | Self::Id(_))
+    }
+
     pub fn to_rust(&self, required: bool) -> String {
         let r#type = match self {
             Self::Bool => "bool",
             Self::Decimal => "f64",
             Self::Int => "u64",
             Self::String => "Cow<'a, str>",
+            Self::File => "crate::FileUpload<'a>",
+            Self::Json => "serde_json::Value",
+            Self::Timestamp => "crate::Timestamp",
+            Self::Typed(r#type) | Self::Id(r#type) => r#type.as_str(),
         };
         if required {
             r#type.to_string()
@@ -675,6 +1321,8 @@ impl Response {
             ResponseType::Int if !top => ("u64".into(), Vec::new()),
             ResponseType::String if !top => ("String".into(), Vec::new()),
             ResponseType::RawJson if !top => ("serde_json::Value".into(), Vec::new()),
+            ResponseType::Timestamp if !top => ("crate::Timestamp".into(), Vec::new()),
+            ResponseType::Typed(r#type) if !top => (r#type.clone(), Vec::new()),
             ResponseType::Vec(res) if !top => {
                 let (r#type, types) = res.to_rust(method_name, name, error_name, false, names)?;
                 (format!("Vec<{}>", r#type), types)
@@ -710,9 +1358,12 @@ impl Response {
                     i += 1;
                 }
                 names.insert(struct_name.clone());
+                if top {
+                    members.push("#[serde(skip)]\npub(crate) http: crate::HttpInfo,".into());
+                }
 
                 let out = format!(
-                    "#[derive(Clone, Debug, Deserialize)]
+                    "#[derive(Clone, Debug, Deserialize, Serialize)]
                     pub struct {name} {{
                         {members}
                     }}",
@@ -722,9 +1373,18 @@ impl Response {
                 types.push(out);
                 if top {
                     let error_handling = if mem.iter().any(|m| m.name == "error") {
-                        "Err(self.error.as_ref().map(String::as_ref).unwrap_or(\"\").into())".into()
+                        format!(
+                            "{metadata}
+                            let context = crate::ErrorContext::new({needed}, {provided}, {warning}, messages, warnings);
+                            Err({error_name}::new(self.error.as_ref().map(String::as_ref).unwrap_or(\"\"), context))",
+                            metadata = error_metadata(mem),
+                            needed = error_member(mem, "needed"),
+                            provided = error_member(mem, "provided"),
+                            warning = error_member(mem, "warning"),
+                            error_name = error_name,
+                        )
                     } else {
-                        format!("Err({}::Unknown(\"Server failed without providing an error message.\".into()))", error_name)
+                        format!("Err({}::Unknown(\"Server failed without providing an error message.\".into(), crate::ErrorContext::default()))", error_name)
                     };
                     let var_ok = if mem.iter().any(|m| m.name == "ok") {
                         "ok"
@@ -747,6 +1407,20 @@ impl Response {
                         var_ok = var_ok,
                     );
                     types.push(out);
+                    let out = format!(
+                        "impl crate::SlackResponse for {name} {{
+                            fn http(&self) -> &crate::HttpInfo {{
+                                &self.http
+                            }}
+
+                            fn warnings(&self) -> Vec<&str> {{
+                                {warnings}
+                            }}
+                        }}",
+                        name = name,
+                        warnings = response_warnings(mem),
+                    );
+                    types.push(out);
                 }
                 (struct_name, types)
             }
@@ -756,6 +1430,57 @@ impl Response {
     }
 }
 
+/// The expression moving the string member `name`, which Slack sends along with errors, out of
+/// a response as an `Option`.
+fn error_member(members: &[Member], name: &str) -> String {
+    match members.iter().find(|m| m.name == name) {
+        Some(m) if m.r#type.required => format!("Some(self.{})", name),
+        Some(_) => format!("self.{}", name),
+        None => "None".into(),
+    }
+}
+
+/// The expression collecting the warnings of a response from its `warning` member and its
+/// `response_metadata.warnings`.
+fn response_warnings(members: &[Member]) -> String {
+    let warning = match members.iter().find(|m| m.name == "warning") {
+        Some(m) if m.r#type.required => "Some(self.warning.as_str())",
+        Some(_) => "self.warning.as_deref()",
+        None => "None",
+    };
+    let warnings = match members.iter().find(|m| m.name == "response_metadata") {
+        Some(m) if m.r#type.required => "self.response_metadata.warnings.as_deref()",
+        Some(_) => "self.response_metadata.as_ref().and_then(|m| m.warnings.as_deref())",
+        None => "None",
+    };
+    if warning == "None" && warnings == "None" {
+        "Vec::new()".into()
+    } else {
+        format!(
+            "crate::response::collect_warnings({}, {})",
+            warning, warnings
+        )
+    }
+}
+
+/// The statement moving the `messages` and `warnings` of a response's `response_metadata` out
+/// of it, as `Option`s.
+fn error_metadata(members: &[Member]) -> String {
+    let metadata = members.iter().find(|m| m.name == "response_metadata");
+    match metadata {
+        Some(m) if m.r#type.required => "let (messages, warnings) = (
+                self.response_metadata.messages,
+                self.response_metadata.warnings,
+            );"
+        .into(),
+        Some(_) => "let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));"
+            .into(),
+        None => "let (messages, warnings) = (None, None);".into(),
+    }
+}
+
 impl TryFrom<&schema::Schema> for Response {
     type Error = anyhow::Error;
 
@@ -792,7 +1517,12 @@ impl Member {
         if !self.r#type.required && self.name != "ok" {
             r#type = format!("Option<{}>", r#type);
         }
-        let name = match self.name.as_ref() {
+        // Claims such as `https://slack.com/team_id` are named after their last segment.
+        let (name, rename) = match self.name.rsplit_once('/') {
+            Some((_, short)) => (short, format!("#[serde(rename = \"{}\")]\n", self.name)),
+            None => (self.name.as_ref(), String::new()),
+        };
+        let name = match name {
             "self" => "r#_self",
             "type" => "r#type",
             v => v,
@@ -803,7 +1533,7 @@ impl Member {
             _ => "pub ",
         };
         Ok((
-            format!("{pub}{name}: {type},", pub=r#pub, name=name, type=r#type),
+            format!("{rename}{pub}{name}: {type},", rename=rename, pub=r#pub, name=name, type=r#type),
             types,
         ))
     }
@@ -830,6 +1560,10 @@ pub enum ResponseType {
     Object(Vec<Member>),
     RawJson,
     Vec(Box<Response>),
+    /// A message `ts`; only set by the adapt step.
+    Timestamp,
+    /// A hand-written Rust type such as `crate::blocks::View`; only set by the adapt step.
+    Typed(String),
 }
 
 impl TryFrom<&schema::Schema> for ResponseType {
@@ -900,13 +1634,17 @@ impl TryFrom<&schema::Schema> for ResponseType {
 pub enum HttpMethod {
     Get,
     Post,
+    /// Sends the parameters as an `application/json` body; only set by the adapt step.
+    PostJson,
+    /// Sends `client_id` and `client_secret` as HTTP Basic auth; only set by the adapt step.
+    PostClientAuth,
 }
 
 impl HttpMethod {
     fn method(self) -> &'static str {
         match self {
             Self::Get => "get",
-            Self::Post => "post",
+            Self::Post | Self::PostJson | Self::PostClientAuth => "post",
         }
     }
 }
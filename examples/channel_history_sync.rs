@@ -12,9 +12,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         &client,
         &token,
         &slack::conversations::HistoryRequest {
-            channel: env::args()
-                .nth(1)
-                .ok_or("must specify channel id as argument e.g. C09123456")?,
+            channel: Some(
+                env::args()
+                    .nth(1)
+                    .ok_or("must specify channel id as argument e.g. C09123456")?
+                    .parse()?,
+            ),
             ..slack::conversations::HistoryRequest::default()
         },
     );
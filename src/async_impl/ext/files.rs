@@ -0,0 +1,143 @@
+//! The external upload flow as a single call.
+
+use crate::async_impl::mods::files::{complete_upload_external, get_upload_url_external};
+use crate::async_impl::SlackWebRequestSender;
+use crate::mod_types::files::{
+    CompleteUploadExternalRequest, CompleteUploadExternalResponse, GetUploadURLExternalRequest,
+};
+use crate::upload::complete_upload_files;
+pub use crate::upload::{UploadV2Error, UploadV2File, UploadV2Request};
+
+/// Uploads files and shares them, optionally in a thread, in one message.
+///
+/// Runs Slack's upload flow: `files.getUploadURLExternal` for every file, a `POST` of its contents
+/// to the returned URL, then one `files.completeUploadExternal` for all of them. Slack needs the
+/// length of every file up front, so readers are read into memory first unless they were created
+/// with [`FileUpload::reader_with_length`](crate::FileUpload::reader_with_length).
+///
+/// # Examples
+///
+/// ```no_run
+/// # #[cfg(feature = "reqwest")]
+/// # async fn run() {
+/// use slack_api::files::{upload_v2, UploadV2File, UploadV2Request};
+/// use slack_api::FileUpload;
+///
+/// let client = slack_api::default_client().unwrap();
+/// let request = UploadV2Request {
+///     files: vec![UploadV2File::new(
+///         "build.log",
+///         FileUpload::path(std::path::Path::new("target/build.log")),
+///     )],
+///     channel_id: Some("C0123456".parse().unwrap()),
+///     initial_comment: Some("Latest build log".into()),
+///     ..Default::default()
+/// };
+/// let response = upload_v2(&client, "xoxb-token", &request).await;
+/// # }
+/// ```
+pub async fn upload_v2<R>(
+    client: &R,
+    token: &str,
+    request: &UploadV2Request<'_>,
+) -> Result<CompleteUploadExternalResponse, UploadV2Error<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let mut uploaded = Vec::with_capacity(request.files.len());
+    for file in &request.files {
+        let (content, length) = file.content.with_length().map_err(UploadV2Error::Read)?;
+        let external = get_upload_url_external(
+            client,
+            token,
+            &GetUploadURLExternalRequest {
+                alt_txt: file.alt_txt.clone(),
+                filename: file.filename.clone(),
+                length,
+                snippet_type: file.snippet_type.clone(),
+            },
+        )
+        .await
+        .map_err(UploadV2Error::GetUploadUrl)?;
+        let (upload_url, file_id) = match (external.upload_url, external.file_id) {
+            (Some(upload_url), Some(file_id)) => (upload_url, file_id),
+            _ => return Err(UploadV2Error::MissingUploadUrl),
+        };
+
+        let response = client
+            .post_multipart(&upload_url, None, &[], &[("filename", &content)])
+            .await
+            .map_err(UploadV2Error::Client)?;
+        if response.is_multipart_unsupported() {
+            return Err(UploadV2Error::MultipartUnsupported);
+        }
+        if response.status != 200 {
+            return Err(UploadV2Error::Upload {
+                status: response.status,
+                body: response.body,
+            });
+        }
+        uploaded.push((file_id, file.title.as_deref()));
+    }
+
+    complete_upload_external(
+        client,
+        token,
+        &CompleteUploadExternalRequest {
+            channel_id: request.channel_id.clone(),
+            files: complete_upload_files(&uploaded).into(),
+            initial_comment: request.initial_comment.clone(),
+            thread_ts: request.thread_ts,
+        },
+    )
+    .await
+    .map_err(UploadV2Error::Complete)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{FormOnly, Scripted};
+    use crate::{FileUpload, SlackWebResponse};
+
+    #[tokio::test]
+    async fn uploads_then_completes() {
+        let sender = Scripted::new(|call| {
+            SlackWebResponse::from_body(match call.method() {
+                "files.getUploadURLExternal" => {
+                    r#"{"ok": true, "upload_url": "https://files.slack.com/upload/v1/1", "file_id": "F1"}"#.into()
+                }
+                "files.completeUploadExternal" => r#"{"ok": true, "files": [{"id": "F1"}]}"#.into(),
+                _ => "OK - 5".into(),
+            })
+        });
+        let request = UploadV2Request {
+            files: vec![UploadV2File::new("a.log", FileUpload::bytes(&b"a-log"[..]))],
+            ..Default::default()
+        };
+        let response = upload_v2(&sender, "xoxb", &request).await.unwrap();
+        assert_eq!(response.files.unwrap().len(), 1);
+        let calls = sender.calls();
+        assert_eq!(calls[1].url, "https://files.slack.com/upload/v1/1");
+        assert_eq!(calls[1].files[0].2, b"a-log");
+        assert_eq!(calls[2].method(), "files.completeUploadExternal");
+    }
+
+    #[tokio::test]
+    async fn senders_without_multipart_fail_before_completing() {
+        let sender = FormOnly(Scripted::body(
+            r#"{"ok": true, "upload_url": "https://files.slack.com/upload/v1/1", "file_id": "F1"}"#,
+        ));
+        let request = UploadV2Request {
+            files: vec![UploadV2File::new("a.log", FileUpload::bytes(&b"a-log"[..]))],
+            ..Default::default()
+        };
+        match upload_v2(&sender, "xoxb", &request).await {
+            Err(UploadV2Error::MultipartUnsupported) => {}
+            other => panic!("unexpected {:?}", other),
+        }
+        let calls = sender.0.calls();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].method(), "files.getUploadURLExternal");
+    }
+}
@@ -0,0 +1,5 @@
+//! Handwritten additions to the generated modules in `mods`, which re-export them.
+
+pub mod files;
+pub mod oauth;
+pub mod openid;
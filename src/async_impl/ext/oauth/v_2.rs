@@ -0,0 +1,26 @@
+//! The end of the install flow as a single call.
+
+use crate::async_impl::mods::oauth::v_2::access;
+use crate::async_impl::SlackWebRequestSender;
+pub use crate::install::{InstallError, InstallFlow, StateError};
+use crate::mod_types::oauth::v_2_types::AccessResponse;
+
+/// Checks the `state` Slack redirected the user back with against the `expected_state` kept for
+/// the user, then exchanges the `code` for tokens with `oauth.v2.access`, authenticated with the
+/// app's client ID and secret.
+pub async fn exchange_code<R>(
+    client: &R,
+    flow: &InstallFlow,
+    code: &str,
+    state: &str,
+    expected_state: &str,
+) -> Result<AccessResponse, InstallError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    flow.check_state(state, expected_state)
+        .map_err(InstallError::State)?;
+    access(client, &flow.access_request(code))
+        .await
+        .map_err(InstallError::Access)
+}
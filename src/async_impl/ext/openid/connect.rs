@@ -0,0 +1,42 @@
+//! The end of the Sign in with Slack flow as a single call.
+
+#[cfg(feature = "openid")]
+use crate::async_impl::mods::openid::connect::token;
+#[cfg(feature = "openid")]
+use crate::async_impl::SlackWebRequestSender;
+#[cfg(feature = "openid")]
+use crate::mod_types::openid::connect_types::TokenResponse;
+pub use crate::sign_in::{IdTokenClaims, Jwk, Jwks, SignInFlow};
+#[cfg(feature = "openid")]
+pub use crate::sign_in::{IdTokenError, IdTokenVerifier, SignInError};
+
+/// Checks the `state` Slack redirected the user back with against the `expected_state` kept for
+/// the user, exchanges the `code` for tokens with `openid.connect.token`, then checks its ID
+/// token, which must carry `nonce`, with `verifier`.
+#[cfg(feature = "openid")]
+pub async fn sign_in<R>(
+    client: &R,
+    flow: &SignInFlow,
+    verifier: &IdTokenVerifier,
+    code: &str,
+    state: &str,
+    expected_state: &str,
+    nonce: &str,
+) -> Result<(TokenResponse, IdTokenClaims), SignInError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    flow.check_state(state, expected_state)
+        .map_err(SignInError::State)?;
+    let response = token(client, &flow.token_request(code))
+        .await
+        .map_err(SignInError::Token)?;
+    let id_token = response
+        .id_token
+        .as_deref()
+        .ok_or(SignInError::MissingIdToken)?;
+    let claims = verifier
+        .verify(id_token, Some(nonce))
+        .map_err(SignInError::IdToken)?;
+    Ok((response, claims))
+}
@@ -34,7 +34,6 @@ where
 {
     let limit: Option<Cow<'_, str>> = request.limit.as_ref().map(|limit| limit.to_string().into());
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
         request
             .cursor
             .as_ref()
@@ -50,14 +49,47 @@ where
             .map(|team_id| ("team_id", team_id.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.apps.approved.list");
+    let url = crate::get_slack_url_for_method(client.base_url(), "admin.apps.approved.list");
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .await
         .map_err(ListError::Client)
         .and_then(|result| {
-            serde_json::from_str::<ListResponse>(&result)
-                .map_err(|e| ListError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<ListResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => ListError::new(&code, context),
+                    None => ListError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.apps.approved.list", &warnings);
+            }
+        })
+}
+/// Calls [`list`] page by page and yields the `approved_apps` of every page.
+///
+/// Follows `response_metadata.next_cursor` until it is empty, and stops early once
+/// `max_items` items were yielded. An error is yielded once and ends the stream.
+pub fn list_stream<'a, R>(
+    client: &'a R,
+    token: &'a str,
+    request: &ListRequest<'a>,
+    max_items: Option<usize>,
+) -> impl futures_util::Stream<Item = Result<serde_json::Value, ListError<R::Error>>> + 'a
+where
+    R: SlackWebRequestSender,
+{
+    crate::async_impl::pagination::cursor_stream(
+        request.clone(),
+        max_items,
+        move |request| async move { list(client, token, &request).await },
+    )
 }
@@ -30,23 +30,40 @@ use std::borrow::Cow;
 pub async fn archive<R>(
     client: &R,
     token: &str,
-    request: &ArchiveRequest<'_>,
+    request: &ArchiveRequest,
 ) -> Result<ArchiveResponse, ArchiveError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
-    let params: Vec<Option<(&str, &str)>> = vec![Some(("channel_id", request.channel_id.as_ref()))];
+    let params: Vec<Option<(&str, &str)>> = vec![request
+        .channel_id
+        .as_ref()
+        .map(|channel_id| ("channel_id", channel_id.as_ref()))];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.conversations.archive");
+    let url = crate::get_slack_url_for_method(client.base_url(), "admin.conversations.archive");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .await
         .map_err(ArchiveError::Client)
         .and_then(|result| {
-            serde_json::from_str::<ArchiveResponse>(&result)
-                .map_err(|e| ArchiveError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<ArchiveResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => ArchiveError::new(&code, context),
+                    None => ArchiveError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.conversations.archive", &warnings);
+            }
+        })
 }
 /// Convert a public channel to a private channel.
 ///
@@ -55,23 +72,41 @@ where
 pub async fn convert_to_private<R>(
     client: &R,
     token: &str,
-    request: &ConvertToPrivateRequest<'_>,
+    request: &ConvertToPrivateRequest,
 ) -> Result<ConvertToPrivateResponse, ConvertToPrivateError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
-    let params: Vec<Option<(&str, &str)>> = vec![Some(("channel_id", request.channel_id.as_ref()))];
+    let params: Vec<Option<(&str, &str)>> = vec![request
+        .channel_id
+        .as_ref()
+        .map(|channel_id| ("channel_id", channel_id.as_ref()))];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.conversations.convertToPrivate");
+    let url =
+        crate::get_slack_url_for_method(client.base_url(), "admin.conversations.convertToPrivate");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .await
         .map_err(ConvertToPrivateError::Client)
         .and_then(|result| {
-            serde_json::from_str::<ConvertToPrivateResponse>(&result)
-                .map_err(|e| ConvertToPrivateError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<ConvertToPrivateResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => ConvertToPrivateError::new(&code, context),
+                    None => ConvertToPrivateError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.conversations.convertToPrivate", &warnings);
+            }
+        })
 }
 /// Create a public or private channel-based conversation.
 ///
@@ -108,16 +143,30 @@ where
             .map(|team_id| ("team_id", team_id.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.conversations.create");
+    let url = crate::get_slack_url_for_method(client.base_url(), "admin.conversations.create");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .await
         .map_err(CreateError::Client)
         .and_then(|result| {
-            serde_json::from_str::<CreateResponse>(&result)
-                .map_err(|e| CreateError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<CreateResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => CreateError::new(&code, context),
+                    None => CreateError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.conversations.create", &warnings);
+            }
+        })
 }
 /// Delete a public or private channel.
 ///
@@ -126,23 +175,40 @@ where
 pub async fn delete<R>(
     client: &R,
     token: &str,
-    request: &DeleteRequest<'_>,
+    request: &DeleteRequest,
 ) -> Result<DeleteResponse, DeleteError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
-    let params: Vec<Option<(&str, &str)>> = vec![Some(("channel_id", request.channel_id.as_ref()))];
+    let params: Vec<Option<(&str, &str)>> = vec![request
+        .channel_id
+        .as_ref()
+        .map(|channel_id| ("channel_id", channel_id.as_ref()))];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.conversations.delete");
+    let url = crate::get_slack_url_for_method(client.base_url(), "admin.conversations.delete");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .await
         .map_err(DeleteError::Client)
         .and_then(|result| {
-            serde_json::from_str::<DeleteResponse>(&result)
-                .map_err(|e| DeleteError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<DeleteResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => DeleteError::new(&code, context),
+                    None => DeleteError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.conversations.delete", &warnings);
+            }
+        })
 }
 /// Disconnect a connected channel from one or more workspaces.
 ///
@@ -157,23 +223,41 @@ where
     R: SlackWebRequestSender,
 {
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("channel_id", request.channel_id.as_ref())),
+        request
+            .channel_id
+            .as_ref()
+            .map(|channel_id| ("channel_id", channel_id.as_ref())),
         request
             .leaving_team_ids
             .as_ref()
             .map(|leaving_team_ids| ("leaving_team_ids", leaving_team_ids.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.conversations.disconnectShared");
+    let url =
+        crate::get_slack_url_for_method(client.base_url(), "admin.conversations.disconnectShared");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .await
         .map_err(DisconnectSharedError::Client)
         .and_then(|result| {
-            serde_json::from_str::<DisconnectSharedResponse>(&result)
-                .map_err(|e| DisconnectSharedError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<DisconnectSharedResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => DisconnectSharedError::new(&code, context),
+                    None => DisconnectSharedError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.conversations.disconnectShared", &warnings);
+            }
+        })
 }
 /// Get conversation preferences for a public or private channel.
 ///
@@ -182,26 +266,43 @@ where
 pub async fn get_conversation_prefs<R>(
     client: &R,
     token: &str,
-    request: &GetConversationPrefsRequest<'_>,
+    request: &GetConversationPrefsRequest,
 ) -> Result<GetConversationPrefsResponse, GetConversationPrefsError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
-    let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
-        Some(("channel_id", request.channel_id.as_ref())),
-    ];
+    let params: Vec<Option<(&str, &str)>> = vec![request
+        .channel_id
+        .as_ref()
+        .map(|channel_id| ("channel_id", channel_id.as_ref()))];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.conversations.getConversationPrefs");
+    let url = crate::get_slack_url_for_method(
+        client.base_url(),
+        "admin.conversations.getConversationPrefs",
+    );
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .await
         .map_err(GetConversationPrefsError::Client)
         .and_then(|result| {
-            serde_json::from_str::<GetConversationPrefsResponse>(&result)
-                .map_err(|e| GetConversationPrefsError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<GetConversationPrefsResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => GetConversationPrefsError::new(&code, context),
+                    None => GetConversationPrefsError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.conversations.getConversationPrefs", &warnings);
+            }
+        })
 }
 /// Get all the workspaces a given public or private channel is connected to within this Enterprise org.
 ///
@@ -217,8 +318,10 @@ where
 {
     let limit: Option<Cow<'_, str>> = request.limit.as_ref().map(|limit| limit.to_string().into());
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
-        Some(("channel_id", request.channel_id.as_ref())),
+        request
+            .channel_id
+            .as_ref()
+            .map(|channel_id| ("channel_id", channel_id.as_ref())),
         request
             .cursor
             .as_ref()
@@ -226,16 +329,49 @@ where
         limit.as_ref().map(|limit| ("limit", limit.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.conversations.getTeams");
+    let url = crate::get_slack_url_for_method(client.base_url(), "admin.conversations.getTeams");
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .await
         .map_err(GetTeamsError::Client)
         .and_then(|result| {
-            serde_json::from_str::<GetTeamsResponse>(&result)
-                .map_err(|e| GetTeamsError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<GetTeamsResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => GetTeamsError::new(&code, context),
+                    None => GetTeamsError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.conversations.getTeams", &warnings);
+            }
+        })
+}
+/// Calls [`get_teams`] page by page and yields the `team_ids` of every page.
+///
+/// Follows `response_metadata.next_cursor` until it is empty, and stops early once
+/// `max_items` items were yielded. An error is yielded once and ends the stream.
+pub fn get_teams_stream<'a, R>(
+    client: &'a R,
+    token: &'a str,
+    request: &GetTeamsRequest<'a>,
+    max_items: Option<usize>,
+) -> impl futures_util::Stream<Item = Result<String, GetTeamsError<R::Error>>> + 'a
+where
+    R: SlackWebRequestSender,
+{
+    crate::async_impl::pagination::cursor_stream(
+        request.clone(),
+        max_items,
+        move |request| async move { get_teams(client, token, &request).await },
+    )
 }
 /// Invite a user to a public or private channel.
 ///
@@ -250,20 +386,37 @@ where
     R: SlackWebRequestSender,
 {
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("channel_id", request.channel_id.as_ref())),
+        request
+            .channel_id
+            .as_ref()
+            .map(|channel_id| ("channel_id", channel_id.as_ref())),
         Some(("user_ids", request.user_ids.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.conversations.invite");
+    let url = crate::get_slack_url_for_method(client.base_url(), "admin.conversations.invite");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .await
         .map_err(InviteError::Client)
         .and_then(|result| {
-            serde_json::from_str::<InviteResponse>(&result)
-                .map_err(|e| InviteError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<InviteResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => InviteError::new(&code, context),
+                    None => InviteError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.conversations.invite", &warnings);
+            }
+        })
 }
 /// Rename a public or private channel.
 ///
@@ -278,20 +431,37 @@ where
     R: SlackWebRequestSender,
 {
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("channel_id", request.channel_id.as_ref())),
+        request
+            .channel_id
+            .as_ref()
+            .map(|channel_id| ("channel_id", channel_id.as_ref())),
         Some(("name", request.name.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.conversations.rename");
+    let url = crate::get_slack_url_for_method(client.base_url(), "admin.conversations.rename");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .await
         .map_err(RenameError::Client)
         .and_then(|result| {
-            serde_json::from_str::<RenameResponse>(&result)
-                .map_err(|e| RenameError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<RenameResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => RenameError::new(&code, context),
+                    None => RenameError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.conversations.rename", &warnings);
+            }
+        })
 }
 /// Search for public or private channels in an Enterprise organization.
 ///
@@ -307,7 +477,6 @@ where
 {
     let limit: Option<Cow<'_, str>> = request.limit.as_ref().map(|limit| limit.to_string().into());
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
         request
             .cursor
             .as_ref()
@@ -332,16 +501,49 @@ where
             .map(|team_ids| ("team_ids", team_ids.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.conversations.search");
+    let url = crate::get_slack_url_for_method(client.base_url(), "admin.conversations.search");
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .await
         .map_err(SearchError::Client)
         .and_then(|result| {
-            serde_json::from_str::<SearchResponse>(&result)
-                .map_err(|e| SearchError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<SearchResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => SearchError::new(&code, context),
+                    None => SearchError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.conversations.search", &warnings);
+            }
+        })
+}
+/// Calls [`search`] page by page and yields the `channels` of every page.
+///
+/// Follows `next_cursor` until it is empty, and stops early once
+/// `max_items` items were yielded. An error is yielded once and ends the stream.
+pub fn search_stream<'a, R>(
+    client: &'a R,
+    token: &'a str,
+    request: &SearchRequest<'a>,
+    max_items: Option<usize>,
+) -> impl futures_util::Stream<Item = Result<SearchChannelsInner, SearchError<R::Error>>> + 'a
+where
+    R: SlackWebRequestSender,
+{
+    crate::async_impl::pagination::cursor_stream(
+        request.clone(),
+        max_items,
+        move |request| async move { search(client, token, &request).await },
+    )
 }
 /// Set the posting permissions for a public or private channel.
 ///
@@ -356,20 +558,40 @@ where
     R: SlackWebRequestSender,
 {
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("channel_id", request.channel_id.as_ref())),
+        request
+            .channel_id
+            .as_ref()
+            .map(|channel_id| ("channel_id", channel_id.as_ref())),
         Some(("prefs", request.prefs.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.conversations.setConversationPrefs");
+    let url = crate::get_slack_url_for_method(
+        client.base_url(),
+        "admin.conversations.setConversationPrefs",
+    );
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .await
         .map_err(SetConversationPrefsError::Client)
         .and_then(|result| {
-            serde_json::from_str::<SetConversationPrefsResponse>(&result)
-                .map_err(|e| SetConversationPrefsError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<SetConversationPrefsResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => SetConversationPrefsError::new(&code, context),
+                    None => SetConversationPrefsError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.conversations.setConversationPrefs", &warnings);
+            }
+        })
 }
 /// Set the workspaces in an Enterprise grid org that connect to a public or private channel.
 ///
@@ -388,7 +610,10 @@ where
         .as_ref()
         .map(|org_channel| org_channel.to_string().into());
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("channel_id", request.channel_id.as_ref())),
+        request
+            .channel_id
+            .as_ref()
+            .map(|channel_id| ("channel_id", channel_id.as_ref())),
         org_channel
             .as_ref()
             .map(|org_channel| ("org_channel", org_channel.as_ref())),
@@ -402,16 +627,30 @@ where
             .map(|team_id| ("team_id", team_id.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.conversations.setTeams");
+    let url = crate::get_slack_url_for_method(client.base_url(), "admin.conversations.setTeams");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .await
         .map_err(SetTeamsError::Client)
         .and_then(|result| {
-            serde_json::from_str::<SetTeamsResponse>(&result)
-                .map_err(|e| SetTeamsError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<SetTeamsResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => SetTeamsError::new(&code, context),
+                    None => SetTeamsError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.conversations.setTeams", &warnings);
+            }
+        })
 }
 /// Unarchive a public or private channel.
 ///
@@ -420,21 +659,38 @@ where
 pub async fn unarchive<R>(
     client: &R,
     token: &str,
-    request: &UnarchiveRequest<'_>,
+    request: &UnarchiveRequest,
 ) -> Result<UnarchiveResponse, UnarchiveError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
-    let params: Vec<Option<(&str, &str)>> = vec![Some(("channel_id", request.channel_id.as_ref()))];
+    let params: Vec<Option<(&str, &str)>> = vec![request
+        .channel_id
+        .as_ref()
+        .map(|channel_id| ("channel_id", channel_id.as_ref()))];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.conversations.unarchive");
+    let url = crate::get_slack_url_for_method(client.base_url(), "admin.conversations.unarchive");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .await
         .map_err(UnarchiveError::Client)
         .and_then(|result| {
-            serde_json::from_str::<UnarchiveResponse>(&result)
-                .map_err(|e| UnarchiveError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<UnarchiveResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => UnarchiveError::new(&code, context),
+                    None => UnarchiveError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.conversations.unarchive", &warnings);
+            }
+        })
 }
@@ -33,7 +33,10 @@ where
     R: SlackWebRequestSender,
 {
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("channel_id", request.channel_id.as_ref())),
+        request
+            .channel_id
+            .as_ref()
+            .map(|channel_id| ("channel_id", channel_id.as_ref())),
         Some(("group_id", request.group_id.as_ref())),
         request
             .team_id
@@ -41,16 +44,33 @@ where
             .map(|team_id| ("team_id", team_id.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.conversations.restrictAccess.addGroup");
+    let url = crate::get_slack_url_for_method(
+        client.base_url(),
+        "admin.conversations.restrictAccess.addGroup",
+    );
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .await
         .map_err(AddGroupError::Client)
         .and_then(|result| {
-            serde_json::from_str::<AddGroupResponse>(&result)
-                .map_err(|e| AddGroupError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<AddGroupResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => AddGroupError::new(&code, context),
+                    None => AddGroupError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.conversations.restrictAccess.addGroup", &warnings);
+            }
+        })
 }
 /// List all IDP Groups linked to a channel
 ///
@@ -59,30 +79,49 @@ where
 pub async fn list_groups<R>(
     client: &R,
     token: &str,
-    request: &ListGroupsRequest<'_>,
+    request: &ListGroupsRequest,
 ) -> Result<ListGroupsResponse, ListGroupsError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
-        Some(("channel_id", request.channel_id.as_ref())),
+        request
+            .channel_id
+            .as_ref()
+            .map(|channel_id| ("channel_id", channel_id.as_ref())),
         request
             .team_id
             .as_ref()
             .map(|team_id| ("team_id", team_id.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.conversations.restrictAccess.listGroups");
+    let url = crate::get_slack_url_for_method(
+        client.base_url(),
+        "admin.conversations.restrictAccess.listGroups",
+    );
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .await
         .map_err(ListGroupsError::Client)
         .and_then(|result| {
-            serde_json::from_str::<ListGroupsResponse>(&result)
-                .map_err(|e| ListGroupsError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<ListGroupsResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => ListGroupsError::new(&code, context),
+                    None => ListGroupsError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.conversations.restrictAccess.listGroups", &warnings);
+            }
+        })
 }
 /// Remove a linked IDP group linked from a private channel
 ///
@@ -97,19 +136,42 @@ where
     R: SlackWebRequestSender,
 {
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("channel_id", request.channel_id.as_ref())),
+        request
+            .channel_id
+            .as_ref()
+            .map(|channel_id| ("channel_id", channel_id.as_ref())),
         Some(("group_id", request.group_id.as_ref())),
-        Some(("team_id", request.team_id.as_ref())),
+        request
+            .team_id
+            .as_ref()
+            .map(|team_id| ("team_id", team_id.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.conversations.restrictAccess.removeGroup");
+    let url = crate::get_slack_url_for_method(
+        client.base_url(),
+        "admin.conversations.restrictAccess.removeGroup",
+    );
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .await
         .map_err(RemoveGroupError::Client)
         .and_then(|result| {
-            serde_json::from_str::<RemoveGroupResponse>(&result)
-                .map_err(|e| RemoveGroupError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<RemoveGroupResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => RemoveGroupError::new(&code, context),
+                    None => RemoveGroupError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.conversations.restrictAccess.removeGroup", &warnings);
+            }
+        })
 }
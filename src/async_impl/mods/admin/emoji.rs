@@ -37,16 +37,30 @@ where
         Some(("url", request.url.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.emoji.add");
+    let url = crate::get_slack_url_for_method(client.base_url(), "admin.emoji.add");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .await
         .map_err(AddError::Client)
         .and_then(|result| {
-            serde_json::from_str::<AddResponse>(&result)
-                .map_err(|e| AddError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<AddResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => AddError::new(&code, context),
+                    None => AddError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.emoji.add", &warnings);
+            }
+        })
 }
 /// Add an emoji alias.
 ///
@@ -65,16 +79,30 @@ where
         Some(("name", request.name.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.emoji.addAlias");
+    let url = crate::get_slack_url_for_method(client.base_url(), "admin.emoji.addAlias");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .await
         .map_err(AddAliasError::Client)
         .and_then(|result| {
-            serde_json::from_str::<AddAliasResponse>(&result)
-                .map_err(|e| AddAliasError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<AddAliasResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => AddAliasError::new(&code, context),
+                    None => AddAliasError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.emoji.addAlias", &warnings);
+            }
+        })
 }
 /// List emoji for an Enterprise Grid organization.
 ///
@@ -90,7 +118,6 @@ where
 {
     let limit: Option<Cow<'_, str>> = request.limit.as_ref().map(|limit| limit.to_string().into());
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
         request
             .cursor
             .as_ref()
@@ -98,16 +125,30 @@ where
         limit.as_ref().map(|limit| ("limit", limit.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.emoji.list");
+    let url = crate::get_slack_url_for_method(client.base_url(), "admin.emoji.list");
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .await
         .map_err(ListError::Client)
         .and_then(|result| {
-            serde_json::from_str::<ListResponse>(&result)
-                .map_err(|e| ListError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<ListResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => ListError::new(&code, context),
+                    None => ListError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.emoji.list", &warnings);
+            }
+        })
 }
 /// Remove an emoji across an Enterprise Grid organization
 ///
@@ -123,16 +164,30 @@ where
 {
     let params: Vec<Option<(&str, &str)>> = vec![Some(("name", request.name.as_ref()))];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.emoji.remove");
+    let url = crate::get_slack_url_for_method(client.base_url(), "admin.emoji.remove");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .await
         .map_err(RemoveError::Client)
         .and_then(|result| {
-            serde_json::from_str::<RemoveResponse>(&result)
-                .map_err(|e| RemoveError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<RemoveResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => RemoveError::new(&code, context),
+                    None => RemoveError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.emoji.remove", &warnings);
+            }
+        })
 }
 /// Rename an emoji.
 ///
@@ -151,14 +206,28 @@ where
         Some(("new_name", request.new_name.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.emoji.rename");
+    let url = crate::get_slack_url_for_method(client.base_url(), "admin.emoji.rename");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .await
         .map_err(RenameError::Client)
         .and_then(|result| {
-            serde_json::from_str::<RenameResponse>(&result)
-                .map_err(|e| RenameError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<RenameResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => RenameError::new(&code, context),
+                    None => RenameError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.emoji.rename", &warnings);
+            }
+        })
 }
@@ -38,19 +38,36 @@ where
             .team_id
             .as_ref()
             .map(|team_id| ("team_id", team_id.as_ref())),
-        Some(("usergroup_id", request.usergroup_id.as_ref())),
+        request
+            .usergroup_id
+            .as_ref()
+            .map(|usergroup_id| ("usergroup_id", usergroup_id.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.usergroups.addChannels");
+    let url = crate::get_slack_url_for_method(client.base_url(), "admin.usergroups.addChannels");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .await
         .map_err(AddChannelsError::Client)
         .and_then(|result| {
-            serde_json::from_str::<AddChannelsResponse>(&result)
-                .map_err(|e| AddChannelsError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<AddChannelsResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => AddChannelsError::new(&code, context),
+                    None => AddChannelsError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.usergroups.addChannels", &warnings);
+            }
+        })
 }
 /// Associate one or more default workspaces with an organization-wide IDP group.
 ///
@@ -73,19 +90,36 @@ where
             .as_ref()
             .map(|auto_provision| ("auto_provision", auto_provision.as_ref())),
         Some(("team_ids", request.team_ids.as_ref())),
-        Some(("usergroup_id", request.usergroup_id.as_ref())),
+        request
+            .usergroup_id
+            .as_ref()
+            .map(|usergroup_id| ("usergroup_id", usergroup_id.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.usergroups.addTeams");
+    let url = crate::get_slack_url_for_method(client.base_url(), "admin.usergroups.addTeams");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .await
         .map_err(AddTeamsError::Client)
         .and_then(|result| {
-            serde_json::from_str::<AddTeamsResponse>(&result)
-                .map_err(|e| AddTeamsError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<AddTeamsResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => AddTeamsError::new(&code, context),
+                    None => AddTeamsError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.usergroups.addTeams", &warnings);
+            }
+        })
 }
 /// List the channels linked to an org-level IDP group (user group).
 ///
@@ -94,7 +128,7 @@ where
 pub async fn list_channels<R>(
     client: &R,
     token: &str,
-    request: &ListChannelsRequest<'_>,
+    request: &ListChannelsRequest,
 ) -> Result<ListChannelsResponse, ListChannelsError<R::Error>>
 where
     R: SlackWebRequestSender,
@@ -104,7 +138,6 @@ where
         .as_ref()
         .map(|include_num_members| include_num_members.to_string().into());
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
         include_num_members
             .as_ref()
             .map(|include_num_members| ("include_num_members", include_num_members.as_ref())),
@@ -112,19 +145,36 @@ where
             .team_id
             .as_ref()
             .map(|team_id| ("team_id", team_id.as_ref())),
-        Some(("usergroup_id", request.usergroup_id.as_ref())),
+        request
+            .usergroup_id
+            .as_ref()
+            .map(|usergroup_id| ("usergroup_id", usergroup_id.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.usergroups.listChannels");
+    let url = crate::get_slack_url_for_method(client.base_url(), "admin.usergroups.listChannels");
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .await
         .map_err(ListChannelsError::Client)
         .and_then(|result| {
-            serde_json::from_str::<ListChannelsResponse>(&result)
-                .map_err(|e| ListChannelsError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<ListChannelsResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => ListChannelsError::new(&code, context),
+                    None => ListChannelsError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.usergroups.listChannels", &warnings);
+            }
+        })
 }
 /// Remove one or more default channels from an org-level IDP group (user group).
 ///
@@ -140,17 +190,34 @@ where
 {
     let params: Vec<Option<(&str, &str)>> = vec![
         Some(("channel_ids", request.channel_ids.as_ref())),
-        Some(("usergroup_id", request.usergroup_id.as_ref())),
+        request
+            .usergroup_id
+            .as_ref()
+            .map(|usergroup_id| ("usergroup_id", usergroup_id.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.usergroups.removeChannels");
+    let url = crate::get_slack_url_for_method(client.base_url(), "admin.usergroups.removeChannels");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .await
         .map_err(RemoveChannelsError::Client)
         .and_then(|result| {
-            serde_json::from_str::<RemoveChannelsResponse>(&result)
-                .map_err(|e| RemoveChannelsError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<RemoveChannelsResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => RemoveChannelsError::new(&code, context),
+                    None => RemoveChannelsError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.usergroups.removeChannels", &warnings);
+            }
+        })
 }
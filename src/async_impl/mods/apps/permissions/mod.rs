@@ -36,18 +36,32 @@ pub async fn info<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params: Vec<Option<(&str, &str)>> = vec![token.map(|token| ("token", token))];
+    let params: Vec<Option<(&str, &str)>> = vec![];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/apps.permissions.info");
+    let url = crate::get_slack_url_for_method(client.base_url(), "apps.permissions.info");
     client
-        .get(&url, &params[..])
+        .get_response(&url, token, &params[..])
         .await
         .map_err(InfoError::Client)
         .and_then(|result| {
-            serde_json::from_str::<InfoResponse>(&result)
-                .map_err(|e| InfoError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<InfoResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => InfoError::new(&code, context),
+                    None => InfoError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("apps.permissions.info", &warnings);
+            }
+        })
 }
 /// Allows an app to request additional scopes
 ///
@@ -62,19 +76,32 @@ where
     R: SlackWebRequestSender,
 {
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
         Some(("scopes", request.scopes.as_ref())),
         Some(("trigger_id", request.trigger_id.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/apps.permissions.request");
+    let url = crate::get_slack_url_for_method(client.base_url(), "apps.permissions.request");
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .await
         .map_err(RequestError::Client)
         .and_then(|result| {
-            serde_json::from_str::<RequestResponse>(&result)
-                .map_err(|e| RequestError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<RequestResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => RequestError::new(&code, context),
+                    None => RequestError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("apps.permissions.request", &warnings);
+            }
+        })
 }
@@ -32,16 +32,30 @@ pub async fn list<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params: Vec<Option<(&str, &str)>> = vec![Some(("token", token))];
+    let params: Vec<Option<(&str, &str)>> = vec![];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/apps.permissions.scopes.list");
+    let url = crate::get_slack_url_for_method(client.base_url(), "apps.permissions.scopes.list");
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .await
         .map_err(ListError::Client)
         .and_then(|result| {
-            serde_json::from_str::<ListResponse>(&result)
-                .map_err(|e| ListError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<ListResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => ListError::new(&code, context),
+                    None => ListError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("apps.permissions.scopes.list", &warnings);
+            }
+        })
 }
@@ -33,21 +33,32 @@ where
     R: SlackWebRequestSender,
 {
     let test: Option<Cow<'_, str>> = request.test.as_ref().map(|test| test.to_string().into());
-    let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
-        test.as_ref().map(|test| ("test", test.as_ref())),
-    ];
+    let params: Vec<Option<(&str, &str)>> = vec![test.as_ref().map(|test| ("test", test.as_ref()))];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/auth.revoke");
+    let url = crate::get_slack_url_for_method(client.base_url(), "auth.revoke");
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .await
         .map_err(RevokeError::Client)
         .and_then(|result| {
-            serde_json::from_str::<RevokeResponse>(&result)
-                .map_err(|e| RevokeError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<RevokeResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => RevokeError::new(&code, context),
+                    None => RevokeError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("auth.revoke", &warnings);
+            }
+        })
 }
 /// Checks authentication & identity.
 ///
@@ -61,16 +72,30 @@ pub async fn test<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params: Vec<Option<(&str, &str)>> = vec![Some(("token", token))];
+    let params: Vec<Option<(&str, &str)>> = vec![];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/auth.test");
+    let url = crate::get_slack_url_for_method(client.base_url(), "auth.test");
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .await
         .map_err(TestError::Client)
         .and_then(|result| {
-            serde_json::from_str::<TestResponse>(&result)
-                .map_err(|e| TestError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<TestResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => TestError::new(&code, context),
+                    None => TestError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("auth.test", &warnings);
+            }
+        })
 }
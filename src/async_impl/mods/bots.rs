@@ -27,24 +27,36 @@ use std::borrow::Cow;
 pub async fn info<R>(
     client: &R,
     token: &str,
-    request: &InfoRequest<'_>,
+    request: &InfoRequest,
 ) -> Result<InfoResponse, InfoError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
-    let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
-        request.bot.as_ref().map(|bot| ("bot", bot.as_ref())),
-    ];
+    let params: Vec<Option<(&str, &str)>> =
+        vec![request.bot.as_ref().map(|bot| ("bot", bot.as_ref()))];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/bots.info");
+    let url = crate::get_slack_url_for_method(client.base_url(), "bots.info");
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .await
         .map_err(InfoError::Client)
         .and_then(|result| {
-            serde_json::from_str::<InfoResponse>(&result)
-                .map_err(|e| InfoError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<InfoResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => InfoError::new(&code, context),
+                    None => InfoError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("bots.info", &warnings);
+            }
+        })
 }
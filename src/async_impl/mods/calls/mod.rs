@@ -34,48 +34,66 @@ pub async fn add<R>(
 where
     R: SlackWebRequestSender,
 {
-    let date_start: Option<Cow<'_, str>> = request
-        .date_start
-        .as_ref()
-        .map(|date_start| date_start.to_string().into());
-    let params: Vec<Option<(&str, &str)>> = vec![
+    let body: Vec<Option<(&str, serde_json::Value)>> = vec![
         request
             .created_by
             .as_ref()
-            .map(|created_by| ("created_by", created_by.as_ref())),
-        date_start
-            .as_ref()
-            .map(|date_start| ("date_start", date_start.as_ref())),
+            .map(|created_by| ("created_by", created_by.as_ref().into())),
+        request
+            .date_start
+            .map(|date_start| ("date_start", date_start.into())),
         request
             .desktop_app_join_url
             .as_ref()
-            .map(|desktop_app_join_url| ("desktop_app_join_url", desktop_app_join_url.as_ref())),
+            .map(|desktop_app_join_url| {
+                ("desktop_app_join_url", desktop_app_join_url.as_ref().into())
+            }),
         request
             .external_display_id
             .as_ref()
-            .map(|external_display_id| ("external_display_id", external_display_id.as_ref())),
-        Some(("external_unique_id", request.external_unique_id.as_ref())),
-        Some(("join_url", request.join_url.as_ref())),
+            .map(|external_display_id| {
+                ("external_display_id", external_display_id.as_ref().into())
+            }),
+        Some((
+            "external_unique_id",
+            request.external_unique_id.as_ref().into(),
+        )),
+        Some(("join_url", request.join_url.as_ref().into())),
         request
             .title
             .as_ref()
-            .map(|title| ("title", title.as_ref())),
-        request
-            .users
-            .as_ref()
-            .map(|users| ("users", users.as_ref())),
+            .map(|title| ("title", title.as_ref().into())),
+        request.users.clone().map(|users| ("users", users)),
     ];
-    let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/calls.add");
+    let body: serde_json::Map<String, serde_json::Value> = body
+        .into_iter()
+        .filter_map(|x| x)
+        .map(|(name, value)| (name.to_owned(), value))
+        .collect();
+    let url = crate::get_slack_url_for_method(client.base_url(), "calls.add");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_json(&url, Some(token), &serde_json::Value::Object(body))
         .await
         .map_err(AddError::Client)
         .and_then(|result| {
-            serde_json::from_str::<AddResponse>(&result)
-                .map_err(|e| AddError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<AddResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => AddError::new(&code, context),
+                    None => AddError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("calls.add", &warnings);
+            }
+        })
 }
 /// Ends a Call.
 ///
@@ -89,27 +107,41 @@ pub async fn end<R>(
 where
     R: SlackWebRequestSender,
 {
-    let duration: Option<Cow<'_, str>> = request
-        .duration
-        .as_ref()
-        .map(|duration| duration.to_string().into());
-    let params: Vec<Option<(&str, &str)>> = vec![
-        duration
-            .as_ref()
-            .map(|duration| ("duration", duration.as_ref())),
-        Some(("id", request.id.as_ref())),
+    let body: Vec<Option<(&str, serde_json::Value)>> = vec![
+        request
+            .duration
+            .map(|duration| ("duration", duration.into())),
+        Some(("id", request.id.as_ref().into())),
     ];
-    let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/calls.end");
+    let body: serde_json::Map<String, serde_json::Value> = body
+        .into_iter()
+        .filter_map(|x| x)
+        .map(|(name, value)| (name.to_owned(), value))
+        .collect();
+    let url = crate::get_slack_url_for_method(client.base_url(), "calls.end");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_json(&url, Some(token), &serde_json::Value::Object(body))
         .await
         .map_err(EndError::Client)
         .and_then(|result| {
-            serde_json::from_str::<EndResponse>(&result)
-                .map_err(|e| EndError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<EndResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => EndError::new(&code, context),
+                    None => EndError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("calls.end", &warnings);
+            }
+        })
 }
 /// Returns information about a Call.
 ///
@@ -123,19 +155,37 @@ pub async fn info<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params: Vec<Option<(&str, &str)>> =
-        vec![Some(("token", token)), Some(("id", request.id.as_ref()))];
-    let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/calls.info");
+    let body: Vec<Option<(&str, serde_json::Value)>> =
+        vec![Some(("id", request.id.as_ref().into()))];
+    let body: serde_json::Map<String, serde_json::Value> = body
+        .into_iter()
+        .filter_map(|x| x)
+        .map(|(name, value)| (name.to_owned(), value))
+        .collect();
+    let url = crate::get_slack_url_for_method(client.base_url(), "calls.info");
     client
-        .get(&url, &params[..])
+        .post_json(&url, Some(token), &serde_json::Value::Object(body))
         .await
         .map_err(InfoError::Client)
         .and_then(|result| {
-            serde_json::from_str::<InfoResponse>(&result)
-                .map_err(|e| InfoError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<InfoResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => InfoError::new(&code, context),
+                    None => InfoError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("calls.info", &warnings);
+            }
+        })
 }
 /// Updates information about a Call.
 ///
@@ -149,30 +199,50 @@ pub async fn update<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params: Vec<Option<(&str, &str)>> = vec![
+    let body: Vec<Option<(&str, serde_json::Value)>> = vec![
         request
             .desktop_app_join_url
             .as_ref()
-            .map(|desktop_app_join_url| ("desktop_app_join_url", desktop_app_join_url.as_ref())),
-        Some(("id", request.id.as_ref())),
+            .map(|desktop_app_join_url| {
+                ("desktop_app_join_url", desktop_app_join_url.as_ref().into())
+            }),
+        Some(("id", request.id.as_ref().into())),
         request
             .join_url
             .as_ref()
-            .map(|join_url| ("join_url", join_url.as_ref())),
+            .map(|join_url| ("join_url", join_url.as_ref().into())),
         request
             .title
             .as_ref()
-            .map(|title| ("title", title.as_ref())),
+            .map(|title| ("title", title.as_ref().into())),
     ];
-    let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/calls.update");
+    let body: serde_json::Map<String, serde_json::Value> = body
+        .into_iter()
+        .filter_map(|x| x)
+        .map(|(name, value)| (name.to_owned(), value))
+        .collect();
+    let url = crate::get_slack_url_for_method(client.base_url(), "calls.update");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_json(&url, Some(token), &serde_json::Value::Object(body))
         .await
         .map_err(UpdateError::Client)
         .and_then(|result| {
-            serde_json::from_str::<UpdateResponse>(&result)
-                .map_err(|e| UpdateError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<UpdateResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => UpdateError::new(&code, context),
+                    None => UpdateError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("calls.update", &warnings);
+            }
+        })
 }
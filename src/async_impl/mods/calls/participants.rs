@@ -32,21 +32,39 @@ pub async fn add<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("id", request.id.as_ref())),
-        Some(("users", request.users.as_ref())),
+    let body: Vec<Option<(&str, serde_json::Value)>> = vec![
+        Some(("id", request.id.as_ref().into())),
+        Some(("users", request.users.clone())),
     ];
-    let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/calls.participants.add");
+    let body: serde_json::Map<String, serde_json::Value> = body
+        .into_iter()
+        .filter_map(|x| x)
+        .map(|(name, value)| (name.to_owned(), value))
+        .collect();
+    let url = crate::get_slack_url_for_method(client.base_url(), "calls.participants.add");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_json(&url, Some(token), &serde_json::Value::Object(body))
         .await
         .map_err(AddError::Client)
         .and_then(|result| {
-            serde_json::from_str::<AddResponse>(&result)
-                .map_err(|e| AddError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<AddResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => AddError::new(&code, context),
+                    None => AddError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("calls.participants.add", &warnings);
+            }
+        })
 }
 /// Registers participants removed from a Call.
 ///
@@ -60,19 +78,37 @@ pub async fn remove<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("id", request.id.as_ref())),
-        Some(("users", request.users.as_ref())),
+    let body: Vec<Option<(&str, serde_json::Value)>> = vec![
+        Some(("id", request.id.as_ref().into())),
+        Some(("users", request.users.clone())),
     ];
-    let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/calls.participants.remove");
+    let body: serde_json::Map<String, serde_json::Value> = body
+        .into_iter()
+        .filter_map(|x| x)
+        .map(|(name, value)| (name.to_owned(), value))
+        .collect();
+    let url = crate::get_slack_url_for_method(client.base_url(), "calls.participants.remove");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_json(&url, Some(token), &serde_json::Value::Object(body))
         .await
         .map_err(RemoveError::Client)
         .and_then(|result| {
-            serde_json::from_str::<RemoveResponse>(&result)
-                .map_err(|e| RemoveError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<RemoveResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => RemoveError::new(&code, context),
+                    None => RemoveError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("calls.participants.remove", &warnings);
+            }
+        })
 }
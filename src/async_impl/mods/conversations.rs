@@ -27,7 +27,7 @@ use std::borrow::Cow;
 pub async fn archive<R>(
     client: &R,
     token: Option<&str>,
-    request: &ArchiveRequest<'_>,
+    request: &ArchiveRequest,
 ) -> Result<ArchiveResponse, ArchiveError<R::Error>>
 where
     R: SlackWebRequestSender,
@@ -37,20 +37,30 @@ where
         .as_ref()
         .map(|channel| ("channel", channel.as_ref()))];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/conversations.archive");
+    let url = crate::get_slack_url_for_method(client.base_url(), "conversations.archive");
     client
-        .post(
-            &url,
-            &params[..],
-            &token.map_or(vec![], |t| vec![("token", t)]),
-        )
+        .post_response(&url, token, &params[..])
         .await
         .map_err(ArchiveError::Client)
         .and_then(|result| {
-            serde_json::from_str::<ArchiveResponse>(&result)
-                .map_err(|e| ArchiveError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<ArchiveResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => ArchiveError::new(&code, context),
+                    None => ArchiveError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("conversations.archive", &warnings);
+            }
+        })
 }
 /// Closes a direct message or multi-person direct message.
 ///
@@ -59,7 +69,7 @@ where
 pub async fn close<R>(
     client: &R,
     token: Option<&str>,
-    request: &CloseRequest<'_>,
+    request: &CloseRequest,
 ) -> Result<CloseResponse, CloseError<R::Error>>
 where
     R: SlackWebRequestSender,
@@ -69,20 +79,30 @@ where
         .as_ref()
         .map(|channel| ("channel", channel.as_ref()))];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/conversations.close");
+    let url = crate::get_slack_url_for_method(client.base_url(), "conversations.close");
     client
-        .post(
-            &url,
-            &params[..],
-            &token.map_or(vec![], |t| vec![("token", t)]),
-        )
+        .post_response(&url, token, &params[..])
         .await
         .map_err(CloseError::Client)
         .and_then(|result| {
-            serde_json::from_str::<CloseResponse>(&result)
-                .map_err(|e| CloseError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<CloseResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => CloseError::new(&code, context),
+                    None => CloseError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("conversations.close", &warnings);
+            }
+        })
 }
 /// Initiates a public or private channel-based conversation
 ///
@@ -107,20 +127,30 @@ where
         request.name.as_ref().map(|name| ("name", name.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/conversations.create");
+    let url = crate::get_slack_url_for_method(client.base_url(), "conversations.create");
     client
-        .post(
-            &url,
-            &params[..],
-            &token.map_or(vec![], |t| vec![("token", t)]),
-        )
+        .post_response(&url, token, &params[..])
         .await
         .map_err(CreateError::Client)
         .and_then(|result| {
-            serde_json::from_str::<CreateResponse>(&result)
-                .map_err(|e| CreateError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<CreateResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => CreateError::new(&code, context),
+                    None => CreateError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("conversations.create", &warnings);
+            }
+        })
 }
 /// Fetches a conversation's history of messages and events.
 ///
@@ -141,15 +171,17 @@ where
     let latest: Option<Cow<'_, str>> = request
         .latest
         .as_ref()
-        .map(|latest| latest.to_string().into());
+        .map(|latest| latest.to_param_value().into());
     let limit: Option<Cow<'_, str>> = request.limit.as_ref().map(|limit| limit.to_string().into());
     let oldest: Option<Cow<'_, str>> = request
         .oldest
         .as_ref()
-        .map(|oldest| oldest.to_string().into());
+        .map(|oldest| oldest.to_param_value().into());
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
-        Some(("channel", request.channel.as_ref())),
+        request
+            .channel
+            .as_ref()
+            .map(|channel| ("channel", channel.as_ref())),
         request
             .cursor
             .as_ref()
@@ -162,16 +194,49 @@ where
         oldest.as_ref().map(|oldest| ("oldest", oldest.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/conversations.history");
+    let url = crate::get_slack_url_for_method(client.base_url(), "conversations.history");
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .await
         .map_err(HistoryError::Client)
         .and_then(|result| {
-            serde_json::from_str::<HistoryResponse>(&result)
-                .map_err(|e| HistoryError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<HistoryResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => HistoryError::new(&code, context),
+                    None => HistoryError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("conversations.history", &warnings);
+            }
+        })
+}
+/// Calls [`history`] page by page and yields the `messages` of every page.
+///
+/// Follows `response_metadata.next_cursor` until it is empty, and stops early once
+/// `max_items` items were yielded. An error is yielded once and ends the stream.
+pub fn history_stream<'a, R>(
+    client: &'a R,
+    token: &'a str,
+    request: &HistoryRequest<'a>,
+    max_items: Option<usize>,
+) -> impl futures_util::Stream<Item = Result<HistoryMessagesInner, HistoryError<R::Error>>> + 'a
+where
+    R: SlackWebRequestSender,
+{
+    crate::async_impl::pagination::cursor_stream(
+        request.clone(),
+        max_items,
+        move |request| async move { history(client, token, &request).await },
+    )
 }
 /// Retrieve information about a conversation.
 ///
@@ -180,7 +245,7 @@ where
 pub async fn info<R>(
     client: &R,
     token: &str,
-    request: &InfoRequest<'_>,
+    request: &InfoRequest,
 ) -> Result<InfoResponse, InfoError<R::Error>>
 where
     R: SlackWebRequestSender,
@@ -194,8 +259,10 @@ where
         .as_ref()
         .map(|include_num_members| include_num_members.to_string().into());
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
-        Some(("channel", request.channel.as_ref())),
+        request
+            .channel
+            .as_ref()
+            .map(|channel| ("channel", channel.as_ref())),
         include_locale
             .as_ref()
             .map(|include_locale| ("include_locale", include_locale.as_ref())),
@@ -204,16 +271,30 @@ where
             .map(|include_num_members| ("include_num_members", include_num_members.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/conversations.info");
+    let url = crate::get_slack_url_for_method(client.base_url(), "conversations.info");
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .await
         .map_err(InfoError::Client)
         .and_then(|result| {
-            serde_json::from_str::<InfoResponse>(&result)
-                .map_err(|e| InfoError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<InfoResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => InfoError::new(&code, context),
+                    None => InfoError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("conversations.info", &warnings);
+            }
+        })
 }
 /// Invites users to a channel.
 ///
@@ -238,20 +319,30 @@ where
             .map(|users| ("users", users.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/conversations.invite");
+    let url = crate::get_slack_url_for_method(client.base_url(), "conversations.invite");
     client
-        .post(
-            &url,
-            &params[..],
-            &token.map_or(vec![], |t| vec![("token", t)]),
-        )
+        .post_response(&url, token, &params[..])
         .await
         .map_err(InviteError::Client)
         .and_then(|result| {
-            serde_json::from_str::<InviteResponse>(&result)
-                .map_err(|e| InviteError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<InviteResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => InviteError::new(&code, context),
+                    None => InviteError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("conversations.invite", &warnings);
+            }
+        })
 }
 /// Joins an existing conversation.
 ///
@@ -260,7 +351,7 @@ where
 pub async fn join<R>(
     client: &R,
     token: Option<&str>,
-    request: &JoinRequest<'_>,
+    request: &JoinRequest,
 ) -> Result<JoinResponse, JoinError<R::Error>>
 where
     R: SlackWebRequestSender,
@@ -270,20 +361,30 @@ where
         .as_ref()
         .map(|channel| ("channel", channel.as_ref()))];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/conversations.join");
+    let url = crate::get_slack_url_for_method(client.base_url(), "conversations.join");
     client
-        .post(
-            &url,
-            &params[..],
-            &token.map_or(vec![], |t| vec![("token", t)]),
-        )
+        .post_response(&url, token, &params[..])
         .await
         .map_err(JoinError::Client)
         .and_then(|result| {
-            serde_json::from_str::<JoinResponse>(&result)
-                .map_err(|e| JoinError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<JoinResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => JoinError::new(&code, context),
+                    None => JoinError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("conversations.join", &warnings);
+            }
+        })
 }
 /// Removes a user from a conversation.
 ///
@@ -292,7 +393,7 @@ where
 pub async fn kick<R>(
     client: &R,
     token: Option<&str>,
-    request: &KickRequest<'_>,
+    request: &KickRequest,
 ) -> Result<KickResponse, KickError<R::Error>>
 where
     R: SlackWebRequestSender,
@@ -305,20 +406,30 @@ where
         request.user.as_ref().map(|user| ("user", user.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/conversations.kick");
+    let url = crate::get_slack_url_for_method(client.base_url(), "conversations.kick");
     client
-        .post(
-            &url,
-            &params[..],
-            &token.map_or(vec![], |t| vec![("token", t)]),
-        )
+        .post_response(&url, token, &params[..])
         .await
         .map_err(KickError::Client)
         .and_then(|result| {
-            serde_json::from_str::<KickResponse>(&result)
-                .map_err(|e| KickError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<KickResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => KickError::new(&code, context),
+                    None => KickError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("conversations.kick", &warnings);
+            }
+        })
 }
 /// Leaves a conversation.
 ///
@@ -327,7 +438,7 @@ where
 pub async fn leave<R>(
     client: &R,
     token: Option<&str>,
-    request: &LeaveRequest<'_>,
+    request: &LeaveRequest,
 ) -> Result<LeaveResponse, LeaveError<R::Error>>
 where
     R: SlackWebRequestSender,
@@ -337,20 +448,30 @@ where
         .as_ref()
         .map(|channel| ("channel", channel.as_ref()))];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/conversations.leave");
+    let url = crate::get_slack_url_for_method(client.base_url(), "conversations.leave");
     client
-        .post(
-            &url,
-            &params[..],
-            &token.map_or(vec![], |t| vec![("token", t)]),
-        )
+        .post_response(&url, token, &params[..])
         .await
         .map_err(LeaveError::Client)
         .and_then(|result| {
-            serde_json::from_str::<LeaveResponse>(&result)
-                .map_err(|e| LeaveError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<LeaveResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => LeaveError::new(&code, context),
+                    None => LeaveError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("conversations.leave", &warnings);
+            }
+        })
 }
 /// Lists all channels in a Slack team.
 ///
@@ -370,7 +491,6 @@ where
         .map(|exclude_archived| exclude_archived.to_string().into());
     let limit: Option<Cow<'_, str>> = request.limit.as_ref().map(|limit| limit.to_string().into());
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
         request
             .cursor
             .as_ref()
@@ -385,16 +505,49 @@ where
             .map(|types| ("types", types.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/conversations.list");
+    let url = crate::get_slack_url_for_method(client.base_url(), "conversations.list");
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .await
         .map_err(ListError::Client)
         .and_then(|result| {
-            serde_json::from_str::<ListResponse>(&result)
-                .map_err(|e| ListError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<ListResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => ListError::new(&code, context),
+                    None => ListError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("conversations.list", &warnings);
+            }
+        })
+}
+/// Calls [`list`] page by page and yields the `channels` of every page.
+///
+/// Follows `response_metadata.next_cursor` until it is empty, and stops early once
+/// `max_items` items were yielded. An error is yielded once and ends the stream.
+pub fn list_stream<'a, R>(
+    client: &'a R,
+    token: &'a str,
+    request: &ListRequest<'a>,
+    max_items: Option<usize>,
+) -> impl futures_util::Stream<Item = Result<ListChannelsInner, ListError<R::Error>>> + 'a
+where
+    R: SlackWebRequestSender,
+{
+    crate::async_impl::pagination::cursor_stream(
+        request.clone(),
+        max_items,
+        move |request| async move { list(client, token, &request).await },
+    )
 }
 /// Sets the read cursor in a channel.
 ///
@@ -403,12 +556,12 @@ where
 pub async fn mark<R>(
     client: &R,
     token: Option<&str>,
-    request: &MarkRequest<'_>,
+    request: &MarkRequest,
 ) -> Result<MarkResponse, MarkError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
-    let ts: Option<Cow<'_, str>> = request.ts.as_ref().map(|ts| ts.to_string().into());
+    let ts: Option<Cow<'_, str>> = request.ts.as_ref().map(|ts| ts.to_param_value().into());
     let params: Vec<Option<(&str, &str)>> = vec![
         request
             .channel
@@ -417,20 +570,30 @@ where
         ts.as_ref().map(|ts| ("ts", ts.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/conversations.mark");
+    let url = crate::get_slack_url_for_method(client.base_url(), "conversations.mark");
     client
-        .post(
-            &url,
-            &params[..],
-            &token.map_or(vec![], |t| vec![("token", t)]),
-        )
+        .post_response(&url, token, &params[..])
         .await
         .map_err(MarkError::Client)
         .and_then(|result| {
-            serde_json::from_str::<MarkResponse>(&result)
-                .map_err(|e| MarkError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<MarkResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => MarkError::new(&code, context),
+                    None => MarkError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("conversations.mark", &warnings);
+            }
+        })
 }
 /// Retrieve members of a conversation.
 ///
@@ -446,7 +609,6 @@ where
 {
     let limit: Option<Cow<'_, str>> = request.limit.as_ref().map(|limit| limit.to_string().into());
     let params: Vec<Option<(&str, &str)>> = vec![
-        token.map(|token| ("token", token)),
         request
             .channel
             .as_ref()
@@ -458,16 +620,49 @@ where
         limit.as_ref().map(|limit| ("limit", limit.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/conversations.members");
+    let url = crate::get_slack_url_for_method(client.base_url(), "conversations.members");
     client
-        .get(&url, &params[..])
+        .get_response(&url, token, &params[..])
         .await
         .map_err(MembersError::Client)
         .and_then(|result| {
-            serde_json::from_str::<MembersResponse>(&result)
-                .map_err(|e| MembersError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<MembersResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => MembersError::new(&code, context),
+                    None => MembersError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("conversations.members", &warnings);
+            }
+        })
+}
+/// Calls [`members`] page by page and yields the `members` of every page.
+///
+/// Follows `response_metadata.next_cursor` until it is empty, and stops early once
+/// `max_items` items were yielded. An error is yielded once and ends the stream.
+pub fn members_stream<'a, R>(
+    client: &'a R,
+    token: Option<&'a str>,
+    request: &MembersRequest<'a>,
+    max_items: Option<usize>,
+) -> impl futures_util::Stream<Item = Result<String, MembersError<R::Error>>> + 'a
+where
+    R: SlackWebRequestSender,
+{
+    crate::async_impl::pagination::cursor_stream(
+        request.clone(),
+        max_items,
+        move |request| async move { members(client, token, &request).await },
+    )
 }
 /// Opens or resumes a direct message or multi-person direct message.
 ///
@@ -499,20 +694,30 @@ where
             .map(|users| ("users", users.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/conversations.open");
+    let url = crate::get_slack_url_for_method(client.base_url(), "conversations.open");
     client
-        .post(
-            &url,
-            &params[..],
-            &token.map_or(vec![], |t| vec![("token", t)]),
-        )
+        .post_response(&url, token, &params[..])
         .await
         .map_err(OpenError::Client)
         .and_then(|result| {
-            serde_json::from_str::<OpenResponse>(&result)
-                .map_err(|e| OpenError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<OpenResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => OpenError::new(&code, context),
+                    None => OpenError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("conversations.open", &warnings);
+            }
+        })
 }
 /// Renames a conversation.
 ///
@@ -534,20 +739,30 @@ where
         request.name.as_ref().map(|name| ("name", name.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/conversations.rename");
+    let url = crate::get_slack_url_for_method(client.base_url(), "conversations.rename");
     client
-        .post(
-            &url,
-            &params[..],
-            &token.map_or(vec![], |t| vec![("token", t)]),
-        )
+        .post_response(&url, token, &params[..])
         .await
         .map_err(RenameError::Client)
         .and_then(|result| {
-            serde_json::from_str::<RenameResponse>(&result)
-                .map_err(|e| RenameError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<RenameResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => RenameError::new(&code, context),
+                    None => RenameError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("conversations.rename", &warnings);
+            }
+        })
 }
 /// Retrieve a thread of messages posted to a conversation
 ///
@@ -568,15 +783,14 @@ where
     let latest: Option<Cow<'_, str>> = request
         .latest
         .as_ref()
-        .map(|latest| latest.to_string().into());
+        .map(|latest| latest.to_param_value().into());
     let limit: Option<Cow<'_, str>> = request.limit.as_ref().map(|limit| limit.to_string().into());
     let oldest: Option<Cow<'_, str>> = request
         .oldest
         .as_ref()
-        .map(|oldest| oldest.to_string().into());
-    let ts: Option<Cow<'_, str>> = request.ts.as_ref().map(|ts| ts.to_string().into());
+        .map(|oldest| oldest.to_param_value().into());
+    let ts: Option<Cow<'_, str>> = request.ts.as_ref().map(|ts| ts.to_param_value().into());
     let params: Vec<Option<(&str, &str)>> = vec![
-        token.map(|token| ("token", token)),
         request
             .channel
             .as_ref()
@@ -594,16 +808,49 @@ where
         ts.as_ref().map(|ts| ("ts", ts.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/conversations.replies");
+    let url = crate::get_slack_url_for_method(client.base_url(), "conversations.replies");
     client
-        .get(&url, &params[..])
+        .get_response(&url, token, &params[..])
         .await
         .map_err(RepliesError::Client)
         .and_then(|result| {
-            serde_json::from_str::<RepliesResponse>(&result)
-                .map_err(|e| RepliesError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<RepliesResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => RepliesError::new(&code, context),
+                    None => RepliesError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("conversations.replies", &warnings);
+            }
+        })
+}
+/// Calls [`replies`] page by page and yields the `messages` of every page.
+///
+/// Follows `response_metadata.next_cursor` until it is empty, and stops early once
+/// `max_items` items were yielded. An error is yielded once and ends the stream.
+pub fn replies_stream<'a, R>(
+    client: &'a R,
+    token: Option<&'a str>,
+    request: &RepliesRequest<'a>,
+    max_items: Option<usize>,
+) -> impl futures_util::Stream<Item = Result<RepliesMessagesInner, RepliesError<R::Error>>> + 'a
+where
+    R: SlackWebRequestSender,
+{
+    crate::async_impl::pagination::cursor_stream(
+        request.clone(),
+        max_items,
+        move |request| async move { replies(client, token, &request).await },
+    )
 }
 /// Sets the purpose for a conversation.
 ///
@@ -628,20 +875,30 @@ where
             .map(|purpose| ("purpose", purpose.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/conversations.setPurpose");
+    let url = crate::get_slack_url_for_method(client.base_url(), "conversations.setPurpose");
     client
-        .post(
-            &url,
-            &params[..],
-            &token.map_or(vec![], |t| vec![("token", t)]),
-        )
+        .post_response(&url, token, &params[..])
         .await
         .map_err(SetPurposeError::Client)
         .and_then(|result| {
-            serde_json::from_str::<SetPurposeResponse>(&result)
-                .map_err(|e| SetPurposeError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<SetPurposeResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => SetPurposeError::new(&code, context),
+                    None => SetPurposeError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("conversations.setPurpose", &warnings);
+            }
+        })
 }
 /// Sets the topic for a conversation.
 ///
@@ -666,20 +923,30 @@ where
             .map(|topic| ("topic", topic.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/conversations.setTopic");
+    let url = crate::get_slack_url_for_method(client.base_url(), "conversations.setTopic");
     client
-        .post(
-            &url,
-            &params[..],
-            &token.map_or(vec![], |t| vec![("token", t)]),
-        )
+        .post_response(&url, token, &params[..])
         .await
         .map_err(SetTopicError::Client)
         .and_then(|result| {
-            serde_json::from_str::<SetTopicResponse>(&result)
-                .map_err(|e| SetTopicError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<SetTopicResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => SetTopicError::new(&code, context),
+                    None => SetTopicError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("conversations.setTopic", &warnings);
+            }
+        })
 }
 /// Reverses conversation archival.
 ///
@@ -688,7 +955,7 @@ where
 pub async fn unarchive<R>(
     client: &R,
     token: Option<&str>,
-    request: &UnarchiveRequest<'_>,
+    request: &UnarchiveRequest,
 ) -> Result<UnarchiveResponse, UnarchiveError<R::Error>>
 where
     R: SlackWebRequestSender,
@@ -698,18 +965,28 @@ where
         .as_ref()
         .map(|channel| ("channel", channel.as_ref()))];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/conversations.unarchive");
+    let url = crate::get_slack_url_for_method(client.base_url(), "conversations.unarchive");
     client
-        .post(
-            &url,
-            &params[..],
-            &token.map_or(vec![], |t| vec![("token", t)]),
-        )
+        .post_response(&url, token, &params[..])
         .await
         .map_err(UnarchiveError::Client)
         .and_then(|result| {
-            serde_json::from_str::<UnarchiveResponse>(&result)
-                .map_err(|e| UnarchiveError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<UnarchiveResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => UnarchiveError::new(&code, context),
+                    None => UnarchiveError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("conversations.unarchive", &warnings);
+            }
+        })
 }
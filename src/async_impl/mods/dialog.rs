@@ -32,20 +32,37 @@ pub async fn open<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
-        Some(("dialog", request.dialog.as_ref())),
-        Some(("trigger_id", request.trigger_id.as_ref())),
+    let body: Vec<Option<(&str, serde_json::Value)>> = vec![
+        Some(("dialog", request.dialog.clone())),
+        Some(("trigger_id", request.trigger_id.as_ref().into())),
     ];
-    let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/dialog.open");
+    let body: serde_json::Map<String, serde_json::Value> = body
+        .into_iter()
+        .filter_map(|x| x)
+        .map(|(name, value)| (name.to_owned(), value))
+        .collect();
+    let url = crate::get_slack_url_for_method(client.base_url(), "dialog.open");
     client
-        .get(&url, &params[..])
+        .post_json(&url, Some(token), &serde_json::Value::Object(body))
         .await
         .map_err(OpenError::Client)
         .and_then(|result| {
-            serde_json::from_str::<OpenResponse>(&result)
-                .map_err(|e| OpenError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<OpenResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => OpenError::new(&code, context),
+                    None => OpenError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("dialog.open", &warnings);
+            }
+        })
 }
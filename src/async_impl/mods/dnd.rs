@@ -34,16 +34,30 @@ where
 {
     let params: Vec<Option<(&str, &str)>> = vec![];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/dnd.endDnd");
+    let url = crate::get_slack_url_for_method(client.base_url(), "dnd.endDnd");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .await
         .map_err(EndDndError::Client)
         .and_then(|result| {
-            serde_json::from_str::<EndDndResponse>(&result)
-                .map_err(|e| EndDndError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<EndDndResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => EndDndError::new(&code, context),
+                    None => EndDndError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("dnd.endDnd", &warnings);
+            }
+        })
 }
 /// Ends the current user's snooze mode immediately.
 ///
@@ -59,16 +73,30 @@ where
 {
     let params: Vec<Option<(&str, &str)>> = vec![];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/dnd.endSnooze");
+    let url = crate::get_slack_url_for_method(client.base_url(), "dnd.endSnooze");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .await
         .map_err(EndSnoozeError::Client)
         .and_then(|result| {
-            serde_json::from_str::<EndSnoozeResponse>(&result)
-                .map_err(|e| EndSnoozeError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<EndSnoozeResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => EndSnoozeError::new(&code, context),
+                    None => EndSnoozeError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("dnd.endSnooze", &warnings);
+            }
+        })
 }
 /// Retrieves a user's current Do Not Disturb status.
 ///
@@ -77,26 +105,38 @@ where
 pub async fn info<R>(
     client: &R,
     token: Option<&str>,
-    request: &InfoRequest<'_>,
+    request: &InfoRequest,
 ) -> Result<InfoResponse, InfoError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
-    let params: Vec<Option<(&str, &str)>> = vec![
-        token.map(|token| ("token", token)),
-        request.user.as_ref().map(|user| ("user", user.as_ref())),
-    ];
+    let params: Vec<Option<(&str, &str)>> =
+        vec![request.user.as_ref().map(|user| ("user", user.as_ref()))];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/dnd.info");
+    let url = crate::get_slack_url_for_method(client.base_url(), "dnd.info");
     client
-        .get(&url, &params[..])
+        .get_response(&url, token, &params[..])
         .await
         .map_err(InfoError::Client)
         .and_then(|result| {
-            serde_json::from_str::<InfoResponse>(&result)
-                .map_err(|e| InfoError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<InfoResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => InfoError::new(&code, context),
+                    None => InfoError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("dnd.info", &warnings);
+            }
+        })
 }
 /// Turns on Do Not Disturb mode for the current user, or changes its duration.
 ///
@@ -113,16 +153,30 @@ where
     let params: Vec<Option<(&str, &str)>> =
         vec![Some(("num_minutes", request.num_minutes.as_ref()))];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/dnd.setSnooze");
+    let url = crate::get_slack_url_for_method(client.base_url(), "dnd.setSnooze");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .await
         .map_err(SetSnoozeError::Client)
         .and_then(|result| {
-            serde_json::from_str::<SetSnoozeResponse>(&result)
-                .map_err(|e| SetSnoozeError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<SetSnoozeResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => SetSnoozeError::new(&code, context),
+                    None => SetSnoozeError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("dnd.setSnooze", &warnings);
+            }
+        })
 }
 /// Retrieves the Do Not Disturb status for up to 50 users on a team.
 ///
@@ -136,22 +190,33 @@ pub async fn team_info<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params: Vec<Option<(&str, &str)>> = vec![
-        token.map(|token| ("token", token)),
-        request
-            .users
-            .as_ref()
-            .map(|users| ("users", users.as_ref())),
-    ];
+    let params: Vec<Option<(&str, &str)>> = vec![request
+        .users
+        .as_ref()
+        .map(|users| ("users", users.as_ref()))];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/dnd.teamInfo");
+    let url = crate::get_slack_url_for_method(client.base_url(), "dnd.teamInfo");
     client
-        .get(&url, &params[..])
+        .get_response(&url, token, &params[..])
         .await
         .map_err(TeamInfoError::Client)
         .and_then(|result| {
-            serde_json::from_str::<TeamInfoResponse>(&result)
-                .map_err(|e| TeamInfoError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<TeamInfoResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => TeamInfoError::new(&code, context),
+                    None => TeamInfoError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("dnd.teamInfo", &warnings);
+            }
+        })
 }
@@ -37,18 +37,28 @@ where
         request.id.as_ref().map(|id| ("id", id.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/files.comments.delete");
+    let url = crate::get_slack_url_for_method(client.base_url(), "files.comments.delete");
     client
-        .post(
-            &url,
-            &params[..],
-            &token.map_or(vec![], |t| vec![("token", t)]),
-        )
+        .post_response(&url, token, &params[..])
         .await
         .map_err(DeleteError::Client)
         .and_then(|result| {
-            serde_json::from_str::<DeleteResponse>(&result)
-                .map_err(|e| DeleteError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<DeleteResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => DeleteError::new(&code, context),
+                    None => DeleteError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("files.comments.delete", &warnings);
+            }
+        })
 }
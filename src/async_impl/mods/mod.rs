@@ -12,6 +12,7 @@ pub mod emoji;
 pub mod files;
 pub mod migration;
 pub mod oauth;
+pub mod openid;
 pub mod pins;
 pub mod reactions;
 pub mod reminders;
@@ -19,6 +20,7 @@ pub mod rtm;
 pub mod search;
 pub mod stars;
 pub mod team;
+pub mod tooling;
 pub mod usergroups;
 pub mod users;
 pub mod views;
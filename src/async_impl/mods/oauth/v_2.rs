@@ -16,6 +16,7 @@
 #![allow(clippy::match_single_binding)]
 #![allow(clippy::blacklisted_name)]
 
+pub use crate::async_impl::ext::oauth::v_2::*;
 use crate::async_impl::SlackWebRequestSender;
 pub use crate::mod_types::oauth::v_2_types::*;
 use std::borrow::Cow;
@@ -32,29 +33,48 @@ where
     R: SlackWebRequestSender,
 {
     let params: Vec<Option<(&str, &str)>> = vec![
+        request.code.as_ref().map(|code| ("code", code.as_ref())),
         request
-            .client_id
+            .grant_type
             .as_ref()
-            .map(|client_id| ("client_id", client_id.as_ref())),
-        request
-            .client_secret
-            .as_ref()
-            .map(|client_secret| ("client_secret", client_secret.as_ref())),
-        Some(("code", request.code.as_ref())),
+            .map(|grant_type| ("grant_type", grant_type.as_ref())),
         request
             .redirect_uri
             .as_ref()
             .map(|redirect_uri| ("redirect_uri", redirect_uri.as_ref())),
+        request
+            .refresh_token
+            .as_ref()
+            .map(|refresh_token| ("refresh_token", refresh_token.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/oauth.v2.access");
+    let url = crate::get_slack_url_for_method(client.base_url(), "oauth.v2.access");
     client
-        .get(&url, &params[..])
+        .post_client_auth(
+            &url,
+            request.client_id.as_ref(),
+            request.client_secret.as_ref(),
+            &params[..],
+        )
         .await
         .map_err(AccessError::Client)
         .and_then(|result| {
-            serde_json::from_str::<AccessResponse>(&result)
-                .map_err(|e| AccessError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<AccessResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => AccessError::new(&code, context),
+                    None => AccessError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("oauth.v2.access", &warnings);
+            }
+        })
 }
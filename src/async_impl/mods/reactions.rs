@@ -32,22 +32,42 @@ pub async fn add<R>(
 where
     R: SlackWebRequestSender,
 {
+    let timestamp: Option<Cow<'_, str>> = Some(request.timestamp.to_param_value().into());
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("channel", request.channel.as_ref())),
+        request
+            .channel
+            .as_ref()
+            .map(|channel| ("channel", channel.as_ref())),
         Some(("name", request.name.as_ref())),
-        Some(("timestamp", request.timestamp.as_ref())),
+        timestamp
+            .as_ref()
+            .map(|timestamp| ("timestamp", timestamp.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/reactions.add");
+    let url = crate::get_slack_url_for_method(client.base_url(), "reactions.add");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .await
         .map_err(AddError::Client)
         .and_then(|result| {
-            serde_json::from_str::<AddResponse>(&result)
-                .map_err(|e| AddError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<AddResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => AddError::new(&code, context),
+                    None => AddError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("reactions.add", &warnings);
+            }
+        })
 }
 /// Gets reactions for an item.
 ///
@@ -62,8 +82,11 @@ where
     R: SlackWebRequestSender,
 {
     let full: Option<Cow<'_, str>> = request.full.as_ref().map(|full| full.to_string().into());
+    let timestamp: Option<Cow<'_, str>> = request
+        .timestamp
+        .as_ref()
+        .map(|timestamp| timestamp.to_param_value().into());
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
         request
             .channel
             .as_ref()
@@ -74,22 +97,35 @@ where
             .as_ref()
             .map(|file_comment| ("file_comment", file_comment.as_ref())),
         full.as_ref().map(|full| ("full", full.as_ref())),
-        request
-            .timestamp
+        timestamp
             .as_ref()
             .map(|timestamp| ("timestamp", timestamp.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/reactions.get");
+    let url = crate::get_slack_url_for_method(client.base_url(), "reactions.get");
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .await
         .map_err(GetError::Client)
         .and_then(|result| {
-            serde_json::from_str::<GetResponse>(&result)
-                .map_err(|e| GetError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<GetResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => GetError::new(&code, context),
+                    None => GetError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("reactions.get", &warnings);
+            }
+        })
 }
 /// Lists reactions made by a user.
 ///
@@ -108,7 +144,6 @@ where
     let limit: Option<Cow<'_, str>> = request.limit.as_ref().map(|limit| limit.to_string().into());
     let page: Option<Cow<'_, str>> = request.page.as_ref().map(|page| page.to_string().into());
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
         count.as_ref().map(|count| ("count", count.as_ref())),
         request
             .cursor
@@ -120,16 +155,49 @@ where
         request.user.as_ref().map(|user| ("user", user.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/reactions.list");
+    let url = crate::get_slack_url_for_method(client.base_url(), "reactions.list");
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .await
         .map_err(ListError::Client)
         .and_then(|result| {
-            serde_json::from_str::<ListResponse>(&result)
-                .map_err(|e| ListError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<ListResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => ListError::new(&code, context),
+                    None => ListError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("reactions.list", &warnings);
+            }
+        })
+}
+/// Calls [`list`] page by page and yields the `items` of every page.
+///
+/// Follows `paging.page` up to `paging.pages`, and stops early once
+/// `max_items` items were yielded. An error is yielded once and ends the stream.
+pub fn list_stream<'a, R>(
+    client: &'a R,
+    token: &'a str,
+    request: &ListRequest<'a>,
+    max_items: Option<usize>,
+) -> impl futures_util::Stream<Item = Result<ListItemsInner, ListError<R::Error>>> + 'a
+where
+    R: SlackWebRequestSender,
+{
+    crate::async_impl::pagination::page_stream(
+        request.clone(),
+        max_items,
+        move |request| async move { list(client, token, &request).await },
+    )
 }
 /// Removes a reaction from an item.
 ///
@@ -143,6 +211,10 @@ pub async fn remove<R>(
 where
     R: SlackWebRequestSender,
 {
+    let timestamp: Option<Cow<'_, str>> = request
+        .timestamp
+        .as_ref()
+        .map(|timestamp| timestamp.to_param_value().into());
     let params: Vec<Option<(&str, &str)>> = vec![
         request
             .channel
@@ -154,20 +226,33 @@ where
             .as_ref()
             .map(|file_comment| ("file_comment", file_comment.as_ref())),
         Some(("name", request.name.as_ref())),
-        request
-            .timestamp
+        timestamp
             .as_ref()
             .map(|timestamp| ("timestamp", timestamp.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/reactions.remove");
+    let url = crate::get_slack_url_for_method(client.base_url(), "reactions.remove");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .await
         .map_err(RemoveError::Client)
         .and_then(|result| {
-            serde_json::from_str::<RemoveResponse>(&result)
-                .map_err(|e| RemoveError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<RemoveResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => RemoveError::new(&code, context),
+                    None => RemoveError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("reactions.remove", &warnings);
+            }
+        })
 }
@@ -41,7 +41,6 @@ where
         .as_ref()
         .map(|presence_sub| presence_sub.to_string().into());
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
         batch_presence_aware
             .as_ref()
             .map(|batch_presence_aware| ("batch_presence_aware", batch_presence_aware.as_ref())),
@@ -50,14 +49,28 @@ where
             .map(|presence_sub| ("presence_sub", presence_sub.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/rtm.connect");
+    let url = crate::get_slack_url_for_method(client.base_url(), "rtm.connect");
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .await
         .map_err(ConnectError::Client)
         .and_then(|result| {
-            serde_json::from_str::<ConnectResponse>(&result)
-                .map_err(|e| ConnectError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<ConnectResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => ConnectError::new(&code, context),
+                    None => ConnectError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("rtm.connect", &warnings);
+            }
+        })
 }
@@ -32,6 +32,10 @@ pub async fn add<R>(
 where
     R: SlackWebRequestSender,
 {
+    let timestamp: Option<Cow<'_, str>> = request
+        .timestamp
+        .as_ref()
+        .map(|timestamp| timestamp.to_param_value().into());
     let params: Vec<Option<(&str, &str)>> = vec![
         request
             .channel
@@ -42,22 +46,35 @@ where
             .file_comment
             .as_ref()
             .map(|file_comment| ("file_comment", file_comment.as_ref())),
-        request
-            .timestamp
+        timestamp
             .as_ref()
             .map(|timestamp| ("timestamp", timestamp.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/stars.add");
+    let url = crate::get_slack_url_for_method(client.base_url(), "stars.add");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .await
         .map_err(AddError::Client)
         .and_then(|result| {
-            serde_json::from_str::<AddResponse>(&result)
-                .map_err(|e| AddError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<AddResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => AddError::new(&code, context),
+                    None => AddError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("stars.add", &warnings);
+            }
+        })
 }
 /// Lists stars for a user.
 ///
@@ -73,7 +90,6 @@ where
 {
     let limit: Option<Cow<'_, str>> = request.limit.as_ref().map(|limit| limit.to_string().into());
     let params: Vec<Option<(&str, &str)>> = vec![
-        token.map(|token| ("token", token)),
         request
             .count
             .as_ref()
@@ -86,16 +102,49 @@ where
         request.page.as_ref().map(|page| ("page", page.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/stars.list");
+    let url = crate::get_slack_url_for_method(client.base_url(), "stars.list");
     client
-        .get(&url, &params[..])
+        .get_response(&url, token, &params[..])
         .await
         .map_err(ListError::Client)
         .and_then(|result| {
-            serde_json::from_str::<ListResponse>(&result)
-                .map_err(|e| ListError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<ListResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => ListError::new(&code, context),
+                    None => ListError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("stars.list", &warnings);
+            }
+        })
+}
+/// Calls [`list`] page by page and yields the `items` of every page.
+///
+/// Follows `paging.page` up to `paging.pages`, and stops early once
+/// `max_items` items were yielded. An error is yielded once and ends the stream.
+pub fn list_stream<'a, R>(
+    client: &'a R,
+    token: Option<&'a str>,
+    request: &ListRequest<'a>,
+    max_items: Option<usize>,
+) -> impl futures_util::Stream<Item = Result<ListItemsInner, ListError<R::Error>>> + 'a
+where
+    R: SlackWebRequestSender,
+{
+    crate::async_impl::pagination::page_stream(
+        request.clone(),
+        max_items,
+        move |request| async move { list(client, token, &request).await },
+    )
 }
 /// Removes a star from an item.
 ///
@@ -109,6 +158,10 @@ pub async fn remove<R>(
 where
     R: SlackWebRequestSender,
 {
+    let timestamp: Option<Cow<'_, str>> = request
+        .timestamp
+        .as_ref()
+        .map(|timestamp| timestamp.to_param_value().into());
     let params: Vec<Option<(&str, &str)>> = vec![
         request
             .channel
@@ -119,20 +172,33 @@ where
             .file_comment
             .as_ref()
             .map(|file_comment| ("file_comment", file_comment.as_ref())),
-        request
-            .timestamp
+        timestamp
             .as_ref()
             .map(|timestamp| ("timestamp", timestamp.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/stars.remove");
+    let url = crate::get_slack_url_for_method(client.base_url(), "stars.remove");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .await
         .map_err(RemoveError::Client)
         .and_then(|result| {
-            serde_json::from_str::<RemoveResponse>(&result)
-                .map_err(|e| RemoveError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<RemoveResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => RemoveError::new(&code, context),
+                    None => RemoveError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("stars.remove", &warnings);
+            }
+        })
 }
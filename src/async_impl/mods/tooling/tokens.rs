@@ -0,0 +1,61 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+#![allow(unused_imports)]
+#![allow(clippy::match_single_binding)]
+#![allow(clippy::blacklisted_name)]
+
+use crate::async_impl::SlackWebRequestSender;
+pub use crate::mod_types::tooling::tokens_types::*;
+use std::borrow::Cow;
+
+/// Exchanges a refresh token for a new app configuration token.
+///
+/// Wraps https://api.slack.com/methods/tooling.tokens.rotate
+
+pub async fn rotate<R>(
+    client: &R,
+    request: &RotateRequest<'_>,
+) -> Result<RotateResponse, RotateError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let params: Vec<Option<(&str, &str)>> =
+        vec![Some(("refresh_token", request.refresh_token.as_ref()))];
+    let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "tooling.tokens.rotate");
+    client
+        .post_response(&url, None, &params[..])
+        .await
+        .map_err(RotateError::Client)
+        .and_then(|result| {
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<RotateResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => RotateError::new(&code, context),
+                    None => RotateError::MalformedResponse(body, e),
+                })
+        })
+        .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("tooling.tokens.rotate", &warnings);
+            }
+        })
+}
@@ -57,16 +57,30 @@ where
         Some(("name", request.name.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/usergroups.create");
+    let url = crate::get_slack_url_for_method(client.base_url(), "usergroups.create");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .await
         .map_err(CreateError::Client)
         .and_then(|result| {
-            serde_json::from_str::<CreateResponse>(&result)
-                .map_err(|e| CreateError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<CreateResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => CreateError::new(&code, context),
+                    None => CreateError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("usergroups.create", &warnings);
+            }
+        })
 }
 /// Disable an existing User Group
 ///
@@ -75,7 +89,7 @@ where
 pub async fn disable<R>(
     client: &R,
     token: &str,
-    request: &DisableRequest<'_>,
+    request: &DisableRequest,
 ) -> Result<DisableResponse, DisableError<R::Error>>
 where
     R: SlackWebRequestSender,
@@ -88,19 +102,36 @@ where
         include_count
             .as_ref()
             .map(|include_count| ("include_count", include_count.as_ref())),
-        Some(("usergroup", request.usergroup.as_ref())),
+        request
+            .usergroup
+            .as_ref()
+            .map(|usergroup| ("usergroup", usergroup.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/usergroups.disable");
+    let url = crate::get_slack_url_for_method(client.base_url(), "usergroups.disable");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .await
         .map_err(DisableError::Client)
         .and_then(|result| {
-            serde_json::from_str::<DisableResponse>(&result)
-                .map_err(|e| DisableError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<DisableResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => DisableError::new(&code, context),
+                    None => DisableError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("usergroups.disable", &warnings);
+            }
+        })
 }
 /// Enable a User Group
 ///
@@ -109,7 +140,7 @@ where
 pub async fn enable<R>(
     client: &R,
     token: &str,
-    request: &EnableRequest<'_>,
+    request: &EnableRequest,
 ) -> Result<EnableResponse, EnableError<R::Error>>
 where
     R: SlackWebRequestSender,
@@ -122,19 +153,36 @@ where
         include_count
             .as_ref()
             .map(|include_count| ("include_count", include_count.as_ref())),
-        Some(("usergroup", request.usergroup.as_ref())),
+        request
+            .usergroup
+            .as_ref()
+            .map(|usergroup| ("usergroup", usergroup.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/usergroups.enable");
+    let url = crate::get_slack_url_for_method(client.base_url(), "usergroups.enable");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .await
         .map_err(EnableError::Client)
         .and_then(|result| {
-            serde_json::from_str::<EnableResponse>(&result)
-                .map_err(|e| EnableError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<EnableResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => EnableError::new(&code, context),
+                    None => EnableError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("usergroups.enable", &warnings);
+            }
+        })
 }
 /// List all User Groups for a team
 ///
@@ -161,7 +209,6 @@ where
         .as_ref()
         .map(|include_users| include_users.to_string().into());
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
         include_count
             .as_ref()
             .map(|include_count| ("include_count", include_count.as_ref())),
@@ -173,16 +220,30 @@ where
             .map(|include_users| ("include_users", include_users.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/usergroups.list");
+    let url = crate::get_slack_url_for_method(client.base_url(), "usergroups.list");
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .await
         .map_err(ListError::Client)
         .and_then(|result| {
-            serde_json::from_str::<ListResponse>(&result)
-                .map_err(|e| ListError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<ListResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => ListError::new(&code, context),
+                    None => ListError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("usergroups.list", &warnings);
+            }
+        })
 }
 /// Update an existing User Group
 ///
@@ -217,17 +278,34 @@ where
             .as_ref()
             .map(|include_count| ("include_count", include_count.as_ref())),
         request.name.as_ref().map(|name| ("name", name.as_ref())),
-        Some(("usergroup", request.usergroup.as_ref())),
+        request
+            .usergroup
+            .as_ref()
+            .map(|usergroup| ("usergroup", usergroup.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/usergroups.update");
+    let url = crate::get_slack_url_for_method(client.base_url(), "usergroups.update");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .await
         .map_err(UpdateError::Client)
         .and_then(|result| {
-            serde_json::from_str::<UpdateResponse>(&result)
-                .map_err(|e| UpdateError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<UpdateResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => UpdateError::new(&code, context),
+                    None => UpdateError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("usergroups.update", &warnings);
+            }
+        })
 }
@@ -40,7 +40,6 @@ where
         .map(|exclude_archived| exclude_archived.to_string().into());
     let limit: Option<Cow<'_, str>> = request.limit.as_ref().map(|limit| limit.to_string().into());
     let params: Vec<Option<(&str, &str)>> = vec![
-        token.map(|token| ("token", token)),
         request
             .cursor
             .as_ref()
@@ -56,16 +55,50 @@ where
         request.user.as_ref().map(|user| ("user", user.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/users.conversations");
+    let url = crate::get_slack_url_for_method(client.base_url(), "users.conversations");
     client
-        .get(&url, &params[..])
+        .get_response(&url, token, &params[..])
         .await
         .map_err(ConversationsError::Client)
         .and_then(|result| {
-            serde_json::from_str::<ConversationsResponse>(&result)
-                .map_err(|e| ConversationsError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<ConversationsResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => ConversationsError::new(&code, context),
+                    None => ConversationsError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("users.conversations", &warnings);
+            }
+        })
+}
+/// Calls [`conversations`] page by page and yields the `channels` of every page.
+///
+/// Follows `response_metadata.next_cursor` until it is empty, and stops early once
+/// `max_items` items were yielded. An error is yielded once and ends the stream.
+pub fn conversations_stream<'a, R>(
+    client: &'a R,
+    token: Option<&'a str>,
+    request: &ConversationsRequest<'a>,
+    max_items: Option<usize>,
+) -> impl futures_util::Stream<Item = Result<ConversationsChannelsInner, ConversationsError<R::Error>>>
+       + 'a
+where
+    R: SlackWebRequestSender,
+{
+    crate::async_impl::pagination::cursor_stream(
+        request.clone(),
+        max_items,
+        move |request| async move { conversations(client, token, &request).await },
+    )
 }
 /// Delete the user profile photo
 ///
@@ -81,16 +114,30 @@ where
 {
     let params: Vec<Option<(&str, &str)>> = vec![];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/users.deletePhoto");
+    let url = crate::get_slack_url_for_method(client.base_url(), "users.deletePhoto");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .await
         .map_err(DeletePhotoError::Client)
         .and_then(|result| {
-            serde_json::from_str::<DeletePhotoResponse>(&result)
-                .map_err(|e| DeletePhotoError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<DeletePhotoResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => DeletePhotoError::new(&code, context),
+                    None => DeletePhotoError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("users.deletePhoto", &warnings);
+            }
+        })
 }
 /// Gets user presence information.
 ///
@@ -99,26 +146,38 @@ where
 pub async fn get_presence<R>(
     client: &R,
     token: &str,
-    request: &GetPresenceRequest<'_>,
+    request: &GetPresenceRequest,
 ) -> Result<GetPresenceResponse, GetPresenceError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
-    let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
-        request.user.as_ref().map(|user| ("user", user.as_ref())),
-    ];
+    let params: Vec<Option<(&str, &str)>> =
+        vec![request.user.as_ref().map(|user| ("user", user.as_ref()))];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/users.getPresence");
+    let url = crate::get_slack_url_for_method(client.base_url(), "users.getPresence");
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .await
         .map_err(GetPresenceError::Client)
         .and_then(|result| {
-            serde_json::from_str::<GetPresenceResponse>(&result)
-                .map_err(|e| GetPresenceError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<GetPresenceResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => GetPresenceError::new(&code, context),
+                    None => GetPresenceError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("users.getPresence", &warnings);
+            }
+        })
 }
 /// Get a user's identity.
 ///
@@ -132,18 +191,32 @@ pub async fn identity<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params: Vec<Option<(&str, &str)>> = vec![token.map(|token| ("token", token))];
+    let params: Vec<Option<(&str, &str)>> = vec![];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/users.identity");
+    let url = crate::get_slack_url_for_method(client.base_url(), "users.identity");
     client
-        .get(&url, &params[..])
+        .get_response(&url, token, &params[..])
         .await
         .map_err(IdentityError::Client)
         .and_then(|result| {
-            serde_json::from_str::<IdentityResponse>(&result)
-                .map_err(|e| IdentityError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<IdentityResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => IdentityError::new(&code, context),
+                    None => IdentityError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("users.identity", &warnings);
+            }
+        })
 }
 /// Gets information about a user.
 ///
@@ -152,7 +225,7 @@ where
 pub async fn info<R>(
     client: &R,
     token: &str,
-    request: &InfoRequest<'_>,
+    request: &InfoRequest,
 ) -> Result<InfoResponse, InfoError<R::Error>>
 where
     R: SlackWebRequestSender,
@@ -162,23 +235,36 @@ where
         .as_ref()
         .map(|include_locale| include_locale.to_string().into());
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
         include_locale
             .as_ref()
             .map(|include_locale| ("include_locale", include_locale.as_ref())),
         request.user.as_ref().map(|user| ("user", user.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/users.info");
+    let url = crate::get_slack_url_for_method(client.base_url(), "users.info");
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .await
         .map_err(InfoError::Client)
         .and_then(|result| {
-            serde_json::from_str::<InfoResponse>(&result)
-                .map_err(|e| InfoError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<InfoResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => InfoError::new(&code, context),
+                    None => InfoError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("users.info", &warnings);
+            }
+        })
 }
 /// Lists all users in a Slack team.
 ///
@@ -198,7 +284,6 @@ where
         .map(|include_locale| include_locale.to_string().into());
     let limit: Option<Cow<'_, str>> = request.limit.as_ref().map(|limit| limit.to_string().into());
     let params: Vec<Option<(&str, &str)>> = vec![
-        token.map(|token| ("token", token)),
         request
             .cursor
             .as_ref()
@@ -209,16 +294,49 @@ where
         limit.as_ref().map(|limit| ("limit", limit.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/users.list");
+    let url = crate::get_slack_url_for_method(client.base_url(), "users.list");
     client
-        .get(&url, &params[..])
+        .get_response(&url, token, &params[..])
         .await
         .map_err(ListError::Client)
         .and_then(|result| {
-            serde_json::from_str::<ListResponse>(&result)
-                .map_err(|e| ListError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<ListResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => ListError::new(&code, context),
+                    None => ListError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("users.list", &warnings);
+            }
+        })
+}
+/// Calls [`list`] page by page and yields the `members` of every page.
+///
+/// Follows `response_metadata.next_cursor` until it is empty, and stops early once
+/// `max_items` items were yielded. An error is yielded once and ends the stream.
+pub fn list_stream<'a, R>(
+    client: &'a R,
+    token: Option<&'a str>,
+    request: &ListRequest<'a>,
+    max_items: Option<usize>,
+) -> impl futures_util::Stream<Item = Result<ListMembersInner, ListError<R::Error>>> + 'a
+where
+    R: SlackWebRequestSender,
+{
+    crate::async_impl::pagination::cursor_stream(
+        request.clone(),
+        max_items,
+        move |request| async move { list(client, token, &request).await },
+    )
 }
 /// Find a user with an email address.
 ///
@@ -232,21 +350,32 @@ pub async fn lookup_by_email<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
-        Some(("email", request.email.as_ref())),
-    ];
+    let params: Vec<Option<(&str, &str)>> = vec![Some(("email", request.email.as_ref()))];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/users.lookupByEmail");
+    let url = crate::get_slack_url_for_method(client.base_url(), "users.lookupByEmail");
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .await
         .map_err(LookupByEmailError::Client)
         .and_then(|result| {
-            serde_json::from_str::<LookupByEmailResponse>(&result)
-                .map_err(|e| LookupByEmailError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<LookupByEmailResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => LookupByEmailError::new(&code, context),
+                    None => LookupByEmailError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("users.lookupByEmail", &warnings);
+            }
+        })
 }
 /// Marked a user as active. Deprecated and non-functional.
 ///
@@ -262,16 +391,30 @@ where
 {
     let params: Vec<Option<(&str, &str)>> = vec![];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/users.setActive");
+    let url = crate::get_slack_url_for_method(client.base_url(), "users.setActive");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .await
         .map_err(SetActiveError::Client)
         .and_then(|result| {
-            serde_json::from_str::<SetActiveResponse>(&result)
-                .map_err(|e| SetActiveError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<SetActiveResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => SetActiveError::new(&code, context),
+                    None => SetActiveError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("users.setActive", &warnings);
+            }
+        })
 }
 /// Set the user profile photo
 ///
@@ -298,22 +441,41 @@ where
             .crop_y
             .as_ref()
             .map(|crop_y| ("crop_y", crop_y.as_ref())),
-        request
-            .image
-            .as_ref()
-            .map(|image| ("image", image.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/users.setPhoto");
-    client
-        .post(&url, &params[..], &[("token", token)])
-        .await
+    let files: Vec<Option<(&str, &crate::FileUpload<'_>)>> =
+        vec![request.image.as_ref().map(|image| ("image", image))];
+    let files: Vec<(&str, &crate::FileUpload<'_>)> =
+        files.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "users.setPhoto");
+    let response = if files.is_empty() {
+        client.post_response(&url, Some(token), &params[..]).await
+    } else {
+        client
+            .post_multipart(&url, Some(token), &params[..], &files[..])
+            .await
+    };
+    response
         .map_err(SetPhotoError::Client)
         .and_then(|result| {
-            serde_json::from_str::<SetPhotoResponse>(&result)
-                .map_err(|e| SetPhotoError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<SetPhotoResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => SetPhotoError::new(&code, context),
+                    None => SetPhotoError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("users.setPhoto", &warnings);
+            }
+        })
 }
 /// Manually sets user presence.
 ///
@@ -329,14 +491,28 @@ where
 {
     let params: Vec<Option<(&str, &str)>> = vec![Some(("presence", request.presence.as_ref()))];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/users.setPresence");
+    let url = crate::get_slack_url_for_method(client.base_url(), "users.setPresence");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .await
         .map_err(SetPresenceError::Client)
         .and_then(|result| {
-            serde_json::from_str::<SetPresenceResponse>(&result)
-                .map_err(|e| SetPresenceError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<SetPresenceResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => SetPresenceError::new(&code, context),
+                    None => SetPresenceError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("users.setPresence", &warnings);
+            }
+        })
 }
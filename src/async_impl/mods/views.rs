@@ -32,22 +32,39 @@ pub async fn open<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
-        Some(("trigger_id", request.trigger_id.as_ref())),
-        Some(("view", request.view.as_ref())),
+    let body: Vec<Option<(&str, serde_json::Value)>> = vec![
+        Some(("trigger_id", request.trigger_id.as_ref().into())),
+        Some(("view", crate::blocks::to_json(&request.view))),
     ];
-    let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/views.open");
+    let body: serde_json::Map<String, serde_json::Value> = body
+        .into_iter()
+        .filter_map(|x| x)
+        .map(|(name, value)| (name.to_owned(), value))
+        .collect();
+    let url = crate::get_slack_url_for_method(client.base_url(), "views.open");
     client
-        .get(&url, &params[..])
+        .post_json(&url, Some(token), &serde_json::Value::Object(body))
         .await
         .map_err(OpenError::Client)
         .and_then(|result| {
-            serde_json::from_str::<OpenResponse>(&result)
-                .map_err(|e| OpenError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<OpenResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => OpenError::new(&code, context),
+                    None => OpenError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("views.open", &warnings);
+            }
+        })
 }
 /// Publish a static view for a User.
 ///
@@ -61,23 +78,46 @@ pub async fn publish<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
-        request.hash.as_ref().map(|hash| ("hash", hash.as_ref())),
-        Some(("user_id", request.user_id.as_ref())),
-        Some(("view", request.view.as_ref())),
+    let body: Vec<Option<(&str, serde_json::Value)>> = vec![
+        request
+            .hash
+            .as_ref()
+            .map(|hash| ("hash", hash.as_ref().into())),
+        request
+            .user_id
+            .as_ref()
+            .map(|user_id| ("user_id", user_id.as_ref().into())),
+        Some(("view", crate::blocks::to_json(&request.view))),
     ];
-    let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/views.publish");
+    let body: serde_json::Map<String, serde_json::Value> = body
+        .into_iter()
+        .filter_map(|x| x)
+        .map(|(name, value)| (name.to_owned(), value))
+        .collect();
+    let url = crate::get_slack_url_for_method(client.base_url(), "views.publish");
     client
-        .get(&url, &params[..])
+        .post_json(&url, Some(token), &serde_json::Value::Object(body))
         .await
         .map_err(PublishError::Client)
         .and_then(|result| {
-            serde_json::from_str::<PublishResponse>(&result)
-                .map_err(|e| PublishError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<PublishResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => PublishError::new(&code, context),
+                    None => PublishError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("views.publish", &warnings);
+            }
+        })
 }
 /// Push a view onto the stack of a root view.
 ///
@@ -91,22 +131,39 @@ pub async fn push<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
-        Some(("trigger_id", request.trigger_id.as_ref())),
-        Some(("view", request.view.as_ref())),
+    let body: Vec<Option<(&str, serde_json::Value)>> = vec![
+        Some(("trigger_id", request.trigger_id.as_ref().into())),
+        Some(("view", crate::blocks::to_json(&request.view))),
     ];
-    let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/views.push");
+    let body: serde_json::Map<String, serde_json::Value> = body
+        .into_iter()
+        .filter_map(|x| x)
+        .map(|(name, value)| (name.to_owned(), value))
+        .collect();
+    let url = crate::get_slack_url_for_method(client.base_url(), "views.push");
     client
-        .get(&url, &params[..])
+        .post_json(&url, Some(token), &serde_json::Value::Object(body))
         .await
         .map_err(PushError::Client)
         .and_then(|result| {
-            serde_json::from_str::<PushResponse>(&result)
-                .map_err(|e| PushError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<PushResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => PushError::new(&code, context),
+                    None => PushError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("views.push", &warnings);
+            }
+        })
 }
 /// Update an existing view.
 ///
@@ -120,28 +177,48 @@ pub async fn update<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
+    let body: Vec<Option<(&str, serde_json::Value)>> = vec![
         request
             .external_id
             .as_ref()
-            .map(|external_id| ("external_id", external_id.as_ref())),
-        request.hash.as_ref().map(|hash| ("hash", hash.as_ref())),
-        request.view.as_ref().map(|view| ("view", view.as_ref())),
+            .map(|external_id| ("external_id", external_id.as_ref().into())),
+        request
+            .hash
+            .as_ref()
+            .map(|hash| ("hash", hash.as_ref().into())),
+        Some(("view", crate::blocks::to_json(&request.view))),
         request
             .view_id
             .as_ref()
-            .map(|view_id| ("view_id", view_id.as_ref())),
+            .map(|view_id| ("view_id", view_id.as_ref().into())),
     ];
-    let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/views.update");
+    let body: serde_json::Map<String, serde_json::Value> = body
+        .into_iter()
+        .filter_map(|x| x)
+        .map(|(name, value)| (name.to_owned(), value))
+        .collect();
+    let url = crate::get_slack_url_for_method(client.base_url(), "views.update");
     client
-        .get(&url, &params[..])
+        .post_json(&url, Some(token), &serde_json::Value::Object(body))
         .await
         .map_err(UpdateError::Client)
         .and_then(|result| {
-            serde_json::from_str::<UpdateResponse>(&result)
-                .map_err(|e| UpdateError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<UpdateResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => UpdateError::new(&code, context),
+                    None => UpdateError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("views.update", &warnings);
+            }
+        })
 }
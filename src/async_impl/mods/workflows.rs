@@ -33,7 +33,6 @@ where
     R: SlackWebRequestSender,
 {
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
         request
             .outputs
             .as_ref()
@@ -44,16 +43,30 @@ where
         )),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/workflows.stepCompleted");
+    let url = crate::get_slack_url_for_method(client.base_url(), "workflows.stepCompleted");
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .await
         .map_err(StepCompletedError::Client)
         .and_then(|result| {
-            serde_json::from_str::<StepCompletedResponse>(&result)
-                .map_err(|e| StepCompletedError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<StepCompletedResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => StepCompletedError::new(&code, context),
+                    None => StepCompletedError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("workflows.stepCompleted", &warnings);
+            }
+        })
 }
 /// Indicate that an app's step in a workflow failed to execute.
 ///
@@ -68,7 +81,6 @@ where
     R: SlackWebRequestSender,
 {
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
         Some(("error", request.error.as_ref())),
         Some((
             "workflow_step_execute_id",
@@ -76,16 +88,30 @@ where
         )),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/workflows.stepFailed");
+    let url = crate::get_slack_url_for_method(client.base_url(), "workflows.stepFailed");
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .await
         .map_err(StepFailedError::Client)
         .and_then(|result| {
-            serde_json::from_str::<StepFailedResponse>(&result)
-                .map_err(|e| StepFailedError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<StepFailedResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => StepFailedError::new(&code, context),
+                    None => StepFailedError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("workflows.stepFailed", &warnings);
+            }
+        })
 }
 /// Update the configuration for a workflow extension step.
 ///
@@ -100,7 +126,6 @@ where
     R: SlackWebRequestSender,
 {
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
         request
             .inputs
             .as_ref()
@@ -123,14 +148,28 @@ where
         )),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/workflows.updateStep");
+    let url = crate::get_slack_url_for_method(client.base_url(), "workflows.updateStep");
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .await
         .map_err(UpdateStepError::Client)
         .and_then(|result| {
-            serde_json::from_str::<UpdateStepResponse>(&result)
-                .map_err(|e| UpdateStepError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<UpdateStepResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => UpdateStepError::new(&code, context),
+                    None => UpdateStepError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("workflows.updateStep", &warnings);
+            }
+        })
 }
@@ -0,0 +1,75 @@
+//! The streams behind the generated `*_stream` functions.
+
+use std::future::Future;
+
+use futures_util::stream::{self, Stream};
+
+use crate::pagination::{
+    Cursor, CursorPage, CursorRequest, Numbered, NumberedPage, Page, PageRequest, PageState,
+};
+
+/// Calls a cursor-paginated method page by page and yields the items of every page.
+///
+/// An error is yielded once and ends the stream.
+pub(crate) fn cursor_stream<Req, P, E, F, Fut>(
+    request: Req,
+    max_items: Option<usize>,
+    call: F,
+) -> impl Stream<Item = Result<P::Item, E>>
+where
+    Req: CursorRequest,
+    P: CursorPage,
+    F: Fn(Req) -> Fut,
+    Fut: Future<Output = Result<P, E>>,
+{
+    paginate(request, max_items, move |request| {
+        let page = call(request);
+        async move { page.await.map(Cursor) }
+    })
+}
+
+/// Calls a method paginated by page number page by page and yields the items of every page.
+///
+/// An error is yielded once and ends the stream.
+pub(crate) fn page_stream<Req, P, E, F, Fut>(
+    request: Req,
+    max_items: Option<usize>,
+    call: F,
+) -> impl Stream<Item = Result<P::Item, E>>
+where
+    Req: PageRequest,
+    P: NumberedPage,
+    F: Fn(Req) -> Fut,
+    Fut: Future<Output = Result<P, E>>,
+{
+    paginate(request, max_items, move |request| {
+        let page = call(request);
+        async move { page.await.map(Numbered) }
+    })
+}
+
+fn paginate<Req, P, E, F, Fut>(
+    request: Req,
+    max_items: Option<usize>,
+    call: F,
+) -> impl Stream<Item = Result<P::Item, E>>
+where
+    Req: Clone,
+    P: Page<Req>,
+    F: Fn(Req) -> Fut,
+    Fut: Future<Output = Result<P, E>>,
+{
+    let state = PageState::new(request, max_items);
+    stream::unfold((state, call), |(mut state, call)| async move {
+        loop {
+            if let Some(item) = state.next_item() {
+                return Some((Ok(item), (state, call)));
+            }
+            let request = state.next_request()?;
+            match call(request.clone()).await {
+                Ok(page) => state.add_page(request, page),
+                Err(e) => return Some((Err(e), (state, call))),
+            }
+        }
+    })
+}
@@ -6,7 +6,15 @@ mod reqwest_support {
     use async_trait::async_trait;
     use reqwest_ as reqwest;
 
+    use std::io::{self, Read};
+
+    use futures_util::stream::{self, Stream};
+    use reqwest::header::CONTENT_TYPE;
+    use reqwest::multipart::{Form, Part};
+    use tokio::task;
+
     use crate::async_impl::SlackWebRequestSender;
+    use crate::{FileUpload, SharedReader, SlackWebResponse, UploadSource};
 
     type Client = reqwest::Client;
 
@@ -17,33 +25,235 @@ mod reqwest_support {
         async fn get<S>(
             &self,
             method_url: S,
+            token: Option<&str>,
             params: &[(&str, &str)],
         ) -> Result<String, Self::Error>
         where
             S: AsRef<str> + Send,
         {
-            let mut url = reqwest::Url::parse(method_url.as_ref()).expect("Unable to parse url");
-
-            url.query_pairs_mut().extend_pairs(params);
-
-            Ok(self.get(url).send().await?.text().await?)
+            self.get_response(method_url, token, params)
+                .await
+                .map(|response| response.body)
         }
 
         async fn post<S>(
             &self,
             method_url: S,
+            token: Option<&str>,
             form: &[(&str, &str)],
-            headers: &[(&str, &str)],
         ) -> Result<String, Self::Error>
         where
             S: AsRef<str> + Send,
         {
-            let url = reqwest::Url::parse(method_url.as_ref()).expect("Unable to parse url");
-            let mut req = self.post(url).form(form);
-            for (k, v) in headers {
-                req = req.header(*k, *v);
+            self.post_response(method_url, token, form)
+                .await
+                .map(|response| response.body)
+        }
+
+        async fn get_response<S>(
+            &self,
+            method_url: S,
+            token: Option<&str>,
+            params: &[(&str, &str)],
+        ) -> Result<SlackWebResponse, Self::Error>
+        where
+            S: AsRef<str> + Send,
+        {
+            let mut request = self.get(method_url.as_ref()).query(params);
+
+            if let Some(token) = token {
+                request = request.bearer_auth(token);
+            }
+
+            into_slack_web_response(request.send().await?).await
+        }
+
+        async fn post_response<S>(
+            &self,
+            method_url: S,
+            token: Option<&str>,
+            form: &[(&str, &str)],
+        ) -> Result<SlackWebResponse, Self::Error>
+        where
+            S: AsRef<str> + Send,
+        {
+            let mut request = self.post(method_url.as_ref()).form(form);
+
+            if let Some(token) = token {
+                request = request.bearer_auth(token);
+            }
+
+            into_slack_web_response(request.send().await?).await
+        }
+
+        async fn post_multipart<S>(
+            &self,
+            method_url: S,
+            token: Option<&str>,
+            form: &[(&str, &str)],
+            files: &[(&str, &FileUpload<'_>)],
+        ) -> Result<SlackWebResponse, Self::Error>
+        where
+            S: AsRef<str> + Send,
+        {
+            let mut multipart = Form::new();
+            for (name, value) in form {
+                multipart = multipart.text(name.to_string(), value.to_string());
+            }
+            for (name, upload) in files {
+                multipart = multipart.part(name.to_string(), into_part(upload)?);
+            }
+
+            let mut request = self.post(method_url.as_ref()).multipart(multipart);
+
+            if let Some(token) = token {
+                request = request.bearer_auth(token);
+            }
+
+            into_slack_web_response(request.send().await?).await
+        }
+
+        async fn post_json<S>(
+            &self,
+            method_url: S,
+            token: Option<&str>,
+            body: &serde_json::Value,
+        ) -> Result<SlackWebResponse, Self::Error>
+        where
+            S: AsRef<str> + Send,
+        {
+            let mut request = self
+                .post(method_url.as_ref())
+                .header(CONTENT_TYPE, "application/json; charset=utf-8")
+                .body(body.to_string());
+
+            if let Some(token) = token {
+                request = request.bearer_auth(token);
             }
-            Ok(req.send().await?.text().await?)
+
+            into_slack_web_response(request.send().await?).await
+        }
+
+        async fn post_client_auth<S>(
+            &self,
+            method_url: S,
+            client_id: &str,
+            client_secret: &str,
+            form: &[(&str, &str)],
+        ) -> Result<SlackWebResponse, Self::Error>
+        where
+            S: AsRef<str> + Send,
+        {
+            let request = self
+                .post(method_url.as_ref())
+                .basic_auth(client_id, Some(client_secret))
+                .form(form);
+
+            into_slack_web_response(request.send().await?).await
+        }
+    }
+
+    /// The size of the chunks files and readers are streamed in.
+    const CHUNK_SIZE: usize = 64 * 1024;
+
+    /// Streams files and readers without blocking the runtime: they are opened and read chunk by
+    /// chunk on its blocking threads, and a file that cannot be read fails the request when its
+    /// body is sent.
+    fn into_part(upload: &FileUpload<'_>) -> Result<Part, reqwest::Error> {
+        let body = match upload.source() {
+            UploadSource::Bytes(bytes) => reqwest::Body::from(bytes.to_vec()),
+            _ => reqwest::Body::wrap_stream(read_chunks(upload.to_static())),
+        };
+        let mut part = Part::stream(body);
+        if let Some(filename) = upload.filename() {
+            part = part.file_name(filename.to_owned());
+        }
+        if let Some(mime_type) = upload.mime_type() {
+            part = part.mime_str(mime_type)?;
+        }
+        Ok(part)
+    }
+
+    fn read_chunks(upload: FileUpload<'static>) -> impl Stream<Item = io::Result<Vec<u8>>> {
+        stream::try_unfold(
+            (upload, None),
+            |(upload, reader): (FileUpload<'static>, Option<SharedReader>)| async move {
+                let (upload, reader, chunk) = task::spawn_blocking(move || {
+                    let mut reader = match reader {
+                        Some(reader) => reader,
+                        None => SharedReader::new(upload.open()?.0),
+                    };
+                    let mut chunk = vec![0; CHUNK_SIZE];
+                    let len = loop {
+                        match reader.read(&mut chunk) {
+                            Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {}
+                            result => break result?,
+                        }
+                    };
+                    chunk.truncate(len);
+                    Ok::<_, io::Error>((upload, reader, chunk))
+                })
+                .await
+                .map_err(io::Error::other)??;
+                if chunk.is_empty() {
+                    Ok(None)
+                } else {
+                    Ok(Some((chunk, (upload, Some(reader)))))
+                }
+            },
+        )
+    }
+
+    async fn into_slack_web_response(
+        response: reqwest::Response,
+    ) -> Result<SlackWebResponse, reqwest::Error> {
+        let status = response.status().as_u16();
+        let headers = response
+            .headers()
+            .iter()
+            .filter_map(|(k, v)| Some((k.as_str().to_owned(), v.to_str().ok()?.to_owned())))
+            .collect();
+        let body = response.text().await?;
+        Ok(SlackWebResponse {
+            status,
+            headers,
+            body,
+        })
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use futures_util::TryStreamExt;
+
+        #[tokio::test]
+        async fn streams_readers_in_chunks() {
+            let contents: Vec<u8> = (0..CHUNK_SIZE * 2 + 3).map(|i| i as u8).collect();
+            let upload = FileUpload::reader(io::Cursor::new(contents.clone()));
+            let chunks: Vec<Vec<u8>> = read_chunks(upload.to_static()).try_collect().await.unwrap();
+            assert_eq!(chunks.len(), 3);
+            assert_eq!(chunks.concat(), contents);
+        }
+
+        #[tokio::test]
+        async fn missing_file_fails_the_stream() {
+            let upload = FileUpload::path(std::path::Path::new("/nonexistent/slack-api-upload"));
+            let error = read_chunks(upload.to_static())
+                .try_collect::<Vec<_>>()
+                .await
+                .unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::NotFound);
+        }
+
+        #[tokio::test]
+        async fn invalid_base_url_fails_the_request() {
+            use crate::async_impl::{auth, WithBaseUrl};
+
+            let client = WithBaseUrl::new(Client::new(), "not a url");
+            let error = auth::test(&client, "xoxb", &auth::TestRequest {})
+                .await
+                .unwrap_err();
+            assert!(matches!(error, auth::TestError::Client(ref e) if e.is_builder()));
         }
     }
 
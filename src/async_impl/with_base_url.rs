@@ -0,0 +1,156 @@
+//! A request sender that talks to a different Slack Web API base URL.
+
+use async_trait::async_trait;
+
+use crate::async_impl::SlackWebRequestSender;
+use crate::{FileUpload, SlackWebResponse};
+
+/// Wraps a [`SlackWebRequestSender`] and sends every request to `base_url` instead of
+/// `https://slack.com/api`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "reqwest")]
+/// # fn run() {
+/// # let token = "some_token";
+/// let client = slack_api::WithBaseUrl::new(
+///     slack_api::default_client().unwrap(),
+///     slack_api::GOV_SLACK_API_URL,
+/// );
+/// let params = Default::default();
+/// let response = slack_api::conversations::list(&client, &token, &params);
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct WithBaseUrl<S> {
+    inner: S,
+    base_url: String,
+}
+
+impl<S> WithBaseUrl<S> {
+    /// Wraps `inner` so that e.g. `chat.postMessage` is sent to `{base_url}/chat.postMessage`.
+    ///
+    /// The `reqwest` clients fail every request with a builder error if the result is not a valid
+    /// URL.
+    pub fn new<U: Into<String>>(inner: S, base_url: U) -> Self {
+        WithBaseUrl {
+            inner,
+            base_url: base_url.into(),
+        }
+    }
+
+    pub fn get_ref(&self) -> &S {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+#[async_trait]
+impl<R> SlackWebRequestSender for WithBaseUrl<R>
+where
+    R: SlackWebRequestSender + Send,
+{
+    type Error = R::Error;
+
+    fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn on_warnings(&self, method: &str, warnings: &[&str]) {
+        self.inner.on_warnings(method, warnings)
+    }
+
+    async fn get<S>(
+        &self,
+        method_url: S,
+        token: Option<&str>,
+        params: &[(&str, &str)],
+    ) -> Result<String, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        self.inner.get(method_url, token, params).await
+    }
+
+    async fn post<S>(
+        &self,
+        method_url: S,
+        token: Option<&str>,
+        form: &[(&str, &str)],
+    ) -> Result<String, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        self.inner.post(method_url, token, form).await
+    }
+
+    async fn get_response<S>(
+        &self,
+        method_url: S,
+        token: Option<&str>,
+        params: &[(&str, &str)],
+    ) -> Result<SlackWebResponse, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        self.inner.get_response(method_url, token, params).await
+    }
+
+    async fn post_response<S>(
+        &self,
+        method_url: S,
+        token: Option<&str>,
+        form: &[(&str, &str)],
+    ) -> Result<SlackWebResponse, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        self.inner.post_response(method_url, token, form).await
+    }
+
+    async fn post_multipart<S>(
+        &self,
+        method_url: S,
+        token: Option<&str>,
+        form: &[(&str, &str)],
+        files: &[(&str, &FileUpload<'_>)],
+    ) -> Result<SlackWebResponse, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        self.inner
+            .post_multipart(method_url, token, form, files)
+            .await
+    }
+
+    async fn post_json<S>(
+        &self,
+        method_url: S,
+        token: Option<&str>,
+        body: &serde_json::Value,
+    ) -> Result<SlackWebResponse, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        self.inner.post_json(method_url, token, body).await
+    }
+
+    async fn post_client_auth<S>(
+        &self,
+        method_url: S,
+        client_id: &str,
+        client_secret: &str,
+        form: &[(&str, &str)],
+    ) -> Result<SlackWebResponse, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        self.inner
+            .post_client_auth(method_url, client_id, client_secret, form)
+            .await
+    }
+}
@@ -0,0 +1,354 @@
+//! A request sender that keeps rotating tokens fresh.
+
+use std::future::Future;
+
+use async_trait::async_trait;
+use futures_util::lock::Mutex;
+
+use crate::async_impl::oauth::v_2::access;
+use crate::async_impl::SlackWebRequestSender;
+use crate::token_rotation::{is_token_expired, RotatingToken, RotationError};
+use crate::{FileUpload, SlackWebResponse};
+
+/// Wraps a [`SlackWebRequestSender`] and sends the current access token of a [`RotatingToken`]
+/// in place of any token it issued.
+///
+/// The token is refreshed with `oauth.v2.access` before it expires, and once more if Slack
+/// answers a call with `token_expired`, which is then retried. Uploads of readers cannot be sent
+/// twice, so they return the `token_expired` response instead. Calls with other tokens are sent
+/// as they are.
+///
+/// # Examples
+///
+/// ```no_run
+/// # #[cfg(feature = "reqwest")]
+/// # async fn f(token: slack_api::token_rotation::RotatingToken) {
+/// use slack_api::{auth, default_client, WithTokenRotation};
+///
+/// let initial = token.access_token();
+/// let client = WithTokenRotation::new(default_client().unwrap(), token);
+/// // Still works after the initial token expired.
+/// auth::test(&client, &initial, &auth::TestRequest {}).await.unwrap();
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct WithTokenRotation<S> {
+    inner: S,
+    token: RotatingToken,
+    refreshing: Mutex<()>,
+}
+
+impl<S> WithTokenRotation<S> {
+    pub fn new(inner: S, token: RotatingToken) -> Self {
+        WithTokenRotation {
+            inner,
+            token,
+            refreshing: Mutex::new(()),
+        }
+    }
+
+    pub fn token(&self) -> &RotatingToken {
+        &self.token
+    }
+
+    pub fn get_ref(&self) -> &S {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<R> WithTokenRotation<R>
+where
+    R: SlackWebRequestSender + Send,
+    R::Error: Send + 'static,
+{
+    /// Refreshes the token if `expired` is still current, or without it, if it expires soon;
+    /// either may have changed while another call was refreshing.
+    async fn refresh(&self, expired: Option<&str>) -> Result<(), RotationError<R::Error>> {
+        let _refreshing = self.refreshing.lock().await;
+        let stale = match expired {
+            Some(expired) => self.token.access_token() == expired,
+            None => self.token.needs_refresh(),
+        };
+        if stale {
+            let response = access(&self.inner, &self.token.refresh_request())
+                .await
+                .map_err(RotationError::Refresh)?;
+            self.token
+                .update(&response)
+                .ok_or(RotationError::NotRotated)?;
+        }
+        Ok(())
+    }
+
+    /// Sends `call` with the current token, and again after a refresh if it expired and the call
+    /// is `replayable`.
+    async fn send<F, Fut>(
+        &self,
+        token: Option<&str>,
+        replayable: bool,
+        call: F,
+    ) -> Result<SlackWebResponse, RotationError<R::Error>>
+    where
+        F: Fn(Option<String>) -> Fut + Send,
+        Fut: Future<Output = Result<SlackWebResponse, R::Error>> + Send,
+    {
+        match token {
+            Some(token) if self.token.issued(token) => {}
+            token => {
+                return call(token.map(str::to_owned))
+                    .await
+                    .map_err(RotationError::Client)
+            }
+        }
+        if self.token.needs_refresh() {
+            self.refresh(None).await?;
+        }
+        let current = self.token.access_token();
+        let response = call(Some(current.clone()))
+            .await
+            .map_err(RotationError::Client)?;
+        if !is_token_expired(&response) {
+            return Ok(response);
+        }
+        self.refresh(Some(&current)).await?;
+        if !replayable {
+            return Ok(response);
+        }
+        call(Some(self.token.access_token()))
+            .await
+            .map_err(RotationError::Client)
+    }
+}
+
+#[async_trait]
+impl<R> SlackWebRequestSender for WithTokenRotation<R>
+where
+    R: SlackWebRequestSender + Send,
+    R::Error: Send + 'static,
+{
+    type Error = RotationError<R::Error>;
+
+    fn base_url(&self) -> &str {
+        self.inner.base_url()
+    }
+
+    fn on_warnings(&self, method: &str, warnings: &[&str]) {
+        self.inner.on_warnings(method, warnings)
+    }
+
+    async fn get<S>(
+        &self,
+        method_url: S,
+        token: Option<&str>,
+        params: &[(&str, &str)],
+    ) -> Result<String, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        self.get_response(method_url, token, params)
+            .await
+            .map(|response| response.body)
+    }
+
+    async fn post<S>(
+        &self,
+        method_url: S,
+        token: Option<&str>,
+        form: &[(&str, &str)],
+    ) -> Result<String, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        self.post_response(method_url, token, form)
+            .await
+            .map(|response| response.body)
+    }
+
+    async fn get_response<S>(
+        &self,
+        method_url: S,
+        token: Option<&str>,
+        params: &[(&str, &str)],
+    ) -> Result<SlackWebResponse, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        let method_url = method_url.as_ref();
+        let inner = &self.inner;
+        self.send(token, true, |token| async move {
+            inner
+                .get_response(method_url, token.as_deref(), params)
+                .await
+        })
+        .await
+    }
+
+    async fn post_response<S>(
+        &self,
+        method_url: S,
+        token: Option<&str>,
+        form: &[(&str, &str)],
+    ) -> Result<SlackWebResponse, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        let method_url = method_url.as_ref();
+        let inner = &self.inner;
+        self.send(token, true, |token| async move {
+            inner
+                .post_response(method_url, token.as_deref(), form)
+                .await
+        })
+        .await
+    }
+
+    async fn post_multipart<S>(
+        &self,
+        method_url: S,
+        token: Option<&str>,
+        form: &[(&str, &str)],
+        files: &[(&str, &FileUpload<'_>)],
+    ) -> Result<SlackWebResponse, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        let method_url = method_url.as_ref();
+        let inner = &self.inner;
+        let replayable = files.iter().all(|(_, file)| file.is_replayable());
+        self.send(token, replayable, |token| async move {
+            inner
+                .post_multipart(method_url, token.as_deref(), form, files)
+                .await
+        })
+        .await
+    }
+
+    async fn post_json<S>(
+        &self,
+        method_url: S,
+        token: Option<&str>,
+        body: &serde_json::Value,
+    ) -> Result<SlackWebResponse, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        let method_url = method_url.as_ref();
+        let inner = &self.inner;
+        self.send(token, true, |token| async move {
+            inner.post_json(method_url, token.as_deref(), body).await
+        })
+        .await
+    }
+
+    async fn post_client_auth<S>(
+        &self,
+        method_url: S,
+        client_id: &str,
+        client_secret: &str,
+        form: &[(&str, &str)],
+    ) -> Result<SlackWebResponse, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        self.inner
+            .post_client_auth(method_url, client_id, client_secret, form)
+            .await
+            .map_err(RotationError::Client)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{Scripted, Via};
+    use crate::token_rotation::TokenSet;
+    use std::time::{Duration, SystemTime};
+
+    fn client(expires_in: Duration) -> WithTokenRotation<Scripted> {
+        let token = RotatingToken::new(
+            "123.456",
+            "secret",
+            TokenSet {
+                access_token: "xoxe.xoxb-1".into(),
+                refresh_token: "xoxe-1".into(),
+                expires_at: SystemTime::now() + expires_in,
+            },
+        );
+        WithTokenRotation::new(Scripted::rotating(), token)
+    }
+
+    /// The tokens of every call but refreshes.
+    fn sent(client: &WithTokenRotation<Scripted>) -> Vec<String> {
+        client
+            .get_ref()
+            .calls()
+            .into_iter()
+            .filter(|call| call.via != Via::ClientAuth)
+            .map(|call| call.token.unwrap_or_default())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn refreshes_before_expiry() {
+        let client = client(Duration::from_secs(60));
+        let response = client
+            .get_response("auth.test", Some("xoxe.xoxb-1"), &[])
+            .await;
+        assert_eq!(response.unwrap().body, r#"{"ok": true}"#);
+        assert_eq!(sent(&client), vec!["xoxe.xoxb-2"]);
+    }
+
+    #[tokio::test]
+    async fn refreshes_on_token_expired() {
+        let client = client(Duration::from_secs(3600));
+        let response = client
+            .post_response("chat.postMessage", Some("xoxe.xoxb-1"), &[])
+            .await;
+        assert_eq!(response.unwrap().body, r#"{"ok": true}"#);
+        assert_eq!(sent(&client), vec!["xoxe.xoxb-1", "xoxe.xoxb-2"]);
+        assert_eq!(client.token().tokens().refresh_token, "xoxe-2");
+    }
+
+    #[tokio::test]
+    async fn sends_other_tokens_as_is() {
+        let client = client(Duration::from_secs(60));
+        let response = client
+            .get_response("auth.test", Some("xoxb-other"), &[])
+            .await;
+        assert!(response.unwrap().body.contains("token_expired"));
+        assert_eq!(sent(&client), vec!["xoxb-other"]);
+    }
+
+    #[tokio::test]
+    async fn returns_token_expired_for_reader_uploads() {
+        let client = client(Duration::from_secs(3600));
+        let reader = FileUpload::reader(&b"abc"[..]);
+        let response = client
+            .post_multipart(
+                "files.upload",
+                Some("xoxe.xoxb-1"),
+                &[],
+                &[("file", &reader)],
+            )
+            .await;
+        assert!(response.unwrap().body.contains("token_expired"));
+        assert_eq!(sent(&client), vec!["xoxe.xoxb-1"]);
+        assert_eq!(client.token().tokens().refresh_token, "xoxe-2");
+
+        let bytes = FileUpload::bytes(&b"abc"[..]);
+        let response = client
+            .post_multipart(
+                "files.upload",
+                Some("xoxe.xoxb-1"),
+                &[],
+                &[("file", &bytes)],
+            )
+            .await;
+        assert_eq!(response.unwrap().body, r#"{"ok": true}"#);
+        assert_eq!(sent(&client), vec!["xoxe.xoxb-1", "xoxe.xoxb-2"]);
+    }
+}
@@ -0,0 +1,161 @@
+//! A request sender that reports the warnings of successful calls.
+
+use std::fmt;
+
+use async_trait::async_trait;
+
+use crate::async_impl::SlackWebRequestSender;
+use crate::{FileUpload, SlackWebResponse};
+
+/// Wraps a [`SlackWebRequestSender`] and calls `hook` with the method's name and the warnings of
+/// every successful call that has any, such as a `chat.postMessage` whose text was truncated.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "reqwest")]
+/// # fn run() {
+/// let client = slack_api::WithWarningHook::new(
+///     slack_api::default_client().unwrap(),
+///     |method: &str, warnings: &[&str]| eprintln!("{} warned: {}", method, warnings.join(", ")),
+/// );
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct WithWarningHook<S, F> {
+    inner: S,
+    hook: F,
+}
+
+impl<S, F> WithWarningHook<S, F>
+where
+    F: Fn(&str, &[&str]),
+{
+    pub fn new(inner: S, hook: F) -> Self {
+        WithWarningHook { inner, hook }
+    }
+
+    pub fn get_ref(&self) -> &S {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S: fmt::Debug, F> fmt::Debug for WithWarningHook<S, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WithWarningHook")
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl<R, F> SlackWebRequestSender for WithWarningHook<R, F>
+where
+    R: SlackWebRequestSender + Send,
+    F: Fn(&str, &[&str]) + Send + Sync,
+{
+    type Error = R::Error;
+
+    fn base_url(&self) -> &str {
+        self.inner.base_url()
+    }
+
+    fn on_warnings(&self, method: &str, warnings: &[&str]) {
+        (self.hook)(method, warnings);
+        self.inner.on_warnings(method, warnings)
+    }
+
+    async fn get<S>(
+        &self,
+        method_url: S,
+        token: Option<&str>,
+        params: &[(&str, &str)],
+    ) -> Result<String, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        self.inner.get(method_url, token, params).await
+    }
+
+    async fn post<S>(
+        &self,
+        method_url: S,
+        token: Option<&str>,
+        form: &[(&str, &str)],
+    ) -> Result<String, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        self.inner.post(method_url, token, form).await
+    }
+
+    async fn get_response<S>(
+        &self,
+        method_url: S,
+        token: Option<&str>,
+        params: &[(&str, &str)],
+    ) -> Result<SlackWebResponse, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        self.inner.get_response(method_url, token, params).await
+    }
+
+    async fn post_response<S>(
+        &self,
+        method_url: S,
+        token: Option<&str>,
+        form: &[(&str, &str)],
+    ) -> Result<SlackWebResponse, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        self.inner.post_response(method_url, token, form).await
+    }
+
+    async fn post_multipart<S>(
+        &self,
+        method_url: S,
+        token: Option<&str>,
+        form: &[(&str, &str)],
+        files: &[(&str, &FileUpload<'_>)],
+    ) -> Result<SlackWebResponse, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        self.inner
+            .post_multipart(method_url, token, form, files)
+            .await
+    }
+
+    async fn post_json<S>(
+        &self,
+        method_url: S,
+        token: Option<&str>,
+        body: &serde_json::Value,
+    ) -> Result<SlackWebResponse, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        self.inner.post_json(method_url, token, body).await
+    }
+
+    async fn post_client_auth<S>(
+        &self,
+        method_url: S,
+        client_id: &str,
+        client_secret: &str,
+        form: &[(&str, &str)],
+    ) -> Result<SlackWebResponse, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        self.inner
+            .post_client_auth(method_url, client_id, client_secret, form)
+            .await
+    }
+}
@@ -0,0 +1,238 @@
+//! Composition objects, the parts that blocks and elements are made of.
+
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+
+/// A text object, formatted with `mrkdwn` or shown as plain text.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Text {
+    PlainText {
+        text: String,
+        /// Whether emoji colon codes are shown as emoji.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        emoji: Option<bool>,
+    },
+    Mrkdwn {
+        text: String,
+        /// Whether URLs, conversation names and mentions are left as typed instead of being
+        /// linked.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        verbatim: Option<bool>,
+    },
+}
+
+impl Text {
+    pub fn plain<S: Into<String>>(text: S) -> Self {
+        Text::PlainText {
+            text: text.into(),
+            emoji: None,
+        }
+    }
+
+    pub fn mrkdwn<S: Into<String>>(text: S) -> Self {
+        Text::Mrkdwn {
+            text: text.into(),
+            verbatim: None,
+        }
+    }
+
+    /// The text, whichever way it is formatted.
+    pub fn text(&self) -> &str {
+        match self {
+            Text::PlainText { text, .. } | Text::Mrkdwn { text, .. } => text,
+        }
+    }
+}
+
+impl From<PlainText> for Text {
+    fn from(text: PlainText) -> Self {
+        Text::PlainText {
+            text: text.text,
+            emoji: text.emoji,
+        }
+    }
+}
+
+/// A `plain_text` text object, for the fields that do not accept `mrkdwn`, such as titles and
+/// labels.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(try_from = "Text", into = "Text")]
+pub struct PlainText {
+    pub text: String,
+    /// Whether emoji colon codes are shown as emoji.
+    pub emoji: Option<bool>,
+}
+
+impl PlainText {
+    pub fn new<S: Into<String>>(text: S) -> Self {
+        PlainText {
+            text: text.into(),
+            emoji: None,
+        }
+    }
+
+    pub fn with_emoji(mut self, emoji: bool) -> Self {
+        self.emoji = Some(emoji);
+        self
+    }
+}
+
+impl From<&str> for PlainText {
+    fn from(text: &str) -> Self {
+        PlainText::new(text)
+    }
+}
+
+impl From<String> for PlainText {
+    fn from(text: String) -> Self {
+        PlainText::new(text)
+    }
+}
+
+impl TryFrom<Text> for PlainText {
+    type Error = NotPlainTextError;
+
+    fn try_from(text: Text) -> Result<Self, Self::Error> {
+        match text {
+            Text::PlainText { text, emoji } => Ok(PlainText { text, emoji }),
+            Text::Mrkdwn { .. } => Err(NotPlainTextError(())),
+        }
+    }
+}
+
+/// The error of converting a `mrkdwn` [`Text`] into a [`PlainText`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NotPlainTextError(());
+
+impl fmt::Display for NotPlainTextError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("expected a plain_text text object")
+    }
+}
+
+impl Error for NotPlainTextError {}
+
+/// The color of a button, or of the confirm button of a [`ConfirmationDialog`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ButtonStyle {
+    Primary,
+    Danger,
+}
+
+/// A dialog asking to confirm an element's action before it is sent.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ConfirmationDialog {
+    pub title: PlainText,
+    pub text: Text,
+    /// The text of the button that confirms the action.
+    pub confirm: PlainText,
+    /// The text of the button that cancels the action.
+    pub deny: PlainText,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<ButtonStyle>,
+}
+
+impl ConfirmationDialog {
+    pub fn new<T, C, D>(title: T, text: Text, confirm: C, deny: D) -> Self
+    where
+        T: Into<PlainText>,
+        C: Into<PlainText>,
+        D: Into<PlainText>,
+    {
+        ConfirmationDialog {
+            title: title.into(),
+            text,
+            confirm: confirm.into(),
+            deny: deny.into(),
+            style: None,
+        }
+    }
+
+    pub fn with_style(mut self, style: ButtonStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+}
+
+/// An option of a select menu, overflow menu, checkbox group or radio button group.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct SelectOption {
+    /// The text shown for the option; only checkboxes and radio buttons accept `mrkdwn`.
+    pub text: Text,
+    /// The value sent in the interaction payload when the option is chosen.
+    pub value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<Text>,
+    /// A URL to open when the option is clicked; only for overflow menus.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+impl SelectOption {
+    pub fn new<T: Into<Text>, S: Into<String>>(text: T, value: S) -> Self {
+        SelectOption {
+            text: text.into(),
+            value: value.into(),
+            description: None,
+            url: None,
+        }
+    }
+
+    pub fn with_description<T: Into<Text>>(mut self, description: T) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+/// A labelled group of options of a select menu.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct OptionGroup {
+    pub label: PlainText,
+    pub options: Vec<SelectOption>,
+}
+
+impl OptionGroup {
+    pub fn new<L: Into<PlainText>>(label: L, options: Vec<SelectOption>) -> Self {
+        OptionGroup {
+            label: label.into(),
+            options,
+        }
+    }
+}
+
+/// When an input element sends a `block_actions` payload, for input blocks with
+/// `dispatch_action` set.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct DispatchActionConfig {
+    pub trigger_actions_on: Vec<TriggerAction>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TriggerAction {
+    OnEnterPressed,
+    OnCharacterEntered,
+}
+
+/// Which conversations a conversations select menu lists.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct ConversationFilter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include: Option<Vec<ConversationKind>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclude_external_shared_channels: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclude_bot_users: Option<bool>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConversationKind {
+    Im,
+    Mpim,
+    Private,
+    Public,
+}
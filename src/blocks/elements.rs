@@ -0,0 +1,712 @@
+//! Block elements: the interactive elements of actions, section and input blocks, and images.
+
+use super::{
+    ButtonStyle, ConfirmationDialog, ConversationFilter, DispatchActionConfig, OptionGroup,
+    PlainText, SelectOption,
+};
+
+/// An element of an actions block, the accessory of a section block or the element of an input
+/// block.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type")]
+pub enum BlockElement {
+    #[serde(rename = "button")]
+    Button(Button),
+    #[serde(rename = "checkboxes")]
+    Checkboxes(Checkboxes),
+    #[serde(rename = "datepicker")]
+    DatePicker(DatePicker),
+    #[serde(rename = "datetimepicker")]
+    DatetimePicker(DatetimePicker),
+    #[serde(rename = "email_text_input")]
+    EmailInput(EmailInput),
+    #[serde(rename = "image")]
+    Image(ImageElement),
+    #[serde(rename = "number_input")]
+    NumberInput(NumberInput),
+    #[serde(rename = "overflow")]
+    Overflow(Overflow),
+    #[serde(rename = "plain_text_input")]
+    PlainTextInput(PlainTextInput),
+    #[serde(rename = "radio_buttons")]
+    RadioButtons(RadioButtons),
+    #[serde(rename = "static_select")]
+    StaticSelect(StaticSelect),
+    #[serde(rename = "multi_static_select")]
+    MultiStaticSelect(MultiStaticSelect),
+    #[serde(rename = "external_select")]
+    ExternalSelect(ExternalSelect),
+    #[serde(rename = "multi_external_select")]
+    MultiExternalSelect(MultiExternalSelect),
+    #[serde(rename = "users_select")]
+    UsersSelect(UsersSelect),
+    #[serde(rename = "multi_users_select")]
+    MultiUsersSelect(MultiUsersSelect),
+    #[serde(rename = "conversations_select")]
+    ConversationsSelect(ConversationsSelect),
+    #[serde(rename = "multi_conversations_select")]
+    MultiConversationsSelect(MultiConversationsSelect),
+    #[serde(rename = "channels_select")]
+    ChannelsSelect(ChannelsSelect),
+    #[serde(rename = "multi_channels_select")]
+    MultiChannelsSelect(MultiChannelsSelect),
+    #[serde(rename = "timepicker")]
+    TimePicker(TimePicker),
+    #[serde(rename = "url_text_input")]
+    UrlInput(UrlInput),
+    /// An element of a type this crate does not know, or could not read, kept as JSON.
+    #[serde(untagged)]
+    Other(serde_json::Value),
+}
+
+macro_rules! elements {
+    ($($variant:ident($element:ident)),* $(,)?) => {
+        $(
+            impl From<$element> for BlockElement {
+                fn from(element: $element) -> Self {
+                    BlockElement::$variant(element)
+                }
+            }
+        )*
+    };
+}
+
+elements! {
+    Button(Button),
+    Checkboxes(Checkboxes),
+    DatePicker(DatePicker),
+    DatetimePicker(DatetimePicker),
+    EmailInput(EmailInput),
+    Image(ImageElement),
+    NumberInput(NumberInput),
+    Overflow(Overflow),
+    PlainTextInput(PlainTextInput),
+    RadioButtons(RadioButtons),
+    StaticSelect(StaticSelect),
+    MultiStaticSelect(MultiStaticSelect),
+    ExternalSelect(ExternalSelect),
+    MultiExternalSelect(MultiExternalSelect),
+    UsersSelect(UsersSelect),
+    MultiUsersSelect(MultiUsersSelect),
+    ConversationsSelect(ConversationsSelect),
+    MultiConversationsSelect(MultiConversationsSelect),
+    ChannelsSelect(ChannelsSelect),
+    MultiChannelsSelect(MultiChannelsSelect),
+    TimePicker(TimePicker),
+    UrlInput(UrlInput),
+}
+
+/// Adds `with_action_id`, and `with_placeholder` to the elements that show one.
+macro_rules! builders {
+    (action_id: $($element:ident),*; placeholder: $($input:ident),* $(,)?) => {
+        $(
+            impl $element {
+                /// Sets the `action_id` that identifies the element in interaction payloads.
+                pub fn with_action_id<S: Into<String>>(mut self, action_id: S) -> Self {
+                    self.action_id = Some(action_id.into());
+                    self
+                }
+            }
+        )*
+        $(
+            impl $input {
+                pub fn with_placeholder<P: Into<PlainText>>(mut self, placeholder: P) -> Self {
+                    self.placeholder = Some(placeholder.into());
+                    self
+                }
+            }
+        )*
+    };
+}
+
+builders! {
+    action_id: Button, Checkboxes, DatePicker, DatetimePicker, EmailInput, NumberInput, Overflow,
+        PlainTextInput, RadioButtons, StaticSelect, MultiStaticSelect, ExternalSelect,
+        MultiExternalSelect, UsersSelect, MultiUsersSelect, ConversationsSelect,
+        MultiConversationsSelect, ChannelsSelect, MultiChannelsSelect, TimePicker, UrlInput;
+    placeholder: DatePicker, EmailInput, NumberInput, PlainTextInput, StaticSelect,
+        MultiStaticSelect, ExternalSelect, MultiExternalSelect, UsersSelect, MultiUsersSelect,
+        ConversationsSelect, MultiConversationsSelect, ChannelsSelect, MultiChannelsSelect,
+        TimePicker, UrlInput,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Button {
+    pub text: PlainText,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    /// A URL to open in the user's browser when the button is clicked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// The value sent in the interaction payload when the button is clicked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<ButtonStyle>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accessibility_label: Option<String>,
+}
+
+impl Button {
+    pub fn new<T: Into<PlainText>>(text: T) -> Self {
+        Button {
+            text: text.into(),
+            action_id: None,
+            url: None,
+            value: None,
+            style: None,
+            confirm: None,
+            accessibility_label: None,
+        }
+    }
+
+    pub fn with_url<S: Into<String>>(mut self, url: S) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    pub fn with_value<S: Into<String>>(mut self, value: S) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    pub fn with_style(mut self, style: ButtonStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    pub fn with_confirm(mut self, confirm: ConfirmationDialog) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Checkboxes {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    pub options: Vec<SelectOption>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_options: Option<Vec<SelectOption>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub focus_on_load: Option<bool>,
+}
+
+impl Checkboxes {
+    pub fn new(options: Vec<SelectOption>) -> Self {
+        Checkboxes {
+            action_id: None,
+            options,
+            initial_options: None,
+            confirm: None,
+            focus_on_load: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct DatePicker {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    /// The date selected at first, as `YYYY-MM-DD`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub focus_on_load: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<PlainText>,
+}
+
+impl DatePicker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct DatetimePicker {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    /// The date and time selected at first, in seconds since the UNIX epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_date_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub focus_on_load: Option<bool>,
+}
+
+impl DatetimePicker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct EmailInput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dispatch_action_config: Option<DispatchActionConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub focus_on_load: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<PlainText>,
+}
+
+impl EmailInput {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// An image shown within a section or context block; see [`ImageBlock`](super::ImageBlock)
+/// for a block of its own.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ImageElement {
+    pub image_url: String,
+    /// A description of the image for screen readers.
+    pub alt_text: String,
+}
+
+impl ImageElement {
+    pub fn new<U: Into<String>, A: Into<String>>(image_url: U, alt_text: A) -> Self {
+        ImageElement {
+            image_url: image_url.into(),
+            alt_text: alt_text.into(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct NumberInput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    pub is_decimal_allowed: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dispatch_action_config: Option<DispatchActionConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub focus_on_load: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<PlainText>,
+}
+
+impl NumberInput {
+    pub fn new(is_decimal_allowed: bool) -> Self {
+        NumberInput {
+            is_decimal_allowed,
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Overflow {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    pub options: Vec<SelectOption>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+impl Overflow {
+    pub fn new(options: Vec<SelectOption>) -> Self {
+        Overflow {
+            action_id: None,
+            options,
+            confirm: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct PlainTextInput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multiline: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dispatch_action_config: Option<DispatchActionConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub focus_on_load: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<PlainText>,
+}
+
+impl PlainTextInput {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn multiline(mut self) -> Self {
+        self.multiline = Some(true);
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct RadioButtons {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    pub options: Vec<SelectOption>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_option: Option<SelectOption>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub focus_on_load: Option<bool>,
+}
+
+impl RadioButtons {
+    pub fn new(options: Vec<SelectOption>) -> Self {
+        RadioButtons {
+            action_id: None,
+            options,
+            initial_option: None,
+            confirm: None,
+            focus_on_load: None,
+        }
+    }
+}
+
+/// A select menu of options given in the block, either `options` or `option_groups`.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct StaticSelect {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<PlainText>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<Vec<SelectOption>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub option_groups: Option<Vec<OptionGroup>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_option: Option<SelectOption>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub focus_on_load: Option<bool>,
+}
+
+impl StaticSelect {
+    pub fn new(options: Vec<SelectOption>) -> Self {
+        StaticSelect {
+            options: Some(options),
+            ..Self::default()
+        }
+    }
+
+    pub fn grouped(option_groups: Vec<OptionGroup>) -> Self {
+        StaticSelect {
+            option_groups: Some(option_groups),
+            ..Self::default()
+        }
+    }
+
+    pub fn with_initial_option(mut self, option: SelectOption) -> Self {
+        self.initial_option = Some(option);
+        self
+    }
+}
+
+/// A select menu of several options given in the block, either `options` or `option_groups`.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct MultiStaticSelect {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<PlainText>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<Vec<SelectOption>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub option_groups: Option<Vec<OptionGroup>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_options: Option<Vec<SelectOption>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_selected_items: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub focus_on_load: Option<bool>,
+}
+
+impl MultiStaticSelect {
+    pub fn new(options: Vec<SelectOption>) -> Self {
+        MultiStaticSelect {
+            options: Some(options),
+            ..Self::default()
+        }
+    }
+
+    pub fn grouped(option_groups: Vec<OptionGroup>) -> Self {
+        MultiStaticSelect {
+            option_groups: Some(option_groups),
+            ..Self::default()
+        }
+    }
+}
+
+/// A select menu of options loaded from the app's options load URL.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct ExternalSelect {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<PlainText>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_option: Option<SelectOption>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_query_length: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub focus_on_load: Option<bool>,
+}
+
+impl ExternalSelect {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// A select menu of several options loaded from the app's options load URL.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct MultiExternalSelect {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<PlainText>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_options: Option<Vec<SelectOption>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_query_length: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_selected_items: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub focus_on_load: Option<bool>,
+}
+
+impl MultiExternalSelect {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct UsersSelect {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<PlainText>,
+    /// The ID of the user selected at first.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub focus_on_load: Option<bool>,
+}
+
+impl UsersSelect {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct MultiUsersSelect {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<PlainText>,
+    /// The IDs of the users selected at first.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_users: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_selected_items: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub focus_on_load: Option<bool>,
+}
+
+impl MultiUsersSelect {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct ConversationsSelect {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<PlainText>,
+    /// The ID of the conversation selected at first.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_conversation: Option<String>,
+    /// Whether to select the conversation the menu is shown in at first.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_to_current_conversation: Option<bool>,
+    /// Whether the `view_submission` payload gets a `response_url` for the selected
+    /// conversation; only in input blocks of modals.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_url_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<ConversationFilter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub focus_on_load: Option<bool>,
+}
+
+impl ConversationsSelect {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct MultiConversationsSelect {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<PlainText>,
+    /// The IDs of the conversations selected at first.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_conversations: Option<Vec<String>>,
+    /// Whether to select the conversation the menu is shown in at first.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_to_current_conversation: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_selected_items: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<ConversationFilter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub focus_on_load: Option<bool>,
+}
+
+impl MultiConversationsSelect {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// A select menu of public channels.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct ChannelsSelect {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<PlainText>,
+    /// The ID of the channel selected at first.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_channel: Option<String>,
+    /// Whether the `view_submission` payload gets a `response_url` for the selected channel;
+    /// only in input blocks of modals.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_url_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub focus_on_load: Option<bool>,
+}
+
+impl ChannelsSelect {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// A select menu of several public channels.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct MultiChannelsSelect {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<PlainText>,
+    /// The IDs of the channels selected at first.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_channels: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_selected_items: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub focus_on_load: Option<bool>,
+}
+
+impl MultiChannelsSelect {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct TimePicker {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    /// The time selected at first, as `HH:mm`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_time: Option<String>,
+    /// The IANA time zone the time is in, such as `America/Chicago`, instead of the user's.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub focus_on_load: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<PlainText>,
+}
+
+impl TimePicker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct UrlInput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dispatch_action_config: Option<DispatchActionConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub focus_on_load: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<PlainText>,
+}
+
+impl UrlInput {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
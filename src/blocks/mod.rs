@@ -0,0 +1,522 @@
+//! Typed [Block Kit](https://api.slack.com/block-kit) blocks, elements and composition objects.
+//!
+//! They are taken by the `blocks` of `chat::post_message`, `chat::post_ephemeral`,
+//! `chat::update` and `chat::schedule_message`, and come back in the `blocks` of messages read
+//! with methods such as `conversations::history`. Blocks, elements and rich text of a type this
+//! crate does not know are kept as their JSON in an `Other` variant.
+//!
+//! # Examples
+//!
+//! ```
+//! use slack_api::blocks::{
+//!     ActionsBlock, Block, Button, ButtonStyle, DividerBlock, HeaderBlock, SectionBlock, Text,
+//! };
+//!
+//! let blocks: Vec<Block> = vec![
+//!     HeaderBlock::new("Deploy").into(),
+//!     SectionBlock::new(Text::mrkdwn("*main* is ready to ship")).into(),
+//!     DividerBlock::new().into(),
+//!     ActionsBlock::new(vec![Button::new("Ship it")
+//!         .with_action_id("ship")
+//!         .with_style(ButtonStyle::Primary)
+//!         .into()])
+//!     .into(),
+//! ];
+//! # #[cfg(not(feature = "async"))]
+//! # use slack_api::sync::chat;
+//! # #[cfg(feature = "async")]
+//! use slack_api::chat;
+//!
+//! let request = chat::PostMessageRequest {
+//!     channel: Some("C123".parse().unwrap()),
+//!     text: "main is ready to ship".into(),
+//!     blocks: Some(blocks),
+//!     ..Default::default()
+//! };
+//! # let _ = request;
+//! ```
+
+mod composition;
+pub use self::composition::*;
+
+mod elements;
+pub use self::elements::*;
+
+mod rich_text;
+pub use self::rich_text::*;
+
+mod validate;
+pub use self::validate::*;
+
+mod views;
+pub use self::views::*;
+
+/// A layout block of a message, modal or Home tab.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Block {
+    Actions(ActionsBlock),
+    Context(ContextBlock),
+    Divider(DividerBlock),
+    Header(HeaderBlock),
+    Image(ImageBlock),
+    Input(InputBlock),
+    RichText(RichTextBlock),
+    Section(SectionBlock),
+    Video(VideoBlock),
+    /// A block of a type this crate does not know, such as the `file` and `call` blocks Slack
+    /// adds to messages, or one it could not read, kept as JSON.
+    #[serde(untagged)]
+    Other(serde_json::Value),
+}
+
+impl Block {
+    /// The `block_id` of the block, if it has one.
+    pub fn block_id(&self) -> Option<&str> {
+        match self {
+            Block::Actions(block) => block.block_id.as_deref(),
+            Block::Context(block) => block.block_id.as_deref(),
+            Block::Divider(block) => block.block_id.as_deref(),
+            Block::Header(block) => block.block_id.as_deref(),
+            Block::Image(block) => block.block_id.as_deref(),
+            Block::Input(block) => block.block_id.as_deref(),
+            Block::RichText(block) => block.block_id.as_deref(),
+            Block::Section(block) => block.block_id.as_deref(),
+            Block::Video(block) => block.block_id.as_deref(),
+            Block::Other(block) => block.get("block_id").and_then(|id| id.as_str()),
+        }
+    }
+}
+
+/// Adds `From<...> for Block` and `with_block_id` to every block.
+macro_rules! blocks {
+    ($($variant:ident($block:ident)),* $(,)?) => {
+        $(
+            impl From<$block> for Block {
+                fn from(block: $block) -> Self {
+                    Block::$variant(block)
+                }
+            }
+
+            impl $block {
+                /// Sets the `block_id` that identifies the block in interaction payloads and
+                /// `view_submission` state.
+                pub fn with_block_id<S: Into<String>>(mut self, block_id: S) -> Self {
+                    self.block_id = Some(block_id.into());
+                    self
+                }
+            }
+        )*
+    };
+}
+
+blocks! {
+    Actions(ActionsBlock),
+    Context(ContextBlock),
+    Divider(DividerBlock),
+    Header(HeaderBlock),
+    Image(ImageBlock),
+    Input(InputBlock),
+    RichText(RichTextBlock),
+    Section(SectionBlock),
+    Video(VideoBlock),
+}
+
+/// A block of interactive elements.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ActionsBlock {
+    pub elements: Vec<BlockElement>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+impl ActionsBlock {
+    pub fn new(elements: Vec<BlockElement>) -> Self {
+        ActionsBlock {
+            elements,
+            block_id: None,
+        }
+    }
+}
+
+/// A block of small text and images.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ContextBlock {
+    pub elements: Vec<ContextElement>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+impl ContextBlock {
+    pub fn new(elements: Vec<ContextElement>) -> Self {
+        ContextBlock {
+            elements,
+            block_id: None,
+        }
+    }
+}
+
+/// An element of a context block.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ContextElement {
+    Image(ImageElement),
+    #[serde(untagged)]
+    Text(Text),
+}
+
+impl From<ImageElement> for ContextElement {
+    fn from(image: ImageElement) -> Self {
+        ContextElement::Image(image)
+    }
+}
+
+impl From<Text> for ContextElement {
+    fn from(text: Text) -> Self {
+        ContextElement::Text(text)
+    }
+}
+
+impl From<PlainText> for ContextElement {
+    fn from(text: PlainText) -> Self {
+        ContextElement::Text(text.into())
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct DividerBlock {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+impl DividerBlock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// A block of large, bold text.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct HeaderBlock {
+    pub text: PlainText,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+impl HeaderBlock {
+    pub fn new<T: Into<PlainText>>(text: T) -> Self {
+        HeaderBlock {
+            text: text.into(),
+            block_id: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ImageBlock {
+    pub image_url: String,
+    /// A description of the image for screen readers.
+    pub alt_text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<PlainText>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+impl ImageBlock {
+    pub fn new<U: Into<String>, A: Into<String>>(image_url: U, alt_text: A) -> Self {
+        ImageBlock {
+            image_url: image_url.into(),
+            alt_text: alt_text.into(),
+            title: None,
+            block_id: None,
+        }
+    }
+
+    pub fn with_title<T: Into<PlainText>>(mut self, title: T) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+}
+
+/// A block collecting user input with one element, in modals and Home tabs, or in messages
+/// for elements that dispatch actions.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct InputBlock {
+    pub label: PlainText,
+    pub element: BlockElement,
+    /// Whether the element sends a `block_actions` payload when it is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dispatch_action: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hint: Option<PlainText>,
+    /// Whether the input may be left empty when the modal is submitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optional: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+impl InputBlock {
+    pub fn new<L: Into<PlainText>, E: Into<BlockElement>>(label: L, element: E) -> Self {
+        InputBlock {
+            label: label.into(),
+            element: element.into(),
+            dispatch_action: None,
+            hint: None,
+            optional: None,
+            block_id: None,
+        }
+    }
+
+    pub fn with_hint<H: Into<PlainText>>(mut self, hint: H) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    pub fn optional(mut self) -> Self {
+        self.optional = Some(true);
+        self
+    }
+}
+
+/// A block of formatted text, as written in Slack's composer.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct RichTextBlock {
+    pub elements: Vec<RichTextObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+impl RichTextBlock {
+    pub fn new(elements: Vec<RichTextObject>) -> Self {
+        RichTextBlock {
+            elements,
+            block_id: None,
+        }
+    }
+}
+
+/// A block of text, with up to 10 `fields` shown in two columns and an `accessory` element.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct SectionBlock {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<Vec<Text>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accessory: Option<BlockElement>,
+    /// Whether long text is shown in full instead of behind "see more".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expand: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+impl SectionBlock {
+    pub fn new<T: Into<Text>>(text: T) -> Self {
+        SectionBlock {
+            text: Some(text.into()),
+            ..Self::default()
+        }
+    }
+
+    /// A section of `fields` only.
+    pub fn fields(fields: Vec<Text>) -> Self {
+        SectionBlock {
+            fields: Some(fields),
+            ..Self::default()
+        }
+    }
+
+    pub fn with_fields(mut self, fields: Vec<Text>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn with_accessory<E: Into<BlockElement>>(mut self, accessory: E) -> Self {
+        self.accessory = Some(accessory.into());
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct VideoBlock {
+    pub title: PlainText,
+    /// The URL of the embeddable video; its domain must be listed in the app's unfurl domains.
+    pub video_url: String,
+    pub thumbnail_url: String,
+    /// A description of the video for screen readers.
+    pub alt_text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<PlainText>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_icon_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+impl VideoBlock {
+    pub fn new<T, V, U, A>(title: T, video_url: V, thumbnail_url: U, alt_text: A) -> Self
+    where
+        T: Into<PlainText>,
+        V: Into<String>,
+        U: Into<String>,
+        A: Into<String>,
+    {
+        VideoBlock {
+            title: title.into(),
+            video_url: video_url.into(),
+            thumbnail_url: thumbnail_url.into(),
+            alt_text: alt_text.into(),
+            title_url: None,
+            description: None,
+            author_name: None,
+            provider_name: None,
+            provider_icon_url: None,
+            block_id: None,
+        }
+    }
+}
+
+/// The JSON of blocks or views as embedded in the body of methods such as `chat.postMessage`.
+pub(crate) fn to_json<T: serde::Serialize + ?Sized>(value: &T) -> serde_json::Value {
+    serde_json::to_value(value).expect("blocks and views are always valid JSON")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn blocks_serialize_with_their_types() {
+        let blocks = vec![
+            SectionBlock::new(Text::mrkdwn("*hi*"))
+                .with_accessory(
+                    StaticSelect::new(vec![SelectOption::new(Text::plain("One"), "1")])
+                        .with_action_id("pick")
+                        .with_placeholder("Pick"),
+                )
+                .into(),
+            ContextBlock::new(vec![
+                ImageElement::new("https://example.com/a.png", "a").into(),
+                PlainText::new("note").into(),
+            ])
+            .into(),
+            InputBlock::new("Name", PlainTextInput::new().with_action_id("name"))
+                .with_block_id("b")
+                .into(),
+        ];
+        assert_eq!(
+            to_json(&blocks),
+            json!([
+                {
+                    "type": "section",
+                    "text": {"type": "mrkdwn", "text": "*hi*"},
+                    "accessory": {
+                        "type": "static_select",
+                        "action_id": "pick",
+                        "placeholder": {"type": "plain_text", "text": "Pick"},
+                        "options": [{"text": {"type": "plain_text", "text": "One"}, "value": "1"}],
+                    },
+                },
+                {
+                    "type": "context",
+                    "elements": [
+                        {"type": "image", "image_url": "https://example.com/a.png", "alt_text": "a"},
+                        {"type": "plain_text", "text": "note"},
+                    ],
+                },
+                {
+                    "type": "input",
+                    "label": {"type": "plain_text", "text": "Name"},
+                    "element": {"type": "plain_text_input", "action_id": "name"},
+                    "block_id": "b",
+                },
+            ])
+        );
+        let read: Vec<Block> = serde_json::from_value(to_json(&blocks)).unwrap();
+        assert_eq!(read, blocks);
+    }
+
+    #[test]
+    fn unknown_blocks_are_kept_as_json() {
+        let json = json!([
+            {"type": "divider", "block_id": "d"},
+            {"type": "call", "block_id": "c", "call_id": "R1"},
+            {"type": "actions", "elements": [{"type": "workflow_button", "text": "x"}]},
+        ]);
+        let blocks: Vec<Block> = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(blocks[0], DividerBlock::new().with_block_id("d").into());
+        assert_eq!(blocks[1].block_id(), Some("c"));
+        assert!(matches!(blocks[1], Block::Other(_)));
+        match &blocks[2] {
+            Block::Actions(actions) => {
+                assert!(matches!(actions.elements[0], BlockElement::Other(_)))
+            }
+            block => panic!("expected an actions block, got {:?}", block),
+        }
+        assert_eq!(serde_json::to_value(&blocks).unwrap(), json);
+    }
+
+    #[test]
+    fn plain_text_rejects_mrkdwn() {
+        assert!(
+            serde_json::from_value::<PlainText>(json!({"type": "mrkdwn", "text": "x"})).is_err()
+        );
+        assert_eq!(
+            serde_json::from_value::<PlainText>(
+                json!({"type": "plain_text", "text": "x", "emoji": true})
+            )
+            .unwrap(),
+            PlainText::new("x").with_emoji(true)
+        );
+    }
+
+    #[test]
+    fn rich_text_is_read_from_messages() {
+        let block: Block = serde_json::from_value(json!({
+            "type": "rich_text",
+            "block_id": "r",
+            "elements": [{
+                "type": "rich_text_section",
+                "elements": [
+                    {"type": "text", "text": "hi ", "style": {"bold": true}},
+                    {"type": "user", "user_id": "U1"},
+                    {"type": "emoji", "name": "wave", "unicode": "1f44b"},
+                ],
+            }],
+        }))
+        .unwrap();
+        assert_eq!(
+            block,
+            RichTextBlock::new(vec![RichTextObject::section(vec![
+                RichTextElement::styled_text(
+                    "hi ",
+                    RichTextStyle {
+                        bold: Some(true),
+                        ..RichTextStyle::default()
+                    }
+                ),
+                RichTextElement::User {
+                    user_id: "U1".into(),
+                    style: None,
+                },
+                RichTextElement::Emoji {
+                    name: "wave".into(),
+                    unicode: Some("1f44b".into()),
+                },
+            ])])
+            .with_block_id("r")
+            .into()
+        );
+    }
+}
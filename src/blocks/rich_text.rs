@@ -0,0 +1,143 @@
+//! The contents of rich text blocks, which Slack also uses for messages written in its composer.
+
+/// A top-level element of a rich text block.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type")]
+pub enum RichTextObject {
+    /// A paragraph of inline elements.
+    #[serde(rename = "rich_text_section")]
+    Section { elements: Vec<RichTextElement> },
+    /// A list whose items are usually sections.
+    #[serde(rename = "rich_text_list")]
+    List {
+        style: RichTextListStyle,
+        elements: Vec<RichTextObject>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        indent: Option<u64>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        offset: Option<u64>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        border: Option<u64>,
+    },
+    /// A code block.
+    #[serde(rename = "rich_text_preformatted")]
+    Preformatted {
+        elements: Vec<RichTextElement>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        border: Option<u64>,
+    },
+    #[serde(rename = "rich_text_quote")]
+    Quote {
+        elements: Vec<RichTextElement>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        border: Option<u64>,
+    },
+    /// An element of a type this crate does not know, or could not read, kept as JSON.
+    #[serde(untagged)]
+    Other(serde_json::Value),
+}
+
+impl RichTextObject {
+    pub fn section(elements: Vec<RichTextElement>) -> Self {
+        RichTextObject::Section { elements }
+    }
+
+    pub fn list(style: RichTextListStyle, items: Vec<RichTextObject>) -> Self {
+        RichTextObject::List {
+            style,
+            elements: items,
+            indent: None,
+            offset: None,
+            border: None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RichTextListStyle {
+    Bullet,
+    Ordered,
+}
+
+/// An inline element of a rich text section, preformatted block or quote.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RichTextElement {
+    Text {
+        text: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        style: Option<RichTextStyle>,
+    },
+    Link {
+        url: String,
+        /// The text shown for the link instead of the URL.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        text: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        style: Option<RichTextStyle>,
+    },
+    Emoji {
+        name: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        unicode: Option<String>,
+    },
+    User {
+        user_id: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        style: Option<RichTextStyle>,
+    },
+    Channel {
+        channel_id: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        style: Option<RichTextStyle>,
+    },
+    Usergroup {
+        usergroup_id: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        style: Option<RichTextStyle>,
+    },
+    /// A mention of `here`, `channel` or `everyone`.
+    Broadcast { range: String },
+    /// A date shown in the reader's time zone.
+    Date {
+        /// Seconds since the UNIX epoch.
+        timestamp: u64,
+        /// How the date is shown, such as `{date_short} at {time}`.
+        format: String,
+        /// The text shown for clients that cannot show the date.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        fallback: Option<String>,
+    },
+    /// An element of a type this crate does not know, or could not read, kept as JSON.
+    #[serde(untagged)]
+    Other(serde_json::Value),
+}
+
+impl RichTextElement {
+    pub fn text<S: Into<String>>(text: S) -> Self {
+        RichTextElement::Text {
+            text: text.into(),
+            style: None,
+        }
+    }
+
+    pub fn styled_text<S: Into<String>>(text: S, style: RichTextStyle) -> Self {
+        RichTextElement::Text {
+            text: text.into(),
+            style: Some(style),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RichTextStyle {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bold: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub italic: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strike: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<bool>,
+}
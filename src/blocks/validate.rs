@@ -0,0 +1,612 @@
+//! Offline checks of the Block Kit limits that Slack otherwise reports as `invalid_blocks`.
+
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt;
+
+use super::{
+    Block, BlockElement, ConfirmationDialog, ContextElement, HomeView, ModalView, OptionGroup,
+    PlainText, SelectOption, Text, UpdatedView,
+};
+use crate::mod_types::chat::PostMessageRequest;
+use crate::mod_types::views_types::{OpenRequest, PublishRequest, PushRequest, UpdateRequest};
+
+/// The most characters of a message's `text`; Slack truncates longer texts.
+const MAX_MESSAGE_TEXT: usize = 40_000;
+
+/// Where blocks are shown, which sets how many there may be and which blocks are allowed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Surface {
+    Message,
+    Modal,
+    Home,
+}
+
+impl Surface {
+    fn max_blocks(self) -> usize {
+        match self {
+            Surface::Message => 50,
+            Surface::Modal | Surface::Home => 100,
+        }
+    }
+}
+
+/// One limit a payload breaks.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Violation {
+    /// The JSON path of the offending value, such as `blocks[2].fields[10]`.
+    pub path: String,
+    pub kind: ViolationKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ViolationKind {
+    /// A text has more than `max` characters.
+    TooLong { max: usize, actual: usize },
+    /// A list has more than `max` items.
+    TooMany { max: usize, actual: usize },
+    /// A list has fewer than `min` items.
+    TooFew { min: usize, actual: usize },
+    /// A `block_id` or `action_id` is used twice where it must be unique.
+    Duplicate(String),
+    /// A block or element is not allowed where it is.
+    NotAllowed(&'static str),
+    /// A value required here is missing.
+    Missing(&'static str),
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: ", self.path)?;
+        match &self.kind {
+            ViolationKind::TooLong { max, actual } => {
+                write!(f, "{} characters, more than {}", actual, max)
+            }
+            ViolationKind::TooMany { max, actual } => {
+                write!(f, "{} items, more than {}", actual, max)
+            }
+            ViolationKind::TooFew { min, actual } => {
+                write!(f, "{} items, fewer than {}", actual, min)
+            }
+            ViolationKind::Duplicate(id) => write!(f, "duplicate id `{}`", id),
+            ViolationKind::NotAllowed(what) => write!(f, "{} is not allowed here", what),
+            ViolationKind::Missing(what) => write!(f, "{} is missing", what),
+        }
+    }
+}
+
+/// The limits a payload breaks, in the order they appear in it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationError {
+    pub violations: Vec<Violation>,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} Block Kit violation(s)", self.violations.len())?;
+        for violation in &self.violations {
+            write!(f, "\n  {}", violation)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ValidationError {}
+
+/// Checks `blocks` against Slack's limits for `surface`.
+pub fn validate_blocks(blocks: &[Block], surface: Surface) -> Result<(), ValidationError> {
+    let mut validator = Validator::default();
+    validator.blocks("blocks", blocks, surface);
+    validator.finish()
+}
+
+impl PostMessageRequest<'_> {
+    /// Checks the `text` and `blocks` of the message against Slack's limits, without calling
+    /// Slack.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let mut validator = Validator::default();
+        validator.str("text", &self.text, MAX_MESSAGE_TEXT);
+        if let Some(blocks) = &self.blocks {
+            validator.blocks("blocks", blocks, Surface::Message);
+        }
+        validator.finish()
+    }
+}
+
+impl OpenRequest<'_> {
+    /// Checks the modal against Slack's limits, without calling Slack.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let mut validator = Validator::default();
+        validator.modal("view", &self.view);
+        validator.finish()
+    }
+}
+
+impl PushRequest<'_> {
+    /// Checks the modal against Slack's limits, without calling Slack.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let mut validator = Validator::default();
+        validator.modal("view", &self.view);
+        validator.finish()
+    }
+}
+
+impl UpdateRequest<'_> {
+    /// Checks the modal or Home tab against Slack's limits, without calling Slack.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let mut validator = Validator::default();
+        match &self.view {
+            UpdatedView::Modal(view) => validator.modal("view", view),
+            UpdatedView::Home(view) => validator.home("view", view),
+        }
+        validator.finish()
+    }
+}
+
+impl PublishRequest<'_> {
+    /// Checks the Home tab against Slack's limits, without calling Slack.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let mut validator = Validator::default();
+        validator.home("view", &self.view);
+        validator.finish()
+    }
+}
+
+#[derive(Default)]
+struct Validator {
+    violations: Vec<Violation>,
+}
+
+impl Validator {
+    fn finish(self) -> Result<(), ValidationError> {
+        if self.violations.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationError {
+                violations: self.violations,
+            })
+        }
+    }
+
+    fn violation(&mut self, path: String, kind: ViolationKind) {
+        self.violations.push(Violation { path, kind });
+    }
+
+    fn str(&mut self, path: &str, s: &str, max: usize) {
+        let actual = s.chars().count();
+        if actual > max {
+            self.violation(path.to_owned(), ViolationKind::TooLong { max, actual });
+        }
+    }
+
+    fn count(&mut self, path: &str, actual: usize, min: usize, max: usize) {
+        if actual > max {
+            self.violation(path.to_owned(), ViolationKind::TooMany { max, actual });
+        } else if actual < min {
+            self.violation(path.to_owned(), ViolationKind::TooFew { min, actual });
+        }
+    }
+
+    fn text(&mut self, path: &str, text: &Text, max: usize) {
+        self.str(&format!("{}.text", path), text.text(), max);
+    }
+
+    fn plain_text(&mut self, path: &str, text: &PlainText, max: usize) {
+        self.str(&format!("{}.text", path), &text.text, max);
+    }
+
+    fn id(&mut self, path: String, id: &str, seen: &mut HashSet<String>) {
+        self.str(&path, id, 255);
+        if !seen.insert(id.to_owned()) {
+            self.violation(path, ViolationKind::Duplicate(id.to_owned()));
+        }
+    }
+
+    fn modal(&mut self, path: &str, view: &ModalView) {
+        self.plain_text(&format!("{}.title", path), &view.title, 24);
+        if let Some(close) = &view.close {
+            self.plain_text(&format!("{}.close", path), close, 24);
+        }
+        if let Some(submit) = &view.submit {
+            self.plain_text(&format!("{}.submit", path), submit, 24);
+        }
+        self.metadata(path, &view.private_metadata);
+        let blocks = format!("{}.blocks", path);
+        self.blocks(&blocks, &view.blocks, Surface::Modal);
+        let has_input = view.blocks.iter().any(|b| matches!(b, Block::Input(_)));
+        if has_input && view.submit.is_none() {
+            self.violation(
+                format!("{}.submit", path),
+                ViolationKind::Missing("a submit button for input blocks"),
+            );
+        }
+    }
+
+    fn home(&mut self, path: &str, view: &HomeView) {
+        self.metadata(path, &view.private_metadata);
+        self.blocks(&format!("{}.blocks", path), &view.blocks, Surface::Home);
+    }
+
+    fn metadata(&mut self, path: &str, private_metadata: &Option<String>) {
+        if let Some(private_metadata) = private_metadata {
+            self.str(
+                &format!("{}.private_metadata", path),
+                private_metadata,
+                3000,
+            );
+        }
+    }
+
+    fn blocks(&mut self, path: &str, blocks: &[Block], surface: Surface) {
+        self.count(path, blocks.len(), 0, surface.max_blocks());
+        let mut block_ids = HashSet::new();
+        for (i, block) in blocks.iter().enumerate() {
+            let path = format!("{}[{}]", path, i);
+            if let Some(block_id) = block.block_id() {
+                self.id(format!("{}.block_id", path), block_id, &mut block_ids);
+            }
+            self.block(&path, block, surface);
+        }
+    }
+
+    fn block(&mut self, path: &str, block: &Block, surface: Surface) {
+        let mut action_ids = HashSet::new();
+        match block {
+            Block::Actions(actions) => {
+                let elements = format!("{}.elements", path);
+                self.count(&elements, actions.elements.len(), 1, 25);
+                for (i, element) in actions.elements.iter().enumerate() {
+                    self.element(&format!("{}[{}]", elements, i), element, &mut action_ids);
+                }
+            }
+            Block::Context(context) => {
+                let elements = format!("{}.elements", path);
+                self.count(&elements, context.elements.len(), 1, 10);
+                for (i, element) in context.elements.iter().enumerate() {
+                    let path = format!("{}[{}]", elements, i);
+                    match element {
+                        ContextElement::Image(image) => {
+                            self.str(&format!("{}.image_url", path), &image.image_url, 3000);
+                            self.str(&format!("{}.alt_text", path), &image.alt_text, 2000);
+                        }
+                        ContextElement::Text(text) => self.text(&path, text, 3000),
+                    }
+                }
+            }
+            Block::Divider(_) | Block::RichText(_) | Block::Other(_) => {}
+            Block::Header(header) => self.plain_text(&format!("{}.text", path), &header.text, 150),
+            Block::Image(image) => {
+                self.str(&format!("{}.image_url", path), &image.image_url, 3000);
+                self.str(&format!("{}.alt_text", path), &image.alt_text, 2000);
+                if let Some(title) = &image.title {
+                    self.plain_text(&format!("{}.title", path), title, 2000);
+                }
+            }
+            Block::Input(input) => {
+                if surface == Surface::Message {
+                    self.violation(path.to_owned(), ViolationKind::NotAllowed("an input block"));
+                }
+                self.plain_text(&format!("{}.label", path), &input.label, 2000);
+                if let Some(hint) = &input.hint {
+                    self.plain_text(&format!("{}.hint", path), hint, 2000);
+                }
+                self.element(
+                    &format!("{}.element", path),
+                    &input.element,
+                    &mut action_ids,
+                );
+            }
+            Block::Section(section) => {
+                if let Some(text) = &section.text {
+                    self.text(&format!("{}.text", path), text, 3000);
+                }
+                if let Some(fields) = &section.fields {
+                    let path = format!("{}.fields", path);
+                    self.count(&path, fields.len(), 0, 10);
+                    for (i, field) in fields.iter().enumerate() {
+                        self.text(&format!("{}[{}]", path, i), field, 2000);
+                    }
+                }
+                if section.text.is_none() && section.fields.is_none() {
+                    self.violation(path.to_owned(), ViolationKind::Missing("text or fields"));
+                }
+                if let Some(accessory) = &section.accessory {
+                    self.element(&format!("{}.accessory", path), accessory, &mut action_ids);
+                }
+            }
+            Block::Video(video) => {
+                self.plain_text(&format!("{}.title", path), &video.title, 200);
+                if let Some(description) = &video.description {
+                    self.plain_text(&format!("{}.description", path), description, 200);
+                }
+            }
+        }
+    }
+
+    fn element(&mut self, path: &str, element: &BlockElement, action_ids: &mut HashSet<String>) {
+        let (action_id, placeholder) = match element {
+            BlockElement::Button(button) => {
+                self.plain_text(&format!("{}.text", path), &button.text, 75);
+                if let Some(value) = &button.value {
+                    self.str(&format!("{}.value", path), value, 2000);
+                }
+                if let Some(url) = &button.url {
+                    self.str(&format!("{}.url", path), url, 3000);
+                }
+                self.confirm(path, &button.confirm);
+                (&button.action_id, &None)
+            }
+            BlockElement::Checkboxes(checkboxes) => {
+                self.options(path, &checkboxes.options, 1, 10);
+                self.confirm(path, &checkboxes.confirm);
+                (&checkboxes.action_id, &None)
+            }
+            BlockElement::DatePicker(picker) => {
+                self.confirm(path, &picker.confirm);
+                (&picker.action_id, &picker.placeholder)
+            }
+            BlockElement::DatetimePicker(picker) => {
+                self.confirm(path, &picker.confirm);
+                (&picker.action_id, &None)
+            }
+            BlockElement::EmailInput(input) => (&input.action_id, &input.placeholder),
+            BlockElement::Image(image) => {
+                self.str(&format!("{}.image_url", path), &image.image_url, 3000);
+                self.str(&format!("{}.alt_text", path), &image.alt_text, 2000);
+                (&None, &None)
+            }
+            BlockElement::NumberInput(input) => (&input.action_id, &input.placeholder),
+            BlockElement::Overflow(overflow) => {
+                self.options(path, &overflow.options, 1, 5);
+                self.confirm(path, &overflow.confirm);
+                (&overflow.action_id, &None)
+            }
+            BlockElement::PlainTextInput(input) => {
+                if let Some(initial_value) = &input.initial_value {
+                    self.str(&format!("{}.initial_value", path), initial_value, 3000);
+                }
+                (&input.action_id, &input.placeholder)
+            }
+            BlockElement::RadioButtons(radio) => {
+                self.options(path, &radio.options, 1, 10);
+                self.confirm(path, &radio.confirm);
+                (&radio.action_id, &None)
+            }
+            BlockElement::StaticSelect(select) => {
+                self.select_options(path, &select.options, &select.option_groups);
+                self.confirm(path, &select.confirm);
+                (&select.action_id, &select.placeholder)
+            }
+            BlockElement::MultiStaticSelect(select) => {
+                self.select_options(path, &select.options, &select.option_groups);
+                self.confirm(path, &select.confirm);
+                (&select.action_id, &select.placeholder)
+            }
+            BlockElement::ExternalSelect(select) => {
+                self.confirm(path, &select.confirm);
+                (&select.action_id, &select.placeholder)
+            }
+            BlockElement::MultiExternalSelect(select) => {
+                self.confirm(path, &select.confirm);
+                (&select.action_id, &select.placeholder)
+            }
+            BlockElement::UsersSelect(select) => {
+                self.confirm(path, &select.confirm);
+                (&select.action_id, &select.placeholder)
+            }
+            BlockElement::MultiUsersSelect(select) => {
+                self.confirm(path, &select.confirm);
+                (&select.action_id, &select.placeholder)
+            }
+            BlockElement::ConversationsSelect(select) => {
+                self.confirm(path, &select.confirm);
+                (&select.action_id, &select.placeholder)
+            }
+            BlockElement::MultiConversationsSelect(select) => {
+                self.confirm(path, &select.confirm);
+                (&select.action_id, &select.placeholder)
+            }
+            BlockElement::ChannelsSelect(select) => {
+                self.confirm(path, &select.confirm);
+                (&select.action_id, &select.placeholder)
+            }
+            BlockElement::MultiChannelsSelect(select) => {
+                self.confirm(path, &select.confirm);
+                (&select.action_id, &select.placeholder)
+            }
+            BlockElement::TimePicker(picker) => {
+                self.confirm(path, &picker.confirm);
+                (&picker.action_id, &picker.placeholder)
+            }
+            BlockElement::UrlInput(input) => (&input.action_id, &input.placeholder),
+            BlockElement::Other(_) => (&None, &None),
+        };
+        if let Some(action_id) = action_id {
+            self.id(format!("{}.action_id", path), action_id, action_ids);
+        }
+        if let Some(placeholder) = placeholder {
+            self.plain_text(&format!("{}.placeholder", path), placeholder, 150);
+        }
+    }
+
+    fn confirm(&mut self, path: &str, confirm: &Option<ConfirmationDialog>) {
+        if let Some(confirm) = confirm {
+            let path = format!("{}.confirm", path);
+            self.plain_text(&format!("{}.title", path), &confirm.title, 100);
+            self.text(&format!("{}.text", path), &confirm.text, 300);
+            self.plain_text(&format!("{}.confirm", path), &confirm.confirm, 30);
+            self.plain_text(&format!("{}.deny", path), &confirm.deny, 30);
+        }
+    }
+
+    fn select_options(
+        &mut self,
+        path: &str,
+        options: &Option<Vec<SelectOption>>,
+        option_groups: &Option<Vec<OptionGroup>>,
+    ) {
+        match (options, option_groups) {
+            (Some(options), None) => self.options(path, options, 0, 100),
+            (None, Some(groups)) => {
+                let path = format!("{}.option_groups", path);
+                self.count(&path, groups.len(), 0, 100);
+                for (i, group) in groups.iter().enumerate() {
+                    let path = format!("{}[{}]", path, i);
+                    self.plain_text(&format!("{}.label", path), &group.label, 75);
+                    self.options(&path, &group.options, 0, 100);
+                }
+            }
+            (Some(_), Some(_)) => self.violation(
+                path.to_owned(),
+                ViolationKind::NotAllowed("options along with option_groups"),
+            ),
+            (None, None) => self.violation(
+                path.to_owned(),
+                ViolationKind::Missing("options or option_groups"),
+            ),
+        }
+    }
+
+    fn options(&mut self, path: &str, options: &[SelectOption], min: usize, max: usize) {
+        let path = format!("{}.options", path);
+        self.count(&path, options.len(), min, max);
+        for (i, option) in options.iter().enumerate() {
+            let path = format!("{}[{}]", path, i);
+            self.text(&format!("{}.text", path), &option.text, 75);
+            self.str(&format!("{}.value", path), &option.value, 150);
+            if let Some(description) = &option.description {
+                self.text(&format!("{}.description", path), description, 75);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blocks::{
+        ActionsBlock, Button, DividerBlock, InputBlock, PlainTextInput, SectionBlock,
+    };
+
+    fn paths(result: Result<(), ValidationError>) -> Vec<(String, ViolationKind)> {
+        result
+            .unwrap_err()
+            .violations
+            .into_iter()
+            .map(|v| (v.path, v.kind))
+            .collect()
+    }
+
+    #[test]
+    fn message_blocks_within_limits_are_valid() {
+        let request = PostMessageRequest {
+            channel: Some("C1".parse().unwrap()),
+            text: "hi".into(),
+            blocks: Some(vec![
+                SectionBlock::new(Text::mrkdwn("hi")).into(),
+                DividerBlock::new().into(),
+            ]),
+            ..Default::default()
+        };
+        assert_eq!(request.validate(), Ok(()));
+    }
+
+    #[test]
+    fn message_violations_have_json_paths() {
+        let mut blocks: Vec<Block> = vec![
+            SectionBlock::new(Text::mrkdwn("x".repeat(3001)))
+                .with_fields(vec![Text::plain("f"); 11])
+                .into(),
+            ActionsBlock::new(vec![
+                Button::new("a").with_action_id("go").into(),
+                Button::new("b").with_action_id("go").into(),
+            ])
+            .into(),
+            InputBlock::new("Name", PlainTextInput::new()).into(),
+        ];
+        blocks.resize(51, DividerBlock::new().into());
+        assert_eq!(
+            paths(validate_blocks(&blocks, Surface::Message)),
+            vec![
+                (
+                    "blocks".to_owned(),
+                    ViolationKind::TooMany {
+                        max: 50,
+                        actual: 51
+                    }
+                ),
+                (
+                    "blocks[0].text.text".to_owned(),
+                    ViolationKind::TooLong {
+                        max: 3000,
+                        actual: 3001
+                    }
+                ),
+                (
+                    "blocks[0].fields".to_owned(),
+                    ViolationKind::TooMany {
+                        max: 10,
+                        actual: 11
+                    }
+                ),
+                (
+                    "blocks[1].elements[1].action_id".to_owned(),
+                    ViolationKind::Duplicate("go".to_owned())
+                ),
+                (
+                    "blocks[2]".to_owned(),
+                    ViolationKind::NotAllowed("an input block")
+                ),
+            ]
+        );
+        blocks.truncate(3);
+        assert_eq!(paths(validate_blocks(&blocks, Surface::Modal)).len(), 3);
+    }
+
+    #[test]
+    fn views_are_checked_for_their_surface() {
+        let request = OpenRequest {
+            trigger_id: "t".into(),
+            view: ModalView::new(
+                "A title longer than allowed",
+                vec![DividerBlock::new().into(); 101],
+            ),
+        };
+        assert_eq!(
+            paths(request.validate()),
+            vec![
+                (
+                    "view.title.text".to_owned(),
+                    ViolationKind::TooLong {
+                        max: 24,
+                        actual: 27
+                    }
+                ),
+                (
+                    "view.blocks".to_owned(),
+                    ViolationKind::TooMany {
+                        max: 100,
+                        actual: 101
+                    }
+                ),
+            ]
+        );
+
+        // Home tabs are checked as Home tabs, which have no title
+        let request = UpdateRequest {
+            view: HomeView::new(vec![DividerBlock::new().into(); 101]).into(),
+            view_id: Some("V1".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            paths(request.validate()),
+            vec![(
+                "view.blocks".to_owned(),
+                ViolationKind::TooMany {
+                    max: 100,
+                    actual: 101
+                }
+            )]
+        );
+    }
+}
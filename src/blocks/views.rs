@@ -0,0 +1,300 @@
+//! Views: the modals and Home tabs that blocks are shown in.
+
+use std::collections::HashMap;
+
+use super::{Block, PlainText, SelectOption};
+
+/// A modal, as opened with `views::open`, pushed with `views::push` or updated with
+/// `views::update` as an [`UpdatedView`].
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", rename = "modal")]
+pub struct ModalView {
+    /// The title at the top of the modal, up to 24 characters.
+    pub title: PlainText,
+    pub blocks: Vec<Block>,
+    /// The text of the close button, up to 24 characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close: Option<PlainText>,
+    /// The text of the submit button, up to 24 characters; required for modals with input
+    /// blocks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submit: Option<PlainText>,
+    /// A string of up to 3000 characters sent back in interaction payloads of the view.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_metadata: Option<String>,
+    /// Identifies the view in `view_submission` and `view_closed` payloads.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_id: Option<String>,
+    /// Whether closing this modal closes all the modals of its stack.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clear_on_close: Option<bool>,
+    /// Whether the app gets a `view_closed` payload when the modal is closed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notify_on_close: Option<bool>,
+    /// A unique ID of the app's choosing, to update the view with instead of its `view_id`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submit_disabled: Option<bool>,
+}
+
+impl ModalView {
+    pub fn new<T: Into<PlainText>>(title: T, blocks: Vec<Block>) -> Self {
+        ModalView {
+            title: title.into(),
+            blocks,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_submit<T: Into<PlainText>>(mut self, submit: T) -> Self {
+        self.submit = Some(submit.into());
+        self
+    }
+
+    pub fn with_close<T: Into<PlainText>>(mut self, close: T) -> Self {
+        self.close = Some(close.into());
+        self
+    }
+
+    pub fn with_callback_id<S: Into<String>>(mut self, callback_id: S) -> Self {
+        self.callback_id = Some(callback_id.into());
+        self
+    }
+
+    pub fn with_private_metadata<S: Into<String>>(mut self, private_metadata: S) -> Self {
+        self.private_metadata = Some(private_metadata.into());
+        self
+    }
+
+    pub fn with_external_id<S: Into<String>>(mut self, external_id: S) -> Self {
+        self.external_id = Some(external_id.into());
+        self
+    }
+}
+
+/// A Home tab, as published with `views::publish` or updated with `views::update` as an
+/// [`UpdatedView`].
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", rename = "home")]
+pub struct HomeView {
+    pub blocks: Vec<Block>,
+    /// A string of up to 3000 characters sent back in interaction payloads of the view.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_metadata: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+}
+
+impl HomeView {
+    pub fn new(blocks: Vec<Block>) -> Self {
+        HomeView {
+            blocks,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_callback_id<S: Into<String>>(mut self, callback_id: S) -> Self {
+        self.callback_id = Some(callback_id.into());
+        self
+    }
+
+    pub fn with_private_metadata<S: Into<String>>(mut self, private_metadata: S) -> Self {
+        self.private_metadata = Some(private_metadata.into());
+        self
+    }
+}
+
+/// A modal or a Home tab, as updated with `views::update`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum UpdatedView {
+    Modal(ModalView),
+    Home(HomeView),
+}
+
+impl Default for UpdatedView {
+    fn default() -> Self {
+        UpdatedView::Modal(ModalView::default())
+    }
+}
+
+impl From<ModalView> for UpdatedView {
+    fn from(view: ModalView) -> Self {
+        UpdatedView::Modal(view)
+    }
+}
+
+impl From<HomeView> for UpdatedView {
+    fn from(view: HomeView) -> Self {
+        UpdatedView::Home(view)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ViewType {
+    Modal,
+    Home,
+    WorkflowStep,
+    #[serde(other)]
+    Other,
+}
+
+/// A view as Slack returns it from the `views::*` methods and in interaction payloads.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct View {
+    /// The `view_id` to update or push onto this view with.
+    pub id: String,
+    pub team_id: String,
+    pub r#type: ViewType,
+    #[serde(default)]
+    pub blocks: Vec<Block>,
+    pub title: Option<PlainText>,
+    pub close: Option<PlainText>,
+    pub submit: Option<PlainText>,
+    pub private_metadata: Option<String>,
+    pub callback_id: Option<String>,
+    pub external_id: Option<String>,
+    /// The values of the view's input elements.
+    pub state: Option<ViewState>,
+    /// Pass as the `hash` of `views::update` or `views::publish` so that Slack refuses the
+    /// update with `hash_conflict` if the view changed since.
+    pub hash: String,
+    pub clear_on_close: Option<bool>,
+    pub notify_on_close: Option<bool>,
+    pub submit_disabled: Option<bool>,
+    /// The view at the bottom of the modal stack this view is in.
+    pub root_view_id: Option<String>,
+    /// The view this one was pushed onto.
+    pub previous_view_id: Option<String>,
+    pub app_id: Option<String>,
+    pub app_installed_team_id: Option<String>,
+    pub bot_id: Option<String>,
+}
+
+/// The values of a view's input elements, by `block_id` and then `action_id`.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct ViewState {
+    pub values: HashMap<String, HashMap<String, ViewStateValue>>,
+}
+
+impl ViewState {
+    /// The value of the element `action_id` in block `block_id`.
+    pub fn get(&self, block_id: &str, action_id: &str) -> Option<&ViewStateValue> {
+        self.values.get(block_id)?.get(action_id)
+    }
+}
+
+/// The value of one input element; only the field matching the element's `type` is set.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ViewStateValue {
+    /// The type of the element, such as `plain_text_input` or `static_select`.
+    pub r#type: String,
+    /// The value of text, email, URL and number inputs.
+    pub value: Option<String>,
+    pub selected_option: Option<SelectOption>,
+    pub selected_options: Option<Vec<SelectOption>>,
+    pub selected_user: Option<String>,
+    pub selected_users: Option<Vec<String>>,
+    pub selected_conversation: Option<String>,
+    pub selected_conversations: Option<Vec<String>>,
+    pub selected_channel: Option<String>,
+    pub selected_channels: Option<Vec<String>>,
+    /// A date as `YYYY-MM-DD`.
+    pub selected_date: Option<String>,
+    /// A time as `HH:mm`.
+    pub selected_time: Option<String>,
+    /// Seconds since the UNIX epoch.
+    pub selected_date_time: Option<u64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blocks::{InputBlock, PlainTextInput};
+    use crate::mod_types::views_types::{OpenError, OpenResponse};
+    use serde_json::json;
+
+    #[test]
+    fn modal_serializes_with_its_type() {
+        let modal = ModalView::new(
+            "Wizard",
+            vec![
+                InputBlock::new("Name", PlainTextInput::new().with_action_id("name"))
+                    .with_block_id("b")
+                    .into(),
+            ],
+        )
+        .with_submit("Next")
+        .with_callback_id("step-1");
+        assert_eq!(
+            serde_json::to_value(&modal).unwrap(),
+            json!({
+                "type": "modal",
+                "title": {"type": "plain_text", "text": "Wizard"},
+                "blocks": [{
+                    "type": "input",
+                    "block_id": "b",
+                    "label": {"type": "plain_text", "text": "Name"},
+                    "element": {"type": "plain_text_input", "action_id": "name"},
+                }],
+                "submit": {"type": "plain_text", "text": "Next"},
+                "callback_id": "step-1",
+            })
+        );
+    }
+
+    #[test]
+    fn updated_views_keep_their_type() {
+        let home: UpdatedView = HomeView::new(Vec::new()).into();
+        let value = serde_json::to_value(&home).unwrap();
+        assert_eq!(value, json!({"type": "home", "blocks": []}));
+        assert_eq!(serde_json::from_value::<UpdatedView>(value).unwrap(), home);
+
+        let modal: UpdatedView = ModalView::new("Wizard", Vec::new()).into();
+        let value = serde_json::to_value(&modal).unwrap();
+        assert_eq!(value["type"], "modal");
+        assert_eq!(serde_json::from_value::<UpdatedView>(value).unwrap(), modal);
+    }
+
+    #[test]
+    fn responses_read_the_view() {
+        let response: OpenResponse = serde_json::from_value(json!({
+            "ok": true,
+            "view": {
+                "id": "V1",
+                "team_id": "T1",
+                "type": "modal",
+                "title": {"type": "plain_text", "text": "Wizard", "emoji": true},
+                "close": null,
+                "submit": null,
+                "blocks": [],
+                "private_metadata": "",
+                "callback_id": "step-1",
+                "state": {"values": {"b": {"name": {"type": "plain_text_input", "value": "Ada"}}}},
+                "hash": "156772938.1827394",
+                "root_view_id": "V1",
+                "previous_view_id": null,
+                "app_id": "A1",
+                "bot_id": "B1",
+            },
+        }))
+        .unwrap();
+        let view = response.view.unwrap();
+        assert_eq!((view.id.as_str(), view.r#type), ("V1", ViewType::Modal));
+        assert_eq!(view.hash, "156772938.1827394");
+        let state = view.state.unwrap();
+        assert_eq!(
+            state.get("b", "name").unwrap().value.as_deref(),
+            Some("Ada")
+        );
+
+        let response: OpenResponse =
+            serde_json::from_value(json!({"ok": false, "error": "expired_trigger_id"})).unwrap();
+        let result: Result<_, OpenError<std::fmt::Error>> = response.into();
+        assert_eq!(result.unwrap_err().to_string(), "expired_trigger_id");
+    }
+}
@@ -0,0 +1,377 @@
+//! What all methods' errors have in common, for code that handles them alike, such as retrying
+//! or alerting on revoked tokens.
+
+use std::error::Error;
+use std::fmt;
+
+/// The broad reason a call failed, shared by the errors of all methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// The token is missing, invalid, revoked or expired, or its account is inactive.
+    Auth,
+    /// The token lacks a scope the method needs.
+    MissingScope,
+    /// Slack rejected the call for exceeding a rate limit.
+    RateLimited,
+    /// The channel, user, message or other object the call refers to does not exist.
+    NotFound,
+    /// The token may not do this, for example because of workspace settings or the user's role.
+    Permission,
+    /// The arguments were missing, malformed or out of range.
+    InvalidArgs,
+    /// Slack had an internal problem or timed out; trying again later may succeed.
+    Transient,
+    /// The request sender failed, before or while talking to Slack.
+    Client,
+    /// The response could not be read as the method's response.
+    Malformed,
+    /// Any other error, specific to the method.
+    Other,
+}
+
+impl ErrorKind {
+    /// Classifies an error string Slack returned, such as `token_revoked`.
+    pub fn from_code(code: &str) -> Self {
+        match code {
+            "not_authed"
+            | "invalid_auth"
+            | "bad_token"
+            | "account_inactive"
+            | "token_revoked"
+            | "token_expired"
+            | "org_login_required"
+            | "not_allowed_token_type"
+            | "user_disabled" => ErrorKind::Auth,
+            "missing_scope" => ErrorKind::MissingScope,
+            "ratelimited" | "rate_limited" => ErrorKind::RateLimited,
+            "not_found" | "file_deleted" => ErrorKind::NotFound,
+            "internal_error"
+            | "fatal_error"
+            | "request_timeout"
+            | "service_unavailable"
+            | "team_added_to_org" => ErrorKind::Transient,
+            "no_permission"
+            | "permission_denied"
+            | "access_denied"
+            | "ekm_access_denied"
+            | "not_authorized"
+            | "not_allowed"
+            | "not_in_channel"
+            | "is_archived"
+            | "user_is_bot"
+            | "user_is_restricted"
+            | "user_is_ultra_restricted"
+            | "not_admin"
+            | "not_an_admin"
+            | "not_app_admin"
+            | "user_must_be_admin"
+            | "paid_only"
+            | "feature_not_enabled"
+            | "enterprise_is_restricted" => ErrorKind::Permission,
+            "no_text" | "no_channel" | "no_user" | "no_item_specified" | "bad_timestamp"
+            | "json_not_object" | "msg_too_long" | "too_long" | "too_large" | "name_taken"
+            | "unknown_type" | "validation_errors" => ErrorKind::InvalidArgs,
+            _ if code.ends_with("_not_found") => ErrorKind::NotFound,
+            _ if code.starts_with("restricted_action")
+                || code.starts_with("cant_")
+                || code.starts_with("cannot_") =>
+            {
+                ErrorKind::Permission
+            }
+            _ if code.starts_with("invalid_")
+                || code.starts_with("missing_")
+                || code.starts_with("too_many_") =>
+            {
+                ErrorKind::InvalidArgs
+            }
+            _ => ErrorKind::Other,
+        }
+    }
+
+    /// Whether the same call may succeed if it is sent again later: rate limits, transient
+    /// errors on Slack's side, and failures of the request sender, which are mostly network
+    /// errors.
+    pub fn is_retryable(self) -> bool {
+        matches!(
+            self,
+            ErrorKind::RateLimited | ErrorKind::Transient | ErrorKind::Client
+        )
+    }
+}
+
+/// Implemented by every generated `*Error<E>`.
+pub trait MethodError: Error {
+    /// The error string Slack returned, such as `channel_not_found`; `None` if the request
+    /// sender failed or the response could not be read.
+    fn code(&self) -> Option<&str>;
+
+    fn kind(&self) -> ErrorKind;
+
+    /// What else Slack said about the error; `None` if the request sender failed or the response
+    /// could not be read.
+    fn context(&self) -> Option<&ErrorContext>;
+
+    /// Whether the same call may succeed if it is sent again later.
+    fn is_retryable(&self) -> bool {
+        self.kind().is_retryable()
+    }
+}
+
+/// What else Slack said about an error, such as the scopes a `missing_scope` error is about.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ErrorContext(Option<Box<ErrorDetails>>);
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct ErrorDetails {
+    needed: Option<String>,
+    provided: Option<String>,
+    warning: Option<String>,
+    messages: Vec<String>,
+    warnings: Vec<String>,
+}
+
+impl ErrorContext {
+    pub(crate) fn new(
+        needed: Option<String>,
+        provided: Option<String>,
+        warning: Option<String>,
+        messages: Option<Vec<String>>,
+        warnings: Option<Vec<String>>,
+    ) -> Self {
+        let details = ErrorDetails {
+            needed,
+            provided,
+            warning,
+            messages: messages.unwrap_or_default(),
+            warnings: warnings.unwrap_or_default(),
+        };
+        if details == ErrorDetails::default() {
+            ErrorContext(None)
+        } else {
+            ErrorContext(Some(Box::new(details)))
+        }
+    }
+
+    /// Whether Slack said nothing more about the error.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_none()
+    }
+
+    /// The scopes the method needs, from the `needed` member of `missing_scope` errors.
+    pub fn needed(&self) -> Vec<&str> {
+        crate::response::split_scopes(self.details().and_then(|d| d.needed.as_deref()))
+    }
+
+    /// The scopes the token has, from the `provided` member of `missing_scope` errors.
+    pub fn provided(&self) -> Vec<&str> {
+        crate::response::split_scopes(self.details().and_then(|d| d.provided.as_deref()))
+    }
+
+    /// The warnings of `warning`, such as `missing_charset`, and of `response_metadata.warnings`.
+    pub fn warnings(&self) -> Vec<&str> {
+        self.details().map_or_else(Vec::new, |d| {
+            crate::response::collect_warnings(d.warning.as_deref(), Some(&d.warnings))
+        })
+    }
+
+    /// The explanations of `response_metadata.messages`, such as
+    /// `[ERROR] missing required field: channel`.
+    pub fn messages(&self) -> &[String] {
+        self.details().map_or(&[], |d| &d.messages)
+    }
+
+    fn details(&self) -> Option<&ErrorDetails> {
+        self.0.as_deref()
+    }
+}
+
+/// The members every failed call's response has.
+#[derive(Deserialize)]
+struct ErrorResponse {
+    #[serde(default)]
+    ok: bool,
+    error: Option<String>,
+    needed: Option<String>,
+    provided: Option<String>,
+    warning: Option<String>,
+    response_metadata: Option<crate::ResponseMetadata>,
+}
+
+/// Reads the error string and context of a failed call from a body that did not parse as the
+/// method's response, as happens when members required on success are missing.
+pub(crate) fn read_error(body: &str) -> Option<(String, ErrorContext)> {
+    let response: ErrorResponse = serde_json::from_str(body).ok()?;
+    if response.ok {
+        return None;
+    }
+    let (messages, warnings) = response
+        .response_metadata
+        .map_or((None, None), |m| (m.messages, m.warnings));
+    let context = ErrorContext::new(
+        response.needed,
+        response.provided,
+        response.warning,
+        messages,
+        warnings,
+    );
+    Some((response.error.unwrap_or_default(), context))
+}
+
+/// The error of any method, converted from its `*Error<E>` with `From`, so that `?` can mix calls
+/// to different methods.
+#[derive(Debug)]
+pub struct SlackError {
+    kind: ErrorKind,
+    code: Option<String>,
+    context: ErrorContext,
+    inner: Box<dyn Error + Send + Sync>,
+}
+
+impl SlackError {
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// The error string Slack returned, such as `channel_not_found`.
+    pub fn code(&self) -> Option<&str> {
+        self.code.as_deref()
+    }
+
+    pub fn is_retryable(&self) -> bool {
+        self.kind.is_retryable()
+    }
+
+    /// What else Slack said about the error.
+    pub fn context(&self) -> &ErrorContext {
+        &self.context
+    }
+
+    /// The method's error this was converted from.
+    pub fn get_ref(&self) -> &(dyn Error + Send + Sync + 'static) {
+        &*self.inner
+    }
+
+    /// Gets back the method's error, such as a `chat::PostMessageError<reqwest::Error>`.
+    pub fn downcast<T: Error + 'static>(self) -> Result<T, Self> {
+        if self.inner.is::<T>() {
+            Ok(*self.inner.downcast::<T>().expect("checked with is"))
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl<T: MethodError + Send + Sync + 'static> From<T> for SlackError {
+    fn from(error: T) -> Self {
+        SlackError {
+            kind: error.kind(),
+            code: error.code().map(str::to_owned),
+            context: error.context().cloned().unwrap_or_default(),
+            inner: Box::new(error),
+        }
+    }
+}
+
+impl fmt::Display for SlackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.inner, f)
+    }
+}
+
+impl Error for SlackError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.inner.source()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mod_types::chat::DeleteError;
+
+    #[test]
+    fn codes_are_classified() {
+        assert_eq!(ErrorKind::from_code("token_revoked"), ErrorKind::Auth);
+        assert_eq!(
+            ErrorKind::from_code("missing_scope"),
+            ErrorKind::MissingScope
+        );
+        assert_eq!(ErrorKind::from_code("ratelimited"), ErrorKind::RateLimited);
+        assert_eq!(
+            ErrorKind::from_code("channel_not_found"),
+            ErrorKind::NotFound
+        );
+        assert_eq!(
+            ErrorKind::from_code("cant_delete_message"),
+            ErrorKind::Permission
+        );
+        assert_eq!(
+            ErrorKind::from_code("invalid_cursor"),
+            ErrorKind::InvalidArgs
+        );
+        assert_eq!(ErrorKind::from_code("internal_error"), ErrorKind::Transient);
+        assert_eq!(ErrorKind::from_code("edit_window_closed"), ErrorKind::Other);
+    }
+
+    #[test]
+    fn method_errors_convert_to_slack_error() {
+        let error = DeleteError::<std::io::Error>::from("ratelimited");
+        assert_eq!(error.kind(), ErrorKind::RateLimited);
+        assert_eq!(error.code(), Some("ratelimited"));
+        assert!(error.is_retryable());
+
+        let error = SlackError::from(DeleteError::<std::io::Error>::from("token_revoked"));
+        assert_eq!(
+            (error.code(), error.kind()),
+            (Some("token_revoked"), ErrorKind::Auth)
+        );
+        assert!(!error.is_retryable());
+        assert!(matches!(
+            error.downcast::<DeleteError<std::io::Error>>(),
+            Ok(DeleteError::TokenRevoked(_))
+        ));
+
+        let client = std::io::Error::new(std::io::ErrorKind::ConnectionReset, "connection reset");
+        let error = SlackError::from(DeleteError::Client(client));
+        assert_eq!((error.code(), error.kind()), (None, ErrorKind::Client));
+        assert_eq!(error.to_string(), "connection reset");
+    }
+
+    #[test]
+    fn errors_keep_what_slack_said() {
+        let body = r#"{
+            "ok": false,
+            "error": "missing_scope",
+            "needed": "chat:write",
+            "provided": "channels:read,users:read",
+            "warning": "missing_charset,superfluous_charset",
+            "response_metadata": {
+                "messages": ["[ERROR] missing required field: channel"],
+                "warnings": ["missing_charset"]
+            }
+        }"#;
+        // chat.delete's response requires `channel` and `ts`, which failed calls lack.
+        let (code, context) = read_error(body).unwrap();
+        let error = SlackError::from(DeleteError::<std::io::Error>::new(&code, context));
+        assert_eq!(error.kind(), ErrorKind::MissingScope);
+        assert_eq!(error.context().needed(), vec!["chat:write"]);
+        assert_eq!(
+            error.context().provided(),
+            vec!["channels:read", "users:read"]
+        );
+        assert_eq!(
+            error.context().warnings(),
+            vec!["missing_charset", "superfluous_charset"]
+        );
+        assert_eq!(
+            error.context().messages(),
+            ["[ERROR] missing required field: channel"]
+        );
+
+        assert!(read_error(r#"{"ok": true}"#).is_none());
+        assert!(DeleteError::<std::io::Error>::from("token_revoked")
+            .context()
+            .unwrap()
+            .is_empty());
+    }
+}
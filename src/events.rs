@@ -0,0 +1,615 @@
+//! Typed payloads of the [Events API](https://api.slack.com/apis/connections/events-api), which
+//! Slack sends to an app's Request URL.
+//!
+//! An [`EventRequest`] is the body of such a request: a `url_verification` challenge when the
+//! Request URL is set up, an `event_callback` carrying an [`Event`], or `app_rate_limited` when
+//! Slack stops sending events for a while. Events of a type this crate does not know, or that do
+//! not match their type's fields, such as messages of a new subtype, are kept as their JSON in
+//! [`Event::Other`].
+//!
+//! # Examples
+//!
+//! ```
+//! use slack_api::events::{Event, EventRequest};
+//!
+//! let body = r#"{
+//!     "type": "event_callback",
+//!     "token": "XXYYZZ",
+//!     "team_id": "T1",
+//!     "api_app_id": "A1",
+//!     "event": {
+//!         "type": "app_mention",
+//!         "user": "U1",
+//!         "text": "<@U2> hi",
+//!         "ts": "1515449522.000016",
+//!         "channel": "C1",
+//!         "event_ts": "1515449522.000016"
+//!     },
+//!     "event_id": "Ev1",
+//!     "event_time": 1515449522,
+//!     "authorizations": [
+//!         {"team_id": "T1", "user_id": "U2", "is_bot": true, "is_enterprise_install": false}
+//!     ]
+//! }"#;
+//! match serde_json::from_str(body).unwrap() {
+//!     EventRequest::UrlVerification(verification) => println!("{}", verification.challenge),
+//!     EventRequest::EventCallback(callback) => match callback.event {
+//!         Event::AppMention(mention) => assert_eq!(mention.text, "<@U2> hi"),
+//!         _ => {}
+//!     },
+//!     _ => {}
+//! }
+//! ```
+
+use serde::de::Error as _;
+use serde::ser::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::blocks::{Block, View};
+use crate::installation_store::InstallationKey;
+use crate::{AppId, ChannelId, EnterpriseId, FileId, Message, TeamId, Timestamp, User, UserId};
+
+/// The body of a request from the Events API.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EventRequest {
+    UrlVerification(UrlVerification),
+    EventCallback(EventCallback),
+    AppRateLimited(AppRateLimited),
+    /// A request of a type this crate does not know
+    #[serde(untagged)]
+    Other(serde_json::Value),
+}
+
+/// Sent once when the Request URL is set up. The app answers with the `challenge`, e.g. as a
+/// `text/plain` body.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UrlVerification {
+    /// The deprecated verification token; check the request's signature instead.
+    pub token: Option<String>,
+    pub challenge: String,
+}
+
+/// An event that happened in a workspace the app is installed in.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EventCallback {
+    /// The deprecated verification token; check the request's signature instead.
+    pub token: Option<String>,
+    /// The workspace the event happened in.
+    pub team_id: TeamId,
+    pub enterprise_id: Option<EnterpriseId>,
+    pub api_app_id: AppId,
+    pub event: Event,
+    /// Identifies the event across retries of the request.
+    pub event_id: String,
+    /// When the event was dispatched, in seconds since the Unix epoch.
+    pub event_time: u64,
+    pub event_context: Option<String>,
+    /// The installations that can see the event. Slack lists only one of them; call
+    /// `apps.event.authorizations.list` with `event_context` for the others.
+    #[serde(default)]
+    pub authorizations: Vec<Authorization>,
+    /// Whether the event happened in a channel shared with another organization.
+    pub is_ext_shared_channel: Option<bool>,
+    pub context_team_id: Option<TeamId>,
+    pub context_enterprise_id: Option<EnterpriseId>,
+}
+
+impl EventCallback {
+    /// The key of the installation the event is for, to find its tokens in an
+    /// [`InstallationStore`](crate::installation_store::InstallationStore).
+    pub fn installation_key(&self) -> InstallationKey {
+        let is_enterprise_install = matches!(
+            self.authorizations.first(),
+            Some(authorization) if authorization.is_enterprise_install
+        );
+        InstallationKey::new(
+            self.enterprise_id.clone(),
+            Some(self.team_id.clone()),
+            is_enterprise_install,
+        )
+    }
+}
+
+/// An installation of the app that can see an event.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Authorization {
+    pub enterprise_id: Option<EnterpriseId>,
+    /// `None` for org-wide installs.
+    pub team_id: Option<TeamId>,
+    /// The bot user, or the user who installed the app for user tokens.
+    pub user_id: UserId,
+    #[serde(default)]
+    pub is_bot: bool,
+    #[serde(default)]
+    pub is_enterprise_install: bool,
+}
+
+impl Authorization {
+    /// The key of the installation in an
+    /// [`InstallationStore`](crate::installation_store::InstallationStore).
+    pub fn installation_key(&self) -> InstallationKey {
+        InstallationKey::new(
+            self.enterprise_id.clone(),
+            self.team_id.clone(),
+            self.is_enterprise_install,
+        )
+    }
+}
+
+/// Sent when Slack stops sending events because the app received more than 30,000 of a
+/// workspace's within an hour.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AppRateLimited {
+    pub token: Option<String>,
+    pub team_id: TeamId,
+    /// The start of the rate limited minute, in seconds since the Unix epoch.
+    pub minute_rate_limited: u64,
+    pub api_app_id: AppId,
+}
+
+/// The event of an [`EventCallback`], by its `type`.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug)]
+pub enum Event {
+    AppHomeOpened(AppHomeOpenedEvent),
+    AppMention(AppMentionEvent),
+    AppUninstalled(AppUninstalledEvent),
+    ChannelArchive(ChannelArchiveEvent),
+    ChannelCreated(ChannelCreatedEvent),
+    ChannelDeleted(ChannelDeletedEvent),
+    ChannelRename(ChannelRenameEvent),
+    ChannelUnarchive(ChannelArchiveEvent),
+    FileDeleted(FileDeletedEvent),
+    FileShared(FileSharedEvent),
+    LinkShared(LinkSharedEvent),
+    MemberJoinedChannel(MemberChannelEvent),
+    MemberLeftChannel(MemberChannelEvent),
+    Message(Message),
+    ReactionAdded(ReactionEvent),
+    ReactionRemoved(ReactionEvent),
+    TeamJoin(UserEvent),
+    TokensRevoked(TokensRevokedEvent),
+    UserChange(UserEvent),
+    /// An event of a type this crate does not know, or that did not match its type's fields
+    Other(serde_json::Value),
+}
+
+impl Event {
+    /// The event's `type`, such as `"app_mention"`.
+    pub fn event_type(&self) -> Option<&str> {
+        Some(match *self {
+            Event::AppHomeOpened(_) => "app_home_opened",
+            Event::AppMention(_) => "app_mention",
+            Event::AppUninstalled(_) => "app_uninstalled",
+            Event::ChannelArchive(_) => "channel_archive",
+            Event::ChannelCreated(_) => "channel_created",
+            Event::ChannelDeleted(_) => "channel_deleted",
+            Event::ChannelRename(_) => "channel_rename",
+            Event::ChannelUnarchive(_) => "channel_unarchive",
+            Event::FileDeleted(_) => "file_deleted",
+            Event::FileShared(_) => "file_shared",
+            Event::LinkShared(_) => "link_shared",
+            Event::MemberJoinedChannel(_) => "member_joined_channel",
+            Event::MemberLeftChannel(_) => "member_left_channel",
+            Event::Message(_) => "message",
+            Event::ReactionAdded(_) => "reaction_added",
+            Event::ReactionRemoved(_) => "reaction_removed",
+            Event::TeamJoin(_) => "team_join",
+            Event::TokensRevoked(_) => "tokens_revoked",
+            Event::UserChange(_) => "user_change",
+            Event::Other(ref event) => return event.get("type").and_then(|ty| ty.as_str()),
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for Event {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        fn typed<T, F>(value: &serde_json::Value, variant: F) -> Option<Event>
+        where
+            T: serde::de::DeserializeOwned,
+            F: FnOnce(T) -> Event,
+        {
+            serde_json::from_value(value.clone()).ok().map(variant)
+        }
+
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let ty = value
+            .get("type")
+            .and_then(|ty| ty.as_str())
+            .ok_or_else(|| D::Error::missing_field("type"))?;
+        let event = match ty {
+            "app_home_opened" => typed(&value, Event::AppHomeOpened),
+            "app_mention" => typed(&value, Event::AppMention),
+            "app_uninstalled" => typed(&value, Event::AppUninstalled),
+            "channel_archive" => typed(&value, Event::ChannelArchive),
+            "channel_created" => typed(&value, Event::ChannelCreated),
+            "channel_deleted" => typed(&value, Event::ChannelDeleted),
+            "channel_rename" => typed(&value, Event::ChannelRename),
+            "channel_unarchive" => typed(&value, Event::ChannelUnarchive),
+            "file_deleted" => typed(&value, Event::FileDeleted),
+            "file_shared" => typed(&value, Event::FileShared),
+            "link_shared" => typed(&value, Event::LinkShared),
+            "member_joined_channel" => typed(&value, Event::MemberJoinedChannel),
+            "member_left_channel" => typed(&value, Event::MemberLeftChannel),
+            "message" => typed(&value, Event::Message),
+            "reaction_added" => typed(&value, Event::ReactionAdded),
+            "reaction_removed" => typed(&value, Event::ReactionRemoved),
+            "team_join" => typed(&value, Event::TeamJoin),
+            "tokens_revoked" => typed(&value, Event::TokensRevoked),
+            "user_change" => typed(&value, Event::UserChange),
+            _ => None,
+        };
+        Ok(event.unwrap_or(Event::Other(value)))
+    }
+}
+
+impl Serialize for Event {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let value = match *self {
+            Event::AppHomeOpened(ref event) => serde_json::to_value(event),
+            Event::AppMention(ref event) => serde_json::to_value(event),
+            Event::AppUninstalled(ref event) => serde_json::to_value(event),
+            Event::ChannelArchive(ref event) | Event::ChannelUnarchive(ref event) => {
+                serde_json::to_value(event)
+            }
+            Event::ChannelCreated(ref event) => serde_json::to_value(event),
+            Event::ChannelDeleted(ref event) => serde_json::to_value(event),
+            Event::ChannelRename(ref event) => serde_json::to_value(event),
+            Event::FileDeleted(ref event) => serde_json::to_value(event),
+            Event::FileShared(ref event) => serde_json::to_value(event),
+            Event::LinkShared(ref event) => serde_json::to_value(event),
+            Event::MemberJoinedChannel(ref event) | Event::MemberLeftChannel(ref event) => {
+                serde_json::to_value(event)
+            }
+            Event::Message(ref event) => serde_json::to_value(event),
+            Event::ReactionAdded(ref event) | Event::ReactionRemoved(ref event) => {
+                serde_json::to_value(event)
+            }
+            Event::TeamJoin(ref event) | Event::UserChange(ref event) => {
+                serde_json::to_value(event)
+            }
+            Event::TokensRevoked(ref event) => serde_json::to_value(event),
+            Event::Other(ref event) => return event.serialize(serializer),
+        };
+        let mut value = value.map_err(S::Error::custom)?;
+        if let (Some(object), Some(ty)) = (value.as_object_mut(), self.event_type()) {
+            object.insert("type".into(), ty.into());
+        }
+        value.serialize(serializer)
+    }
+}
+
+/// `app_home_opened`: a user opened the app's App Home.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AppHomeOpenedEvent {
+    pub user: UserId,
+    pub channel: ChannelId,
+    /// `"home"` or `"messages"`.
+    pub tab: String,
+    pub event_ts: Timestamp,
+    /// The Home tab's current view, if one was published.
+    pub view: Option<View>,
+}
+
+/// `app_mention`: a message mentioned the app's bot user.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AppMentionEvent {
+    pub user: Option<UserId>,
+    pub bot_id: Option<String>,
+    pub text: String,
+    pub ts: Timestamp,
+    pub channel: ChannelId,
+    pub event_ts: Timestamp,
+    pub thread_ts: Option<Timestamp>,
+    pub team: Option<TeamId>,
+    pub blocks: Option<Vec<Block>>,
+}
+
+/// `app_uninstalled`: the app was uninstalled from the workspace, whose installation can be
+/// deleted from the installation store.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct AppUninstalledEvent {
+    pub event_ts: Option<Timestamp>,
+}
+
+/// `channel_archive` and `channel_unarchive`: a channel was archived or unarchived.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ChannelArchiveEvent {
+    pub channel: ChannelId,
+    pub user: UserId,
+    pub is_moved: Option<u32>,
+    pub event_ts: Option<Timestamp>,
+}
+
+/// `channel_created`: a channel was created.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ChannelCreatedEvent {
+    pub channel: CreatedChannel,
+    pub event_ts: Option<Timestamp>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CreatedChannel {
+    pub id: ChannelId,
+    pub name: String,
+    /// When the channel was created, in seconds since the Unix epoch.
+    pub created: u64,
+    pub creator: UserId,
+    pub is_channel: Option<bool>,
+    pub is_private: Option<bool>,
+    pub is_shared: Option<bool>,
+    pub context_team_id: Option<TeamId>,
+}
+
+/// `channel_deleted`: a channel was deleted.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ChannelDeletedEvent {
+    pub channel: ChannelId,
+    pub event_ts: Option<Timestamp>,
+}
+
+/// `channel_rename`: a channel was renamed.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ChannelRenameEvent {
+    pub channel: RenamedChannel,
+    pub event_ts: Option<Timestamp>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RenamedChannel {
+    pub id: ChannelId,
+    /// The new name.
+    pub name: String,
+    pub created: u64,
+}
+
+/// `file_deleted`: a file was deleted.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FileDeletedEvent {
+    pub file_id: FileId,
+    pub event_ts: Option<Timestamp>,
+}
+
+/// `file_shared`: a file was shared. `files::info` returns more about it.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FileSharedEvent {
+    pub file_id: FileId,
+    pub user_id: UserId,
+    pub channel_id: Option<ChannelId>,
+    pub event_ts: Option<Timestamp>,
+}
+
+/// `link_shared`: a message contains links to one of the app's unfurl domains.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LinkSharedEvent {
+    pub channel: ChannelId,
+    pub user: UserId,
+    pub message_ts: Timestamp,
+    pub thread_ts: Option<Timestamp>,
+    pub links: Vec<SharedLink>,
+    /// Pass to `chat.unfurl` along with `source` to unfurl links composed but not sent yet.
+    pub unfurl_id: Option<String>,
+    /// `"conversations_history"` or `"composer"`.
+    pub source: Option<String>,
+    pub is_bot_user_member: Option<bool>,
+    pub event_ts: Option<Timestamp>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SharedLink {
+    pub domain: String,
+    pub url: String,
+}
+
+/// `member_joined_channel` and `member_left_channel`: a user joined or left a channel.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MemberChannelEvent {
+    pub user: UserId,
+    pub channel: ChannelId,
+    /// `"C"` for public channels and `"G"` for private ones.
+    pub channel_type: Option<String>,
+    pub team: Option<TeamId>,
+    /// The user who invited `user` to the channel, if any.
+    pub inviter: Option<UserId>,
+    pub event_ts: Option<Timestamp>,
+}
+
+/// `reaction_added` and `reaction_removed`: a user added or removed a reaction.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ReactionEvent {
+    pub user: UserId,
+    /// The emoji's name, without colons.
+    pub reaction: String,
+    /// The user who created the item.
+    pub item_user: Option<UserId>,
+    pub item: ReactionItem,
+    pub event_ts: Timestamp,
+}
+
+/// The item a reaction was added to or removed from.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ReactionItem {
+    Message {
+        channel: ChannelId,
+        ts: Timestamp,
+    },
+    File {
+        file: FileId,
+    },
+    FileComment {
+        file: FileId,
+        file_comment: String,
+    },
+    /// An item of a type this crate does not know
+    #[serde(untagged)]
+    Other(serde_json::Value),
+}
+
+/// `team_join` and `user_change`: a user joined the workspace, or their profile changed.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct UserEvent {
+    pub user: User,
+    pub event_ts: Option<Timestamp>,
+}
+
+/// `tokens_revoked`: tokens of the app were revoked, and can be revoked in the installation
+/// store.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TokensRevokedEvent {
+    pub tokens: RevokedTokens,
+    pub event_ts: Option<Timestamp>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RevokedTokens {
+    /// The users whose user tokens were revoked.
+    #[serde(default)]
+    pub oauth: Vec<UserId>,
+    /// The bot users whose bot tokens were revoked.
+    #[serde(default)]
+    pub bot: Vec<UserId>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn callback(event: &str) -> EventCallback {
+        let body = format!(
+            r#"{{"type": "event_callback", "token": "t", "team_id": "T1", "enterprise_id": "E1", "api_app_id": "A1", "event": {}, "event_id": "Ev1", "event_time": 1, "is_ext_shared_channel": false, "authorizations": [{{"enterprise_id": "E1", "team_id": null, "user_id": "U1", "is_bot": true, "is_enterprise_install": true}}]}}"#,
+            event
+        );
+        match serde_json::from_str(&body).unwrap() {
+            EventRequest::EventCallback(callback) => callback,
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn envelopes() {
+        let request: EventRequest = serde_json::from_str(
+            r#"{"type": "url_verification", "token": "t", "challenge": "3eZbrw1aBm2rZgRNFdxV2595E9CY3gmdALWMmHkvFXO7tYXAYM8P"}"#,
+        )
+        .unwrap();
+        assert!(
+            matches!(request, EventRequest::UrlVerification(ref v) if v.challenge.starts_with("3eZ"))
+        );
+        let request: EventRequest = serde_json::from_str(
+            r#"{"type": "app_rate_limited", "token": "t", "team_id": "T1", "minute_rate_limited": 1518467820, "api_app_id": "A1"}"#,
+        )
+        .unwrap();
+        assert!(matches!(request, EventRequest::AppRateLimited(ref r) if r.team_id == "T1"));
+
+        let callback = callback(r#"{"type": "app_uninstalled"}"#);
+        assert!(matches!(callback.event, Event::AppUninstalled(_)));
+        assert_eq!(callback.is_ext_shared_channel, Some(false));
+        let key = InstallationKey {
+            enterprise_id: Some("E1".parse().unwrap()),
+            team_id: None,
+        };
+        assert_eq!(callback.installation_key(), key);
+        assert_eq!(callback.authorizations[0].installation_key(), key);
+    }
+
+    #[test]
+    fn typed_events() {
+        let event = callback(
+            r#"{"type": "message", "channel": "C1", "user": "U1", "text": "hi", "ts": "1355517523.000005", "event_ts": "1355517523.000005", "channel_type": "channel"}"#,
+        )
+        .event;
+        match event {
+            Event::Message(Message::Standard(ref message)) => {
+                assert_eq!(message.text.as_deref(), Some("hi"))
+            }
+            ref other => panic!("{:?}", other),
+        }
+        assert_eq!(serde_json::to_value(&event).unwrap()["type"], "message");
+
+        let event = callback(
+            r#"{"type": "reaction_added", "user": "U1", "reaction": "thumbsup", "item_user": "U2", "item": {"type": "message", "channel": "C1", "ts": "1360782400.498405"}, "event_ts": "1360782804.083113"}"#,
+        )
+        .event;
+        match event {
+            Event::ReactionAdded(ref reaction) => {
+                assert_eq!(reaction.reaction, "thumbsup");
+                assert!(
+                    matches!(reaction.item, ReactionItem::Message { ref channel, .. } if channel == "C1")
+                );
+            }
+            ref other => panic!("{:?}", other),
+        }
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["type"], "reaction_added");
+        assert_eq!(json["item"]["type"], "message");
+
+        let event = callback(
+            r#"{"type": "tokens_revoked", "tokens": {"oauth": ["U1"], "bot": ["U2"]}, "event_ts": "1360782804.083113"}"#,
+        )
+        .event;
+        match event {
+            Event::TokensRevoked(ref revoked) => {
+                assert_eq!(revoked.tokens.bot, vec![UserId::new("U2").unwrap()])
+            }
+            ref other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn every_event_round_trips() {
+        let events = [
+            r#"{"type": "app_home_opened", "user": "U1", "channel": "D1", "tab": "home", "event_ts": "1.000001"}"#,
+            r#"{"type": "app_mention", "user": "U1", "text": "<@U2> hi", "ts": "1.000001", "channel": "C1", "event_ts": "1.000001"}"#,
+            r#"{"type": "app_uninstalled"}"#,
+            r#"{"type": "channel_archive", "channel": "C1", "user": "U1"}"#,
+            r#"{"type": "channel_created", "channel": {"id": "C1", "name": "general", "created": 1360782804, "creator": "U1"}}"#,
+            r#"{"type": "channel_deleted", "channel": "C1"}"#,
+            r#"{"type": "channel_rename", "channel": {"id": "C1", "name": "random", "created": 1360782804}}"#,
+            r#"{"type": "channel_unarchive", "channel": "C1", "user": "U1"}"#,
+            r#"{"type": "file_deleted", "file_id": "F1"}"#,
+            r#"{"type": "file_shared", "file_id": "F1", "user_id": "U1"}"#,
+            r#"{"type": "link_shared", "channel": "C1", "user": "U1", "message_ts": "1.000001", "links": [{"domain": "example.com", "url": "https://example.com/1"}]}"#,
+            r#"{"type": "member_joined_channel", "user": "U1", "channel": "C1"}"#,
+            r#"{"type": "member_left_channel", "user": "U1", "channel": "C1"}"#,
+            r#"{"type": "message", "channel": "C1", "user": "U1", "text": "hi", "ts": "1.000001"}"#,
+            r#"{"type": "reaction_added", "user": "U1", "reaction": "thumbsup", "item": {"type": "file", "file": "F1"}, "event_ts": "1.000001"}"#,
+            r#"{"type": "reaction_removed", "user": "U1", "reaction": "thumbsup", "item": {"type": "file_comment", "file": "F1", "file_comment": "Fc1"}, "event_ts": "1.000001"}"#,
+            r#"{"type": "team_join", "user": {"id": "U1", "name": "spengler"}}"#,
+            r#"{"type": "tokens_revoked", "tokens": {"oauth": ["U1"]}}"#,
+            r#"{"type": "user_change", "user": {"id": "U1", "name": "spengler"}}"#,
+        ];
+        for json in events.iter() {
+            let event: Event = serde_json::from_str(json).unwrap();
+            assert!(!matches!(event, Event::Other(_)), "{}", json);
+            let value = serde_json::to_value(&event).unwrap();
+            assert_eq!(
+                value["type"],
+                serde_json::from_str::<serde_json::Value>(json).unwrap()["type"]
+            );
+            let again: Event = serde_json::from_value(value.clone()).unwrap();
+            assert_eq!(again.event_type(), event.event_type());
+            assert_eq!(serde_json::to_value(&again).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn unknown_events_are_kept() {
+        let event =
+            callback(r#"{"type": "emoji_changed", "subtype": "add", "name": "picard"}"#).event;
+        assert_eq!(event.event_type(), Some("emoji_changed"));
+        assert!(matches!(event, Event::Other(ref json) if json["name"] == "picard"));
+
+        let event =
+            callback(r#"{"type": "message", "subtype": "huddle_thread", "channel": "C1"}"#).event;
+        assert!(matches!(event, Event::Other(_)));
+        assert_eq!(event.event_type(), Some("message"));
+    }
+}
@@ -0,0 +1,169 @@
+use std::borrow::Borrow;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+macro_rules! ids {
+    ($($(#[$attr:meta])* $name:ident($what:literal, $($prefix:literal)|+);)*) => {$(
+        $(#[$attr])*
+        ///
+        /// Parsing with [`FromStr`] or `new` checks the ID's prefix, while `new_unchecked` takes
+        /// the string as is. IDs are read from responses as is too, so that a new kind of ID
+        /// never makes a response fail to parse.
+        #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+        #[serde(transparent)]
+        pub struct $name(String);
+
+        impl $name {
+            /// The first letters that IDs of this kind start with.
+            pub const PREFIXES: &'static [char] = &[$($prefix),+];
+
+            /// Checks that `id` looks like an ID of this kind.
+            pub fn new<S: Into<String>>(id: S) -> Result<Self, ParseIdError> {
+                let id = id.into();
+                if is_valid(&id, Self::PREFIXES) {
+                    Ok($name(id))
+                } else {
+                    Err(ParseIdError($what))
+                }
+            }
+
+            /// Takes `id` as is, e.g. for a channel name where an ID or name is accepted, or an ID
+            /// read from a trusted source.
+            pub fn new_unchecked<S: Into<String>>(id: S) -> Self {
+                $name(id.into())
+            }
+
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            pub fn into_string(self) -> String {
+                self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ParseIdError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $name::new(s)
+            }
+        }
+
+        impl From<&$name> for $name {
+            fn from(id: &$name) -> Self {
+                id.clone()
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl Borrow<str> for $name {
+            fn borrow(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+    )*};
+}
+
+ids! {
+    /// The ID of a user, starting with `U`, or `W` for users of Enterprise Grid organizations.
+    UserId("user", 'U' | 'W');
+    /// The ID of a channel, starting with `C`, `G` for older private channels, or `D` for
+    /// direct messages.
+    ChannelId("channel", 'C' | 'G' | 'D');
+    /// The ID of a workspace, starting with `T`.
+    TeamId("team", 'T');
+    /// The ID of an Enterprise Grid organization, starting with `E`.
+    EnterpriseId("enterprise", 'E');
+    /// The ID of a bot, starting with `B`.
+    BotId("bot", 'B');
+    /// The ID of an app, starting with `A`.
+    AppId("app", 'A');
+    /// The ID of a file, starting with `F`.
+    FileId("file", 'F');
+    /// The ID of a user group, starting with `S`.
+    UsergroupId("user group", 'S');
+}
+
+/// Whether `id` starts with one of `prefixes` and goes on with uppercase letters and digits.
+fn is_valid(id: &str, prefixes: &[char]) -> bool {
+    let mut chars = id.chars();
+    matches!(chars.next(), Some(c) if prefixes.contains(&c))
+        && id.len() > 1
+        && chars.all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+}
+
+/// The error returned when parsing an ID whose prefix or characters do not match its kind.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseIdError(&'static str);
+
+impl fmt::Display for ParseIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid Slack {} ID", self.0)
+    }
+}
+
+impl Error for ParseIdError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn ids_are_checked_by_prefix() {
+        assert!("U012AB3CD".parse::<UserId>().is_ok());
+        assert!("W012AB3CD".parse::<UserId>().is_ok());
+        assert!("D012AB3CD".parse::<ChannelId>().is_ok());
+        assert!("S0614TZR7".parse::<UsergroupId>().is_ok());
+        assert_eq!(
+            "C012AB3CD".parse::<UserId>().unwrap_err().to_string(),
+            "invalid Slack user ID"
+        );
+        assert!("T".parse::<TeamId>().is_err());
+        assert!("Tlowercase".parse::<TeamId>().is_err());
+        assert!("".parse::<FileId>().is_err());
+        // Unchecked, for channel names and IDs from trusted sources.
+        assert_eq!(ChannelId::new_unchecked("#general"), "#general");
+    }
+
+    #[test]
+    fn ids_serialize_as_strings() {
+        let id: ChannelId = serde_json::from_str("\"C012AB3CD\"").unwrap();
+        assert_eq!(id.to_string(), "C012AB3CD");
+        assert_eq!(serde_json::to_string(&id).unwrap(), "\"C012AB3CD\"");
+
+        let names: HashMap<ChannelId, &str> = vec![(id, "general")].into_iter().collect();
+        assert_eq!(names.get("C012AB3CD"), Some(&"general"));
+    }
+}
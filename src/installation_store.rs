@@ -0,0 +1,544 @@
+//! Where apps distributed to many workspaces keep the tokens of each installation.
+//!
+//! An [`Installation`] is built from the `oauth.v2.access` response of an install and saved in an
+//! [`InstallationStore`] under the workspace, or the organization for org-wide installs, that it
+//! belongs to. Requests from Slack carry the `team_id`, `enterprise_id` and
+//! `is_enterprise_install` that [`InstallationKey::new`] turns back into the key to find it with.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::sync::Arc;
+//! use slack_api::installation_store::{Installation, InstallationStore, MemoryInstallationStore};
+//! use slack_api::token_rotation::RotatingToken;
+//!
+//! # let response = serde_json::from_str(r#"{"ok": true, "app_id": "A1", "authed_user": {"id": "U1"},
+//! #     "team": {"id": "T1", "name": "Team"}, "access_token": "xoxe.xoxb-1", "token_type": "bot",
+//! #     "refresh_token": "xoxe-1", "expires_in": 43200}"#).unwrap();
+//! let store = Arc::new(MemoryInstallationStore::new());
+//! // `response` is what `oauth::v_2::exchange_code` returned.
+//! let installation = Installation::from_access_response(&response);
+//! store.save(installation.clone()).unwrap();
+//!
+//! // Keep the stored bot token up to date as it rotates.
+//! let key = installation.key();
+//! let tokens = installation.bot_token_set().unwrap();
+//! let token = RotatingToken::new("123.456", "client secret", tokens).with_on_rotate({
+//!     let store = store.clone();
+//!     move |tokens| store.rotate(&key, None, tokens).unwrap()
+//! });
+//! ```
+
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::error::Error;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::time::SystemTime;
+
+use crate::mod_types::oauth::v_2_types::AccessResponse;
+use crate::token_rotation::TokenSet;
+use crate::{AppId, EnterpriseId, TeamId, UserId};
+
+/// The workspace, or the organization of an org-wide install, that an app is installed in.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct InstallationKey {
+    pub enterprise_id: Option<EnterpriseId>,
+    /// `None` for org-wide installs, which cover every workspace of the organization.
+    pub team_id: Option<TeamId>,
+}
+
+impl InstallationKey {
+    /// The key of the installation a request from Slack is for, from its `enterprise_id`,
+    /// `team_id` and `is_enterprise_install`.
+    pub fn new(
+        enterprise_id: Option<EnterpriseId>,
+        team_id: Option<TeamId>,
+        is_enterprise_install: bool,
+    ) -> Self {
+        InstallationKey {
+            team_id: if is_enterprise_install { None } else { team_id },
+            enterprise_id,
+        }
+    }
+}
+
+/// An app's installation in a workspace or organization, with its bot token and the tokens of
+/// the users who installed it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Installation {
+    pub app_id: AppId,
+    pub enterprise_id: Option<EnterpriseId>,
+    pub enterprise_name: Option<String>,
+    pub team_id: Option<TeamId>,
+    pub team_name: Option<String>,
+    pub is_enterprise_install: bool,
+    pub bot: Option<BotInstallation>,
+    pub users: Vec<UserInstallation>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BotInstallation {
+    pub user_id: Option<UserId>,
+    pub token: String,
+    pub scopes: Vec<String>,
+    /// Set for apps with token rotation enabled.
+    pub refresh_token: Option<String>,
+    pub expires_at: Option<SystemTime>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UserInstallation {
+    pub user_id: UserId,
+    pub token: String,
+    pub scopes: Vec<String>,
+    /// Set for apps with token rotation enabled.
+    pub refresh_token: Option<String>,
+    pub expires_at: Option<SystemTime>,
+}
+
+impl Installation {
+    /// The installation an `oauth.v2.access` response grants.
+    pub fn from_access_response(response: &AccessResponse) -> Self {
+        let now = SystemTime::now();
+        let expires_at = |expires_in: Option<u64>| {
+            expires_in.map(|secs| now + std::time::Duration::from_secs(secs))
+        };
+        let bot = response
+            .access_token
+            .as_ref()
+            .filter(|_| response.token_type.as_deref() != Some("user"))
+            .map(|token| BotInstallation {
+                user_id: response.bot_user_id.clone(),
+                token: token.clone(),
+                scopes: split_scopes(response.scope.as_deref()),
+                refresh_token: response.refresh_token.clone(),
+                expires_at: expires_at(response.expires_in),
+            });
+        let user = &response.authed_user;
+        let users = user
+            .access_token
+            .as_ref()
+            .map(|token| UserInstallation {
+                user_id: user.id.clone(),
+                token: token.clone(),
+                scopes: split_scopes(user.scope.as_deref()),
+                refresh_token: user.refresh_token.clone(),
+                expires_at: expires_at(user.expires_in),
+            })
+            .into_iter()
+            .collect();
+        Installation {
+            app_id: response.app_id.clone(),
+            enterprise_id: response.enterprise.as_ref().map(|e| e.id.clone()),
+            enterprise_name: response.enterprise.as_ref().map(|e| e.name.clone()),
+            team_id: response.team.as_ref().map(|t| t.id.clone()),
+            team_name: response.team.as_ref().map(|t| t.name.clone()),
+            is_enterprise_install: response.is_enterprise_install.unwrap_or(false),
+            bot,
+            users,
+        }
+    }
+
+    pub fn key(&self) -> InstallationKey {
+        InstallationKey::new(
+            self.enterprise_id.clone(),
+            self.team_id.clone(),
+            self.is_enterprise_install,
+        )
+    }
+
+    /// The token of the user `user_id`, if they installed the app.
+    pub fn user(&self, user_id: &UserId) -> Option<&UserInstallation> {
+        self.users.iter().find(|user| user.user_id == *user_id)
+    }
+
+    /// The rotating bot token, to start a `RotatingToken` from.
+    pub fn bot_token_set(&self) -> Option<TokenSet> {
+        let bot = self.bot.as_ref()?;
+        Some(TokenSet {
+            access_token: bot.token.clone(),
+            refresh_token: bot.refresh_token.clone()?,
+            expires_at: bot.expires_at?,
+        })
+    }
+
+    /// Takes the bot token of a newer install, unless it has none, and adds or replaces the
+    /// tokens of its users.
+    fn merge(&mut self, newer: Installation) {
+        let users = std::mem::take(&mut self.users);
+        let bot = self.bot.take();
+        *self = Installation {
+            bot: newer.bot.clone().or(bot),
+            users: users
+                .into_iter()
+                .filter(|user| newer.user(&user.user_id).is_none())
+                .chain(newer.users.iter().cloned())
+                .collect(),
+            ..newer
+        };
+    }
+}
+
+fn split_scopes(scopes: Option<&str>) -> Vec<String> {
+    scopes
+        .into_iter()
+        .flat_map(|scopes| scopes.split(','))
+        .filter(|scope| !scope.is_empty())
+        .map(str::to_owned)
+        .collect()
+}
+
+/// Keeps the installations of an app by workspace or organization.
+///
+/// `app_uninstalled` events are handled with [`delete`](Self::delete), `tokens_revoked` events
+/// with [`revoke`](Self::revoke), and the callback of a `RotatingToken` with
+/// [`rotate`](Self::rotate).
+pub trait InstallationStore {
+    type Error: Error;
+
+    /// Saves an installation. The bot token of an earlier installation in the same workspace or
+    /// organization is replaced, unless the new one has none, and so are the tokens of the users
+    /// who installed it again; those of other users are kept.
+    fn save(&self, installation: Installation) -> Result<(), Self::Error>;
+
+    fn find(&self, key: &InstallationKey) -> Result<Option<Installation>, Self::Error>;
+
+    /// Forgets the installation, as when the app was uninstalled.
+    fn delete(&self, key: &InstallationKey) -> Result<(), Self::Error>;
+
+    /// Replaces the rotated bot token, or the token of `user_id`, with `tokens`.
+    fn rotate(
+        &self,
+        key: &InstallationKey,
+        user_id: Option<&UserId>,
+        tokens: &TokenSet,
+    ) -> Result<(), Self::Error>;
+
+    /// Forgets the tokens of `user_ids` and, if `bot`, the bot token, as when they were revoked.
+    /// The installation is deleted once it has no token left.
+    fn revoke(
+        &self,
+        key: &InstallationKey,
+        user_ids: &[UserId],
+        bot: bool,
+    ) -> Result<(), Self::Error>;
+}
+
+/// An [`InstallationStore`] in memory, for tests and single-process apps.
+#[derive(Debug, Default)]
+pub struct MemoryInstallationStore {
+    installations: Mutex<HashMap<InstallationKey, Installation>>,
+}
+
+impl MemoryInstallationStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Every installation, in no particular order.
+    pub fn installations(&self) -> Vec<Installation> {
+        self.lock().values().cloned().collect()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<InstallationKey, Installation>> {
+        self.installations
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl InstallationStore for MemoryInstallationStore {
+    type Error = Infallible;
+
+    fn save(&self, installation: Installation) -> Result<(), Self::Error> {
+        let mut installations = self.lock();
+        match installations.get_mut(&installation.key()) {
+            Some(existing) => existing.merge(installation),
+            None => {
+                installations.insert(installation.key(), installation);
+            }
+        }
+        Ok(())
+    }
+
+    fn find(&self, key: &InstallationKey) -> Result<Option<Installation>, Self::Error> {
+        Ok(self.lock().get(key).cloned())
+    }
+
+    fn delete(&self, key: &InstallationKey) -> Result<(), Self::Error> {
+        self.lock().remove(key);
+        Ok(())
+    }
+
+    fn rotate(
+        &self,
+        key: &InstallationKey,
+        user_id: Option<&UserId>,
+        tokens: &TokenSet,
+    ) -> Result<(), Self::Error> {
+        let mut installations = self.lock();
+        let installation = match installations.get_mut(key) {
+            Some(installation) => installation,
+            None => return Ok(()),
+        };
+        match user_id {
+            Some(user_id) => {
+                if let Some(user) = installation
+                    .users
+                    .iter_mut()
+                    .find(|user| user.user_id == *user_id)
+                {
+                    user.token = tokens.access_token.clone();
+                    user.refresh_token = Some(tokens.refresh_token.clone());
+                    user.expires_at = Some(tokens.expires_at);
+                }
+            }
+            None => {
+                if let Some(ref mut bot) = installation.bot {
+                    bot.token = tokens.access_token.clone();
+                    bot.refresh_token = Some(tokens.refresh_token.clone());
+                    bot.expires_at = Some(tokens.expires_at);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn revoke(
+        &self,
+        key: &InstallationKey,
+        user_ids: &[UserId],
+        bot: bool,
+    ) -> Result<(), Self::Error> {
+        let mut installations = self.lock();
+        if let Some(installation) = installations.get_mut(key) {
+            installation
+                .users
+                .retain(|user| !user_ids.contains(&user.user_id));
+            if bot {
+                installation.bot = None;
+            }
+            if installation.bot.is_none() && installation.users.is_empty() {
+                installations.remove(key);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An [`InstallationStore`] that keeps every installation in one JSON file, rewritten on every
+/// change.
+///
+/// Suits apps running as a single process; the file is read once, by [`open`](Self::open).
+#[derive(Debug)]
+pub struct JsonFileInstallationStore {
+    path: PathBuf,
+    memory: MemoryInstallationStore,
+    writing: Mutex<()>,
+}
+
+impl JsonFileInstallationStore {
+    /// Opens the store at `path`, which is created on the first change if it does not exist.
+    pub fn open<P: Into<PathBuf>>(path: P) -> io::Result<Self> {
+        let path = path.into();
+        let memory = MemoryInstallationStore::new();
+        match fs::read(&path) {
+            Ok(json) => {
+                let installations: Vec<Installation> = serde_json::from_slice(&json)?;
+                memory.lock().extend(
+                    installations
+                        .into_iter()
+                        .map(|installation| (installation.key(), installation)),
+                );
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e),
+        }
+        Ok(JsonFileInstallationStore {
+            path,
+            memory,
+            writing: Mutex::new(()),
+        })
+    }
+
+    /// Applies `change` and writes the store, holding one lock throughout so that concurrent
+    /// changes reach the file in the order they were made.
+    fn change<F>(&self, change: F) -> io::Result<()>
+    where
+        F: FnOnce(&MemoryInstallationStore) -> Result<(), Infallible>,
+    {
+        let _writing = self.writing.lock().unwrap_or_else(PoisonError::into_inner);
+        infallible(change(&self.memory));
+        self.write()
+    }
+
+    /// Writes the installations to a temporary file next to the store, readable only by its
+    /// owner, then moves it over the store, so that a crash cannot leave it half written.
+    fn write(&self) -> io::Result<()> {
+        let mut installations = self.memory.installations();
+        installations
+            .sort_by(|a, b| (&a.enterprise_id, &a.team_id).cmp(&(&b.enterprise_id, &b.team_id)));
+        let json = serde_json::to_vec_pretty(&installations)?;
+        let mut tmp = self.path.clone().into_os_string();
+        tmp.push(".tmp");
+        let mut options = OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        let mut file = options.open(&tmp)?;
+        file.write_all(&json)?;
+        file.sync_all()?;
+        fs::rename(&tmp, &self.path)
+    }
+}
+
+impl InstallationStore for JsonFileInstallationStore {
+    type Error = io::Error;
+
+    fn save(&self, installation: Installation) -> Result<(), Self::Error> {
+        self.change(|memory| memory.save(installation))
+    }
+
+    fn find(&self, key: &InstallationKey) -> Result<Option<Installation>, Self::Error> {
+        Ok(infallible(self.memory.find(key)))
+    }
+
+    fn delete(&self, key: &InstallationKey) -> Result<(), Self::Error> {
+        self.change(|memory| memory.delete(key))
+    }
+
+    fn rotate(
+        &self,
+        key: &InstallationKey,
+        user_id: Option<&UserId>,
+        tokens: &TokenSet,
+    ) -> Result<(), Self::Error> {
+        self.change(|memory| memory.rotate(key, user_id, tokens))
+    }
+
+    fn revoke(
+        &self,
+        key: &InstallationKey,
+        user_ids: &[UserId],
+        bot: bool,
+    ) -> Result<(), Self::Error> {
+        self.change(|memory| memory.revoke(key, user_ids, bot))
+    }
+}
+
+fn infallible<T>(result: Result<T, Infallible>) -> T {
+    result.unwrap_or_else(|never| match never {})
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn install(team: &str, user: &str, bot_token: Option<&str>) -> Installation {
+        let bot = bot_token.map_or(String::new(), |token| {
+            format!(
+                r#""access_token": "{}", "token_type": "bot", "bot_user_id": "UB", "scope": "chat:write,commands","#,
+                token
+            )
+        });
+        let body = format!(
+            r#"{{"ok": true, {} "app_id": "A1", "team": {{"id": "{}", "name": "Team"}}, "authed_user": {{"id": "{}", "access_token": "xoxp-{}", "scope": "search:read"}}}}"#,
+            bot, team, user, user
+        );
+        Installation::from_access_response(&serde_json::from_str(&body).unwrap())
+    }
+
+    fn key(team: &str) -> InstallationKey {
+        InstallationKey::new(None, Some(team.parse().unwrap()), false)
+    }
+
+    #[test]
+    fn installation_from_access_response() {
+        let installation = install("T1", "U1", Some("xoxb-1"));
+        let bot = installation.bot.as_ref().unwrap();
+        assert_eq!(bot.token, "xoxb-1");
+        assert_eq!(bot.scopes, vec!["chat:write", "commands"]);
+        assert_eq!(
+            installation.user(&"U1".parse().unwrap()).unwrap().token,
+            "xoxp-U1"
+        );
+        assert_eq!(installation.key(), key("T1"));
+        assert!(installation.bot_token_set().is_none());
+
+        let org = InstallationKey::new(
+            Some("E1".parse().unwrap()),
+            Some("T1".parse().unwrap()),
+            true,
+        );
+        assert_eq!(org.team_id, None);
+    }
+
+    #[test]
+    fn save_merges_users_and_keeps_bot() {
+        let store = MemoryInstallationStore::new();
+        store.save(install("T1", "U1", Some("xoxb-1"))).unwrap();
+        store.save(install("T1", "U2", None)).unwrap();
+        store.save(install("T2", "U3", Some("xoxb-2"))).unwrap();
+
+        let installation = store.find(&key("T1")).unwrap().unwrap();
+        assert_eq!(installation.bot.unwrap().token, "xoxb-1");
+        assert_eq!(installation.users.len(), 2);
+        assert_eq!(store.installations().len(), 2);
+    }
+
+    #[test]
+    fn rotate_revoke_and_delete() {
+        let store = MemoryInstallationStore::new();
+        store.save(install("T1", "U1", Some("xoxb-1"))).unwrap();
+        let tokens = TokenSet {
+            access_token: "xoxe.xoxb-2".into(),
+            refresh_token: "xoxe-2".into(),
+            expires_at: SystemTime::UNIX_EPOCH,
+        };
+        store.rotate(&key("T1"), None, &tokens).unwrap();
+        let installation = store.find(&key("T1")).unwrap().unwrap();
+        assert_eq!(installation.bot_token_set(), Some(tokens));
+
+        store
+            .revoke(&key("T1"), &["U1".parse().unwrap()], false)
+            .unwrap();
+        assert!(store.find(&key("T1")).unwrap().unwrap().users.is_empty());
+        store.revoke(&key("T1"), &[], true).unwrap();
+        assert!(store.find(&key("T1")).unwrap().is_none());
+
+        store.save(install("T1", "U1", Some("xoxb-1"))).unwrap();
+        store.delete(&key("T1")).unwrap();
+        assert!(store.find(&key("T1")).unwrap().is_none());
+    }
+
+    #[test]
+    fn json_file_store_persists() {
+        let path = std::env::temp_dir().join(format!(
+            "slack_api_installations_{}.json",
+            std::process::id()
+        ));
+        let _ = fs::remove_file(&path);
+        {
+            let store = JsonFileInstallationStore::open(&path).unwrap();
+            store.save(install("T1", "U1", Some("xoxb-1"))).unwrap();
+            store.save(install("T2", "U2", Some("xoxb-2"))).unwrap();
+            store.delete(&key("T2")).unwrap();
+        }
+        let store = JsonFileInstallationStore::open(&path).unwrap();
+        assert_eq!(
+            store.find(&key("T1")).unwrap(),
+            Some(install("T1", "U1", Some("xoxb-1")))
+        );
+        assert!(store.find(&key("T2")).unwrap().is_none());
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+        fs::remove_file(&path).unwrap();
+    }
+}
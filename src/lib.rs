@@ -18,14 +18,53 @@
 #[macro_use]
 extern crate serde_derive;
 
+pub mod blocks;
+
+mod error;
+pub use crate::error::*;
+
+pub mod events;
+
+mod id;
+pub use crate::id::*;
+
+mod install;
+pub use crate::install::{InstallFlow, StateError, AUTHORIZE_URL, GOV_AUTHORIZE_URL};
+
+pub mod installation_store;
+
 mod mod_types;
 
+pub mod pagination;
+
+pub mod rate_limit;
+
+pub mod signature;
+
+mod sign_in;
+pub use crate::sign_in::{
+    IdTokenClaims, Jwk, Jwks, SignInFlow, OPENID_AUTHORIZE_URL, OPENID_ISSUER, OPENID_KEYS_URL,
+};
+#[cfg(feature = "openid")]
+pub use crate::sign_in::{IdTokenError, IdTokenVerifier};
+
+mod response;
+pub use crate::response::*;
+
+#[cfg(all(test, any(feature = "sync", feature = "async")))]
+mod testing;
+
 mod timestamp;
 pub use crate::timestamp::*;
 
+pub mod token_rotation;
+
 mod types;
 pub use crate::types::*;
 
+mod upload;
+pub use crate::upload::{FileUpload, SharedReader, UploadSource};
+
 #[cfg(feature = "async")]
 mod async_impl;
 
@@ -35,8 +74,31 @@ pub use async_impl::*;
 #[cfg(feature = "sync")]
 pub mod sync;
 
-fn get_slack_url_for_method(method: &str) -> String {
-    format!("https://slack.com/api/{}", method)
+/// The base URL of the Slack Web API, used unless a request sender overrides `base_url`.
+pub const SLACK_API_URL: &str = "https://slack.com/api";
+
+/// The base URL of the Slack Web API for GovSlack workspaces.
+pub const GOV_SLACK_API_URL: &str = "https://slack-gov.com/api";
+
+fn get_slack_url_for_method(base_url: &str, method: &str) -> String {
+    format!(
+        "{}/{}",
+        base_url.trim_end_matches('/'),
+        method.trim_start_matches('/')
+    )
+}
+
+/// Flattens a JSON body into form parameters for senders without a JSON path: strings are sent
+/// as is, and everything else, such as `blocks`, as its JSON encoding.
+fn json_body_to_form(body: &serde_json::Value) -> Vec<(String, String)> {
+    body.as_object()
+        .into_iter()
+        .flatten()
+        .map(|(name, value)| match value {
+            serde_json::Value::String(s) => (name.clone(), s.clone()),
+            value => (name.clone(), value.to_string()),
+        })
+        .collect()
 }
 
 fn optional_struct_or_empty_array<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
@@ -123,6 +185,232 @@ mod tests {
         assert!(user_profile.fields.is_none());
     }
 
+    #[test]
+    fn test_slack_url_for_custom_base_url() {
+        assert_eq!(
+            crate::get_slack_url_for_method("http://localhost:8080/api/", "/chat.postMessage"),
+            "http://localhost:8080/api/chat.postMessage"
+        );
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_generated_call_passes_token_and_keeps_http_info() {
+        use crate::sync::auth;
+        use crate::testing::{Scripted, Via};
+        use crate::{SlackResponse, SlackWebResponse};
+
+        let client = Scripted::new(|_| SlackWebResponse {
+            status: 200,
+            headers: vec![
+                ("x-oauth-scopes".into(), "users:read,chat:write".into()),
+                ("x-slack-req-id".into(), "abc123".into()),
+            ],
+            body: r#"{"ok": true, "team": "T", "team_id": "T1", "url": "u", "user": "U", "user_id": "U1"}"#.into(),
+        });
+        let response = auth::test(&client, "xoxb", &auth::TestRequest {}).unwrap();
+        assert_eq!(
+            response.http().oauth_scopes(),
+            vec!["users:read", "chat:write"]
+        );
+        assert_eq!(response.http().request_id(), Some("abc123"));
+        let call = &client.calls()[0];
+        assert_eq!(call.via, Via::Get);
+        assert_eq!(call.url, "https://slack.com/api/auth.test");
+        assert_eq!(call.token.as_deref(), Some("xoxb"));
+        assert!(call.params.is_empty());
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_generated_upload_sends_files_as_multipart() {
+        use crate::sync::users;
+        use crate::testing::{Scripted, Via};
+        use crate::FileUpload;
+
+        let client = Scripted::body(
+            r#"{"ok": true, "profile": {"avatar_hash": "h", "image_1024": "", "image_192": "", "image_24": "", "image_32": "", "image_48": "", "image_512": "", "image_72": "", "image_original": ""}}"#,
+        );
+        let request = users::SetPhotoRequest {
+            crop_w: Some("100".into()),
+            image: Some(FileUpload::bytes(&b"\x89PNG"[..]).with_filename("me.png")),
+            ..Default::default()
+        };
+        let response = users::set_photo(&client, "xoxp", &request).unwrap();
+        assert_eq!(response.profile.avatar_hash, "h");
+        let call = &client.calls()[0];
+        assert_eq!(call.via, Via::Multipart);
+        assert_eq!(call.url, "https://slack.com/api/users.setPhoto");
+        assert_eq!(call.token.as_deref(), Some("xoxp"));
+        assert_eq!(call.params, vec![("crop_w".to_owned(), "100".to_owned())]);
+        assert_eq!(
+            call.files,
+            vec![(
+                "image".to_owned(),
+                Some("me.png".to_owned()),
+                b"\x89PNG".to_vec()
+            )]
+        );
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_generated_upload_without_files_posts_form() {
+        use crate::sync::files;
+        use crate::testing::{FormOnly, Scripted, Via};
+
+        let client = FormOnly(Scripted::body(r#"{"ok": true, "file": {}}"#));
+        let request = files::UploadRequest {
+            content: Some("hello".into()),
+            filename: Some("hello.txt".into()),
+            ..Default::default()
+        };
+        files::upload(&client, Some("xoxb"), &request).unwrap();
+        let call = &client.0.calls()[0];
+        assert_eq!(call.via, Via::Post);
+        assert_eq!(call.url, "https://slack.com/api/files.upload");
+        assert_eq!(call.param("content"), Some("hello"));
+        assert!(call.files.is_empty());
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_senders_without_multipart_report_unsupported() {
+        use crate::sync::users;
+        use crate::testing::{FormOnly, Scripted};
+        use crate::{FileUpload, MethodError};
+
+        let client = FormOnly(Scripted::body(r#"{"ok": true}"#));
+        let request = users::SetPhotoRequest {
+            image: Some(FileUpload::bytes(&b"\x89PNG"[..])),
+            ..Default::default()
+        };
+        let error = users::set_photo(&client, "xoxp", &request).unwrap_err();
+        assert_eq!(error.code(), Some(crate::MULTIPART_UNSUPPORTED));
+        assert!(client.0.calls().is_empty());
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_generated_chat_call_sends_json_body() {
+        use crate::sync::chat;
+        use crate::testing::{Scripted, Via};
+
+        let client = Scripted::body(
+            r#"{"ok": true, "channel": "C1", "message": {"text": "hi", "ts": "1.2", "type": "message"}, "ts": "1.2"}"#,
+        );
+        let request = chat::PostMessageRequest {
+            blocks: Some(vec![crate::blocks::DividerBlock::new().into()]),
+            channel: Some("C1".parse().unwrap()),
+            mrkdwn: Some(false),
+            text: "hi".into(),
+            ..Default::default()
+        };
+        let response = chat::post_message(&client, "xoxb", &request).unwrap();
+        assert_eq!(response.ts.to_param_value(), "1.200000");
+        let call = &client.calls()[0];
+        assert_eq!(call.via, Via::Json);
+        assert_eq!(call.url, "https://slack.com/api/chat.postMessage");
+        assert_eq!(call.token.as_deref(), Some("xoxb"));
+        assert_eq!(
+            call.json,
+            Some(serde_json::json!({
+                "blocks": [{"type": "divider"}],
+                "channel": "C1",
+                "mrkdwn": false,
+                "text": "hi",
+            }))
+        );
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_generated_call_reports_warnings() {
+        use crate::sync::{chat, WithWarningHook};
+        use crate::testing::Scripted;
+        use crate::SlackResponse;
+        use std::cell::RefCell;
+
+        let reported = RefCell::new(Vec::new());
+        let client = WithWarningHook::new(
+            Scripted::body(
+                r#"{"ok": true, "channel": "C1", "message": {"text": "hi", "ts": "1.2", "type": "message"}, "ts": "1.2", "warning": "message_truncated", "response_metadata": {"warnings": ["message_truncated", "superfluous_charset"]}}"#,
+            ),
+            |method, warnings| {
+                reported
+                    .borrow_mut()
+                    .push(format!("{}: {}", method, warnings.join(",")))
+            },
+        );
+        let request = chat::PostMessageRequest {
+            channel: Some("C1".parse().unwrap()),
+            text: "hi".into(),
+            ..Default::default()
+        };
+        let response = chat::post_message(&client, "xoxb", &request).unwrap();
+        assert_eq!(
+            response.warnings(),
+            vec!["message_truncated", "superfluous_charset"]
+        );
+        assert_eq!(
+            reported.into_inner(),
+            vec!["chat.postMessage: message_truncated,superfluous_charset"]
+        );
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_install_flow_exchanges_code_with_client_auth() {
+        use crate::sync::oauth::v_2::{exchange_code, InstallError, StateError};
+        use crate::testing::{Scripted, Via};
+        use crate::InstallFlow;
+
+        let client = Scripted::body(
+            r#"{"ok": true, "access_token": "xoxb-1", "app_id": "A1", "authed_user": {"id": "U1"}, "team": {"id": "T1", "name": "Team"}}"#,
+        );
+        let flow =
+            InstallFlow::new("123.456", "secret").with_redirect_uri("https://example.com/oauth");
+        let state = flow.new_state();
+        let response = exchange_code(&client, &flow, "c0de", &state, &state).unwrap();
+        assert_eq!(response.access_token.as_deref(), Some("xoxb-1"));
+        assert_eq!(response.team.unwrap().id, "T1");
+        let call = &client.calls()[0];
+        assert_eq!(call.via, Via::ClientAuth);
+        assert_eq!(call.url, "https://slack.com/api/oauth.v2.access");
+        assert_eq!(
+            call.client_auth,
+            Some(("123.456".to_owned(), "secret".to_owned()))
+        );
+        assert_eq!(call.param("code"), Some("c0de"));
+        assert_eq!(call.param("redirect_uri"), Some("https://example.com/oauth"));
+
+        assert!(matches!(
+            exchange_code(&client, &flow, "c0de", "forged", "forged"),
+            Err(InstallError::State(StateError::Invalid))
+        ));
+        assert!(matches!(
+            exchange_code(&client, &flow, "c0de", &flow.new_state(), &state),
+            Err(InstallError::State(StateError::Mismatch))
+        ));
+        assert_eq!(client.calls().len(), 1);
+    }
+
+    #[test]
+    fn test_json_body_to_form_encodes_structured_members() {
+        let body =
+            serde_json::json!({"blocks": [{"type": "divider"}], "text": "hi", "mrkdwn": false});
+        let mut form = crate::json_body_to_form(&body);
+        form.sort();
+        assert_eq!(
+            form,
+            vec![
+                ("blocks".to_owned(), r#"[{"type":"divider"}]"#.to_owned()),
+                ("mrkdwn".to_owned(), "false".to_owned()),
+                ("text".to_owned(), "hi".to_owned()),
+            ]
+        );
+    }
+
     #[test]
     fn test_timestamp_to_param_value() {
         assert_eq!(
@@ -154,4 +442,144 @@ mod tests {
             m => panic!("expected Message::Standard but got {:?}", m),
         };
     }
+
+    #[test]
+    fn test_timestamp_of_last_read() {
+        let channel: crate::Channel =
+            serde_json::from_str(r#"{"id": "C1", "last_read": "1588861564.009805"}"#).unwrap();
+        assert_eq!(
+            channel.last_read.unwrap().to_param_value(),
+            "1588861564.009805"
+        );
+    }
+
+    #[test]
+    fn test_message_serialize_round_trip() {
+        let messages = [
+            r#"{"type": "message", "text": "hi", "user": "U1", "ts": "1588861564.009805"}"#,
+            r#"{"type": "message", "subtype": "bot_message", "bot_id": "B1", "text": "hi", "ts": "1588861564.009805"}"#,
+            r#"{"type": "message", "subtype": "channel_join", "user": "U1", "text": "<@U1> has joined the channel", "ts": "1588861564.009805"}"#,
+        ];
+        for msg in messages.iter() {
+            let message: crate::Message = serde_json::from_str(msg).unwrap();
+            let value = serde_json::to_value(&message).unwrap();
+            assert_eq!(
+                value.get("subtype"),
+                serde_json::from_str::<serde_json::Value>(msg)
+                    .unwrap()
+                    .get("subtype")
+            );
+            assert_eq!(value["ts"], "1588861564.009805");
+            let again: crate::Message = serde_json::from_value(value.clone()).unwrap();
+            assert_eq!(serde_json::to_value(&again).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn test_every_message_subtype_round_trips() {
+        let subtypes = [
+            "bot_add",
+            "bot_disable",
+            "bot_enable",
+            "bot_remove",
+            "bot_message",
+            "channel_archive",
+            "channel_join",
+            "channel_leave",
+            "channel_name",
+            "channel_purpose",
+            "channel_topic",
+            "channel_unarchive",
+            "file_comment",
+            "file_mention",
+            "file_share",
+            "group_archive",
+            "group_join",
+            "group_leave",
+            "group_name",
+            "group_purpose",
+            "group_topic",
+            "group_unarchive",
+            "me_message",
+            "message_changed",
+            "message_deleted",
+            "message_replied",
+            "pinned_item",
+            "reminder_add",
+            "reply_broadcast",
+            "thread_broadcast",
+            "unpinned_item",
+            "sh_room_created",
+            "slackbot_response",
+        ];
+        for subtype in subtypes.iter() {
+            let msg = serde_json::json!({"type": "message", "subtype": subtype, "ts": "1.000001"});
+            let message: crate::Message = serde_json::from_value(msg).unwrap();
+            assert!(!matches!(message, crate::Message::Standard(_)));
+            let value = serde_json::to_value(&message).unwrap();
+            assert_eq!(value["subtype"], *subtype);
+            let again: crate::Message = serde_json::from_value(value.clone()).unwrap();
+            assert_eq!(serde_json::to_value(&again).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn test_message_serialize_puts_subtype_of_variant() {
+        let join: crate::MessageChannelJoin =
+            serde_json::from_str(r#"{"user": "U1", "text": "hi"}"#).unwrap();
+        let value = serde_json::to_value(crate::Message::ChannelJoin(join)).unwrap();
+        assert_eq!(value["subtype"], "channel_join");
+        match serde_json::from_value(value).unwrap() {
+            crate::Message::ChannelJoin(join) => assert_eq!(join.user.as_deref(), Some("U1")),
+            m => panic!("expected Message::ChannelJoin but got {:?}", m),
+        }
+    }
+
+    /// Parses `body` as `T`, checks that serializing it gives back what it parsed, and returns the
+    /// serialized JSON.
+    fn response_round_trip<T>(body: &str) -> serde_json::Value
+    where
+        T: serde::de::DeserializeOwned + serde::Serialize,
+    {
+        let response: T = serde_json::from_str(body).unwrap();
+        let value = serde_json::to_value(&response).unwrap();
+        let again: T = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&again).unwrap(), value);
+        value
+    }
+
+    #[test]
+    fn test_response_serialize_round_trip() {
+        use crate::mod_types::conversations_types as conversations;
+        use crate::mod_types::views_types as views;
+        use crate::mod_types::{chat, team};
+
+        let value = response_round_trip::<chat::PostMessageResponse>(
+            r#"{"ok": true, "channel": "C1", "message": {"text": "hi", "ts": "1588861564.009805", "type": "message"}, "ts": "1588861564.009805"}"#,
+        );
+        assert_eq!(value["ts"], "1588861564.009805");
+        assert_eq!(value["message"]["text"], "hi");
+
+        let value = response_round_trip::<conversations::HistoryResponse>(
+            r#"{"ok": true, "channel_actions_count": 0, "has_more": false, "pin_count": 0, "messages": [{"text": "hi", "ts": "1588861564.009805", "type": "message"}], "response_metadata": {"next_cursor": "bmV4dA=="}}"#,
+        );
+        assert_eq!(value["messages"][0]["ts"], "1588861564.009805");
+        assert_eq!(value["response_metadata"]["next_cursor"], "bmV4dA==");
+
+        let value = response_round_trip::<conversations::RepliesResponse>(
+            r#"{"ok": true, "messages": [{"text": "hi", "thread_ts": "1588861564.009805", "ts": "1588861565.000001", "type": "message"}]}"#,
+        );
+        assert_eq!(value["messages"][0]["thread_ts"], "1588861564.009805");
+
+        let value = response_round_trip::<team::AccessLogsResponse>(
+            r#"{"ok": true, "logins": [{"count": 1, "country": "US", "date_first": 1422922864, "date_last": 1422922864, "ip": "127.0.0.1", "isp": "isp", "region": "CA", "user_agent": "agent", "user_id": "U1", "username": "spengler"}], "paging": {"page": 1, "pages": 1, "total": 1}}"#,
+        );
+        assert_eq!(value["logins"][0]["user_id"], "U1");
+
+        let value = response_round_trip::<views::PublishResponse>(
+            r#"{"ok": true, "view": {"id": "V1", "team_id": "T1", "type": "home", "hash": "1.abc", "blocks": [{"type": "divider"}]}}"#,
+        );
+        assert_eq!(value["view"]["type"], "home");
+        assert_eq!(value["view"]["blocks"][0]["type"], "divider");
+    }
 }
@@ -25,16 +25,30 @@ use std::fmt;
 pub struct ListRequest<'a> {
     /// Set `cursor` to `next_cursor` returned by the previous call to list items in the next page
     pub cursor: Option<Cow<'a, str>>,
-    pub enterprise_id: Option<Cow<'a, str>>,
+    pub enterprise_id: Option<crate::EnterpriseId>,
     /// The maximum number of items to return. Must be between 1 - 1000 both inclusive.
     pub limit: Option<u64>,
-    pub team_id: Option<Cow<'a, str>>,
+    pub team_id: Option<crate::TeamId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl crate::pagination::CursorRequest for ListRequest<'_> {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor.into());
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponse {
+    pub approved_apps: Option<Vec<serde_json::Value>>,
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
@@ -42,28 +56,75 @@ impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(ListError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(ListError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for ListResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
+impl crate::pagination::CursorPage for ListResponse {
+    type Item = serde_json::Value;
+
+    fn next_cursor(&self) -> Option<&str> {
+        self.response_metadata
+            .as_ref()
+            .and_then(|response_metadata| response_metadata.next_cursor.as_deref())
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.approved_apps.unwrap_or_default()
+    }
+}
+
 #[derive(Debug)]
 pub enum ListError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> ListError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => ListError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for ListError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => ListError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -71,7 +132,7 @@ impl<E: Error> fmt::Display for ListError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             ListError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            ListError::Unknown(ref s) => write!(f, "{}", s),
+            ListError::Unknown(ref s, _) => write!(f, "{}", s),
             ListError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -86,3 +147,29 @@ impl<E: Error + 'static> Error for ListError<E> {
         }
     }
 }
+
+impl<E: Error + 'static> crate::MethodError for ListError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            ListError::Unknown(ref s, _) => Some(s),
+            ListError::MalformedResponse(..) | ListError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            ListError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            ListError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            ListError::Unknown(_, ref context) => Some(context),
+            ListError::MalformedResponse(..) | ListError::Client(_) => None,
+        }
+    }
+}
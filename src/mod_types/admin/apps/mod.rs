@@ -28,18 +28,25 @@ use std::fmt;
 #[derive(Clone, Default, Debug)]
 pub struct ApproveRequest<'a> {
     /// The id of the app to approve.
-    pub app_id: Option<Cow<'a, str>>,
+    pub app_id: Option<crate::AppId>,
     /// The ID of the enterprise to approve the app on
-    pub enterprise_id: Option<Cow<'a, str>>,
+    pub enterprise_id: Option<crate::EnterpriseId>,
     /// The id of the request to approve.
     pub request_id: Option<Cow<'a, str>>,
-    pub team_id: Option<Cow<'a, str>>,
+    pub team_id: Option<crate::TeamId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ApproveResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<ApproveResponse, ApproveError<E>>> for ApproveResponse {
@@ -47,28 +54,61 @@ impl<E: Error> Into<Result<ApproveResponse, ApproveError<E>>> for ApproveRespons
         if self.ok {
             Ok(self)
         } else {
-            Err(ApproveError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(ApproveError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for ApproveResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum ApproveError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> ApproveError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => ApproveError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for ApproveError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => ApproveError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -76,7 +116,7 @@ impl<E: Error> fmt::Display for ApproveError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             ApproveError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            ApproveError::Unknown(ref s) => write!(f, "{}", s),
+            ApproveError::Unknown(ref s, _) => write!(f, "{}", s),
             ApproveError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -92,19 +132,52 @@ impl<E: Error + 'static> Error for ApproveError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for ApproveError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            ApproveError::Unknown(ref s, _) => Some(s),
+            ApproveError::MalformedResponse(..) | ApproveError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            ApproveError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            ApproveError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            ApproveError::Unknown(_, ref context) => Some(context),
+            ApproveError::MalformedResponse(..) | ApproveError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct RestrictRequest<'a> {
     /// The id of the app to restrict.
-    pub app_id: Option<Cow<'a, str>>,
+    pub app_id: Option<crate::AppId>,
     /// The id of the request to restrict.
     pub request_id: Option<Cow<'a, str>>,
-    pub team_id: Option<Cow<'a, str>>,
+    pub team_id: Option<crate::TeamId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RestrictResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<RestrictResponse, RestrictError<E>>> for RestrictResponse {
@@ -112,28 +185,61 @@ impl<E: Error> Into<Result<RestrictResponse, RestrictError<E>>> for RestrictResp
         if self.ok {
             Ok(self)
         } else {
-            Err(RestrictError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(RestrictError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for RestrictResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum RestrictError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> RestrictError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => RestrictError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for RestrictError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => RestrictError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -141,7 +247,7 @@ impl<E: Error> fmt::Display for RestrictError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             RestrictError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            RestrictError::Unknown(ref s) => write!(f, "{}", s),
+            RestrictError::Unknown(ref s, _) => write!(f, "{}", s),
             RestrictError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -156,3 +262,29 @@ impl<E: Error + 'static> Error for RestrictError<E> {
         }
     }
 }
+
+impl<E: Error + 'static> crate::MethodError for RestrictError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            RestrictError::Unknown(ref s, _) => Some(s),
+            RestrictError::MalformedResponse(..) | RestrictError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            RestrictError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            RestrictError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            RestrictError::Unknown(_, ref context) => Some(context),
+            RestrictError::MalformedResponse(..) | RestrictError::Client(_) => None,
+        }
+    }
+}
@@ -33,10 +33,24 @@ pub struct ListOriginalConnectedChannelInfoRequest<'a> {
     pub team_ids: Option<Cow<'a, str>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl crate::pagination::CursorRequest for ListOriginalConnectedChannelInfoRequest<'_> {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor.into());
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListOriginalConnectedChannelInfoResponse {
+    pub channels: Option<Vec<serde_json::Value>>,
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error>
@@ -50,28 +64,75 @@ impl<E: Error>
         if self.ok {
             Ok(self)
         } else {
-            Err(ListOriginalConnectedChannelInfoError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(ListOriginalConnectedChannelInfoError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for ListOriginalConnectedChannelInfoResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
+impl crate::pagination::CursorPage for ListOriginalConnectedChannelInfoResponse {
+    type Item = serde_json::Value;
+
+    fn next_cursor(&self) -> Option<&str> {
+        self.response_metadata
+            .as_ref()
+            .and_then(|response_metadata| response_metadata.next_cursor.as_deref())
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.channels.unwrap_or_default()
+    }
+}
+
 #[derive(Debug)]
 pub enum ListOriginalConnectedChannelInfoError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> ListOriginalConnectedChannelInfoError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => ListOriginalConnectedChannelInfoError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for ListOriginalConnectedChannelInfoError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => ListOriginalConnectedChannelInfoError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -81,7 +142,7 @@ impl<E: Error> fmt::Display for ListOriginalConnectedChannelInfoError<E> {
             ListOriginalConnectedChannelInfoError::MalformedResponse(_, ref e) => {
                 write!(f, "{}", e)
             }
-            ListOriginalConnectedChannelInfoError::Unknown(ref s) => write!(f, "{}", s),
+            ListOriginalConnectedChannelInfoError::Unknown(ref s, _) => write!(f, "{}", s),
             ListOriginalConnectedChannelInfoError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -96,3 +157,33 @@ impl<E: Error + 'static> Error for ListOriginalConnectedChannelInfoError<E> {
         }
     }
 }
+
+impl<E: Error + 'static> crate::MethodError for ListOriginalConnectedChannelInfoError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            ListOriginalConnectedChannelInfoError::Unknown(ref s, _) => Some(s),
+            ListOriginalConnectedChannelInfoError::MalformedResponse(..)
+            | ListOriginalConnectedChannelInfoError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            ListOriginalConnectedChannelInfoError::MalformedResponse(..) => {
+                crate::ErrorKind::Malformed
+            }
+            ListOriginalConnectedChannelInfoError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            ListOriginalConnectedChannelInfoError::Unknown(_, ref context) => Some(context),
+            ListOriginalConnectedChannelInfoError::MalformedResponse(..)
+            | ListOriginalConnectedChannelInfoError::Client(_) => None,
+        }
+    }
+}
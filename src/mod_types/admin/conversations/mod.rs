@@ -25,16 +25,22 @@ use std::error::Error;
 use std::fmt;
 
 #[derive(Clone, Default, Debug)]
-pub struct ArchiveRequest<'a> {
+pub struct ArchiveRequest {
     /// The channel to archive.
-    pub channel_id: Cow<'a, str>,
+    pub channel_id: Option<crate::ChannelId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ArchiveResponse {
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<ArchiveResponse, ArchiveError<E>>> for ArchiveResponse {
@@ -42,68 +48,107 @@ impl<E: Error> Into<Result<ArchiveResponse, ArchiveError<E>>> for ArchiveRespons
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(ArchiveError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for ArchiveResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum ArchiveError<E: Error> {
-    AlreadyArchived,
-    CantArchiveGeneral,
-    ChannelNotFound,
-    ChannelTypeNotSupported,
-    CouldNotArchiveChannel,
-    DefaultOrgWideChannel,
-    FeatureNotEnabled,
-    RestrictedAction,
+    AlreadyArchived(crate::ErrorContext),
+    CantArchiveGeneral(crate::ErrorContext),
+    ChannelNotFound(crate::ErrorContext),
+    ChannelTypeNotSupported(crate::ErrorContext),
+    CouldNotArchiveChannel(crate::ErrorContext),
+    DefaultOrgWideChannel(crate::ErrorContext),
+    FeatureNotEnabled(crate::ErrorContext),
+    RestrictedAction(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> ArchiveError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "already_archived" => ArchiveError::AlreadyArchived(context),
+            "cant_archive_general" => ArchiveError::CantArchiveGeneral(context),
+            "channel_not_found" => ArchiveError::ChannelNotFound(context),
+            "channel_type_not_supported" => ArchiveError::ChannelTypeNotSupported(context),
+            "could_not_archive_channel" => ArchiveError::CouldNotArchiveChannel(context),
+            "default_org_wide_channel" => ArchiveError::DefaultOrgWideChannel(context),
+            "feature_not_enabled" => ArchiveError::FeatureNotEnabled(context),
+            "restricted_action" => ArchiveError::RestrictedAction(context),
+            _ => ArchiveError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for ArchiveError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "already_archived" => ArchiveError::AlreadyArchived,
-            "cant_archive_general" => ArchiveError::CantArchiveGeneral,
-            "channel_not_found" => ArchiveError::ChannelNotFound,
-            "channel_type_not_supported" => ArchiveError::ChannelTypeNotSupported,
-            "could_not_archive_channel" => ArchiveError::CouldNotArchiveChannel,
-            "default_org_wide_channel" => ArchiveError::DefaultOrgWideChannel,
-            "feature_not_enabled" => ArchiveError::FeatureNotEnabled,
-            "restricted_action" => ArchiveError::RestrictedAction,
-            _ => ArchiveError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for ArchiveError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            ArchiveError::AlreadyArchived => write!(f, "Server returned error already_archived"),
-            ArchiveError::CantArchiveGeneral => {
+            ArchiveError::AlreadyArchived(_) => write!(f, "Server returned error already_archived"),
+            ArchiveError::CantArchiveGeneral(_) => {
                 write!(f, "Server returned error cant_archive_general")
             }
-            ArchiveError::ChannelNotFound => write!(f, "Server returned error channel_not_found"),
-            ArchiveError::ChannelTypeNotSupported => {
+            ArchiveError::ChannelNotFound(_) => {
+                write!(f, "Server returned error channel_not_found")
+            }
+            ArchiveError::ChannelTypeNotSupported(_) => {
                 write!(f, "Server returned error channel_type_not_supported")
             }
-            ArchiveError::CouldNotArchiveChannel => {
+            ArchiveError::CouldNotArchiveChannel(_) => {
                 write!(f, "Server returned error could_not_archive_channel")
             }
-            ArchiveError::DefaultOrgWideChannel => {
+            ArchiveError::DefaultOrgWideChannel(_) => {
                 write!(f, "Server returned error default_org_wide_channel")
             }
-            ArchiveError::FeatureNotEnabled => {
+            ArchiveError::FeatureNotEnabled(_) => {
                 write!(f, "Server returned error feature_not_enabled")
             }
-            ArchiveError::RestrictedAction => write!(f, "Server returned error restricted_action"),
+            ArchiveError::RestrictedAction(_) => {
+                write!(f, "Server returned error restricted_action")
+            }
             ArchiveError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            ArchiveError::Unknown(ref s) => write!(f, "{}", s),
+            ArchiveError::Unknown(ref s, _) => write!(f, "{}", s),
             ArchiveError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -119,17 +164,65 @@ impl<E: Error + 'static> Error for ArchiveError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for ArchiveError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            ArchiveError::AlreadyArchived(_) => Some("already_archived"),
+            ArchiveError::CantArchiveGeneral(_) => Some("cant_archive_general"),
+            ArchiveError::ChannelNotFound(_) => Some("channel_not_found"),
+            ArchiveError::ChannelTypeNotSupported(_) => Some("channel_type_not_supported"),
+            ArchiveError::CouldNotArchiveChannel(_) => Some("could_not_archive_channel"),
+            ArchiveError::DefaultOrgWideChannel(_) => Some("default_org_wide_channel"),
+            ArchiveError::FeatureNotEnabled(_) => Some("feature_not_enabled"),
+            ArchiveError::RestrictedAction(_) => Some("restricted_action"),
+            ArchiveError::Unknown(ref s, _) => Some(s),
+            ArchiveError::MalformedResponse(..) | ArchiveError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            ArchiveError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            ArchiveError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            ArchiveError::AlreadyArchived(ref context)
+            | ArchiveError::CantArchiveGeneral(ref context)
+            | ArchiveError::ChannelNotFound(ref context)
+            | ArchiveError::ChannelTypeNotSupported(ref context)
+            | ArchiveError::CouldNotArchiveChannel(ref context)
+            | ArchiveError::DefaultOrgWideChannel(ref context)
+            | ArchiveError::FeatureNotEnabled(ref context)
+            | ArchiveError::RestrictedAction(ref context)
+            | ArchiveError::Unknown(_, ref context) => Some(context),
+            ArchiveError::MalformedResponse(..) | ArchiveError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
-pub struct ConvertToPrivateRequest<'a> {
+pub struct ConvertToPrivateRequest {
     /// The channel to convert to private.
-    pub channel_id: Cow<'a, str>,
+    pub channel_id: Option<crate::ChannelId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ConvertToPrivateResponse {
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<ConvertToPrivateResponse, ConvertToPrivateError<E>>>
@@ -139,80 +232,117 @@ impl<E: Error> Into<Result<ConvertToPrivateResponse, ConvertToPrivateError<E>>>
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(ConvertToPrivateError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for ConvertToPrivateResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum ConvertToPrivateError<E: Error> {
-    ChannelNotFound,
-    ChannelTypeNotSupported,
-    CouldNotConvertChannel,
-    DefaultOrgWideChannel,
-    ExternalChannelMigrating,
-    FeatureNotEnabled,
-    MethodNotSupportedForChannelType,
-    NameTaken,
-    RestrictedAction,
+    ChannelNotFound(crate::ErrorContext),
+    ChannelTypeNotSupported(crate::ErrorContext),
+    CouldNotConvertChannel(crate::ErrorContext),
+    DefaultOrgWideChannel(crate::ErrorContext),
+    ExternalChannelMigrating(crate::ErrorContext),
+    FeatureNotEnabled(crate::ErrorContext),
+    MethodNotSupportedForChannelType(crate::ErrorContext),
+    NameTaken(crate::ErrorContext),
+    RestrictedAction(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
-impl<'a, E: Error> From<&'a str> for ConvertToPrivateError<E> {
-    fn from(s: &'a str) -> Self {
-        match s {
-            "channel_not_found" => ConvertToPrivateError::ChannelNotFound,
-            "channel_type_not_supported" => ConvertToPrivateError::ChannelTypeNotSupported,
-            "could_not_convert_channel" => ConvertToPrivateError::CouldNotConvertChannel,
-            "default_org_wide_channel" => ConvertToPrivateError::DefaultOrgWideChannel,
-            "external_channel_migrating" => ConvertToPrivateError::ExternalChannelMigrating,
-            "feature_not_enabled" => ConvertToPrivateError::FeatureNotEnabled,
+impl<E: Error> ConvertToPrivateError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "channel_not_found" => ConvertToPrivateError::ChannelNotFound(context),
+            "channel_type_not_supported" => ConvertToPrivateError::ChannelTypeNotSupported(context),
+            "could_not_convert_channel" => ConvertToPrivateError::CouldNotConvertChannel(context),
+            "default_org_wide_channel" => ConvertToPrivateError::DefaultOrgWideChannel(context),
+            "external_channel_migrating" => {
+                ConvertToPrivateError::ExternalChannelMigrating(context)
+            }
+            "feature_not_enabled" => ConvertToPrivateError::FeatureNotEnabled(context),
             "method_not_supported_for_channel_type" => {
-                ConvertToPrivateError::MethodNotSupportedForChannelType
+                ConvertToPrivateError::MethodNotSupportedForChannelType(context)
             }
-            "name_taken" => ConvertToPrivateError::NameTaken,
-            "restricted_action" => ConvertToPrivateError::RestrictedAction,
-            _ => ConvertToPrivateError::Unknown(s.to_owned()),
+            "name_taken" => ConvertToPrivateError::NameTaken(context),
+            "restricted_action" => ConvertToPrivateError::RestrictedAction(context),
+            _ => ConvertToPrivateError::Unknown(code.to_owned(), context),
         }
     }
 }
 
+impl<'a, E: Error> From<&'a str> for ConvertToPrivateError<E> {
+    fn from(s: &'a str) -> Self {
+        Self::new(s, crate::ErrorContext::default())
+    }
+}
+
 impl<E: Error> fmt::Display for ConvertToPrivateError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            ConvertToPrivateError::ChannelNotFound => {
+            ConvertToPrivateError::ChannelNotFound(_) => {
                 write!(f, "Server returned error channel_not_found")
             }
-            ConvertToPrivateError::ChannelTypeNotSupported => {
+            ConvertToPrivateError::ChannelTypeNotSupported(_) => {
                 write!(f, "Server returned error channel_type_not_supported")
             }
-            ConvertToPrivateError::CouldNotConvertChannel => {
+            ConvertToPrivateError::CouldNotConvertChannel(_) => {
                 write!(f, "Server returned error could_not_convert_channel")
             }
-            ConvertToPrivateError::DefaultOrgWideChannel => {
+            ConvertToPrivateError::DefaultOrgWideChannel(_) => {
                 write!(f, "Server returned error default_org_wide_channel")
             }
-            ConvertToPrivateError::ExternalChannelMigrating => {
+            ConvertToPrivateError::ExternalChannelMigrating(_) => {
                 write!(f, "Server returned error external_channel_migrating")
             }
-            ConvertToPrivateError::FeatureNotEnabled => {
+            ConvertToPrivateError::FeatureNotEnabled(_) => {
                 write!(f, "Server returned error feature_not_enabled")
             }
-            ConvertToPrivateError::MethodNotSupportedForChannelType => write!(
+            ConvertToPrivateError::MethodNotSupportedForChannelType(_) => write!(
                 f,
                 "Server returned error method_not_supported_for_channel_type"
             ),
-            ConvertToPrivateError::NameTaken => write!(f, "Server returned error name_taken"),
-            ConvertToPrivateError::RestrictedAction => {
+            ConvertToPrivateError::NameTaken(_) => write!(f, "Server returned error name_taken"),
+            ConvertToPrivateError::RestrictedAction(_) => {
                 write!(f, "Server returned error restricted_action")
             }
             ConvertToPrivateError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            ConvertToPrivateError::Unknown(ref s) => write!(f, "{}", s),
+            ConvertToPrivateError::Unknown(ref s, _) => write!(f, "{}", s),
             ConvertToPrivateError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -228,6 +358,54 @@ impl<E: Error + 'static> Error for ConvertToPrivateError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for ConvertToPrivateError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            ConvertToPrivateError::ChannelNotFound(_) => Some("channel_not_found"),
+            ConvertToPrivateError::ChannelTypeNotSupported(_) => Some("channel_type_not_supported"),
+            ConvertToPrivateError::CouldNotConvertChannel(_) => Some("could_not_convert_channel"),
+            ConvertToPrivateError::DefaultOrgWideChannel(_) => Some("default_org_wide_channel"),
+            ConvertToPrivateError::ExternalChannelMigrating(_) => {
+                Some("external_channel_migrating")
+            }
+            ConvertToPrivateError::FeatureNotEnabled(_) => Some("feature_not_enabled"),
+            ConvertToPrivateError::MethodNotSupportedForChannelType(_) => {
+                Some("method_not_supported_for_channel_type")
+            }
+            ConvertToPrivateError::NameTaken(_) => Some("name_taken"),
+            ConvertToPrivateError::RestrictedAction(_) => Some("restricted_action"),
+            ConvertToPrivateError::Unknown(ref s, _) => Some(s),
+            ConvertToPrivateError::MalformedResponse(..) | ConvertToPrivateError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            ConvertToPrivateError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            ConvertToPrivateError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            ConvertToPrivateError::ChannelNotFound(ref context)
+            | ConvertToPrivateError::ChannelTypeNotSupported(ref context)
+            | ConvertToPrivateError::CouldNotConvertChannel(ref context)
+            | ConvertToPrivateError::DefaultOrgWideChannel(ref context)
+            | ConvertToPrivateError::ExternalChannelMigrating(ref context)
+            | ConvertToPrivateError::FeatureNotEnabled(ref context)
+            | ConvertToPrivateError::MethodNotSupportedForChannelType(ref context)
+            | ConvertToPrivateError::NameTaken(ref context)
+            | ConvertToPrivateError::RestrictedAction(ref context)
+            | ConvertToPrivateError::Unknown(_, ref context) => Some(context),
+            ConvertToPrivateError::MalformedResponse(..) | ConvertToPrivateError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct CreateRequest<'a> {
     /// Description of the public or private channel to create.
@@ -239,15 +417,21 @@ pub struct CreateRequest<'a> {
     /// When `true`, the channel will be available org-wide. Note: if the channel is not `org_wide=true`, you must specify a `team_id` for this channel
     pub org_wide: Option<bool>,
     /// The workspace to create the channel in. Note: this argument is required unless you set `org_wide=true`.
-    pub team_id: Option<Cow<'a, str>>,
+    pub team_id: Option<crate::TeamId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreateResponse {
-    pub channel_id: Option<String>,
+    pub channel_id: Option<crate::ChannelId>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<CreateResponse, CreateError<E>>> for CreateResponse {
@@ -255,64 +439,101 @@ impl<E: Error> Into<Result<CreateResponse, CreateError<E>>> for CreateResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(CreateError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for CreateResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum CreateError<E: Error> {
-    CouldNotCreateChannel,
-    FeatureNotEnabled,
-    InvalidName,
-    InvalidTeam,
-    NameTaken,
-    RestrictedAction,
-    TeamIdOrOrgRequired,
-    TeamNotFound,
+    CouldNotCreateChannel(crate::ErrorContext),
+    FeatureNotEnabled(crate::ErrorContext),
+    InvalidName(crate::ErrorContext),
+    InvalidTeam(crate::ErrorContext),
+    NameTaken(crate::ErrorContext),
+    RestrictedAction(crate::ErrorContext),
+    TeamIdOrOrgRequired(crate::ErrorContext),
+    TeamNotFound(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> CreateError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "could_not_create_channel" => CreateError::CouldNotCreateChannel(context),
+            "feature_not_enabled" => CreateError::FeatureNotEnabled(context),
+            "invalid_name" => CreateError::InvalidName(context),
+            "invalid_team" => CreateError::InvalidTeam(context),
+            "name_taken" => CreateError::NameTaken(context),
+            "restricted_action" => CreateError::RestrictedAction(context),
+            "team_id_or_org_required" => CreateError::TeamIdOrOrgRequired(context),
+            "team_not_found" => CreateError::TeamNotFound(context),
+            _ => CreateError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for CreateError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "could_not_create_channel" => CreateError::CouldNotCreateChannel,
-            "feature_not_enabled" => CreateError::FeatureNotEnabled,
-            "invalid_name" => CreateError::InvalidName,
-            "invalid_team" => CreateError::InvalidTeam,
-            "name_taken" => CreateError::NameTaken,
-            "restricted_action" => CreateError::RestrictedAction,
-            "team_id_or_org_required" => CreateError::TeamIdOrOrgRequired,
-            "team_not_found" => CreateError::TeamNotFound,
-            _ => CreateError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for CreateError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            CreateError::CouldNotCreateChannel => {
+            CreateError::CouldNotCreateChannel(_) => {
                 write!(f, "Server returned error could_not_create_channel")
             }
-            CreateError::FeatureNotEnabled => {
+            CreateError::FeatureNotEnabled(_) => {
                 write!(f, "Server returned error feature_not_enabled")
             }
-            CreateError::InvalidName => write!(f, "Server returned error invalid_name"),
-            CreateError::InvalidTeam => write!(f, "Server returned error invalid_team"),
-            CreateError::NameTaken => write!(f, "Server returned error name_taken"),
-            CreateError::RestrictedAction => write!(f, "Server returned error restricted_action"),
-            CreateError::TeamIdOrOrgRequired => {
+            CreateError::InvalidName(_) => write!(f, "Server returned error invalid_name"),
+            CreateError::InvalidTeam(_) => write!(f, "Server returned error invalid_team"),
+            CreateError::NameTaken(_) => write!(f, "Server returned error name_taken"),
+            CreateError::RestrictedAction(_) => {
+                write!(f, "Server returned error restricted_action")
+            }
+            CreateError::TeamIdOrOrgRequired(_) => {
                 write!(f, "Server returned error team_id_or_org_required")
             }
-            CreateError::TeamNotFound => write!(f, "Server returned error team_not_found"),
+            CreateError::TeamNotFound(_) => write!(f, "Server returned error team_not_found"),
             CreateError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            CreateError::Unknown(ref s) => write!(f, "{}", s),
+            CreateError::Unknown(ref s, _) => write!(f, "{}", s),
             CreateError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -328,17 +549,65 @@ impl<E: Error + 'static> Error for CreateError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for CreateError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            CreateError::CouldNotCreateChannel(_) => Some("could_not_create_channel"),
+            CreateError::FeatureNotEnabled(_) => Some("feature_not_enabled"),
+            CreateError::InvalidName(_) => Some("invalid_name"),
+            CreateError::InvalidTeam(_) => Some("invalid_team"),
+            CreateError::NameTaken(_) => Some("name_taken"),
+            CreateError::RestrictedAction(_) => Some("restricted_action"),
+            CreateError::TeamIdOrOrgRequired(_) => Some("team_id_or_org_required"),
+            CreateError::TeamNotFound(_) => Some("team_not_found"),
+            CreateError::Unknown(ref s, _) => Some(s),
+            CreateError::MalformedResponse(..) | CreateError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            CreateError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            CreateError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            CreateError::CouldNotCreateChannel(ref context)
+            | CreateError::FeatureNotEnabled(ref context)
+            | CreateError::InvalidName(ref context)
+            | CreateError::InvalidTeam(ref context)
+            | CreateError::NameTaken(ref context)
+            | CreateError::RestrictedAction(ref context)
+            | CreateError::TeamIdOrOrgRequired(ref context)
+            | CreateError::TeamNotFound(ref context)
+            | CreateError::Unknown(_, ref context) => Some(context),
+            CreateError::MalformedResponse(..) | CreateError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
-pub struct DeleteRequest<'a> {
+pub struct DeleteRequest {
     /// The channel to delete.
-    pub channel_id: Cow<'a, str>,
+    pub channel_id: Option<crate::ChannelId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct DeleteResponse {
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<DeleteResponse, DeleteError<E>>> for DeleteResponse {
@@ -346,66 +615,103 @@ impl<E: Error> Into<Result<DeleteResponse, DeleteError<E>>> for DeleteResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(DeleteError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for DeleteResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum DeleteError<E: Error> {
-    ChannelNotFound,
-    ChannelTypeNotSupported,
-    CouldNotDeleteChannel,
-    DefaultOrgWideChannel,
-    FeatureNotEnabled,
-    MissingScope,
-    NotAnAdmin,
-    RestrictedAction,
+    ChannelNotFound(crate::ErrorContext),
+    ChannelTypeNotSupported(crate::ErrorContext),
+    CouldNotDeleteChannel(crate::ErrorContext),
+    DefaultOrgWideChannel(crate::ErrorContext),
+    FeatureNotEnabled(crate::ErrorContext),
+    MissingScope(crate::ErrorContext),
+    NotAnAdmin(crate::ErrorContext),
+    RestrictedAction(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> DeleteError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "channel_not_found" => DeleteError::ChannelNotFound(context),
+            "channel_type_not_supported" => DeleteError::ChannelTypeNotSupported(context),
+            "could_not_delete_channel" => DeleteError::CouldNotDeleteChannel(context),
+            "default_org_wide_channel" => DeleteError::DefaultOrgWideChannel(context),
+            "feature_not_enabled" => DeleteError::FeatureNotEnabled(context),
+            "missing_scope" => DeleteError::MissingScope(context),
+            "not_an_admin" => DeleteError::NotAnAdmin(context),
+            "restricted_action" => DeleteError::RestrictedAction(context),
+            _ => DeleteError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for DeleteError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "channel_not_found" => DeleteError::ChannelNotFound,
-            "channel_type_not_supported" => DeleteError::ChannelTypeNotSupported,
-            "could_not_delete_channel" => DeleteError::CouldNotDeleteChannel,
-            "default_org_wide_channel" => DeleteError::DefaultOrgWideChannel,
-            "feature_not_enabled" => DeleteError::FeatureNotEnabled,
-            "missing_scope" => DeleteError::MissingScope,
-            "not_an_admin" => DeleteError::NotAnAdmin,
-            "restricted_action" => DeleteError::RestrictedAction,
-            _ => DeleteError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for DeleteError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            DeleteError::ChannelNotFound => write!(f, "Server returned error channel_not_found"),
-            DeleteError::ChannelTypeNotSupported => {
+            DeleteError::ChannelNotFound(_) => write!(f, "Server returned error channel_not_found"),
+            DeleteError::ChannelTypeNotSupported(_) => {
                 write!(f, "Server returned error channel_type_not_supported")
             }
-            DeleteError::CouldNotDeleteChannel => {
+            DeleteError::CouldNotDeleteChannel(_) => {
                 write!(f, "Server returned error could_not_delete_channel")
             }
-            DeleteError::DefaultOrgWideChannel => {
+            DeleteError::DefaultOrgWideChannel(_) => {
                 write!(f, "Server returned error default_org_wide_channel")
             }
-            DeleteError::FeatureNotEnabled => {
+            DeleteError::FeatureNotEnabled(_) => {
                 write!(f, "Server returned error feature_not_enabled")
             }
-            DeleteError::MissingScope => write!(f, "Server returned error missing_scope"),
-            DeleteError::NotAnAdmin => write!(f, "Server returned error not_an_admin"),
-            DeleteError::RestrictedAction => write!(f, "Server returned error restricted_action"),
+            DeleteError::MissingScope(_) => write!(f, "Server returned error missing_scope"),
+            DeleteError::NotAnAdmin(_) => write!(f, "Server returned error not_an_admin"),
+            DeleteError::RestrictedAction(_) => {
+                write!(f, "Server returned error restricted_action")
+            }
             DeleteError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            DeleteError::Unknown(ref s) => write!(f, "{}", s),
+            DeleteError::Unknown(ref s, _) => write!(f, "{}", s),
             DeleteError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -421,19 +727,67 @@ impl<E: Error + 'static> Error for DeleteError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for DeleteError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            DeleteError::ChannelNotFound(_) => Some("channel_not_found"),
+            DeleteError::ChannelTypeNotSupported(_) => Some("channel_type_not_supported"),
+            DeleteError::CouldNotDeleteChannel(_) => Some("could_not_delete_channel"),
+            DeleteError::DefaultOrgWideChannel(_) => Some("default_org_wide_channel"),
+            DeleteError::FeatureNotEnabled(_) => Some("feature_not_enabled"),
+            DeleteError::MissingScope(_) => Some("missing_scope"),
+            DeleteError::NotAnAdmin(_) => Some("not_an_admin"),
+            DeleteError::RestrictedAction(_) => Some("restricted_action"),
+            DeleteError::Unknown(ref s, _) => Some(s),
+            DeleteError::MalformedResponse(..) | DeleteError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            DeleteError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            DeleteError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            DeleteError::ChannelNotFound(ref context)
+            | DeleteError::ChannelTypeNotSupported(ref context)
+            | DeleteError::CouldNotDeleteChannel(ref context)
+            | DeleteError::DefaultOrgWideChannel(ref context)
+            | DeleteError::FeatureNotEnabled(ref context)
+            | DeleteError::MissingScope(ref context)
+            | DeleteError::NotAnAdmin(ref context)
+            | DeleteError::RestrictedAction(ref context)
+            | DeleteError::Unknown(_, ref context) => Some(context),
+            DeleteError::MalformedResponse(..) | DeleteError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct DisconnectSharedRequest<'a> {
     /// The channel to be disconnected from some workspaces.
-    pub channel_id: Cow<'a, str>,
+    pub channel_id: Option<crate::ChannelId>,
     /// The team to be removed from the channel. Currently only a single team id can be specified.
     pub leaving_team_ids: Option<Cow<'a, str>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct DisconnectSharedResponse {
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<DisconnectSharedResponse, DisconnectSharedError<E>>>
@@ -443,93 +797,134 @@ impl<E: Error> Into<Result<DisconnectSharedResponse, DisconnectSharedError<E>>>
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(DisconnectSharedError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for DisconnectSharedResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum DisconnectSharedError<E: Error> {
-    CannotKickHomeTeam,
-    CannotKickTeam,
-    ChannelNotFound,
-    FeatureNotEnabled,
-    LeavingTeamNotInChannel,
-    LeavingTeamRequired,
-    MissingScope,
-    NoTeamsToDisconnect,
-    NotAnAdmin,
-    NotAnEnterprise,
-    NotSupported,
-    RestrictedAction,
-    TeamNotFound,
+    CannotKickHomeTeam(crate::ErrorContext),
+    CannotKickTeam(crate::ErrorContext),
+    ChannelNotFound(crate::ErrorContext),
+    FeatureNotEnabled(crate::ErrorContext),
+    LeavingTeamNotInChannel(crate::ErrorContext),
+    LeavingTeamRequired(crate::ErrorContext),
+    MissingScope(crate::ErrorContext),
+    NoTeamsToDisconnect(crate::ErrorContext),
+    NotAnAdmin(crate::ErrorContext),
+    NotAnEnterprise(crate::ErrorContext),
+    NotSupported(crate::ErrorContext),
+    RestrictedAction(crate::ErrorContext),
+    TeamNotFound(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> DisconnectSharedError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "cannot_kick_home_team" => DisconnectSharedError::CannotKickHomeTeam(context),
+            "cannot_kick_team" => DisconnectSharedError::CannotKickTeam(context),
+            "channel_not_found" => DisconnectSharedError::ChannelNotFound(context),
+            "feature_not_enabled" => DisconnectSharedError::FeatureNotEnabled(context),
+            "leaving_team_not_in_channel" => {
+                DisconnectSharedError::LeavingTeamNotInChannel(context)
+            }
+            "leaving_team_required" => DisconnectSharedError::LeavingTeamRequired(context),
+            "missing_scope" => DisconnectSharedError::MissingScope(context),
+            "no_teams_to_disconnect" => DisconnectSharedError::NoTeamsToDisconnect(context),
+            "not_an_admin" => DisconnectSharedError::NotAnAdmin(context),
+            "not_an_enterprise" => DisconnectSharedError::NotAnEnterprise(context),
+            "not_supported" => DisconnectSharedError::NotSupported(context),
+            "restricted_action" => DisconnectSharedError::RestrictedAction(context),
+            "team_not_found" => DisconnectSharedError::TeamNotFound(context),
+            _ => DisconnectSharedError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for DisconnectSharedError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "cannot_kick_home_team" => DisconnectSharedError::CannotKickHomeTeam,
-            "cannot_kick_team" => DisconnectSharedError::CannotKickTeam,
-            "channel_not_found" => DisconnectSharedError::ChannelNotFound,
-            "feature_not_enabled" => DisconnectSharedError::FeatureNotEnabled,
-            "leaving_team_not_in_channel" => DisconnectSharedError::LeavingTeamNotInChannel,
-            "leaving_team_required" => DisconnectSharedError::LeavingTeamRequired,
-            "missing_scope" => DisconnectSharedError::MissingScope,
-            "no_teams_to_disconnect" => DisconnectSharedError::NoTeamsToDisconnect,
-            "not_an_admin" => DisconnectSharedError::NotAnAdmin,
-            "not_an_enterprise" => DisconnectSharedError::NotAnEnterprise,
-            "not_supported" => DisconnectSharedError::NotSupported,
-            "restricted_action" => DisconnectSharedError::RestrictedAction,
-            "team_not_found" => DisconnectSharedError::TeamNotFound,
-            _ => DisconnectSharedError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for DisconnectSharedError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            DisconnectSharedError::CannotKickHomeTeam => {
+            DisconnectSharedError::CannotKickHomeTeam(_) => {
                 write!(f, "Server returned error cannot_kick_home_team")
             }
-            DisconnectSharedError::CannotKickTeam => {
+            DisconnectSharedError::CannotKickTeam(_) => {
                 write!(f, "Server returned error cannot_kick_team")
             }
-            DisconnectSharedError::ChannelNotFound => {
+            DisconnectSharedError::ChannelNotFound(_) => {
                 write!(f, "Server returned error channel_not_found")
             }
-            DisconnectSharedError::FeatureNotEnabled => {
+            DisconnectSharedError::FeatureNotEnabled(_) => {
                 write!(f, "Server returned error feature_not_enabled")
             }
-            DisconnectSharedError::LeavingTeamNotInChannel => {
+            DisconnectSharedError::LeavingTeamNotInChannel(_) => {
                 write!(f, "Server returned error leaving_team_not_in_channel")
             }
-            DisconnectSharedError::LeavingTeamRequired => {
+            DisconnectSharedError::LeavingTeamRequired(_) => {
                 write!(f, "Server returned error leaving_team_required")
             }
-            DisconnectSharedError::MissingScope => write!(f, "Server returned error missing_scope"),
-            DisconnectSharedError::NoTeamsToDisconnect => {
+            DisconnectSharedError::MissingScope(_) => {
+                write!(f, "Server returned error missing_scope")
+            }
+            DisconnectSharedError::NoTeamsToDisconnect(_) => {
                 write!(f, "Server returned error no_teams_to_disconnect")
             }
-            DisconnectSharedError::NotAnAdmin => write!(f, "Server returned error not_an_admin"),
-            DisconnectSharedError::NotAnEnterprise => {
+            DisconnectSharedError::NotAnAdmin(_) => write!(f, "Server returned error not_an_admin"),
+            DisconnectSharedError::NotAnEnterprise(_) => {
                 write!(f, "Server returned error not_an_enterprise")
             }
-            DisconnectSharedError::NotSupported => write!(f, "Server returned error not_supported"),
-            DisconnectSharedError::RestrictedAction => {
+            DisconnectSharedError::NotSupported(_) => {
+                write!(f, "Server returned error not_supported")
+            }
+            DisconnectSharedError::RestrictedAction(_) => {
                 write!(f, "Server returned error restricted_action")
             }
-            DisconnectSharedError::TeamNotFound => {
+            DisconnectSharedError::TeamNotFound(_) => {
                 write!(f, "Server returned error team_not_found")
             }
             DisconnectSharedError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            DisconnectSharedError::Unknown(ref s) => write!(f, "{}", s),
+            DisconnectSharedError::Unknown(ref s, _) => write!(f, "{}", s),
             DisconnectSharedError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -545,36 +940,96 @@ impl<E: Error + 'static> Error for DisconnectSharedError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for DisconnectSharedError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            DisconnectSharedError::CannotKickHomeTeam(_) => Some("cannot_kick_home_team"),
+            DisconnectSharedError::CannotKickTeam(_) => Some("cannot_kick_team"),
+            DisconnectSharedError::ChannelNotFound(_) => Some("channel_not_found"),
+            DisconnectSharedError::FeatureNotEnabled(_) => Some("feature_not_enabled"),
+            DisconnectSharedError::LeavingTeamNotInChannel(_) => {
+                Some("leaving_team_not_in_channel")
+            }
+            DisconnectSharedError::LeavingTeamRequired(_) => Some("leaving_team_required"),
+            DisconnectSharedError::MissingScope(_) => Some("missing_scope"),
+            DisconnectSharedError::NoTeamsToDisconnect(_) => Some("no_teams_to_disconnect"),
+            DisconnectSharedError::NotAnAdmin(_) => Some("not_an_admin"),
+            DisconnectSharedError::NotAnEnterprise(_) => Some("not_an_enterprise"),
+            DisconnectSharedError::NotSupported(_) => Some("not_supported"),
+            DisconnectSharedError::RestrictedAction(_) => Some("restricted_action"),
+            DisconnectSharedError::TeamNotFound(_) => Some("team_not_found"),
+            DisconnectSharedError::Unknown(ref s, _) => Some(s),
+            DisconnectSharedError::MalformedResponse(..) | DisconnectSharedError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            DisconnectSharedError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            DisconnectSharedError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            DisconnectSharedError::CannotKickHomeTeam(ref context)
+            | DisconnectSharedError::CannotKickTeam(ref context)
+            | DisconnectSharedError::ChannelNotFound(ref context)
+            | DisconnectSharedError::FeatureNotEnabled(ref context)
+            | DisconnectSharedError::LeavingTeamNotInChannel(ref context)
+            | DisconnectSharedError::LeavingTeamRequired(ref context)
+            | DisconnectSharedError::MissingScope(ref context)
+            | DisconnectSharedError::NoTeamsToDisconnect(ref context)
+            | DisconnectSharedError::NotAnAdmin(ref context)
+            | DisconnectSharedError::NotAnEnterprise(ref context)
+            | DisconnectSharedError::NotSupported(ref context)
+            | DisconnectSharedError::RestrictedAction(ref context)
+            | DisconnectSharedError::TeamNotFound(ref context)
+            | DisconnectSharedError::Unknown(_, ref context) => Some(context),
+            DisconnectSharedError::MalformedResponse(..) | DisconnectSharedError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
-pub struct GetConversationPrefsRequest<'a> {
+pub struct GetConversationPrefsRequest {
     /// The channel to get preferences for.
-    pub channel_id: Cow<'a, str>,
+    pub channel_id: Option<crate::ChannelId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GetConversationPrefsCanThreadInner {
     pub r#type: Option<Vec<String>>,
     pub user: Option<Vec<String>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GetConversationPrefsWhoCanPostInner {
     pub r#type: Option<Vec<String>>,
     pub user: Option<Vec<String>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GetConversationPrefsPrefsInner {
     pub can_thread: Option<GetConversationPrefsCanThreadInner>,
     pub who_can_post: Option<GetConversationPrefsWhoCanPostInner>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GetConversationPrefsResponse {
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
     pub prefs: Option<GetConversationPrefsPrefsInner>,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<GetConversationPrefsResponse, GetConversationPrefsError<E>>>
@@ -584,76 +1039,113 @@ impl<E: Error> Into<Result<GetConversationPrefsResponse, GetConversationPrefsErr
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(GetConversationPrefsError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for GetConversationPrefsResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum GetConversationPrefsError<E: Error> {
-    ChannelNotFound,
-    ChannelTypeNotSupported,
-    CouldNotGetConversationPrefs,
-    FeatureNotEnabled,
-    MissingScope,
-    NotAnAdmin,
-    NotAnEnterprise,
-    RestrictedAction,
+    ChannelNotFound(crate::ErrorContext),
+    ChannelTypeNotSupported(crate::ErrorContext),
+    CouldNotGetConversationPrefs(crate::ErrorContext),
+    FeatureNotEnabled(crate::ErrorContext),
+    MissingScope(crate::ErrorContext),
+    NotAnAdmin(crate::ErrorContext),
+    NotAnEnterprise(crate::ErrorContext),
+    RestrictedAction(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
-impl<'a, E: Error> From<&'a str> for GetConversationPrefsError<E> {
-    fn from(s: &'a str) -> Self {
-        match s {
-            "channel_not_found" => GetConversationPrefsError::ChannelNotFound,
-            "channel_type_not_supported" => GetConversationPrefsError::ChannelTypeNotSupported,
-            "could_not_get_conversation_prefs" => {
-                GetConversationPrefsError::CouldNotGetConversationPrefs
+impl<E: Error> GetConversationPrefsError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "channel_not_found" => GetConversationPrefsError::ChannelNotFound(context),
+            "channel_type_not_supported" => {
+                GetConversationPrefsError::ChannelTypeNotSupported(context)
             }
-            "feature_not_enabled" => GetConversationPrefsError::FeatureNotEnabled,
-            "missing_scope" => GetConversationPrefsError::MissingScope,
-            "not_an_admin" => GetConversationPrefsError::NotAnAdmin,
-            "not_an_enterprise" => GetConversationPrefsError::NotAnEnterprise,
-            "restricted_action" => GetConversationPrefsError::RestrictedAction,
-            _ => GetConversationPrefsError::Unknown(s.to_owned()),
+            "could_not_get_conversation_prefs" => {
+                GetConversationPrefsError::CouldNotGetConversationPrefs(context)
+            }
+            "feature_not_enabled" => GetConversationPrefsError::FeatureNotEnabled(context),
+            "missing_scope" => GetConversationPrefsError::MissingScope(context),
+            "not_an_admin" => GetConversationPrefsError::NotAnAdmin(context),
+            "not_an_enterprise" => GetConversationPrefsError::NotAnEnterprise(context),
+            "restricted_action" => GetConversationPrefsError::RestrictedAction(context),
+            _ => GetConversationPrefsError::Unknown(code.to_owned(), context),
         }
     }
 }
 
+impl<'a, E: Error> From<&'a str> for GetConversationPrefsError<E> {
+    fn from(s: &'a str) -> Self {
+        Self::new(s, crate::ErrorContext::default())
+    }
+}
+
 impl<E: Error> fmt::Display for GetConversationPrefsError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            GetConversationPrefsError::ChannelNotFound => {
+            GetConversationPrefsError::ChannelNotFound(_) => {
                 write!(f, "Server returned error channel_not_found")
             }
-            GetConversationPrefsError::ChannelTypeNotSupported => {
+            GetConversationPrefsError::ChannelTypeNotSupported(_) => {
                 write!(f, "Server returned error channel_type_not_supported")
             }
-            GetConversationPrefsError::CouldNotGetConversationPrefs => {
+            GetConversationPrefsError::CouldNotGetConversationPrefs(_) => {
                 write!(f, "Server returned error could_not_get_conversation_prefs")
             }
-            GetConversationPrefsError::FeatureNotEnabled => {
+            GetConversationPrefsError::FeatureNotEnabled(_) => {
                 write!(f, "Server returned error feature_not_enabled")
             }
-            GetConversationPrefsError::MissingScope => {
+            GetConversationPrefsError::MissingScope(_) => {
                 write!(f, "Server returned error missing_scope")
             }
-            GetConversationPrefsError::NotAnAdmin => {
+            GetConversationPrefsError::NotAnAdmin(_) => {
                 write!(f, "Server returned error not_an_admin")
             }
-            GetConversationPrefsError::NotAnEnterprise => {
+            GetConversationPrefsError::NotAnEnterprise(_) => {
                 write!(f, "Server returned error not_an_enterprise")
             }
-            GetConversationPrefsError::RestrictedAction => {
+            GetConversationPrefsError::RestrictedAction(_) => {
                 write!(f, "Server returned error restricted_action")
             }
             GetConversationPrefsError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            GetConversationPrefsError::Unknown(ref s) => write!(f, "{}", s),
+            GetConversationPrefsError::Unknown(ref s, _) => write!(f, "{}", s),
             GetConversationPrefsError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -669,28 +1161,89 @@ impl<E: Error + 'static> Error for GetConversationPrefsError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for GetConversationPrefsError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            GetConversationPrefsError::ChannelNotFound(_) => Some("channel_not_found"),
+            GetConversationPrefsError::ChannelTypeNotSupported(_) => {
+                Some("channel_type_not_supported")
+            }
+            GetConversationPrefsError::CouldNotGetConversationPrefs(_) => {
+                Some("could_not_get_conversation_prefs")
+            }
+            GetConversationPrefsError::FeatureNotEnabled(_) => Some("feature_not_enabled"),
+            GetConversationPrefsError::MissingScope(_) => Some("missing_scope"),
+            GetConversationPrefsError::NotAnAdmin(_) => Some("not_an_admin"),
+            GetConversationPrefsError::NotAnEnterprise(_) => Some("not_an_enterprise"),
+            GetConversationPrefsError::RestrictedAction(_) => Some("restricted_action"),
+            GetConversationPrefsError::Unknown(ref s, _) => Some(s),
+            GetConversationPrefsError::MalformedResponse(..)
+            | GetConversationPrefsError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            GetConversationPrefsError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            GetConversationPrefsError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            GetConversationPrefsError::ChannelNotFound(ref context)
+            | GetConversationPrefsError::ChannelTypeNotSupported(ref context)
+            | GetConversationPrefsError::CouldNotGetConversationPrefs(ref context)
+            | GetConversationPrefsError::FeatureNotEnabled(ref context)
+            | GetConversationPrefsError::MissingScope(ref context)
+            | GetConversationPrefsError::NotAnAdmin(ref context)
+            | GetConversationPrefsError::NotAnEnterprise(ref context)
+            | GetConversationPrefsError::RestrictedAction(ref context)
+            | GetConversationPrefsError::Unknown(_, ref context) => Some(context),
+            GetConversationPrefsError::MalformedResponse(..)
+            | GetConversationPrefsError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct GetTeamsRequest<'a> {
     /// The channel to determine connected workspaces within the organization for.
-    pub channel_id: Cow<'a, str>,
+    pub channel_id: Option<crate::ChannelId>,
     /// Set `cursor` to `next_cursor` returned by the previous call to list items in the next page
     pub cursor: Option<Cow<'a, str>>,
     /// The maximum number of items to return. Must be between 1 - 1000 both inclusive.
     pub limit: Option<u64>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl crate::pagination::CursorRequest for GetTeamsRequest<'_> {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor.into());
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GetTeamsResponseMetadataInner {
+    pub messages: Option<Vec<String>>,
     pub next_cursor: String,
+    pub warnings: Option<Vec<String>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GetTeamsResponse {
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
     pub response_metadata: Option<GetTeamsResponseMetadataInner>,
     pub team_ids: Vec<String>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<GetTeamsResponse, GetTeamsError<E>>> for GetTeamsResponse {
@@ -698,66 +1251,119 @@ impl<E: Error> Into<Result<GetTeamsResponse, GetTeamsError<E>>> for GetTeamsResp
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(GetTeamsError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for GetTeamsResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
+impl crate::pagination::CursorPage for GetTeamsResponse {
+    type Item = String;
+
+    fn next_cursor(&self) -> Option<&str> {
+        self.response_metadata
+            .as_ref()
+            .map(|response_metadata| response_metadata.next_cursor.as_str())
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.team_ids
+    }
+}
+
 #[derive(Debug)]
 pub enum GetTeamsError<E: Error> {
-    ChannelNotFound,
-    ChannelTypeNotSupported,
-    CouldNotGetTeams,
-    FeatureNotEnabled,
-    InvalidCursor,
-    InvalidLimit,
-    RestrictedAction,
-    UnsupportedTeamType,
+    ChannelNotFound(crate::ErrorContext),
+    ChannelTypeNotSupported(crate::ErrorContext),
+    CouldNotGetTeams(crate::ErrorContext),
+    FeatureNotEnabled(crate::ErrorContext),
+    InvalidCursor(crate::ErrorContext),
+    InvalidLimit(crate::ErrorContext),
+    RestrictedAction(crate::ErrorContext),
+    UnsupportedTeamType(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> GetTeamsError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "channel_not_found" => GetTeamsError::ChannelNotFound(context),
+            "channel_type_not_supported" => GetTeamsError::ChannelTypeNotSupported(context),
+            "could_not_get_teams" => GetTeamsError::CouldNotGetTeams(context),
+            "feature_not_enabled" => GetTeamsError::FeatureNotEnabled(context),
+            "invalid_cursor" => GetTeamsError::InvalidCursor(context),
+            "invalid_limit" => GetTeamsError::InvalidLimit(context),
+            "restricted_action" => GetTeamsError::RestrictedAction(context),
+            "unsupported_team_type" => GetTeamsError::UnsupportedTeamType(context),
+            _ => GetTeamsError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for GetTeamsError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "channel_not_found" => GetTeamsError::ChannelNotFound,
-            "channel_type_not_supported" => GetTeamsError::ChannelTypeNotSupported,
-            "could_not_get_teams" => GetTeamsError::CouldNotGetTeams,
-            "feature_not_enabled" => GetTeamsError::FeatureNotEnabled,
-            "invalid_cursor" => GetTeamsError::InvalidCursor,
-            "invalid_limit" => GetTeamsError::InvalidLimit,
-            "restricted_action" => GetTeamsError::RestrictedAction,
-            "unsupported_team_type" => GetTeamsError::UnsupportedTeamType,
-            _ => GetTeamsError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for GetTeamsError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            GetTeamsError::ChannelNotFound => write!(f, "Server returned error channel_not_found"),
-            GetTeamsError::ChannelTypeNotSupported => {
+            GetTeamsError::ChannelNotFound(_) => {
+                write!(f, "Server returned error channel_not_found")
+            }
+            GetTeamsError::ChannelTypeNotSupported(_) => {
                 write!(f, "Server returned error channel_type_not_supported")
             }
-            GetTeamsError::CouldNotGetTeams => {
+            GetTeamsError::CouldNotGetTeams(_) => {
                 write!(f, "Server returned error could_not_get_teams")
             }
-            GetTeamsError::FeatureNotEnabled => {
+            GetTeamsError::FeatureNotEnabled(_) => {
                 write!(f, "Server returned error feature_not_enabled")
             }
-            GetTeamsError::InvalidCursor => write!(f, "Server returned error invalid_cursor"),
-            GetTeamsError::InvalidLimit => write!(f, "Server returned error invalid_limit"),
-            GetTeamsError::RestrictedAction => write!(f, "Server returned error restricted_action"),
-            GetTeamsError::UnsupportedTeamType => {
+            GetTeamsError::InvalidCursor(_) => write!(f, "Server returned error invalid_cursor"),
+            GetTeamsError::InvalidLimit(_) => write!(f, "Server returned error invalid_limit"),
+            GetTeamsError::RestrictedAction(_) => {
+                write!(f, "Server returned error restricted_action")
+            }
+            GetTeamsError::UnsupportedTeamType(_) => {
                 write!(f, "Server returned error unsupported_team_type")
             }
             GetTeamsError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            GetTeamsError::Unknown(ref s) => write!(f, "{}", s),
+            GetTeamsError::Unknown(ref s, _) => write!(f, "{}", s),
             GetTeamsError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -773,19 +1379,67 @@ impl<E: Error + 'static> Error for GetTeamsError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for GetTeamsError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            GetTeamsError::ChannelNotFound(_) => Some("channel_not_found"),
+            GetTeamsError::ChannelTypeNotSupported(_) => Some("channel_type_not_supported"),
+            GetTeamsError::CouldNotGetTeams(_) => Some("could_not_get_teams"),
+            GetTeamsError::FeatureNotEnabled(_) => Some("feature_not_enabled"),
+            GetTeamsError::InvalidCursor(_) => Some("invalid_cursor"),
+            GetTeamsError::InvalidLimit(_) => Some("invalid_limit"),
+            GetTeamsError::RestrictedAction(_) => Some("restricted_action"),
+            GetTeamsError::UnsupportedTeamType(_) => Some("unsupported_team_type"),
+            GetTeamsError::Unknown(ref s, _) => Some(s),
+            GetTeamsError::MalformedResponse(..) | GetTeamsError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            GetTeamsError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            GetTeamsError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            GetTeamsError::ChannelNotFound(ref context)
+            | GetTeamsError::ChannelTypeNotSupported(ref context)
+            | GetTeamsError::CouldNotGetTeams(ref context)
+            | GetTeamsError::FeatureNotEnabled(ref context)
+            | GetTeamsError::InvalidCursor(ref context)
+            | GetTeamsError::InvalidLimit(ref context)
+            | GetTeamsError::RestrictedAction(ref context)
+            | GetTeamsError::UnsupportedTeamType(ref context)
+            | GetTeamsError::Unknown(_, ref context) => Some(context),
+            GetTeamsError::MalformedResponse(..) | GetTeamsError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct InviteRequest<'a> {
     /// The channel that the users will be invited to.
-    pub channel_id: Cow<'a, str>,
+    pub channel_id: Option<crate::ChannelId>,
     /// The users to invite.
     pub user_ids: Cow<'a, str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InviteResponse {
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<InviteResponse, InviteError<E>>> for InviteResponse {
@@ -793,63 +1447,102 @@ impl<E: Error> Into<Result<InviteResponse, InviteError<E>>> for InviteResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(InviteError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for InviteResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum InviteError<E: Error> {
-    ChannelNotFound,
-    ChannelTypeNotSupported,
-    DefaultOrgWideChannel,
-    FailedForSomeUsers,
-    FeatureNotEnabled,
-    RestrictedAction,
-    UserMustBeAdmin,
+    ChannelNotFound(crate::ErrorContext),
+    ChannelTypeNotSupported(crate::ErrorContext),
+    DefaultOrgWideChannel(crate::ErrorContext),
+    FailedForSomeUsers(crate::ErrorContext),
+    FeatureNotEnabled(crate::ErrorContext),
+    RestrictedAction(crate::ErrorContext),
+    UserMustBeAdmin(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> InviteError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "channel_not_found" => InviteError::ChannelNotFound(context),
+            "channel_type_not_supported" => InviteError::ChannelTypeNotSupported(context),
+            "default_org_wide_channel" => InviteError::DefaultOrgWideChannel(context),
+            "failed_for_some_users" => InviteError::FailedForSomeUsers(context),
+            "feature_not_enabled" => InviteError::FeatureNotEnabled(context),
+            "restricted_action" => InviteError::RestrictedAction(context),
+            "user_must_be_admin" => InviteError::UserMustBeAdmin(context),
+            _ => InviteError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for InviteError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "channel_not_found" => InviteError::ChannelNotFound,
-            "channel_type_not_supported" => InviteError::ChannelTypeNotSupported,
-            "default_org_wide_channel" => InviteError::DefaultOrgWideChannel,
-            "failed_for_some_users" => InviteError::FailedForSomeUsers,
-            "feature_not_enabled" => InviteError::FeatureNotEnabled,
-            "restricted_action" => InviteError::RestrictedAction,
-            "user_must_be_admin" => InviteError::UserMustBeAdmin,
-            _ => InviteError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for InviteError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            InviteError::ChannelNotFound => write!(f, "Server returned error channel_not_found"),
-            InviteError::ChannelTypeNotSupported => {
+            InviteError::ChannelNotFound(_) => write!(f, "Server returned error channel_not_found"),
+            InviteError::ChannelTypeNotSupported(_) => {
                 write!(f, "Server returned error channel_type_not_supported")
             }
-            InviteError::DefaultOrgWideChannel => {
+            InviteError::DefaultOrgWideChannel(_) => {
                 write!(f, "Server returned error default_org_wide_channel")
             }
-            InviteError::FailedForSomeUsers => {
+            InviteError::FailedForSomeUsers(_) => {
                 write!(f, "Server returned error failed_for_some_users")
             }
-            InviteError::FeatureNotEnabled => {
+            InviteError::FeatureNotEnabled(_) => {
                 write!(f, "Server returned error feature_not_enabled")
             }
-            InviteError::RestrictedAction => write!(f, "Server returned error restricted_action"),
-            InviteError::UserMustBeAdmin => write!(f, "Server returned error user_must_be_admin"),
+            InviteError::RestrictedAction(_) => {
+                write!(f, "Server returned error restricted_action")
+            }
+            InviteError::UserMustBeAdmin(_) => {
+                write!(f, "Server returned error user_must_be_admin")
+            }
             InviteError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            InviteError::Unknown(ref s) => write!(f, "{}", s),
+            InviteError::Unknown(ref s, _) => write!(f, "{}", s),
             InviteError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -865,18 +1558,64 @@ impl<E: Error + 'static> Error for InviteError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for InviteError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            InviteError::ChannelNotFound(_) => Some("channel_not_found"),
+            InviteError::ChannelTypeNotSupported(_) => Some("channel_type_not_supported"),
+            InviteError::DefaultOrgWideChannel(_) => Some("default_org_wide_channel"),
+            InviteError::FailedForSomeUsers(_) => Some("failed_for_some_users"),
+            InviteError::FeatureNotEnabled(_) => Some("feature_not_enabled"),
+            InviteError::RestrictedAction(_) => Some("restricted_action"),
+            InviteError::UserMustBeAdmin(_) => Some("user_must_be_admin"),
+            InviteError::Unknown(ref s, _) => Some(s),
+            InviteError::MalformedResponse(..) | InviteError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            InviteError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            InviteError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            InviteError::ChannelNotFound(ref context)
+            | InviteError::ChannelTypeNotSupported(ref context)
+            | InviteError::DefaultOrgWideChannel(ref context)
+            | InviteError::FailedForSomeUsers(ref context)
+            | InviteError::FeatureNotEnabled(ref context)
+            | InviteError::RestrictedAction(ref context)
+            | InviteError::UserMustBeAdmin(ref context)
+            | InviteError::Unknown(_, ref context) => Some(context),
+            InviteError::MalformedResponse(..) | InviteError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct RenameRequest<'a> {
     /// The channel to rename.
-    pub channel_id: Cow<'a, str>,
+    pub channel_id: Option<crate::ChannelId>,
     pub name: Cow<'a, str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RenameResponse {
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<RenameResponse, RenameError<E>>> for RenameResponse {
@@ -884,63 +1623,100 @@ impl<E: Error> Into<Result<RenameResponse, RenameError<E>>> for RenameResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(RenameError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for RenameResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum RenameError<E: Error> {
-    ChannelNotFound,
-    ChannelTypeNotSupported,
-    CouldNotRenameChannel,
-    DefaultOrgWideChannel,
-    FeatureNotEnabled,
-    NameTaken,
-    RestrictedAction,
+    ChannelNotFound(crate::ErrorContext),
+    ChannelTypeNotSupported(crate::ErrorContext),
+    CouldNotRenameChannel(crate::ErrorContext),
+    DefaultOrgWideChannel(crate::ErrorContext),
+    FeatureNotEnabled(crate::ErrorContext),
+    NameTaken(crate::ErrorContext),
+    RestrictedAction(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> RenameError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "channel_not_found" => RenameError::ChannelNotFound(context),
+            "channel_type_not_supported" => RenameError::ChannelTypeNotSupported(context),
+            "could_not_rename_channel" => RenameError::CouldNotRenameChannel(context),
+            "default_org_wide_channel" => RenameError::DefaultOrgWideChannel(context),
+            "feature_not_enabled" => RenameError::FeatureNotEnabled(context),
+            "name_taken" => RenameError::NameTaken(context),
+            "restricted_action" => RenameError::RestrictedAction(context),
+            _ => RenameError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for RenameError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "channel_not_found" => RenameError::ChannelNotFound,
-            "channel_type_not_supported" => RenameError::ChannelTypeNotSupported,
-            "could_not_rename_channel" => RenameError::CouldNotRenameChannel,
-            "default_org_wide_channel" => RenameError::DefaultOrgWideChannel,
-            "feature_not_enabled" => RenameError::FeatureNotEnabled,
-            "name_taken" => RenameError::NameTaken,
-            "restricted_action" => RenameError::RestrictedAction,
-            _ => RenameError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for RenameError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            RenameError::ChannelNotFound => write!(f, "Server returned error channel_not_found"),
-            RenameError::ChannelTypeNotSupported => {
+            RenameError::ChannelNotFound(_) => write!(f, "Server returned error channel_not_found"),
+            RenameError::ChannelTypeNotSupported(_) => {
                 write!(f, "Server returned error channel_type_not_supported")
             }
-            RenameError::CouldNotRenameChannel => {
+            RenameError::CouldNotRenameChannel(_) => {
                 write!(f, "Server returned error could_not_rename_channel")
             }
-            RenameError::DefaultOrgWideChannel => {
+            RenameError::DefaultOrgWideChannel(_) => {
                 write!(f, "Server returned error default_org_wide_channel")
             }
-            RenameError::FeatureNotEnabled => {
+            RenameError::FeatureNotEnabled(_) => {
                 write!(f, "Server returned error feature_not_enabled")
             }
-            RenameError::NameTaken => write!(f, "Server returned error name_taken"),
-            RenameError::RestrictedAction => write!(f, "Server returned error restricted_action"),
+            RenameError::NameTaken(_) => write!(f, "Server returned error name_taken"),
+            RenameError::RestrictedAction(_) => {
+                write!(f, "Server returned error restricted_action")
+            }
             RenameError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            RenameError::Unknown(ref s) => write!(f, "{}", s),
+            RenameError::Unknown(ref s, _) => write!(f, "{}", s),
             RenameError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -956,6 +1732,46 @@ impl<E: Error + 'static> Error for RenameError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for RenameError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            RenameError::ChannelNotFound(_) => Some("channel_not_found"),
+            RenameError::ChannelTypeNotSupported(_) => Some("channel_type_not_supported"),
+            RenameError::CouldNotRenameChannel(_) => Some("could_not_rename_channel"),
+            RenameError::DefaultOrgWideChannel(_) => Some("default_org_wide_channel"),
+            RenameError::FeatureNotEnabled(_) => Some("feature_not_enabled"),
+            RenameError::NameTaken(_) => Some("name_taken"),
+            RenameError::RestrictedAction(_) => Some("restricted_action"),
+            RenameError::Unknown(ref s, _) => Some(s),
+            RenameError::MalformedResponse(..) | RenameError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            RenameError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            RenameError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            RenameError::ChannelNotFound(ref context)
+            | RenameError::ChannelTypeNotSupported(ref context)
+            | RenameError::CouldNotRenameChannel(ref context)
+            | RenameError::DefaultOrgWideChannel(ref context)
+            | RenameError::FeatureNotEnabled(ref context)
+            | RenameError::NameTaken(ref context)
+            | RenameError::RestrictedAction(ref context)
+            | RenameError::Unknown(_, ref context) => Some(context),
+            RenameError::MalformedResponse(..) | RenameError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct SearchRequest<'a> {
     /// Set `cursor` to `next_cursor` returned by the previous call to list items in the next page.
@@ -974,7 +1790,13 @@ pub struct SearchRequest<'a> {
     pub team_ids: Option<Cow<'a, str>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl crate::pagination::CursorRequest for SearchRequest<'_> {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor.into());
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SearchAttachmentsInner {
     pub fallback: Option<String>,
     pub id: u64,
@@ -984,37 +1806,32 @@ pub struct SearchAttachmentsInner {
     pub image_width: Option<u64>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct SearchBlocksInner {
-    pub r#type: String,
-}
-
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SearchIconsInner {
     pub image_36: String,
     pub image_48: String,
     pub image_72: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SearchBotProfileInner {
-    pub app_id: String,
+    pub app_id: crate::AppId,
     pub deleted: bool,
     pub icons: SearchIconsInner,
-    pub id: String,
+    pub id: crate::BotId,
     pub name: String,
-    pub team_id: String,
+    pub team_id: crate::TeamId,
     pub updated: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SearchReactionsInner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SearchCommentInner {
     pub comment: String,
     pub created: u64,
@@ -1026,23 +1843,23 @@ pub struct SearchCommentInner {
     pub pinned_to: Option<Vec<String>>,
     pub reactions: Option<Vec<SearchReactionsInner>>,
     pub timestamp: u64,
-    pub user: String,
+    pub user: crate::UserId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SearchReactions1Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SearchSharesInner {
     pub private: Option<serde_json::Value>,
     pub public: Option<serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SearchFileInner {
     pub channels: Option<Vec<String>>,
     pub comments_count: Option<u64>,
@@ -1057,7 +1874,7 @@ pub struct SearchFileInner {
     pub filetype: Option<String>,
     pub groups: Option<Vec<String>>,
     pub has_rich_preview: Option<bool>,
-    pub id: Option<String>,
+    pub id: Option<crate::FileId>,
     pub image_exif_rotation: Option<u64>,
     pub ims: Option<Vec<String>>,
     pub is_external: Option<bool>,
@@ -1111,25 +1928,25 @@ pub struct SearchFileInner {
     pub updated: Option<u64>,
     pub url_private: Option<String>,
     pub url_private_download: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SearchReactions2Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SearchShares1Inner {
     pub private: Option<serde_json::Value>,
     pub public: Option<serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SearchFilesInner {
     pub channels: Option<Vec<String>>,
     pub comments_count: Option<u64>,
@@ -1144,7 +1961,7 @@ pub struct SearchFilesInner {
     pub filetype: Option<String>,
     pub groups: Option<Vec<String>>,
     pub has_rich_preview: Option<bool>,
-    pub id: Option<String>,
+    pub id: Option<crate::FileId>,
     pub image_exif_rotation: Option<u64>,
     pub ims: Option<Vec<String>>,
     pub is_external: Option<bool>,
@@ -1198,25 +2015,25 @@ pub struct SearchFilesInner {
     pub updated: Option<u64>,
     pub url_private: Option<String>,
     pub url_private_download: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SearchIcons1Inner {
     pub emoji: Option<String>,
     pub image_64: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SearchReactions3Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SearchUserProfileInner {
     pub avatar_hash: String,
     pub display_name: String,
@@ -1228,13 +2045,13 @@ pub struct SearchUserProfileInner {
     pub name: String,
     pub real_name: String,
     pub real_name_normalized: Option<String>,
-    pub team: String,
+    pub team: crate::TeamId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SearchLatestInner {
     pub attachments: Option<Vec<SearchAttachmentsInner>>,
-    pub blocks: Option<Vec<SearchBlocksInner>>,
+    pub blocks: Option<Vec<crate::blocks::Block>>,
     pub bot_id: Option<Vec<String>>,
     pub bot_profile: Option<SearchBotProfileInner>,
     pub client_msg_id: Option<String>,
@@ -1247,8 +2064,8 @@ pub struct SearchLatestInner {
     pub is_delayed_message: Option<bool>,
     pub is_intro: Option<bool>,
     pub is_starred: Option<bool>,
-    pub last_read: Option<String>,
-    pub latest_reply: Option<String>,
+    pub last_read: Option<crate::Timestamp>,
+    pub latest_reply: Option<crate::Timestamp>,
     pub name: Option<String>,
     pub old_name: Option<String>,
     pub parent_user_id: Option<String>,
@@ -1262,40 +2079,40 @@ pub struct SearchLatestInner {
     pub source_team: Option<String>,
     pub subscribed: Option<bool>,
     pub subtype: Option<String>,
-    pub team: Option<String>,
+    pub team: Option<crate::TeamId>,
     pub text: String,
-    pub thread_ts: Option<String>,
+    pub thread_ts: Option<crate::Timestamp>,
     pub topic: Option<String>,
-    pub ts: String,
+    pub ts: crate::Timestamp,
     pub r#type: String,
     pub unread_count: Option<u64>,
     pub upload: Option<bool>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_profile: Option<SearchUserProfileInner>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SearchPurposeInner {
     pub creator: String,
     pub last_set: u64,
     pub value: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SearchTopicInner {
     pub creator: String,
     pub last_set: u64,
     pub value: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SearchChannelsInner {
     pub accepted_user: Option<String>,
     pub created: u64,
     pub creator: String,
-    pub id: String,
+    pub id: crate::ChannelId,
     pub is_archived: Option<bool>,
     pub is_channel: bool,
     pub is_frozen: Option<bool>,
@@ -1310,7 +2127,7 @@ pub struct SearchChannelsInner {
     pub is_read_only: Option<bool>,
     pub is_shared: bool,
     pub is_thread_only: Option<bool>,
-    pub last_read: Option<String>,
+    pub last_read: Option<crate::Timestamp>,
     pub latest: Option<Vec<SearchLatestInner>>,
     pub members: Vec<String>,
     pub name: String,
@@ -1326,13 +2143,19 @@ pub struct SearchChannelsInner {
     pub unread_count_display: Option<u64>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SearchResponse {
     pub channels: Vec<SearchChannelsInner>,
     error: Option<String>,
+    pub needed: Option<String>,
     pub next_cursor: String,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<SearchResponse, SearchError<E>>> for SearchResponse {
@@ -1340,68 +2163,115 @@ impl<E: Error> Into<Result<SearchResponse, SearchError<E>>> for SearchResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(SearchError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for SearchResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
+impl crate::pagination::CursorPage for SearchResponse {
+    type Item = SearchChannelsInner;
+
+    fn next_cursor(&self) -> Option<&str> {
+        Some(self.next_cursor.as_str())
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.channels
+    }
+}
+
 #[derive(Debug)]
 pub enum SearchError<E: Error> {
-    FeatureNotEnabled,
-    InvalidAuth,
-    InvalidCursor,
-    InvalidSearchChannelType,
-    InvalidSort,
-    InvalidSortDir,
-    NotAllowed,
-    NotAnAdmin,
-    NotAnEnterprise,
-    TeamNotFound,
+    FeatureNotEnabled(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCursor(crate::ErrorContext),
+    InvalidSearchChannelType(crate::ErrorContext),
+    InvalidSort(crate::ErrorContext),
+    InvalidSortDir(crate::ErrorContext),
+    NotAllowed(crate::ErrorContext),
+    NotAnAdmin(crate::ErrorContext),
+    NotAnEnterprise(crate::ErrorContext),
+    TeamNotFound(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> SearchError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "feature_not_enabled" => SearchError::FeatureNotEnabled(context),
+            "invalid_auth" => SearchError::InvalidAuth(context),
+            "invalid_cursor" => SearchError::InvalidCursor(context),
+            "invalid_search_channel_type" => SearchError::InvalidSearchChannelType(context),
+            "invalid_sort" => SearchError::InvalidSort(context),
+            "invalid_sort_dir" => SearchError::InvalidSortDir(context),
+            "not_allowed" => SearchError::NotAllowed(context),
+            "not_an_admin" => SearchError::NotAnAdmin(context),
+            "not_an_enterprise" => SearchError::NotAnEnterprise(context),
+            "team_not_found" => SearchError::TeamNotFound(context),
+            _ => SearchError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for SearchError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "feature_not_enabled" => SearchError::FeatureNotEnabled,
-            "invalid_auth" => SearchError::InvalidAuth,
-            "invalid_cursor" => SearchError::InvalidCursor,
-            "invalid_search_channel_type" => SearchError::InvalidSearchChannelType,
-            "invalid_sort" => SearchError::InvalidSort,
-            "invalid_sort_dir" => SearchError::InvalidSortDir,
-            "not_allowed" => SearchError::NotAllowed,
-            "not_an_admin" => SearchError::NotAnAdmin,
-            "not_an_enterprise" => SearchError::NotAnEnterprise,
-            "team_not_found" => SearchError::TeamNotFound,
-            _ => SearchError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for SearchError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            SearchError::FeatureNotEnabled => {
+            SearchError::FeatureNotEnabled(_) => {
                 write!(f, "Server returned error feature_not_enabled")
             }
-            SearchError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            SearchError::InvalidCursor => write!(f, "Server returned error invalid_cursor"),
-            SearchError::InvalidSearchChannelType => {
+            SearchError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            SearchError::InvalidCursor(_) => write!(f, "Server returned error invalid_cursor"),
+            SearchError::InvalidSearchChannelType(_) => {
                 write!(f, "Server returned error invalid_search_channel_type")
             }
-            SearchError::InvalidSort => write!(f, "Server returned error invalid_sort"),
-            SearchError::InvalidSortDir => write!(f, "Server returned error invalid_sort_dir"),
-            SearchError::NotAllowed => write!(f, "Server returned error not_allowed"),
-            SearchError::NotAnAdmin => write!(f, "Server returned error not_an_admin"),
-            SearchError::NotAnEnterprise => write!(f, "Server returned error not_an_enterprise"),
-            SearchError::TeamNotFound => write!(f, "Server returned error team_not_found"),
+            SearchError::InvalidSort(_) => write!(f, "Server returned error invalid_sort"),
+            SearchError::InvalidSortDir(_) => write!(f, "Server returned error invalid_sort_dir"),
+            SearchError::NotAllowed(_) => write!(f, "Server returned error not_allowed"),
+            SearchError::NotAnAdmin(_) => write!(f, "Server returned error not_an_admin"),
+            SearchError::NotAnEnterprise(_) => write!(f, "Server returned error not_an_enterprise"),
+            SearchError::TeamNotFound(_) => write!(f, "Server returned error team_not_found"),
             SearchError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            SearchError::Unknown(ref s) => write!(f, "{}", s),
+            SearchError::Unknown(ref s, _) => write!(f, "{}", s),
             SearchError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -1417,19 +2287,71 @@ impl<E: Error + 'static> Error for SearchError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for SearchError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            SearchError::FeatureNotEnabled(_) => Some("feature_not_enabled"),
+            SearchError::InvalidAuth(_) => Some("invalid_auth"),
+            SearchError::InvalidCursor(_) => Some("invalid_cursor"),
+            SearchError::InvalidSearchChannelType(_) => Some("invalid_search_channel_type"),
+            SearchError::InvalidSort(_) => Some("invalid_sort"),
+            SearchError::InvalidSortDir(_) => Some("invalid_sort_dir"),
+            SearchError::NotAllowed(_) => Some("not_allowed"),
+            SearchError::NotAnAdmin(_) => Some("not_an_admin"),
+            SearchError::NotAnEnterprise(_) => Some("not_an_enterprise"),
+            SearchError::TeamNotFound(_) => Some("team_not_found"),
+            SearchError::Unknown(ref s, _) => Some(s),
+            SearchError::MalformedResponse(..) | SearchError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            SearchError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            SearchError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            SearchError::FeatureNotEnabled(ref context)
+            | SearchError::InvalidAuth(ref context)
+            | SearchError::InvalidCursor(ref context)
+            | SearchError::InvalidSearchChannelType(ref context)
+            | SearchError::InvalidSort(ref context)
+            | SearchError::InvalidSortDir(ref context)
+            | SearchError::NotAllowed(ref context)
+            | SearchError::NotAnAdmin(ref context)
+            | SearchError::NotAnEnterprise(ref context)
+            | SearchError::TeamNotFound(ref context)
+            | SearchError::Unknown(_, ref context) => Some(context),
+            SearchError::MalformedResponse(..) | SearchError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct SetConversationPrefsRequest<'a> {
     /// The channel to set the prefs for
-    pub channel_id: Cow<'a, str>,
+    pub channel_id: Option<crate::ChannelId>,
     /// The prefs for this channel in a stringified JSON format.
     pub prefs: Cow<'a, str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetConversationPrefsResponse {
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<SetConversationPrefsResponse, SetConversationPrefsError<E>>>
@@ -1439,74 +2361,113 @@ impl<E: Error> Into<Result<SetConversationPrefsResponse, SetConversationPrefsErr
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(SetConversationPrefsError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for SetConversationPrefsResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum SetConversationPrefsError<E: Error> {
-    ChannelNotFound,
-    ChannelTypeNotSupported,
-    CouldNotSetChannelPref,
-    DefaultOrgWideChannel,
-    FeatureNotEnabled,
-    MissingScope,
-    NotAnAdmin,
-    RestrictedAction,
+    ChannelNotFound(crate::ErrorContext),
+    ChannelTypeNotSupported(crate::ErrorContext),
+    CouldNotSetChannelPref(crate::ErrorContext),
+    DefaultOrgWideChannel(crate::ErrorContext),
+    FeatureNotEnabled(crate::ErrorContext),
+    MissingScope(crate::ErrorContext),
+    NotAnAdmin(crate::ErrorContext),
+    RestrictedAction(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> SetConversationPrefsError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "channel_not_found" => SetConversationPrefsError::ChannelNotFound(context),
+            "channel_type_not_supported" => {
+                SetConversationPrefsError::ChannelTypeNotSupported(context)
+            }
+            "could_not_set_channel_pref" => {
+                SetConversationPrefsError::CouldNotSetChannelPref(context)
+            }
+            "default_org_wide_channel" => SetConversationPrefsError::DefaultOrgWideChannel(context),
+            "feature_not_enabled" => SetConversationPrefsError::FeatureNotEnabled(context),
+            "missing_scope" => SetConversationPrefsError::MissingScope(context),
+            "not_an_admin" => SetConversationPrefsError::NotAnAdmin(context),
+            "restricted_action" => SetConversationPrefsError::RestrictedAction(context),
+            _ => SetConversationPrefsError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for SetConversationPrefsError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "channel_not_found" => SetConversationPrefsError::ChannelNotFound,
-            "channel_type_not_supported" => SetConversationPrefsError::ChannelTypeNotSupported,
-            "could_not_set_channel_pref" => SetConversationPrefsError::CouldNotSetChannelPref,
-            "default_org_wide_channel" => SetConversationPrefsError::DefaultOrgWideChannel,
-            "feature_not_enabled" => SetConversationPrefsError::FeatureNotEnabled,
-            "missing_scope" => SetConversationPrefsError::MissingScope,
-            "not_an_admin" => SetConversationPrefsError::NotAnAdmin,
-            "restricted_action" => SetConversationPrefsError::RestrictedAction,
-            _ => SetConversationPrefsError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for SetConversationPrefsError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            SetConversationPrefsError::ChannelNotFound => {
+            SetConversationPrefsError::ChannelNotFound(_) => {
                 write!(f, "Server returned error channel_not_found")
             }
-            SetConversationPrefsError::ChannelTypeNotSupported => {
+            SetConversationPrefsError::ChannelTypeNotSupported(_) => {
                 write!(f, "Server returned error channel_type_not_supported")
             }
-            SetConversationPrefsError::CouldNotSetChannelPref => {
+            SetConversationPrefsError::CouldNotSetChannelPref(_) => {
                 write!(f, "Server returned error could_not_set_channel_pref")
             }
-            SetConversationPrefsError::DefaultOrgWideChannel => {
+            SetConversationPrefsError::DefaultOrgWideChannel(_) => {
                 write!(f, "Server returned error default_org_wide_channel")
             }
-            SetConversationPrefsError::FeatureNotEnabled => {
+            SetConversationPrefsError::FeatureNotEnabled(_) => {
                 write!(f, "Server returned error feature_not_enabled")
             }
-            SetConversationPrefsError::MissingScope => {
+            SetConversationPrefsError::MissingScope(_) => {
                 write!(f, "Server returned error missing_scope")
             }
-            SetConversationPrefsError::NotAnAdmin => {
+            SetConversationPrefsError::NotAnAdmin(_) => {
                 write!(f, "Server returned error not_an_admin")
             }
-            SetConversationPrefsError::RestrictedAction => {
+            SetConversationPrefsError::RestrictedAction(_) => {
                 write!(f, "Server returned error restricted_action")
             }
             SetConversationPrefsError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            SetConversationPrefsError::Unknown(ref s) => write!(f, "{}", s),
+            SetConversationPrefsError::Unknown(ref s, _) => write!(f, "{}", s),
             SetConversationPrefsError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -1522,22 +2483,77 @@ impl<E: Error + 'static> Error for SetConversationPrefsError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for SetConversationPrefsError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            SetConversationPrefsError::ChannelNotFound(_) => Some("channel_not_found"),
+            SetConversationPrefsError::ChannelTypeNotSupported(_) => {
+                Some("channel_type_not_supported")
+            }
+            SetConversationPrefsError::CouldNotSetChannelPref(_) => {
+                Some("could_not_set_channel_pref")
+            }
+            SetConversationPrefsError::DefaultOrgWideChannel(_) => Some("default_org_wide_channel"),
+            SetConversationPrefsError::FeatureNotEnabled(_) => Some("feature_not_enabled"),
+            SetConversationPrefsError::MissingScope(_) => Some("missing_scope"),
+            SetConversationPrefsError::NotAnAdmin(_) => Some("not_an_admin"),
+            SetConversationPrefsError::RestrictedAction(_) => Some("restricted_action"),
+            SetConversationPrefsError::Unknown(ref s, _) => Some(s),
+            SetConversationPrefsError::MalformedResponse(..)
+            | SetConversationPrefsError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            SetConversationPrefsError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            SetConversationPrefsError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            SetConversationPrefsError::ChannelNotFound(ref context)
+            | SetConversationPrefsError::ChannelTypeNotSupported(ref context)
+            | SetConversationPrefsError::CouldNotSetChannelPref(ref context)
+            | SetConversationPrefsError::DefaultOrgWideChannel(ref context)
+            | SetConversationPrefsError::FeatureNotEnabled(ref context)
+            | SetConversationPrefsError::MissingScope(ref context)
+            | SetConversationPrefsError::NotAnAdmin(ref context)
+            | SetConversationPrefsError::RestrictedAction(ref context)
+            | SetConversationPrefsError::Unknown(_, ref context) => Some(context),
+            SetConversationPrefsError::MalformedResponse(..)
+            | SetConversationPrefsError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct SetTeamsRequest<'a> {
     /// The encoded `channel_id` to add or remove to workspaces.
-    pub channel_id: Cow<'a, str>,
+    pub channel_id: Option<crate::ChannelId>,
     /// True if channel has to be converted to an org channel
     pub org_channel: Option<bool>,
     /// A comma-separated list of workspaces to which the channel should be shared. Not required if the channel is being shared org-wide.
     pub target_team_ids: Option<Cow<'a, str>>,
     /// The workspace to which the channel belongs. Omit this argument if the channel is a cross-workspace shared channel.
-    pub team_id: Option<Cow<'a, str>>,
+    pub team_id: Option<crate::TeamId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetTeamsResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<SetTeamsResponse, SetTeamsError<E>>> for SetTeamsResponse {
@@ -1545,28 +2561,61 @@ impl<E: Error> Into<Result<SetTeamsResponse, SetTeamsError<E>>> for SetTeamsResp
         if self.ok {
             Ok(self)
         } else {
-            Err(SetTeamsError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(SetTeamsError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for SetTeamsResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum SetTeamsError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> SetTeamsError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => SetTeamsError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for SetTeamsError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => SetTeamsError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -1574,7 +2623,7 @@ impl<E: Error> fmt::Display for SetTeamsError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             SetTeamsError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            SetTeamsError::Unknown(ref s) => write!(f, "{}", s),
+            SetTeamsError::Unknown(ref s, _) => write!(f, "{}", s),
             SetTeamsError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -1590,17 +2639,49 @@ impl<E: Error + 'static> Error for SetTeamsError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for SetTeamsError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            SetTeamsError::Unknown(ref s, _) => Some(s),
+            SetTeamsError::MalformedResponse(..) | SetTeamsError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            SetTeamsError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            SetTeamsError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            SetTeamsError::Unknown(_, ref context) => Some(context),
+            SetTeamsError::MalformedResponse(..) | SetTeamsError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
-pub struct UnarchiveRequest<'a> {
+pub struct UnarchiveRequest {
     /// The channel to unarchive.
-    pub channel_id: Cow<'a, str>,
+    pub channel_id: Option<crate::ChannelId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UnarchiveResponse {
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<UnarchiveResponse, UnarchiveError<E>>> for UnarchiveResponse {
@@ -1608,70 +2689,107 @@ impl<E: Error> Into<Result<UnarchiveResponse, UnarchiveError<E>>> for UnarchiveR
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(UnarchiveError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for UnarchiveResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum UnarchiveError<E: Error> {
-    ChannelNotArchived,
-    ChannelNotFound,
-    ChannelTypeNotSupported,
-    CouldNotUnarchiveChannel,
-    DefaultOrgWideChannel,
-    FeatureNotEnabled,
-    MissingScope,
-    RestrictedAction,
+    ChannelNotArchived(crate::ErrorContext),
+    ChannelNotFound(crate::ErrorContext),
+    ChannelTypeNotSupported(crate::ErrorContext),
+    CouldNotUnarchiveChannel(crate::ErrorContext),
+    DefaultOrgWideChannel(crate::ErrorContext),
+    FeatureNotEnabled(crate::ErrorContext),
+    MissingScope(crate::ErrorContext),
+    RestrictedAction(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> UnarchiveError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "channel_not_archived" => UnarchiveError::ChannelNotArchived(context),
+            "channel_not_found" => UnarchiveError::ChannelNotFound(context),
+            "channel_type_not_supported" => UnarchiveError::ChannelTypeNotSupported(context),
+            "could_not_unarchive_channel" => UnarchiveError::CouldNotUnarchiveChannel(context),
+            "default_org_wide_channel" => UnarchiveError::DefaultOrgWideChannel(context),
+            "feature_not_enabled" => UnarchiveError::FeatureNotEnabled(context),
+            "missing_scope" => UnarchiveError::MissingScope(context),
+            "restricted_action" => UnarchiveError::RestrictedAction(context),
+            _ => UnarchiveError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for UnarchiveError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "channel_not_archived" => UnarchiveError::ChannelNotArchived,
-            "channel_not_found" => UnarchiveError::ChannelNotFound,
-            "channel_type_not_supported" => UnarchiveError::ChannelTypeNotSupported,
-            "could_not_unarchive_channel" => UnarchiveError::CouldNotUnarchiveChannel,
-            "default_org_wide_channel" => UnarchiveError::DefaultOrgWideChannel,
-            "feature_not_enabled" => UnarchiveError::FeatureNotEnabled,
-            "missing_scope" => UnarchiveError::MissingScope,
-            "restricted_action" => UnarchiveError::RestrictedAction,
-            _ => UnarchiveError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for UnarchiveError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            UnarchiveError::ChannelNotArchived => {
+            UnarchiveError::ChannelNotArchived(_) => {
                 write!(f, "Server returned error channel_not_archived")
             }
-            UnarchiveError::ChannelNotFound => write!(f, "Server returned error channel_not_found"),
-            UnarchiveError::ChannelTypeNotSupported => {
+            UnarchiveError::ChannelNotFound(_) => {
+                write!(f, "Server returned error channel_not_found")
+            }
+            UnarchiveError::ChannelTypeNotSupported(_) => {
                 write!(f, "Server returned error channel_type_not_supported")
             }
-            UnarchiveError::CouldNotUnarchiveChannel => {
+            UnarchiveError::CouldNotUnarchiveChannel(_) => {
                 write!(f, "Server returned error could_not_unarchive_channel")
             }
-            UnarchiveError::DefaultOrgWideChannel => {
+            UnarchiveError::DefaultOrgWideChannel(_) => {
                 write!(f, "Server returned error default_org_wide_channel")
             }
-            UnarchiveError::FeatureNotEnabled => {
+            UnarchiveError::FeatureNotEnabled(_) => {
                 write!(f, "Server returned error feature_not_enabled")
             }
-            UnarchiveError::MissingScope => write!(f, "Server returned error missing_scope"),
-            UnarchiveError::RestrictedAction => {
+            UnarchiveError::MissingScope(_) => write!(f, "Server returned error missing_scope"),
+            UnarchiveError::RestrictedAction(_) => {
                 write!(f, "Server returned error restricted_action")
             }
             UnarchiveError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            UnarchiveError::Unknown(ref s) => write!(f, "{}", s),
+            UnarchiveError::Unknown(ref s, _) => write!(f, "{}", s),
             UnarchiveError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -1686,3 +2804,45 @@ impl<E: Error + 'static> Error for UnarchiveError<E> {
         }
     }
 }
+
+impl<E: Error + 'static> crate::MethodError for UnarchiveError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            UnarchiveError::ChannelNotArchived(_) => Some("channel_not_archived"),
+            UnarchiveError::ChannelNotFound(_) => Some("channel_not_found"),
+            UnarchiveError::ChannelTypeNotSupported(_) => Some("channel_type_not_supported"),
+            UnarchiveError::CouldNotUnarchiveChannel(_) => Some("could_not_unarchive_channel"),
+            UnarchiveError::DefaultOrgWideChannel(_) => Some("default_org_wide_channel"),
+            UnarchiveError::FeatureNotEnabled(_) => Some("feature_not_enabled"),
+            UnarchiveError::MissingScope(_) => Some("missing_scope"),
+            UnarchiveError::RestrictedAction(_) => Some("restricted_action"),
+            UnarchiveError::Unknown(ref s, _) => Some(s),
+            UnarchiveError::MalformedResponse(..) | UnarchiveError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            UnarchiveError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            UnarchiveError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            UnarchiveError::ChannelNotArchived(ref context)
+            | UnarchiveError::ChannelNotFound(ref context)
+            | UnarchiveError::ChannelTypeNotSupported(ref context)
+            | UnarchiveError::CouldNotUnarchiveChannel(ref context)
+            | UnarchiveError::DefaultOrgWideChannel(ref context)
+            | UnarchiveError::FeatureNotEnabled(ref context)
+            | UnarchiveError::MissingScope(ref context)
+            | UnarchiveError::RestrictedAction(ref context)
+            | UnarchiveError::Unknown(_, ref context) => Some(context),
+            UnarchiveError::MalformedResponse(..) | UnarchiveError::Client(_) => None,
+        }
+    }
+}
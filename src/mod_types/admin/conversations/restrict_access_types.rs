@@ -24,17 +24,24 @@ use std::fmt;
 #[derive(Clone, Default, Debug)]
 pub struct AddGroupRequest<'a> {
     /// The channel to link this group to.
-    pub channel_id: Cow<'a, str>,
+    pub channel_id: Option<crate::ChannelId>,
     /// The [IDP Group](https://slack.com/help/articles/115001435788-Connect-identity-provider-groups-to-your-Enterprise-Grid-org) ID to be an allowlist for the private channel.
     pub group_id: Cow<'a, str>,
     /// The workspace where the channel exists. This argument is required for channels only tied to one workspace, and optional for channels that are shared across an organization.
-    pub team_id: Option<Cow<'a, str>>,
+    pub team_id: Option<crate::TeamId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AddGroupResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<AddGroupResponse, AddGroupError<E>>> for AddGroupResponse {
@@ -42,28 +49,61 @@ impl<E: Error> Into<Result<AddGroupResponse, AddGroupError<E>>> for AddGroupResp
         if self.ok {
             Ok(self)
         } else {
-            Err(AddGroupError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(AddGroupError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for AddGroupResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum AddGroupError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> AddGroupError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => AddGroupError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for AddGroupError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => AddGroupError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -71,7 +111,7 @@ impl<E: Error> fmt::Display for AddGroupError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             AddGroupError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            AddGroupError::Unknown(ref s) => write!(f, "{}", s),
+            AddGroupError::Unknown(ref s, _) => write!(f, "{}", s),
             AddGroupError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -87,17 +127,50 @@ impl<E: Error + 'static> Error for AddGroupError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for AddGroupError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            AddGroupError::Unknown(ref s, _) => Some(s),
+            AddGroupError::MalformedResponse(..) | AddGroupError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            AddGroupError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            AddGroupError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            AddGroupError::Unknown(_, ref context) => Some(context),
+            AddGroupError::MalformedResponse(..) | AddGroupError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
-pub struct ListGroupsRequest<'a> {
-    pub channel_id: Cow<'a, str>,
+pub struct ListGroupsRequest {
+    pub channel_id: Option<crate::ChannelId>,
     /// The workspace where the channel exists. This argument is required for channels only tied to one workspace, and optional for channels that are shared across an organization.
-    pub team_id: Option<Cow<'a, str>>,
+    pub team_id: Option<crate::TeamId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListGroupsResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<ListGroupsResponse, ListGroupsError<E>>> for ListGroupsResponse {
@@ -105,28 +178,61 @@ impl<E: Error> Into<Result<ListGroupsResponse, ListGroupsError<E>>> for ListGrou
         if self.ok {
             Ok(self)
         } else {
-            Err(ListGroupsError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(ListGroupsError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for ListGroupsResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum ListGroupsError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> ListGroupsError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => ListGroupsError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for ListGroupsError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => ListGroupsError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -134,7 +240,7 @@ impl<E: Error> fmt::Display for ListGroupsError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             ListGroupsError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            ListGroupsError::Unknown(ref s) => write!(f, "{}", s),
+            ListGroupsError::Unknown(ref s, _) => write!(f, "{}", s),
             ListGroupsError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -150,20 +256,53 @@ impl<E: Error + 'static> Error for ListGroupsError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for ListGroupsError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            ListGroupsError::Unknown(ref s, _) => Some(s),
+            ListGroupsError::MalformedResponse(..) | ListGroupsError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            ListGroupsError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            ListGroupsError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            ListGroupsError::Unknown(_, ref context) => Some(context),
+            ListGroupsError::MalformedResponse(..) | ListGroupsError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct RemoveGroupRequest<'a> {
     /// The channel to remove the linked group from.
-    pub channel_id: Cow<'a, str>,
+    pub channel_id: Option<crate::ChannelId>,
     /// The [IDP Group](https://slack.com/help/articles/115001435788-Connect-identity-provider-groups-to-your-Enterprise-Grid-org) ID to remove from the private channel.
     pub group_id: Cow<'a, str>,
     /// The workspace where the channel exists. This argument is required for channels only tied to one workspace, and optional for channels that are shared across an organization.
-    pub team_id: Cow<'a, str>,
+    pub team_id: Option<crate::TeamId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RemoveGroupResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<RemoveGroupResponse, RemoveGroupError<E>>> for RemoveGroupResponse {
@@ -171,28 +310,61 @@ impl<E: Error> Into<Result<RemoveGroupResponse, RemoveGroupError<E>>> for Remove
         if self.ok {
             Ok(self)
         } else {
-            Err(RemoveGroupError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(RemoveGroupError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for RemoveGroupResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum RemoveGroupError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> RemoveGroupError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => RemoveGroupError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for RemoveGroupError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => RemoveGroupError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -200,7 +372,7 @@ impl<E: Error> fmt::Display for RemoveGroupError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             RemoveGroupError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            RemoveGroupError::Unknown(ref s) => write!(f, "{}", s),
+            RemoveGroupError::Unknown(ref s, _) => write!(f, "{}", s),
             RemoveGroupError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -215,3 +387,29 @@ impl<E: Error + 'static> Error for RemoveGroupError<E> {
         }
     }
 }
+
+impl<E: Error + 'static> crate::MethodError for RemoveGroupError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            RemoveGroupError::Unknown(ref s, _) => Some(s),
+            RemoveGroupError::MalformedResponse(..) | RemoveGroupError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            RemoveGroupError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            RemoveGroupError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            RemoveGroupError::Unknown(_, ref context) => Some(context),
+            RemoveGroupError::MalformedResponse(..) | RemoveGroupError::Client(_) => None,
+        }
+    }
+}
@@ -29,10 +29,17 @@ pub struct AddRequest<'a> {
     pub url: Cow<'a, str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AddResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<AddResponse, AddError<E>>> for AddResponse {
@@ -40,28 +47,61 @@ impl<E: Error> Into<Result<AddResponse, AddError<E>>> for AddResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(AddError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(AddError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for AddResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum AddError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> AddError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => AddError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for AddError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => AddError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -69,7 +109,7 @@ impl<E: Error> fmt::Display for AddError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             AddError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            AddError::Unknown(ref s) => write!(f, "{}", s),
+            AddError::Unknown(ref s, _) => write!(f, "{}", s),
             AddError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -85,6 +125,32 @@ impl<E: Error + 'static> Error for AddError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for AddError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            AddError::Unknown(ref s, _) => Some(s),
+            AddError::MalformedResponse(..) | AddError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            AddError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            AddError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            AddError::Unknown(_, ref context) => Some(context),
+            AddError::MalformedResponse(..) | AddError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct AddAliasRequest<'a> {
     /// The alias of the emoji.
@@ -93,10 +159,17 @@ pub struct AddAliasRequest<'a> {
     pub name: Cow<'a, str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AddAliasResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<AddAliasResponse, AddAliasError<E>>> for AddAliasResponse {
@@ -104,28 +177,61 @@ impl<E: Error> Into<Result<AddAliasResponse, AddAliasError<E>>> for AddAliasResp
         if self.ok {
             Ok(self)
         } else {
-            Err(AddAliasError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(AddAliasError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for AddAliasResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum AddAliasError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> AddAliasError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => AddAliasError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for AddAliasError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => AddAliasError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -133,7 +239,7 @@ impl<E: Error> fmt::Display for AddAliasError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             AddAliasError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            AddAliasError::Unknown(ref s) => write!(f, "{}", s),
+            AddAliasError::Unknown(ref s, _) => write!(f, "{}", s),
             AddAliasError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -149,6 +255,32 @@ impl<E: Error + 'static> Error for AddAliasError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for AddAliasError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            AddAliasError::Unknown(ref s, _) => Some(s),
+            AddAliasError::MalformedResponse(..) | AddAliasError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            AddAliasError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            AddAliasError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            AddAliasError::Unknown(_, ref context) => Some(context),
+            AddAliasError::MalformedResponse(..) | AddAliasError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct ListRequest<'a> {
     /// Set `cursor` to `next_cursor` returned by the previous call to list items in the next page
@@ -157,10 +289,17 @@ pub struct ListRequest<'a> {
     pub limit: Option<u64>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
@@ -168,28 +307,61 @@ impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(ListError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(ListError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for ListResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum ListError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> ListError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => ListError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for ListError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => ListError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -197,7 +369,7 @@ impl<E: Error> fmt::Display for ListError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             ListError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            ListError::Unknown(ref s) => write!(f, "{}", s),
+            ListError::Unknown(ref s, _) => write!(f, "{}", s),
             ListError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -213,16 +385,49 @@ impl<E: Error + 'static> Error for ListError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for ListError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            ListError::Unknown(ref s, _) => Some(s),
+            ListError::MalformedResponse(..) | ListError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            ListError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            ListError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            ListError::Unknown(_, ref context) => Some(context),
+            ListError::MalformedResponse(..) | ListError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct RemoveRequest<'a> {
     /// The name of the emoji to be removed. Colons (`:myemoji:`) around the value are not required, although they may be included.
     pub name: Cow<'a, str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RemoveResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<RemoveResponse, RemoveError<E>>> for RemoveResponse {
@@ -230,28 +435,61 @@ impl<E: Error> Into<Result<RemoveResponse, RemoveError<E>>> for RemoveResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(RemoveError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(RemoveError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for RemoveResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum RemoveError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> RemoveError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => RemoveError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for RemoveError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => RemoveError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -259,7 +497,7 @@ impl<E: Error> fmt::Display for RemoveError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             RemoveError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            RemoveError::Unknown(ref s) => write!(f, "{}", s),
+            RemoveError::Unknown(ref s, _) => write!(f, "{}", s),
             RemoveError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -275,6 +513,32 @@ impl<E: Error + 'static> Error for RemoveError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for RemoveError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            RemoveError::Unknown(ref s, _) => Some(s),
+            RemoveError::MalformedResponse(..) | RemoveError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            RemoveError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            RemoveError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            RemoveError::Unknown(_, ref context) => Some(context),
+            RemoveError::MalformedResponse(..) | RemoveError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct RenameRequest<'a> {
     /// The name of the emoji to be renamed. Colons (`:myemoji:`) around the value are not required, although they may be included.
@@ -283,10 +547,17 @@ pub struct RenameRequest<'a> {
     pub new_name: Cow<'a, str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RenameResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<RenameResponse, RenameError<E>>> for RenameResponse {
@@ -294,28 +565,61 @@ impl<E: Error> Into<Result<RenameResponse, RenameError<E>>> for RenameResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(RenameError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(RenameError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for RenameResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum RenameError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> RenameError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => RenameError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for RenameError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => RenameError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -323,7 +627,7 @@ impl<E: Error> fmt::Display for RenameError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             RenameError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            RenameError::Unknown(ref s) => write!(f, "{}", s),
+            RenameError::Unknown(ref s, _) => write!(f, "{}", s),
             RenameError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -338,3 +642,29 @@ impl<E: Error + 'static> Error for RenameError<E> {
         }
     }
 }
+
+impl<E: Error + 'static> crate::MethodError for RenameError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            RenameError::Unknown(ref s, _) => Some(s),
+            RenameError::MalformedResponse(..) | RenameError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            RenameError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            RenameError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            RenameError::Unknown(_, ref context) => Some(context),
+            RenameError::MalformedResponse(..) | RenameError::Client(_) => None,
+        }
+    }
+}
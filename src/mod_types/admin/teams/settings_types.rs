@@ -22,14 +22,21 @@ use std::error::Error;
 use std::fmt;
 
 #[derive(Clone, Default, Debug)]
-pub struct InfoRequest<'a> {
-    pub team_id: Cow<'a, str>,
+pub struct InfoRequest {
+    pub team_id: Option<crate::TeamId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<InfoResponse, InfoError<E>>> for InfoResponse {
@@ -37,28 +44,61 @@ impl<E: Error> Into<Result<InfoResponse, InfoError<E>>> for InfoResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(InfoError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(InfoError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for InfoResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum InfoError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> InfoError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => InfoError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for InfoError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => InfoError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -66,7 +106,7 @@ impl<E: Error> fmt::Display for InfoError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             InfoError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            InfoError::Unknown(ref s) => write!(f, "{}", s),
+            InfoError::Unknown(ref s, _) => write!(f, "{}", s),
             InfoError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -82,18 +122,51 @@ impl<E: Error + 'static> Error for InfoError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for InfoError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            InfoError::Unknown(ref s, _) => Some(s),
+            InfoError::MalformedResponse(..) | InfoError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            InfoError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            InfoError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            InfoError::Unknown(_, ref context) => Some(context),
+            InfoError::MalformedResponse(..) | InfoError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct SetDefaultChannelsRequest<'a> {
     /// An array of channel IDs.
     pub channel_ids: Cow<'a, str>,
     /// ID for the workspace to set the default channel for.
-    pub team_id: Cow<'a, str>,
+    pub team_id: Option<crate::TeamId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetDefaultChannelsResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<SetDefaultChannelsResponse, SetDefaultChannelsError<E>>>
@@ -103,28 +176,61 @@ impl<E: Error> Into<Result<SetDefaultChannelsResponse, SetDefaultChannelsError<E
         if self.ok {
             Ok(self)
         } else {
-            Err(SetDefaultChannelsError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(SetDefaultChannelsError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for SetDefaultChannelsResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum SetDefaultChannelsError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> SetDefaultChannelsError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => SetDefaultChannelsError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for SetDefaultChannelsError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => SetDefaultChannelsError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -132,7 +238,7 @@ impl<E: Error> fmt::Display for SetDefaultChannelsError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             SetDefaultChannelsError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            SetDefaultChannelsError::Unknown(ref s) => write!(f, "{}", s),
+            SetDefaultChannelsError::Unknown(ref s, _) => write!(f, "{}", s),
             SetDefaultChannelsError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -148,18 +254,55 @@ impl<E: Error + 'static> Error for SetDefaultChannelsError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for SetDefaultChannelsError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            SetDefaultChannelsError::Unknown(ref s, _) => Some(s),
+            SetDefaultChannelsError::MalformedResponse(..) | SetDefaultChannelsError::Client(_) => {
+                None
+            }
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            SetDefaultChannelsError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            SetDefaultChannelsError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            SetDefaultChannelsError::Unknown(_, ref context) => Some(context),
+            SetDefaultChannelsError::MalformedResponse(..) | SetDefaultChannelsError::Client(_) => {
+                None
+            }
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct SetDescriptionRequest<'a> {
     /// The new description for the workspace.
     pub description: Cow<'a, str>,
     /// ID for the workspace to set the description for.
-    pub team_id: Cow<'a, str>,
+    pub team_id: Option<crate::TeamId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetDescriptionResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<SetDescriptionResponse, SetDescriptionError<E>>>
@@ -169,28 +312,61 @@ impl<E: Error> Into<Result<SetDescriptionResponse, SetDescriptionError<E>>>
         if self.ok {
             Ok(self)
         } else {
-            Err(SetDescriptionError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(SetDescriptionError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for SetDescriptionResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum SetDescriptionError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> SetDescriptionError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => SetDescriptionError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for SetDescriptionError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => SetDescriptionError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -198,7 +374,7 @@ impl<E: Error> fmt::Display for SetDescriptionError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             SetDescriptionError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            SetDescriptionError::Unknown(ref s) => write!(f, "{}", s),
+            SetDescriptionError::Unknown(ref s, _) => write!(f, "{}", s),
             SetDescriptionError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -214,18 +390,51 @@ impl<E: Error + 'static> Error for SetDescriptionError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for SetDescriptionError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            SetDescriptionError::Unknown(ref s, _) => Some(s),
+            SetDescriptionError::MalformedResponse(..) | SetDescriptionError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            SetDescriptionError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            SetDescriptionError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            SetDescriptionError::Unknown(_, ref context) => Some(context),
+            SetDescriptionError::MalformedResponse(..) | SetDescriptionError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct SetDiscoverabilityRequest<'a> {
     /// This workspace's discovery setting. It must be set to one of `open`, `invite_only`, `closed`, or `unlisted`.
     pub discoverability: Cow<'a, str>,
     /// The ID of the workspace to set discoverability on.
-    pub team_id: Cow<'a, str>,
+    pub team_id: Option<crate::TeamId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetDiscoverabilityResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<SetDiscoverabilityResponse, SetDiscoverabilityError<E>>>
@@ -235,28 +444,61 @@ impl<E: Error> Into<Result<SetDiscoverabilityResponse, SetDiscoverabilityError<E
         if self.ok {
             Ok(self)
         } else {
-            Err(SetDiscoverabilityError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(SetDiscoverabilityError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for SetDiscoverabilityResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum SetDiscoverabilityError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> SetDiscoverabilityError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => SetDiscoverabilityError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for SetDiscoverabilityError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => SetDiscoverabilityError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -264,7 +506,7 @@ impl<E: Error> fmt::Display for SetDiscoverabilityError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             SetDiscoverabilityError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            SetDiscoverabilityError::Unknown(ref s) => write!(f, "{}", s),
+            SetDiscoverabilityError::Unknown(ref s, _) => write!(f, "{}", s),
             SetDiscoverabilityError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -280,18 +522,55 @@ impl<E: Error + 'static> Error for SetDiscoverabilityError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for SetDiscoverabilityError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            SetDiscoverabilityError::Unknown(ref s, _) => Some(s),
+            SetDiscoverabilityError::MalformedResponse(..) | SetDiscoverabilityError::Client(_) => {
+                None
+            }
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            SetDiscoverabilityError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            SetDiscoverabilityError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            SetDiscoverabilityError::Unknown(_, ref context) => Some(context),
+            SetDiscoverabilityError::MalformedResponse(..) | SetDiscoverabilityError::Client(_) => {
+                None
+            }
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct SetIconRequest<'a> {
     /// Image URL for the icon
     pub image_url: Cow<'a, str>,
     /// ID for the workspace to set the icon for.
-    pub team_id: Cow<'a, str>,
+    pub team_id: Option<crate::TeamId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetIconResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<SetIconResponse, SetIconError<E>>> for SetIconResponse {
@@ -299,28 +578,61 @@ impl<E: Error> Into<Result<SetIconResponse, SetIconError<E>>> for SetIconRespons
         if self.ok {
             Ok(self)
         } else {
-            Err(SetIconError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(SetIconError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for SetIconResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum SetIconError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> SetIconError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => SetIconError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for SetIconError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => SetIconError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -328,7 +640,7 @@ impl<E: Error> fmt::Display for SetIconError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             SetIconError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            SetIconError::Unknown(ref s) => write!(f, "{}", s),
+            SetIconError::Unknown(ref s, _) => write!(f, "{}", s),
             SetIconError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -344,18 +656,51 @@ impl<E: Error + 'static> Error for SetIconError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for SetIconError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            SetIconError::Unknown(ref s, _) => Some(s),
+            SetIconError::MalformedResponse(..) | SetIconError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            SetIconError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            SetIconError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            SetIconError::Unknown(_, ref context) => Some(context),
+            SetIconError::MalformedResponse(..) | SetIconError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct SetNameRequest<'a> {
     /// The new name of the workspace.
     pub name: Cow<'a, str>,
     /// ID for the workspace to set the name for.
-    pub team_id: Cow<'a, str>,
+    pub team_id: Option<crate::TeamId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetNameResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<SetNameResponse, SetNameError<E>>> for SetNameResponse {
@@ -363,28 +708,61 @@ impl<E: Error> Into<Result<SetNameResponse, SetNameError<E>>> for SetNameRespons
         if self.ok {
             Ok(self)
         } else {
-            Err(SetNameError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(SetNameError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for SetNameResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum SetNameError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> SetNameError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => SetNameError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for SetNameError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => SetNameError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -392,7 +770,7 @@ impl<E: Error> fmt::Display for SetNameError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             SetNameError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            SetNameError::Unknown(ref s) => write!(f, "{}", s),
+            SetNameError::Unknown(ref s, _) => write!(f, "{}", s),
             SetNameError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -407,3 +785,29 @@ impl<E: Error + 'static> Error for SetNameError<E> {
         }
     }
 }
+
+impl<E: Error + 'static> crate::MethodError for SetNameError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            SetNameError::Unknown(ref s, _) => Some(s),
+            SetNameError::MalformedResponse(..) | SetNameError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            SetNameError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            SetNameError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            SetNameError::Unknown(_, ref context) => Some(context),
+            SetNameError::MalformedResponse(..) | SetNameError::Client(_) => None,
+        }
+    }
+}
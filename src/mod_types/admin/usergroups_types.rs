@@ -26,15 +26,22 @@ pub struct AddChannelsRequest<'a> {
     /// Comma separated string of channel IDs.
     pub channel_ids: Cow<'a, str>,
     /// The workspace to add default channels in.
-    pub team_id: Option<Cow<'a, str>>,
+    pub team_id: Option<crate::TeamId>,
     /// ID of the IDP group to add default channels for.
-    pub usergroup_id: Cow<'a, str>,
+    pub usergroup_id: Option<crate::UsergroupId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AddChannelsResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<AddChannelsResponse, AddChannelsError<E>>> for AddChannelsResponse {
@@ -42,28 +49,61 @@ impl<E: Error> Into<Result<AddChannelsResponse, AddChannelsError<E>>> for AddCha
         if self.ok {
             Ok(self)
         } else {
-            Err(AddChannelsError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(AddChannelsError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for AddChannelsResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum AddChannelsError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> AddChannelsError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => AddChannelsError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for AddChannelsError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => AddChannelsError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -71,7 +111,7 @@ impl<E: Error> fmt::Display for AddChannelsError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             AddChannelsError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            AddChannelsError::Unknown(ref s) => write!(f, "{}", s),
+            AddChannelsError::Unknown(ref s, _) => write!(f, "{}", s),
             AddChannelsError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -87,6 +127,32 @@ impl<E: Error + 'static> Error for AddChannelsError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for AddChannelsError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            AddChannelsError::Unknown(ref s, _) => Some(s),
+            AddChannelsError::MalformedResponse(..) | AddChannelsError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            AddChannelsError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            AddChannelsError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            AddChannelsError::Unknown(_, ref context) => Some(context),
+            AddChannelsError::MalformedResponse(..) | AddChannelsError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct AddTeamsRequest<'a> {
     /// When `true`, this method automatically creates new workspace accounts for the IDP group members.
@@ -94,13 +160,20 @@ pub struct AddTeamsRequest<'a> {
     /// A comma separated list of encoded team (workspace) IDs. Each workspace *MUST* belong to the organization associated with the token.
     pub team_ids: Cow<'a, str>,
     /// An encoded usergroup (IDP Group) ID.
-    pub usergroup_id: Cow<'a, str>,
+    pub usergroup_id: Option<crate::UsergroupId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AddTeamsResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<AddTeamsResponse, AddTeamsError<E>>> for AddTeamsResponse {
@@ -108,28 +181,61 @@ impl<E: Error> Into<Result<AddTeamsResponse, AddTeamsError<E>>> for AddTeamsResp
         if self.ok {
             Ok(self)
         } else {
-            Err(AddTeamsError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(AddTeamsError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for AddTeamsResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum AddTeamsError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> AddTeamsError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => AddTeamsError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for AddTeamsError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => AddTeamsError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -137,7 +243,7 @@ impl<E: Error> fmt::Display for AddTeamsError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             AddTeamsError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            AddTeamsError::Unknown(ref s) => write!(f, "{}", s),
+            AddTeamsError::Unknown(ref s, _) => write!(f, "{}", s),
             AddTeamsError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -153,20 +259,53 @@ impl<E: Error + 'static> Error for AddTeamsError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for AddTeamsError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            AddTeamsError::Unknown(ref s, _) => Some(s),
+            AddTeamsError::MalformedResponse(..) | AddTeamsError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            AddTeamsError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            AddTeamsError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            AddTeamsError::Unknown(_, ref context) => Some(context),
+            AddTeamsError::MalformedResponse(..) | AddTeamsError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
-pub struct ListChannelsRequest<'a> {
+pub struct ListChannelsRequest {
     /// Flag to include or exclude the count of members per channel.
     pub include_num_members: Option<bool>,
     /// ID of the the workspace.
-    pub team_id: Option<Cow<'a, str>>,
+    pub team_id: Option<crate::TeamId>,
     /// ID of the IDP group to list default channels for.
-    pub usergroup_id: Cow<'a, str>,
+    pub usergroup_id: Option<crate::UsergroupId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListChannelsResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<ListChannelsResponse, ListChannelsError<E>>> for ListChannelsResponse {
@@ -174,28 +313,61 @@ impl<E: Error> Into<Result<ListChannelsResponse, ListChannelsError<E>>> for List
         if self.ok {
             Ok(self)
         } else {
-            Err(ListChannelsError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(ListChannelsError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for ListChannelsResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum ListChannelsError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> ListChannelsError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => ListChannelsError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for ListChannelsError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => ListChannelsError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -203,7 +375,7 @@ impl<E: Error> fmt::Display for ListChannelsError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             ListChannelsError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            ListChannelsError::Unknown(ref s) => write!(f, "{}", s),
+            ListChannelsError::Unknown(ref s, _) => write!(f, "{}", s),
             ListChannelsError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -219,18 +391,51 @@ impl<E: Error + 'static> Error for ListChannelsError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for ListChannelsError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            ListChannelsError::Unknown(ref s, _) => Some(s),
+            ListChannelsError::MalformedResponse(..) | ListChannelsError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            ListChannelsError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            ListChannelsError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            ListChannelsError::Unknown(_, ref context) => Some(context),
+            ListChannelsError::MalformedResponse(..) | ListChannelsError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct RemoveChannelsRequest<'a> {
     /// Comma-separated string of channel IDs
     pub channel_ids: Cow<'a, str>,
     /// ID of the IDP Group
-    pub usergroup_id: Cow<'a, str>,
+    pub usergroup_id: Option<crate::UsergroupId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RemoveChannelsResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<RemoveChannelsResponse, RemoveChannelsError<E>>>
@@ -240,28 +445,61 @@ impl<E: Error> Into<Result<RemoveChannelsResponse, RemoveChannelsError<E>>>
         if self.ok {
             Ok(self)
         } else {
-            Err(RemoveChannelsError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(RemoveChannelsError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for RemoveChannelsResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum RemoveChannelsError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> RemoveChannelsError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => RemoveChannelsError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for RemoveChannelsError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => RemoveChannelsError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -269,7 +507,7 @@ impl<E: Error> fmt::Display for RemoveChannelsError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             RemoveChannelsError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            RemoveChannelsError::Unknown(ref s) => write!(f, "{}", s),
+            RemoveChannelsError::Unknown(ref s, _) => write!(f, "{}", s),
             RemoveChannelsError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -284,3 +522,29 @@ impl<E: Error + 'static> Error for RemoveChannelsError<E> {
         }
     }
 }
+
+impl<E: Error + 'static> crate::MethodError for RemoveChannelsError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            RemoveChannelsError::Unknown(ref s, _) => Some(s),
+            RemoveChannelsError::MalformedResponse(..) | RemoveChannelsError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            RemoveChannelsError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            RemoveChannelsError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            RemoveChannelsError::Unknown(_, ref context) => Some(context),
+            RemoveChannelsError::MalformedResponse(..) | RemoveChannelsError::Client(_) => None,
+        }
+    }
+}
@@ -32,15 +32,22 @@ pub struct AssignRequest<'a> {
     /// True if user should be added to the workspace as a single-channel guest.
     pub is_ultra_restricted: Option<bool>,
     /// The ID (`T1234`) of the workspace.
-    pub team_id: Cow<'a, str>,
+    pub team_id: Option<crate::TeamId>,
     /// The ID of the user to add to the workspace.
-    pub user_id: Cow<'a, str>,
+    pub user_id: Option<crate::UserId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AssignResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<AssignResponse, AssignError<E>>> for AssignResponse {
@@ -48,28 +55,61 @@ impl<E: Error> Into<Result<AssignResponse, AssignError<E>>> for AssignResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(AssignError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(AssignError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for AssignResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum AssignError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> AssignError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => AssignError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for AssignError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => AssignError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -77,7 +117,7 @@ impl<E: Error> fmt::Display for AssignError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             AssignError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            AssignError::Unknown(ref s) => write!(f, "{}", s),
+            AssignError::Unknown(ref s, _) => write!(f, "{}", s),
             AssignError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -93,6 +133,32 @@ impl<E: Error + 'static> Error for AssignError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for AssignError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            AssignError::Unknown(ref s, _) => Some(s),
+            AssignError::MalformedResponse(..) | AssignError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            AssignError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            AssignError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            AssignError::Unknown(_, ref context) => Some(context),
+            AssignError::MalformedResponse(..) | AssignError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct InviteRequest<'a> {
     /// A comma-separated list of `channel_id`s for this user to join. At least one channel is required.
@@ -112,13 +178,20 @@ pub struct InviteRequest<'a> {
     /// Allow this invite to be resent in the future if a user has not signed up yet. (default: false)
     pub resend: Option<bool>,
     /// The ID (`T1234`) of the workspace.
-    pub team_id: Cow<'a, str>,
+    pub team_id: Option<crate::TeamId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InviteResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<InviteResponse, InviteError<E>>> for InviteResponse {
@@ -126,28 +199,61 @@ impl<E: Error> Into<Result<InviteResponse, InviteError<E>>> for InviteResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(InviteError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(InviteError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for InviteResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum InviteError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> InviteError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => InviteError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for InviteError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => InviteError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -155,7 +261,7 @@ impl<E: Error> fmt::Display for InviteError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             InviteError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            InviteError::Unknown(ref s) => write!(f, "{}", s),
+            InviteError::Unknown(ref s, _) => write!(f, "{}", s),
             InviteError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -171,6 +277,32 @@ impl<E: Error + 'static> Error for InviteError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for InviteError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            InviteError::Unknown(ref s, _) => Some(s),
+            InviteError::MalformedResponse(..) | InviteError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            InviteError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            InviteError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            InviteError::Unknown(_, ref context) => Some(context),
+            InviteError::MalformedResponse(..) | InviteError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct ListRequest<'a> {
     /// Set `cursor` to `next_cursor` returned by the previous call to list items in the next page.
@@ -178,13 +310,27 @@ pub struct ListRequest<'a> {
     /// Limit for how many users to be retrieved per page
     pub limit: Option<u64>,
     /// The ID (`T1234`) of the workspace.
-    pub team_id: Cow<'a, str>,
+    pub team_id: Option<crate::TeamId>,
+}
+
+impl crate::pagination::CursorRequest for ListRequest<'_> {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor.into());
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub users: Option<Vec<serde_json::Value>>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
@@ -192,28 +338,75 @@ impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(ListError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(ListError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for ListResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
+impl crate::pagination::CursorPage for ListResponse {
+    type Item = serde_json::Value;
+
+    fn next_cursor(&self) -> Option<&str> {
+        self.response_metadata
+            .as_ref()
+            .and_then(|response_metadata| response_metadata.next_cursor.as_deref())
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.users.unwrap_or_default()
+    }
+}
+
 #[derive(Debug)]
 pub enum ListError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> ListError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => ListError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for ListError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => ListError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -221,7 +414,7 @@ impl<E: Error> fmt::Display for ListError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             ListError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            ListError::Unknown(ref s) => write!(f, "{}", s),
+            ListError::Unknown(ref s, _) => write!(f, "{}", s),
             ListError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -237,18 +430,51 @@ impl<E: Error + 'static> Error for ListError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for ListError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            ListError::Unknown(ref s, _) => Some(s),
+            ListError::MalformedResponse(..) | ListError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            ListError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            ListError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            ListError::Unknown(_, ref context) => Some(context),
+            ListError::MalformedResponse(..) | ListError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
-pub struct RemoveRequest<'a> {
+pub struct RemoveRequest {
     /// The ID (`T1234`) of the workspace.
-    pub team_id: Cow<'a, str>,
+    pub team_id: Option<crate::TeamId>,
     /// The ID of the user to remove.
-    pub user_id: Cow<'a, str>,
+    pub user_id: Option<crate::UserId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RemoveResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<RemoveResponse, RemoveError<E>>> for RemoveResponse {
@@ -256,28 +482,61 @@ impl<E: Error> Into<Result<RemoveResponse, RemoveError<E>>> for RemoveResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(RemoveError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(RemoveError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for RemoveResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum RemoveError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> RemoveError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => RemoveError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for RemoveError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => RemoveError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -285,7 +544,7 @@ impl<E: Error> fmt::Display for RemoveError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             RemoveError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            RemoveError::Unknown(ref s) => write!(f, "{}", s),
+            RemoveError::Unknown(ref s, _) => write!(f, "{}", s),
             RemoveError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -301,18 +560,51 @@ impl<E: Error + 'static> Error for RemoveError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for RemoveError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            RemoveError::Unknown(ref s, _) => Some(s),
+            RemoveError::MalformedResponse(..) | RemoveError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            RemoveError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            RemoveError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            RemoveError::Unknown(_, ref context) => Some(context),
+            RemoveError::MalformedResponse(..) | RemoveError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
-pub struct SetAdminRequest<'a> {
+pub struct SetAdminRequest {
     /// The ID (`T1234`) of the workspace.
-    pub team_id: Cow<'a, str>,
+    pub team_id: Option<crate::TeamId>,
     /// The ID of the user to designate as an admin.
-    pub user_id: Cow<'a, str>,
+    pub user_id: Option<crate::UserId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetAdminResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<SetAdminResponse, SetAdminError<E>>> for SetAdminResponse {
@@ -320,28 +612,61 @@ impl<E: Error> Into<Result<SetAdminResponse, SetAdminError<E>>> for SetAdminResp
         if self.ok {
             Ok(self)
         } else {
-            Err(SetAdminError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(SetAdminError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for SetAdminResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum SetAdminError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> SetAdminError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => SetAdminError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for SetAdminError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => SetAdminError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -349,7 +674,7 @@ impl<E: Error> fmt::Display for SetAdminError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             SetAdminError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            SetAdminError::Unknown(ref s) => write!(f, "{}", s),
+            SetAdminError::Unknown(ref s, _) => write!(f, "{}", s),
             SetAdminError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -365,20 +690,53 @@ impl<E: Error + 'static> Error for SetAdminError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for SetAdminError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            SetAdminError::Unknown(ref s, _) => Some(s),
+            SetAdminError::MalformedResponse(..) | SetAdminError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            SetAdminError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            SetAdminError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            SetAdminError::Unknown(_, ref context) => Some(context),
+            SetAdminError::MalformedResponse(..) | SetAdminError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
-pub struct SetExpirationRequest<'a> {
+pub struct SetExpirationRequest {
     /// Timestamp when guest account should be disabled.
     pub expiration_ts: u64,
     /// The ID (`T1234`) of the workspace.
-    pub team_id: Cow<'a, str>,
+    pub team_id: Option<crate::TeamId>,
     /// The ID of the user to set an expiration for.
-    pub user_id: Cow<'a, str>,
+    pub user_id: Option<crate::UserId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetExpirationResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<SetExpirationResponse, SetExpirationError<E>>>
@@ -388,28 +746,61 @@ impl<E: Error> Into<Result<SetExpirationResponse, SetExpirationError<E>>>
         if self.ok {
             Ok(self)
         } else {
-            Err(SetExpirationError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(SetExpirationError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for SetExpirationResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum SetExpirationError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> SetExpirationError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => SetExpirationError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for SetExpirationError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => SetExpirationError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -417,7 +808,7 @@ impl<E: Error> fmt::Display for SetExpirationError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             SetExpirationError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            SetExpirationError::Unknown(ref s) => write!(f, "{}", s),
+            SetExpirationError::Unknown(ref s, _) => write!(f, "{}", s),
             SetExpirationError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -433,18 +824,51 @@ impl<E: Error + 'static> Error for SetExpirationError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for SetExpirationError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            SetExpirationError::Unknown(ref s, _) => Some(s),
+            SetExpirationError::MalformedResponse(..) | SetExpirationError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            SetExpirationError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            SetExpirationError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            SetExpirationError::Unknown(_, ref context) => Some(context),
+            SetExpirationError::MalformedResponse(..) | SetExpirationError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
-pub struct SetOwnerRequest<'a> {
+pub struct SetOwnerRequest {
     /// The ID (`T1234`) of the workspace.
-    pub team_id: Cow<'a, str>,
+    pub team_id: Option<crate::TeamId>,
     /// Id of the user to promote to owner.
-    pub user_id: Cow<'a, str>,
+    pub user_id: Option<crate::UserId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetOwnerResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<SetOwnerResponse, SetOwnerError<E>>> for SetOwnerResponse {
@@ -452,28 +876,61 @@ impl<E: Error> Into<Result<SetOwnerResponse, SetOwnerError<E>>> for SetOwnerResp
         if self.ok {
             Ok(self)
         } else {
-            Err(SetOwnerError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(SetOwnerError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for SetOwnerResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum SetOwnerError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> SetOwnerError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => SetOwnerError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for SetOwnerError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => SetOwnerError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -481,7 +938,7 @@ impl<E: Error> fmt::Display for SetOwnerError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             SetOwnerError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            SetOwnerError::Unknown(ref s) => write!(f, "{}", s),
+            SetOwnerError::Unknown(ref s, _) => write!(f, "{}", s),
             SetOwnerError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -497,18 +954,51 @@ impl<E: Error + 'static> Error for SetOwnerError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for SetOwnerError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            SetOwnerError::Unknown(ref s, _) => Some(s),
+            SetOwnerError::MalformedResponse(..) | SetOwnerError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            SetOwnerError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            SetOwnerError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            SetOwnerError::Unknown(_, ref context) => Some(context),
+            SetOwnerError::MalformedResponse(..) | SetOwnerError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
-pub struct SetRegularRequest<'a> {
+pub struct SetRegularRequest {
     /// The ID (`T1234`) of the workspace.
-    pub team_id: Cow<'a, str>,
+    pub team_id: Option<crate::TeamId>,
     /// The ID of the user to designate as a regular user.
-    pub user_id: Cow<'a, str>,
+    pub user_id: Option<crate::UserId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetRegularResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<SetRegularResponse, SetRegularError<E>>> for SetRegularResponse {
@@ -516,28 +1006,61 @@ impl<E: Error> Into<Result<SetRegularResponse, SetRegularError<E>>> for SetRegul
         if self.ok {
             Ok(self)
         } else {
-            Err(SetRegularError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(SetRegularError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for SetRegularResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum SetRegularError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> SetRegularError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => SetRegularError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for SetRegularError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => SetRegularError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -545,7 +1068,7 @@ impl<E: Error> fmt::Display for SetRegularError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             SetRegularError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            SetRegularError::Unknown(ref s) => write!(f, "{}", s),
+            SetRegularError::Unknown(ref s, _) => write!(f, "{}", s),
             SetRegularError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -560,3 +1083,29 @@ impl<E: Error + 'static> Error for SetRegularError<E> {
         }
     }
 }
+
+impl<E: Error + 'static> crate::MethodError for SetRegularError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            SetRegularError::Unknown(ref s, _) => Some(s),
+            SetRegularError::MalformedResponse(..) | SetRegularError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            SetRegularError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            SetRegularError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            SetRegularError::Unknown(_, ref context) => Some(context),
+            SetRegularError::MalformedResponse(..) | SetRegularError::Client(_) => None,
+        }
+    }
+}
@@ -22,16 +22,23 @@ use std::error::Error;
 use std::fmt;
 
 #[derive(Clone, Default, Debug)]
-pub struct InvalidateRequest<'a> {
+pub struct InvalidateRequest {
     pub session_id: u64,
     /// ID of the team that the session belongs to
-    pub team_id: Cow<'a, str>,
+    pub team_id: Option<crate::TeamId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InvalidateResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<InvalidateResponse, InvalidateError<E>>> for InvalidateResponse {
@@ -39,28 +46,61 @@ impl<E: Error> Into<Result<InvalidateResponse, InvalidateError<E>>> for Invalida
         if self.ok {
             Ok(self)
         } else {
-            Err(InvalidateError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(InvalidateError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for InvalidateResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum InvalidateError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> InvalidateError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => InvalidateError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for InvalidateError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => InvalidateError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -68,7 +108,7 @@ impl<E: Error> fmt::Display for InvalidateError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             InvalidateError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            InvalidateError::Unknown(ref s) => write!(f, "{}", s),
+            InvalidateError::Unknown(ref s, _) => write!(f, "{}", s),
             InvalidateError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -84,20 +124,53 @@ impl<E: Error + 'static> Error for InvalidateError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for InvalidateError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            InvalidateError::Unknown(ref s, _) => Some(s),
+            InvalidateError::MalformedResponse(..) | InvalidateError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            InvalidateError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            InvalidateError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            InvalidateError::Unknown(_, ref context) => Some(context),
+            InvalidateError::MalformedResponse(..) | InvalidateError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
-pub struct ResetRequest<'a> {
+pub struct ResetRequest {
     /// Only expire mobile sessions (default: false)
     pub mobile_only: Option<bool>,
     /// The ID of the user to wipe sessions for
-    pub user_id: Cow<'a, str>,
+    pub user_id: Option<crate::UserId>,
     /// Only expire web sessions (default: false)
     pub web_only: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ResetResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<ResetResponse, ResetError<E>>> for ResetResponse {
@@ -105,28 +178,61 @@ impl<E: Error> Into<Result<ResetResponse, ResetError<E>>> for ResetResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(ResetError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(ResetError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for ResetResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum ResetError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> ResetError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => ResetError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for ResetError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => ResetError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -134,7 +240,7 @@ impl<E: Error> fmt::Display for ResetError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             ResetError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            ResetError::Unknown(ref s) => write!(f, "{}", s),
+            ResetError::Unknown(ref s, _) => write!(f, "{}", s),
             ResetError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -149,3 +255,29 @@ impl<E: Error + 'static> Error for ResetError<E> {
         }
     }
 }
+
+impl<E: Error + 'static> crate::MethodError for ResetError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            ResetError::Unknown(ref s, _) => Some(s),
+            ResetError::MalformedResponse(..) | ResetError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            ResetError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            ResetError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            ResetError::Unknown(_, ref context) => Some(context),
+            ResetError::MalformedResponse(..) | ResetError::Client(_) => None,
+        }
+    }
+}
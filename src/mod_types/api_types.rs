@@ -29,11 +29,17 @@ pub struct TestRequest<'a> {
     pub foo: Option<Cow<'a, str>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct TestResponse {
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<TestResponse, TestError<E>>> for TestResponse {
@@ -41,26 +47,61 @@ impl<E: Error> Into<Result<TestResponse, TestError<E>>> for TestResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(TestError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for TestResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum TestError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> TestError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => TestError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for TestError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => TestError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -68,7 +109,7 @@ impl<E: Error> fmt::Display for TestError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             TestError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            TestError::Unknown(ref s) => write!(f, "{}", s),
+            TestError::Unknown(ref s, _) => write!(f, "{}", s),
             TestError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -83,3 +124,29 @@ impl<E: Error + 'static> Error for TestError<E> {
         }
     }
 }
+
+impl<E: Error + 'static> crate::MethodError for TestError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            TestError::Unknown(ref s, _) => Some(s),
+            TestError::MalformedResponse(..) | TestError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            TestError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            TestError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            TestError::Unknown(_, ref context) => Some(context),
+            TestError::MalformedResponse(..) | TestError::Client(_) => None,
+        }
+    }
+}
@@ -32,12 +32,18 @@ pub struct UninstallRequest<'a> {
     pub client_secret: Option<Cow<'a, str>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UninstallResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<UninstallResponse, UninstallError<E>>> for UninstallResponse {
@@ -45,104 +51,159 @@ impl<E: Error> Into<Result<UninstallResponse, UninstallError<E>>> for UninstallR
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(UninstallError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for UninstallResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum UninstallError<E: Error> {
-    AccountInactive,
-    BadClientSecret,
-    ClientIdTokenMismatch,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidClientId,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    OrgLoginRequired,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequired,
-    UserIsBot,
+    AccountInactive(crate::ErrorContext),
+    BadClientSecret(crate::ErrorContext),
+    ClientIdTokenMismatch(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidClientId(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserIsBot(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> UninstallError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => UninstallError::AccountInactive(context),
+            "bad_client_secret" => UninstallError::BadClientSecret(context),
+            "client_id_token_mismatch" => UninstallError::ClientIdTokenMismatch(context),
+            "fatal_error" => UninstallError::FatalError(context),
+            "invalid_arg_name" => UninstallError::InvalidArgName(context),
+            "invalid_array_arg" => UninstallError::InvalidArrayArg(context),
+            "invalid_auth" => UninstallError::InvalidAuth(context),
+            "invalid_charset" => UninstallError::InvalidCharset(context),
+            "invalid_client_id" => UninstallError::InvalidClientId(context),
+            "invalid_form_data" => UninstallError::InvalidFormData(context),
+            "invalid_json" => UninstallError::InvalidJson(context),
+            "invalid_post_type" => UninstallError::InvalidPostType(context),
+            "json_not_object" => UninstallError::JsonNotObject(context),
+            "missing_post_type" => UninstallError::MissingPostType(context),
+            "no_permission" => UninstallError::NoPermission(context),
+            "not_authed" => UninstallError::NotAuthed(context),
+            "org_login_required" => UninstallError::OrgLoginRequired(context),
+            "request_timeout" => UninstallError::RequestTimeout(context),
+            "team_added_to_org" => UninstallError::TeamAddedToOrg(context),
+            "token_revoked" => UninstallError::TokenRevoked(context),
+            "upgrade_required" => UninstallError::UpgradeRequired(context),
+            "user_is_bot" => UninstallError::UserIsBot(context),
+            _ => UninstallError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for UninstallError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => UninstallError::AccountInactive,
-            "bad_client_secret" => UninstallError::BadClientSecret,
-            "client_id_token_mismatch" => UninstallError::ClientIdTokenMismatch,
-            "fatal_error" => UninstallError::FatalError,
-            "invalid_arg_name" => UninstallError::InvalidArgName,
-            "invalid_array_arg" => UninstallError::InvalidArrayArg,
-            "invalid_auth" => UninstallError::InvalidAuth,
-            "invalid_charset" => UninstallError::InvalidCharset,
-            "invalid_client_id" => UninstallError::InvalidClientId,
-            "invalid_form_data" => UninstallError::InvalidFormData,
-            "invalid_json" => UninstallError::InvalidJson,
-            "invalid_post_type" => UninstallError::InvalidPostType,
-            "json_not_object" => UninstallError::JsonNotObject,
-            "missing_post_type" => UninstallError::MissingPostType,
-            "no_permission" => UninstallError::NoPermission,
-            "not_authed" => UninstallError::NotAuthed,
-            "org_login_required" => UninstallError::OrgLoginRequired,
-            "request_timeout" => UninstallError::RequestTimeout,
-            "team_added_to_org" => UninstallError::TeamAddedToOrg,
-            "token_revoked" => UninstallError::TokenRevoked,
-            "upgrade_required" => UninstallError::UpgradeRequired,
-            "user_is_bot" => UninstallError::UserIsBot,
-            _ => UninstallError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for UninstallError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            UninstallError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            UninstallError::BadClientSecret => write!(f, "Server returned error bad_client_secret"),
-            UninstallError::ClientIdTokenMismatch => {
+            UninstallError::AccountInactive(_) => {
+                write!(f, "Server returned error account_inactive")
+            }
+            UninstallError::BadClientSecret(_) => {
+                write!(f, "Server returned error bad_client_secret")
+            }
+            UninstallError::ClientIdTokenMismatch(_) => {
                 write!(f, "Server returned error client_id_token_mismatch")
             }
-            UninstallError::FatalError => write!(f, "Server returned error fatal_error"),
-            UninstallError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            UninstallError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            UninstallError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            UninstallError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            UninstallError::InvalidClientId => write!(f, "Server returned error invalid_client_id"),
-            UninstallError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            UninstallError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            UninstallError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            UninstallError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            UninstallError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            UninstallError::NoPermission => write!(f, "Server returned error no_permission"),
-            UninstallError::NotAuthed => write!(f, "Server returned error not_authed"),
-            UninstallError::OrgLoginRequired => {
+            UninstallError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            UninstallError::InvalidArgName(_) => {
+                write!(f, "Server returned error invalid_arg_name")
+            }
+            UninstallError::InvalidArrayArg(_) => {
+                write!(f, "Server returned error invalid_array_arg")
+            }
+            UninstallError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            UninstallError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            UninstallError::InvalidClientId(_) => {
+                write!(f, "Server returned error invalid_client_id")
+            }
+            UninstallError::InvalidFormData(_) => {
+                write!(f, "Server returned error invalid_form_data")
+            }
+            UninstallError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            UninstallError::InvalidPostType(_) => {
+                write!(f, "Server returned error invalid_post_type")
+            }
+            UninstallError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            UninstallError::MissingPostType(_) => {
+                write!(f, "Server returned error missing_post_type")
+            }
+            UninstallError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            UninstallError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            UninstallError::OrgLoginRequired(_) => {
                 write!(f, "Server returned error org_login_required")
             }
-            UninstallError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            UninstallError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            UninstallError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            UninstallError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
-            UninstallError::UserIsBot => write!(f, "Server returned error user_is_bot"),
+            UninstallError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            UninstallError::TeamAddedToOrg(_) => {
+                write!(f, "Server returned error team_added_to_org")
+            }
+            UninstallError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            UninstallError::UpgradeRequired(_) => {
+                write!(f, "Server returned error upgrade_required")
+            }
+            UninstallError::UserIsBot(_) => write!(f, "Server returned error user_is_bot"),
             UninstallError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            UninstallError::Unknown(ref s) => write!(f, "{}", s),
+            UninstallError::Unknown(ref s, _) => write!(f, "{}", s),
             UninstallError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -157,3 +218,73 @@ impl<E: Error + 'static> Error for UninstallError<E> {
         }
     }
 }
+
+impl<E: Error + 'static> crate::MethodError for UninstallError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            UninstallError::AccountInactive(_) => Some("account_inactive"),
+            UninstallError::BadClientSecret(_) => Some("bad_client_secret"),
+            UninstallError::ClientIdTokenMismatch(_) => Some("client_id_token_mismatch"),
+            UninstallError::FatalError(_) => Some("fatal_error"),
+            UninstallError::InvalidArgName(_) => Some("invalid_arg_name"),
+            UninstallError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            UninstallError::InvalidAuth(_) => Some("invalid_auth"),
+            UninstallError::InvalidCharset(_) => Some("invalid_charset"),
+            UninstallError::InvalidClientId(_) => Some("invalid_client_id"),
+            UninstallError::InvalidFormData(_) => Some("invalid_form_data"),
+            UninstallError::InvalidJson(_) => Some("invalid_json"),
+            UninstallError::InvalidPostType(_) => Some("invalid_post_type"),
+            UninstallError::JsonNotObject(_) => Some("json_not_object"),
+            UninstallError::MissingPostType(_) => Some("missing_post_type"),
+            UninstallError::NoPermission(_) => Some("no_permission"),
+            UninstallError::NotAuthed(_) => Some("not_authed"),
+            UninstallError::OrgLoginRequired(_) => Some("org_login_required"),
+            UninstallError::RequestTimeout(_) => Some("request_timeout"),
+            UninstallError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            UninstallError::TokenRevoked(_) => Some("token_revoked"),
+            UninstallError::UpgradeRequired(_) => Some("upgrade_required"),
+            UninstallError::UserIsBot(_) => Some("user_is_bot"),
+            UninstallError::Unknown(ref s, _) => Some(s),
+            UninstallError::MalformedResponse(..) | UninstallError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            UninstallError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            UninstallError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            UninstallError::AccountInactive(ref context)
+            | UninstallError::BadClientSecret(ref context)
+            | UninstallError::ClientIdTokenMismatch(ref context)
+            | UninstallError::FatalError(ref context)
+            | UninstallError::InvalidArgName(ref context)
+            | UninstallError::InvalidArrayArg(ref context)
+            | UninstallError::InvalidAuth(ref context)
+            | UninstallError::InvalidCharset(ref context)
+            | UninstallError::InvalidClientId(ref context)
+            | UninstallError::InvalidFormData(ref context)
+            | UninstallError::InvalidJson(ref context)
+            | UninstallError::InvalidPostType(ref context)
+            | UninstallError::JsonNotObject(ref context)
+            | UninstallError::MissingPostType(ref context)
+            | UninstallError::NoPermission(ref context)
+            | UninstallError::NotAuthed(ref context)
+            | UninstallError::OrgLoginRequired(ref context)
+            | UninstallError::RequestTimeout(ref context)
+            | UninstallError::TeamAddedToOrg(ref context)
+            | UninstallError::TokenRevoked(ref context)
+            | UninstallError::UpgradeRequired(ref context)
+            | UninstallError::UserIsBot(ref context)
+            | UninstallError::Unknown(_, ref context) => Some(context),
+            UninstallError::MalformedResponse(..) | UninstallError::Client(_) => None,
+        }
+    }
+}
@@ -28,85 +28,85 @@ use std::fmt;
 #[derive(Clone, Default, Debug)]
 pub struct InfoRequest {}
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoResourcesInner {
     pub excluded_ids: Option<Vec<Vec<String>>>,
     pub ids: Vec<Vec<String>>,
     pub wildcard: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoAppHomeInner {
     pub resources: Option<InfoResourcesInner>,
     pub scopes: Option<Vec<String>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoResources1Inner {
     pub excluded_ids: Option<Vec<Vec<String>>>,
     pub ids: Vec<Vec<String>>,
     pub wildcard: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoChannelInner {
     pub resources: Option<InfoResources1Inner>,
     pub scopes: Option<Vec<String>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoResources2Inner {
     pub excluded_ids: Option<Vec<Vec<String>>>,
     pub ids: Vec<Vec<String>>,
     pub wildcard: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoGroupInner {
     pub resources: Option<InfoResources2Inner>,
     pub scopes: Option<Vec<String>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoResources3Inner {
     pub excluded_ids: Option<Vec<Vec<String>>>,
     pub ids: Vec<Vec<String>>,
     pub wildcard: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoImInner {
     pub resources: Option<InfoResources3Inner>,
     pub scopes: Option<Vec<String>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoResources4Inner {
     pub excluded_ids: Option<Vec<Vec<String>>>,
     pub ids: Vec<Vec<String>>,
     pub wildcard: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoMpimInner {
     pub resources: Option<InfoResources4Inner>,
     pub scopes: Option<Vec<String>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoResources5Inner {
     pub excluded_ids: Option<Vec<Vec<String>>>,
     pub ids: Vec<Vec<String>>,
     pub wildcard: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoTeamInner {
     pub resources: InfoResources5Inner,
     pub scopes: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoInfoInner {
     pub app_home: InfoAppHomeInner,
     pub channel: InfoChannelInner,
@@ -116,13 +116,19 @@ pub struct InfoInfoInner {
     pub team: InfoTeamInner,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoResponse {
     pub callstack: Option<String>,
     error: Option<String>,
     pub info: InfoInfoInner,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<InfoResponse, InfoError<E>>> for InfoResponse {
@@ -130,91 +136,126 @@ impl<E: Error> Into<Result<InfoResponse, InfoError<E>>> for InfoResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(InfoError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for InfoResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum InfoError<E: Error> {
-    AccountInactive,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    OrgLoginRequired,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequired,
-    UserIsBot,
+    AccountInactive(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserIsBot(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> InfoError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => InfoError::AccountInactive(context),
+            "fatal_error" => InfoError::FatalError(context),
+            "invalid_arg_name" => InfoError::InvalidArgName(context),
+            "invalid_array_arg" => InfoError::InvalidArrayArg(context),
+            "invalid_auth" => InfoError::InvalidAuth(context),
+            "invalid_charset" => InfoError::InvalidCharset(context),
+            "invalid_form_data" => InfoError::InvalidFormData(context),
+            "invalid_json" => InfoError::InvalidJson(context),
+            "invalid_post_type" => InfoError::InvalidPostType(context),
+            "json_not_object" => InfoError::JsonNotObject(context),
+            "missing_post_type" => InfoError::MissingPostType(context),
+            "no_permission" => InfoError::NoPermission(context),
+            "not_authed" => InfoError::NotAuthed(context),
+            "org_login_required" => InfoError::OrgLoginRequired(context),
+            "request_timeout" => InfoError::RequestTimeout(context),
+            "team_added_to_org" => InfoError::TeamAddedToOrg(context),
+            "token_revoked" => InfoError::TokenRevoked(context),
+            "upgrade_required" => InfoError::UpgradeRequired(context),
+            "user_is_bot" => InfoError::UserIsBot(context),
+            _ => InfoError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for InfoError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => InfoError::AccountInactive,
-            "fatal_error" => InfoError::FatalError,
-            "invalid_arg_name" => InfoError::InvalidArgName,
-            "invalid_array_arg" => InfoError::InvalidArrayArg,
-            "invalid_auth" => InfoError::InvalidAuth,
-            "invalid_charset" => InfoError::InvalidCharset,
-            "invalid_form_data" => InfoError::InvalidFormData,
-            "invalid_json" => InfoError::InvalidJson,
-            "invalid_post_type" => InfoError::InvalidPostType,
-            "json_not_object" => InfoError::JsonNotObject,
-            "missing_post_type" => InfoError::MissingPostType,
-            "no_permission" => InfoError::NoPermission,
-            "not_authed" => InfoError::NotAuthed,
-            "org_login_required" => InfoError::OrgLoginRequired,
-            "request_timeout" => InfoError::RequestTimeout,
-            "team_added_to_org" => InfoError::TeamAddedToOrg,
-            "token_revoked" => InfoError::TokenRevoked,
-            "upgrade_required" => InfoError::UpgradeRequired,
-            "user_is_bot" => InfoError::UserIsBot,
-            _ => InfoError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for InfoError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            InfoError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            InfoError::FatalError => write!(f, "Server returned error fatal_error"),
-            InfoError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            InfoError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            InfoError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            InfoError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            InfoError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            InfoError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            InfoError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            InfoError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            InfoError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            InfoError::NoPermission => write!(f, "Server returned error no_permission"),
-            InfoError::NotAuthed => write!(f, "Server returned error not_authed"),
-            InfoError::OrgLoginRequired => write!(f, "Server returned error org_login_required"),
-            InfoError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            InfoError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            InfoError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            InfoError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
-            InfoError::UserIsBot => write!(f, "Server returned error user_is_bot"),
+            InfoError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            InfoError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            InfoError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            InfoError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            InfoError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            InfoError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            InfoError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            InfoError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            InfoError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            InfoError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            InfoError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            InfoError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            InfoError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            InfoError::OrgLoginRequired(_) => write!(f, "Server returned error org_login_required"),
+            InfoError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            InfoError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            InfoError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            InfoError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
+            InfoError::UserIsBot(_) => write!(f, "Server returned error user_is_bot"),
             InfoError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            InfoError::Unknown(ref s) => write!(f, "{}", s),
+            InfoError::Unknown(ref s, _) => write!(f, "{}", s),
             InfoError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -230,6 +271,70 @@ impl<E: Error + 'static> Error for InfoError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for InfoError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            InfoError::AccountInactive(_) => Some("account_inactive"),
+            InfoError::FatalError(_) => Some("fatal_error"),
+            InfoError::InvalidArgName(_) => Some("invalid_arg_name"),
+            InfoError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            InfoError::InvalidAuth(_) => Some("invalid_auth"),
+            InfoError::InvalidCharset(_) => Some("invalid_charset"),
+            InfoError::InvalidFormData(_) => Some("invalid_form_data"),
+            InfoError::InvalidJson(_) => Some("invalid_json"),
+            InfoError::InvalidPostType(_) => Some("invalid_post_type"),
+            InfoError::JsonNotObject(_) => Some("json_not_object"),
+            InfoError::MissingPostType(_) => Some("missing_post_type"),
+            InfoError::NoPermission(_) => Some("no_permission"),
+            InfoError::NotAuthed(_) => Some("not_authed"),
+            InfoError::OrgLoginRequired(_) => Some("org_login_required"),
+            InfoError::RequestTimeout(_) => Some("request_timeout"),
+            InfoError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            InfoError::TokenRevoked(_) => Some("token_revoked"),
+            InfoError::UpgradeRequired(_) => Some("upgrade_required"),
+            InfoError::UserIsBot(_) => Some("user_is_bot"),
+            InfoError::Unknown(ref s, _) => Some(s),
+            InfoError::MalformedResponse(..) | InfoError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            InfoError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            InfoError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            InfoError::AccountInactive(ref context)
+            | InfoError::FatalError(ref context)
+            | InfoError::InvalidArgName(ref context)
+            | InfoError::InvalidArrayArg(ref context)
+            | InfoError::InvalidAuth(ref context)
+            | InfoError::InvalidCharset(ref context)
+            | InfoError::InvalidFormData(ref context)
+            | InfoError::InvalidJson(ref context)
+            | InfoError::InvalidPostType(ref context)
+            | InfoError::JsonNotObject(ref context)
+            | InfoError::MissingPostType(ref context)
+            | InfoError::NoPermission(ref context)
+            | InfoError::NotAuthed(ref context)
+            | InfoError::OrgLoginRequired(ref context)
+            | InfoError::RequestTimeout(ref context)
+            | InfoError::TeamAddedToOrg(ref context)
+            | InfoError::TokenRevoked(ref context)
+            | InfoError::UpgradeRequired(ref context)
+            | InfoError::UserIsBot(ref context)
+            | InfoError::Unknown(_, ref context) => Some(context),
+            InfoError::MalformedResponse(..) | InfoError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct RequestRequest<'a> {
     /// A comma separated list of scopes to request for
@@ -238,12 +343,18 @@ pub struct RequestRequest<'a> {
     pub trigger_id: Cow<'a, str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RequestResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<RequestResponse, RequestError<E>>> for RequestResponse {
@@ -251,103 +362,150 @@ impl<E: Error> Into<Result<RequestResponse, RequestError<E>>> for RequestRespons
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(RequestError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for RequestResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum RequestError<E: Error> {
-    AccountInactive,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    InvalidScope,
-    InvalidTrigger,
-    InvalidUser,
-    JsonNotObject,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    OrgLoginRequired,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TokenRevoked,
-    TriggerExchanged,
-    UpgradeRequired,
-    UserIsBot,
+    AccountInactive(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    InvalidScope(crate::ErrorContext),
+    InvalidTrigger(crate::ErrorContext),
+    InvalidUser(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    TriggerExchanged(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserIsBot(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> RequestError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => RequestError::AccountInactive(context),
+            "fatal_error" => RequestError::FatalError(context),
+            "invalid_arg_name" => RequestError::InvalidArgName(context),
+            "invalid_array_arg" => RequestError::InvalidArrayArg(context),
+            "invalid_auth" => RequestError::InvalidAuth(context),
+            "invalid_charset" => RequestError::InvalidCharset(context),
+            "invalid_form_data" => RequestError::InvalidFormData(context),
+            "invalid_json" => RequestError::InvalidJson(context),
+            "invalid_post_type" => RequestError::InvalidPostType(context),
+            "invalid_scope" => RequestError::InvalidScope(context),
+            "invalid_trigger" => RequestError::InvalidTrigger(context),
+            "invalid_user" => RequestError::InvalidUser(context),
+            "json_not_object" => RequestError::JsonNotObject(context),
+            "missing_post_type" => RequestError::MissingPostType(context),
+            "no_permission" => RequestError::NoPermission(context),
+            "not_authed" => RequestError::NotAuthed(context),
+            "org_login_required" => RequestError::OrgLoginRequired(context),
+            "request_timeout" => RequestError::RequestTimeout(context),
+            "team_added_to_org" => RequestError::TeamAddedToOrg(context),
+            "token_revoked" => RequestError::TokenRevoked(context),
+            "trigger_exchanged" => RequestError::TriggerExchanged(context),
+            "upgrade_required" => RequestError::UpgradeRequired(context),
+            "user_is_bot" => RequestError::UserIsBot(context),
+            _ => RequestError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for RequestError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => RequestError::AccountInactive,
-            "fatal_error" => RequestError::FatalError,
-            "invalid_arg_name" => RequestError::InvalidArgName,
-            "invalid_array_arg" => RequestError::InvalidArrayArg,
-            "invalid_auth" => RequestError::InvalidAuth,
-            "invalid_charset" => RequestError::InvalidCharset,
-            "invalid_form_data" => RequestError::InvalidFormData,
-            "invalid_json" => RequestError::InvalidJson,
-            "invalid_post_type" => RequestError::InvalidPostType,
-            "invalid_scope" => RequestError::InvalidScope,
-            "invalid_trigger" => RequestError::InvalidTrigger,
-            "invalid_user" => RequestError::InvalidUser,
-            "json_not_object" => RequestError::JsonNotObject,
-            "missing_post_type" => RequestError::MissingPostType,
-            "no_permission" => RequestError::NoPermission,
-            "not_authed" => RequestError::NotAuthed,
-            "org_login_required" => RequestError::OrgLoginRequired,
-            "request_timeout" => RequestError::RequestTimeout,
-            "team_added_to_org" => RequestError::TeamAddedToOrg,
-            "token_revoked" => RequestError::TokenRevoked,
-            "trigger_exchanged" => RequestError::TriggerExchanged,
-            "upgrade_required" => RequestError::UpgradeRequired,
-            "user_is_bot" => RequestError::UserIsBot,
-            _ => RequestError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for RequestError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            RequestError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            RequestError::FatalError => write!(f, "Server returned error fatal_error"),
-            RequestError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            RequestError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            RequestError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            RequestError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            RequestError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            RequestError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            RequestError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            RequestError::InvalidScope => write!(f, "Server returned error invalid_scope"),
-            RequestError::InvalidTrigger => write!(f, "Server returned error invalid_trigger"),
-            RequestError::InvalidUser => write!(f, "Server returned error invalid_user"),
-            RequestError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            RequestError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            RequestError::NoPermission => write!(f, "Server returned error no_permission"),
-            RequestError::NotAuthed => write!(f, "Server returned error not_authed"),
-            RequestError::OrgLoginRequired => write!(f, "Server returned error org_login_required"),
-            RequestError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            RequestError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            RequestError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            RequestError::TriggerExchanged => write!(f, "Server returned error trigger_exchanged"),
-            RequestError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
-            RequestError::UserIsBot => write!(f, "Server returned error user_is_bot"),
+            RequestError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            RequestError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            RequestError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            RequestError::InvalidArrayArg(_) => {
+                write!(f, "Server returned error invalid_array_arg")
+            }
+            RequestError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            RequestError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            RequestError::InvalidFormData(_) => {
+                write!(f, "Server returned error invalid_form_data")
+            }
+            RequestError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            RequestError::InvalidPostType(_) => {
+                write!(f, "Server returned error invalid_post_type")
+            }
+            RequestError::InvalidScope(_) => write!(f, "Server returned error invalid_scope"),
+            RequestError::InvalidTrigger(_) => write!(f, "Server returned error invalid_trigger"),
+            RequestError::InvalidUser(_) => write!(f, "Server returned error invalid_user"),
+            RequestError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            RequestError::MissingPostType(_) => {
+                write!(f, "Server returned error missing_post_type")
+            }
+            RequestError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            RequestError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            RequestError::OrgLoginRequired(_) => {
+                write!(f, "Server returned error org_login_required")
+            }
+            RequestError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            RequestError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            RequestError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            RequestError::TriggerExchanged(_) => {
+                write!(f, "Server returned error trigger_exchanged")
+            }
+            RequestError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
+            RequestError::UserIsBot(_) => write!(f, "Server returned error user_is_bot"),
             RequestError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            RequestError::Unknown(ref s) => write!(f, "{}", s),
+            RequestError::Unknown(ref s, _) => write!(f, "{}", s),
             RequestError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -362,3 +520,75 @@ impl<E: Error + 'static> Error for RequestError<E> {
         }
     }
 }
+
+impl<E: Error + 'static> crate::MethodError for RequestError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            RequestError::AccountInactive(_) => Some("account_inactive"),
+            RequestError::FatalError(_) => Some("fatal_error"),
+            RequestError::InvalidArgName(_) => Some("invalid_arg_name"),
+            RequestError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            RequestError::InvalidAuth(_) => Some("invalid_auth"),
+            RequestError::InvalidCharset(_) => Some("invalid_charset"),
+            RequestError::InvalidFormData(_) => Some("invalid_form_data"),
+            RequestError::InvalidJson(_) => Some("invalid_json"),
+            RequestError::InvalidPostType(_) => Some("invalid_post_type"),
+            RequestError::InvalidScope(_) => Some("invalid_scope"),
+            RequestError::InvalidTrigger(_) => Some("invalid_trigger"),
+            RequestError::InvalidUser(_) => Some("invalid_user"),
+            RequestError::JsonNotObject(_) => Some("json_not_object"),
+            RequestError::MissingPostType(_) => Some("missing_post_type"),
+            RequestError::NoPermission(_) => Some("no_permission"),
+            RequestError::NotAuthed(_) => Some("not_authed"),
+            RequestError::OrgLoginRequired(_) => Some("org_login_required"),
+            RequestError::RequestTimeout(_) => Some("request_timeout"),
+            RequestError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            RequestError::TokenRevoked(_) => Some("token_revoked"),
+            RequestError::TriggerExchanged(_) => Some("trigger_exchanged"),
+            RequestError::UpgradeRequired(_) => Some("upgrade_required"),
+            RequestError::UserIsBot(_) => Some("user_is_bot"),
+            RequestError::Unknown(ref s, _) => Some(s),
+            RequestError::MalformedResponse(..) | RequestError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            RequestError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            RequestError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            RequestError::AccountInactive(ref context)
+            | RequestError::FatalError(ref context)
+            | RequestError::InvalidArgName(ref context)
+            | RequestError::InvalidArrayArg(ref context)
+            | RequestError::InvalidAuth(ref context)
+            | RequestError::InvalidCharset(ref context)
+            | RequestError::InvalidFormData(ref context)
+            | RequestError::InvalidJson(ref context)
+            | RequestError::InvalidPostType(ref context)
+            | RequestError::InvalidScope(ref context)
+            | RequestError::InvalidTrigger(ref context)
+            | RequestError::InvalidUser(ref context)
+            | RequestError::JsonNotObject(ref context)
+            | RequestError::MissingPostType(ref context)
+            | RequestError::NoPermission(ref context)
+            | RequestError::NotAuthed(ref context)
+            | RequestError::OrgLoginRequired(ref context)
+            | RequestError::RequestTimeout(ref context)
+            | RequestError::TeamAddedToOrg(ref context)
+            | RequestError::TokenRevoked(ref context)
+            | RequestError::TriggerExchanged(ref context)
+            | RequestError::UpgradeRequired(ref context)
+            | RequestError::UserIsBot(ref context)
+            | RequestError::Unknown(_, ref context) => Some(context),
+            RequestError::MalformedResponse(..) | RequestError::Client(_) => None,
+        }
+    }
+}
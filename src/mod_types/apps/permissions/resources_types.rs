@@ -29,25 +29,38 @@ pub struct ListRequest<'a> {
     pub limit: Option<u64>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl crate::pagination::CursorRequest for ListRequest<'_> {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor.into());
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResourcesInner {
     pub id: Option<String>,
     pub r#type: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponseMetadataInner {
+    pub messages: Option<Vec<String>>,
     pub next_cursor: String,
+    pub warnings: Option<Vec<String>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
     pub resources: Vec<ListResourcesInner>,
     pub response_metadata: Option<ListResponseMetadataInner>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
@@ -55,94 +68,143 @@ impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(ListError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for ListResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
+impl crate::pagination::CursorPage for ListResponse {
+    type Item = ListResourcesInner;
+
+    fn next_cursor(&self) -> Option<&str> {
+        self.response_metadata
+            .as_ref()
+            .map(|response_metadata| response_metadata.next_cursor.as_str())
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.resources
+    }
+}
+
 #[derive(Debug)]
 pub enum ListError<E: Error> {
-    AccountInactive,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidCursor,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    OrgLoginRequired,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequired,
-    UserIsBot,
+    AccountInactive(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidCursor(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserIsBot(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> ListError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => ListError::AccountInactive(context),
+            "fatal_error" => ListError::FatalError(context),
+            "invalid_arg_name" => ListError::InvalidArgName(context),
+            "invalid_array_arg" => ListError::InvalidArrayArg(context),
+            "invalid_auth" => ListError::InvalidAuth(context),
+            "invalid_charset" => ListError::InvalidCharset(context),
+            "invalid_cursor" => ListError::InvalidCursor(context),
+            "invalid_form_data" => ListError::InvalidFormData(context),
+            "invalid_json" => ListError::InvalidJson(context),
+            "invalid_post_type" => ListError::InvalidPostType(context),
+            "json_not_object" => ListError::JsonNotObject(context),
+            "missing_post_type" => ListError::MissingPostType(context),
+            "no_permission" => ListError::NoPermission(context),
+            "not_authed" => ListError::NotAuthed(context),
+            "org_login_required" => ListError::OrgLoginRequired(context),
+            "request_timeout" => ListError::RequestTimeout(context),
+            "team_added_to_org" => ListError::TeamAddedToOrg(context),
+            "token_revoked" => ListError::TokenRevoked(context),
+            "upgrade_required" => ListError::UpgradeRequired(context),
+            "user_is_bot" => ListError::UserIsBot(context),
+            _ => ListError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for ListError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => ListError::AccountInactive,
-            "fatal_error" => ListError::FatalError,
-            "invalid_arg_name" => ListError::InvalidArgName,
-            "invalid_array_arg" => ListError::InvalidArrayArg,
-            "invalid_auth" => ListError::InvalidAuth,
-            "invalid_charset" => ListError::InvalidCharset,
-            "invalid_cursor" => ListError::InvalidCursor,
-            "invalid_form_data" => ListError::InvalidFormData,
-            "invalid_json" => ListError::InvalidJson,
-            "invalid_post_type" => ListError::InvalidPostType,
-            "json_not_object" => ListError::JsonNotObject,
-            "missing_post_type" => ListError::MissingPostType,
-            "no_permission" => ListError::NoPermission,
-            "not_authed" => ListError::NotAuthed,
-            "org_login_required" => ListError::OrgLoginRequired,
-            "request_timeout" => ListError::RequestTimeout,
-            "team_added_to_org" => ListError::TeamAddedToOrg,
-            "token_revoked" => ListError::TokenRevoked,
-            "upgrade_required" => ListError::UpgradeRequired,
-            "user_is_bot" => ListError::UserIsBot,
-            _ => ListError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for ListError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            ListError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            ListError::FatalError => write!(f, "Server returned error fatal_error"),
-            ListError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            ListError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            ListError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            ListError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            ListError::InvalidCursor => write!(f, "Server returned error invalid_cursor"),
-            ListError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            ListError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            ListError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            ListError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            ListError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            ListError::NoPermission => write!(f, "Server returned error no_permission"),
-            ListError::NotAuthed => write!(f, "Server returned error not_authed"),
-            ListError::OrgLoginRequired => write!(f, "Server returned error org_login_required"),
-            ListError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            ListError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            ListError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            ListError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
-            ListError::UserIsBot => write!(f, "Server returned error user_is_bot"),
+            ListError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            ListError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            ListError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            ListError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            ListError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            ListError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            ListError::InvalidCursor(_) => write!(f, "Server returned error invalid_cursor"),
+            ListError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            ListError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            ListError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            ListError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            ListError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            ListError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            ListError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            ListError::OrgLoginRequired(_) => write!(f, "Server returned error org_login_required"),
+            ListError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            ListError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            ListError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            ListError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
+            ListError::UserIsBot(_) => write!(f, "Server returned error user_is_bot"),
             ListError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            ListError::Unknown(ref s) => write!(f, "{}", s),
+            ListError::Unknown(ref s, _) => write!(f, "{}", s),
             ListError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -157,3 +219,69 @@ impl<E: Error + 'static> Error for ListError<E> {
         }
     }
 }
+
+impl<E: Error + 'static> crate::MethodError for ListError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            ListError::AccountInactive(_) => Some("account_inactive"),
+            ListError::FatalError(_) => Some("fatal_error"),
+            ListError::InvalidArgName(_) => Some("invalid_arg_name"),
+            ListError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            ListError::InvalidAuth(_) => Some("invalid_auth"),
+            ListError::InvalidCharset(_) => Some("invalid_charset"),
+            ListError::InvalidCursor(_) => Some("invalid_cursor"),
+            ListError::InvalidFormData(_) => Some("invalid_form_data"),
+            ListError::InvalidJson(_) => Some("invalid_json"),
+            ListError::InvalidPostType(_) => Some("invalid_post_type"),
+            ListError::JsonNotObject(_) => Some("json_not_object"),
+            ListError::MissingPostType(_) => Some("missing_post_type"),
+            ListError::NoPermission(_) => Some("no_permission"),
+            ListError::NotAuthed(_) => Some("not_authed"),
+            ListError::OrgLoginRequired(_) => Some("org_login_required"),
+            ListError::RequestTimeout(_) => Some("request_timeout"),
+            ListError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            ListError::TokenRevoked(_) => Some("token_revoked"),
+            ListError::UpgradeRequired(_) => Some("upgrade_required"),
+            ListError::UserIsBot(_) => Some("user_is_bot"),
+            ListError::Unknown(ref s, _) => Some(s),
+            ListError::MalformedResponse(..) | ListError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            ListError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            ListError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            ListError::AccountInactive(ref context)
+            | ListError::FatalError(ref context)
+            | ListError::InvalidArgName(ref context)
+            | ListError::InvalidArrayArg(ref context)
+            | ListError::InvalidAuth(ref context)
+            | ListError::InvalidCharset(ref context)
+            | ListError::InvalidCursor(ref context)
+            | ListError::InvalidFormData(ref context)
+            | ListError::InvalidJson(ref context)
+            | ListError::InvalidPostType(ref context)
+            | ListError::JsonNotObject(ref context)
+            | ListError::MissingPostType(ref context)
+            | ListError::NoPermission(ref context)
+            | ListError::NotAuthed(ref context)
+            | ListError::OrgLoginRequired(ref context)
+            | ListError::RequestTimeout(ref context)
+            | ListError::TeamAddedToOrg(ref context)
+            | ListError::TokenRevoked(ref context)
+            | ListError::UpgradeRequired(ref context)
+            | ListError::UserIsBot(ref context)
+            | ListError::Unknown(_, ref context) => Some(context),
+            ListError::MalformedResponse(..) | ListError::Client(_) => None,
+        }
+    }
+}
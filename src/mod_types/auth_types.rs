@@ -27,13 +27,19 @@ pub struct RevokeRequest {
     pub test: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RevokeResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
     pub revoked: bool,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<RevokeResponse, RevokeError<E>>> for RevokeResponse {
@@ -41,88 +47,125 @@ impl<E: Error> Into<Result<RevokeResponse, RevokeError<E>>> for RevokeResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(RevokeError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for RevokeResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum RevokeError<E: Error> {
-    AccountInactive,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    OrgLoginRequired,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequired,
+    AccountInactive(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> RevokeError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => RevokeError::AccountInactive(context),
+            "fatal_error" => RevokeError::FatalError(context),
+            "invalid_arg_name" => RevokeError::InvalidArgName(context),
+            "invalid_array_arg" => RevokeError::InvalidArrayArg(context),
+            "invalid_auth" => RevokeError::InvalidAuth(context),
+            "invalid_charset" => RevokeError::InvalidCharset(context),
+            "invalid_form_data" => RevokeError::InvalidFormData(context),
+            "invalid_json" => RevokeError::InvalidJson(context),
+            "invalid_post_type" => RevokeError::InvalidPostType(context),
+            "json_not_object" => RevokeError::JsonNotObject(context),
+            "missing_post_type" => RevokeError::MissingPostType(context),
+            "no_permission" => RevokeError::NoPermission(context),
+            "not_authed" => RevokeError::NotAuthed(context),
+            "org_login_required" => RevokeError::OrgLoginRequired(context),
+            "request_timeout" => RevokeError::RequestTimeout(context),
+            "team_added_to_org" => RevokeError::TeamAddedToOrg(context),
+            "token_revoked" => RevokeError::TokenRevoked(context),
+            "upgrade_required" => RevokeError::UpgradeRequired(context),
+            _ => RevokeError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for RevokeError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => RevokeError::AccountInactive,
-            "fatal_error" => RevokeError::FatalError,
-            "invalid_arg_name" => RevokeError::InvalidArgName,
-            "invalid_array_arg" => RevokeError::InvalidArrayArg,
-            "invalid_auth" => RevokeError::InvalidAuth,
-            "invalid_charset" => RevokeError::InvalidCharset,
-            "invalid_form_data" => RevokeError::InvalidFormData,
-            "invalid_json" => RevokeError::InvalidJson,
-            "invalid_post_type" => RevokeError::InvalidPostType,
-            "json_not_object" => RevokeError::JsonNotObject,
-            "missing_post_type" => RevokeError::MissingPostType,
-            "no_permission" => RevokeError::NoPermission,
-            "not_authed" => RevokeError::NotAuthed,
-            "org_login_required" => RevokeError::OrgLoginRequired,
-            "request_timeout" => RevokeError::RequestTimeout,
-            "team_added_to_org" => RevokeError::TeamAddedToOrg,
-            "token_revoked" => RevokeError::TokenRevoked,
-            "upgrade_required" => RevokeError::UpgradeRequired,
-            _ => RevokeError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for RevokeError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            RevokeError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            RevokeError::FatalError => write!(f, "Server returned error fatal_error"),
-            RevokeError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            RevokeError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            RevokeError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            RevokeError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            RevokeError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            RevokeError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            RevokeError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            RevokeError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            RevokeError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            RevokeError::NoPermission => write!(f, "Server returned error no_permission"),
-            RevokeError::NotAuthed => write!(f, "Server returned error not_authed"),
-            RevokeError::OrgLoginRequired => write!(f, "Server returned error org_login_required"),
-            RevokeError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            RevokeError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            RevokeError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            RevokeError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
+            RevokeError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            RevokeError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            RevokeError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            RevokeError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            RevokeError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            RevokeError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            RevokeError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            RevokeError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            RevokeError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            RevokeError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            RevokeError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            RevokeError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            RevokeError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            RevokeError::OrgLoginRequired(_) => {
+                write!(f, "Server returned error org_login_required")
+            }
+            RevokeError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            RevokeError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            RevokeError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            RevokeError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
             RevokeError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            RevokeError::Unknown(ref s) => write!(f, "{}", s),
+            RevokeError::Unknown(ref s, _) => write!(f, "{}", s),
             RevokeError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -138,22 +181,90 @@ impl<E: Error + 'static> Error for RevokeError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for RevokeError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            RevokeError::AccountInactive(_) => Some("account_inactive"),
+            RevokeError::FatalError(_) => Some("fatal_error"),
+            RevokeError::InvalidArgName(_) => Some("invalid_arg_name"),
+            RevokeError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            RevokeError::InvalidAuth(_) => Some("invalid_auth"),
+            RevokeError::InvalidCharset(_) => Some("invalid_charset"),
+            RevokeError::InvalidFormData(_) => Some("invalid_form_data"),
+            RevokeError::InvalidJson(_) => Some("invalid_json"),
+            RevokeError::InvalidPostType(_) => Some("invalid_post_type"),
+            RevokeError::JsonNotObject(_) => Some("json_not_object"),
+            RevokeError::MissingPostType(_) => Some("missing_post_type"),
+            RevokeError::NoPermission(_) => Some("no_permission"),
+            RevokeError::NotAuthed(_) => Some("not_authed"),
+            RevokeError::OrgLoginRequired(_) => Some("org_login_required"),
+            RevokeError::RequestTimeout(_) => Some("request_timeout"),
+            RevokeError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            RevokeError::TokenRevoked(_) => Some("token_revoked"),
+            RevokeError::UpgradeRequired(_) => Some("upgrade_required"),
+            RevokeError::Unknown(ref s, _) => Some(s),
+            RevokeError::MalformedResponse(..) | RevokeError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            RevokeError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            RevokeError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            RevokeError::AccountInactive(ref context)
+            | RevokeError::FatalError(ref context)
+            | RevokeError::InvalidArgName(ref context)
+            | RevokeError::InvalidArrayArg(ref context)
+            | RevokeError::InvalidAuth(ref context)
+            | RevokeError::InvalidCharset(ref context)
+            | RevokeError::InvalidFormData(ref context)
+            | RevokeError::InvalidJson(ref context)
+            | RevokeError::InvalidPostType(ref context)
+            | RevokeError::JsonNotObject(ref context)
+            | RevokeError::MissingPostType(ref context)
+            | RevokeError::NoPermission(ref context)
+            | RevokeError::NotAuthed(ref context)
+            | RevokeError::OrgLoginRequired(ref context)
+            | RevokeError::RequestTimeout(ref context)
+            | RevokeError::TeamAddedToOrg(ref context)
+            | RevokeError::TokenRevoked(ref context)
+            | RevokeError::UpgradeRequired(ref context)
+            | RevokeError::Unknown(_, ref context) => Some(context),
+            RevokeError::MalformedResponse(..) | RevokeError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct TestRequest {}
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct TestResponse {
-    pub bot_id: Option<String>,
+    pub bot_id: Option<crate::BotId>,
     pub callstack: Option<String>,
     error: Option<String>,
     pub is_enterprise_install: Option<bool>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
     pub team: String,
-    pub team_id: String,
+    pub team_id: Option<crate::TeamId>,
     pub url: String,
     pub user: String,
-    pub user_id: String,
+    pub user_id: Option<crate::UserId>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<TestResponse, TestError<E>>> for TestResponse {
@@ -161,76 +272,111 @@ impl<E: Error> Into<Result<TestResponse, TestError<E>>> for TestResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(TestError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for TestResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum TestError<E: Error> {
-    AccountInactive,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    NotAuthed,
-    RequestTimeout,
-    TokenRevoked,
-    UpgradeRequired,
+    AccountInactive(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> TestError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => TestError::AccountInactive(context),
+            "invalid_arg_name" => TestError::InvalidArgName(context),
+            "invalid_array_arg" => TestError::InvalidArrayArg(context),
+            "invalid_auth" => TestError::InvalidAuth(context),
+            "invalid_charset" => TestError::InvalidCharset(context),
+            "invalid_form_data" => TestError::InvalidFormData(context),
+            "invalid_json" => TestError::InvalidJson(context),
+            "invalid_post_type" => TestError::InvalidPostType(context),
+            "json_not_object" => TestError::JsonNotObject(context),
+            "missing_post_type" => TestError::MissingPostType(context),
+            "not_authed" => TestError::NotAuthed(context),
+            "request_timeout" => TestError::RequestTimeout(context),
+            "token_revoked" => TestError::TokenRevoked(context),
+            "upgrade_required" => TestError::UpgradeRequired(context),
+            _ => TestError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for TestError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => TestError::AccountInactive,
-            "invalid_arg_name" => TestError::InvalidArgName,
-            "invalid_array_arg" => TestError::InvalidArrayArg,
-            "invalid_auth" => TestError::InvalidAuth,
-            "invalid_charset" => TestError::InvalidCharset,
-            "invalid_form_data" => TestError::InvalidFormData,
-            "invalid_json" => TestError::InvalidJson,
-            "invalid_post_type" => TestError::InvalidPostType,
-            "json_not_object" => TestError::JsonNotObject,
-            "missing_post_type" => TestError::MissingPostType,
-            "not_authed" => TestError::NotAuthed,
-            "request_timeout" => TestError::RequestTimeout,
-            "token_revoked" => TestError::TokenRevoked,
-            "upgrade_required" => TestError::UpgradeRequired,
-            _ => TestError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for TestError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            TestError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            TestError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            TestError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            TestError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            TestError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            TestError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            TestError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            TestError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            TestError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            TestError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            TestError::NotAuthed => write!(f, "Server returned error not_authed"),
-            TestError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            TestError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            TestError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
+            TestError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            TestError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            TestError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            TestError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            TestError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            TestError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            TestError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            TestError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            TestError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            TestError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            TestError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            TestError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            TestError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            TestError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
             TestError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            TestError::Unknown(ref s) => write!(f, "{}", s),
+            TestError::Unknown(ref s, _) => write!(f, "{}", s),
             TestError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -245,3 +391,57 @@ impl<E: Error + 'static> Error for TestError<E> {
         }
     }
 }
+
+impl<E: Error + 'static> crate::MethodError for TestError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            TestError::AccountInactive(_) => Some("account_inactive"),
+            TestError::InvalidArgName(_) => Some("invalid_arg_name"),
+            TestError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            TestError::InvalidAuth(_) => Some("invalid_auth"),
+            TestError::InvalidCharset(_) => Some("invalid_charset"),
+            TestError::InvalidFormData(_) => Some("invalid_form_data"),
+            TestError::InvalidJson(_) => Some("invalid_json"),
+            TestError::InvalidPostType(_) => Some("invalid_post_type"),
+            TestError::JsonNotObject(_) => Some("json_not_object"),
+            TestError::MissingPostType(_) => Some("missing_post_type"),
+            TestError::NotAuthed(_) => Some("not_authed"),
+            TestError::RequestTimeout(_) => Some("request_timeout"),
+            TestError::TokenRevoked(_) => Some("token_revoked"),
+            TestError::UpgradeRequired(_) => Some("upgrade_required"),
+            TestError::Unknown(ref s, _) => Some(s),
+            TestError::MalformedResponse(..) | TestError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            TestError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            TestError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            TestError::AccountInactive(ref context)
+            | TestError::InvalidArgName(ref context)
+            | TestError::InvalidArrayArg(ref context)
+            | TestError::InvalidAuth(ref context)
+            | TestError::InvalidCharset(ref context)
+            | TestError::InvalidFormData(ref context)
+            | TestError::InvalidJson(ref context)
+            | TestError::InvalidPostType(ref context)
+            | TestError::JsonNotObject(ref context)
+            | TestError::MissingPostType(ref context)
+            | TestError::NotAuthed(ref context)
+            | TestError::RequestTimeout(ref context)
+            | TestError::TokenRevoked(ref context)
+            | TestError::UpgradeRequired(ref context)
+            | TestError::Unknown(_, ref context) => Some(context),
+            TestError::MalformedResponse(..) | TestError::Client(_) => None,
+        }
+    }
+}
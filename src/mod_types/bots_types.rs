@@ -22,36 +22,42 @@ use std::error::Error;
 use std::fmt;
 
 #[derive(Clone, Default, Debug)]
-pub struct InfoRequest<'a> {
+pub struct InfoRequest {
     /// Bot user to get info on
-    pub bot: Option<Cow<'a, str>>,
+    pub bot: Option<crate::BotId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoIconsInner {
     pub image_36: String,
     pub image_48: String,
     pub image_72: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoBotInner {
-    pub app_id: String,
+    pub app_id: crate::AppId,
     pub deleted: bool,
     pub icons: InfoIconsInner,
-    pub id: String,
+    pub id: crate::BotId,
     pub name: String,
     pub updated: u64,
-    pub user_id: Option<String>,
+    pub user_id: Option<crate::UserId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoResponse {
     pub bot: InfoBotInner,
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<InfoResponse, InfoError<E>>> for InfoResponse {
@@ -59,91 +65,126 @@ impl<E: Error> Into<Result<InfoResponse, InfoError<E>>> for InfoResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(InfoError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for InfoResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum InfoError<E: Error> {
-    AccountInactive,
-    BotNotFound,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    OrgLoginRequired,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequired,
+    AccountInactive(crate::ErrorContext),
+    BotNotFound(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> InfoError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => InfoError::AccountInactive(context),
+            "bot_not_found" => InfoError::BotNotFound(context),
+            "fatal_error" => InfoError::FatalError(context),
+            "invalid_arg_name" => InfoError::InvalidArgName(context),
+            "invalid_array_arg" => InfoError::InvalidArrayArg(context),
+            "invalid_auth" => InfoError::InvalidAuth(context),
+            "invalid_charset" => InfoError::InvalidCharset(context),
+            "invalid_form_data" => InfoError::InvalidFormData(context),
+            "invalid_json" => InfoError::InvalidJson(context),
+            "invalid_post_type" => InfoError::InvalidPostType(context),
+            "json_not_object" => InfoError::JsonNotObject(context),
+            "missing_post_type" => InfoError::MissingPostType(context),
+            "no_permission" => InfoError::NoPermission(context),
+            "not_authed" => InfoError::NotAuthed(context),
+            "org_login_required" => InfoError::OrgLoginRequired(context),
+            "request_timeout" => InfoError::RequestTimeout(context),
+            "team_added_to_org" => InfoError::TeamAddedToOrg(context),
+            "token_revoked" => InfoError::TokenRevoked(context),
+            "upgrade_required" => InfoError::UpgradeRequired(context),
+            _ => InfoError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for InfoError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => InfoError::AccountInactive,
-            "bot_not_found" => InfoError::BotNotFound,
-            "fatal_error" => InfoError::FatalError,
-            "invalid_arg_name" => InfoError::InvalidArgName,
-            "invalid_array_arg" => InfoError::InvalidArrayArg,
-            "invalid_auth" => InfoError::InvalidAuth,
-            "invalid_charset" => InfoError::InvalidCharset,
-            "invalid_form_data" => InfoError::InvalidFormData,
-            "invalid_json" => InfoError::InvalidJson,
-            "invalid_post_type" => InfoError::InvalidPostType,
-            "json_not_object" => InfoError::JsonNotObject,
-            "missing_post_type" => InfoError::MissingPostType,
-            "no_permission" => InfoError::NoPermission,
-            "not_authed" => InfoError::NotAuthed,
-            "org_login_required" => InfoError::OrgLoginRequired,
-            "request_timeout" => InfoError::RequestTimeout,
-            "team_added_to_org" => InfoError::TeamAddedToOrg,
-            "token_revoked" => InfoError::TokenRevoked,
-            "upgrade_required" => InfoError::UpgradeRequired,
-            _ => InfoError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for InfoError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            InfoError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            InfoError::BotNotFound => write!(f, "Server returned error bot_not_found"),
-            InfoError::FatalError => write!(f, "Server returned error fatal_error"),
-            InfoError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            InfoError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            InfoError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            InfoError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            InfoError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            InfoError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            InfoError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            InfoError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            InfoError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            InfoError::NoPermission => write!(f, "Server returned error no_permission"),
-            InfoError::NotAuthed => write!(f, "Server returned error not_authed"),
-            InfoError::OrgLoginRequired => write!(f, "Server returned error org_login_required"),
-            InfoError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            InfoError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            InfoError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            InfoError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
+            InfoError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            InfoError::BotNotFound(_) => write!(f, "Server returned error bot_not_found"),
+            InfoError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            InfoError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            InfoError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            InfoError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            InfoError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            InfoError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            InfoError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            InfoError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            InfoError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            InfoError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            InfoError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            InfoError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            InfoError::OrgLoginRequired(_) => write!(f, "Server returned error org_login_required"),
+            InfoError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            InfoError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            InfoError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            InfoError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
             InfoError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            InfoError::Unknown(ref s) => write!(f, "{}", s),
+            InfoError::Unknown(ref s, _) => write!(f, "{}", s),
             InfoError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -158,3 +199,67 @@ impl<E: Error + 'static> Error for InfoError<E> {
         }
     }
 }
+
+impl<E: Error + 'static> crate::MethodError for InfoError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            InfoError::AccountInactive(_) => Some("account_inactive"),
+            InfoError::BotNotFound(_) => Some("bot_not_found"),
+            InfoError::FatalError(_) => Some("fatal_error"),
+            InfoError::InvalidArgName(_) => Some("invalid_arg_name"),
+            InfoError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            InfoError::InvalidAuth(_) => Some("invalid_auth"),
+            InfoError::InvalidCharset(_) => Some("invalid_charset"),
+            InfoError::InvalidFormData(_) => Some("invalid_form_data"),
+            InfoError::InvalidJson(_) => Some("invalid_json"),
+            InfoError::InvalidPostType(_) => Some("invalid_post_type"),
+            InfoError::JsonNotObject(_) => Some("json_not_object"),
+            InfoError::MissingPostType(_) => Some("missing_post_type"),
+            InfoError::NoPermission(_) => Some("no_permission"),
+            InfoError::NotAuthed(_) => Some("not_authed"),
+            InfoError::OrgLoginRequired(_) => Some("org_login_required"),
+            InfoError::RequestTimeout(_) => Some("request_timeout"),
+            InfoError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            InfoError::TokenRevoked(_) => Some("token_revoked"),
+            InfoError::UpgradeRequired(_) => Some("upgrade_required"),
+            InfoError::Unknown(ref s, _) => Some(s),
+            InfoError::MalformedResponse(..) | InfoError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            InfoError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            InfoError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            InfoError::AccountInactive(ref context)
+            | InfoError::BotNotFound(ref context)
+            | InfoError::FatalError(ref context)
+            | InfoError::InvalidArgName(ref context)
+            | InfoError::InvalidArrayArg(ref context)
+            | InfoError::InvalidAuth(ref context)
+            | InfoError::InvalidCharset(ref context)
+            | InfoError::InvalidFormData(ref context)
+            | InfoError::InvalidJson(ref context)
+            | InfoError::InvalidPostType(ref context)
+            | InfoError::JsonNotObject(ref context)
+            | InfoError::MissingPostType(ref context)
+            | InfoError::NoPermission(ref context)
+            | InfoError::NotAuthed(ref context)
+            | InfoError::OrgLoginRequired(ref context)
+            | InfoError::RequestTimeout(ref context)
+            | InfoError::TeamAddedToOrg(ref context)
+            | InfoError::TokenRevoked(ref context)
+            | InfoError::UpgradeRequired(ref context)
+            | InfoError::Unknown(_, ref context) => Some(context),
+            InfoError::MalformedResponse(..) | InfoError::Client(_) => None,
+        }
+    }
+}
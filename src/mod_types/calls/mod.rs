@@ -40,13 +40,20 @@ pub struct AddRequest<'a> {
     /// The name of the Call.
     pub title: Option<Cow<'a, str>>,
     /// The list of users to register as participants in the Call. [Read more on how to specify users here](/apis/calls#users).
-    pub users: Option<Cow<'a, str>>,
+    pub users: Option<serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AddResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<AddResponse, AddError<E>>> for AddResponse {
@@ -54,28 +61,61 @@ impl<E: Error> Into<Result<AddResponse, AddError<E>>> for AddResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(AddError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(AddError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for AddResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum AddError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> AddError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => AddError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for AddError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => AddError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -83,7 +123,7 @@ impl<E: Error> fmt::Display for AddError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             AddError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            AddError::Unknown(ref s) => write!(f, "{}", s),
+            AddError::Unknown(ref s, _) => write!(f, "{}", s),
             AddError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -99,6 +139,32 @@ impl<E: Error + 'static> Error for AddError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for AddError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            AddError::Unknown(ref s, _) => Some(s),
+            AddError::MalformedResponse(..) | AddError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            AddError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            AddError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            AddError::Unknown(_, ref context) => Some(context),
+            AddError::MalformedResponse(..) | AddError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct EndRequest<'a> {
     /// Call duration in seconds
@@ -107,10 +173,17 @@ pub struct EndRequest<'a> {
     pub id: Cow<'a, str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct EndResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<EndResponse, EndError<E>>> for EndResponse {
@@ -118,28 +191,61 @@ impl<E: Error> Into<Result<EndResponse, EndError<E>>> for EndResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(EndError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(EndError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for EndResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum EndError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> EndError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => EndError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for EndError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => EndError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -147,7 +253,7 @@ impl<E: Error> fmt::Display for EndError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             EndError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            EndError::Unknown(ref s) => write!(f, "{}", s),
+            EndError::Unknown(ref s, _) => write!(f, "{}", s),
             EndError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -163,16 +269,49 @@ impl<E: Error + 'static> Error for EndError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for EndError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            EndError::Unknown(ref s, _) => Some(s),
+            EndError::MalformedResponse(..) | EndError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            EndError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            EndError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            EndError::Unknown(_, ref context) => Some(context),
+            EndError::MalformedResponse(..) | EndError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct InfoRequest<'a> {
     /// `id` of the Call returned by the [`calls.add`](/methods/calls.add) method.
     pub id: Cow<'a, str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<InfoResponse, InfoError<E>>> for InfoResponse {
@@ -180,28 +319,61 @@ impl<E: Error> Into<Result<InfoResponse, InfoError<E>>> for InfoResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(InfoError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(InfoError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for InfoResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum InfoError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> InfoError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => InfoError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for InfoError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => InfoError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -209,7 +381,7 @@ impl<E: Error> fmt::Display for InfoError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             InfoError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            InfoError::Unknown(ref s) => write!(f, "{}", s),
+            InfoError::Unknown(ref s, _) => write!(f, "{}", s),
             InfoError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -225,6 +397,32 @@ impl<E: Error + 'static> Error for InfoError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for InfoError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            InfoError::Unknown(ref s, _) => Some(s),
+            InfoError::MalformedResponse(..) | InfoError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            InfoError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            InfoError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            InfoError::Unknown(_, ref context) => Some(context),
+            InfoError::MalformedResponse(..) | InfoError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct UpdateRequest<'a> {
     /// When supplied, available Slack clients will attempt to directly launch the 3rd-party Call with this URL.
@@ -237,10 +435,17 @@ pub struct UpdateRequest<'a> {
     pub title: Option<Cow<'a, str>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UpdateResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<UpdateResponse, UpdateError<E>>> for UpdateResponse {
@@ -248,28 +453,61 @@ impl<E: Error> Into<Result<UpdateResponse, UpdateError<E>>> for UpdateResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(UpdateError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(UpdateError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for UpdateResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum UpdateError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> UpdateError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => UpdateError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for UpdateError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => UpdateError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -277,7 +515,7 @@ impl<E: Error> fmt::Display for UpdateError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             UpdateError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            UpdateError::Unknown(ref s) => write!(f, "{}", s),
+            UpdateError::Unknown(ref s, _) => write!(f, "{}", s),
             UpdateError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -292,3 +530,29 @@ impl<E: Error + 'static> Error for UpdateError<E> {
         }
     }
 }
+
+impl<E: Error + 'static> crate::MethodError for UpdateError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            UpdateError::Unknown(ref s, _) => Some(s),
+            UpdateError::MalformedResponse(..) | UpdateError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            UpdateError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            UpdateError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            UpdateError::Unknown(_, ref context) => Some(context),
+            UpdateError::MalformedResponse(..) | UpdateError::Client(_) => None,
+        }
+    }
+}
@@ -24,23 +24,29 @@ use std::error::Error;
 use std::fmt;
 
 #[derive(Clone, Default, Debug)]
-pub struct DeleteRequest<'a> {
+pub struct DeleteRequest {
     /// Pass true to delete the message as the authed user with `chat:write:user` scope. [Bot users](/bot-users) in this context are considered authed users. If unused or false, the message will be deleted with `chat:write:bot` scope.
     pub as_user: Option<bool>,
     /// Channel containing the message to be deleted.
-    pub channel: Cow<'a, str>,
+    pub channel: Option<crate::ChannelId>,
     /// Timestamp of the message to be deleted.
-    pub ts: f64,
+    pub ts: crate::Timestamp,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct DeleteResponse {
     pub callstack: Option<String>,
-    pub channel: String,
+    pub channel: crate::ChannelId,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
-    pub ts: String,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub ts: crate::Timestamp,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<DeleteResponse, DeleteError<E>>> for DeleteResponse {
@@ -48,102 +54,139 @@ impl<E: Error> Into<Result<DeleteResponse, DeleteError<E>>> for DeleteResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(DeleteError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for DeleteResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum DeleteError<E: Error> {
-    AccountInactive,
-    CantDeleteMessage,
-    ChannelNotFound,
-    ComplianceExportsPreventDeletion,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MessageNotFound,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequired,
+    AccountInactive(crate::ErrorContext),
+    CantDeleteMessage(crate::ErrorContext),
+    ChannelNotFound(crate::ErrorContext),
+    ComplianceExportsPreventDeletion(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MessageNotFound(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> DeleteError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => DeleteError::AccountInactive(context),
+            "cant_delete_message" => DeleteError::CantDeleteMessage(context),
+            "channel_not_found" => DeleteError::ChannelNotFound(context),
+            "compliance_exports_prevent_deletion" => {
+                DeleteError::ComplianceExportsPreventDeletion(context)
+            }
+            "fatal_error" => DeleteError::FatalError(context),
+            "invalid_arg_name" => DeleteError::InvalidArgName(context),
+            "invalid_array_arg" => DeleteError::InvalidArrayArg(context),
+            "invalid_auth" => DeleteError::InvalidAuth(context),
+            "invalid_charset" => DeleteError::InvalidCharset(context),
+            "invalid_form_data" => DeleteError::InvalidFormData(context),
+            "invalid_json" => DeleteError::InvalidJson(context),
+            "invalid_post_type" => DeleteError::InvalidPostType(context),
+            "json_not_object" => DeleteError::JsonNotObject(context),
+            "message_not_found" => DeleteError::MessageNotFound(context),
+            "missing_post_type" => DeleteError::MissingPostType(context),
+            "no_permission" => DeleteError::NoPermission(context),
+            "not_authed" => DeleteError::NotAuthed(context),
+            "request_timeout" => DeleteError::RequestTimeout(context),
+            "team_added_to_org" => DeleteError::TeamAddedToOrg(context),
+            "token_revoked" => DeleteError::TokenRevoked(context),
+            "upgrade_required" => DeleteError::UpgradeRequired(context),
+            _ => DeleteError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for DeleteError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => DeleteError::AccountInactive,
-            "cant_delete_message" => DeleteError::CantDeleteMessage,
-            "channel_not_found" => DeleteError::ChannelNotFound,
-            "compliance_exports_prevent_deletion" => DeleteError::ComplianceExportsPreventDeletion,
-            "fatal_error" => DeleteError::FatalError,
-            "invalid_arg_name" => DeleteError::InvalidArgName,
-            "invalid_array_arg" => DeleteError::InvalidArrayArg,
-            "invalid_auth" => DeleteError::InvalidAuth,
-            "invalid_charset" => DeleteError::InvalidCharset,
-            "invalid_form_data" => DeleteError::InvalidFormData,
-            "invalid_json" => DeleteError::InvalidJson,
-            "invalid_post_type" => DeleteError::InvalidPostType,
-            "json_not_object" => DeleteError::JsonNotObject,
-            "message_not_found" => DeleteError::MessageNotFound,
-            "missing_post_type" => DeleteError::MissingPostType,
-            "no_permission" => DeleteError::NoPermission,
-            "not_authed" => DeleteError::NotAuthed,
-            "request_timeout" => DeleteError::RequestTimeout,
-            "team_added_to_org" => DeleteError::TeamAddedToOrg,
-            "token_revoked" => DeleteError::TokenRevoked,
-            "upgrade_required" => DeleteError::UpgradeRequired,
-            _ => DeleteError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for DeleteError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            DeleteError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            DeleteError::CantDeleteMessage => {
+            DeleteError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            DeleteError::CantDeleteMessage(_) => {
                 write!(f, "Server returned error cant_delete_message")
             }
-            DeleteError::ChannelNotFound => write!(f, "Server returned error channel_not_found"),
-            DeleteError::ComplianceExportsPreventDeletion => write!(
+            DeleteError::ChannelNotFound(_) => write!(f, "Server returned error channel_not_found"),
+            DeleteError::ComplianceExportsPreventDeletion(_) => write!(
                 f,
                 "Server returned error compliance_exports_prevent_deletion"
             ),
-            DeleteError::FatalError => write!(f, "Server returned error fatal_error"),
-            DeleteError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            DeleteError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            DeleteError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            DeleteError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            DeleteError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            DeleteError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            DeleteError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            DeleteError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            DeleteError::MessageNotFound => write!(f, "Server returned error message_not_found"),
-            DeleteError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            DeleteError::NoPermission => write!(f, "Server returned error no_permission"),
-            DeleteError::NotAuthed => write!(f, "Server returned error not_authed"),
-            DeleteError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            DeleteError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            DeleteError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            DeleteError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
+            DeleteError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            DeleteError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            DeleteError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            DeleteError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            DeleteError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            DeleteError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            DeleteError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            DeleteError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            DeleteError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            DeleteError::MessageNotFound(_) => write!(f, "Server returned error message_not_found"),
+            DeleteError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            DeleteError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            DeleteError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            DeleteError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            DeleteError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            DeleteError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            DeleteError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
             DeleteError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            DeleteError::Unknown(ref s) => write!(f, "{}", s),
+            DeleteError::Unknown(ref s, _) => write!(f, "{}", s),
             DeleteError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -159,22 +202,98 @@ impl<E: Error + 'static> Error for DeleteError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for DeleteError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            DeleteError::AccountInactive(_) => Some("account_inactive"),
+            DeleteError::CantDeleteMessage(_) => Some("cant_delete_message"),
+            DeleteError::ChannelNotFound(_) => Some("channel_not_found"),
+            DeleteError::ComplianceExportsPreventDeletion(_) => {
+                Some("compliance_exports_prevent_deletion")
+            }
+            DeleteError::FatalError(_) => Some("fatal_error"),
+            DeleteError::InvalidArgName(_) => Some("invalid_arg_name"),
+            DeleteError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            DeleteError::InvalidAuth(_) => Some("invalid_auth"),
+            DeleteError::InvalidCharset(_) => Some("invalid_charset"),
+            DeleteError::InvalidFormData(_) => Some("invalid_form_data"),
+            DeleteError::InvalidJson(_) => Some("invalid_json"),
+            DeleteError::InvalidPostType(_) => Some("invalid_post_type"),
+            DeleteError::JsonNotObject(_) => Some("json_not_object"),
+            DeleteError::MessageNotFound(_) => Some("message_not_found"),
+            DeleteError::MissingPostType(_) => Some("missing_post_type"),
+            DeleteError::NoPermission(_) => Some("no_permission"),
+            DeleteError::NotAuthed(_) => Some("not_authed"),
+            DeleteError::RequestTimeout(_) => Some("request_timeout"),
+            DeleteError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            DeleteError::TokenRevoked(_) => Some("token_revoked"),
+            DeleteError::UpgradeRequired(_) => Some("upgrade_required"),
+            DeleteError::Unknown(ref s, _) => Some(s),
+            DeleteError::MalformedResponse(..) | DeleteError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            DeleteError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            DeleteError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            DeleteError::AccountInactive(ref context)
+            | DeleteError::CantDeleteMessage(ref context)
+            | DeleteError::ChannelNotFound(ref context)
+            | DeleteError::ComplianceExportsPreventDeletion(ref context)
+            | DeleteError::FatalError(ref context)
+            | DeleteError::InvalidArgName(ref context)
+            | DeleteError::InvalidArrayArg(ref context)
+            | DeleteError::InvalidAuth(ref context)
+            | DeleteError::InvalidCharset(ref context)
+            | DeleteError::InvalidFormData(ref context)
+            | DeleteError::InvalidJson(ref context)
+            | DeleteError::InvalidPostType(ref context)
+            | DeleteError::JsonNotObject(ref context)
+            | DeleteError::MessageNotFound(ref context)
+            | DeleteError::MissingPostType(ref context)
+            | DeleteError::NoPermission(ref context)
+            | DeleteError::NotAuthed(ref context)
+            | DeleteError::RequestTimeout(ref context)
+            | DeleteError::TeamAddedToOrg(ref context)
+            | DeleteError::TokenRevoked(ref context)
+            | DeleteError::UpgradeRequired(ref context)
+            | DeleteError::Unknown(_, ref context) => Some(context),
+            DeleteError::MalformedResponse(..) | DeleteError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct DeleteScheduledMessageRequest<'a> {
     /// Pass true to delete the message as the authed user with `chat:write:user` scope. [Bot users](/bot-users) in this context are considered authed users. If unused or false, the message will be deleted with `chat:write:bot` scope.
     pub as_user: Option<bool>,
     /// The channel the scheduled_message is posting to
-    pub channel: Cow<'a, str>,
+    pub channel: Option<crate::ChannelId>,
     /// `scheduled_message_id` returned from call to chat.scheduleMessage
     pub scheduled_message_id: Cow<'a, str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct DeleteScheduledMessageResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<DeleteScheduledMessageResponse, DeleteScheduledMessageError<E>>>
@@ -184,147 +303,186 @@ impl<E: Error> Into<Result<DeleteScheduledMessageResponse, DeleteScheduledMessag
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(DeleteScheduledMessageError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for DeleteScheduledMessageResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum DeleteScheduledMessageError<E: Error> {
-    AccountInactive,
-    BadToken,
-    ChannelNotFound,
-    EkmAccessDenied,
-    FatalError,
-    InvalidArgName,
-    InvalidArguments,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    InvalidScheduledMessageId,
-    JsonNotObject,
-    MissingPostType,
-    MissingScope,
-    NoPermission,
-    NotAuthed,
-    OrgLoginRequired,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequired,
+    AccountInactive(crate::ErrorContext),
+    BadToken(crate::ErrorContext),
+    ChannelNotFound(crate::ErrorContext),
+    EkmAccessDenied(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArguments(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    InvalidScheduledMessageId(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    MissingScope(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
-impl<'a, E: Error> From<&'a str> for DeleteScheduledMessageError<E> {
-    fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => DeleteScheduledMessageError::AccountInactive,
-            "bad_token" => DeleteScheduledMessageError::BadToken,
-            "channel_not_found" => DeleteScheduledMessageError::ChannelNotFound,
-            "ekm_access_denied" => DeleteScheduledMessageError::EkmAccessDenied,
-            "fatal_error" => DeleteScheduledMessageError::FatalError,
-            "invalid_arg_name" => DeleteScheduledMessageError::InvalidArgName,
-            "invalid_arguments" => DeleteScheduledMessageError::InvalidArguments,
-            "invalid_auth" => DeleteScheduledMessageError::InvalidAuth,
-            "invalid_charset" => DeleteScheduledMessageError::InvalidCharset,
-            "invalid_form_data" => DeleteScheduledMessageError::InvalidFormData,
-            "invalid_json" => DeleteScheduledMessageError::InvalidJson,
-            "invalid_post_type" => DeleteScheduledMessageError::InvalidPostType,
+impl<E: Error> DeleteScheduledMessageError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => DeleteScheduledMessageError::AccountInactive(context),
+            "bad_token" => DeleteScheduledMessageError::BadToken(context),
+            "channel_not_found" => DeleteScheduledMessageError::ChannelNotFound(context),
+            "ekm_access_denied" => DeleteScheduledMessageError::EkmAccessDenied(context),
+            "fatal_error" => DeleteScheduledMessageError::FatalError(context),
+            "invalid_arg_name" => DeleteScheduledMessageError::InvalidArgName(context),
+            "invalid_arguments" => DeleteScheduledMessageError::InvalidArguments(context),
+            "invalid_auth" => DeleteScheduledMessageError::InvalidAuth(context),
+            "invalid_charset" => DeleteScheduledMessageError::InvalidCharset(context),
+            "invalid_form_data" => DeleteScheduledMessageError::InvalidFormData(context),
+            "invalid_json" => DeleteScheduledMessageError::InvalidJson(context),
+            "invalid_post_type" => DeleteScheduledMessageError::InvalidPostType(context),
             "invalid_scheduled_message_id" => {
-                DeleteScheduledMessageError::InvalidScheduledMessageId
-            }
-            "json_not_object" => DeleteScheduledMessageError::JsonNotObject,
-            "missing_post_type" => DeleteScheduledMessageError::MissingPostType,
-            "missing_scope" => DeleteScheduledMessageError::MissingScope,
-            "no_permission" => DeleteScheduledMessageError::NoPermission,
-            "not_authed" => DeleteScheduledMessageError::NotAuthed,
-            "org_login_required" => DeleteScheduledMessageError::OrgLoginRequired,
-            "request_timeout" => DeleteScheduledMessageError::RequestTimeout,
-            "team_added_to_org" => DeleteScheduledMessageError::TeamAddedToOrg,
-            "token_revoked" => DeleteScheduledMessageError::TokenRevoked,
-            "upgrade_required" => DeleteScheduledMessageError::UpgradeRequired,
-            _ => DeleteScheduledMessageError::Unknown(s.to_owned()),
+                DeleteScheduledMessageError::InvalidScheduledMessageId(context)
+            }
+            "json_not_object" => DeleteScheduledMessageError::JsonNotObject(context),
+            "missing_post_type" => DeleteScheduledMessageError::MissingPostType(context),
+            "missing_scope" => DeleteScheduledMessageError::MissingScope(context),
+            "no_permission" => DeleteScheduledMessageError::NoPermission(context),
+            "not_authed" => DeleteScheduledMessageError::NotAuthed(context),
+            "org_login_required" => DeleteScheduledMessageError::OrgLoginRequired(context),
+            "request_timeout" => DeleteScheduledMessageError::RequestTimeout(context),
+            "team_added_to_org" => DeleteScheduledMessageError::TeamAddedToOrg(context),
+            "token_revoked" => DeleteScheduledMessageError::TokenRevoked(context),
+            "upgrade_required" => DeleteScheduledMessageError::UpgradeRequired(context),
+            _ => DeleteScheduledMessageError::Unknown(code.to_owned(), context),
         }
     }
 }
 
+impl<'a, E: Error> From<&'a str> for DeleteScheduledMessageError<E> {
+    fn from(s: &'a str) -> Self {
+        Self::new(s, crate::ErrorContext::default())
+    }
+}
+
 impl<E: Error> fmt::Display for DeleteScheduledMessageError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            DeleteScheduledMessageError::AccountInactive => {
+            DeleteScheduledMessageError::AccountInactive(_) => {
                 write!(f, "Server returned error account_inactive")
             }
-            DeleteScheduledMessageError::BadToken => write!(f, "Server returned error bad_token"),
-            DeleteScheduledMessageError::ChannelNotFound => {
+            DeleteScheduledMessageError::BadToken(_) => {
+                write!(f, "Server returned error bad_token")
+            }
+            DeleteScheduledMessageError::ChannelNotFound(_) => {
                 write!(f, "Server returned error channel_not_found")
             }
-            DeleteScheduledMessageError::EkmAccessDenied => {
+            DeleteScheduledMessageError::EkmAccessDenied(_) => {
                 write!(f, "Server returned error ekm_access_denied")
             }
-            DeleteScheduledMessageError::FatalError => {
+            DeleteScheduledMessageError::FatalError(_) => {
                 write!(f, "Server returned error fatal_error")
             }
-            DeleteScheduledMessageError::InvalidArgName => {
+            DeleteScheduledMessageError::InvalidArgName(_) => {
                 write!(f, "Server returned error invalid_arg_name")
             }
-            DeleteScheduledMessageError::InvalidArguments => {
+            DeleteScheduledMessageError::InvalidArguments(_) => {
                 write!(f, "Server returned error invalid_arguments")
             }
-            DeleteScheduledMessageError::InvalidAuth => {
+            DeleteScheduledMessageError::InvalidAuth(_) => {
                 write!(f, "Server returned error invalid_auth")
             }
-            DeleteScheduledMessageError::InvalidCharset => {
+            DeleteScheduledMessageError::InvalidCharset(_) => {
                 write!(f, "Server returned error invalid_charset")
             }
-            DeleteScheduledMessageError::InvalidFormData => {
+            DeleteScheduledMessageError::InvalidFormData(_) => {
                 write!(f, "Server returned error invalid_form_data")
             }
-            DeleteScheduledMessageError::InvalidJson => {
+            DeleteScheduledMessageError::InvalidJson(_) => {
                 write!(f, "Server returned error invalid_json")
             }
-            DeleteScheduledMessageError::InvalidPostType => {
+            DeleteScheduledMessageError::InvalidPostType(_) => {
                 write!(f, "Server returned error invalid_post_type")
             }
-            DeleteScheduledMessageError::InvalidScheduledMessageId => {
+            DeleteScheduledMessageError::InvalidScheduledMessageId(_) => {
                 write!(f, "Server returned error invalid_scheduled_message_id")
             }
-            DeleteScheduledMessageError::JsonNotObject => {
+            DeleteScheduledMessageError::JsonNotObject(_) => {
                 write!(f, "Server returned error json_not_object")
             }
-            DeleteScheduledMessageError::MissingPostType => {
+            DeleteScheduledMessageError::MissingPostType(_) => {
                 write!(f, "Server returned error missing_post_type")
             }
-            DeleteScheduledMessageError::MissingScope => {
+            DeleteScheduledMessageError::MissingScope(_) => {
                 write!(f, "Server returned error missing_scope")
             }
-            DeleteScheduledMessageError::NoPermission => {
+            DeleteScheduledMessageError::NoPermission(_) => {
                 write!(f, "Server returned error no_permission")
             }
-            DeleteScheduledMessageError::NotAuthed => write!(f, "Server returned error not_authed"),
-            DeleteScheduledMessageError::OrgLoginRequired => {
+            DeleteScheduledMessageError::NotAuthed(_) => {
+                write!(f, "Server returned error not_authed")
+            }
+            DeleteScheduledMessageError::OrgLoginRequired(_) => {
                 write!(f, "Server returned error org_login_required")
             }
-            DeleteScheduledMessageError::RequestTimeout => {
+            DeleteScheduledMessageError::RequestTimeout(_) => {
                 write!(f, "Server returned error request_timeout")
             }
-            DeleteScheduledMessageError::TeamAddedToOrg => {
+            DeleteScheduledMessageError::TeamAddedToOrg(_) => {
                 write!(f, "Server returned error team_added_to_org")
             }
-            DeleteScheduledMessageError::TokenRevoked => {
+            DeleteScheduledMessageError::TokenRevoked(_) => {
                 write!(f, "Server returned error token_revoked")
             }
-            DeleteScheduledMessageError::UpgradeRequired => {
+            DeleteScheduledMessageError::UpgradeRequired(_) => {
                 write!(f, "Server returned error upgrade_required")
             }
             DeleteScheduledMessageError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            DeleteScheduledMessageError::Unknown(ref s) => write!(f, "{}", s),
+            DeleteScheduledMessageError::Unknown(ref s, _) => write!(f, "{}", s),
             DeleteScheduledMessageError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -340,22 +498,104 @@ impl<E: Error + 'static> Error for DeleteScheduledMessageError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for DeleteScheduledMessageError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            DeleteScheduledMessageError::AccountInactive(_) => Some("account_inactive"),
+            DeleteScheduledMessageError::BadToken(_) => Some("bad_token"),
+            DeleteScheduledMessageError::ChannelNotFound(_) => Some("channel_not_found"),
+            DeleteScheduledMessageError::EkmAccessDenied(_) => Some("ekm_access_denied"),
+            DeleteScheduledMessageError::FatalError(_) => Some("fatal_error"),
+            DeleteScheduledMessageError::InvalidArgName(_) => Some("invalid_arg_name"),
+            DeleteScheduledMessageError::InvalidArguments(_) => Some("invalid_arguments"),
+            DeleteScheduledMessageError::InvalidAuth(_) => Some("invalid_auth"),
+            DeleteScheduledMessageError::InvalidCharset(_) => Some("invalid_charset"),
+            DeleteScheduledMessageError::InvalidFormData(_) => Some("invalid_form_data"),
+            DeleteScheduledMessageError::InvalidJson(_) => Some("invalid_json"),
+            DeleteScheduledMessageError::InvalidPostType(_) => Some("invalid_post_type"),
+            DeleteScheduledMessageError::InvalidScheduledMessageId(_) => {
+                Some("invalid_scheduled_message_id")
+            }
+            DeleteScheduledMessageError::JsonNotObject(_) => Some("json_not_object"),
+            DeleteScheduledMessageError::MissingPostType(_) => Some("missing_post_type"),
+            DeleteScheduledMessageError::MissingScope(_) => Some("missing_scope"),
+            DeleteScheduledMessageError::NoPermission(_) => Some("no_permission"),
+            DeleteScheduledMessageError::NotAuthed(_) => Some("not_authed"),
+            DeleteScheduledMessageError::OrgLoginRequired(_) => Some("org_login_required"),
+            DeleteScheduledMessageError::RequestTimeout(_) => Some("request_timeout"),
+            DeleteScheduledMessageError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            DeleteScheduledMessageError::TokenRevoked(_) => Some("token_revoked"),
+            DeleteScheduledMessageError::UpgradeRequired(_) => Some("upgrade_required"),
+            DeleteScheduledMessageError::Unknown(ref s, _) => Some(s),
+            DeleteScheduledMessageError::MalformedResponse(..)
+            | DeleteScheduledMessageError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            DeleteScheduledMessageError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            DeleteScheduledMessageError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            DeleteScheduledMessageError::AccountInactive(ref context)
+            | DeleteScheduledMessageError::BadToken(ref context)
+            | DeleteScheduledMessageError::ChannelNotFound(ref context)
+            | DeleteScheduledMessageError::EkmAccessDenied(ref context)
+            | DeleteScheduledMessageError::FatalError(ref context)
+            | DeleteScheduledMessageError::InvalidArgName(ref context)
+            | DeleteScheduledMessageError::InvalidArguments(ref context)
+            | DeleteScheduledMessageError::InvalidAuth(ref context)
+            | DeleteScheduledMessageError::InvalidCharset(ref context)
+            | DeleteScheduledMessageError::InvalidFormData(ref context)
+            | DeleteScheduledMessageError::InvalidJson(ref context)
+            | DeleteScheduledMessageError::InvalidPostType(ref context)
+            | DeleteScheduledMessageError::InvalidScheduledMessageId(ref context)
+            | DeleteScheduledMessageError::JsonNotObject(ref context)
+            | DeleteScheduledMessageError::MissingPostType(ref context)
+            | DeleteScheduledMessageError::MissingScope(ref context)
+            | DeleteScheduledMessageError::NoPermission(ref context)
+            | DeleteScheduledMessageError::NotAuthed(ref context)
+            | DeleteScheduledMessageError::OrgLoginRequired(ref context)
+            | DeleteScheduledMessageError::RequestTimeout(ref context)
+            | DeleteScheduledMessageError::TeamAddedToOrg(ref context)
+            | DeleteScheduledMessageError::TokenRevoked(ref context)
+            | DeleteScheduledMessageError::UpgradeRequired(ref context)
+            | DeleteScheduledMessageError::Unknown(_, ref context) => Some(context),
+            DeleteScheduledMessageError::MalformedResponse(..)
+            | DeleteScheduledMessageError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
-pub struct GetPermalinkRequest<'a> {
+pub struct GetPermalinkRequest {
     /// The ID of the conversation or channel containing the message
-    pub channel: Cow<'a, str>,
+    pub channel: Option<crate::ChannelId>,
     /// A message's `ts` value, uniquely identifying it within a channel
-    pub message_ts: Cow<'a, str>,
+    pub message_ts: crate::Timestamp,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GetPermalinkResponse {
     pub callstack: Option<String>,
-    pub channel: String,
+    pub channel: crate::ChannelId,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
     pub permalink: String,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<GetPermalinkResponse, GetPermalinkError<E>>> for GetPermalinkResponse {
@@ -363,116 +603,157 @@ impl<E: Error> Into<Result<GetPermalinkResponse, GetPermalinkError<E>>> for GetP
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(GetPermalinkError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for GetPermalinkResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum GetPermalinkError<E: Error> {
-    AccountInactive,
-    ChannelNotFound,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MessageNotFound,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    OrgLoginRequired,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequired,
+    AccountInactive(crate::ErrorContext),
+    ChannelNotFound(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MessageNotFound(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> GetPermalinkError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => GetPermalinkError::AccountInactive(context),
+            "channel_not_found" => GetPermalinkError::ChannelNotFound(context),
+            "fatal_error" => GetPermalinkError::FatalError(context),
+            "invalid_arg_name" => GetPermalinkError::InvalidArgName(context),
+            "invalid_array_arg" => GetPermalinkError::InvalidArrayArg(context),
+            "invalid_auth" => GetPermalinkError::InvalidAuth(context),
+            "invalid_charset" => GetPermalinkError::InvalidCharset(context),
+            "invalid_form_data" => GetPermalinkError::InvalidFormData(context),
+            "invalid_json" => GetPermalinkError::InvalidJson(context),
+            "invalid_post_type" => GetPermalinkError::InvalidPostType(context),
+            "json_not_object" => GetPermalinkError::JsonNotObject(context),
+            "message_not_found" => GetPermalinkError::MessageNotFound(context),
+            "missing_post_type" => GetPermalinkError::MissingPostType(context),
+            "no_permission" => GetPermalinkError::NoPermission(context),
+            "not_authed" => GetPermalinkError::NotAuthed(context),
+            "org_login_required" => GetPermalinkError::OrgLoginRequired(context),
+            "request_timeout" => GetPermalinkError::RequestTimeout(context),
+            "team_added_to_org" => GetPermalinkError::TeamAddedToOrg(context),
+            "token_revoked" => GetPermalinkError::TokenRevoked(context),
+            "upgrade_required" => GetPermalinkError::UpgradeRequired(context),
+            _ => GetPermalinkError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for GetPermalinkError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => GetPermalinkError::AccountInactive,
-            "channel_not_found" => GetPermalinkError::ChannelNotFound,
-            "fatal_error" => GetPermalinkError::FatalError,
-            "invalid_arg_name" => GetPermalinkError::InvalidArgName,
-            "invalid_array_arg" => GetPermalinkError::InvalidArrayArg,
-            "invalid_auth" => GetPermalinkError::InvalidAuth,
-            "invalid_charset" => GetPermalinkError::InvalidCharset,
-            "invalid_form_data" => GetPermalinkError::InvalidFormData,
-            "invalid_json" => GetPermalinkError::InvalidJson,
-            "invalid_post_type" => GetPermalinkError::InvalidPostType,
-            "json_not_object" => GetPermalinkError::JsonNotObject,
-            "message_not_found" => GetPermalinkError::MessageNotFound,
-            "missing_post_type" => GetPermalinkError::MissingPostType,
-            "no_permission" => GetPermalinkError::NoPermission,
-            "not_authed" => GetPermalinkError::NotAuthed,
-            "org_login_required" => GetPermalinkError::OrgLoginRequired,
-            "request_timeout" => GetPermalinkError::RequestTimeout,
-            "team_added_to_org" => GetPermalinkError::TeamAddedToOrg,
-            "token_revoked" => GetPermalinkError::TokenRevoked,
-            "upgrade_required" => GetPermalinkError::UpgradeRequired,
-            _ => GetPermalinkError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for GetPermalinkError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            GetPermalinkError::AccountInactive => {
+            GetPermalinkError::AccountInactive(_) => {
                 write!(f, "Server returned error account_inactive")
             }
-            GetPermalinkError::ChannelNotFound => {
+            GetPermalinkError::ChannelNotFound(_) => {
                 write!(f, "Server returned error channel_not_found")
             }
-            GetPermalinkError::FatalError => write!(f, "Server returned error fatal_error"),
-            GetPermalinkError::InvalidArgName => {
+            GetPermalinkError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            GetPermalinkError::InvalidArgName(_) => {
                 write!(f, "Server returned error invalid_arg_name")
             }
-            GetPermalinkError::InvalidArrayArg => {
+            GetPermalinkError::InvalidArrayArg(_) => {
                 write!(f, "Server returned error invalid_array_arg")
             }
-            GetPermalinkError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            GetPermalinkError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            GetPermalinkError::InvalidFormData => {
+            GetPermalinkError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            GetPermalinkError::InvalidCharset(_) => {
+                write!(f, "Server returned error invalid_charset")
+            }
+            GetPermalinkError::InvalidFormData(_) => {
                 write!(f, "Server returned error invalid_form_data")
             }
-            GetPermalinkError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            GetPermalinkError::InvalidPostType => {
+            GetPermalinkError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            GetPermalinkError::InvalidPostType(_) => {
                 write!(f, "Server returned error invalid_post_type")
             }
-            GetPermalinkError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            GetPermalinkError::MessageNotFound => {
+            GetPermalinkError::JsonNotObject(_) => {
+                write!(f, "Server returned error json_not_object")
+            }
+            GetPermalinkError::MessageNotFound(_) => {
                 write!(f, "Server returned error message_not_found")
             }
-            GetPermalinkError::MissingPostType => {
+            GetPermalinkError::MissingPostType(_) => {
                 write!(f, "Server returned error missing_post_type")
             }
-            GetPermalinkError::NoPermission => write!(f, "Server returned error no_permission"),
-            GetPermalinkError::NotAuthed => write!(f, "Server returned error not_authed"),
-            GetPermalinkError::OrgLoginRequired => {
+            GetPermalinkError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            GetPermalinkError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            GetPermalinkError::OrgLoginRequired(_) => {
                 write!(f, "Server returned error org_login_required")
             }
-            GetPermalinkError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            GetPermalinkError::TeamAddedToOrg => {
+            GetPermalinkError::RequestTimeout(_) => {
+                write!(f, "Server returned error request_timeout")
+            }
+            GetPermalinkError::TeamAddedToOrg(_) => {
                 write!(f, "Server returned error team_added_to_org")
             }
-            GetPermalinkError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            GetPermalinkError::UpgradeRequired => {
+            GetPermalinkError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            GetPermalinkError::UpgradeRequired(_) => {
                 write!(f, "Server returned error upgrade_required")
             }
             GetPermalinkError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            GetPermalinkError::Unknown(ref s) => write!(f, "{}", s),
+            GetPermalinkError::Unknown(ref s, _) => write!(f, "{}", s),
             GetPermalinkError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -488,22 +769,94 @@ impl<E: Error + 'static> Error for GetPermalinkError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for GetPermalinkError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            GetPermalinkError::AccountInactive(_) => Some("account_inactive"),
+            GetPermalinkError::ChannelNotFound(_) => Some("channel_not_found"),
+            GetPermalinkError::FatalError(_) => Some("fatal_error"),
+            GetPermalinkError::InvalidArgName(_) => Some("invalid_arg_name"),
+            GetPermalinkError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            GetPermalinkError::InvalidAuth(_) => Some("invalid_auth"),
+            GetPermalinkError::InvalidCharset(_) => Some("invalid_charset"),
+            GetPermalinkError::InvalidFormData(_) => Some("invalid_form_data"),
+            GetPermalinkError::InvalidJson(_) => Some("invalid_json"),
+            GetPermalinkError::InvalidPostType(_) => Some("invalid_post_type"),
+            GetPermalinkError::JsonNotObject(_) => Some("json_not_object"),
+            GetPermalinkError::MessageNotFound(_) => Some("message_not_found"),
+            GetPermalinkError::MissingPostType(_) => Some("missing_post_type"),
+            GetPermalinkError::NoPermission(_) => Some("no_permission"),
+            GetPermalinkError::NotAuthed(_) => Some("not_authed"),
+            GetPermalinkError::OrgLoginRequired(_) => Some("org_login_required"),
+            GetPermalinkError::RequestTimeout(_) => Some("request_timeout"),
+            GetPermalinkError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            GetPermalinkError::TokenRevoked(_) => Some("token_revoked"),
+            GetPermalinkError::UpgradeRequired(_) => Some("upgrade_required"),
+            GetPermalinkError::Unknown(ref s, _) => Some(s),
+            GetPermalinkError::MalformedResponse(..) | GetPermalinkError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            GetPermalinkError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            GetPermalinkError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            GetPermalinkError::AccountInactive(ref context)
+            | GetPermalinkError::ChannelNotFound(ref context)
+            | GetPermalinkError::FatalError(ref context)
+            | GetPermalinkError::InvalidArgName(ref context)
+            | GetPermalinkError::InvalidArrayArg(ref context)
+            | GetPermalinkError::InvalidAuth(ref context)
+            | GetPermalinkError::InvalidCharset(ref context)
+            | GetPermalinkError::InvalidFormData(ref context)
+            | GetPermalinkError::InvalidJson(ref context)
+            | GetPermalinkError::InvalidPostType(ref context)
+            | GetPermalinkError::JsonNotObject(ref context)
+            | GetPermalinkError::MessageNotFound(ref context)
+            | GetPermalinkError::MissingPostType(ref context)
+            | GetPermalinkError::NoPermission(ref context)
+            | GetPermalinkError::NotAuthed(ref context)
+            | GetPermalinkError::OrgLoginRequired(ref context)
+            | GetPermalinkError::RequestTimeout(ref context)
+            | GetPermalinkError::TeamAddedToOrg(ref context)
+            | GetPermalinkError::TokenRevoked(ref context)
+            | GetPermalinkError::UpgradeRequired(ref context)
+            | GetPermalinkError::Unknown(_, ref context) => Some(context),
+            GetPermalinkError::MalformedResponse(..) | GetPermalinkError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct MeMessageRequest<'a> {
-    /// Channel to send message to. Can be a public channel, private group or IM channel. Can be an encoded ID, or a name.
-    pub channel: Cow<'a, str>,
+    /// Channel to send message to. Can be a public channel, private group or IM channel. Can be an encoded ID, or a name. Pass a name with `crate::ChannelId::new_unchecked`.
+    pub channel: Option<crate::ChannelId>,
     /// Text of the message to send.
     pub text: Cow<'a, str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MeMessageResponse {
     pub callstack: Option<String>,
-    pub channel: Option<String>,
+    pub channel: Option<crate::ChannelId>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
-    pub ts: Option<String>,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub ts: Option<crate::Timestamp>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<MeMessageResponse, MeMessageError<E>>> for MeMessageResponse {
@@ -511,108 +864,161 @@ impl<E: Error> Into<Result<MeMessageResponse, MeMessageError<E>>> for MeMessageR
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(MeMessageError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for MeMessageResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum MeMessageError<E: Error> {
-    AccountInactive,
-    ChannelNotFound,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    IsArchived,
-    JsonNotObject,
-    MissingPostType,
-    MsgTooLong,
-    NoPermission,
-    NoText,
-    NotAuthed,
-    NotInChannel,
-    OrgLoginRequired,
-    RateLimited,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequired,
+    AccountInactive(crate::ErrorContext),
+    ChannelNotFound(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    IsArchived(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    MsgTooLong(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NoText(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    NotInChannel(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    RateLimited(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> MeMessageError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => MeMessageError::AccountInactive(context),
+            "channel_not_found" => MeMessageError::ChannelNotFound(context),
+            "fatal_error" => MeMessageError::FatalError(context),
+            "invalid_arg_name" => MeMessageError::InvalidArgName(context),
+            "invalid_array_arg" => MeMessageError::InvalidArrayArg(context),
+            "invalid_auth" => MeMessageError::InvalidAuth(context),
+            "invalid_charset" => MeMessageError::InvalidCharset(context),
+            "invalid_form_data" => MeMessageError::InvalidFormData(context),
+            "invalid_json" => MeMessageError::InvalidJson(context),
+            "invalid_post_type" => MeMessageError::InvalidPostType(context),
+            "is_archived" => MeMessageError::IsArchived(context),
+            "json_not_object" => MeMessageError::JsonNotObject(context),
+            "missing_post_type" => MeMessageError::MissingPostType(context),
+            "msg_too_long" => MeMessageError::MsgTooLong(context),
+            "no_permission" => MeMessageError::NoPermission(context),
+            "no_text" => MeMessageError::NoText(context),
+            "not_authed" => MeMessageError::NotAuthed(context),
+            "not_in_channel" => MeMessageError::NotInChannel(context),
+            "org_login_required" => MeMessageError::OrgLoginRequired(context),
+            "rate_limited" => MeMessageError::RateLimited(context),
+            "request_timeout" => MeMessageError::RequestTimeout(context),
+            "team_added_to_org" => MeMessageError::TeamAddedToOrg(context),
+            "token_revoked" => MeMessageError::TokenRevoked(context),
+            "upgrade_required" => MeMessageError::UpgradeRequired(context),
+            _ => MeMessageError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for MeMessageError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => MeMessageError::AccountInactive,
-            "channel_not_found" => MeMessageError::ChannelNotFound,
-            "fatal_error" => MeMessageError::FatalError,
-            "invalid_arg_name" => MeMessageError::InvalidArgName,
-            "invalid_array_arg" => MeMessageError::InvalidArrayArg,
-            "invalid_auth" => MeMessageError::InvalidAuth,
-            "invalid_charset" => MeMessageError::InvalidCharset,
-            "invalid_form_data" => MeMessageError::InvalidFormData,
-            "invalid_json" => MeMessageError::InvalidJson,
-            "invalid_post_type" => MeMessageError::InvalidPostType,
-            "is_archived" => MeMessageError::IsArchived,
-            "json_not_object" => MeMessageError::JsonNotObject,
-            "missing_post_type" => MeMessageError::MissingPostType,
-            "msg_too_long" => MeMessageError::MsgTooLong,
-            "no_permission" => MeMessageError::NoPermission,
-            "no_text" => MeMessageError::NoText,
-            "not_authed" => MeMessageError::NotAuthed,
-            "not_in_channel" => MeMessageError::NotInChannel,
-            "org_login_required" => MeMessageError::OrgLoginRequired,
-            "rate_limited" => MeMessageError::RateLimited,
-            "request_timeout" => MeMessageError::RequestTimeout,
-            "team_added_to_org" => MeMessageError::TeamAddedToOrg,
-            "token_revoked" => MeMessageError::TokenRevoked,
-            "upgrade_required" => MeMessageError::UpgradeRequired,
-            _ => MeMessageError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for MeMessageError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            MeMessageError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            MeMessageError::ChannelNotFound => write!(f, "Server returned error channel_not_found"),
-            MeMessageError::FatalError => write!(f, "Server returned error fatal_error"),
-            MeMessageError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            MeMessageError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            MeMessageError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            MeMessageError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            MeMessageError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            MeMessageError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            MeMessageError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            MeMessageError::IsArchived => write!(f, "Server returned error is_archived"),
-            MeMessageError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            MeMessageError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            MeMessageError::MsgTooLong => write!(f, "Server returned error msg_too_long"),
-            MeMessageError::NoPermission => write!(f, "Server returned error no_permission"),
-            MeMessageError::NoText => write!(f, "Server returned error no_text"),
-            MeMessageError::NotAuthed => write!(f, "Server returned error not_authed"),
-            MeMessageError::NotInChannel => write!(f, "Server returned error not_in_channel"),
-            MeMessageError::OrgLoginRequired => {
+            MeMessageError::AccountInactive(_) => {
+                write!(f, "Server returned error account_inactive")
+            }
+            MeMessageError::ChannelNotFound(_) => {
+                write!(f, "Server returned error channel_not_found")
+            }
+            MeMessageError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            MeMessageError::InvalidArgName(_) => {
+                write!(f, "Server returned error invalid_arg_name")
+            }
+            MeMessageError::InvalidArrayArg(_) => {
+                write!(f, "Server returned error invalid_array_arg")
+            }
+            MeMessageError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            MeMessageError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            MeMessageError::InvalidFormData(_) => {
+                write!(f, "Server returned error invalid_form_data")
+            }
+            MeMessageError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            MeMessageError::InvalidPostType(_) => {
+                write!(f, "Server returned error invalid_post_type")
+            }
+            MeMessageError::IsArchived(_) => write!(f, "Server returned error is_archived"),
+            MeMessageError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            MeMessageError::MissingPostType(_) => {
+                write!(f, "Server returned error missing_post_type")
+            }
+            MeMessageError::MsgTooLong(_) => write!(f, "Server returned error msg_too_long"),
+            MeMessageError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            MeMessageError::NoText(_) => write!(f, "Server returned error no_text"),
+            MeMessageError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            MeMessageError::NotInChannel(_) => write!(f, "Server returned error not_in_channel"),
+            MeMessageError::OrgLoginRequired(_) => {
                 write!(f, "Server returned error org_login_required")
             }
-            MeMessageError::RateLimited => write!(f, "Server returned error rate_limited"),
-            MeMessageError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            MeMessageError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            MeMessageError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            MeMessageError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
+            MeMessageError::RateLimited(_) => write!(f, "Server returned error rate_limited"),
+            MeMessageError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            MeMessageError::TeamAddedToOrg(_) => {
+                write!(f, "Server returned error team_added_to_org")
+            }
+            MeMessageError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            MeMessageError::UpgradeRequired(_) => {
+                write!(f, "Server returned error upgrade_required")
+            }
             MeMessageError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            MeMessageError::Unknown(ref s) => write!(f, "{}", s),
+            MeMessageError::Unknown(ref s, _) => write!(f, "{}", s),
             MeMessageError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -628,16 +1034,90 @@ impl<E: Error + 'static> Error for MeMessageError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for MeMessageError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            MeMessageError::AccountInactive(_) => Some("account_inactive"),
+            MeMessageError::ChannelNotFound(_) => Some("channel_not_found"),
+            MeMessageError::FatalError(_) => Some("fatal_error"),
+            MeMessageError::InvalidArgName(_) => Some("invalid_arg_name"),
+            MeMessageError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            MeMessageError::InvalidAuth(_) => Some("invalid_auth"),
+            MeMessageError::InvalidCharset(_) => Some("invalid_charset"),
+            MeMessageError::InvalidFormData(_) => Some("invalid_form_data"),
+            MeMessageError::InvalidJson(_) => Some("invalid_json"),
+            MeMessageError::InvalidPostType(_) => Some("invalid_post_type"),
+            MeMessageError::IsArchived(_) => Some("is_archived"),
+            MeMessageError::JsonNotObject(_) => Some("json_not_object"),
+            MeMessageError::MissingPostType(_) => Some("missing_post_type"),
+            MeMessageError::MsgTooLong(_) => Some("msg_too_long"),
+            MeMessageError::NoPermission(_) => Some("no_permission"),
+            MeMessageError::NoText(_) => Some("no_text"),
+            MeMessageError::NotAuthed(_) => Some("not_authed"),
+            MeMessageError::NotInChannel(_) => Some("not_in_channel"),
+            MeMessageError::OrgLoginRequired(_) => Some("org_login_required"),
+            MeMessageError::RateLimited(_) => Some("rate_limited"),
+            MeMessageError::RequestTimeout(_) => Some("request_timeout"),
+            MeMessageError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            MeMessageError::TokenRevoked(_) => Some("token_revoked"),
+            MeMessageError::UpgradeRequired(_) => Some("upgrade_required"),
+            MeMessageError::Unknown(ref s, _) => Some(s),
+            MeMessageError::MalformedResponse(..) | MeMessageError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            MeMessageError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            MeMessageError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            MeMessageError::AccountInactive(ref context)
+            | MeMessageError::ChannelNotFound(ref context)
+            | MeMessageError::FatalError(ref context)
+            | MeMessageError::InvalidArgName(ref context)
+            | MeMessageError::InvalidArrayArg(ref context)
+            | MeMessageError::InvalidAuth(ref context)
+            | MeMessageError::InvalidCharset(ref context)
+            | MeMessageError::InvalidFormData(ref context)
+            | MeMessageError::InvalidJson(ref context)
+            | MeMessageError::InvalidPostType(ref context)
+            | MeMessageError::IsArchived(ref context)
+            | MeMessageError::JsonNotObject(ref context)
+            | MeMessageError::MissingPostType(ref context)
+            | MeMessageError::MsgTooLong(ref context)
+            | MeMessageError::NoPermission(ref context)
+            | MeMessageError::NoText(ref context)
+            | MeMessageError::NotAuthed(ref context)
+            | MeMessageError::NotInChannel(ref context)
+            | MeMessageError::OrgLoginRequired(ref context)
+            | MeMessageError::RateLimited(ref context)
+            | MeMessageError::RequestTimeout(ref context)
+            | MeMessageError::TeamAddedToOrg(ref context)
+            | MeMessageError::TokenRevoked(ref context)
+            | MeMessageError::UpgradeRequired(ref context)
+            | MeMessageError::Unknown(_, ref context) => Some(context),
+            MeMessageError::MalformedResponse(..) | MeMessageError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct PostEphemeralRequest<'a> {
     /// Pass true to post the message as the authed user. Defaults to true if the chat:write:bot scope is not included. Otherwise, defaults to false.
     pub as_user: Option<bool>,
-    /// A JSON-based array of structured attachments, presented as a URL-encoded string.
-    pub attachments: Option<Cow<'a, str>>,
-    /// A JSON-based array of structured blocks, presented as a URL-encoded string.
-    pub blocks: Option<Cow<'a, str>>,
-    /// Channel, private group, or IM channel to send message to. Can be an encoded ID, or a name.
-    pub channel: Cow<'a, str>,
+    /// A JSON-based array of structured attachments.
+    pub attachments: Option<serde_json::Value>,
+    /// A JSON-based array of structured blocks.
+    pub blocks: Option<Vec<crate::blocks::Block>>,
+    /// Channel, private group, or IM channel to send message to. Can be an encoded ID, or a name. Pass a name with `crate::ChannelId::new_unchecked`.
+    pub channel: Option<crate::ChannelId>,
     /// Emoji to use as the icon for this message. Overrides `icon_url`. Must be used in conjunction with `as_user` set to `false`, otherwise ignored. See [authorship](#authorship) below.
     pub icon_emoji: Option<Cow<'a, str>>,
     /// URL to an image to use as the icon for this message. Must be used in conjunction with `as_user` set to false, otherwise ignored. See [authorship](#authorship) below.
@@ -649,20 +1129,26 @@ pub struct PostEphemeralRequest<'a> {
     /// How this field works and whether it is required depends on other fields you use in your API call. [See below](#text_usage) for more detail.
     pub text: Option<Cow<'a, str>>,
     /// Provide another message's `ts` value to post this message in a thread. Avoid using a reply's `ts` value; use its parent's value instead. Ephemeral messages in threads are only shown if there is already an active thread.
-    pub thread_ts: Option<Cow<'a, str>>,
+    pub thread_ts: Option<crate::Timestamp>,
     /// `id` of the user who will receive the ephemeral message. The user should be in the channel specified by the `channel` argument.
-    pub user: Cow<'a, str>,
+    pub user: Option<crate::UserId>,
     /// Set your bot's user name. Must be used in conjunction with `as_user` set to false, otherwise ignored. See [authorship](#authorship) below.
     pub username: Option<Cow<'a, str>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct PostEphemeralResponse {
     pub callstack: Option<String>,
     error: Option<String>,
-    pub message_ts: String,
+    pub message_ts: crate::Timestamp,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<PostEphemeralResponse, PostEphemeralError<E>>>
@@ -672,139 +1158,176 @@ impl<E: Error> Into<Result<PostEphemeralResponse, PostEphemeralError<E>>>
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(PostEphemeralError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for PostEphemeralResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum PostEphemeralError<E: Error> {
-    AccountInactive,
-    ChannelNotFound,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    IsArchived,
-    JsonNotObject,
-    MissingPostType,
-    MsgTooLong,
-    NoPermission,
-    NoText,
-    NotAuthed,
-    OrgLoginRequired,
-    RequestTimeout,
-    RestrictedAction,
-    TeamAddedToOrg,
-    TokenRevoked,
-    TooManyAttachments,
-    UpgradeRequired,
-    UserNotInChannel,
+    AccountInactive(crate::ErrorContext),
+    ChannelNotFound(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    IsArchived(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    MsgTooLong(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NoText(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    RestrictedAction(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    TooManyAttachments(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserNotInChannel(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> PostEphemeralError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => PostEphemeralError::AccountInactive(context),
+            "channel_not_found" => PostEphemeralError::ChannelNotFound(context),
+            "fatal_error" => PostEphemeralError::FatalError(context),
+            "invalid_arg_name" => PostEphemeralError::InvalidArgName(context),
+            "invalid_array_arg" => PostEphemeralError::InvalidArrayArg(context),
+            "invalid_auth" => PostEphemeralError::InvalidAuth(context),
+            "invalid_charset" => PostEphemeralError::InvalidCharset(context),
+            "invalid_form_data" => PostEphemeralError::InvalidFormData(context),
+            "invalid_json" => PostEphemeralError::InvalidJson(context),
+            "invalid_post_type" => PostEphemeralError::InvalidPostType(context),
+            "is_archived" => PostEphemeralError::IsArchived(context),
+            "json_not_object" => PostEphemeralError::JsonNotObject(context),
+            "missing_post_type" => PostEphemeralError::MissingPostType(context),
+            "msg_too_long" => PostEphemeralError::MsgTooLong(context),
+            "no_permission" => PostEphemeralError::NoPermission(context),
+            "no_text" => PostEphemeralError::NoText(context),
+            "not_authed" => PostEphemeralError::NotAuthed(context),
+            "org_login_required" => PostEphemeralError::OrgLoginRequired(context),
+            "request_timeout" => PostEphemeralError::RequestTimeout(context),
+            "restricted_action" => PostEphemeralError::RestrictedAction(context),
+            "team_added_to_org" => PostEphemeralError::TeamAddedToOrg(context),
+            "token_revoked" => PostEphemeralError::TokenRevoked(context),
+            "too_many_attachments" => PostEphemeralError::TooManyAttachments(context),
+            "upgrade_required" => PostEphemeralError::UpgradeRequired(context),
+            "user_not_in_channel" => PostEphemeralError::UserNotInChannel(context),
+            _ => PostEphemeralError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for PostEphemeralError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => PostEphemeralError::AccountInactive,
-            "channel_not_found" => PostEphemeralError::ChannelNotFound,
-            "fatal_error" => PostEphemeralError::FatalError,
-            "invalid_arg_name" => PostEphemeralError::InvalidArgName,
-            "invalid_array_arg" => PostEphemeralError::InvalidArrayArg,
-            "invalid_auth" => PostEphemeralError::InvalidAuth,
-            "invalid_charset" => PostEphemeralError::InvalidCharset,
-            "invalid_form_data" => PostEphemeralError::InvalidFormData,
-            "invalid_json" => PostEphemeralError::InvalidJson,
-            "invalid_post_type" => PostEphemeralError::InvalidPostType,
-            "is_archived" => PostEphemeralError::IsArchived,
-            "json_not_object" => PostEphemeralError::JsonNotObject,
-            "missing_post_type" => PostEphemeralError::MissingPostType,
-            "msg_too_long" => PostEphemeralError::MsgTooLong,
-            "no_permission" => PostEphemeralError::NoPermission,
-            "no_text" => PostEphemeralError::NoText,
-            "not_authed" => PostEphemeralError::NotAuthed,
-            "org_login_required" => PostEphemeralError::OrgLoginRequired,
-            "request_timeout" => PostEphemeralError::RequestTimeout,
-            "restricted_action" => PostEphemeralError::RestrictedAction,
-            "team_added_to_org" => PostEphemeralError::TeamAddedToOrg,
-            "token_revoked" => PostEphemeralError::TokenRevoked,
-            "too_many_attachments" => PostEphemeralError::TooManyAttachments,
-            "upgrade_required" => PostEphemeralError::UpgradeRequired,
-            "user_not_in_channel" => PostEphemeralError::UserNotInChannel,
-            _ => PostEphemeralError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for PostEphemeralError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            PostEphemeralError::AccountInactive => {
+            PostEphemeralError::AccountInactive(_) => {
                 write!(f, "Server returned error account_inactive")
             }
-            PostEphemeralError::ChannelNotFound => {
+            PostEphemeralError::ChannelNotFound(_) => {
                 write!(f, "Server returned error channel_not_found")
             }
-            PostEphemeralError::FatalError => write!(f, "Server returned error fatal_error"),
-            PostEphemeralError::InvalidArgName => {
+            PostEphemeralError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            PostEphemeralError::InvalidArgName(_) => {
                 write!(f, "Server returned error invalid_arg_name")
             }
-            PostEphemeralError::InvalidArrayArg => {
+            PostEphemeralError::InvalidArrayArg(_) => {
                 write!(f, "Server returned error invalid_array_arg")
             }
-            PostEphemeralError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            PostEphemeralError::InvalidCharset => {
+            PostEphemeralError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            PostEphemeralError::InvalidCharset(_) => {
                 write!(f, "Server returned error invalid_charset")
             }
-            PostEphemeralError::InvalidFormData => {
+            PostEphemeralError::InvalidFormData(_) => {
                 write!(f, "Server returned error invalid_form_data")
             }
-            PostEphemeralError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            PostEphemeralError::InvalidPostType => {
+            PostEphemeralError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            PostEphemeralError::InvalidPostType(_) => {
                 write!(f, "Server returned error invalid_post_type")
             }
-            PostEphemeralError::IsArchived => write!(f, "Server returned error is_archived"),
-            PostEphemeralError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            PostEphemeralError::MissingPostType => {
+            PostEphemeralError::IsArchived(_) => write!(f, "Server returned error is_archived"),
+            PostEphemeralError::JsonNotObject(_) => {
+                write!(f, "Server returned error json_not_object")
+            }
+            PostEphemeralError::MissingPostType(_) => {
                 write!(f, "Server returned error missing_post_type")
             }
-            PostEphemeralError::MsgTooLong => write!(f, "Server returned error msg_too_long"),
-            PostEphemeralError::NoPermission => write!(f, "Server returned error no_permission"),
-            PostEphemeralError::NoText => write!(f, "Server returned error no_text"),
-            PostEphemeralError::NotAuthed => write!(f, "Server returned error not_authed"),
-            PostEphemeralError::OrgLoginRequired => {
+            PostEphemeralError::MsgTooLong(_) => write!(f, "Server returned error msg_too_long"),
+            PostEphemeralError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            PostEphemeralError::NoText(_) => write!(f, "Server returned error no_text"),
+            PostEphemeralError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            PostEphemeralError::OrgLoginRequired(_) => {
                 write!(f, "Server returned error org_login_required")
             }
-            PostEphemeralError::RequestTimeout => {
+            PostEphemeralError::RequestTimeout(_) => {
                 write!(f, "Server returned error request_timeout")
             }
-            PostEphemeralError::RestrictedAction => {
+            PostEphemeralError::RestrictedAction(_) => {
                 write!(f, "Server returned error restricted_action")
             }
-            PostEphemeralError::TeamAddedToOrg => {
+            PostEphemeralError::TeamAddedToOrg(_) => {
                 write!(f, "Server returned error team_added_to_org")
             }
-            PostEphemeralError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            PostEphemeralError::TooManyAttachments => {
+            PostEphemeralError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            PostEphemeralError::TooManyAttachments(_) => {
                 write!(f, "Server returned error too_many_attachments")
             }
-            PostEphemeralError::UpgradeRequired => {
+            PostEphemeralError::UpgradeRequired(_) => {
                 write!(f, "Server returned error upgrade_required")
             }
-            PostEphemeralError::UserNotInChannel => {
+            PostEphemeralError::UserNotInChannel(_) => {
                 write!(f, "Server returned error user_not_in_channel")
             }
             PostEphemeralError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            PostEphemeralError::Unknown(ref s) => write!(f, "{}", s),
+            PostEphemeralError::Unknown(ref s, _) => write!(f, "{}", s),
             PostEphemeralError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -820,16 +1343,92 @@ impl<E: Error + 'static> Error for PostEphemeralError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for PostEphemeralError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            PostEphemeralError::AccountInactive(_) => Some("account_inactive"),
+            PostEphemeralError::ChannelNotFound(_) => Some("channel_not_found"),
+            PostEphemeralError::FatalError(_) => Some("fatal_error"),
+            PostEphemeralError::InvalidArgName(_) => Some("invalid_arg_name"),
+            PostEphemeralError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            PostEphemeralError::InvalidAuth(_) => Some("invalid_auth"),
+            PostEphemeralError::InvalidCharset(_) => Some("invalid_charset"),
+            PostEphemeralError::InvalidFormData(_) => Some("invalid_form_data"),
+            PostEphemeralError::InvalidJson(_) => Some("invalid_json"),
+            PostEphemeralError::InvalidPostType(_) => Some("invalid_post_type"),
+            PostEphemeralError::IsArchived(_) => Some("is_archived"),
+            PostEphemeralError::JsonNotObject(_) => Some("json_not_object"),
+            PostEphemeralError::MissingPostType(_) => Some("missing_post_type"),
+            PostEphemeralError::MsgTooLong(_) => Some("msg_too_long"),
+            PostEphemeralError::NoPermission(_) => Some("no_permission"),
+            PostEphemeralError::NoText(_) => Some("no_text"),
+            PostEphemeralError::NotAuthed(_) => Some("not_authed"),
+            PostEphemeralError::OrgLoginRequired(_) => Some("org_login_required"),
+            PostEphemeralError::RequestTimeout(_) => Some("request_timeout"),
+            PostEphemeralError::RestrictedAction(_) => Some("restricted_action"),
+            PostEphemeralError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            PostEphemeralError::TokenRevoked(_) => Some("token_revoked"),
+            PostEphemeralError::TooManyAttachments(_) => Some("too_many_attachments"),
+            PostEphemeralError::UpgradeRequired(_) => Some("upgrade_required"),
+            PostEphemeralError::UserNotInChannel(_) => Some("user_not_in_channel"),
+            PostEphemeralError::Unknown(ref s, _) => Some(s),
+            PostEphemeralError::MalformedResponse(..) | PostEphemeralError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            PostEphemeralError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            PostEphemeralError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            PostEphemeralError::AccountInactive(ref context)
+            | PostEphemeralError::ChannelNotFound(ref context)
+            | PostEphemeralError::FatalError(ref context)
+            | PostEphemeralError::InvalidArgName(ref context)
+            | PostEphemeralError::InvalidArrayArg(ref context)
+            | PostEphemeralError::InvalidAuth(ref context)
+            | PostEphemeralError::InvalidCharset(ref context)
+            | PostEphemeralError::InvalidFormData(ref context)
+            | PostEphemeralError::InvalidJson(ref context)
+            | PostEphemeralError::InvalidPostType(ref context)
+            | PostEphemeralError::IsArchived(ref context)
+            | PostEphemeralError::JsonNotObject(ref context)
+            | PostEphemeralError::MissingPostType(ref context)
+            | PostEphemeralError::MsgTooLong(ref context)
+            | PostEphemeralError::NoPermission(ref context)
+            | PostEphemeralError::NoText(ref context)
+            | PostEphemeralError::NotAuthed(ref context)
+            | PostEphemeralError::OrgLoginRequired(ref context)
+            | PostEphemeralError::RequestTimeout(ref context)
+            | PostEphemeralError::RestrictedAction(ref context)
+            | PostEphemeralError::TeamAddedToOrg(ref context)
+            | PostEphemeralError::TokenRevoked(ref context)
+            | PostEphemeralError::TooManyAttachments(ref context)
+            | PostEphemeralError::UpgradeRequired(ref context)
+            | PostEphemeralError::UserNotInChannel(ref context)
+            | PostEphemeralError::Unknown(_, ref context) => Some(context),
+            PostEphemeralError::MalformedResponse(..) | PostEphemeralError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct PostMessageRequest<'a> {
     /// Pass true to post the message as the authed user, instead of as a bot. Defaults to false. See [authorship](#authorship) below.
     pub as_user: Option<Cow<'a, str>>,
-    /// A JSON-based array of structured attachments, presented as a URL-encoded string.
-    pub attachments: Option<Cow<'a, str>>,
-    /// A JSON-based array of structured blocks, presented as a URL-encoded string.
-    pub blocks: Option<Cow<'a, str>>,
-    /// Channel, private group, or IM channel to send message to. Can be an encoded ID, or a name. See [below](#channels) for more details.
-    pub channel: Cow<'a, str>,
+    /// A JSON-based array of structured attachments.
+    pub attachments: Option<serde_json::Value>,
+    /// A JSON-based array of structured blocks.
+    pub blocks: Option<Vec<crate::blocks::Block>>,
+    /// Channel, private group, or IM channel to send message to. Can be an encoded ID, or a name. See [below](#channels) for more details. Pass a name with `crate::ChannelId::new_unchecked`.
+    pub channel: Option<crate::ChannelId>,
     /// Emoji to use as the icon for this message. Overrides `icon_url`. Must be used in conjunction with `as_user` set to `false`, otherwise ignored. See [authorship](#authorship) below.
     pub icon_emoji: Option<Cow<'a, str>>,
     /// URL to an image to use as the icon for this message. Must be used in conjunction with `as_user` set to false, otherwise ignored. See [authorship](#authorship) below.
@@ -845,7 +1444,7 @@ pub struct PostMessageRequest<'a> {
     /// How this field works and whether it is required depends on other fields you use in your API call. [See below](#text_usage) for more detail.
     pub text: Cow<'a, str>,
     /// Provide another message's `ts` value to make this message a reply. Avoid using a reply's `ts` value; use its parent instead.
-    pub thread_ts: Option<Cow<'a, str>>,
+    pub thread_ts: Option<crate::Timestamp>,
     /// Pass true to enable unfurling of primarily text-based content.
     pub unfurl_links: Option<bool>,
     /// Pass false to disable unfurling of media content.
@@ -854,7 +1453,7 @@ pub struct PostMessageRequest<'a> {
     pub username: Option<Cow<'a, str>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct PostMessageAttachmentsInner {
     pub fallback: Option<String>,
     pub id: u64,
@@ -864,37 +1463,32 @@ pub struct PostMessageAttachmentsInner {
     pub image_width: Option<u64>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct PostMessageBlocksInner {
-    pub r#type: String,
-}
-
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct PostMessageIconsInner {
     pub image_36: String,
     pub image_48: String,
     pub image_72: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct PostMessageBotProfileInner {
-    pub app_id: String,
+    pub app_id: crate::AppId,
     pub deleted: bool,
     pub icons: PostMessageIconsInner,
-    pub id: String,
+    pub id: crate::BotId,
     pub name: String,
-    pub team_id: String,
+    pub team_id: crate::TeamId,
     pub updated: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct PostMessageReactionsInner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct PostMessageCommentInner {
     pub comment: String,
     pub created: u64,
@@ -906,23 +1500,23 @@ pub struct PostMessageCommentInner {
     pub pinned_to: Option<Vec<String>>,
     pub reactions: Option<Vec<PostMessageReactionsInner>>,
     pub timestamp: u64,
-    pub user: String,
+    pub user: crate::UserId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct PostMessageReactions1Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct PostMessageSharesInner {
     pub private: Option<serde_json::Value>,
     pub public: Option<serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct PostMessageFileInner {
     pub channels: Option<Vec<String>>,
     pub comments_count: Option<u64>,
@@ -937,7 +1531,7 @@ pub struct PostMessageFileInner {
     pub filetype: Option<String>,
     pub groups: Option<Vec<String>>,
     pub has_rich_preview: Option<bool>,
-    pub id: Option<String>,
+    pub id: Option<crate::FileId>,
     pub image_exif_rotation: Option<u64>,
     pub ims: Option<Vec<String>>,
     pub is_external: Option<bool>,
@@ -991,25 +1585,25 @@ pub struct PostMessageFileInner {
     pub updated: Option<u64>,
     pub url_private: Option<String>,
     pub url_private_download: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct PostMessageReactions2Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct PostMessageShares1Inner {
     pub private: Option<serde_json::Value>,
     pub public: Option<serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct PostMessageFilesInner {
     pub channels: Option<Vec<String>>,
     pub comments_count: Option<u64>,
@@ -1024,7 +1618,7 @@ pub struct PostMessageFilesInner {
     pub filetype: Option<String>,
     pub groups: Option<Vec<String>>,
     pub has_rich_preview: Option<bool>,
-    pub id: Option<String>,
+    pub id: Option<crate::FileId>,
     pub image_exif_rotation: Option<u64>,
     pub ims: Option<Vec<String>>,
     pub is_external: Option<bool>,
@@ -1078,25 +1672,25 @@ pub struct PostMessageFilesInner {
     pub updated: Option<u64>,
     pub url_private: Option<String>,
     pub url_private_download: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct PostMessageIcons1Inner {
     pub emoji: Option<String>,
     pub image_64: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct PostMessageReactions3Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct PostMessageUserProfileInner {
     pub avatar_hash: String,
     pub display_name: String,
@@ -1108,14 +1702,14 @@ pub struct PostMessageUserProfileInner {
     pub name: String,
     pub real_name: String,
     pub real_name_normalized: Option<String>,
-    pub team: String,
+    pub team: crate::TeamId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct PostMessageMessageInner {
     pub attachments: Option<Vec<PostMessageAttachmentsInner>>,
-    pub blocks: Option<Vec<PostMessageBlocksInner>>,
-    pub bot_id: Option<String>,
+    pub blocks: Option<Vec<crate::blocks::Block>>,
+    pub bot_id: Option<crate::BotId>,
     pub bot_profile: Option<PostMessageBotProfileInner>,
     pub client_msg_id: Option<String>,
     pub comment: Option<PostMessageCommentInner>,
@@ -1127,8 +1721,8 @@ pub struct PostMessageMessageInner {
     pub is_delayed_message: Option<bool>,
     pub is_intro: Option<bool>,
     pub is_starred: Option<bool>,
-    pub last_read: Option<String>,
-    pub latest_reply: Option<String>,
+    pub last_read: Option<crate::Timestamp>,
+    pub latest_reply: Option<crate::Timestamp>,
     pub name: Option<String>,
     pub old_name: Option<String>,
     pub parent_user_id: Option<String>,
@@ -1142,29 +1736,35 @@ pub struct PostMessageMessageInner {
     pub source_team: Option<String>,
     pub subscribed: Option<bool>,
     pub subtype: Option<String>,
-    pub team: Option<String>,
+    pub team: Option<crate::TeamId>,
     pub text: String,
-    pub thread_ts: Option<String>,
+    pub thread_ts: Option<crate::Timestamp>,
     pub topic: Option<String>,
-    pub ts: String,
+    pub ts: crate::Timestamp,
     pub r#type: String,
     pub unread_count: Option<u64>,
     pub upload: Option<bool>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_profile: Option<PostMessageUserProfileInner>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct PostMessageResponse {
     pub callstack: Option<String>,
-    pub channel: String,
+    pub channel: crate::ChannelId,
     error: Option<String>,
     pub message: PostMessageMessageInner,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
-    pub ts: String,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub ts: crate::Timestamp,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<PostMessageResponse, PostMessageError<E>>> for PostMessageResponse {
@@ -1172,96 +1772,135 @@ impl<E: Error> Into<Result<PostMessageResponse, PostMessageError<E>>> for PostMe
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(PostMessageError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for PostMessageResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum PostMessageError<E: Error> {
-    AccountInactive,
-    ChannelNotFound,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidPostType,
-    IsArchived,
-    MissingPostType,
-    MsgTooLong,
-    NoText,
-    NotAuthed,
-    NotInChannel,
-    RateLimited,
-    TooManyAttachments,
+    AccountInactive(crate::ErrorContext),
+    ChannelNotFound(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    IsArchived(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    MsgTooLong(crate::ErrorContext),
+    NoText(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    NotInChannel(crate::ErrorContext),
+    RateLimited(crate::ErrorContext),
+    TooManyAttachments(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> PostMessageError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => PostMessageError::AccountInactive(context),
+            "channel_not_found" => PostMessageError::ChannelNotFound(context),
+            "invalid_arg_name" => PostMessageError::InvalidArgName(context),
+            "invalid_array_arg" => PostMessageError::InvalidArrayArg(context),
+            "invalid_auth" => PostMessageError::InvalidAuth(context),
+            "invalid_charset" => PostMessageError::InvalidCharset(context),
+            "invalid_form_data" => PostMessageError::InvalidFormData(context),
+            "invalid_post_type" => PostMessageError::InvalidPostType(context),
+            "is_archived" => PostMessageError::IsArchived(context),
+            "missing_post_type" => PostMessageError::MissingPostType(context),
+            "msg_too_long" => PostMessageError::MsgTooLong(context),
+            "no_text" => PostMessageError::NoText(context),
+            "not_authed" => PostMessageError::NotAuthed(context),
+            "not_in_channel" => PostMessageError::NotInChannel(context),
+            "rate_limited" => PostMessageError::RateLimited(context),
+            "too_many_attachments" => PostMessageError::TooManyAttachments(context),
+            _ => PostMessageError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for PostMessageError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => PostMessageError::AccountInactive,
-            "channel_not_found" => PostMessageError::ChannelNotFound,
-            "invalid_arg_name" => PostMessageError::InvalidArgName,
-            "invalid_array_arg" => PostMessageError::InvalidArrayArg,
-            "invalid_auth" => PostMessageError::InvalidAuth,
-            "invalid_charset" => PostMessageError::InvalidCharset,
-            "invalid_form_data" => PostMessageError::InvalidFormData,
-            "invalid_post_type" => PostMessageError::InvalidPostType,
-            "is_archived" => PostMessageError::IsArchived,
-            "missing_post_type" => PostMessageError::MissingPostType,
-            "msg_too_long" => PostMessageError::MsgTooLong,
-            "no_text" => PostMessageError::NoText,
-            "not_authed" => PostMessageError::NotAuthed,
-            "not_in_channel" => PostMessageError::NotInChannel,
-            "rate_limited" => PostMessageError::RateLimited,
-            "too_many_attachments" => PostMessageError::TooManyAttachments,
-            _ => PostMessageError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for PostMessageError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            PostMessageError::AccountInactive => {
+            PostMessageError::AccountInactive(_) => {
                 write!(f, "Server returned error account_inactive")
             }
-            PostMessageError::ChannelNotFound => {
+            PostMessageError::ChannelNotFound(_) => {
                 write!(f, "Server returned error channel_not_found")
             }
-            PostMessageError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            PostMessageError::InvalidArrayArg => {
+            PostMessageError::InvalidArgName(_) => {
+                write!(f, "Server returned error invalid_arg_name")
+            }
+            PostMessageError::InvalidArrayArg(_) => {
                 write!(f, "Server returned error invalid_array_arg")
             }
-            PostMessageError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            PostMessageError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            PostMessageError::InvalidFormData => {
+            PostMessageError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            PostMessageError::InvalidCharset(_) => {
+                write!(f, "Server returned error invalid_charset")
+            }
+            PostMessageError::InvalidFormData(_) => {
                 write!(f, "Server returned error invalid_form_data")
             }
-            PostMessageError::InvalidPostType => {
+            PostMessageError::InvalidPostType(_) => {
                 write!(f, "Server returned error invalid_post_type")
             }
-            PostMessageError::IsArchived => write!(f, "Server returned error is_archived"),
-            PostMessageError::MissingPostType => {
+            PostMessageError::IsArchived(_) => write!(f, "Server returned error is_archived"),
+            PostMessageError::MissingPostType(_) => {
                 write!(f, "Server returned error missing_post_type")
             }
-            PostMessageError::MsgTooLong => write!(f, "Server returned error msg_too_long"),
-            PostMessageError::NoText => write!(f, "Server returned error no_text"),
-            PostMessageError::NotAuthed => write!(f, "Server returned error not_authed"),
-            PostMessageError::NotInChannel => write!(f, "Server returned error not_in_channel"),
-            PostMessageError::RateLimited => write!(f, "Server returned error rate_limited"),
-            PostMessageError::TooManyAttachments => {
+            PostMessageError::MsgTooLong(_) => write!(f, "Server returned error msg_too_long"),
+            PostMessageError::NoText(_) => write!(f, "Server returned error no_text"),
+            PostMessageError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            PostMessageError::NotInChannel(_) => write!(f, "Server returned error not_in_channel"),
+            PostMessageError::RateLimited(_) => write!(f, "Server returned error rate_limited"),
+            PostMessageError::TooManyAttachments(_) => {
                 write!(f, "Server returned error too_many_attachments")
             }
             PostMessageError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            PostMessageError::Unknown(ref s) => write!(f, "{}", s),
+            PostMessageError::Unknown(ref s, _) => write!(f, "{}", s),
             PostMessageError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -1277,16 +1916,74 @@ impl<E: Error + 'static> Error for PostMessageError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for PostMessageError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            PostMessageError::AccountInactive(_) => Some("account_inactive"),
+            PostMessageError::ChannelNotFound(_) => Some("channel_not_found"),
+            PostMessageError::InvalidArgName(_) => Some("invalid_arg_name"),
+            PostMessageError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            PostMessageError::InvalidAuth(_) => Some("invalid_auth"),
+            PostMessageError::InvalidCharset(_) => Some("invalid_charset"),
+            PostMessageError::InvalidFormData(_) => Some("invalid_form_data"),
+            PostMessageError::InvalidPostType(_) => Some("invalid_post_type"),
+            PostMessageError::IsArchived(_) => Some("is_archived"),
+            PostMessageError::MissingPostType(_) => Some("missing_post_type"),
+            PostMessageError::MsgTooLong(_) => Some("msg_too_long"),
+            PostMessageError::NoText(_) => Some("no_text"),
+            PostMessageError::NotAuthed(_) => Some("not_authed"),
+            PostMessageError::NotInChannel(_) => Some("not_in_channel"),
+            PostMessageError::RateLimited(_) => Some("rate_limited"),
+            PostMessageError::TooManyAttachments(_) => Some("too_many_attachments"),
+            PostMessageError::Unknown(ref s, _) => Some(s),
+            PostMessageError::MalformedResponse(..) | PostMessageError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            PostMessageError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            PostMessageError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            PostMessageError::AccountInactive(ref context)
+            | PostMessageError::ChannelNotFound(ref context)
+            | PostMessageError::InvalidArgName(ref context)
+            | PostMessageError::InvalidArrayArg(ref context)
+            | PostMessageError::InvalidAuth(ref context)
+            | PostMessageError::InvalidCharset(ref context)
+            | PostMessageError::InvalidFormData(ref context)
+            | PostMessageError::InvalidPostType(ref context)
+            | PostMessageError::IsArchived(ref context)
+            | PostMessageError::MissingPostType(ref context)
+            | PostMessageError::MsgTooLong(ref context)
+            | PostMessageError::NoText(ref context)
+            | PostMessageError::NotAuthed(ref context)
+            | PostMessageError::NotInChannel(ref context)
+            | PostMessageError::RateLimited(ref context)
+            | PostMessageError::TooManyAttachments(ref context)
+            | PostMessageError::Unknown(_, ref context) => Some(context),
+            PostMessageError::MalformedResponse(..) | PostMessageError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct ScheduleMessageRequest<'a> {
     /// Pass true to post the message as the authed user, instead of as a bot. Defaults to false. See [chat.postMessage](chat.postMessage#authorship).
     pub as_user: Option<bool>,
-    /// A JSON-based array of structured attachments, presented as a URL-encoded string.
-    pub attachments: Option<Cow<'a, str>>,
-    /// A JSON-based array of structured blocks, presented as a URL-encoded string.
-    pub blocks: Option<Cow<'a, str>>,
-    /// Channel, private group, or DM channel to send message to. Can be an encoded ID, or a name. See [below](#channels) for more details.
-    pub channel: Option<Cow<'a, str>>,
+    /// A JSON-based array of structured attachments.
+    pub attachments: Option<serde_json::Value>,
+    /// A JSON-based array of structured blocks.
+    pub blocks: Option<Vec<crate::blocks::Block>>,
+    /// Channel, private group, or DM channel to send message to. Can be an encoded ID, or a name. See [below](#channels) for more details. Pass a name with `crate::ChannelId::new_unchecked`.
+    pub channel: Option<crate::ChannelId>,
     /// Find and link channel names and usernames.
     pub link_names: Option<bool>,
     /// Change how messages are treated. Defaults to `none`. See [chat.postMessage](chat.postMessage#formatting).
@@ -1298,52 +1995,58 @@ pub struct ScheduleMessageRequest<'a> {
     /// How this field works and whether it is required depends on other fields you use in your API call. [See below](#text_usage) for more detail.
     pub text: Option<Cow<'a, str>>,
     /// Provide another message's `ts` value to make this message a reply. Avoid using a reply's `ts` value; use its parent instead.
-    pub thread_ts: Option<f64>,
+    pub thread_ts: Option<crate::Timestamp>,
     /// Pass true to enable unfurling of primarily text-based content.
     pub unfurl_links: Option<bool>,
     /// Pass false to disable unfurling of media content.
     pub unfurl_media: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ScheduleMessageIconsInner {
     pub image_36: String,
     pub image_48: String,
     pub image_72: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ScheduleMessageBotProfileInner {
-    pub app_id: String,
+    pub app_id: crate::AppId,
     pub deleted: bool,
     pub icons: ScheduleMessageIconsInner,
-    pub id: String,
+    pub id: crate::BotId,
     pub name: String,
-    pub team_id: String,
+    pub team_id: crate::TeamId,
     pub updated: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ScheduleMessageMessageInner {
-    pub bot_id: String,
+    pub bot_id: crate::BotId,
     pub bot_profile: Option<ScheduleMessageBotProfileInner>,
-    pub team: String,
+    pub team: crate::TeamId,
     pub text: String,
     pub r#type: String,
-    pub user: String,
+    pub user: crate::UserId,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ScheduleMessageResponse {
     pub callstack: Option<String>,
-    pub channel: String,
+    pub channel: crate::ChannelId,
     error: Option<String>,
     pub message: ScheduleMessageMessageInner,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
     pub post_at: u64,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
     pub scheduled_message_id: String,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<ScheduleMessageResponse, ScheduleMessageError<E>>>
@@ -1353,183 +2056,226 @@ impl<E: Error> Into<Result<ScheduleMessageResponse, ScheduleMessageError<E>>>
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(ScheduleMessageError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for ScheduleMessageResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum ScheduleMessageError<E: Error> {
-    AccountInactive,
-    ChannelNotFound,
-    EkmAccessDenied,
-    FatalError,
-    InvalidArgName,
-    InvalidArguments,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    InvalidTime,
-    IsArchived,
-    JsonNotObject,
-    MissingPostType,
-    MissingScope,
-    MsgTooLong,
-    NoPermission,
-    NoText,
-    NotAuthed,
-    NotInChannel,
-    OrgLoginRequired,
-    RateLimited,
-    RequestTimeout,
-    RestrictedAction,
-    RestrictedActionNonThreadableChannel,
-    RestrictedActionReadOnlyChannel,
-    RestrictedActionThreadOnlyChannel,
-    TeamAddedToOrg,
-    TimeInPast,
-    TimeTooFar,
-    TokenRevoked,
-    TooManyAttachments,
-    UpgradeRequired,
+    AccountInactive(crate::ErrorContext),
+    ChannelNotFound(crate::ErrorContext),
+    EkmAccessDenied(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArguments(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    InvalidTime(crate::ErrorContext),
+    IsArchived(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    MissingScope(crate::ErrorContext),
+    MsgTooLong(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NoText(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    NotInChannel(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    RateLimited(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    RestrictedAction(crate::ErrorContext),
+    RestrictedActionNonThreadableChannel(crate::ErrorContext),
+    RestrictedActionReadOnlyChannel(crate::ErrorContext),
+    RestrictedActionThreadOnlyChannel(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TimeInPast(crate::ErrorContext),
+    TimeTooFar(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    TooManyAttachments(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
-impl<'a, E: Error> From<&'a str> for ScheduleMessageError<E> {
-    fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => ScheduleMessageError::AccountInactive,
-            "channel_not_found" => ScheduleMessageError::ChannelNotFound,
-            "ekm_access_denied" => ScheduleMessageError::EkmAccessDenied,
-            "fatal_error" => ScheduleMessageError::FatalError,
-            "invalid_arg_name" => ScheduleMessageError::InvalidArgName,
-            "invalid_arguments" => ScheduleMessageError::InvalidArguments,
-            "invalid_auth" => ScheduleMessageError::InvalidAuth,
-            "invalid_charset" => ScheduleMessageError::InvalidCharset,
-            "invalid_form_data" => ScheduleMessageError::InvalidFormData,
-            "invalid_json" => ScheduleMessageError::InvalidJson,
-            "invalid_post_type" => ScheduleMessageError::InvalidPostType,
-            "invalid_time" => ScheduleMessageError::InvalidTime,
-            "is_archived" => ScheduleMessageError::IsArchived,
-            "json_not_object" => ScheduleMessageError::JsonNotObject,
-            "missing_post_type" => ScheduleMessageError::MissingPostType,
-            "missing_scope" => ScheduleMessageError::MissingScope,
-            "msg_too_long" => ScheduleMessageError::MsgTooLong,
-            "no_permission" => ScheduleMessageError::NoPermission,
-            "no_text" => ScheduleMessageError::NoText,
-            "not_authed" => ScheduleMessageError::NotAuthed,
-            "not_in_channel" => ScheduleMessageError::NotInChannel,
-            "org_login_required" => ScheduleMessageError::OrgLoginRequired,
-            "rate_limited" => ScheduleMessageError::RateLimited,
-            "request_timeout" => ScheduleMessageError::RequestTimeout,
-            "restricted_action" => ScheduleMessageError::RestrictedAction,
+impl<E: Error> ScheduleMessageError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => ScheduleMessageError::AccountInactive(context),
+            "channel_not_found" => ScheduleMessageError::ChannelNotFound(context),
+            "ekm_access_denied" => ScheduleMessageError::EkmAccessDenied(context),
+            "fatal_error" => ScheduleMessageError::FatalError(context),
+            "invalid_arg_name" => ScheduleMessageError::InvalidArgName(context),
+            "invalid_arguments" => ScheduleMessageError::InvalidArguments(context),
+            "invalid_auth" => ScheduleMessageError::InvalidAuth(context),
+            "invalid_charset" => ScheduleMessageError::InvalidCharset(context),
+            "invalid_form_data" => ScheduleMessageError::InvalidFormData(context),
+            "invalid_json" => ScheduleMessageError::InvalidJson(context),
+            "invalid_post_type" => ScheduleMessageError::InvalidPostType(context),
+            "invalid_time" => ScheduleMessageError::InvalidTime(context),
+            "is_archived" => ScheduleMessageError::IsArchived(context),
+            "json_not_object" => ScheduleMessageError::JsonNotObject(context),
+            "missing_post_type" => ScheduleMessageError::MissingPostType(context),
+            "missing_scope" => ScheduleMessageError::MissingScope(context),
+            "msg_too_long" => ScheduleMessageError::MsgTooLong(context),
+            "no_permission" => ScheduleMessageError::NoPermission(context),
+            "no_text" => ScheduleMessageError::NoText(context),
+            "not_authed" => ScheduleMessageError::NotAuthed(context),
+            "not_in_channel" => ScheduleMessageError::NotInChannel(context),
+            "org_login_required" => ScheduleMessageError::OrgLoginRequired(context),
+            "rate_limited" => ScheduleMessageError::RateLimited(context),
+            "request_timeout" => ScheduleMessageError::RequestTimeout(context),
+            "restricted_action" => ScheduleMessageError::RestrictedAction(context),
             "restricted_action_non_threadable_channel" => {
-                ScheduleMessageError::RestrictedActionNonThreadableChannel
+                ScheduleMessageError::RestrictedActionNonThreadableChannel(context)
             }
             "restricted_action_read_only_channel" => {
-                ScheduleMessageError::RestrictedActionReadOnlyChannel
+                ScheduleMessageError::RestrictedActionReadOnlyChannel(context)
             }
             "restricted_action_thread_only_channel" => {
-                ScheduleMessageError::RestrictedActionThreadOnlyChannel
-            }
-            "team_added_to_org" => ScheduleMessageError::TeamAddedToOrg,
-            "time_in_past" => ScheduleMessageError::TimeInPast,
-            "time_too_far" => ScheduleMessageError::TimeTooFar,
-            "token_revoked" => ScheduleMessageError::TokenRevoked,
-            "too_many_attachments" => ScheduleMessageError::TooManyAttachments,
-            "upgrade_required" => ScheduleMessageError::UpgradeRequired,
-            _ => ScheduleMessageError::Unknown(s.to_owned()),
+                ScheduleMessageError::RestrictedActionThreadOnlyChannel(context)
+            }
+            "team_added_to_org" => ScheduleMessageError::TeamAddedToOrg(context),
+            "time_in_past" => ScheduleMessageError::TimeInPast(context),
+            "time_too_far" => ScheduleMessageError::TimeTooFar(context),
+            "token_revoked" => ScheduleMessageError::TokenRevoked(context),
+            "too_many_attachments" => ScheduleMessageError::TooManyAttachments(context),
+            "upgrade_required" => ScheduleMessageError::UpgradeRequired(context),
+            _ => ScheduleMessageError::Unknown(code.to_owned(), context),
         }
     }
 }
 
+impl<'a, E: Error> From<&'a str> for ScheduleMessageError<E> {
+    fn from(s: &'a str) -> Self {
+        Self::new(s, crate::ErrorContext::default())
+    }
+}
+
 impl<E: Error> fmt::Display for ScheduleMessageError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            ScheduleMessageError::AccountInactive => {
+            ScheduleMessageError::AccountInactive(_) => {
                 write!(f, "Server returned error account_inactive")
             }
-            ScheduleMessageError::ChannelNotFound => {
+            ScheduleMessageError::ChannelNotFound(_) => {
                 write!(f, "Server returned error channel_not_found")
             }
-            ScheduleMessageError::EkmAccessDenied => {
+            ScheduleMessageError::EkmAccessDenied(_) => {
                 write!(f, "Server returned error ekm_access_denied")
             }
-            ScheduleMessageError::FatalError => write!(f, "Server returned error fatal_error"),
-            ScheduleMessageError::InvalidArgName => {
+            ScheduleMessageError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            ScheduleMessageError::InvalidArgName(_) => {
                 write!(f, "Server returned error invalid_arg_name")
             }
-            ScheduleMessageError::InvalidArguments => {
+            ScheduleMessageError::InvalidArguments(_) => {
                 write!(f, "Server returned error invalid_arguments")
             }
-            ScheduleMessageError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            ScheduleMessageError::InvalidCharset => {
+            ScheduleMessageError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            ScheduleMessageError::InvalidCharset(_) => {
                 write!(f, "Server returned error invalid_charset")
             }
-            ScheduleMessageError::InvalidFormData => {
+            ScheduleMessageError::InvalidFormData(_) => {
                 write!(f, "Server returned error invalid_form_data")
             }
-            ScheduleMessageError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            ScheduleMessageError::InvalidPostType => {
+            ScheduleMessageError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            ScheduleMessageError::InvalidPostType(_) => {
                 write!(f, "Server returned error invalid_post_type")
             }
-            ScheduleMessageError::InvalidTime => write!(f, "Server returned error invalid_time"),
-            ScheduleMessageError::IsArchived => write!(f, "Server returned error is_archived"),
-            ScheduleMessageError::JsonNotObject => {
+            ScheduleMessageError::InvalidTime(_) => write!(f, "Server returned error invalid_time"),
+            ScheduleMessageError::IsArchived(_) => write!(f, "Server returned error is_archived"),
+            ScheduleMessageError::JsonNotObject(_) => {
                 write!(f, "Server returned error json_not_object")
             }
-            ScheduleMessageError::MissingPostType => {
+            ScheduleMessageError::MissingPostType(_) => {
                 write!(f, "Server returned error missing_post_type")
             }
-            ScheduleMessageError::MissingScope => write!(f, "Server returned error missing_scope"),
-            ScheduleMessageError::MsgTooLong => write!(f, "Server returned error msg_too_long"),
-            ScheduleMessageError::NoPermission => write!(f, "Server returned error no_permission"),
-            ScheduleMessageError::NoText => write!(f, "Server returned error no_text"),
-            ScheduleMessageError::NotAuthed => write!(f, "Server returned error not_authed"),
-            ScheduleMessageError::NotInChannel => write!(f, "Server returned error not_in_channel"),
-            ScheduleMessageError::OrgLoginRequired => {
+            ScheduleMessageError::MissingScope(_) => {
+                write!(f, "Server returned error missing_scope")
+            }
+            ScheduleMessageError::MsgTooLong(_) => write!(f, "Server returned error msg_too_long"),
+            ScheduleMessageError::NoPermission(_) => {
+                write!(f, "Server returned error no_permission")
+            }
+            ScheduleMessageError::NoText(_) => write!(f, "Server returned error no_text"),
+            ScheduleMessageError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            ScheduleMessageError::NotInChannel(_) => {
+                write!(f, "Server returned error not_in_channel")
+            }
+            ScheduleMessageError::OrgLoginRequired(_) => {
                 write!(f, "Server returned error org_login_required")
             }
-            ScheduleMessageError::RateLimited => write!(f, "Server returned error rate_limited"),
-            ScheduleMessageError::RequestTimeout => {
+            ScheduleMessageError::RateLimited(_) => write!(f, "Server returned error rate_limited"),
+            ScheduleMessageError::RequestTimeout(_) => {
                 write!(f, "Server returned error request_timeout")
             }
-            ScheduleMessageError::RestrictedAction => {
+            ScheduleMessageError::RestrictedAction(_) => {
                 write!(f, "Server returned error restricted_action")
             }
-            ScheduleMessageError::RestrictedActionNonThreadableChannel => write!(
+            ScheduleMessageError::RestrictedActionNonThreadableChannel(_) => write!(
                 f,
                 "Server returned error restricted_action_non_threadable_channel"
             ),
-            ScheduleMessageError::RestrictedActionReadOnlyChannel => write!(
+            ScheduleMessageError::RestrictedActionReadOnlyChannel(_) => write!(
                 f,
                 "Server returned error restricted_action_read_only_channel"
             ),
-            ScheduleMessageError::RestrictedActionThreadOnlyChannel => write!(
+            ScheduleMessageError::RestrictedActionThreadOnlyChannel(_) => write!(
                 f,
                 "Server returned error restricted_action_thread_only_channel"
             ),
-            ScheduleMessageError::TeamAddedToOrg => {
+            ScheduleMessageError::TeamAddedToOrg(_) => {
                 write!(f, "Server returned error team_added_to_org")
             }
-            ScheduleMessageError::TimeInPast => write!(f, "Server returned error time_in_past"),
-            ScheduleMessageError::TimeTooFar => write!(f, "Server returned error time_too_far"),
-            ScheduleMessageError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            ScheduleMessageError::TooManyAttachments => {
+            ScheduleMessageError::TimeInPast(_) => write!(f, "Server returned error time_in_past"),
+            ScheduleMessageError::TimeTooFar(_) => write!(f, "Server returned error time_too_far"),
+            ScheduleMessageError::TokenRevoked(_) => {
+                write!(f, "Server returned error token_revoked")
+            }
+            ScheduleMessageError::TooManyAttachments(_) => {
                 write!(f, "Server returned error too_many_attachments")
             }
-            ScheduleMessageError::UpgradeRequired => {
+            ScheduleMessageError::UpgradeRequired(_) => {
                 write!(f, "Server returned error upgrade_required")
             }
             ScheduleMessageError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            ScheduleMessageError::Unknown(ref s) => write!(f, "{}", s),
+            ScheduleMessageError::Unknown(ref s, _) => write!(f, "{}", s),
             ScheduleMessageError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -1545,14 +2291,114 @@ impl<E: Error + 'static> Error for ScheduleMessageError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for ScheduleMessageError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            ScheduleMessageError::AccountInactive(_) => Some("account_inactive"),
+            ScheduleMessageError::ChannelNotFound(_) => Some("channel_not_found"),
+            ScheduleMessageError::EkmAccessDenied(_) => Some("ekm_access_denied"),
+            ScheduleMessageError::FatalError(_) => Some("fatal_error"),
+            ScheduleMessageError::InvalidArgName(_) => Some("invalid_arg_name"),
+            ScheduleMessageError::InvalidArguments(_) => Some("invalid_arguments"),
+            ScheduleMessageError::InvalidAuth(_) => Some("invalid_auth"),
+            ScheduleMessageError::InvalidCharset(_) => Some("invalid_charset"),
+            ScheduleMessageError::InvalidFormData(_) => Some("invalid_form_data"),
+            ScheduleMessageError::InvalidJson(_) => Some("invalid_json"),
+            ScheduleMessageError::InvalidPostType(_) => Some("invalid_post_type"),
+            ScheduleMessageError::InvalidTime(_) => Some("invalid_time"),
+            ScheduleMessageError::IsArchived(_) => Some("is_archived"),
+            ScheduleMessageError::JsonNotObject(_) => Some("json_not_object"),
+            ScheduleMessageError::MissingPostType(_) => Some("missing_post_type"),
+            ScheduleMessageError::MissingScope(_) => Some("missing_scope"),
+            ScheduleMessageError::MsgTooLong(_) => Some("msg_too_long"),
+            ScheduleMessageError::NoPermission(_) => Some("no_permission"),
+            ScheduleMessageError::NoText(_) => Some("no_text"),
+            ScheduleMessageError::NotAuthed(_) => Some("not_authed"),
+            ScheduleMessageError::NotInChannel(_) => Some("not_in_channel"),
+            ScheduleMessageError::OrgLoginRequired(_) => Some("org_login_required"),
+            ScheduleMessageError::RateLimited(_) => Some("rate_limited"),
+            ScheduleMessageError::RequestTimeout(_) => Some("request_timeout"),
+            ScheduleMessageError::RestrictedAction(_) => Some("restricted_action"),
+            ScheduleMessageError::RestrictedActionNonThreadableChannel(_) => {
+                Some("restricted_action_non_threadable_channel")
+            }
+            ScheduleMessageError::RestrictedActionReadOnlyChannel(_) => {
+                Some("restricted_action_read_only_channel")
+            }
+            ScheduleMessageError::RestrictedActionThreadOnlyChannel(_) => {
+                Some("restricted_action_thread_only_channel")
+            }
+            ScheduleMessageError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            ScheduleMessageError::TimeInPast(_) => Some("time_in_past"),
+            ScheduleMessageError::TimeTooFar(_) => Some("time_too_far"),
+            ScheduleMessageError::TokenRevoked(_) => Some("token_revoked"),
+            ScheduleMessageError::TooManyAttachments(_) => Some("too_many_attachments"),
+            ScheduleMessageError::UpgradeRequired(_) => Some("upgrade_required"),
+            ScheduleMessageError::Unknown(ref s, _) => Some(s),
+            ScheduleMessageError::MalformedResponse(..) | ScheduleMessageError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            ScheduleMessageError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            ScheduleMessageError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            ScheduleMessageError::AccountInactive(ref context)
+            | ScheduleMessageError::ChannelNotFound(ref context)
+            | ScheduleMessageError::EkmAccessDenied(ref context)
+            | ScheduleMessageError::FatalError(ref context)
+            | ScheduleMessageError::InvalidArgName(ref context)
+            | ScheduleMessageError::InvalidArguments(ref context)
+            | ScheduleMessageError::InvalidAuth(ref context)
+            | ScheduleMessageError::InvalidCharset(ref context)
+            | ScheduleMessageError::InvalidFormData(ref context)
+            | ScheduleMessageError::InvalidJson(ref context)
+            | ScheduleMessageError::InvalidPostType(ref context)
+            | ScheduleMessageError::InvalidTime(ref context)
+            | ScheduleMessageError::IsArchived(ref context)
+            | ScheduleMessageError::JsonNotObject(ref context)
+            | ScheduleMessageError::MissingPostType(ref context)
+            | ScheduleMessageError::MissingScope(ref context)
+            | ScheduleMessageError::MsgTooLong(ref context)
+            | ScheduleMessageError::NoPermission(ref context)
+            | ScheduleMessageError::NoText(ref context)
+            | ScheduleMessageError::NotAuthed(ref context)
+            | ScheduleMessageError::NotInChannel(ref context)
+            | ScheduleMessageError::OrgLoginRequired(ref context)
+            | ScheduleMessageError::RateLimited(ref context)
+            | ScheduleMessageError::RequestTimeout(ref context)
+            | ScheduleMessageError::RestrictedAction(ref context)
+            | ScheduleMessageError::RestrictedActionNonThreadableChannel(ref context)
+            | ScheduleMessageError::RestrictedActionReadOnlyChannel(ref context)
+            | ScheduleMessageError::RestrictedActionThreadOnlyChannel(ref context)
+            | ScheduleMessageError::TeamAddedToOrg(ref context)
+            | ScheduleMessageError::TimeInPast(ref context)
+            | ScheduleMessageError::TimeTooFar(ref context)
+            | ScheduleMessageError::TokenRevoked(ref context)
+            | ScheduleMessageError::TooManyAttachments(ref context)
+            | ScheduleMessageError::UpgradeRequired(ref context)
+            | ScheduleMessageError::Unknown(_, ref context) => Some(context),
+            ScheduleMessageError::MalformedResponse(..) | ScheduleMessageError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct UnfurlRequest<'a> {
     /// Channel ID of the message
-    pub channel: Cow<'a, str>,
+    pub channel: Option<crate::ChannelId>,
     /// Timestamp of the message to add unfurl behavior to.
-    pub ts: Cow<'a, str>,
-    /// URL-encoded JSON map with keys set to URLs featured in the the message, pointing to their unfurl blocks or message attachments.
-    pub unfurls: Cow<'a, str>,
+    pub ts: crate::Timestamp,
+    /// JSON map with keys set to URLs featured in the the message, pointing to their unfurl blocks or message attachments.
+    pub unfurls: serde_json::Value,
     /// Provide a simply-formatted string to send as an ephemeral message to the user as invitation to authenticate further and enable full unfurling behavior
     pub user_auth_message: Option<Cow<'a, str>>,
     /// Set to `true` or `1` to indicate the user must install your Slack app to trigger unfurls for this domain
@@ -1561,12 +2407,18 @@ pub struct UnfurlRequest<'a> {
     pub user_auth_url: Option<Cow<'a, str>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UnfurlResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<UnfurlResponse, UnfurlError<E>>> for UnfurlResponse {
@@ -1574,105 +2426,142 @@ impl<E: Error> Into<Result<UnfurlResponse, UnfurlError<E>>> for UnfurlResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(UnfurlError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for UnfurlResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum UnfurlError<E: Error> {
-    AccountInactive,
-    CannotFindService,
-    CannotPrompt,
-    CannotUnfurlUrl,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    MissingUnfurls,
-    NoPermission,
-    NotAuthed,
-    OrgLoginRequired,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequired,
-    UserIsBot,
+    AccountInactive(crate::ErrorContext),
+    CannotFindService(crate::ErrorContext),
+    CannotPrompt(crate::ErrorContext),
+    CannotUnfurlUrl(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    MissingUnfurls(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserIsBot(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> UnfurlError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => UnfurlError::AccountInactive(context),
+            "cannot_find_service" => UnfurlError::CannotFindService(context),
+            "cannot_prompt" => UnfurlError::CannotPrompt(context),
+            "cannot_unfurl_url" => UnfurlError::CannotUnfurlUrl(context),
+            "fatal_error" => UnfurlError::FatalError(context),
+            "invalid_arg_name" => UnfurlError::InvalidArgName(context),
+            "invalid_array_arg" => UnfurlError::InvalidArrayArg(context),
+            "invalid_auth" => UnfurlError::InvalidAuth(context),
+            "invalid_charset" => UnfurlError::InvalidCharset(context),
+            "invalid_form_data" => UnfurlError::InvalidFormData(context),
+            "invalid_json" => UnfurlError::InvalidJson(context),
+            "invalid_post_type" => UnfurlError::InvalidPostType(context),
+            "json_not_object" => UnfurlError::JsonNotObject(context),
+            "missing_post_type" => UnfurlError::MissingPostType(context),
+            "missing_unfurls" => UnfurlError::MissingUnfurls(context),
+            "no_permission" => UnfurlError::NoPermission(context),
+            "not_authed" => UnfurlError::NotAuthed(context),
+            "org_login_required" => UnfurlError::OrgLoginRequired(context),
+            "request_timeout" => UnfurlError::RequestTimeout(context),
+            "team_added_to_org" => UnfurlError::TeamAddedToOrg(context),
+            "token_revoked" => UnfurlError::TokenRevoked(context),
+            "upgrade_required" => UnfurlError::UpgradeRequired(context),
+            "user_is_bot" => UnfurlError::UserIsBot(context),
+            _ => UnfurlError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for UnfurlError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => UnfurlError::AccountInactive,
-            "cannot_find_service" => UnfurlError::CannotFindService,
-            "cannot_prompt" => UnfurlError::CannotPrompt,
-            "cannot_unfurl_url" => UnfurlError::CannotUnfurlUrl,
-            "fatal_error" => UnfurlError::FatalError,
-            "invalid_arg_name" => UnfurlError::InvalidArgName,
-            "invalid_array_arg" => UnfurlError::InvalidArrayArg,
-            "invalid_auth" => UnfurlError::InvalidAuth,
-            "invalid_charset" => UnfurlError::InvalidCharset,
-            "invalid_form_data" => UnfurlError::InvalidFormData,
-            "invalid_json" => UnfurlError::InvalidJson,
-            "invalid_post_type" => UnfurlError::InvalidPostType,
-            "json_not_object" => UnfurlError::JsonNotObject,
-            "missing_post_type" => UnfurlError::MissingPostType,
-            "missing_unfurls" => UnfurlError::MissingUnfurls,
-            "no_permission" => UnfurlError::NoPermission,
-            "not_authed" => UnfurlError::NotAuthed,
-            "org_login_required" => UnfurlError::OrgLoginRequired,
-            "request_timeout" => UnfurlError::RequestTimeout,
-            "team_added_to_org" => UnfurlError::TeamAddedToOrg,
-            "token_revoked" => UnfurlError::TokenRevoked,
-            "upgrade_required" => UnfurlError::UpgradeRequired,
-            "user_is_bot" => UnfurlError::UserIsBot,
-            _ => UnfurlError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for UnfurlError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            UnfurlError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            UnfurlError::CannotFindService => {
+            UnfurlError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            UnfurlError::CannotFindService(_) => {
                 write!(f, "Server returned error cannot_find_service")
             }
-            UnfurlError::CannotPrompt => write!(f, "Server returned error cannot_prompt"),
-            UnfurlError::CannotUnfurlUrl => write!(f, "Server returned error cannot_unfurl_url"),
-            UnfurlError::FatalError => write!(f, "Server returned error fatal_error"),
-            UnfurlError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            UnfurlError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            UnfurlError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            UnfurlError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            UnfurlError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            UnfurlError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            UnfurlError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            UnfurlError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            UnfurlError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            UnfurlError::MissingUnfurls => write!(f, "Server returned error missing_unfurls"),
-            UnfurlError::NoPermission => write!(f, "Server returned error no_permission"),
-            UnfurlError::NotAuthed => write!(f, "Server returned error not_authed"),
-            UnfurlError::OrgLoginRequired => write!(f, "Server returned error org_login_required"),
-            UnfurlError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            UnfurlError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            UnfurlError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            UnfurlError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
-            UnfurlError::UserIsBot => write!(f, "Server returned error user_is_bot"),
+            UnfurlError::CannotPrompt(_) => write!(f, "Server returned error cannot_prompt"),
+            UnfurlError::CannotUnfurlUrl(_) => write!(f, "Server returned error cannot_unfurl_url"),
+            UnfurlError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            UnfurlError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            UnfurlError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            UnfurlError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            UnfurlError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            UnfurlError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            UnfurlError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            UnfurlError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            UnfurlError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            UnfurlError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            UnfurlError::MissingUnfurls(_) => write!(f, "Server returned error missing_unfurls"),
+            UnfurlError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            UnfurlError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            UnfurlError::OrgLoginRequired(_) => {
+                write!(f, "Server returned error org_login_required")
+            }
+            UnfurlError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            UnfurlError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            UnfurlError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            UnfurlError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
+            UnfurlError::UserIsBot(_) => write!(f, "Server returned error user_is_bot"),
             UnfurlError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            UnfurlError::Unknown(ref s) => write!(f, "{}", s),
+            UnfurlError::Unknown(ref s, _) => write!(f, "{}", s),
             UnfurlError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -1688,16 +2577,88 @@ impl<E: Error + 'static> Error for UnfurlError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for UnfurlError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            UnfurlError::AccountInactive(_) => Some("account_inactive"),
+            UnfurlError::CannotFindService(_) => Some("cannot_find_service"),
+            UnfurlError::CannotPrompt(_) => Some("cannot_prompt"),
+            UnfurlError::CannotUnfurlUrl(_) => Some("cannot_unfurl_url"),
+            UnfurlError::FatalError(_) => Some("fatal_error"),
+            UnfurlError::InvalidArgName(_) => Some("invalid_arg_name"),
+            UnfurlError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            UnfurlError::InvalidAuth(_) => Some("invalid_auth"),
+            UnfurlError::InvalidCharset(_) => Some("invalid_charset"),
+            UnfurlError::InvalidFormData(_) => Some("invalid_form_data"),
+            UnfurlError::InvalidJson(_) => Some("invalid_json"),
+            UnfurlError::InvalidPostType(_) => Some("invalid_post_type"),
+            UnfurlError::JsonNotObject(_) => Some("json_not_object"),
+            UnfurlError::MissingPostType(_) => Some("missing_post_type"),
+            UnfurlError::MissingUnfurls(_) => Some("missing_unfurls"),
+            UnfurlError::NoPermission(_) => Some("no_permission"),
+            UnfurlError::NotAuthed(_) => Some("not_authed"),
+            UnfurlError::OrgLoginRequired(_) => Some("org_login_required"),
+            UnfurlError::RequestTimeout(_) => Some("request_timeout"),
+            UnfurlError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            UnfurlError::TokenRevoked(_) => Some("token_revoked"),
+            UnfurlError::UpgradeRequired(_) => Some("upgrade_required"),
+            UnfurlError::UserIsBot(_) => Some("user_is_bot"),
+            UnfurlError::Unknown(ref s, _) => Some(s),
+            UnfurlError::MalformedResponse(..) | UnfurlError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            UnfurlError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            UnfurlError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            UnfurlError::AccountInactive(ref context)
+            | UnfurlError::CannotFindService(ref context)
+            | UnfurlError::CannotPrompt(ref context)
+            | UnfurlError::CannotUnfurlUrl(ref context)
+            | UnfurlError::FatalError(ref context)
+            | UnfurlError::InvalidArgName(ref context)
+            | UnfurlError::InvalidArrayArg(ref context)
+            | UnfurlError::InvalidAuth(ref context)
+            | UnfurlError::InvalidCharset(ref context)
+            | UnfurlError::InvalidFormData(ref context)
+            | UnfurlError::InvalidJson(ref context)
+            | UnfurlError::InvalidPostType(ref context)
+            | UnfurlError::JsonNotObject(ref context)
+            | UnfurlError::MissingPostType(ref context)
+            | UnfurlError::MissingUnfurls(ref context)
+            | UnfurlError::NoPermission(ref context)
+            | UnfurlError::NotAuthed(ref context)
+            | UnfurlError::OrgLoginRequired(ref context)
+            | UnfurlError::RequestTimeout(ref context)
+            | UnfurlError::TeamAddedToOrg(ref context)
+            | UnfurlError::TokenRevoked(ref context)
+            | UnfurlError::UpgradeRequired(ref context)
+            | UnfurlError::UserIsBot(ref context)
+            | UnfurlError::Unknown(_, ref context) => Some(context),
+            UnfurlError::MalformedResponse(..) | UnfurlError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct UpdateRequest<'a> {
     /// Pass true to update the message as the authed user. [Bot users](/bot-users) in this context are considered authed users.
     pub as_user: Option<Cow<'a, str>>,
-    /// A JSON-based array of structured attachments, presented as a URL-encoded string. This field is required when not presenting `text`. If you don't include this field, the message's previous `attachments` will be retained. To remove previous `attachments`, include an empty array for this field.
-    pub attachments: Option<Cow<'a, str>>,
-    /// A JSON-based array of [structured blocks](/block-kit/building), presented as a URL-encoded string. If you don't include this field, the message's previous `blocks` will be retained. To remove previous `blocks`, include an empty array for this field.
-    pub blocks: Option<Cow<'a, str>>,
+    /// A JSON-based array of structured attachments. This field is required when not presenting `text`. If you don't include this field, the message's previous `attachments` will be retained. To remove previous `attachments`, include an empty array for this field.
+    pub attachments: Option<serde_json::Value>,
+    /// A JSON-based array of [structured blocks](/block-kit/building). If you don't include this field, the message's previous `blocks` will be retained. To remove previous `blocks`, include an empty array for this field.
+    pub blocks: Option<Vec<crate::blocks::Block>>,
     /// Channel containing the message to be updated.
-    pub channel: Cow<'a, str>,
+    pub channel: Option<crate::ChannelId>,
     /// Find and link channel names and usernames. Defaults to `none`. If you do not specify a value for this field, the original value set for the message will be overwritten with the default, `none`.
     pub link_names: Option<Cow<'a, str>>,
     /// Change how messages are treated. Defaults to `client`, unlike `chat.postMessage`. Accepts either `none` or `full`. If you do not specify a value for this field, the original value set for the message will be overwritten with the default, `client`.
@@ -1705,26 +2666,32 @@ pub struct UpdateRequest<'a> {
     /// New text for the message, using the [default formatting rules](/reference/surfaces/formatting). It's not required when presenting `blocks` or `attachments`.
     pub text: Option<Cow<'a, str>>,
     /// Timestamp of the message to be updated.
-    pub ts: Cow<'a, str>,
+    pub ts: crate::Timestamp,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UpdateMessageInner {
     pub attachments: Option<Vec<serde_json::Value>>,
-    pub blocks: Option<serde_json::Value>,
+    pub blocks: Option<Vec<crate::blocks::Block>>,
     pub text: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UpdateResponse {
     pub callstack: Option<String>,
-    pub channel: String,
+    pub channel: crate::ChannelId,
     error: Option<String>,
     pub message: UpdateMessageInner,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
     pub text: String,
-    pub ts: String,
+    pub ts: crate::Timestamp,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<UpdateResponse, UpdateError<E>>> for UpdateResponse {
@@ -1732,113 +2699,150 @@ impl<E: Error> Into<Result<UpdateResponse, UpdateError<E>>> for UpdateResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(UpdateError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for UpdateResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum UpdateError<E: Error> {
-    AccountInactive,
-    CantUpdateMessage,
-    ChannelNotFound,
-    EditWindowClosed,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    IsInactive,
-    JsonNotObject,
-    MessageNotFound,
-    MissingPostType,
-    MsgTooLong,
-    NoPermission,
-    NoText,
-    NotAuthed,
-    RateLimited,
-    RequestTimeout,
-    TokenRevoked,
-    TooManyAttachments,
-    UpgradeRequired,
+    AccountInactive(crate::ErrorContext),
+    CantUpdateMessage(crate::ErrorContext),
+    ChannelNotFound(crate::ErrorContext),
+    EditWindowClosed(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    IsInactive(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MessageNotFound(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    MsgTooLong(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NoText(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    RateLimited(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    TooManyAttachments(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> UpdateError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => UpdateError::AccountInactive(context),
+            "cant_update_message" => UpdateError::CantUpdateMessage(context),
+            "channel_not_found" => UpdateError::ChannelNotFound(context),
+            "edit_window_closed" => UpdateError::EditWindowClosed(context),
+            "fatal_error" => UpdateError::FatalError(context),
+            "invalid_arg_name" => UpdateError::InvalidArgName(context),
+            "invalid_array_arg" => UpdateError::InvalidArrayArg(context),
+            "invalid_auth" => UpdateError::InvalidAuth(context),
+            "invalid_charset" => UpdateError::InvalidCharset(context),
+            "invalid_form_data" => UpdateError::InvalidFormData(context),
+            "invalid_json" => UpdateError::InvalidJson(context),
+            "invalid_post_type" => UpdateError::InvalidPostType(context),
+            "is_inactive" => UpdateError::IsInactive(context),
+            "json_not_object" => UpdateError::JsonNotObject(context),
+            "message_not_found" => UpdateError::MessageNotFound(context),
+            "missing_post_type" => UpdateError::MissingPostType(context),
+            "msg_too_long" => UpdateError::MsgTooLong(context),
+            "no_permission" => UpdateError::NoPermission(context),
+            "no_text" => UpdateError::NoText(context),
+            "not_authed" => UpdateError::NotAuthed(context),
+            "rate_limited" => UpdateError::RateLimited(context),
+            "request_timeout" => UpdateError::RequestTimeout(context),
+            "token_revoked" => UpdateError::TokenRevoked(context),
+            "too_many_attachments" => UpdateError::TooManyAttachments(context),
+            "upgrade_required" => UpdateError::UpgradeRequired(context),
+            _ => UpdateError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for UpdateError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => UpdateError::AccountInactive,
-            "cant_update_message" => UpdateError::CantUpdateMessage,
-            "channel_not_found" => UpdateError::ChannelNotFound,
-            "edit_window_closed" => UpdateError::EditWindowClosed,
-            "fatal_error" => UpdateError::FatalError,
-            "invalid_arg_name" => UpdateError::InvalidArgName,
-            "invalid_array_arg" => UpdateError::InvalidArrayArg,
-            "invalid_auth" => UpdateError::InvalidAuth,
-            "invalid_charset" => UpdateError::InvalidCharset,
-            "invalid_form_data" => UpdateError::InvalidFormData,
-            "invalid_json" => UpdateError::InvalidJson,
-            "invalid_post_type" => UpdateError::InvalidPostType,
-            "is_inactive" => UpdateError::IsInactive,
-            "json_not_object" => UpdateError::JsonNotObject,
-            "message_not_found" => UpdateError::MessageNotFound,
-            "missing_post_type" => UpdateError::MissingPostType,
-            "msg_too_long" => UpdateError::MsgTooLong,
-            "no_permission" => UpdateError::NoPermission,
-            "no_text" => UpdateError::NoText,
-            "not_authed" => UpdateError::NotAuthed,
-            "rate_limited" => UpdateError::RateLimited,
-            "request_timeout" => UpdateError::RequestTimeout,
-            "token_revoked" => UpdateError::TokenRevoked,
-            "too_many_attachments" => UpdateError::TooManyAttachments,
-            "upgrade_required" => UpdateError::UpgradeRequired,
-            _ => UpdateError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for UpdateError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            UpdateError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            UpdateError::CantUpdateMessage => {
+            UpdateError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            UpdateError::CantUpdateMessage(_) => {
                 write!(f, "Server returned error cant_update_message")
             }
-            UpdateError::ChannelNotFound => write!(f, "Server returned error channel_not_found"),
-            UpdateError::EditWindowClosed => write!(f, "Server returned error edit_window_closed"),
-            UpdateError::FatalError => write!(f, "Server returned error fatal_error"),
-            UpdateError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            UpdateError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            UpdateError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            UpdateError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            UpdateError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            UpdateError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            UpdateError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            UpdateError::IsInactive => write!(f, "Server returned error is_inactive"),
-            UpdateError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            UpdateError::MessageNotFound => write!(f, "Server returned error message_not_found"),
-            UpdateError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            UpdateError::MsgTooLong => write!(f, "Server returned error msg_too_long"),
-            UpdateError::NoPermission => write!(f, "Server returned error no_permission"),
-            UpdateError::NoText => write!(f, "Server returned error no_text"),
-            UpdateError::NotAuthed => write!(f, "Server returned error not_authed"),
-            UpdateError::RateLimited => write!(f, "Server returned error rate_limited"),
-            UpdateError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            UpdateError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            UpdateError::TooManyAttachments => {
+            UpdateError::ChannelNotFound(_) => write!(f, "Server returned error channel_not_found"),
+            UpdateError::EditWindowClosed(_) => {
+                write!(f, "Server returned error edit_window_closed")
+            }
+            UpdateError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            UpdateError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            UpdateError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            UpdateError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            UpdateError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            UpdateError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            UpdateError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            UpdateError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            UpdateError::IsInactive(_) => write!(f, "Server returned error is_inactive"),
+            UpdateError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            UpdateError::MessageNotFound(_) => write!(f, "Server returned error message_not_found"),
+            UpdateError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            UpdateError::MsgTooLong(_) => write!(f, "Server returned error msg_too_long"),
+            UpdateError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            UpdateError::NoText(_) => write!(f, "Server returned error no_text"),
+            UpdateError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            UpdateError::RateLimited(_) => write!(f, "Server returned error rate_limited"),
+            UpdateError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            UpdateError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            UpdateError::TooManyAttachments(_) => {
                 write!(f, "Server returned error too_many_attachments")
             }
-            UpdateError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
+            UpdateError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
             UpdateError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            UpdateError::Unknown(ref s) => write!(f, "{}", s),
+            UpdateError::Unknown(ref s, _) => write!(f, "{}", s),
             UpdateError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -1853,3 +2857,79 @@ impl<E: Error + 'static> Error for UpdateError<E> {
         }
     }
 }
+
+impl<E: Error + 'static> crate::MethodError for UpdateError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            UpdateError::AccountInactive(_) => Some("account_inactive"),
+            UpdateError::CantUpdateMessage(_) => Some("cant_update_message"),
+            UpdateError::ChannelNotFound(_) => Some("channel_not_found"),
+            UpdateError::EditWindowClosed(_) => Some("edit_window_closed"),
+            UpdateError::FatalError(_) => Some("fatal_error"),
+            UpdateError::InvalidArgName(_) => Some("invalid_arg_name"),
+            UpdateError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            UpdateError::InvalidAuth(_) => Some("invalid_auth"),
+            UpdateError::InvalidCharset(_) => Some("invalid_charset"),
+            UpdateError::InvalidFormData(_) => Some("invalid_form_data"),
+            UpdateError::InvalidJson(_) => Some("invalid_json"),
+            UpdateError::InvalidPostType(_) => Some("invalid_post_type"),
+            UpdateError::IsInactive(_) => Some("is_inactive"),
+            UpdateError::JsonNotObject(_) => Some("json_not_object"),
+            UpdateError::MessageNotFound(_) => Some("message_not_found"),
+            UpdateError::MissingPostType(_) => Some("missing_post_type"),
+            UpdateError::MsgTooLong(_) => Some("msg_too_long"),
+            UpdateError::NoPermission(_) => Some("no_permission"),
+            UpdateError::NoText(_) => Some("no_text"),
+            UpdateError::NotAuthed(_) => Some("not_authed"),
+            UpdateError::RateLimited(_) => Some("rate_limited"),
+            UpdateError::RequestTimeout(_) => Some("request_timeout"),
+            UpdateError::TokenRevoked(_) => Some("token_revoked"),
+            UpdateError::TooManyAttachments(_) => Some("too_many_attachments"),
+            UpdateError::UpgradeRequired(_) => Some("upgrade_required"),
+            UpdateError::Unknown(ref s, _) => Some(s),
+            UpdateError::MalformedResponse(..) | UpdateError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            UpdateError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            UpdateError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            UpdateError::AccountInactive(ref context)
+            | UpdateError::CantUpdateMessage(ref context)
+            | UpdateError::ChannelNotFound(ref context)
+            | UpdateError::EditWindowClosed(ref context)
+            | UpdateError::FatalError(ref context)
+            | UpdateError::InvalidArgName(ref context)
+            | UpdateError::InvalidArrayArg(ref context)
+            | UpdateError::InvalidAuth(ref context)
+            | UpdateError::InvalidCharset(ref context)
+            | UpdateError::InvalidFormData(ref context)
+            | UpdateError::InvalidJson(ref context)
+            | UpdateError::InvalidPostType(ref context)
+            | UpdateError::IsInactive(ref context)
+            | UpdateError::JsonNotObject(ref context)
+            | UpdateError::MessageNotFound(ref context)
+            | UpdateError::MissingPostType(ref context)
+            | UpdateError::MsgTooLong(ref context)
+            | UpdateError::NoPermission(ref context)
+            | UpdateError::NoText(ref context)
+            | UpdateError::NotAuthed(ref context)
+            | UpdateError::RateLimited(ref context)
+            | UpdateError::RequestTimeout(ref context)
+            | UpdateError::TokenRevoked(ref context)
+            | UpdateError::TooManyAttachments(ref context)
+            | UpdateError::UpgradeRequired(ref context)
+            | UpdateError::Unknown(_, ref context) => Some(context),
+            UpdateError::MalformedResponse(..) | UpdateError::Client(_) => None,
+        }
+    }
+}
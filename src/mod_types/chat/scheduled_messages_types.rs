@@ -24,39 +24,52 @@ use std::fmt;
 #[derive(Clone, Default, Debug)]
 pub struct ListRequest<'a> {
     /// The channel of the scheduled messages
-    pub channel: Option<Cow<'a, str>>,
+    pub channel: Option<crate::ChannelId>,
     /// For pagination purposes, this is the `cursor` value returned from a previous call to `chat.scheduledmessages.list` indicating where you want to start this call from.
     pub cursor: Option<Cow<'a, str>>,
     /// A UNIX timestamp of the latest value in the time range
-    pub latest: Option<f64>,
+    pub latest: Option<crate::Timestamp>,
     /// Maximum number of original entries to return.
     pub limit: Option<u64>,
     /// A UNIX timestamp of the oldest value in the time range
-    pub oldest: Option<f64>,
+    pub oldest: Option<crate::Timestamp>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl crate::pagination::CursorRequest for ListRequest<'_> {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor.into());
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponseMetadataInner {
+    pub messages: Option<Vec<String>>,
     pub next_cursor: String,
+    pub warnings: Option<Vec<String>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListScheduledMessagesInner {
-    pub channel_id: String,
+    pub channel_id: crate::ChannelId,
     pub date_created: u64,
     pub id: String,
     pub post_at: u64,
     pub text: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
     pub response_metadata: ListResponseMetadataInner,
     pub scheduled_messages: Vec<ListScheduledMessagesInner>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
@@ -64,97 +77,143 @@ impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = (
+                self.response_metadata.messages,
+                self.response_metadata.warnings,
+            );
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(ListError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for ListResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata.warnings.as_deref(),
+        )
+    }
+}
+
+impl crate::pagination::CursorPage for ListResponse {
+    type Item = ListScheduledMessagesInner;
+
+    fn next_cursor(&self) -> Option<&str> {
+        Some(self.response_metadata.next_cursor.as_str())
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.scheduled_messages
+    }
+}
+
 #[derive(Debug)]
 pub enum ListError<E: Error> {
-    AccountInactive,
-    EkmAccessDenied,
-    FatalError,
-    InvalidArgName,
-    InvalidArguments,
-    InvalidAuth,
-    InvalidChannel,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    MissingScope,
-    NoPermission,
-    NotAuthed,
-    OrgLoginRequired,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequired,
+    AccountInactive(crate::ErrorContext),
+    EkmAccessDenied(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArguments(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidChannel(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    MissingScope(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> ListError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => ListError::AccountInactive(context),
+            "ekm_access_denied" => ListError::EkmAccessDenied(context),
+            "fatal_error" => ListError::FatalError(context),
+            "invalid_arg_name" => ListError::InvalidArgName(context),
+            "invalid_arguments" => ListError::InvalidArguments(context),
+            "invalid_auth" => ListError::InvalidAuth(context),
+            "invalid_channel" => ListError::InvalidChannel(context),
+            "invalid_charset" => ListError::InvalidCharset(context),
+            "invalid_form_data" => ListError::InvalidFormData(context),
+            "invalid_json" => ListError::InvalidJson(context),
+            "invalid_post_type" => ListError::InvalidPostType(context),
+            "json_not_object" => ListError::JsonNotObject(context),
+            "missing_post_type" => ListError::MissingPostType(context),
+            "missing_scope" => ListError::MissingScope(context),
+            "no_permission" => ListError::NoPermission(context),
+            "not_authed" => ListError::NotAuthed(context),
+            "org_login_required" => ListError::OrgLoginRequired(context),
+            "request_timeout" => ListError::RequestTimeout(context),
+            "team_added_to_org" => ListError::TeamAddedToOrg(context),
+            "token_revoked" => ListError::TokenRevoked(context),
+            "upgrade_required" => ListError::UpgradeRequired(context),
+            _ => ListError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for ListError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => ListError::AccountInactive,
-            "ekm_access_denied" => ListError::EkmAccessDenied,
-            "fatal_error" => ListError::FatalError,
-            "invalid_arg_name" => ListError::InvalidArgName,
-            "invalid_arguments" => ListError::InvalidArguments,
-            "invalid_auth" => ListError::InvalidAuth,
-            "invalid_channel" => ListError::InvalidChannel,
-            "invalid_charset" => ListError::InvalidCharset,
-            "invalid_form_data" => ListError::InvalidFormData,
-            "invalid_json" => ListError::InvalidJson,
-            "invalid_post_type" => ListError::InvalidPostType,
-            "json_not_object" => ListError::JsonNotObject,
-            "missing_post_type" => ListError::MissingPostType,
-            "missing_scope" => ListError::MissingScope,
-            "no_permission" => ListError::NoPermission,
-            "not_authed" => ListError::NotAuthed,
-            "org_login_required" => ListError::OrgLoginRequired,
-            "request_timeout" => ListError::RequestTimeout,
-            "team_added_to_org" => ListError::TeamAddedToOrg,
-            "token_revoked" => ListError::TokenRevoked,
-            "upgrade_required" => ListError::UpgradeRequired,
-            _ => ListError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for ListError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            ListError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            ListError::EkmAccessDenied => write!(f, "Server returned error ekm_access_denied"),
-            ListError::FatalError => write!(f, "Server returned error fatal_error"),
-            ListError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            ListError::InvalidArguments => write!(f, "Server returned error invalid_arguments"),
-            ListError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            ListError::InvalidChannel => write!(f, "Server returned error invalid_channel"),
-            ListError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            ListError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            ListError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            ListError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            ListError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            ListError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            ListError::MissingScope => write!(f, "Server returned error missing_scope"),
-            ListError::NoPermission => write!(f, "Server returned error no_permission"),
-            ListError::NotAuthed => write!(f, "Server returned error not_authed"),
-            ListError::OrgLoginRequired => write!(f, "Server returned error org_login_required"),
-            ListError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            ListError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            ListError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            ListError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
+            ListError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            ListError::EkmAccessDenied(_) => write!(f, "Server returned error ekm_access_denied"),
+            ListError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            ListError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            ListError::InvalidArguments(_) => write!(f, "Server returned error invalid_arguments"),
+            ListError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            ListError::InvalidChannel(_) => write!(f, "Server returned error invalid_channel"),
+            ListError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            ListError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            ListError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            ListError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            ListError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            ListError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            ListError::MissingScope(_) => write!(f, "Server returned error missing_scope"),
+            ListError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            ListError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            ListError::OrgLoginRequired(_) => write!(f, "Server returned error org_login_required"),
+            ListError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            ListError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            ListError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            ListError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
             ListError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            ListError::Unknown(ref s) => write!(f, "{}", s),
+            ListError::Unknown(ref s, _) => write!(f, "{}", s),
             ListError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -169,3 +228,71 @@ impl<E: Error + 'static> Error for ListError<E> {
         }
     }
 }
+
+impl<E: Error + 'static> crate::MethodError for ListError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            ListError::AccountInactive(_) => Some("account_inactive"),
+            ListError::EkmAccessDenied(_) => Some("ekm_access_denied"),
+            ListError::FatalError(_) => Some("fatal_error"),
+            ListError::InvalidArgName(_) => Some("invalid_arg_name"),
+            ListError::InvalidArguments(_) => Some("invalid_arguments"),
+            ListError::InvalidAuth(_) => Some("invalid_auth"),
+            ListError::InvalidChannel(_) => Some("invalid_channel"),
+            ListError::InvalidCharset(_) => Some("invalid_charset"),
+            ListError::InvalidFormData(_) => Some("invalid_form_data"),
+            ListError::InvalidJson(_) => Some("invalid_json"),
+            ListError::InvalidPostType(_) => Some("invalid_post_type"),
+            ListError::JsonNotObject(_) => Some("json_not_object"),
+            ListError::MissingPostType(_) => Some("missing_post_type"),
+            ListError::MissingScope(_) => Some("missing_scope"),
+            ListError::NoPermission(_) => Some("no_permission"),
+            ListError::NotAuthed(_) => Some("not_authed"),
+            ListError::OrgLoginRequired(_) => Some("org_login_required"),
+            ListError::RequestTimeout(_) => Some("request_timeout"),
+            ListError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            ListError::TokenRevoked(_) => Some("token_revoked"),
+            ListError::UpgradeRequired(_) => Some("upgrade_required"),
+            ListError::Unknown(ref s, _) => Some(s),
+            ListError::MalformedResponse(..) | ListError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            ListError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            ListError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            ListError::AccountInactive(ref context)
+            | ListError::EkmAccessDenied(ref context)
+            | ListError::FatalError(ref context)
+            | ListError::InvalidArgName(ref context)
+            | ListError::InvalidArguments(ref context)
+            | ListError::InvalidAuth(ref context)
+            | ListError::InvalidChannel(ref context)
+            | ListError::InvalidCharset(ref context)
+            | ListError::InvalidFormData(ref context)
+            | ListError::InvalidJson(ref context)
+            | ListError::InvalidPostType(ref context)
+            | ListError::JsonNotObject(ref context)
+            | ListError::MissingPostType(ref context)
+            | ListError::MissingScope(ref context)
+            | ListError::NoPermission(ref context)
+            | ListError::NotAuthed(ref context)
+            | ListError::OrgLoginRequired(ref context)
+            | ListError::RequestTimeout(ref context)
+            | ListError::TeamAddedToOrg(ref context)
+            | ListError::TokenRevoked(ref context)
+            | ListError::UpgradeRequired(ref context)
+            | ListError::Unknown(_, ref context) => Some(context),
+            ListError::MalformedResponse(..) | ListError::Client(_) => None,
+        }
+    }
+}
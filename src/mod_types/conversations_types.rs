@@ -22,12 +22,12 @@ use std::error::Error;
 use std::fmt;
 
 #[derive(Clone, Default, Debug)]
-pub struct ArchiveRequest<'a> {
+pub struct ArchiveRequest {
     /// ID of conversation to archive
-    pub channel: Option<Cow<'a, str>>,
+    pub channel: Option<crate::ChannelId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ArchiveResponse {
     pub callstack: Option<String>,
     error: Option<String>,
@@ -35,6 +35,10 @@ pub struct ArchiveResponse {
     #[serde(default)]
     ok: bool,
     pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<ArchiveResponse, ArchiveError<E>>> for ArchiveResponse {
@@ -42,123 +46,172 @@ impl<E: Error> Into<Result<ArchiveResponse, ArchiveError<E>>> for ArchiveRespons
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(ArchiveError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for ArchiveResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum ArchiveError<E: Error> {
-    AccountInactive,
-    AlreadyArchived,
-    CantArchiveGeneral,
-    ChannelNotFound,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MethodNotSupportedForChannelType,
-    MissingCharset,
-    MissingPostType,
-    MissingScope,
-    NotAuthed,
-    NotSupported,
-    RequestTimeout,
-    RestrictedAction,
-    SuperfluousCharset,
-    TeamAddedToOrg,
-    UpgradeRequired,
-    UserIsBot,
-    UserIsRestricted,
-    UserIsUltraRestricted,
+    AccountInactive(crate::ErrorContext),
+    AlreadyArchived(crate::ErrorContext),
+    CantArchiveGeneral(crate::ErrorContext),
+    ChannelNotFound(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MethodNotSupportedForChannelType(crate::ErrorContext),
+    MissingCharset(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    MissingScope(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    NotSupported(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    RestrictedAction(crate::ErrorContext),
+    SuperfluousCharset(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserIsBot(crate::ErrorContext),
+    UserIsRestricted(crate::ErrorContext),
+    UserIsUltraRestricted(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
-impl<'a, E: Error> From<&'a str> for ArchiveError<E> {
-    fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => ArchiveError::AccountInactive,
-            "already_archived" => ArchiveError::AlreadyArchived,
-            "cant_archive_general" => ArchiveError::CantArchiveGeneral,
-            "channel_not_found" => ArchiveError::ChannelNotFound,
-            "invalid_arg_name" => ArchiveError::InvalidArgName,
-            "invalid_array_arg" => ArchiveError::InvalidArrayArg,
-            "invalid_auth" => ArchiveError::InvalidAuth,
-            "invalid_charset" => ArchiveError::InvalidCharset,
-            "invalid_form_data" => ArchiveError::InvalidFormData,
-            "invalid_json" => ArchiveError::InvalidJson,
-            "invalid_post_type" => ArchiveError::InvalidPostType,
-            "json_not_object" => ArchiveError::JsonNotObject,
+impl<E: Error> ArchiveError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => ArchiveError::AccountInactive(context),
+            "already_archived" => ArchiveError::AlreadyArchived(context),
+            "cant_archive_general" => ArchiveError::CantArchiveGeneral(context),
+            "channel_not_found" => ArchiveError::ChannelNotFound(context),
+            "invalid_arg_name" => ArchiveError::InvalidArgName(context),
+            "invalid_array_arg" => ArchiveError::InvalidArrayArg(context),
+            "invalid_auth" => ArchiveError::InvalidAuth(context),
+            "invalid_charset" => ArchiveError::InvalidCharset(context),
+            "invalid_form_data" => ArchiveError::InvalidFormData(context),
+            "invalid_json" => ArchiveError::InvalidJson(context),
+            "invalid_post_type" => ArchiveError::InvalidPostType(context),
+            "json_not_object" => ArchiveError::JsonNotObject(context),
             "method_not_supported_for_channel_type" => {
-                ArchiveError::MethodNotSupportedForChannelType
+                ArchiveError::MethodNotSupportedForChannelType(context)
             }
-            "missing_charset" => ArchiveError::MissingCharset,
-            "missing_post_type" => ArchiveError::MissingPostType,
-            "missing_scope" => ArchiveError::MissingScope,
-            "not_authed" => ArchiveError::NotAuthed,
-            "not_supported" => ArchiveError::NotSupported,
-            "request_timeout" => ArchiveError::RequestTimeout,
-            "restricted_action" => ArchiveError::RestrictedAction,
-            "superfluous_charset" => ArchiveError::SuperfluousCharset,
-            "team_added_to_org" => ArchiveError::TeamAddedToOrg,
-            "upgrade_required" => ArchiveError::UpgradeRequired,
-            "user_is_bot" => ArchiveError::UserIsBot,
-            "user_is_restricted" => ArchiveError::UserIsRestricted,
-            "user_is_ultra_restricted" => ArchiveError::UserIsUltraRestricted,
-            _ => ArchiveError::Unknown(s.to_owned()),
+            "missing_charset" => ArchiveError::MissingCharset(context),
+            "missing_post_type" => ArchiveError::MissingPostType(context),
+            "missing_scope" => ArchiveError::MissingScope(context),
+            "not_authed" => ArchiveError::NotAuthed(context),
+            "not_supported" => ArchiveError::NotSupported(context),
+            "request_timeout" => ArchiveError::RequestTimeout(context),
+            "restricted_action" => ArchiveError::RestrictedAction(context),
+            "superfluous_charset" => ArchiveError::SuperfluousCharset(context),
+            "team_added_to_org" => ArchiveError::TeamAddedToOrg(context),
+            "upgrade_required" => ArchiveError::UpgradeRequired(context),
+            "user_is_bot" => ArchiveError::UserIsBot(context),
+            "user_is_restricted" => ArchiveError::UserIsRestricted(context),
+            "user_is_ultra_restricted" => ArchiveError::UserIsUltraRestricted(context),
+            _ => ArchiveError::Unknown(code.to_owned(), context),
         }
     }
 }
 
+impl<'a, E: Error> From<&'a str> for ArchiveError<E> {
+    fn from(s: &'a str) -> Self {
+        Self::new(s, crate::ErrorContext::default())
+    }
+}
+
 impl<E: Error> fmt::Display for ArchiveError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            ArchiveError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            ArchiveError::AlreadyArchived => write!(f, "Server returned error already_archived"),
-            ArchiveError::CantArchiveGeneral => {
+            ArchiveError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            ArchiveError::AlreadyArchived(_) => write!(f, "Server returned error already_archived"),
+            ArchiveError::CantArchiveGeneral(_) => {
                 write!(f, "Server returned error cant_archive_general")
             }
-            ArchiveError::ChannelNotFound => write!(f, "Server returned error channel_not_found"),
-            ArchiveError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            ArchiveError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            ArchiveError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            ArchiveError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            ArchiveError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            ArchiveError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            ArchiveError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            ArchiveError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            ArchiveError::MethodNotSupportedForChannelType => write!(
+            ArchiveError::ChannelNotFound(_) => {
+                write!(f, "Server returned error channel_not_found")
+            }
+            ArchiveError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            ArchiveError::InvalidArrayArg(_) => {
+                write!(f, "Server returned error invalid_array_arg")
+            }
+            ArchiveError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            ArchiveError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            ArchiveError::InvalidFormData(_) => {
+                write!(f, "Server returned error invalid_form_data")
+            }
+            ArchiveError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            ArchiveError::InvalidPostType(_) => {
+                write!(f, "Server returned error invalid_post_type")
+            }
+            ArchiveError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            ArchiveError::MethodNotSupportedForChannelType(_) => write!(
                 f,
                 "Server returned error method_not_supported_for_channel_type"
             ),
-            ArchiveError::MissingCharset => write!(f, "Server returned error missing_charset"),
-            ArchiveError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            ArchiveError::MissingScope => write!(f, "Server returned error missing_scope"),
-            ArchiveError::NotAuthed => write!(f, "Server returned error not_authed"),
-            ArchiveError::NotSupported => write!(f, "Server returned error not_supported"),
-            ArchiveError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            ArchiveError::RestrictedAction => write!(f, "Server returned error restricted_action"),
-            ArchiveError::SuperfluousCharset => {
+            ArchiveError::MissingCharset(_) => write!(f, "Server returned error missing_charset"),
+            ArchiveError::MissingPostType(_) => {
+                write!(f, "Server returned error missing_post_type")
+            }
+            ArchiveError::MissingScope(_) => write!(f, "Server returned error missing_scope"),
+            ArchiveError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            ArchiveError::NotSupported(_) => write!(f, "Server returned error not_supported"),
+            ArchiveError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            ArchiveError::RestrictedAction(_) => {
+                write!(f, "Server returned error restricted_action")
+            }
+            ArchiveError::SuperfluousCharset(_) => {
                 write!(f, "Server returned error superfluous_charset")
             }
-            ArchiveError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            ArchiveError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
-            ArchiveError::UserIsBot => write!(f, "Server returned error user_is_bot"),
-            ArchiveError::UserIsRestricted => write!(f, "Server returned error user_is_restricted"),
-            ArchiveError::UserIsUltraRestricted => {
+            ArchiveError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            ArchiveError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
+            ArchiveError::UserIsBot(_) => write!(f, "Server returned error user_is_bot"),
+            ArchiveError::UserIsRestricted(_) => {
+                write!(f, "Server returned error user_is_restricted")
+            }
+            ArchiveError::UserIsUltraRestricted(_) => {
                 write!(f, "Server returned error user_is_ultra_restricted")
             }
             ArchiveError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            ArchiveError::Unknown(ref s) => write!(f, "{}", s),
+            ArchiveError::Unknown(ref s, _) => write!(f, "{}", s),
             ArchiveError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -174,13 +227,93 @@ impl<E: Error + 'static> Error for ArchiveError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for ArchiveError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            ArchiveError::AccountInactive(_) => Some("account_inactive"),
+            ArchiveError::AlreadyArchived(_) => Some("already_archived"),
+            ArchiveError::CantArchiveGeneral(_) => Some("cant_archive_general"),
+            ArchiveError::ChannelNotFound(_) => Some("channel_not_found"),
+            ArchiveError::InvalidArgName(_) => Some("invalid_arg_name"),
+            ArchiveError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            ArchiveError::InvalidAuth(_) => Some("invalid_auth"),
+            ArchiveError::InvalidCharset(_) => Some("invalid_charset"),
+            ArchiveError::InvalidFormData(_) => Some("invalid_form_data"),
+            ArchiveError::InvalidJson(_) => Some("invalid_json"),
+            ArchiveError::InvalidPostType(_) => Some("invalid_post_type"),
+            ArchiveError::JsonNotObject(_) => Some("json_not_object"),
+            ArchiveError::MethodNotSupportedForChannelType(_) => {
+                Some("method_not_supported_for_channel_type")
+            }
+            ArchiveError::MissingCharset(_) => Some("missing_charset"),
+            ArchiveError::MissingPostType(_) => Some("missing_post_type"),
+            ArchiveError::MissingScope(_) => Some("missing_scope"),
+            ArchiveError::NotAuthed(_) => Some("not_authed"),
+            ArchiveError::NotSupported(_) => Some("not_supported"),
+            ArchiveError::RequestTimeout(_) => Some("request_timeout"),
+            ArchiveError::RestrictedAction(_) => Some("restricted_action"),
+            ArchiveError::SuperfluousCharset(_) => Some("superfluous_charset"),
+            ArchiveError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            ArchiveError::UpgradeRequired(_) => Some("upgrade_required"),
+            ArchiveError::UserIsBot(_) => Some("user_is_bot"),
+            ArchiveError::UserIsRestricted(_) => Some("user_is_restricted"),
+            ArchiveError::UserIsUltraRestricted(_) => Some("user_is_ultra_restricted"),
+            ArchiveError::Unknown(ref s, _) => Some(s),
+            ArchiveError::MalformedResponse(..) | ArchiveError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            ArchiveError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            ArchiveError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            ArchiveError::AccountInactive(ref context)
+            | ArchiveError::AlreadyArchived(ref context)
+            | ArchiveError::CantArchiveGeneral(ref context)
+            | ArchiveError::ChannelNotFound(ref context)
+            | ArchiveError::InvalidArgName(ref context)
+            | ArchiveError::InvalidArrayArg(ref context)
+            | ArchiveError::InvalidAuth(ref context)
+            | ArchiveError::InvalidCharset(ref context)
+            | ArchiveError::InvalidFormData(ref context)
+            | ArchiveError::InvalidJson(ref context)
+            | ArchiveError::InvalidPostType(ref context)
+            | ArchiveError::JsonNotObject(ref context)
+            | ArchiveError::MethodNotSupportedForChannelType(ref context)
+            | ArchiveError::MissingCharset(ref context)
+            | ArchiveError::MissingPostType(ref context)
+            | ArchiveError::MissingScope(ref context)
+            | ArchiveError::NotAuthed(ref context)
+            | ArchiveError::NotSupported(ref context)
+            | ArchiveError::RequestTimeout(ref context)
+            | ArchiveError::RestrictedAction(ref context)
+            | ArchiveError::SuperfluousCharset(ref context)
+            | ArchiveError::TeamAddedToOrg(ref context)
+            | ArchiveError::UpgradeRequired(ref context)
+            | ArchiveError::UserIsBot(ref context)
+            | ArchiveError::UserIsRestricted(ref context)
+            | ArchiveError::UserIsUltraRestricted(ref context)
+            | ArchiveError::Unknown(_, ref context) => Some(context),
+            ArchiveError::MalformedResponse(..) | ArchiveError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
-pub struct CloseRequest<'a> {
+pub struct CloseRequest {
     /// Conversation to close.
-    pub channel: Option<Cow<'a, str>>,
+    pub channel: Option<crate::ChannelId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CloseResponse {
     pub already_closed: Option<bool>,
     pub callstack: Option<String>,
@@ -190,6 +323,10 @@ pub struct CloseResponse {
     #[serde(default)]
     ok: bool,
     pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<CloseResponse, CloseError<E>>> for CloseResponse {
@@ -197,93 +334,130 @@ impl<E: Error> Into<Result<CloseResponse, CloseError<E>>> for CloseResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(CloseError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for CloseResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum CloseError<E: Error> {
-    AccountInactive,
-    ChannelNotFound,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MethodNotSupportedForChannelType,
-    MissingPostType,
-    MissingScope,
-    NotAuthed,
-    RequestTimeout,
-    TeamAddedToOrg,
-    UpgradeRequired,
-    UserDoesNotOwnChannel,
+    AccountInactive(crate::ErrorContext),
+    ChannelNotFound(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MethodNotSupportedForChannelType(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    MissingScope(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserDoesNotOwnChannel(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> CloseError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => CloseError::AccountInactive(context),
+            "channel_not_found" => CloseError::ChannelNotFound(context),
+            "invalid_arg_name" => CloseError::InvalidArgName(context),
+            "invalid_array_arg" => CloseError::InvalidArrayArg(context),
+            "invalid_auth" => CloseError::InvalidAuth(context),
+            "invalid_charset" => CloseError::InvalidCharset(context),
+            "invalid_form_data" => CloseError::InvalidFormData(context),
+            "invalid_json" => CloseError::InvalidJson(context),
+            "invalid_post_type" => CloseError::InvalidPostType(context),
+            "json_not_object" => CloseError::JsonNotObject(context),
+            "method_not_supported_for_channel_type" => {
+                CloseError::MethodNotSupportedForChannelType(context)
+            }
+            "missing_post_type" => CloseError::MissingPostType(context),
+            "missing_scope" => CloseError::MissingScope(context),
+            "not_authed" => CloseError::NotAuthed(context),
+            "request_timeout" => CloseError::RequestTimeout(context),
+            "team_added_to_org" => CloseError::TeamAddedToOrg(context),
+            "upgrade_required" => CloseError::UpgradeRequired(context),
+            "user_does_not_own_channel" => CloseError::UserDoesNotOwnChannel(context),
+            _ => CloseError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for CloseError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => CloseError::AccountInactive,
-            "channel_not_found" => CloseError::ChannelNotFound,
-            "invalid_arg_name" => CloseError::InvalidArgName,
-            "invalid_array_arg" => CloseError::InvalidArrayArg,
-            "invalid_auth" => CloseError::InvalidAuth,
-            "invalid_charset" => CloseError::InvalidCharset,
-            "invalid_form_data" => CloseError::InvalidFormData,
-            "invalid_json" => CloseError::InvalidJson,
-            "invalid_post_type" => CloseError::InvalidPostType,
-            "json_not_object" => CloseError::JsonNotObject,
-            "method_not_supported_for_channel_type" => CloseError::MethodNotSupportedForChannelType,
-            "missing_post_type" => CloseError::MissingPostType,
-            "missing_scope" => CloseError::MissingScope,
-            "not_authed" => CloseError::NotAuthed,
-            "request_timeout" => CloseError::RequestTimeout,
-            "team_added_to_org" => CloseError::TeamAddedToOrg,
-            "upgrade_required" => CloseError::UpgradeRequired,
-            "user_does_not_own_channel" => CloseError::UserDoesNotOwnChannel,
-            _ => CloseError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for CloseError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            CloseError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            CloseError::ChannelNotFound => write!(f, "Server returned error channel_not_found"),
-            CloseError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            CloseError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            CloseError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            CloseError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            CloseError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            CloseError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            CloseError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            CloseError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            CloseError::MethodNotSupportedForChannelType => write!(
+            CloseError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            CloseError::ChannelNotFound(_) => write!(f, "Server returned error channel_not_found"),
+            CloseError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            CloseError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            CloseError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            CloseError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            CloseError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            CloseError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            CloseError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            CloseError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            CloseError::MethodNotSupportedForChannelType(_) => write!(
                 f,
                 "Server returned error method_not_supported_for_channel_type"
             ),
-            CloseError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            CloseError::MissingScope => write!(f, "Server returned error missing_scope"),
-            CloseError::NotAuthed => write!(f, "Server returned error not_authed"),
-            CloseError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            CloseError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            CloseError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
-            CloseError::UserDoesNotOwnChannel => {
+            CloseError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            CloseError::MissingScope(_) => write!(f, "Server returned error missing_scope"),
+            CloseError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            CloseError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            CloseError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            CloseError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
+            CloseError::UserDoesNotOwnChannel(_) => {
                 write!(f, "Server returned error user_does_not_own_channel")
             }
             CloseError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            CloseError::Unknown(ref s) => write!(f, "{}", s),
+            CloseError::Unknown(ref s, _) => write!(f, "{}", s),
             CloseError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -299,6 +473,70 @@ impl<E: Error + 'static> Error for CloseError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for CloseError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            CloseError::AccountInactive(_) => Some("account_inactive"),
+            CloseError::ChannelNotFound(_) => Some("channel_not_found"),
+            CloseError::InvalidArgName(_) => Some("invalid_arg_name"),
+            CloseError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            CloseError::InvalidAuth(_) => Some("invalid_auth"),
+            CloseError::InvalidCharset(_) => Some("invalid_charset"),
+            CloseError::InvalidFormData(_) => Some("invalid_form_data"),
+            CloseError::InvalidJson(_) => Some("invalid_json"),
+            CloseError::InvalidPostType(_) => Some("invalid_post_type"),
+            CloseError::JsonNotObject(_) => Some("json_not_object"),
+            CloseError::MethodNotSupportedForChannelType(_) => {
+                Some("method_not_supported_for_channel_type")
+            }
+            CloseError::MissingPostType(_) => Some("missing_post_type"),
+            CloseError::MissingScope(_) => Some("missing_scope"),
+            CloseError::NotAuthed(_) => Some("not_authed"),
+            CloseError::RequestTimeout(_) => Some("request_timeout"),
+            CloseError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            CloseError::UpgradeRequired(_) => Some("upgrade_required"),
+            CloseError::UserDoesNotOwnChannel(_) => Some("user_does_not_own_channel"),
+            CloseError::Unknown(ref s, _) => Some(s),
+            CloseError::MalformedResponse(..) | CloseError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            CloseError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            CloseError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            CloseError::AccountInactive(ref context)
+            | CloseError::ChannelNotFound(ref context)
+            | CloseError::InvalidArgName(ref context)
+            | CloseError::InvalidArrayArg(ref context)
+            | CloseError::InvalidAuth(ref context)
+            | CloseError::InvalidCharset(ref context)
+            | CloseError::InvalidFormData(ref context)
+            | CloseError::InvalidJson(ref context)
+            | CloseError::InvalidPostType(ref context)
+            | CloseError::JsonNotObject(ref context)
+            | CloseError::MethodNotSupportedForChannelType(ref context)
+            | CloseError::MissingPostType(ref context)
+            | CloseError::MissingScope(ref context)
+            | CloseError::NotAuthed(ref context)
+            | CloseError::RequestTimeout(ref context)
+            | CloseError::TeamAddedToOrg(ref context)
+            | CloseError::UpgradeRequired(ref context)
+            | CloseError::UserDoesNotOwnChannel(ref context)
+            | CloseError::Unknown(_, ref context) => Some(context),
+            CloseError::MalformedResponse(..) | CloseError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct CreateRequest<'a> {
     /// Create a private channel instead of a public one
@@ -307,13 +545,13 @@ pub struct CreateRequest<'a> {
     pub name: Option<Cow<'a, str>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreateDisplayCountsInner {
     pub display_counts: u64,
     pub guest_counts: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreateAttachmentsInner {
     pub fallback: Option<String>,
     pub id: u64,
@@ -323,37 +561,32 @@ pub struct CreateAttachmentsInner {
     pub image_width: Option<u64>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct CreateBlocksInner {
-    pub r#type: String,
-}
-
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreateIconsInner {
     pub image_36: String,
     pub image_48: String,
     pub image_72: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreateBotProfileInner {
-    pub app_id: String,
+    pub app_id: crate::AppId,
     pub deleted: bool,
     pub icons: CreateIconsInner,
-    pub id: String,
+    pub id: crate::BotId,
     pub name: String,
-    pub team_id: String,
+    pub team_id: crate::TeamId,
     pub updated: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreateReactionsInner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreateCommentInner {
     pub comment: String,
     pub created: u64,
@@ -365,23 +598,23 @@ pub struct CreateCommentInner {
     pub pinned_to: Option<Vec<String>>,
     pub reactions: Option<Vec<CreateReactionsInner>>,
     pub timestamp: u64,
-    pub user: String,
+    pub user: crate::UserId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreateReactions1Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreateSharesInner {
     pub private: Option<serde_json::Value>,
     pub public: Option<serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreateFileInner {
     pub channels: Option<Vec<String>>,
     pub comments_count: Option<u64>,
@@ -396,7 +629,7 @@ pub struct CreateFileInner {
     pub filetype: Option<String>,
     pub groups: Option<Vec<String>>,
     pub has_rich_preview: Option<bool>,
-    pub id: Option<String>,
+    pub id: Option<crate::FileId>,
     pub image_exif_rotation: Option<u64>,
     pub ims: Option<Vec<String>>,
     pub is_external: Option<bool>,
@@ -450,25 +683,25 @@ pub struct CreateFileInner {
     pub updated: Option<u64>,
     pub url_private: Option<String>,
     pub url_private_download: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreateReactions2Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreateShares1Inner {
     pub private: Option<serde_json::Value>,
     pub public: Option<serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreateFilesInner {
     pub channels: Option<Vec<String>>,
     pub comments_count: Option<u64>,
@@ -483,7 +716,7 @@ pub struct CreateFilesInner {
     pub filetype: Option<String>,
     pub groups: Option<Vec<String>>,
     pub has_rich_preview: Option<bool>,
-    pub id: Option<String>,
+    pub id: Option<crate::FileId>,
     pub image_exif_rotation: Option<u64>,
     pub ims: Option<Vec<String>>,
     pub is_external: Option<bool>,
@@ -537,25 +770,25 @@ pub struct CreateFilesInner {
     pub updated: Option<u64>,
     pub url_private: Option<String>,
     pub url_private_download: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreateIcons1Inner {
     pub emoji: Option<String>,
     pub image_64: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreateReactions3Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreateUserProfileInner {
     pub avatar_hash: String,
     pub display_name: String,
@@ -567,13 +800,13 @@ pub struct CreateUserProfileInner {
     pub name: String,
     pub real_name: String,
     pub real_name_normalized: Option<String>,
-    pub team: String,
+    pub team: crate::TeamId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreateLatestInner {
     pub attachments: Option<Vec<CreateAttachmentsInner>>,
-    pub blocks: Option<Vec<CreateBlocksInner>>,
+    pub blocks: Option<Vec<crate::blocks::Block>>,
     pub bot_id: Option<Vec<String>>,
     pub bot_profile: Option<CreateBotProfileInner>,
     pub client_msg_id: Option<String>,
@@ -586,8 +819,8 @@ pub struct CreateLatestInner {
     pub is_delayed_message: Option<bool>,
     pub is_intro: Option<bool>,
     pub is_starred: Option<bool>,
-    pub last_read: Option<String>,
-    pub latest_reply: Option<String>,
+    pub last_read: Option<crate::Timestamp>,
+    pub latest_reply: Option<crate::Timestamp>,
     pub name: Option<String>,
     pub old_name: Option<String>,
     pub parent_user_id: Option<String>,
@@ -601,40 +834,40 @@ pub struct CreateLatestInner {
     pub source_team: Option<String>,
     pub subscribed: Option<bool>,
     pub subtype: Option<String>,
-    pub team: Option<String>,
+    pub team: Option<crate::TeamId>,
     pub text: String,
-    pub thread_ts: Option<String>,
+    pub thread_ts: Option<crate::Timestamp>,
     pub topic: Option<String>,
-    pub ts: String,
+    pub ts: crate::Timestamp,
     pub r#type: String,
     pub unread_count: Option<u64>,
     pub upload: Option<bool>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_profile: Option<CreateUserProfileInner>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreatePurposeInner {
     pub creator: String,
     pub last_set: u64,
     pub value: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreateCurrentInner {
     pub date_started: u64,
-    pub team_id: String,
+    pub team_id: crate::TeamId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreateExternalOrgMigrationsInner {
     pub current: Vec<CreateCurrentInner>,
     pub date_updated: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreateIconInner {
     pub image_102: Option<String>,
     pub image_132: Option<String>,
@@ -646,20 +879,20 @@ pub struct CreateIconInner {
     pub image_default: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreatePrimaryOwnerInner {
     pub email: String,
-    pub id: String,
+    pub id: crate::UserId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreateSsoProviderInner {
     pub label: Option<String>,
     pub name: Option<String>,
     pub r#type: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreateTeamInner {
     pub archived: Option<bool>,
     pub avatar_base_url: Option<String>,
@@ -670,12 +903,12 @@ pub struct CreateTeamInner {
     pub discoverable: Option<Vec<String>>,
     pub domain: String,
     pub email_domain: String,
-    pub enterprise_id: Option<String>,
+    pub enterprise_id: Option<crate::EnterpriseId>,
     pub enterprise_name: Option<String>,
     pub external_org_migrations: Option<CreateExternalOrgMigrationsInner>,
     pub has_compliance_export: Option<bool>,
     pub icon: CreateIconInner,
-    pub id: String,
+    pub id: crate::TeamId,
     pub is_assigned: Option<bool>,
     pub is_enterprise: Option<u64>,
     pub is_over_storage_limit: Option<bool>,
@@ -692,22 +925,22 @@ pub struct CreateTeamInner {
     pub sso_provider: Option<CreateSsoProviderInner>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreateShares2Inner {
     pub accepted_user: Option<String>,
     pub is_active: bool,
     pub team: CreateTeamInner,
-    pub user: String,
+    pub user: crate::UserId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreateTopicInner {
     pub creator: String,
     pub last_set: u64,
     pub value: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreateChannelInner {
     pub accepted_user: Option<String>,
     pub connected_team_ids: Option<Vec<String>>,
@@ -715,9 +948,9 @@ pub struct CreateChannelInner {
     pub created: u64,
     pub creator: String,
     pub display_counts: Option<CreateDisplayCountsInner>,
-    pub enterprise_id: Option<String>,
+    pub enterprise_id: Option<crate::EnterpriseId>,
     pub has_pins: Option<bool>,
-    pub id: String,
+    pub id: crate::ChannelId,
     pub internal_team_ids: Option<Vec<String>>,
     pub is_archived: bool,
     pub is_channel: bool,
@@ -741,7 +974,7 @@ pub struct CreateChannelInner {
     pub is_shared: bool,
     pub is_starred: Option<bool>,
     pub is_thread_only: Option<bool>,
-    pub last_read: Option<String>,
+    pub last_read: Option<crate::Timestamp>,
     pub latest: Option<Vec<CreateLatestInner>>,
     pub members: Option<Vec<String>>,
     pub name: String,
@@ -762,11 +995,11 @@ pub struct CreateChannelInner {
     pub unread_count: Option<u64>,
     pub unread_count_display: Option<u64>,
     pub use_case: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub version: Option<u64>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreateResponse {
     pub callstack: Option<String>,
     pub channel: Vec<CreateChannelInner>,
@@ -776,6 +1009,10 @@ pub struct CreateResponse {
     #[serde(default)]
     ok: bool,
     pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<CreateResponse, CreateError<E>>> for CreateResponse {
@@ -783,125 +1020,164 @@ impl<E: Error> Into<Result<CreateResponse, CreateError<E>>> for CreateResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(CreateError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for CreateResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum CreateError<E: Error> {
-    AccountInactive,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidName,
-    InvalidNameMaxlength,
-    InvalidNamePunctuation,
-    InvalidNameRequired,
-    InvalidNameSpecials,
-    InvalidPostType,
-    JsonNotObject,
-    MethodNotSupportedForChannelType,
-    MissingPostType,
-    MissingScope,
-    NameTaken,
-    NoChannel,
-    NotAuthed,
-    RequestTimeout,
-    RestrictedAction,
-    TeamAddedToOrg,
-    UpgradeRequired,
-    UserIsBot,
-    UserIsRestricted,
+    AccountInactive(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidName(crate::ErrorContext),
+    InvalidNameMaxlength(crate::ErrorContext),
+    InvalidNamePunctuation(crate::ErrorContext),
+    InvalidNameRequired(crate::ErrorContext),
+    InvalidNameSpecials(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MethodNotSupportedForChannelType(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    MissingScope(crate::ErrorContext),
+    NameTaken(crate::ErrorContext),
+    NoChannel(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    RestrictedAction(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserIsBot(crate::ErrorContext),
+    UserIsRestricted(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
-impl<'a, E: Error> From<&'a str> for CreateError<E> {
-    fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => CreateError::AccountInactive,
-            "invalid_arg_name" => CreateError::InvalidArgName,
-            "invalid_array_arg" => CreateError::InvalidArrayArg,
-            "invalid_auth" => CreateError::InvalidAuth,
-            "invalid_charset" => CreateError::InvalidCharset,
-            "invalid_form_data" => CreateError::InvalidFormData,
-            "invalid_json" => CreateError::InvalidJson,
-            "invalid_name" => CreateError::InvalidName,
-            "invalid_name_maxlength" => CreateError::InvalidNameMaxlength,
-            "invalid_name_punctuation" => CreateError::InvalidNamePunctuation,
-            "invalid_name_required" => CreateError::InvalidNameRequired,
-            "invalid_name_specials" => CreateError::InvalidNameSpecials,
-            "invalid_post_type" => CreateError::InvalidPostType,
-            "json_not_object" => CreateError::JsonNotObject,
+impl<E: Error> CreateError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => CreateError::AccountInactive(context),
+            "invalid_arg_name" => CreateError::InvalidArgName(context),
+            "invalid_array_arg" => CreateError::InvalidArrayArg(context),
+            "invalid_auth" => CreateError::InvalidAuth(context),
+            "invalid_charset" => CreateError::InvalidCharset(context),
+            "invalid_form_data" => CreateError::InvalidFormData(context),
+            "invalid_json" => CreateError::InvalidJson(context),
+            "invalid_name" => CreateError::InvalidName(context),
+            "invalid_name_maxlength" => CreateError::InvalidNameMaxlength(context),
+            "invalid_name_punctuation" => CreateError::InvalidNamePunctuation(context),
+            "invalid_name_required" => CreateError::InvalidNameRequired(context),
+            "invalid_name_specials" => CreateError::InvalidNameSpecials(context),
+            "invalid_post_type" => CreateError::InvalidPostType(context),
+            "json_not_object" => CreateError::JsonNotObject(context),
             "method_not_supported_for_channel_type" => {
-                CreateError::MethodNotSupportedForChannelType
+                CreateError::MethodNotSupportedForChannelType(context)
             }
-            "missing_post_type" => CreateError::MissingPostType,
-            "missing_scope" => CreateError::MissingScope,
-            "name_taken" => CreateError::NameTaken,
-            "no_channel" => CreateError::NoChannel,
-            "not_authed" => CreateError::NotAuthed,
-            "request_timeout" => CreateError::RequestTimeout,
-            "restricted_action" => CreateError::RestrictedAction,
-            "team_added_to_org" => CreateError::TeamAddedToOrg,
-            "upgrade_required" => CreateError::UpgradeRequired,
-            "user_is_bot" => CreateError::UserIsBot,
-            "user_is_restricted" => CreateError::UserIsRestricted,
-            _ => CreateError::Unknown(s.to_owned()),
+            "missing_post_type" => CreateError::MissingPostType(context),
+            "missing_scope" => CreateError::MissingScope(context),
+            "name_taken" => CreateError::NameTaken(context),
+            "no_channel" => CreateError::NoChannel(context),
+            "not_authed" => CreateError::NotAuthed(context),
+            "request_timeout" => CreateError::RequestTimeout(context),
+            "restricted_action" => CreateError::RestrictedAction(context),
+            "team_added_to_org" => CreateError::TeamAddedToOrg(context),
+            "upgrade_required" => CreateError::UpgradeRequired(context),
+            "user_is_bot" => CreateError::UserIsBot(context),
+            "user_is_restricted" => CreateError::UserIsRestricted(context),
+            _ => CreateError::Unknown(code.to_owned(), context),
         }
     }
 }
 
+impl<'a, E: Error> From<&'a str> for CreateError<E> {
+    fn from(s: &'a str) -> Self {
+        Self::new(s, crate::ErrorContext::default())
+    }
+}
+
 impl<E: Error> fmt::Display for CreateError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            CreateError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            CreateError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            CreateError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            CreateError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            CreateError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            CreateError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            CreateError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            CreateError::InvalidName => write!(f, "Server returned error invalid_name"),
-            CreateError::InvalidNameMaxlength => {
+            CreateError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            CreateError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            CreateError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            CreateError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            CreateError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            CreateError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            CreateError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            CreateError::InvalidName(_) => write!(f, "Server returned error invalid_name"),
+            CreateError::InvalidNameMaxlength(_) => {
                 write!(f, "Server returned error invalid_name_maxlength")
             }
-            CreateError::InvalidNamePunctuation => {
+            CreateError::InvalidNamePunctuation(_) => {
                 write!(f, "Server returned error invalid_name_punctuation")
             }
-            CreateError::InvalidNameRequired => {
+            CreateError::InvalidNameRequired(_) => {
                 write!(f, "Server returned error invalid_name_required")
             }
-            CreateError::InvalidNameSpecials => {
+            CreateError::InvalidNameSpecials(_) => {
                 write!(f, "Server returned error invalid_name_specials")
             }
-            CreateError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            CreateError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            CreateError::MethodNotSupportedForChannelType => write!(
+            CreateError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            CreateError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            CreateError::MethodNotSupportedForChannelType(_) => write!(
                 f,
                 "Server returned error method_not_supported_for_channel_type"
             ),
-            CreateError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            CreateError::MissingScope => write!(f, "Server returned error missing_scope"),
-            CreateError::NameTaken => write!(f, "Server returned error name_taken"),
-            CreateError::NoChannel => write!(f, "Server returned error no_channel"),
-            CreateError::NotAuthed => write!(f, "Server returned error not_authed"),
-            CreateError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            CreateError::RestrictedAction => write!(f, "Server returned error restricted_action"),
-            CreateError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            CreateError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
-            CreateError::UserIsBot => write!(f, "Server returned error user_is_bot"),
-            CreateError::UserIsRestricted => write!(f, "Server returned error user_is_restricted"),
+            CreateError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            CreateError::MissingScope(_) => write!(f, "Server returned error missing_scope"),
+            CreateError::NameTaken(_) => write!(f, "Server returned error name_taken"),
+            CreateError::NoChannel(_) => write!(f, "Server returned error no_channel"),
+            CreateError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            CreateError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            CreateError::RestrictedAction(_) => {
+                write!(f, "Server returned error restricted_action")
+            }
+            CreateError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            CreateError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
+            CreateError::UserIsBot(_) => write!(f, "Server returned error user_is_bot"),
+            CreateError::UserIsRestricted(_) => {
+                write!(f, "Server returned error user_is_restricted")
+            }
             CreateError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            CreateError::Unknown(ref s) => write!(f, "{}", s),
+            CreateError::Unknown(ref s, _) => write!(f, "{}", s),
             CreateError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -917,23 +1193,109 @@ impl<E: Error + 'static> Error for CreateError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for CreateError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            CreateError::AccountInactive(_) => Some("account_inactive"),
+            CreateError::InvalidArgName(_) => Some("invalid_arg_name"),
+            CreateError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            CreateError::InvalidAuth(_) => Some("invalid_auth"),
+            CreateError::InvalidCharset(_) => Some("invalid_charset"),
+            CreateError::InvalidFormData(_) => Some("invalid_form_data"),
+            CreateError::InvalidJson(_) => Some("invalid_json"),
+            CreateError::InvalidName(_) => Some("invalid_name"),
+            CreateError::InvalidNameMaxlength(_) => Some("invalid_name_maxlength"),
+            CreateError::InvalidNamePunctuation(_) => Some("invalid_name_punctuation"),
+            CreateError::InvalidNameRequired(_) => Some("invalid_name_required"),
+            CreateError::InvalidNameSpecials(_) => Some("invalid_name_specials"),
+            CreateError::InvalidPostType(_) => Some("invalid_post_type"),
+            CreateError::JsonNotObject(_) => Some("json_not_object"),
+            CreateError::MethodNotSupportedForChannelType(_) => {
+                Some("method_not_supported_for_channel_type")
+            }
+            CreateError::MissingPostType(_) => Some("missing_post_type"),
+            CreateError::MissingScope(_) => Some("missing_scope"),
+            CreateError::NameTaken(_) => Some("name_taken"),
+            CreateError::NoChannel(_) => Some("no_channel"),
+            CreateError::NotAuthed(_) => Some("not_authed"),
+            CreateError::RequestTimeout(_) => Some("request_timeout"),
+            CreateError::RestrictedAction(_) => Some("restricted_action"),
+            CreateError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            CreateError::UpgradeRequired(_) => Some("upgrade_required"),
+            CreateError::UserIsBot(_) => Some("user_is_bot"),
+            CreateError::UserIsRestricted(_) => Some("user_is_restricted"),
+            CreateError::Unknown(ref s, _) => Some(s),
+            CreateError::MalformedResponse(..) | CreateError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            CreateError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            CreateError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            CreateError::AccountInactive(ref context)
+            | CreateError::InvalidArgName(ref context)
+            | CreateError::InvalidArrayArg(ref context)
+            | CreateError::InvalidAuth(ref context)
+            | CreateError::InvalidCharset(ref context)
+            | CreateError::InvalidFormData(ref context)
+            | CreateError::InvalidJson(ref context)
+            | CreateError::InvalidName(ref context)
+            | CreateError::InvalidNameMaxlength(ref context)
+            | CreateError::InvalidNamePunctuation(ref context)
+            | CreateError::InvalidNameRequired(ref context)
+            | CreateError::InvalidNameSpecials(ref context)
+            | CreateError::InvalidPostType(ref context)
+            | CreateError::JsonNotObject(ref context)
+            | CreateError::MethodNotSupportedForChannelType(ref context)
+            | CreateError::MissingPostType(ref context)
+            | CreateError::MissingScope(ref context)
+            | CreateError::NameTaken(ref context)
+            | CreateError::NoChannel(ref context)
+            | CreateError::NotAuthed(ref context)
+            | CreateError::RequestTimeout(ref context)
+            | CreateError::RestrictedAction(ref context)
+            | CreateError::TeamAddedToOrg(ref context)
+            | CreateError::UpgradeRequired(ref context)
+            | CreateError::UserIsBot(ref context)
+            | CreateError::UserIsRestricted(ref context)
+            | CreateError::Unknown(_, ref context) => Some(context),
+            CreateError::MalformedResponse(..) | CreateError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct HistoryRequest<'a> {
     /// Conversation ID to fetch history for.
-    pub channel: Cow<'a, str>,
+    pub channel: Option<crate::ChannelId>,
     /// Paginate through collections of data by setting the `cursor` parameter to a `next_cursor` attribute returned by a previous request's `response_metadata`. Default value fetches the first "page" of the collection. See [pagination](/docs/pagination) for more detail.
     pub cursor: Option<Cow<'a, str>>,
     /// Include messages with latest or oldest timestamp in results only when either timestamp is specified.
     pub inclusive: Option<bool>,
     /// End of time range of messages to include in results.
-    pub latest: Option<f64>,
+    pub latest: Option<crate::Timestamp>,
     /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the users list hasn't been reached.
     pub limit: Option<u64>,
     /// Start of time range of messages to include in results.
-    pub oldest: Option<f64>,
+    pub oldest: Option<crate::Timestamp>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl crate::pagination::CursorRequest for HistoryRequest<'_> {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor.into());
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct HistoryAttachmentsInner {
     pub fallback: Option<String>,
     pub id: Option<u64>,
@@ -943,37 +1305,32 @@ pub struct HistoryAttachmentsInner {
     pub image_width: Option<u64>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct HistoryBlocksInner {
-    pub r#type: String,
-}
-
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct HistoryIconsInner {
     pub image_36: String,
     pub image_48: String,
     pub image_72: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct HistoryBotProfileInner {
-    pub app_id: String,
+    pub app_id: crate::AppId,
     pub deleted: bool,
     pub icons: HistoryIconsInner,
-    pub id: String,
+    pub id: crate::BotId,
     pub name: String,
-    pub team_id: String,
+    pub team_id: crate::TeamId,
     pub updated: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct HistoryReactionsInner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct HistoryCommentInner {
     pub comment: String,
     pub created: u64,
@@ -985,23 +1342,23 @@ pub struct HistoryCommentInner {
     pub pinned_to: Option<Vec<String>>,
     pub reactions: Option<Vec<HistoryReactionsInner>>,
     pub timestamp: u64,
-    pub user: String,
+    pub user: crate::UserId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct HistoryReactions1Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct HistorySharesInner {
     pub private: Option<serde_json::Value>,
     pub public: Option<serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct HistoryFileInner {
     pub channels: Option<Vec<String>>,
     pub comments_count: Option<u64>,
@@ -1016,7 +1373,7 @@ pub struct HistoryFileInner {
     pub filetype: Option<String>,
     pub groups: Option<Vec<String>>,
     pub has_rich_preview: Option<bool>,
-    pub id: Option<String>,
+    pub id: Option<crate::FileId>,
     pub image_exif_rotation: Option<u64>,
     pub ims: Option<Vec<String>>,
     pub is_external: Option<bool>,
@@ -1070,25 +1427,25 @@ pub struct HistoryFileInner {
     pub updated: Option<u64>,
     pub url_private: Option<String>,
     pub url_private_download: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct HistoryReactions2Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct HistoryShares1Inner {
     pub private: Option<serde_json::Value>,
     pub public: Option<serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct HistoryFilesInner {
     pub channels: Option<Vec<String>>,
     pub comments_count: Option<u64>,
@@ -1103,7 +1460,7 @@ pub struct HistoryFilesInner {
     pub filetype: Option<String>,
     pub groups: Option<Vec<String>>,
     pub has_rich_preview: Option<bool>,
-    pub id: Option<String>,
+    pub id: Option<crate::FileId>,
     pub image_exif_rotation: Option<u64>,
     pub ims: Option<Vec<String>>,
     pub is_external: Option<bool>,
@@ -1157,25 +1514,25 @@ pub struct HistoryFilesInner {
     pub updated: Option<u64>,
     pub url_private: Option<String>,
     pub url_private_download: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct HistoryIcons1Inner {
     pub emoji: Option<String>,
     pub image_64: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct HistoryReactions3Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct HistoryUserProfileInner {
     pub avatar_hash: String,
     pub display_name: String,
@@ -1187,14 +1544,14 @@ pub struct HistoryUserProfileInner {
     pub name: String,
     pub real_name: String,
     pub real_name_normalized: Option<String>,
-    pub team: String,
+    pub team: crate::TeamId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct HistoryMessagesInner {
     pub attachments: Option<Vec<HistoryAttachmentsInner>>,
-    pub blocks: Option<Vec<HistoryBlocksInner>>,
-    pub bot_id: Option<String>,
+    pub blocks: Option<Vec<crate::blocks::Block>>,
+    pub bot_id: Option<crate::BotId>,
     pub bot_profile: Option<HistoryBotProfileInner>,
     pub client_msg_id: Option<String>,
     pub comment: Option<HistoryCommentInner>,
@@ -1206,8 +1563,8 @@ pub struct HistoryMessagesInner {
     pub is_delayed_message: Option<bool>,
     pub is_intro: Option<bool>,
     pub is_starred: Option<bool>,
-    pub last_read: Option<String>,
-    pub latest_reply: Option<String>,
+    pub last_read: Option<crate::Timestamp>,
+    pub latest_reply: Option<crate::Timestamp>,
     pub name: Option<String>,
     pub old_name: Option<String>,
     pub parent_user_id: Option<String>,
@@ -1221,21 +1578,28 @@ pub struct HistoryMessagesInner {
     pub source_team: Option<String>,
     pub subscribed: Option<bool>,
     pub subtype: Option<String>,
-    pub team: Option<String>,
+    pub team: Option<crate::TeamId>,
     pub text: String,
-    pub thread_ts: Option<String>,
+    pub thread_ts: Option<crate::Timestamp>,
     pub topic: Option<String>,
-    pub ts: String,
+    pub ts: crate::Timestamp,
     pub r#type: String,
     pub unread_count: Option<u64>,
     pub upload: Option<bool>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_profile: Option<HistoryUserProfileInner>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct HistoryResponseMetadataInner {
+    pub messages: Option<Vec<String>>,
+    pub next_cursor: Option<String>,
+    pub warnings: Option<Vec<String>>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct HistoryResponse {
     pub callstack: Option<String>,
     pub channel_actions_count: u64,
@@ -1248,6 +1612,10 @@ pub struct HistoryResponse {
     ok: bool,
     pub pin_count: u64,
     pub provided: Option<String>,
+    pub response_metadata: Option<HistoryResponseMetadataInner>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<HistoryResponse, HistoryError<E>>> for HistoryResponse {
@@ -1255,85 +1623,148 @@ impl<E: Error> Into<Result<HistoryResponse, HistoryError<E>>> for HistoryRespons
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(HistoryError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for HistoryResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
+impl crate::pagination::CursorPage for HistoryResponse {
+    type Item = HistoryMessagesInner;
+
+    fn next_cursor(&self) -> Option<&str> {
+        self.response_metadata
+            .as_ref()
+            .and_then(|response_metadata| response_metadata.next_cursor.as_deref())
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.messages.unwrap_or_default()
+    }
+}
+
 #[derive(Debug)]
 pub enum HistoryError<E: Error> {
-    AccountInactive,
-    ChannelNotFound,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    InvalidTsLatest,
-    InvalidTsOldest,
-    JsonNotObject,
-    MissingPostType,
-    MissingScope,
-    NotAuthed,
-    RequestTimeout,
-    UpgradeRequired,
+    AccountInactive(crate::ErrorContext),
+    ChannelNotFound(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    InvalidTsLatest(crate::ErrorContext),
+    InvalidTsOldest(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    MissingScope(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> HistoryError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => HistoryError::AccountInactive(context),
+            "channel_not_found" => HistoryError::ChannelNotFound(context),
+            "invalid_arg_name" => HistoryError::InvalidArgName(context),
+            "invalid_array_arg" => HistoryError::InvalidArrayArg(context),
+            "invalid_auth" => HistoryError::InvalidAuth(context),
+            "invalid_charset" => HistoryError::InvalidCharset(context),
+            "invalid_form_data" => HistoryError::InvalidFormData(context),
+            "invalid_json" => HistoryError::InvalidJson(context),
+            "invalid_post_type" => HistoryError::InvalidPostType(context),
+            "invalid_ts_latest" => HistoryError::InvalidTsLatest(context),
+            "invalid_ts_oldest" => HistoryError::InvalidTsOldest(context),
+            "json_not_object" => HistoryError::JsonNotObject(context),
+            "missing_post_type" => HistoryError::MissingPostType(context),
+            "missing_scope" => HistoryError::MissingScope(context),
+            "not_authed" => HistoryError::NotAuthed(context),
+            "request_timeout" => HistoryError::RequestTimeout(context),
+            "upgrade_required" => HistoryError::UpgradeRequired(context),
+            _ => HistoryError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for HistoryError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => HistoryError::AccountInactive,
-            "channel_not_found" => HistoryError::ChannelNotFound,
-            "invalid_arg_name" => HistoryError::InvalidArgName,
-            "invalid_array_arg" => HistoryError::InvalidArrayArg,
-            "invalid_auth" => HistoryError::InvalidAuth,
-            "invalid_charset" => HistoryError::InvalidCharset,
-            "invalid_form_data" => HistoryError::InvalidFormData,
-            "invalid_json" => HistoryError::InvalidJson,
-            "invalid_post_type" => HistoryError::InvalidPostType,
-            "invalid_ts_latest" => HistoryError::InvalidTsLatest,
-            "invalid_ts_oldest" => HistoryError::InvalidTsOldest,
-            "json_not_object" => HistoryError::JsonNotObject,
-            "missing_post_type" => HistoryError::MissingPostType,
-            "missing_scope" => HistoryError::MissingScope,
-            "not_authed" => HistoryError::NotAuthed,
-            "request_timeout" => HistoryError::RequestTimeout,
-            "upgrade_required" => HistoryError::UpgradeRequired,
-            _ => HistoryError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for HistoryError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            HistoryError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            HistoryError::ChannelNotFound => write!(f, "Server returned error channel_not_found"),
-            HistoryError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            HistoryError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            HistoryError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            HistoryError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            HistoryError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            HistoryError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            HistoryError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            HistoryError::InvalidTsLatest => write!(f, "Server returned error invalid_ts_latest"),
-            HistoryError::InvalidTsOldest => write!(f, "Server returned error invalid_ts_oldest"),
-            HistoryError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            HistoryError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            HistoryError::MissingScope => write!(f, "Server returned error missing_scope"),
-            HistoryError::NotAuthed => write!(f, "Server returned error not_authed"),
-            HistoryError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            HistoryError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
+            HistoryError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            HistoryError::ChannelNotFound(_) => {
+                write!(f, "Server returned error channel_not_found")
+            }
+            HistoryError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            HistoryError::InvalidArrayArg(_) => {
+                write!(f, "Server returned error invalid_array_arg")
+            }
+            HistoryError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            HistoryError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            HistoryError::InvalidFormData(_) => {
+                write!(f, "Server returned error invalid_form_data")
+            }
+            HistoryError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            HistoryError::InvalidPostType(_) => {
+                write!(f, "Server returned error invalid_post_type")
+            }
+            HistoryError::InvalidTsLatest(_) => {
+                write!(f, "Server returned error invalid_ts_latest")
+            }
+            HistoryError::InvalidTsOldest(_) => {
+                write!(f, "Server returned error invalid_ts_oldest")
+            }
+            HistoryError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            HistoryError::MissingPostType(_) => {
+                write!(f, "Server returned error missing_post_type")
+            }
+            HistoryError::MissingScope(_) => write!(f, "Server returned error missing_scope"),
+            HistoryError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            HistoryError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            HistoryError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
             HistoryError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            HistoryError::Unknown(ref s) => write!(f, "{}", s),
+            HistoryError::Unknown(ref s, _) => write!(f, "{}", s),
             HistoryError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -1349,23 +1780,83 @@ impl<E: Error + 'static> Error for HistoryError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for HistoryError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            HistoryError::AccountInactive(_) => Some("account_inactive"),
+            HistoryError::ChannelNotFound(_) => Some("channel_not_found"),
+            HistoryError::InvalidArgName(_) => Some("invalid_arg_name"),
+            HistoryError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            HistoryError::InvalidAuth(_) => Some("invalid_auth"),
+            HistoryError::InvalidCharset(_) => Some("invalid_charset"),
+            HistoryError::InvalidFormData(_) => Some("invalid_form_data"),
+            HistoryError::InvalidJson(_) => Some("invalid_json"),
+            HistoryError::InvalidPostType(_) => Some("invalid_post_type"),
+            HistoryError::InvalidTsLatest(_) => Some("invalid_ts_latest"),
+            HistoryError::InvalidTsOldest(_) => Some("invalid_ts_oldest"),
+            HistoryError::JsonNotObject(_) => Some("json_not_object"),
+            HistoryError::MissingPostType(_) => Some("missing_post_type"),
+            HistoryError::MissingScope(_) => Some("missing_scope"),
+            HistoryError::NotAuthed(_) => Some("not_authed"),
+            HistoryError::RequestTimeout(_) => Some("request_timeout"),
+            HistoryError::UpgradeRequired(_) => Some("upgrade_required"),
+            HistoryError::Unknown(ref s, _) => Some(s),
+            HistoryError::MalformedResponse(..) | HistoryError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            HistoryError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            HistoryError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            HistoryError::AccountInactive(ref context)
+            | HistoryError::ChannelNotFound(ref context)
+            | HistoryError::InvalidArgName(ref context)
+            | HistoryError::InvalidArrayArg(ref context)
+            | HistoryError::InvalidAuth(ref context)
+            | HistoryError::InvalidCharset(ref context)
+            | HistoryError::InvalidFormData(ref context)
+            | HistoryError::InvalidJson(ref context)
+            | HistoryError::InvalidPostType(ref context)
+            | HistoryError::InvalidTsLatest(ref context)
+            | HistoryError::InvalidTsOldest(ref context)
+            | HistoryError::JsonNotObject(ref context)
+            | HistoryError::MissingPostType(ref context)
+            | HistoryError::MissingScope(ref context)
+            | HistoryError::NotAuthed(ref context)
+            | HistoryError::RequestTimeout(ref context)
+            | HistoryError::UpgradeRequired(ref context)
+            | HistoryError::Unknown(_, ref context) => Some(context),
+            HistoryError::MalformedResponse(..) | HistoryError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
-pub struct InfoRequest<'a> {
+pub struct InfoRequest {
     /// Conversation ID to learn more about
-    pub channel: Cow<'a, str>,
+    pub channel: Option<crate::ChannelId>,
     /// Set this to `true` to receive the locale for this conversation. Defaults to `false`
     pub include_locale: Option<bool>,
     /// Set to `true` to include the member count for the specified conversation. Defaults to `false`
     pub include_num_members: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoDisplayCountsInner {
     pub display_counts: u64,
     pub guest_counts: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoAttachmentsInner {
     pub fallback: Option<String>,
     pub id: u64,
@@ -1375,37 +1866,32 @@ pub struct InfoAttachmentsInner {
     pub image_width: Option<u64>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct InfoBlocksInner {
-    pub r#type: String,
-}
-
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoIconsInner {
     pub image_36: String,
     pub image_48: String,
     pub image_72: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoBotProfileInner {
-    pub app_id: String,
+    pub app_id: crate::AppId,
     pub deleted: bool,
     pub icons: InfoIconsInner,
-    pub id: String,
+    pub id: crate::BotId,
     pub name: String,
-    pub team_id: String,
+    pub team_id: crate::TeamId,
     pub updated: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoReactionsInner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoCommentInner {
     pub comment: String,
     pub created: u64,
@@ -1417,23 +1903,23 @@ pub struct InfoCommentInner {
     pub pinned_to: Option<Vec<String>>,
     pub reactions: Option<Vec<InfoReactionsInner>>,
     pub timestamp: u64,
-    pub user: String,
+    pub user: crate::UserId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoReactions1Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoSharesInner {
     pub private: Option<serde_json::Value>,
     pub public: Option<serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoFileInner {
     pub channels: Option<Vec<String>>,
     pub comments_count: Option<u64>,
@@ -1448,7 +1934,7 @@ pub struct InfoFileInner {
     pub filetype: Option<String>,
     pub groups: Option<Vec<String>>,
     pub has_rich_preview: Option<bool>,
-    pub id: Option<String>,
+    pub id: Option<crate::FileId>,
     pub image_exif_rotation: Option<u64>,
     pub ims: Option<Vec<String>>,
     pub is_external: Option<bool>,
@@ -1502,25 +1988,25 @@ pub struct InfoFileInner {
     pub updated: Option<u64>,
     pub url_private: Option<String>,
     pub url_private_download: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoReactions2Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoShares1Inner {
     pub private: Option<serde_json::Value>,
     pub public: Option<serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoFilesInner {
     pub channels: Option<Vec<String>>,
     pub comments_count: Option<u64>,
@@ -1535,7 +2021,7 @@ pub struct InfoFilesInner {
     pub filetype: Option<String>,
     pub groups: Option<Vec<String>>,
     pub has_rich_preview: Option<bool>,
-    pub id: Option<String>,
+    pub id: Option<crate::FileId>,
     pub image_exif_rotation: Option<u64>,
     pub ims: Option<Vec<String>>,
     pub is_external: Option<bool>,
@@ -1589,25 +2075,25 @@ pub struct InfoFilesInner {
     pub updated: Option<u64>,
     pub url_private: Option<String>,
     pub url_private_download: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoIcons1Inner {
     pub emoji: Option<String>,
     pub image_64: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoReactions3Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoUserProfileInner {
     pub avatar_hash: String,
     pub display_name: String,
@@ -1619,13 +2105,13 @@ pub struct InfoUserProfileInner {
     pub name: String,
     pub real_name: String,
     pub real_name_normalized: Option<String>,
-    pub team: String,
+    pub team: crate::TeamId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoLatestInner {
     pub attachments: Option<Vec<InfoAttachmentsInner>>,
-    pub blocks: Option<Vec<InfoBlocksInner>>,
+    pub blocks: Option<Vec<crate::blocks::Block>>,
     pub bot_id: Option<Vec<String>>,
     pub bot_profile: Option<InfoBotProfileInner>,
     pub client_msg_id: Option<String>,
@@ -1638,8 +2124,8 @@ pub struct InfoLatestInner {
     pub is_delayed_message: Option<bool>,
     pub is_intro: Option<bool>,
     pub is_starred: Option<bool>,
-    pub last_read: Option<String>,
-    pub latest_reply: Option<String>,
+    pub last_read: Option<crate::Timestamp>,
+    pub latest_reply: Option<crate::Timestamp>,
     pub name: Option<String>,
     pub old_name: Option<String>,
     pub parent_user_id: Option<String>,
@@ -1653,40 +2139,40 @@ pub struct InfoLatestInner {
     pub source_team: Option<String>,
     pub subscribed: Option<bool>,
     pub subtype: Option<String>,
-    pub team: Option<String>,
+    pub team: Option<crate::TeamId>,
     pub text: String,
-    pub thread_ts: Option<String>,
+    pub thread_ts: Option<crate::Timestamp>,
     pub topic: Option<String>,
-    pub ts: String,
+    pub ts: crate::Timestamp,
     pub r#type: String,
     pub unread_count: Option<u64>,
     pub upload: Option<bool>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_profile: Option<InfoUserProfileInner>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoPurposeInner {
     pub creator: String,
     pub last_set: u64,
     pub value: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoCurrentInner {
     pub date_started: u64,
-    pub team_id: String,
+    pub team_id: crate::TeamId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoExternalOrgMigrationsInner {
     pub current: Vec<InfoCurrentInner>,
     pub date_updated: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoIconInner {
     pub image_102: Option<String>,
     pub image_132: Option<String>,
@@ -1698,20 +2184,20 @@ pub struct InfoIconInner {
     pub image_default: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoPrimaryOwnerInner {
     pub email: String,
-    pub id: String,
+    pub id: crate::UserId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoSsoProviderInner {
     pub label: Option<String>,
     pub name: Option<String>,
     pub r#type: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoTeamInner {
     pub archived: Option<bool>,
     pub avatar_base_url: Option<String>,
@@ -1722,12 +2208,12 @@ pub struct InfoTeamInner {
     pub discoverable: Option<Vec<String>>,
     pub domain: String,
     pub email_domain: String,
-    pub enterprise_id: Option<String>,
+    pub enterprise_id: Option<crate::EnterpriseId>,
     pub enterprise_name: Option<String>,
     pub external_org_migrations: Option<InfoExternalOrgMigrationsInner>,
     pub has_compliance_export: Option<bool>,
     pub icon: InfoIconInner,
-    pub id: String,
+    pub id: crate::TeamId,
     pub is_assigned: Option<bool>,
     pub is_enterprise: Option<u64>,
     pub is_over_storage_limit: Option<bool>,
@@ -1744,22 +2230,22 @@ pub struct InfoTeamInner {
     pub sso_provider: Option<InfoSsoProviderInner>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoShares2Inner {
     pub accepted_user: Option<String>,
     pub is_active: bool,
     pub team: InfoTeamInner,
-    pub user: String,
+    pub user: crate::UserId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoTopicInner {
     pub creator: String,
     pub last_set: u64,
     pub value: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoChannelInner {
     pub accepted_user: Option<String>,
     pub connected_team_ids: Option<Vec<String>>,
@@ -1767,9 +2253,9 @@ pub struct InfoChannelInner {
     pub created: u64,
     pub creator: String,
     pub display_counts: Option<InfoDisplayCountsInner>,
-    pub enterprise_id: Option<String>,
+    pub enterprise_id: Option<crate::EnterpriseId>,
     pub has_pins: Option<bool>,
-    pub id: String,
+    pub id: crate::ChannelId,
     pub internal_team_ids: Option<Vec<String>>,
     pub is_archived: bool,
     pub is_channel: bool,
@@ -1793,7 +2279,7 @@ pub struct InfoChannelInner {
     pub is_shared: bool,
     pub is_starred: Option<bool>,
     pub is_thread_only: Option<bool>,
-    pub last_read: Option<String>,
+    pub last_read: Option<crate::Timestamp>,
     pub latest: Option<Vec<InfoLatestInner>>,
     pub members: Option<Vec<String>>,
     pub name: String,
@@ -1814,11 +2300,11 @@ pub struct InfoChannelInner {
     pub unread_count: Option<u64>,
     pub unread_count_display: Option<u64>,
     pub use_case: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub version: Option<u64>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoResponse {
     pub callstack: Option<String>,
     pub channel: InfoChannelInner,
@@ -1827,6 +2313,10 @@ pub struct InfoResponse {
     #[serde(default)]
     ok: bool,
     pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<InfoResponse, InfoError<E>>> for InfoResponse {
@@ -1834,82 +2324,117 @@ impl<E: Error> Into<Result<InfoResponse, InfoError<E>>> for InfoResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(InfoError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for InfoResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum InfoError<E: Error> {
-    AccountInactive,
-    ChannelNotFound,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    MissingScope,
-    NotAuthed,
-    RequestTimeout,
-    TeamAddedToOrg,
-    UpgradeRequired,
+    AccountInactive(crate::ErrorContext),
+    ChannelNotFound(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    MissingScope(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> InfoError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => InfoError::AccountInactive(context),
+            "channel_not_found" => InfoError::ChannelNotFound(context),
+            "invalid_arg_name" => InfoError::InvalidArgName(context),
+            "invalid_array_arg" => InfoError::InvalidArrayArg(context),
+            "invalid_auth" => InfoError::InvalidAuth(context),
+            "invalid_charset" => InfoError::InvalidCharset(context),
+            "invalid_form_data" => InfoError::InvalidFormData(context),
+            "invalid_json" => InfoError::InvalidJson(context),
+            "invalid_post_type" => InfoError::InvalidPostType(context),
+            "json_not_object" => InfoError::JsonNotObject(context),
+            "missing_post_type" => InfoError::MissingPostType(context),
+            "missing_scope" => InfoError::MissingScope(context),
+            "not_authed" => InfoError::NotAuthed(context),
+            "request_timeout" => InfoError::RequestTimeout(context),
+            "team_added_to_org" => InfoError::TeamAddedToOrg(context),
+            "upgrade_required" => InfoError::UpgradeRequired(context),
+            _ => InfoError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for InfoError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => InfoError::AccountInactive,
-            "channel_not_found" => InfoError::ChannelNotFound,
-            "invalid_arg_name" => InfoError::InvalidArgName,
-            "invalid_array_arg" => InfoError::InvalidArrayArg,
-            "invalid_auth" => InfoError::InvalidAuth,
-            "invalid_charset" => InfoError::InvalidCharset,
-            "invalid_form_data" => InfoError::InvalidFormData,
-            "invalid_json" => InfoError::InvalidJson,
-            "invalid_post_type" => InfoError::InvalidPostType,
-            "json_not_object" => InfoError::JsonNotObject,
-            "missing_post_type" => InfoError::MissingPostType,
-            "missing_scope" => InfoError::MissingScope,
-            "not_authed" => InfoError::NotAuthed,
-            "request_timeout" => InfoError::RequestTimeout,
-            "team_added_to_org" => InfoError::TeamAddedToOrg,
-            "upgrade_required" => InfoError::UpgradeRequired,
-            _ => InfoError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for InfoError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            InfoError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            InfoError::ChannelNotFound => write!(f, "Server returned error channel_not_found"),
-            InfoError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            InfoError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            InfoError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            InfoError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            InfoError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            InfoError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            InfoError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            InfoError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            InfoError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            InfoError::MissingScope => write!(f, "Server returned error missing_scope"),
-            InfoError::NotAuthed => write!(f, "Server returned error not_authed"),
-            InfoError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            InfoError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            InfoError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
+            InfoError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            InfoError::ChannelNotFound(_) => write!(f, "Server returned error channel_not_found"),
+            InfoError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            InfoError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            InfoError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            InfoError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            InfoError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            InfoError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            InfoError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            InfoError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            InfoError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            InfoError::MissingScope(_) => write!(f, "Server returned error missing_scope"),
+            InfoError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            InfoError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            InfoError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            InfoError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
             InfoError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            InfoError::Unknown(ref s) => write!(f, "{}", s),
+            InfoError::Unknown(ref s, _) => write!(f, "{}", s),
             InfoError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -1925,21 +2450,79 @@ impl<E: Error + 'static> Error for InfoError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for InfoError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            InfoError::AccountInactive(_) => Some("account_inactive"),
+            InfoError::ChannelNotFound(_) => Some("channel_not_found"),
+            InfoError::InvalidArgName(_) => Some("invalid_arg_name"),
+            InfoError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            InfoError::InvalidAuth(_) => Some("invalid_auth"),
+            InfoError::InvalidCharset(_) => Some("invalid_charset"),
+            InfoError::InvalidFormData(_) => Some("invalid_form_data"),
+            InfoError::InvalidJson(_) => Some("invalid_json"),
+            InfoError::InvalidPostType(_) => Some("invalid_post_type"),
+            InfoError::JsonNotObject(_) => Some("json_not_object"),
+            InfoError::MissingPostType(_) => Some("missing_post_type"),
+            InfoError::MissingScope(_) => Some("missing_scope"),
+            InfoError::NotAuthed(_) => Some("not_authed"),
+            InfoError::RequestTimeout(_) => Some("request_timeout"),
+            InfoError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            InfoError::UpgradeRequired(_) => Some("upgrade_required"),
+            InfoError::Unknown(ref s, _) => Some(s),
+            InfoError::MalformedResponse(..) | InfoError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            InfoError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            InfoError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            InfoError::AccountInactive(ref context)
+            | InfoError::ChannelNotFound(ref context)
+            | InfoError::InvalidArgName(ref context)
+            | InfoError::InvalidArrayArg(ref context)
+            | InfoError::InvalidAuth(ref context)
+            | InfoError::InvalidCharset(ref context)
+            | InfoError::InvalidFormData(ref context)
+            | InfoError::InvalidJson(ref context)
+            | InfoError::InvalidPostType(ref context)
+            | InfoError::JsonNotObject(ref context)
+            | InfoError::MissingPostType(ref context)
+            | InfoError::MissingScope(ref context)
+            | InfoError::NotAuthed(ref context)
+            | InfoError::RequestTimeout(ref context)
+            | InfoError::TeamAddedToOrg(ref context)
+            | InfoError::UpgradeRequired(ref context)
+            | InfoError::Unknown(_, ref context) => Some(context),
+            InfoError::MalformedResponse(..) | InfoError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct InviteRequest<'a> {
     /// The ID of the public or private channel to invite user(s) to.
-    pub channel: Option<Cow<'a, str>>,
+    pub channel: Option<crate::ChannelId>,
     /// A comma separated list of user IDs. Up to 1000 users may be listed.
     pub users: Option<Cow<'a, str>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InviteDisplayCountsInner {
     pub display_counts: u64,
     pub guest_counts: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InviteAttachmentsInner {
     pub fallback: Option<String>,
     pub id: u64,
@@ -1949,37 +2532,32 @@ pub struct InviteAttachmentsInner {
     pub image_width: Option<u64>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct InviteBlocksInner {
-    pub r#type: String,
-}
-
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InviteIconsInner {
     pub image_36: String,
     pub image_48: String,
     pub image_72: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InviteBotProfileInner {
-    pub app_id: String,
+    pub app_id: crate::AppId,
     pub deleted: bool,
     pub icons: InviteIconsInner,
-    pub id: String,
+    pub id: crate::BotId,
     pub name: String,
-    pub team_id: String,
+    pub team_id: crate::TeamId,
     pub updated: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InviteReactionsInner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InviteCommentInner {
     pub comment: String,
     pub created: u64,
@@ -1991,23 +2569,23 @@ pub struct InviteCommentInner {
     pub pinned_to: Option<Vec<String>>,
     pub reactions: Option<Vec<InviteReactionsInner>>,
     pub timestamp: u64,
-    pub user: String,
+    pub user: crate::UserId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InviteReactions1Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InviteSharesInner {
     pub private: Option<serde_json::Value>,
     pub public: Option<serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InviteFileInner {
     pub channels: Option<Vec<String>>,
     pub comments_count: Option<u64>,
@@ -2022,7 +2600,7 @@ pub struct InviteFileInner {
     pub filetype: Option<String>,
     pub groups: Option<Vec<String>>,
     pub has_rich_preview: Option<bool>,
-    pub id: Option<String>,
+    pub id: Option<crate::FileId>,
     pub image_exif_rotation: Option<u64>,
     pub ims: Option<Vec<String>>,
     pub is_external: Option<bool>,
@@ -2076,25 +2654,25 @@ pub struct InviteFileInner {
     pub updated: Option<u64>,
     pub url_private: Option<String>,
     pub url_private_download: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InviteReactions2Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InviteShares1Inner {
     pub private: Option<serde_json::Value>,
     pub public: Option<serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InviteFilesInner {
     pub channels: Option<Vec<String>>,
     pub comments_count: Option<u64>,
@@ -2109,7 +2687,7 @@ pub struct InviteFilesInner {
     pub filetype: Option<String>,
     pub groups: Option<Vec<String>>,
     pub has_rich_preview: Option<bool>,
-    pub id: Option<String>,
+    pub id: Option<crate::FileId>,
     pub image_exif_rotation: Option<u64>,
     pub ims: Option<Vec<String>>,
     pub is_external: Option<bool>,
@@ -2163,25 +2741,25 @@ pub struct InviteFilesInner {
     pub updated: Option<u64>,
     pub url_private: Option<String>,
     pub url_private_download: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InviteIcons1Inner {
     pub emoji: Option<String>,
     pub image_64: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InviteReactions3Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InviteUserProfileInner {
     pub avatar_hash: String,
     pub display_name: String,
@@ -2193,13 +2771,13 @@ pub struct InviteUserProfileInner {
     pub name: String,
     pub real_name: String,
     pub real_name_normalized: Option<String>,
-    pub team: String,
+    pub team: crate::TeamId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InviteLatestInner {
     pub attachments: Option<Vec<InviteAttachmentsInner>>,
-    pub blocks: Option<Vec<InviteBlocksInner>>,
+    pub blocks: Option<Vec<crate::blocks::Block>>,
     pub bot_id: Option<Vec<String>>,
     pub bot_profile: Option<InviteBotProfileInner>,
     pub client_msg_id: Option<String>,
@@ -2212,8 +2790,8 @@ pub struct InviteLatestInner {
     pub is_delayed_message: Option<bool>,
     pub is_intro: Option<bool>,
     pub is_starred: Option<bool>,
-    pub last_read: Option<String>,
-    pub latest_reply: Option<String>,
+    pub last_read: Option<crate::Timestamp>,
+    pub latest_reply: Option<crate::Timestamp>,
     pub name: Option<String>,
     pub old_name: Option<String>,
     pub parent_user_id: Option<String>,
@@ -2227,40 +2805,40 @@ pub struct InviteLatestInner {
     pub source_team: Option<String>,
     pub subscribed: Option<bool>,
     pub subtype: Option<String>,
-    pub team: Option<String>,
+    pub team: Option<crate::TeamId>,
     pub text: String,
-    pub thread_ts: Option<String>,
+    pub thread_ts: Option<crate::Timestamp>,
     pub topic: Option<String>,
-    pub ts: String,
+    pub ts: crate::Timestamp,
     pub r#type: String,
     pub unread_count: Option<u64>,
     pub upload: Option<bool>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_profile: Option<InviteUserProfileInner>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InvitePurposeInner {
     pub creator: String,
     pub last_set: u64,
     pub value: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InviteCurrentInner {
     pub date_started: u64,
-    pub team_id: String,
+    pub team_id: crate::TeamId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InviteExternalOrgMigrationsInner {
     pub current: Vec<InviteCurrentInner>,
     pub date_updated: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InviteIconInner {
     pub image_102: Option<String>,
     pub image_132: Option<String>,
@@ -2272,20 +2850,20 @@ pub struct InviteIconInner {
     pub image_default: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InvitePrimaryOwnerInner {
     pub email: String,
-    pub id: String,
+    pub id: crate::UserId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InviteSsoProviderInner {
     pub label: Option<String>,
     pub name: Option<String>,
     pub r#type: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InviteTeamInner {
     pub archived: Option<bool>,
     pub avatar_base_url: Option<String>,
@@ -2296,12 +2874,12 @@ pub struct InviteTeamInner {
     pub discoverable: Option<Vec<String>>,
     pub domain: String,
     pub email_domain: String,
-    pub enterprise_id: Option<String>,
+    pub enterprise_id: Option<crate::EnterpriseId>,
     pub enterprise_name: Option<String>,
     pub external_org_migrations: Option<InviteExternalOrgMigrationsInner>,
     pub has_compliance_export: Option<bool>,
     pub icon: InviteIconInner,
-    pub id: String,
+    pub id: crate::TeamId,
     pub is_assigned: Option<bool>,
     pub is_enterprise: Option<u64>,
     pub is_over_storage_limit: Option<bool>,
@@ -2318,22 +2896,22 @@ pub struct InviteTeamInner {
     pub sso_provider: Option<InviteSsoProviderInner>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InviteShares2Inner {
     pub accepted_user: Option<String>,
     pub is_active: bool,
     pub team: InviteTeamInner,
-    pub user: String,
+    pub user: crate::UserId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InviteTopicInner {
     pub creator: String,
     pub last_set: u64,
     pub value: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InviteChannelInner {
     pub accepted_user: Option<String>,
     pub connected_team_ids: Option<Vec<String>>,
@@ -2341,9 +2919,9 @@ pub struct InviteChannelInner {
     pub created: u64,
     pub creator: String,
     pub display_counts: Option<InviteDisplayCountsInner>,
-    pub enterprise_id: Option<String>,
+    pub enterprise_id: Option<crate::EnterpriseId>,
     pub has_pins: Option<bool>,
-    pub id: String,
+    pub id: crate::ChannelId,
     pub internal_team_ids: Option<Vec<String>>,
     pub is_archived: bool,
     pub is_channel: bool,
@@ -2367,7 +2945,7 @@ pub struct InviteChannelInner {
     pub is_shared: bool,
     pub is_starred: Option<bool>,
     pub is_thread_only: Option<bool>,
-    pub last_read: Option<String>,
+    pub last_read: Option<crate::Timestamp>,
     pub latest: Option<Vec<InviteLatestInner>>,
     pub members: Option<Vec<String>>,
     pub name: String,
@@ -2388,19 +2966,19 @@ pub struct InviteChannelInner {
     pub unread_count: Option<u64>,
     pub unread_count_display: Option<u64>,
     pub use_case: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub version: Option<u64>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InviteErrorsInner {
     error: String,
     #[serde(default)]
     ok: bool,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InviteResponse {
     pub callstack: Option<String>,
     pub channel: Vec<InviteChannelInner>,
@@ -2410,6 +2988,10 @@ pub struct InviteResponse {
     #[serde(default)]
     ok: bool,
     pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<InviteResponse, InviteError<E>>> for InviteResponse {
@@ -2417,136 +2999,175 @@ impl<E: Error> Into<Result<InviteResponse, InviteError<E>>> for InviteResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(InviteError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for InviteResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum InviteError<E: Error> {
-    AccountInactive,
-    AlreadyInChannel,
-    CantInvite,
-    CantInviteSelf,
-    ChannelNotFound,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    IsArchived,
-    JsonNotObject,
-    MethodNotSupportedForChannelType,
-    MissingCharset,
-    MissingPostType,
-    MissingScope,
-    NoUser,
-    NotAuthed,
-    NotInChannel,
-    RequestTimeout,
-    SuperfluousCharset,
-    TeamAddedToOrg,
-    TooManyUsers,
-    UpgradeRequired,
-    UraMaxChannels,
-    UserIsBot,
-    UserIsRestricted,
-    UserIsUltraRestricted,
-    UserNotFound,
+    AccountInactive(crate::ErrorContext),
+    AlreadyInChannel(crate::ErrorContext),
+    CantInvite(crate::ErrorContext),
+    CantInviteSelf(crate::ErrorContext),
+    ChannelNotFound(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    IsArchived(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MethodNotSupportedForChannelType(crate::ErrorContext),
+    MissingCharset(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    MissingScope(crate::ErrorContext),
+    NoUser(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    NotInChannel(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    SuperfluousCharset(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TooManyUsers(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UraMaxChannels(crate::ErrorContext),
+    UserIsBot(crate::ErrorContext),
+    UserIsRestricted(crate::ErrorContext),
+    UserIsUltraRestricted(crate::ErrorContext),
+    UserNotFound(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
-impl<'a, E: Error> From<&'a str> for InviteError<E> {
-    fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => InviteError::AccountInactive,
-            "already_in_channel" => InviteError::AlreadyInChannel,
-            "cant_invite" => InviteError::CantInvite,
-            "cant_invite_self" => InviteError::CantInviteSelf,
-            "channel_not_found" => InviteError::ChannelNotFound,
-            "invalid_arg_name" => InviteError::InvalidArgName,
-            "invalid_array_arg" => InviteError::InvalidArrayArg,
-            "invalid_auth" => InviteError::InvalidAuth,
-            "invalid_charset" => InviteError::InvalidCharset,
-            "invalid_form_data" => InviteError::InvalidFormData,
-            "invalid_json" => InviteError::InvalidJson,
-            "invalid_post_type" => InviteError::InvalidPostType,
-            "is_archived" => InviteError::IsArchived,
-            "json_not_object" => InviteError::JsonNotObject,
+impl<E: Error> InviteError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => InviteError::AccountInactive(context),
+            "already_in_channel" => InviteError::AlreadyInChannel(context),
+            "cant_invite" => InviteError::CantInvite(context),
+            "cant_invite_self" => InviteError::CantInviteSelf(context),
+            "channel_not_found" => InviteError::ChannelNotFound(context),
+            "invalid_arg_name" => InviteError::InvalidArgName(context),
+            "invalid_array_arg" => InviteError::InvalidArrayArg(context),
+            "invalid_auth" => InviteError::InvalidAuth(context),
+            "invalid_charset" => InviteError::InvalidCharset(context),
+            "invalid_form_data" => InviteError::InvalidFormData(context),
+            "invalid_json" => InviteError::InvalidJson(context),
+            "invalid_post_type" => InviteError::InvalidPostType(context),
+            "is_archived" => InviteError::IsArchived(context),
+            "json_not_object" => InviteError::JsonNotObject(context),
             "method_not_supported_for_channel_type" => {
-                InviteError::MethodNotSupportedForChannelType
+                InviteError::MethodNotSupportedForChannelType(context)
             }
-            "missing_charset" => InviteError::MissingCharset,
-            "missing_post_type" => InviteError::MissingPostType,
-            "missing_scope" => InviteError::MissingScope,
-            "no_user" => InviteError::NoUser,
-            "not_authed" => InviteError::NotAuthed,
-            "not_in_channel" => InviteError::NotInChannel,
-            "request_timeout" => InviteError::RequestTimeout,
-            "superfluous_charset" => InviteError::SuperfluousCharset,
-            "team_added_to_org" => InviteError::TeamAddedToOrg,
-            "too_many_users" => InviteError::TooManyUsers,
-            "upgrade_required" => InviteError::UpgradeRequired,
-            "ura_max_channels" => InviteError::UraMaxChannels,
-            "user_is_bot" => InviteError::UserIsBot,
-            "user_is_restricted" => InviteError::UserIsRestricted,
-            "user_is_ultra_restricted" => InviteError::UserIsUltraRestricted,
-            "user_not_found" => InviteError::UserNotFound,
-            _ => InviteError::Unknown(s.to_owned()),
+            "missing_charset" => InviteError::MissingCharset(context),
+            "missing_post_type" => InviteError::MissingPostType(context),
+            "missing_scope" => InviteError::MissingScope(context),
+            "no_user" => InviteError::NoUser(context),
+            "not_authed" => InviteError::NotAuthed(context),
+            "not_in_channel" => InviteError::NotInChannel(context),
+            "request_timeout" => InviteError::RequestTimeout(context),
+            "superfluous_charset" => InviteError::SuperfluousCharset(context),
+            "team_added_to_org" => InviteError::TeamAddedToOrg(context),
+            "too_many_users" => InviteError::TooManyUsers(context),
+            "upgrade_required" => InviteError::UpgradeRequired(context),
+            "ura_max_channels" => InviteError::UraMaxChannels(context),
+            "user_is_bot" => InviteError::UserIsBot(context),
+            "user_is_restricted" => InviteError::UserIsRestricted(context),
+            "user_is_ultra_restricted" => InviteError::UserIsUltraRestricted(context),
+            "user_not_found" => InviteError::UserNotFound(context),
+            _ => InviteError::Unknown(code.to_owned(), context),
         }
     }
 }
 
+impl<'a, E: Error> From<&'a str> for InviteError<E> {
+    fn from(s: &'a str) -> Self {
+        Self::new(s, crate::ErrorContext::default())
+    }
+}
+
 impl<E: Error> fmt::Display for InviteError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            InviteError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            InviteError::AlreadyInChannel => write!(f, "Server returned error already_in_channel"),
-            InviteError::CantInvite => write!(f, "Server returned error cant_invite"),
-            InviteError::CantInviteSelf => write!(f, "Server returned error cant_invite_self"),
-            InviteError::ChannelNotFound => write!(f, "Server returned error channel_not_found"),
-            InviteError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            InviteError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            InviteError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            InviteError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            InviteError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            InviteError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            InviteError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            InviteError::IsArchived => write!(f, "Server returned error is_archived"),
-            InviteError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            InviteError::MethodNotSupportedForChannelType => write!(
+            InviteError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            InviteError::AlreadyInChannel(_) => {
+                write!(f, "Server returned error already_in_channel")
+            }
+            InviteError::CantInvite(_) => write!(f, "Server returned error cant_invite"),
+            InviteError::CantInviteSelf(_) => write!(f, "Server returned error cant_invite_self"),
+            InviteError::ChannelNotFound(_) => write!(f, "Server returned error channel_not_found"),
+            InviteError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            InviteError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            InviteError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            InviteError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            InviteError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            InviteError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            InviteError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            InviteError::IsArchived(_) => write!(f, "Server returned error is_archived"),
+            InviteError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            InviteError::MethodNotSupportedForChannelType(_) => write!(
                 f,
                 "Server returned error method_not_supported_for_channel_type"
             ),
-            InviteError::MissingCharset => write!(f, "Server returned error missing_charset"),
-            InviteError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            InviteError::MissingScope => write!(f, "Server returned error missing_scope"),
-            InviteError::NoUser => write!(f, "Server returned error no_user"),
-            InviteError::NotAuthed => write!(f, "Server returned error not_authed"),
-            InviteError::NotInChannel => write!(f, "Server returned error not_in_channel"),
-            InviteError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            InviteError::SuperfluousCharset => {
+            InviteError::MissingCharset(_) => write!(f, "Server returned error missing_charset"),
+            InviteError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            InviteError::MissingScope(_) => write!(f, "Server returned error missing_scope"),
+            InviteError::NoUser(_) => write!(f, "Server returned error no_user"),
+            InviteError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            InviteError::NotInChannel(_) => write!(f, "Server returned error not_in_channel"),
+            InviteError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            InviteError::SuperfluousCharset(_) => {
                 write!(f, "Server returned error superfluous_charset")
             }
-            InviteError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            InviteError::TooManyUsers => write!(f, "Server returned error too_many_users"),
-            InviteError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
-            InviteError::UraMaxChannels => write!(f, "Server returned error ura_max_channels"),
-            InviteError::UserIsBot => write!(f, "Server returned error user_is_bot"),
-            InviteError::UserIsRestricted => write!(f, "Server returned error user_is_restricted"),
-            InviteError::UserIsUltraRestricted => {
+            InviteError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            InviteError::TooManyUsers(_) => write!(f, "Server returned error too_many_users"),
+            InviteError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
+            InviteError::UraMaxChannels(_) => write!(f, "Server returned error ura_max_channels"),
+            InviteError::UserIsBot(_) => write!(f, "Server returned error user_is_bot"),
+            InviteError::UserIsRestricted(_) => {
+                write!(f, "Server returned error user_is_restricted")
+            }
+            InviteError::UserIsUltraRestricted(_) => {
                 write!(f, "Server returned error user_is_ultra_restricted")
             }
-            InviteError::UserNotFound => write!(f, "Server returned error user_not_found"),
+            InviteError::UserNotFound(_) => write!(f, "Server returned error user_not_found"),
             InviteError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            InviteError::Unknown(ref s) => write!(f, "{}", s),
+            InviteError::Unknown(ref s, _) => write!(f, "{}", s),
             InviteError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -2562,19 +3183,109 @@ impl<E: Error + 'static> Error for InviteError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for InviteError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            InviteError::AccountInactive(_) => Some("account_inactive"),
+            InviteError::AlreadyInChannel(_) => Some("already_in_channel"),
+            InviteError::CantInvite(_) => Some("cant_invite"),
+            InviteError::CantInviteSelf(_) => Some("cant_invite_self"),
+            InviteError::ChannelNotFound(_) => Some("channel_not_found"),
+            InviteError::InvalidArgName(_) => Some("invalid_arg_name"),
+            InviteError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            InviteError::InvalidAuth(_) => Some("invalid_auth"),
+            InviteError::InvalidCharset(_) => Some("invalid_charset"),
+            InviteError::InvalidFormData(_) => Some("invalid_form_data"),
+            InviteError::InvalidJson(_) => Some("invalid_json"),
+            InviteError::InvalidPostType(_) => Some("invalid_post_type"),
+            InviteError::IsArchived(_) => Some("is_archived"),
+            InviteError::JsonNotObject(_) => Some("json_not_object"),
+            InviteError::MethodNotSupportedForChannelType(_) => {
+                Some("method_not_supported_for_channel_type")
+            }
+            InviteError::MissingCharset(_) => Some("missing_charset"),
+            InviteError::MissingPostType(_) => Some("missing_post_type"),
+            InviteError::MissingScope(_) => Some("missing_scope"),
+            InviteError::NoUser(_) => Some("no_user"),
+            InviteError::NotAuthed(_) => Some("not_authed"),
+            InviteError::NotInChannel(_) => Some("not_in_channel"),
+            InviteError::RequestTimeout(_) => Some("request_timeout"),
+            InviteError::SuperfluousCharset(_) => Some("superfluous_charset"),
+            InviteError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            InviteError::TooManyUsers(_) => Some("too_many_users"),
+            InviteError::UpgradeRequired(_) => Some("upgrade_required"),
+            InviteError::UraMaxChannels(_) => Some("ura_max_channels"),
+            InviteError::UserIsBot(_) => Some("user_is_bot"),
+            InviteError::UserIsRestricted(_) => Some("user_is_restricted"),
+            InviteError::UserIsUltraRestricted(_) => Some("user_is_ultra_restricted"),
+            InviteError::UserNotFound(_) => Some("user_not_found"),
+            InviteError::Unknown(ref s, _) => Some(s),
+            InviteError::MalformedResponse(..) | InviteError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            InviteError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            InviteError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            InviteError::AccountInactive(ref context)
+            | InviteError::AlreadyInChannel(ref context)
+            | InviteError::CantInvite(ref context)
+            | InviteError::CantInviteSelf(ref context)
+            | InviteError::ChannelNotFound(ref context)
+            | InviteError::InvalidArgName(ref context)
+            | InviteError::InvalidArrayArg(ref context)
+            | InviteError::InvalidAuth(ref context)
+            | InviteError::InvalidCharset(ref context)
+            | InviteError::InvalidFormData(ref context)
+            | InviteError::InvalidJson(ref context)
+            | InviteError::InvalidPostType(ref context)
+            | InviteError::IsArchived(ref context)
+            | InviteError::JsonNotObject(ref context)
+            | InviteError::MethodNotSupportedForChannelType(ref context)
+            | InviteError::MissingCharset(ref context)
+            | InviteError::MissingPostType(ref context)
+            | InviteError::MissingScope(ref context)
+            | InviteError::NoUser(ref context)
+            | InviteError::NotAuthed(ref context)
+            | InviteError::NotInChannel(ref context)
+            | InviteError::RequestTimeout(ref context)
+            | InviteError::SuperfluousCharset(ref context)
+            | InviteError::TeamAddedToOrg(ref context)
+            | InviteError::TooManyUsers(ref context)
+            | InviteError::UpgradeRequired(ref context)
+            | InviteError::UraMaxChannels(ref context)
+            | InviteError::UserIsBot(ref context)
+            | InviteError::UserIsRestricted(ref context)
+            | InviteError::UserIsUltraRestricted(ref context)
+            | InviteError::UserNotFound(ref context)
+            | InviteError::Unknown(_, ref context) => Some(context),
+            InviteError::MalformedResponse(..) | InviteError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
-pub struct JoinRequest<'a> {
+pub struct JoinRequest {
     /// ID of conversation to join
-    pub channel: Option<Cow<'a, str>>,
+    pub channel: Option<crate::ChannelId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct JoinDisplayCountsInner {
     pub display_counts: u64,
     pub guest_counts: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct JoinAttachmentsInner {
     pub fallback: Option<String>,
     pub id: u64,
@@ -2584,37 +3295,32 @@ pub struct JoinAttachmentsInner {
     pub image_width: Option<u64>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct JoinBlocksInner {
-    pub r#type: String,
-}
-
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct JoinIconsInner {
     pub image_36: String,
     pub image_48: String,
     pub image_72: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct JoinBotProfileInner {
-    pub app_id: String,
+    pub app_id: crate::AppId,
     pub deleted: bool,
     pub icons: JoinIconsInner,
-    pub id: String,
+    pub id: crate::BotId,
     pub name: String,
-    pub team_id: String,
+    pub team_id: crate::TeamId,
     pub updated: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct JoinReactionsInner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct JoinCommentInner {
     pub comment: String,
     pub created: u64,
@@ -2626,23 +3332,23 @@ pub struct JoinCommentInner {
     pub pinned_to: Option<Vec<String>>,
     pub reactions: Option<Vec<JoinReactionsInner>>,
     pub timestamp: u64,
-    pub user: String,
+    pub user: crate::UserId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct JoinReactions1Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct JoinSharesInner {
     pub private: Option<serde_json::Value>,
     pub public: Option<serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct JoinFileInner {
     pub channels: Option<Vec<String>>,
     pub comments_count: Option<u64>,
@@ -2657,7 +3363,7 @@ pub struct JoinFileInner {
     pub filetype: Option<String>,
     pub groups: Option<Vec<String>>,
     pub has_rich_preview: Option<bool>,
-    pub id: Option<String>,
+    pub id: Option<crate::FileId>,
     pub image_exif_rotation: Option<u64>,
     pub ims: Option<Vec<String>>,
     pub is_external: Option<bool>,
@@ -2711,25 +3417,25 @@ pub struct JoinFileInner {
     pub updated: Option<u64>,
     pub url_private: Option<String>,
     pub url_private_download: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct JoinReactions2Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct JoinShares1Inner {
     pub private: Option<serde_json::Value>,
     pub public: Option<serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct JoinFilesInner {
     pub channels: Option<Vec<String>>,
     pub comments_count: Option<u64>,
@@ -2744,7 +3450,7 @@ pub struct JoinFilesInner {
     pub filetype: Option<String>,
     pub groups: Option<Vec<String>>,
     pub has_rich_preview: Option<bool>,
-    pub id: Option<String>,
+    pub id: Option<crate::FileId>,
     pub image_exif_rotation: Option<u64>,
     pub ims: Option<Vec<String>>,
     pub is_external: Option<bool>,
@@ -2798,25 +3504,25 @@ pub struct JoinFilesInner {
     pub updated: Option<u64>,
     pub url_private: Option<String>,
     pub url_private_download: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct JoinIcons1Inner {
     pub emoji: Option<String>,
     pub image_64: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct JoinReactions3Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct JoinUserProfileInner {
     pub avatar_hash: String,
     pub display_name: String,
@@ -2828,13 +3534,13 @@ pub struct JoinUserProfileInner {
     pub name: String,
     pub real_name: String,
     pub real_name_normalized: Option<String>,
-    pub team: String,
+    pub team: crate::TeamId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct JoinLatestInner {
     pub attachments: Option<Vec<JoinAttachmentsInner>>,
-    pub blocks: Option<Vec<JoinBlocksInner>>,
+    pub blocks: Option<Vec<crate::blocks::Block>>,
     pub bot_id: Option<Vec<String>>,
     pub bot_profile: Option<JoinBotProfileInner>,
     pub client_msg_id: Option<String>,
@@ -2847,8 +3553,8 @@ pub struct JoinLatestInner {
     pub is_delayed_message: Option<bool>,
     pub is_intro: Option<bool>,
     pub is_starred: Option<bool>,
-    pub last_read: Option<String>,
-    pub latest_reply: Option<String>,
+    pub last_read: Option<crate::Timestamp>,
+    pub latest_reply: Option<crate::Timestamp>,
     pub name: Option<String>,
     pub old_name: Option<String>,
     pub parent_user_id: Option<String>,
@@ -2862,40 +3568,40 @@ pub struct JoinLatestInner {
     pub source_team: Option<String>,
     pub subscribed: Option<bool>,
     pub subtype: Option<String>,
-    pub team: Option<String>,
+    pub team: Option<crate::TeamId>,
     pub text: String,
-    pub thread_ts: Option<String>,
+    pub thread_ts: Option<crate::Timestamp>,
     pub topic: Option<String>,
-    pub ts: String,
+    pub ts: crate::Timestamp,
     pub r#type: String,
     pub unread_count: Option<u64>,
     pub upload: Option<bool>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_profile: Option<JoinUserProfileInner>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct JoinPurposeInner {
     pub creator: String,
     pub last_set: u64,
     pub value: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct JoinCurrentInner {
     pub date_started: u64,
-    pub team_id: String,
+    pub team_id: crate::TeamId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct JoinExternalOrgMigrationsInner {
     pub current: Vec<JoinCurrentInner>,
     pub date_updated: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct JoinIconInner {
     pub image_102: Option<String>,
     pub image_132: Option<String>,
@@ -2907,20 +3613,20 @@ pub struct JoinIconInner {
     pub image_default: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct JoinPrimaryOwnerInner {
     pub email: String,
-    pub id: String,
+    pub id: crate::UserId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct JoinSsoProviderInner {
     pub label: Option<String>,
     pub name: Option<String>,
     pub r#type: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct JoinTeamInner {
     pub archived: Option<bool>,
     pub avatar_base_url: Option<String>,
@@ -2931,12 +3637,12 @@ pub struct JoinTeamInner {
     pub discoverable: Option<Vec<String>>,
     pub domain: String,
     pub email_domain: String,
-    pub enterprise_id: Option<String>,
+    pub enterprise_id: Option<crate::EnterpriseId>,
     pub enterprise_name: Option<String>,
     pub external_org_migrations: Option<JoinExternalOrgMigrationsInner>,
     pub has_compliance_export: Option<bool>,
     pub icon: JoinIconInner,
-    pub id: String,
+    pub id: crate::TeamId,
     pub is_assigned: Option<bool>,
     pub is_enterprise: Option<u64>,
     pub is_over_storage_limit: Option<bool>,
@@ -2953,22 +3659,22 @@ pub struct JoinTeamInner {
     pub sso_provider: Option<JoinSsoProviderInner>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct JoinShares2Inner {
     pub accepted_user: Option<String>,
     pub is_active: bool,
     pub team: JoinTeamInner,
-    pub user: String,
+    pub user: crate::UserId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct JoinTopicInner {
     pub creator: String,
     pub last_set: u64,
     pub value: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct JoinChannelInner {
     pub accepted_user: Option<String>,
     pub connected_team_ids: Option<Vec<String>>,
@@ -2976,9 +3682,9 @@ pub struct JoinChannelInner {
     pub created: u64,
     pub creator: String,
     pub display_counts: Option<JoinDisplayCountsInner>,
-    pub enterprise_id: Option<String>,
+    pub enterprise_id: Option<crate::EnterpriseId>,
     pub has_pins: Option<bool>,
-    pub id: String,
+    pub id: crate::ChannelId,
     pub internal_team_ids: Option<Vec<String>>,
     pub is_archived: bool,
     pub is_channel: bool,
@@ -3002,7 +3708,7 @@ pub struct JoinChannelInner {
     pub is_shared: bool,
     pub is_starred: Option<bool>,
     pub is_thread_only: Option<bool>,
-    pub last_read: Option<String>,
+    pub last_read: Option<crate::Timestamp>,
     pub latest: Option<Vec<JoinLatestInner>>,
     pub members: Option<Vec<String>>,
     pub name: String,
@@ -3023,16 +3729,17 @@ pub struct JoinChannelInner {
     pub unread_count: Option<u64>,
     pub unread_count_display: Option<u64>,
     pub use_case: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub version: Option<u64>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct JoinResponseMetadataInner {
+    pub messages: Option<Vec<String>>,
     pub warnings: Option<Vec<String>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct JoinResponse {
     pub callstack: Option<String>,
     pub channel: Vec<JoinChannelInner>,
@@ -3043,6 +3750,8 @@ pub struct JoinResponse {
     pub provided: Option<String>,
     pub response_metadata: Option<JoinResponseMetadataInner>,
     pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<JoinResponse, JoinError<E>>> for JoinResponse {
@@ -3050,108 +3759,147 @@ impl<E: Error> Into<Result<JoinResponse, JoinError<E>>> for JoinResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(JoinError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for JoinResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum JoinError<E: Error> {
-    AccountInactive,
-    ChannelNotFound,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    IsArchived,
-    JsonNotObject,
-    MethodNotSupportedForChannelType,
-    MissingCharset,
-    MissingPostType,
-    MissingScope,
-    NotAuthed,
-    RequestTimeout,
-    SuperfluousCharset,
-    TeamAddedToOrg,
-    UpgradeRequired,
-    UserIsBot,
-    UserIsRestricted,
-    UserIsUltraRestricted,
+    AccountInactive(crate::ErrorContext),
+    ChannelNotFound(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    IsArchived(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MethodNotSupportedForChannelType(crate::ErrorContext),
+    MissingCharset(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    MissingScope(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    SuperfluousCharset(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserIsBot(crate::ErrorContext),
+    UserIsRestricted(crate::ErrorContext),
+    UserIsUltraRestricted(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> JoinError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => JoinError::AccountInactive(context),
+            "channel_not_found" => JoinError::ChannelNotFound(context),
+            "invalid_arg_name" => JoinError::InvalidArgName(context),
+            "invalid_array_arg" => JoinError::InvalidArrayArg(context),
+            "invalid_auth" => JoinError::InvalidAuth(context),
+            "invalid_charset" => JoinError::InvalidCharset(context),
+            "invalid_form_data" => JoinError::InvalidFormData(context),
+            "invalid_json" => JoinError::InvalidJson(context),
+            "invalid_post_type" => JoinError::InvalidPostType(context),
+            "is_archived" => JoinError::IsArchived(context),
+            "json_not_object" => JoinError::JsonNotObject(context),
+            "method_not_supported_for_channel_type" => {
+                JoinError::MethodNotSupportedForChannelType(context)
+            }
+            "missing_charset" => JoinError::MissingCharset(context),
+            "missing_post_type" => JoinError::MissingPostType(context),
+            "missing_scope" => JoinError::MissingScope(context),
+            "not_authed" => JoinError::NotAuthed(context),
+            "request_timeout" => JoinError::RequestTimeout(context),
+            "superfluous_charset" => JoinError::SuperfluousCharset(context),
+            "team_added_to_org" => JoinError::TeamAddedToOrg(context),
+            "upgrade_required" => JoinError::UpgradeRequired(context),
+            "user_is_bot" => JoinError::UserIsBot(context),
+            "user_is_restricted" => JoinError::UserIsRestricted(context),
+            "user_is_ultra_restricted" => JoinError::UserIsUltraRestricted(context),
+            _ => JoinError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for JoinError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => JoinError::AccountInactive,
-            "channel_not_found" => JoinError::ChannelNotFound,
-            "invalid_arg_name" => JoinError::InvalidArgName,
-            "invalid_array_arg" => JoinError::InvalidArrayArg,
-            "invalid_auth" => JoinError::InvalidAuth,
-            "invalid_charset" => JoinError::InvalidCharset,
-            "invalid_form_data" => JoinError::InvalidFormData,
-            "invalid_json" => JoinError::InvalidJson,
-            "invalid_post_type" => JoinError::InvalidPostType,
-            "is_archived" => JoinError::IsArchived,
-            "json_not_object" => JoinError::JsonNotObject,
-            "method_not_supported_for_channel_type" => JoinError::MethodNotSupportedForChannelType,
-            "missing_charset" => JoinError::MissingCharset,
-            "missing_post_type" => JoinError::MissingPostType,
-            "missing_scope" => JoinError::MissingScope,
-            "not_authed" => JoinError::NotAuthed,
-            "request_timeout" => JoinError::RequestTimeout,
-            "superfluous_charset" => JoinError::SuperfluousCharset,
-            "team_added_to_org" => JoinError::TeamAddedToOrg,
-            "upgrade_required" => JoinError::UpgradeRequired,
-            "user_is_bot" => JoinError::UserIsBot,
-            "user_is_restricted" => JoinError::UserIsRestricted,
-            "user_is_ultra_restricted" => JoinError::UserIsUltraRestricted,
-            _ => JoinError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for JoinError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            JoinError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            JoinError::ChannelNotFound => write!(f, "Server returned error channel_not_found"),
-            JoinError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            JoinError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            JoinError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            JoinError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            JoinError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            JoinError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            JoinError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            JoinError::IsArchived => write!(f, "Server returned error is_archived"),
-            JoinError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            JoinError::MethodNotSupportedForChannelType => write!(
+            JoinError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            JoinError::ChannelNotFound(_) => write!(f, "Server returned error channel_not_found"),
+            JoinError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            JoinError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            JoinError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            JoinError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            JoinError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            JoinError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            JoinError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            JoinError::IsArchived(_) => write!(f, "Server returned error is_archived"),
+            JoinError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            JoinError::MethodNotSupportedForChannelType(_) => write!(
                 f,
                 "Server returned error method_not_supported_for_channel_type"
             ),
-            JoinError::MissingCharset => write!(f, "Server returned error missing_charset"),
-            JoinError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            JoinError::MissingScope => write!(f, "Server returned error missing_scope"),
-            JoinError::NotAuthed => write!(f, "Server returned error not_authed"),
-            JoinError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            JoinError::SuperfluousCharset => write!(f, "Server returned error superfluous_charset"),
-            JoinError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            JoinError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
-            JoinError::UserIsBot => write!(f, "Server returned error user_is_bot"),
-            JoinError::UserIsRestricted => write!(f, "Server returned error user_is_restricted"),
-            JoinError::UserIsUltraRestricted => {
+            JoinError::MissingCharset(_) => write!(f, "Server returned error missing_charset"),
+            JoinError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            JoinError::MissingScope(_) => write!(f, "Server returned error missing_scope"),
+            JoinError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            JoinError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            JoinError::SuperfluousCharset(_) => {
+                write!(f, "Server returned error superfluous_charset")
+            }
+            JoinError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            JoinError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
+            JoinError::UserIsBot(_) => write!(f, "Server returned error user_is_bot"),
+            JoinError::UserIsRestricted(_) => write!(f, "Server returned error user_is_restricted"),
+            JoinError::UserIsUltraRestricted(_) => {
                 write!(f, "Server returned error user_is_ultra_restricted")
             }
             JoinError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            JoinError::Unknown(ref s) => write!(f, "{}", s),
+            JoinError::Unknown(ref s, _) => write!(f, "{}", s),
             JoinError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -3167,22 +3915,100 @@ impl<E: Error + 'static> Error for JoinError<E> {
     }
 }
 
-#[derive(Clone, Default, Debug)]
-pub struct KickRequest<'a> {
-    /// ID of conversation to remove user from.
-    pub channel: Option<Cow<'a, str>>,
-    /// User ID to be removed.
-    pub user: Option<Cow<'a, str>>,
-}
+impl<E: Error + 'static> crate::MethodError for JoinError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            JoinError::AccountInactive(_) => Some("account_inactive"),
+            JoinError::ChannelNotFound(_) => Some("channel_not_found"),
+            JoinError::InvalidArgName(_) => Some("invalid_arg_name"),
+            JoinError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            JoinError::InvalidAuth(_) => Some("invalid_auth"),
+            JoinError::InvalidCharset(_) => Some("invalid_charset"),
+            JoinError::InvalidFormData(_) => Some("invalid_form_data"),
+            JoinError::InvalidJson(_) => Some("invalid_json"),
+            JoinError::InvalidPostType(_) => Some("invalid_post_type"),
+            JoinError::IsArchived(_) => Some("is_archived"),
+            JoinError::JsonNotObject(_) => Some("json_not_object"),
+            JoinError::MethodNotSupportedForChannelType(_) => {
+                Some("method_not_supported_for_channel_type")
+            }
+            JoinError::MissingCharset(_) => Some("missing_charset"),
+            JoinError::MissingPostType(_) => Some("missing_post_type"),
+            JoinError::MissingScope(_) => Some("missing_scope"),
+            JoinError::NotAuthed(_) => Some("not_authed"),
+            JoinError::RequestTimeout(_) => Some("request_timeout"),
+            JoinError::SuperfluousCharset(_) => Some("superfluous_charset"),
+            JoinError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            JoinError::UpgradeRequired(_) => Some("upgrade_required"),
+            JoinError::UserIsBot(_) => Some("user_is_bot"),
+            JoinError::UserIsRestricted(_) => Some("user_is_restricted"),
+            JoinError::UserIsUltraRestricted(_) => Some("user_is_ultra_restricted"),
+            JoinError::Unknown(ref s, _) => Some(s),
+            JoinError::MalformedResponse(..) | JoinError::Client(_) => None,
+        }
+    }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct KickResponse {
-    pub callstack: Option<String>,
-    error: Option<String>,
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            JoinError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            JoinError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            JoinError::AccountInactive(ref context)
+            | JoinError::ChannelNotFound(ref context)
+            | JoinError::InvalidArgName(ref context)
+            | JoinError::InvalidArrayArg(ref context)
+            | JoinError::InvalidAuth(ref context)
+            | JoinError::InvalidCharset(ref context)
+            | JoinError::InvalidFormData(ref context)
+            | JoinError::InvalidJson(ref context)
+            | JoinError::InvalidPostType(ref context)
+            | JoinError::IsArchived(ref context)
+            | JoinError::JsonNotObject(ref context)
+            | JoinError::MethodNotSupportedForChannelType(ref context)
+            | JoinError::MissingCharset(ref context)
+            | JoinError::MissingPostType(ref context)
+            | JoinError::MissingScope(ref context)
+            | JoinError::NotAuthed(ref context)
+            | JoinError::RequestTimeout(ref context)
+            | JoinError::SuperfluousCharset(ref context)
+            | JoinError::TeamAddedToOrg(ref context)
+            | JoinError::UpgradeRequired(ref context)
+            | JoinError::UserIsBot(ref context)
+            | JoinError::UserIsRestricted(ref context)
+            | JoinError::UserIsUltraRestricted(ref context)
+            | JoinError::Unknown(_, ref context) => Some(context),
+            JoinError::MalformedResponse(..) | JoinError::Client(_) => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct KickRequest {
+    /// ID of conversation to remove user from.
+    pub channel: Option<crate::ChannelId>,
+    /// User ID to be removed.
+    pub user: Option<crate::UserId>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct KickResponse {
+    pub callstack: Option<String>,
+    error: Option<String>,
     pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
     pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<KickResponse, KickError<E>>> for KickResponse {
@@ -3190,108 +4016,145 @@ impl<E: Error> Into<Result<KickResponse, KickError<E>>> for KickResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(KickError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for KickResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum KickError<E: Error> {
-    AccountInactive,
-    CantKickFromGeneral,
-    CantKickSelf,
-    ChannelNotFound,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MethodNotSupportedForChannelType,
-    MissingPostType,
-    MissingScope,
-    NotAuthed,
-    NotInChannel,
-    RequestTimeout,
-    RestrictedAction,
-    UpgradeRequired,
-    UserIsBot,
-    UserIsRestricted,
-    UserNotFound,
+    AccountInactive(crate::ErrorContext),
+    CantKickFromGeneral(crate::ErrorContext),
+    CantKickSelf(crate::ErrorContext),
+    ChannelNotFound(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MethodNotSupportedForChannelType(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    MissingScope(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    NotInChannel(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    RestrictedAction(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserIsBot(crate::ErrorContext),
+    UserIsRestricted(crate::ErrorContext),
+    UserNotFound(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> KickError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => KickError::AccountInactive(context),
+            "cant_kick_from_general" => KickError::CantKickFromGeneral(context),
+            "cant_kick_self" => KickError::CantKickSelf(context),
+            "channel_not_found" => KickError::ChannelNotFound(context),
+            "invalid_arg_name" => KickError::InvalidArgName(context),
+            "invalid_array_arg" => KickError::InvalidArrayArg(context),
+            "invalid_auth" => KickError::InvalidAuth(context),
+            "invalid_charset" => KickError::InvalidCharset(context),
+            "invalid_form_data" => KickError::InvalidFormData(context),
+            "invalid_json" => KickError::InvalidJson(context),
+            "invalid_post_type" => KickError::InvalidPostType(context),
+            "json_not_object" => KickError::JsonNotObject(context),
+            "method_not_supported_for_channel_type" => {
+                KickError::MethodNotSupportedForChannelType(context)
+            }
+            "missing_post_type" => KickError::MissingPostType(context),
+            "missing_scope" => KickError::MissingScope(context),
+            "not_authed" => KickError::NotAuthed(context),
+            "not_in_channel" => KickError::NotInChannel(context),
+            "request_timeout" => KickError::RequestTimeout(context),
+            "restricted_action" => KickError::RestrictedAction(context),
+            "upgrade_required" => KickError::UpgradeRequired(context),
+            "user_is_bot" => KickError::UserIsBot(context),
+            "user_is_restricted" => KickError::UserIsRestricted(context),
+            "user_not_found" => KickError::UserNotFound(context),
+            _ => KickError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for KickError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => KickError::AccountInactive,
-            "cant_kick_from_general" => KickError::CantKickFromGeneral,
-            "cant_kick_self" => KickError::CantKickSelf,
-            "channel_not_found" => KickError::ChannelNotFound,
-            "invalid_arg_name" => KickError::InvalidArgName,
-            "invalid_array_arg" => KickError::InvalidArrayArg,
-            "invalid_auth" => KickError::InvalidAuth,
-            "invalid_charset" => KickError::InvalidCharset,
-            "invalid_form_data" => KickError::InvalidFormData,
-            "invalid_json" => KickError::InvalidJson,
-            "invalid_post_type" => KickError::InvalidPostType,
-            "json_not_object" => KickError::JsonNotObject,
-            "method_not_supported_for_channel_type" => KickError::MethodNotSupportedForChannelType,
-            "missing_post_type" => KickError::MissingPostType,
-            "missing_scope" => KickError::MissingScope,
-            "not_authed" => KickError::NotAuthed,
-            "not_in_channel" => KickError::NotInChannel,
-            "request_timeout" => KickError::RequestTimeout,
-            "restricted_action" => KickError::RestrictedAction,
-            "upgrade_required" => KickError::UpgradeRequired,
-            "user_is_bot" => KickError::UserIsBot,
-            "user_is_restricted" => KickError::UserIsRestricted,
-            "user_not_found" => KickError::UserNotFound,
-            _ => KickError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for KickError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            KickError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            KickError::CantKickFromGeneral => {
+            KickError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            KickError::CantKickFromGeneral(_) => {
                 write!(f, "Server returned error cant_kick_from_general")
             }
-            KickError::CantKickSelf => write!(f, "Server returned error cant_kick_self"),
-            KickError::ChannelNotFound => write!(f, "Server returned error channel_not_found"),
-            KickError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            KickError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            KickError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            KickError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            KickError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            KickError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            KickError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            KickError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            KickError::MethodNotSupportedForChannelType => write!(
+            KickError::CantKickSelf(_) => write!(f, "Server returned error cant_kick_self"),
+            KickError::ChannelNotFound(_) => write!(f, "Server returned error channel_not_found"),
+            KickError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            KickError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            KickError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            KickError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            KickError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            KickError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            KickError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            KickError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            KickError::MethodNotSupportedForChannelType(_) => write!(
                 f,
                 "Server returned error method_not_supported_for_channel_type"
             ),
-            KickError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            KickError::MissingScope => write!(f, "Server returned error missing_scope"),
-            KickError::NotAuthed => write!(f, "Server returned error not_authed"),
-            KickError::NotInChannel => write!(f, "Server returned error not_in_channel"),
-            KickError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            KickError::RestrictedAction => write!(f, "Server returned error restricted_action"),
-            KickError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
-            KickError::UserIsBot => write!(f, "Server returned error user_is_bot"),
-            KickError::UserIsRestricted => write!(f, "Server returned error user_is_restricted"),
-            KickError::UserNotFound => write!(f, "Server returned error user_not_found"),
+            KickError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            KickError::MissingScope(_) => write!(f, "Server returned error missing_scope"),
+            KickError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            KickError::NotInChannel(_) => write!(f, "Server returned error not_in_channel"),
+            KickError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            KickError::RestrictedAction(_) => write!(f, "Server returned error restricted_action"),
+            KickError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
+            KickError::UserIsBot(_) => write!(f, "Server returned error user_is_bot"),
+            KickError::UserIsRestricted(_) => write!(f, "Server returned error user_is_restricted"),
+            KickError::UserNotFound(_) => write!(f, "Server returned error user_not_found"),
             KickError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            KickError::Unknown(ref s) => write!(f, "{}", s),
+            KickError::Unknown(ref s, _) => write!(f, "{}", s),
             KickError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -3307,13 +4170,87 @@ impl<E: Error + 'static> Error for KickError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for KickError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            KickError::AccountInactive(_) => Some("account_inactive"),
+            KickError::CantKickFromGeneral(_) => Some("cant_kick_from_general"),
+            KickError::CantKickSelf(_) => Some("cant_kick_self"),
+            KickError::ChannelNotFound(_) => Some("channel_not_found"),
+            KickError::InvalidArgName(_) => Some("invalid_arg_name"),
+            KickError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            KickError::InvalidAuth(_) => Some("invalid_auth"),
+            KickError::InvalidCharset(_) => Some("invalid_charset"),
+            KickError::InvalidFormData(_) => Some("invalid_form_data"),
+            KickError::InvalidJson(_) => Some("invalid_json"),
+            KickError::InvalidPostType(_) => Some("invalid_post_type"),
+            KickError::JsonNotObject(_) => Some("json_not_object"),
+            KickError::MethodNotSupportedForChannelType(_) => {
+                Some("method_not_supported_for_channel_type")
+            }
+            KickError::MissingPostType(_) => Some("missing_post_type"),
+            KickError::MissingScope(_) => Some("missing_scope"),
+            KickError::NotAuthed(_) => Some("not_authed"),
+            KickError::NotInChannel(_) => Some("not_in_channel"),
+            KickError::RequestTimeout(_) => Some("request_timeout"),
+            KickError::RestrictedAction(_) => Some("restricted_action"),
+            KickError::UpgradeRequired(_) => Some("upgrade_required"),
+            KickError::UserIsBot(_) => Some("user_is_bot"),
+            KickError::UserIsRestricted(_) => Some("user_is_restricted"),
+            KickError::UserNotFound(_) => Some("user_not_found"),
+            KickError::Unknown(ref s, _) => Some(s),
+            KickError::MalformedResponse(..) | KickError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            KickError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            KickError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            KickError::AccountInactive(ref context)
+            | KickError::CantKickFromGeneral(ref context)
+            | KickError::CantKickSelf(ref context)
+            | KickError::ChannelNotFound(ref context)
+            | KickError::InvalidArgName(ref context)
+            | KickError::InvalidArrayArg(ref context)
+            | KickError::InvalidAuth(ref context)
+            | KickError::InvalidCharset(ref context)
+            | KickError::InvalidFormData(ref context)
+            | KickError::InvalidJson(ref context)
+            | KickError::InvalidPostType(ref context)
+            | KickError::JsonNotObject(ref context)
+            | KickError::MethodNotSupportedForChannelType(ref context)
+            | KickError::MissingPostType(ref context)
+            | KickError::MissingScope(ref context)
+            | KickError::NotAuthed(ref context)
+            | KickError::NotInChannel(ref context)
+            | KickError::RequestTimeout(ref context)
+            | KickError::RestrictedAction(ref context)
+            | KickError::UpgradeRequired(ref context)
+            | KickError::UserIsBot(ref context)
+            | KickError::UserIsRestricted(ref context)
+            | KickError::UserNotFound(ref context)
+            | KickError::Unknown(_, ref context) => Some(context),
+            KickError::MalformedResponse(..) | KickError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
-pub struct LeaveRequest<'a> {
+pub struct LeaveRequest {
     /// Conversation to leave
-    pub channel: Option<Cow<'a, str>>,
+    pub channel: Option<crate::ChannelId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct LeaveResponse {
     pub callstack: Option<String>,
     error: Option<String>,
@@ -3322,6 +4259,10 @@ pub struct LeaveResponse {
     #[serde(default)]
     ok: bool,
     pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<LeaveResponse, LeaveError<E>>> for LeaveResponse {
@@ -3329,116 +4270,157 @@ impl<E: Error> Into<Result<LeaveResponse, LeaveError<E>>> for LeaveResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(LeaveError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for LeaveResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum LeaveError<E: Error> {
-    AccountInactive,
-    CantLeaveGeneral,
-    ChannelNotFound,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    IsArchived,
-    JsonNotObject,
-    LastMember,
-    MethodNotSupportedForChannelType,
-    MissingCharset,
-    MissingPostType,
-    MissingScope,
-    NotAuthed,
-    RequestTimeout,
-    SuperfluousCharset,
-    TeamAddedToOrg,
-    UpgradeRequired,
-    UserIsBot,
-    UserIsRestricted,
-    UserIsUltraRestricted,
+    AccountInactive(crate::ErrorContext),
+    CantLeaveGeneral(crate::ErrorContext),
+    ChannelNotFound(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    IsArchived(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    LastMember(crate::ErrorContext),
+    MethodNotSupportedForChannelType(crate::ErrorContext),
+    MissingCharset(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    MissingScope(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    SuperfluousCharset(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserIsBot(crate::ErrorContext),
+    UserIsRestricted(crate::ErrorContext),
+    UserIsUltraRestricted(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> LeaveError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => LeaveError::AccountInactive(context),
+            "cant_leave_general" => LeaveError::CantLeaveGeneral(context),
+            "channel_not_found" => LeaveError::ChannelNotFound(context),
+            "invalid_arg_name" => LeaveError::InvalidArgName(context),
+            "invalid_array_arg" => LeaveError::InvalidArrayArg(context),
+            "invalid_auth" => LeaveError::InvalidAuth(context),
+            "invalid_charset" => LeaveError::InvalidCharset(context),
+            "invalid_form_data" => LeaveError::InvalidFormData(context),
+            "invalid_json" => LeaveError::InvalidJson(context),
+            "invalid_post_type" => LeaveError::InvalidPostType(context),
+            "is_archived" => LeaveError::IsArchived(context),
+            "json_not_object" => LeaveError::JsonNotObject(context),
+            "last_member" => LeaveError::LastMember(context),
+            "method_not_supported_for_channel_type" => {
+                LeaveError::MethodNotSupportedForChannelType(context)
+            }
+            "missing_charset" => LeaveError::MissingCharset(context),
+            "missing_post_type" => LeaveError::MissingPostType(context),
+            "missing_scope" => LeaveError::MissingScope(context),
+            "not_authed" => LeaveError::NotAuthed(context),
+            "request_timeout" => LeaveError::RequestTimeout(context),
+            "superfluous_charset" => LeaveError::SuperfluousCharset(context),
+            "team_added_to_org" => LeaveError::TeamAddedToOrg(context),
+            "upgrade_required" => LeaveError::UpgradeRequired(context),
+            "user_is_bot" => LeaveError::UserIsBot(context),
+            "user_is_restricted" => LeaveError::UserIsRestricted(context),
+            "user_is_ultra_restricted" => LeaveError::UserIsUltraRestricted(context),
+            _ => LeaveError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for LeaveError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => LeaveError::AccountInactive,
-            "cant_leave_general" => LeaveError::CantLeaveGeneral,
-            "channel_not_found" => LeaveError::ChannelNotFound,
-            "invalid_arg_name" => LeaveError::InvalidArgName,
-            "invalid_array_arg" => LeaveError::InvalidArrayArg,
-            "invalid_auth" => LeaveError::InvalidAuth,
-            "invalid_charset" => LeaveError::InvalidCharset,
-            "invalid_form_data" => LeaveError::InvalidFormData,
-            "invalid_json" => LeaveError::InvalidJson,
-            "invalid_post_type" => LeaveError::InvalidPostType,
-            "is_archived" => LeaveError::IsArchived,
-            "json_not_object" => LeaveError::JsonNotObject,
-            "last_member" => LeaveError::LastMember,
-            "method_not_supported_for_channel_type" => LeaveError::MethodNotSupportedForChannelType,
-            "missing_charset" => LeaveError::MissingCharset,
-            "missing_post_type" => LeaveError::MissingPostType,
-            "missing_scope" => LeaveError::MissingScope,
-            "not_authed" => LeaveError::NotAuthed,
-            "request_timeout" => LeaveError::RequestTimeout,
-            "superfluous_charset" => LeaveError::SuperfluousCharset,
-            "team_added_to_org" => LeaveError::TeamAddedToOrg,
-            "upgrade_required" => LeaveError::UpgradeRequired,
-            "user_is_bot" => LeaveError::UserIsBot,
-            "user_is_restricted" => LeaveError::UserIsRestricted,
-            "user_is_ultra_restricted" => LeaveError::UserIsUltraRestricted,
-            _ => LeaveError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for LeaveError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            LeaveError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            LeaveError::CantLeaveGeneral => write!(f, "Server returned error cant_leave_general"),
-            LeaveError::ChannelNotFound => write!(f, "Server returned error channel_not_found"),
-            LeaveError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            LeaveError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            LeaveError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            LeaveError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            LeaveError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            LeaveError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            LeaveError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            LeaveError::IsArchived => write!(f, "Server returned error is_archived"),
-            LeaveError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            LeaveError::LastMember => write!(f, "Server returned error last_member"),
-            LeaveError::MethodNotSupportedForChannelType => write!(
+            LeaveError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            LeaveError::CantLeaveGeneral(_) => {
+                write!(f, "Server returned error cant_leave_general")
+            }
+            LeaveError::ChannelNotFound(_) => write!(f, "Server returned error channel_not_found"),
+            LeaveError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            LeaveError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            LeaveError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            LeaveError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            LeaveError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            LeaveError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            LeaveError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            LeaveError::IsArchived(_) => write!(f, "Server returned error is_archived"),
+            LeaveError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            LeaveError::LastMember(_) => write!(f, "Server returned error last_member"),
+            LeaveError::MethodNotSupportedForChannelType(_) => write!(
                 f,
                 "Server returned error method_not_supported_for_channel_type"
             ),
-            LeaveError::MissingCharset => write!(f, "Server returned error missing_charset"),
-            LeaveError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            LeaveError::MissingScope => write!(f, "Server returned error missing_scope"),
-            LeaveError::NotAuthed => write!(f, "Server returned error not_authed"),
-            LeaveError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            LeaveError::SuperfluousCharset => {
+            LeaveError::MissingCharset(_) => write!(f, "Server returned error missing_charset"),
+            LeaveError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            LeaveError::MissingScope(_) => write!(f, "Server returned error missing_scope"),
+            LeaveError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            LeaveError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            LeaveError::SuperfluousCharset(_) => {
                 write!(f, "Server returned error superfluous_charset")
             }
-            LeaveError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            LeaveError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
-            LeaveError::UserIsBot => write!(f, "Server returned error user_is_bot"),
-            LeaveError::UserIsRestricted => write!(f, "Server returned error user_is_restricted"),
-            LeaveError::UserIsUltraRestricted => {
+            LeaveError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            LeaveError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
+            LeaveError::UserIsBot(_) => write!(f, "Server returned error user_is_bot"),
+            LeaveError::UserIsRestricted(_) => {
+                write!(f, "Server returned error user_is_restricted")
+            }
+            LeaveError::UserIsUltraRestricted(_) => {
                 write!(f, "Server returned error user_is_ultra_restricted")
             }
             LeaveError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            LeaveError::Unknown(ref s) => write!(f, "{}", s),
+            LeaveError::Unknown(ref s, _) => write!(f, "{}", s),
             LeaveError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -3454,6 +4436,84 @@ impl<E: Error + 'static> Error for LeaveError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for LeaveError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            LeaveError::AccountInactive(_) => Some("account_inactive"),
+            LeaveError::CantLeaveGeneral(_) => Some("cant_leave_general"),
+            LeaveError::ChannelNotFound(_) => Some("channel_not_found"),
+            LeaveError::InvalidArgName(_) => Some("invalid_arg_name"),
+            LeaveError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            LeaveError::InvalidAuth(_) => Some("invalid_auth"),
+            LeaveError::InvalidCharset(_) => Some("invalid_charset"),
+            LeaveError::InvalidFormData(_) => Some("invalid_form_data"),
+            LeaveError::InvalidJson(_) => Some("invalid_json"),
+            LeaveError::InvalidPostType(_) => Some("invalid_post_type"),
+            LeaveError::IsArchived(_) => Some("is_archived"),
+            LeaveError::JsonNotObject(_) => Some("json_not_object"),
+            LeaveError::LastMember(_) => Some("last_member"),
+            LeaveError::MethodNotSupportedForChannelType(_) => {
+                Some("method_not_supported_for_channel_type")
+            }
+            LeaveError::MissingCharset(_) => Some("missing_charset"),
+            LeaveError::MissingPostType(_) => Some("missing_post_type"),
+            LeaveError::MissingScope(_) => Some("missing_scope"),
+            LeaveError::NotAuthed(_) => Some("not_authed"),
+            LeaveError::RequestTimeout(_) => Some("request_timeout"),
+            LeaveError::SuperfluousCharset(_) => Some("superfluous_charset"),
+            LeaveError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            LeaveError::UpgradeRequired(_) => Some("upgrade_required"),
+            LeaveError::UserIsBot(_) => Some("user_is_bot"),
+            LeaveError::UserIsRestricted(_) => Some("user_is_restricted"),
+            LeaveError::UserIsUltraRestricted(_) => Some("user_is_ultra_restricted"),
+            LeaveError::Unknown(ref s, _) => Some(s),
+            LeaveError::MalformedResponse(..) | LeaveError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            LeaveError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            LeaveError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            LeaveError::AccountInactive(ref context)
+            | LeaveError::CantLeaveGeneral(ref context)
+            | LeaveError::ChannelNotFound(ref context)
+            | LeaveError::InvalidArgName(ref context)
+            | LeaveError::InvalidArrayArg(ref context)
+            | LeaveError::InvalidAuth(ref context)
+            | LeaveError::InvalidCharset(ref context)
+            | LeaveError::InvalidFormData(ref context)
+            | LeaveError::InvalidJson(ref context)
+            | LeaveError::InvalidPostType(ref context)
+            | LeaveError::IsArchived(ref context)
+            | LeaveError::JsonNotObject(ref context)
+            | LeaveError::LastMember(ref context)
+            | LeaveError::MethodNotSupportedForChannelType(ref context)
+            | LeaveError::MissingCharset(ref context)
+            | LeaveError::MissingPostType(ref context)
+            | LeaveError::MissingScope(ref context)
+            | LeaveError::NotAuthed(ref context)
+            | LeaveError::RequestTimeout(ref context)
+            | LeaveError::SuperfluousCharset(ref context)
+            | LeaveError::TeamAddedToOrg(ref context)
+            | LeaveError::UpgradeRequired(ref context)
+            | LeaveError::UserIsBot(ref context)
+            | LeaveError::UserIsRestricted(ref context)
+            | LeaveError::UserIsUltraRestricted(ref context)
+            | LeaveError::Unknown(_, ref context) => Some(context),
+            LeaveError::MalformedResponse(..) | LeaveError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct ListRequest<'a> {
     /// Paginate through collections of data by setting the `cursor` parameter to a `next_cursor` attribute returned by a previous request's `response_metadata`. Default value fetches the first "page" of the collection. See [pagination](/docs/pagination) for more detail.
@@ -3466,13 +4526,19 @@ pub struct ListRequest<'a> {
     pub types: Option<Cow<'a, str>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl crate::pagination::CursorRequest for ListRequest<'_> {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor.into());
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListDisplayCountsInner {
     pub display_counts: u64,
     pub guest_counts: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListAttachmentsInner {
     pub fallback: Option<String>,
     pub id: u64,
@@ -3482,37 +4548,32 @@ pub struct ListAttachmentsInner {
     pub image_width: Option<u64>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct ListBlocksInner {
-    pub r#type: String,
-}
-
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListIconsInner {
     pub image_36: String,
     pub image_48: String,
     pub image_72: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListBotProfileInner {
-    pub app_id: String,
+    pub app_id: crate::AppId,
     pub deleted: bool,
     pub icons: ListIconsInner,
-    pub id: String,
+    pub id: crate::BotId,
     pub name: String,
-    pub team_id: String,
+    pub team_id: crate::TeamId,
     pub updated: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListReactionsInner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListCommentInner {
     pub comment: String,
     pub created: u64,
@@ -3524,23 +4585,23 @@ pub struct ListCommentInner {
     pub pinned_to: Option<Vec<String>>,
     pub reactions: Option<Vec<ListReactionsInner>>,
     pub timestamp: u64,
-    pub user: String,
+    pub user: crate::UserId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListReactions1Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListSharesInner {
     pub private: Option<serde_json::Value>,
     pub public: Option<serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListFileInner {
     pub channels: Option<Vec<String>>,
     pub comments_count: Option<u64>,
@@ -3555,7 +4616,7 @@ pub struct ListFileInner {
     pub filetype: Option<String>,
     pub groups: Option<Vec<String>>,
     pub has_rich_preview: Option<bool>,
-    pub id: Option<String>,
+    pub id: Option<crate::FileId>,
     pub image_exif_rotation: Option<u64>,
     pub ims: Option<Vec<String>>,
     pub is_external: Option<bool>,
@@ -3609,25 +4670,25 @@ pub struct ListFileInner {
     pub updated: Option<u64>,
     pub url_private: Option<String>,
     pub url_private_download: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListReactions2Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListShares1Inner {
     pub private: Option<serde_json::Value>,
     pub public: Option<serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListFilesInner {
     pub channels: Option<Vec<String>>,
     pub comments_count: Option<u64>,
@@ -3642,7 +4703,7 @@ pub struct ListFilesInner {
     pub filetype: Option<String>,
     pub groups: Option<Vec<String>>,
     pub has_rich_preview: Option<bool>,
-    pub id: Option<String>,
+    pub id: Option<crate::FileId>,
     pub image_exif_rotation: Option<u64>,
     pub ims: Option<Vec<String>>,
     pub is_external: Option<bool>,
@@ -3696,25 +4757,25 @@ pub struct ListFilesInner {
     pub updated: Option<u64>,
     pub url_private: Option<String>,
     pub url_private_download: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListIcons1Inner {
     pub emoji: Option<String>,
     pub image_64: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListReactions3Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListUserProfileInner {
     pub avatar_hash: String,
     pub display_name: String,
@@ -3726,13 +4787,13 @@ pub struct ListUserProfileInner {
     pub name: String,
     pub real_name: String,
     pub real_name_normalized: Option<String>,
-    pub team: String,
+    pub team: crate::TeamId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListLatestInner {
     pub attachments: Option<Vec<ListAttachmentsInner>>,
-    pub blocks: Option<Vec<ListBlocksInner>>,
+    pub blocks: Option<Vec<crate::blocks::Block>>,
     pub bot_id: Option<Vec<String>>,
     pub bot_profile: Option<ListBotProfileInner>,
     pub client_msg_id: Option<String>,
@@ -3745,8 +4806,8 @@ pub struct ListLatestInner {
     pub is_delayed_message: Option<bool>,
     pub is_intro: Option<bool>,
     pub is_starred: Option<bool>,
-    pub last_read: Option<String>,
-    pub latest_reply: Option<String>,
+    pub last_read: Option<crate::Timestamp>,
+    pub latest_reply: Option<crate::Timestamp>,
     pub name: Option<String>,
     pub old_name: Option<String>,
     pub parent_user_id: Option<String>,
@@ -3760,40 +4821,40 @@ pub struct ListLatestInner {
     pub source_team: Option<String>,
     pub subscribed: Option<bool>,
     pub subtype: Option<String>,
-    pub team: Option<String>,
+    pub team: Option<crate::TeamId>,
     pub text: String,
-    pub thread_ts: Option<String>,
+    pub thread_ts: Option<crate::Timestamp>,
     pub topic: Option<String>,
-    pub ts: String,
+    pub ts: crate::Timestamp,
     pub r#type: String,
     pub unread_count: Option<u64>,
     pub upload: Option<bool>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_profile: Option<ListUserProfileInner>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListPurposeInner {
     pub creator: String,
     pub last_set: u64,
     pub value: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListCurrentInner {
     pub date_started: u64,
-    pub team_id: String,
+    pub team_id: crate::TeamId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListExternalOrgMigrationsInner {
     pub current: Vec<ListCurrentInner>,
     pub date_updated: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListIconInner {
     pub image_102: Option<String>,
     pub image_132: Option<String>,
@@ -3805,20 +4866,20 @@ pub struct ListIconInner {
     pub image_default: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListPrimaryOwnerInner {
     pub email: String,
-    pub id: String,
+    pub id: crate::UserId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListSsoProviderInner {
     pub label: Option<String>,
     pub name: Option<String>,
     pub r#type: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListTeamInner {
     pub archived: Option<bool>,
     pub avatar_base_url: Option<String>,
@@ -3829,12 +4890,12 @@ pub struct ListTeamInner {
     pub discoverable: Option<Vec<String>>,
     pub domain: String,
     pub email_domain: String,
-    pub enterprise_id: Option<String>,
+    pub enterprise_id: Option<crate::EnterpriseId>,
     pub enterprise_name: Option<String>,
     pub external_org_migrations: Option<ListExternalOrgMigrationsInner>,
     pub has_compliance_export: Option<bool>,
     pub icon: ListIconInner,
-    pub id: String,
+    pub id: crate::TeamId,
     pub is_assigned: Option<bool>,
     pub is_enterprise: Option<u64>,
     pub is_over_storage_limit: Option<bool>,
@@ -3851,22 +4912,22 @@ pub struct ListTeamInner {
     pub sso_provider: Option<ListSsoProviderInner>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListShares2Inner {
     pub accepted_user: Option<String>,
     pub is_active: bool,
     pub team: ListTeamInner,
-    pub user: String,
+    pub user: crate::UserId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListTopicInner {
     pub creator: String,
     pub last_set: u64,
     pub value: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListChannelsInner {
     pub accepted_user: Option<String>,
     pub connected_team_ids: Option<Vec<String>>,
@@ -3874,9 +4935,9 @@ pub struct ListChannelsInner {
     pub created: u64,
     pub creator: String,
     pub display_counts: Option<ListDisplayCountsInner>,
-    pub enterprise_id: Option<String>,
+    pub enterprise_id: Option<crate::EnterpriseId>,
     pub has_pins: Option<bool>,
-    pub id: String,
+    pub id: crate::ChannelId,
     pub internal_team_ids: Option<Vec<String>>,
     pub is_archived: bool,
     pub is_channel: bool,
@@ -3900,7 +4961,7 @@ pub struct ListChannelsInner {
     pub is_shared: bool,
     pub is_starred: Option<bool>,
     pub is_thread_only: Option<bool>,
-    pub last_read: Option<String>,
+    pub last_read: Option<crate::Timestamp>,
     pub latest: Option<Vec<ListLatestInner>>,
     pub members: Option<Vec<String>>,
     pub name: String,
@@ -3921,16 +4982,18 @@ pub struct ListChannelsInner {
     pub unread_count: Option<u64>,
     pub unread_count_display: Option<u64>,
     pub use_case: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub version: Option<u64>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponseMetadataInner {
+    pub messages: Option<Vec<String>>,
     pub next_cursor: String,
+    pub warnings: Option<Vec<String>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponse {
     pub callstack: Option<String>,
     pub channels: Vec<ListChannelsInner>,
@@ -3940,6 +5003,9 @@ pub struct ListResponse {
     ok: bool,
     pub provided: Option<String>,
     pub response_metadata: Option<ListResponseMetadataInner>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
@@ -3947,76 +5013,125 @@ impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(ListError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for ListResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
+impl crate::pagination::CursorPage for ListResponse {
+    type Item = ListChannelsInner;
+
+    fn next_cursor(&self) -> Option<&str> {
+        self.response_metadata
+            .as_ref()
+            .map(|response_metadata| response_metadata.next_cursor.as_str())
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.channels
+    }
+}
+
 #[derive(Debug)]
 pub enum ListError<E: Error> {
-    AccountInactive,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    MissingScope,
-    NotAuthed,
-    RequestTimeout,
-    UpgradeRequired,
+    AccountInactive(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    MissingScope(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> ListError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => ListError::AccountInactive(context),
+            "invalid_arg_name" => ListError::InvalidArgName(context),
+            "invalid_array_arg" => ListError::InvalidArrayArg(context),
+            "invalid_auth" => ListError::InvalidAuth(context),
+            "invalid_charset" => ListError::InvalidCharset(context),
+            "invalid_form_data" => ListError::InvalidFormData(context),
+            "invalid_json" => ListError::InvalidJson(context),
+            "invalid_post_type" => ListError::InvalidPostType(context),
+            "json_not_object" => ListError::JsonNotObject(context),
+            "missing_post_type" => ListError::MissingPostType(context),
+            "missing_scope" => ListError::MissingScope(context),
+            "not_authed" => ListError::NotAuthed(context),
+            "request_timeout" => ListError::RequestTimeout(context),
+            "upgrade_required" => ListError::UpgradeRequired(context),
+            _ => ListError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for ListError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => ListError::AccountInactive,
-            "invalid_arg_name" => ListError::InvalidArgName,
-            "invalid_array_arg" => ListError::InvalidArrayArg,
-            "invalid_auth" => ListError::InvalidAuth,
-            "invalid_charset" => ListError::InvalidCharset,
-            "invalid_form_data" => ListError::InvalidFormData,
-            "invalid_json" => ListError::InvalidJson,
-            "invalid_post_type" => ListError::InvalidPostType,
-            "json_not_object" => ListError::JsonNotObject,
-            "missing_post_type" => ListError::MissingPostType,
-            "missing_scope" => ListError::MissingScope,
-            "not_authed" => ListError::NotAuthed,
-            "request_timeout" => ListError::RequestTimeout,
-            "upgrade_required" => ListError::UpgradeRequired,
-            _ => ListError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for ListError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            ListError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            ListError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            ListError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            ListError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            ListError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            ListError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            ListError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            ListError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            ListError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            ListError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            ListError::MissingScope => write!(f, "Server returned error missing_scope"),
-            ListError::NotAuthed => write!(f, "Server returned error not_authed"),
-            ListError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            ListError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
+            ListError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            ListError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            ListError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            ListError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            ListError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            ListError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            ListError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            ListError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            ListError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            ListError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            ListError::MissingScope(_) => write!(f, "Server returned error missing_scope"),
+            ListError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            ListError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            ListError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
             ListError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            ListError::Unknown(ref s) => write!(f, "{}", s),
+            ListError::Unknown(ref s, _) => write!(f, "{}", s),
             ListError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -4032,15 +5147,69 @@ impl<E: Error + 'static> Error for ListError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for ListError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            ListError::AccountInactive(_) => Some("account_inactive"),
+            ListError::InvalidArgName(_) => Some("invalid_arg_name"),
+            ListError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            ListError::InvalidAuth(_) => Some("invalid_auth"),
+            ListError::InvalidCharset(_) => Some("invalid_charset"),
+            ListError::InvalidFormData(_) => Some("invalid_form_data"),
+            ListError::InvalidJson(_) => Some("invalid_json"),
+            ListError::InvalidPostType(_) => Some("invalid_post_type"),
+            ListError::JsonNotObject(_) => Some("json_not_object"),
+            ListError::MissingPostType(_) => Some("missing_post_type"),
+            ListError::MissingScope(_) => Some("missing_scope"),
+            ListError::NotAuthed(_) => Some("not_authed"),
+            ListError::RequestTimeout(_) => Some("request_timeout"),
+            ListError::UpgradeRequired(_) => Some("upgrade_required"),
+            ListError::Unknown(ref s, _) => Some(s),
+            ListError::MalformedResponse(..) | ListError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            ListError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            ListError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            ListError::AccountInactive(ref context)
+            | ListError::InvalidArgName(ref context)
+            | ListError::InvalidArrayArg(ref context)
+            | ListError::InvalidAuth(ref context)
+            | ListError::InvalidCharset(ref context)
+            | ListError::InvalidFormData(ref context)
+            | ListError::InvalidJson(ref context)
+            | ListError::InvalidPostType(ref context)
+            | ListError::JsonNotObject(ref context)
+            | ListError::MissingPostType(ref context)
+            | ListError::MissingScope(ref context)
+            | ListError::NotAuthed(ref context)
+            | ListError::RequestTimeout(ref context)
+            | ListError::UpgradeRequired(ref context)
+            | ListError::Unknown(_, ref context) => Some(context),
+            ListError::MalformedResponse(..) | ListError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
-pub struct MarkRequest<'a> {
+pub struct MarkRequest {
     /// Channel or conversation to set the read cursor for.
-    pub channel: Option<Cow<'a, str>>,
+    pub channel: Option<crate::ChannelId>,
     /// Unique identifier of message you want marked as most recently seen in this conversation.
-    pub ts: Option<f64>,
+    pub ts: Option<crate::Timestamp>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MarkResponse {
     pub callstack: Option<String>,
     error: Option<String>,
@@ -4048,6 +5217,10 @@ pub struct MarkResponse {
     #[serde(default)]
     ok: bool,
     pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<MarkResponse, MarkError<E>>> for MarkResponse {
@@ -4055,96 +5228,133 @@ impl<E: Error> Into<Result<MarkResponse, MarkError<E>>> for MarkResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(MarkError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for MarkResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum MarkError<E: Error> {
-    AccountInactive,
-    ChannelNotFound,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    InvalidTimestamp,
-    JsonNotObject,
-    MethodNotSupportedForChannelType,
-    MissingPostType,
-    MissingScope,
-    NotAllowedTokenType,
-    NotAuthed,
-    NotInChannel,
-    RequestTimeout,
-    UpgradeRequired,
+    AccountInactive(crate::ErrorContext),
+    ChannelNotFound(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    InvalidTimestamp(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MethodNotSupportedForChannelType(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    MissingScope(crate::ErrorContext),
+    NotAllowedTokenType(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    NotInChannel(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> MarkError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => MarkError::AccountInactive(context),
+            "channel_not_found" => MarkError::ChannelNotFound(context),
+            "invalid_arg_name" => MarkError::InvalidArgName(context),
+            "invalid_array_arg" => MarkError::InvalidArrayArg(context),
+            "invalid_auth" => MarkError::InvalidAuth(context),
+            "invalid_charset" => MarkError::InvalidCharset(context),
+            "invalid_form_data" => MarkError::InvalidFormData(context),
+            "invalid_json" => MarkError::InvalidJson(context),
+            "invalid_post_type" => MarkError::InvalidPostType(context),
+            "invalid_timestamp" => MarkError::InvalidTimestamp(context),
+            "json_not_object" => MarkError::JsonNotObject(context),
+            "method_not_supported_for_channel_type" => {
+                MarkError::MethodNotSupportedForChannelType(context)
+            }
+            "missing_post_type" => MarkError::MissingPostType(context),
+            "missing_scope" => MarkError::MissingScope(context),
+            "not_allowed_token_type" => MarkError::NotAllowedTokenType(context),
+            "not_authed" => MarkError::NotAuthed(context),
+            "not_in_channel" => MarkError::NotInChannel(context),
+            "request_timeout" => MarkError::RequestTimeout(context),
+            "upgrade_required" => MarkError::UpgradeRequired(context),
+            _ => MarkError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for MarkError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => MarkError::AccountInactive,
-            "channel_not_found" => MarkError::ChannelNotFound,
-            "invalid_arg_name" => MarkError::InvalidArgName,
-            "invalid_array_arg" => MarkError::InvalidArrayArg,
-            "invalid_auth" => MarkError::InvalidAuth,
-            "invalid_charset" => MarkError::InvalidCharset,
-            "invalid_form_data" => MarkError::InvalidFormData,
-            "invalid_json" => MarkError::InvalidJson,
-            "invalid_post_type" => MarkError::InvalidPostType,
-            "invalid_timestamp" => MarkError::InvalidTimestamp,
-            "json_not_object" => MarkError::JsonNotObject,
-            "method_not_supported_for_channel_type" => MarkError::MethodNotSupportedForChannelType,
-            "missing_post_type" => MarkError::MissingPostType,
-            "missing_scope" => MarkError::MissingScope,
-            "not_allowed_token_type" => MarkError::NotAllowedTokenType,
-            "not_authed" => MarkError::NotAuthed,
-            "not_in_channel" => MarkError::NotInChannel,
-            "request_timeout" => MarkError::RequestTimeout,
-            "upgrade_required" => MarkError::UpgradeRequired,
-            _ => MarkError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for MarkError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            MarkError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            MarkError::ChannelNotFound => write!(f, "Server returned error channel_not_found"),
-            MarkError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            MarkError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            MarkError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            MarkError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            MarkError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            MarkError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            MarkError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            MarkError::InvalidTimestamp => write!(f, "Server returned error invalid_timestamp"),
-            MarkError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            MarkError::MethodNotSupportedForChannelType => write!(
+            MarkError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            MarkError::ChannelNotFound(_) => write!(f, "Server returned error channel_not_found"),
+            MarkError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            MarkError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            MarkError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            MarkError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            MarkError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            MarkError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            MarkError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            MarkError::InvalidTimestamp(_) => write!(f, "Server returned error invalid_timestamp"),
+            MarkError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            MarkError::MethodNotSupportedForChannelType(_) => write!(
                 f,
                 "Server returned error method_not_supported_for_channel_type"
             ),
-            MarkError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            MarkError::MissingScope => write!(f, "Server returned error missing_scope"),
-            MarkError::NotAllowedTokenType => {
+            MarkError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            MarkError::MissingScope(_) => write!(f, "Server returned error missing_scope"),
+            MarkError::NotAllowedTokenType(_) => {
                 write!(f, "Server returned error not_allowed_token_type")
             }
-            MarkError::NotAuthed => write!(f, "Server returned error not_authed"),
-            MarkError::NotInChannel => write!(f, "Server returned error not_in_channel"),
-            MarkError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            MarkError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
+            MarkError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            MarkError::NotInChannel(_) => write!(f, "Server returned error not_in_channel"),
+            MarkError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            MarkError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
             MarkError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            MarkError::Unknown(ref s) => write!(f, "{}", s),
+            MarkError::Unknown(ref s, _) => write!(f, "{}", s),
             MarkError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -4160,29 +5370,108 @@ impl<E: Error + 'static> Error for MarkError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for MarkError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            MarkError::AccountInactive(_) => Some("account_inactive"),
+            MarkError::ChannelNotFound(_) => Some("channel_not_found"),
+            MarkError::InvalidArgName(_) => Some("invalid_arg_name"),
+            MarkError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            MarkError::InvalidAuth(_) => Some("invalid_auth"),
+            MarkError::InvalidCharset(_) => Some("invalid_charset"),
+            MarkError::InvalidFormData(_) => Some("invalid_form_data"),
+            MarkError::InvalidJson(_) => Some("invalid_json"),
+            MarkError::InvalidPostType(_) => Some("invalid_post_type"),
+            MarkError::InvalidTimestamp(_) => Some("invalid_timestamp"),
+            MarkError::JsonNotObject(_) => Some("json_not_object"),
+            MarkError::MethodNotSupportedForChannelType(_) => {
+                Some("method_not_supported_for_channel_type")
+            }
+            MarkError::MissingPostType(_) => Some("missing_post_type"),
+            MarkError::MissingScope(_) => Some("missing_scope"),
+            MarkError::NotAllowedTokenType(_) => Some("not_allowed_token_type"),
+            MarkError::NotAuthed(_) => Some("not_authed"),
+            MarkError::NotInChannel(_) => Some("not_in_channel"),
+            MarkError::RequestTimeout(_) => Some("request_timeout"),
+            MarkError::UpgradeRequired(_) => Some("upgrade_required"),
+            MarkError::Unknown(ref s, _) => Some(s),
+            MarkError::MalformedResponse(..) | MarkError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            MarkError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            MarkError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            MarkError::AccountInactive(ref context)
+            | MarkError::ChannelNotFound(ref context)
+            | MarkError::InvalidArgName(ref context)
+            | MarkError::InvalidArrayArg(ref context)
+            | MarkError::InvalidAuth(ref context)
+            | MarkError::InvalidCharset(ref context)
+            | MarkError::InvalidFormData(ref context)
+            | MarkError::InvalidJson(ref context)
+            | MarkError::InvalidPostType(ref context)
+            | MarkError::InvalidTimestamp(ref context)
+            | MarkError::JsonNotObject(ref context)
+            | MarkError::MethodNotSupportedForChannelType(ref context)
+            | MarkError::MissingPostType(ref context)
+            | MarkError::MissingScope(ref context)
+            | MarkError::NotAllowedTokenType(ref context)
+            | MarkError::NotAuthed(ref context)
+            | MarkError::NotInChannel(ref context)
+            | MarkError::RequestTimeout(ref context)
+            | MarkError::UpgradeRequired(ref context)
+            | MarkError::Unknown(_, ref context) => Some(context),
+            MarkError::MalformedResponse(..) | MarkError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct MembersRequest<'a> {
     /// ID of the conversation to retrieve members for
-    pub channel: Option<Cow<'a, str>>,
+    pub channel: Option<crate::ChannelId>,
     /// Paginate through collections of data by setting the `cursor` parameter to a `next_cursor` attribute returned by a previous request's `response_metadata`. Default value fetches the first "page" of the collection. See [pagination](/docs/pagination) for more detail.
     pub cursor: Option<Cow<'a, str>>,
     /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the users list hasn't been reached.
     pub limit: Option<u64>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl crate::pagination::CursorRequest for MembersRequest<'_> {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor.into());
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MembersResponseMetadataInner {
+    pub messages: Option<Vec<String>>,
     pub next_cursor: String,
+    pub warnings: Option<Vec<String>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MembersResponse {
     pub callstack: Option<String>,
     error: Option<String>,
     pub members: Vec<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
     pub response_metadata: MembersResponseMetadataInner,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<MembersResponse, MembersError<E>>> for MembersResponse {
@@ -4190,90 +5479,146 @@ impl<E: Error> Into<Result<MembersResponse, MembersError<E>>> for MembersRespons
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = (
+                self.response_metadata.messages,
+                self.response_metadata.warnings,
+            );
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(MembersError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for MembersResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata.warnings.as_deref(),
+        )
+    }
+}
+
+impl crate::pagination::CursorPage for MembersResponse {
+    type Item = String;
+
+    fn next_cursor(&self) -> Option<&str> {
+        Some(self.response_metadata.next_cursor.as_str())
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.members
+    }
+}
+
 #[derive(Debug)]
 pub enum MembersError<E: Error> {
-    AccountInactive,
-    ChannelNotFound,
-    FetchMembersFailed,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidCursor,
-    InvalidFormData,
-    InvalidJson,
-    InvalidLimit,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    NotAuthed,
-    RequestTimeout,
-    TeamAddedToOrg,
-    UpgradeRequired,
+    AccountInactive(crate::ErrorContext),
+    ChannelNotFound(crate::ErrorContext),
+    FetchMembersFailed(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidCursor(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidLimit(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> MembersError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => MembersError::AccountInactive(context),
+            "channel_not_found" => MembersError::ChannelNotFound(context),
+            "fetch_members_failed" => MembersError::FetchMembersFailed(context),
+            "invalid_arg_name" => MembersError::InvalidArgName(context),
+            "invalid_array_arg" => MembersError::InvalidArrayArg(context),
+            "invalid_auth" => MembersError::InvalidAuth(context),
+            "invalid_charset" => MembersError::InvalidCharset(context),
+            "invalid_cursor" => MembersError::InvalidCursor(context),
+            "invalid_form_data" => MembersError::InvalidFormData(context),
+            "invalid_json" => MembersError::InvalidJson(context),
+            "invalid_limit" => MembersError::InvalidLimit(context),
+            "invalid_post_type" => MembersError::InvalidPostType(context),
+            "json_not_object" => MembersError::JsonNotObject(context),
+            "missing_post_type" => MembersError::MissingPostType(context),
+            "not_authed" => MembersError::NotAuthed(context),
+            "request_timeout" => MembersError::RequestTimeout(context),
+            "team_added_to_org" => MembersError::TeamAddedToOrg(context),
+            "upgrade_required" => MembersError::UpgradeRequired(context),
+            _ => MembersError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for MembersError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => MembersError::AccountInactive,
-            "channel_not_found" => MembersError::ChannelNotFound,
-            "fetch_members_failed" => MembersError::FetchMembersFailed,
-            "invalid_arg_name" => MembersError::InvalidArgName,
-            "invalid_array_arg" => MembersError::InvalidArrayArg,
-            "invalid_auth" => MembersError::InvalidAuth,
-            "invalid_charset" => MembersError::InvalidCharset,
-            "invalid_cursor" => MembersError::InvalidCursor,
-            "invalid_form_data" => MembersError::InvalidFormData,
-            "invalid_json" => MembersError::InvalidJson,
-            "invalid_limit" => MembersError::InvalidLimit,
-            "invalid_post_type" => MembersError::InvalidPostType,
-            "json_not_object" => MembersError::JsonNotObject,
-            "missing_post_type" => MembersError::MissingPostType,
-            "not_authed" => MembersError::NotAuthed,
-            "request_timeout" => MembersError::RequestTimeout,
-            "team_added_to_org" => MembersError::TeamAddedToOrg,
-            "upgrade_required" => MembersError::UpgradeRequired,
-            _ => MembersError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for MembersError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            MembersError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            MembersError::ChannelNotFound => write!(f, "Server returned error channel_not_found"),
-            MembersError::FetchMembersFailed => {
+            MembersError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            MembersError::ChannelNotFound(_) => {
+                write!(f, "Server returned error channel_not_found")
+            }
+            MembersError::FetchMembersFailed(_) => {
                 write!(f, "Server returned error fetch_members_failed")
             }
-            MembersError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            MembersError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            MembersError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            MembersError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            MembersError::InvalidCursor => write!(f, "Server returned error invalid_cursor"),
-            MembersError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            MembersError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            MembersError::InvalidLimit => write!(f, "Server returned error invalid_limit"),
-            MembersError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            MembersError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            MembersError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            MembersError::NotAuthed => write!(f, "Server returned error not_authed"),
-            MembersError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            MembersError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            MembersError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
+            MembersError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            MembersError::InvalidArrayArg(_) => {
+                write!(f, "Server returned error invalid_array_arg")
+            }
+            MembersError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            MembersError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            MembersError::InvalidCursor(_) => write!(f, "Server returned error invalid_cursor"),
+            MembersError::InvalidFormData(_) => {
+                write!(f, "Server returned error invalid_form_data")
+            }
+            MembersError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            MembersError::InvalidLimit(_) => write!(f, "Server returned error invalid_limit"),
+            MembersError::InvalidPostType(_) => {
+                write!(f, "Server returned error invalid_post_type")
+            }
+            MembersError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            MembersError::MissingPostType(_) => {
+                write!(f, "Server returned error missing_post_type")
+            }
+            MembersError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            MembersError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            MembersError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            MembersError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
             MembersError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            MembersError::Unknown(ref s) => write!(f, "{}", s),
+            MembersError::Unknown(ref s, _) => write!(f, "{}", s),
             MembersError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -4289,23 +5634,85 @@ impl<E: Error + 'static> Error for MembersError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for MembersError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            MembersError::AccountInactive(_) => Some("account_inactive"),
+            MembersError::ChannelNotFound(_) => Some("channel_not_found"),
+            MembersError::FetchMembersFailed(_) => Some("fetch_members_failed"),
+            MembersError::InvalidArgName(_) => Some("invalid_arg_name"),
+            MembersError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            MembersError::InvalidAuth(_) => Some("invalid_auth"),
+            MembersError::InvalidCharset(_) => Some("invalid_charset"),
+            MembersError::InvalidCursor(_) => Some("invalid_cursor"),
+            MembersError::InvalidFormData(_) => Some("invalid_form_data"),
+            MembersError::InvalidJson(_) => Some("invalid_json"),
+            MembersError::InvalidLimit(_) => Some("invalid_limit"),
+            MembersError::InvalidPostType(_) => Some("invalid_post_type"),
+            MembersError::JsonNotObject(_) => Some("json_not_object"),
+            MembersError::MissingPostType(_) => Some("missing_post_type"),
+            MembersError::NotAuthed(_) => Some("not_authed"),
+            MembersError::RequestTimeout(_) => Some("request_timeout"),
+            MembersError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            MembersError::UpgradeRequired(_) => Some("upgrade_required"),
+            MembersError::Unknown(ref s, _) => Some(s),
+            MembersError::MalformedResponse(..) | MembersError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            MembersError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            MembersError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            MembersError::AccountInactive(ref context)
+            | MembersError::ChannelNotFound(ref context)
+            | MembersError::FetchMembersFailed(ref context)
+            | MembersError::InvalidArgName(ref context)
+            | MembersError::InvalidArrayArg(ref context)
+            | MembersError::InvalidAuth(ref context)
+            | MembersError::InvalidCharset(ref context)
+            | MembersError::InvalidCursor(ref context)
+            | MembersError::InvalidFormData(ref context)
+            | MembersError::InvalidJson(ref context)
+            | MembersError::InvalidLimit(ref context)
+            | MembersError::InvalidPostType(ref context)
+            | MembersError::JsonNotObject(ref context)
+            | MembersError::MissingPostType(ref context)
+            | MembersError::NotAuthed(ref context)
+            | MembersError::RequestTimeout(ref context)
+            | MembersError::TeamAddedToOrg(ref context)
+            | MembersError::UpgradeRequired(ref context)
+            | MembersError::Unknown(_, ref context) => Some(context),
+            MembersError::MalformedResponse(..) | MembersError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct OpenRequest<'a> {
     /// Resume a conversation by supplying an `im` or `mpim`'s ID. Or provide the `users` field instead.
-    pub channel: Option<Cow<'a, str>>,
+    pub channel: Option<crate::ChannelId>,
     /// Boolean, indicates you want the full IM channel definition in the response.
     pub return_im: Option<bool>,
     /// Comma separated lists of users. If only one user is included, this creates a 1:1 DM.  The ordering of the users is preserved whenever a multi-person direct message is returned. Supply a `channel` when not supplying `users`.
     pub users: Option<Cow<'a, str>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct OpenDisplayCountsInner {
     pub display_counts: u64,
     pub guest_counts: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct OpenAttachmentsInner {
     pub fallback: Option<String>,
     pub id: u64,
@@ -4315,37 +5722,32 @@ pub struct OpenAttachmentsInner {
     pub image_width: Option<u64>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct OpenBlocksInner {
-    pub r#type: String,
-}
-
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct OpenIconsInner {
     pub image_36: String,
     pub image_48: String,
     pub image_72: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct OpenBotProfileInner {
-    pub app_id: String,
+    pub app_id: crate::AppId,
     pub deleted: bool,
     pub icons: OpenIconsInner,
-    pub id: String,
+    pub id: crate::BotId,
     pub name: String,
-    pub team_id: String,
+    pub team_id: crate::TeamId,
     pub updated: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct OpenReactionsInner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct OpenCommentInner {
     pub comment: String,
     pub created: u64,
@@ -4357,23 +5759,23 @@ pub struct OpenCommentInner {
     pub pinned_to: Option<Vec<String>>,
     pub reactions: Option<Vec<OpenReactionsInner>>,
     pub timestamp: u64,
-    pub user: String,
+    pub user: crate::UserId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct OpenReactions1Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct OpenSharesInner {
     pub private: Option<serde_json::Value>,
     pub public: Option<serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct OpenFileInner {
     pub channels: Option<Vec<String>>,
     pub comments_count: Option<u64>,
@@ -4388,7 +5790,7 @@ pub struct OpenFileInner {
     pub filetype: Option<String>,
     pub groups: Option<Vec<String>>,
     pub has_rich_preview: Option<bool>,
-    pub id: Option<String>,
+    pub id: Option<crate::FileId>,
     pub image_exif_rotation: Option<u64>,
     pub ims: Option<Vec<String>>,
     pub is_external: Option<bool>,
@@ -4442,25 +5844,25 @@ pub struct OpenFileInner {
     pub updated: Option<u64>,
     pub url_private: Option<String>,
     pub url_private_download: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct OpenReactions2Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct OpenShares1Inner {
     pub private: Option<serde_json::Value>,
     pub public: Option<serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct OpenFilesInner {
     pub channels: Option<Vec<String>>,
     pub comments_count: Option<u64>,
@@ -4475,7 +5877,7 @@ pub struct OpenFilesInner {
     pub filetype: Option<String>,
     pub groups: Option<Vec<String>>,
     pub has_rich_preview: Option<bool>,
-    pub id: Option<String>,
+    pub id: Option<crate::FileId>,
     pub image_exif_rotation: Option<u64>,
     pub ims: Option<Vec<String>>,
     pub is_external: Option<bool>,
@@ -4529,25 +5931,25 @@ pub struct OpenFilesInner {
     pub updated: Option<u64>,
     pub url_private: Option<String>,
     pub url_private_download: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct OpenIcons1Inner {
     pub emoji: Option<String>,
     pub image_64: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct OpenReactions3Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct OpenUserProfileInner {
     pub avatar_hash: String,
     pub display_name: String,
@@ -4559,13 +5961,13 @@ pub struct OpenUserProfileInner {
     pub name: String,
     pub real_name: String,
     pub real_name_normalized: Option<String>,
-    pub team: String,
+    pub team: crate::TeamId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct OpenLatestInner {
     pub attachments: Option<Vec<OpenAttachmentsInner>>,
-    pub blocks: Option<Vec<OpenBlocksInner>>,
+    pub blocks: Option<Vec<crate::blocks::Block>>,
     pub bot_id: Option<Vec<String>>,
     pub bot_profile: Option<OpenBotProfileInner>,
     pub client_msg_id: Option<String>,
@@ -4578,8 +5980,8 @@ pub struct OpenLatestInner {
     pub is_delayed_message: Option<bool>,
     pub is_intro: Option<bool>,
     pub is_starred: Option<bool>,
-    pub last_read: Option<String>,
-    pub latest_reply: Option<String>,
+    pub last_read: Option<crate::Timestamp>,
+    pub latest_reply: Option<crate::Timestamp>,
     pub name: Option<String>,
     pub old_name: Option<String>,
     pub parent_user_id: Option<String>,
@@ -4593,40 +5995,40 @@ pub struct OpenLatestInner {
     pub source_team: Option<String>,
     pub subscribed: Option<bool>,
     pub subtype: Option<String>,
-    pub team: Option<String>,
+    pub team: Option<crate::TeamId>,
     pub text: String,
-    pub thread_ts: Option<String>,
+    pub thread_ts: Option<crate::Timestamp>,
     pub topic: Option<String>,
-    pub ts: String,
+    pub ts: crate::Timestamp,
     pub r#type: String,
     pub unread_count: Option<u64>,
     pub upload: Option<bool>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_profile: Option<OpenUserProfileInner>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct OpenPurposeInner {
     pub creator: String,
     pub last_set: u64,
     pub value: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct OpenCurrentInner {
     pub date_started: u64,
-    pub team_id: String,
+    pub team_id: crate::TeamId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct OpenExternalOrgMigrationsInner {
     pub current: Vec<OpenCurrentInner>,
     pub date_updated: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct OpenIconInner {
     pub image_102: Option<String>,
     pub image_132: Option<String>,
@@ -4638,20 +6040,20 @@ pub struct OpenIconInner {
     pub image_default: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct OpenPrimaryOwnerInner {
     pub email: String,
-    pub id: String,
+    pub id: crate::UserId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct OpenSsoProviderInner {
     pub label: Option<String>,
     pub name: Option<String>,
     pub r#type: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct OpenTeamInner {
     pub archived: Option<bool>,
     pub avatar_base_url: Option<String>,
@@ -4662,12 +6064,12 @@ pub struct OpenTeamInner {
     pub discoverable: Option<Vec<String>>,
     pub domain: String,
     pub email_domain: String,
-    pub enterprise_id: Option<String>,
+    pub enterprise_id: Option<crate::EnterpriseId>,
     pub enterprise_name: Option<String>,
     pub external_org_migrations: Option<OpenExternalOrgMigrationsInner>,
     pub has_compliance_export: Option<bool>,
     pub icon: OpenIconInner,
-    pub id: String,
+    pub id: crate::TeamId,
     pub is_assigned: Option<bool>,
     pub is_enterprise: Option<u64>,
     pub is_over_storage_limit: Option<bool>,
@@ -4684,22 +6086,22 @@ pub struct OpenTeamInner {
     pub sso_provider: Option<OpenSsoProviderInner>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct OpenShares2Inner {
     pub accepted_user: Option<String>,
     pub is_active: bool,
     pub team: OpenTeamInner,
-    pub user: String,
+    pub user: crate::UserId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct OpenTopicInner {
     pub creator: String,
     pub last_set: u64,
     pub value: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct OpenChannelInner {
     pub accepted_user: Option<String>,
     pub connected_team_ids: Option<Vec<String>>,
@@ -4707,9 +6109,9 @@ pub struct OpenChannelInner {
     pub created: u64,
     pub creator: String,
     pub display_counts: Option<OpenDisplayCountsInner>,
-    pub enterprise_id: Option<String>,
+    pub enterprise_id: Option<crate::EnterpriseId>,
     pub has_pins: Option<bool>,
-    pub id: String,
+    pub id: crate::ChannelId,
     pub internal_team_ids: Option<Vec<String>>,
     pub is_archived: bool,
     pub is_channel: bool,
@@ -4733,7 +6135,7 @@ pub struct OpenChannelInner {
     pub is_shared: bool,
     pub is_starred: Option<bool>,
     pub is_thread_only: Option<bool>,
-    pub last_read: Option<String>,
+    pub last_read: Option<crate::Timestamp>,
     pub latest: Option<Vec<OpenLatestInner>>,
     pub members: Option<Vec<String>>,
     pub name: String,
@@ -4754,19 +6156,25 @@ pub struct OpenChannelInner {
     pub unread_count: Option<u64>,
     pub unread_count_display: Option<u64>,
     pub use_case: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub version: Option<u64>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct OpenResponse {
     pub already_open: Option<bool>,
     pub callstack: Option<String>,
     pub channel: Vec<Vec<OpenChannelInner>>,
     error: Option<String>,
+    pub needed: Option<String>,
     pub no_op: Option<bool>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<OpenResponse, OpenError<E>>> for OpenResponse {
@@ -4774,110 +6182,147 @@ impl<E: Error> Into<Result<OpenResponse, OpenError<E>>> for OpenResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(OpenError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for OpenResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum OpenError<E: Error> {
-    AccountInactive,
-    ChannelNotFound,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    InvalidUserCombination,
-    JsonNotObject,
-    MethodNotSupportedForChannelType,
-    MissingPostType,
-    NotAuthed,
-    NotEnoughUsers,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TooManyUsers,
-    UpgradeRequired,
-    UserDisabled,
-    UserNotFound,
-    UserNotVisible,
-    UsersListNotSupplied,
+    AccountInactive(crate::ErrorContext),
+    ChannelNotFound(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    InvalidUserCombination(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MethodNotSupportedForChannelType(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    NotEnoughUsers(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TooManyUsers(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserDisabled(crate::ErrorContext),
+    UserNotFound(crate::ErrorContext),
+    UserNotVisible(crate::ErrorContext),
+    UsersListNotSupplied(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> OpenError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => OpenError::AccountInactive(context),
+            "channel_not_found" => OpenError::ChannelNotFound(context),
+            "invalid_arg_name" => OpenError::InvalidArgName(context),
+            "invalid_array_arg" => OpenError::InvalidArrayArg(context),
+            "invalid_auth" => OpenError::InvalidAuth(context),
+            "invalid_charset" => OpenError::InvalidCharset(context),
+            "invalid_form_data" => OpenError::InvalidFormData(context),
+            "invalid_json" => OpenError::InvalidJson(context),
+            "invalid_post_type" => OpenError::InvalidPostType(context),
+            "invalid_user_combination" => OpenError::InvalidUserCombination(context),
+            "json_not_object" => OpenError::JsonNotObject(context),
+            "method_not_supported_for_channel_type" => {
+                OpenError::MethodNotSupportedForChannelType(context)
+            }
+            "missing_post_type" => OpenError::MissingPostType(context),
+            "not_authed" => OpenError::NotAuthed(context),
+            "not_enough_users" => OpenError::NotEnoughUsers(context),
+            "request_timeout" => OpenError::RequestTimeout(context),
+            "team_added_to_org" => OpenError::TeamAddedToOrg(context),
+            "too_many_users" => OpenError::TooManyUsers(context),
+            "upgrade_required" => OpenError::UpgradeRequired(context),
+            "user_disabled" => OpenError::UserDisabled(context),
+            "user_not_found" => OpenError::UserNotFound(context),
+            "user_not_visible" => OpenError::UserNotVisible(context),
+            "users_list_not_supplied" => OpenError::UsersListNotSupplied(context),
+            _ => OpenError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for OpenError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => OpenError::AccountInactive,
-            "channel_not_found" => OpenError::ChannelNotFound,
-            "invalid_arg_name" => OpenError::InvalidArgName,
-            "invalid_array_arg" => OpenError::InvalidArrayArg,
-            "invalid_auth" => OpenError::InvalidAuth,
-            "invalid_charset" => OpenError::InvalidCharset,
-            "invalid_form_data" => OpenError::InvalidFormData,
-            "invalid_json" => OpenError::InvalidJson,
-            "invalid_post_type" => OpenError::InvalidPostType,
-            "invalid_user_combination" => OpenError::InvalidUserCombination,
-            "json_not_object" => OpenError::JsonNotObject,
-            "method_not_supported_for_channel_type" => OpenError::MethodNotSupportedForChannelType,
-            "missing_post_type" => OpenError::MissingPostType,
-            "not_authed" => OpenError::NotAuthed,
-            "not_enough_users" => OpenError::NotEnoughUsers,
-            "request_timeout" => OpenError::RequestTimeout,
-            "team_added_to_org" => OpenError::TeamAddedToOrg,
-            "too_many_users" => OpenError::TooManyUsers,
-            "upgrade_required" => OpenError::UpgradeRequired,
-            "user_disabled" => OpenError::UserDisabled,
-            "user_not_found" => OpenError::UserNotFound,
-            "user_not_visible" => OpenError::UserNotVisible,
-            "users_list_not_supplied" => OpenError::UsersListNotSupplied,
-            _ => OpenError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for OpenError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            OpenError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            OpenError::ChannelNotFound => write!(f, "Server returned error channel_not_found"),
-            OpenError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            OpenError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            OpenError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            OpenError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            OpenError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            OpenError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            OpenError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            OpenError::InvalidUserCombination => {
+            OpenError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            OpenError::ChannelNotFound(_) => write!(f, "Server returned error channel_not_found"),
+            OpenError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            OpenError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            OpenError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            OpenError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            OpenError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            OpenError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            OpenError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            OpenError::InvalidUserCombination(_) => {
                 write!(f, "Server returned error invalid_user_combination")
             }
-            OpenError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            OpenError::MethodNotSupportedForChannelType => write!(
+            OpenError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            OpenError::MethodNotSupportedForChannelType(_) => write!(
                 f,
                 "Server returned error method_not_supported_for_channel_type"
             ),
-            OpenError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            OpenError::NotAuthed => write!(f, "Server returned error not_authed"),
-            OpenError::NotEnoughUsers => write!(f, "Server returned error not_enough_users"),
-            OpenError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            OpenError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            OpenError::TooManyUsers => write!(f, "Server returned error too_many_users"),
-            OpenError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
-            OpenError::UserDisabled => write!(f, "Server returned error user_disabled"),
-            OpenError::UserNotFound => write!(f, "Server returned error user_not_found"),
-            OpenError::UserNotVisible => write!(f, "Server returned error user_not_visible"),
-            OpenError::UsersListNotSupplied => {
+            OpenError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            OpenError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            OpenError::NotEnoughUsers(_) => write!(f, "Server returned error not_enough_users"),
+            OpenError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            OpenError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            OpenError::TooManyUsers(_) => write!(f, "Server returned error too_many_users"),
+            OpenError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
+            OpenError::UserDisabled(_) => write!(f, "Server returned error user_disabled"),
+            OpenError::UserNotFound(_) => write!(f, "Server returned error user_not_found"),
+            OpenError::UserNotVisible(_) => write!(f, "Server returned error user_not_visible"),
+            OpenError::UsersListNotSupplied(_) => {
                 write!(f, "Server returned error users_list_not_supplied")
             }
             OpenError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            OpenError::Unknown(ref s) => write!(f, "{}", s),
+            OpenError::Unknown(ref s, _) => write!(f, "{}", s),
             OpenError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -4893,21 +6338,95 @@ impl<E: Error + 'static> Error for OpenError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for OpenError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            OpenError::AccountInactive(_) => Some("account_inactive"),
+            OpenError::ChannelNotFound(_) => Some("channel_not_found"),
+            OpenError::InvalidArgName(_) => Some("invalid_arg_name"),
+            OpenError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            OpenError::InvalidAuth(_) => Some("invalid_auth"),
+            OpenError::InvalidCharset(_) => Some("invalid_charset"),
+            OpenError::InvalidFormData(_) => Some("invalid_form_data"),
+            OpenError::InvalidJson(_) => Some("invalid_json"),
+            OpenError::InvalidPostType(_) => Some("invalid_post_type"),
+            OpenError::InvalidUserCombination(_) => Some("invalid_user_combination"),
+            OpenError::JsonNotObject(_) => Some("json_not_object"),
+            OpenError::MethodNotSupportedForChannelType(_) => {
+                Some("method_not_supported_for_channel_type")
+            }
+            OpenError::MissingPostType(_) => Some("missing_post_type"),
+            OpenError::NotAuthed(_) => Some("not_authed"),
+            OpenError::NotEnoughUsers(_) => Some("not_enough_users"),
+            OpenError::RequestTimeout(_) => Some("request_timeout"),
+            OpenError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            OpenError::TooManyUsers(_) => Some("too_many_users"),
+            OpenError::UpgradeRequired(_) => Some("upgrade_required"),
+            OpenError::UserDisabled(_) => Some("user_disabled"),
+            OpenError::UserNotFound(_) => Some("user_not_found"),
+            OpenError::UserNotVisible(_) => Some("user_not_visible"),
+            OpenError::UsersListNotSupplied(_) => Some("users_list_not_supplied"),
+            OpenError::Unknown(ref s, _) => Some(s),
+            OpenError::MalformedResponse(..) | OpenError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            OpenError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            OpenError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            OpenError::AccountInactive(ref context)
+            | OpenError::ChannelNotFound(ref context)
+            | OpenError::InvalidArgName(ref context)
+            | OpenError::InvalidArrayArg(ref context)
+            | OpenError::InvalidAuth(ref context)
+            | OpenError::InvalidCharset(ref context)
+            | OpenError::InvalidFormData(ref context)
+            | OpenError::InvalidJson(ref context)
+            | OpenError::InvalidPostType(ref context)
+            | OpenError::InvalidUserCombination(ref context)
+            | OpenError::JsonNotObject(ref context)
+            | OpenError::MethodNotSupportedForChannelType(ref context)
+            | OpenError::MissingPostType(ref context)
+            | OpenError::NotAuthed(ref context)
+            | OpenError::NotEnoughUsers(ref context)
+            | OpenError::RequestTimeout(ref context)
+            | OpenError::TeamAddedToOrg(ref context)
+            | OpenError::TooManyUsers(ref context)
+            | OpenError::UpgradeRequired(ref context)
+            | OpenError::UserDisabled(ref context)
+            | OpenError::UserNotFound(ref context)
+            | OpenError::UserNotVisible(ref context)
+            | OpenError::UsersListNotSupplied(ref context)
+            | OpenError::Unknown(_, ref context) => Some(context),
+            OpenError::MalformedResponse(..) | OpenError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct RenameRequest<'a> {
     /// ID of conversation to rename
-    pub channel: Option<Cow<'a, str>>,
+    pub channel: Option<crate::ChannelId>,
     /// New name for conversation.
     pub name: Option<Cow<'a, str>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RenameDisplayCountsInner {
     pub display_counts: u64,
     pub guest_counts: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RenameAttachmentsInner {
     pub fallback: Option<String>,
     pub id: u64,
@@ -4917,37 +6436,32 @@ pub struct RenameAttachmentsInner {
     pub image_width: Option<u64>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct RenameBlocksInner {
-    pub r#type: String,
-}
-
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RenameIconsInner {
     pub image_36: String,
     pub image_48: String,
     pub image_72: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RenameBotProfileInner {
-    pub app_id: String,
+    pub app_id: crate::AppId,
     pub deleted: bool,
     pub icons: RenameIconsInner,
-    pub id: String,
+    pub id: crate::BotId,
     pub name: String,
-    pub team_id: String,
+    pub team_id: crate::TeamId,
     pub updated: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RenameReactionsInner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RenameCommentInner {
     pub comment: String,
     pub created: u64,
@@ -4959,23 +6473,23 @@ pub struct RenameCommentInner {
     pub pinned_to: Option<Vec<String>>,
     pub reactions: Option<Vec<RenameReactionsInner>>,
     pub timestamp: u64,
-    pub user: String,
+    pub user: crate::UserId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RenameReactions1Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RenameSharesInner {
     pub private: Option<serde_json::Value>,
     pub public: Option<serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RenameFileInner {
     pub channels: Option<Vec<String>>,
     pub comments_count: Option<u64>,
@@ -4990,7 +6504,7 @@ pub struct RenameFileInner {
     pub filetype: Option<String>,
     pub groups: Option<Vec<String>>,
     pub has_rich_preview: Option<bool>,
-    pub id: Option<String>,
+    pub id: Option<crate::FileId>,
     pub image_exif_rotation: Option<u64>,
     pub ims: Option<Vec<String>>,
     pub is_external: Option<bool>,
@@ -5044,25 +6558,25 @@ pub struct RenameFileInner {
     pub updated: Option<u64>,
     pub url_private: Option<String>,
     pub url_private_download: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RenameReactions2Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RenameShares1Inner {
     pub private: Option<serde_json::Value>,
     pub public: Option<serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RenameFilesInner {
     pub channels: Option<Vec<String>>,
     pub comments_count: Option<u64>,
@@ -5077,7 +6591,7 @@ pub struct RenameFilesInner {
     pub filetype: Option<String>,
     pub groups: Option<Vec<String>>,
     pub has_rich_preview: Option<bool>,
-    pub id: Option<String>,
+    pub id: Option<crate::FileId>,
     pub image_exif_rotation: Option<u64>,
     pub ims: Option<Vec<String>>,
     pub is_external: Option<bool>,
@@ -5131,25 +6645,25 @@ pub struct RenameFilesInner {
     pub updated: Option<u64>,
     pub url_private: Option<String>,
     pub url_private_download: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RenameIcons1Inner {
     pub emoji: Option<String>,
     pub image_64: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RenameReactions3Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RenameUserProfileInner {
     pub avatar_hash: String,
     pub display_name: String,
@@ -5161,13 +6675,13 @@ pub struct RenameUserProfileInner {
     pub name: String,
     pub real_name: String,
     pub real_name_normalized: Option<String>,
-    pub team: String,
+    pub team: crate::TeamId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RenameLatestInner {
     pub attachments: Option<Vec<RenameAttachmentsInner>>,
-    pub blocks: Option<Vec<RenameBlocksInner>>,
+    pub blocks: Option<Vec<crate::blocks::Block>>,
     pub bot_id: Option<Vec<String>>,
     pub bot_profile: Option<RenameBotProfileInner>,
     pub client_msg_id: Option<String>,
@@ -5180,8 +6694,8 @@ pub struct RenameLatestInner {
     pub is_delayed_message: Option<bool>,
     pub is_intro: Option<bool>,
     pub is_starred: Option<bool>,
-    pub last_read: Option<String>,
-    pub latest_reply: Option<String>,
+    pub last_read: Option<crate::Timestamp>,
+    pub latest_reply: Option<crate::Timestamp>,
     pub name: Option<String>,
     pub old_name: Option<String>,
     pub parent_user_id: Option<String>,
@@ -5195,40 +6709,40 @@ pub struct RenameLatestInner {
     pub source_team: Option<String>,
     pub subscribed: Option<bool>,
     pub subtype: Option<String>,
-    pub team: Option<String>,
+    pub team: Option<crate::TeamId>,
     pub text: String,
-    pub thread_ts: Option<String>,
+    pub thread_ts: Option<crate::Timestamp>,
     pub topic: Option<String>,
-    pub ts: String,
+    pub ts: crate::Timestamp,
     pub r#type: String,
     pub unread_count: Option<u64>,
     pub upload: Option<bool>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_profile: Option<RenameUserProfileInner>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RenamePurposeInner {
     pub creator: String,
     pub last_set: u64,
     pub value: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RenameCurrentInner {
     pub date_started: u64,
-    pub team_id: String,
+    pub team_id: crate::TeamId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RenameExternalOrgMigrationsInner {
     pub current: Vec<RenameCurrentInner>,
     pub date_updated: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RenameIconInner {
     pub image_102: Option<String>,
     pub image_132: Option<String>,
@@ -5240,20 +6754,20 @@ pub struct RenameIconInner {
     pub image_default: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RenamePrimaryOwnerInner {
     pub email: String,
-    pub id: String,
+    pub id: crate::UserId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RenameSsoProviderInner {
     pub label: Option<String>,
     pub name: Option<String>,
     pub r#type: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RenameTeamInner {
     pub archived: Option<bool>,
     pub avatar_base_url: Option<String>,
@@ -5264,12 +6778,12 @@ pub struct RenameTeamInner {
     pub discoverable: Option<Vec<String>>,
     pub domain: String,
     pub email_domain: String,
-    pub enterprise_id: Option<String>,
+    pub enterprise_id: Option<crate::EnterpriseId>,
     pub enterprise_name: Option<String>,
     pub external_org_migrations: Option<RenameExternalOrgMigrationsInner>,
     pub has_compliance_export: Option<bool>,
     pub icon: RenameIconInner,
-    pub id: String,
+    pub id: crate::TeamId,
     pub is_assigned: Option<bool>,
     pub is_enterprise: Option<u64>,
     pub is_over_storage_limit: Option<bool>,
@@ -5286,22 +6800,22 @@ pub struct RenameTeamInner {
     pub sso_provider: Option<RenameSsoProviderInner>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RenameShares2Inner {
     pub accepted_user: Option<String>,
     pub is_active: bool,
     pub team: RenameTeamInner,
-    pub user: String,
+    pub user: crate::UserId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RenameTopicInner {
     pub creator: String,
     pub last_set: u64,
     pub value: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RenameChannelInner {
     pub accepted_user: Option<String>,
     pub connected_team_ids: Option<Vec<String>>,
@@ -5309,9 +6823,9 @@ pub struct RenameChannelInner {
     pub created: u64,
     pub creator: String,
     pub display_counts: Option<RenameDisplayCountsInner>,
-    pub enterprise_id: Option<String>,
+    pub enterprise_id: Option<crate::EnterpriseId>,
     pub has_pins: Option<bool>,
-    pub id: String,
+    pub id: crate::ChannelId,
     pub internal_team_ids: Option<Vec<String>>,
     pub is_archived: bool,
     pub is_channel: bool,
@@ -5335,7 +6849,7 @@ pub struct RenameChannelInner {
     pub is_shared: bool,
     pub is_starred: Option<bool>,
     pub is_thread_only: Option<bool>,
-    pub last_read: Option<String>,
+    pub last_read: Option<crate::Timestamp>,
     pub latest: Option<Vec<RenameLatestInner>>,
     pub members: Option<Vec<String>>,
     pub name: String,
@@ -5356,11 +6870,11 @@ pub struct RenameChannelInner {
     pub unread_count: Option<u64>,
     pub unread_count_display: Option<u64>,
     pub use_case: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub version: Option<u64>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RenameResponse {
     pub callstack: Option<String>,
     pub channel: Vec<RenameChannelInner>,
@@ -5369,6 +6883,10 @@ pub struct RenameResponse {
     #[serde(default)]
     ok: bool,
     pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<RenameResponse, RenameError<E>>> for RenameResponse {
@@ -5376,122 +6894,159 @@ impl<E: Error> Into<Result<RenameResponse, RenameError<E>>> for RenameResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(RenameError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for RenameResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum RenameError<E: Error> {
-    AccountInactive,
-    ChannelNotFound,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidName,
-    InvalidNameMaxlength,
-    InvalidNamePunctuation,
-    InvalidNameRequired,
-    InvalidNameSpecials,
-    InvalidPostType,
-    JsonNotObject,
-    MethodNotSupportedForChannelType,
-    MissingPostType,
-    MissingScope,
-    NameTaken,
-    NotAuthed,
-    NotAuthorized,
-    NotInChannel,
-    RequestTimeout,
-    UpgradeRequired,
-    UserIsRestricted,
+    AccountInactive(crate::ErrorContext),
+    ChannelNotFound(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidName(crate::ErrorContext),
+    InvalidNameMaxlength(crate::ErrorContext),
+    InvalidNamePunctuation(crate::ErrorContext),
+    InvalidNameRequired(crate::ErrorContext),
+    InvalidNameSpecials(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MethodNotSupportedForChannelType(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    MissingScope(crate::ErrorContext),
+    NameTaken(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    NotAuthorized(crate::ErrorContext),
+    NotInChannel(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserIsRestricted(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
-impl<'a, E: Error> From<&'a str> for RenameError<E> {
-    fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => RenameError::AccountInactive,
-            "channel_not_found" => RenameError::ChannelNotFound,
-            "invalid_arg_name" => RenameError::InvalidArgName,
-            "invalid_array_arg" => RenameError::InvalidArrayArg,
-            "invalid_auth" => RenameError::InvalidAuth,
-            "invalid_charset" => RenameError::InvalidCharset,
-            "invalid_form_data" => RenameError::InvalidFormData,
-            "invalid_json" => RenameError::InvalidJson,
-            "invalid_name" => RenameError::InvalidName,
-            "invalid_name_maxlength" => RenameError::InvalidNameMaxlength,
-            "invalid_name_punctuation" => RenameError::InvalidNamePunctuation,
-            "invalid_name_required" => RenameError::InvalidNameRequired,
-            "invalid_name_specials" => RenameError::InvalidNameSpecials,
-            "invalid_post_type" => RenameError::InvalidPostType,
-            "json_not_object" => RenameError::JsonNotObject,
+impl<E: Error> RenameError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => RenameError::AccountInactive(context),
+            "channel_not_found" => RenameError::ChannelNotFound(context),
+            "invalid_arg_name" => RenameError::InvalidArgName(context),
+            "invalid_array_arg" => RenameError::InvalidArrayArg(context),
+            "invalid_auth" => RenameError::InvalidAuth(context),
+            "invalid_charset" => RenameError::InvalidCharset(context),
+            "invalid_form_data" => RenameError::InvalidFormData(context),
+            "invalid_json" => RenameError::InvalidJson(context),
+            "invalid_name" => RenameError::InvalidName(context),
+            "invalid_name_maxlength" => RenameError::InvalidNameMaxlength(context),
+            "invalid_name_punctuation" => RenameError::InvalidNamePunctuation(context),
+            "invalid_name_required" => RenameError::InvalidNameRequired(context),
+            "invalid_name_specials" => RenameError::InvalidNameSpecials(context),
+            "invalid_post_type" => RenameError::InvalidPostType(context),
+            "json_not_object" => RenameError::JsonNotObject(context),
             "method_not_supported_for_channel_type" => {
-                RenameError::MethodNotSupportedForChannelType
+                RenameError::MethodNotSupportedForChannelType(context)
             }
-            "missing_post_type" => RenameError::MissingPostType,
-            "missing_scope" => RenameError::MissingScope,
-            "name_taken" => RenameError::NameTaken,
-            "not_authed" => RenameError::NotAuthed,
-            "not_authorized" => RenameError::NotAuthorized,
-            "not_in_channel" => RenameError::NotInChannel,
-            "request_timeout" => RenameError::RequestTimeout,
-            "upgrade_required" => RenameError::UpgradeRequired,
-            "user_is_restricted" => RenameError::UserIsRestricted,
-            _ => RenameError::Unknown(s.to_owned()),
+            "missing_post_type" => RenameError::MissingPostType(context),
+            "missing_scope" => RenameError::MissingScope(context),
+            "name_taken" => RenameError::NameTaken(context),
+            "not_authed" => RenameError::NotAuthed(context),
+            "not_authorized" => RenameError::NotAuthorized(context),
+            "not_in_channel" => RenameError::NotInChannel(context),
+            "request_timeout" => RenameError::RequestTimeout(context),
+            "upgrade_required" => RenameError::UpgradeRequired(context),
+            "user_is_restricted" => RenameError::UserIsRestricted(context),
+            _ => RenameError::Unknown(code.to_owned(), context),
         }
     }
 }
 
+impl<'a, E: Error> From<&'a str> for RenameError<E> {
+    fn from(s: &'a str) -> Self {
+        Self::new(s, crate::ErrorContext::default())
+    }
+}
+
 impl<E: Error> fmt::Display for RenameError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            RenameError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            RenameError::ChannelNotFound => write!(f, "Server returned error channel_not_found"),
-            RenameError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            RenameError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            RenameError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            RenameError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            RenameError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            RenameError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            RenameError::InvalidName => write!(f, "Server returned error invalid_name"),
-            RenameError::InvalidNameMaxlength => {
+            RenameError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            RenameError::ChannelNotFound(_) => write!(f, "Server returned error channel_not_found"),
+            RenameError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            RenameError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            RenameError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            RenameError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            RenameError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            RenameError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            RenameError::InvalidName(_) => write!(f, "Server returned error invalid_name"),
+            RenameError::InvalidNameMaxlength(_) => {
                 write!(f, "Server returned error invalid_name_maxlength")
             }
-            RenameError::InvalidNamePunctuation => {
+            RenameError::InvalidNamePunctuation(_) => {
                 write!(f, "Server returned error invalid_name_punctuation")
             }
-            RenameError::InvalidNameRequired => {
+            RenameError::InvalidNameRequired(_) => {
                 write!(f, "Server returned error invalid_name_required")
             }
-            RenameError::InvalidNameSpecials => {
+            RenameError::InvalidNameSpecials(_) => {
                 write!(f, "Server returned error invalid_name_specials")
             }
-            RenameError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            RenameError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            RenameError::MethodNotSupportedForChannelType => write!(
+            RenameError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            RenameError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            RenameError::MethodNotSupportedForChannelType(_) => write!(
                 f,
                 "Server returned error method_not_supported_for_channel_type"
             ),
-            RenameError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            RenameError::MissingScope => write!(f, "Server returned error missing_scope"),
-            RenameError::NameTaken => write!(f, "Server returned error name_taken"),
-            RenameError::NotAuthed => write!(f, "Server returned error not_authed"),
-            RenameError::NotAuthorized => write!(f, "Server returned error not_authorized"),
-            RenameError::NotInChannel => write!(f, "Server returned error not_in_channel"),
-            RenameError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            RenameError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
-            RenameError::UserIsRestricted => write!(f, "Server returned error user_is_restricted"),
+            RenameError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            RenameError::MissingScope(_) => write!(f, "Server returned error missing_scope"),
+            RenameError::NameTaken(_) => write!(f, "Server returned error name_taken"),
+            RenameError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            RenameError::NotAuthorized(_) => write!(f, "Server returned error not_authorized"),
+            RenameError::NotInChannel(_) => write!(f, "Server returned error not_in_channel"),
+            RenameError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            RenameError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
+            RenameError::UserIsRestricted(_) => {
+                write!(f, "Server returned error user_is_restricted")
+            }
             RenameError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            RenameError::Unknown(ref s) => write!(f, "{}", s),
+            RenameError::Unknown(ref s, _) => write!(f, "{}", s),
             RenameError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -5507,25 +7062,109 @@ impl<E: Error + 'static> Error for RenameError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for RenameError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            RenameError::AccountInactive(_) => Some("account_inactive"),
+            RenameError::ChannelNotFound(_) => Some("channel_not_found"),
+            RenameError::InvalidArgName(_) => Some("invalid_arg_name"),
+            RenameError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            RenameError::InvalidAuth(_) => Some("invalid_auth"),
+            RenameError::InvalidCharset(_) => Some("invalid_charset"),
+            RenameError::InvalidFormData(_) => Some("invalid_form_data"),
+            RenameError::InvalidJson(_) => Some("invalid_json"),
+            RenameError::InvalidName(_) => Some("invalid_name"),
+            RenameError::InvalidNameMaxlength(_) => Some("invalid_name_maxlength"),
+            RenameError::InvalidNamePunctuation(_) => Some("invalid_name_punctuation"),
+            RenameError::InvalidNameRequired(_) => Some("invalid_name_required"),
+            RenameError::InvalidNameSpecials(_) => Some("invalid_name_specials"),
+            RenameError::InvalidPostType(_) => Some("invalid_post_type"),
+            RenameError::JsonNotObject(_) => Some("json_not_object"),
+            RenameError::MethodNotSupportedForChannelType(_) => {
+                Some("method_not_supported_for_channel_type")
+            }
+            RenameError::MissingPostType(_) => Some("missing_post_type"),
+            RenameError::MissingScope(_) => Some("missing_scope"),
+            RenameError::NameTaken(_) => Some("name_taken"),
+            RenameError::NotAuthed(_) => Some("not_authed"),
+            RenameError::NotAuthorized(_) => Some("not_authorized"),
+            RenameError::NotInChannel(_) => Some("not_in_channel"),
+            RenameError::RequestTimeout(_) => Some("request_timeout"),
+            RenameError::UpgradeRequired(_) => Some("upgrade_required"),
+            RenameError::UserIsRestricted(_) => Some("user_is_restricted"),
+            RenameError::Unknown(ref s, _) => Some(s),
+            RenameError::MalformedResponse(..) | RenameError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            RenameError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            RenameError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            RenameError::AccountInactive(ref context)
+            | RenameError::ChannelNotFound(ref context)
+            | RenameError::InvalidArgName(ref context)
+            | RenameError::InvalidArrayArg(ref context)
+            | RenameError::InvalidAuth(ref context)
+            | RenameError::InvalidCharset(ref context)
+            | RenameError::InvalidFormData(ref context)
+            | RenameError::InvalidJson(ref context)
+            | RenameError::InvalidName(ref context)
+            | RenameError::InvalidNameMaxlength(ref context)
+            | RenameError::InvalidNamePunctuation(ref context)
+            | RenameError::InvalidNameRequired(ref context)
+            | RenameError::InvalidNameSpecials(ref context)
+            | RenameError::InvalidPostType(ref context)
+            | RenameError::JsonNotObject(ref context)
+            | RenameError::MethodNotSupportedForChannelType(ref context)
+            | RenameError::MissingPostType(ref context)
+            | RenameError::MissingScope(ref context)
+            | RenameError::NameTaken(ref context)
+            | RenameError::NotAuthed(ref context)
+            | RenameError::NotAuthorized(ref context)
+            | RenameError::NotInChannel(ref context)
+            | RenameError::RequestTimeout(ref context)
+            | RenameError::UpgradeRequired(ref context)
+            | RenameError::UserIsRestricted(ref context)
+            | RenameError::Unknown(_, ref context) => Some(context),
+            RenameError::MalformedResponse(..) | RenameError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct RepliesRequest<'a> {
     /// Conversation ID to fetch thread from.
-    pub channel: Option<Cow<'a, str>>,
+    pub channel: Option<crate::ChannelId>,
     /// Paginate through collections of data by setting the `cursor` parameter to a `next_cursor` attribute returned by a previous request's `response_metadata`. Default value fetches the first "page" of the collection. See [pagination](/docs/pagination) for more detail.
     pub cursor: Option<Cow<'a, str>>,
     /// Include messages with latest or oldest timestamp in results only when either timestamp is specified.
     pub inclusive: Option<bool>,
     /// End of time range of messages to include in results.
-    pub latest: Option<f64>,
+    pub latest: Option<crate::Timestamp>,
     /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the users list hasn't been reached.
     pub limit: Option<u64>,
     /// Start of time range of messages to include in results.
-    pub oldest: Option<f64>,
+    pub oldest: Option<crate::Timestamp>,
     /// Unique identifier of a thread's parent message. `ts` must be the timestamp of an existing message with 0 or more replies. If there are no replies then just the single message referenced by `ts` will return - it is just an ordinary, unthreaded message.
-    pub ts: Option<f64>,
+    pub ts: Option<crate::Timestamp>,
+}
+
+impl crate::pagination::CursorRequest for RepliesRequest<'_> {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor.into());
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RepliesUserProfileInner {
     pub avatar_hash: String,
     pub display_name: String,
@@ -5537,30 +7176,37 @@ pub struct RepliesUserProfileInner {
     pub name: String,
     pub real_name: String,
     pub real_name_normalized: Option<String>,
-    pub team: String,
+    pub team: crate::TeamId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RepliesMessagesInner {
-    pub last_read: Option<String>,
-    pub latest_reply: Option<String>,
+    pub last_read: Option<crate::Timestamp>,
+    pub latest_reply: Option<crate::Timestamp>,
     pub reply_count: Option<u64>,
     pub reply_users: Option<Vec<String>>,
     pub reply_users_count: Option<u64>,
     pub source_team: Option<String>,
     pub subscribed: Option<bool>,
-    pub team: Option<String>,
+    pub team: Option<crate::TeamId>,
     pub text: String,
-    pub thread_ts: String,
-    pub ts: String,
+    pub thread_ts: crate::Timestamp,
+    pub ts: crate::Timestamp,
     pub r#type: String,
     pub unread_count: Option<u64>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_profile: Option<RepliesUserProfileInner>,
     pub user_team: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RepliesResponseMetadataInner {
+    pub messages: Option<Vec<String>>,
+    pub next_cursor: Option<String>,
+    pub warnings: Option<Vec<String>>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RepliesResponse {
     pub callstack: Option<String>,
     error: Option<String>,
@@ -5570,6 +7216,10 @@ pub struct RepliesResponse {
     #[serde(default)]
     ok: bool,
     pub provided: Option<String>,
+    pub response_metadata: Option<RepliesResponseMetadataInner>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<RepliesResponse, RepliesError<E>>> for RepliesResponse {
@@ -5577,85 +7227,144 @@ impl<E: Error> Into<Result<RepliesResponse, RepliesError<E>>> for RepliesRespons
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(RepliesError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for RepliesResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
+impl crate::pagination::CursorPage for RepliesResponse {
+    type Item = RepliesMessagesInner;
+
+    fn next_cursor(&self) -> Option<&str> {
+        self.response_metadata
+            .as_ref()
+            .and_then(|response_metadata| response_metadata.next_cursor.as_deref())
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.messages.unwrap_or_default()
+    }
+}
+
 #[derive(Debug)]
 pub enum RepliesError<E: Error> {
-    AccountInactive,
-    ChannelNotFound,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    MissingScope,
-    NotAuthed,
-    RequestTimeout,
-    TeamAddedToOrg,
-    ThreadNotFound,
-    UpgradeRequired,
+    AccountInactive(crate::ErrorContext),
+    ChannelNotFound(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    MissingScope(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    ThreadNotFound(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> RepliesError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => RepliesError::AccountInactive(context),
+            "channel_not_found" => RepliesError::ChannelNotFound(context),
+            "invalid_arg_name" => RepliesError::InvalidArgName(context),
+            "invalid_array_arg" => RepliesError::InvalidArrayArg(context),
+            "invalid_auth" => RepliesError::InvalidAuth(context),
+            "invalid_charset" => RepliesError::InvalidCharset(context),
+            "invalid_form_data" => RepliesError::InvalidFormData(context),
+            "invalid_json" => RepliesError::InvalidJson(context),
+            "invalid_post_type" => RepliesError::InvalidPostType(context),
+            "json_not_object" => RepliesError::JsonNotObject(context),
+            "missing_post_type" => RepliesError::MissingPostType(context),
+            "missing_scope" => RepliesError::MissingScope(context),
+            "not_authed" => RepliesError::NotAuthed(context),
+            "request_timeout" => RepliesError::RequestTimeout(context),
+            "team_added_to_org" => RepliesError::TeamAddedToOrg(context),
+            "thread_not_found" => RepliesError::ThreadNotFound(context),
+            "upgrade_required" => RepliesError::UpgradeRequired(context),
+            _ => RepliesError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for RepliesError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => RepliesError::AccountInactive,
-            "channel_not_found" => RepliesError::ChannelNotFound,
-            "invalid_arg_name" => RepliesError::InvalidArgName,
-            "invalid_array_arg" => RepliesError::InvalidArrayArg,
-            "invalid_auth" => RepliesError::InvalidAuth,
-            "invalid_charset" => RepliesError::InvalidCharset,
-            "invalid_form_data" => RepliesError::InvalidFormData,
-            "invalid_json" => RepliesError::InvalidJson,
-            "invalid_post_type" => RepliesError::InvalidPostType,
-            "json_not_object" => RepliesError::JsonNotObject,
-            "missing_post_type" => RepliesError::MissingPostType,
-            "missing_scope" => RepliesError::MissingScope,
-            "not_authed" => RepliesError::NotAuthed,
-            "request_timeout" => RepliesError::RequestTimeout,
-            "team_added_to_org" => RepliesError::TeamAddedToOrg,
-            "thread_not_found" => RepliesError::ThreadNotFound,
-            "upgrade_required" => RepliesError::UpgradeRequired,
-            _ => RepliesError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for RepliesError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            RepliesError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            RepliesError::ChannelNotFound => write!(f, "Server returned error channel_not_found"),
-            RepliesError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            RepliesError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            RepliesError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            RepliesError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            RepliesError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            RepliesError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            RepliesError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            RepliesError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            RepliesError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            RepliesError::MissingScope => write!(f, "Server returned error missing_scope"),
-            RepliesError::NotAuthed => write!(f, "Server returned error not_authed"),
-            RepliesError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            RepliesError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            RepliesError::ThreadNotFound => write!(f, "Server returned error thread_not_found"),
-            RepliesError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
+            RepliesError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            RepliesError::ChannelNotFound(_) => {
+                write!(f, "Server returned error channel_not_found")
+            }
+            RepliesError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            RepliesError::InvalidArrayArg(_) => {
+                write!(f, "Server returned error invalid_array_arg")
+            }
+            RepliesError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            RepliesError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            RepliesError::InvalidFormData(_) => {
+                write!(f, "Server returned error invalid_form_data")
+            }
+            RepliesError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            RepliesError::InvalidPostType(_) => {
+                write!(f, "Server returned error invalid_post_type")
+            }
+            RepliesError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            RepliesError::MissingPostType(_) => {
+                write!(f, "Server returned error missing_post_type")
+            }
+            RepliesError::MissingScope(_) => write!(f, "Server returned error missing_scope"),
+            RepliesError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            RepliesError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            RepliesError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            RepliesError::ThreadNotFound(_) => write!(f, "Server returned error thread_not_found"),
+            RepliesError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
             RepliesError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            RepliesError::Unknown(ref s) => write!(f, "{}", s),
+            RepliesError::Unknown(ref s, _) => write!(f, "{}", s),
             RepliesError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -5671,21 +7380,81 @@ impl<E: Error + 'static> Error for RepliesError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for RepliesError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            RepliesError::AccountInactive(_) => Some("account_inactive"),
+            RepliesError::ChannelNotFound(_) => Some("channel_not_found"),
+            RepliesError::InvalidArgName(_) => Some("invalid_arg_name"),
+            RepliesError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            RepliesError::InvalidAuth(_) => Some("invalid_auth"),
+            RepliesError::InvalidCharset(_) => Some("invalid_charset"),
+            RepliesError::InvalidFormData(_) => Some("invalid_form_data"),
+            RepliesError::InvalidJson(_) => Some("invalid_json"),
+            RepliesError::InvalidPostType(_) => Some("invalid_post_type"),
+            RepliesError::JsonNotObject(_) => Some("json_not_object"),
+            RepliesError::MissingPostType(_) => Some("missing_post_type"),
+            RepliesError::MissingScope(_) => Some("missing_scope"),
+            RepliesError::NotAuthed(_) => Some("not_authed"),
+            RepliesError::RequestTimeout(_) => Some("request_timeout"),
+            RepliesError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            RepliesError::ThreadNotFound(_) => Some("thread_not_found"),
+            RepliesError::UpgradeRequired(_) => Some("upgrade_required"),
+            RepliesError::Unknown(ref s, _) => Some(s),
+            RepliesError::MalformedResponse(..) | RepliesError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            RepliesError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            RepliesError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            RepliesError::AccountInactive(ref context)
+            | RepliesError::ChannelNotFound(ref context)
+            | RepliesError::InvalidArgName(ref context)
+            | RepliesError::InvalidArrayArg(ref context)
+            | RepliesError::InvalidAuth(ref context)
+            | RepliesError::InvalidCharset(ref context)
+            | RepliesError::InvalidFormData(ref context)
+            | RepliesError::InvalidJson(ref context)
+            | RepliesError::InvalidPostType(ref context)
+            | RepliesError::JsonNotObject(ref context)
+            | RepliesError::MissingPostType(ref context)
+            | RepliesError::MissingScope(ref context)
+            | RepliesError::NotAuthed(ref context)
+            | RepliesError::RequestTimeout(ref context)
+            | RepliesError::TeamAddedToOrg(ref context)
+            | RepliesError::ThreadNotFound(ref context)
+            | RepliesError::UpgradeRequired(ref context)
+            | RepliesError::Unknown(_, ref context) => Some(context),
+            RepliesError::MalformedResponse(..) | RepliesError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct SetPurposeRequest<'a> {
     /// Conversation to set the purpose of
-    pub channel: Option<Cow<'a, str>>,
+    pub channel: Option<crate::ChannelId>,
     /// A new, specialer purpose
     pub purpose: Option<Cow<'a, str>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetPurposeDisplayCountsInner {
     pub display_counts: u64,
     pub guest_counts: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetPurposeAttachmentsInner {
     pub fallback: Option<String>,
     pub id: u64,
@@ -5695,37 +7464,32 @@ pub struct SetPurposeAttachmentsInner {
     pub image_width: Option<u64>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct SetPurposeBlocksInner {
-    pub r#type: String,
-}
-
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetPurposeIconsInner {
     pub image_36: String,
     pub image_48: String,
     pub image_72: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetPurposeBotProfileInner {
-    pub app_id: String,
+    pub app_id: crate::AppId,
     pub deleted: bool,
     pub icons: SetPurposeIconsInner,
-    pub id: String,
+    pub id: crate::BotId,
     pub name: String,
-    pub team_id: String,
+    pub team_id: crate::TeamId,
     pub updated: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetPurposeReactionsInner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetPurposeCommentInner {
     pub comment: String,
     pub created: u64,
@@ -5737,23 +7501,23 @@ pub struct SetPurposeCommentInner {
     pub pinned_to: Option<Vec<String>>,
     pub reactions: Option<Vec<SetPurposeReactionsInner>>,
     pub timestamp: u64,
-    pub user: String,
+    pub user: crate::UserId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetPurposeReactions1Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetPurposeSharesInner {
     pub private: Option<serde_json::Value>,
     pub public: Option<serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetPurposeFileInner {
     pub channels: Option<Vec<String>>,
     pub comments_count: Option<u64>,
@@ -5768,7 +7532,7 @@ pub struct SetPurposeFileInner {
     pub filetype: Option<String>,
     pub groups: Option<Vec<String>>,
     pub has_rich_preview: Option<bool>,
-    pub id: Option<String>,
+    pub id: Option<crate::FileId>,
     pub image_exif_rotation: Option<u64>,
     pub ims: Option<Vec<String>>,
     pub is_external: Option<bool>,
@@ -5822,25 +7586,25 @@ pub struct SetPurposeFileInner {
     pub updated: Option<u64>,
     pub url_private: Option<String>,
     pub url_private_download: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetPurposeReactions2Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetPurposeShares1Inner {
     pub private: Option<serde_json::Value>,
     pub public: Option<serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetPurposeFilesInner {
     pub channels: Option<Vec<String>>,
     pub comments_count: Option<u64>,
@@ -5855,7 +7619,7 @@ pub struct SetPurposeFilesInner {
     pub filetype: Option<String>,
     pub groups: Option<Vec<String>>,
     pub has_rich_preview: Option<bool>,
-    pub id: Option<String>,
+    pub id: Option<crate::FileId>,
     pub image_exif_rotation: Option<u64>,
     pub ims: Option<Vec<String>>,
     pub is_external: Option<bool>,
@@ -5909,25 +7673,25 @@ pub struct SetPurposeFilesInner {
     pub updated: Option<u64>,
     pub url_private: Option<String>,
     pub url_private_download: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetPurposeIcons1Inner {
     pub emoji: Option<String>,
     pub image_64: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetPurposeReactions3Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetPurposeUserProfileInner {
     pub avatar_hash: String,
     pub display_name: String,
@@ -5939,13 +7703,13 @@ pub struct SetPurposeUserProfileInner {
     pub name: String,
     pub real_name: String,
     pub real_name_normalized: Option<String>,
-    pub team: String,
+    pub team: crate::TeamId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetPurposeLatestInner {
     pub attachments: Option<Vec<SetPurposeAttachmentsInner>>,
-    pub blocks: Option<Vec<SetPurposeBlocksInner>>,
+    pub blocks: Option<Vec<crate::blocks::Block>>,
     pub bot_id: Option<Vec<String>>,
     pub bot_profile: Option<SetPurposeBotProfileInner>,
     pub client_msg_id: Option<String>,
@@ -5958,8 +7722,8 @@ pub struct SetPurposeLatestInner {
     pub is_delayed_message: Option<bool>,
     pub is_intro: Option<bool>,
     pub is_starred: Option<bool>,
-    pub last_read: Option<String>,
-    pub latest_reply: Option<String>,
+    pub last_read: Option<crate::Timestamp>,
+    pub latest_reply: Option<crate::Timestamp>,
     pub name: Option<String>,
     pub old_name: Option<String>,
     pub parent_user_id: Option<String>,
@@ -5973,40 +7737,40 @@ pub struct SetPurposeLatestInner {
     pub source_team: Option<String>,
     pub subscribed: Option<bool>,
     pub subtype: Option<String>,
-    pub team: Option<String>,
+    pub team: Option<crate::TeamId>,
     pub text: String,
-    pub thread_ts: Option<String>,
+    pub thread_ts: Option<crate::Timestamp>,
     pub topic: Option<String>,
-    pub ts: String,
+    pub ts: crate::Timestamp,
     pub r#type: String,
     pub unread_count: Option<u64>,
     pub upload: Option<bool>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_profile: Option<SetPurposeUserProfileInner>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetPurposePurposeInner {
     pub creator: String,
     pub last_set: u64,
     pub value: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetPurposeCurrentInner {
     pub date_started: u64,
-    pub team_id: String,
+    pub team_id: crate::TeamId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetPurposeExternalOrgMigrationsInner {
     pub current: Vec<SetPurposeCurrentInner>,
     pub date_updated: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetPurposeIconInner {
     pub image_102: Option<String>,
     pub image_132: Option<String>,
@@ -6018,20 +7782,20 @@ pub struct SetPurposeIconInner {
     pub image_default: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetPurposePrimaryOwnerInner {
     pub email: String,
-    pub id: String,
+    pub id: crate::UserId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetPurposeSsoProviderInner {
     pub label: Option<String>,
     pub name: Option<String>,
     pub r#type: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetPurposeTeamInner {
     pub archived: Option<bool>,
     pub avatar_base_url: Option<String>,
@@ -6042,12 +7806,12 @@ pub struct SetPurposeTeamInner {
     pub discoverable: Option<Vec<String>>,
     pub domain: String,
     pub email_domain: String,
-    pub enterprise_id: Option<String>,
+    pub enterprise_id: Option<crate::EnterpriseId>,
     pub enterprise_name: Option<String>,
     pub external_org_migrations: Option<SetPurposeExternalOrgMigrationsInner>,
     pub has_compliance_export: Option<bool>,
     pub icon: SetPurposeIconInner,
-    pub id: String,
+    pub id: crate::TeamId,
     pub is_assigned: Option<bool>,
     pub is_enterprise: Option<u64>,
     pub is_over_storage_limit: Option<bool>,
@@ -6064,22 +7828,22 @@ pub struct SetPurposeTeamInner {
     pub sso_provider: Option<SetPurposeSsoProviderInner>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetPurposeShares2Inner {
     pub accepted_user: Option<String>,
     pub is_active: bool,
     pub team: SetPurposeTeamInner,
-    pub user: String,
+    pub user: crate::UserId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetPurposeTopicInner {
     pub creator: String,
     pub last_set: u64,
     pub value: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetPurposeChannelInner {
     pub accepted_user: Option<String>,
     pub connected_team_ids: Option<Vec<String>>,
@@ -6087,9 +7851,9 @@ pub struct SetPurposeChannelInner {
     pub created: u64,
     pub creator: String,
     pub display_counts: Option<SetPurposeDisplayCountsInner>,
-    pub enterprise_id: Option<String>,
+    pub enterprise_id: Option<crate::EnterpriseId>,
     pub has_pins: Option<bool>,
-    pub id: String,
+    pub id: crate::ChannelId,
     pub internal_team_ids: Option<Vec<String>>,
     pub is_archived: bool,
     pub is_channel: bool,
@@ -6113,7 +7877,7 @@ pub struct SetPurposeChannelInner {
     pub is_shared: bool,
     pub is_starred: Option<bool>,
     pub is_thread_only: Option<bool>,
-    pub last_read: Option<String>,
+    pub last_read: Option<crate::Timestamp>,
     pub latest: Option<Vec<SetPurposeLatestInner>>,
     pub members: Option<Vec<String>>,
     pub name: String,
@@ -6134,11 +7898,11 @@ pub struct SetPurposeChannelInner {
     pub unread_count: Option<u64>,
     pub unread_count_display: Option<u64>,
     pub use_case: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub version: Option<u64>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetPurposeResponse {
     pub callstack: Option<String>,
     pub channel: Vec<SetPurposeChannelInner>,
@@ -6147,6 +7911,10 @@ pub struct SetPurposeResponse {
     #[serde(default)]
     ok: bool,
     pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<SetPurposeResponse, SetPurposeError<E>>> for SetPurposeResponse {
@@ -6154,114 +7922,161 @@ impl<E: Error> Into<Result<SetPurposeResponse, SetPurposeError<E>>> for SetPurpo
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(SetPurposeError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for SetPurposeResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum SetPurposeError<E: Error> {
-    AccountInactive,
-    ChannelNotFound,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    IsArchived,
-    JsonNotObject,
-    MethodNotSupportedForChannelType,
-    MissingPostType,
-    MissingScope,
-    NotAuthed,
-    NotInChannel,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TooLong,
-    UpgradeRequired,
-    UserIsRestricted,
+    AccountInactive(crate::ErrorContext),
+    ChannelNotFound(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    IsArchived(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MethodNotSupportedForChannelType(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    MissingScope(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    NotInChannel(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TooLong(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserIsRestricted(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
-impl<'a, E: Error> From<&'a str> for SetPurposeError<E> {
-    fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => SetPurposeError::AccountInactive,
-            "channel_not_found" => SetPurposeError::ChannelNotFound,
-            "invalid_arg_name" => SetPurposeError::InvalidArgName,
-            "invalid_array_arg" => SetPurposeError::InvalidArrayArg,
-            "invalid_auth" => SetPurposeError::InvalidAuth,
-            "invalid_charset" => SetPurposeError::InvalidCharset,
-            "invalid_form_data" => SetPurposeError::InvalidFormData,
-            "invalid_json" => SetPurposeError::InvalidJson,
-            "invalid_post_type" => SetPurposeError::InvalidPostType,
-            "is_archived" => SetPurposeError::IsArchived,
-            "json_not_object" => SetPurposeError::JsonNotObject,
+impl<E: Error> SetPurposeError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => SetPurposeError::AccountInactive(context),
+            "channel_not_found" => SetPurposeError::ChannelNotFound(context),
+            "invalid_arg_name" => SetPurposeError::InvalidArgName(context),
+            "invalid_array_arg" => SetPurposeError::InvalidArrayArg(context),
+            "invalid_auth" => SetPurposeError::InvalidAuth(context),
+            "invalid_charset" => SetPurposeError::InvalidCharset(context),
+            "invalid_form_data" => SetPurposeError::InvalidFormData(context),
+            "invalid_json" => SetPurposeError::InvalidJson(context),
+            "invalid_post_type" => SetPurposeError::InvalidPostType(context),
+            "is_archived" => SetPurposeError::IsArchived(context),
+            "json_not_object" => SetPurposeError::JsonNotObject(context),
             "method_not_supported_for_channel_type" => {
-                SetPurposeError::MethodNotSupportedForChannelType
+                SetPurposeError::MethodNotSupportedForChannelType(context)
             }
-            "missing_post_type" => SetPurposeError::MissingPostType,
-            "missing_scope" => SetPurposeError::MissingScope,
-            "not_authed" => SetPurposeError::NotAuthed,
-            "not_in_channel" => SetPurposeError::NotInChannel,
-            "request_timeout" => SetPurposeError::RequestTimeout,
-            "team_added_to_org" => SetPurposeError::TeamAddedToOrg,
-            "too_long" => SetPurposeError::TooLong,
-            "upgrade_required" => SetPurposeError::UpgradeRequired,
-            "user_is_restricted" => SetPurposeError::UserIsRestricted,
-            _ => SetPurposeError::Unknown(s.to_owned()),
+            "missing_post_type" => SetPurposeError::MissingPostType(context),
+            "missing_scope" => SetPurposeError::MissingScope(context),
+            "not_authed" => SetPurposeError::NotAuthed(context),
+            "not_in_channel" => SetPurposeError::NotInChannel(context),
+            "request_timeout" => SetPurposeError::RequestTimeout(context),
+            "team_added_to_org" => SetPurposeError::TeamAddedToOrg(context),
+            "too_long" => SetPurposeError::TooLong(context),
+            "upgrade_required" => SetPurposeError::UpgradeRequired(context),
+            "user_is_restricted" => SetPurposeError::UserIsRestricted(context),
+            _ => SetPurposeError::Unknown(code.to_owned(), context),
         }
     }
 }
 
+impl<'a, E: Error> From<&'a str> for SetPurposeError<E> {
+    fn from(s: &'a str) -> Self {
+        Self::new(s, crate::ErrorContext::default())
+    }
+}
+
 impl<E: Error> fmt::Display for SetPurposeError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            SetPurposeError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            SetPurposeError::ChannelNotFound => {
+            SetPurposeError::AccountInactive(_) => {
+                write!(f, "Server returned error account_inactive")
+            }
+            SetPurposeError::ChannelNotFound(_) => {
                 write!(f, "Server returned error channel_not_found")
             }
-            SetPurposeError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            SetPurposeError::InvalidArrayArg => {
+            SetPurposeError::InvalidArgName(_) => {
+                write!(f, "Server returned error invalid_arg_name")
+            }
+            SetPurposeError::InvalidArrayArg(_) => {
                 write!(f, "Server returned error invalid_array_arg")
             }
-            SetPurposeError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            SetPurposeError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            SetPurposeError::InvalidFormData => {
+            SetPurposeError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            SetPurposeError::InvalidCharset(_) => {
+                write!(f, "Server returned error invalid_charset")
+            }
+            SetPurposeError::InvalidFormData(_) => {
                 write!(f, "Server returned error invalid_form_data")
             }
-            SetPurposeError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            SetPurposeError::InvalidPostType => {
+            SetPurposeError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            SetPurposeError::InvalidPostType(_) => {
                 write!(f, "Server returned error invalid_post_type")
             }
-            SetPurposeError::IsArchived => write!(f, "Server returned error is_archived"),
-            SetPurposeError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            SetPurposeError::MethodNotSupportedForChannelType => write!(
+            SetPurposeError::IsArchived(_) => write!(f, "Server returned error is_archived"),
+            SetPurposeError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            SetPurposeError::MethodNotSupportedForChannelType(_) => write!(
                 f,
                 "Server returned error method_not_supported_for_channel_type"
             ),
-            SetPurposeError::MissingPostType => {
+            SetPurposeError::MissingPostType(_) => {
                 write!(f, "Server returned error missing_post_type")
             }
-            SetPurposeError::MissingScope => write!(f, "Server returned error missing_scope"),
-            SetPurposeError::NotAuthed => write!(f, "Server returned error not_authed"),
-            SetPurposeError::NotInChannel => write!(f, "Server returned error not_in_channel"),
-            SetPurposeError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            SetPurposeError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            SetPurposeError::TooLong => write!(f, "Server returned error too_long"),
-            SetPurposeError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
-            SetPurposeError::UserIsRestricted => {
+            SetPurposeError::MissingScope(_) => write!(f, "Server returned error missing_scope"),
+            SetPurposeError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            SetPurposeError::NotInChannel(_) => write!(f, "Server returned error not_in_channel"),
+            SetPurposeError::RequestTimeout(_) => {
+                write!(f, "Server returned error request_timeout")
+            }
+            SetPurposeError::TeamAddedToOrg(_) => {
+                write!(f, "Server returned error team_added_to_org")
+            }
+            SetPurposeError::TooLong(_) => write!(f, "Server returned error too_long"),
+            SetPurposeError::UpgradeRequired(_) => {
+                write!(f, "Server returned error upgrade_required")
+            }
+            SetPurposeError::UserIsRestricted(_) => {
                 write!(f, "Server returned error user_is_restricted")
             }
             SetPurposeError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            SetPurposeError::Unknown(ref s) => write!(f, "{}", s),
+            SetPurposeError::Unknown(ref s, _) => write!(f, "{}", s),
             SetPurposeError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -6277,21 +8092,91 @@ impl<E: Error + 'static> Error for SetPurposeError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for SetPurposeError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            SetPurposeError::AccountInactive(_) => Some("account_inactive"),
+            SetPurposeError::ChannelNotFound(_) => Some("channel_not_found"),
+            SetPurposeError::InvalidArgName(_) => Some("invalid_arg_name"),
+            SetPurposeError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            SetPurposeError::InvalidAuth(_) => Some("invalid_auth"),
+            SetPurposeError::InvalidCharset(_) => Some("invalid_charset"),
+            SetPurposeError::InvalidFormData(_) => Some("invalid_form_data"),
+            SetPurposeError::InvalidJson(_) => Some("invalid_json"),
+            SetPurposeError::InvalidPostType(_) => Some("invalid_post_type"),
+            SetPurposeError::IsArchived(_) => Some("is_archived"),
+            SetPurposeError::JsonNotObject(_) => Some("json_not_object"),
+            SetPurposeError::MethodNotSupportedForChannelType(_) => {
+                Some("method_not_supported_for_channel_type")
+            }
+            SetPurposeError::MissingPostType(_) => Some("missing_post_type"),
+            SetPurposeError::MissingScope(_) => Some("missing_scope"),
+            SetPurposeError::NotAuthed(_) => Some("not_authed"),
+            SetPurposeError::NotInChannel(_) => Some("not_in_channel"),
+            SetPurposeError::RequestTimeout(_) => Some("request_timeout"),
+            SetPurposeError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            SetPurposeError::TooLong(_) => Some("too_long"),
+            SetPurposeError::UpgradeRequired(_) => Some("upgrade_required"),
+            SetPurposeError::UserIsRestricted(_) => Some("user_is_restricted"),
+            SetPurposeError::Unknown(ref s, _) => Some(s),
+            SetPurposeError::MalformedResponse(..) | SetPurposeError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            SetPurposeError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            SetPurposeError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            SetPurposeError::AccountInactive(ref context)
+            | SetPurposeError::ChannelNotFound(ref context)
+            | SetPurposeError::InvalidArgName(ref context)
+            | SetPurposeError::InvalidArrayArg(ref context)
+            | SetPurposeError::InvalidAuth(ref context)
+            | SetPurposeError::InvalidCharset(ref context)
+            | SetPurposeError::InvalidFormData(ref context)
+            | SetPurposeError::InvalidJson(ref context)
+            | SetPurposeError::InvalidPostType(ref context)
+            | SetPurposeError::IsArchived(ref context)
+            | SetPurposeError::JsonNotObject(ref context)
+            | SetPurposeError::MethodNotSupportedForChannelType(ref context)
+            | SetPurposeError::MissingPostType(ref context)
+            | SetPurposeError::MissingScope(ref context)
+            | SetPurposeError::NotAuthed(ref context)
+            | SetPurposeError::NotInChannel(ref context)
+            | SetPurposeError::RequestTimeout(ref context)
+            | SetPurposeError::TeamAddedToOrg(ref context)
+            | SetPurposeError::TooLong(ref context)
+            | SetPurposeError::UpgradeRequired(ref context)
+            | SetPurposeError::UserIsRestricted(ref context)
+            | SetPurposeError::Unknown(_, ref context) => Some(context),
+            SetPurposeError::MalformedResponse(..) | SetPurposeError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct SetTopicRequest<'a> {
     /// Conversation to set the topic of
-    pub channel: Option<Cow<'a, str>>,
+    pub channel: Option<crate::ChannelId>,
     /// The new topic string. Does not support formatting or linkification.
     pub topic: Option<Cow<'a, str>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetTopicDisplayCountsInner {
     pub display_counts: u64,
     pub guest_counts: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetTopicAttachmentsInner {
     pub fallback: Option<String>,
     pub id: u64,
@@ -6301,37 +8186,32 @@ pub struct SetTopicAttachmentsInner {
     pub image_width: Option<u64>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct SetTopicBlocksInner {
-    pub r#type: String,
-}
-
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetTopicIconsInner {
     pub image_36: String,
     pub image_48: String,
     pub image_72: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetTopicBotProfileInner {
-    pub app_id: String,
+    pub app_id: crate::AppId,
     pub deleted: bool,
     pub icons: SetTopicIconsInner,
-    pub id: String,
+    pub id: crate::BotId,
     pub name: String,
-    pub team_id: String,
+    pub team_id: crate::TeamId,
     pub updated: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetTopicReactionsInner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetTopicCommentInner {
     pub comment: String,
     pub created: u64,
@@ -6343,23 +8223,23 @@ pub struct SetTopicCommentInner {
     pub pinned_to: Option<Vec<String>>,
     pub reactions: Option<Vec<SetTopicReactionsInner>>,
     pub timestamp: u64,
-    pub user: String,
+    pub user: crate::UserId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetTopicReactions1Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetTopicSharesInner {
     pub private: Option<serde_json::Value>,
     pub public: Option<serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetTopicFileInner {
     pub channels: Option<Vec<String>>,
     pub comments_count: Option<u64>,
@@ -6374,7 +8254,7 @@ pub struct SetTopicFileInner {
     pub filetype: Option<String>,
     pub groups: Option<Vec<String>>,
     pub has_rich_preview: Option<bool>,
-    pub id: Option<String>,
+    pub id: Option<crate::FileId>,
     pub image_exif_rotation: Option<u64>,
     pub ims: Option<Vec<String>>,
     pub is_external: Option<bool>,
@@ -6428,25 +8308,25 @@ pub struct SetTopicFileInner {
     pub updated: Option<u64>,
     pub url_private: Option<String>,
     pub url_private_download: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetTopicReactions2Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetTopicShares1Inner {
     pub private: Option<serde_json::Value>,
     pub public: Option<serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetTopicFilesInner {
     pub channels: Option<Vec<String>>,
     pub comments_count: Option<u64>,
@@ -6461,7 +8341,7 @@ pub struct SetTopicFilesInner {
     pub filetype: Option<String>,
     pub groups: Option<Vec<String>>,
     pub has_rich_preview: Option<bool>,
-    pub id: Option<String>,
+    pub id: Option<crate::FileId>,
     pub image_exif_rotation: Option<u64>,
     pub ims: Option<Vec<String>>,
     pub is_external: Option<bool>,
@@ -6515,25 +8395,25 @@ pub struct SetTopicFilesInner {
     pub updated: Option<u64>,
     pub url_private: Option<String>,
     pub url_private_download: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetTopicIcons1Inner {
     pub emoji: Option<String>,
     pub image_64: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetTopicReactions3Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetTopicUserProfileInner {
     pub avatar_hash: String,
     pub display_name: String,
@@ -6545,13 +8425,13 @@ pub struct SetTopicUserProfileInner {
     pub name: String,
     pub real_name: String,
     pub real_name_normalized: Option<String>,
-    pub team: String,
+    pub team: crate::TeamId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetTopicLatestInner {
     pub attachments: Option<Vec<SetTopicAttachmentsInner>>,
-    pub blocks: Option<Vec<SetTopicBlocksInner>>,
+    pub blocks: Option<Vec<crate::blocks::Block>>,
     pub bot_id: Option<Vec<String>>,
     pub bot_profile: Option<SetTopicBotProfileInner>,
     pub client_msg_id: Option<String>,
@@ -6564,8 +8444,8 @@ pub struct SetTopicLatestInner {
     pub is_delayed_message: Option<bool>,
     pub is_intro: Option<bool>,
     pub is_starred: Option<bool>,
-    pub last_read: Option<String>,
-    pub latest_reply: Option<String>,
+    pub last_read: Option<crate::Timestamp>,
+    pub latest_reply: Option<crate::Timestamp>,
     pub name: Option<String>,
     pub old_name: Option<String>,
     pub parent_user_id: Option<String>,
@@ -6579,40 +8459,40 @@ pub struct SetTopicLatestInner {
     pub source_team: Option<String>,
     pub subscribed: Option<bool>,
     pub subtype: Option<String>,
-    pub team: Option<String>,
+    pub team: Option<crate::TeamId>,
     pub text: String,
-    pub thread_ts: Option<String>,
+    pub thread_ts: Option<crate::Timestamp>,
     pub topic: Option<String>,
-    pub ts: String,
+    pub ts: crate::Timestamp,
     pub r#type: String,
     pub unread_count: Option<u64>,
     pub upload: Option<bool>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_profile: Option<SetTopicUserProfileInner>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetTopicPurposeInner {
     pub creator: String,
     pub last_set: u64,
     pub value: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetTopicCurrentInner {
     pub date_started: u64,
-    pub team_id: String,
+    pub team_id: crate::TeamId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetTopicExternalOrgMigrationsInner {
     pub current: Vec<SetTopicCurrentInner>,
     pub date_updated: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetTopicIconInner {
     pub image_102: Option<String>,
     pub image_132: Option<String>,
@@ -6624,20 +8504,20 @@ pub struct SetTopicIconInner {
     pub image_default: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetTopicPrimaryOwnerInner {
     pub email: String,
-    pub id: String,
+    pub id: crate::UserId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetTopicSsoProviderInner {
     pub label: Option<String>,
     pub name: Option<String>,
     pub r#type: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetTopicTeamInner {
     pub archived: Option<bool>,
     pub avatar_base_url: Option<String>,
@@ -6648,12 +8528,12 @@ pub struct SetTopicTeamInner {
     pub discoverable: Option<Vec<String>>,
     pub domain: String,
     pub email_domain: String,
-    pub enterprise_id: Option<String>,
+    pub enterprise_id: Option<crate::EnterpriseId>,
     pub enterprise_name: Option<String>,
     pub external_org_migrations: Option<SetTopicExternalOrgMigrationsInner>,
     pub has_compliance_export: Option<bool>,
     pub icon: SetTopicIconInner,
-    pub id: String,
+    pub id: crate::TeamId,
     pub is_assigned: Option<bool>,
     pub is_enterprise: Option<u64>,
     pub is_over_storage_limit: Option<bool>,
@@ -6670,22 +8550,22 @@ pub struct SetTopicTeamInner {
     pub sso_provider: Option<SetTopicSsoProviderInner>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetTopicShares2Inner {
     pub accepted_user: Option<String>,
     pub is_active: bool,
     pub team: SetTopicTeamInner,
-    pub user: String,
+    pub user: crate::UserId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetTopicTopicInner {
     pub creator: String,
     pub last_set: u64,
     pub value: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetTopicChannelInner {
     pub accepted_user: Option<String>,
     pub connected_team_ids: Option<Vec<String>>,
@@ -6693,9 +8573,9 @@ pub struct SetTopicChannelInner {
     pub created: u64,
     pub creator: String,
     pub display_counts: Option<SetTopicDisplayCountsInner>,
-    pub enterprise_id: Option<String>,
+    pub enterprise_id: Option<crate::EnterpriseId>,
     pub has_pins: Option<bool>,
-    pub id: String,
+    pub id: crate::ChannelId,
     pub internal_team_ids: Option<Vec<String>>,
     pub is_archived: bool,
     pub is_channel: bool,
@@ -6719,7 +8599,7 @@ pub struct SetTopicChannelInner {
     pub is_shared: bool,
     pub is_starred: Option<bool>,
     pub is_thread_only: Option<bool>,
-    pub last_read: Option<String>,
+    pub last_read: Option<crate::Timestamp>,
     pub latest: Option<Vec<SetTopicLatestInner>>,
     pub members: Option<Vec<String>>,
     pub name: String,
@@ -6740,11 +8620,11 @@ pub struct SetTopicChannelInner {
     pub unread_count: Option<u64>,
     pub unread_count_display: Option<u64>,
     pub use_case: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub version: Option<u64>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetTopicResponse {
     pub callstack: Option<String>,
     pub channel: Vec<SetTopicChannelInner>,
@@ -6753,6 +8633,10 @@ pub struct SetTopicResponse {
     #[serde(default)]
     ok: bool,
     pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<SetTopicResponse, SetTopicError<E>>> for SetTopicResponse {
@@ -6760,104 +8644,155 @@ impl<E: Error> Into<Result<SetTopicResponse, SetTopicError<E>>> for SetTopicResp
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(SetTopicError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for SetTopicResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum SetTopicError<E: Error> {
-    AccountInactive,
-    ChannelNotFound,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    IsArchived,
-    JsonNotObject,
-    MethodNotSupportedForChannelType,
-    MissingPostType,
-    MissingScope,
-    NotAuthed,
-    NotInChannel,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TooLong,
-    UpgradeRequired,
-    UserIsRestricted,
+    AccountInactive(crate::ErrorContext),
+    ChannelNotFound(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    IsArchived(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MethodNotSupportedForChannelType(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    MissingScope(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    NotInChannel(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TooLong(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserIsRestricted(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
-impl<'a, E: Error> From<&'a str> for SetTopicError<E> {
-    fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => SetTopicError::AccountInactive,
-            "channel_not_found" => SetTopicError::ChannelNotFound,
-            "invalid_arg_name" => SetTopicError::InvalidArgName,
-            "invalid_array_arg" => SetTopicError::InvalidArrayArg,
-            "invalid_auth" => SetTopicError::InvalidAuth,
-            "invalid_charset" => SetTopicError::InvalidCharset,
-            "invalid_form_data" => SetTopicError::InvalidFormData,
-            "invalid_json" => SetTopicError::InvalidJson,
-            "invalid_post_type" => SetTopicError::InvalidPostType,
-            "is_archived" => SetTopicError::IsArchived,
-            "json_not_object" => SetTopicError::JsonNotObject,
+impl<E: Error> SetTopicError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => SetTopicError::AccountInactive(context),
+            "channel_not_found" => SetTopicError::ChannelNotFound(context),
+            "invalid_arg_name" => SetTopicError::InvalidArgName(context),
+            "invalid_array_arg" => SetTopicError::InvalidArrayArg(context),
+            "invalid_auth" => SetTopicError::InvalidAuth(context),
+            "invalid_charset" => SetTopicError::InvalidCharset(context),
+            "invalid_form_data" => SetTopicError::InvalidFormData(context),
+            "invalid_json" => SetTopicError::InvalidJson(context),
+            "invalid_post_type" => SetTopicError::InvalidPostType(context),
+            "is_archived" => SetTopicError::IsArchived(context),
+            "json_not_object" => SetTopicError::JsonNotObject(context),
             "method_not_supported_for_channel_type" => {
-                SetTopicError::MethodNotSupportedForChannelType
+                SetTopicError::MethodNotSupportedForChannelType(context)
             }
-            "missing_post_type" => SetTopicError::MissingPostType,
-            "missing_scope" => SetTopicError::MissingScope,
-            "not_authed" => SetTopicError::NotAuthed,
-            "not_in_channel" => SetTopicError::NotInChannel,
-            "request_timeout" => SetTopicError::RequestTimeout,
-            "team_added_to_org" => SetTopicError::TeamAddedToOrg,
-            "too_long" => SetTopicError::TooLong,
-            "upgrade_required" => SetTopicError::UpgradeRequired,
-            "user_is_restricted" => SetTopicError::UserIsRestricted,
-            _ => SetTopicError::Unknown(s.to_owned()),
+            "missing_post_type" => SetTopicError::MissingPostType(context),
+            "missing_scope" => SetTopicError::MissingScope(context),
+            "not_authed" => SetTopicError::NotAuthed(context),
+            "not_in_channel" => SetTopicError::NotInChannel(context),
+            "request_timeout" => SetTopicError::RequestTimeout(context),
+            "team_added_to_org" => SetTopicError::TeamAddedToOrg(context),
+            "too_long" => SetTopicError::TooLong(context),
+            "upgrade_required" => SetTopicError::UpgradeRequired(context),
+            "user_is_restricted" => SetTopicError::UserIsRestricted(context),
+            _ => SetTopicError::Unknown(code.to_owned(), context),
         }
     }
 }
 
+impl<'a, E: Error> From<&'a str> for SetTopicError<E> {
+    fn from(s: &'a str) -> Self {
+        Self::new(s, crate::ErrorContext::default())
+    }
+}
+
 impl<E: Error> fmt::Display for SetTopicError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            SetTopicError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            SetTopicError::ChannelNotFound => write!(f, "Server returned error channel_not_found"),
-            SetTopicError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            SetTopicError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            SetTopicError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            SetTopicError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            SetTopicError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            SetTopicError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            SetTopicError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            SetTopicError::IsArchived => write!(f, "Server returned error is_archived"),
-            SetTopicError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            SetTopicError::MethodNotSupportedForChannelType => write!(
+            SetTopicError::AccountInactive(_) => {
+                write!(f, "Server returned error account_inactive")
+            }
+            SetTopicError::ChannelNotFound(_) => {
+                write!(f, "Server returned error channel_not_found")
+            }
+            SetTopicError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            SetTopicError::InvalidArrayArg(_) => {
+                write!(f, "Server returned error invalid_array_arg")
+            }
+            SetTopicError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            SetTopicError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            SetTopicError::InvalidFormData(_) => {
+                write!(f, "Server returned error invalid_form_data")
+            }
+            SetTopicError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            SetTopicError::InvalidPostType(_) => {
+                write!(f, "Server returned error invalid_post_type")
+            }
+            SetTopicError::IsArchived(_) => write!(f, "Server returned error is_archived"),
+            SetTopicError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            SetTopicError::MethodNotSupportedForChannelType(_) => write!(
                 f,
                 "Server returned error method_not_supported_for_channel_type"
             ),
-            SetTopicError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            SetTopicError::MissingScope => write!(f, "Server returned error missing_scope"),
-            SetTopicError::NotAuthed => write!(f, "Server returned error not_authed"),
-            SetTopicError::NotInChannel => write!(f, "Server returned error not_in_channel"),
-            SetTopicError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            SetTopicError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            SetTopicError::TooLong => write!(f, "Server returned error too_long"),
-            SetTopicError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
-            SetTopicError::UserIsRestricted => {
+            SetTopicError::MissingPostType(_) => {
+                write!(f, "Server returned error missing_post_type")
+            }
+            SetTopicError::MissingScope(_) => write!(f, "Server returned error missing_scope"),
+            SetTopicError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            SetTopicError::NotInChannel(_) => write!(f, "Server returned error not_in_channel"),
+            SetTopicError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            SetTopicError::TeamAddedToOrg(_) => {
+                write!(f, "Server returned error team_added_to_org")
+            }
+            SetTopicError::TooLong(_) => write!(f, "Server returned error too_long"),
+            SetTopicError::UpgradeRequired(_) => {
+                write!(f, "Server returned error upgrade_required")
+            }
+            SetTopicError::UserIsRestricted(_) => {
                 write!(f, "Server returned error user_is_restricted")
             }
             SetTopicError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            SetTopicError::Unknown(ref s) => write!(f, "{}", s),
+            SetTopicError::Unknown(ref s, _) => write!(f, "{}", s),
             SetTopicError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -6873,13 +8808,83 @@ impl<E: Error + 'static> Error for SetTopicError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for SetTopicError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            SetTopicError::AccountInactive(_) => Some("account_inactive"),
+            SetTopicError::ChannelNotFound(_) => Some("channel_not_found"),
+            SetTopicError::InvalidArgName(_) => Some("invalid_arg_name"),
+            SetTopicError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            SetTopicError::InvalidAuth(_) => Some("invalid_auth"),
+            SetTopicError::InvalidCharset(_) => Some("invalid_charset"),
+            SetTopicError::InvalidFormData(_) => Some("invalid_form_data"),
+            SetTopicError::InvalidJson(_) => Some("invalid_json"),
+            SetTopicError::InvalidPostType(_) => Some("invalid_post_type"),
+            SetTopicError::IsArchived(_) => Some("is_archived"),
+            SetTopicError::JsonNotObject(_) => Some("json_not_object"),
+            SetTopicError::MethodNotSupportedForChannelType(_) => {
+                Some("method_not_supported_for_channel_type")
+            }
+            SetTopicError::MissingPostType(_) => Some("missing_post_type"),
+            SetTopicError::MissingScope(_) => Some("missing_scope"),
+            SetTopicError::NotAuthed(_) => Some("not_authed"),
+            SetTopicError::NotInChannel(_) => Some("not_in_channel"),
+            SetTopicError::RequestTimeout(_) => Some("request_timeout"),
+            SetTopicError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            SetTopicError::TooLong(_) => Some("too_long"),
+            SetTopicError::UpgradeRequired(_) => Some("upgrade_required"),
+            SetTopicError::UserIsRestricted(_) => Some("user_is_restricted"),
+            SetTopicError::Unknown(ref s, _) => Some(s),
+            SetTopicError::MalformedResponse(..) | SetTopicError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            SetTopicError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            SetTopicError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            SetTopicError::AccountInactive(ref context)
+            | SetTopicError::ChannelNotFound(ref context)
+            | SetTopicError::InvalidArgName(ref context)
+            | SetTopicError::InvalidArrayArg(ref context)
+            | SetTopicError::InvalidAuth(ref context)
+            | SetTopicError::InvalidCharset(ref context)
+            | SetTopicError::InvalidFormData(ref context)
+            | SetTopicError::InvalidJson(ref context)
+            | SetTopicError::InvalidPostType(ref context)
+            | SetTopicError::IsArchived(ref context)
+            | SetTopicError::JsonNotObject(ref context)
+            | SetTopicError::MethodNotSupportedForChannelType(ref context)
+            | SetTopicError::MissingPostType(ref context)
+            | SetTopicError::MissingScope(ref context)
+            | SetTopicError::NotAuthed(ref context)
+            | SetTopicError::NotInChannel(ref context)
+            | SetTopicError::RequestTimeout(ref context)
+            | SetTopicError::TeamAddedToOrg(ref context)
+            | SetTopicError::TooLong(ref context)
+            | SetTopicError::UpgradeRequired(ref context)
+            | SetTopicError::UserIsRestricted(ref context)
+            | SetTopicError::Unknown(_, ref context) => Some(context),
+            SetTopicError::MalformedResponse(..) | SetTopicError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
-pub struct UnarchiveRequest<'a> {
+pub struct UnarchiveRequest {
     /// ID of conversation to unarchive
-    pub channel: Option<Cow<'a, str>>,
+    pub channel: Option<crate::ChannelId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UnarchiveResponse {
     pub callstack: Option<String>,
     error: Option<String>,
@@ -6887,6 +8892,10 @@ pub struct UnarchiveResponse {
     #[serde(default)]
     ok: bool,
     pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<UnarchiveResponse, UnarchiveError<E>>> for UnarchiveResponse {
@@ -6894,114 +8903,167 @@ impl<E: Error> Into<Result<UnarchiveResponse, UnarchiveError<E>>> for UnarchiveR
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(UnarchiveError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for UnarchiveResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum UnarchiveError<E: Error> {
-    AccountInactive,
-    ChannelNotFound,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MethodNotSupportedForChannelType,
-    MissingCharset,
-    MissingPostType,
-    MissingScope,
-    NotArchived,
-    NotAuthed,
-    RequestTimeout,
-    SuperfluousCharset,
-    TeamAddedToOrg,
-    UpgradeRequired,
-    UserIsBot,
-    UserIsRestricted,
-    UserIsUltraRestricted,
+    AccountInactive(crate::ErrorContext),
+    ChannelNotFound(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MethodNotSupportedForChannelType(crate::ErrorContext),
+    MissingCharset(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    MissingScope(crate::ErrorContext),
+    NotArchived(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    SuperfluousCharset(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserIsBot(crate::ErrorContext),
+    UserIsRestricted(crate::ErrorContext),
+    UserIsUltraRestricted(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
-impl<'a, E: Error> From<&'a str> for UnarchiveError<E> {
-    fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => UnarchiveError::AccountInactive,
-            "channel_not_found" => UnarchiveError::ChannelNotFound,
-            "invalid_arg_name" => UnarchiveError::InvalidArgName,
-            "invalid_array_arg" => UnarchiveError::InvalidArrayArg,
-            "invalid_auth" => UnarchiveError::InvalidAuth,
-            "invalid_charset" => UnarchiveError::InvalidCharset,
-            "invalid_form_data" => UnarchiveError::InvalidFormData,
-            "invalid_json" => UnarchiveError::InvalidJson,
-            "invalid_post_type" => UnarchiveError::InvalidPostType,
-            "json_not_object" => UnarchiveError::JsonNotObject,
+impl<E: Error> UnarchiveError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => UnarchiveError::AccountInactive(context),
+            "channel_not_found" => UnarchiveError::ChannelNotFound(context),
+            "invalid_arg_name" => UnarchiveError::InvalidArgName(context),
+            "invalid_array_arg" => UnarchiveError::InvalidArrayArg(context),
+            "invalid_auth" => UnarchiveError::InvalidAuth(context),
+            "invalid_charset" => UnarchiveError::InvalidCharset(context),
+            "invalid_form_data" => UnarchiveError::InvalidFormData(context),
+            "invalid_json" => UnarchiveError::InvalidJson(context),
+            "invalid_post_type" => UnarchiveError::InvalidPostType(context),
+            "json_not_object" => UnarchiveError::JsonNotObject(context),
             "method_not_supported_for_channel_type" => {
-                UnarchiveError::MethodNotSupportedForChannelType
+                UnarchiveError::MethodNotSupportedForChannelType(context)
             }
-            "missing_charset" => UnarchiveError::MissingCharset,
-            "missing_post_type" => UnarchiveError::MissingPostType,
-            "missing_scope" => UnarchiveError::MissingScope,
-            "not_archived" => UnarchiveError::NotArchived,
-            "not_authed" => UnarchiveError::NotAuthed,
-            "request_timeout" => UnarchiveError::RequestTimeout,
-            "superfluous_charset" => UnarchiveError::SuperfluousCharset,
-            "team_added_to_org" => UnarchiveError::TeamAddedToOrg,
-            "upgrade_required" => UnarchiveError::UpgradeRequired,
-            "user_is_bot" => UnarchiveError::UserIsBot,
-            "user_is_restricted" => UnarchiveError::UserIsRestricted,
-            "user_is_ultra_restricted" => UnarchiveError::UserIsUltraRestricted,
-            _ => UnarchiveError::Unknown(s.to_owned()),
+            "missing_charset" => UnarchiveError::MissingCharset(context),
+            "missing_post_type" => UnarchiveError::MissingPostType(context),
+            "missing_scope" => UnarchiveError::MissingScope(context),
+            "not_archived" => UnarchiveError::NotArchived(context),
+            "not_authed" => UnarchiveError::NotAuthed(context),
+            "request_timeout" => UnarchiveError::RequestTimeout(context),
+            "superfluous_charset" => UnarchiveError::SuperfluousCharset(context),
+            "team_added_to_org" => UnarchiveError::TeamAddedToOrg(context),
+            "upgrade_required" => UnarchiveError::UpgradeRequired(context),
+            "user_is_bot" => UnarchiveError::UserIsBot(context),
+            "user_is_restricted" => UnarchiveError::UserIsRestricted(context),
+            "user_is_ultra_restricted" => UnarchiveError::UserIsUltraRestricted(context),
+            _ => UnarchiveError::Unknown(code.to_owned(), context),
         }
     }
 }
 
+impl<'a, E: Error> From<&'a str> for UnarchiveError<E> {
+    fn from(s: &'a str) -> Self {
+        Self::new(s, crate::ErrorContext::default())
+    }
+}
+
 impl<E: Error> fmt::Display for UnarchiveError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            UnarchiveError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            UnarchiveError::ChannelNotFound => write!(f, "Server returned error channel_not_found"),
-            UnarchiveError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            UnarchiveError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            UnarchiveError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            UnarchiveError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            UnarchiveError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            UnarchiveError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            UnarchiveError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            UnarchiveError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            UnarchiveError::MethodNotSupportedForChannelType => write!(
+            UnarchiveError::AccountInactive(_) => {
+                write!(f, "Server returned error account_inactive")
+            }
+            UnarchiveError::ChannelNotFound(_) => {
+                write!(f, "Server returned error channel_not_found")
+            }
+            UnarchiveError::InvalidArgName(_) => {
+                write!(f, "Server returned error invalid_arg_name")
+            }
+            UnarchiveError::InvalidArrayArg(_) => {
+                write!(f, "Server returned error invalid_array_arg")
+            }
+            UnarchiveError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            UnarchiveError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            UnarchiveError::InvalidFormData(_) => {
+                write!(f, "Server returned error invalid_form_data")
+            }
+            UnarchiveError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            UnarchiveError::InvalidPostType(_) => {
+                write!(f, "Server returned error invalid_post_type")
+            }
+            UnarchiveError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            UnarchiveError::MethodNotSupportedForChannelType(_) => write!(
                 f,
                 "Server returned error method_not_supported_for_channel_type"
             ),
-            UnarchiveError::MissingCharset => write!(f, "Server returned error missing_charset"),
-            UnarchiveError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            UnarchiveError::MissingScope => write!(f, "Server returned error missing_scope"),
-            UnarchiveError::NotArchived => write!(f, "Server returned error not_archived"),
-            UnarchiveError::NotAuthed => write!(f, "Server returned error not_authed"),
-            UnarchiveError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            UnarchiveError::SuperfluousCharset => {
+            UnarchiveError::MissingCharset(_) => write!(f, "Server returned error missing_charset"),
+            UnarchiveError::MissingPostType(_) => {
+                write!(f, "Server returned error missing_post_type")
+            }
+            UnarchiveError::MissingScope(_) => write!(f, "Server returned error missing_scope"),
+            UnarchiveError::NotArchived(_) => write!(f, "Server returned error not_archived"),
+            UnarchiveError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            UnarchiveError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            UnarchiveError::SuperfluousCharset(_) => {
                 write!(f, "Server returned error superfluous_charset")
             }
-            UnarchiveError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            UnarchiveError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
-            UnarchiveError::UserIsBot => write!(f, "Server returned error user_is_bot"),
-            UnarchiveError::UserIsRestricted => {
+            UnarchiveError::TeamAddedToOrg(_) => {
+                write!(f, "Server returned error team_added_to_org")
+            }
+            UnarchiveError::UpgradeRequired(_) => {
+                write!(f, "Server returned error upgrade_required")
+            }
+            UnarchiveError::UserIsBot(_) => write!(f, "Server returned error user_is_bot"),
+            UnarchiveError::UserIsRestricted(_) => {
                 write!(f, "Server returned error user_is_restricted")
             }
-            UnarchiveError::UserIsUltraRestricted => {
+            UnarchiveError::UserIsUltraRestricted(_) => {
                 write!(f, "Server returned error user_is_ultra_restricted")
             }
             UnarchiveError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            UnarchiveError::Unknown(ref s) => write!(f, "{}", s),
+            UnarchiveError::Unknown(ref s, _) => write!(f, "{}", s),
             UnarchiveError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -7016,3 +9078,77 @@ impl<E: Error + 'static> Error for UnarchiveError<E> {
         }
     }
 }
+
+impl<E: Error + 'static> crate::MethodError for UnarchiveError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            UnarchiveError::AccountInactive(_) => Some("account_inactive"),
+            UnarchiveError::ChannelNotFound(_) => Some("channel_not_found"),
+            UnarchiveError::InvalidArgName(_) => Some("invalid_arg_name"),
+            UnarchiveError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            UnarchiveError::InvalidAuth(_) => Some("invalid_auth"),
+            UnarchiveError::InvalidCharset(_) => Some("invalid_charset"),
+            UnarchiveError::InvalidFormData(_) => Some("invalid_form_data"),
+            UnarchiveError::InvalidJson(_) => Some("invalid_json"),
+            UnarchiveError::InvalidPostType(_) => Some("invalid_post_type"),
+            UnarchiveError::JsonNotObject(_) => Some("json_not_object"),
+            UnarchiveError::MethodNotSupportedForChannelType(_) => {
+                Some("method_not_supported_for_channel_type")
+            }
+            UnarchiveError::MissingCharset(_) => Some("missing_charset"),
+            UnarchiveError::MissingPostType(_) => Some("missing_post_type"),
+            UnarchiveError::MissingScope(_) => Some("missing_scope"),
+            UnarchiveError::NotArchived(_) => Some("not_archived"),
+            UnarchiveError::NotAuthed(_) => Some("not_authed"),
+            UnarchiveError::RequestTimeout(_) => Some("request_timeout"),
+            UnarchiveError::SuperfluousCharset(_) => Some("superfluous_charset"),
+            UnarchiveError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            UnarchiveError::UpgradeRequired(_) => Some("upgrade_required"),
+            UnarchiveError::UserIsBot(_) => Some("user_is_bot"),
+            UnarchiveError::UserIsRestricted(_) => Some("user_is_restricted"),
+            UnarchiveError::UserIsUltraRestricted(_) => Some("user_is_ultra_restricted"),
+            UnarchiveError::Unknown(ref s, _) => Some(s),
+            UnarchiveError::MalformedResponse(..) | UnarchiveError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            UnarchiveError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            UnarchiveError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            UnarchiveError::AccountInactive(ref context)
+            | UnarchiveError::ChannelNotFound(ref context)
+            | UnarchiveError::InvalidArgName(ref context)
+            | UnarchiveError::InvalidArrayArg(ref context)
+            | UnarchiveError::InvalidAuth(ref context)
+            | UnarchiveError::InvalidCharset(ref context)
+            | UnarchiveError::InvalidFormData(ref context)
+            | UnarchiveError::InvalidJson(ref context)
+            | UnarchiveError::InvalidPostType(ref context)
+            | UnarchiveError::JsonNotObject(ref context)
+            | UnarchiveError::MethodNotSupportedForChannelType(ref context)
+            | UnarchiveError::MissingCharset(ref context)
+            | UnarchiveError::MissingPostType(ref context)
+            | UnarchiveError::MissingScope(ref context)
+            | UnarchiveError::NotArchived(ref context)
+            | UnarchiveError::NotAuthed(ref context)
+            | UnarchiveError::RequestTimeout(ref context)
+            | UnarchiveError::SuperfluousCharset(ref context)
+            | UnarchiveError::TeamAddedToOrg(ref context)
+            | UnarchiveError::UpgradeRequired(ref context)
+            | UnarchiveError::UserIsBot(ref context)
+            | UnarchiveError::UserIsRestricted(ref context)
+            | UnarchiveError::UserIsUltraRestricted(ref context)
+            | UnarchiveError::Unknown(_, ref context) => Some(context),
+            UnarchiveError::MalformedResponse(..) | UnarchiveError::Client(_) => None,
+        }
+    }
+}
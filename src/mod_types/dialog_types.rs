@@ -23,18 +23,24 @@ use std::fmt;
 
 #[derive(Clone, Default, Debug)]
 pub struct OpenRequest<'a> {
-    /// The dialog definition. This must be a JSON-encoded string.
-    pub dialog: Cow<'a, str>,
+    /// The dialog definition.
+    pub dialog: serde_json::Value,
     /// Exchange a trigger to post to the user.
     pub trigger_id: Cow<'a, str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct OpenResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<OpenResponse, OpenError<E>>> for OpenResponse {
@@ -42,121 +48,156 @@ impl<E: Error> Into<Result<OpenResponse, OpenError<E>>> for OpenResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(OpenError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for OpenResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum OpenError<E: Error> {
-    AccountInactive,
-    AppMissingActionUrl,
-    CannotCreateDialog,
-    FailedSendingDialog,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    InvalidTrigger,
-    JsonNotObject,
-    MissingDialog,
-    MissingPostType,
-    MissingTrigger,
-    NoPermission,
-    NotAuthed,
-    OrgLoginRequired,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TokenRevoked,
-    TriggerExchanged,
-    TriggerExpired,
-    UpgradeRequired,
-    ValidationErrors,
+    AccountInactive(crate::ErrorContext),
+    AppMissingActionUrl(crate::ErrorContext),
+    CannotCreateDialog(crate::ErrorContext),
+    FailedSendingDialog(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    InvalidTrigger(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingDialog(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    MissingTrigger(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    TriggerExchanged(crate::ErrorContext),
+    TriggerExpired(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    ValidationErrors(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> OpenError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => OpenError::AccountInactive(context),
+            "app_missing_action_url" => OpenError::AppMissingActionUrl(context),
+            "cannot_create_dialog" => OpenError::CannotCreateDialog(context),
+            "failed_sending_dialog" => OpenError::FailedSendingDialog(context),
+            "fatal_error" => OpenError::FatalError(context),
+            "invalid_arg_name" => OpenError::InvalidArgName(context),
+            "invalid_array_arg" => OpenError::InvalidArrayArg(context),
+            "invalid_auth" => OpenError::InvalidAuth(context),
+            "invalid_charset" => OpenError::InvalidCharset(context),
+            "invalid_form_data" => OpenError::InvalidFormData(context),
+            "invalid_json" => OpenError::InvalidJson(context),
+            "invalid_post_type" => OpenError::InvalidPostType(context),
+            "invalid_trigger" => OpenError::InvalidTrigger(context),
+            "json_not_object" => OpenError::JsonNotObject(context),
+            "missing_dialog" => OpenError::MissingDialog(context),
+            "missing_post_type" => OpenError::MissingPostType(context),
+            "missing_trigger" => OpenError::MissingTrigger(context),
+            "no_permission" => OpenError::NoPermission(context),
+            "not_authed" => OpenError::NotAuthed(context),
+            "org_login_required" => OpenError::OrgLoginRequired(context),
+            "request_timeout" => OpenError::RequestTimeout(context),
+            "team_added_to_org" => OpenError::TeamAddedToOrg(context),
+            "token_revoked" => OpenError::TokenRevoked(context),
+            "trigger_exchanged" => OpenError::TriggerExchanged(context),
+            "trigger_expired" => OpenError::TriggerExpired(context),
+            "upgrade_required" => OpenError::UpgradeRequired(context),
+            "validation_errors" => OpenError::ValidationErrors(context),
+            _ => OpenError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for OpenError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => OpenError::AccountInactive,
-            "app_missing_action_url" => OpenError::AppMissingActionUrl,
-            "cannot_create_dialog" => OpenError::CannotCreateDialog,
-            "failed_sending_dialog" => OpenError::FailedSendingDialog,
-            "fatal_error" => OpenError::FatalError,
-            "invalid_arg_name" => OpenError::InvalidArgName,
-            "invalid_array_arg" => OpenError::InvalidArrayArg,
-            "invalid_auth" => OpenError::InvalidAuth,
-            "invalid_charset" => OpenError::InvalidCharset,
-            "invalid_form_data" => OpenError::InvalidFormData,
-            "invalid_json" => OpenError::InvalidJson,
-            "invalid_post_type" => OpenError::InvalidPostType,
-            "invalid_trigger" => OpenError::InvalidTrigger,
-            "json_not_object" => OpenError::JsonNotObject,
-            "missing_dialog" => OpenError::MissingDialog,
-            "missing_post_type" => OpenError::MissingPostType,
-            "missing_trigger" => OpenError::MissingTrigger,
-            "no_permission" => OpenError::NoPermission,
-            "not_authed" => OpenError::NotAuthed,
-            "org_login_required" => OpenError::OrgLoginRequired,
-            "request_timeout" => OpenError::RequestTimeout,
-            "team_added_to_org" => OpenError::TeamAddedToOrg,
-            "token_revoked" => OpenError::TokenRevoked,
-            "trigger_exchanged" => OpenError::TriggerExchanged,
-            "trigger_expired" => OpenError::TriggerExpired,
-            "upgrade_required" => OpenError::UpgradeRequired,
-            "validation_errors" => OpenError::ValidationErrors,
-            _ => OpenError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for OpenError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            OpenError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            OpenError::AppMissingActionUrl => {
+            OpenError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            OpenError::AppMissingActionUrl(_) => {
                 write!(f, "Server returned error app_missing_action_url")
             }
-            OpenError::CannotCreateDialog => {
+            OpenError::CannotCreateDialog(_) => {
                 write!(f, "Server returned error cannot_create_dialog")
             }
-            OpenError::FailedSendingDialog => {
+            OpenError::FailedSendingDialog(_) => {
                 write!(f, "Server returned error failed_sending_dialog")
             }
-            OpenError::FatalError => write!(f, "Server returned error fatal_error"),
-            OpenError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            OpenError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            OpenError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            OpenError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            OpenError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            OpenError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            OpenError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            OpenError::InvalidTrigger => write!(f, "Server returned error invalid_trigger"),
-            OpenError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            OpenError::MissingDialog => write!(f, "Server returned error missing_dialog"),
-            OpenError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            OpenError::MissingTrigger => write!(f, "Server returned error missing_trigger"),
-            OpenError::NoPermission => write!(f, "Server returned error no_permission"),
-            OpenError::NotAuthed => write!(f, "Server returned error not_authed"),
-            OpenError::OrgLoginRequired => write!(f, "Server returned error org_login_required"),
-            OpenError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            OpenError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            OpenError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            OpenError::TriggerExchanged => write!(f, "Server returned error trigger_exchanged"),
-            OpenError::TriggerExpired => write!(f, "Server returned error trigger_expired"),
-            OpenError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
-            OpenError::ValidationErrors => write!(f, "Server returned error validation_errors"),
+            OpenError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            OpenError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            OpenError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            OpenError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            OpenError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            OpenError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            OpenError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            OpenError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            OpenError::InvalidTrigger(_) => write!(f, "Server returned error invalid_trigger"),
+            OpenError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            OpenError::MissingDialog(_) => write!(f, "Server returned error missing_dialog"),
+            OpenError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            OpenError::MissingTrigger(_) => write!(f, "Server returned error missing_trigger"),
+            OpenError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            OpenError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            OpenError::OrgLoginRequired(_) => write!(f, "Server returned error org_login_required"),
+            OpenError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            OpenError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            OpenError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            OpenError::TriggerExchanged(_) => write!(f, "Server returned error trigger_exchanged"),
+            OpenError::TriggerExpired(_) => write!(f, "Server returned error trigger_expired"),
+            OpenError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
+            OpenError::ValidationErrors(_) => write!(f, "Server returned error validation_errors"),
             OpenError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            OpenError::Unknown(ref s) => write!(f, "{}", s),
+            OpenError::Unknown(ref s, _) => write!(f, "{}", s),
             OpenError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -171,3 +212,83 @@ impl<E: Error + 'static> Error for OpenError<E> {
         }
     }
 }
+
+impl<E: Error + 'static> crate::MethodError for OpenError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            OpenError::AccountInactive(_) => Some("account_inactive"),
+            OpenError::AppMissingActionUrl(_) => Some("app_missing_action_url"),
+            OpenError::CannotCreateDialog(_) => Some("cannot_create_dialog"),
+            OpenError::FailedSendingDialog(_) => Some("failed_sending_dialog"),
+            OpenError::FatalError(_) => Some("fatal_error"),
+            OpenError::InvalidArgName(_) => Some("invalid_arg_name"),
+            OpenError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            OpenError::InvalidAuth(_) => Some("invalid_auth"),
+            OpenError::InvalidCharset(_) => Some("invalid_charset"),
+            OpenError::InvalidFormData(_) => Some("invalid_form_data"),
+            OpenError::InvalidJson(_) => Some("invalid_json"),
+            OpenError::InvalidPostType(_) => Some("invalid_post_type"),
+            OpenError::InvalidTrigger(_) => Some("invalid_trigger"),
+            OpenError::JsonNotObject(_) => Some("json_not_object"),
+            OpenError::MissingDialog(_) => Some("missing_dialog"),
+            OpenError::MissingPostType(_) => Some("missing_post_type"),
+            OpenError::MissingTrigger(_) => Some("missing_trigger"),
+            OpenError::NoPermission(_) => Some("no_permission"),
+            OpenError::NotAuthed(_) => Some("not_authed"),
+            OpenError::OrgLoginRequired(_) => Some("org_login_required"),
+            OpenError::RequestTimeout(_) => Some("request_timeout"),
+            OpenError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            OpenError::TokenRevoked(_) => Some("token_revoked"),
+            OpenError::TriggerExchanged(_) => Some("trigger_exchanged"),
+            OpenError::TriggerExpired(_) => Some("trigger_expired"),
+            OpenError::UpgradeRequired(_) => Some("upgrade_required"),
+            OpenError::ValidationErrors(_) => Some("validation_errors"),
+            OpenError::Unknown(ref s, _) => Some(s),
+            OpenError::MalformedResponse(..) | OpenError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            OpenError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            OpenError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            OpenError::AccountInactive(ref context)
+            | OpenError::AppMissingActionUrl(ref context)
+            | OpenError::CannotCreateDialog(ref context)
+            | OpenError::FailedSendingDialog(ref context)
+            | OpenError::FatalError(ref context)
+            | OpenError::InvalidArgName(ref context)
+            | OpenError::InvalidArrayArg(ref context)
+            | OpenError::InvalidAuth(ref context)
+            | OpenError::InvalidCharset(ref context)
+            | OpenError::InvalidFormData(ref context)
+            | OpenError::InvalidJson(ref context)
+            | OpenError::InvalidPostType(ref context)
+            | OpenError::InvalidTrigger(ref context)
+            | OpenError::JsonNotObject(ref context)
+            | OpenError::MissingDialog(ref context)
+            | OpenError::MissingPostType(ref context)
+            | OpenError::MissingTrigger(ref context)
+            | OpenError::NoPermission(ref context)
+            | OpenError::NotAuthed(ref context)
+            | OpenError::OrgLoginRequired(ref context)
+            | OpenError::RequestTimeout(ref context)
+            | OpenError::TeamAddedToOrg(ref context)
+            | OpenError::TokenRevoked(ref context)
+            | OpenError::TriggerExchanged(ref context)
+            | OpenError::TriggerExpired(ref context)
+            | OpenError::UpgradeRequired(ref context)
+            | OpenError::ValidationErrors(ref context)
+            | OpenError::Unknown(_, ref context) => Some(context),
+            OpenError::MalformedResponse(..) | OpenError::Client(_) => None,
+        }
+    }
+}
@@ -24,12 +24,18 @@ use std::fmt;
 #[derive(Clone, Default, Debug)]
 pub struct EndDndRequest {}
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct EndDndResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<EndDndResponse, EndDndError<E>>> for EndDndResponse {
@@ -37,94 +43,131 @@ impl<E: Error> Into<Result<EndDndResponse, EndDndError<E>>> for EndDndResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(EndDndError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for EndDndResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum EndDndError<E: Error> {
-    AccountInactive,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    OrgLoginRequired,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UnknownError,
-    UpgradeRequired,
-    UserIsBot,
+    AccountInactive(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UnknownError(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserIsBot(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> EndDndError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => EndDndError::AccountInactive(context),
+            "fatal_error" => EndDndError::FatalError(context),
+            "invalid_arg_name" => EndDndError::InvalidArgName(context),
+            "invalid_array_arg" => EndDndError::InvalidArrayArg(context),
+            "invalid_auth" => EndDndError::InvalidAuth(context),
+            "invalid_charset" => EndDndError::InvalidCharset(context),
+            "invalid_form_data" => EndDndError::InvalidFormData(context),
+            "invalid_json" => EndDndError::InvalidJson(context),
+            "invalid_post_type" => EndDndError::InvalidPostType(context),
+            "json_not_object" => EndDndError::JsonNotObject(context),
+            "missing_post_type" => EndDndError::MissingPostType(context),
+            "no_permission" => EndDndError::NoPermission(context),
+            "not_authed" => EndDndError::NotAuthed(context),
+            "org_login_required" => EndDndError::OrgLoginRequired(context),
+            "request_timeout" => EndDndError::RequestTimeout(context),
+            "team_added_to_org" => EndDndError::TeamAddedToOrg(context),
+            "token_revoked" => EndDndError::TokenRevoked(context),
+            "unknown_error" => EndDndError::UnknownError(context),
+            "upgrade_required" => EndDndError::UpgradeRequired(context),
+            "user_is_bot" => EndDndError::UserIsBot(context),
+            _ => EndDndError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for EndDndError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => EndDndError::AccountInactive,
-            "fatal_error" => EndDndError::FatalError,
-            "invalid_arg_name" => EndDndError::InvalidArgName,
-            "invalid_array_arg" => EndDndError::InvalidArrayArg,
-            "invalid_auth" => EndDndError::InvalidAuth,
-            "invalid_charset" => EndDndError::InvalidCharset,
-            "invalid_form_data" => EndDndError::InvalidFormData,
-            "invalid_json" => EndDndError::InvalidJson,
-            "invalid_post_type" => EndDndError::InvalidPostType,
-            "json_not_object" => EndDndError::JsonNotObject,
-            "missing_post_type" => EndDndError::MissingPostType,
-            "no_permission" => EndDndError::NoPermission,
-            "not_authed" => EndDndError::NotAuthed,
-            "org_login_required" => EndDndError::OrgLoginRequired,
-            "request_timeout" => EndDndError::RequestTimeout,
-            "team_added_to_org" => EndDndError::TeamAddedToOrg,
-            "token_revoked" => EndDndError::TokenRevoked,
-            "unknown_error" => EndDndError::UnknownError,
-            "upgrade_required" => EndDndError::UpgradeRequired,
-            "user_is_bot" => EndDndError::UserIsBot,
-            _ => EndDndError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for EndDndError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            EndDndError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            EndDndError::FatalError => write!(f, "Server returned error fatal_error"),
-            EndDndError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            EndDndError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            EndDndError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            EndDndError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            EndDndError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            EndDndError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            EndDndError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            EndDndError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            EndDndError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            EndDndError::NoPermission => write!(f, "Server returned error no_permission"),
-            EndDndError::NotAuthed => write!(f, "Server returned error not_authed"),
-            EndDndError::OrgLoginRequired => write!(f, "Server returned error org_login_required"),
-            EndDndError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            EndDndError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            EndDndError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            EndDndError::UnknownError => write!(f, "Server returned error unknown_error"),
-            EndDndError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
-            EndDndError::UserIsBot => write!(f, "Server returned error user_is_bot"),
+            EndDndError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            EndDndError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            EndDndError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            EndDndError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            EndDndError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            EndDndError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            EndDndError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            EndDndError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            EndDndError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            EndDndError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            EndDndError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            EndDndError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            EndDndError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            EndDndError::OrgLoginRequired(_) => {
+                write!(f, "Server returned error org_login_required")
+            }
+            EndDndError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            EndDndError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            EndDndError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            EndDndError::UnknownError(_) => write!(f, "Server returned error unknown_error"),
+            EndDndError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
+            EndDndError::UserIsBot(_) => write!(f, "Server returned error user_is_bot"),
             EndDndError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            EndDndError::Unknown(ref s) => write!(f, "{}", s),
+            EndDndError::Unknown(ref s, _) => write!(f, "{}", s),
             EndDndError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -140,19 +183,91 @@ impl<E: Error + 'static> Error for EndDndError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for EndDndError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            EndDndError::AccountInactive(_) => Some("account_inactive"),
+            EndDndError::FatalError(_) => Some("fatal_error"),
+            EndDndError::InvalidArgName(_) => Some("invalid_arg_name"),
+            EndDndError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            EndDndError::InvalidAuth(_) => Some("invalid_auth"),
+            EndDndError::InvalidCharset(_) => Some("invalid_charset"),
+            EndDndError::InvalidFormData(_) => Some("invalid_form_data"),
+            EndDndError::InvalidJson(_) => Some("invalid_json"),
+            EndDndError::InvalidPostType(_) => Some("invalid_post_type"),
+            EndDndError::JsonNotObject(_) => Some("json_not_object"),
+            EndDndError::MissingPostType(_) => Some("missing_post_type"),
+            EndDndError::NoPermission(_) => Some("no_permission"),
+            EndDndError::NotAuthed(_) => Some("not_authed"),
+            EndDndError::OrgLoginRequired(_) => Some("org_login_required"),
+            EndDndError::RequestTimeout(_) => Some("request_timeout"),
+            EndDndError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            EndDndError::TokenRevoked(_) => Some("token_revoked"),
+            EndDndError::UnknownError(_) => Some("unknown_error"),
+            EndDndError::UpgradeRequired(_) => Some("upgrade_required"),
+            EndDndError::UserIsBot(_) => Some("user_is_bot"),
+            EndDndError::Unknown(ref s, _) => Some(s),
+            EndDndError::MalformedResponse(..) | EndDndError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            EndDndError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            EndDndError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            EndDndError::AccountInactive(ref context)
+            | EndDndError::FatalError(ref context)
+            | EndDndError::InvalidArgName(ref context)
+            | EndDndError::InvalidArrayArg(ref context)
+            | EndDndError::InvalidAuth(ref context)
+            | EndDndError::InvalidCharset(ref context)
+            | EndDndError::InvalidFormData(ref context)
+            | EndDndError::InvalidJson(ref context)
+            | EndDndError::InvalidPostType(ref context)
+            | EndDndError::JsonNotObject(ref context)
+            | EndDndError::MissingPostType(ref context)
+            | EndDndError::NoPermission(ref context)
+            | EndDndError::NotAuthed(ref context)
+            | EndDndError::OrgLoginRequired(ref context)
+            | EndDndError::RequestTimeout(ref context)
+            | EndDndError::TeamAddedToOrg(ref context)
+            | EndDndError::TokenRevoked(ref context)
+            | EndDndError::UnknownError(ref context)
+            | EndDndError::UpgradeRequired(ref context)
+            | EndDndError::UserIsBot(ref context)
+            | EndDndError::Unknown(_, ref context) => Some(context),
+            EndDndError::MalformedResponse(..) | EndDndError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct EndSnoozeRequest {}
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct EndSnoozeResponse {
     pub callstack: Option<String>,
     pub dnd_enabled: bool,
     error: Option<String>,
+    pub needed: Option<String>,
     pub next_dnd_end_ts: u64,
     pub next_dnd_start_ts: u64,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
     pub snooze_enabled: bool,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<EndSnoozeResponse, EndSnoozeError<E>>> for EndSnoozeResponse {
@@ -160,99 +275,154 @@ impl<E: Error> Into<Result<EndSnoozeResponse, EndSnoozeError<E>>> for EndSnoozeR
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(EndSnoozeError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for EndSnoozeResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum EndSnoozeError<E: Error> {
-    AccountInactive,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    OrgLoginRequired,
-    RequestTimeout,
-    SnoozeEndFailed,
-    SnoozeNotActive,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequired,
-    UserIsBot,
+    AccountInactive(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    SnoozeEndFailed(crate::ErrorContext),
+    SnoozeNotActive(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserIsBot(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> EndSnoozeError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => EndSnoozeError::AccountInactive(context),
+            "fatal_error" => EndSnoozeError::FatalError(context),
+            "invalid_arg_name" => EndSnoozeError::InvalidArgName(context),
+            "invalid_array_arg" => EndSnoozeError::InvalidArrayArg(context),
+            "invalid_auth" => EndSnoozeError::InvalidAuth(context),
+            "invalid_charset" => EndSnoozeError::InvalidCharset(context),
+            "invalid_form_data" => EndSnoozeError::InvalidFormData(context),
+            "invalid_json" => EndSnoozeError::InvalidJson(context),
+            "invalid_post_type" => EndSnoozeError::InvalidPostType(context),
+            "json_not_object" => EndSnoozeError::JsonNotObject(context),
+            "missing_post_type" => EndSnoozeError::MissingPostType(context),
+            "no_permission" => EndSnoozeError::NoPermission(context),
+            "not_authed" => EndSnoozeError::NotAuthed(context),
+            "org_login_required" => EndSnoozeError::OrgLoginRequired(context),
+            "request_timeout" => EndSnoozeError::RequestTimeout(context),
+            "snooze_end_failed" => EndSnoozeError::SnoozeEndFailed(context),
+            "snooze_not_active" => EndSnoozeError::SnoozeNotActive(context),
+            "team_added_to_org" => EndSnoozeError::TeamAddedToOrg(context),
+            "token_revoked" => EndSnoozeError::TokenRevoked(context),
+            "upgrade_required" => EndSnoozeError::UpgradeRequired(context),
+            "user_is_bot" => EndSnoozeError::UserIsBot(context),
+            _ => EndSnoozeError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for EndSnoozeError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => EndSnoozeError::AccountInactive,
-            "fatal_error" => EndSnoozeError::FatalError,
-            "invalid_arg_name" => EndSnoozeError::InvalidArgName,
-            "invalid_array_arg" => EndSnoozeError::InvalidArrayArg,
-            "invalid_auth" => EndSnoozeError::InvalidAuth,
-            "invalid_charset" => EndSnoozeError::InvalidCharset,
-            "invalid_form_data" => EndSnoozeError::InvalidFormData,
-            "invalid_json" => EndSnoozeError::InvalidJson,
-            "invalid_post_type" => EndSnoozeError::InvalidPostType,
-            "json_not_object" => EndSnoozeError::JsonNotObject,
-            "missing_post_type" => EndSnoozeError::MissingPostType,
-            "no_permission" => EndSnoozeError::NoPermission,
-            "not_authed" => EndSnoozeError::NotAuthed,
-            "org_login_required" => EndSnoozeError::OrgLoginRequired,
-            "request_timeout" => EndSnoozeError::RequestTimeout,
-            "snooze_end_failed" => EndSnoozeError::SnoozeEndFailed,
-            "snooze_not_active" => EndSnoozeError::SnoozeNotActive,
-            "team_added_to_org" => EndSnoozeError::TeamAddedToOrg,
-            "token_revoked" => EndSnoozeError::TokenRevoked,
-            "upgrade_required" => EndSnoozeError::UpgradeRequired,
-            "user_is_bot" => EndSnoozeError::UserIsBot,
-            _ => EndSnoozeError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for EndSnoozeError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            EndSnoozeError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            EndSnoozeError::FatalError => write!(f, "Server returned error fatal_error"),
-            EndSnoozeError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            EndSnoozeError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            EndSnoozeError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            EndSnoozeError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            EndSnoozeError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            EndSnoozeError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            EndSnoozeError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            EndSnoozeError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            EndSnoozeError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            EndSnoozeError::NoPermission => write!(f, "Server returned error no_permission"),
-            EndSnoozeError::NotAuthed => write!(f, "Server returned error not_authed"),
-            EndSnoozeError::OrgLoginRequired => {
+            EndSnoozeError::AccountInactive(_) => {
+                write!(f, "Server returned error account_inactive")
+            }
+            EndSnoozeError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            EndSnoozeError::InvalidArgName(_) => {
+                write!(f, "Server returned error invalid_arg_name")
+            }
+            EndSnoozeError::InvalidArrayArg(_) => {
+                write!(f, "Server returned error invalid_array_arg")
+            }
+            EndSnoozeError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            EndSnoozeError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            EndSnoozeError::InvalidFormData(_) => {
+                write!(f, "Server returned error invalid_form_data")
+            }
+            EndSnoozeError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            EndSnoozeError::InvalidPostType(_) => {
+                write!(f, "Server returned error invalid_post_type")
+            }
+            EndSnoozeError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            EndSnoozeError::MissingPostType(_) => {
+                write!(f, "Server returned error missing_post_type")
+            }
+            EndSnoozeError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            EndSnoozeError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            EndSnoozeError::OrgLoginRequired(_) => {
                 write!(f, "Server returned error org_login_required")
             }
-            EndSnoozeError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            EndSnoozeError::SnoozeEndFailed => write!(f, "Server returned error snooze_end_failed"),
-            EndSnoozeError::SnoozeNotActive => write!(f, "Server returned error snooze_not_active"),
-            EndSnoozeError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            EndSnoozeError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            EndSnoozeError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
-            EndSnoozeError::UserIsBot => write!(f, "Server returned error user_is_bot"),
+            EndSnoozeError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            EndSnoozeError::SnoozeEndFailed(_) => {
+                write!(f, "Server returned error snooze_end_failed")
+            }
+            EndSnoozeError::SnoozeNotActive(_) => {
+                write!(f, "Server returned error snooze_not_active")
+            }
+            EndSnoozeError::TeamAddedToOrg(_) => {
+                write!(f, "Server returned error team_added_to_org")
+            }
+            EndSnoozeError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            EndSnoozeError::UpgradeRequired(_) => {
+                write!(f, "Server returned error upgrade_required")
+            }
+            EndSnoozeError::UserIsBot(_) => write!(f, "Server returned error user_is_bot"),
             EndSnoozeError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            EndSnoozeError::Unknown(ref s) => write!(f, "{}", s),
+            EndSnoozeError::Unknown(ref s, _) => write!(f, "{}", s),
             EndSnoozeError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -268,24 +438,98 @@ impl<E: Error + 'static> Error for EndSnoozeError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for EndSnoozeError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            EndSnoozeError::AccountInactive(_) => Some("account_inactive"),
+            EndSnoozeError::FatalError(_) => Some("fatal_error"),
+            EndSnoozeError::InvalidArgName(_) => Some("invalid_arg_name"),
+            EndSnoozeError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            EndSnoozeError::InvalidAuth(_) => Some("invalid_auth"),
+            EndSnoozeError::InvalidCharset(_) => Some("invalid_charset"),
+            EndSnoozeError::InvalidFormData(_) => Some("invalid_form_data"),
+            EndSnoozeError::InvalidJson(_) => Some("invalid_json"),
+            EndSnoozeError::InvalidPostType(_) => Some("invalid_post_type"),
+            EndSnoozeError::JsonNotObject(_) => Some("json_not_object"),
+            EndSnoozeError::MissingPostType(_) => Some("missing_post_type"),
+            EndSnoozeError::NoPermission(_) => Some("no_permission"),
+            EndSnoozeError::NotAuthed(_) => Some("not_authed"),
+            EndSnoozeError::OrgLoginRequired(_) => Some("org_login_required"),
+            EndSnoozeError::RequestTimeout(_) => Some("request_timeout"),
+            EndSnoozeError::SnoozeEndFailed(_) => Some("snooze_end_failed"),
+            EndSnoozeError::SnoozeNotActive(_) => Some("snooze_not_active"),
+            EndSnoozeError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            EndSnoozeError::TokenRevoked(_) => Some("token_revoked"),
+            EndSnoozeError::UpgradeRequired(_) => Some("upgrade_required"),
+            EndSnoozeError::UserIsBot(_) => Some("user_is_bot"),
+            EndSnoozeError::Unknown(ref s, _) => Some(s),
+            EndSnoozeError::MalformedResponse(..) | EndSnoozeError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            EndSnoozeError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            EndSnoozeError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            EndSnoozeError::AccountInactive(ref context)
+            | EndSnoozeError::FatalError(ref context)
+            | EndSnoozeError::InvalidArgName(ref context)
+            | EndSnoozeError::InvalidArrayArg(ref context)
+            | EndSnoozeError::InvalidAuth(ref context)
+            | EndSnoozeError::InvalidCharset(ref context)
+            | EndSnoozeError::InvalidFormData(ref context)
+            | EndSnoozeError::InvalidJson(ref context)
+            | EndSnoozeError::InvalidPostType(ref context)
+            | EndSnoozeError::JsonNotObject(ref context)
+            | EndSnoozeError::MissingPostType(ref context)
+            | EndSnoozeError::NoPermission(ref context)
+            | EndSnoozeError::NotAuthed(ref context)
+            | EndSnoozeError::OrgLoginRequired(ref context)
+            | EndSnoozeError::RequestTimeout(ref context)
+            | EndSnoozeError::SnoozeEndFailed(ref context)
+            | EndSnoozeError::SnoozeNotActive(ref context)
+            | EndSnoozeError::TeamAddedToOrg(ref context)
+            | EndSnoozeError::TokenRevoked(ref context)
+            | EndSnoozeError::UpgradeRequired(ref context)
+            | EndSnoozeError::UserIsBot(ref context)
+            | EndSnoozeError::Unknown(_, ref context) => Some(context),
+            EndSnoozeError::MalformedResponse(..) | EndSnoozeError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
-pub struct InfoRequest<'a> {
+pub struct InfoRequest {
     /// User to fetch status for (defaults to current user)
-    pub user: Option<Cow<'a, str>>,
+    pub user: Option<crate::UserId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoResponse {
     pub callstack: Option<String>,
     pub dnd_enabled: bool,
     error: Option<String>,
+    pub needed: Option<String>,
     pub next_dnd_end_ts: u64,
     pub next_dnd_start_ts: u64,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
     pub snooze_enabled: Option<bool>,
     pub snooze_endtime: Option<u64>,
     pub snooze_remaining: Option<u64>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<InfoResponse, InfoError<E>>> for InfoResponse {
@@ -293,91 +537,126 @@ impl<E: Error> Into<Result<InfoResponse, InfoError<E>>> for InfoResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(InfoError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for InfoResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum InfoError<E: Error> {
-    AccountInactive,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    OrgLoginRequired,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequired,
-    UserNotFound,
+    AccountInactive(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserNotFound(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> InfoError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => InfoError::AccountInactive(context),
+            "fatal_error" => InfoError::FatalError(context),
+            "invalid_arg_name" => InfoError::InvalidArgName(context),
+            "invalid_array_arg" => InfoError::InvalidArrayArg(context),
+            "invalid_auth" => InfoError::InvalidAuth(context),
+            "invalid_charset" => InfoError::InvalidCharset(context),
+            "invalid_form_data" => InfoError::InvalidFormData(context),
+            "invalid_json" => InfoError::InvalidJson(context),
+            "invalid_post_type" => InfoError::InvalidPostType(context),
+            "json_not_object" => InfoError::JsonNotObject(context),
+            "missing_post_type" => InfoError::MissingPostType(context),
+            "no_permission" => InfoError::NoPermission(context),
+            "not_authed" => InfoError::NotAuthed(context),
+            "org_login_required" => InfoError::OrgLoginRequired(context),
+            "request_timeout" => InfoError::RequestTimeout(context),
+            "team_added_to_org" => InfoError::TeamAddedToOrg(context),
+            "token_revoked" => InfoError::TokenRevoked(context),
+            "upgrade_required" => InfoError::UpgradeRequired(context),
+            "user_not_found" => InfoError::UserNotFound(context),
+            _ => InfoError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for InfoError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => InfoError::AccountInactive,
-            "fatal_error" => InfoError::FatalError,
-            "invalid_arg_name" => InfoError::InvalidArgName,
-            "invalid_array_arg" => InfoError::InvalidArrayArg,
-            "invalid_auth" => InfoError::InvalidAuth,
-            "invalid_charset" => InfoError::InvalidCharset,
-            "invalid_form_data" => InfoError::InvalidFormData,
-            "invalid_json" => InfoError::InvalidJson,
-            "invalid_post_type" => InfoError::InvalidPostType,
-            "json_not_object" => InfoError::JsonNotObject,
-            "missing_post_type" => InfoError::MissingPostType,
-            "no_permission" => InfoError::NoPermission,
-            "not_authed" => InfoError::NotAuthed,
-            "org_login_required" => InfoError::OrgLoginRequired,
-            "request_timeout" => InfoError::RequestTimeout,
-            "team_added_to_org" => InfoError::TeamAddedToOrg,
-            "token_revoked" => InfoError::TokenRevoked,
-            "upgrade_required" => InfoError::UpgradeRequired,
-            "user_not_found" => InfoError::UserNotFound,
-            _ => InfoError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for InfoError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            InfoError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            InfoError::FatalError => write!(f, "Server returned error fatal_error"),
-            InfoError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            InfoError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            InfoError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            InfoError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            InfoError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            InfoError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            InfoError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            InfoError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            InfoError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            InfoError::NoPermission => write!(f, "Server returned error no_permission"),
-            InfoError::NotAuthed => write!(f, "Server returned error not_authed"),
-            InfoError::OrgLoginRequired => write!(f, "Server returned error org_login_required"),
-            InfoError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            InfoError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            InfoError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            InfoError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
-            InfoError::UserNotFound => write!(f, "Server returned error user_not_found"),
+            InfoError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            InfoError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            InfoError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            InfoError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            InfoError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            InfoError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            InfoError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            InfoError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            InfoError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            InfoError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            InfoError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            InfoError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            InfoError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            InfoError::OrgLoginRequired(_) => write!(f, "Server returned error org_login_required"),
+            InfoError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            InfoError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            InfoError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            InfoError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
+            InfoError::UserNotFound(_) => write!(f, "Server returned error user_not_found"),
             InfoError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            InfoError::Unknown(ref s) => write!(f, "{}", s),
+            InfoError::Unknown(ref s, _) => write!(f, "{}", s),
             InfoError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -393,21 +672,91 @@ impl<E: Error + 'static> Error for InfoError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for InfoError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            InfoError::AccountInactive(_) => Some("account_inactive"),
+            InfoError::FatalError(_) => Some("fatal_error"),
+            InfoError::InvalidArgName(_) => Some("invalid_arg_name"),
+            InfoError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            InfoError::InvalidAuth(_) => Some("invalid_auth"),
+            InfoError::InvalidCharset(_) => Some("invalid_charset"),
+            InfoError::InvalidFormData(_) => Some("invalid_form_data"),
+            InfoError::InvalidJson(_) => Some("invalid_json"),
+            InfoError::InvalidPostType(_) => Some("invalid_post_type"),
+            InfoError::JsonNotObject(_) => Some("json_not_object"),
+            InfoError::MissingPostType(_) => Some("missing_post_type"),
+            InfoError::NoPermission(_) => Some("no_permission"),
+            InfoError::NotAuthed(_) => Some("not_authed"),
+            InfoError::OrgLoginRequired(_) => Some("org_login_required"),
+            InfoError::RequestTimeout(_) => Some("request_timeout"),
+            InfoError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            InfoError::TokenRevoked(_) => Some("token_revoked"),
+            InfoError::UpgradeRequired(_) => Some("upgrade_required"),
+            InfoError::UserNotFound(_) => Some("user_not_found"),
+            InfoError::Unknown(ref s, _) => Some(s),
+            InfoError::MalformedResponse(..) | InfoError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            InfoError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            InfoError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            InfoError::AccountInactive(ref context)
+            | InfoError::FatalError(ref context)
+            | InfoError::InvalidArgName(ref context)
+            | InfoError::InvalidArrayArg(ref context)
+            | InfoError::InvalidAuth(ref context)
+            | InfoError::InvalidCharset(ref context)
+            | InfoError::InvalidFormData(ref context)
+            | InfoError::InvalidJson(ref context)
+            | InfoError::InvalidPostType(ref context)
+            | InfoError::JsonNotObject(ref context)
+            | InfoError::MissingPostType(ref context)
+            | InfoError::NoPermission(ref context)
+            | InfoError::NotAuthed(ref context)
+            | InfoError::OrgLoginRequired(ref context)
+            | InfoError::RequestTimeout(ref context)
+            | InfoError::TeamAddedToOrg(ref context)
+            | InfoError::TokenRevoked(ref context)
+            | InfoError::UpgradeRequired(ref context)
+            | InfoError::UserNotFound(ref context)
+            | InfoError::Unknown(_, ref context) => Some(context),
+            InfoError::MalformedResponse(..) | InfoError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct SetSnoozeRequest<'a> {
     /// Number of minutes, from now, to snooze until.
     pub num_minutes: Cow<'a, str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetSnoozeResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
     pub snooze_enabled: bool,
     pub snooze_endtime: u64,
     pub snooze_remaining: u64,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<SetSnoozeResponse, SetSnoozeError<E>>> for SetSnoozeResponse {
@@ -415,102 +764,155 @@ impl<E: Error> Into<Result<SetSnoozeResponse, SetSnoozeError<E>>> for SetSnoozeR
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(SetSnoozeError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for SetSnoozeResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum SetSnoozeError<E: Error> {
-    AccountInactive,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingDuration,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    OrgLoginRequired,
-    RequestTimeout,
-    SnoozeFailed,
-    TeamAddedToOrg,
-    TokenRevoked,
-    TooLong,
-    UpgradeRequired,
-    UserIsBot,
+    AccountInactive(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingDuration(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    SnoozeFailed(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    TooLong(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserIsBot(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> SetSnoozeError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => SetSnoozeError::AccountInactive(context),
+            "fatal_error" => SetSnoozeError::FatalError(context),
+            "invalid_arg_name" => SetSnoozeError::InvalidArgName(context),
+            "invalid_array_arg" => SetSnoozeError::InvalidArrayArg(context),
+            "invalid_auth" => SetSnoozeError::InvalidAuth(context),
+            "invalid_charset" => SetSnoozeError::InvalidCharset(context),
+            "invalid_form_data" => SetSnoozeError::InvalidFormData(context),
+            "invalid_json" => SetSnoozeError::InvalidJson(context),
+            "invalid_post_type" => SetSnoozeError::InvalidPostType(context),
+            "json_not_object" => SetSnoozeError::JsonNotObject(context),
+            "missing_duration" => SetSnoozeError::MissingDuration(context),
+            "missing_post_type" => SetSnoozeError::MissingPostType(context),
+            "no_permission" => SetSnoozeError::NoPermission(context),
+            "not_authed" => SetSnoozeError::NotAuthed(context),
+            "org_login_required" => SetSnoozeError::OrgLoginRequired(context),
+            "request_timeout" => SetSnoozeError::RequestTimeout(context),
+            "snooze_failed" => SetSnoozeError::SnoozeFailed(context),
+            "team_added_to_org" => SetSnoozeError::TeamAddedToOrg(context),
+            "token_revoked" => SetSnoozeError::TokenRevoked(context),
+            "too_long" => SetSnoozeError::TooLong(context),
+            "upgrade_required" => SetSnoozeError::UpgradeRequired(context),
+            "user_is_bot" => SetSnoozeError::UserIsBot(context),
+            _ => SetSnoozeError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for SetSnoozeError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => SetSnoozeError::AccountInactive,
-            "fatal_error" => SetSnoozeError::FatalError,
-            "invalid_arg_name" => SetSnoozeError::InvalidArgName,
-            "invalid_array_arg" => SetSnoozeError::InvalidArrayArg,
-            "invalid_auth" => SetSnoozeError::InvalidAuth,
-            "invalid_charset" => SetSnoozeError::InvalidCharset,
-            "invalid_form_data" => SetSnoozeError::InvalidFormData,
-            "invalid_json" => SetSnoozeError::InvalidJson,
-            "invalid_post_type" => SetSnoozeError::InvalidPostType,
-            "json_not_object" => SetSnoozeError::JsonNotObject,
-            "missing_duration" => SetSnoozeError::MissingDuration,
-            "missing_post_type" => SetSnoozeError::MissingPostType,
-            "no_permission" => SetSnoozeError::NoPermission,
-            "not_authed" => SetSnoozeError::NotAuthed,
-            "org_login_required" => SetSnoozeError::OrgLoginRequired,
-            "request_timeout" => SetSnoozeError::RequestTimeout,
-            "snooze_failed" => SetSnoozeError::SnoozeFailed,
-            "team_added_to_org" => SetSnoozeError::TeamAddedToOrg,
-            "token_revoked" => SetSnoozeError::TokenRevoked,
-            "too_long" => SetSnoozeError::TooLong,
-            "upgrade_required" => SetSnoozeError::UpgradeRequired,
-            "user_is_bot" => SetSnoozeError::UserIsBot,
-            _ => SetSnoozeError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for SetSnoozeError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            SetSnoozeError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            SetSnoozeError::FatalError => write!(f, "Server returned error fatal_error"),
-            SetSnoozeError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            SetSnoozeError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            SetSnoozeError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            SetSnoozeError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            SetSnoozeError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            SetSnoozeError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            SetSnoozeError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            SetSnoozeError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            SetSnoozeError::MissingDuration => write!(f, "Server returned error missing_duration"),
-            SetSnoozeError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            SetSnoozeError::NoPermission => write!(f, "Server returned error no_permission"),
-            SetSnoozeError::NotAuthed => write!(f, "Server returned error not_authed"),
-            SetSnoozeError::OrgLoginRequired => {
+            SetSnoozeError::AccountInactive(_) => {
+                write!(f, "Server returned error account_inactive")
+            }
+            SetSnoozeError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            SetSnoozeError::InvalidArgName(_) => {
+                write!(f, "Server returned error invalid_arg_name")
+            }
+            SetSnoozeError::InvalidArrayArg(_) => {
+                write!(f, "Server returned error invalid_array_arg")
+            }
+            SetSnoozeError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            SetSnoozeError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            SetSnoozeError::InvalidFormData(_) => {
+                write!(f, "Server returned error invalid_form_data")
+            }
+            SetSnoozeError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            SetSnoozeError::InvalidPostType(_) => {
+                write!(f, "Server returned error invalid_post_type")
+            }
+            SetSnoozeError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            SetSnoozeError::MissingDuration(_) => {
+                write!(f, "Server returned error missing_duration")
+            }
+            SetSnoozeError::MissingPostType(_) => {
+                write!(f, "Server returned error missing_post_type")
+            }
+            SetSnoozeError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            SetSnoozeError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            SetSnoozeError::OrgLoginRequired(_) => {
                 write!(f, "Server returned error org_login_required")
             }
-            SetSnoozeError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            SetSnoozeError::SnoozeFailed => write!(f, "Server returned error snooze_failed"),
-            SetSnoozeError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            SetSnoozeError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            SetSnoozeError::TooLong => write!(f, "Server returned error too_long"),
-            SetSnoozeError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
-            SetSnoozeError::UserIsBot => write!(f, "Server returned error user_is_bot"),
+            SetSnoozeError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            SetSnoozeError::SnoozeFailed(_) => write!(f, "Server returned error snooze_failed"),
+            SetSnoozeError::TeamAddedToOrg(_) => {
+                write!(f, "Server returned error team_added_to_org")
+            }
+            SetSnoozeError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            SetSnoozeError::TooLong(_) => write!(f, "Server returned error too_long"),
+            SetSnoozeError::UpgradeRequired(_) => {
+                write!(f, "Server returned error upgrade_required")
+            }
+            SetSnoozeError::UserIsBot(_) => write!(f, "Server returned error user_is_bot"),
             SetSnoozeError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            SetSnoozeError::Unknown(ref s) => write!(f, "{}", s),
+            SetSnoozeError::Unknown(ref s, _) => write!(f, "{}", s),
             SetSnoozeError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -526,16 +928,93 @@ impl<E: Error + 'static> Error for SetSnoozeError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for SetSnoozeError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            SetSnoozeError::AccountInactive(_) => Some("account_inactive"),
+            SetSnoozeError::FatalError(_) => Some("fatal_error"),
+            SetSnoozeError::InvalidArgName(_) => Some("invalid_arg_name"),
+            SetSnoozeError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            SetSnoozeError::InvalidAuth(_) => Some("invalid_auth"),
+            SetSnoozeError::InvalidCharset(_) => Some("invalid_charset"),
+            SetSnoozeError::InvalidFormData(_) => Some("invalid_form_data"),
+            SetSnoozeError::InvalidJson(_) => Some("invalid_json"),
+            SetSnoozeError::InvalidPostType(_) => Some("invalid_post_type"),
+            SetSnoozeError::JsonNotObject(_) => Some("json_not_object"),
+            SetSnoozeError::MissingDuration(_) => Some("missing_duration"),
+            SetSnoozeError::MissingPostType(_) => Some("missing_post_type"),
+            SetSnoozeError::NoPermission(_) => Some("no_permission"),
+            SetSnoozeError::NotAuthed(_) => Some("not_authed"),
+            SetSnoozeError::OrgLoginRequired(_) => Some("org_login_required"),
+            SetSnoozeError::RequestTimeout(_) => Some("request_timeout"),
+            SetSnoozeError::SnoozeFailed(_) => Some("snooze_failed"),
+            SetSnoozeError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            SetSnoozeError::TokenRevoked(_) => Some("token_revoked"),
+            SetSnoozeError::TooLong(_) => Some("too_long"),
+            SetSnoozeError::UpgradeRequired(_) => Some("upgrade_required"),
+            SetSnoozeError::UserIsBot(_) => Some("user_is_bot"),
+            SetSnoozeError::Unknown(ref s, _) => Some(s),
+            SetSnoozeError::MalformedResponse(..) | SetSnoozeError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            SetSnoozeError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            SetSnoozeError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            SetSnoozeError::AccountInactive(ref context)
+            | SetSnoozeError::FatalError(ref context)
+            | SetSnoozeError::InvalidArgName(ref context)
+            | SetSnoozeError::InvalidArrayArg(ref context)
+            | SetSnoozeError::InvalidAuth(ref context)
+            | SetSnoozeError::InvalidCharset(ref context)
+            | SetSnoozeError::InvalidFormData(ref context)
+            | SetSnoozeError::InvalidJson(ref context)
+            | SetSnoozeError::InvalidPostType(ref context)
+            | SetSnoozeError::JsonNotObject(ref context)
+            | SetSnoozeError::MissingDuration(ref context)
+            | SetSnoozeError::MissingPostType(ref context)
+            | SetSnoozeError::NoPermission(ref context)
+            | SetSnoozeError::NotAuthed(ref context)
+            | SetSnoozeError::OrgLoginRequired(ref context)
+            | SetSnoozeError::RequestTimeout(ref context)
+            | SetSnoozeError::SnoozeFailed(ref context)
+            | SetSnoozeError::TeamAddedToOrg(ref context)
+            | SetSnoozeError::TokenRevoked(ref context)
+            | SetSnoozeError::TooLong(ref context)
+            | SetSnoozeError::UpgradeRequired(ref context)
+            | SetSnoozeError::UserIsBot(ref context)
+            | SetSnoozeError::Unknown(_, ref context) => Some(context),
+            SetSnoozeError::MalformedResponse(..) | SetSnoozeError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct TeamInfoRequest<'a> {
     /// Comma-separated list of users to fetch Do Not Disturb status for
     pub users: Option<Cow<'a, str>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct TeamInfoResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<TeamInfoResponse, TeamInfoError<E>>> for TeamInfoResponse {
@@ -543,28 +1022,61 @@ impl<E: Error> Into<Result<TeamInfoResponse, TeamInfoError<E>>> for TeamInfoResp
         if self.ok {
             Ok(self)
         } else {
-            Err(TeamInfoError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(TeamInfoError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for TeamInfoResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum TeamInfoError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> TeamInfoError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => TeamInfoError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for TeamInfoError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => TeamInfoError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -572,7 +1084,7 @@ impl<E: Error> fmt::Display for TeamInfoError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             TeamInfoError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            TeamInfoError::Unknown(ref s) => write!(f, "{}", s),
+            TeamInfoError::Unknown(ref s, _) => write!(f, "{}", s),
             TeamInfoError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -587,3 +1099,29 @@ impl<E: Error + 'static> Error for TeamInfoError<E> {
         }
     }
 }
+
+impl<E: Error + 'static> crate::MethodError for TeamInfoError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            TeamInfoError::Unknown(ref s, _) => Some(s),
+            TeamInfoError::MalformedResponse(..) | TeamInfoError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            TeamInfoError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            TeamInfoError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            TeamInfoError::Unknown(_, ref context) => Some(context),
+            TeamInfoError::MalformedResponse(..) | TeamInfoError::Client(_) => None,
+        }
+    }
+}
@@ -24,17 +24,23 @@ use std::fmt;
 #[derive(Clone, Default, Debug)]
 pub struct DeleteRequest<'a> {
     /// File to delete a comment from.
-    pub file: Option<Cow<'a, str>>,
+    pub file: Option<crate::FileId>,
     /// The comment to delete.
     pub id: Option<Cow<'a, str>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct DeleteResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<DeleteResponse, DeleteError<E>>> for DeleteResponse {
@@ -42,82 +48,117 @@ impl<E: Error> Into<Result<DeleteResponse, DeleteError<E>>> for DeleteResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(DeleteError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for DeleteResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum DeleteError<E: Error> {
-    AccountInactive,
-    CantDelete,
-    CommentNotFound,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    RequestTimeout,
-    UpgradeRequired,
+    AccountInactive(crate::ErrorContext),
+    CantDelete(crate::ErrorContext),
+    CommentNotFound(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> DeleteError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => DeleteError::AccountInactive(context),
+            "cant_delete" => DeleteError::CantDelete(context),
+            "comment_not_found" => DeleteError::CommentNotFound(context),
+            "invalid_arg_name" => DeleteError::InvalidArgName(context),
+            "invalid_array_arg" => DeleteError::InvalidArrayArg(context),
+            "invalid_auth" => DeleteError::InvalidAuth(context),
+            "invalid_charset" => DeleteError::InvalidCharset(context),
+            "invalid_form_data" => DeleteError::InvalidFormData(context),
+            "invalid_json" => DeleteError::InvalidJson(context),
+            "invalid_post_type" => DeleteError::InvalidPostType(context),
+            "json_not_object" => DeleteError::JsonNotObject(context),
+            "missing_post_type" => DeleteError::MissingPostType(context),
+            "no_permission" => DeleteError::NoPermission(context),
+            "not_authed" => DeleteError::NotAuthed(context),
+            "request_timeout" => DeleteError::RequestTimeout(context),
+            "upgrade_required" => DeleteError::UpgradeRequired(context),
+            _ => DeleteError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for DeleteError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => DeleteError::AccountInactive,
-            "cant_delete" => DeleteError::CantDelete,
-            "comment_not_found" => DeleteError::CommentNotFound,
-            "invalid_arg_name" => DeleteError::InvalidArgName,
-            "invalid_array_arg" => DeleteError::InvalidArrayArg,
-            "invalid_auth" => DeleteError::InvalidAuth,
-            "invalid_charset" => DeleteError::InvalidCharset,
-            "invalid_form_data" => DeleteError::InvalidFormData,
-            "invalid_json" => DeleteError::InvalidJson,
-            "invalid_post_type" => DeleteError::InvalidPostType,
-            "json_not_object" => DeleteError::JsonNotObject,
-            "missing_post_type" => DeleteError::MissingPostType,
-            "no_permission" => DeleteError::NoPermission,
-            "not_authed" => DeleteError::NotAuthed,
-            "request_timeout" => DeleteError::RequestTimeout,
-            "upgrade_required" => DeleteError::UpgradeRequired,
-            _ => DeleteError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for DeleteError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            DeleteError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            DeleteError::CantDelete => write!(f, "Server returned error cant_delete"),
-            DeleteError::CommentNotFound => write!(f, "Server returned error comment_not_found"),
-            DeleteError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            DeleteError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            DeleteError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            DeleteError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            DeleteError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            DeleteError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            DeleteError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            DeleteError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            DeleteError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            DeleteError::NoPermission => write!(f, "Server returned error no_permission"),
-            DeleteError::NotAuthed => write!(f, "Server returned error not_authed"),
-            DeleteError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            DeleteError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
+            DeleteError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            DeleteError::CantDelete(_) => write!(f, "Server returned error cant_delete"),
+            DeleteError::CommentNotFound(_) => write!(f, "Server returned error comment_not_found"),
+            DeleteError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            DeleteError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            DeleteError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            DeleteError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            DeleteError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            DeleteError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            DeleteError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            DeleteError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            DeleteError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            DeleteError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            DeleteError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            DeleteError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            DeleteError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
             DeleteError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            DeleteError::Unknown(ref s) => write!(f, "{}", s),
+            DeleteError::Unknown(ref s, _) => write!(f, "{}", s),
             DeleteError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -132,3 +173,61 @@ impl<E: Error + 'static> Error for DeleteError<E> {
         }
     }
 }
+
+impl<E: Error + 'static> crate::MethodError for DeleteError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            DeleteError::AccountInactive(_) => Some("account_inactive"),
+            DeleteError::CantDelete(_) => Some("cant_delete"),
+            DeleteError::CommentNotFound(_) => Some("comment_not_found"),
+            DeleteError::InvalidArgName(_) => Some("invalid_arg_name"),
+            DeleteError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            DeleteError::InvalidAuth(_) => Some("invalid_auth"),
+            DeleteError::InvalidCharset(_) => Some("invalid_charset"),
+            DeleteError::InvalidFormData(_) => Some("invalid_form_data"),
+            DeleteError::InvalidJson(_) => Some("invalid_json"),
+            DeleteError::InvalidPostType(_) => Some("invalid_post_type"),
+            DeleteError::JsonNotObject(_) => Some("json_not_object"),
+            DeleteError::MissingPostType(_) => Some("missing_post_type"),
+            DeleteError::NoPermission(_) => Some("no_permission"),
+            DeleteError::NotAuthed(_) => Some("not_authed"),
+            DeleteError::RequestTimeout(_) => Some("request_timeout"),
+            DeleteError::UpgradeRequired(_) => Some("upgrade_required"),
+            DeleteError::Unknown(ref s, _) => Some(s),
+            DeleteError::MalformedResponse(..) | DeleteError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            DeleteError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            DeleteError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            DeleteError::AccountInactive(ref context)
+            | DeleteError::CantDelete(ref context)
+            | DeleteError::CommentNotFound(ref context)
+            | DeleteError::InvalidArgName(ref context)
+            | DeleteError::InvalidArrayArg(ref context)
+            | DeleteError::InvalidAuth(ref context)
+            | DeleteError::InvalidCharset(ref context)
+            | DeleteError::InvalidFormData(ref context)
+            | DeleteError::InvalidJson(ref context)
+            | DeleteError::InvalidPostType(ref context)
+            | DeleteError::JsonNotObject(ref context)
+            | DeleteError::MissingPostType(ref context)
+            | DeleteError::NoPermission(ref context)
+            | DeleteError::NotAuthed(ref context)
+            | DeleteError::RequestTimeout(ref context)
+            | DeleteError::UpgradeRequired(ref context)
+            | DeleteError::Unknown(_, ref context) => Some(context),
+            DeleteError::MalformedResponse(..) | DeleteError::Client(_) => None,
+        }
+    }
+}
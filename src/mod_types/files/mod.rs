@@ -25,17 +25,329 @@ use std::error::Error;
 use std::fmt;
 
 #[derive(Clone, Default, Debug)]
-pub struct DeleteRequest<'a> {
+pub struct CompleteUploadExternalRequest<'a> {
+    /// Channel ID where the file will be shared. If not specified the file will be private.
+    pub channel_id: Option<crate::ChannelId>,
+    /// Array of file ids and their corresponding (optional) titles, as JSON.
+    pub files: Cow<'a, str>,
+    /// The message text introducing the file in specified channel.
+    pub initial_comment: Option<Cow<'a, str>>,
+    /// Provide another message's `ts` value to upload this file as a reply. Never use a reply's `ts` value; use its parent instead.
+    pub thread_ts: Option<crate::Timestamp>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CompleteUploadExternalFilesInner {
+    pub id: crate::FileId,
+    pub title: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CompleteUploadExternalResponse {
+    error: Option<String>,
+    pub files: Option<Vec<CompleteUploadExternalFilesInner>>,
+    pub needed: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
+}
+
+impl<E: Error> Into<Result<CompleteUploadExternalResponse, CompleteUploadExternalError<E>>>
+    for CompleteUploadExternalResponse
+{
+    fn into(self) -> Result<CompleteUploadExternalResponse, CompleteUploadExternalError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(CompleteUploadExternalError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
+        }
+    }
+}
+
+impl crate::SlackResponse for CompleteUploadExternalResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
+#[derive(Debug)]
+pub enum CompleteUploadExternalError<E: Error> {
+    AccessDenied(crate::ErrorContext),
+    AccountInactive(crate::ErrorContext),
+    ChannelNotFound(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    FileNotFound(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArguments(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidChannel(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    MissingScope(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    NotInChannel(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    Ratelimited(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    ServiceUnavailable(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenExpired(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String, crate::ErrorContext),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<E: Error> CompleteUploadExternalError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "access_denied" => CompleteUploadExternalError::AccessDenied(context),
+            "account_inactive" => CompleteUploadExternalError::AccountInactive(context),
+            "channel_not_found" => CompleteUploadExternalError::ChannelNotFound(context),
+            "fatal_error" => CompleteUploadExternalError::FatalError(context),
+            "file_not_found" => CompleteUploadExternalError::FileNotFound(context),
+            "invalid_arg_name" => CompleteUploadExternalError::InvalidArgName(context),
+            "invalid_arguments" => CompleteUploadExternalError::InvalidArguments(context),
+            "invalid_auth" => CompleteUploadExternalError::InvalidAuth(context),
+            "invalid_channel" => CompleteUploadExternalError::InvalidChannel(context),
+            "invalid_charset" => CompleteUploadExternalError::InvalidCharset(context),
+            "invalid_form_data" => CompleteUploadExternalError::InvalidFormData(context),
+            "invalid_post_type" => CompleteUploadExternalError::InvalidPostType(context),
+            "missing_post_type" => CompleteUploadExternalError::MissingPostType(context),
+            "missing_scope" => CompleteUploadExternalError::MissingScope(context),
+            "not_authed" => CompleteUploadExternalError::NotAuthed(context),
+            "not_in_channel" => CompleteUploadExternalError::NotInChannel(context),
+            "org_login_required" => CompleteUploadExternalError::OrgLoginRequired(context),
+            "ratelimited" => CompleteUploadExternalError::Ratelimited(context),
+            "request_timeout" => CompleteUploadExternalError::RequestTimeout(context),
+            "service_unavailable" => CompleteUploadExternalError::ServiceUnavailable(context),
+            "team_added_to_org" => CompleteUploadExternalError::TeamAddedToOrg(context),
+            "token_expired" => CompleteUploadExternalError::TokenExpired(context),
+            "token_revoked" => CompleteUploadExternalError::TokenRevoked(context),
+            _ => CompleteUploadExternalError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
+impl<'a, E: Error> From<&'a str> for CompleteUploadExternalError<E> {
+    fn from(s: &'a str) -> Self {
+        Self::new(s, crate::ErrorContext::default())
+    }
+}
+
+impl<E: Error> fmt::Display for CompleteUploadExternalError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            CompleteUploadExternalError::AccessDenied(_) => {
+                write!(f, "Server returned error access_denied")
+            }
+            CompleteUploadExternalError::AccountInactive(_) => {
+                write!(f, "Server returned error account_inactive")
+            }
+            CompleteUploadExternalError::ChannelNotFound(_) => {
+                write!(f, "Server returned error channel_not_found")
+            }
+            CompleteUploadExternalError::FatalError(_) => {
+                write!(f, "Server returned error fatal_error")
+            }
+            CompleteUploadExternalError::FileNotFound(_) => {
+                write!(f, "Server returned error file_not_found")
+            }
+            CompleteUploadExternalError::InvalidArgName(_) => {
+                write!(f, "Server returned error invalid_arg_name")
+            }
+            CompleteUploadExternalError::InvalidArguments(_) => {
+                write!(f, "Server returned error invalid_arguments")
+            }
+            CompleteUploadExternalError::InvalidAuth(_) => {
+                write!(f, "Server returned error invalid_auth")
+            }
+            CompleteUploadExternalError::InvalidChannel(_) => {
+                write!(f, "Server returned error invalid_channel")
+            }
+            CompleteUploadExternalError::InvalidCharset(_) => {
+                write!(f, "Server returned error invalid_charset")
+            }
+            CompleteUploadExternalError::InvalidFormData(_) => {
+                write!(f, "Server returned error invalid_form_data")
+            }
+            CompleteUploadExternalError::InvalidPostType(_) => {
+                write!(f, "Server returned error invalid_post_type")
+            }
+            CompleteUploadExternalError::MissingPostType(_) => {
+                write!(f, "Server returned error missing_post_type")
+            }
+            CompleteUploadExternalError::MissingScope(_) => {
+                write!(f, "Server returned error missing_scope")
+            }
+            CompleteUploadExternalError::NotAuthed(_) => {
+                write!(f, "Server returned error not_authed")
+            }
+            CompleteUploadExternalError::NotInChannel(_) => {
+                write!(f, "Server returned error not_in_channel")
+            }
+            CompleteUploadExternalError::OrgLoginRequired(_) => {
+                write!(f, "Server returned error org_login_required")
+            }
+            CompleteUploadExternalError::Ratelimited(_) => {
+                write!(f, "Server returned error ratelimited")
+            }
+            CompleteUploadExternalError::RequestTimeout(_) => {
+                write!(f, "Server returned error request_timeout")
+            }
+            CompleteUploadExternalError::ServiceUnavailable(_) => {
+                write!(f, "Server returned error service_unavailable")
+            }
+            CompleteUploadExternalError::TeamAddedToOrg(_) => {
+                write!(f, "Server returned error team_added_to_org")
+            }
+            CompleteUploadExternalError::TokenExpired(_) => {
+                write!(f, "Server returned error token_expired")
+            }
+            CompleteUploadExternalError::TokenRevoked(_) => {
+                write!(f, "Server returned error token_revoked")
+            }
+            CompleteUploadExternalError::MalformedResponse(_, ref e) => write!(f, "{}", e),
+            CompleteUploadExternalError::Unknown(ref s, _) => write!(f, "{}", s),
+            CompleteUploadExternalError::Client(ref inner) => write!(f, "{}", inner),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for CompleteUploadExternalError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            CompleteUploadExternalError::MalformedResponse(_, ref e) => Some(e),
+            CompleteUploadExternalError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+impl<E: Error + 'static> crate::MethodError for CompleteUploadExternalError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            CompleteUploadExternalError::AccessDenied(_) => Some("access_denied"),
+            CompleteUploadExternalError::AccountInactive(_) => Some("account_inactive"),
+            CompleteUploadExternalError::ChannelNotFound(_) => Some("channel_not_found"),
+            CompleteUploadExternalError::FatalError(_) => Some("fatal_error"),
+            CompleteUploadExternalError::FileNotFound(_) => Some("file_not_found"),
+            CompleteUploadExternalError::InvalidArgName(_) => Some("invalid_arg_name"),
+            CompleteUploadExternalError::InvalidArguments(_) => Some("invalid_arguments"),
+            CompleteUploadExternalError::InvalidAuth(_) => Some("invalid_auth"),
+            CompleteUploadExternalError::InvalidChannel(_) => Some("invalid_channel"),
+            CompleteUploadExternalError::InvalidCharset(_) => Some("invalid_charset"),
+            CompleteUploadExternalError::InvalidFormData(_) => Some("invalid_form_data"),
+            CompleteUploadExternalError::InvalidPostType(_) => Some("invalid_post_type"),
+            CompleteUploadExternalError::MissingPostType(_) => Some("missing_post_type"),
+            CompleteUploadExternalError::MissingScope(_) => Some("missing_scope"),
+            CompleteUploadExternalError::NotAuthed(_) => Some("not_authed"),
+            CompleteUploadExternalError::NotInChannel(_) => Some("not_in_channel"),
+            CompleteUploadExternalError::OrgLoginRequired(_) => Some("org_login_required"),
+            CompleteUploadExternalError::Ratelimited(_) => Some("ratelimited"),
+            CompleteUploadExternalError::RequestTimeout(_) => Some("request_timeout"),
+            CompleteUploadExternalError::ServiceUnavailable(_) => Some("service_unavailable"),
+            CompleteUploadExternalError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            CompleteUploadExternalError::TokenExpired(_) => Some("token_expired"),
+            CompleteUploadExternalError::TokenRevoked(_) => Some("token_revoked"),
+            CompleteUploadExternalError::Unknown(ref s, _) => Some(s),
+            CompleteUploadExternalError::MalformedResponse(..)
+            | CompleteUploadExternalError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            CompleteUploadExternalError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            CompleteUploadExternalError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            CompleteUploadExternalError::AccessDenied(ref context)
+            | CompleteUploadExternalError::AccountInactive(ref context)
+            | CompleteUploadExternalError::ChannelNotFound(ref context)
+            | CompleteUploadExternalError::FatalError(ref context)
+            | CompleteUploadExternalError::FileNotFound(ref context)
+            | CompleteUploadExternalError::InvalidArgName(ref context)
+            | CompleteUploadExternalError::InvalidArguments(ref context)
+            | CompleteUploadExternalError::InvalidAuth(ref context)
+            | CompleteUploadExternalError::InvalidChannel(ref context)
+            | CompleteUploadExternalError::InvalidCharset(ref context)
+            | CompleteUploadExternalError::InvalidFormData(ref context)
+            | CompleteUploadExternalError::InvalidPostType(ref context)
+            | CompleteUploadExternalError::MissingPostType(ref context)
+            | CompleteUploadExternalError::MissingScope(ref context)
+            | CompleteUploadExternalError::NotAuthed(ref context)
+            | CompleteUploadExternalError::NotInChannel(ref context)
+            | CompleteUploadExternalError::OrgLoginRequired(ref context)
+            | CompleteUploadExternalError::Ratelimited(ref context)
+            | CompleteUploadExternalError::RequestTimeout(ref context)
+            | CompleteUploadExternalError::ServiceUnavailable(ref context)
+            | CompleteUploadExternalError::TeamAddedToOrg(ref context)
+            | CompleteUploadExternalError::TokenExpired(ref context)
+            | CompleteUploadExternalError::TokenRevoked(ref context)
+            | CompleteUploadExternalError::Unknown(_, ref context) => Some(context),
+            CompleteUploadExternalError::MalformedResponse(..)
+            | CompleteUploadExternalError::Client(_) => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct DeleteRequest {
     /// ID of file to delete.
-    pub file: Option<Cow<'a, str>>,
+    pub file: Option<crate::FileId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct DeleteResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<DeleteResponse, DeleteError<E>>> for DeleteResponse {
@@ -43,88 +355,123 @@ impl<E: Error> Into<Result<DeleteResponse, DeleteError<E>>> for DeleteResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(DeleteError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for DeleteResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum DeleteError<E: Error> {
-    AccountInactive,
-    CantDeleteFile,
-    FileDeleted,
-    FileNotFound,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    RequestTimeout,
-    TeamAddedToOrg,
-    UpgradeRequired,
+    AccountInactive(crate::ErrorContext),
+    CantDeleteFile(crate::ErrorContext),
+    FileDeleted(crate::ErrorContext),
+    FileNotFound(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> DeleteError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => DeleteError::AccountInactive(context),
+            "cant_delete_file" => DeleteError::CantDeleteFile(context),
+            "file_deleted" => DeleteError::FileDeleted(context),
+            "file_not_found" => DeleteError::FileNotFound(context),
+            "invalid_arg_name" => DeleteError::InvalidArgName(context),
+            "invalid_array_arg" => DeleteError::InvalidArrayArg(context),
+            "invalid_auth" => DeleteError::InvalidAuth(context),
+            "invalid_charset" => DeleteError::InvalidCharset(context),
+            "invalid_form_data" => DeleteError::InvalidFormData(context),
+            "invalid_json" => DeleteError::InvalidJson(context),
+            "invalid_post_type" => DeleteError::InvalidPostType(context),
+            "json_not_object" => DeleteError::JsonNotObject(context),
+            "missing_post_type" => DeleteError::MissingPostType(context),
+            "no_permission" => DeleteError::NoPermission(context),
+            "not_authed" => DeleteError::NotAuthed(context),
+            "request_timeout" => DeleteError::RequestTimeout(context),
+            "team_added_to_org" => DeleteError::TeamAddedToOrg(context),
+            "upgrade_required" => DeleteError::UpgradeRequired(context),
+            _ => DeleteError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for DeleteError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => DeleteError::AccountInactive,
-            "cant_delete_file" => DeleteError::CantDeleteFile,
-            "file_deleted" => DeleteError::FileDeleted,
-            "file_not_found" => DeleteError::FileNotFound,
-            "invalid_arg_name" => DeleteError::InvalidArgName,
-            "invalid_array_arg" => DeleteError::InvalidArrayArg,
-            "invalid_auth" => DeleteError::InvalidAuth,
-            "invalid_charset" => DeleteError::InvalidCharset,
-            "invalid_form_data" => DeleteError::InvalidFormData,
-            "invalid_json" => DeleteError::InvalidJson,
-            "invalid_post_type" => DeleteError::InvalidPostType,
-            "json_not_object" => DeleteError::JsonNotObject,
-            "missing_post_type" => DeleteError::MissingPostType,
-            "no_permission" => DeleteError::NoPermission,
-            "not_authed" => DeleteError::NotAuthed,
-            "request_timeout" => DeleteError::RequestTimeout,
-            "team_added_to_org" => DeleteError::TeamAddedToOrg,
-            "upgrade_required" => DeleteError::UpgradeRequired,
-            _ => DeleteError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for DeleteError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            DeleteError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            DeleteError::CantDeleteFile => write!(f, "Server returned error cant_delete_file"),
-            DeleteError::FileDeleted => write!(f, "Server returned error file_deleted"),
-            DeleteError::FileNotFound => write!(f, "Server returned error file_not_found"),
-            DeleteError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            DeleteError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            DeleteError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            DeleteError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            DeleteError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            DeleteError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            DeleteError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            DeleteError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            DeleteError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            DeleteError::NoPermission => write!(f, "Server returned error no_permission"),
-            DeleteError::NotAuthed => write!(f, "Server returned error not_authed"),
-            DeleteError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            DeleteError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            DeleteError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
+            DeleteError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            DeleteError::CantDeleteFile(_) => write!(f, "Server returned error cant_delete_file"),
+            DeleteError::FileDeleted(_) => write!(f, "Server returned error file_deleted"),
+            DeleteError::FileNotFound(_) => write!(f, "Server returned error file_not_found"),
+            DeleteError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            DeleteError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            DeleteError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            DeleteError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            DeleteError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            DeleteError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            DeleteError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            DeleteError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            DeleteError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            DeleteError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            DeleteError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            DeleteError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            DeleteError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            DeleteError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
             DeleteError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            DeleteError::Unknown(ref s) => write!(f, "{}", s),
+            DeleteError::Unknown(ref s, _) => write!(f, "{}", s),
             DeleteError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -140,32 +487,367 @@ impl<E: Error + 'static> Error for DeleteError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for DeleteError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            DeleteError::AccountInactive(_) => Some("account_inactive"),
+            DeleteError::CantDeleteFile(_) => Some("cant_delete_file"),
+            DeleteError::FileDeleted(_) => Some("file_deleted"),
+            DeleteError::FileNotFound(_) => Some("file_not_found"),
+            DeleteError::InvalidArgName(_) => Some("invalid_arg_name"),
+            DeleteError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            DeleteError::InvalidAuth(_) => Some("invalid_auth"),
+            DeleteError::InvalidCharset(_) => Some("invalid_charset"),
+            DeleteError::InvalidFormData(_) => Some("invalid_form_data"),
+            DeleteError::InvalidJson(_) => Some("invalid_json"),
+            DeleteError::InvalidPostType(_) => Some("invalid_post_type"),
+            DeleteError::JsonNotObject(_) => Some("json_not_object"),
+            DeleteError::MissingPostType(_) => Some("missing_post_type"),
+            DeleteError::NoPermission(_) => Some("no_permission"),
+            DeleteError::NotAuthed(_) => Some("not_authed"),
+            DeleteError::RequestTimeout(_) => Some("request_timeout"),
+            DeleteError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            DeleteError::UpgradeRequired(_) => Some("upgrade_required"),
+            DeleteError::Unknown(ref s, _) => Some(s),
+            DeleteError::MalformedResponse(..) | DeleteError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            DeleteError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            DeleteError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            DeleteError::AccountInactive(ref context)
+            | DeleteError::CantDeleteFile(ref context)
+            | DeleteError::FileDeleted(ref context)
+            | DeleteError::FileNotFound(ref context)
+            | DeleteError::InvalidArgName(ref context)
+            | DeleteError::InvalidArrayArg(ref context)
+            | DeleteError::InvalidAuth(ref context)
+            | DeleteError::InvalidCharset(ref context)
+            | DeleteError::InvalidFormData(ref context)
+            | DeleteError::InvalidJson(ref context)
+            | DeleteError::InvalidPostType(ref context)
+            | DeleteError::JsonNotObject(ref context)
+            | DeleteError::MissingPostType(ref context)
+            | DeleteError::NoPermission(ref context)
+            | DeleteError::NotAuthed(ref context)
+            | DeleteError::RequestTimeout(ref context)
+            | DeleteError::TeamAddedToOrg(ref context)
+            | DeleteError::UpgradeRequired(ref context)
+            | DeleteError::Unknown(_, ref context) => Some(context),
+            DeleteError::MalformedResponse(..) | DeleteError::Client(_) => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct GetUploadURLExternalRequest<'a> {
+    /// Description of image for screen-reader.
+    pub alt_txt: Option<Cow<'a, str>>,
+    /// Name of the file being uploaded.
+    pub filename: Cow<'a, str>,
+    /// Size in bytes of the file being uploaded.
+    pub length: u64,
+    /// Syntax type of the snippet being uploaded.
+    pub snippet_type: Option<Cow<'a, str>>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GetUploadURLExternalResponse {
+    error: Option<String>,
+    pub file_id: Option<crate::FileId>,
+    pub needed: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub upload_url: Option<String>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
+}
+
+impl<E: Error> Into<Result<GetUploadURLExternalResponse, GetUploadURLExternalError<E>>>
+    for GetUploadURLExternalResponse
+{
+    fn into(self) -> Result<GetUploadURLExternalResponse, GetUploadURLExternalError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(GetUploadURLExternalError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
+        }
+    }
+}
+
+impl crate::SlackResponse for GetUploadURLExternalResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
+#[derive(Debug)]
+pub enum GetUploadURLExternalError<E: Error> {
+    AccessDenied(crate::ErrorContext),
+    AccountInactive(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArguments(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    MissingScope(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    Ratelimited(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    ServiceUnavailable(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenExpired(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String, crate::ErrorContext),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<E: Error> GetUploadURLExternalError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "access_denied" => GetUploadURLExternalError::AccessDenied(context),
+            "account_inactive" => GetUploadURLExternalError::AccountInactive(context),
+            "fatal_error" => GetUploadURLExternalError::FatalError(context),
+            "invalid_arg_name" => GetUploadURLExternalError::InvalidArgName(context),
+            "invalid_arguments" => GetUploadURLExternalError::InvalidArguments(context),
+            "invalid_auth" => GetUploadURLExternalError::InvalidAuth(context),
+            "invalid_charset" => GetUploadURLExternalError::InvalidCharset(context),
+            "invalid_form_data" => GetUploadURLExternalError::InvalidFormData(context),
+            "invalid_post_type" => GetUploadURLExternalError::InvalidPostType(context),
+            "missing_post_type" => GetUploadURLExternalError::MissingPostType(context),
+            "missing_scope" => GetUploadURLExternalError::MissingScope(context),
+            "not_authed" => GetUploadURLExternalError::NotAuthed(context),
+            "org_login_required" => GetUploadURLExternalError::OrgLoginRequired(context),
+            "ratelimited" => GetUploadURLExternalError::Ratelimited(context),
+            "request_timeout" => GetUploadURLExternalError::RequestTimeout(context),
+            "service_unavailable" => GetUploadURLExternalError::ServiceUnavailable(context),
+            "team_added_to_org" => GetUploadURLExternalError::TeamAddedToOrg(context),
+            "token_expired" => GetUploadURLExternalError::TokenExpired(context),
+            "token_revoked" => GetUploadURLExternalError::TokenRevoked(context),
+            _ => GetUploadURLExternalError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
+impl<'a, E: Error> From<&'a str> for GetUploadURLExternalError<E> {
+    fn from(s: &'a str) -> Self {
+        Self::new(s, crate::ErrorContext::default())
+    }
+}
+
+impl<E: Error> fmt::Display for GetUploadURLExternalError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            GetUploadURLExternalError::AccessDenied(_) => {
+                write!(f, "Server returned error access_denied")
+            }
+            GetUploadURLExternalError::AccountInactive(_) => {
+                write!(f, "Server returned error account_inactive")
+            }
+            GetUploadURLExternalError::FatalError(_) => {
+                write!(f, "Server returned error fatal_error")
+            }
+            GetUploadURLExternalError::InvalidArgName(_) => {
+                write!(f, "Server returned error invalid_arg_name")
+            }
+            GetUploadURLExternalError::InvalidArguments(_) => {
+                write!(f, "Server returned error invalid_arguments")
+            }
+            GetUploadURLExternalError::InvalidAuth(_) => {
+                write!(f, "Server returned error invalid_auth")
+            }
+            GetUploadURLExternalError::InvalidCharset(_) => {
+                write!(f, "Server returned error invalid_charset")
+            }
+            GetUploadURLExternalError::InvalidFormData(_) => {
+                write!(f, "Server returned error invalid_form_data")
+            }
+            GetUploadURLExternalError::InvalidPostType(_) => {
+                write!(f, "Server returned error invalid_post_type")
+            }
+            GetUploadURLExternalError::MissingPostType(_) => {
+                write!(f, "Server returned error missing_post_type")
+            }
+            GetUploadURLExternalError::MissingScope(_) => {
+                write!(f, "Server returned error missing_scope")
+            }
+            GetUploadURLExternalError::NotAuthed(_) => {
+                write!(f, "Server returned error not_authed")
+            }
+            GetUploadURLExternalError::OrgLoginRequired(_) => {
+                write!(f, "Server returned error org_login_required")
+            }
+            GetUploadURLExternalError::Ratelimited(_) => {
+                write!(f, "Server returned error ratelimited")
+            }
+            GetUploadURLExternalError::RequestTimeout(_) => {
+                write!(f, "Server returned error request_timeout")
+            }
+            GetUploadURLExternalError::ServiceUnavailable(_) => {
+                write!(f, "Server returned error service_unavailable")
+            }
+            GetUploadURLExternalError::TeamAddedToOrg(_) => {
+                write!(f, "Server returned error team_added_to_org")
+            }
+            GetUploadURLExternalError::TokenExpired(_) => {
+                write!(f, "Server returned error token_expired")
+            }
+            GetUploadURLExternalError::TokenRevoked(_) => {
+                write!(f, "Server returned error token_revoked")
+            }
+            GetUploadURLExternalError::MalformedResponse(_, ref e) => write!(f, "{}", e),
+            GetUploadURLExternalError::Unknown(ref s, _) => write!(f, "{}", s),
+            GetUploadURLExternalError::Client(ref inner) => write!(f, "{}", inner),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for GetUploadURLExternalError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            GetUploadURLExternalError::MalformedResponse(_, ref e) => Some(e),
+            GetUploadURLExternalError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+impl<E: Error + 'static> crate::MethodError for GetUploadURLExternalError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            GetUploadURLExternalError::AccessDenied(_) => Some("access_denied"),
+            GetUploadURLExternalError::AccountInactive(_) => Some("account_inactive"),
+            GetUploadURLExternalError::FatalError(_) => Some("fatal_error"),
+            GetUploadURLExternalError::InvalidArgName(_) => Some("invalid_arg_name"),
+            GetUploadURLExternalError::InvalidArguments(_) => Some("invalid_arguments"),
+            GetUploadURLExternalError::InvalidAuth(_) => Some("invalid_auth"),
+            GetUploadURLExternalError::InvalidCharset(_) => Some("invalid_charset"),
+            GetUploadURLExternalError::InvalidFormData(_) => Some("invalid_form_data"),
+            GetUploadURLExternalError::InvalidPostType(_) => Some("invalid_post_type"),
+            GetUploadURLExternalError::MissingPostType(_) => Some("missing_post_type"),
+            GetUploadURLExternalError::MissingScope(_) => Some("missing_scope"),
+            GetUploadURLExternalError::NotAuthed(_) => Some("not_authed"),
+            GetUploadURLExternalError::OrgLoginRequired(_) => Some("org_login_required"),
+            GetUploadURLExternalError::Ratelimited(_) => Some("ratelimited"),
+            GetUploadURLExternalError::RequestTimeout(_) => Some("request_timeout"),
+            GetUploadURLExternalError::ServiceUnavailable(_) => Some("service_unavailable"),
+            GetUploadURLExternalError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            GetUploadURLExternalError::TokenExpired(_) => Some("token_expired"),
+            GetUploadURLExternalError::TokenRevoked(_) => Some("token_revoked"),
+            GetUploadURLExternalError::Unknown(ref s, _) => Some(s),
+            GetUploadURLExternalError::MalformedResponse(..)
+            | GetUploadURLExternalError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            GetUploadURLExternalError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            GetUploadURLExternalError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            GetUploadURLExternalError::AccessDenied(ref context)
+            | GetUploadURLExternalError::AccountInactive(ref context)
+            | GetUploadURLExternalError::FatalError(ref context)
+            | GetUploadURLExternalError::InvalidArgName(ref context)
+            | GetUploadURLExternalError::InvalidArguments(ref context)
+            | GetUploadURLExternalError::InvalidAuth(ref context)
+            | GetUploadURLExternalError::InvalidCharset(ref context)
+            | GetUploadURLExternalError::InvalidFormData(ref context)
+            | GetUploadURLExternalError::InvalidPostType(ref context)
+            | GetUploadURLExternalError::MissingPostType(ref context)
+            | GetUploadURLExternalError::MissingScope(ref context)
+            | GetUploadURLExternalError::NotAuthed(ref context)
+            | GetUploadURLExternalError::OrgLoginRequired(ref context)
+            | GetUploadURLExternalError::Ratelimited(ref context)
+            | GetUploadURLExternalError::RequestTimeout(ref context)
+            | GetUploadURLExternalError::ServiceUnavailable(ref context)
+            | GetUploadURLExternalError::TeamAddedToOrg(ref context)
+            | GetUploadURLExternalError::TokenExpired(ref context)
+            | GetUploadURLExternalError::TokenRevoked(ref context)
+            | GetUploadURLExternalError::Unknown(_, ref context) => Some(context),
+            GetUploadURLExternalError::MalformedResponse(..)
+            | GetUploadURLExternalError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct InfoRequest<'a> {
     pub count: Option<Cow<'a, str>>,
     /// Parameter for pagination. File comments are paginated for a single file. Set `cursor` equal to the `next_cursor` attribute returned by the previous request's `response_metadata`. This parameter is optional, but pagination is mandatory: the default value simply fetches the first "page" of the collection of comments. See [pagination](/docs/pagination) for more details.
     pub cursor: Option<Cow<'a, str>>,
     /// Specify a file by providing its ID.
-    pub file: Option<Cow<'a, str>>,
+    pub file: Option<crate::FileId>,
     /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached.
     pub limit: Option<u64>,
     pub page: Option<Cow<'a, str>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoReactionsInner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoSharesInner {
     pub private: Option<serde_json::Value>,
     pub public: Option<serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoFileInner {
     pub channels: Option<Vec<String>>,
     pub comments_count: Option<u64>,
@@ -180,7 +862,7 @@ pub struct InfoFileInner {
     pub filetype: Option<String>,
     pub groups: Option<Vec<String>>,
     pub has_rich_preview: Option<bool>,
-    pub id: Option<String>,
+    pub id: Option<crate::FileId>,
     pub image_exif_rotation: Option<u64>,
     pub ims: Option<Vec<String>>,
     pub is_external: Option<bool>,
@@ -234,12 +916,12 @@ pub struct InfoFileInner {
     pub updated: Option<u64>,
     pub url_private: Option<String>,
     pub url_private_download: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoPagingInner {
     pub count: Option<u64>,
     pub page: u64,
@@ -249,12 +931,7 @@ pub struct InfoPagingInner {
     pub total: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct InfoResponseMetadataInner {
-    pub next_cursor: String,
-}
-
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoResponse {
     pub callstack: Option<String>,
     pub comments: Vec<serde_json::Value>,
@@ -262,10 +939,15 @@ pub struct InfoResponse {
     pub editor: Option<String>,
     error: Option<String>,
     pub file: InfoFileInner,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
     pub paging: Option<InfoPagingInner>,
-    pub response_metadata: Option<Vec<InfoResponseMetadataInner>>,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<InfoResponse, InfoError<E>>> for InfoResponse {
@@ -273,90 +955,125 @@ impl<E: Error> Into<Result<InfoResponse, InfoError<E>>> for InfoResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(InfoError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for InfoResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum InfoError<E: Error> {
-    AccountInactive,
-    FileDeleted,
-    FileNotFound,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TimezoneCountFailed,
-    UpgradeRequired,
+    AccountInactive(crate::ErrorContext),
+    FileDeleted(crate::ErrorContext),
+    FileNotFound(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TimezoneCountFailed(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> InfoError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => InfoError::AccountInactive(context),
+            "file_deleted" => InfoError::FileDeleted(context),
+            "file_not_found" => InfoError::FileNotFound(context),
+            "invalid_arg_name" => InfoError::InvalidArgName(context),
+            "invalid_array_arg" => InfoError::InvalidArrayArg(context),
+            "invalid_auth" => InfoError::InvalidAuth(context),
+            "invalid_charset" => InfoError::InvalidCharset(context),
+            "invalid_form_data" => InfoError::InvalidFormData(context),
+            "invalid_json" => InfoError::InvalidJson(context),
+            "invalid_post_type" => InfoError::InvalidPostType(context),
+            "json_not_object" => InfoError::JsonNotObject(context),
+            "missing_post_type" => InfoError::MissingPostType(context),
+            "no_permission" => InfoError::NoPermission(context),
+            "not_authed" => InfoError::NotAuthed(context),
+            "request_timeout" => InfoError::RequestTimeout(context),
+            "team_added_to_org" => InfoError::TeamAddedToOrg(context),
+            "timezone_count_failed" => InfoError::TimezoneCountFailed(context),
+            "upgrade_required" => InfoError::UpgradeRequired(context),
+            _ => InfoError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for InfoError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => InfoError::AccountInactive,
-            "file_deleted" => InfoError::FileDeleted,
-            "file_not_found" => InfoError::FileNotFound,
-            "invalid_arg_name" => InfoError::InvalidArgName,
-            "invalid_array_arg" => InfoError::InvalidArrayArg,
-            "invalid_auth" => InfoError::InvalidAuth,
-            "invalid_charset" => InfoError::InvalidCharset,
-            "invalid_form_data" => InfoError::InvalidFormData,
-            "invalid_json" => InfoError::InvalidJson,
-            "invalid_post_type" => InfoError::InvalidPostType,
-            "json_not_object" => InfoError::JsonNotObject,
-            "missing_post_type" => InfoError::MissingPostType,
-            "no_permission" => InfoError::NoPermission,
-            "not_authed" => InfoError::NotAuthed,
-            "request_timeout" => InfoError::RequestTimeout,
-            "team_added_to_org" => InfoError::TeamAddedToOrg,
-            "timezone_count_failed" => InfoError::TimezoneCountFailed,
-            "upgrade_required" => InfoError::UpgradeRequired,
-            _ => InfoError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for InfoError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            InfoError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            InfoError::FileDeleted => write!(f, "Server returned error file_deleted"),
-            InfoError::FileNotFound => write!(f, "Server returned error file_not_found"),
-            InfoError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            InfoError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            InfoError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            InfoError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            InfoError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            InfoError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            InfoError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            InfoError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            InfoError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            InfoError::NoPermission => write!(f, "Server returned error no_permission"),
-            InfoError::NotAuthed => write!(f, "Server returned error not_authed"),
-            InfoError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            InfoError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            InfoError::TimezoneCountFailed => {
+            InfoError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            InfoError::FileDeleted(_) => write!(f, "Server returned error file_deleted"),
+            InfoError::FileNotFound(_) => write!(f, "Server returned error file_not_found"),
+            InfoError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            InfoError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            InfoError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            InfoError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            InfoError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            InfoError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            InfoError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            InfoError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            InfoError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            InfoError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            InfoError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            InfoError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            InfoError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            InfoError::TimezoneCountFailed(_) => {
                 write!(f, "Server returned error timezone_count_failed")
             }
-            InfoError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
+            InfoError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
             InfoError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            InfoError::Unknown(ref s) => write!(f, "{}", s),
+            InfoError::Unknown(ref s, _) => write!(f, "{}", s),
             InfoError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -372,38 +1089,106 @@ impl<E: Error + 'static> Error for InfoError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for InfoError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            InfoError::AccountInactive(_) => Some("account_inactive"),
+            InfoError::FileDeleted(_) => Some("file_deleted"),
+            InfoError::FileNotFound(_) => Some("file_not_found"),
+            InfoError::InvalidArgName(_) => Some("invalid_arg_name"),
+            InfoError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            InfoError::InvalidAuth(_) => Some("invalid_auth"),
+            InfoError::InvalidCharset(_) => Some("invalid_charset"),
+            InfoError::InvalidFormData(_) => Some("invalid_form_data"),
+            InfoError::InvalidJson(_) => Some("invalid_json"),
+            InfoError::InvalidPostType(_) => Some("invalid_post_type"),
+            InfoError::JsonNotObject(_) => Some("json_not_object"),
+            InfoError::MissingPostType(_) => Some("missing_post_type"),
+            InfoError::NoPermission(_) => Some("no_permission"),
+            InfoError::NotAuthed(_) => Some("not_authed"),
+            InfoError::RequestTimeout(_) => Some("request_timeout"),
+            InfoError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            InfoError::TimezoneCountFailed(_) => Some("timezone_count_failed"),
+            InfoError::UpgradeRequired(_) => Some("upgrade_required"),
+            InfoError::Unknown(ref s, _) => Some(s),
+            InfoError::MalformedResponse(..) | InfoError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            InfoError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            InfoError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            InfoError::AccountInactive(ref context)
+            | InfoError::FileDeleted(ref context)
+            | InfoError::FileNotFound(ref context)
+            | InfoError::InvalidArgName(ref context)
+            | InfoError::InvalidArrayArg(ref context)
+            | InfoError::InvalidAuth(ref context)
+            | InfoError::InvalidCharset(ref context)
+            | InfoError::InvalidFormData(ref context)
+            | InfoError::InvalidJson(ref context)
+            | InfoError::InvalidPostType(ref context)
+            | InfoError::JsonNotObject(ref context)
+            | InfoError::MissingPostType(ref context)
+            | InfoError::NoPermission(ref context)
+            | InfoError::NotAuthed(ref context)
+            | InfoError::RequestTimeout(ref context)
+            | InfoError::TeamAddedToOrg(ref context)
+            | InfoError::TimezoneCountFailed(ref context)
+            | InfoError::UpgradeRequired(ref context)
+            | InfoError::Unknown(_, ref context) => Some(context),
+            InfoError::MalformedResponse(..) | InfoError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct ListRequest<'a> {
     /// Filter files appearing in a specific channel, indicated by its ID.
-    pub channel: Option<Cow<'a, str>>,
+    pub channel: Option<crate::ChannelId>,
     pub count: Option<Cow<'a, str>>,
     pub page: Option<Cow<'a, str>>,
     /// Show truncated file info for files hidden due to being too old, and the team who owns the file being over the file limit.
     pub show_files_hidden_by_limit: Option<bool>,
     /// Filter files created after this timestamp (inclusive).
-    pub ts_from: Option<f64>,
+    pub ts_from: Option<crate::Timestamp>,
     /// Filter files created before this timestamp (inclusive).
-    pub ts_to: Option<f64>,
+    pub ts_to: Option<crate::Timestamp>,
     /// Filter files by type ([see below](#file_types)). You can pass multiple values in the types argument, like `types=spaces,snippets`.The default value is `all`, which does not filter the list.
     pub types: Option<Cow<'a, str>>,
     /// Filter files created by a single user.
-    pub user: Option<Cow<'a, str>>,
+    pub user: Option<crate::UserId>,
+}
+
+impl crate::pagination::PageRequest for ListRequest<'_> {
+    fn set_page(&mut self, page: u64) {
+        self.page = Some(page.to_string().into());
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListReactionsInner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListSharesInner {
     pub private: Option<serde_json::Value>,
     pub public: Option<serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListFilesInner {
     pub channels: Option<Vec<String>>,
     pub comments_count: Option<u64>,
@@ -418,7 +1203,7 @@ pub struct ListFilesInner {
     pub filetype: Option<String>,
     pub groups: Option<Vec<String>>,
     pub has_rich_preview: Option<bool>,
-    pub id: Option<String>,
+    pub id: Option<crate::FileId>,
     pub image_exif_rotation: Option<u64>,
     pub ims: Option<Vec<String>>,
     pub is_external: Option<bool>,
@@ -472,12 +1257,12 @@ pub struct ListFilesInner {
     pub updated: Option<u64>,
     pub url_private: Option<String>,
     pub url_private_download: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListPagingInner {
     pub count: Option<u64>,
     pub page: u64,
@@ -487,14 +1272,20 @@ pub struct ListPagingInner {
     pub total: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponse {
     pub callstack: Option<String>,
     error: Option<String>,
     pub files: Vec<ListFilesInner>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
     pub paging: ListPagingInner,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
@@ -502,88 +1293,139 @@ impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(ListError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for ListResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
+impl crate::pagination::NumberedPage for ListResponse {
+    type Item = ListFilesInner;
+
+    fn page(&self) -> Option<u64> {
+        Some(self.paging.page)
+    }
+
+    fn pages(&self) -> Option<u64> {
+        self.paging.pages
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.files
+    }
+}
+
 #[derive(Debug)]
 pub enum ListError<E: Error> {
-    AccountInactive,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    RequestTimeout,
-    TeamAddedToOrg,
-    UnknownType,
-    UpgradeRequired,
-    UserIsBot,
-    UserNotFound,
+    AccountInactive(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    UnknownType(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserIsBot(crate::ErrorContext),
+    UserNotFound(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> ListError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => ListError::AccountInactive(context),
+            "invalid_arg_name" => ListError::InvalidArgName(context),
+            "invalid_array_arg" => ListError::InvalidArrayArg(context),
+            "invalid_auth" => ListError::InvalidAuth(context),
+            "invalid_charset" => ListError::InvalidCharset(context),
+            "invalid_form_data" => ListError::InvalidFormData(context),
+            "invalid_json" => ListError::InvalidJson(context),
+            "invalid_post_type" => ListError::InvalidPostType(context),
+            "json_not_object" => ListError::JsonNotObject(context),
+            "missing_post_type" => ListError::MissingPostType(context),
+            "no_permission" => ListError::NoPermission(context),
+            "not_authed" => ListError::NotAuthed(context),
+            "request_timeout" => ListError::RequestTimeout(context),
+            "team_added_to_org" => ListError::TeamAddedToOrg(context),
+            "unknown_type" => ListError::UnknownType(context),
+            "upgrade_required" => ListError::UpgradeRequired(context),
+            "user_is_bot" => ListError::UserIsBot(context),
+            "user_not_found" => ListError::UserNotFound(context),
+            _ => ListError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for ListError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => ListError::AccountInactive,
-            "invalid_arg_name" => ListError::InvalidArgName,
-            "invalid_array_arg" => ListError::InvalidArrayArg,
-            "invalid_auth" => ListError::InvalidAuth,
-            "invalid_charset" => ListError::InvalidCharset,
-            "invalid_form_data" => ListError::InvalidFormData,
-            "invalid_json" => ListError::InvalidJson,
-            "invalid_post_type" => ListError::InvalidPostType,
-            "json_not_object" => ListError::JsonNotObject,
-            "missing_post_type" => ListError::MissingPostType,
-            "no_permission" => ListError::NoPermission,
-            "not_authed" => ListError::NotAuthed,
-            "request_timeout" => ListError::RequestTimeout,
-            "team_added_to_org" => ListError::TeamAddedToOrg,
-            "unknown_type" => ListError::UnknownType,
-            "upgrade_required" => ListError::UpgradeRequired,
-            "user_is_bot" => ListError::UserIsBot,
-            "user_not_found" => ListError::UserNotFound,
-            _ => ListError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for ListError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            ListError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            ListError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            ListError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            ListError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            ListError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            ListError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            ListError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            ListError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            ListError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            ListError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            ListError::NoPermission => write!(f, "Server returned error no_permission"),
-            ListError::NotAuthed => write!(f, "Server returned error not_authed"),
-            ListError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            ListError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            ListError::UnknownType => write!(f, "Server returned error unknown_type"),
-            ListError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
-            ListError::UserIsBot => write!(f, "Server returned error user_is_bot"),
-            ListError::UserNotFound => write!(f, "Server returned error user_not_found"),
+            ListError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            ListError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            ListError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            ListError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            ListError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            ListError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            ListError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            ListError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            ListError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            ListError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            ListError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            ListError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            ListError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            ListError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            ListError::UnknownType(_) => write!(f, "Server returned error unknown_type"),
+            ListError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
+            ListError::UserIsBot(_) => write!(f, "Server returned error user_is_bot"),
+            ListError::UserNotFound(_) => write!(f, "Server returned error user_not_found"),
             ListError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            ListError::Unknown(ref s) => write!(f, "{}", s),
+            ListError::Unknown(ref s, _) => write!(f, "{}", s),
             ListError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -599,26 +1441,88 @@ impl<E: Error + 'static> Error for ListError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for ListError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            ListError::AccountInactive(_) => Some("account_inactive"),
+            ListError::InvalidArgName(_) => Some("invalid_arg_name"),
+            ListError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            ListError::InvalidAuth(_) => Some("invalid_auth"),
+            ListError::InvalidCharset(_) => Some("invalid_charset"),
+            ListError::InvalidFormData(_) => Some("invalid_form_data"),
+            ListError::InvalidJson(_) => Some("invalid_json"),
+            ListError::InvalidPostType(_) => Some("invalid_post_type"),
+            ListError::JsonNotObject(_) => Some("json_not_object"),
+            ListError::MissingPostType(_) => Some("missing_post_type"),
+            ListError::NoPermission(_) => Some("no_permission"),
+            ListError::NotAuthed(_) => Some("not_authed"),
+            ListError::RequestTimeout(_) => Some("request_timeout"),
+            ListError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            ListError::UnknownType(_) => Some("unknown_type"),
+            ListError::UpgradeRequired(_) => Some("upgrade_required"),
+            ListError::UserIsBot(_) => Some("user_is_bot"),
+            ListError::UserNotFound(_) => Some("user_not_found"),
+            ListError::Unknown(ref s, _) => Some(s),
+            ListError::MalformedResponse(..) | ListError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            ListError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            ListError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            ListError::AccountInactive(ref context)
+            | ListError::InvalidArgName(ref context)
+            | ListError::InvalidArrayArg(ref context)
+            | ListError::InvalidAuth(ref context)
+            | ListError::InvalidCharset(ref context)
+            | ListError::InvalidFormData(ref context)
+            | ListError::InvalidJson(ref context)
+            | ListError::InvalidPostType(ref context)
+            | ListError::JsonNotObject(ref context)
+            | ListError::MissingPostType(ref context)
+            | ListError::NoPermission(ref context)
+            | ListError::NotAuthed(ref context)
+            | ListError::RequestTimeout(ref context)
+            | ListError::TeamAddedToOrg(ref context)
+            | ListError::UnknownType(ref context)
+            | ListError::UpgradeRequired(ref context)
+            | ListError::UserIsBot(ref context)
+            | ListError::UserNotFound(ref context)
+            | ListError::Unknown(_, ref context) => Some(context),
+            ListError::MalformedResponse(..) | ListError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
-pub struct RevokePublicURLRequest<'a> {
+pub struct RevokePublicURLRequest {
     /// File to revoke
-    pub file: Option<Cow<'a, str>>,
+    pub file: Option<crate::FileId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RevokePublicURLReactionsInner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RevokePublicURLSharesInner {
     pub private: Option<serde_json::Value>,
     pub public: Option<serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RevokePublicURLFileInner {
     pub channels: Option<Vec<String>>,
     pub comments_count: Option<u64>,
@@ -633,7 +1537,7 @@ pub struct RevokePublicURLFileInner {
     pub filetype: Option<String>,
     pub groups: Option<Vec<String>>,
     pub has_rich_preview: Option<bool>,
-    pub id: Option<String>,
+    pub id: Option<crate::FileId>,
     pub image_exif_rotation: Option<u64>,
     pub ims: Option<Vec<String>>,
     pub is_external: Option<bool>,
@@ -687,18 +1591,24 @@ pub struct RevokePublicURLFileInner {
     pub updated: Option<u64>,
     pub url_private: Option<String>,
     pub url_private_download: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RevokePublicURLResponse {
     pub callstack: Option<String>,
     error: Option<String>,
     pub file: RevokePublicURLFileInner,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<RevokePublicURLResponse, RevokePublicURLError<E>>>
@@ -708,123 +1618,164 @@ impl<E: Error> Into<Result<RevokePublicURLResponse, RevokePublicURLError<E>>>
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(RevokePublicURLError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for RevokePublicURLResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum RevokePublicURLError<E: Error> {
-    AccountInactive,
-    FatalError,
-    FileNotFound,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    OrgLoginRequired,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequired,
-    UserIsBot,
-    UserIsRestricted,
+    AccountInactive(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    FileNotFound(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserIsBot(crate::ErrorContext),
+    UserIsRestricted(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> RevokePublicURLError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => RevokePublicURLError::AccountInactive(context),
+            "fatal_error" => RevokePublicURLError::FatalError(context),
+            "file_not_found" => RevokePublicURLError::FileNotFound(context),
+            "invalid_arg_name" => RevokePublicURLError::InvalidArgName(context),
+            "invalid_array_arg" => RevokePublicURLError::InvalidArrayArg(context),
+            "invalid_auth" => RevokePublicURLError::InvalidAuth(context),
+            "invalid_charset" => RevokePublicURLError::InvalidCharset(context),
+            "invalid_form_data" => RevokePublicURLError::InvalidFormData(context),
+            "invalid_json" => RevokePublicURLError::InvalidJson(context),
+            "invalid_post_type" => RevokePublicURLError::InvalidPostType(context),
+            "json_not_object" => RevokePublicURLError::JsonNotObject(context),
+            "missing_post_type" => RevokePublicURLError::MissingPostType(context),
+            "no_permission" => RevokePublicURLError::NoPermission(context),
+            "not_authed" => RevokePublicURLError::NotAuthed(context),
+            "org_login_required" => RevokePublicURLError::OrgLoginRequired(context),
+            "request_timeout" => RevokePublicURLError::RequestTimeout(context),
+            "team_added_to_org" => RevokePublicURLError::TeamAddedToOrg(context),
+            "token_revoked" => RevokePublicURLError::TokenRevoked(context),
+            "upgrade_required" => RevokePublicURLError::UpgradeRequired(context),
+            "user_is_bot" => RevokePublicURLError::UserIsBot(context),
+            "user_is_restricted" => RevokePublicURLError::UserIsRestricted(context),
+            _ => RevokePublicURLError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for RevokePublicURLError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => RevokePublicURLError::AccountInactive,
-            "fatal_error" => RevokePublicURLError::FatalError,
-            "file_not_found" => RevokePublicURLError::FileNotFound,
-            "invalid_arg_name" => RevokePublicURLError::InvalidArgName,
-            "invalid_array_arg" => RevokePublicURLError::InvalidArrayArg,
-            "invalid_auth" => RevokePublicURLError::InvalidAuth,
-            "invalid_charset" => RevokePublicURLError::InvalidCharset,
-            "invalid_form_data" => RevokePublicURLError::InvalidFormData,
-            "invalid_json" => RevokePublicURLError::InvalidJson,
-            "invalid_post_type" => RevokePublicURLError::InvalidPostType,
-            "json_not_object" => RevokePublicURLError::JsonNotObject,
-            "missing_post_type" => RevokePublicURLError::MissingPostType,
-            "no_permission" => RevokePublicURLError::NoPermission,
-            "not_authed" => RevokePublicURLError::NotAuthed,
-            "org_login_required" => RevokePublicURLError::OrgLoginRequired,
-            "request_timeout" => RevokePublicURLError::RequestTimeout,
-            "team_added_to_org" => RevokePublicURLError::TeamAddedToOrg,
-            "token_revoked" => RevokePublicURLError::TokenRevoked,
-            "upgrade_required" => RevokePublicURLError::UpgradeRequired,
-            "user_is_bot" => RevokePublicURLError::UserIsBot,
-            "user_is_restricted" => RevokePublicURLError::UserIsRestricted,
-            _ => RevokePublicURLError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for RevokePublicURLError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            RevokePublicURLError::AccountInactive => {
+            RevokePublicURLError::AccountInactive(_) => {
                 write!(f, "Server returned error account_inactive")
             }
-            RevokePublicURLError::FatalError => write!(f, "Server returned error fatal_error"),
-            RevokePublicURLError::FileNotFound => write!(f, "Server returned error file_not_found"),
-            RevokePublicURLError::InvalidArgName => {
+            RevokePublicURLError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            RevokePublicURLError::FileNotFound(_) => {
+                write!(f, "Server returned error file_not_found")
+            }
+            RevokePublicURLError::InvalidArgName(_) => {
                 write!(f, "Server returned error invalid_arg_name")
             }
-            RevokePublicURLError::InvalidArrayArg => {
+            RevokePublicURLError::InvalidArrayArg(_) => {
                 write!(f, "Server returned error invalid_array_arg")
             }
-            RevokePublicURLError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            RevokePublicURLError::InvalidCharset => {
+            RevokePublicURLError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            RevokePublicURLError::InvalidCharset(_) => {
                 write!(f, "Server returned error invalid_charset")
             }
-            RevokePublicURLError::InvalidFormData => {
+            RevokePublicURLError::InvalidFormData(_) => {
                 write!(f, "Server returned error invalid_form_data")
             }
-            RevokePublicURLError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            RevokePublicURLError::InvalidPostType => {
+            RevokePublicURLError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            RevokePublicURLError::InvalidPostType(_) => {
                 write!(f, "Server returned error invalid_post_type")
             }
-            RevokePublicURLError::JsonNotObject => {
+            RevokePublicURLError::JsonNotObject(_) => {
                 write!(f, "Server returned error json_not_object")
             }
-            RevokePublicURLError::MissingPostType => {
+            RevokePublicURLError::MissingPostType(_) => {
                 write!(f, "Server returned error missing_post_type")
             }
-            RevokePublicURLError::NoPermission => write!(f, "Server returned error no_permission"),
-            RevokePublicURLError::NotAuthed => write!(f, "Server returned error not_authed"),
-            RevokePublicURLError::OrgLoginRequired => {
+            RevokePublicURLError::NoPermission(_) => {
+                write!(f, "Server returned error no_permission")
+            }
+            RevokePublicURLError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            RevokePublicURLError::OrgLoginRequired(_) => {
                 write!(f, "Server returned error org_login_required")
             }
-            RevokePublicURLError::RequestTimeout => {
+            RevokePublicURLError::RequestTimeout(_) => {
                 write!(f, "Server returned error request_timeout")
             }
-            RevokePublicURLError::TeamAddedToOrg => {
+            RevokePublicURLError::TeamAddedToOrg(_) => {
                 write!(f, "Server returned error team_added_to_org")
             }
-            RevokePublicURLError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            RevokePublicURLError::UpgradeRequired => {
+            RevokePublicURLError::TokenRevoked(_) => {
+                write!(f, "Server returned error token_revoked")
+            }
+            RevokePublicURLError::UpgradeRequired(_) => {
                 write!(f, "Server returned error upgrade_required")
             }
-            RevokePublicURLError::UserIsBot => write!(f, "Server returned error user_is_bot"),
-            RevokePublicURLError::UserIsRestricted => {
+            RevokePublicURLError::UserIsBot(_) => write!(f, "Server returned error user_is_bot"),
+            RevokePublicURLError::UserIsRestricted(_) => {
                 write!(f, "Server returned error user_is_restricted")
             }
             RevokePublicURLError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            RevokePublicURLError::Unknown(ref s) => write!(f, "{}", s),
+            RevokePublicURLError::Unknown(ref s, _) => write!(f, "{}", s),
             RevokePublicURLError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -840,26 +1791,94 @@ impl<E: Error + 'static> Error for RevokePublicURLError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for RevokePublicURLError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            RevokePublicURLError::AccountInactive(_) => Some("account_inactive"),
+            RevokePublicURLError::FatalError(_) => Some("fatal_error"),
+            RevokePublicURLError::FileNotFound(_) => Some("file_not_found"),
+            RevokePublicURLError::InvalidArgName(_) => Some("invalid_arg_name"),
+            RevokePublicURLError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            RevokePublicURLError::InvalidAuth(_) => Some("invalid_auth"),
+            RevokePublicURLError::InvalidCharset(_) => Some("invalid_charset"),
+            RevokePublicURLError::InvalidFormData(_) => Some("invalid_form_data"),
+            RevokePublicURLError::InvalidJson(_) => Some("invalid_json"),
+            RevokePublicURLError::InvalidPostType(_) => Some("invalid_post_type"),
+            RevokePublicURLError::JsonNotObject(_) => Some("json_not_object"),
+            RevokePublicURLError::MissingPostType(_) => Some("missing_post_type"),
+            RevokePublicURLError::NoPermission(_) => Some("no_permission"),
+            RevokePublicURLError::NotAuthed(_) => Some("not_authed"),
+            RevokePublicURLError::OrgLoginRequired(_) => Some("org_login_required"),
+            RevokePublicURLError::RequestTimeout(_) => Some("request_timeout"),
+            RevokePublicURLError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            RevokePublicURLError::TokenRevoked(_) => Some("token_revoked"),
+            RevokePublicURLError::UpgradeRequired(_) => Some("upgrade_required"),
+            RevokePublicURLError::UserIsBot(_) => Some("user_is_bot"),
+            RevokePublicURLError::UserIsRestricted(_) => Some("user_is_restricted"),
+            RevokePublicURLError::Unknown(ref s, _) => Some(s),
+            RevokePublicURLError::MalformedResponse(..) | RevokePublicURLError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            RevokePublicURLError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            RevokePublicURLError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            RevokePublicURLError::AccountInactive(ref context)
+            | RevokePublicURLError::FatalError(ref context)
+            | RevokePublicURLError::FileNotFound(ref context)
+            | RevokePublicURLError::InvalidArgName(ref context)
+            | RevokePublicURLError::InvalidArrayArg(ref context)
+            | RevokePublicURLError::InvalidAuth(ref context)
+            | RevokePublicURLError::InvalidCharset(ref context)
+            | RevokePublicURLError::InvalidFormData(ref context)
+            | RevokePublicURLError::InvalidJson(ref context)
+            | RevokePublicURLError::InvalidPostType(ref context)
+            | RevokePublicURLError::JsonNotObject(ref context)
+            | RevokePublicURLError::MissingPostType(ref context)
+            | RevokePublicURLError::NoPermission(ref context)
+            | RevokePublicURLError::NotAuthed(ref context)
+            | RevokePublicURLError::OrgLoginRequired(ref context)
+            | RevokePublicURLError::RequestTimeout(ref context)
+            | RevokePublicURLError::TeamAddedToOrg(ref context)
+            | RevokePublicURLError::TokenRevoked(ref context)
+            | RevokePublicURLError::UpgradeRequired(ref context)
+            | RevokePublicURLError::UserIsBot(ref context)
+            | RevokePublicURLError::UserIsRestricted(ref context)
+            | RevokePublicURLError::Unknown(_, ref context) => Some(context),
+            RevokePublicURLError::MalformedResponse(..) | RevokePublicURLError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
-pub struct SharedPublicURLRequest<'a> {
+pub struct SharedPublicURLRequest {
     /// File to share
-    pub file: Option<Cow<'a, str>>,
+    pub file: Option<crate::FileId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SharedPublicURLReactionsInner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SharedPublicURLSharesInner {
     pub private: Option<serde_json::Value>,
     pub public: Option<serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SharedPublicURLFileInner {
     pub channels: Option<Vec<String>>,
     pub comments_count: Option<u64>,
@@ -874,7 +1893,7 @@ pub struct SharedPublicURLFileInner {
     pub filetype: Option<String>,
     pub groups: Option<Vec<String>>,
     pub has_rich_preview: Option<bool>,
-    pub id: Option<String>,
+    pub id: Option<crate::FileId>,
     pub image_exif_rotation: Option<u64>,
     pub ims: Option<Vec<String>>,
     pub is_external: Option<bool>,
@@ -928,18 +1947,24 @@ pub struct SharedPublicURLFileInner {
     pub updated: Option<u64>,
     pub url_private: Option<String>,
     pub url_private_download: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SharedPublicURLResponse {
     pub callstack: Option<String>,
     error: Option<String>,
     pub file: SharedPublicURLFileInner,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<SharedPublicURLResponse, SharedPublicURLError<E>>>
@@ -949,126 +1974,167 @@ impl<E: Error> Into<Result<SharedPublicURLResponse, SharedPublicURLError<E>>>
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(SharedPublicURLError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for SharedPublicURLResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum SharedPublicURLError<E: Error> {
-    AccountInactive,
-    FatalError,
-    FileNotFound,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    NoPermission,
-    NotAllowed,
-    NotAuthed,
-    OrgLoginRequired,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequired,
-    UserIsBot,
-    UserIsRestricted,
+    AccountInactive(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    FileNotFound(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAllowed(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserIsBot(crate::ErrorContext),
+    UserIsRestricted(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> SharedPublicURLError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => SharedPublicURLError::AccountInactive(context),
+            "fatal_error" => SharedPublicURLError::FatalError(context),
+            "file_not_found" => SharedPublicURLError::FileNotFound(context),
+            "invalid_arg_name" => SharedPublicURLError::InvalidArgName(context),
+            "invalid_array_arg" => SharedPublicURLError::InvalidArrayArg(context),
+            "invalid_auth" => SharedPublicURLError::InvalidAuth(context),
+            "invalid_charset" => SharedPublicURLError::InvalidCharset(context),
+            "invalid_form_data" => SharedPublicURLError::InvalidFormData(context),
+            "invalid_json" => SharedPublicURLError::InvalidJson(context),
+            "invalid_post_type" => SharedPublicURLError::InvalidPostType(context),
+            "json_not_object" => SharedPublicURLError::JsonNotObject(context),
+            "missing_post_type" => SharedPublicURLError::MissingPostType(context),
+            "no_permission" => SharedPublicURLError::NoPermission(context),
+            "not_allowed" => SharedPublicURLError::NotAllowed(context),
+            "not_authed" => SharedPublicURLError::NotAuthed(context),
+            "org_login_required" => SharedPublicURLError::OrgLoginRequired(context),
+            "request_timeout" => SharedPublicURLError::RequestTimeout(context),
+            "team_added_to_org" => SharedPublicURLError::TeamAddedToOrg(context),
+            "token_revoked" => SharedPublicURLError::TokenRevoked(context),
+            "upgrade_required" => SharedPublicURLError::UpgradeRequired(context),
+            "user_is_bot" => SharedPublicURLError::UserIsBot(context),
+            "user_is_restricted" => SharedPublicURLError::UserIsRestricted(context),
+            _ => SharedPublicURLError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for SharedPublicURLError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => SharedPublicURLError::AccountInactive,
-            "fatal_error" => SharedPublicURLError::FatalError,
-            "file_not_found" => SharedPublicURLError::FileNotFound,
-            "invalid_arg_name" => SharedPublicURLError::InvalidArgName,
-            "invalid_array_arg" => SharedPublicURLError::InvalidArrayArg,
-            "invalid_auth" => SharedPublicURLError::InvalidAuth,
-            "invalid_charset" => SharedPublicURLError::InvalidCharset,
-            "invalid_form_data" => SharedPublicURLError::InvalidFormData,
-            "invalid_json" => SharedPublicURLError::InvalidJson,
-            "invalid_post_type" => SharedPublicURLError::InvalidPostType,
-            "json_not_object" => SharedPublicURLError::JsonNotObject,
-            "missing_post_type" => SharedPublicURLError::MissingPostType,
-            "no_permission" => SharedPublicURLError::NoPermission,
-            "not_allowed" => SharedPublicURLError::NotAllowed,
-            "not_authed" => SharedPublicURLError::NotAuthed,
-            "org_login_required" => SharedPublicURLError::OrgLoginRequired,
-            "request_timeout" => SharedPublicURLError::RequestTimeout,
-            "team_added_to_org" => SharedPublicURLError::TeamAddedToOrg,
-            "token_revoked" => SharedPublicURLError::TokenRevoked,
-            "upgrade_required" => SharedPublicURLError::UpgradeRequired,
-            "user_is_bot" => SharedPublicURLError::UserIsBot,
-            "user_is_restricted" => SharedPublicURLError::UserIsRestricted,
-            _ => SharedPublicURLError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for SharedPublicURLError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            SharedPublicURLError::AccountInactive => {
+            SharedPublicURLError::AccountInactive(_) => {
                 write!(f, "Server returned error account_inactive")
             }
-            SharedPublicURLError::FatalError => write!(f, "Server returned error fatal_error"),
-            SharedPublicURLError::FileNotFound => write!(f, "Server returned error file_not_found"),
-            SharedPublicURLError::InvalidArgName => {
+            SharedPublicURLError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            SharedPublicURLError::FileNotFound(_) => {
+                write!(f, "Server returned error file_not_found")
+            }
+            SharedPublicURLError::InvalidArgName(_) => {
                 write!(f, "Server returned error invalid_arg_name")
             }
-            SharedPublicURLError::InvalidArrayArg => {
+            SharedPublicURLError::InvalidArrayArg(_) => {
                 write!(f, "Server returned error invalid_array_arg")
             }
-            SharedPublicURLError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            SharedPublicURLError::InvalidCharset => {
+            SharedPublicURLError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            SharedPublicURLError::InvalidCharset(_) => {
                 write!(f, "Server returned error invalid_charset")
             }
-            SharedPublicURLError::InvalidFormData => {
+            SharedPublicURLError::InvalidFormData(_) => {
                 write!(f, "Server returned error invalid_form_data")
             }
-            SharedPublicURLError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            SharedPublicURLError::InvalidPostType => {
+            SharedPublicURLError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            SharedPublicURLError::InvalidPostType(_) => {
                 write!(f, "Server returned error invalid_post_type")
             }
-            SharedPublicURLError::JsonNotObject => {
+            SharedPublicURLError::JsonNotObject(_) => {
                 write!(f, "Server returned error json_not_object")
             }
-            SharedPublicURLError::MissingPostType => {
+            SharedPublicURLError::MissingPostType(_) => {
                 write!(f, "Server returned error missing_post_type")
             }
-            SharedPublicURLError::NoPermission => write!(f, "Server returned error no_permission"),
-            SharedPublicURLError::NotAllowed => write!(f, "Server returned error not_allowed"),
-            SharedPublicURLError::NotAuthed => write!(f, "Server returned error not_authed"),
-            SharedPublicURLError::OrgLoginRequired => {
+            SharedPublicURLError::NoPermission(_) => {
+                write!(f, "Server returned error no_permission")
+            }
+            SharedPublicURLError::NotAllowed(_) => write!(f, "Server returned error not_allowed"),
+            SharedPublicURLError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            SharedPublicURLError::OrgLoginRequired(_) => {
                 write!(f, "Server returned error org_login_required")
             }
-            SharedPublicURLError::RequestTimeout => {
+            SharedPublicURLError::RequestTimeout(_) => {
                 write!(f, "Server returned error request_timeout")
             }
-            SharedPublicURLError::TeamAddedToOrg => {
+            SharedPublicURLError::TeamAddedToOrg(_) => {
                 write!(f, "Server returned error team_added_to_org")
             }
-            SharedPublicURLError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            SharedPublicURLError::UpgradeRequired => {
+            SharedPublicURLError::TokenRevoked(_) => {
+                write!(f, "Server returned error token_revoked")
+            }
+            SharedPublicURLError::UpgradeRequired(_) => {
                 write!(f, "Server returned error upgrade_required")
             }
-            SharedPublicURLError::UserIsBot => write!(f, "Server returned error user_is_bot"),
-            SharedPublicURLError::UserIsRestricted => {
+            SharedPublicURLError::UserIsBot(_) => write!(f, "Server returned error user_is_bot"),
+            SharedPublicURLError::UserIsRestricted(_) => {
                 write!(f, "Server returned error user_is_restricted")
             }
             SharedPublicURLError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            SharedPublicURLError::Unknown(ref s) => write!(f, "{}", s),
+            SharedPublicURLError::Unknown(ref s, _) => write!(f, "{}", s),
             SharedPublicURLError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -1084,6 +2150,76 @@ impl<E: Error + 'static> Error for SharedPublicURLError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for SharedPublicURLError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            SharedPublicURLError::AccountInactive(_) => Some("account_inactive"),
+            SharedPublicURLError::FatalError(_) => Some("fatal_error"),
+            SharedPublicURLError::FileNotFound(_) => Some("file_not_found"),
+            SharedPublicURLError::InvalidArgName(_) => Some("invalid_arg_name"),
+            SharedPublicURLError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            SharedPublicURLError::InvalidAuth(_) => Some("invalid_auth"),
+            SharedPublicURLError::InvalidCharset(_) => Some("invalid_charset"),
+            SharedPublicURLError::InvalidFormData(_) => Some("invalid_form_data"),
+            SharedPublicURLError::InvalidJson(_) => Some("invalid_json"),
+            SharedPublicURLError::InvalidPostType(_) => Some("invalid_post_type"),
+            SharedPublicURLError::JsonNotObject(_) => Some("json_not_object"),
+            SharedPublicURLError::MissingPostType(_) => Some("missing_post_type"),
+            SharedPublicURLError::NoPermission(_) => Some("no_permission"),
+            SharedPublicURLError::NotAllowed(_) => Some("not_allowed"),
+            SharedPublicURLError::NotAuthed(_) => Some("not_authed"),
+            SharedPublicURLError::OrgLoginRequired(_) => Some("org_login_required"),
+            SharedPublicURLError::RequestTimeout(_) => Some("request_timeout"),
+            SharedPublicURLError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            SharedPublicURLError::TokenRevoked(_) => Some("token_revoked"),
+            SharedPublicURLError::UpgradeRequired(_) => Some("upgrade_required"),
+            SharedPublicURLError::UserIsBot(_) => Some("user_is_bot"),
+            SharedPublicURLError::UserIsRestricted(_) => Some("user_is_restricted"),
+            SharedPublicURLError::Unknown(ref s, _) => Some(s),
+            SharedPublicURLError::MalformedResponse(..) | SharedPublicURLError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            SharedPublicURLError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            SharedPublicURLError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            SharedPublicURLError::AccountInactive(ref context)
+            | SharedPublicURLError::FatalError(ref context)
+            | SharedPublicURLError::FileNotFound(ref context)
+            | SharedPublicURLError::InvalidArgName(ref context)
+            | SharedPublicURLError::InvalidArrayArg(ref context)
+            | SharedPublicURLError::InvalidAuth(ref context)
+            | SharedPublicURLError::InvalidCharset(ref context)
+            | SharedPublicURLError::InvalidFormData(ref context)
+            | SharedPublicURLError::InvalidJson(ref context)
+            | SharedPublicURLError::InvalidPostType(ref context)
+            | SharedPublicURLError::JsonNotObject(ref context)
+            | SharedPublicURLError::MissingPostType(ref context)
+            | SharedPublicURLError::NoPermission(ref context)
+            | SharedPublicURLError::NotAllowed(ref context)
+            | SharedPublicURLError::NotAuthed(ref context)
+            | SharedPublicURLError::OrgLoginRequired(ref context)
+            | SharedPublicURLError::RequestTimeout(ref context)
+            | SharedPublicURLError::TeamAddedToOrg(ref context)
+            | SharedPublicURLError::TokenRevoked(ref context)
+            | SharedPublicURLError::UpgradeRequired(ref context)
+            | SharedPublicURLError::UserIsBot(ref context)
+            | SharedPublicURLError::UserIsRestricted(ref context)
+            | SharedPublicURLError::Unknown(_, ref context) => Some(context),
+            SharedPublicURLError::MalformedResponse(..) | SharedPublicURLError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct UploadRequest<'a> {
     /// Comma-separated list of channel names or IDs where the file will be shared.
@@ -1091,7 +2227,7 @@ pub struct UploadRequest<'a> {
     /// File contents via a POST variable. If omitting this parameter, you must provide a `file`.
     pub content: Option<Cow<'a, str>>,
     /// File contents via `multipart/form-data`. If omitting this parameter, you must submit `content`.
-    pub file: Option<Cow<'a, str>>,
+    pub file: Option<crate::FileUpload<'a>>,
     /// Filename of file.
     pub filename: Option<Cow<'a, str>>,
     /// A [file type](/types/file#file_types) identifier.
@@ -1099,25 +2235,25 @@ pub struct UploadRequest<'a> {
     /// The message text introducing the file in specified `channels`.
     pub initial_comment: Option<Cow<'a, str>>,
     /// Provide another message's `ts` value to upload this file as a reply. Never use a reply's `ts` value; use its parent instead.
-    pub thread_ts: Option<f64>,
+    pub thread_ts: Option<crate::Timestamp>,
     /// Title of file.
     pub title: Option<Cow<'a, str>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UploadReactionsInner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UploadSharesInner {
     pub private: Option<serde_json::Value>,
     pub public: Option<serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UploadFileInner {
     pub channels: Option<Vec<String>>,
     pub comments_count: Option<u64>,
@@ -1132,7 +2268,7 @@ pub struct UploadFileInner {
     pub filetype: Option<String>,
     pub groups: Option<Vec<String>>,
     pub has_rich_preview: Option<bool>,
-    pub id: Option<String>,
+    pub id: Option<crate::FileId>,
     pub image_exif_rotation: Option<u64>,
     pub ims: Option<Vec<String>>,
     pub is_external: Option<bool>,
@@ -1186,18 +2322,24 @@ pub struct UploadFileInner {
     pub updated: Option<u64>,
     pub url_private: Option<String>,
     pub url_private_download: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UploadResponse {
     pub callstack: Option<String>,
     error: Option<String>,
     pub file: UploadFileInner,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<UploadResponse, UploadError<E>>> for UploadResponse {
@@ -1205,108 +2347,147 @@ impl<E: Error> Into<Result<UploadResponse, UploadError<E>>> for UploadResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(UploadError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for UploadResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum UploadError<E: Error> {
-    AccountInactive,
-    FileUploadSizeRestricted,
-    FileUploadsDisabled,
-    FileUploadsExceptImagesDisabled,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidChannel,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    PostingToGeneralChannelDenied,
-    RequestTimeout,
-    StorageLimitReached,
-    TeamAddedToOrg,
-    UpgradeRequired,
+    AccountInactive(crate::ErrorContext),
+    FileUploadSizeRestricted(crate::ErrorContext),
+    FileUploadsDisabled(crate::ErrorContext),
+    FileUploadsExceptImagesDisabled(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidChannel(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    PostingToGeneralChannelDenied(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    StorageLimitReached(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> UploadError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => UploadError::AccountInactive(context),
+            "file_upload_size_restricted" => UploadError::FileUploadSizeRestricted(context),
+            "file_uploads_disabled" => UploadError::FileUploadsDisabled(context),
+            "file_uploads_except_images_disabled" => {
+                UploadError::FileUploadsExceptImagesDisabled(context)
+            }
+            "invalid_arg_name" => UploadError::InvalidArgName(context),
+            "invalid_array_arg" => UploadError::InvalidArrayArg(context),
+            "invalid_auth" => UploadError::InvalidAuth(context),
+            "invalid_channel" => UploadError::InvalidChannel(context),
+            "invalid_charset" => UploadError::InvalidCharset(context),
+            "invalid_form_data" => UploadError::InvalidFormData(context),
+            "invalid_json" => UploadError::InvalidJson(context),
+            "invalid_post_type" => UploadError::InvalidPostType(context),
+            "json_not_object" => UploadError::JsonNotObject(context),
+            "missing_post_type" => UploadError::MissingPostType(context),
+            "no_permission" => UploadError::NoPermission(context),
+            "not_authed" => UploadError::NotAuthed(context),
+            "posting_to_general_channel_denied" => {
+                UploadError::PostingToGeneralChannelDenied(context)
+            }
+            "request_timeout" => UploadError::RequestTimeout(context),
+            "storage_limit_reached" => UploadError::StorageLimitReached(context),
+            "team_added_to_org" => UploadError::TeamAddedToOrg(context),
+            "upgrade_required" => UploadError::UpgradeRequired(context),
+            _ => UploadError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for UploadError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => UploadError::AccountInactive,
-            "file_upload_size_restricted" => UploadError::FileUploadSizeRestricted,
-            "file_uploads_disabled" => UploadError::FileUploadsDisabled,
-            "file_uploads_except_images_disabled" => UploadError::FileUploadsExceptImagesDisabled,
-            "invalid_arg_name" => UploadError::InvalidArgName,
-            "invalid_array_arg" => UploadError::InvalidArrayArg,
-            "invalid_auth" => UploadError::InvalidAuth,
-            "invalid_channel" => UploadError::InvalidChannel,
-            "invalid_charset" => UploadError::InvalidCharset,
-            "invalid_form_data" => UploadError::InvalidFormData,
-            "invalid_json" => UploadError::InvalidJson,
-            "invalid_post_type" => UploadError::InvalidPostType,
-            "json_not_object" => UploadError::JsonNotObject,
-            "missing_post_type" => UploadError::MissingPostType,
-            "no_permission" => UploadError::NoPermission,
-            "not_authed" => UploadError::NotAuthed,
-            "posting_to_general_channel_denied" => UploadError::PostingToGeneralChannelDenied,
-            "request_timeout" => UploadError::RequestTimeout,
-            "storage_limit_reached" => UploadError::StorageLimitReached,
-            "team_added_to_org" => UploadError::TeamAddedToOrg,
-            "upgrade_required" => UploadError::UpgradeRequired,
-            _ => UploadError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for UploadError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            UploadError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            UploadError::FileUploadSizeRestricted => {
+            UploadError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            UploadError::FileUploadSizeRestricted(_) => {
                 write!(f, "Server returned error file_upload_size_restricted")
             }
-            UploadError::FileUploadsDisabled => {
+            UploadError::FileUploadsDisabled(_) => {
                 write!(f, "Server returned error file_uploads_disabled")
             }
-            UploadError::FileUploadsExceptImagesDisabled => write!(
+            UploadError::FileUploadsExceptImagesDisabled(_) => write!(
                 f,
                 "Server returned error file_uploads_except_images_disabled"
             ),
-            UploadError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            UploadError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            UploadError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            UploadError::InvalidChannel => write!(f, "Server returned error invalid_channel"),
-            UploadError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            UploadError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            UploadError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            UploadError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            UploadError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            UploadError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            UploadError::NoPermission => write!(f, "Server returned error no_permission"),
-            UploadError::NotAuthed => write!(f, "Server returned error not_authed"),
-            UploadError::PostingToGeneralChannelDenied => {
+            UploadError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            UploadError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            UploadError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            UploadError::InvalidChannel(_) => write!(f, "Server returned error invalid_channel"),
+            UploadError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            UploadError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            UploadError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            UploadError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            UploadError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            UploadError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            UploadError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            UploadError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            UploadError::PostingToGeneralChannelDenied(_) => {
                 write!(f, "Server returned error posting_to_general_channel_denied")
             }
-            UploadError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            UploadError::StorageLimitReached => {
+            UploadError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            UploadError::StorageLimitReached(_) => {
                 write!(f, "Server returned error storage_limit_reached")
             }
-            UploadError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            UploadError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
+            UploadError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            UploadError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
             UploadError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            UploadError::Unknown(ref s) => write!(f, "{}", s),
+            UploadError::Unknown(ref s, _) => write!(f, "{}", s),
             UploadError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -1321,3 +2502,75 @@ impl<E: Error + 'static> Error for UploadError<E> {
         }
     }
 }
+
+impl<E: Error + 'static> crate::MethodError for UploadError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            UploadError::AccountInactive(_) => Some("account_inactive"),
+            UploadError::FileUploadSizeRestricted(_) => Some("file_upload_size_restricted"),
+            UploadError::FileUploadsDisabled(_) => Some("file_uploads_disabled"),
+            UploadError::FileUploadsExceptImagesDisabled(_) => {
+                Some("file_uploads_except_images_disabled")
+            }
+            UploadError::InvalidArgName(_) => Some("invalid_arg_name"),
+            UploadError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            UploadError::InvalidAuth(_) => Some("invalid_auth"),
+            UploadError::InvalidChannel(_) => Some("invalid_channel"),
+            UploadError::InvalidCharset(_) => Some("invalid_charset"),
+            UploadError::InvalidFormData(_) => Some("invalid_form_data"),
+            UploadError::InvalidJson(_) => Some("invalid_json"),
+            UploadError::InvalidPostType(_) => Some("invalid_post_type"),
+            UploadError::JsonNotObject(_) => Some("json_not_object"),
+            UploadError::MissingPostType(_) => Some("missing_post_type"),
+            UploadError::NoPermission(_) => Some("no_permission"),
+            UploadError::NotAuthed(_) => Some("not_authed"),
+            UploadError::PostingToGeneralChannelDenied(_) => {
+                Some("posting_to_general_channel_denied")
+            }
+            UploadError::RequestTimeout(_) => Some("request_timeout"),
+            UploadError::StorageLimitReached(_) => Some("storage_limit_reached"),
+            UploadError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            UploadError::UpgradeRequired(_) => Some("upgrade_required"),
+            UploadError::Unknown(ref s, _) => Some(s),
+            UploadError::MalformedResponse(..) | UploadError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            UploadError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            UploadError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            UploadError::AccountInactive(ref context)
+            | UploadError::FileUploadSizeRestricted(ref context)
+            | UploadError::FileUploadsDisabled(ref context)
+            | UploadError::FileUploadsExceptImagesDisabled(ref context)
+            | UploadError::InvalidArgName(ref context)
+            | UploadError::InvalidArrayArg(ref context)
+            | UploadError::InvalidAuth(ref context)
+            | UploadError::InvalidChannel(ref context)
+            | UploadError::InvalidCharset(ref context)
+            | UploadError::InvalidFormData(ref context)
+            | UploadError::InvalidJson(ref context)
+            | UploadError::InvalidPostType(ref context)
+            | UploadError::JsonNotObject(ref context)
+            | UploadError::MissingPostType(ref context)
+            | UploadError::NoPermission(ref context)
+            | UploadError::NotAuthed(ref context)
+            | UploadError::PostingToGeneralChannelDenied(ref context)
+            | UploadError::RequestTimeout(ref context)
+            | UploadError::StorageLimitReached(ref context)
+            | UploadError::TeamAddedToOrg(ref context)
+            | UploadError::UpgradeRequired(ref context)
+            | UploadError::Unknown(_, ref context) => Some(context),
+            UploadError::MalformedResponse(..) | UploadError::Client(_) => None,
+        }
+    }
+}
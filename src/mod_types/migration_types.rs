@@ -24,23 +24,29 @@ use std::fmt;
 #[derive(Clone, Default, Debug)]
 pub struct ExchangeRequest<'a> {
     /// Specify team_id starts with `T` in case of Org Token
-    pub team_id: Option<Cow<'a, str>>,
+    pub team_id: Option<crate::TeamId>,
     /// Specify `true` to convert `W` global user IDs to workspace-specific `U` IDs. Defaults to `false`.
     pub to_old: Option<bool>,
     /// A comma-separated list of user ids, up to 400 per request
     pub users: Cow<'a, str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ExchangeResponse {
     pub callstack: Option<String>,
-    pub enterprise_id: String,
+    pub enterprise_id: crate::EnterpriseId,
     error: Option<String>,
     pub invalid_user_ids: Option<Vec<String>>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
-    pub team_id: String,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub team_id: crate::TeamId,
     pub user_id_map: Option<serde_json::Value>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<ExchangeResponse, ExchangeError<E>>> for ExchangeResponse {
@@ -48,98 +54,147 @@ impl<E: Error> Into<Result<ExchangeResponse, ExchangeError<E>>> for ExchangeResp
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(ExchangeError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for ExchangeResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum ExchangeError<E: Error> {
-    AccountInactive,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    NotEnterpriseTeam,
-    OrgLoginRequired,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TokenRevoked,
-    TooManyUsers,
-    UpgradeRequired,
+    AccountInactive(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    NotEnterpriseTeam(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    TooManyUsers(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> ExchangeError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => ExchangeError::AccountInactive(context),
+            "fatal_error" => ExchangeError::FatalError(context),
+            "invalid_arg_name" => ExchangeError::InvalidArgName(context),
+            "invalid_array_arg" => ExchangeError::InvalidArrayArg(context),
+            "invalid_auth" => ExchangeError::InvalidAuth(context),
+            "invalid_charset" => ExchangeError::InvalidCharset(context),
+            "invalid_form_data" => ExchangeError::InvalidFormData(context),
+            "invalid_json" => ExchangeError::InvalidJson(context),
+            "invalid_post_type" => ExchangeError::InvalidPostType(context),
+            "json_not_object" => ExchangeError::JsonNotObject(context),
+            "missing_post_type" => ExchangeError::MissingPostType(context),
+            "no_permission" => ExchangeError::NoPermission(context),
+            "not_authed" => ExchangeError::NotAuthed(context),
+            "not_enterprise_team" => ExchangeError::NotEnterpriseTeam(context),
+            "org_login_required" => ExchangeError::OrgLoginRequired(context),
+            "request_timeout" => ExchangeError::RequestTimeout(context),
+            "team_added_to_org" => ExchangeError::TeamAddedToOrg(context),
+            "token_revoked" => ExchangeError::TokenRevoked(context),
+            "too_many_users" => ExchangeError::TooManyUsers(context),
+            "upgrade_required" => ExchangeError::UpgradeRequired(context),
+            _ => ExchangeError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for ExchangeError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => ExchangeError::AccountInactive,
-            "fatal_error" => ExchangeError::FatalError,
-            "invalid_arg_name" => ExchangeError::InvalidArgName,
-            "invalid_array_arg" => ExchangeError::InvalidArrayArg,
-            "invalid_auth" => ExchangeError::InvalidAuth,
-            "invalid_charset" => ExchangeError::InvalidCharset,
-            "invalid_form_data" => ExchangeError::InvalidFormData,
-            "invalid_json" => ExchangeError::InvalidJson,
-            "invalid_post_type" => ExchangeError::InvalidPostType,
-            "json_not_object" => ExchangeError::JsonNotObject,
-            "missing_post_type" => ExchangeError::MissingPostType,
-            "no_permission" => ExchangeError::NoPermission,
-            "not_authed" => ExchangeError::NotAuthed,
-            "not_enterprise_team" => ExchangeError::NotEnterpriseTeam,
-            "org_login_required" => ExchangeError::OrgLoginRequired,
-            "request_timeout" => ExchangeError::RequestTimeout,
-            "team_added_to_org" => ExchangeError::TeamAddedToOrg,
-            "token_revoked" => ExchangeError::TokenRevoked,
-            "too_many_users" => ExchangeError::TooManyUsers,
-            "upgrade_required" => ExchangeError::UpgradeRequired,
-            _ => ExchangeError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for ExchangeError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            ExchangeError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            ExchangeError::FatalError => write!(f, "Server returned error fatal_error"),
-            ExchangeError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            ExchangeError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            ExchangeError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            ExchangeError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            ExchangeError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            ExchangeError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            ExchangeError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            ExchangeError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            ExchangeError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            ExchangeError::NoPermission => write!(f, "Server returned error no_permission"),
-            ExchangeError::NotAuthed => write!(f, "Server returned error not_authed"),
-            ExchangeError::NotEnterpriseTeam => {
+            ExchangeError::AccountInactive(_) => {
+                write!(f, "Server returned error account_inactive")
+            }
+            ExchangeError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            ExchangeError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            ExchangeError::InvalidArrayArg(_) => {
+                write!(f, "Server returned error invalid_array_arg")
+            }
+            ExchangeError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            ExchangeError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            ExchangeError::InvalidFormData(_) => {
+                write!(f, "Server returned error invalid_form_data")
+            }
+            ExchangeError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            ExchangeError::InvalidPostType(_) => {
+                write!(f, "Server returned error invalid_post_type")
+            }
+            ExchangeError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            ExchangeError::MissingPostType(_) => {
+                write!(f, "Server returned error missing_post_type")
+            }
+            ExchangeError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            ExchangeError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            ExchangeError::NotEnterpriseTeam(_) => {
                 write!(f, "Server returned error not_enterprise_team")
             }
-            ExchangeError::OrgLoginRequired => {
+            ExchangeError::OrgLoginRequired(_) => {
                 write!(f, "Server returned error org_login_required")
             }
-            ExchangeError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            ExchangeError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            ExchangeError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            ExchangeError::TooManyUsers => write!(f, "Server returned error too_many_users"),
-            ExchangeError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
+            ExchangeError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            ExchangeError::TeamAddedToOrg(_) => {
+                write!(f, "Server returned error team_added_to_org")
+            }
+            ExchangeError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            ExchangeError::TooManyUsers(_) => write!(f, "Server returned error too_many_users"),
+            ExchangeError::UpgradeRequired(_) => {
+                write!(f, "Server returned error upgrade_required")
+            }
             ExchangeError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            ExchangeError::Unknown(ref s) => write!(f, "{}", s),
+            ExchangeError::Unknown(ref s, _) => write!(f, "{}", s),
             ExchangeError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -154,3 +209,69 @@ impl<E: Error + 'static> Error for ExchangeError<E> {
         }
     }
 }
+
+impl<E: Error + 'static> crate::MethodError for ExchangeError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            ExchangeError::AccountInactive(_) => Some("account_inactive"),
+            ExchangeError::FatalError(_) => Some("fatal_error"),
+            ExchangeError::InvalidArgName(_) => Some("invalid_arg_name"),
+            ExchangeError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            ExchangeError::InvalidAuth(_) => Some("invalid_auth"),
+            ExchangeError::InvalidCharset(_) => Some("invalid_charset"),
+            ExchangeError::InvalidFormData(_) => Some("invalid_form_data"),
+            ExchangeError::InvalidJson(_) => Some("invalid_json"),
+            ExchangeError::InvalidPostType(_) => Some("invalid_post_type"),
+            ExchangeError::JsonNotObject(_) => Some("json_not_object"),
+            ExchangeError::MissingPostType(_) => Some("missing_post_type"),
+            ExchangeError::NoPermission(_) => Some("no_permission"),
+            ExchangeError::NotAuthed(_) => Some("not_authed"),
+            ExchangeError::NotEnterpriseTeam(_) => Some("not_enterprise_team"),
+            ExchangeError::OrgLoginRequired(_) => Some("org_login_required"),
+            ExchangeError::RequestTimeout(_) => Some("request_timeout"),
+            ExchangeError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            ExchangeError::TokenRevoked(_) => Some("token_revoked"),
+            ExchangeError::TooManyUsers(_) => Some("too_many_users"),
+            ExchangeError::UpgradeRequired(_) => Some("upgrade_required"),
+            ExchangeError::Unknown(ref s, _) => Some(s),
+            ExchangeError::MalformedResponse(..) | ExchangeError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            ExchangeError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            ExchangeError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            ExchangeError::AccountInactive(ref context)
+            | ExchangeError::FatalError(ref context)
+            | ExchangeError::InvalidArgName(ref context)
+            | ExchangeError::InvalidArrayArg(ref context)
+            | ExchangeError::InvalidAuth(ref context)
+            | ExchangeError::InvalidCharset(ref context)
+            | ExchangeError::InvalidFormData(ref context)
+            | ExchangeError::InvalidJson(ref context)
+            | ExchangeError::InvalidPostType(ref context)
+            | ExchangeError::JsonNotObject(ref context)
+            | ExchangeError::MissingPostType(ref context)
+            | ExchangeError::NoPermission(ref context)
+            | ExchangeError::NotAuthed(ref context)
+            | ExchangeError::NotEnterpriseTeam(ref context)
+            | ExchangeError::OrgLoginRequired(ref context)
+            | ExchangeError::RequestTimeout(ref context)
+            | ExchangeError::TeamAddedToOrg(ref context)
+            | ExchangeError::TokenRevoked(ref context)
+            | ExchangeError::TooManyUsers(ref context)
+            | ExchangeError::UpgradeRequired(ref context)
+            | ExchangeError::Unknown(_, ref context) => Some(context),
+            ExchangeError::MalformedResponse(..) | ExchangeError::Client(_) => None,
+        }
+    }
+}
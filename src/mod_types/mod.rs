@@ -12,6 +12,7 @@ pub mod emoji_types;
 pub mod files;
 pub mod migration_types;
 pub mod oauth;
+pub mod openid;
 pub mod pins_types;
 pub mod reactions_types;
 pub mod reminders_types;
@@ -19,6 +20,7 @@ pub mod rtm_types;
 pub mod search_types;
 pub mod stars_types;
 pub mod team;
+pub mod tooling;
 pub mod usergroups;
 pub mod users;
 pub mod views_types;
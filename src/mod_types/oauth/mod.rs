@@ -26,9 +26,9 @@ use std::fmt;
 #[derive(Clone, Default, Debug)]
 pub struct AccessRequest<'a> {
     /// Issued when you created your application.
-    pub client_id: Option<Cow<'a, str>>,
+    pub client_id: Cow<'a, str>,
     /// Issued when you created your application.
-    pub client_secret: Option<Cow<'a, str>>,
+    pub client_secret: Cow<'a, str>,
     /// The `code` param returned via the OAuth callback.
     pub code: Option<Cow<'a, str>>,
     /// This must match the originally submitted URI (if one was sent).
@@ -37,10 +37,39 @@ pub struct AccessRequest<'a> {
     pub single_channel: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AccessBotInner {
+    pub bot_access_token: String,
+    pub bot_user_id: crate::UserId,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AccessIncomingWebhookInner {
+    pub channel: String,
+    pub channel_id: crate::ChannelId,
+    pub configuration_url: String,
+    pub url: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AccessResponse {
+    pub access_token: String,
+    pub bot: Option<AccessBotInner>,
+    pub enterprise_id: Option<crate::EnterpriseId>,
+    error: Option<String>,
+    pub incoming_webhook: Option<AccessIncomingWebhookInner>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub scope: String,
+    pub team_id: Option<crate::TeamId>,
+    pub team_name: Option<String>,
+    pub user_id: Option<crate::UserId>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<AccessResponse, AccessError<E>>> for AccessResponse {
@@ -48,28 +77,61 @@ impl<E: Error> Into<Result<AccessResponse, AccessError<E>>> for AccessResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(AccessError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(AccessError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for AccessResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum AccessError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> AccessError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => AccessError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for AccessError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => AccessError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -77,7 +139,7 @@ impl<E: Error> fmt::Display for AccessError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             AccessError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            AccessError::Unknown(ref s) => write!(f, "{}", s),
+            AccessError::Unknown(ref s, _) => write!(f, "{}", s),
             AccessError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -93,6 +155,32 @@ impl<E: Error + 'static> Error for AccessError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for AccessError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            AccessError::Unknown(ref s, _) => Some(s),
+            AccessError::MalformedResponse(..) | AccessError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            AccessError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            AccessError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            AccessError::Unknown(_, ref context) => Some(context),
+            AccessError::MalformedResponse(..) | AccessError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct TokenRequest<'a> {
     /// Issued when you created your application.
@@ -107,10 +195,17 @@ pub struct TokenRequest<'a> {
     pub single_channel: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct TokenResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<TokenResponse, TokenError<E>>> for TokenResponse {
@@ -118,28 +213,61 @@ impl<E: Error> Into<Result<TokenResponse, TokenError<E>>> for TokenResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(TokenError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(TokenError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for TokenResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum TokenError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> TokenError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => TokenError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for TokenError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => TokenError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -147,7 +275,7 @@ impl<E: Error> fmt::Display for TokenError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             TokenError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            TokenError::Unknown(ref s) => write!(f, "{}", s),
+            TokenError::Unknown(ref s, _) => write!(f, "{}", s),
             TokenError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -162,3 +290,29 @@ impl<E: Error + 'static> Error for TokenError<E> {
         }
     }
 }
+
+impl<E: Error + 'static> crate::MethodError for TokenError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            TokenError::Unknown(ref s, _) => Some(s),
+            TokenError::MalformedResponse(..) | TokenError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            TokenError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            TokenError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            TokenError::Unknown(_, ref context) => Some(context),
+            TokenError::MalformedResponse(..) | TokenError::Client(_) => None,
+        }
+    }
+}
@@ -0,0 +1,470 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+#![allow(unused_imports)]
+#![allow(clippy::match_single_binding)]
+#![allow(clippy::blacklisted_name)]
+
+use std::borrow::Cow;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Clone, Default, Debug)]
+pub struct TokenRequest<'a> {
+    /// Issued when you created your application.
+    pub client_id: Cow<'a, str>,
+    /// Issued when you created your application.
+    pub client_secret: Cow<'a, str>,
+    /// The `code` param returned via the OAuth callback.
+    pub code: Option<Cow<'a, str>>,
+    /// The `grant_type` param as described in the OAuth spec.
+    pub grant_type: Option<Cow<'a, str>>,
+    /// This must match the originally submitted URI (if one was sent).
+    pub redirect_uri: Option<Cow<'a, str>>,
+    /// The `refresh_token` param as described in the OAuth spec.
+    pub refresh_token: Option<Cow<'a, str>>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TokenResponse {
+    pub access_token: Option<String>,
+    error: Option<String>,
+    pub expires_in: Option<u64>,
+    pub id_token: Option<String>,
+    pub needed: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub provided: Option<String>,
+    pub refresh_token: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub token_type: Option<String>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
+}
+
+impl<E: Error> Into<Result<TokenResponse, TokenError<E>>> for TokenResponse {
+    fn into(self) -> Result<TokenResponse, TokenError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(TokenError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
+        }
+    }
+}
+
+impl crate::SlackResponse for TokenResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
+#[derive(Debug)]
+pub enum TokenError<E: Error> {
+    BadClientSecret(crate::ErrorContext),
+    BadRedirectUri(crate::ErrorContext),
+    InvalidArguments(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidClientId(crate::ErrorContext),
+    InvalidCode(crate::ErrorContext),
+    InvalidGrantType(crate::ErrorContext),
+    InvalidRefreshToken(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    OauthAuthorizationUrlMismatch(crate::ErrorContext),
+    Ratelimited(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    ServiceUnavailable(crate::ErrorContext),
+    TokenExpired(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String, crate::ErrorContext),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<E: Error> TokenError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "bad_client_secret" => TokenError::BadClientSecret(context),
+            "bad_redirect_uri" => TokenError::BadRedirectUri(context),
+            "invalid_arguments" => TokenError::InvalidArguments(context),
+            "invalid_auth" => TokenError::InvalidAuth(context),
+            "invalid_client_id" => TokenError::InvalidClientId(context),
+            "invalid_code" => TokenError::InvalidCode(context),
+            "invalid_grant_type" => TokenError::InvalidGrantType(context),
+            "invalid_refresh_token" => TokenError::InvalidRefreshToken(context),
+            "not_authed" => TokenError::NotAuthed(context),
+            "oauth_authorization_url_mismatch" => {
+                TokenError::OauthAuthorizationUrlMismatch(context)
+            }
+            "ratelimited" => TokenError::Ratelimited(context),
+            "request_timeout" => TokenError::RequestTimeout(context),
+            "service_unavailable" => TokenError::ServiceUnavailable(context),
+            "token_expired" => TokenError::TokenExpired(context),
+            "token_revoked" => TokenError::TokenRevoked(context),
+            _ => TokenError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
+impl<'a, E: Error> From<&'a str> for TokenError<E> {
+    fn from(s: &'a str) -> Self {
+        Self::new(s, crate::ErrorContext::default())
+    }
+}
+
+impl<E: Error> fmt::Display for TokenError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            TokenError::BadClientSecret(_) => write!(f, "Server returned error bad_client_secret"),
+            TokenError::BadRedirectUri(_) => write!(f, "Server returned error bad_redirect_uri"),
+            TokenError::InvalidArguments(_) => write!(f, "Server returned error invalid_arguments"),
+            TokenError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            TokenError::InvalidClientId(_) => write!(f, "Server returned error invalid_client_id"),
+            TokenError::InvalidCode(_) => write!(f, "Server returned error invalid_code"),
+            TokenError::InvalidGrantType(_) => {
+                write!(f, "Server returned error invalid_grant_type")
+            }
+            TokenError::InvalidRefreshToken(_) => {
+                write!(f, "Server returned error invalid_refresh_token")
+            }
+            TokenError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            TokenError::OauthAuthorizationUrlMismatch(_) => {
+                write!(f, "Server returned error oauth_authorization_url_mismatch")
+            }
+            TokenError::Ratelimited(_) => write!(f, "Server returned error ratelimited"),
+            TokenError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            TokenError::ServiceUnavailable(_) => {
+                write!(f, "Server returned error service_unavailable")
+            }
+            TokenError::TokenExpired(_) => write!(f, "Server returned error token_expired"),
+            TokenError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            TokenError::MalformedResponse(_, ref e) => write!(f, "{}", e),
+            TokenError::Unknown(ref s, _) => write!(f, "{}", s),
+            TokenError::Client(ref inner) => write!(f, "{}", inner),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for TokenError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            TokenError::MalformedResponse(_, ref e) => Some(e),
+            TokenError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+impl<E: Error + 'static> crate::MethodError for TokenError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            TokenError::BadClientSecret(_) => Some("bad_client_secret"),
+            TokenError::BadRedirectUri(_) => Some("bad_redirect_uri"),
+            TokenError::InvalidArguments(_) => Some("invalid_arguments"),
+            TokenError::InvalidAuth(_) => Some("invalid_auth"),
+            TokenError::InvalidClientId(_) => Some("invalid_client_id"),
+            TokenError::InvalidCode(_) => Some("invalid_code"),
+            TokenError::InvalidGrantType(_) => Some("invalid_grant_type"),
+            TokenError::InvalidRefreshToken(_) => Some("invalid_refresh_token"),
+            TokenError::NotAuthed(_) => Some("not_authed"),
+            TokenError::OauthAuthorizationUrlMismatch(_) => {
+                Some("oauth_authorization_url_mismatch")
+            }
+            TokenError::Ratelimited(_) => Some("ratelimited"),
+            TokenError::RequestTimeout(_) => Some("request_timeout"),
+            TokenError::ServiceUnavailable(_) => Some("service_unavailable"),
+            TokenError::TokenExpired(_) => Some("token_expired"),
+            TokenError::TokenRevoked(_) => Some("token_revoked"),
+            TokenError::Unknown(ref s, _) => Some(s),
+            TokenError::MalformedResponse(..) | TokenError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            TokenError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            TokenError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            TokenError::BadClientSecret(ref context)
+            | TokenError::BadRedirectUri(ref context)
+            | TokenError::InvalidArguments(ref context)
+            | TokenError::InvalidAuth(ref context)
+            | TokenError::InvalidClientId(ref context)
+            | TokenError::InvalidCode(ref context)
+            | TokenError::InvalidGrantType(ref context)
+            | TokenError::InvalidRefreshToken(ref context)
+            | TokenError::NotAuthed(ref context)
+            | TokenError::OauthAuthorizationUrlMismatch(ref context)
+            | TokenError::Ratelimited(ref context)
+            | TokenError::RequestTimeout(ref context)
+            | TokenError::ServiceUnavailable(ref context)
+            | TokenError::TokenExpired(ref context)
+            | TokenError::TokenRevoked(ref context)
+            | TokenError::Unknown(_, ref context) => Some(context),
+            TokenError::MalformedResponse(..) | TokenError::Client(_) => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct UserInfoRequest {}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct UserInfoResponse {
+    pub date_email_verified: Option<u64>,
+    pub email: Option<String>,
+    pub email_verified: Option<bool>,
+    error: Option<String>,
+    pub family_name: Option<String>,
+    pub given_name: Option<String>,
+    #[serde(rename = "https://slack.com/enterprise_id")]
+    pub enterprise_id: Option<crate::EnterpriseId>,
+    #[serde(rename = "https://slack.com/team_domain")]
+    pub team_domain: Option<String>,
+    #[serde(rename = "https://slack.com/team_id")]
+    pub team_id: Option<crate::TeamId>,
+    #[serde(rename = "https://slack.com/team_image_102")]
+    pub team_image_102: Option<String>,
+    #[serde(rename = "https://slack.com/team_image_132")]
+    pub team_image_132: Option<String>,
+    #[serde(rename = "https://slack.com/team_image_230")]
+    pub team_image_230: Option<String>,
+    #[serde(rename = "https://slack.com/team_image_34")]
+    pub team_image_34: Option<String>,
+    #[serde(rename = "https://slack.com/team_image_44")]
+    pub team_image_44: Option<String>,
+    #[serde(rename = "https://slack.com/team_image_68")]
+    pub team_image_68: Option<String>,
+    #[serde(rename = "https://slack.com/team_image_88")]
+    pub team_image_88: Option<String>,
+    #[serde(rename = "https://slack.com/team_image_default")]
+    pub team_image_default: Option<bool>,
+    #[serde(rename = "https://slack.com/team_name")]
+    pub team_name: Option<String>,
+    #[serde(rename = "https://slack.com/user_id")]
+    pub user_id: Option<crate::UserId>,
+    #[serde(rename = "https://slack.com/user_image_1024")]
+    pub user_image_1024: Option<String>,
+    #[serde(rename = "https://slack.com/user_image_192")]
+    pub user_image_192: Option<String>,
+    #[serde(rename = "https://slack.com/user_image_24")]
+    pub user_image_24: Option<String>,
+    #[serde(rename = "https://slack.com/user_image_32")]
+    pub user_image_32: Option<String>,
+    #[serde(rename = "https://slack.com/user_image_48")]
+    pub user_image_48: Option<String>,
+    #[serde(rename = "https://slack.com/user_image_512")]
+    pub user_image_512: Option<String>,
+    #[serde(rename = "https://slack.com/user_image_72")]
+    pub user_image_72: Option<String>,
+    pub locale: Option<String>,
+    pub name: Option<String>,
+    pub needed: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub picture: Option<String>,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub sub: Option<String>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
+}
+
+impl<E: Error> Into<Result<UserInfoResponse, UserInfoError<E>>> for UserInfoResponse {
+    fn into(self) -> Result<UserInfoResponse, UserInfoError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(UserInfoError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
+        }
+    }
+}
+
+impl crate::SlackResponse for UserInfoResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
+#[derive(Debug)]
+pub enum UserInfoError<E: Error> {
+    InvalidArguments(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    Ratelimited(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    ServiceUnavailable(crate::ErrorContext),
+    TokenExpired(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String, crate::ErrorContext),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<E: Error> UserInfoError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "invalid_arguments" => UserInfoError::InvalidArguments(context),
+            "invalid_auth" => UserInfoError::InvalidAuth(context),
+            "not_authed" => UserInfoError::NotAuthed(context),
+            "ratelimited" => UserInfoError::Ratelimited(context),
+            "request_timeout" => UserInfoError::RequestTimeout(context),
+            "service_unavailable" => UserInfoError::ServiceUnavailable(context),
+            "token_expired" => UserInfoError::TokenExpired(context),
+            "token_revoked" => UserInfoError::TokenRevoked(context),
+            _ => UserInfoError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
+impl<'a, E: Error> From<&'a str> for UserInfoError<E> {
+    fn from(s: &'a str) -> Self {
+        Self::new(s, crate::ErrorContext::default())
+    }
+}
+
+impl<E: Error> fmt::Display for UserInfoError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            UserInfoError::InvalidArguments(_) => {
+                write!(f, "Server returned error invalid_arguments")
+            }
+            UserInfoError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            UserInfoError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            UserInfoError::Ratelimited(_) => write!(f, "Server returned error ratelimited"),
+            UserInfoError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            UserInfoError::ServiceUnavailable(_) => {
+                write!(f, "Server returned error service_unavailable")
+            }
+            UserInfoError::TokenExpired(_) => write!(f, "Server returned error token_expired"),
+            UserInfoError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            UserInfoError::MalformedResponse(_, ref e) => write!(f, "{}", e),
+            UserInfoError::Unknown(ref s, _) => write!(f, "{}", s),
+            UserInfoError::Client(ref inner) => write!(f, "{}", inner),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for UserInfoError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            UserInfoError::MalformedResponse(_, ref e) => Some(e),
+            UserInfoError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+impl<E: Error + 'static> crate::MethodError for UserInfoError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            UserInfoError::InvalidArguments(_) => Some("invalid_arguments"),
+            UserInfoError::InvalidAuth(_) => Some("invalid_auth"),
+            UserInfoError::NotAuthed(_) => Some("not_authed"),
+            UserInfoError::Ratelimited(_) => Some("ratelimited"),
+            UserInfoError::RequestTimeout(_) => Some("request_timeout"),
+            UserInfoError::ServiceUnavailable(_) => Some("service_unavailable"),
+            UserInfoError::TokenExpired(_) => Some("token_expired"),
+            UserInfoError::TokenRevoked(_) => Some("token_revoked"),
+            UserInfoError::Unknown(ref s, _) => Some(s),
+            UserInfoError::MalformedResponse(..) | UserInfoError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            UserInfoError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            UserInfoError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            UserInfoError::InvalidArguments(ref context)
+            | UserInfoError::InvalidAuth(ref context)
+            | UserInfoError::NotAuthed(ref context)
+            | UserInfoError::Ratelimited(ref context)
+            | UserInfoError::RequestTimeout(ref context)
+            | UserInfoError::ServiceUnavailable(ref context)
+            | UserInfoError::TokenExpired(ref context)
+            | UserInfoError::TokenRevoked(ref context)
+            | UserInfoError::Unknown(_, ref context) => Some(context),
+            UserInfoError::MalformedResponse(..) | UserInfoError::Client(_) => None,
+        }
+    }
+}
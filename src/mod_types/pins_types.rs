@@ -22,19 +22,25 @@ use std::error::Error;
 use std::fmt;
 
 #[derive(Clone, Default, Debug)]
-pub struct AddRequest<'a> {
+pub struct AddRequest {
     /// Channel to pin the item in.
-    pub channel: Cow<'a, str>,
+    pub channel: Option<crate::ChannelId>,
     /// Timestamp of the message to pin.
-    pub timestamp: Option<Cow<'a, str>>,
+    pub timestamp: Option<crate::Timestamp>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AddResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<AddResponse, AddError<E>>> for AddResponse {
@@ -42,103 +48,138 @@ impl<E: Error> Into<Result<AddResponse, AddError<E>>> for AddResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(AddError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for AddResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum AddError<E: Error> {
-    AccountInactive,
-    AlreadyPinned,
-    BadTimestamp,
-    ChannelNotFound,
-    FileNotShared,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MessageNotFound,
-    MissingPostType,
-    NoItemSpecified,
-    NoPermission,
-    NotAuthed,
-    NotPinnable,
-    PermissionDenied,
-    RequestTimeout,
-    TeamAddedToOrg,
-    UpgradeRequired,
+    AccountInactive(crate::ErrorContext),
+    AlreadyPinned(crate::ErrorContext),
+    BadTimestamp(crate::ErrorContext),
+    ChannelNotFound(crate::ErrorContext),
+    FileNotShared(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MessageNotFound(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoItemSpecified(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    NotPinnable(crate::ErrorContext),
+    PermissionDenied(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> AddError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => AddError::AccountInactive(context),
+            "already_pinned" => AddError::AlreadyPinned(context),
+            "bad_timestamp" => AddError::BadTimestamp(context),
+            "channel_not_found" => AddError::ChannelNotFound(context),
+            "file_not_shared" => AddError::FileNotShared(context),
+            "invalid_arg_name" => AddError::InvalidArgName(context),
+            "invalid_array_arg" => AddError::InvalidArrayArg(context),
+            "invalid_auth" => AddError::InvalidAuth(context),
+            "invalid_charset" => AddError::InvalidCharset(context),
+            "invalid_form_data" => AddError::InvalidFormData(context),
+            "invalid_json" => AddError::InvalidJson(context),
+            "invalid_post_type" => AddError::InvalidPostType(context),
+            "json_not_object" => AddError::JsonNotObject(context),
+            "message_not_found" => AddError::MessageNotFound(context),
+            "missing_post_type" => AddError::MissingPostType(context),
+            "no_item_specified" => AddError::NoItemSpecified(context),
+            "no_permission" => AddError::NoPermission(context),
+            "not_authed" => AddError::NotAuthed(context),
+            "not_pinnable" => AddError::NotPinnable(context),
+            "permission_denied" => AddError::PermissionDenied(context),
+            "request_timeout" => AddError::RequestTimeout(context),
+            "team_added_to_org" => AddError::TeamAddedToOrg(context),
+            "upgrade_required" => AddError::UpgradeRequired(context),
+            _ => AddError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for AddError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => AddError::AccountInactive,
-            "already_pinned" => AddError::AlreadyPinned,
-            "bad_timestamp" => AddError::BadTimestamp,
-            "channel_not_found" => AddError::ChannelNotFound,
-            "file_not_shared" => AddError::FileNotShared,
-            "invalid_arg_name" => AddError::InvalidArgName,
-            "invalid_array_arg" => AddError::InvalidArrayArg,
-            "invalid_auth" => AddError::InvalidAuth,
-            "invalid_charset" => AddError::InvalidCharset,
-            "invalid_form_data" => AddError::InvalidFormData,
-            "invalid_json" => AddError::InvalidJson,
-            "invalid_post_type" => AddError::InvalidPostType,
-            "json_not_object" => AddError::JsonNotObject,
-            "message_not_found" => AddError::MessageNotFound,
-            "missing_post_type" => AddError::MissingPostType,
-            "no_item_specified" => AddError::NoItemSpecified,
-            "no_permission" => AddError::NoPermission,
-            "not_authed" => AddError::NotAuthed,
-            "not_pinnable" => AddError::NotPinnable,
-            "permission_denied" => AddError::PermissionDenied,
-            "request_timeout" => AddError::RequestTimeout,
-            "team_added_to_org" => AddError::TeamAddedToOrg,
-            "upgrade_required" => AddError::UpgradeRequired,
-            _ => AddError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for AddError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            AddError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            AddError::AlreadyPinned => write!(f, "Server returned error already_pinned"),
-            AddError::BadTimestamp => write!(f, "Server returned error bad_timestamp"),
-            AddError::ChannelNotFound => write!(f, "Server returned error channel_not_found"),
-            AddError::FileNotShared => write!(f, "Server returned error file_not_shared"),
-            AddError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            AddError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            AddError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            AddError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            AddError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            AddError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            AddError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            AddError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            AddError::MessageNotFound => write!(f, "Server returned error message_not_found"),
-            AddError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            AddError::NoItemSpecified => write!(f, "Server returned error no_item_specified"),
-            AddError::NoPermission => write!(f, "Server returned error no_permission"),
-            AddError::NotAuthed => write!(f, "Server returned error not_authed"),
-            AddError::NotPinnable => write!(f, "Server returned error not_pinnable"),
-            AddError::PermissionDenied => write!(f, "Server returned error permission_denied"),
-            AddError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            AddError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            AddError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
+            AddError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            AddError::AlreadyPinned(_) => write!(f, "Server returned error already_pinned"),
+            AddError::BadTimestamp(_) => write!(f, "Server returned error bad_timestamp"),
+            AddError::ChannelNotFound(_) => write!(f, "Server returned error channel_not_found"),
+            AddError::FileNotShared(_) => write!(f, "Server returned error file_not_shared"),
+            AddError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            AddError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            AddError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            AddError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            AddError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            AddError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            AddError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            AddError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            AddError::MessageNotFound(_) => write!(f, "Server returned error message_not_found"),
+            AddError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            AddError::NoItemSpecified(_) => write!(f, "Server returned error no_item_specified"),
+            AddError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            AddError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            AddError::NotPinnable(_) => write!(f, "Server returned error not_pinnable"),
+            AddError::PermissionDenied(_) => write!(f, "Server returned error permission_denied"),
+            AddError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            AddError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            AddError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
             AddError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            AddError::Unknown(ref s) => write!(f, "{}", s),
+            AddError::Unknown(ref s, _) => write!(f, "{}", s),
             AddError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -154,26 +195,98 @@ impl<E: Error + 'static> Error for AddError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for AddError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            AddError::AccountInactive(_) => Some("account_inactive"),
+            AddError::AlreadyPinned(_) => Some("already_pinned"),
+            AddError::BadTimestamp(_) => Some("bad_timestamp"),
+            AddError::ChannelNotFound(_) => Some("channel_not_found"),
+            AddError::FileNotShared(_) => Some("file_not_shared"),
+            AddError::InvalidArgName(_) => Some("invalid_arg_name"),
+            AddError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            AddError::InvalidAuth(_) => Some("invalid_auth"),
+            AddError::InvalidCharset(_) => Some("invalid_charset"),
+            AddError::InvalidFormData(_) => Some("invalid_form_data"),
+            AddError::InvalidJson(_) => Some("invalid_json"),
+            AddError::InvalidPostType(_) => Some("invalid_post_type"),
+            AddError::JsonNotObject(_) => Some("json_not_object"),
+            AddError::MessageNotFound(_) => Some("message_not_found"),
+            AddError::MissingPostType(_) => Some("missing_post_type"),
+            AddError::NoItemSpecified(_) => Some("no_item_specified"),
+            AddError::NoPermission(_) => Some("no_permission"),
+            AddError::NotAuthed(_) => Some("not_authed"),
+            AddError::NotPinnable(_) => Some("not_pinnable"),
+            AddError::PermissionDenied(_) => Some("permission_denied"),
+            AddError::RequestTimeout(_) => Some("request_timeout"),
+            AddError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            AddError::UpgradeRequired(_) => Some("upgrade_required"),
+            AddError::Unknown(ref s, _) => Some(s),
+            AddError::MalformedResponse(..) | AddError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            AddError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            AddError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            AddError::AccountInactive(ref context)
+            | AddError::AlreadyPinned(ref context)
+            | AddError::BadTimestamp(ref context)
+            | AddError::ChannelNotFound(ref context)
+            | AddError::FileNotShared(ref context)
+            | AddError::InvalidArgName(ref context)
+            | AddError::InvalidArrayArg(ref context)
+            | AddError::InvalidAuth(ref context)
+            | AddError::InvalidCharset(ref context)
+            | AddError::InvalidFormData(ref context)
+            | AddError::InvalidJson(ref context)
+            | AddError::InvalidPostType(ref context)
+            | AddError::JsonNotObject(ref context)
+            | AddError::MessageNotFound(ref context)
+            | AddError::MissingPostType(ref context)
+            | AddError::NoItemSpecified(ref context)
+            | AddError::NoPermission(ref context)
+            | AddError::NotAuthed(ref context)
+            | AddError::NotPinnable(ref context)
+            | AddError::PermissionDenied(ref context)
+            | AddError::RequestTimeout(ref context)
+            | AddError::TeamAddedToOrg(ref context)
+            | AddError::UpgradeRequired(ref context)
+            | AddError::Unknown(_, ref context) => Some(context),
+            AddError::MalformedResponse(..) | AddError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
-pub struct ListRequest<'a> {
+pub struct ListRequest {
     /// Channel to get pinned items for.
-    pub channel: Cow<'a, str>,
+    pub channel: Option<crate::ChannelId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListReactionsInner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListSharesInner {
     pub private: Option<serde_json::Value>,
     pub public: Option<serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListFileInner {
     pub channels: Option<Vec<String>>,
     pub comments_count: Option<u64>,
@@ -188,7 +301,7 @@ pub struct ListFileInner {
     pub filetype: Option<String>,
     pub groups: Option<Vec<String>>,
     pub has_rich_preview: Option<bool>,
-    pub id: Option<String>,
+    pub id: Option<crate::FileId>,
     pub image_exif_rotation: Option<u64>,
     pub ims: Option<Vec<String>>,
     pub is_external: Option<bool>,
@@ -242,12 +355,12 @@ pub struct ListFileInner {
     pub updated: Option<u64>,
     pub url_private: Option<String>,
     pub url_private_download: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListItemsInner {
     pub created: Option<u64>,
     pub created_by: Option<String>,
@@ -255,16 +368,18 @@ pub struct ListItemsInner {
     pub r#type: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResultInner {
     pub items: Vec<ListItemsInner>,
     #[serde(default)]
     ok: bool,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponse {
     pub result: ListResultInner,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
@@ -274,82 +389,100 @@ impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
         } else {
             Err(ListError::Unknown(
                 "Server failed without providing an error message.".into(),
+                crate::ErrorContext::default(),
             ))
         }
     }
 }
 
+impl crate::SlackResponse for ListResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        Vec::new()
+    }
+}
+
 #[derive(Debug)]
 pub enum ListError<E: Error> {
-    AccountInactive,
-    ChannelNotFound,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    RequestTimeout,
-    TeamAddedToOrg,
-    UpgradeRequired,
+    AccountInactive(crate::ErrorContext),
+    ChannelNotFound(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> ListError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => ListError::AccountInactive(context),
+            "channel_not_found" => ListError::ChannelNotFound(context),
+            "invalid_arg_name" => ListError::InvalidArgName(context),
+            "invalid_array_arg" => ListError::InvalidArrayArg(context),
+            "invalid_auth" => ListError::InvalidAuth(context),
+            "invalid_charset" => ListError::InvalidCharset(context),
+            "invalid_form_data" => ListError::InvalidFormData(context),
+            "invalid_json" => ListError::InvalidJson(context),
+            "invalid_post_type" => ListError::InvalidPostType(context),
+            "json_not_object" => ListError::JsonNotObject(context),
+            "missing_post_type" => ListError::MissingPostType(context),
+            "no_permission" => ListError::NoPermission(context),
+            "not_authed" => ListError::NotAuthed(context),
+            "request_timeout" => ListError::RequestTimeout(context),
+            "team_added_to_org" => ListError::TeamAddedToOrg(context),
+            "upgrade_required" => ListError::UpgradeRequired(context),
+            _ => ListError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for ListError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => ListError::AccountInactive,
-            "channel_not_found" => ListError::ChannelNotFound,
-            "invalid_arg_name" => ListError::InvalidArgName,
-            "invalid_array_arg" => ListError::InvalidArrayArg,
-            "invalid_auth" => ListError::InvalidAuth,
-            "invalid_charset" => ListError::InvalidCharset,
-            "invalid_form_data" => ListError::InvalidFormData,
-            "invalid_json" => ListError::InvalidJson,
-            "invalid_post_type" => ListError::InvalidPostType,
-            "json_not_object" => ListError::JsonNotObject,
-            "missing_post_type" => ListError::MissingPostType,
-            "no_permission" => ListError::NoPermission,
-            "not_authed" => ListError::NotAuthed,
-            "request_timeout" => ListError::RequestTimeout,
-            "team_added_to_org" => ListError::TeamAddedToOrg,
-            "upgrade_required" => ListError::UpgradeRequired,
-            _ => ListError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for ListError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            ListError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            ListError::ChannelNotFound => write!(f, "Server returned error channel_not_found"),
-            ListError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            ListError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            ListError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            ListError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            ListError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            ListError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            ListError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            ListError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            ListError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            ListError::NoPermission => write!(f, "Server returned error no_permission"),
-            ListError::NotAuthed => write!(f, "Server returned error not_authed"),
-            ListError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            ListError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            ListError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
+            ListError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            ListError::ChannelNotFound(_) => write!(f, "Server returned error channel_not_found"),
+            ListError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            ListError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            ListError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            ListError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            ListError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            ListError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            ListError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            ListError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            ListError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            ListError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            ListError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            ListError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            ListError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            ListError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
             ListError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            ListError::Unknown(ref s) => write!(f, "{}", s),
+            ListError::Unknown(ref s, _) => write!(f, "{}", s),
             ListError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -365,20 +498,84 @@ impl<E: Error + 'static> Error for ListError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for ListError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            ListError::AccountInactive(_) => Some("account_inactive"),
+            ListError::ChannelNotFound(_) => Some("channel_not_found"),
+            ListError::InvalidArgName(_) => Some("invalid_arg_name"),
+            ListError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            ListError::InvalidAuth(_) => Some("invalid_auth"),
+            ListError::InvalidCharset(_) => Some("invalid_charset"),
+            ListError::InvalidFormData(_) => Some("invalid_form_data"),
+            ListError::InvalidJson(_) => Some("invalid_json"),
+            ListError::InvalidPostType(_) => Some("invalid_post_type"),
+            ListError::JsonNotObject(_) => Some("json_not_object"),
+            ListError::MissingPostType(_) => Some("missing_post_type"),
+            ListError::NoPermission(_) => Some("no_permission"),
+            ListError::NotAuthed(_) => Some("not_authed"),
+            ListError::RequestTimeout(_) => Some("request_timeout"),
+            ListError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            ListError::UpgradeRequired(_) => Some("upgrade_required"),
+            ListError::Unknown(ref s, _) => Some(s),
+            ListError::MalformedResponse(..) | ListError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            ListError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            ListError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            ListError::AccountInactive(ref context)
+            | ListError::ChannelNotFound(ref context)
+            | ListError::InvalidArgName(ref context)
+            | ListError::InvalidArrayArg(ref context)
+            | ListError::InvalidAuth(ref context)
+            | ListError::InvalidCharset(ref context)
+            | ListError::InvalidFormData(ref context)
+            | ListError::InvalidJson(ref context)
+            | ListError::InvalidPostType(ref context)
+            | ListError::JsonNotObject(ref context)
+            | ListError::MissingPostType(ref context)
+            | ListError::NoPermission(ref context)
+            | ListError::NotAuthed(ref context)
+            | ListError::RequestTimeout(ref context)
+            | ListError::TeamAddedToOrg(ref context)
+            | ListError::UpgradeRequired(ref context)
+            | ListError::Unknown(_, ref context) => Some(context),
+            ListError::MalformedResponse(..) | ListError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
-pub struct RemoveRequest<'a> {
+pub struct RemoveRequest {
     /// Channel where the item is pinned to.
-    pub channel: Cow<'a, str>,
+    pub channel: Option<crate::ChannelId>,
     /// Timestamp of the message to un-pin.
-    pub timestamp: Option<Cow<'a, str>>,
+    pub timestamp: Option<crate::Timestamp>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RemoveResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<RemoveResponse, RemoveError<E>>> for RemoveResponse {
@@ -386,102 +583,139 @@ impl<E: Error> Into<Result<RemoveResponse, RemoveError<E>>> for RemoveResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(RemoveError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for RemoveResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum RemoveError<E: Error> {
-    AccountInactive,
-    BadTimestamp,
-    FileCommentNotFound,
-    FileNotFound,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostTyp,
-    JsonNotObject,
-    MessageNotFound,
-    MissingPostTyp,
-    NoItemSpecified,
-    NoPermission,
-    NotAuthed,
-    NotPinned,
-    PermissionDenied,
-    RequestTimeou,
-    TeamAddedToOrg,
-    UpgradeRequired,
+    AccountInactive(crate::ErrorContext),
+    BadTimestamp(crate::ErrorContext),
+    FileCommentNotFound(crate::ErrorContext),
+    FileNotFound(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostTyp(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MessageNotFound(crate::ErrorContext),
+    MissingPostTyp(crate::ErrorContext),
+    NoItemSpecified(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    NotPinned(crate::ErrorContext),
+    PermissionDenied(crate::ErrorContext),
+    RequestTimeou(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> RemoveError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => RemoveError::AccountInactive(context),
+            "bad_timestamp" => RemoveError::BadTimestamp(context),
+            "file_comment_not_found" => RemoveError::FileCommentNotFound(context),
+            "file_not_found" => RemoveError::FileNotFound(context),
+            "invalid_arg_name" => RemoveError::InvalidArgName(context),
+            "invalid_array_arg" => RemoveError::InvalidArrayArg(context),
+            "invalid_auth" => RemoveError::InvalidAuth(context),
+            "invalid_charset" => RemoveError::InvalidCharset(context),
+            "invalid_form_data" => RemoveError::InvalidFormData(context),
+            "invalid_json" => RemoveError::InvalidJson(context),
+            "invalid_post_typ" => RemoveError::InvalidPostTyp(context),
+            "json_not_object" => RemoveError::JsonNotObject(context),
+            "message_not_found" => RemoveError::MessageNotFound(context),
+            "missing_post_typ" => RemoveError::MissingPostTyp(context),
+            "no_item_specified" => RemoveError::NoItemSpecified(context),
+            "no_permission" => RemoveError::NoPermission(context),
+            "not_authed" => RemoveError::NotAuthed(context),
+            "not_pinned" => RemoveError::NotPinned(context),
+            "permission_denied" => RemoveError::PermissionDenied(context),
+            "request_timeou" => RemoveError::RequestTimeou(context),
+            "team_added_to_org" => RemoveError::TeamAddedToOrg(context),
+            "upgrade_required" => RemoveError::UpgradeRequired(context),
+            _ => RemoveError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for RemoveError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => RemoveError::AccountInactive,
-            "bad_timestamp" => RemoveError::BadTimestamp,
-            "file_comment_not_found" => RemoveError::FileCommentNotFound,
-            "file_not_found" => RemoveError::FileNotFound,
-            "invalid_arg_name" => RemoveError::InvalidArgName,
-            "invalid_array_arg" => RemoveError::InvalidArrayArg,
-            "invalid_auth" => RemoveError::InvalidAuth,
-            "invalid_charset" => RemoveError::InvalidCharset,
-            "invalid_form_data" => RemoveError::InvalidFormData,
-            "invalid_json" => RemoveError::InvalidJson,
-            "invalid_post_typ" => RemoveError::InvalidPostTyp,
-            "json_not_object" => RemoveError::JsonNotObject,
-            "message_not_found" => RemoveError::MessageNotFound,
-            "missing_post_typ" => RemoveError::MissingPostTyp,
-            "no_item_specified" => RemoveError::NoItemSpecified,
-            "no_permission" => RemoveError::NoPermission,
-            "not_authed" => RemoveError::NotAuthed,
-            "not_pinned" => RemoveError::NotPinned,
-            "permission_denied" => RemoveError::PermissionDenied,
-            "request_timeou" => RemoveError::RequestTimeou,
-            "team_added_to_org" => RemoveError::TeamAddedToOrg,
-            "upgrade_required" => RemoveError::UpgradeRequired,
-            _ => RemoveError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for RemoveError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            RemoveError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            RemoveError::BadTimestamp => write!(f, "Server returned error bad_timestamp"),
-            RemoveError::FileCommentNotFound => {
+            RemoveError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            RemoveError::BadTimestamp(_) => write!(f, "Server returned error bad_timestamp"),
+            RemoveError::FileCommentNotFound(_) => {
                 write!(f, "Server returned error file_comment_not_found")
             }
-            RemoveError::FileNotFound => write!(f, "Server returned error file_not_found"),
-            RemoveError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            RemoveError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            RemoveError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            RemoveError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            RemoveError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            RemoveError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            RemoveError::InvalidPostTyp => write!(f, "Server returned error invalid_post_typ"),
-            RemoveError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            RemoveError::MessageNotFound => write!(f, "Server returned error message_not_found"),
-            RemoveError::MissingPostTyp => write!(f, "Server returned error missing_post_typ"),
-            RemoveError::NoItemSpecified => write!(f, "Server returned error no_item_specified"),
-            RemoveError::NoPermission => write!(f, "Server returned error no_permission"),
-            RemoveError::NotAuthed => write!(f, "Server returned error not_authed"),
-            RemoveError::NotPinned => write!(f, "Server returned error not_pinned"),
-            RemoveError::PermissionDenied => write!(f, "Server returned error permission_denied"),
-            RemoveError::RequestTimeou => write!(f, "Server returned error request_timeou"),
-            RemoveError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            RemoveError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
+            RemoveError::FileNotFound(_) => write!(f, "Server returned error file_not_found"),
+            RemoveError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            RemoveError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            RemoveError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            RemoveError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            RemoveError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            RemoveError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            RemoveError::InvalidPostTyp(_) => write!(f, "Server returned error invalid_post_typ"),
+            RemoveError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            RemoveError::MessageNotFound(_) => write!(f, "Server returned error message_not_found"),
+            RemoveError::MissingPostTyp(_) => write!(f, "Server returned error missing_post_typ"),
+            RemoveError::NoItemSpecified(_) => write!(f, "Server returned error no_item_specified"),
+            RemoveError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            RemoveError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            RemoveError::NotPinned(_) => write!(f, "Server returned error not_pinned"),
+            RemoveError::PermissionDenied(_) => {
+                write!(f, "Server returned error permission_denied")
+            }
+            RemoveError::RequestTimeou(_) => write!(f, "Server returned error request_timeou"),
+            RemoveError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            RemoveError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
             RemoveError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            RemoveError::Unknown(ref s) => write!(f, "{}", s),
+            RemoveError::Unknown(ref s, _) => write!(f, "{}", s),
             RemoveError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -496,3 +730,73 @@ impl<E: Error + 'static> Error for RemoveError<E> {
         }
     }
 }
+
+impl<E: Error + 'static> crate::MethodError for RemoveError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            RemoveError::AccountInactive(_) => Some("account_inactive"),
+            RemoveError::BadTimestamp(_) => Some("bad_timestamp"),
+            RemoveError::FileCommentNotFound(_) => Some("file_comment_not_found"),
+            RemoveError::FileNotFound(_) => Some("file_not_found"),
+            RemoveError::InvalidArgName(_) => Some("invalid_arg_name"),
+            RemoveError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            RemoveError::InvalidAuth(_) => Some("invalid_auth"),
+            RemoveError::InvalidCharset(_) => Some("invalid_charset"),
+            RemoveError::InvalidFormData(_) => Some("invalid_form_data"),
+            RemoveError::InvalidJson(_) => Some("invalid_json"),
+            RemoveError::InvalidPostTyp(_) => Some("invalid_post_typ"),
+            RemoveError::JsonNotObject(_) => Some("json_not_object"),
+            RemoveError::MessageNotFound(_) => Some("message_not_found"),
+            RemoveError::MissingPostTyp(_) => Some("missing_post_typ"),
+            RemoveError::NoItemSpecified(_) => Some("no_item_specified"),
+            RemoveError::NoPermission(_) => Some("no_permission"),
+            RemoveError::NotAuthed(_) => Some("not_authed"),
+            RemoveError::NotPinned(_) => Some("not_pinned"),
+            RemoveError::PermissionDenied(_) => Some("permission_denied"),
+            RemoveError::RequestTimeou(_) => Some("request_timeou"),
+            RemoveError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            RemoveError::UpgradeRequired(_) => Some("upgrade_required"),
+            RemoveError::Unknown(ref s, _) => Some(s),
+            RemoveError::MalformedResponse(..) | RemoveError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            RemoveError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            RemoveError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            RemoveError::AccountInactive(ref context)
+            | RemoveError::BadTimestamp(ref context)
+            | RemoveError::FileCommentNotFound(ref context)
+            | RemoveError::FileNotFound(ref context)
+            | RemoveError::InvalidArgName(ref context)
+            | RemoveError::InvalidArrayArg(ref context)
+            | RemoveError::InvalidAuth(ref context)
+            | RemoveError::InvalidCharset(ref context)
+            | RemoveError::InvalidFormData(ref context)
+            | RemoveError::InvalidJson(ref context)
+            | RemoveError::InvalidPostTyp(ref context)
+            | RemoveError::JsonNotObject(ref context)
+            | RemoveError::MessageNotFound(ref context)
+            | RemoveError::MissingPostTyp(ref context)
+            | RemoveError::NoItemSpecified(ref context)
+            | RemoveError::NoPermission(ref context)
+            | RemoveError::NotAuthed(ref context)
+            | RemoveError::NotPinned(ref context)
+            | RemoveError::PermissionDenied(ref context)
+            | RemoveError::RequestTimeou(ref context)
+            | RemoveError::TeamAddedToOrg(ref context)
+            | RemoveError::UpgradeRequired(ref context)
+            | RemoveError::Unknown(_, ref context) => Some(context),
+            RemoveError::MalformedResponse(..) | RemoveError::Client(_) => None,
+        }
+    }
+}
@@ -24,19 +24,25 @@ use std::fmt;
 #[derive(Clone, Default, Debug)]
 pub struct AddRequest<'a> {
     /// Channel where the message to add reaction to was posted.
-    pub channel: Cow<'a, str>,
+    pub channel: Option<crate::ChannelId>,
     /// Reaction (emoji) name.
     pub name: Cow<'a, str>,
     /// Timestamp of the message to add reaction to.
-    pub timestamp: Cow<'a, str>,
+    pub timestamp: crate::Timestamp,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AddResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<AddResponse, AddError<E>>> for AddResponse {
@@ -44,100 +50,135 @@ impl<E: Error> Into<Result<AddResponse, AddError<E>>> for AddResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(AddError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for AddResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum AddError<E: Error> {
-    AccountInactive,
-    AlreadyReacted,
-    BadTimestamp,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidName,
-    InvalidPostType,
-    JsonNotObject,
-    MessageNotFound,
-    MissingPostType,
-    NoItemSpecified,
-    NoPermission,
-    NotAuthed,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TooManyEmoji,
-    TooManyReactions,
-    UpgradeRequired,
+    AccountInactive(crate::ErrorContext),
+    AlreadyReacted(crate::ErrorContext),
+    BadTimestamp(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidName(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MessageNotFound(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoItemSpecified(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TooManyEmoji(crate::ErrorContext),
+    TooManyReactions(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> AddError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => AddError::AccountInactive(context),
+            "already_reacted" => AddError::AlreadyReacted(context),
+            "bad_timestamp" => AddError::BadTimestamp(context),
+            "invalid_arg_name" => AddError::InvalidArgName(context),
+            "invalid_array_arg" => AddError::InvalidArrayArg(context),
+            "invalid_auth" => AddError::InvalidAuth(context),
+            "invalid_charset" => AddError::InvalidCharset(context),
+            "invalid_form_data" => AddError::InvalidFormData(context),
+            "invalid_json" => AddError::InvalidJson(context),
+            "invalid_name" => AddError::InvalidName(context),
+            "invalid_post_type" => AddError::InvalidPostType(context),
+            "json_not_object" => AddError::JsonNotObject(context),
+            "message_not_found" => AddError::MessageNotFound(context),
+            "missing_post_type" => AddError::MissingPostType(context),
+            "no_item_specified" => AddError::NoItemSpecified(context),
+            "no_permission" => AddError::NoPermission(context),
+            "not_authed" => AddError::NotAuthed(context),
+            "request_timeout" => AddError::RequestTimeout(context),
+            "team_added_to_org" => AddError::TeamAddedToOrg(context),
+            "too_many_emoji" => AddError::TooManyEmoji(context),
+            "too_many_reactions" => AddError::TooManyReactions(context),
+            "upgrade_required" => AddError::UpgradeRequired(context),
+            _ => AddError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for AddError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => AddError::AccountInactive,
-            "already_reacted" => AddError::AlreadyReacted,
-            "bad_timestamp" => AddError::BadTimestamp,
-            "invalid_arg_name" => AddError::InvalidArgName,
-            "invalid_array_arg" => AddError::InvalidArrayArg,
-            "invalid_auth" => AddError::InvalidAuth,
-            "invalid_charset" => AddError::InvalidCharset,
-            "invalid_form_data" => AddError::InvalidFormData,
-            "invalid_json" => AddError::InvalidJson,
-            "invalid_name" => AddError::InvalidName,
-            "invalid_post_type" => AddError::InvalidPostType,
-            "json_not_object" => AddError::JsonNotObject,
-            "message_not_found" => AddError::MessageNotFound,
-            "missing_post_type" => AddError::MissingPostType,
-            "no_item_specified" => AddError::NoItemSpecified,
-            "no_permission" => AddError::NoPermission,
-            "not_authed" => AddError::NotAuthed,
-            "request_timeout" => AddError::RequestTimeout,
-            "team_added_to_org" => AddError::TeamAddedToOrg,
-            "too_many_emoji" => AddError::TooManyEmoji,
-            "too_many_reactions" => AddError::TooManyReactions,
-            "upgrade_required" => AddError::UpgradeRequired,
-            _ => AddError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for AddError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            AddError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            AddError::AlreadyReacted => write!(f, "Server returned error already_reacted"),
-            AddError::BadTimestamp => write!(f, "Server returned error bad_timestamp"),
-            AddError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            AddError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            AddError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            AddError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            AddError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            AddError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            AddError::InvalidName => write!(f, "Server returned error invalid_name"),
-            AddError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            AddError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            AddError::MessageNotFound => write!(f, "Server returned error message_not_found"),
-            AddError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            AddError::NoItemSpecified => write!(f, "Server returned error no_item_specified"),
-            AddError::NoPermission => write!(f, "Server returned error no_permission"),
-            AddError::NotAuthed => write!(f, "Server returned error not_authed"),
-            AddError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            AddError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            AddError::TooManyEmoji => write!(f, "Server returned error too_many_emoji"),
-            AddError::TooManyReactions => write!(f, "Server returned error too_many_reactions"),
-            AddError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
+            AddError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            AddError::AlreadyReacted(_) => write!(f, "Server returned error already_reacted"),
+            AddError::BadTimestamp(_) => write!(f, "Server returned error bad_timestamp"),
+            AddError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            AddError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            AddError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            AddError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            AddError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            AddError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            AddError::InvalidName(_) => write!(f, "Server returned error invalid_name"),
+            AddError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            AddError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            AddError::MessageNotFound(_) => write!(f, "Server returned error message_not_found"),
+            AddError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            AddError::NoItemSpecified(_) => write!(f, "Server returned error no_item_specified"),
+            AddError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            AddError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            AddError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            AddError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            AddError::TooManyEmoji(_) => write!(f, "Server returned error too_many_emoji"),
+            AddError::TooManyReactions(_) => write!(f, "Server returned error too_many_reactions"),
+            AddError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
             AddError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            AddError::Unknown(ref s) => write!(f, "{}", s),
+            AddError::Unknown(ref s, _) => write!(f, "{}", s),
             AddError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -153,26 +194,102 @@ impl<E: Error + 'static> Error for AddError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for AddError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            AddError::AccountInactive(_) => Some("account_inactive"),
+            AddError::AlreadyReacted(_) => Some("already_reacted"),
+            AddError::BadTimestamp(_) => Some("bad_timestamp"),
+            AddError::InvalidArgName(_) => Some("invalid_arg_name"),
+            AddError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            AddError::InvalidAuth(_) => Some("invalid_auth"),
+            AddError::InvalidCharset(_) => Some("invalid_charset"),
+            AddError::InvalidFormData(_) => Some("invalid_form_data"),
+            AddError::InvalidJson(_) => Some("invalid_json"),
+            AddError::InvalidName(_) => Some("invalid_name"),
+            AddError::InvalidPostType(_) => Some("invalid_post_type"),
+            AddError::JsonNotObject(_) => Some("json_not_object"),
+            AddError::MessageNotFound(_) => Some("message_not_found"),
+            AddError::MissingPostType(_) => Some("missing_post_type"),
+            AddError::NoItemSpecified(_) => Some("no_item_specified"),
+            AddError::NoPermission(_) => Some("no_permission"),
+            AddError::NotAuthed(_) => Some("not_authed"),
+            AddError::RequestTimeout(_) => Some("request_timeout"),
+            AddError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            AddError::TooManyEmoji(_) => Some("too_many_emoji"),
+            AddError::TooManyReactions(_) => Some("too_many_reactions"),
+            AddError::UpgradeRequired(_) => Some("upgrade_required"),
+            AddError::Unknown(ref s, _) => Some(s),
+            AddError::MalformedResponse(..) | AddError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            AddError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            AddError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            AddError::AccountInactive(ref context)
+            | AddError::AlreadyReacted(ref context)
+            | AddError::BadTimestamp(ref context)
+            | AddError::InvalidArgName(ref context)
+            | AddError::InvalidArrayArg(ref context)
+            | AddError::InvalidAuth(ref context)
+            | AddError::InvalidCharset(ref context)
+            | AddError::InvalidFormData(ref context)
+            | AddError::InvalidJson(ref context)
+            | AddError::InvalidName(ref context)
+            | AddError::InvalidPostType(ref context)
+            | AddError::JsonNotObject(ref context)
+            | AddError::MessageNotFound(ref context)
+            | AddError::MissingPostType(ref context)
+            | AddError::NoItemSpecified(ref context)
+            | AddError::NoPermission(ref context)
+            | AddError::NotAuthed(ref context)
+            | AddError::RequestTimeout(ref context)
+            | AddError::TeamAddedToOrg(ref context)
+            | AddError::TooManyEmoji(ref context)
+            | AddError::TooManyReactions(ref context)
+            | AddError::UpgradeRequired(ref context)
+            | AddError::Unknown(_, ref context) => Some(context),
+            AddError::MalformedResponse(..) | AddError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct GetRequest<'a> {
     /// Channel where the message to get reactions for was posted.
-    pub channel: Option<Cow<'a, str>>,
+    pub channel: Option<crate::ChannelId>,
     /// File to get reactions for.
-    pub file: Option<Cow<'a, str>>,
+    pub file: Option<crate::FileId>,
     /// File comment to get reactions for.
     pub file_comment: Option<Cow<'a, str>>,
     /// If true always return the complete reaction list.
     pub full: Option<bool>,
     /// Timestamp of the message to get reactions for.
-    pub timestamp: Option<Cow<'a, str>>,
+    pub timestamp: Option<crate::Timestamp>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GetResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<GetResponse, GetError<E>>> for GetResponse {
@@ -180,93 +297,128 @@ impl<E: Error> Into<Result<GetResponse, GetError<E>>> for GetResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(GetError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for GetResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum GetError<E: Error> {
-    AccountInactive,
-    BadTimestamp,
-    FileCommentNotFound,
-    FileNotFound,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MessageNotFound,
-    MissingPostType,
-    NoItemSpecified,
-    NoPermission,
-    NotAuthed,
-    RequestTimeout,
-    TeamAddedToOrg,
-    UpgradeRequired,
+    AccountInactive(crate::ErrorContext),
+    BadTimestamp(crate::ErrorContext),
+    FileCommentNotFound(crate::ErrorContext),
+    FileNotFound(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MessageNotFound(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoItemSpecified(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> GetError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => GetError::AccountInactive(context),
+            "bad_timestamp" => GetError::BadTimestamp(context),
+            "file_comment_not_found" => GetError::FileCommentNotFound(context),
+            "file_not_found" => GetError::FileNotFound(context),
+            "invalid_array_arg" => GetError::InvalidArrayArg(context),
+            "invalid_auth" => GetError::InvalidAuth(context),
+            "invalid_charset" => GetError::InvalidCharset(context),
+            "invalid_form_data" => GetError::InvalidFormData(context),
+            "invalid_json" => GetError::InvalidJson(context),
+            "invalid_post_type" => GetError::InvalidPostType(context),
+            "json_not_object" => GetError::JsonNotObject(context),
+            "message_not_found" => GetError::MessageNotFound(context),
+            "missing_post_type" => GetError::MissingPostType(context),
+            "no_item_specified" => GetError::NoItemSpecified(context),
+            "no_permission" => GetError::NoPermission(context),
+            "not_authed" => GetError::NotAuthed(context),
+            "request_timeout" => GetError::RequestTimeout(context),
+            "team_added_to_org" => GetError::TeamAddedToOrg(context),
+            "upgrade_required" => GetError::UpgradeRequired(context),
+            _ => GetError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for GetError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => GetError::AccountInactive,
-            "bad_timestamp" => GetError::BadTimestamp,
-            "file_comment_not_found" => GetError::FileCommentNotFound,
-            "file_not_found" => GetError::FileNotFound,
-            "invalid_array_arg" => GetError::InvalidArrayArg,
-            "invalid_auth" => GetError::InvalidAuth,
-            "invalid_charset" => GetError::InvalidCharset,
-            "invalid_form_data" => GetError::InvalidFormData,
-            "invalid_json" => GetError::InvalidJson,
-            "invalid_post_type" => GetError::InvalidPostType,
-            "json_not_object" => GetError::JsonNotObject,
-            "message_not_found" => GetError::MessageNotFound,
-            "missing_post_type" => GetError::MissingPostType,
-            "no_item_specified" => GetError::NoItemSpecified,
-            "no_permission" => GetError::NoPermission,
-            "not_authed" => GetError::NotAuthed,
-            "request_timeout" => GetError::RequestTimeout,
-            "team_added_to_org" => GetError::TeamAddedToOrg,
-            "upgrade_required" => GetError::UpgradeRequired,
-            _ => GetError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for GetError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            GetError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            GetError::BadTimestamp => write!(f, "Server returned error bad_timestamp"),
-            GetError::FileCommentNotFound => {
+            GetError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            GetError::BadTimestamp(_) => write!(f, "Server returned error bad_timestamp"),
+            GetError::FileCommentNotFound(_) => {
                 write!(f, "Server returned error file_comment_not_found")
             }
-            GetError::FileNotFound => write!(f, "Server returned error file_not_found"),
-            GetError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            GetError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            GetError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            GetError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            GetError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            GetError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            GetError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            GetError::MessageNotFound => write!(f, "Server returned error message_not_found"),
-            GetError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            GetError::NoItemSpecified => write!(f, "Server returned error no_item_specified"),
-            GetError::NoPermission => write!(f, "Server returned error no_permission"),
-            GetError::NotAuthed => write!(f, "Server returned error not_authed"),
-            GetError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            GetError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            GetError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
+            GetError::FileNotFound(_) => write!(f, "Server returned error file_not_found"),
+            GetError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            GetError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            GetError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            GetError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            GetError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            GetError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            GetError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            GetError::MessageNotFound(_) => write!(f, "Server returned error message_not_found"),
+            GetError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            GetError::NoItemSpecified(_) => write!(f, "Server returned error no_item_specified"),
+            GetError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            GetError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            GetError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            GetError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            GetError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
             GetError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            GetError::Unknown(ref s) => write!(f, "{}", s),
+            GetError::Unknown(ref s, _) => write!(f, "{}", s),
             GetError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -282,6 +434,70 @@ impl<E: Error + 'static> Error for GetError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for GetError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            GetError::AccountInactive(_) => Some("account_inactive"),
+            GetError::BadTimestamp(_) => Some("bad_timestamp"),
+            GetError::FileCommentNotFound(_) => Some("file_comment_not_found"),
+            GetError::FileNotFound(_) => Some("file_not_found"),
+            GetError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            GetError::InvalidAuth(_) => Some("invalid_auth"),
+            GetError::InvalidCharset(_) => Some("invalid_charset"),
+            GetError::InvalidFormData(_) => Some("invalid_form_data"),
+            GetError::InvalidJson(_) => Some("invalid_json"),
+            GetError::InvalidPostType(_) => Some("invalid_post_type"),
+            GetError::JsonNotObject(_) => Some("json_not_object"),
+            GetError::MessageNotFound(_) => Some("message_not_found"),
+            GetError::MissingPostType(_) => Some("missing_post_type"),
+            GetError::NoItemSpecified(_) => Some("no_item_specified"),
+            GetError::NoPermission(_) => Some("no_permission"),
+            GetError::NotAuthed(_) => Some("not_authed"),
+            GetError::RequestTimeout(_) => Some("request_timeout"),
+            GetError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            GetError::UpgradeRequired(_) => Some("upgrade_required"),
+            GetError::Unknown(ref s, _) => Some(s),
+            GetError::MalformedResponse(..) | GetError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            GetError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            GetError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            GetError::AccountInactive(ref context)
+            | GetError::BadTimestamp(ref context)
+            | GetError::FileCommentNotFound(ref context)
+            | GetError::FileNotFound(ref context)
+            | GetError::InvalidArrayArg(ref context)
+            | GetError::InvalidAuth(ref context)
+            | GetError::InvalidCharset(ref context)
+            | GetError::InvalidFormData(ref context)
+            | GetError::InvalidJson(ref context)
+            | GetError::InvalidPostType(ref context)
+            | GetError::JsonNotObject(ref context)
+            | GetError::MessageNotFound(ref context)
+            | GetError::MissingPostType(ref context)
+            | GetError::NoItemSpecified(ref context)
+            | GetError::NoPermission(ref context)
+            | GetError::NotAuthed(ref context)
+            | GetError::RequestTimeout(ref context)
+            | GetError::TeamAddedToOrg(ref context)
+            | GetError::UpgradeRequired(ref context)
+            | GetError::Unknown(_, ref context) => Some(context),
+            GetError::MalformedResponse(..) | GetError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct ListRequest<'a> {
     pub count: Option<u64>,
@@ -293,10 +509,16 @@ pub struct ListRequest<'a> {
     pub limit: Option<u64>,
     pub page: Option<u64>,
     /// Show reactions made by this user. Defaults to the authed user.
-    pub user: Option<Cow<'a, str>>,
+    pub user: Option<crate::UserId>,
+}
+
+impl crate::pagination::PageRequest for ListRequest<'_> {
+    fn set_page(&mut self, page: u64) {
+        self.page = Some(page);
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListAttachmentsInner {
     pub fallback: Option<String>,
     pub id: u64,
@@ -306,37 +528,32 @@ pub struct ListAttachmentsInner {
     pub image_width: Option<u64>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct ListBlocksInner {
-    pub r#type: String,
-}
-
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListIconsInner {
     pub image_36: String,
     pub image_48: String,
     pub image_72: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListBotProfileInner {
-    pub app_id: String,
+    pub app_id: crate::AppId,
     pub deleted: bool,
     pub icons: ListIconsInner,
-    pub id: String,
+    pub id: crate::BotId,
     pub name: String,
-    pub team_id: String,
+    pub team_id: crate::TeamId,
     pub updated: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListReactionsInner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListCommentInner {
     pub comment: String,
     pub created: u64,
@@ -348,23 +565,23 @@ pub struct ListCommentInner {
     pub pinned_to: Option<Vec<String>>,
     pub reactions: Option<Vec<ListReactionsInner>>,
     pub timestamp: u64,
-    pub user: String,
+    pub user: crate::UserId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListReactions1Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListSharesInner {
     pub private: Option<serde_json::Value>,
     pub public: Option<serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListFileInner {
     pub channels: Option<Vec<String>>,
     pub comments_count: Option<u64>,
@@ -379,7 +596,7 @@ pub struct ListFileInner {
     pub filetype: Option<String>,
     pub groups: Option<Vec<String>>,
     pub has_rich_preview: Option<bool>,
-    pub id: Option<String>,
+    pub id: Option<crate::FileId>,
     pub image_exif_rotation: Option<u64>,
     pub ims: Option<Vec<String>>,
     pub is_external: Option<bool>,
@@ -433,25 +650,25 @@ pub struct ListFileInner {
     pub updated: Option<u64>,
     pub url_private: Option<String>,
     pub url_private_download: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListReactions2Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListShares1Inner {
     pub private: Option<serde_json::Value>,
     pub public: Option<serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListFilesInner {
     pub channels: Option<Vec<String>>,
     pub comments_count: Option<u64>,
@@ -466,7 +683,7 @@ pub struct ListFilesInner {
     pub filetype: Option<String>,
     pub groups: Option<Vec<String>>,
     pub has_rich_preview: Option<bool>,
-    pub id: Option<String>,
+    pub id: Option<crate::FileId>,
     pub image_exif_rotation: Option<u64>,
     pub ims: Option<Vec<String>>,
     pub is_external: Option<bool>,
@@ -520,25 +737,25 @@ pub struct ListFilesInner {
     pub updated: Option<u64>,
     pub url_private: Option<String>,
     pub url_private_download: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListIcons1Inner {
     pub emoji: Option<String>,
     pub image_64: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListReactions3Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListUserProfileInner {
     pub avatar_hash: String,
     pub display_name: String,
@@ -550,13 +767,13 @@ pub struct ListUserProfileInner {
     pub name: String,
     pub real_name: String,
     pub real_name_normalized: Option<String>,
-    pub team: String,
+    pub team: crate::TeamId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListMessageInner {
     pub attachments: Option<Vec<ListAttachmentsInner>>,
-    pub blocks: Option<Vec<ListBlocksInner>>,
+    pub blocks: Option<Vec<crate::blocks::Block>>,
     pub bot_id: Option<Vec<String>>,
     pub bot_profile: Option<ListBotProfileInner>,
     pub client_msg_id: Option<String>,
@@ -569,8 +786,8 @@ pub struct ListMessageInner {
     pub is_delayed_message: Option<bool>,
     pub is_intro: Option<bool>,
     pub is_starred: Option<bool>,
-    pub last_read: Option<String>,
-    pub latest_reply: Option<String>,
+    pub last_read: Option<crate::Timestamp>,
+    pub latest_reply: Option<crate::Timestamp>,
     pub name: Option<String>,
     pub old_name: Option<String>,
     pub parent_user_id: Option<String>,
@@ -584,28 +801,28 @@ pub struct ListMessageInner {
     pub source_team: Option<String>,
     pub subscribed: Option<bool>,
     pub subtype: Option<String>,
-    pub team: Option<String>,
+    pub team: Option<crate::TeamId>,
     pub text: String,
-    pub thread_ts: Option<String>,
+    pub thread_ts: Option<crate::Timestamp>,
     pub topic: Option<String>,
-    pub ts: String,
+    pub ts: crate::Timestamp,
     pub r#type: String,
     pub unread_count: Option<u64>,
     pub upload: Option<bool>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_profile: Option<ListUserProfileInner>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListItemsInner {
-    pub channel: String,
+    pub channel: crate::ChannelId,
     pub message: ListMessageInner,
     pub r#type: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListPagingInner {
     pub count: Option<u64>,
     pub page: u64,
@@ -615,20 +832,20 @@ pub struct ListPagingInner {
     pub total: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct ListResponseMetadataInner {
-    pub next_cursor: String,
-}
-
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponse {
     pub callstack: Option<String>,
     error: Option<String>,
-    pub items: Vec<Vec<ListItemsInner>>,
+    pub items: Vec<ListItemsInner>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
     pub paging: Option<ListPagingInner>,
-    pub response_metadata: Option<Vec<ListResponseMetadataInner>>,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
@@ -636,85 +853,136 @@ impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(ListError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for ListResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
+impl crate::pagination::NumberedPage for ListResponse {
+    type Item = ListItemsInner;
+
+    fn page(&self) -> Option<u64> {
+        self.paging.as_ref().map(|paging| paging.page)
+    }
+
+    fn pages(&self) -> Option<u64> {
+        self.paging.as_ref().and_then(|paging| paging.pages)
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.items
+    }
+}
+
 #[derive(Debug)]
 pub enum ListError<E: Error> {
-    AccountInactiv,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    RequestTimeout,
-    TeamAddedToOrg,
-    UpgradeRequired,
-    UserNotFound,
+    AccountInactiv(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserNotFound(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> ListError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactiv" => ListError::AccountInactiv(context),
+            "fatal_error" => ListError::FatalError(context),
+            "invalid_arg_name" => ListError::InvalidArgName(context),
+            "invalid_array_arg" => ListError::InvalidArrayArg(context),
+            "invalid_auth" => ListError::InvalidAuth(context),
+            "invalid_charset" => ListError::InvalidCharset(context),
+            "invalid_form_data" => ListError::InvalidFormData(context),
+            "invalid_json" => ListError::InvalidJson(context),
+            "invalid_post_type" => ListError::InvalidPostType(context),
+            "json_not_object" => ListError::JsonNotObject(context),
+            "missing_post_type" => ListError::MissingPostType(context),
+            "no_permission" => ListError::NoPermission(context),
+            "not_authed" => ListError::NotAuthed(context),
+            "request_timeout" => ListError::RequestTimeout(context),
+            "team_added_to_org" => ListError::TeamAddedToOrg(context),
+            "upgrade_required" => ListError::UpgradeRequired(context),
+            "user_not_found" => ListError::UserNotFound(context),
+            _ => ListError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for ListError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactiv" => ListError::AccountInactiv,
-            "fatal_error" => ListError::FatalError,
-            "invalid_arg_name" => ListError::InvalidArgName,
-            "invalid_array_arg" => ListError::InvalidArrayArg,
-            "invalid_auth" => ListError::InvalidAuth,
-            "invalid_charset" => ListError::InvalidCharset,
-            "invalid_form_data" => ListError::InvalidFormData,
-            "invalid_json" => ListError::InvalidJson,
-            "invalid_post_type" => ListError::InvalidPostType,
-            "json_not_object" => ListError::JsonNotObject,
-            "missing_post_type" => ListError::MissingPostType,
-            "no_permission" => ListError::NoPermission,
-            "not_authed" => ListError::NotAuthed,
-            "request_timeout" => ListError::RequestTimeout,
-            "team_added_to_org" => ListError::TeamAddedToOrg,
-            "upgrade_required" => ListError::UpgradeRequired,
-            "user_not_found" => ListError::UserNotFound,
-            _ => ListError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for ListError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            ListError::AccountInactiv => write!(f, "Server returned error account_inactiv"),
-            ListError::FatalError => write!(f, "Server returned error fatal_error"),
-            ListError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            ListError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            ListError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            ListError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            ListError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            ListError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            ListError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            ListError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            ListError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            ListError::NoPermission => write!(f, "Server returned error no_permission"),
-            ListError::NotAuthed => write!(f, "Server returned error not_authed"),
-            ListError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            ListError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            ListError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
-            ListError::UserNotFound => write!(f, "Server returned error user_not_found"),
+            ListError::AccountInactiv(_) => write!(f, "Server returned error account_inactiv"),
+            ListError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            ListError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            ListError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            ListError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            ListError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            ListError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            ListError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            ListError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            ListError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            ListError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            ListError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            ListError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            ListError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            ListError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            ListError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
+            ListError::UserNotFound(_) => write!(f, "Server returned error user_not_found"),
             ListError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            ListError::Unknown(ref s) => write!(f, "{}", s),
+            ListError::Unknown(ref s, _) => write!(f, "{}", s),
             ListError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -730,26 +998,92 @@ impl<E: Error + 'static> Error for ListError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for ListError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            ListError::AccountInactiv(_) => Some("account_inactiv"),
+            ListError::FatalError(_) => Some("fatal_error"),
+            ListError::InvalidArgName(_) => Some("invalid_arg_name"),
+            ListError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            ListError::InvalidAuth(_) => Some("invalid_auth"),
+            ListError::InvalidCharset(_) => Some("invalid_charset"),
+            ListError::InvalidFormData(_) => Some("invalid_form_data"),
+            ListError::InvalidJson(_) => Some("invalid_json"),
+            ListError::InvalidPostType(_) => Some("invalid_post_type"),
+            ListError::JsonNotObject(_) => Some("json_not_object"),
+            ListError::MissingPostType(_) => Some("missing_post_type"),
+            ListError::NoPermission(_) => Some("no_permission"),
+            ListError::NotAuthed(_) => Some("not_authed"),
+            ListError::RequestTimeout(_) => Some("request_timeout"),
+            ListError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            ListError::UpgradeRequired(_) => Some("upgrade_required"),
+            ListError::UserNotFound(_) => Some("user_not_found"),
+            ListError::Unknown(ref s, _) => Some(s),
+            ListError::MalformedResponse(..) | ListError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            ListError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            ListError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            ListError::AccountInactiv(ref context)
+            | ListError::FatalError(ref context)
+            | ListError::InvalidArgName(ref context)
+            | ListError::InvalidArrayArg(ref context)
+            | ListError::InvalidAuth(ref context)
+            | ListError::InvalidCharset(ref context)
+            | ListError::InvalidFormData(ref context)
+            | ListError::InvalidJson(ref context)
+            | ListError::InvalidPostType(ref context)
+            | ListError::JsonNotObject(ref context)
+            | ListError::MissingPostType(ref context)
+            | ListError::NoPermission(ref context)
+            | ListError::NotAuthed(ref context)
+            | ListError::RequestTimeout(ref context)
+            | ListError::TeamAddedToOrg(ref context)
+            | ListError::UpgradeRequired(ref context)
+            | ListError::UserNotFound(ref context)
+            | ListError::Unknown(_, ref context) => Some(context),
+            ListError::MalformedResponse(..) | ListError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct RemoveRequest<'a> {
     /// Channel where the message to remove reaction from was posted.
-    pub channel: Option<Cow<'a, str>>,
+    pub channel: Option<crate::ChannelId>,
     /// File to remove reaction from.
-    pub file: Option<Cow<'a, str>>,
+    pub file: Option<crate::FileId>,
     /// File comment to remove reaction from.
     pub file_comment: Option<Cow<'a, str>>,
     /// Reaction (emoji) name.
     pub name: Cow<'a, str>,
     /// Timestamp of the message to remove reaction from.
-    pub timestamp: Option<Cow<'a, str>>,
+    pub timestamp: Option<crate::Timestamp>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RemoveResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<RemoveResponse, RemoveError<E>>> for RemoveResponse {
@@ -757,105 +1091,140 @@ impl<E: Error> Into<Result<RemoveResponse, RemoveError<E>>> for RemoveResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(RemoveError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for RemoveResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum RemoveError<E: Error> {
-    AccountInactive,
-    BadTimestamp,
-    FatalError,
-    FileCommentNotFound,
-    FileNotFound,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidName,
-    InvalidPostType,
-    JsonNotObject,
-    MessageNotFound,
-    MissingPostType,
-    NoItemSpecified,
-    NoPermission,
-    NoReaction,
-    NotAuthed,
-    RequestTimeout,
-    TeamAddedToOrg,
-    UpgradeRequired,
+    AccountInactive(crate::ErrorContext),
+    BadTimestamp(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    FileCommentNotFound(crate::ErrorContext),
+    FileNotFound(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidName(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MessageNotFound(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoItemSpecified(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NoReaction(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> RemoveError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => RemoveError::AccountInactive(context),
+            "bad_timestamp" => RemoveError::BadTimestamp(context),
+            "fatal_error" => RemoveError::FatalError(context),
+            "file_comment_not_found" => RemoveError::FileCommentNotFound(context),
+            "file_not_found" => RemoveError::FileNotFound(context),
+            "invalid_arg_name" => RemoveError::InvalidArgName(context),
+            "invalid_array_arg" => RemoveError::InvalidArrayArg(context),
+            "invalid_auth" => RemoveError::InvalidAuth(context),
+            "invalid_charset" => RemoveError::InvalidCharset(context),
+            "invalid_form_data" => RemoveError::InvalidFormData(context),
+            "invalid_json" => RemoveError::InvalidJson(context),
+            "invalid_name" => RemoveError::InvalidName(context),
+            "invalid_post_type" => RemoveError::InvalidPostType(context),
+            "json_not_object" => RemoveError::JsonNotObject(context),
+            "message_not_found" => RemoveError::MessageNotFound(context),
+            "missing_post_type" => RemoveError::MissingPostType(context),
+            "no_item_specified" => RemoveError::NoItemSpecified(context),
+            "no_permission" => RemoveError::NoPermission(context),
+            "no_reaction" => RemoveError::NoReaction(context),
+            "not_authed" => RemoveError::NotAuthed(context),
+            "request_timeout" => RemoveError::RequestTimeout(context),
+            "team_added_to_org" => RemoveError::TeamAddedToOrg(context),
+            "upgrade_required" => RemoveError::UpgradeRequired(context),
+            _ => RemoveError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for RemoveError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => RemoveError::AccountInactive,
-            "bad_timestamp" => RemoveError::BadTimestamp,
-            "fatal_error" => RemoveError::FatalError,
-            "file_comment_not_found" => RemoveError::FileCommentNotFound,
-            "file_not_found" => RemoveError::FileNotFound,
-            "invalid_arg_name" => RemoveError::InvalidArgName,
-            "invalid_array_arg" => RemoveError::InvalidArrayArg,
-            "invalid_auth" => RemoveError::InvalidAuth,
-            "invalid_charset" => RemoveError::InvalidCharset,
-            "invalid_form_data" => RemoveError::InvalidFormData,
-            "invalid_json" => RemoveError::InvalidJson,
-            "invalid_name" => RemoveError::InvalidName,
-            "invalid_post_type" => RemoveError::InvalidPostType,
-            "json_not_object" => RemoveError::JsonNotObject,
-            "message_not_found" => RemoveError::MessageNotFound,
-            "missing_post_type" => RemoveError::MissingPostType,
-            "no_item_specified" => RemoveError::NoItemSpecified,
-            "no_permission" => RemoveError::NoPermission,
-            "no_reaction" => RemoveError::NoReaction,
-            "not_authed" => RemoveError::NotAuthed,
-            "request_timeout" => RemoveError::RequestTimeout,
-            "team_added_to_org" => RemoveError::TeamAddedToOrg,
-            "upgrade_required" => RemoveError::UpgradeRequired,
-            _ => RemoveError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for RemoveError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            RemoveError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            RemoveError::BadTimestamp => write!(f, "Server returned error bad_timestamp"),
-            RemoveError::FatalError => write!(f, "Server returned error fatal_error"),
-            RemoveError::FileCommentNotFound => {
+            RemoveError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            RemoveError::BadTimestamp(_) => write!(f, "Server returned error bad_timestamp"),
+            RemoveError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            RemoveError::FileCommentNotFound(_) => {
                 write!(f, "Server returned error file_comment_not_found")
             }
-            RemoveError::FileNotFound => write!(f, "Server returned error file_not_found"),
-            RemoveError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            RemoveError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            RemoveError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            RemoveError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            RemoveError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            RemoveError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            RemoveError::InvalidName => write!(f, "Server returned error invalid_name"),
-            RemoveError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            RemoveError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            RemoveError::MessageNotFound => write!(f, "Server returned error message_not_found"),
-            RemoveError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            RemoveError::NoItemSpecified => write!(f, "Server returned error no_item_specified"),
-            RemoveError::NoPermission => write!(f, "Server returned error no_permission"),
-            RemoveError::NoReaction => write!(f, "Server returned error no_reaction"),
-            RemoveError::NotAuthed => write!(f, "Server returned error not_authed"),
-            RemoveError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            RemoveError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            RemoveError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
+            RemoveError::FileNotFound(_) => write!(f, "Server returned error file_not_found"),
+            RemoveError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            RemoveError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            RemoveError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            RemoveError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            RemoveError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            RemoveError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            RemoveError::InvalidName(_) => write!(f, "Server returned error invalid_name"),
+            RemoveError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            RemoveError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            RemoveError::MessageNotFound(_) => write!(f, "Server returned error message_not_found"),
+            RemoveError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            RemoveError::NoItemSpecified(_) => write!(f, "Server returned error no_item_specified"),
+            RemoveError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            RemoveError::NoReaction(_) => write!(f, "Server returned error no_reaction"),
+            RemoveError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            RemoveError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            RemoveError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            RemoveError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
             RemoveError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            RemoveError::Unknown(ref s) => write!(f, "{}", s),
+            RemoveError::Unknown(ref s, _) => write!(f, "{}", s),
             RemoveError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -870,3 +1239,75 @@ impl<E: Error + 'static> Error for RemoveError<E> {
         }
     }
 }
+
+impl<E: Error + 'static> crate::MethodError for RemoveError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            RemoveError::AccountInactive(_) => Some("account_inactive"),
+            RemoveError::BadTimestamp(_) => Some("bad_timestamp"),
+            RemoveError::FatalError(_) => Some("fatal_error"),
+            RemoveError::FileCommentNotFound(_) => Some("file_comment_not_found"),
+            RemoveError::FileNotFound(_) => Some("file_not_found"),
+            RemoveError::InvalidArgName(_) => Some("invalid_arg_name"),
+            RemoveError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            RemoveError::InvalidAuth(_) => Some("invalid_auth"),
+            RemoveError::InvalidCharset(_) => Some("invalid_charset"),
+            RemoveError::InvalidFormData(_) => Some("invalid_form_data"),
+            RemoveError::InvalidJson(_) => Some("invalid_json"),
+            RemoveError::InvalidName(_) => Some("invalid_name"),
+            RemoveError::InvalidPostType(_) => Some("invalid_post_type"),
+            RemoveError::JsonNotObject(_) => Some("json_not_object"),
+            RemoveError::MessageNotFound(_) => Some("message_not_found"),
+            RemoveError::MissingPostType(_) => Some("missing_post_type"),
+            RemoveError::NoItemSpecified(_) => Some("no_item_specified"),
+            RemoveError::NoPermission(_) => Some("no_permission"),
+            RemoveError::NoReaction(_) => Some("no_reaction"),
+            RemoveError::NotAuthed(_) => Some("not_authed"),
+            RemoveError::RequestTimeout(_) => Some("request_timeout"),
+            RemoveError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            RemoveError::UpgradeRequired(_) => Some("upgrade_required"),
+            RemoveError::Unknown(ref s, _) => Some(s),
+            RemoveError::MalformedResponse(..) | RemoveError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            RemoveError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            RemoveError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            RemoveError::AccountInactive(ref context)
+            | RemoveError::BadTimestamp(ref context)
+            | RemoveError::FatalError(ref context)
+            | RemoveError::FileCommentNotFound(ref context)
+            | RemoveError::FileNotFound(ref context)
+            | RemoveError::InvalidArgName(ref context)
+            | RemoveError::InvalidArrayArg(ref context)
+            | RemoveError::InvalidAuth(ref context)
+            | RemoveError::InvalidCharset(ref context)
+            | RemoveError::InvalidFormData(ref context)
+            | RemoveError::InvalidJson(ref context)
+            | RemoveError::InvalidName(ref context)
+            | RemoveError::InvalidPostType(ref context)
+            | RemoveError::JsonNotObject(ref context)
+            | RemoveError::MessageNotFound(ref context)
+            | RemoveError::MissingPostType(ref context)
+            | RemoveError::NoItemSpecified(ref context)
+            | RemoveError::NoPermission(ref context)
+            | RemoveError::NoReaction(ref context)
+            | RemoveError::NotAuthed(ref context)
+            | RemoveError::RequestTimeout(ref context)
+            | RemoveError::TeamAddedToOrg(ref context)
+            | RemoveError::UpgradeRequired(ref context)
+            | RemoveError::Unknown(_, ref context) => Some(context),
+            RemoveError::MalformedResponse(..) | RemoveError::Client(_) => None,
+        }
+    }
+}
@@ -28,10 +28,10 @@ pub struct AddRequest<'a> {
     /// When this reminder should happen: the Unix timestamp (up to five years from now), the number of seconds until the reminder (if within 24 hours), or a natural language description (Ex. "in 15 minutes," or "every Thursday")
     pub time: Cow<'a, str>,
     /// The user who will receive the reminder. If no user is specified, the reminder will go to user who created it.
-    pub user: Option<Cow<'a, str>>,
+    pub user: Option<crate::UserId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AddReminderInner {
     pub complete_ts: Option<u64>,
     pub creator: String,
@@ -39,16 +39,22 @@ pub struct AddReminderInner {
     pub recurring: bool,
     pub text: String,
     pub time: Option<u64>,
-    pub user: String,
+    pub user: crate::UserId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AddResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
     pub reminder: AddReminderInner,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<AddResponse, AddError<E>>> for AddResponse {
@@ -56,111 +62,148 @@ impl<E: Error> Into<Result<AddResponse, AddError<E>>> for AddResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(AddError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for AddResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum AddError<E: Error> {
-    AccountInactive,
-    CannotAddBot,
-    CannotAddOthers,
-    CannotAddOthersRecurring,
-    CannotAddSlackbot,
-    CannotParse,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    OrgLoginRequired,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequired,
-    UserIsBot,
-    UserNotFound,
+    AccountInactive(crate::ErrorContext),
+    CannotAddBot(crate::ErrorContext),
+    CannotAddOthers(crate::ErrorContext),
+    CannotAddOthersRecurring(crate::ErrorContext),
+    CannotAddSlackbot(crate::ErrorContext),
+    CannotParse(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserIsBot(crate::ErrorContext),
+    UserNotFound(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> AddError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => AddError::AccountInactive(context),
+            "cannot_add_bot" => AddError::CannotAddBot(context),
+            "cannot_add_others" => AddError::CannotAddOthers(context),
+            "cannot_add_others_recurring" => AddError::CannotAddOthersRecurring(context),
+            "cannot_add_slackbot" => AddError::CannotAddSlackbot(context),
+            "cannot_parse" => AddError::CannotParse(context),
+            "fatal_error" => AddError::FatalError(context),
+            "invalid_arg_name" => AddError::InvalidArgName(context),
+            "invalid_array_arg" => AddError::InvalidArrayArg(context),
+            "invalid_auth" => AddError::InvalidAuth(context),
+            "invalid_charset" => AddError::InvalidCharset(context),
+            "invalid_form_data" => AddError::InvalidFormData(context),
+            "invalid_json" => AddError::InvalidJson(context),
+            "invalid_post_type" => AddError::InvalidPostType(context),
+            "json_not_object" => AddError::JsonNotObject(context),
+            "missing_post_type" => AddError::MissingPostType(context),
+            "no_permission" => AddError::NoPermission(context),
+            "not_authed" => AddError::NotAuthed(context),
+            "org_login_required" => AddError::OrgLoginRequired(context),
+            "request_timeout" => AddError::RequestTimeout(context),
+            "team_added_to_org" => AddError::TeamAddedToOrg(context),
+            "token_revoked" => AddError::TokenRevoked(context),
+            "upgrade_required" => AddError::UpgradeRequired(context),
+            "user_is_bot" => AddError::UserIsBot(context),
+            "user_not_found" => AddError::UserNotFound(context),
+            _ => AddError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for AddError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => AddError::AccountInactive,
-            "cannot_add_bot" => AddError::CannotAddBot,
-            "cannot_add_others" => AddError::CannotAddOthers,
-            "cannot_add_others_recurring" => AddError::CannotAddOthersRecurring,
-            "cannot_add_slackbot" => AddError::CannotAddSlackbot,
-            "cannot_parse" => AddError::CannotParse,
-            "fatal_error" => AddError::FatalError,
-            "invalid_arg_name" => AddError::InvalidArgName,
-            "invalid_array_arg" => AddError::InvalidArrayArg,
-            "invalid_auth" => AddError::InvalidAuth,
-            "invalid_charset" => AddError::InvalidCharset,
-            "invalid_form_data" => AddError::InvalidFormData,
-            "invalid_json" => AddError::InvalidJson,
-            "invalid_post_type" => AddError::InvalidPostType,
-            "json_not_object" => AddError::JsonNotObject,
-            "missing_post_type" => AddError::MissingPostType,
-            "no_permission" => AddError::NoPermission,
-            "not_authed" => AddError::NotAuthed,
-            "org_login_required" => AddError::OrgLoginRequired,
-            "request_timeout" => AddError::RequestTimeout,
-            "team_added_to_org" => AddError::TeamAddedToOrg,
-            "token_revoked" => AddError::TokenRevoked,
-            "upgrade_required" => AddError::UpgradeRequired,
-            "user_is_bot" => AddError::UserIsBot,
-            "user_not_found" => AddError::UserNotFound,
-            _ => AddError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for AddError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            AddError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            AddError::CannotAddBot => write!(f, "Server returned error cannot_add_bot"),
-            AddError::CannotAddOthers => write!(f, "Server returned error cannot_add_others"),
-            AddError::CannotAddOthersRecurring => {
+            AddError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            AddError::CannotAddBot(_) => write!(f, "Server returned error cannot_add_bot"),
+            AddError::CannotAddOthers(_) => write!(f, "Server returned error cannot_add_others"),
+            AddError::CannotAddOthersRecurring(_) => {
                 write!(f, "Server returned error cannot_add_others_recurring")
             }
-            AddError::CannotAddSlackbot => write!(f, "Server returned error cannot_add_slackbot"),
-            AddError::CannotParse => write!(f, "Server returned error cannot_parse"),
-            AddError::FatalError => write!(f, "Server returned error fatal_error"),
-            AddError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            AddError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            AddError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            AddError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            AddError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            AddError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            AddError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            AddError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            AddError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            AddError::NoPermission => write!(f, "Server returned error no_permission"),
-            AddError::NotAuthed => write!(f, "Server returned error not_authed"),
-            AddError::OrgLoginRequired => write!(f, "Server returned error org_login_required"),
-            AddError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            AddError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            AddError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            AddError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
-            AddError::UserIsBot => write!(f, "Server returned error user_is_bot"),
-            AddError::UserNotFound => write!(f, "Server returned error user_not_found"),
+            AddError::CannotAddSlackbot(_) => {
+                write!(f, "Server returned error cannot_add_slackbot")
+            }
+            AddError::CannotParse(_) => write!(f, "Server returned error cannot_parse"),
+            AddError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            AddError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            AddError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            AddError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            AddError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            AddError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            AddError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            AddError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            AddError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            AddError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            AddError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            AddError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            AddError::OrgLoginRequired(_) => write!(f, "Server returned error org_login_required"),
+            AddError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            AddError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            AddError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            AddError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
+            AddError::UserIsBot(_) => write!(f, "Server returned error user_is_bot"),
+            AddError::UserNotFound(_) => write!(f, "Server returned error user_not_found"),
             AddError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            AddError::Unknown(ref s) => write!(f, "{}", s),
+            AddError::Unknown(ref s, _) => write!(f, "{}", s),
             AddError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -176,18 +219,100 @@ impl<E: Error + 'static> Error for AddError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for AddError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            AddError::AccountInactive(_) => Some("account_inactive"),
+            AddError::CannotAddBot(_) => Some("cannot_add_bot"),
+            AddError::CannotAddOthers(_) => Some("cannot_add_others"),
+            AddError::CannotAddOthersRecurring(_) => Some("cannot_add_others_recurring"),
+            AddError::CannotAddSlackbot(_) => Some("cannot_add_slackbot"),
+            AddError::CannotParse(_) => Some("cannot_parse"),
+            AddError::FatalError(_) => Some("fatal_error"),
+            AddError::InvalidArgName(_) => Some("invalid_arg_name"),
+            AddError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            AddError::InvalidAuth(_) => Some("invalid_auth"),
+            AddError::InvalidCharset(_) => Some("invalid_charset"),
+            AddError::InvalidFormData(_) => Some("invalid_form_data"),
+            AddError::InvalidJson(_) => Some("invalid_json"),
+            AddError::InvalidPostType(_) => Some("invalid_post_type"),
+            AddError::JsonNotObject(_) => Some("json_not_object"),
+            AddError::MissingPostType(_) => Some("missing_post_type"),
+            AddError::NoPermission(_) => Some("no_permission"),
+            AddError::NotAuthed(_) => Some("not_authed"),
+            AddError::OrgLoginRequired(_) => Some("org_login_required"),
+            AddError::RequestTimeout(_) => Some("request_timeout"),
+            AddError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            AddError::TokenRevoked(_) => Some("token_revoked"),
+            AddError::UpgradeRequired(_) => Some("upgrade_required"),
+            AddError::UserIsBot(_) => Some("user_is_bot"),
+            AddError::UserNotFound(_) => Some("user_not_found"),
+            AddError::Unknown(ref s, _) => Some(s),
+            AddError::MalformedResponse(..) | AddError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            AddError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            AddError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            AddError::AccountInactive(ref context)
+            | AddError::CannotAddBot(ref context)
+            | AddError::CannotAddOthers(ref context)
+            | AddError::CannotAddOthersRecurring(ref context)
+            | AddError::CannotAddSlackbot(ref context)
+            | AddError::CannotParse(ref context)
+            | AddError::FatalError(ref context)
+            | AddError::InvalidArgName(ref context)
+            | AddError::InvalidArrayArg(ref context)
+            | AddError::InvalidAuth(ref context)
+            | AddError::InvalidCharset(ref context)
+            | AddError::InvalidFormData(ref context)
+            | AddError::InvalidJson(ref context)
+            | AddError::InvalidPostType(ref context)
+            | AddError::JsonNotObject(ref context)
+            | AddError::MissingPostType(ref context)
+            | AddError::NoPermission(ref context)
+            | AddError::NotAuthed(ref context)
+            | AddError::OrgLoginRequired(ref context)
+            | AddError::RequestTimeout(ref context)
+            | AddError::TeamAddedToOrg(ref context)
+            | AddError::TokenRevoked(ref context)
+            | AddError::UpgradeRequired(ref context)
+            | AddError::UserIsBot(ref context)
+            | AddError::UserNotFound(ref context)
+            | AddError::Unknown(_, ref context) => Some(context),
+            AddError::MalformedResponse(..) | AddError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct CompleteRequest<'a> {
     /// The ID of the reminder to be marked as complete
     pub reminder: Option<Cow<'a, str>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CompleteResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<CompleteResponse, CompleteError<E>>> for CompleteResponse {
@@ -195,106 +320,155 @@ impl<E: Error> Into<Result<CompleteResponse, CompleteError<E>>> for CompleteResp
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(CompleteError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for CompleteResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum CompleteError<E: Error> {
-    AccountInactive,
-    CannotCompleteOthers,
-    CannotCompleteRecurring,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    NotFound,
-    OrgLoginRequired,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequired,
-    UserIsBot,
+    AccountInactive(crate::ErrorContext),
+    CannotCompleteOthers(crate::ErrorContext),
+    CannotCompleteRecurring(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    NotFound(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserIsBot(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> CompleteError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => CompleteError::AccountInactive(context),
+            "cannot_complete_others" => CompleteError::CannotCompleteOthers(context),
+            "cannot_complete_recurring" => CompleteError::CannotCompleteRecurring(context),
+            "fatal_error" => CompleteError::FatalError(context),
+            "invalid_arg_name" => CompleteError::InvalidArgName(context),
+            "invalid_array_arg" => CompleteError::InvalidArrayArg(context),
+            "invalid_auth" => CompleteError::InvalidAuth(context),
+            "invalid_charset" => CompleteError::InvalidCharset(context),
+            "invalid_form_data" => CompleteError::InvalidFormData(context),
+            "invalid_json" => CompleteError::InvalidJson(context),
+            "invalid_post_type" => CompleteError::InvalidPostType(context),
+            "json_not_object" => CompleteError::JsonNotObject(context),
+            "missing_post_type" => CompleteError::MissingPostType(context),
+            "no_permission" => CompleteError::NoPermission(context),
+            "not_authed" => CompleteError::NotAuthed(context),
+            "not_found" => CompleteError::NotFound(context),
+            "org_login_required" => CompleteError::OrgLoginRequired(context),
+            "request_timeout" => CompleteError::RequestTimeout(context),
+            "team_added_to_org" => CompleteError::TeamAddedToOrg(context),
+            "token_revoked" => CompleteError::TokenRevoked(context),
+            "upgrade_required" => CompleteError::UpgradeRequired(context),
+            "user_is_bot" => CompleteError::UserIsBot(context),
+            _ => CompleteError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for CompleteError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => CompleteError::AccountInactive,
-            "cannot_complete_others" => CompleteError::CannotCompleteOthers,
-            "cannot_complete_recurring" => CompleteError::CannotCompleteRecurring,
-            "fatal_error" => CompleteError::FatalError,
-            "invalid_arg_name" => CompleteError::InvalidArgName,
-            "invalid_array_arg" => CompleteError::InvalidArrayArg,
-            "invalid_auth" => CompleteError::InvalidAuth,
-            "invalid_charset" => CompleteError::InvalidCharset,
-            "invalid_form_data" => CompleteError::InvalidFormData,
-            "invalid_json" => CompleteError::InvalidJson,
-            "invalid_post_type" => CompleteError::InvalidPostType,
-            "json_not_object" => CompleteError::JsonNotObject,
-            "missing_post_type" => CompleteError::MissingPostType,
-            "no_permission" => CompleteError::NoPermission,
-            "not_authed" => CompleteError::NotAuthed,
-            "not_found" => CompleteError::NotFound,
-            "org_login_required" => CompleteError::OrgLoginRequired,
-            "request_timeout" => CompleteError::RequestTimeout,
-            "team_added_to_org" => CompleteError::TeamAddedToOrg,
-            "token_revoked" => CompleteError::TokenRevoked,
-            "upgrade_required" => CompleteError::UpgradeRequired,
-            "user_is_bot" => CompleteError::UserIsBot,
-            _ => CompleteError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for CompleteError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            CompleteError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            CompleteError::CannotCompleteOthers => {
+            CompleteError::AccountInactive(_) => {
+                write!(f, "Server returned error account_inactive")
+            }
+            CompleteError::CannotCompleteOthers(_) => {
                 write!(f, "Server returned error cannot_complete_others")
             }
-            CompleteError::CannotCompleteRecurring => {
+            CompleteError::CannotCompleteRecurring(_) => {
                 write!(f, "Server returned error cannot_complete_recurring")
             }
-            CompleteError::FatalError => write!(f, "Server returned error fatal_error"),
-            CompleteError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            CompleteError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            CompleteError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            CompleteError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            CompleteError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            CompleteError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            CompleteError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            CompleteError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            CompleteError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            CompleteError::NoPermission => write!(f, "Server returned error no_permission"),
-            CompleteError::NotAuthed => write!(f, "Server returned error not_authed"),
-            CompleteError::NotFound => write!(f, "Server returned error not_found"),
-            CompleteError::OrgLoginRequired => {
+            CompleteError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            CompleteError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            CompleteError::InvalidArrayArg(_) => {
+                write!(f, "Server returned error invalid_array_arg")
+            }
+            CompleteError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            CompleteError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            CompleteError::InvalidFormData(_) => {
+                write!(f, "Server returned error invalid_form_data")
+            }
+            CompleteError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            CompleteError::InvalidPostType(_) => {
+                write!(f, "Server returned error invalid_post_type")
+            }
+            CompleteError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            CompleteError::MissingPostType(_) => {
+                write!(f, "Server returned error missing_post_type")
+            }
+            CompleteError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            CompleteError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            CompleteError::NotFound(_) => write!(f, "Server returned error not_found"),
+            CompleteError::OrgLoginRequired(_) => {
                 write!(f, "Server returned error org_login_required")
             }
-            CompleteError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            CompleteError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            CompleteError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            CompleteError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
-            CompleteError::UserIsBot => write!(f, "Server returned error user_is_bot"),
+            CompleteError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            CompleteError::TeamAddedToOrg(_) => {
+                write!(f, "Server returned error team_added_to_org")
+            }
+            CompleteError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            CompleteError::UpgradeRequired(_) => {
+                write!(f, "Server returned error upgrade_required")
+            }
+            CompleteError::UserIsBot(_) => write!(f, "Server returned error user_is_bot"),
             CompleteError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            CompleteError::Unknown(ref s) => write!(f, "{}", s),
+            CompleteError::Unknown(ref s, _) => write!(f, "{}", s),
             CompleteError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -310,18 +484,94 @@ impl<E: Error + 'static> Error for CompleteError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for CompleteError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            CompleteError::AccountInactive(_) => Some("account_inactive"),
+            CompleteError::CannotCompleteOthers(_) => Some("cannot_complete_others"),
+            CompleteError::CannotCompleteRecurring(_) => Some("cannot_complete_recurring"),
+            CompleteError::FatalError(_) => Some("fatal_error"),
+            CompleteError::InvalidArgName(_) => Some("invalid_arg_name"),
+            CompleteError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            CompleteError::InvalidAuth(_) => Some("invalid_auth"),
+            CompleteError::InvalidCharset(_) => Some("invalid_charset"),
+            CompleteError::InvalidFormData(_) => Some("invalid_form_data"),
+            CompleteError::InvalidJson(_) => Some("invalid_json"),
+            CompleteError::InvalidPostType(_) => Some("invalid_post_type"),
+            CompleteError::JsonNotObject(_) => Some("json_not_object"),
+            CompleteError::MissingPostType(_) => Some("missing_post_type"),
+            CompleteError::NoPermission(_) => Some("no_permission"),
+            CompleteError::NotAuthed(_) => Some("not_authed"),
+            CompleteError::NotFound(_) => Some("not_found"),
+            CompleteError::OrgLoginRequired(_) => Some("org_login_required"),
+            CompleteError::RequestTimeout(_) => Some("request_timeout"),
+            CompleteError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            CompleteError::TokenRevoked(_) => Some("token_revoked"),
+            CompleteError::UpgradeRequired(_) => Some("upgrade_required"),
+            CompleteError::UserIsBot(_) => Some("user_is_bot"),
+            CompleteError::Unknown(ref s, _) => Some(s),
+            CompleteError::MalformedResponse(..) | CompleteError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            CompleteError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            CompleteError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            CompleteError::AccountInactive(ref context)
+            | CompleteError::CannotCompleteOthers(ref context)
+            | CompleteError::CannotCompleteRecurring(ref context)
+            | CompleteError::FatalError(ref context)
+            | CompleteError::InvalidArgName(ref context)
+            | CompleteError::InvalidArrayArg(ref context)
+            | CompleteError::InvalidAuth(ref context)
+            | CompleteError::InvalidCharset(ref context)
+            | CompleteError::InvalidFormData(ref context)
+            | CompleteError::InvalidJson(ref context)
+            | CompleteError::InvalidPostType(ref context)
+            | CompleteError::JsonNotObject(ref context)
+            | CompleteError::MissingPostType(ref context)
+            | CompleteError::NoPermission(ref context)
+            | CompleteError::NotAuthed(ref context)
+            | CompleteError::NotFound(ref context)
+            | CompleteError::OrgLoginRequired(ref context)
+            | CompleteError::RequestTimeout(ref context)
+            | CompleteError::TeamAddedToOrg(ref context)
+            | CompleteError::TokenRevoked(ref context)
+            | CompleteError::UpgradeRequired(ref context)
+            | CompleteError::UserIsBot(ref context)
+            | CompleteError::Unknown(_, ref context) => Some(context),
+            CompleteError::MalformedResponse(..) | CompleteError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct DeleteRequest<'a> {
     /// The ID of the reminder
     pub reminder: Option<Cow<'a, str>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct DeleteResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<DeleteResponse, DeleteError<E>>> for DeleteResponse {
@@ -329,94 +579,131 @@ impl<E: Error> Into<Result<DeleteResponse, DeleteError<E>>> for DeleteResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(DeleteError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for DeleteResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum DeleteError<E: Error> {
-    AccountInactive,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    NotFound,
-    OrgLoginRequired,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequired,
-    UserIsBot,
+    AccountInactive(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    NotFound(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserIsBot(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> DeleteError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => DeleteError::AccountInactive(context),
+            "fatal_error" => DeleteError::FatalError(context),
+            "invalid_arg_name" => DeleteError::InvalidArgName(context),
+            "invalid_array_arg" => DeleteError::InvalidArrayArg(context),
+            "invalid_auth" => DeleteError::InvalidAuth(context),
+            "invalid_charset" => DeleteError::InvalidCharset(context),
+            "invalid_form_data" => DeleteError::InvalidFormData(context),
+            "invalid_json" => DeleteError::InvalidJson(context),
+            "invalid_post_type" => DeleteError::InvalidPostType(context),
+            "json_not_object" => DeleteError::JsonNotObject(context),
+            "missing_post_type" => DeleteError::MissingPostType(context),
+            "no_permission" => DeleteError::NoPermission(context),
+            "not_authed" => DeleteError::NotAuthed(context),
+            "not_found" => DeleteError::NotFound(context),
+            "org_login_required" => DeleteError::OrgLoginRequired(context),
+            "request_timeout" => DeleteError::RequestTimeout(context),
+            "team_added_to_org" => DeleteError::TeamAddedToOrg(context),
+            "token_revoked" => DeleteError::TokenRevoked(context),
+            "upgrade_required" => DeleteError::UpgradeRequired(context),
+            "user_is_bot" => DeleteError::UserIsBot(context),
+            _ => DeleteError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for DeleteError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => DeleteError::AccountInactive,
-            "fatal_error" => DeleteError::FatalError,
-            "invalid_arg_name" => DeleteError::InvalidArgName,
-            "invalid_array_arg" => DeleteError::InvalidArrayArg,
-            "invalid_auth" => DeleteError::InvalidAuth,
-            "invalid_charset" => DeleteError::InvalidCharset,
-            "invalid_form_data" => DeleteError::InvalidFormData,
-            "invalid_json" => DeleteError::InvalidJson,
-            "invalid_post_type" => DeleteError::InvalidPostType,
-            "json_not_object" => DeleteError::JsonNotObject,
-            "missing_post_type" => DeleteError::MissingPostType,
-            "no_permission" => DeleteError::NoPermission,
-            "not_authed" => DeleteError::NotAuthed,
-            "not_found" => DeleteError::NotFound,
-            "org_login_required" => DeleteError::OrgLoginRequired,
-            "request_timeout" => DeleteError::RequestTimeout,
-            "team_added_to_org" => DeleteError::TeamAddedToOrg,
-            "token_revoked" => DeleteError::TokenRevoked,
-            "upgrade_required" => DeleteError::UpgradeRequired,
-            "user_is_bot" => DeleteError::UserIsBot,
-            _ => DeleteError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for DeleteError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            DeleteError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            DeleteError::FatalError => write!(f, "Server returned error fatal_error"),
-            DeleteError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            DeleteError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            DeleteError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            DeleteError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            DeleteError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            DeleteError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            DeleteError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            DeleteError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            DeleteError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            DeleteError::NoPermission => write!(f, "Server returned error no_permission"),
-            DeleteError::NotAuthed => write!(f, "Server returned error not_authed"),
-            DeleteError::NotFound => write!(f, "Server returned error not_found"),
-            DeleteError::OrgLoginRequired => write!(f, "Server returned error org_login_required"),
-            DeleteError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            DeleteError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            DeleteError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            DeleteError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
-            DeleteError::UserIsBot => write!(f, "Server returned error user_is_bot"),
+            DeleteError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            DeleteError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            DeleteError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            DeleteError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            DeleteError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            DeleteError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            DeleteError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            DeleteError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            DeleteError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            DeleteError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            DeleteError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            DeleteError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            DeleteError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            DeleteError::NotFound(_) => write!(f, "Server returned error not_found"),
+            DeleteError::OrgLoginRequired(_) => {
+                write!(f, "Server returned error org_login_required")
+            }
+            DeleteError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            DeleteError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            DeleteError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            DeleteError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
+            DeleteError::UserIsBot(_) => write!(f, "Server returned error user_is_bot"),
             DeleteError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            DeleteError::Unknown(ref s) => write!(f, "{}", s),
+            DeleteError::Unknown(ref s, _) => write!(f, "{}", s),
             DeleteError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -432,13 +719,79 @@ impl<E: Error + 'static> Error for DeleteError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for DeleteError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            DeleteError::AccountInactive(_) => Some("account_inactive"),
+            DeleteError::FatalError(_) => Some("fatal_error"),
+            DeleteError::InvalidArgName(_) => Some("invalid_arg_name"),
+            DeleteError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            DeleteError::InvalidAuth(_) => Some("invalid_auth"),
+            DeleteError::InvalidCharset(_) => Some("invalid_charset"),
+            DeleteError::InvalidFormData(_) => Some("invalid_form_data"),
+            DeleteError::InvalidJson(_) => Some("invalid_json"),
+            DeleteError::InvalidPostType(_) => Some("invalid_post_type"),
+            DeleteError::JsonNotObject(_) => Some("json_not_object"),
+            DeleteError::MissingPostType(_) => Some("missing_post_type"),
+            DeleteError::NoPermission(_) => Some("no_permission"),
+            DeleteError::NotAuthed(_) => Some("not_authed"),
+            DeleteError::NotFound(_) => Some("not_found"),
+            DeleteError::OrgLoginRequired(_) => Some("org_login_required"),
+            DeleteError::RequestTimeout(_) => Some("request_timeout"),
+            DeleteError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            DeleteError::TokenRevoked(_) => Some("token_revoked"),
+            DeleteError::UpgradeRequired(_) => Some("upgrade_required"),
+            DeleteError::UserIsBot(_) => Some("user_is_bot"),
+            DeleteError::Unknown(ref s, _) => Some(s),
+            DeleteError::MalformedResponse(..) | DeleteError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            DeleteError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            DeleteError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            DeleteError::AccountInactive(ref context)
+            | DeleteError::FatalError(ref context)
+            | DeleteError::InvalidArgName(ref context)
+            | DeleteError::InvalidArrayArg(ref context)
+            | DeleteError::InvalidAuth(ref context)
+            | DeleteError::InvalidCharset(ref context)
+            | DeleteError::InvalidFormData(ref context)
+            | DeleteError::InvalidJson(ref context)
+            | DeleteError::InvalidPostType(ref context)
+            | DeleteError::JsonNotObject(ref context)
+            | DeleteError::MissingPostType(ref context)
+            | DeleteError::NoPermission(ref context)
+            | DeleteError::NotAuthed(ref context)
+            | DeleteError::NotFound(ref context)
+            | DeleteError::OrgLoginRequired(ref context)
+            | DeleteError::RequestTimeout(ref context)
+            | DeleteError::TeamAddedToOrg(ref context)
+            | DeleteError::TokenRevoked(ref context)
+            | DeleteError::UpgradeRequired(ref context)
+            | DeleteError::UserIsBot(ref context)
+            | DeleteError::Unknown(_, ref context) => Some(context),
+            DeleteError::MalformedResponse(..) | DeleteError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct InfoRequest<'a> {
     /// The ID of the reminder
     pub reminder: Option<Cow<'a, str>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoReminderInner {
     pub complete_ts: Option<u64>,
     pub creator: String,
@@ -446,16 +799,22 @@ pub struct InfoReminderInner {
     pub recurring: bool,
     pub text: String,
     pub time: Option<u64>,
-    pub user: String,
+    pub user: crate::UserId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
     pub reminder: InfoReminderInner,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<InfoResponse, InfoError<E>>> for InfoResponse {
@@ -463,94 +822,129 @@ impl<E: Error> Into<Result<InfoResponse, InfoError<E>>> for InfoResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(InfoError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for InfoResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum InfoError<E: Error> {
-    AccountInactive,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    NotFound,
-    OrgLoginRequired,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequired,
-    UserIsBot,
+    AccountInactive(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    NotFound(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserIsBot(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> InfoError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => InfoError::AccountInactive(context),
+            "fatal_error" => InfoError::FatalError(context),
+            "invalid_arg_name" => InfoError::InvalidArgName(context),
+            "invalid_array_arg" => InfoError::InvalidArrayArg(context),
+            "invalid_auth" => InfoError::InvalidAuth(context),
+            "invalid_charset" => InfoError::InvalidCharset(context),
+            "invalid_form_data" => InfoError::InvalidFormData(context),
+            "invalid_json" => InfoError::InvalidJson(context),
+            "invalid_post_type" => InfoError::InvalidPostType(context),
+            "json_not_object" => InfoError::JsonNotObject(context),
+            "missing_post_type" => InfoError::MissingPostType(context),
+            "no_permission" => InfoError::NoPermission(context),
+            "not_authed" => InfoError::NotAuthed(context),
+            "not_found" => InfoError::NotFound(context),
+            "org_login_required" => InfoError::OrgLoginRequired(context),
+            "request_timeout" => InfoError::RequestTimeout(context),
+            "team_added_to_org" => InfoError::TeamAddedToOrg(context),
+            "token_revoked" => InfoError::TokenRevoked(context),
+            "upgrade_required" => InfoError::UpgradeRequired(context),
+            "user_is_bot" => InfoError::UserIsBot(context),
+            _ => InfoError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for InfoError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => InfoError::AccountInactive,
-            "fatal_error" => InfoError::FatalError,
-            "invalid_arg_name" => InfoError::InvalidArgName,
-            "invalid_array_arg" => InfoError::InvalidArrayArg,
-            "invalid_auth" => InfoError::InvalidAuth,
-            "invalid_charset" => InfoError::InvalidCharset,
-            "invalid_form_data" => InfoError::InvalidFormData,
-            "invalid_json" => InfoError::InvalidJson,
-            "invalid_post_type" => InfoError::InvalidPostType,
-            "json_not_object" => InfoError::JsonNotObject,
-            "missing_post_type" => InfoError::MissingPostType,
-            "no_permission" => InfoError::NoPermission,
-            "not_authed" => InfoError::NotAuthed,
-            "not_found" => InfoError::NotFound,
-            "org_login_required" => InfoError::OrgLoginRequired,
-            "request_timeout" => InfoError::RequestTimeout,
-            "team_added_to_org" => InfoError::TeamAddedToOrg,
-            "token_revoked" => InfoError::TokenRevoked,
-            "upgrade_required" => InfoError::UpgradeRequired,
-            "user_is_bot" => InfoError::UserIsBot,
-            _ => InfoError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for InfoError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            InfoError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            InfoError::FatalError => write!(f, "Server returned error fatal_error"),
-            InfoError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            InfoError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            InfoError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            InfoError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            InfoError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            InfoError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            InfoError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            InfoError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            InfoError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            InfoError::NoPermission => write!(f, "Server returned error no_permission"),
-            InfoError::NotAuthed => write!(f, "Server returned error not_authed"),
-            InfoError::NotFound => write!(f, "Server returned error not_found"),
-            InfoError::OrgLoginRequired => write!(f, "Server returned error org_login_required"),
-            InfoError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            InfoError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            InfoError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            InfoError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
-            InfoError::UserIsBot => write!(f, "Server returned error user_is_bot"),
+            InfoError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            InfoError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            InfoError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            InfoError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            InfoError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            InfoError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            InfoError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            InfoError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            InfoError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            InfoError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            InfoError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            InfoError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            InfoError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            InfoError::NotFound(_) => write!(f, "Server returned error not_found"),
+            InfoError::OrgLoginRequired(_) => write!(f, "Server returned error org_login_required"),
+            InfoError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            InfoError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            InfoError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            InfoError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
+            InfoError::UserIsBot(_) => write!(f, "Server returned error user_is_bot"),
             InfoError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            InfoError::Unknown(ref s) => write!(f, "{}", s),
+            InfoError::Unknown(ref s, _) => write!(f, "{}", s),
             InfoError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -566,10 +960,76 @@ impl<E: Error + 'static> Error for InfoError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for InfoError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            InfoError::AccountInactive(_) => Some("account_inactive"),
+            InfoError::FatalError(_) => Some("fatal_error"),
+            InfoError::InvalidArgName(_) => Some("invalid_arg_name"),
+            InfoError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            InfoError::InvalidAuth(_) => Some("invalid_auth"),
+            InfoError::InvalidCharset(_) => Some("invalid_charset"),
+            InfoError::InvalidFormData(_) => Some("invalid_form_data"),
+            InfoError::InvalidJson(_) => Some("invalid_json"),
+            InfoError::InvalidPostType(_) => Some("invalid_post_type"),
+            InfoError::JsonNotObject(_) => Some("json_not_object"),
+            InfoError::MissingPostType(_) => Some("missing_post_type"),
+            InfoError::NoPermission(_) => Some("no_permission"),
+            InfoError::NotAuthed(_) => Some("not_authed"),
+            InfoError::NotFound(_) => Some("not_found"),
+            InfoError::OrgLoginRequired(_) => Some("org_login_required"),
+            InfoError::RequestTimeout(_) => Some("request_timeout"),
+            InfoError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            InfoError::TokenRevoked(_) => Some("token_revoked"),
+            InfoError::UpgradeRequired(_) => Some("upgrade_required"),
+            InfoError::UserIsBot(_) => Some("user_is_bot"),
+            InfoError::Unknown(ref s, _) => Some(s),
+            InfoError::MalformedResponse(..) | InfoError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            InfoError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            InfoError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            InfoError::AccountInactive(ref context)
+            | InfoError::FatalError(ref context)
+            | InfoError::InvalidArgName(ref context)
+            | InfoError::InvalidArrayArg(ref context)
+            | InfoError::InvalidAuth(ref context)
+            | InfoError::InvalidCharset(ref context)
+            | InfoError::InvalidFormData(ref context)
+            | InfoError::InvalidJson(ref context)
+            | InfoError::InvalidPostType(ref context)
+            | InfoError::JsonNotObject(ref context)
+            | InfoError::MissingPostType(ref context)
+            | InfoError::NoPermission(ref context)
+            | InfoError::NotAuthed(ref context)
+            | InfoError::NotFound(ref context)
+            | InfoError::OrgLoginRequired(ref context)
+            | InfoError::RequestTimeout(ref context)
+            | InfoError::TeamAddedToOrg(ref context)
+            | InfoError::TokenRevoked(ref context)
+            | InfoError::UpgradeRequired(ref context)
+            | InfoError::UserIsBot(ref context)
+            | InfoError::Unknown(_, ref context) => Some(context),
+            InfoError::MalformedResponse(..) | InfoError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct ListRequest {}
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListRemindersInner {
     pub complete_ts: Option<u64>,
     pub creator: String,
@@ -577,16 +1037,22 @@ pub struct ListRemindersInner {
     pub recurring: bool,
     pub text: String,
     pub time: Option<u64>,
-    pub user: String,
+    pub user: Option<crate::UserId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
     pub reminders: Vec<ListRemindersInner>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
@@ -594,91 +1060,126 @@ impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(ListError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for ListResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum ListError<E: Error> {
-    AccountInactive,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    OrgLoginRequired,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequired,
-    UserIsBot,
+    AccountInactive(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserIsBot(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> ListError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => ListError::AccountInactive(context),
+            "fatal_error" => ListError::FatalError(context),
+            "invalid_arg_name" => ListError::InvalidArgName(context),
+            "invalid_array_arg" => ListError::InvalidArrayArg(context),
+            "invalid_auth" => ListError::InvalidAuth(context),
+            "invalid_charset" => ListError::InvalidCharset(context),
+            "invalid_form_data" => ListError::InvalidFormData(context),
+            "invalid_json" => ListError::InvalidJson(context),
+            "invalid_post_type" => ListError::InvalidPostType(context),
+            "json_not_object" => ListError::JsonNotObject(context),
+            "missing_post_type" => ListError::MissingPostType(context),
+            "no_permission" => ListError::NoPermission(context),
+            "not_authed" => ListError::NotAuthed(context),
+            "org_login_required" => ListError::OrgLoginRequired(context),
+            "request_timeout" => ListError::RequestTimeout(context),
+            "team_added_to_org" => ListError::TeamAddedToOrg(context),
+            "token_revoked" => ListError::TokenRevoked(context),
+            "upgrade_required" => ListError::UpgradeRequired(context),
+            "user_is_bot" => ListError::UserIsBot(context),
+            _ => ListError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for ListError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => ListError::AccountInactive,
-            "fatal_error" => ListError::FatalError,
-            "invalid_arg_name" => ListError::InvalidArgName,
-            "invalid_array_arg" => ListError::InvalidArrayArg,
-            "invalid_auth" => ListError::InvalidAuth,
-            "invalid_charset" => ListError::InvalidCharset,
-            "invalid_form_data" => ListError::InvalidFormData,
-            "invalid_json" => ListError::InvalidJson,
-            "invalid_post_type" => ListError::InvalidPostType,
-            "json_not_object" => ListError::JsonNotObject,
-            "missing_post_type" => ListError::MissingPostType,
-            "no_permission" => ListError::NoPermission,
-            "not_authed" => ListError::NotAuthed,
-            "org_login_required" => ListError::OrgLoginRequired,
-            "request_timeout" => ListError::RequestTimeout,
-            "team_added_to_org" => ListError::TeamAddedToOrg,
-            "token_revoked" => ListError::TokenRevoked,
-            "upgrade_required" => ListError::UpgradeRequired,
-            "user_is_bot" => ListError::UserIsBot,
-            _ => ListError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for ListError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            ListError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            ListError::FatalError => write!(f, "Server returned error fatal_error"),
-            ListError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            ListError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            ListError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            ListError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            ListError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            ListError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            ListError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            ListError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            ListError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            ListError::NoPermission => write!(f, "Server returned error no_permission"),
-            ListError::NotAuthed => write!(f, "Server returned error not_authed"),
-            ListError::OrgLoginRequired => write!(f, "Server returned error org_login_required"),
-            ListError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            ListError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            ListError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            ListError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
-            ListError::UserIsBot => write!(f, "Server returned error user_is_bot"),
+            ListError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            ListError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            ListError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            ListError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            ListError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            ListError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            ListError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            ListError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            ListError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            ListError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            ListError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            ListError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            ListError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            ListError::OrgLoginRequired(_) => write!(f, "Server returned error org_login_required"),
+            ListError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            ListError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            ListError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            ListError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
+            ListError::UserIsBot(_) => write!(f, "Server returned error user_is_bot"),
             ListError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            ListError::Unknown(ref s) => write!(f, "{}", s),
+            ListError::Unknown(ref s, _) => write!(f, "{}", s),
             ListError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -693,3 +1194,67 @@ impl<E: Error + 'static> Error for ListError<E> {
         }
     }
 }
+
+impl<E: Error + 'static> crate::MethodError for ListError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            ListError::AccountInactive(_) => Some("account_inactive"),
+            ListError::FatalError(_) => Some("fatal_error"),
+            ListError::InvalidArgName(_) => Some("invalid_arg_name"),
+            ListError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            ListError::InvalidAuth(_) => Some("invalid_auth"),
+            ListError::InvalidCharset(_) => Some("invalid_charset"),
+            ListError::InvalidFormData(_) => Some("invalid_form_data"),
+            ListError::InvalidJson(_) => Some("invalid_json"),
+            ListError::InvalidPostType(_) => Some("invalid_post_type"),
+            ListError::JsonNotObject(_) => Some("json_not_object"),
+            ListError::MissingPostType(_) => Some("missing_post_type"),
+            ListError::NoPermission(_) => Some("no_permission"),
+            ListError::NotAuthed(_) => Some("not_authed"),
+            ListError::OrgLoginRequired(_) => Some("org_login_required"),
+            ListError::RequestTimeout(_) => Some("request_timeout"),
+            ListError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            ListError::TokenRevoked(_) => Some("token_revoked"),
+            ListError::UpgradeRequired(_) => Some("upgrade_required"),
+            ListError::UserIsBot(_) => Some("user_is_bot"),
+            ListError::Unknown(ref s, _) => Some(s),
+            ListError::MalformedResponse(..) | ListError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            ListError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            ListError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            ListError::AccountInactive(ref context)
+            | ListError::FatalError(ref context)
+            | ListError::InvalidArgName(ref context)
+            | ListError::InvalidArrayArg(ref context)
+            | ListError::InvalidAuth(ref context)
+            | ListError::InvalidCharset(ref context)
+            | ListError::InvalidFormData(ref context)
+            | ListError::InvalidJson(ref context)
+            | ListError::InvalidPostType(ref context)
+            | ListError::JsonNotObject(ref context)
+            | ListError::MissingPostType(ref context)
+            | ListError::NoPermission(ref context)
+            | ListError::NotAuthed(ref context)
+            | ListError::OrgLoginRequired(ref context)
+            | ListError::RequestTimeout(ref context)
+            | ListError::TeamAddedToOrg(ref context)
+            | ListError::TokenRevoked(ref context)
+            | ListError::UpgradeRequired(ref context)
+            | ListError::UserIsBot(ref context)
+            | ListError::Unknown(_, ref context) => Some(context),
+            ListError::MalformedResponse(..) | ListError::Client(_) => None,
+        }
+    }
+}
@@ -29,28 +29,34 @@ pub struct ConnectRequest {
     pub presence_sub: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ConnectSelfInner {
-    pub id: String,
+    pub id: crate::UserId,
     pub name: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ConnectTeamInner {
     pub domain: String,
-    pub id: String,
+    pub id: crate::TeamId,
     pub name: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ConnectResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
     pub r#_self: ConnectSelfInner,
     pub team: ConnectTeamInner,
     pub url: String,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<ConnectResponse, ConnectError<E>>> for ConnectResponse {
@@ -58,85 +64,128 @@ impl<E: Error> Into<Result<ConnectResponse, ConnectError<E>>> for ConnectRespons
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(ConnectError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for ConnectResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum ConnectError<E: Error> {
-    AccountInactive,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequired,
+    AccountInactive(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> ConnectError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => ConnectError::AccountInactive(context),
+            "fatal_error" => ConnectError::FatalError(context),
+            "invalid_arg_name" => ConnectError::InvalidArgName(context),
+            "invalid_array_arg" => ConnectError::InvalidArrayArg(context),
+            "invalid_auth" => ConnectError::InvalidAuth(context),
+            "invalid_charset" => ConnectError::InvalidCharset(context),
+            "invalid_form_data" => ConnectError::InvalidFormData(context),
+            "invalid_json" => ConnectError::InvalidJson(context),
+            "invalid_post_type" => ConnectError::InvalidPostType(context),
+            "json_not_object" => ConnectError::JsonNotObject(context),
+            "missing_post_type" => ConnectError::MissingPostType(context),
+            "no_permission" => ConnectError::NoPermission(context),
+            "not_authed" => ConnectError::NotAuthed(context),
+            "request_timeout" => ConnectError::RequestTimeout(context),
+            "team_added_to_org" => ConnectError::TeamAddedToOrg(context),
+            "token_revoked" => ConnectError::TokenRevoked(context),
+            "upgrade_required" => ConnectError::UpgradeRequired(context),
+            _ => ConnectError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for ConnectError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => ConnectError::AccountInactive,
-            "fatal_error" => ConnectError::FatalError,
-            "invalid_arg_name" => ConnectError::InvalidArgName,
-            "invalid_array_arg" => ConnectError::InvalidArrayArg,
-            "invalid_auth" => ConnectError::InvalidAuth,
-            "invalid_charset" => ConnectError::InvalidCharset,
-            "invalid_form_data" => ConnectError::InvalidFormData,
-            "invalid_json" => ConnectError::InvalidJson,
-            "invalid_post_type" => ConnectError::InvalidPostType,
-            "json_not_object" => ConnectError::JsonNotObject,
-            "missing_post_type" => ConnectError::MissingPostType,
-            "no_permission" => ConnectError::NoPermission,
-            "not_authed" => ConnectError::NotAuthed,
-            "request_timeout" => ConnectError::RequestTimeout,
-            "team_added_to_org" => ConnectError::TeamAddedToOrg,
-            "token_revoked" => ConnectError::TokenRevoked,
-            "upgrade_required" => ConnectError::UpgradeRequired,
-            _ => ConnectError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for ConnectError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            ConnectError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            ConnectError::FatalError => write!(f, "Server returned error fatal_error"),
-            ConnectError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            ConnectError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            ConnectError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            ConnectError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            ConnectError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            ConnectError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            ConnectError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            ConnectError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            ConnectError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            ConnectError::NoPermission => write!(f, "Server returned error no_permission"),
-            ConnectError::NotAuthed => write!(f, "Server returned error not_authed"),
-            ConnectError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            ConnectError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            ConnectError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            ConnectError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
+            ConnectError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            ConnectError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            ConnectError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            ConnectError::InvalidArrayArg(_) => {
+                write!(f, "Server returned error invalid_array_arg")
+            }
+            ConnectError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            ConnectError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            ConnectError::InvalidFormData(_) => {
+                write!(f, "Server returned error invalid_form_data")
+            }
+            ConnectError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            ConnectError::InvalidPostType(_) => {
+                write!(f, "Server returned error invalid_post_type")
+            }
+            ConnectError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            ConnectError::MissingPostType(_) => {
+                write!(f, "Server returned error missing_post_type")
+            }
+            ConnectError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            ConnectError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            ConnectError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            ConnectError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            ConnectError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            ConnectError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
             ConnectError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            ConnectError::Unknown(ref s) => write!(f, "{}", s),
+            ConnectError::Unknown(ref s, _) => write!(f, "{}", s),
             ConnectError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -151,3 +200,63 @@ impl<E: Error + 'static> Error for ConnectError<E> {
         }
     }
 }
+
+impl<E: Error + 'static> crate::MethodError for ConnectError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            ConnectError::AccountInactive(_) => Some("account_inactive"),
+            ConnectError::FatalError(_) => Some("fatal_error"),
+            ConnectError::InvalidArgName(_) => Some("invalid_arg_name"),
+            ConnectError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            ConnectError::InvalidAuth(_) => Some("invalid_auth"),
+            ConnectError::InvalidCharset(_) => Some("invalid_charset"),
+            ConnectError::InvalidFormData(_) => Some("invalid_form_data"),
+            ConnectError::InvalidJson(_) => Some("invalid_json"),
+            ConnectError::InvalidPostType(_) => Some("invalid_post_type"),
+            ConnectError::JsonNotObject(_) => Some("json_not_object"),
+            ConnectError::MissingPostType(_) => Some("missing_post_type"),
+            ConnectError::NoPermission(_) => Some("no_permission"),
+            ConnectError::NotAuthed(_) => Some("not_authed"),
+            ConnectError::RequestTimeout(_) => Some("request_timeout"),
+            ConnectError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            ConnectError::TokenRevoked(_) => Some("token_revoked"),
+            ConnectError::UpgradeRequired(_) => Some("upgrade_required"),
+            ConnectError::Unknown(ref s, _) => Some(s),
+            ConnectError::MalformedResponse(..) | ConnectError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            ConnectError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            ConnectError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            ConnectError::AccountInactive(ref context)
+            | ConnectError::FatalError(ref context)
+            | ConnectError::InvalidArgName(ref context)
+            | ConnectError::InvalidArrayArg(ref context)
+            | ConnectError::InvalidAuth(ref context)
+            | ConnectError::InvalidCharset(ref context)
+            | ConnectError::InvalidFormData(ref context)
+            | ConnectError::InvalidJson(ref context)
+            | ConnectError::InvalidPostType(ref context)
+            | ConnectError::JsonNotObject(ref context)
+            | ConnectError::MissingPostType(ref context)
+            | ConnectError::NoPermission(ref context)
+            | ConnectError::NotAuthed(ref context)
+            | ConnectError::RequestTimeout(ref context)
+            | ConnectError::TeamAddedToOrg(ref context)
+            | ConnectError::TokenRevoked(ref context)
+            | ConnectError::UpgradeRequired(ref context)
+            | ConnectError::Unknown(_, ref context) => Some(context),
+            ConnectError::MalformedResponse(..) | ConnectError::Client(_) => None,
+        }
+    }
+}
@@ -36,10 +36,39 @@ pub struct MessagesRequest<'a> {
     pub sort_dir: Option<Cow<'a, str>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl crate::pagination::PageRequest for MessagesRequest<'_> {
+    fn set_page(&mut self, page: u64) {
+        self.page = Some(page);
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MessagesPagingInner {
+    pub count: Option<u64>,
+    pub page: u64,
+    pub pages: Option<u64>,
+    pub total: u64,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MessagesMessagesInner {
+    pub matches: Vec<serde_json::Value>,
+    pub paging: MessagesPagingInner,
+    pub total: u64,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessagesResponse {
+    error: Option<String>,
+    pub messages: MessagesMessagesInner,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<MessagesResponse, MessagesError<E>>> for MessagesResponse {
@@ -47,28 +76,77 @@ impl<E: Error> Into<Result<MessagesResponse, MessagesError<E>>> for MessagesResp
         if self.ok {
             Ok(self)
         } else {
-            Err(MessagesError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(MessagesError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for MessagesResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
+impl crate::pagination::NumberedPage for MessagesResponse {
+    type Item = serde_json::Value;
+
+    fn page(&self) -> Option<u64> {
+        Some(self.messages.paging.page)
+    }
+
+    fn pages(&self) -> Option<u64> {
+        self.messages.paging.pages
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.messages.matches
+    }
+}
+
 #[derive(Debug)]
 pub enum MessagesError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> MessagesError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => MessagesError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for MessagesError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => MessagesError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -76,7 +154,7 @@ impl<E: Error> fmt::Display for MessagesError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             MessagesError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            MessagesError::Unknown(ref s) => write!(f, "{}", s),
+            MessagesError::Unknown(ref s, _) => write!(f, "{}", s),
             MessagesError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -91,3 +169,29 @@ impl<E: Error + 'static> Error for MessagesError<E> {
         }
     }
 }
+
+impl<E: Error + 'static> crate::MethodError for MessagesError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            MessagesError::Unknown(ref s, _) => Some(s),
+            MessagesError::MalformedResponse(..) | MessagesError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            MessagesError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            MessagesError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            MessagesError::Unknown(_, ref context) => Some(context),
+            MessagesError::MalformedResponse(..) | MessagesError::Client(_) => None,
+        }
+    }
+}
@@ -24,21 +24,27 @@ use std::fmt;
 #[derive(Clone, Default, Debug)]
 pub struct AddRequest<'a> {
     /// Channel to add star to, or channel where the message to add star to was posted (used with `timestamp`).
-    pub channel: Option<Cow<'a, str>>,
+    pub channel: Option<crate::ChannelId>,
     /// File to add star to.
-    pub file: Option<Cow<'a, str>>,
+    pub file: Option<crate::FileId>,
     /// File comment to add star to.
     pub file_comment: Option<Cow<'a, str>>,
     /// Timestamp of the message to add star to.
-    pub timestamp: Option<Cow<'a, str>>,
+    pub timestamp: Option<crate::Timestamp>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AddResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<AddResponse, AddError<E>>> for AddResponse {
@@ -46,111 +52,146 @@ impl<E: Error> Into<Result<AddResponse, AddError<E>>> for AddResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(AddError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for AddResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum AddError<E: Error> {
-    AccountInactive,
-    AlreadyStarred,
-    BadTimestamp,
-    ChannelNotFound,
-    FatalError,
-    FileCommentNotFound,
-    FileNotFound,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MessageNotFound,
-    MissingPostType,
-    NoItemSpecified,
-    NoPermission,
-    NotAuthed,
-    OrgLoginRequired,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequired,
+    AccountInactive(crate::ErrorContext),
+    AlreadyStarred(crate::ErrorContext),
+    BadTimestamp(crate::ErrorContext),
+    ChannelNotFound(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    FileCommentNotFound(crate::ErrorContext),
+    FileNotFound(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MessageNotFound(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoItemSpecified(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> AddError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => AddError::AccountInactive(context),
+            "already_starred" => AddError::AlreadyStarred(context),
+            "bad_timestamp" => AddError::BadTimestamp(context),
+            "channel_not_found" => AddError::ChannelNotFound(context),
+            "fatal_error" => AddError::FatalError(context),
+            "file_comment_not_found" => AddError::FileCommentNotFound(context),
+            "file_not_found" => AddError::FileNotFound(context),
+            "invalid_arg_name" => AddError::InvalidArgName(context),
+            "invalid_array_arg" => AddError::InvalidArrayArg(context),
+            "invalid_auth" => AddError::InvalidAuth(context),
+            "invalid_charset" => AddError::InvalidCharset(context),
+            "invalid_form_data" => AddError::InvalidFormData(context),
+            "invalid_json" => AddError::InvalidJson(context),
+            "invalid_post_type" => AddError::InvalidPostType(context),
+            "json_not_object" => AddError::JsonNotObject(context),
+            "message_not_found" => AddError::MessageNotFound(context),
+            "missing_post_type" => AddError::MissingPostType(context),
+            "no_item_specified" => AddError::NoItemSpecified(context),
+            "no_permission" => AddError::NoPermission(context),
+            "not_authed" => AddError::NotAuthed(context),
+            "org_login_required" => AddError::OrgLoginRequired(context),
+            "request_timeout" => AddError::RequestTimeout(context),
+            "team_added_to_org" => AddError::TeamAddedToOrg(context),
+            "token_revoked" => AddError::TokenRevoked(context),
+            "upgrade_required" => AddError::UpgradeRequired(context),
+            _ => AddError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for AddError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => AddError::AccountInactive,
-            "already_starred" => AddError::AlreadyStarred,
-            "bad_timestamp" => AddError::BadTimestamp,
-            "channel_not_found" => AddError::ChannelNotFound,
-            "fatal_error" => AddError::FatalError,
-            "file_comment_not_found" => AddError::FileCommentNotFound,
-            "file_not_found" => AddError::FileNotFound,
-            "invalid_arg_name" => AddError::InvalidArgName,
-            "invalid_array_arg" => AddError::InvalidArrayArg,
-            "invalid_auth" => AddError::InvalidAuth,
-            "invalid_charset" => AddError::InvalidCharset,
-            "invalid_form_data" => AddError::InvalidFormData,
-            "invalid_json" => AddError::InvalidJson,
-            "invalid_post_type" => AddError::InvalidPostType,
-            "json_not_object" => AddError::JsonNotObject,
-            "message_not_found" => AddError::MessageNotFound,
-            "missing_post_type" => AddError::MissingPostType,
-            "no_item_specified" => AddError::NoItemSpecified,
-            "no_permission" => AddError::NoPermission,
-            "not_authed" => AddError::NotAuthed,
-            "org_login_required" => AddError::OrgLoginRequired,
-            "request_timeout" => AddError::RequestTimeout,
-            "team_added_to_org" => AddError::TeamAddedToOrg,
-            "token_revoked" => AddError::TokenRevoked,
-            "upgrade_required" => AddError::UpgradeRequired,
-            _ => AddError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for AddError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            AddError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            AddError::AlreadyStarred => write!(f, "Server returned error already_starred"),
-            AddError::BadTimestamp => write!(f, "Server returned error bad_timestamp"),
-            AddError::ChannelNotFound => write!(f, "Server returned error channel_not_found"),
-            AddError::FatalError => write!(f, "Server returned error fatal_error"),
-            AddError::FileCommentNotFound => {
+            AddError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            AddError::AlreadyStarred(_) => write!(f, "Server returned error already_starred"),
+            AddError::BadTimestamp(_) => write!(f, "Server returned error bad_timestamp"),
+            AddError::ChannelNotFound(_) => write!(f, "Server returned error channel_not_found"),
+            AddError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            AddError::FileCommentNotFound(_) => {
                 write!(f, "Server returned error file_comment_not_found")
             }
-            AddError::FileNotFound => write!(f, "Server returned error file_not_found"),
-            AddError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            AddError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            AddError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            AddError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            AddError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            AddError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            AddError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            AddError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            AddError::MessageNotFound => write!(f, "Server returned error message_not_found"),
-            AddError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            AddError::NoItemSpecified => write!(f, "Server returned error no_item_specified"),
-            AddError::NoPermission => write!(f, "Server returned error no_permission"),
-            AddError::NotAuthed => write!(f, "Server returned error not_authed"),
-            AddError::OrgLoginRequired => write!(f, "Server returned error org_login_required"),
-            AddError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            AddError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            AddError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            AddError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
+            AddError::FileNotFound(_) => write!(f, "Server returned error file_not_found"),
+            AddError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            AddError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            AddError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            AddError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            AddError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            AddError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            AddError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            AddError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            AddError::MessageNotFound(_) => write!(f, "Server returned error message_not_found"),
+            AddError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            AddError::NoItemSpecified(_) => write!(f, "Server returned error no_item_specified"),
+            AddError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            AddError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            AddError::OrgLoginRequired(_) => write!(f, "Server returned error org_login_required"),
+            AddError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            AddError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            AddError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            AddError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
             AddError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            AddError::Unknown(ref s) => write!(f, "{}", s),
+            AddError::Unknown(ref s, _) => write!(f, "{}", s),
             AddError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -166,6 +207,82 @@ impl<E: Error + 'static> Error for AddError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for AddError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            AddError::AccountInactive(_) => Some("account_inactive"),
+            AddError::AlreadyStarred(_) => Some("already_starred"),
+            AddError::BadTimestamp(_) => Some("bad_timestamp"),
+            AddError::ChannelNotFound(_) => Some("channel_not_found"),
+            AddError::FatalError(_) => Some("fatal_error"),
+            AddError::FileCommentNotFound(_) => Some("file_comment_not_found"),
+            AddError::FileNotFound(_) => Some("file_not_found"),
+            AddError::InvalidArgName(_) => Some("invalid_arg_name"),
+            AddError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            AddError::InvalidAuth(_) => Some("invalid_auth"),
+            AddError::InvalidCharset(_) => Some("invalid_charset"),
+            AddError::InvalidFormData(_) => Some("invalid_form_data"),
+            AddError::InvalidJson(_) => Some("invalid_json"),
+            AddError::InvalidPostType(_) => Some("invalid_post_type"),
+            AddError::JsonNotObject(_) => Some("json_not_object"),
+            AddError::MessageNotFound(_) => Some("message_not_found"),
+            AddError::MissingPostType(_) => Some("missing_post_type"),
+            AddError::NoItemSpecified(_) => Some("no_item_specified"),
+            AddError::NoPermission(_) => Some("no_permission"),
+            AddError::NotAuthed(_) => Some("not_authed"),
+            AddError::OrgLoginRequired(_) => Some("org_login_required"),
+            AddError::RequestTimeout(_) => Some("request_timeout"),
+            AddError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            AddError::TokenRevoked(_) => Some("token_revoked"),
+            AddError::UpgradeRequired(_) => Some("upgrade_required"),
+            AddError::Unknown(ref s, _) => Some(s),
+            AddError::MalformedResponse(..) | AddError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            AddError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            AddError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            AddError::AccountInactive(ref context)
+            | AddError::AlreadyStarred(ref context)
+            | AddError::BadTimestamp(ref context)
+            | AddError::ChannelNotFound(ref context)
+            | AddError::FatalError(ref context)
+            | AddError::FileCommentNotFound(ref context)
+            | AddError::FileNotFound(ref context)
+            | AddError::InvalidArgName(ref context)
+            | AddError::InvalidArrayArg(ref context)
+            | AddError::InvalidAuth(ref context)
+            | AddError::InvalidCharset(ref context)
+            | AddError::InvalidFormData(ref context)
+            | AddError::InvalidJson(ref context)
+            | AddError::InvalidPostType(ref context)
+            | AddError::JsonNotObject(ref context)
+            | AddError::MessageNotFound(ref context)
+            | AddError::MissingPostType(ref context)
+            | AddError::NoItemSpecified(ref context)
+            | AddError::NoPermission(ref context)
+            | AddError::NotAuthed(ref context)
+            | AddError::OrgLoginRequired(ref context)
+            | AddError::RequestTimeout(ref context)
+            | AddError::TeamAddedToOrg(ref context)
+            | AddError::TokenRevoked(ref context)
+            | AddError::UpgradeRequired(ref context)
+            | AddError::Unknown(_, ref context) => Some(context),
+            AddError::MalformedResponse(..) | AddError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct ListRequest<'a> {
     pub count: Option<Cow<'a, str>>,
@@ -176,7 +293,13 @@ pub struct ListRequest<'a> {
     pub page: Option<Cow<'a, str>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl crate::pagination::PageRequest for ListRequest<'_> {
+    fn set_page(&mut self, page: u64) {
+        self.page = Some(page.to_string().into());
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListAttachmentsInner {
     pub fallback: Option<String>,
     pub id: u64,
@@ -186,37 +309,32 @@ pub struct ListAttachmentsInner {
     pub image_width: Option<u64>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct ListBlocksInner {
-    pub r#type: String,
-}
-
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListIconsInner {
     pub image_36: String,
     pub image_48: String,
     pub image_72: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListBotProfileInner {
-    pub app_id: String,
+    pub app_id: crate::AppId,
     pub deleted: bool,
     pub icons: ListIconsInner,
-    pub id: String,
+    pub id: crate::BotId,
     pub name: String,
-    pub team_id: String,
+    pub team_id: crate::TeamId,
     pub updated: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListReactionsInner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListCommentInner {
     pub comment: String,
     pub created: u64,
@@ -228,23 +346,23 @@ pub struct ListCommentInner {
     pub pinned_to: Option<Vec<String>>,
     pub reactions: Option<Vec<ListReactionsInner>>,
     pub timestamp: u64,
-    pub user: String,
+    pub user: crate::UserId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListReactions1Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListSharesInner {
     pub private: Option<serde_json::Value>,
     pub public: Option<serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListFileInner {
     pub channels: Option<Vec<String>>,
     pub comments_count: Option<u64>,
@@ -259,7 +377,7 @@ pub struct ListFileInner {
     pub filetype: Option<String>,
     pub groups: Option<Vec<String>>,
     pub has_rich_preview: Option<bool>,
-    pub id: Option<String>,
+    pub id: Option<crate::FileId>,
     pub image_exif_rotation: Option<u64>,
     pub ims: Option<Vec<String>>,
     pub is_external: Option<bool>,
@@ -313,25 +431,25 @@ pub struct ListFileInner {
     pub updated: Option<u64>,
     pub url_private: Option<String>,
     pub url_private_download: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListReactions2Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListShares1Inner {
     pub private: Option<serde_json::Value>,
     pub public: Option<serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListFilesInner {
     pub channels: Option<Vec<String>>,
     pub comments_count: Option<u64>,
@@ -346,7 +464,7 @@ pub struct ListFilesInner {
     pub filetype: Option<String>,
     pub groups: Option<Vec<String>>,
     pub has_rich_preview: Option<bool>,
-    pub id: Option<String>,
+    pub id: Option<crate::FileId>,
     pub image_exif_rotation: Option<u64>,
     pub ims: Option<Vec<String>>,
     pub is_external: Option<bool>,
@@ -400,25 +518,25 @@ pub struct ListFilesInner {
     pub updated: Option<u64>,
     pub url_private: Option<String>,
     pub url_private_download: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListIcons1Inner {
     pub emoji: Option<String>,
     pub image_64: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListReactions3Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListUserProfileInner {
     pub avatar_hash: String,
     pub display_name: String,
@@ -430,13 +548,13 @@ pub struct ListUserProfileInner {
     pub name: String,
     pub real_name: String,
     pub real_name_normalized: Option<String>,
-    pub team: String,
+    pub team: crate::TeamId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListMessageInner {
     pub attachments: Option<Vec<ListAttachmentsInner>>,
-    pub blocks: Option<Vec<ListBlocksInner>>,
+    pub blocks: Option<Vec<crate::blocks::Block>>,
     pub bot_id: Option<Vec<String>>,
     pub bot_profile: Option<ListBotProfileInner>,
     pub client_msg_id: Option<String>,
@@ -449,8 +567,8 @@ pub struct ListMessageInner {
     pub is_delayed_message: Option<bool>,
     pub is_intro: Option<bool>,
     pub is_starred: Option<bool>,
-    pub last_read: Option<String>,
-    pub latest_reply: Option<String>,
+    pub last_read: Option<crate::Timestamp>,
+    pub latest_reply: Option<crate::Timestamp>,
     pub name: Option<String>,
     pub old_name: Option<String>,
     pub parent_user_id: Option<String>,
@@ -464,29 +582,29 @@ pub struct ListMessageInner {
     pub source_team: Option<String>,
     pub subscribed: Option<bool>,
     pub subtype: Option<String>,
-    pub team: Option<String>,
+    pub team: Option<crate::TeamId>,
     pub text: String,
-    pub thread_ts: Option<String>,
+    pub thread_ts: Option<crate::Timestamp>,
     pub topic: Option<String>,
-    pub ts: String,
+    pub ts: crate::Timestamp,
     pub r#type: String,
     pub unread_count: Option<u64>,
     pub upload: Option<bool>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_profile: Option<ListUserProfileInner>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListItemsInner {
-    pub channel: String,
+    pub channel: crate::ChannelId,
     pub date_create: u64,
     pub message: ListMessageInner,
     pub r#type: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListPagingInner {
     pub count: Option<u64>,
     pub page: u64,
@@ -496,14 +614,20 @@ pub struct ListPagingInner {
     pub total: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponse {
     pub callstack: Option<String>,
     error: Option<String>,
-    pub items: Vec<Vec<ListItemsInner>>,
+    pub items: Vec<ListItemsInner>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
     pub paging: Option<ListPagingInner>,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
@@ -511,91 +635,142 @@ impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(ListError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for ListResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
+impl crate::pagination::NumberedPage for ListResponse {
+    type Item = ListItemsInner;
+
+    fn page(&self) -> Option<u64> {
+        self.paging.as_ref().map(|paging| paging.page)
+    }
+
+    fn pages(&self) -> Option<u64> {
+        self.paging.as_ref().and_then(|paging| paging.pages)
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.items
+    }
+}
+
 #[derive(Debug)]
 pub enum ListError<E: Error> {
-    AccountInactive,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    OrgLoginRequired,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequired,
-    UserIsBot,
+    AccountInactive(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserIsBot(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> ListError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => ListError::AccountInactive(context),
+            "fatal_error" => ListError::FatalError(context),
+            "invalid_arg_name" => ListError::InvalidArgName(context),
+            "invalid_array_arg" => ListError::InvalidArrayArg(context),
+            "invalid_auth" => ListError::InvalidAuth(context),
+            "invalid_charset" => ListError::InvalidCharset(context),
+            "invalid_form_data" => ListError::InvalidFormData(context),
+            "invalid_json" => ListError::InvalidJson(context),
+            "invalid_post_type" => ListError::InvalidPostType(context),
+            "json_not_object" => ListError::JsonNotObject(context),
+            "missing_post_type" => ListError::MissingPostType(context),
+            "no_permission" => ListError::NoPermission(context),
+            "not_authed" => ListError::NotAuthed(context),
+            "org_login_required" => ListError::OrgLoginRequired(context),
+            "request_timeout" => ListError::RequestTimeout(context),
+            "team_added_to_org" => ListError::TeamAddedToOrg(context),
+            "token_revoked" => ListError::TokenRevoked(context),
+            "upgrade_required" => ListError::UpgradeRequired(context),
+            "user_is_bot" => ListError::UserIsBot(context),
+            _ => ListError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for ListError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => ListError::AccountInactive,
-            "fatal_error" => ListError::FatalError,
-            "invalid_arg_name" => ListError::InvalidArgName,
-            "invalid_array_arg" => ListError::InvalidArrayArg,
-            "invalid_auth" => ListError::InvalidAuth,
-            "invalid_charset" => ListError::InvalidCharset,
-            "invalid_form_data" => ListError::InvalidFormData,
-            "invalid_json" => ListError::InvalidJson,
-            "invalid_post_type" => ListError::InvalidPostType,
-            "json_not_object" => ListError::JsonNotObject,
-            "missing_post_type" => ListError::MissingPostType,
-            "no_permission" => ListError::NoPermission,
-            "not_authed" => ListError::NotAuthed,
-            "org_login_required" => ListError::OrgLoginRequired,
-            "request_timeout" => ListError::RequestTimeout,
-            "team_added_to_org" => ListError::TeamAddedToOrg,
-            "token_revoked" => ListError::TokenRevoked,
-            "upgrade_required" => ListError::UpgradeRequired,
-            "user_is_bot" => ListError::UserIsBot,
-            _ => ListError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for ListError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            ListError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            ListError::FatalError => write!(f, "Server returned error fatal_error"),
-            ListError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            ListError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            ListError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            ListError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            ListError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            ListError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            ListError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            ListError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            ListError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            ListError::NoPermission => write!(f, "Server returned error no_permission"),
-            ListError::NotAuthed => write!(f, "Server returned error not_authed"),
-            ListError::OrgLoginRequired => write!(f, "Server returned error org_login_required"),
-            ListError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            ListError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            ListError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            ListError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
-            ListError::UserIsBot => write!(f, "Server returned error user_is_bot"),
+            ListError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            ListError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            ListError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            ListError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            ListError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            ListError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            ListError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            ListError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            ListError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            ListError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            ListError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            ListError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            ListError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            ListError::OrgLoginRequired(_) => write!(f, "Server returned error org_login_required"),
+            ListError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            ListError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            ListError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            ListError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
+            ListError::UserIsBot(_) => write!(f, "Server returned error user_is_bot"),
             ListError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            ListError::Unknown(ref s) => write!(f, "{}", s),
+            ListError::Unknown(ref s, _) => write!(f, "{}", s),
             ListError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -611,24 +786,94 @@ impl<E: Error + 'static> Error for ListError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for ListError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            ListError::AccountInactive(_) => Some("account_inactive"),
+            ListError::FatalError(_) => Some("fatal_error"),
+            ListError::InvalidArgName(_) => Some("invalid_arg_name"),
+            ListError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            ListError::InvalidAuth(_) => Some("invalid_auth"),
+            ListError::InvalidCharset(_) => Some("invalid_charset"),
+            ListError::InvalidFormData(_) => Some("invalid_form_data"),
+            ListError::InvalidJson(_) => Some("invalid_json"),
+            ListError::InvalidPostType(_) => Some("invalid_post_type"),
+            ListError::JsonNotObject(_) => Some("json_not_object"),
+            ListError::MissingPostType(_) => Some("missing_post_type"),
+            ListError::NoPermission(_) => Some("no_permission"),
+            ListError::NotAuthed(_) => Some("not_authed"),
+            ListError::OrgLoginRequired(_) => Some("org_login_required"),
+            ListError::RequestTimeout(_) => Some("request_timeout"),
+            ListError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            ListError::TokenRevoked(_) => Some("token_revoked"),
+            ListError::UpgradeRequired(_) => Some("upgrade_required"),
+            ListError::UserIsBot(_) => Some("user_is_bot"),
+            ListError::Unknown(ref s, _) => Some(s),
+            ListError::MalformedResponse(..) | ListError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            ListError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            ListError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            ListError::AccountInactive(ref context)
+            | ListError::FatalError(ref context)
+            | ListError::InvalidArgName(ref context)
+            | ListError::InvalidArrayArg(ref context)
+            | ListError::InvalidAuth(ref context)
+            | ListError::InvalidCharset(ref context)
+            | ListError::InvalidFormData(ref context)
+            | ListError::InvalidJson(ref context)
+            | ListError::InvalidPostType(ref context)
+            | ListError::JsonNotObject(ref context)
+            | ListError::MissingPostType(ref context)
+            | ListError::NoPermission(ref context)
+            | ListError::NotAuthed(ref context)
+            | ListError::OrgLoginRequired(ref context)
+            | ListError::RequestTimeout(ref context)
+            | ListError::TeamAddedToOrg(ref context)
+            | ListError::TokenRevoked(ref context)
+            | ListError::UpgradeRequired(ref context)
+            | ListError::UserIsBot(ref context)
+            | ListError::Unknown(_, ref context) => Some(context),
+            ListError::MalformedResponse(..) | ListError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct RemoveRequest<'a> {
     /// Channel to remove star from, or channel where the message to remove star from was posted (used with `timestamp`).
-    pub channel: Option<Cow<'a, str>>,
+    pub channel: Option<crate::ChannelId>,
     /// File to remove star from.
-    pub file: Option<Cow<'a, str>>,
+    pub file: Option<crate::FileId>,
     /// File comment to remove star from.
     pub file_comment: Option<Cow<'a, str>>,
     /// Timestamp of the message to remove star from.
-    pub timestamp: Option<Cow<'a, str>>,
+    pub timestamp: Option<crate::Timestamp>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RemoveResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<RemoveResponse, RemoveError<E>>> for RemoveResponse {
@@ -636,111 +881,148 @@ impl<E: Error> Into<Result<RemoveResponse, RemoveError<E>>> for RemoveResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(RemoveError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for RemoveResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum RemoveError<E: Error> {
-    AccountInactive,
-    BadTimestamp,
-    ChannelNotFound,
-    FatalError,
-    FileCommentNotFound,
-    FileNotFound,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MessageNotFound,
-    MissingPostType,
-    NoItemSpecified,
-    NoPermission,
-    NotAuthed,
-    NotStarred,
-    OrgLoginRequired,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequired,
+    AccountInactive(crate::ErrorContext),
+    BadTimestamp(crate::ErrorContext),
+    ChannelNotFound(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    FileCommentNotFound(crate::ErrorContext),
+    FileNotFound(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MessageNotFound(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoItemSpecified(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    NotStarred(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> RemoveError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => RemoveError::AccountInactive(context),
+            "bad_timestamp" => RemoveError::BadTimestamp(context),
+            "channel_not_found" => RemoveError::ChannelNotFound(context),
+            "fatal_error" => RemoveError::FatalError(context),
+            "file_comment_not_found" => RemoveError::FileCommentNotFound(context),
+            "file_not_found" => RemoveError::FileNotFound(context),
+            "invalid_arg_name" => RemoveError::InvalidArgName(context),
+            "invalid_array_arg" => RemoveError::InvalidArrayArg(context),
+            "invalid_auth" => RemoveError::InvalidAuth(context),
+            "invalid_charset" => RemoveError::InvalidCharset(context),
+            "invalid_form_data" => RemoveError::InvalidFormData(context),
+            "invalid_json" => RemoveError::InvalidJson(context),
+            "invalid_post_type" => RemoveError::InvalidPostType(context),
+            "json_not_object" => RemoveError::JsonNotObject(context),
+            "message_not_found" => RemoveError::MessageNotFound(context),
+            "missing_post_type" => RemoveError::MissingPostType(context),
+            "no_item_specified" => RemoveError::NoItemSpecified(context),
+            "no_permission" => RemoveError::NoPermission(context),
+            "not_authed" => RemoveError::NotAuthed(context),
+            "not_starred" => RemoveError::NotStarred(context),
+            "org_login_required" => RemoveError::OrgLoginRequired(context),
+            "request_timeout" => RemoveError::RequestTimeout(context),
+            "team_added_to_org" => RemoveError::TeamAddedToOrg(context),
+            "token_revoked" => RemoveError::TokenRevoked(context),
+            "upgrade_required" => RemoveError::UpgradeRequired(context),
+            _ => RemoveError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for RemoveError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => RemoveError::AccountInactive,
-            "bad_timestamp" => RemoveError::BadTimestamp,
-            "channel_not_found" => RemoveError::ChannelNotFound,
-            "fatal_error" => RemoveError::FatalError,
-            "file_comment_not_found" => RemoveError::FileCommentNotFound,
-            "file_not_found" => RemoveError::FileNotFound,
-            "invalid_arg_name" => RemoveError::InvalidArgName,
-            "invalid_array_arg" => RemoveError::InvalidArrayArg,
-            "invalid_auth" => RemoveError::InvalidAuth,
-            "invalid_charset" => RemoveError::InvalidCharset,
-            "invalid_form_data" => RemoveError::InvalidFormData,
-            "invalid_json" => RemoveError::InvalidJson,
-            "invalid_post_type" => RemoveError::InvalidPostType,
-            "json_not_object" => RemoveError::JsonNotObject,
-            "message_not_found" => RemoveError::MessageNotFound,
-            "missing_post_type" => RemoveError::MissingPostType,
-            "no_item_specified" => RemoveError::NoItemSpecified,
-            "no_permission" => RemoveError::NoPermission,
-            "not_authed" => RemoveError::NotAuthed,
-            "not_starred" => RemoveError::NotStarred,
-            "org_login_required" => RemoveError::OrgLoginRequired,
-            "request_timeout" => RemoveError::RequestTimeout,
-            "team_added_to_org" => RemoveError::TeamAddedToOrg,
-            "token_revoked" => RemoveError::TokenRevoked,
-            "upgrade_required" => RemoveError::UpgradeRequired,
-            _ => RemoveError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for RemoveError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            RemoveError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            RemoveError::BadTimestamp => write!(f, "Server returned error bad_timestamp"),
-            RemoveError::ChannelNotFound => write!(f, "Server returned error channel_not_found"),
-            RemoveError::FatalError => write!(f, "Server returned error fatal_error"),
-            RemoveError::FileCommentNotFound => {
+            RemoveError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            RemoveError::BadTimestamp(_) => write!(f, "Server returned error bad_timestamp"),
+            RemoveError::ChannelNotFound(_) => write!(f, "Server returned error channel_not_found"),
+            RemoveError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            RemoveError::FileCommentNotFound(_) => {
                 write!(f, "Server returned error file_comment_not_found")
             }
-            RemoveError::FileNotFound => write!(f, "Server returned error file_not_found"),
-            RemoveError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            RemoveError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            RemoveError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            RemoveError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            RemoveError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            RemoveError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            RemoveError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            RemoveError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            RemoveError::MessageNotFound => write!(f, "Server returned error message_not_found"),
-            RemoveError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            RemoveError::NoItemSpecified => write!(f, "Server returned error no_item_specified"),
-            RemoveError::NoPermission => write!(f, "Server returned error no_permission"),
-            RemoveError::NotAuthed => write!(f, "Server returned error not_authed"),
-            RemoveError::NotStarred => write!(f, "Server returned error not_starred"),
-            RemoveError::OrgLoginRequired => write!(f, "Server returned error org_login_required"),
-            RemoveError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            RemoveError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            RemoveError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            RemoveError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
+            RemoveError::FileNotFound(_) => write!(f, "Server returned error file_not_found"),
+            RemoveError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            RemoveError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            RemoveError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            RemoveError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            RemoveError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            RemoveError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            RemoveError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            RemoveError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            RemoveError::MessageNotFound(_) => write!(f, "Server returned error message_not_found"),
+            RemoveError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            RemoveError::NoItemSpecified(_) => write!(f, "Server returned error no_item_specified"),
+            RemoveError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            RemoveError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            RemoveError::NotStarred(_) => write!(f, "Server returned error not_starred"),
+            RemoveError::OrgLoginRequired(_) => {
+                write!(f, "Server returned error org_login_required")
+            }
+            RemoveError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            RemoveError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            RemoveError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            RemoveError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
             RemoveError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            RemoveError::Unknown(ref s) => write!(f, "{}", s),
+            RemoveError::Unknown(ref s, _) => write!(f, "{}", s),
             RemoveError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -755,3 +1037,79 @@ impl<E: Error + 'static> Error for RemoveError<E> {
         }
     }
 }
+
+impl<E: Error + 'static> crate::MethodError for RemoveError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            RemoveError::AccountInactive(_) => Some("account_inactive"),
+            RemoveError::BadTimestamp(_) => Some("bad_timestamp"),
+            RemoveError::ChannelNotFound(_) => Some("channel_not_found"),
+            RemoveError::FatalError(_) => Some("fatal_error"),
+            RemoveError::FileCommentNotFound(_) => Some("file_comment_not_found"),
+            RemoveError::FileNotFound(_) => Some("file_not_found"),
+            RemoveError::InvalidArgName(_) => Some("invalid_arg_name"),
+            RemoveError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            RemoveError::InvalidAuth(_) => Some("invalid_auth"),
+            RemoveError::InvalidCharset(_) => Some("invalid_charset"),
+            RemoveError::InvalidFormData(_) => Some("invalid_form_data"),
+            RemoveError::InvalidJson(_) => Some("invalid_json"),
+            RemoveError::InvalidPostType(_) => Some("invalid_post_type"),
+            RemoveError::JsonNotObject(_) => Some("json_not_object"),
+            RemoveError::MessageNotFound(_) => Some("message_not_found"),
+            RemoveError::MissingPostType(_) => Some("missing_post_type"),
+            RemoveError::NoItemSpecified(_) => Some("no_item_specified"),
+            RemoveError::NoPermission(_) => Some("no_permission"),
+            RemoveError::NotAuthed(_) => Some("not_authed"),
+            RemoveError::NotStarred(_) => Some("not_starred"),
+            RemoveError::OrgLoginRequired(_) => Some("org_login_required"),
+            RemoveError::RequestTimeout(_) => Some("request_timeout"),
+            RemoveError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            RemoveError::TokenRevoked(_) => Some("token_revoked"),
+            RemoveError::UpgradeRequired(_) => Some("upgrade_required"),
+            RemoveError::Unknown(ref s, _) => Some(s),
+            RemoveError::MalformedResponse(..) | RemoveError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            RemoveError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            RemoveError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            RemoveError::AccountInactive(ref context)
+            | RemoveError::BadTimestamp(ref context)
+            | RemoveError::ChannelNotFound(ref context)
+            | RemoveError::FatalError(ref context)
+            | RemoveError::FileCommentNotFound(ref context)
+            | RemoveError::FileNotFound(ref context)
+            | RemoveError::InvalidArgName(ref context)
+            | RemoveError::InvalidArrayArg(ref context)
+            | RemoveError::InvalidAuth(ref context)
+            | RemoveError::InvalidCharset(ref context)
+            | RemoveError::InvalidFormData(ref context)
+            | RemoveError::InvalidJson(ref context)
+            | RemoveError::InvalidPostType(ref context)
+            | RemoveError::JsonNotObject(ref context)
+            | RemoveError::MessageNotFound(ref context)
+            | RemoveError::MissingPostType(ref context)
+            | RemoveError::NoItemSpecified(ref context)
+            | RemoveError::NoPermission(ref context)
+            | RemoveError::NotAuthed(ref context)
+            | RemoveError::NotStarred(ref context)
+            | RemoveError::OrgLoginRequired(ref context)
+            | RemoveError::RequestTimeout(ref context)
+            | RemoveError::TeamAddedToOrg(ref context)
+            | RemoveError::TokenRevoked(ref context)
+            | RemoveError::UpgradeRequired(ref context)
+            | RemoveError::Unknown(_, ref context) => Some(context),
+            RemoveError::MalformedResponse(..) | RemoveError::Client(_) => None,
+        }
+    }
+}
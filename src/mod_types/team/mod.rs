@@ -26,12 +26,22 @@ use std::fmt;
 #[derive(Clone, Default, Debug)]
 pub struct AccessLogsRequest<'a> {
     /// End of time range of logs to include in results (inclusive).
-    pub before: Option<Cow<'a, str>>,
+    pub before: Option<crate::Timestamp>,
     pub count: Option<Cow<'a, str>>,
     pub page: Option<Cow<'a, str>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl crate::pagination::PageRequest for AccessLogsRequest<'_> {
+    fn set_page(&mut self, page: u64) {
+        self.page = Some(page.to_string().into());
+    }
+
+    fn set_before(&mut self, before: crate::Timestamp) {
+        self.before = Some(before);
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AccessLogsLoginsInner {
     pub count: u64,
     pub country: String,
@@ -41,11 +51,11 @@ pub struct AccessLogsLoginsInner {
     pub isp: String,
     pub region: String,
     pub user_agent: String,
-    pub user_id: String,
+    pub user_id: crate::UserId,
     pub username: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AccessLogsPagingInner {
     pub count: Option<u64>,
     pub page: u64,
@@ -55,14 +65,20 @@ pub struct AccessLogsPagingInner {
     pub total: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AccessLogsResponse {
     pub callstack: Option<String>,
     error: Option<String>,
     pub logins: Vec<AccessLogsLoginsInner>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
     pub paging: AccessLogsPagingInner,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<AccessLogsResponse, AccessLogsError<E>>> for AccessLogsResponse {
@@ -70,109 +86,180 @@ impl<E: Error> Into<Result<AccessLogsResponse, AccessLogsError<E>>> for AccessLo
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(AccessLogsError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for AccessLogsResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
+impl crate::pagination::NumberedPage for AccessLogsResponse {
+    type Item = AccessLogsLoginsInner;
+
+    fn page(&self) -> Option<u64> {
+        Some(self.paging.page)
+    }
+
+    fn pages(&self) -> Option<u64> {
+        self.paging.pages
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.logins
+    }
+
+    fn continue_before(&self) -> Option<crate::Timestamp> {
+        self.logins
+            .iter()
+            .map(|item| item.date_first)
+            .min()
+            .map(crate::Timestamp::from)
+    }
+}
+
 #[derive(Debug)]
 pub enum AccessLogsError<E: Error> {
-    AccountInactive,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    OrgLoginRequired,
-    OverPaginationLimit,
-    PaidOnly,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequired,
-    UserIsBot,
+    AccountInactive(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    OverPaginationLimit(crate::ErrorContext),
+    PaidOnly(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserIsBot(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> AccessLogsError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => AccessLogsError::AccountInactive(context),
+            "fatal_error" => AccessLogsError::FatalError(context),
+            "invalid_arg_name" => AccessLogsError::InvalidArgName(context),
+            "invalid_array_arg" => AccessLogsError::InvalidArrayArg(context),
+            "invalid_auth" => AccessLogsError::InvalidAuth(context),
+            "invalid_charset" => AccessLogsError::InvalidCharset(context),
+            "invalid_form_data" => AccessLogsError::InvalidFormData(context),
+            "invalid_json" => AccessLogsError::InvalidJson(context),
+            "invalid_post_type" => AccessLogsError::InvalidPostType(context),
+            "json_not_object" => AccessLogsError::JsonNotObject(context),
+            "missing_post_type" => AccessLogsError::MissingPostType(context),
+            "no_permission" => AccessLogsError::NoPermission(context),
+            "not_authed" => AccessLogsError::NotAuthed(context),
+            "org_login_required" => AccessLogsError::OrgLoginRequired(context),
+            "over_pagination_limit" => AccessLogsError::OverPaginationLimit(context),
+            "paid_only" => AccessLogsError::PaidOnly(context),
+            "request_timeout" => AccessLogsError::RequestTimeout(context),
+            "team_added_to_org" => AccessLogsError::TeamAddedToOrg(context),
+            "token_revoked" => AccessLogsError::TokenRevoked(context),
+            "upgrade_required" => AccessLogsError::UpgradeRequired(context),
+            "user_is_bot" => AccessLogsError::UserIsBot(context),
+            _ => AccessLogsError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for AccessLogsError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => AccessLogsError::AccountInactive,
-            "fatal_error" => AccessLogsError::FatalError,
-            "invalid_arg_name" => AccessLogsError::InvalidArgName,
-            "invalid_array_arg" => AccessLogsError::InvalidArrayArg,
-            "invalid_auth" => AccessLogsError::InvalidAuth,
-            "invalid_charset" => AccessLogsError::InvalidCharset,
-            "invalid_form_data" => AccessLogsError::InvalidFormData,
-            "invalid_json" => AccessLogsError::InvalidJson,
-            "invalid_post_type" => AccessLogsError::InvalidPostType,
-            "json_not_object" => AccessLogsError::JsonNotObject,
-            "missing_post_type" => AccessLogsError::MissingPostType,
-            "no_permission" => AccessLogsError::NoPermission,
-            "not_authed" => AccessLogsError::NotAuthed,
-            "org_login_required" => AccessLogsError::OrgLoginRequired,
-            "over_pagination_limit" => AccessLogsError::OverPaginationLimit,
-            "paid_only" => AccessLogsError::PaidOnly,
-            "request_timeout" => AccessLogsError::RequestTimeout,
-            "team_added_to_org" => AccessLogsError::TeamAddedToOrg,
-            "token_revoked" => AccessLogsError::TokenRevoked,
-            "upgrade_required" => AccessLogsError::UpgradeRequired,
-            "user_is_bot" => AccessLogsError::UserIsBot,
-            _ => AccessLogsError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for AccessLogsError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            AccessLogsError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            AccessLogsError::FatalError => write!(f, "Server returned error fatal_error"),
-            AccessLogsError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            AccessLogsError::InvalidArrayArg => {
+            AccessLogsError::AccountInactive(_) => {
+                write!(f, "Server returned error account_inactive")
+            }
+            AccessLogsError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            AccessLogsError::InvalidArgName(_) => {
+                write!(f, "Server returned error invalid_arg_name")
+            }
+            AccessLogsError::InvalidArrayArg(_) => {
                 write!(f, "Server returned error invalid_array_arg")
             }
-            AccessLogsError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            AccessLogsError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            AccessLogsError::InvalidFormData => {
+            AccessLogsError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            AccessLogsError::InvalidCharset(_) => {
+                write!(f, "Server returned error invalid_charset")
+            }
+            AccessLogsError::InvalidFormData(_) => {
                 write!(f, "Server returned error invalid_form_data")
             }
-            AccessLogsError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            AccessLogsError::InvalidPostType => {
+            AccessLogsError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            AccessLogsError::InvalidPostType(_) => {
                 write!(f, "Server returned error invalid_post_type")
             }
-            AccessLogsError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            AccessLogsError::MissingPostType => {
+            AccessLogsError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            AccessLogsError::MissingPostType(_) => {
                 write!(f, "Server returned error missing_post_type")
             }
-            AccessLogsError::NoPermission => write!(f, "Server returned error no_permission"),
-            AccessLogsError::NotAuthed => write!(f, "Server returned error not_authed"),
-            AccessLogsError::OrgLoginRequired => {
+            AccessLogsError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            AccessLogsError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            AccessLogsError::OrgLoginRequired(_) => {
                 write!(f, "Server returned error org_login_required")
             }
-            AccessLogsError::OverPaginationLimit => {
+            AccessLogsError::OverPaginationLimit(_) => {
                 write!(f, "Server returned error over_pagination_limit")
             }
-            AccessLogsError::PaidOnly => write!(f, "Server returned error paid_only"),
-            AccessLogsError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            AccessLogsError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            AccessLogsError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            AccessLogsError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
-            AccessLogsError::UserIsBot => write!(f, "Server returned error user_is_bot"),
+            AccessLogsError::PaidOnly(_) => write!(f, "Server returned error paid_only"),
+            AccessLogsError::RequestTimeout(_) => {
+                write!(f, "Server returned error request_timeout")
+            }
+            AccessLogsError::TeamAddedToOrg(_) => {
+                write!(f, "Server returned error team_added_to_org")
+            }
+            AccessLogsError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            AccessLogsError::UpgradeRequired(_) => {
+                write!(f, "Server returned error upgrade_required")
+            }
+            AccessLogsError::UserIsBot(_) => write!(f, "Server returned error user_is_bot"),
             AccessLogsError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            AccessLogsError::Unknown(ref s) => write!(f, "{}", s),
+            AccessLogsError::Unknown(ref s, _) => write!(f, "{}", s),
             AccessLogsError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -188,16 +275,91 @@ impl<E: Error + 'static> Error for AccessLogsError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for AccessLogsError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            AccessLogsError::AccountInactive(_) => Some("account_inactive"),
+            AccessLogsError::FatalError(_) => Some("fatal_error"),
+            AccessLogsError::InvalidArgName(_) => Some("invalid_arg_name"),
+            AccessLogsError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            AccessLogsError::InvalidAuth(_) => Some("invalid_auth"),
+            AccessLogsError::InvalidCharset(_) => Some("invalid_charset"),
+            AccessLogsError::InvalidFormData(_) => Some("invalid_form_data"),
+            AccessLogsError::InvalidJson(_) => Some("invalid_json"),
+            AccessLogsError::InvalidPostType(_) => Some("invalid_post_type"),
+            AccessLogsError::JsonNotObject(_) => Some("json_not_object"),
+            AccessLogsError::MissingPostType(_) => Some("missing_post_type"),
+            AccessLogsError::NoPermission(_) => Some("no_permission"),
+            AccessLogsError::NotAuthed(_) => Some("not_authed"),
+            AccessLogsError::OrgLoginRequired(_) => Some("org_login_required"),
+            AccessLogsError::OverPaginationLimit(_) => Some("over_pagination_limit"),
+            AccessLogsError::PaidOnly(_) => Some("paid_only"),
+            AccessLogsError::RequestTimeout(_) => Some("request_timeout"),
+            AccessLogsError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            AccessLogsError::TokenRevoked(_) => Some("token_revoked"),
+            AccessLogsError::UpgradeRequired(_) => Some("upgrade_required"),
+            AccessLogsError::UserIsBot(_) => Some("user_is_bot"),
+            AccessLogsError::Unknown(ref s, _) => Some(s),
+            AccessLogsError::MalformedResponse(..) | AccessLogsError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            AccessLogsError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            AccessLogsError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            AccessLogsError::AccountInactive(ref context)
+            | AccessLogsError::FatalError(ref context)
+            | AccessLogsError::InvalidArgName(ref context)
+            | AccessLogsError::InvalidArrayArg(ref context)
+            | AccessLogsError::InvalidAuth(ref context)
+            | AccessLogsError::InvalidCharset(ref context)
+            | AccessLogsError::InvalidFormData(ref context)
+            | AccessLogsError::InvalidJson(ref context)
+            | AccessLogsError::InvalidPostType(ref context)
+            | AccessLogsError::JsonNotObject(ref context)
+            | AccessLogsError::MissingPostType(ref context)
+            | AccessLogsError::NoPermission(ref context)
+            | AccessLogsError::NotAuthed(ref context)
+            | AccessLogsError::OrgLoginRequired(ref context)
+            | AccessLogsError::OverPaginationLimit(ref context)
+            | AccessLogsError::PaidOnly(ref context)
+            | AccessLogsError::RequestTimeout(ref context)
+            | AccessLogsError::TeamAddedToOrg(ref context)
+            | AccessLogsError::TokenRevoked(ref context)
+            | AccessLogsError::UpgradeRequired(ref context)
+            | AccessLogsError::UserIsBot(ref context)
+            | AccessLogsError::Unknown(_, ref context) => Some(context),
+            AccessLogsError::MalformedResponse(..) | AccessLogsError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
-pub struct BillableInfoRequest<'a> {
+pub struct BillableInfoRequest {
     /// A user to retrieve the billable information for. Defaults to all users.
-    pub user: Option<Cow<'a, str>>,
+    pub user: Option<crate::UserId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct BillableInfoResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<BillableInfoResponse, BillableInfoError<E>>> for BillableInfoResponse {
@@ -205,28 +367,61 @@ impl<E: Error> Into<Result<BillableInfoResponse, BillableInfoError<E>>> for Bill
         if self.ok {
             Ok(self)
         } else {
-            Err(BillableInfoError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(BillableInfoError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for BillableInfoResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum BillableInfoError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> BillableInfoError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => BillableInfoError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for BillableInfoError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => BillableInfoError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -234,7 +429,7 @@ impl<E: Error> fmt::Display for BillableInfoError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             BillableInfoError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            BillableInfoError::Unknown(ref s) => write!(f, "{}", s),
+            BillableInfoError::Unknown(ref s, _) => write!(f, "{}", s),
             BillableInfoError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -250,25 +445,51 @@ impl<E: Error + 'static> Error for BillableInfoError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for BillableInfoError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            BillableInfoError::Unknown(ref s, _) => Some(s),
+            BillableInfoError::MalformedResponse(..) | BillableInfoError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            BillableInfoError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            BillableInfoError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            BillableInfoError::Unknown(_, ref context) => Some(context),
+            BillableInfoError::MalformedResponse(..) | BillableInfoError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
-pub struct InfoRequest<'a> {
+pub struct InfoRequest {
     /// Team to get info on, if omitted, will return information about the current team. Will only return team that the authenticated token is allowed to see through external shared channels
-    pub team: Option<Cow<'a, str>>,
+    pub team: Option<crate::TeamId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoCurrentInner {
     pub date_started: u64,
-    pub team_id: String,
+    pub team_id: crate::TeamId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoExternalOrgMigrationsInner {
     pub current: Vec<InfoCurrentInner>,
     pub date_updated: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoIconInner {
     pub image_102: Option<String>,
     pub image_132: Option<String>,
@@ -280,20 +501,20 @@ pub struct InfoIconInner {
     pub image_default: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoPrimaryOwnerInner {
     pub email: String,
-    pub id: String,
+    pub id: crate::UserId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoSsoProviderInner {
     pub label: Option<String>,
     pub name: Option<String>,
     pub r#type: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoTeamInner {
     pub archived: Option<bool>,
     pub avatar_base_url: Option<String>,
@@ -304,12 +525,12 @@ pub struct InfoTeamInner {
     pub discoverable: Option<Vec<String>>,
     pub domain: String,
     pub email_domain: String,
-    pub enterprise_id: Option<String>,
+    pub enterprise_id: Option<crate::EnterpriseId>,
     pub enterprise_name: Option<String>,
     pub external_org_migrations: Option<InfoExternalOrgMigrationsInner>,
     pub has_compliance_export: Option<bool>,
     pub icon: InfoIconInner,
-    pub id: String,
+    pub id: crate::TeamId,
     pub is_assigned: Option<bool>,
     pub is_enterprise: Option<u64>,
     pub is_over_storage_limit: Option<bool>,
@@ -326,13 +547,19 @@ pub struct InfoTeamInner {
     pub sso_provider: Option<InfoSsoProviderInner>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
     pub team: InfoTeamInner,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<InfoResponse, InfoError<E>>> for InfoResponse {
@@ -340,85 +567,120 @@ impl<E: Error> Into<Result<InfoResponse, InfoError<E>>> for InfoResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(InfoError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for InfoResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum InfoError<E: Error> {
-    AccountInactive,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequired,
+    AccountInactive(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> InfoError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => InfoError::AccountInactive(context),
+            "fatal_error" => InfoError::FatalError(context),
+            "invalid_arg_name" => InfoError::InvalidArgName(context),
+            "invalid_array_arg" => InfoError::InvalidArrayArg(context),
+            "invalid_auth" => InfoError::InvalidAuth(context),
+            "invalid_charset" => InfoError::InvalidCharset(context),
+            "invalid_form_data" => InfoError::InvalidFormData(context),
+            "invalid_json" => InfoError::InvalidJson(context),
+            "invalid_post_type" => InfoError::InvalidPostType(context),
+            "json_not_object" => InfoError::JsonNotObject(context),
+            "missing_post_type" => InfoError::MissingPostType(context),
+            "no_permission" => InfoError::NoPermission(context),
+            "not_authed" => InfoError::NotAuthed(context),
+            "request_timeout" => InfoError::RequestTimeout(context),
+            "team_added_to_org" => InfoError::TeamAddedToOrg(context),
+            "token_revoked" => InfoError::TokenRevoked(context),
+            "upgrade_required" => InfoError::UpgradeRequired(context),
+            _ => InfoError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for InfoError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => InfoError::AccountInactive,
-            "fatal_error" => InfoError::FatalError,
-            "invalid_arg_name" => InfoError::InvalidArgName,
-            "invalid_array_arg" => InfoError::InvalidArrayArg,
-            "invalid_auth" => InfoError::InvalidAuth,
-            "invalid_charset" => InfoError::InvalidCharset,
-            "invalid_form_data" => InfoError::InvalidFormData,
-            "invalid_json" => InfoError::InvalidJson,
-            "invalid_post_type" => InfoError::InvalidPostType,
-            "json_not_object" => InfoError::JsonNotObject,
-            "missing_post_type" => InfoError::MissingPostType,
-            "no_permission" => InfoError::NoPermission,
-            "not_authed" => InfoError::NotAuthed,
-            "request_timeout" => InfoError::RequestTimeout,
-            "team_added_to_org" => InfoError::TeamAddedToOrg,
-            "token_revoked" => InfoError::TokenRevoked,
-            "upgrade_required" => InfoError::UpgradeRequired,
-            _ => InfoError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for InfoError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            InfoError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            InfoError::FatalError => write!(f, "Server returned error fatal_error"),
-            InfoError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            InfoError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            InfoError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            InfoError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            InfoError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            InfoError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            InfoError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            InfoError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            InfoError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            InfoError::NoPermission => write!(f, "Server returned error no_permission"),
-            InfoError::NotAuthed => write!(f, "Server returned error not_authed"),
-            InfoError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            InfoError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            InfoError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            InfoError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
+            InfoError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            InfoError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            InfoError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            InfoError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            InfoError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            InfoError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            InfoError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            InfoError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            InfoError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            InfoError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            InfoError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            InfoError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            InfoError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            InfoError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            InfoError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            InfoError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            InfoError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
             InfoError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            InfoError::Unknown(ref s) => write!(f, "{}", s),
+            InfoError::Unknown(ref s, _) => write!(f, "{}", s),
             InfoError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -434,10 +696,70 @@ impl<E: Error + 'static> Error for InfoError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for InfoError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            InfoError::AccountInactive(_) => Some("account_inactive"),
+            InfoError::FatalError(_) => Some("fatal_error"),
+            InfoError::InvalidArgName(_) => Some("invalid_arg_name"),
+            InfoError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            InfoError::InvalidAuth(_) => Some("invalid_auth"),
+            InfoError::InvalidCharset(_) => Some("invalid_charset"),
+            InfoError::InvalidFormData(_) => Some("invalid_form_data"),
+            InfoError::InvalidJson(_) => Some("invalid_json"),
+            InfoError::InvalidPostType(_) => Some("invalid_post_type"),
+            InfoError::JsonNotObject(_) => Some("json_not_object"),
+            InfoError::MissingPostType(_) => Some("missing_post_type"),
+            InfoError::NoPermission(_) => Some("no_permission"),
+            InfoError::NotAuthed(_) => Some("not_authed"),
+            InfoError::RequestTimeout(_) => Some("request_timeout"),
+            InfoError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            InfoError::TokenRevoked(_) => Some("token_revoked"),
+            InfoError::UpgradeRequired(_) => Some("upgrade_required"),
+            InfoError::Unknown(ref s, _) => Some(s),
+            InfoError::MalformedResponse(..) | InfoError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            InfoError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            InfoError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            InfoError::AccountInactive(ref context)
+            | InfoError::FatalError(ref context)
+            | InfoError::InvalidArgName(ref context)
+            | InfoError::InvalidArrayArg(ref context)
+            | InfoError::InvalidAuth(ref context)
+            | InfoError::InvalidCharset(ref context)
+            | InfoError::InvalidFormData(ref context)
+            | InfoError::InvalidJson(ref context)
+            | InfoError::InvalidPostType(ref context)
+            | InfoError::JsonNotObject(ref context)
+            | InfoError::MissingPostType(ref context)
+            | InfoError::NoPermission(ref context)
+            | InfoError::NotAuthed(ref context)
+            | InfoError::RequestTimeout(ref context)
+            | InfoError::TeamAddedToOrg(ref context)
+            | InfoError::TokenRevoked(ref context)
+            | InfoError::UpgradeRequired(ref context)
+            | InfoError::Unknown(_, ref context) => Some(context),
+            InfoError::MalformedResponse(..) | InfoError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct IntegrationLogsRequest<'a> {
     /// Filter logs to this Slack app. Defaults to all logs.
-    pub app_id: Option<Cow<'a, str>>,
+    pub app_id: Option<crate::AppId>,
     /// Filter logs with this change type. Defaults to all logs.
     pub change_type: Option<Cow<'a, str>>,
     pub count: Option<Cow<'a, str>>,
@@ -445,25 +767,31 @@ pub struct IntegrationLogsRequest<'a> {
     /// Filter logs to this service. Defaults to all logs.
     pub service_id: Option<Cow<'a, str>>,
     /// Filter logs generated by this user’s actions. Defaults to all logs.
-    pub user: Option<Cow<'a, str>>,
+    pub user: Option<crate::UserId>,
+}
+
+impl crate::pagination::PageRequest for IntegrationLogsRequest<'_> {
+    fn set_page(&mut self, page: u64) {
+        self.page = Some(page.to_string().into());
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct IntegrationLogsLogsInner {
     pub admin_app_id: Option<String>,
-    pub app_id: String,
+    pub app_id: crate::AppId,
     pub app_type: String,
     pub change_type: String,
-    pub channel: Option<String>,
+    pub channel: Option<crate::ChannelId>,
     pub date: String,
     pub scope: String,
     pub service_id: Option<String>,
     pub service_type: Option<String>,
-    pub user_id: String,
+    pub user_id: crate::UserId,
     pub user_name: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct IntegrationLogsPagingInner {
     pub count: Option<u64>,
     pub page: u64,
@@ -473,14 +801,20 @@ pub struct IntegrationLogsPagingInner {
     pub total: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct IntegrationLogsResponse {
     pub callstack: Option<String>,
     error: Option<String>,
     pub logs: Vec<IntegrationLogsLogsInner>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
     pub paging: IntegrationLogsPagingInner,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<IntegrationLogsResponse, IntegrationLogsError<E>>>
@@ -490,115 +824,170 @@ impl<E: Error> Into<Result<IntegrationLogsResponse, IntegrationLogsError<E>>>
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(IntegrationLogsError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for IntegrationLogsResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
+impl crate::pagination::NumberedPage for IntegrationLogsResponse {
+    type Item = IntegrationLogsLogsInner;
+
+    fn page(&self) -> Option<u64> {
+        Some(self.paging.page)
+    }
+
+    fn pages(&self) -> Option<u64> {
+        self.paging.pages
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.logs
+    }
+}
+
 #[derive(Debug)]
 pub enum IntegrationLogsError<E: Error> {
-    AccountInactive,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    OrgLoginRequired,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequired,
-    UserIsBot,
+    AccountInactive(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserIsBot(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> IntegrationLogsError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => IntegrationLogsError::AccountInactive(context),
+            "fatal_error" => IntegrationLogsError::FatalError(context),
+            "invalid_arg_name" => IntegrationLogsError::InvalidArgName(context),
+            "invalid_array_arg" => IntegrationLogsError::InvalidArrayArg(context),
+            "invalid_auth" => IntegrationLogsError::InvalidAuth(context),
+            "invalid_charset" => IntegrationLogsError::InvalidCharset(context),
+            "invalid_form_data" => IntegrationLogsError::InvalidFormData(context),
+            "invalid_json" => IntegrationLogsError::InvalidJson(context),
+            "invalid_post_type" => IntegrationLogsError::InvalidPostType(context),
+            "json_not_object" => IntegrationLogsError::JsonNotObject(context),
+            "missing_post_type" => IntegrationLogsError::MissingPostType(context),
+            "no_permission" => IntegrationLogsError::NoPermission(context),
+            "not_authed" => IntegrationLogsError::NotAuthed(context),
+            "org_login_required" => IntegrationLogsError::OrgLoginRequired(context),
+            "request_timeout" => IntegrationLogsError::RequestTimeout(context),
+            "team_added_to_org" => IntegrationLogsError::TeamAddedToOrg(context),
+            "token_revoked" => IntegrationLogsError::TokenRevoked(context),
+            "upgrade_required" => IntegrationLogsError::UpgradeRequired(context),
+            "user_is_bot" => IntegrationLogsError::UserIsBot(context),
+            _ => IntegrationLogsError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for IntegrationLogsError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => IntegrationLogsError::AccountInactive,
-            "fatal_error" => IntegrationLogsError::FatalError,
-            "invalid_arg_name" => IntegrationLogsError::InvalidArgName,
-            "invalid_array_arg" => IntegrationLogsError::InvalidArrayArg,
-            "invalid_auth" => IntegrationLogsError::InvalidAuth,
-            "invalid_charset" => IntegrationLogsError::InvalidCharset,
-            "invalid_form_data" => IntegrationLogsError::InvalidFormData,
-            "invalid_json" => IntegrationLogsError::InvalidJson,
-            "invalid_post_type" => IntegrationLogsError::InvalidPostType,
-            "json_not_object" => IntegrationLogsError::JsonNotObject,
-            "missing_post_type" => IntegrationLogsError::MissingPostType,
-            "no_permission" => IntegrationLogsError::NoPermission,
-            "not_authed" => IntegrationLogsError::NotAuthed,
-            "org_login_required" => IntegrationLogsError::OrgLoginRequired,
-            "request_timeout" => IntegrationLogsError::RequestTimeout,
-            "team_added_to_org" => IntegrationLogsError::TeamAddedToOrg,
-            "token_revoked" => IntegrationLogsError::TokenRevoked,
-            "upgrade_required" => IntegrationLogsError::UpgradeRequired,
-            "user_is_bot" => IntegrationLogsError::UserIsBot,
-            _ => IntegrationLogsError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for IntegrationLogsError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            IntegrationLogsError::AccountInactive => {
+            IntegrationLogsError::AccountInactive(_) => {
                 write!(f, "Server returned error account_inactive")
             }
-            IntegrationLogsError::FatalError => write!(f, "Server returned error fatal_error"),
-            IntegrationLogsError::InvalidArgName => {
+            IntegrationLogsError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            IntegrationLogsError::InvalidArgName(_) => {
                 write!(f, "Server returned error invalid_arg_name")
             }
-            IntegrationLogsError::InvalidArrayArg => {
+            IntegrationLogsError::InvalidArrayArg(_) => {
                 write!(f, "Server returned error invalid_array_arg")
             }
-            IntegrationLogsError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            IntegrationLogsError::InvalidCharset => {
+            IntegrationLogsError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            IntegrationLogsError::InvalidCharset(_) => {
                 write!(f, "Server returned error invalid_charset")
             }
-            IntegrationLogsError::InvalidFormData => {
+            IntegrationLogsError::InvalidFormData(_) => {
                 write!(f, "Server returned error invalid_form_data")
             }
-            IntegrationLogsError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            IntegrationLogsError::InvalidPostType => {
+            IntegrationLogsError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            IntegrationLogsError::InvalidPostType(_) => {
                 write!(f, "Server returned error invalid_post_type")
             }
-            IntegrationLogsError::JsonNotObject => {
+            IntegrationLogsError::JsonNotObject(_) => {
                 write!(f, "Server returned error json_not_object")
             }
-            IntegrationLogsError::MissingPostType => {
+            IntegrationLogsError::MissingPostType(_) => {
                 write!(f, "Server returned error missing_post_type")
             }
-            IntegrationLogsError::NoPermission => write!(f, "Server returned error no_permission"),
-            IntegrationLogsError::NotAuthed => write!(f, "Server returned error not_authed"),
-            IntegrationLogsError::OrgLoginRequired => {
+            IntegrationLogsError::NoPermission(_) => {
+                write!(f, "Server returned error no_permission")
+            }
+            IntegrationLogsError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            IntegrationLogsError::OrgLoginRequired(_) => {
                 write!(f, "Server returned error org_login_required")
             }
-            IntegrationLogsError::RequestTimeout => {
+            IntegrationLogsError::RequestTimeout(_) => {
                 write!(f, "Server returned error request_timeout")
             }
-            IntegrationLogsError::TeamAddedToOrg => {
+            IntegrationLogsError::TeamAddedToOrg(_) => {
                 write!(f, "Server returned error team_added_to_org")
             }
-            IntegrationLogsError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            IntegrationLogsError::UpgradeRequired => {
+            IntegrationLogsError::TokenRevoked(_) => {
+                write!(f, "Server returned error token_revoked")
+            }
+            IntegrationLogsError::UpgradeRequired(_) => {
                 write!(f, "Server returned error upgrade_required")
             }
-            IntegrationLogsError::UserIsBot => write!(f, "Server returned error user_is_bot"),
+            IntegrationLogsError::UserIsBot(_) => write!(f, "Server returned error user_is_bot"),
             IntegrationLogsError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            IntegrationLogsError::Unknown(ref s) => write!(f, "{}", s),
+            IntegrationLogsError::Unknown(ref s, _) => write!(f, "{}", s),
             IntegrationLogsError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -613,3 +1002,67 @@ impl<E: Error + 'static> Error for IntegrationLogsError<E> {
         }
     }
 }
+
+impl<E: Error + 'static> crate::MethodError for IntegrationLogsError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            IntegrationLogsError::AccountInactive(_) => Some("account_inactive"),
+            IntegrationLogsError::FatalError(_) => Some("fatal_error"),
+            IntegrationLogsError::InvalidArgName(_) => Some("invalid_arg_name"),
+            IntegrationLogsError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            IntegrationLogsError::InvalidAuth(_) => Some("invalid_auth"),
+            IntegrationLogsError::InvalidCharset(_) => Some("invalid_charset"),
+            IntegrationLogsError::InvalidFormData(_) => Some("invalid_form_data"),
+            IntegrationLogsError::InvalidJson(_) => Some("invalid_json"),
+            IntegrationLogsError::InvalidPostType(_) => Some("invalid_post_type"),
+            IntegrationLogsError::JsonNotObject(_) => Some("json_not_object"),
+            IntegrationLogsError::MissingPostType(_) => Some("missing_post_type"),
+            IntegrationLogsError::NoPermission(_) => Some("no_permission"),
+            IntegrationLogsError::NotAuthed(_) => Some("not_authed"),
+            IntegrationLogsError::OrgLoginRequired(_) => Some("org_login_required"),
+            IntegrationLogsError::RequestTimeout(_) => Some("request_timeout"),
+            IntegrationLogsError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            IntegrationLogsError::TokenRevoked(_) => Some("token_revoked"),
+            IntegrationLogsError::UpgradeRequired(_) => Some("upgrade_required"),
+            IntegrationLogsError::UserIsBot(_) => Some("user_is_bot"),
+            IntegrationLogsError::Unknown(ref s, _) => Some(s),
+            IntegrationLogsError::MalformedResponse(..) | IntegrationLogsError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            IntegrationLogsError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            IntegrationLogsError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            IntegrationLogsError::AccountInactive(ref context)
+            | IntegrationLogsError::FatalError(ref context)
+            | IntegrationLogsError::InvalidArgName(ref context)
+            | IntegrationLogsError::InvalidArrayArg(ref context)
+            | IntegrationLogsError::InvalidAuth(ref context)
+            | IntegrationLogsError::InvalidCharset(ref context)
+            | IntegrationLogsError::InvalidFormData(ref context)
+            | IntegrationLogsError::InvalidJson(ref context)
+            | IntegrationLogsError::InvalidPostType(ref context)
+            | IntegrationLogsError::JsonNotObject(ref context)
+            | IntegrationLogsError::MissingPostType(ref context)
+            | IntegrationLogsError::NoPermission(ref context)
+            | IntegrationLogsError::NotAuthed(ref context)
+            | IntegrationLogsError::OrgLoginRequired(ref context)
+            | IntegrationLogsError::RequestTimeout(ref context)
+            | IntegrationLogsError::TeamAddedToOrg(ref context)
+            | IntegrationLogsError::TokenRevoked(ref context)
+            | IntegrationLogsError::UpgradeRequired(ref context)
+            | IntegrationLogsError::UserIsBot(ref context)
+            | IntegrationLogsError::Unknown(_, ref context) => Some(context),
+            IntegrationLogsError::MalformedResponse(..) | IntegrationLogsError::Client(_) => None,
+        }
+    }
+}
@@ -27,7 +27,7 @@ pub struct GetRequest<'a> {
     pub visibility: Option<Cow<'a, str>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GetOptionsInner {
     pub is_custom: Option<bool>,
     pub is_multiple_entry: Option<bool>,
@@ -35,7 +35,7 @@ pub struct GetOptionsInner {
     pub is_scim: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GetFieldsInner {
     pub field_name: Option<String>,
     pub hint: String,
@@ -48,18 +48,24 @@ pub struct GetFieldsInner {
     pub r#type: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GetProfileInner {
     pub fields: Vec<GetFieldsInner>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GetResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
     pub profile: GetProfileInner,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<GetResponse, GetError<E>>> for GetResponse {
@@ -67,82 +73,117 @@ impl<E: Error> Into<Result<GetResponse, GetError<E>>> for GetResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(GetError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for GetResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum GetError<E: Error> {
-    AccountInactive,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostTyp,
-    JsonNotObject,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    RequestTimeou,
-    TeamAddedToOrg,
-    UpgradeRequired,
-    UserIsBot,
+    AccountInactive(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostTyp(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    RequestTimeou(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserIsBot(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> GetError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => GetError::AccountInactive(context),
+            "invalid_arg_name" => GetError::InvalidArgName(context),
+            "invalid_array_arg" => GetError::InvalidArrayArg(context),
+            "invalid_auth" => GetError::InvalidAuth(context),
+            "invalid_charset" => GetError::InvalidCharset(context),
+            "invalid_form_data" => GetError::InvalidFormData(context),
+            "invalid_json" => GetError::InvalidJson(context),
+            "invalid_post_typ" => GetError::InvalidPostTyp(context),
+            "json_not_object" => GetError::JsonNotObject(context),
+            "missing_post_type" => GetError::MissingPostType(context),
+            "no_permission" => GetError::NoPermission(context),
+            "not_authed" => GetError::NotAuthed(context),
+            "request_timeou" => GetError::RequestTimeou(context),
+            "team_added_to_org" => GetError::TeamAddedToOrg(context),
+            "upgrade_required" => GetError::UpgradeRequired(context),
+            "user_is_bot" => GetError::UserIsBot(context),
+            _ => GetError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for GetError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => GetError::AccountInactive,
-            "invalid_arg_name" => GetError::InvalidArgName,
-            "invalid_array_arg" => GetError::InvalidArrayArg,
-            "invalid_auth" => GetError::InvalidAuth,
-            "invalid_charset" => GetError::InvalidCharset,
-            "invalid_form_data" => GetError::InvalidFormData,
-            "invalid_json" => GetError::InvalidJson,
-            "invalid_post_typ" => GetError::InvalidPostTyp,
-            "json_not_object" => GetError::JsonNotObject,
-            "missing_post_type" => GetError::MissingPostType,
-            "no_permission" => GetError::NoPermission,
-            "not_authed" => GetError::NotAuthed,
-            "request_timeou" => GetError::RequestTimeou,
-            "team_added_to_org" => GetError::TeamAddedToOrg,
-            "upgrade_required" => GetError::UpgradeRequired,
-            "user_is_bot" => GetError::UserIsBot,
-            _ => GetError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for GetError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            GetError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            GetError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            GetError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            GetError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            GetError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            GetError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            GetError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            GetError::InvalidPostTyp => write!(f, "Server returned error invalid_post_typ"),
-            GetError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            GetError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            GetError::NoPermission => write!(f, "Server returned error no_permission"),
-            GetError::NotAuthed => write!(f, "Server returned error not_authed"),
-            GetError::RequestTimeou => write!(f, "Server returned error request_timeou"),
-            GetError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            GetError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
-            GetError::UserIsBot => write!(f, "Server returned error user_is_bot"),
+            GetError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            GetError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            GetError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            GetError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            GetError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            GetError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            GetError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            GetError::InvalidPostTyp(_) => write!(f, "Server returned error invalid_post_typ"),
+            GetError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            GetError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            GetError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            GetError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            GetError::RequestTimeou(_) => write!(f, "Server returned error request_timeou"),
+            GetError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            GetError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
+            GetError::UserIsBot(_) => write!(f, "Server returned error user_is_bot"),
             GetError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            GetError::Unknown(ref s) => write!(f, "{}", s),
+            GetError::Unknown(ref s, _) => write!(f, "{}", s),
             GetError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -157,3 +198,61 @@ impl<E: Error + 'static> Error for GetError<E> {
         }
     }
 }
+
+impl<E: Error + 'static> crate::MethodError for GetError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            GetError::AccountInactive(_) => Some("account_inactive"),
+            GetError::InvalidArgName(_) => Some("invalid_arg_name"),
+            GetError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            GetError::InvalidAuth(_) => Some("invalid_auth"),
+            GetError::InvalidCharset(_) => Some("invalid_charset"),
+            GetError::InvalidFormData(_) => Some("invalid_form_data"),
+            GetError::InvalidJson(_) => Some("invalid_json"),
+            GetError::InvalidPostTyp(_) => Some("invalid_post_typ"),
+            GetError::JsonNotObject(_) => Some("json_not_object"),
+            GetError::MissingPostType(_) => Some("missing_post_type"),
+            GetError::NoPermission(_) => Some("no_permission"),
+            GetError::NotAuthed(_) => Some("not_authed"),
+            GetError::RequestTimeou(_) => Some("request_timeou"),
+            GetError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            GetError::UpgradeRequired(_) => Some("upgrade_required"),
+            GetError::UserIsBot(_) => Some("user_is_bot"),
+            GetError::Unknown(ref s, _) => Some(s),
+            GetError::MalformedResponse(..) | GetError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            GetError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            GetError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            GetError::AccountInactive(ref context)
+            | GetError::InvalidArgName(ref context)
+            | GetError::InvalidArrayArg(ref context)
+            | GetError::InvalidAuth(ref context)
+            | GetError::InvalidCharset(ref context)
+            | GetError::InvalidFormData(ref context)
+            | GetError::InvalidJson(ref context)
+            | GetError::InvalidPostTyp(ref context)
+            | GetError::JsonNotObject(ref context)
+            | GetError::MissingPostType(ref context)
+            | GetError::NoPermission(ref context)
+            | GetError::NotAuthed(ref context)
+            | GetError::RequestTimeou(ref context)
+            | GetError::TeamAddedToOrg(ref context)
+            | GetError::UpgradeRequired(ref context)
+            | GetError::UserIsBot(ref context)
+            | GetError::Unknown(_, ref context) => Some(context),
+            GetError::MalformedResponse(..) | GetError::Client(_) => None,
+        }
+    }
+}
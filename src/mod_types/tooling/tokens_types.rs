@@ -0,0 +1,185 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+#![allow(unused_imports)]
+#![allow(clippy::match_single_binding)]
+#![allow(clippy::blacklisted_name)]
+
+use std::borrow::Cow;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Clone, Default, Debug)]
+pub struct RotateRequest<'a> {
+    /// The `xoxe` refresh token that was issued along with the old app configuration token.
+    pub refresh_token: Cow<'a, str>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RotateResponse {
+    error: Option<String>,
+    pub exp: Option<u64>,
+    pub iat: Option<u64>,
+    pub needed: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub provided: Option<String>,
+    pub refresh_token: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub team_id: Option<crate::TeamId>,
+    pub token: Option<String>,
+    pub user_id: Option<crate::UserId>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
+}
+
+impl<E: Error> Into<Result<RotateResponse, RotateError<E>>> for RotateResponse {
+    fn into(self) -> Result<RotateResponse, RotateError<E>> {
+        if self.ok {
+            Ok(self)
+        } else {
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(RotateError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
+        }
+    }
+}
+
+impl crate::SlackResponse for RotateResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
+#[derive(Debug)]
+pub enum RotateError<E: Error> {
+    FatalError(crate::ErrorContext),
+    InvalidArguments(crate::ErrorContext),
+    InvalidRefreshToken(crate::ErrorContext),
+    Ratelimited(crate::ErrorContext),
+    ServiceUnavailable(crate::ErrorContext),
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String, crate::ErrorContext),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<E: Error> RotateError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "fatal_error" => RotateError::FatalError(context),
+            "invalid_arguments" => RotateError::InvalidArguments(context),
+            "invalid_refresh_token" => RotateError::InvalidRefreshToken(context),
+            "ratelimited" => RotateError::Ratelimited(context),
+            "service_unavailable" => RotateError::ServiceUnavailable(context),
+            _ => RotateError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
+impl<'a, E: Error> From<&'a str> for RotateError<E> {
+    fn from(s: &'a str) -> Self {
+        Self::new(s, crate::ErrorContext::default())
+    }
+}
+
+impl<E: Error> fmt::Display for RotateError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            RotateError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            RotateError::InvalidArguments(_) => write!(f, "Server returned error invalid_arguments"),
+            RotateError::InvalidRefreshToken(_) => {
+                write!(f, "Server returned error invalid_refresh_token")
+            }
+            RotateError::Ratelimited(_) => write!(f, "Server returned error ratelimited"),
+            RotateError::ServiceUnavailable(_) => {
+                write!(f, "Server returned error service_unavailable")
+            }
+            RotateError::MalformedResponse(_, ref e) => write!(f, "{}", e),
+            RotateError::Unknown(ref s, _) => write!(f, "{}", s),
+            RotateError::Client(ref inner) => write!(f, "{}", inner),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for RotateError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            RotateError::MalformedResponse(_, ref e) => Some(e),
+            RotateError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+impl<E: Error + 'static> crate::MethodError for RotateError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            RotateError::FatalError(_) => Some("fatal_error"),
+            RotateError::InvalidArguments(_) => Some("invalid_arguments"),
+            RotateError::InvalidRefreshToken(_) => Some("invalid_refresh_token"),
+            RotateError::Ratelimited(_) => Some("ratelimited"),
+            RotateError::ServiceUnavailable(_) => Some("service_unavailable"),
+            RotateError::Unknown(ref s, _) => Some(s),
+            RotateError::MalformedResponse(..) | RotateError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            RotateError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            RotateError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            RotateError::FatalError(ref context)
+            | RotateError::InvalidArguments(ref context)
+            | RotateError::InvalidRefreshToken(ref context)
+            | RotateError::Ratelimited(ref context)
+            | RotateError::ServiceUnavailable(ref context)
+            | RotateError::Unknown(_, ref context) => Some(context),
+            RotateError::MalformedResponse(..) | RotateError::Client(_) => None,
+        }
+    }
+}
@@ -37,13 +37,13 @@ pub struct CreateRequest<'a> {
     pub name: Cow<'a, str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreatePrefsInner {
     pub channels: Vec<String>,
     pub groups: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreateUsergroupInner {
     pub auto_provision: bool,
     pub auto_type: Vec<String>,
@@ -56,25 +56,31 @@ pub struct CreateUsergroupInner {
     pub description: String,
     pub enterprise_subteam_id: String,
     pub handle: String,
-    pub id: String,
+    pub id: crate::UsergroupId,
     pub is_external: bool,
     pub is_subteam: bool,
     pub is_usergroup: bool,
     pub name: String,
     pub prefs: CreatePrefsInner,
-    pub team_id: String,
+    pub team_id: crate::TeamId,
     pub updated_by: String,
     pub user_count: Option<u64>,
     pub users: Option<Vec<String>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreateResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
     pub usergroup: CreateUsergroupInner,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<CreateResponse, CreateError<E>>> for CreateResponse {
@@ -82,97 +88,138 @@ impl<E: Error> Into<Result<CreateResponse, CreateError<E>>> for CreateResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(CreateError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for CreateResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum CreateError<E: Error> {
-    AccountInactive,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    OrgLoginRequired,
-    PermissionDenied,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequired,
-    UserIsBot,
-    UserIsRestricted,
+    AccountInactive(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    PermissionDenied(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserIsBot(crate::ErrorContext),
+    UserIsRestricted(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> CreateError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => CreateError::AccountInactive(context),
+            "fatal_error" => CreateError::FatalError(context),
+            "invalid_arg_name" => CreateError::InvalidArgName(context),
+            "invalid_array_arg" => CreateError::InvalidArrayArg(context),
+            "invalid_auth" => CreateError::InvalidAuth(context),
+            "invalid_charset" => CreateError::InvalidCharset(context),
+            "invalid_form_data" => CreateError::InvalidFormData(context),
+            "invalid_json" => CreateError::InvalidJson(context),
+            "invalid_post_type" => CreateError::InvalidPostType(context),
+            "json_not_object" => CreateError::JsonNotObject(context),
+            "missing_post_type" => CreateError::MissingPostType(context),
+            "no_permission" => CreateError::NoPermission(context),
+            "not_authed" => CreateError::NotAuthed(context),
+            "org_login_required" => CreateError::OrgLoginRequired(context),
+            "permission_denied" => CreateError::PermissionDenied(context),
+            "request_timeout" => CreateError::RequestTimeout(context),
+            "team_added_to_org" => CreateError::TeamAddedToOrg(context),
+            "token_revoked" => CreateError::TokenRevoked(context),
+            "upgrade_required" => CreateError::UpgradeRequired(context),
+            "user_is_bot" => CreateError::UserIsBot(context),
+            "user_is_restricted" => CreateError::UserIsRestricted(context),
+            _ => CreateError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for CreateError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => CreateError::AccountInactive,
-            "fatal_error" => CreateError::FatalError,
-            "invalid_arg_name" => CreateError::InvalidArgName,
-            "invalid_array_arg" => CreateError::InvalidArrayArg,
-            "invalid_auth" => CreateError::InvalidAuth,
-            "invalid_charset" => CreateError::InvalidCharset,
-            "invalid_form_data" => CreateError::InvalidFormData,
-            "invalid_json" => CreateError::InvalidJson,
-            "invalid_post_type" => CreateError::InvalidPostType,
-            "json_not_object" => CreateError::JsonNotObject,
-            "missing_post_type" => CreateError::MissingPostType,
-            "no_permission" => CreateError::NoPermission,
-            "not_authed" => CreateError::NotAuthed,
-            "org_login_required" => CreateError::OrgLoginRequired,
-            "permission_denied" => CreateError::PermissionDenied,
-            "request_timeout" => CreateError::RequestTimeout,
-            "team_added_to_org" => CreateError::TeamAddedToOrg,
-            "token_revoked" => CreateError::TokenRevoked,
-            "upgrade_required" => CreateError::UpgradeRequired,
-            "user_is_bot" => CreateError::UserIsBot,
-            "user_is_restricted" => CreateError::UserIsRestricted,
-            _ => CreateError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for CreateError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            CreateError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            CreateError::FatalError => write!(f, "Server returned error fatal_error"),
-            CreateError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            CreateError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            CreateError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            CreateError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            CreateError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            CreateError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            CreateError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            CreateError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            CreateError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            CreateError::NoPermission => write!(f, "Server returned error no_permission"),
-            CreateError::NotAuthed => write!(f, "Server returned error not_authed"),
-            CreateError::OrgLoginRequired => write!(f, "Server returned error org_login_required"),
-            CreateError::PermissionDenied => write!(f, "Server returned error permission_denied"),
-            CreateError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            CreateError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            CreateError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            CreateError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
-            CreateError::UserIsBot => write!(f, "Server returned error user_is_bot"),
-            CreateError::UserIsRestricted => write!(f, "Server returned error user_is_restricted"),
+            CreateError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            CreateError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            CreateError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            CreateError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            CreateError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            CreateError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            CreateError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            CreateError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            CreateError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            CreateError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            CreateError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            CreateError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            CreateError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            CreateError::OrgLoginRequired(_) => {
+                write!(f, "Server returned error org_login_required")
+            }
+            CreateError::PermissionDenied(_) => {
+                write!(f, "Server returned error permission_denied")
+            }
+            CreateError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            CreateError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            CreateError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            CreateError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
+            CreateError::UserIsBot(_) => write!(f, "Server returned error user_is_bot"),
+            CreateError::UserIsRestricted(_) => {
+                write!(f, "Server returned error user_is_restricted")
+            }
             CreateError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            CreateError::Unknown(ref s) => write!(f, "{}", s),
+            CreateError::Unknown(ref s, _) => write!(f, "{}", s),
             CreateError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -188,21 +235,89 @@ impl<E: Error + 'static> Error for CreateError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for CreateError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            CreateError::AccountInactive(_) => Some("account_inactive"),
+            CreateError::FatalError(_) => Some("fatal_error"),
+            CreateError::InvalidArgName(_) => Some("invalid_arg_name"),
+            CreateError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            CreateError::InvalidAuth(_) => Some("invalid_auth"),
+            CreateError::InvalidCharset(_) => Some("invalid_charset"),
+            CreateError::InvalidFormData(_) => Some("invalid_form_data"),
+            CreateError::InvalidJson(_) => Some("invalid_json"),
+            CreateError::InvalidPostType(_) => Some("invalid_post_type"),
+            CreateError::JsonNotObject(_) => Some("json_not_object"),
+            CreateError::MissingPostType(_) => Some("missing_post_type"),
+            CreateError::NoPermission(_) => Some("no_permission"),
+            CreateError::NotAuthed(_) => Some("not_authed"),
+            CreateError::OrgLoginRequired(_) => Some("org_login_required"),
+            CreateError::PermissionDenied(_) => Some("permission_denied"),
+            CreateError::RequestTimeout(_) => Some("request_timeout"),
+            CreateError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            CreateError::TokenRevoked(_) => Some("token_revoked"),
+            CreateError::UpgradeRequired(_) => Some("upgrade_required"),
+            CreateError::UserIsBot(_) => Some("user_is_bot"),
+            CreateError::UserIsRestricted(_) => Some("user_is_restricted"),
+            CreateError::Unknown(ref s, _) => Some(s),
+            CreateError::MalformedResponse(..) | CreateError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            CreateError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            CreateError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            CreateError::AccountInactive(ref context)
+            | CreateError::FatalError(ref context)
+            | CreateError::InvalidArgName(ref context)
+            | CreateError::InvalidArrayArg(ref context)
+            | CreateError::InvalidAuth(ref context)
+            | CreateError::InvalidCharset(ref context)
+            | CreateError::InvalidFormData(ref context)
+            | CreateError::InvalidJson(ref context)
+            | CreateError::InvalidPostType(ref context)
+            | CreateError::JsonNotObject(ref context)
+            | CreateError::MissingPostType(ref context)
+            | CreateError::NoPermission(ref context)
+            | CreateError::NotAuthed(ref context)
+            | CreateError::OrgLoginRequired(ref context)
+            | CreateError::PermissionDenied(ref context)
+            | CreateError::RequestTimeout(ref context)
+            | CreateError::TeamAddedToOrg(ref context)
+            | CreateError::TokenRevoked(ref context)
+            | CreateError::UpgradeRequired(ref context)
+            | CreateError::UserIsBot(ref context)
+            | CreateError::UserIsRestricted(ref context)
+            | CreateError::Unknown(_, ref context) => Some(context),
+            CreateError::MalformedResponse(..) | CreateError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
-pub struct DisableRequest<'a> {
+pub struct DisableRequest {
     /// Include the number of users in the User Group.
     pub include_count: Option<bool>,
     /// The encoded ID of the User Group to disable.
-    pub usergroup: Cow<'a, str>,
+    pub usergroup: Option<crate::UsergroupId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct DisablePrefsInner {
     pub channels: Vec<String>,
     pub groups: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct DisableUsergroupInner {
     pub auto_provision: bool,
     pub auto_type: Vec<String>,
@@ -215,25 +330,31 @@ pub struct DisableUsergroupInner {
     pub description: String,
     pub enterprise_subteam_id: String,
     pub handle: String,
-    pub id: String,
+    pub id: crate::UsergroupId,
     pub is_external: bool,
     pub is_subteam: bool,
     pub is_usergroup: bool,
     pub name: String,
     pub prefs: DisablePrefsInner,
-    pub team_id: String,
+    pub team_id: crate::TeamId,
     pub updated_by: String,
     pub user_count: Option<u64>,
     pub users: Option<Vec<String>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct DisableResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
     pub usergroup: DisableUsergroupInner,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<DisableResponse, DisableError<E>>> for DisableResponse {
@@ -241,97 +362,146 @@ impl<E: Error> Into<Result<DisableResponse, DisableError<E>>> for DisableRespons
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(DisableError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for DisableResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum DisableError<E: Error> {
-    AccountInactive,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    OrgLoginRequired,
-    PermissionDenied,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequired,
-    UserIsBot,
-    UserIsRestricted,
+    AccountInactive(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    PermissionDenied(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserIsBot(crate::ErrorContext),
+    UserIsRestricted(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> DisableError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => DisableError::AccountInactive(context),
+            "fatal_error" => DisableError::FatalError(context),
+            "invalid_arg_name" => DisableError::InvalidArgName(context),
+            "invalid_array_arg" => DisableError::InvalidArrayArg(context),
+            "invalid_auth" => DisableError::InvalidAuth(context),
+            "invalid_charset" => DisableError::InvalidCharset(context),
+            "invalid_form_data" => DisableError::InvalidFormData(context),
+            "invalid_json" => DisableError::InvalidJson(context),
+            "invalid_post_type" => DisableError::InvalidPostType(context),
+            "json_not_object" => DisableError::JsonNotObject(context),
+            "missing_post_type" => DisableError::MissingPostType(context),
+            "no_permission" => DisableError::NoPermission(context),
+            "not_authed" => DisableError::NotAuthed(context),
+            "org_login_required" => DisableError::OrgLoginRequired(context),
+            "permission_denied" => DisableError::PermissionDenied(context),
+            "request_timeout" => DisableError::RequestTimeout(context),
+            "team_added_to_org" => DisableError::TeamAddedToOrg(context),
+            "token_revoked" => DisableError::TokenRevoked(context),
+            "upgrade_required" => DisableError::UpgradeRequired(context),
+            "user_is_bot" => DisableError::UserIsBot(context),
+            "user_is_restricted" => DisableError::UserIsRestricted(context),
+            _ => DisableError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for DisableError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => DisableError::AccountInactive,
-            "fatal_error" => DisableError::FatalError,
-            "invalid_arg_name" => DisableError::InvalidArgName,
-            "invalid_array_arg" => DisableError::InvalidArrayArg,
-            "invalid_auth" => DisableError::InvalidAuth,
-            "invalid_charset" => DisableError::InvalidCharset,
-            "invalid_form_data" => DisableError::InvalidFormData,
-            "invalid_json" => DisableError::InvalidJson,
-            "invalid_post_type" => DisableError::InvalidPostType,
-            "json_not_object" => DisableError::JsonNotObject,
-            "missing_post_type" => DisableError::MissingPostType,
-            "no_permission" => DisableError::NoPermission,
-            "not_authed" => DisableError::NotAuthed,
-            "org_login_required" => DisableError::OrgLoginRequired,
-            "permission_denied" => DisableError::PermissionDenied,
-            "request_timeout" => DisableError::RequestTimeout,
-            "team_added_to_org" => DisableError::TeamAddedToOrg,
-            "token_revoked" => DisableError::TokenRevoked,
-            "upgrade_required" => DisableError::UpgradeRequired,
-            "user_is_bot" => DisableError::UserIsBot,
-            "user_is_restricted" => DisableError::UserIsRestricted,
-            _ => DisableError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for DisableError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            DisableError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            DisableError::FatalError => write!(f, "Server returned error fatal_error"),
-            DisableError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            DisableError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            DisableError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            DisableError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            DisableError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            DisableError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            DisableError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            DisableError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            DisableError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            DisableError::NoPermission => write!(f, "Server returned error no_permission"),
-            DisableError::NotAuthed => write!(f, "Server returned error not_authed"),
-            DisableError::OrgLoginRequired => write!(f, "Server returned error org_login_required"),
-            DisableError::PermissionDenied => write!(f, "Server returned error permission_denied"),
-            DisableError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            DisableError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            DisableError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            DisableError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
-            DisableError::UserIsBot => write!(f, "Server returned error user_is_bot"),
-            DisableError::UserIsRestricted => write!(f, "Server returned error user_is_restricted"),
+            DisableError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            DisableError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            DisableError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            DisableError::InvalidArrayArg(_) => {
+                write!(f, "Server returned error invalid_array_arg")
+            }
+            DisableError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            DisableError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            DisableError::InvalidFormData(_) => {
+                write!(f, "Server returned error invalid_form_data")
+            }
+            DisableError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            DisableError::InvalidPostType(_) => {
+                write!(f, "Server returned error invalid_post_type")
+            }
+            DisableError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            DisableError::MissingPostType(_) => {
+                write!(f, "Server returned error missing_post_type")
+            }
+            DisableError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            DisableError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            DisableError::OrgLoginRequired(_) => {
+                write!(f, "Server returned error org_login_required")
+            }
+            DisableError::PermissionDenied(_) => {
+                write!(f, "Server returned error permission_denied")
+            }
+            DisableError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            DisableError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            DisableError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            DisableError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
+            DisableError::UserIsBot(_) => write!(f, "Server returned error user_is_bot"),
+            DisableError::UserIsRestricted(_) => {
+                write!(f, "Server returned error user_is_restricted")
+            }
             DisableError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            DisableError::Unknown(ref s) => write!(f, "{}", s),
+            DisableError::Unknown(ref s, _) => write!(f, "{}", s),
             DisableError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -347,21 +517,89 @@ impl<E: Error + 'static> Error for DisableError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for DisableError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            DisableError::AccountInactive(_) => Some("account_inactive"),
+            DisableError::FatalError(_) => Some("fatal_error"),
+            DisableError::InvalidArgName(_) => Some("invalid_arg_name"),
+            DisableError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            DisableError::InvalidAuth(_) => Some("invalid_auth"),
+            DisableError::InvalidCharset(_) => Some("invalid_charset"),
+            DisableError::InvalidFormData(_) => Some("invalid_form_data"),
+            DisableError::InvalidJson(_) => Some("invalid_json"),
+            DisableError::InvalidPostType(_) => Some("invalid_post_type"),
+            DisableError::JsonNotObject(_) => Some("json_not_object"),
+            DisableError::MissingPostType(_) => Some("missing_post_type"),
+            DisableError::NoPermission(_) => Some("no_permission"),
+            DisableError::NotAuthed(_) => Some("not_authed"),
+            DisableError::OrgLoginRequired(_) => Some("org_login_required"),
+            DisableError::PermissionDenied(_) => Some("permission_denied"),
+            DisableError::RequestTimeout(_) => Some("request_timeout"),
+            DisableError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            DisableError::TokenRevoked(_) => Some("token_revoked"),
+            DisableError::UpgradeRequired(_) => Some("upgrade_required"),
+            DisableError::UserIsBot(_) => Some("user_is_bot"),
+            DisableError::UserIsRestricted(_) => Some("user_is_restricted"),
+            DisableError::Unknown(ref s, _) => Some(s),
+            DisableError::MalformedResponse(..) | DisableError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            DisableError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            DisableError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            DisableError::AccountInactive(ref context)
+            | DisableError::FatalError(ref context)
+            | DisableError::InvalidArgName(ref context)
+            | DisableError::InvalidArrayArg(ref context)
+            | DisableError::InvalidAuth(ref context)
+            | DisableError::InvalidCharset(ref context)
+            | DisableError::InvalidFormData(ref context)
+            | DisableError::InvalidJson(ref context)
+            | DisableError::InvalidPostType(ref context)
+            | DisableError::JsonNotObject(ref context)
+            | DisableError::MissingPostType(ref context)
+            | DisableError::NoPermission(ref context)
+            | DisableError::NotAuthed(ref context)
+            | DisableError::OrgLoginRequired(ref context)
+            | DisableError::PermissionDenied(ref context)
+            | DisableError::RequestTimeout(ref context)
+            | DisableError::TeamAddedToOrg(ref context)
+            | DisableError::TokenRevoked(ref context)
+            | DisableError::UpgradeRequired(ref context)
+            | DisableError::UserIsBot(ref context)
+            | DisableError::UserIsRestricted(ref context)
+            | DisableError::Unknown(_, ref context) => Some(context),
+            DisableError::MalformedResponse(..) | DisableError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
-pub struct EnableRequest<'a> {
+pub struct EnableRequest {
     /// Include the number of users in the User Group.
     pub include_count: Option<bool>,
     /// The encoded ID of the User Group to enable.
-    pub usergroup: Cow<'a, str>,
+    pub usergroup: Option<crate::UsergroupId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct EnablePrefsInner {
     pub channels: Vec<String>,
     pub groups: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct EnableUsergroupInner {
     pub auto_provision: bool,
     pub auto_type: Vec<String>,
@@ -374,25 +612,31 @@ pub struct EnableUsergroupInner {
     pub description: String,
     pub enterprise_subteam_id: String,
     pub handle: String,
-    pub id: String,
+    pub id: crate::UsergroupId,
     pub is_external: bool,
     pub is_subteam: bool,
     pub is_usergroup: bool,
     pub name: String,
     pub prefs: EnablePrefsInner,
-    pub team_id: String,
+    pub team_id: crate::TeamId,
     pub updated_by: String,
     pub user_count: Option<u64>,
     pub users: Option<Vec<String>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct EnableResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
     pub usergroup: EnableUsergroupInner,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<EnableResponse, EnableError<E>>> for EnableResponse {
@@ -400,102 +644,141 @@ impl<E: Error> Into<Result<EnableResponse, EnableError<E>>> for EnableResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(EnableError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for EnableResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum EnableError<E: Error> {
-    AccountInactive,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingCharset,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    OrgLoginRequired,
-    RequestTimeout,
-    SuperfluousCharset,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequire,
-    UserIsBot,
-    UserIsRestricted,
+    AccountInactive(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingCharset(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    SuperfluousCharset(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequire(crate::ErrorContext),
+    UserIsBot(crate::ErrorContext),
+    UserIsRestricted(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> EnableError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => EnableError::AccountInactive(context),
+            "fatal_error" => EnableError::FatalError(context),
+            "invalid_arg_name" => EnableError::InvalidArgName(context),
+            "invalid_array_arg" => EnableError::InvalidArrayArg(context),
+            "invalid_auth" => EnableError::InvalidAuth(context),
+            "invalid_charset" => EnableError::InvalidCharset(context),
+            "invalid_form_data" => EnableError::InvalidFormData(context),
+            "invalid_json" => EnableError::InvalidJson(context),
+            "invalid_post_type" => EnableError::InvalidPostType(context),
+            "json_not_object" => EnableError::JsonNotObject(context),
+            "missing_charset" => EnableError::MissingCharset(context),
+            "missing_post_type" => EnableError::MissingPostType(context),
+            "no_permission" => EnableError::NoPermission(context),
+            "not_authed" => EnableError::NotAuthed(context),
+            "org_login_required" => EnableError::OrgLoginRequired(context),
+            "request_timeout" => EnableError::RequestTimeout(context),
+            "superfluous_charset" => EnableError::SuperfluousCharset(context),
+            "team_added_to_org" => EnableError::TeamAddedToOrg(context),
+            "token_revoked" => EnableError::TokenRevoked(context),
+            "upgrade_require" => EnableError::UpgradeRequire(context),
+            "user_is_bot" => EnableError::UserIsBot(context),
+            "user_is_restricted" => EnableError::UserIsRestricted(context),
+            _ => EnableError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for EnableError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => EnableError::AccountInactive,
-            "fatal_error" => EnableError::FatalError,
-            "invalid_arg_name" => EnableError::InvalidArgName,
-            "invalid_array_arg" => EnableError::InvalidArrayArg,
-            "invalid_auth" => EnableError::InvalidAuth,
-            "invalid_charset" => EnableError::InvalidCharset,
-            "invalid_form_data" => EnableError::InvalidFormData,
-            "invalid_json" => EnableError::InvalidJson,
-            "invalid_post_type" => EnableError::InvalidPostType,
-            "json_not_object" => EnableError::JsonNotObject,
-            "missing_charset" => EnableError::MissingCharset,
-            "missing_post_type" => EnableError::MissingPostType,
-            "no_permission" => EnableError::NoPermission,
-            "not_authed" => EnableError::NotAuthed,
-            "org_login_required" => EnableError::OrgLoginRequired,
-            "request_timeout" => EnableError::RequestTimeout,
-            "superfluous_charset" => EnableError::SuperfluousCharset,
-            "team_added_to_org" => EnableError::TeamAddedToOrg,
-            "token_revoked" => EnableError::TokenRevoked,
-            "upgrade_require" => EnableError::UpgradeRequire,
-            "user_is_bot" => EnableError::UserIsBot,
-            "user_is_restricted" => EnableError::UserIsRestricted,
-            _ => EnableError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for EnableError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            EnableError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            EnableError::FatalError => write!(f, "Server returned error fatal_error"),
-            EnableError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            EnableError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            EnableError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            EnableError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            EnableError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            EnableError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            EnableError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            EnableError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            EnableError::MissingCharset => write!(f, "Server returned error missing_charset"),
-            EnableError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            EnableError::NoPermission => write!(f, "Server returned error no_permission"),
-            EnableError::NotAuthed => write!(f, "Server returned error not_authed"),
-            EnableError::OrgLoginRequired => write!(f, "Server returned error org_login_required"),
-            EnableError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            EnableError::SuperfluousCharset => {
+            EnableError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            EnableError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            EnableError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            EnableError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            EnableError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            EnableError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            EnableError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            EnableError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            EnableError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            EnableError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            EnableError::MissingCharset(_) => write!(f, "Server returned error missing_charset"),
+            EnableError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            EnableError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            EnableError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            EnableError::OrgLoginRequired(_) => {
+                write!(f, "Server returned error org_login_required")
+            }
+            EnableError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            EnableError::SuperfluousCharset(_) => {
                 write!(f, "Server returned error superfluous_charset")
             }
-            EnableError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            EnableError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            EnableError::UpgradeRequire => write!(f, "Server returned error upgrade_require"),
-            EnableError::UserIsBot => write!(f, "Server returned error user_is_bot"),
-            EnableError::UserIsRestricted => write!(f, "Server returned error user_is_restricted"),
+            EnableError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            EnableError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            EnableError::UpgradeRequire(_) => write!(f, "Server returned error upgrade_require"),
+            EnableError::UserIsBot(_) => write!(f, "Server returned error user_is_bot"),
+            EnableError::UserIsRestricted(_) => {
+                write!(f, "Server returned error user_is_restricted")
+            }
             EnableError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            EnableError::Unknown(ref s) => write!(f, "{}", s),
+            EnableError::Unknown(ref s, _) => write!(f, "{}", s),
             EnableError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -511,6 +794,76 @@ impl<E: Error + 'static> Error for EnableError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for EnableError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            EnableError::AccountInactive(_) => Some("account_inactive"),
+            EnableError::FatalError(_) => Some("fatal_error"),
+            EnableError::InvalidArgName(_) => Some("invalid_arg_name"),
+            EnableError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            EnableError::InvalidAuth(_) => Some("invalid_auth"),
+            EnableError::InvalidCharset(_) => Some("invalid_charset"),
+            EnableError::InvalidFormData(_) => Some("invalid_form_data"),
+            EnableError::InvalidJson(_) => Some("invalid_json"),
+            EnableError::InvalidPostType(_) => Some("invalid_post_type"),
+            EnableError::JsonNotObject(_) => Some("json_not_object"),
+            EnableError::MissingCharset(_) => Some("missing_charset"),
+            EnableError::MissingPostType(_) => Some("missing_post_type"),
+            EnableError::NoPermission(_) => Some("no_permission"),
+            EnableError::NotAuthed(_) => Some("not_authed"),
+            EnableError::OrgLoginRequired(_) => Some("org_login_required"),
+            EnableError::RequestTimeout(_) => Some("request_timeout"),
+            EnableError::SuperfluousCharset(_) => Some("superfluous_charset"),
+            EnableError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            EnableError::TokenRevoked(_) => Some("token_revoked"),
+            EnableError::UpgradeRequire(_) => Some("upgrade_require"),
+            EnableError::UserIsBot(_) => Some("user_is_bot"),
+            EnableError::UserIsRestricted(_) => Some("user_is_restricted"),
+            EnableError::Unknown(ref s, _) => Some(s),
+            EnableError::MalformedResponse(..) | EnableError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            EnableError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            EnableError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            EnableError::AccountInactive(ref context)
+            | EnableError::FatalError(ref context)
+            | EnableError::InvalidArgName(ref context)
+            | EnableError::InvalidArrayArg(ref context)
+            | EnableError::InvalidAuth(ref context)
+            | EnableError::InvalidCharset(ref context)
+            | EnableError::InvalidFormData(ref context)
+            | EnableError::InvalidJson(ref context)
+            | EnableError::InvalidPostType(ref context)
+            | EnableError::JsonNotObject(ref context)
+            | EnableError::MissingCharset(ref context)
+            | EnableError::MissingPostType(ref context)
+            | EnableError::NoPermission(ref context)
+            | EnableError::NotAuthed(ref context)
+            | EnableError::OrgLoginRequired(ref context)
+            | EnableError::RequestTimeout(ref context)
+            | EnableError::SuperfluousCharset(ref context)
+            | EnableError::TeamAddedToOrg(ref context)
+            | EnableError::TokenRevoked(ref context)
+            | EnableError::UpgradeRequire(ref context)
+            | EnableError::UserIsBot(ref context)
+            | EnableError::UserIsRestricted(ref context)
+            | EnableError::Unknown(_, ref context) => Some(context),
+            EnableError::MalformedResponse(..) | EnableError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct ListRequest {
     /// Include the number of users in each User Group.
@@ -521,13 +874,13 @@ pub struct ListRequest {
     pub include_users: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListPrefsInner {
     pub channels: Vec<String>,
     pub groups: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListUsergroupsInner {
     pub auto_provision: bool,
     pub auto_type: Option<String>,
@@ -540,25 +893,31 @@ pub struct ListUsergroupsInner {
     pub description: String,
     pub enterprise_subteam_id: String,
     pub handle: String,
-    pub id: String,
+    pub id: crate::UsergroupId,
     pub is_external: bool,
     pub is_subteam: bool,
     pub is_usergroup: bool,
     pub name: String,
     pub prefs: ListPrefsInner,
-    pub team_id: String,
+    pub team_id: crate::TeamId,
     pub updated_by: String,
     pub user_count: Option<u64>,
     pub users: Option<Vec<String>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
     pub usergroups: Vec<ListUsergroupsInner>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
@@ -566,100 +925,137 @@ impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(ListError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for ListResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum ListError<E: Error> {
-    AccountInactive,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingCharset,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    OrgLoginRequired,
-    RequestTimeout,
-    SuperfluousCharset,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequire,
-    UserIsBot,
-    UserIsRestricted,
+    AccountInactive(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingCharset(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    SuperfluousCharset(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequire(crate::ErrorContext),
+    UserIsBot(crate::ErrorContext),
+    UserIsRestricted(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> ListError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => ListError::AccountInactive(context),
+            "fatal_error" => ListError::FatalError(context),
+            "invalid_arg_name" => ListError::InvalidArgName(context),
+            "invalid_array_arg" => ListError::InvalidArrayArg(context),
+            "invalid_auth" => ListError::InvalidAuth(context),
+            "invalid_charset" => ListError::InvalidCharset(context),
+            "invalid_form_data" => ListError::InvalidFormData(context),
+            "invalid_json" => ListError::InvalidJson(context),
+            "invalid_post_type" => ListError::InvalidPostType(context),
+            "json_not_object" => ListError::JsonNotObject(context),
+            "missing_charset" => ListError::MissingCharset(context),
+            "missing_post_type" => ListError::MissingPostType(context),
+            "no_permission" => ListError::NoPermission(context),
+            "not_authed" => ListError::NotAuthed(context),
+            "org_login_required" => ListError::OrgLoginRequired(context),
+            "request_timeout" => ListError::RequestTimeout(context),
+            "superfluous_charset" => ListError::SuperfluousCharset(context),
+            "team_added_to_org" => ListError::TeamAddedToOrg(context),
+            "token_revoked" => ListError::TokenRevoked(context),
+            "upgrade_require" => ListError::UpgradeRequire(context),
+            "user_is_bot" => ListError::UserIsBot(context),
+            "user_is_restricted" => ListError::UserIsRestricted(context),
+            _ => ListError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for ListError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => ListError::AccountInactive,
-            "fatal_error" => ListError::FatalError,
-            "invalid_arg_name" => ListError::InvalidArgName,
-            "invalid_array_arg" => ListError::InvalidArrayArg,
-            "invalid_auth" => ListError::InvalidAuth,
-            "invalid_charset" => ListError::InvalidCharset,
-            "invalid_form_data" => ListError::InvalidFormData,
-            "invalid_json" => ListError::InvalidJson,
-            "invalid_post_type" => ListError::InvalidPostType,
-            "json_not_object" => ListError::JsonNotObject,
-            "missing_charset" => ListError::MissingCharset,
-            "missing_post_type" => ListError::MissingPostType,
-            "no_permission" => ListError::NoPermission,
-            "not_authed" => ListError::NotAuthed,
-            "org_login_required" => ListError::OrgLoginRequired,
-            "request_timeout" => ListError::RequestTimeout,
-            "superfluous_charset" => ListError::SuperfluousCharset,
-            "team_added_to_org" => ListError::TeamAddedToOrg,
-            "token_revoked" => ListError::TokenRevoked,
-            "upgrade_require" => ListError::UpgradeRequire,
-            "user_is_bot" => ListError::UserIsBot,
-            "user_is_restricted" => ListError::UserIsRestricted,
-            _ => ListError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for ListError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            ListError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            ListError::FatalError => write!(f, "Server returned error fatal_error"),
-            ListError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            ListError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            ListError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            ListError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            ListError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            ListError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            ListError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            ListError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            ListError::MissingCharset => write!(f, "Server returned error missing_charset"),
-            ListError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            ListError::NoPermission => write!(f, "Server returned error no_permission"),
-            ListError::NotAuthed => write!(f, "Server returned error not_authed"),
-            ListError::OrgLoginRequired => write!(f, "Server returned error org_login_required"),
-            ListError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            ListError::SuperfluousCharset => write!(f, "Server returned error superfluous_charset"),
-            ListError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            ListError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            ListError::UpgradeRequire => write!(f, "Server returned error upgrade_require"),
-            ListError::UserIsBot => write!(f, "Server returned error user_is_bot"),
-            ListError::UserIsRestricted => write!(f, "Server returned error user_is_restricted"),
+            ListError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            ListError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            ListError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            ListError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            ListError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            ListError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            ListError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            ListError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            ListError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            ListError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            ListError::MissingCharset(_) => write!(f, "Server returned error missing_charset"),
+            ListError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            ListError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            ListError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            ListError::OrgLoginRequired(_) => write!(f, "Server returned error org_login_required"),
+            ListError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            ListError::SuperfluousCharset(_) => {
+                write!(f, "Server returned error superfluous_charset")
+            }
+            ListError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            ListError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            ListError::UpgradeRequire(_) => write!(f, "Server returned error upgrade_require"),
+            ListError::UserIsBot(_) => write!(f, "Server returned error user_is_bot"),
+            ListError::UserIsRestricted(_) => write!(f, "Server returned error user_is_restricted"),
             ListError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            ListError::Unknown(ref s) => write!(f, "{}", s),
+            ListError::Unknown(ref s, _) => write!(f, "{}", s),
             ListError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -675,6 +1071,76 @@ impl<E: Error + 'static> Error for ListError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for ListError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            ListError::AccountInactive(_) => Some("account_inactive"),
+            ListError::FatalError(_) => Some("fatal_error"),
+            ListError::InvalidArgName(_) => Some("invalid_arg_name"),
+            ListError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            ListError::InvalidAuth(_) => Some("invalid_auth"),
+            ListError::InvalidCharset(_) => Some("invalid_charset"),
+            ListError::InvalidFormData(_) => Some("invalid_form_data"),
+            ListError::InvalidJson(_) => Some("invalid_json"),
+            ListError::InvalidPostType(_) => Some("invalid_post_type"),
+            ListError::JsonNotObject(_) => Some("json_not_object"),
+            ListError::MissingCharset(_) => Some("missing_charset"),
+            ListError::MissingPostType(_) => Some("missing_post_type"),
+            ListError::NoPermission(_) => Some("no_permission"),
+            ListError::NotAuthed(_) => Some("not_authed"),
+            ListError::OrgLoginRequired(_) => Some("org_login_required"),
+            ListError::RequestTimeout(_) => Some("request_timeout"),
+            ListError::SuperfluousCharset(_) => Some("superfluous_charset"),
+            ListError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            ListError::TokenRevoked(_) => Some("token_revoked"),
+            ListError::UpgradeRequire(_) => Some("upgrade_require"),
+            ListError::UserIsBot(_) => Some("user_is_bot"),
+            ListError::UserIsRestricted(_) => Some("user_is_restricted"),
+            ListError::Unknown(ref s, _) => Some(s),
+            ListError::MalformedResponse(..) | ListError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            ListError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            ListError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            ListError::AccountInactive(ref context)
+            | ListError::FatalError(ref context)
+            | ListError::InvalidArgName(ref context)
+            | ListError::InvalidArrayArg(ref context)
+            | ListError::InvalidAuth(ref context)
+            | ListError::InvalidCharset(ref context)
+            | ListError::InvalidFormData(ref context)
+            | ListError::InvalidJson(ref context)
+            | ListError::InvalidPostType(ref context)
+            | ListError::JsonNotObject(ref context)
+            | ListError::MissingCharset(ref context)
+            | ListError::MissingPostType(ref context)
+            | ListError::NoPermission(ref context)
+            | ListError::NotAuthed(ref context)
+            | ListError::OrgLoginRequired(ref context)
+            | ListError::RequestTimeout(ref context)
+            | ListError::SuperfluousCharset(ref context)
+            | ListError::TeamAddedToOrg(ref context)
+            | ListError::TokenRevoked(ref context)
+            | ListError::UpgradeRequire(ref context)
+            | ListError::UserIsBot(ref context)
+            | ListError::UserIsRestricted(ref context)
+            | ListError::Unknown(_, ref context) => Some(context),
+            ListError::MalformedResponse(..) | ListError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct UpdateRequest<'a> {
     /// A comma separated string of encoded channel IDs for which the User Group uses as a default.
@@ -688,16 +1154,16 @@ pub struct UpdateRequest<'a> {
     /// A name for the User Group. Must be unique among User Groups.
     pub name: Option<Cow<'a, str>>,
     /// The encoded ID of the User Group to update.
-    pub usergroup: Cow<'a, str>,
+    pub usergroup: Option<crate::UsergroupId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UpdatePrefsInner {
     pub channels: Vec<String>,
     pub groups: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UpdateUsergroupInner {
     pub auto_provision: bool,
     pub auto_type: Vec<String>,
@@ -710,25 +1176,31 @@ pub struct UpdateUsergroupInner {
     pub description: String,
     pub enterprise_subteam_id: String,
     pub handle: String,
-    pub id: String,
+    pub id: crate::UsergroupId,
     pub is_external: bool,
     pub is_subteam: bool,
     pub is_usergroup: bool,
     pub name: String,
     pub prefs: UpdatePrefsInner,
-    pub team_id: String,
+    pub team_id: crate::TeamId,
     pub updated_by: String,
     pub user_count: Option<u64>,
     pub users: Option<Vec<String>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UpdateResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
     pub usergroup: UpdateUsergroupInner,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<UpdateResponse, UpdateError<E>>> for UpdateResponse {
@@ -736,105 +1208,146 @@ impl<E: Error> Into<Result<UpdateResponse, UpdateError<E>>> for UpdateResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(UpdateError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for UpdateResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum UpdateError<E: Error> {
-    AccountInactive,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingCharset,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    OrgLoginRequired,
-    PermissionDenied,
-    RequestTimeout,
-    SuperfluousCharset,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequire,
-    UserIsBot,
-    UserIsRestricted,
+    AccountInactive(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingCharset(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    PermissionDenied(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    SuperfluousCharset(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequire(crate::ErrorContext),
+    UserIsBot(crate::ErrorContext),
+    UserIsRestricted(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> UpdateError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => UpdateError::AccountInactive(context),
+            "fatal_error" => UpdateError::FatalError(context),
+            "invalid_arg_name" => UpdateError::InvalidArgName(context),
+            "invalid_array_arg" => UpdateError::InvalidArrayArg(context),
+            "invalid_auth" => UpdateError::InvalidAuth(context),
+            "invalid_charset" => UpdateError::InvalidCharset(context),
+            "invalid_form_data" => UpdateError::InvalidFormData(context),
+            "invalid_json" => UpdateError::InvalidJson(context),
+            "invalid_post_type" => UpdateError::InvalidPostType(context),
+            "json_not_object" => UpdateError::JsonNotObject(context),
+            "missing_charset" => UpdateError::MissingCharset(context),
+            "missing_post_type" => UpdateError::MissingPostType(context),
+            "no_permission" => UpdateError::NoPermission(context),
+            "not_authed" => UpdateError::NotAuthed(context),
+            "org_login_required" => UpdateError::OrgLoginRequired(context),
+            "permission_denied" => UpdateError::PermissionDenied(context),
+            "request_timeout" => UpdateError::RequestTimeout(context),
+            "superfluous_charset" => UpdateError::SuperfluousCharset(context),
+            "team_added_to_org" => UpdateError::TeamAddedToOrg(context),
+            "token_revoked" => UpdateError::TokenRevoked(context),
+            "upgrade_require" => UpdateError::UpgradeRequire(context),
+            "user_is_bot" => UpdateError::UserIsBot(context),
+            "user_is_restricted" => UpdateError::UserIsRestricted(context),
+            _ => UpdateError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for UpdateError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => UpdateError::AccountInactive,
-            "fatal_error" => UpdateError::FatalError,
-            "invalid_arg_name" => UpdateError::InvalidArgName,
-            "invalid_array_arg" => UpdateError::InvalidArrayArg,
-            "invalid_auth" => UpdateError::InvalidAuth,
-            "invalid_charset" => UpdateError::InvalidCharset,
-            "invalid_form_data" => UpdateError::InvalidFormData,
-            "invalid_json" => UpdateError::InvalidJson,
-            "invalid_post_type" => UpdateError::InvalidPostType,
-            "json_not_object" => UpdateError::JsonNotObject,
-            "missing_charset" => UpdateError::MissingCharset,
-            "missing_post_type" => UpdateError::MissingPostType,
-            "no_permission" => UpdateError::NoPermission,
-            "not_authed" => UpdateError::NotAuthed,
-            "org_login_required" => UpdateError::OrgLoginRequired,
-            "permission_denied" => UpdateError::PermissionDenied,
-            "request_timeout" => UpdateError::RequestTimeout,
-            "superfluous_charset" => UpdateError::SuperfluousCharset,
-            "team_added_to_org" => UpdateError::TeamAddedToOrg,
-            "token_revoked" => UpdateError::TokenRevoked,
-            "upgrade_require" => UpdateError::UpgradeRequire,
-            "user_is_bot" => UpdateError::UserIsBot,
-            "user_is_restricted" => UpdateError::UserIsRestricted,
-            _ => UpdateError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for UpdateError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            UpdateError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            UpdateError::FatalError => write!(f, "Server returned error fatal_error"),
-            UpdateError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            UpdateError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            UpdateError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            UpdateError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            UpdateError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            UpdateError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            UpdateError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            UpdateError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            UpdateError::MissingCharset => write!(f, "Server returned error missing_charset"),
-            UpdateError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            UpdateError::NoPermission => write!(f, "Server returned error no_permission"),
-            UpdateError::NotAuthed => write!(f, "Server returned error not_authed"),
-            UpdateError::OrgLoginRequired => write!(f, "Server returned error org_login_required"),
-            UpdateError::PermissionDenied => write!(f, "Server returned error permission_denied"),
-            UpdateError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            UpdateError::SuperfluousCharset => {
+            UpdateError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            UpdateError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            UpdateError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            UpdateError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            UpdateError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            UpdateError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            UpdateError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            UpdateError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            UpdateError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            UpdateError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            UpdateError::MissingCharset(_) => write!(f, "Server returned error missing_charset"),
+            UpdateError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            UpdateError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            UpdateError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            UpdateError::OrgLoginRequired(_) => {
+                write!(f, "Server returned error org_login_required")
+            }
+            UpdateError::PermissionDenied(_) => {
+                write!(f, "Server returned error permission_denied")
+            }
+            UpdateError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            UpdateError::SuperfluousCharset(_) => {
                 write!(f, "Server returned error superfluous_charset")
             }
-            UpdateError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            UpdateError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            UpdateError::UpgradeRequire => write!(f, "Server returned error upgrade_require"),
-            UpdateError::UserIsBot => write!(f, "Server returned error user_is_bot"),
-            UpdateError::UserIsRestricted => write!(f, "Server returned error user_is_restricted"),
+            UpdateError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            UpdateError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            UpdateError::UpgradeRequire(_) => write!(f, "Server returned error upgrade_require"),
+            UpdateError::UserIsBot(_) => write!(f, "Server returned error user_is_bot"),
+            UpdateError::UserIsRestricted(_) => {
+                write!(f, "Server returned error user_is_restricted")
+            }
             UpdateError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            UpdateError::Unknown(ref s) => write!(f, "{}", s),
+            UpdateError::Unknown(ref s, _) => write!(f, "{}", s),
             UpdateError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -849,3 +1362,75 @@ impl<E: Error + 'static> Error for UpdateError<E> {
         }
     }
 }
+
+impl<E: Error + 'static> crate::MethodError for UpdateError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            UpdateError::AccountInactive(_) => Some("account_inactive"),
+            UpdateError::FatalError(_) => Some("fatal_error"),
+            UpdateError::InvalidArgName(_) => Some("invalid_arg_name"),
+            UpdateError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            UpdateError::InvalidAuth(_) => Some("invalid_auth"),
+            UpdateError::InvalidCharset(_) => Some("invalid_charset"),
+            UpdateError::InvalidFormData(_) => Some("invalid_form_data"),
+            UpdateError::InvalidJson(_) => Some("invalid_json"),
+            UpdateError::InvalidPostType(_) => Some("invalid_post_type"),
+            UpdateError::JsonNotObject(_) => Some("json_not_object"),
+            UpdateError::MissingCharset(_) => Some("missing_charset"),
+            UpdateError::MissingPostType(_) => Some("missing_post_type"),
+            UpdateError::NoPermission(_) => Some("no_permission"),
+            UpdateError::NotAuthed(_) => Some("not_authed"),
+            UpdateError::OrgLoginRequired(_) => Some("org_login_required"),
+            UpdateError::PermissionDenied(_) => Some("permission_denied"),
+            UpdateError::RequestTimeout(_) => Some("request_timeout"),
+            UpdateError::SuperfluousCharset(_) => Some("superfluous_charset"),
+            UpdateError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            UpdateError::TokenRevoked(_) => Some("token_revoked"),
+            UpdateError::UpgradeRequire(_) => Some("upgrade_require"),
+            UpdateError::UserIsBot(_) => Some("user_is_bot"),
+            UpdateError::UserIsRestricted(_) => Some("user_is_restricted"),
+            UpdateError::Unknown(ref s, _) => Some(s),
+            UpdateError::MalformedResponse(..) | UpdateError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            UpdateError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            UpdateError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            UpdateError::AccountInactive(ref context)
+            | UpdateError::FatalError(ref context)
+            | UpdateError::InvalidArgName(ref context)
+            | UpdateError::InvalidArrayArg(ref context)
+            | UpdateError::InvalidAuth(ref context)
+            | UpdateError::InvalidCharset(ref context)
+            | UpdateError::InvalidFormData(ref context)
+            | UpdateError::InvalidJson(ref context)
+            | UpdateError::InvalidPostType(ref context)
+            | UpdateError::JsonNotObject(ref context)
+            | UpdateError::MissingCharset(ref context)
+            | UpdateError::MissingPostType(ref context)
+            | UpdateError::NoPermission(ref context)
+            | UpdateError::NotAuthed(ref context)
+            | UpdateError::OrgLoginRequired(ref context)
+            | UpdateError::PermissionDenied(ref context)
+            | UpdateError::RequestTimeout(ref context)
+            | UpdateError::SuperfluousCharset(ref context)
+            | UpdateError::TeamAddedToOrg(ref context)
+            | UpdateError::TokenRevoked(ref context)
+            | UpdateError::UpgradeRequire(ref context)
+            | UpdateError::UserIsBot(ref context)
+            | UpdateError::UserIsRestricted(ref context)
+            | UpdateError::Unknown(_, ref context) => Some(context),
+            UpdateError::MalformedResponse(..) | UpdateError::Client(_) => None,
+        }
+    }
+}
@@ -22,20 +22,26 @@ use std::error::Error;
 use std::fmt;
 
 #[derive(Clone, Default, Debug)]
-pub struct ListRequest<'a> {
+pub struct ListRequest {
     /// Allow results that involve disabled User Groups.
     pub include_disabled: Option<bool>,
     /// The encoded ID of the User Group to update.
-    pub usergroup: Cow<'a, str>,
+    pub usergroup: Option<crate::UsergroupId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
     pub users: Vec<String>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
@@ -43,100 +49,137 @@ impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(ListError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for ListResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum ListError<E: Error> {
-    AccountInactive,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingCharset,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    OrgLoginRequired,
-    RequestTimeout,
-    SuperfluousCharset,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequire,
-    UserIsBot,
-    UserIsRestricted,
+    AccountInactive(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingCharset(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    SuperfluousCharset(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequire(crate::ErrorContext),
+    UserIsBot(crate::ErrorContext),
+    UserIsRestricted(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> ListError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => ListError::AccountInactive(context),
+            "fatal_error" => ListError::FatalError(context),
+            "invalid_arg_name" => ListError::InvalidArgName(context),
+            "invalid_array_arg" => ListError::InvalidArrayArg(context),
+            "invalid_auth" => ListError::InvalidAuth(context),
+            "invalid_charset" => ListError::InvalidCharset(context),
+            "invalid_form_data" => ListError::InvalidFormData(context),
+            "invalid_json" => ListError::InvalidJson(context),
+            "invalid_post_type" => ListError::InvalidPostType(context),
+            "json_not_object" => ListError::JsonNotObject(context),
+            "missing_charset" => ListError::MissingCharset(context),
+            "missing_post_type" => ListError::MissingPostType(context),
+            "no_permission" => ListError::NoPermission(context),
+            "not_authed" => ListError::NotAuthed(context),
+            "org_login_required" => ListError::OrgLoginRequired(context),
+            "request_timeout" => ListError::RequestTimeout(context),
+            "superfluous_charset" => ListError::SuperfluousCharset(context),
+            "team_added_to_org" => ListError::TeamAddedToOrg(context),
+            "token_revoked" => ListError::TokenRevoked(context),
+            "upgrade_require" => ListError::UpgradeRequire(context),
+            "user_is_bot" => ListError::UserIsBot(context),
+            "user_is_restricted" => ListError::UserIsRestricted(context),
+            _ => ListError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for ListError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => ListError::AccountInactive,
-            "fatal_error" => ListError::FatalError,
-            "invalid_arg_name" => ListError::InvalidArgName,
-            "invalid_array_arg" => ListError::InvalidArrayArg,
-            "invalid_auth" => ListError::InvalidAuth,
-            "invalid_charset" => ListError::InvalidCharset,
-            "invalid_form_data" => ListError::InvalidFormData,
-            "invalid_json" => ListError::InvalidJson,
-            "invalid_post_type" => ListError::InvalidPostType,
-            "json_not_object" => ListError::JsonNotObject,
-            "missing_charset" => ListError::MissingCharset,
-            "missing_post_type" => ListError::MissingPostType,
-            "no_permission" => ListError::NoPermission,
-            "not_authed" => ListError::NotAuthed,
-            "org_login_required" => ListError::OrgLoginRequired,
-            "request_timeout" => ListError::RequestTimeout,
-            "superfluous_charset" => ListError::SuperfluousCharset,
-            "team_added_to_org" => ListError::TeamAddedToOrg,
-            "token_revoked" => ListError::TokenRevoked,
-            "upgrade_require" => ListError::UpgradeRequire,
-            "user_is_bot" => ListError::UserIsBot,
-            "user_is_restricted" => ListError::UserIsRestricted,
-            _ => ListError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for ListError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            ListError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            ListError::FatalError => write!(f, "Server returned error fatal_error"),
-            ListError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            ListError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            ListError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            ListError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            ListError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            ListError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            ListError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            ListError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            ListError::MissingCharset => write!(f, "Server returned error missing_charset"),
-            ListError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            ListError::NoPermission => write!(f, "Server returned error no_permission"),
-            ListError::NotAuthed => write!(f, "Server returned error not_authed"),
-            ListError::OrgLoginRequired => write!(f, "Server returned error org_login_required"),
-            ListError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            ListError::SuperfluousCharset => write!(f, "Server returned error superfluous_charset"),
-            ListError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            ListError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            ListError::UpgradeRequire => write!(f, "Server returned error upgrade_require"),
-            ListError::UserIsBot => write!(f, "Server returned error user_is_bot"),
-            ListError::UserIsRestricted => write!(f, "Server returned error user_is_restricted"),
+            ListError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            ListError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            ListError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            ListError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            ListError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            ListError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            ListError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            ListError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            ListError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            ListError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            ListError::MissingCharset(_) => write!(f, "Server returned error missing_charset"),
+            ListError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            ListError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            ListError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            ListError::OrgLoginRequired(_) => write!(f, "Server returned error org_login_required"),
+            ListError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            ListError::SuperfluousCharset(_) => {
+                write!(f, "Server returned error superfluous_charset")
+            }
+            ListError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            ListError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            ListError::UpgradeRequire(_) => write!(f, "Server returned error upgrade_require"),
+            ListError::UserIsBot(_) => write!(f, "Server returned error user_is_bot"),
+            ListError::UserIsRestricted(_) => write!(f, "Server returned error user_is_restricted"),
             ListError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            ListError::Unknown(ref s) => write!(f, "{}", s),
+            ListError::Unknown(ref s, _) => write!(f, "{}", s),
             ListError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -152,23 +195,93 @@ impl<E: Error + 'static> Error for ListError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for ListError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            ListError::AccountInactive(_) => Some("account_inactive"),
+            ListError::FatalError(_) => Some("fatal_error"),
+            ListError::InvalidArgName(_) => Some("invalid_arg_name"),
+            ListError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            ListError::InvalidAuth(_) => Some("invalid_auth"),
+            ListError::InvalidCharset(_) => Some("invalid_charset"),
+            ListError::InvalidFormData(_) => Some("invalid_form_data"),
+            ListError::InvalidJson(_) => Some("invalid_json"),
+            ListError::InvalidPostType(_) => Some("invalid_post_type"),
+            ListError::JsonNotObject(_) => Some("json_not_object"),
+            ListError::MissingCharset(_) => Some("missing_charset"),
+            ListError::MissingPostType(_) => Some("missing_post_type"),
+            ListError::NoPermission(_) => Some("no_permission"),
+            ListError::NotAuthed(_) => Some("not_authed"),
+            ListError::OrgLoginRequired(_) => Some("org_login_required"),
+            ListError::RequestTimeout(_) => Some("request_timeout"),
+            ListError::SuperfluousCharset(_) => Some("superfluous_charset"),
+            ListError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            ListError::TokenRevoked(_) => Some("token_revoked"),
+            ListError::UpgradeRequire(_) => Some("upgrade_require"),
+            ListError::UserIsBot(_) => Some("user_is_bot"),
+            ListError::UserIsRestricted(_) => Some("user_is_restricted"),
+            ListError::Unknown(ref s, _) => Some(s),
+            ListError::MalformedResponse(..) | ListError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            ListError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            ListError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            ListError::AccountInactive(ref context)
+            | ListError::FatalError(ref context)
+            | ListError::InvalidArgName(ref context)
+            | ListError::InvalidArrayArg(ref context)
+            | ListError::InvalidAuth(ref context)
+            | ListError::InvalidCharset(ref context)
+            | ListError::InvalidFormData(ref context)
+            | ListError::InvalidJson(ref context)
+            | ListError::InvalidPostType(ref context)
+            | ListError::JsonNotObject(ref context)
+            | ListError::MissingCharset(ref context)
+            | ListError::MissingPostType(ref context)
+            | ListError::NoPermission(ref context)
+            | ListError::NotAuthed(ref context)
+            | ListError::OrgLoginRequired(ref context)
+            | ListError::RequestTimeout(ref context)
+            | ListError::SuperfluousCharset(ref context)
+            | ListError::TeamAddedToOrg(ref context)
+            | ListError::TokenRevoked(ref context)
+            | ListError::UpgradeRequire(ref context)
+            | ListError::UserIsBot(ref context)
+            | ListError::UserIsRestricted(ref context)
+            | ListError::Unknown(_, ref context) => Some(context),
+            ListError::MalformedResponse(..) | ListError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct UpdateRequest<'a> {
     /// Include the number of users in the User Group.
     pub include_count: Option<bool>,
     /// The encoded ID of the User Group to update.
-    pub usergroup: Cow<'a, str>,
+    pub usergroup: Option<crate::UsergroupId>,
     /// A comma separated string of encoded user IDs that represent the entire list of users for the User Group.
     pub users: Cow<'a, str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UpdatePrefsInner {
     pub channels: Vec<String>,
     pub groups: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UpdateUsergroupInner {
     pub auto_provision: bool,
     pub auto_type: Vec<String>,
@@ -181,25 +294,31 @@ pub struct UpdateUsergroupInner {
     pub description: String,
     pub enterprise_subteam_id: String,
     pub handle: String,
-    pub id: String,
+    pub id: crate::UsergroupId,
     pub is_external: bool,
     pub is_subteam: bool,
     pub is_usergroup: bool,
     pub name: String,
     pub prefs: UpdatePrefsInner,
-    pub team_id: String,
+    pub team_id: crate::TeamId,
     pub updated_by: String,
     pub user_count: Option<u64>,
     pub users: Option<Vec<String>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UpdateResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
     pub usergroup: UpdateUsergroupInner,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<UpdateResponse, UpdateError<E>>> for UpdateResponse {
@@ -207,105 +326,146 @@ impl<E: Error> Into<Result<UpdateResponse, UpdateError<E>>> for UpdateResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(UpdateError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for UpdateResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum UpdateError<E: Error> {
-    AccountInactive,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingCharset,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    OrgLoginRequired,
-    PermissionDenied,
-    RequestTimeout,
-    SuperfluousCharset,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequire,
-    UserIsBot,
-    UserIsRestricted,
+    AccountInactive(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingCharset(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    PermissionDenied(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    SuperfluousCharset(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequire(crate::ErrorContext),
+    UserIsBot(crate::ErrorContext),
+    UserIsRestricted(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> UpdateError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => UpdateError::AccountInactive(context),
+            "fatal_error" => UpdateError::FatalError(context),
+            "invalid_arg_name" => UpdateError::InvalidArgName(context),
+            "invalid_array_arg" => UpdateError::InvalidArrayArg(context),
+            "invalid_auth" => UpdateError::InvalidAuth(context),
+            "invalid_charset" => UpdateError::InvalidCharset(context),
+            "invalid_form_data" => UpdateError::InvalidFormData(context),
+            "invalid_json" => UpdateError::InvalidJson(context),
+            "invalid_post_type" => UpdateError::InvalidPostType(context),
+            "json_not_object" => UpdateError::JsonNotObject(context),
+            "missing_charset" => UpdateError::MissingCharset(context),
+            "missing_post_type" => UpdateError::MissingPostType(context),
+            "no_permission" => UpdateError::NoPermission(context),
+            "not_authed" => UpdateError::NotAuthed(context),
+            "org_login_required" => UpdateError::OrgLoginRequired(context),
+            "permission_denied" => UpdateError::PermissionDenied(context),
+            "request_timeout" => UpdateError::RequestTimeout(context),
+            "superfluous_charset" => UpdateError::SuperfluousCharset(context),
+            "team_added_to_org" => UpdateError::TeamAddedToOrg(context),
+            "token_revoked" => UpdateError::TokenRevoked(context),
+            "upgrade_require" => UpdateError::UpgradeRequire(context),
+            "user_is_bot" => UpdateError::UserIsBot(context),
+            "user_is_restricted" => UpdateError::UserIsRestricted(context),
+            _ => UpdateError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for UpdateError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => UpdateError::AccountInactive,
-            "fatal_error" => UpdateError::FatalError,
-            "invalid_arg_name" => UpdateError::InvalidArgName,
-            "invalid_array_arg" => UpdateError::InvalidArrayArg,
-            "invalid_auth" => UpdateError::InvalidAuth,
-            "invalid_charset" => UpdateError::InvalidCharset,
-            "invalid_form_data" => UpdateError::InvalidFormData,
-            "invalid_json" => UpdateError::InvalidJson,
-            "invalid_post_type" => UpdateError::InvalidPostType,
-            "json_not_object" => UpdateError::JsonNotObject,
-            "missing_charset" => UpdateError::MissingCharset,
-            "missing_post_type" => UpdateError::MissingPostType,
-            "no_permission" => UpdateError::NoPermission,
-            "not_authed" => UpdateError::NotAuthed,
-            "org_login_required" => UpdateError::OrgLoginRequired,
-            "permission_denied" => UpdateError::PermissionDenied,
-            "request_timeout" => UpdateError::RequestTimeout,
-            "superfluous_charset" => UpdateError::SuperfluousCharset,
-            "team_added_to_org" => UpdateError::TeamAddedToOrg,
-            "token_revoked" => UpdateError::TokenRevoked,
-            "upgrade_require" => UpdateError::UpgradeRequire,
-            "user_is_bot" => UpdateError::UserIsBot,
-            "user_is_restricted" => UpdateError::UserIsRestricted,
-            _ => UpdateError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for UpdateError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            UpdateError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            UpdateError::FatalError => write!(f, "Server returned error fatal_error"),
-            UpdateError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            UpdateError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            UpdateError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            UpdateError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            UpdateError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            UpdateError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            UpdateError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            UpdateError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            UpdateError::MissingCharset => write!(f, "Server returned error missing_charset"),
-            UpdateError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            UpdateError::NoPermission => write!(f, "Server returned error no_permission"),
-            UpdateError::NotAuthed => write!(f, "Server returned error not_authed"),
-            UpdateError::OrgLoginRequired => write!(f, "Server returned error org_login_required"),
-            UpdateError::PermissionDenied => write!(f, "Server returned error permission_denied"),
-            UpdateError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            UpdateError::SuperfluousCharset => {
+            UpdateError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            UpdateError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            UpdateError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            UpdateError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            UpdateError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            UpdateError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            UpdateError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            UpdateError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            UpdateError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            UpdateError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            UpdateError::MissingCharset(_) => write!(f, "Server returned error missing_charset"),
+            UpdateError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            UpdateError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            UpdateError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            UpdateError::OrgLoginRequired(_) => {
+                write!(f, "Server returned error org_login_required")
+            }
+            UpdateError::PermissionDenied(_) => {
+                write!(f, "Server returned error permission_denied")
+            }
+            UpdateError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            UpdateError::SuperfluousCharset(_) => {
                 write!(f, "Server returned error superfluous_charset")
             }
-            UpdateError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            UpdateError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            UpdateError::UpgradeRequire => write!(f, "Server returned error upgrade_require"),
-            UpdateError::UserIsBot => write!(f, "Server returned error user_is_bot"),
-            UpdateError::UserIsRestricted => write!(f, "Server returned error user_is_restricted"),
+            UpdateError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            UpdateError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            UpdateError::UpgradeRequire(_) => write!(f, "Server returned error upgrade_require"),
+            UpdateError::UserIsBot(_) => write!(f, "Server returned error user_is_bot"),
+            UpdateError::UserIsRestricted(_) => {
+                write!(f, "Server returned error user_is_restricted")
+            }
             UpdateError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            UpdateError::Unknown(ref s) => write!(f, "{}", s),
+            UpdateError::Unknown(ref s, _) => write!(f, "{}", s),
             UpdateError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -320,3 +480,75 @@ impl<E: Error + 'static> Error for UpdateError<E> {
         }
     }
 }
+
+impl<E: Error + 'static> crate::MethodError for UpdateError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            UpdateError::AccountInactive(_) => Some("account_inactive"),
+            UpdateError::FatalError(_) => Some("fatal_error"),
+            UpdateError::InvalidArgName(_) => Some("invalid_arg_name"),
+            UpdateError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            UpdateError::InvalidAuth(_) => Some("invalid_auth"),
+            UpdateError::InvalidCharset(_) => Some("invalid_charset"),
+            UpdateError::InvalidFormData(_) => Some("invalid_form_data"),
+            UpdateError::InvalidJson(_) => Some("invalid_json"),
+            UpdateError::InvalidPostType(_) => Some("invalid_post_type"),
+            UpdateError::JsonNotObject(_) => Some("json_not_object"),
+            UpdateError::MissingCharset(_) => Some("missing_charset"),
+            UpdateError::MissingPostType(_) => Some("missing_post_type"),
+            UpdateError::NoPermission(_) => Some("no_permission"),
+            UpdateError::NotAuthed(_) => Some("not_authed"),
+            UpdateError::OrgLoginRequired(_) => Some("org_login_required"),
+            UpdateError::PermissionDenied(_) => Some("permission_denied"),
+            UpdateError::RequestTimeout(_) => Some("request_timeout"),
+            UpdateError::SuperfluousCharset(_) => Some("superfluous_charset"),
+            UpdateError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            UpdateError::TokenRevoked(_) => Some("token_revoked"),
+            UpdateError::UpgradeRequire(_) => Some("upgrade_require"),
+            UpdateError::UserIsBot(_) => Some("user_is_bot"),
+            UpdateError::UserIsRestricted(_) => Some("user_is_restricted"),
+            UpdateError::Unknown(ref s, _) => Some(s),
+            UpdateError::MalformedResponse(..) | UpdateError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            UpdateError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            UpdateError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            UpdateError::AccountInactive(ref context)
+            | UpdateError::FatalError(ref context)
+            | UpdateError::InvalidArgName(ref context)
+            | UpdateError::InvalidArrayArg(ref context)
+            | UpdateError::InvalidAuth(ref context)
+            | UpdateError::InvalidCharset(ref context)
+            | UpdateError::InvalidFormData(ref context)
+            | UpdateError::InvalidJson(ref context)
+            | UpdateError::InvalidPostType(ref context)
+            | UpdateError::JsonNotObject(ref context)
+            | UpdateError::MissingCharset(ref context)
+            | UpdateError::MissingPostType(ref context)
+            | UpdateError::NoPermission(ref context)
+            | UpdateError::NotAuthed(ref context)
+            | UpdateError::OrgLoginRequired(ref context)
+            | UpdateError::PermissionDenied(ref context)
+            | UpdateError::RequestTimeout(ref context)
+            | UpdateError::SuperfluousCharset(ref context)
+            | UpdateError::TeamAddedToOrg(ref context)
+            | UpdateError::TokenRevoked(ref context)
+            | UpdateError::UpgradeRequire(ref context)
+            | UpdateError::UserIsBot(ref context)
+            | UpdateError::UserIsRestricted(ref context)
+            | UpdateError::Unknown(_, ref context) => Some(context),
+            UpdateError::MalformedResponse(..) | UpdateError::Client(_) => None,
+        }
+    }
+}
@@ -34,16 +34,22 @@ pub struct ConversationsRequest<'a> {
     /// Mix and match channel types by providing a comma-separated list of any combination of `public_channel`, `private_channel`, `mpim`, `im`
     pub types: Option<Cow<'a, str>>,
     /// Browse conversations by a specific user ID's membership. Non-public channels are restricted to those where the calling user shares membership.
-    pub user: Option<Cow<'a, str>>,
+    pub user: Option<crate::UserId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl crate::pagination::CursorRequest for ConversationsRequest<'_> {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor.into());
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ConversationsDisplayCountsInner {
     pub display_counts: u64,
     pub guest_counts: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ConversationsAttachmentsInner {
     pub fallback: Option<String>,
     pub id: u64,
@@ -53,37 +59,32 @@ pub struct ConversationsAttachmentsInner {
     pub image_width: Option<u64>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct ConversationsBlocksInner {
-    pub r#type: String,
-}
-
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ConversationsIconsInner {
     pub image_36: String,
     pub image_48: String,
     pub image_72: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ConversationsBotProfileInner {
-    pub app_id: String,
+    pub app_id: crate::AppId,
     pub deleted: bool,
     pub icons: ConversationsIconsInner,
-    pub id: String,
+    pub id: crate::BotId,
     pub name: String,
-    pub team_id: String,
+    pub team_id: crate::TeamId,
     pub updated: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ConversationsReactionsInner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ConversationsCommentInner {
     pub comment: String,
     pub created: u64,
@@ -95,23 +96,23 @@ pub struct ConversationsCommentInner {
     pub pinned_to: Option<Vec<String>>,
     pub reactions: Option<Vec<ConversationsReactionsInner>>,
     pub timestamp: u64,
-    pub user: String,
+    pub user: crate::UserId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ConversationsReactions1Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ConversationsSharesInner {
     pub private: Option<serde_json::Value>,
     pub public: Option<serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ConversationsFileInner {
     pub channels: Option<Vec<String>>,
     pub comments_count: Option<u64>,
@@ -126,7 +127,7 @@ pub struct ConversationsFileInner {
     pub filetype: Option<String>,
     pub groups: Option<Vec<String>>,
     pub has_rich_preview: Option<bool>,
-    pub id: Option<String>,
+    pub id: Option<crate::FileId>,
     pub image_exif_rotation: Option<u64>,
     pub ims: Option<Vec<String>>,
     pub is_external: Option<bool>,
@@ -180,25 +181,25 @@ pub struct ConversationsFileInner {
     pub updated: Option<u64>,
     pub url_private: Option<String>,
     pub url_private_download: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ConversationsReactions2Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ConversationsShares1Inner {
     pub private: Option<serde_json::Value>,
     pub public: Option<serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ConversationsFilesInner {
     pub channels: Option<Vec<String>>,
     pub comments_count: Option<u64>,
@@ -213,7 +214,7 @@ pub struct ConversationsFilesInner {
     pub filetype: Option<String>,
     pub groups: Option<Vec<String>>,
     pub has_rich_preview: Option<bool>,
-    pub id: Option<String>,
+    pub id: Option<crate::FileId>,
     pub image_exif_rotation: Option<u64>,
     pub ims: Option<Vec<String>>,
     pub is_external: Option<bool>,
@@ -267,25 +268,25 @@ pub struct ConversationsFilesInner {
     pub updated: Option<u64>,
     pub url_private: Option<String>,
     pub url_private_download: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ConversationsIcons1Inner {
     pub emoji: Option<String>,
     pub image_64: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ConversationsReactions3Inner {
     pub count: u64,
     pub name: String,
     pub users: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ConversationsUserProfileInner {
     pub avatar_hash: String,
     pub display_name: String,
@@ -297,13 +298,13 @@ pub struct ConversationsUserProfileInner {
     pub name: String,
     pub real_name: String,
     pub real_name_normalized: Option<String>,
-    pub team: String,
+    pub team: crate::TeamId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ConversationsLatestInner {
     pub attachments: Option<Vec<ConversationsAttachmentsInner>>,
-    pub blocks: Option<Vec<ConversationsBlocksInner>>,
+    pub blocks: Option<Vec<crate::blocks::Block>>,
     pub bot_id: Option<Vec<String>>,
     pub bot_profile: Option<ConversationsBotProfileInner>,
     pub client_msg_id: Option<String>,
@@ -316,8 +317,8 @@ pub struct ConversationsLatestInner {
     pub is_delayed_message: Option<bool>,
     pub is_intro: Option<bool>,
     pub is_starred: Option<bool>,
-    pub last_read: Option<String>,
-    pub latest_reply: Option<String>,
+    pub last_read: Option<crate::Timestamp>,
+    pub latest_reply: Option<crate::Timestamp>,
     pub name: Option<String>,
     pub old_name: Option<String>,
     pub parent_user_id: Option<String>,
@@ -331,40 +332,40 @@ pub struct ConversationsLatestInner {
     pub source_team: Option<String>,
     pub subscribed: Option<bool>,
     pub subtype: Option<String>,
-    pub team: Option<String>,
+    pub team: Option<crate::TeamId>,
     pub text: String,
-    pub thread_ts: Option<String>,
+    pub thread_ts: Option<crate::Timestamp>,
     pub topic: Option<String>,
-    pub ts: String,
+    pub ts: crate::Timestamp,
     pub r#type: String,
     pub unread_count: Option<u64>,
     pub upload: Option<bool>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub user_profile: Option<ConversationsUserProfileInner>,
     pub user_team: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ConversationsPurposeInner {
     pub creator: String,
     pub last_set: u64,
     pub value: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ConversationsCurrentInner {
     pub date_started: u64,
-    pub team_id: String,
+    pub team_id: crate::TeamId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ConversationsExternalOrgMigrationsInner {
     pub current: Vec<ConversationsCurrentInner>,
     pub date_updated: u64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ConversationsIconInner {
     pub image_102: Option<String>,
     pub image_132: Option<String>,
@@ -376,20 +377,20 @@ pub struct ConversationsIconInner {
     pub image_default: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ConversationsPrimaryOwnerInner {
     pub email: String,
-    pub id: String,
+    pub id: crate::UserId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ConversationsSsoProviderInner {
     pub label: Option<String>,
     pub name: Option<String>,
     pub r#type: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ConversationsTeamInner {
     pub archived: Option<bool>,
     pub avatar_base_url: Option<String>,
@@ -400,12 +401,12 @@ pub struct ConversationsTeamInner {
     pub discoverable: Option<Vec<String>>,
     pub domain: String,
     pub email_domain: String,
-    pub enterprise_id: Option<String>,
+    pub enterprise_id: Option<crate::EnterpriseId>,
     pub enterprise_name: Option<String>,
     pub external_org_migrations: Option<ConversationsExternalOrgMigrationsInner>,
     pub has_compliance_export: Option<bool>,
     pub icon: ConversationsIconInner,
-    pub id: String,
+    pub id: crate::TeamId,
     pub is_assigned: Option<bool>,
     pub is_enterprise: Option<u64>,
     pub is_over_storage_limit: Option<bool>,
@@ -422,22 +423,22 @@ pub struct ConversationsTeamInner {
     pub sso_provider: Option<ConversationsSsoProviderInner>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ConversationsShares2Inner {
     pub accepted_user: Option<String>,
     pub is_active: bool,
     pub team: ConversationsTeamInner,
-    pub user: String,
+    pub user: crate::UserId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ConversationsTopicInner {
     pub creator: String,
     pub last_set: u64,
     pub value: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ConversationsChannelsInner {
     pub accepted_user: Option<String>,
     pub connected_team_ids: Option<Vec<String>>,
@@ -445,9 +446,9 @@ pub struct ConversationsChannelsInner {
     pub created: u64,
     pub creator: String,
     pub display_counts: Option<ConversationsDisplayCountsInner>,
-    pub enterprise_id: Option<String>,
+    pub enterprise_id: Option<crate::EnterpriseId>,
     pub has_pins: Option<bool>,
-    pub id: String,
+    pub id: crate::ChannelId,
     pub internal_team_ids: Option<Vec<String>>,
     pub is_archived: bool,
     pub is_channel: bool,
@@ -471,7 +472,7 @@ pub struct ConversationsChannelsInner {
     pub is_shared: bool,
     pub is_starred: Option<bool>,
     pub is_thread_only: Option<bool>,
-    pub last_read: Option<String>,
+    pub last_read: Option<crate::Timestamp>,
     pub latest: Option<Vec<ConversationsLatestInner>>,
     pub members: Option<Vec<String>>,
     pub name: String,
@@ -492,23 +493,30 @@ pub struct ConversationsChannelsInner {
     pub unread_count: Option<u64>,
     pub unread_count_display: Option<u64>,
     pub use_case: Option<String>,
-    pub user: Option<String>,
+    pub user: Option<crate::UserId>,
     pub version: Option<u64>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ConversationsResponseMetadataInner {
+    pub messages: Option<Vec<String>>,
     pub next_cursor: String,
+    pub warnings: Option<Vec<String>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ConversationsResponse {
     pub callstack: Option<String>,
-    pub channels: Vec<Vec<ConversationsChannelsInner>>,
+    pub channels: Vec<ConversationsChannelsInner>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
     pub response_metadata: Option<ConversationsResponseMetadataInner>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<ConversationsResponse, ConversationsError<E>>>
@@ -518,125 +526,178 @@ impl<E: Error> Into<Result<ConversationsResponse, ConversationsError<E>>>
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(ConversationsError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for ConversationsResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
+impl crate::pagination::CursorPage for ConversationsResponse {
+    type Item = ConversationsChannelsInner;
+
+    fn next_cursor(&self) -> Option<&str> {
+        self.response_metadata
+            .as_ref()
+            .map(|response_metadata| response_metadata.next_cursor.as_str())
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.channels
+    }
+}
+
 #[derive(Debug)]
 pub enum ConversationsError<E: Error> {
-    AccountInactive,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidCursor,
-    InvalidFormData,
-    InvalidJson,
-    InvalidLimit,
-    InvalidPostType,
-    InvalidTypes,
-    JsonNotObject,
-    MethodNotSupportedForChannelType,
-    MissingPostType,
-    MissingScope,
-    NoPermission,
-    NotAuthed,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequired,
+    AccountInactive(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidCursor(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidLimit(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    InvalidTypes(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MethodNotSupportedForChannelType(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    MissingScope(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
-impl<'a, E: Error> From<&'a str> for ConversationsError<E> {
-    fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => ConversationsError::AccountInactive,
-            "fatal_error" => ConversationsError::FatalError,
-            "invalid_arg_name" => ConversationsError::InvalidArgName,
-            "invalid_array_arg" => ConversationsError::InvalidArrayArg,
-            "invalid_auth" => ConversationsError::InvalidAuth,
-            "invalid_charset" => ConversationsError::InvalidCharset,
-            "invalid_cursor" => ConversationsError::InvalidCursor,
-            "invalid_form_data" => ConversationsError::InvalidFormData,
-            "invalid_json" => ConversationsError::InvalidJson,
-            "invalid_limit" => ConversationsError::InvalidLimit,
-            "invalid_post_type" => ConversationsError::InvalidPostType,
-            "invalid_types" => ConversationsError::InvalidTypes,
-            "json_not_object" => ConversationsError::JsonNotObject,
+impl<E: Error> ConversationsError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => ConversationsError::AccountInactive(context),
+            "fatal_error" => ConversationsError::FatalError(context),
+            "invalid_arg_name" => ConversationsError::InvalidArgName(context),
+            "invalid_array_arg" => ConversationsError::InvalidArrayArg(context),
+            "invalid_auth" => ConversationsError::InvalidAuth(context),
+            "invalid_charset" => ConversationsError::InvalidCharset(context),
+            "invalid_cursor" => ConversationsError::InvalidCursor(context),
+            "invalid_form_data" => ConversationsError::InvalidFormData(context),
+            "invalid_json" => ConversationsError::InvalidJson(context),
+            "invalid_limit" => ConversationsError::InvalidLimit(context),
+            "invalid_post_type" => ConversationsError::InvalidPostType(context),
+            "invalid_types" => ConversationsError::InvalidTypes(context),
+            "json_not_object" => ConversationsError::JsonNotObject(context),
             "method_not_supported_for_channel_type" => {
-                ConversationsError::MethodNotSupportedForChannelType
+                ConversationsError::MethodNotSupportedForChannelType(context)
             }
-            "missing_post_type" => ConversationsError::MissingPostType,
-            "missing_scope" => ConversationsError::MissingScope,
-            "no_permission" => ConversationsError::NoPermission,
-            "not_authed" => ConversationsError::NotAuthed,
-            "request_timeout" => ConversationsError::RequestTimeout,
-            "team_added_to_org" => ConversationsError::TeamAddedToOrg,
-            "token_revoked" => ConversationsError::TokenRevoked,
-            "upgrade_required" => ConversationsError::UpgradeRequired,
-            _ => ConversationsError::Unknown(s.to_owned()),
+            "missing_post_type" => ConversationsError::MissingPostType(context),
+            "missing_scope" => ConversationsError::MissingScope(context),
+            "no_permission" => ConversationsError::NoPermission(context),
+            "not_authed" => ConversationsError::NotAuthed(context),
+            "request_timeout" => ConversationsError::RequestTimeout(context),
+            "team_added_to_org" => ConversationsError::TeamAddedToOrg(context),
+            "token_revoked" => ConversationsError::TokenRevoked(context),
+            "upgrade_required" => ConversationsError::UpgradeRequired(context),
+            _ => ConversationsError::Unknown(code.to_owned(), context),
         }
     }
 }
 
+impl<'a, E: Error> From<&'a str> for ConversationsError<E> {
+    fn from(s: &'a str) -> Self {
+        Self::new(s, crate::ErrorContext::default())
+    }
+}
+
 impl<E: Error> fmt::Display for ConversationsError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            ConversationsError::AccountInactive => {
+            ConversationsError::AccountInactive(_) => {
                 write!(f, "Server returned error account_inactive")
             }
-            ConversationsError::FatalError => write!(f, "Server returned error fatal_error"),
-            ConversationsError::InvalidArgName => {
+            ConversationsError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            ConversationsError::InvalidArgName(_) => {
                 write!(f, "Server returned error invalid_arg_name")
             }
-            ConversationsError::InvalidArrayArg => {
+            ConversationsError::InvalidArrayArg(_) => {
                 write!(f, "Server returned error invalid_array_arg")
             }
-            ConversationsError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            ConversationsError::InvalidCharset => {
+            ConversationsError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            ConversationsError::InvalidCharset(_) => {
                 write!(f, "Server returned error invalid_charset")
             }
-            ConversationsError::InvalidCursor => write!(f, "Server returned error invalid_cursor"),
-            ConversationsError::InvalidFormData => {
+            ConversationsError::InvalidCursor(_) => {
+                write!(f, "Server returned error invalid_cursor")
+            }
+            ConversationsError::InvalidFormData(_) => {
                 write!(f, "Server returned error invalid_form_data")
             }
-            ConversationsError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            ConversationsError::InvalidLimit => write!(f, "Server returned error invalid_limit"),
-            ConversationsError::InvalidPostType => {
+            ConversationsError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            ConversationsError::InvalidLimit(_) => write!(f, "Server returned error invalid_limit"),
+            ConversationsError::InvalidPostType(_) => {
                 write!(f, "Server returned error invalid_post_type")
             }
-            ConversationsError::InvalidTypes => write!(f, "Server returned error invalid_types"),
-            ConversationsError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            ConversationsError::MethodNotSupportedForChannelType => write!(
+            ConversationsError::InvalidTypes(_) => write!(f, "Server returned error invalid_types"),
+            ConversationsError::JsonNotObject(_) => {
+                write!(f, "Server returned error json_not_object")
+            }
+            ConversationsError::MethodNotSupportedForChannelType(_) => write!(
                 f,
                 "Server returned error method_not_supported_for_channel_type"
             ),
-            ConversationsError::MissingPostType => {
+            ConversationsError::MissingPostType(_) => {
                 write!(f, "Server returned error missing_post_type")
             }
-            ConversationsError::MissingScope => write!(f, "Server returned error missing_scope"),
-            ConversationsError::NoPermission => write!(f, "Server returned error no_permission"),
-            ConversationsError::NotAuthed => write!(f, "Server returned error not_authed"),
-            ConversationsError::RequestTimeout => {
+            ConversationsError::MissingScope(_) => write!(f, "Server returned error missing_scope"),
+            ConversationsError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            ConversationsError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            ConversationsError::RequestTimeout(_) => {
                 write!(f, "Server returned error request_timeout")
             }
-            ConversationsError::TeamAddedToOrg => {
+            ConversationsError::TeamAddedToOrg(_) => {
                 write!(f, "Server returned error team_added_to_org")
             }
-            ConversationsError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            ConversationsError::UpgradeRequired => {
+            ConversationsError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            ConversationsError::UpgradeRequired(_) => {
                 write!(f, "Server returned error upgrade_required")
             }
             ConversationsError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            ConversationsError::Unknown(ref s) => write!(f, "{}", s),
+            ConversationsError::Unknown(ref s, _) => write!(f, "{}", s),
             ConversationsError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -652,15 +713,93 @@ impl<E: Error + 'static> Error for ConversationsError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for ConversationsError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            ConversationsError::AccountInactive(_) => Some("account_inactive"),
+            ConversationsError::FatalError(_) => Some("fatal_error"),
+            ConversationsError::InvalidArgName(_) => Some("invalid_arg_name"),
+            ConversationsError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            ConversationsError::InvalidAuth(_) => Some("invalid_auth"),
+            ConversationsError::InvalidCharset(_) => Some("invalid_charset"),
+            ConversationsError::InvalidCursor(_) => Some("invalid_cursor"),
+            ConversationsError::InvalidFormData(_) => Some("invalid_form_data"),
+            ConversationsError::InvalidJson(_) => Some("invalid_json"),
+            ConversationsError::InvalidLimit(_) => Some("invalid_limit"),
+            ConversationsError::InvalidPostType(_) => Some("invalid_post_type"),
+            ConversationsError::InvalidTypes(_) => Some("invalid_types"),
+            ConversationsError::JsonNotObject(_) => Some("json_not_object"),
+            ConversationsError::MethodNotSupportedForChannelType(_) => {
+                Some("method_not_supported_for_channel_type")
+            }
+            ConversationsError::MissingPostType(_) => Some("missing_post_type"),
+            ConversationsError::MissingScope(_) => Some("missing_scope"),
+            ConversationsError::NoPermission(_) => Some("no_permission"),
+            ConversationsError::NotAuthed(_) => Some("not_authed"),
+            ConversationsError::RequestTimeout(_) => Some("request_timeout"),
+            ConversationsError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            ConversationsError::TokenRevoked(_) => Some("token_revoked"),
+            ConversationsError::UpgradeRequired(_) => Some("upgrade_required"),
+            ConversationsError::Unknown(ref s, _) => Some(s),
+            ConversationsError::MalformedResponse(..) | ConversationsError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            ConversationsError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            ConversationsError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            ConversationsError::AccountInactive(ref context)
+            | ConversationsError::FatalError(ref context)
+            | ConversationsError::InvalidArgName(ref context)
+            | ConversationsError::InvalidArrayArg(ref context)
+            | ConversationsError::InvalidAuth(ref context)
+            | ConversationsError::InvalidCharset(ref context)
+            | ConversationsError::InvalidCursor(ref context)
+            | ConversationsError::InvalidFormData(ref context)
+            | ConversationsError::InvalidJson(ref context)
+            | ConversationsError::InvalidLimit(ref context)
+            | ConversationsError::InvalidPostType(ref context)
+            | ConversationsError::InvalidTypes(ref context)
+            | ConversationsError::JsonNotObject(ref context)
+            | ConversationsError::MethodNotSupportedForChannelType(ref context)
+            | ConversationsError::MissingPostType(ref context)
+            | ConversationsError::MissingScope(ref context)
+            | ConversationsError::NoPermission(ref context)
+            | ConversationsError::NotAuthed(ref context)
+            | ConversationsError::RequestTimeout(ref context)
+            | ConversationsError::TeamAddedToOrg(ref context)
+            | ConversationsError::TokenRevoked(ref context)
+            | ConversationsError::UpgradeRequired(ref context)
+            | ConversationsError::Unknown(_, ref context) => Some(context),
+            ConversationsError::MalformedResponse(..) | ConversationsError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct DeletePhotoRequest {}
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct DeletePhotoResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<DeletePhotoResponse, DeletePhotoError<E>>> for DeletePhotoResponse {
@@ -668,107 +807,150 @@ impl<E: Error> Into<Result<DeletePhotoResponse, DeletePhotoError<E>>> for Delete
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(DeletePhotoError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for DeletePhotoResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum DeletePhotoError<E: Error> {
-    AccountInactive,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    OrgLoginRequired,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequired,
-    UserIsBot,
+    AccountInactive(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserIsBot(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> DeletePhotoError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => DeletePhotoError::AccountInactive(context),
+            "fatal_error" => DeletePhotoError::FatalError(context),
+            "invalid_arg_name" => DeletePhotoError::InvalidArgName(context),
+            "invalid_array_arg" => DeletePhotoError::InvalidArrayArg(context),
+            "invalid_auth" => DeletePhotoError::InvalidAuth(context),
+            "invalid_charset" => DeletePhotoError::InvalidCharset(context),
+            "invalid_form_data" => DeletePhotoError::InvalidFormData(context),
+            "invalid_json" => DeletePhotoError::InvalidJson(context),
+            "invalid_post_type" => DeletePhotoError::InvalidPostType(context),
+            "json_not_object" => DeletePhotoError::JsonNotObject(context),
+            "missing_post_type" => DeletePhotoError::MissingPostType(context),
+            "no_permission" => DeletePhotoError::NoPermission(context),
+            "not_authed" => DeletePhotoError::NotAuthed(context),
+            "org_login_required" => DeletePhotoError::OrgLoginRequired(context),
+            "request_timeout" => DeletePhotoError::RequestTimeout(context),
+            "team_added_to_org" => DeletePhotoError::TeamAddedToOrg(context),
+            "token_revoked" => DeletePhotoError::TokenRevoked(context),
+            "upgrade_required" => DeletePhotoError::UpgradeRequired(context),
+            "user_is_bot" => DeletePhotoError::UserIsBot(context),
+            _ => DeletePhotoError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for DeletePhotoError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => DeletePhotoError::AccountInactive,
-            "fatal_error" => DeletePhotoError::FatalError,
-            "invalid_arg_name" => DeletePhotoError::InvalidArgName,
-            "invalid_array_arg" => DeletePhotoError::InvalidArrayArg,
-            "invalid_auth" => DeletePhotoError::InvalidAuth,
-            "invalid_charset" => DeletePhotoError::InvalidCharset,
-            "invalid_form_data" => DeletePhotoError::InvalidFormData,
-            "invalid_json" => DeletePhotoError::InvalidJson,
-            "invalid_post_type" => DeletePhotoError::InvalidPostType,
-            "json_not_object" => DeletePhotoError::JsonNotObject,
-            "missing_post_type" => DeletePhotoError::MissingPostType,
-            "no_permission" => DeletePhotoError::NoPermission,
-            "not_authed" => DeletePhotoError::NotAuthed,
-            "org_login_required" => DeletePhotoError::OrgLoginRequired,
-            "request_timeout" => DeletePhotoError::RequestTimeout,
-            "team_added_to_org" => DeletePhotoError::TeamAddedToOrg,
-            "token_revoked" => DeletePhotoError::TokenRevoked,
-            "upgrade_required" => DeletePhotoError::UpgradeRequired,
-            "user_is_bot" => DeletePhotoError::UserIsBot,
-            _ => DeletePhotoError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for DeletePhotoError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            DeletePhotoError::AccountInactive => {
+            DeletePhotoError::AccountInactive(_) => {
                 write!(f, "Server returned error account_inactive")
             }
-            DeletePhotoError::FatalError => write!(f, "Server returned error fatal_error"),
-            DeletePhotoError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            DeletePhotoError::InvalidArrayArg => {
+            DeletePhotoError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            DeletePhotoError::InvalidArgName(_) => {
+                write!(f, "Server returned error invalid_arg_name")
+            }
+            DeletePhotoError::InvalidArrayArg(_) => {
                 write!(f, "Server returned error invalid_array_arg")
             }
-            DeletePhotoError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            DeletePhotoError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            DeletePhotoError::InvalidFormData => {
+            DeletePhotoError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            DeletePhotoError::InvalidCharset(_) => {
+                write!(f, "Server returned error invalid_charset")
+            }
+            DeletePhotoError::InvalidFormData(_) => {
                 write!(f, "Server returned error invalid_form_data")
             }
-            DeletePhotoError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            DeletePhotoError::InvalidPostType => {
+            DeletePhotoError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            DeletePhotoError::InvalidPostType(_) => {
                 write!(f, "Server returned error invalid_post_type")
             }
-            DeletePhotoError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            DeletePhotoError::MissingPostType => {
+            DeletePhotoError::JsonNotObject(_) => {
+                write!(f, "Server returned error json_not_object")
+            }
+            DeletePhotoError::MissingPostType(_) => {
                 write!(f, "Server returned error missing_post_type")
             }
-            DeletePhotoError::NoPermission => write!(f, "Server returned error no_permission"),
-            DeletePhotoError::NotAuthed => write!(f, "Server returned error not_authed"),
-            DeletePhotoError::OrgLoginRequired => {
+            DeletePhotoError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            DeletePhotoError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            DeletePhotoError::OrgLoginRequired(_) => {
                 write!(f, "Server returned error org_login_required")
             }
-            DeletePhotoError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            DeletePhotoError::TeamAddedToOrg => {
+            DeletePhotoError::RequestTimeout(_) => {
+                write!(f, "Server returned error request_timeout")
+            }
+            DeletePhotoError::TeamAddedToOrg(_) => {
                 write!(f, "Server returned error team_added_to_org")
             }
-            DeletePhotoError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            DeletePhotoError::UpgradeRequired => {
+            DeletePhotoError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            DeletePhotoError::UpgradeRequired(_) => {
                 write!(f, "Server returned error upgrade_required")
             }
-            DeletePhotoError::UserIsBot => write!(f, "Server returned error user_is_bot"),
+            DeletePhotoError::UserIsBot(_) => write!(f, "Server returned error user_is_bot"),
             DeletePhotoError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            DeletePhotoError::Unknown(ref s) => write!(f, "{}", s),
+            DeletePhotoError::Unknown(ref s, _) => write!(f, "{}", s),
             DeletePhotoError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -784,23 +966,93 @@ impl<E: Error + 'static> Error for DeletePhotoError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for DeletePhotoError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            DeletePhotoError::AccountInactive(_) => Some("account_inactive"),
+            DeletePhotoError::FatalError(_) => Some("fatal_error"),
+            DeletePhotoError::InvalidArgName(_) => Some("invalid_arg_name"),
+            DeletePhotoError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            DeletePhotoError::InvalidAuth(_) => Some("invalid_auth"),
+            DeletePhotoError::InvalidCharset(_) => Some("invalid_charset"),
+            DeletePhotoError::InvalidFormData(_) => Some("invalid_form_data"),
+            DeletePhotoError::InvalidJson(_) => Some("invalid_json"),
+            DeletePhotoError::InvalidPostType(_) => Some("invalid_post_type"),
+            DeletePhotoError::JsonNotObject(_) => Some("json_not_object"),
+            DeletePhotoError::MissingPostType(_) => Some("missing_post_type"),
+            DeletePhotoError::NoPermission(_) => Some("no_permission"),
+            DeletePhotoError::NotAuthed(_) => Some("not_authed"),
+            DeletePhotoError::OrgLoginRequired(_) => Some("org_login_required"),
+            DeletePhotoError::RequestTimeout(_) => Some("request_timeout"),
+            DeletePhotoError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            DeletePhotoError::TokenRevoked(_) => Some("token_revoked"),
+            DeletePhotoError::UpgradeRequired(_) => Some("upgrade_required"),
+            DeletePhotoError::UserIsBot(_) => Some("user_is_bot"),
+            DeletePhotoError::Unknown(ref s, _) => Some(s),
+            DeletePhotoError::MalformedResponse(..) | DeletePhotoError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            DeletePhotoError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            DeletePhotoError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            DeletePhotoError::AccountInactive(ref context)
+            | DeletePhotoError::FatalError(ref context)
+            | DeletePhotoError::InvalidArgName(ref context)
+            | DeletePhotoError::InvalidArrayArg(ref context)
+            | DeletePhotoError::InvalidAuth(ref context)
+            | DeletePhotoError::InvalidCharset(ref context)
+            | DeletePhotoError::InvalidFormData(ref context)
+            | DeletePhotoError::InvalidJson(ref context)
+            | DeletePhotoError::InvalidPostType(ref context)
+            | DeletePhotoError::JsonNotObject(ref context)
+            | DeletePhotoError::MissingPostType(ref context)
+            | DeletePhotoError::NoPermission(ref context)
+            | DeletePhotoError::NotAuthed(ref context)
+            | DeletePhotoError::OrgLoginRequired(ref context)
+            | DeletePhotoError::RequestTimeout(ref context)
+            | DeletePhotoError::TeamAddedToOrg(ref context)
+            | DeletePhotoError::TokenRevoked(ref context)
+            | DeletePhotoError::UpgradeRequired(ref context)
+            | DeletePhotoError::UserIsBot(ref context)
+            | DeletePhotoError::Unknown(_, ref context) => Some(context),
+            DeletePhotoError::MalformedResponse(..) | DeletePhotoError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
-pub struct GetPresenceRequest<'a> {
+pub struct GetPresenceRequest {
     /// User to get presence info on. Defaults to the authed user.
-    pub user: Option<Cow<'a, str>>,
+    pub user: Option<crate::UserId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GetPresenceResponse {
     pub auto_away: Option<bool>,
     pub connection_count: Option<u64>,
     error: Option<String>,
     pub last_activity: Option<u64>,
     pub manual_away: Option<bool>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
     pub online: Option<bool>,
     pub presence: String,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<GetPresenceResponse, GetPresenceError<E>>> for GetPresenceResponse {
@@ -808,26 +1060,61 @@ impl<E: Error> Into<Result<GetPresenceResponse, GetPresenceError<E>>> for GetPre
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(GetPresenceError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for GetPresenceResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum GetPresenceError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> GetPresenceError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => GetPresenceError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for GetPresenceError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => GetPresenceError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -835,7 +1122,7 @@ impl<E: Error> fmt::Display for GetPresenceError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             GetPresenceError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            GetPresenceError::Unknown(ref s) => write!(f, "{}", s),
+            GetPresenceError::Unknown(ref s, _) => write!(f, "{}", s),
             GetPresenceError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -851,21 +1138,47 @@ impl<E: Error + 'static> Error for GetPresenceError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for GetPresenceError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            GetPresenceError::Unknown(ref s, _) => Some(s),
+            GetPresenceError::MalformedResponse(..) | GetPresenceError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            GetPresenceError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            GetPresenceError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            GetPresenceError::Unknown(_, ref context) => Some(context),
+            GetPresenceError::MalformedResponse(..) | GetPresenceError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct IdentityRequest {}
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct IdentityTeamInner {
-    pub id: String,
+    pub id: Option<crate::TeamId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct IdentityUserInner {
-    pub id: String,
+    pub id: crate::UserId,
     pub name: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct IdentityResultInner {
     #[serde(default)]
     ok: bool,
@@ -873,9 +1186,11 @@ pub struct IdentityResultInner {
     pub user: IdentityUserInner,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct IdentityResponse {
     pub result: IdentityResultInner,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<IdentityResponse, IdentityError<E>>> for IdentityResponse {
@@ -885,93 +1200,125 @@ impl<E: Error> Into<Result<IdentityResponse, IdentityError<E>>> for IdentityResp
         } else {
             Err(IdentityError::Unknown(
                 "Server failed without providing an error message.".into(),
+                crate::ErrorContext::default(),
             ))
         }
     }
 }
 
+impl crate::SlackResponse for IdentityResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        Vec::new()
+    }
+}
+
 #[derive(Debug)]
 pub enum IdentityError<E: Error> {
-    AccountInactive,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    OrgLoginRequired,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequired,
-    UserIsBot,
+    AccountInactive(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserIsBot(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> IdentityError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => IdentityError::AccountInactive(context),
+            "fatal_error" => IdentityError::FatalError(context),
+            "invalid_arg_name" => IdentityError::InvalidArgName(context),
+            "invalid_array_arg" => IdentityError::InvalidArrayArg(context),
+            "invalid_auth" => IdentityError::InvalidAuth(context),
+            "invalid_charset" => IdentityError::InvalidCharset(context),
+            "invalid_form_data" => IdentityError::InvalidFormData(context),
+            "invalid_json" => IdentityError::InvalidJson(context),
+            "invalid_post_type" => IdentityError::InvalidPostType(context),
+            "json_not_object" => IdentityError::JsonNotObject(context),
+            "missing_post_type" => IdentityError::MissingPostType(context),
+            "no_permission" => IdentityError::NoPermission(context),
+            "not_authed" => IdentityError::NotAuthed(context),
+            "org_login_required" => IdentityError::OrgLoginRequired(context),
+            "request_timeout" => IdentityError::RequestTimeout(context),
+            "team_added_to_org" => IdentityError::TeamAddedToOrg(context),
+            "token_revoked" => IdentityError::TokenRevoked(context),
+            "upgrade_required" => IdentityError::UpgradeRequired(context),
+            "user_is_bot" => IdentityError::UserIsBot(context),
+            _ => IdentityError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for IdentityError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => IdentityError::AccountInactive,
-            "fatal_error" => IdentityError::FatalError,
-            "invalid_arg_name" => IdentityError::InvalidArgName,
-            "invalid_array_arg" => IdentityError::InvalidArrayArg,
-            "invalid_auth" => IdentityError::InvalidAuth,
-            "invalid_charset" => IdentityError::InvalidCharset,
-            "invalid_form_data" => IdentityError::InvalidFormData,
-            "invalid_json" => IdentityError::InvalidJson,
-            "invalid_post_type" => IdentityError::InvalidPostType,
-            "json_not_object" => IdentityError::JsonNotObject,
-            "missing_post_type" => IdentityError::MissingPostType,
-            "no_permission" => IdentityError::NoPermission,
-            "not_authed" => IdentityError::NotAuthed,
-            "org_login_required" => IdentityError::OrgLoginRequired,
-            "request_timeout" => IdentityError::RequestTimeout,
-            "team_added_to_org" => IdentityError::TeamAddedToOrg,
-            "token_revoked" => IdentityError::TokenRevoked,
-            "upgrade_required" => IdentityError::UpgradeRequired,
-            "user_is_bot" => IdentityError::UserIsBot,
-            _ => IdentityError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for IdentityError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            IdentityError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            IdentityError::FatalError => write!(f, "Server returned error fatal_error"),
-            IdentityError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            IdentityError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            IdentityError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            IdentityError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            IdentityError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            IdentityError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            IdentityError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            IdentityError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            IdentityError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            IdentityError::NoPermission => write!(f, "Server returned error no_permission"),
-            IdentityError::NotAuthed => write!(f, "Server returned error not_authed"),
-            IdentityError::OrgLoginRequired => {
+            IdentityError::AccountInactive(_) => {
+                write!(f, "Server returned error account_inactive")
+            }
+            IdentityError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            IdentityError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            IdentityError::InvalidArrayArg(_) => {
+                write!(f, "Server returned error invalid_array_arg")
+            }
+            IdentityError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            IdentityError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            IdentityError::InvalidFormData(_) => {
+                write!(f, "Server returned error invalid_form_data")
+            }
+            IdentityError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            IdentityError::InvalidPostType(_) => {
+                write!(f, "Server returned error invalid_post_type")
+            }
+            IdentityError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            IdentityError::MissingPostType(_) => {
+                write!(f, "Server returned error missing_post_type")
+            }
+            IdentityError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            IdentityError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            IdentityError::OrgLoginRequired(_) => {
                 write!(f, "Server returned error org_login_required")
             }
-            IdentityError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            IdentityError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            IdentityError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            IdentityError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
-            IdentityError::UserIsBot => write!(f, "Server returned error user_is_bot"),
+            IdentityError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            IdentityError::TeamAddedToOrg(_) => {
+                write!(f, "Server returned error team_added_to_org")
+            }
+            IdentityError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            IdentityError::UpgradeRequired(_) => {
+                write!(f, "Server returned error upgrade_required")
+            }
+            IdentityError::UserIsBot(_) => write!(f, "Server returned error user_is_bot"),
             IdentityError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            IdentityError::Unknown(ref s) => write!(f, "{}", s),
+            IdentityError::Unknown(ref s, _) => write!(f, "{}", s),
             IdentityError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -987,30 +1334,94 @@ impl<E: Error + 'static> Error for IdentityError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for IdentityError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            IdentityError::AccountInactive(_) => Some("account_inactive"),
+            IdentityError::FatalError(_) => Some("fatal_error"),
+            IdentityError::InvalidArgName(_) => Some("invalid_arg_name"),
+            IdentityError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            IdentityError::InvalidAuth(_) => Some("invalid_auth"),
+            IdentityError::InvalidCharset(_) => Some("invalid_charset"),
+            IdentityError::InvalidFormData(_) => Some("invalid_form_data"),
+            IdentityError::InvalidJson(_) => Some("invalid_json"),
+            IdentityError::InvalidPostType(_) => Some("invalid_post_type"),
+            IdentityError::JsonNotObject(_) => Some("json_not_object"),
+            IdentityError::MissingPostType(_) => Some("missing_post_type"),
+            IdentityError::NoPermission(_) => Some("no_permission"),
+            IdentityError::NotAuthed(_) => Some("not_authed"),
+            IdentityError::OrgLoginRequired(_) => Some("org_login_required"),
+            IdentityError::RequestTimeout(_) => Some("request_timeout"),
+            IdentityError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            IdentityError::TokenRevoked(_) => Some("token_revoked"),
+            IdentityError::UpgradeRequired(_) => Some("upgrade_required"),
+            IdentityError::UserIsBot(_) => Some("user_is_bot"),
+            IdentityError::Unknown(ref s, _) => Some(s),
+            IdentityError::MalformedResponse(..) | IdentityError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            IdentityError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            IdentityError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            IdentityError::AccountInactive(ref context)
+            | IdentityError::FatalError(ref context)
+            | IdentityError::InvalidArgName(ref context)
+            | IdentityError::InvalidArrayArg(ref context)
+            | IdentityError::InvalidAuth(ref context)
+            | IdentityError::InvalidCharset(ref context)
+            | IdentityError::InvalidFormData(ref context)
+            | IdentityError::InvalidJson(ref context)
+            | IdentityError::InvalidPostType(ref context)
+            | IdentityError::JsonNotObject(ref context)
+            | IdentityError::MissingPostType(ref context)
+            | IdentityError::NoPermission(ref context)
+            | IdentityError::NotAuthed(ref context)
+            | IdentityError::OrgLoginRequired(ref context)
+            | IdentityError::RequestTimeout(ref context)
+            | IdentityError::TeamAddedToOrg(ref context)
+            | IdentityError::TokenRevoked(ref context)
+            | IdentityError::UpgradeRequired(ref context)
+            | IdentityError::UserIsBot(ref context)
+            | IdentityError::Unknown(_, ref context) => Some(context),
+            IdentityError::MalformedResponse(..) | IdentityError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
-pub struct InfoRequest<'a> {
+pub struct InfoRequest {
     /// Set this to `true` to receive the locale for this user. Defaults to `false`
     pub include_locale: Option<bool>,
     /// User to get info on
-    pub user: Option<Cow<'a, str>>,
+    pub user: Option<crate::UserId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoEnterpriseUserInner {
-    pub enterprise_id: String,
+    pub enterprise_id: crate::EnterpriseId,
     pub enterprise_name: String,
-    pub id: String,
+    pub id: crate::UserId,
     pub is_admin: bool,
     pub is_owner: bool,
     pub teams: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoProfileInner {
     pub always_active: Option<bool>,
     pub api_app_id: Option<String>,
     pub avatar_hash: String,
-    pub bot_id: Option<String>,
+    pub bot_id: Option<crate::BotId>,
     pub display_name: String,
     pub display_name_normalized: String,
     pub email: Option<String>,
@@ -1046,14 +1457,14 @@ pub struct InfoProfileInner {
     pub status_expiration: Option<u64>,
     pub status_text: String,
     pub status_text_canonical: Option<String>,
-    pub team: Option<String>,
+    pub team: Option<crate::TeamId>,
     pub title: String,
     pub updated: Option<u64>,
-    pub user_id: Option<String>,
+    pub user_id: Option<crate::UserId>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoOptionsInner {
     pub is_custom: Option<bool>,
     pub is_multiple_entry: Option<bool>,
@@ -1061,7 +1472,7 @@ pub struct InfoOptionsInner {
     pub is_scim: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoFieldsInner {
     pub field_name: Option<String>,
     pub hint: String,
@@ -1074,18 +1485,18 @@ pub struct InfoFieldsInner {
     pub r#type: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoTeamProfileInner {
     pub fields: Vec<InfoFieldsInner>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoUserInner {
     pub color: Option<String>,
     pub deleted: Option<bool>,
     pub enterprise_user: Option<InfoEnterpriseUserInner>,
     pub has_2fa: Option<bool>,
-    pub id: String,
+    pub id: crate::UserId,
     pub is_admin: Option<bool>,
     pub is_app_user: bool,
     pub is_bot: bool,
@@ -1102,8 +1513,8 @@ pub struct InfoUserInner {
     pub presence: Option<String>,
     pub profile: InfoProfileInner,
     pub real_name: Option<String>,
-    pub team: Option<String>,
-    pub team_id: Option<String>,
+    pub team: Option<crate::TeamId>,
+    pub team_id: Option<crate::TeamId>,
     pub team_profile: Option<InfoTeamProfileInner>,
     pub two_factor_type: Option<String>,
     pub tz: Option<Vec<String>>,
@@ -1112,13 +1523,19 @@ pub struct InfoUserInner {
     pub updated: f64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
     pub user: Vec<InfoUserInner>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<InfoResponse, InfoError<E>>> for InfoResponse {
@@ -1126,82 +1543,117 @@ impl<E: Error> Into<Result<InfoResponse, InfoError<E>>> for InfoResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(InfoError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for InfoResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum InfoError<E: Error> {
-    AccountInactive,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    NotAuthed,
-    RequestTimeout,
-    TeamAddedToOrg,
-    UpgradeRequired,
-    UserNotFound,
-    UserNotVisible,
+    AccountInactive(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserNotFound(crate::ErrorContext),
+    UserNotVisible(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> InfoError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => InfoError::AccountInactive(context),
+            "invalid_arg_name" => InfoError::InvalidArgName(context),
+            "invalid_array_arg" => InfoError::InvalidArrayArg(context),
+            "invalid_auth" => InfoError::InvalidAuth(context),
+            "invalid_charset" => InfoError::InvalidCharset(context),
+            "invalid_form_data" => InfoError::InvalidFormData(context),
+            "invalid_json" => InfoError::InvalidJson(context),
+            "invalid_post_type" => InfoError::InvalidPostType(context),
+            "json_not_object" => InfoError::JsonNotObject(context),
+            "missing_post_type" => InfoError::MissingPostType(context),
+            "not_authed" => InfoError::NotAuthed(context),
+            "request_timeout" => InfoError::RequestTimeout(context),
+            "team_added_to_org" => InfoError::TeamAddedToOrg(context),
+            "upgrade_required" => InfoError::UpgradeRequired(context),
+            "user_not_found" => InfoError::UserNotFound(context),
+            "user_not_visible" => InfoError::UserNotVisible(context),
+            _ => InfoError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for InfoError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => InfoError::AccountInactive,
-            "invalid_arg_name" => InfoError::InvalidArgName,
-            "invalid_array_arg" => InfoError::InvalidArrayArg,
-            "invalid_auth" => InfoError::InvalidAuth,
-            "invalid_charset" => InfoError::InvalidCharset,
-            "invalid_form_data" => InfoError::InvalidFormData,
-            "invalid_json" => InfoError::InvalidJson,
-            "invalid_post_type" => InfoError::InvalidPostType,
-            "json_not_object" => InfoError::JsonNotObject,
-            "missing_post_type" => InfoError::MissingPostType,
-            "not_authed" => InfoError::NotAuthed,
-            "request_timeout" => InfoError::RequestTimeout,
-            "team_added_to_org" => InfoError::TeamAddedToOrg,
-            "upgrade_required" => InfoError::UpgradeRequired,
-            "user_not_found" => InfoError::UserNotFound,
-            "user_not_visible" => InfoError::UserNotVisible,
-            _ => InfoError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for InfoError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            InfoError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            InfoError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            InfoError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            InfoError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            InfoError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            InfoError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            InfoError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            InfoError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            InfoError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            InfoError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            InfoError::NotAuthed => write!(f, "Server returned error not_authed"),
-            InfoError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            InfoError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            InfoError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
-            InfoError::UserNotFound => write!(f, "Server returned error user_not_found"),
-            InfoError::UserNotVisible => write!(f, "Server returned error user_not_visible"),
+            InfoError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            InfoError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            InfoError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            InfoError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            InfoError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            InfoError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            InfoError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            InfoError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            InfoError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            InfoError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            InfoError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            InfoError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            InfoError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            InfoError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
+            InfoError::UserNotFound(_) => write!(f, "Server returned error user_not_found"),
+            InfoError::UserNotVisible(_) => write!(f, "Server returned error user_not_visible"),
             InfoError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            InfoError::Unknown(ref s) => write!(f, "{}", s),
+            InfoError::Unknown(ref s, _) => write!(f, "{}", s),
             InfoError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -1217,6 +1669,64 @@ impl<E: Error + 'static> Error for InfoError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for InfoError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            InfoError::AccountInactive(_) => Some("account_inactive"),
+            InfoError::InvalidArgName(_) => Some("invalid_arg_name"),
+            InfoError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            InfoError::InvalidAuth(_) => Some("invalid_auth"),
+            InfoError::InvalidCharset(_) => Some("invalid_charset"),
+            InfoError::InvalidFormData(_) => Some("invalid_form_data"),
+            InfoError::InvalidJson(_) => Some("invalid_json"),
+            InfoError::InvalidPostType(_) => Some("invalid_post_type"),
+            InfoError::JsonNotObject(_) => Some("json_not_object"),
+            InfoError::MissingPostType(_) => Some("missing_post_type"),
+            InfoError::NotAuthed(_) => Some("not_authed"),
+            InfoError::RequestTimeout(_) => Some("request_timeout"),
+            InfoError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            InfoError::UpgradeRequired(_) => Some("upgrade_required"),
+            InfoError::UserNotFound(_) => Some("user_not_found"),
+            InfoError::UserNotVisible(_) => Some("user_not_visible"),
+            InfoError::Unknown(ref s, _) => Some(s),
+            InfoError::MalformedResponse(..) | InfoError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            InfoError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            InfoError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            InfoError::AccountInactive(ref context)
+            | InfoError::InvalidArgName(ref context)
+            | InfoError::InvalidArrayArg(ref context)
+            | InfoError::InvalidAuth(ref context)
+            | InfoError::InvalidCharset(ref context)
+            | InfoError::InvalidFormData(ref context)
+            | InfoError::InvalidJson(ref context)
+            | InfoError::InvalidPostType(ref context)
+            | InfoError::JsonNotObject(ref context)
+            | InfoError::MissingPostType(ref context)
+            | InfoError::NotAuthed(ref context)
+            | InfoError::RequestTimeout(ref context)
+            | InfoError::TeamAddedToOrg(ref context)
+            | InfoError::UpgradeRequired(ref context)
+            | InfoError::UserNotFound(ref context)
+            | InfoError::UserNotVisible(ref context)
+            | InfoError::Unknown(_, ref context) => Some(context),
+            InfoError::MalformedResponse(..) | InfoError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct ListRequest<'a> {
     /// Paginate through collections of data by setting the `cursor` parameter to a `next_cursor` attribute returned by a previous request's `response_metadata`. Default value fetches the first "page" of the collection. See [pagination](/docs/pagination) for more detail.
@@ -1227,22 +1737,28 @@ pub struct ListRequest<'a> {
     pub limit: Option<u64>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl crate::pagination::CursorRequest for ListRequest<'_> {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor.into());
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListEnterpriseUserInner {
-    pub enterprise_id: String,
+    pub enterprise_id: crate::EnterpriseId,
     pub enterprise_name: String,
-    pub id: String,
+    pub id: crate::UserId,
     pub is_admin: bool,
     pub is_owner: bool,
     pub teams: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListProfileInner {
     pub always_active: Option<bool>,
     pub api_app_id: Option<String>,
     pub avatar_hash: String,
-    pub bot_id: Option<String>,
+    pub bot_id: Option<crate::BotId>,
     pub display_name: String,
     pub display_name_normalized: String,
     pub email: Option<String>,
@@ -1278,14 +1794,14 @@ pub struct ListProfileInner {
     pub status_expiration: Option<u64>,
     pub status_text: String,
     pub status_text_canonical: Option<String>,
-    pub team: Option<String>,
+    pub team: Option<crate::TeamId>,
     pub title: String,
     pub updated: Option<u64>,
-    pub user_id: Option<String>,
+    pub user_id: Option<crate::UserId>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListOptionsInner {
     pub is_custom: Option<bool>,
     pub is_multiple_entry: Option<bool>,
@@ -1293,7 +1809,7 @@ pub struct ListOptionsInner {
     pub is_scim: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListFieldsInner {
     pub field_name: Option<String>,
     pub hint: String,
@@ -1306,18 +1822,18 @@ pub struct ListFieldsInner {
     pub r#type: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListTeamProfileInner {
     pub fields: Vec<ListFieldsInner>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListMembersInner {
     pub color: Option<String>,
     pub deleted: Option<bool>,
     pub enterprise_user: Option<ListEnterpriseUserInner>,
     pub has_2fa: Option<bool>,
-    pub id: String,
+    pub id: crate::UserId,
     pub is_admin: Option<bool>,
     pub is_app_user: bool,
     pub is_bot: bool,
@@ -1334,8 +1850,8 @@ pub struct ListMembersInner {
     pub presence: Option<String>,
     pub profile: ListProfileInner,
     pub real_name: Option<String>,
-    pub team: Option<String>,
-    pub team_id: Option<String>,
+    pub team: Option<crate::TeamId>,
+    pub team_id: Option<crate::TeamId>,
     pub team_profile: Option<ListTeamProfileInner>,
     pub two_factor_type: Option<String>,
     pub tz: Option<Vec<String>>,
@@ -1344,20 +1860,27 @@ pub struct ListMembersInner {
     pub updated: f64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponseMetadataInner {
+    pub messages: Option<Vec<String>>,
     pub next_cursor: String,
+    pub warnings: Option<Vec<String>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponse {
     pub cache_ts: u64,
     pub callstack: Option<String>,
     error: Option<String>,
-    pub members: Vec<Vec<ListMembersInner>>,
+    pub members: Vec<ListMembersInner>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
-    pub response_metadata: Option<Vec<ListResponseMetadataInner>>,
+    pub provided: Option<String>,
+    pub response_metadata: Option<ListResponseMetadataInner>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
@@ -1365,88 +1888,137 @@ impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(ListError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for ListResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
+impl crate::pagination::CursorPage for ListResponse {
+    type Item = ListMembersInner;
+
+    fn next_cursor(&self) -> Option<&str> {
+        self.response_metadata
+            .as_ref()
+            .map(|response_metadata| response_metadata.next_cursor.as_str())
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.members
+    }
+}
+
 #[derive(Debug)]
 pub enum ListError<E: Error> {
-    AccountInactive,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidCursor,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    LimitRequired,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    RequestTimeout,
-    TeamAddedToOrg,
-    UpgradeRequired,
+    AccountInactive(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidCursor(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    LimitRequired(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> ListError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => ListError::AccountInactive(context),
+            "fatal_error" => ListError::FatalError(context),
+            "invalid_arg_name" => ListError::InvalidArgName(context),
+            "invalid_array_arg" => ListError::InvalidArrayArg(context),
+            "invalid_auth" => ListError::InvalidAuth(context),
+            "invalid_charset" => ListError::InvalidCharset(context),
+            "invalid_cursor" => ListError::InvalidCursor(context),
+            "invalid_form_data" => ListError::InvalidFormData(context),
+            "invalid_json" => ListError::InvalidJson(context),
+            "invalid_post_type" => ListError::InvalidPostType(context),
+            "json_not_object" => ListError::JsonNotObject(context),
+            "limit_required" => ListError::LimitRequired(context),
+            "missing_post_type" => ListError::MissingPostType(context),
+            "no_permission" => ListError::NoPermission(context),
+            "not_authed" => ListError::NotAuthed(context),
+            "request_timeout" => ListError::RequestTimeout(context),
+            "team_added_to_org" => ListError::TeamAddedToOrg(context),
+            "upgrade_required" => ListError::UpgradeRequired(context),
+            _ => ListError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for ListError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => ListError::AccountInactive,
-            "fatal_error" => ListError::FatalError,
-            "invalid_arg_name" => ListError::InvalidArgName,
-            "invalid_array_arg" => ListError::InvalidArrayArg,
-            "invalid_auth" => ListError::InvalidAuth,
-            "invalid_charset" => ListError::InvalidCharset,
-            "invalid_cursor" => ListError::InvalidCursor,
-            "invalid_form_data" => ListError::InvalidFormData,
-            "invalid_json" => ListError::InvalidJson,
-            "invalid_post_type" => ListError::InvalidPostType,
-            "json_not_object" => ListError::JsonNotObject,
-            "limit_required" => ListError::LimitRequired,
-            "missing_post_type" => ListError::MissingPostType,
-            "no_permission" => ListError::NoPermission,
-            "not_authed" => ListError::NotAuthed,
-            "request_timeout" => ListError::RequestTimeout,
-            "team_added_to_org" => ListError::TeamAddedToOrg,
-            "upgrade_required" => ListError::UpgradeRequired,
-            _ => ListError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for ListError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            ListError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            ListError::FatalError => write!(f, "Server returned error fatal_error"),
-            ListError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            ListError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            ListError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            ListError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            ListError::InvalidCursor => write!(f, "Server returned error invalid_cursor"),
-            ListError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            ListError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            ListError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            ListError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            ListError::LimitRequired => write!(f, "Server returned error limit_required"),
-            ListError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            ListError::NoPermission => write!(f, "Server returned error no_permission"),
-            ListError::NotAuthed => write!(f, "Server returned error not_authed"),
-            ListError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            ListError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            ListError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
+            ListError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            ListError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            ListError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            ListError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            ListError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            ListError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            ListError::InvalidCursor(_) => write!(f, "Server returned error invalid_cursor"),
+            ListError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            ListError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            ListError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            ListError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            ListError::LimitRequired(_) => write!(f, "Server returned error limit_required"),
+            ListError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            ListError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            ListError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            ListError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            ListError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            ListError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
             ListError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            ListError::Unknown(ref s) => write!(f, "{}", s),
+            ListError::Unknown(ref s, _) => write!(f, "{}", s),
             ListError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -1462,28 +2034,90 @@ impl<E: Error + 'static> Error for ListError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for ListError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            ListError::AccountInactive(_) => Some("account_inactive"),
+            ListError::FatalError(_) => Some("fatal_error"),
+            ListError::InvalidArgName(_) => Some("invalid_arg_name"),
+            ListError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            ListError::InvalidAuth(_) => Some("invalid_auth"),
+            ListError::InvalidCharset(_) => Some("invalid_charset"),
+            ListError::InvalidCursor(_) => Some("invalid_cursor"),
+            ListError::InvalidFormData(_) => Some("invalid_form_data"),
+            ListError::InvalidJson(_) => Some("invalid_json"),
+            ListError::InvalidPostType(_) => Some("invalid_post_type"),
+            ListError::JsonNotObject(_) => Some("json_not_object"),
+            ListError::LimitRequired(_) => Some("limit_required"),
+            ListError::MissingPostType(_) => Some("missing_post_type"),
+            ListError::NoPermission(_) => Some("no_permission"),
+            ListError::NotAuthed(_) => Some("not_authed"),
+            ListError::RequestTimeout(_) => Some("request_timeout"),
+            ListError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            ListError::UpgradeRequired(_) => Some("upgrade_required"),
+            ListError::Unknown(ref s, _) => Some(s),
+            ListError::MalformedResponse(..) | ListError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            ListError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            ListError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            ListError::AccountInactive(ref context)
+            | ListError::FatalError(ref context)
+            | ListError::InvalidArgName(ref context)
+            | ListError::InvalidArrayArg(ref context)
+            | ListError::InvalidAuth(ref context)
+            | ListError::InvalidCharset(ref context)
+            | ListError::InvalidCursor(ref context)
+            | ListError::InvalidFormData(ref context)
+            | ListError::InvalidJson(ref context)
+            | ListError::InvalidPostType(ref context)
+            | ListError::JsonNotObject(ref context)
+            | ListError::LimitRequired(ref context)
+            | ListError::MissingPostType(ref context)
+            | ListError::NoPermission(ref context)
+            | ListError::NotAuthed(ref context)
+            | ListError::RequestTimeout(ref context)
+            | ListError::TeamAddedToOrg(ref context)
+            | ListError::UpgradeRequired(ref context)
+            | ListError::Unknown(_, ref context) => Some(context),
+            ListError::MalformedResponse(..) | ListError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct LookupByEmailRequest<'a> {
     /// An email address belonging to a user in the workspace
     pub email: Cow<'a, str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct LookupByEmailEnterpriseUserInner {
-    pub enterprise_id: String,
+    pub enterprise_id: crate::EnterpriseId,
     pub enterprise_name: String,
-    pub id: String,
+    pub id: crate::UserId,
     pub is_admin: bool,
     pub is_owner: bool,
     pub teams: Vec<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct LookupByEmailProfileInner {
     pub always_active: Option<bool>,
     pub api_app_id: Option<String>,
     pub avatar_hash: String,
-    pub bot_id: Option<String>,
+    pub bot_id: Option<crate::BotId>,
     pub display_name: String,
     pub display_name_normalized: String,
     pub email: Option<String>,
@@ -1519,14 +2153,14 @@ pub struct LookupByEmailProfileInner {
     pub status_expiration: Option<u64>,
     pub status_text: String,
     pub status_text_canonical: Option<String>,
-    pub team: Option<String>,
+    pub team: Option<crate::TeamId>,
     pub title: String,
     pub updated: Option<u64>,
-    pub user_id: Option<String>,
+    pub user_id: Option<crate::UserId>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct LookupByEmailOptionsInner {
     pub is_custom: Option<bool>,
     pub is_multiple_entry: Option<bool>,
@@ -1534,7 +2168,7 @@ pub struct LookupByEmailOptionsInner {
     pub is_scim: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct LookupByEmailFieldsInner {
     pub field_name: Option<String>,
     pub hint: String,
@@ -1547,18 +2181,18 @@ pub struct LookupByEmailFieldsInner {
     pub r#type: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct LookupByEmailTeamProfileInner {
     pub fields: Vec<LookupByEmailFieldsInner>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct LookupByEmailUserInner {
     pub color: Option<String>,
     pub deleted: Option<bool>,
     pub enterprise_user: Option<LookupByEmailEnterpriseUserInner>,
     pub has_2fa: Option<bool>,
-    pub id: String,
+    pub id: crate::UserId,
     pub is_admin: Option<bool>,
     pub is_app_user: bool,
     pub is_bot: bool,
@@ -1575,8 +2209,8 @@ pub struct LookupByEmailUserInner {
     pub presence: Option<String>,
     pub profile: LookupByEmailProfileInner,
     pub real_name: Option<String>,
-    pub team: Option<String>,
-    pub team_id: Option<String>,
+    pub team: Option<crate::TeamId>,
+    pub team_id: Option<crate::TeamId>,
     pub team_profile: Option<LookupByEmailTeamProfileInner>,
     pub two_factor_type: Option<String>,
     pub tz: Option<Vec<String>>,
@@ -1585,13 +2219,19 @@ pub struct LookupByEmailUserInner {
     pub updated: f64,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct LookupByEmailResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
     pub user: Vec<LookupByEmailUserInner>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<LookupByEmailResponse, LookupByEmailError<E>>>
@@ -1601,110 +2241,149 @@ impl<E: Error> Into<Result<LookupByEmailResponse, LookupByEmailError<E>>>
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(LookupByEmailError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for LookupByEmailResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum LookupByEmailError<E: Error> {
-    AccountInactive,
-    EnterpriseIsRestricted,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    RequestTimeout,
-    TeamAddedToOrg,
-    UpgradeRequired,
-    UsersNotFound,
+    AccountInactive(crate::ErrorContext),
+    EnterpriseIsRestricted(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UsersNotFound(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> LookupByEmailError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => LookupByEmailError::AccountInactive(context),
+            "enterprise_is_restricted" => LookupByEmailError::EnterpriseIsRestricted(context),
+            "fatal_error" => LookupByEmailError::FatalError(context),
+            "invalid_arg_name" => LookupByEmailError::InvalidArgName(context),
+            "invalid_array_arg" => LookupByEmailError::InvalidArrayArg(context),
+            "invalid_auth" => LookupByEmailError::InvalidAuth(context),
+            "invalid_charset" => LookupByEmailError::InvalidCharset(context),
+            "invalid_form_data" => LookupByEmailError::InvalidFormData(context),
+            "invalid_json" => LookupByEmailError::InvalidJson(context),
+            "invalid_post_type" => LookupByEmailError::InvalidPostType(context),
+            "json_not_object" => LookupByEmailError::JsonNotObject(context),
+            "missing_post_type" => LookupByEmailError::MissingPostType(context),
+            "no_permission" => LookupByEmailError::NoPermission(context),
+            "not_authed" => LookupByEmailError::NotAuthed(context),
+            "request_timeout" => LookupByEmailError::RequestTimeout(context),
+            "team_added_to_org" => LookupByEmailError::TeamAddedToOrg(context),
+            "upgrade_required" => LookupByEmailError::UpgradeRequired(context),
+            "users_not_found" => LookupByEmailError::UsersNotFound(context),
+            _ => LookupByEmailError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for LookupByEmailError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => LookupByEmailError::AccountInactive,
-            "enterprise_is_restricted" => LookupByEmailError::EnterpriseIsRestricted,
-            "fatal_error" => LookupByEmailError::FatalError,
-            "invalid_arg_name" => LookupByEmailError::InvalidArgName,
-            "invalid_array_arg" => LookupByEmailError::InvalidArrayArg,
-            "invalid_auth" => LookupByEmailError::InvalidAuth,
-            "invalid_charset" => LookupByEmailError::InvalidCharset,
-            "invalid_form_data" => LookupByEmailError::InvalidFormData,
-            "invalid_json" => LookupByEmailError::InvalidJson,
-            "invalid_post_type" => LookupByEmailError::InvalidPostType,
-            "json_not_object" => LookupByEmailError::JsonNotObject,
-            "missing_post_type" => LookupByEmailError::MissingPostType,
-            "no_permission" => LookupByEmailError::NoPermission,
-            "not_authed" => LookupByEmailError::NotAuthed,
-            "request_timeout" => LookupByEmailError::RequestTimeout,
-            "team_added_to_org" => LookupByEmailError::TeamAddedToOrg,
-            "upgrade_required" => LookupByEmailError::UpgradeRequired,
-            "users_not_found" => LookupByEmailError::UsersNotFound,
-            _ => LookupByEmailError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for LookupByEmailError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            LookupByEmailError::AccountInactive => {
+            LookupByEmailError::AccountInactive(_) => {
                 write!(f, "Server returned error account_inactive")
             }
-            LookupByEmailError::EnterpriseIsRestricted => {
+            LookupByEmailError::EnterpriseIsRestricted(_) => {
                 write!(f, "Server returned error enterprise_is_restricted")
             }
-            LookupByEmailError::FatalError => write!(f, "Server returned error fatal_error"),
-            LookupByEmailError::InvalidArgName => {
+            LookupByEmailError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            LookupByEmailError::InvalidArgName(_) => {
                 write!(f, "Server returned error invalid_arg_name")
             }
-            LookupByEmailError::InvalidArrayArg => {
+            LookupByEmailError::InvalidArrayArg(_) => {
                 write!(f, "Server returned error invalid_array_arg")
             }
-            LookupByEmailError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            LookupByEmailError::InvalidCharset => {
+            LookupByEmailError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            LookupByEmailError::InvalidCharset(_) => {
                 write!(f, "Server returned error invalid_charset")
             }
-            LookupByEmailError::InvalidFormData => {
+            LookupByEmailError::InvalidFormData(_) => {
                 write!(f, "Server returned error invalid_form_data")
             }
-            LookupByEmailError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            LookupByEmailError::InvalidPostType => {
+            LookupByEmailError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            LookupByEmailError::InvalidPostType(_) => {
                 write!(f, "Server returned error invalid_post_type")
             }
-            LookupByEmailError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            LookupByEmailError::MissingPostType => {
+            LookupByEmailError::JsonNotObject(_) => {
+                write!(f, "Server returned error json_not_object")
+            }
+            LookupByEmailError::MissingPostType(_) => {
                 write!(f, "Server returned error missing_post_type")
             }
-            LookupByEmailError::NoPermission => write!(f, "Server returned error no_permission"),
-            LookupByEmailError::NotAuthed => write!(f, "Server returned error not_authed"),
-            LookupByEmailError::RequestTimeout => {
+            LookupByEmailError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            LookupByEmailError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            LookupByEmailError::RequestTimeout(_) => {
                 write!(f, "Server returned error request_timeout")
             }
-            LookupByEmailError::TeamAddedToOrg => {
+            LookupByEmailError::TeamAddedToOrg(_) => {
                 write!(f, "Server returned error team_added_to_org")
             }
-            LookupByEmailError::UpgradeRequired => {
+            LookupByEmailError::UpgradeRequired(_) => {
                 write!(f, "Server returned error upgrade_required")
             }
-            LookupByEmailError::UsersNotFound => write!(f, "Server returned error users_not_found"),
+            LookupByEmailError::UsersNotFound(_) => {
+                write!(f, "Server returned error users_not_found")
+            }
             LookupByEmailError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            LookupByEmailError::Unknown(ref s) => write!(f, "{}", s),
+            LookupByEmailError::Unknown(ref s, _) => write!(f, "{}", s),
             LookupByEmailError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -1720,15 +2399,83 @@ impl<E: Error + 'static> Error for LookupByEmailError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for LookupByEmailError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            LookupByEmailError::AccountInactive(_) => Some("account_inactive"),
+            LookupByEmailError::EnterpriseIsRestricted(_) => Some("enterprise_is_restricted"),
+            LookupByEmailError::FatalError(_) => Some("fatal_error"),
+            LookupByEmailError::InvalidArgName(_) => Some("invalid_arg_name"),
+            LookupByEmailError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            LookupByEmailError::InvalidAuth(_) => Some("invalid_auth"),
+            LookupByEmailError::InvalidCharset(_) => Some("invalid_charset"),
+            LookupByEmailError::InvalidFormData(_) => Some("invalid_form_data"),
+            LookupByEmailError::InvalidJson(_) => Some("invalid_json"),
+            LookupByEmailError::InvalidPostType(_) => Some("invalid_post_type"),
+            LookupByEmailError::JsonNotObject(_) => Some("json_not_object"),
+            LookupByEmailError::MissingPostType(_) => Some("missing_post_type"),
+            LookupByEmailError::NoPermission(_) => Some("no_permission"),
+            LookupByEmailError::NotAuthed(_) => Some("not_authed"),
+            LookupByEmailError::RequestTimeout(_) => Some("request_timeout"),
+            LookupByEmailError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            LookupByEmailError::UpgradeRequired(_) => Some("upgrade_required"),
+            LookupByEmailError::UsersNotFound(_) => Some("users_not_found"),
+            LookupByEmailError::Unknown(ref s, _) => Some(s),
+            LookupByEmailError::MalformedResponse(..) | LookupByEmailError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            LookupByEmailError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            LookupByEmailError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            LookupByEmailError::AccountInactive(ref context)
+            | LookupByEmailError::EnterpriseIsRestricted(ref context)
+            | LookupByEmailError::FatalError(ref context)
+            | LookupByEmailError::InvalidArgName(ref context)
+            | LookupByEmailError::InvalidArrayArg(ref context)
+            | LookupByEmailError::InvalidAuth(ref context)
+            | LookupByEmailError::InvalidCharset(ref context)
+            | LookupByEmailError::InvalidFormData(ref context)
+            | LookupByEmailError::InvalidJson(ref context)
+            | LookupByEmailError::InvalidPostType(ref context)
+            | LookupByEmailError::JsonNotObject(ref context)
+            | LookupByEmailError::MissingPostType(ref context)
+            | LookupByEmailError::NoPermission(ref context)
+            | LookupByEmailError::NotAuthed(ref context)
+            | LookupByEmailError::RequestTimeout(ref context)
+            | LookupByEmailError::TeamAddedToOrg(ref context)
+            | LookupByEmailError::UpgradeRequired(ref context)
+            | LookupByEmailError::UsersNotFound(ref context)
+            | LookupByEmailError::Unknown(_, ref context) => Some(context),
+            LookupByEmailError::MalformedResponse(..) | LookupByEmailError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct SetActiveRequest {}
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetActiveResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<SetActiveResponse, SetActiveError<E>>> for SetActiveResponse {
@@ -1736,92 +2483,141 @@ impl<E: Error> Into<Result<SetActiveResponse, SetActiveError<E>>> for SetActiveR
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(SetActiveError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for SetActiveResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum SetActiveError<E: Error> {
-    AccountInactive,
-    EkmAccessDenied,
-    FatalError,
-    InternalError,
-    InvalidArgName,
-    InvalidArguments,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidPostType,
-    MissingPostType,
-    MissingScope,
-    NoPermission,
-    NotAuthed,
-    OrgLoginRequired,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TokenRevoked,
+    AccountInactive(crate::ErrorContext),
+    EkmAccessDenied(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InternalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArguments(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    MissingScope(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> SetActiveError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => SetActiveError::AccountInactive(context),
+            "ekm_access_denied" => SetActiveError::EkmAccessDenied(context),
+            "fatal_error" => SetActiveError::FatalError(context),
+            "internal_error" => SetActiveError::InternalError(context),
+            "invalid_arg_name" => SetActiveError::InvalidArgName(context),
+            "invalid_arguments" => SetActiveError::InvalidArguments(context),
+            "invalid_auth" => SetActiveError::InvalidAuth(context),
+            "invalid_charset" => SetActiveError::InvalidCharset(context),
+            "invalid_form_data" => SetActiveError::InvalidFormData(context),
+            "invalid_post_type" => SetActiveError::InvalidPostType(context),
+            "missing_post_type" => SetActiveError::MissingPostType(context),
+            "missing_scope" => SetActiveError::MissingScope(context),
+            "no_permission" => SetActiveError::NoPermission(context),
+            "not_authed" => SetActiveError::NotAuthed(context),
+            "org_login_required" => SetActiveError::OrgLoginRequired(context),
+            "request_timeout" => SetActiveError::RequestTimeout(context),
+            "team_added_to_org" => SetActiveError::TeamAddedToOrg(context),
+            "token_revoked" => SetActiveError::TokenRevoked(context),
+            _ => SetActiveError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for SetActiveError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => SetActiveError::AccountInactive,
-            "ekm_access_denied" => SetActiveError::EkmAccessDenied,
-            "fatal_error" => SetActiveError::FatalError,
-            "internal_error" => SetActiveError::InternalError,
-            "invalid_arg_name" => SetActiveError::InvalidArgName,
-            "invalid_arguments" => SetActiveError::InvalidArguments,
-            "invalid_auth" => SetActiveError::InvalidAuth,
-            "invalid_charset" => SetActiveError::InvalidCharset,
-            "invalid_form_data" => SetActiveError::InvalidFormData,
-            "invalid_post_type" => SetActiveError::InvalidPostType,
-            "missing_post_type" => SetActiveError::MissingPostType,
-            "missing_scope" => SetActiveError::MissingScope,
-            "no_permission" => SetActiveError::NoPermission,
-            "not_authed" => SetActiveError::NotAuthed,
-            "org_login_required" => SetActiveError::OrgLoginRequired,
-            "request_timeout" => SetActiveError::RequestTimeout,
-            "team_added_to_org" => SetActiveError::TeamAddedToOrg,
-            "token_revoked" => SetActiveError::TokenRevoked,
-            _ => SetActiveError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for SetActiveError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            SetActiveError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            SetActiveError::EkmAccessDenied => write!(f, "Server returned error ekm_access_denied"),
-            SetActiveError::FatalError => write!(f, "Server returned error fatal_error"),
-            SetActiveError::InternalError => write!(f, "Server returned error internal_error"),
-            SetActiveError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            SetActiveError::InvalidArguments => {
+            SetActiveError::AccountInactive(_) => {
+                write!(f, "Server returned error account_inactive")
+            }
+            SetActiveError::EkmAccessDenied(_) => {
+                write!(f, "Server returned error ekm_access_denied")
+            }
+            SetActiveError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            SetActiveError::InternalError(_) => write!(f, "Server returned error internal_error"),
+            SetActiveError::InvalidArgName(_) => {
+                write!(f, "Server returned error invalid_arg_name")
+            }
+            SetActiveError::InvalidArguments(_) => {
                 write!(f, "Server returned error invalid_arguments")
             }
-            SetActiveError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            SetActiveError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            SetActiveError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            SetActiveError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            SetActiveError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            SetActiveError::MissingScope => write!(f, "Server returned error missing_scope"),
-            SetActiveError::NoPermission => write!(f, "Server returned error no_permission"),
-            SetActiveError::NotAuthed => write!(f, "Server returned error not_authed"),
-            SetActiveError::OrgLoginRequired => {
+            SetActiveError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            SetActiveError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            SetActiveError::InvalidFormData(_) => {
+                write!(f, "Server returned error invalid_form_data")
+            }
+            SetActiveError::InvalidPostType(_) => {
+                write!(f, "Server returned error invalid_post_type")
+            }
+            SetActiveError::MissingPostType(_) => {
+                write!(f, "Server returned error missing_post_type")
+            }
+            SetActiveError::MissingScope(_) => write!(f, "Server returned error missing_scope"),
+            SetActiveError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            SetActiveError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            SetActiveError::OrgLoginRequired(_) => {
                 write!(f, "Server returned error org_login_required")
             }
-            SetActiveError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            SetActiveError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            SetActiveError::TokenRevoked => write!(f, "Server returned error token_revoked"),
+            SetActiveError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            SetActiveError::TeamAddedToOrg(_) => {
+                write!(f, "Server returned error team_added_to_org")
+            }
+            SetActiveError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
             SetActiveError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            SetActiveError::Unknown(ref s) => write!(f, "{}", s),
+            SetActiveError::Unknown(ref s, _) => write!(f, "{}", s),
             SetActiveError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -1837,6 +2633,68 @@ impl<E: Error + 'static> Error for SetActiveError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for SetActiveError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            SetActiveError::AccountInactive(_) => Some("account_inactive"),
+            SetActiveError::EkmAccessDenied(_) => Some("ekm_access_denied"),
+            SetActiveError::FatalError(_) => Some("fatal_error"),
+            SetActiveError::InternalError(_) => Some("internal_error"),
+            SetActiveError::InvalidArgName(_) => Some("invalid_arg_name"),
+            SetActiveError::InvalidArguments(_) => Some("invalid_arguments"),
+            SetActiveError::InvalidAuth(_) => Some("invalid_auth"),
+            SetActiveError::InvalidCharset(_) => Some("invalid_charset"),
+            SetActiveError::InvalidFormData(_) => Some("invalid_form_data"),
+            SetActiveError::InvalidPostType(_) => Some("invalid_post_type"),
+            SetActiveError::MissingPostType(_) => Some("missing_post_type"),
+            SetActiveError::MissingScope(_) => Some("missing_scope"),
+            SetActiveError::NoPermission(_) => Some("no_permission"),
+            SetActiveError::NotAuthed(_) => Some("not_authed"),
+            SetActiveError::OrgLoginRequired(_) => Some("org_login_required"),
+            SetActiveError::RequestTimeout(_) => Some("request_timeout"),
+            SetActiveError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            SetActiveError::TokenRevoked(_) => Some("token_revoked"),
+            SetActiveError::Unknown(ref s, _) => Some(s),
+            SetActiveError::MalformedResponse(..) | SetActiveError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            SetActiveError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            SetActiveError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            SetActiveError::AccountInactive(ref context)
+            | SetActiveError::EkmAccessDenied(ref context)
+            | SetActiveError::FatalError(ref context)
+            | SetActiveError::InternalError(ref context)
+            | SetActiveError::InvalidArgName(ref context)
+            | SetActiveError::InvalidArguments(ref context)
+            | SetActiveError::InvalidAuth(ref context)
+            | SetActiveError::InvalidCharset(ref context)
+            | SetActiveError::InvalidFormData(ref context)
+            | SetActiveError::InvalidPostType(ref context)
+            | SetActiveError::MissingPostType(ref context)
+            | SetActiveError::MissingScope(ref context)
+            | SetActiveError::NoPermission(ref context)
+            | SetActiveError::NotAuthed(ref context)
+            | SetActiveError::OrgLoginRequired(ref context)
+            | SetActiveError::RequestTimeout(ref context)
+            | SetActiveError::TeamAddedToOrg(ref context)
+            | SetActiveError::TokenRevoked(ref context)
+            | SetActiveError::Unknown(_, ref context) => Some(context),
+            SetActiveError::MalformedResponse(..) | SetActiveError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct SetPhotoRequest<'a> {
     /// Width/height of crop box (always square)
@@ -1846,10 +2704,10 @@ pub struct SetPhotoRequest<'a> {
     /// Y coordinate of top-left corner of crop box
     pub crop_y: Option<Cow<'a, str>>,
     /// File contents via `multipart/form-data`.
-    pub image: Option<Cow<'a, str>>,
+    pub image: Option<crate::FileUpload<'a>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetPhotoProfileInner {
     pub avatar_hash: String,
     pub image_1024: String,
@@ -1862,16 +2720,22 @@ pub struct SetPhotoProfileInner {
     pub image_original: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetPhotoResponse {
     pub callstack: Option<String>,
     pub debug_step: Option<String>,
     pub dims: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
     pub profile: SetPhotoProfileInner,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
     pub time_ident: Option<u64>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<SetPhotoResponse, SetPhotoError<E>>> for SetPhotoResponse {
@@ -1879,105 +2743,154 @@ impl<E: Error> Into<Result<SetPhotoResponse, SetPhotoError<E>>> for SetPhotoResp
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(SetPhotoError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for SetPhotoResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum SetPhotoError<E: Error> {
-    AccountInactive,
-    BadImage,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    NotFound,
-    OrgLoginRequired,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TokenRevoked,
-    TooLarge,
-    TooManyFrames,
-    UpgradeRequired,
-    UserIsBot,
+    AccountInactive(crate::ErrorContext),
+    BadImage(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    NotFound(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    TooLarge(crate::ErrorContext),
+    TooManyFrames(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserIsBot(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> SetPhotoError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => SetPhotoError::AccountInactive(context),
+            "bad_image" => SetPhotoError::BadImage(context),
+            "fatal_error" => SetPhotoError::FatalError(context),
+            "invalid_arg_name" => SetPhotoError::InvalidArgName(context),
+            "invalid_array_arg" => SetPhotoError::InvalidArrayArg(context),
+            "invalid_auth" => SetPhotoError::InvalidAuth(context),
+            "invalid_charset" => SetPhotoError::InvalidCharset(context),
+            "invalid_form_data" => SetPhotoError::InvalidFormData(context),
+            "invalid_json" => SetPhotoError::InvalidJson(context),
+            "invalid_post_type" => SetPhotoError::InvalidPostType(context),
+            "json_not_object" => SetPhotoError::JsonNotObject(context),
+            "missing_post_type" => SetPhotoError::MissingPostType(context),
+            "no_permission" => SetPhotoError::NoPermission(context),
+            "not_authed" => SetPhotoError::NotAuthed(context),
+            "not_found" => SetPhotoError::NotFound(context),
+            "org_login_required" => SetPhotoError::OrgLoginRequired(context),
+            "request_timeout" => SetPhotoError::RequestTimeout(context),
+            "team_added_to_org" => SetPhotoError::TeamAddedToOrg(context),
+            "token_revoked" => SetPhotoError::TokenRevoked(context),
+            "too_large" => SetPhotoError::TooLarge(context),
+            "too_many_frames" => SetPhotoError::TooManyFrames(context),
+            "upgrade_required" => SetPhotoError::UpgradeRequired(context),
+            "user_is_bot" => SetPhotoError::UserIsBot(context),
+            _ => SetPhotoError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for SetPhotoError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => SetPhotoError::AccountInactive,
-            "bad_image" => SetPhotoError::BadImage,
-            "fatal_error" => SetPhotoError::FatalError,
-            "invalid_arg_name" => SetPhotoError::InvalidArgName,
-            "invalid_array_arg" => SetPhotoError::InvalidArrayArg,
-            "invalid_auth" => SetPhotoError::InvalidAuth,
-            "invalid_charset" => SetPhotoError::InvalidCharset,
-            "invalid_form_data" => SetPhotoError::InvalidFormData,
-            "invalid_json" => SetPhotoError::InvalidJson,
-            "invalid_post_type" => SetPhotoError::InvalidPostType,
-            "json_not_object" => SetPhotoError::JsonNotObject,
-            "missing_post_type" => SetPhotoError::MissingPostType,
-            "no_permission" => SetPhotoError::NoPermission,
-            "not_authed" => SetPhotoError::NotAuthed,
-            "not_found" => SetPhotoError::NotFound,
-            "org_login_required" => SetPhotoError::OrgLoginRequired,
-            "request_timeout" => SetPhotoError::RequestTimeout,
-            "team_added_to_org" => SetPhotoError::TeamAddedToOrg,
-            "token_revoked" => SetPhotoError::TokenRevoked,
-            "too_large" => SetPhotoError::TooLarge,
-            "too_many_frames" => SetPhotoError::TooManyFrames,
-            "upgrade_required" => SetPhotoError::UpgradeRequired,
-            "user_is_bot" => SetPhotoError::UserIsBot,
-            _ => SetPhotoError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for SetPhotoError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            SetPhotoError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            SetPhotoError::BadImage => write!(f, "Server returned error bad_image"),
-            SetPhotoError::FatalError => write!(f, "Server returned error fatal_error"),
-            SetPhotoError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            SetPhotoError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            SetPhotoError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            SetPhotoError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            SetPhotoError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            SetPhotoError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            SetPhotoError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            SetPhotoError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            SetPhotoError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            SetPhotoError::NoPermission => write!(f, "Server returned error no_permission"),
-            SetPhotoError::NotAuthed => write!(f, "Server returned error not_authed"),
-            SetPhotoError::NotFound => write!(f, "Server returned error not_found"),
-            SetPhotoError::OrgLoginRequired => {
+            SetPhotoError::AccountInactive(_) => {
+                write!(f, "Server returned error account_inactive")
+            }
+            SetPhotoError::BadImage(_) => write!(f, "Server returned error bad_image"),
+            SetPhotoError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            SetPhotoError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            SetPhotoError::InvalidArrayArg(_) => {
+                write!(f, "Server returned error invalid_array_arg")
+            }
+            SetPhotoError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            SetPhotoError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            SetPhotoError::InvalidFormData(_) => {
+                write!(f, "Server returned error invalid_form_data")
+            }
+            SetPhotoError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            SetPhotoError::InvalidPostType(_) => {
+                write!(f, "Server returned error invalid_post_type")
+            }
+            SetPhotoError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            SetPhotoError::MissingPostType(_) => {
+                write!(f, "Server returned error missing_post_type")
+            }
+            SetPhotoError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            SetPhotoError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            SetPhotoError::NotFound(_) => write!(f, "Server returned error not_found"),
+            SetPhotoError::OrgLoginRequired(_) => {
                 write!(f, "Server returned error org_login_required")
             }
-            SetPhotoError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            SetPhotoError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            SetPhotoError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            SetPhotoError::TooLarge => write!(f, "Server returned error too_large"),
-            SetPhotoError::TooManyFrames => write!(f, "Server returned error too_many_frames"),
-            SetPhotoError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
-            SetPhotoError::UserIsBot => write!(f, "Server returned error user_is_bot"),
+            SetPhotoError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            SetPhotoError::TeamAddedToOrg(_) => {
+                write!(f, "Server returned error team_added_to_org")
+            }
+            SetPhotoError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            SetPhotoError::TooLarge(_) => write!(f, "Server returned error too_large"),
+            SetPhotoError::TooManyFrames(_) => write!(f, "Server returned error too_many_frames"),
+            SetPhotoError::UpgradeRequired(_) => {
+                write!(f, "Server returned error upgrade_required")
+            }
+            SetPhotoError::UserIsBot(_) => write!(f, "Server returned error user_is_bot"),
             SetPhotoError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            SetPhotoError::Unknown(ref s) => write!(f, "{}", s),
+            SetPhotoError::Unknown(ref s, _) => write!(f, "{}", s),
             SetPhotoError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -1993,18 +2906,96 @@ impl<E: Error + 'static> Error for SetPhotoError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for SetPhotoError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            SetPhotoError::AccountInactive(_) => Some("account_inactive"),
+            SetPhotoError::BadImage(_) => Some("bad_image"),
+            SetPhotoError::FatalError(_) => Some("fatal_error"),
+            SetPhotoError::InvalidArgName(_) => Some("invalid_arg_name"),
+            SetPhotoError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            SetPhotoError::InvalidAuth(_) => Some("invalid_auth"),
+            SetPhotoError::InvalidCharset(_) => Some("invalid_charset"),
+            SetPhotoError::InvalidFormData(_) => Some("invalid_form_data"),
+            SetPhotoError::InvalidJson(_) => Some("invalid_json"),
+            SetPhotoError::InvalidPostType(_) => Some("invalid_post_type"),
+            SetPhotoError::JsonNotObject(_) => Some("json_not_object"),
+            SetPhotoError::MissingPostType(_) => Some("missing_post_type"),
+            SetPhotoError::NoPermission(_) => Some("no_permission"),
+            SetPhotoError::NotAuthed(_) => Some("not_authed"),
+            SetPhotoError::NotFound(_) => Some("not_found"),
+            SetPhotoError::OrgLoginRequired(_) => Some("org_login_required"),
+            SetPhotoError::RequestTimeout(_) => Some("request_timeout"),
+            SetPhotoError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            SetPhotoError::TokenRevoked(_) => Some("token_revoked"),
+            SetPhotoError::TooLarge(_) => Some("too_large"),
+            SetPhotoError::TooManyFrames(_) => Some("too_many_frames"),
+            SetPhotoError::UpgradeRequired(_) => Some("upgrade_required"),
+            SetPhotoError::UserIsBot(_) => Some("user_is_bot"),
+            SetPhotoError::Unknown(ref s, _) => Some(s),
+            SetPhotoError::MalformedResponse(..) | SetPhotoError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            SetPhotoError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            SetPhotoError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            SetPhotoError::AccountInactive(ref context)
+            | SetPhotoError::BadImage(ref context)
+            | SetPhotoError::FatalError(ref context)
+            | SetPhotoError::InvalidArgName(ref context)
+            | SetPhotoError::InvalidArrayArg(ref context)
+            | SetPhotoError::InvalidAuth(ref context)
+            | SetPhotoError::InvalidCharset(ref context)
+            | SetPhotoError::InvalidFormData(ref context)
+            | SetPhotoError::InvalidJson(ref context)
+            | SetPhotoError::InvalidPostType(ref context)
+            | SetPhotoError::JsonNotObject(ref context)
+            | SetPhotoError::MissingPostType(ref context)
+            | SetPhotoError::NoPermission(ref context)
+            | SetPhotoError::NotAuthed(ref context)
+            | SetPhotoError::NotFound(ref context)
+            | SetPhotoError::OrgLoginRequired(ref context)
+            | SetPhotoError::RequestTimeout(ref context)
+            | SetPhotoError::TeamAddedToOrg(ref context)
+            | SetPhotoError::TokenRevoked(ref context)
+            | SetPhotoError::TooLarge(ref context)
+            | SetPhotoError::TooManyFrames(ref context)
+            | SetPhotoError::UpgradeRequired(ref context)
+            | SetPhotoError::UserIsBot(ref context)
+            | SetPhotoError::Unknown(_, ref context) => Some(context),
+            SetPhotoError::MalformedResponse(..) | SetPhotoError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct SetPresenceRequest<'a> {
     /// Either `auto` or `away`
     pub presence: Cow<'a, str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetPresenceResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<SetPresenceResponse, SetPresenceError<E>>> for SetPresenceResponse {
@@ -2012,109 +3003,152 @@ impl<E: Error> Into<Result<SetPresenceResponse, SetPresenceError<E>>> for SetPre
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(SetPresenceError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for SetPresenceResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum SetPresenceError<E: Error> {
-    AccountInactive,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    InvalidPresence,
-    JsonNotObject,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    OrgLoginRequired,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequired,
+    AccountInactive(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    InvalidPresence(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> SetPresenceError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => SetPresenceError::AccountInactive(context),
+            "fatal_error" => SetPresenceError::FatalError(context),
+            "invalid_arg_name" => SetPresenceError::InvalidArgName(context),
+            "invalid_array_arg" => SetPresenceError::InvalidArrayArg(context),
+            "invalid_auth" => SetPresenceError::InvalidAuth(context),
+            "invalid_charset" => SetPresenceError::InvalidCharset(context),
+            "invalid_form_data" => SetPresenceError::InvalidFormData(context),
+            "invalid_json" => SetPresenceError::InvalidJson(context),
+            "invalid_post_type" => SetPresenceError::InvalidPostType(context),
+            "invalid_presence" => SetPresenceError::InvalidPresence(context),
+            "json_not_object" => SetPresenceError::JsonNotObject(context),
+            "missing_post_type" => SetPresenceError::MissingPostType(context),
+            "no_permission" => SetPresenceError::NoPermission(context),
+            "not_authed" => SetPresenceError::NotAuthed(context),
+            "org_login_required" => SetPresenceError::OrgLoginRequired(context),
+            "request_timeout" => SetPresenceError::RequestTimeout(context),
+            "team_added_to_org" => SetPresenceError::TeamAddedToOrg(context),
+            "token_revoked" => SetPresenceError::TokenRevoked(context),
+            "upgrade_required" => SetPresenceError::UpgradeRequired(context),
+            _ => SetPresenceError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for SetPresenceError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => SetPresenceError::AccountInactive,
-            "fatal_error" => SetPresenceError::FatalError,
-            "invalid_arg_name" => SetPresenceError::InvalidArgName,
-            "invalid_array_arg" => SetPresenceError::InvalidArrayArg,
-            "invalid_auth" => SetPresenceError::InvalidAuth,
-            "invalid_charset" => SetPresenceError::InvalidCharset,
-            "invalid_form_data" => SetPresenceError::InvalidFormData,
-            "invalid_json" => SetPresenceError::InvalidJson,
-            "invalid_post_type" => SetPresenceError::InvalidPostType,
-            "invalid_presence" => SetPresenceError::InvalidPresence,
-            "json_not_object" => SetPresenceError::JsonNotObject,
-            "missing_post_type" => SetPresenceError::MissingPostType,
-            "no_permission" => SetPresenceError::NoPermission,
-            "not_authed" => SetPresenceError::NotAuthed,
-            "org_login_required" => SetPresenceError::OrgLoginRequired,
-            "request_timeout" => SetPresenceError::RequestTimeout,
-            "team_added_to_org" => SetPresenceError::TeamAddedToOrg,
-            "token_revoked" => SetPresenceError::TokenRevoked,
-            "upgrade_required" => SetPresenceError::UpgradeRequired,
-            _ => SetPresenceError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for SetPresenceError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            SetPresenceError::AccountInactive => {
+            SetPresenceError::AccountInactive(_) => {
                 write!(f, "Server returned error account_inactive")
             }
-            SetPresenceError::FatalError => write!(f, "Server returned error fatal_error"),
-            SetPresenceError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            SetPresenceError::InvalidArrayArg => {
+            SetPresenceError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            SetPresenceError::InvalidArgName(_) => {
+                write!(f, "Server returned error invalid_arg_name")
+            }
+            SetPresenceError::InvalidArrayArg(_) => {
                 write!(f, "Server returned error invalid_array_arg")
             }
-            SetPresenceError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            SetPresenceError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            SetPresenceError::InvalidFormData => {
+            SetPresenceError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            SetPresenceError::InvalidCharset(_) => {
+                write!(f, "Server returned error invalid_charset")
+            }
+            SetPresenceError::InvalidFormData(_) => {
                 write!(f, "Server returned error invalid_form_data")
             }
-            SetPresenceError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            SetPresenceError::InvalidPostType => {
+            SetPresenceError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            SetPresenceError::InvalidPostType(_) => {
                 write!(f, "Server returned error invalid_post_type")
             }
-            SetPresenceError::InvalidPresence => {
+            SetPresenceError::InvalidPresence(_) => {
                 write!(f, "Server returned error invalid_presence")
             }
-            SetPresenceError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            SetPresenceError::MissingPostType => {
+            SetPresenceError::JsonNotObject(_) => {
+                write!(f, "Server returned error json_not_object")
+            }
+            SetPresenceError::MissingPostType(_) => {
                 write!(f, "Server returned error missing_post_type")
             }
-            SetPresenceError::NoPermission => write!(f, "Server returned error no_permission"),
-            SetPresenceError::NotAuthed => write!(f, "Server returned error not_authed"),
-            SetPresenceError::OrgLoginRequired => {
+            SetPresenceError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            SetPresenceError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            SetPresenceError::OrgLoginRequired(_) => {
                 write!(f, "Server returned error org_login_required")
             }
-            SetPresenceError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            SetPresenceError::TeamAddedToOrg => {
+            SetPresenceError::RequestTimeout(_) => {
+                write!(f, "Server returned error request_timeout")
+            }
+            SetPresenceError::TeamAddedToOrg(_) => {
                 write!(f, "Server returned error team_added_to_org")
             }
-            SetPresenceError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            SetPresenceError::UpgradeRequired => {
+            SetPresenceError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            SetPresenceError::UpgradeRequired(_) => {
                 write!(f, "Server returned error upgrade_required")
             }
             SetPresenceError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            SetPresenceError::Unknown(ref s) => write!(f, "{}", s),
+            SetPresenceError::Unknown(ref s, _) => write!(f, "{}", s),
             SetPresenceError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -2129,3 +3163,67 @@ impl<E: Error + 'static> Error for SetPresenceError<E> {
         }
     }
 }
+
+impl<E: Error + 'static> crate::MethodError for SetPresenceError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            SetPresenceError::AccountInactive(_) => Some("account_inactive"),
+            SetPresenceError::FatalError(_) => Some("fatal_error"),
+            SetPresenceError::InvalidArgName(_) => Some("invalid_arg_name"),
+            SetPresenceError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            SetPresenceError::InvalidAuth(_) => Some("invalid_auth"),
+            SetPresenceError::InvalidCharset(_) => Some("invalid_charset"),
+            SetPresenceError::InvalidFormData(_) => Some("invalid_form_data"),
+            SetPresenceError::InvalidJson(_) => Some("invalid_json"),
+            SetPresenceError::InvalidPostType(_) => Some("invalid_post_type"),
+            SetPresenceError::InvalidPresence(_) => Some("invalid_presence"),
+            SetPresenceError::JsonNotObject(_) => Some("json_not_object"),
+            SetPresenceError::MissingPostType(_) => Some("missing_post_type"),
+            SetPresenceError::NoPermission(_) => Some("no_permission"),
+            SetPresenceError::NotAuthed(_) => Some("not_authed"),
+            SetPresenceError::OrgLoginRequired(_) => Some("org_login_required"),
+            SetPresenceError::RequestTimeout(_) => Some("request_timeout"),
+            SetPresenceError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            SetPresenceError::TokenRevoked(_) => Some("token_revoked"),
+            SetPresenceError::UpgradeRequired(_) => Some("upgrade_required"),
+            SetPresenceError::Unknown(ref s, _) => Some(s),
+            SetPresenceError::MalformedResponse(..) | SetPresenceError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            SetPresenceError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            SetPresenceError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            SetPresenceError::AccountInactive(ref context)
+            | SetPresenceError::FatalError(ref context)
+            | SetPresenceError::InvalidArgName(ref context)
+            | SetPresenceError::InvalidArrayArg(ref context)
+            | SetPresenceError::InvalidAuth(ref context)
+            | SetPresenceError::InvalidCharset(ref context)
+            | SetPresenceError::InvalidFormData(ref context)
+            | SetPresenceError::InvalidJson(ref context)
+            | SetPresenceError::InvalidPostType(ref context)
+            | SetPresenceError::InvalidPresence(ref context)
+            | SetPresenceError::JsonNotObject(ref context)
+            | SetPresenceError::MissingPostType(ref context)
+            | SetPresenceError::NoPermission(ref context)
+            | SetPresenceError::NotAuthed(ref context)
+            | SetPresenceError::OrgLoginRequired(ref context)
+            | SetPresenceError::RequestTimeout(ref context)
+            | SetPresenceError::TeamAddedToOrg(ref context)
+            | SetPresenceError::TokenRevoked(ref context)
+            | SetPresenceError::UpgradeRequired(ref context)
+            | SetPresenceError::Unknown(_, ref context) => Some(context),
+            SetPresenceError::MalformedResponse(..) | SetPresenceError::Client(_) => None,
+        }
+    }
+}
@@ -22,19 +22,19 @@ use std::error::Error;
 use std::fmt;
 
 #[derive(Clone, Default, Debug)]
-pub struct GetRequest<'a> {
+pub struct GetRequest {
     /// Include labels for each ID in custom profile fields
     pub include_labels: Option<bool>,
     /// User to retrieve profile info for
-    pub user: Option<Cow<'a, str>>,
+    pub user: Option<crate::UserId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GetProfileInner {
     pub always_active: Option<bool>,
     pub api_app_id: Option<String>,
     pub avatar_hash: String,
-    pub bot_id: Option<String>,
+    pub bot_id: Option<crate::BotId>,
     pub display_name: String,
     pub display_name_normalized: String,
     pub email: Option<String>,
@@ -70,20 +70,26 @@ pub struct GetProfileInner {
     pub status_expiration: Option<u64>,
     pub status_text: String,
     pub status_text_canonical: Option<String>,
-    pub team: Option<String>,
+    pub team: Option<crate::TeamId>,
     pub title: String,
     pub updated: Option<u64>,
-    pub user_id: Option<String>,
+    pub user_id: Option<crate::UserId>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GetResponse {
     pub callstack: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
     pub profile: GetProfileInner,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<GetResponse, GetError<E>>> for GetResponse {
@@ -91,94 +97,129 @@ impl<E: Error> Into<Result<GetResponse, GetError<E>>> for GetResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(GetError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for GetResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum GetError<E: Error> {
-    AccountInactive,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    JsonNotObject,
-    MissingPostType,
-    NoPermission,
-    NotAuthed,
-    OrgLoginRequired,
-    RequestTimeout,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequired,
-    UserIsBot,
-    UserNotFound,
+    AccountInactive(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserIsBot(crate::ErrorContext),
+    UserNotFound(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> GetError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => GetError::AccountInactive(context),
+            "fatal_error" => GetError::FatalError(context),
+            "invalid_arg_name" => GetError::InvalidArgName(context),
+            "invalid_array_arg" => GetError::InvalidArrayArg(context),
+            "invalid_auth" => GetError::InvalidAuth(context),
+            "invalid_charset" => GetError::InvalidCharset(context),
+            "invalid_form_data" => GetError::InvalidFormData(context),
+            "invalid_json" => GetError::InvalidJson(context),
+            "invalid_post_type" => GetError::InvalidPostType(context),
+            "json_not_object" => GetError::JsonNotObject(context),
+            "missing_post_type" => GetError::MissingPostType(context),
+            "no_permission" => GetError::NoPermission(context),
+            "not_authed" => GetError::NotAuthed(context),
+            "org_login_required" => GetError::OrgLoginRequired(context),
+            "request_timeout" => GetError::RequestTimeout(context),
+            "team_added_to_org" => GetError::TeamAddedToOrg(context),
+            "token_revoked" => GetError::TokenRevoked(context),
+            "upgrade_required" => GetError::UpgradeRequired(context),
+            "user_is_bot" => GetError::UserIsBot(context),
+            "user_not_found" => GetError::UserNotFound(context),
+            _ => GetError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for GetError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => GetError::AccountInactive,
-            "fatal_error" => GetError::FatalError,
-            "invalid_arg_name" => GetError::InvalidArgName,
-            "invalid_array_arg" => GetError::InvalidArrayArg,
-            "invalid_auth" => GetError::InvalidAuth,
-            "invalid_charset" => GetError::InvalidCharset,
-            "invalid_form_data" => GetError::InvalidFormData,
-            "invalid_json" => GetError::InvalidJson,
-            "invalid_post_type" => GetError::InvalidPostType,
-            "json_not_object" => GetError::JsonNotObject,
-            "missing_post_type" => GetError::MissingPostType,
-            "no_permission" => GetError::NoPermission,
-            "not_authed" => GetError::NotAuthed,
-            "org_login_required" => GetError::OrgLoginRequired,
-            "request_timeout" => GetError::RequestTimeout,
-            "team_added_to_org" => GetError::TeamAddedToOrg,
-            "token_revoked" => GetError::TokenRevoked,
-            "upgrade_required" => GetError::UpgradeRequired,
-            "user_is_bot" => GetError::UserIsBot,
-            "user_not_found" => GetError::UserNotFound,
-            _ => GetError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for GetError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            GetError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            GetError::FatalError => write!(f, "Server returned error fatal_error"),
-            GetError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            GetError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            GetError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            GetError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            GetError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            GetError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            GetError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            GetError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            GetError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            GetError::NoPermission => write!(f, "Server returned error no_permission"),
-            GetError::NotAuthed => write!(f, "Server returned error not_authed"),
-            GetError::OrgLoginRequired => write!(f, "Server returned error org_login_required"),
-            GetError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            GetError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            GetError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            GetError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
-            GetError::UserIsBot => write!(f, "Server returned error user_is_bot"),
-            GetError::UserNotFound => write!(f, "Server returned error user_not_found"),
+            GetError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            GetError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            GetError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            GetError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            GetError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            GetError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            GetError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            GetError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            GetError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            GetError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            GetError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            GetError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            GetError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            GetError::OrgLoginRequired(_) => write!(f, "Server returned error org_login_required"),
+            GetError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            GetError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            GetError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            GetError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
+            GetError::UserIsBot(_) => write!(f, "Server returned error user_is_bot"),
+            GetError::UserNotFound(_) => write!(f, "Server returned error user_not_found"),
             GetError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            GetError::Unknown(ref s) => write!(f, "{}", s),
+            GetError::Unknown(ref s, _) => write!(f, "{}", s),
             GetError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -194,24 +235,90 @@ impl<E: Error + 'static> Error for GetError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for GetError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            GetError::AccountInactive(_) => Some("account_inactive"),
+            GetError::FatalError(_) => Some("fatal_error"),
+            GetError::InvalidArgName(_) => Some("invalid_arg_name"),
+            GetError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            GetError::InvalidAuth(_) => Some("invalid_auth"),
+            GetError::InvalidCharset(_) => Some("invalid_charset"),
+            GetError::InvalidFormData(_) => Some("invalid_form_data"),
+            GetError::InvalidJson(_) => Some("invalid_json"),
+            GetError::InvalidPostType(_) => Some("invalid_post_type"),
+            GetError::JsonNotObject(_) => Some("json_not_object"),
+            GetError::MissingPostType(_) => Some("missing_post_type"),
+            GetError::NoPermission(_) => Some("no_permission"),
+            GetError::NotAuthed(_) => Some("not_authed"),
+            GetError::OrgLoginRequired(_) => Some("org_login_required"),
+            GetError::RequestTimeout(_) => Some("request_timeout"),
+            GetError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            GetError::TokenRevoked(_) => Some("token_revoked"),
+            GetError::UpgradeRequired(_) => Some("upgrade_required"),
+            GetError::UserIsBot(_) => Some("user_is_bot"),
+            GetError::UserNotFound(_) => Some("user_not_found"),
+            GetError::Unknown(ref s, _) => Some(s),
+            GetError::MalformedResponse(..) | GetError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            GetError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            GetError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            GetError::AccountInactive(ref context)
+            | GetError::FatalError(ref context)
+            | GetError::InvalidArgName(ref context)
+            | GetError::InvalidArrayArg(ref context)
+            | GetError::InvalidAuth(ref context)
+            | GetError::InvalidCharset(ref context)
+            | GetError::InvalidFormData(ref context)
+            | GetError::InvalidJson(ref context)
+            | GetError::InvalidPostType(ref context)
+            | GetError::JsonNotObject(ref context)
+            | GetError::MissingPostType(ref context)
+            | GetError::NoPermission(ref context)
+            | GetError::NotAuthed(ref context)
+            | GetError::OrgLoginRequired(ref context)
+            | GetError::RequestTimeout(ref context)
+            | GetError::TeamAddedToOrg(ref context)
+            | GetError::TokenRevoked(ref context)
+            | GetError::UpgradeRequired(ref context)
+            | GetError::UserIsBot(ref context)
+            | GetError::UserNotFound(ref context)
+            | GetError::Unknown(_, ref context) => Some(context),
+            GetError::MalformedResponse(..) | GetError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct SetRequest<'a> {
     /// Name of a single key to set. Usable only if `profile` is not passed.
     pub name: Option<Cow<'a, str>>,
-    /// Collection of key:value pairs presented as a URL-encoded JSON hash. At most 50 fields may be set. Each field name is limited to 255 characters.
-    pub profile: Option<Cow<'a, str>>,
+    /// Collection of key:value pairs presented as a JSON hash. At most 50 fields may be set. Each field name is limited to 255 characters.
+    pub profile: Option<serde_json::Value>,
     /// ID of user to change. This argument may only be specified by team admins on paid teams.
-    pub user: Option<Cow<'a, str>>,
+    pub user: Option<crate::UserId>,
     /// Value to set a single key to. Usable only if `profile` is not passed.
     pub value: Option<Cow<'a, str>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetProfileInner {
     pub always_active: Option<bool>,
     pub api_app_id: Option<String>,
     pub avatar_hash: String,
-    pub bot_id: Option<String>,
+    pub bot_id: Option<crate::BotId>,
     pub display_name: String,
     pub display_name_normalized: String,
     pub email: Option<String>,
@@ -247,22 +354,28 @@ pub struct SetProfileInner {
     pub status_expiration: Option<u64>,
     pub status_text: String,
     pub status_text_canonical: Option<String>,
-    pub team: Option<String>,
+    pub team: Option<crate::TeamId>,
     pub title: String,
     pub updated: Option<u64>,
-    pub user_id: Option<String>,
+    pub user_id: Option<crate::UserId>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetResponse {
     pub callstack: Option<String>,
     pub email_pending: Option<String>,
     error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
     pub profile: SetProfileInner,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
     pub username: String,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<SetResponse, SetError<E>>> for SetResponse {
@@ -270,111 +383,146 @@ impl<E: Error> Into<Result<SetResponse, SetError<E>>> for SetResponse {
         if self.ok {
             Ok(self)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(SetError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
+            ))
         }
     }
 }
 
+impl crate::SlackResponse for SetResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum SetError<E: Error> {
-    AccountInactive,
-    CannotUpdateAdminUser,
-    FatalError,
-    InvalidArgName,
-    InvalidArrayArg,
-    InvalidAuth,
-    InvalidCharset,
-    InvalidFormData,
-    InvalidJson,
-    InvalidPostType,
-    InvalidProfile,
-    JsonNotObject,
-    MissingPostType,
-    NoPermission,
-    NotAdmin,
-    NotAppAdmin,
-    NotAuthed,
-    OrgLoginRequired,
-    ProfileSetFailed,
-    RequestTimeout,
-    ReservedName,
-    TeamAddedToOrg,
-    TokenRevoked,
-    UpgradeRequired,
-    UserIsBot,
+    AccountInactive(crate::ErrorContext),
+    CannotUpdateAdminUser(crate::ErrorContext),
+    FatalError(crate::ErrorContext),
+    InvalidArgName(crate::ErrorContext),
+    InvalidArrayArg(crate::ErrorContext),
+    InvalidAuth(crate::ErrorContext),
+    InvalidCharset(crate::ErrorContext),
+    InvalidFormData(crate::ErrorContext),
+    InvalidJson(crate::ErrorContext),
+    InvalidPostType(crate::ErrorContext),
+    InvalidProfile(crate::ErrorContext),
+    JsonNotObject(crate::ErrorContext),
+    MissingPostType(crate::ErrorContext),
+    NoPermission(crate::ErrorContext),
+    NotAdmin(crate::ErrorContext),
+    NotAppAdmin(crate::ErrorContext),
+    NotAuthed(crate::ErrorContext),
+    OrgLoginRequired(crate::ErrorContext),
+    ProfileSetFailed(crate::ErrorContext),
+    RequestTimeout(crate::ErrorContext),
+    ReservedName(crate::ErrorContext),
+    TeamAddedToOrg(crate::ErrorContext),
+    TokenRevoked(crate::ErrorContext),
+    UpgradeRequired(crate::ErrorContext),
+    UserIsBot(crate::ErrorContext),
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> SetError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            "account_inactive" => SetError::AccountInactive(context),
+            "cannot_update_admin_user" => SetError::CannotUpdateAdminUser(context),
+            "fatal_error" => SetError::FatalError(context),
+            "invalid_arg_name" => SetError::InvalidArgName(context),
+            "invalid_array_arg" => SetError::InvalidArrayArg(context),
+            "invalid_auth" => SetError::InvalidAuth(context),
+            "invalid_charset" => SetError::InvalidCharset(context),
+            "invalid_form_data" => SetError::InvalidFormData(context),
+            "invalid_json" => SetError::InvalidJson(context),
+            "invalid_post_type" => SetError::InvalidPostType(context),
+            "invalid_profile" => SetError::InvalidProfile(context),
+            "json_not_object" => SetError::JsonNotObject(context),
+            "missing_post_type" => SetError::MissingPostType(context),
+            "no_permission" => SetError::NoPermission(context),
+            "not_admin" => SetError::NotAdmin(context),
+            "not_app_admin" => SetError::NotAppAdmin(context),
+            "not_authed" => SetError::NotAuthed(context),
+            "org_login_required" => SetError::OrgLoginRequired(context),
+            "profile_set_failed" => SetError::ProfileSetFailed(context),
+            "request_timeout" => SetError::RequestTimeout(context),
+            "reserved_name" => SetError::ReservedName(context),
+            "team_added_to_org" => SetError::TeamAddedToOrg(context),
+            "token_revoked" => SetError::TokenRevoked(context),
+            "upgrade_required" => SetError::UpgradeRequired(context),
+            "user_is_bot" => SetError::UserIsBot(context),
+            _ => SetError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for SetError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            "account_inactive" => SetError::AccountInactive,
-            "cannot_update_admin_user" => SetError::CannotUpdateAdminUser,
-            "fatal_error" => SetError::FatalError,
-            "invalid_arg_name" => SetError::InvalidArgName,
-            "invalid_array_arg" => SetError::InvalidArrayArg,
-            "invalid_auth" => SetError::InvalidAuth,
-            "invalid_charset" => SetError::InvalidCharset,
-            "invalid_form_data" => SetError::InvalidFormData,
-            "invalid_json" => SetError::InvalidJson,
-            "invalid_post_type" => SetError::InvalidPostType,
-            "invalid_profile" => SetError::InvalidProfile,
-            "json_not_object" => SetError::JsonNotObject,
-            "missing_post_type" => SetError::MissingPostType,
-            "no_permission" => SetError::NoPermission,
-            "not_admin" => SetError::NotAdmin,
-            "not_app_admin" => SetError::NotAppAdmin,
-            "not_authed" => SetError::NotAuthed,
-            "org_login_required" => SetError::OrgLoginRequired,
-            "profile_set_failed" => SetError::ProfileSetFailed,
-            "request_timeout" => SetError::RequestTimeout,
-            "reserved_name" => SetError::ReservedName,
-            "team_added_to_org" => SetError::TeamAddedToOrg,
-            "token_revoked" => SetError::TokenRevoked,
-            "upgrade_required" => SetError::UpgradeRequired,
-            "user_is_bot" => SetError::UserIsBot,
-            _ => SetError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
 impl<E: Error> fmt::Display for SetError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            SetError::AccountInactive => write!(f, "Server returned error account_inactive"),
-            SetError::CannotUpdateAdminUser => {
+            SetError::AccountInactive(_) => write!(f, "Server returned error account_inactive"),
+            SetError::CannotUpdateAdminUser(_) => {
                 write!(f, "Server returned error cannot_update_admin_user")
             }
-            SetError::FatalError => write!(f, "Server returned error fatal_error"),
-            SetError::InvalidArgName => write!(f, "Server returned error invalid_arg_name"),
-            SetError::InvalidArrayArg => write!(f, "Server returned error invalid_array_arg"),
-            SetError::InvalidAuth => write!(f, "Server returned error invalid_auth"),
-            SetError::InvalidCharset => write!(f, "Server returned error invalid_charset"),
-            SetError::InvalidFormData => write!(f, "Server returned error invalid_form_data"),
-            SetError::InvalidJson => write!(f, "Server returned error invalid_json"),
-            SetError::InvalidPostType => write!(f, "Server returned error invalid_post_type"),
-            SetError::InvalidProfile => write!(f, "Server returned error invalid_profile"),
-            SetError::JsonNotObject => write!(f, "Server returned error json_not_object"),
-            SetError::MissingPostType => write!(f, "Server returned error missing_post_type"),
-            SetError::NoPermission => write!(f, "Server returned error no_permission"),
-            SetError::NotAdmin => write!(f, "Server returned error not_admin"),
-            SetError::NotAppAdmin => write!(f, "Server returned error not_app_admin"),
-            SetError::NotAuthed => write!(f, "Server returned error not_authed"),
-            SetError::OrgLoginRequired => write!(f, "Server returned error org_login_required"),
-            SetError::ProfileSetFailed => write!(f, "Server returned error profile_set_failed"),
-            SetError::RequestTimeout => write!(f, "Server returned error request_timeout"),
-            SetError::ReservedName => write!(f, "Server returned error reserved_name"),
-            SetError::TeamAddedToOrg => write!(f, "Server returned error team_added_to_org"),
-            SetError::TokenRevoked => write!(f, "Server returned error token_revoked"),
-            SetError::UpgradeRequired => write!(f, "Server returned error upgrade_required"),
-            SetError::UserIsBot => write!(f, "Server returned error user_is_bot"),
+            SetError::FatalError(_) => write!(f, "Server returned error fatal_error"),
+            SetError::InvalidArgName(_) => write!(f, "Server returned error invalid_arg_name"),
+            SetError::InvalidArrayArg(_) => write!(f, "Server returned error invalid_array_arg"),
+            SetError::InvalidAuth(_) => write!(f, "Server returned error invalid_auth"),
+            SetError::InvalidCharset(_) => write!(f, "Server returned error invalid_charset"),
+            SetError::InvalidFormData(_) => write!(f, "Server returned error invalid_form_data"),
+            SetError::InvalidJson(_) => write!(f, "Server returned error invalid_json"),
+            SetError::InvalidPostType(_) => write!(f, "Server returned error invalid_post_type"),
+            SetError::InvalidProfile(_) => write!(f, "Server returned error invalid_profile"),
+            SetError::JsonNotObject(_) => write!(f, "Server returned error json_not_object"),
+            SetError::MissingPostType(_) => write!(f, "Server returned error missing_post_type"),
+            SetError::NoPermission(_) => write!(f, "Server returned error no_permission"),
+            SetError::NotAdmin(_) => write!(f, "Server returned error not_admin"),
+            SetError::NotAppAdmin(_) => write!(f, "Server returned error not_app_admin"),
+            SetError::NotAuthed(_) => write!(f, "Server returned error not_authed"),
+            SetError::OrgLoginRequired(_) => write!(f, "Server returned error org_login_required"),
+            SetError::ProfileSetFailed(_) => write!(f, "Server returned error profile_set_failed"),
+            SetError::RequestTimeout(_) => write!(f, "Server returned error request_timeout"),
+            SetError::ReservedName(_) => write!(f, "Server returned error reserved_name"),
+            SetError::TeamAddedToOrg(_) => write!(f, "Server returned error team_added_to_org"),
+            SetError::TokenRevoked(_) => write!(f, "Server returned error token_revoked"),
+            SetError::UpgradeRequired(_) => write!(f, "Server returned error upgrade_required"),
+            SetError::UserIsBot(_) => write!(f, "Server returned error user_is_bot"),
             SetError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            SetError::Unknown(ref s) => write!(f, "{}", s),
+            SetError::Unknown(ref s, _) => write!(f, "{}", s),
             SetError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -389,3 +537,79 @@ impl<E: Error + 'static> Error for SetError<E> {
         }
     }
 }
+
+impl<E: Error + 'static> crate::MethodError for SetError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            SetError::AccountInactive(_) => Some("account_inactive"),
+            SetError::CannotUpdateAdminUser(_) => Some("cannot_update_admin_user"),
+            SetError::FatalError(_) => Some("fatal_error"),
+            SetError::InvalidArgName(_) => Some("invalid_arg_name"),
+            SetError::InvalidArrayArg(_) => Some("invalid_array_arg"),
+            SetError::InvalidAuth(_) => Some("invalid_auth"),
+            SetError::InvalidCharset(_) => Some("invalid_charset"),
+            SetError::InvalidFormData(_) => Some("invalid_form_data"),
+            SetError::InvalidJson(_) => Some("invalid_json"),
+            SetError::InvalidPostType(_) => Some("invalid_post_type"),
+            SetError::InvalidProfile(_) => Some("invalid_profile"),
+            SetError::JsonNotObject(_) => Some("json_not_object"),
+            SetError::MissingPostType(_) => Some("missing_post_type"),
+            SetError::NoPermission(_) => Some("no_permission"),
+            SetError::NotAdmin(_) => Some("not_admin"),
+            SetError::NotAppAdmin(_) => Some("not_app_admin"),
+            SetError::NotAuthed(_) => Some("not_authed"),
+            SetError::OrgLoginRequired(_) => Some("org_login_required"),
+            SetError::ProfileSetFailed(_) => Some("profile_set_failed"),
+            SetError::RequestTimeout(_) => Some("request_timeout"),
+            SetError::ReservedName(_) => Some("reserved_name"),
+            SetError::TeamAddedToOrg(_) => Some("team_added_to_org"),
+            SetError::TokenRevoked(_) => Some("token_revoked"),
+            SetError::UpgradeRequired(_) => Some("upgrade_required"),
+            SetError::UserIsBot(_) => Some("user_is_bot"),
+            SetError::Unknown(ref s, _) => Some(s),
+            SetError::MalformedResponse(..) | SetError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            SetError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            SetError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            SetError::AccountInactive(ref context)
+            | SetError::CannotUpdateAdminUser(ref context)
+            | SetError::FatalError(ref context)
+            | SetError::InvalidArgName(ref context)
+            | SetError::InvalidArrayArg(ref context)
+            | SetError::InvalidAuth(ref context)
+            | SetError::InvalidCharset(ref context)
+            | SetError::InvalidFormData(ref context)
+            | SetError::InvalidJson(ref context)
+            | SetError::InvalidPostType(ref context)
+            | SetError::InvalidProfile(ref context)
+            | SetError::JsonNotObject(ref context)
+            | SetError::MissingPostType(ref context)
+            | SetError::NoPermission(ref context)
+            | SetError::NotAdmin(ref context)
+            | SetError::NotAppAdmin(ref context)
+            | SetError::NotAuthed(ref context)
+            | SetError::OrgLoginRequired(ref context)
+            | SetError::ProfileSetFailed(ref context)
+            | SetError::RequestTimeout(ref context)
+            | SetError::ReservedName(ref context)
+            | SetError::TeamAddedToOrg(ref context)
+            | SetError::TokenRevoked(ref context)
+            | SetError::UpgradeRequired(ref context)
+            | SetError::UserIsBot(ref context)
+            | SetError::Unknown(_, ref context) => Some(context),
+            SetError::MalformedResponse(..) | SetError::Client(_) => None,
+        }
+    }
+}
@@ -29,10 +29,17 @@ pub struct StepCompletedRequest<'a> {
     pub workflow_step_execute_id: Cow<'a, str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct StepCompletedResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<StepCompletedResponse, StepCompletedError<E>>>
@@ -42,28 +49,61 @@ impl<E: Error> Into<Result<StepCompletedResponse, StepCompletedError<E>>>
         if self.ok {
             Ok(self)
         } else {
-            Err(StepCompletedError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(StepCompletedError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for StepCompletedResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum StepCompletedError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> StepCompletedError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => StepCompletedError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for StepCompletedError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => StepCompletedError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -71,7 +111,7 @@ impl<E: Error> fmt::Display for StepCompletedError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             StepCompletedError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            StepCompletedError::Unknown(ref s) => write!(f, "{}", s),
+            StepCompletedError::Unknown(ref s, _) => write!(f, "{}", s),
             StepCompletedError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -87,6 +127,32 @@ impl<E: Error + 'static> Error for StepCompletedError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for StepCompletedError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            StepCompletedError::Unknown(ref s, _) => Some(s),
+            StepCompletedError::MalformedResponse(..) | StepCompletedError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            StepCompletedError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            StepCompletedError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            StepCompletedError::Unknown(_, ref context) => Some(context),
+            StepCompletedError::MalformedResponse(..) | StepCompletedError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct StepFailedRequest<'a> {
     /// A JSON-based object with a `message` property that should contain a human readable error message.
@@ -95,10 +161,17 @@ pub struct StepFailedRequest<'a> {
     pub workflow_step_execute_id: Cow<'a, str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct StepFailedResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<StepFailedResponse, StepFailedError<E>>> for StepFailedResponse {
@@ -106,28 +179,61 @@ impl<E: Error> Into<Result<StepFailedResponse, StepFailedError<E>>> for StepFail
         if self.ok {
             Ok(self)
         } else {
-            Err(StepFailedError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(StepFailedError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for StepFailedResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum StepFailedError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> StepFailedError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => StepFailedError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for StepFailedError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => StepFailedError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -135,7 +241,7 @@ impl<E: Error> fmt::Display for StepFailedError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             StepFailedError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            StepFailedError::Unknown(ref s) => write!(f, "{}", s),
+            StepFailedError::Unknown(ref s, _) => write!(f, "{}", s),
             StepFailedError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -151,6 +257,32 @@ impl<E: Error + 'static> Error for StepFailedError<E> {
     }
 }
 
+impl<E: Error + 'static> crate::MethodError for StepFailedError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            StepFailedError::Unknown(ref s, _) => Some(s),
+            StepFailedError::MalformedResponse(..) | StepFailedError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            StepFailedError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            StepFailedError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            StepFailedError::Unknown(_, ref context) => Some(context),
+            StepFailedError::MalformedResponse(..) | StepFailedError::Client(_) => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct UpdateStepRequest<'a> {
     /// A JSON key-value map of inputs required from a user during configuration. This is the data your app expects to receive when the workflow step starts. **Please note**: the embedded variable format is set and replaced by the workflow system. You cannot create custom variables that will be replaced at runtime. [Read more about variables in workflow steps here](/workflows/steps#variables).
@@ -165,10 +297,17 @@ pub struct UpdateStepRequest<'a> {
     pub workflow_step_edit_id: Cow<'a, str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UpdateStepResponse {
+    error: Option<String>,
+    pub needed: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub provided: Option<String>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub warning: Option<String>,
+    #[serde(skip)]
+    pub(crate) http: crate::HttpInfo,
 }
 
 impl<E: Error> Into<Result<UpdateStepResponse, UpdateStepError<E>>> for UpdateStepResponse {
@@ -176,28 +315,61 @@ impl<E: Error> Into<Result<UpdateStepResponse, UpdateStepError<E>>> for UpdateSt
         if self.ok {
             Ok(self)
         } else {
-            Err(UpdateStepError::Unknown(
-                "Server failed without providing an error message.".into(),
+            let (messages, warnings) = self
+                .response_metadata
+                .map_or((None, None), |m| (m.messages, m.warnings));
+            let context = crate::ErrorContext::new(
+                self.needed,
+                self.provided,
+                self.warning,
+                messages,
+                warnings,
+            );
+            Err(UpdateStepError::new(
+                self.error.as_ref().map(String::as_ref).unwrap_or(""),
+                context,
             ))
         }
     }
 }
 
+impl crate::SlackResponse for UpdateStepResponse {
+    fn http(&self) -> &crate::HttpInfo {
+        &self.http
+    }
+
+    fn warnings(&self) -> Vec<&str> {
+        crate::response::collect_warnings(
+            self.warning.as_deref(),
+            self.response_metadata
+                .as_ref()
+                .and_then(|m| m.warnings.as_deref()),
+        )
+    }
+}
+
 #[derive(Debug)]
 pub enum UpdateStepError<E: Error> {
     /// The response was not parseable as the expected object
     MalformedResponse(String, serde_json::error::Error),
     /// The response returned an error that was unknown to the library
-    Unknown(String),
+    Unknown(String, crate::ErrorContext),
     /// The client had an error sending the request to Slack
     Client(E),
 }
 
+impl<E: Error> UpdateStepError<E> {
+    /// The error for an error string Slack returned, with what else Slack said about it.
+    pub(crate) fn new(code: &str, context: crate::ErrorContext) -> Self {
+        match code {
+            _ => UpdateStepError::Unknown(code.to_owned(), context),
+        }
+    }
+}
+
 impl<'a, E: Error> From<&'a str> for UpdateStepError<E> {
     fn from(s: &'a str) -> Self {
-        match s {
-            _ => UpdateStepError::Unknown(s.to_owned()),
-        }
+        Self::new(s, crate::ErrorContext::default())
     }
 }
 
@@ -205,7 +377,7 @@ impl<E: Error> fmt::Display for UpdateStepError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             UpdateStepError::MalformedResponse(_, ref e) => write!(f, "{}", e),
-            UpdateStepError::Unknown(ref s) => write!(f, "{}", s),
+            UpdateStepError::Unknown(ref s, _) => write!(f, "{}", s),
             UpdateStepError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
@@ -220,3 +392,29 @@ impl<E: Error + 'static> Error for UpdateStepError<E> {
         }
     }
 }
+
+impl<E: Error + 'static> crate::MethodError for UpdateStepError<E> {
+    fn code(&self) -> Option<&str> {
+        match *self {
+            UpdateStepError::Unknown(ref s, _) => Some(s),
+            UpdateStepError::MalformedResponse(..) | UpdateStepError::Client(_) => None,
+        }
+    }
+
+    fn kind(&self) -> crate::ErrorKind {
+        match *self {
+            UpdateStepError::MalformedResponse(..) => crate::ErrorKind::Malformed,
+            UpdateStepError::Client(_) => crate::ErrorKind::Client,
+            _ => self
+                .code()
+                .map_or(crate::ErrorKind::Other, crate::ErrorKind::from_code),
+        }
+    }
+
+    fn context(&self) -> Option<&crate::ErrorContext> {
+        match *self {
+            UpdateStepError::Unknown(_, ref context) => Some(context),
+            UpdateStepError::MalformedResponse(..) | UpdateStepError::Client(_) => None,
+        }
+    }
+}
@@ -0,0 +1,247 @@
+//! Support for the generated `*_stream` and `*_iter` functions, which follow a list method's
+//! cursor, or its page numbers, across pages.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # #[cfg(feature = "reqwest")]
+//! # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! use futures_util::stream::StreamExt;
+//!
+//! let client = slack_api::default_client()?;
+//! let request = slack_api::conversations::ListRequest::default();
+//! let channels = slack_api::conversations::list_stream(&client, "xoxb-...", &request, Some(500));
+//! futures_util::pin_mut!(channels);
+//! while let Some(channel) = channels.next().await {
+//!     println!("{}", channel?.id);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::vec;
+
+/// The highest page number Slack serves for methods that continue with `before` past it, such
+/// as `team.accessLogs`.
+const MAX_PAGE: u64 = 100;
+
+/// A request of a cursor-paginated method.
+pub trait CursorRequest: Clone {
+    /// Sets the cursor to request the next page with.
+    fn set_cursor(&mut self, cursor: String);
+}
+
+/// A response of a cursor-paginated method.
+pub trait CursorPage {
+    type Item;
+
+    /// The cursor of the next page; `None` or an empty cursor means this is the last page.
+    fn next_cursor(&self) -> Option<&str>;
+
+    /// The items of this page.
+    fn into_items(self) -> Vec<Self::Item>;
+}
+
+/// A request of a method paginated by `page` number.
+pub trait PageRequest: Clone {
+    /// Sets the number of the page to request, starting at 1.
+    fn set_page(&mut self, page: u64);
+
+    /// Sets the `before` argument, for methods that continue past their last page number with it.
+    fn set_before(&mut self, _before: crate::Timestamp) {}
+}
+
+/// A response of a method paginated by `page` number, which describes itself in a `paging`
+/// object.
+pub trait NumberedPage {
+    type Item;
+
+    /// The number of this page, starting at 1.
+    fn page(&self) -> Option<u64>;
+
+    /// The number of pages in total.
+    fn pages(&self) -> Option<u64>;
+
+    /// The items of this page.
+    fn into_items(self) -> Vec<Self::Item>;
+
+    /// The `before` argument to continue with once the highest page number is reached, for
+    /// methods such as `team.accessLogs` that support it.
+    fn continue_before(&self) -> Option<crate::Timestamp> {
+        None
+    }
+}
+
+/// A page of results along with how to request the next one.
+pub(crate) trait Page<Req> {
+    type Item;
+
+    /// The request for the page after this one, made from the request for this one.
+    fn next_request(&self, request: Req) -> Option<Req>;
+
+    fn into_items(self) -> Vec<Self::Item>;
+}
+
+/// A page of a cursor-paginated method.
+pub(crate) struct Cursor<P>(pub(crate) P);
+
+impl<Req: CursorRequest, P: CursorPage> Page<Req> for Cursor<P> {
+    type Item = P::Item;
+
+    fn next_request(&self, mut request: Req) -> Option<Req> {
+        match self.0.next_cursor() {
+            Some(cursor) if !cursor.is_empty() => {
+                request.set_cursor(cursor.to_owned());
+                Some(request)
+            }
+            _ => None,
+        }
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.0.into_items()
+    }
+}
+
+/// A page of a method paginated by page number.
+pub(crate) struct Numbered<P>(pub(crate) P);
+
+impl<Req: PageRequest, P: NumberedPage> Page<Req> for Numbered<P> {
+    type Item = P::Item;
+
+    fn next_request(&self, mut request: Req) -> Option<Req> {
+        let page = self.0.page()?;
+        if page >= self.0.pages()? {
+            return None;
+        }
+        if page >= MAX_PAGE {
+            request.set_before(self.0.continue_before()?);
+            request.set_page(1);
+        } else {
+            request.set_page(page + 1);
+        }
+        Some(request)
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.0.into_items()
+    }
+}
+
+/// The bookkeeping shared by the async streams and sync iterators.
+pub(crate) struct PageState<Req, T> {
+    next_request: Option<Req>,
+    items: vec::IntoIter<T>,
+    remaining: Option<usize>,
+}
+
+impl<Req, T> PageState<Req, T> {
+    pub(crate) fn new(request: Req, max_items: Option<usize>) -> Self {
+        PageState {
+            next_request: Some(request),
+            items: Vec::new().into_iter(),
+            remaining: max_items,
+        }
+    }
+
+    /// Yields the next item of the current page, if any are left and the cap is not reached.
+    pub(crate) fn next_item(&mut self) -> Option<T> {
+        if self.remaining == Some(0) {
+            return None;
+        }
+        let item = self.items.next()?;
+        self.remaining = self.remaining.map(|remaining| remaining - 1);
+        Some(item)
+    }
+
+    /// The request for the next page, unless the last page was reached or the cap was.
+    pub(crate) fn next_request(&mut self) -> Option<Req> {
+        if self.remaining == Some(0) {
+            return None;
+        }
+        self.next_request.take()
+    }
+
+    /// Stores the items of the page returned for `request`, and the request for the page after.
+    pub(crate) fn add_page<P>(&mut self, request: Req, page: P)
+    where
+        P: Page<Req, Item = T>,
+    {
+        self.next_request = page.next_request(request);
+        self.items = page.into_items().into_iter();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, Default, PartialEq)]
+    struct Request {
+        page: Option<u64>,
+        before: Option<crate::Timestamp>,
+    }
+
+    impl PageRequest for Request {
+        fn set_page(&mut self, page: u64) {
+            self.page = Some(page);
+        }
+
+        fn set_before(&mut self, before: crate::Timestamp) {
+            self.before = Some(before);
+        }
+    }
+
+    struct Logs {
+        page: u64,
+        pages: u64,
+        oldest: Option<u64>,
+    }
+
+    impl NumberedPage for Logs {
+        type Item = ();
+
+        fn page(&self) -> Option<u64> {
+            Some(self.page)
+        }
+
+        fn pages(&self) -> Option<u64> {
+            Some(self.pages)
+        }
+
+        fn into_items(self) -> Vec<()> {
+            Vec::new()
+        }
+
+        fn continue_before(&self) -> Option<crate::Timestamp> {
+            self.oldest.map(crate::Timestamp::from)
+        }
+    }
+
+    fn next(page: u64, pages: u64, oldest: Option<u64>) -> Option<Request> {
+        Numbered(Logs {
+            page,
+            pages,
+            oldest,
+        })
+        .next_request(Request::default())
+    }
+
+    #[test]
+    fn numbered_pages_stop_at_last_page() {
+        assert_eq!(next(1, 3, None).and_then(|r| r.page), Some(2));
+        assert_eq!(next(3, 3, None), None);
+    }
+
+    #[test]
+    fn numbered_pages_continue_with_before_past_max_page() {
+        assert_eq!(
+            next(100, 250, Some(1_500_000_000)),
+            Some(Request {
+                page: Some(1),
+                before: Some(1_500_000_000.into()),
+            })
+        );
+        assert_eq!(next(100, 250, None), None);
+    }
+}
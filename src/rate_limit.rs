@@ -0,0 +1,322 @@
+//! Rate limit bookkeeping shared by the async and sync `RateLimitedSender` wrappers.
+//!
+//! Slack groups Web API methods into [rate limit tiers](https://api.slack.com/docs/rate-limits).
+//! A [`RateLimiter`] keeps a per-method budget based on those tiers so that bulk jobs slow down
+//! before Slack starts answering `429 Too Many Requests`, and decides how long to wait when it
+//! does anyway.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::SlackWebResponse;
+
+/// The rate limit tiers Slack assigns to Web API methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RateLimitTier {
+    /// 1+ requests per minute.
+    Tier1,
+    /// 20+ requests per minute.
+    Tier2,
+    /// 50+ requests per minute.
+    Tier3,
+    /// 100+ requests per minute.
+    Tier4,
+    /// Methods with their own limits, such as `chat.postMessage` at roughly one message per
+    /// second.
+    Special,
+}
+
+impl RateLimitTier {
+    /// Looks up the tier Slack documents for a method such as `conversations.history`.
+    ///
+    /// Methods that are not known fall back to `Tier3`.
+    pub fn for_method(method: &str) -> Self {
+        use RateLimitTier::*;
+
+        match method {
+            "rtm.connect" | "rtm.start" | "apps.uninstall" => Tier1,
+
+            "admin.conversations.search"
+            | "conversations.archive"
+            | "conversations.close"
+            | "conversations.create"
+            | "conversations.list"
+            | "conversations.rename"
+            | "conversations.setPurpose"
+            | "conversations.setTopic"
+            | "conversations.unarchive"
+            | "dnd.teamInfo"
+            | "emoji.list"
+            | "files.upload"
+            | "migration.exchange"
+            | "pins.add"
+            | "pins.list"
+            | "pins.remove"
+            | "reactions.list"
+            | "reactions.remove"
+            | "stars.add"
+            | "stars.remove"
+            | "team.accessLogs"
+            | "team.billableInfo"
+            | "team.integrationLogs"
+            | "users.deletePhoto"
+            | "users.list"
+            | "users.setActive"
+            | "users.setPhoto"
+            | "users.setPresence" => Tier2,
+
+            "chat.postEphemeral"
+            | "conversations.members"
+            | "files.completeUploadExternal"
+            | "files.getUploadURLExternal"
+            | "files.info"
+            | "users.identity"
+            | "users.info"
+            | "users.profile.get"
+            | "api.test"
+            | "auth.test"
+            | "dialog.open" => Tier4,
+
+            "chat.postMessage" | "chat.getPermalink" => Special,
+
+            m if m.starts_with("admin.")
+                || m.starts_with("calls.")
+                || m.starts_with("files.remote.")
+                || m.starts_with("reminders.")
+                || m.starts_with("search.")
+                || m.starts_with("usergroups.") =>
+            {
+                Tier2
+            }
+            m if m.starts_with("oauth.") || m.starts_with("views.") => Tier4,
+            _ => Tier3,
+        }
+    }
+
+    /// The number of requests per minute the tier is guaranteed to allow.
+    pub fn requests_per_minute(self) -> u32 {
+        match self {
+            RateLimitTier::Tier1 => 1,
+            RateLimitTier::Tier2 => 20,
+            RateLimitTier::Tier3 => 50,
+            RateLimitTier::Tier4 => 100,
+            RateLimitTier::Special => 60,
+        }
+    }
+}
+
+/// How a `RateLimitedSender` reacts to `429 Too Many Requests`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// How many times a rate limited call is retried before the `429` response is returned.
+    pub max_retries: u32,
+    /// How long to wait when Slack does not send a `Retry-After` header.
+    pub default_retry_after: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: 3,
+            default_retry_after: Duration::from_secs(5),
+        }
+    }
+}
+
+/// Per-method request budget and retry decisions.
+#[derive(Debug)]
+pub struct RateLimiter {
+    policy: RetryPolicy,
+    budget: Option<Mutex<MethodBudget>>,
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RateLimiter {
+    /// A limiter with the default [`RetryPolicy`] that also paces calls by tier.
+    pub fn new() -> Self {
+        Self::with_policy(RetryPolicy::default())
+    }
+
+    /// A limiter with the given retry policy that also paces calls by tier.
+    pub fn with_policy(policy: RetryPolicy) -> Self {
+        RateLimiter {
+            policy,
+            budget: Some(Mutex::new(MethodBudget::default())),
+        }
+    }
+
+    /// Stops pacing calls by tier; only `429` responses cause a wait.
+    pub fn without_budget(mut self) -> Self {
+        self.budget = None;
+        self
+    }
+
+    pub fn policy(&self) -> &RetryPolicy {
+        &self.policy
+    }
+
+    /// Reserves a slot in the method's budget and returns how long to wait before sending.
+    pub(crate) fn before_request(&self, method_url: &str) -> Duration {
+        match &self.budget {
+            Some(budget) => budget
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .reserve(method_name(method_url), Instant::now()),
+            None => Duration::from_secs(0),
+        }
+    }
+
+    /// Returns how long to wait before retrying, or `None` if the response should be returned.
+    pub(crate) fn after_response(
+        &self,
+        method_url: &str,
+        response: &SlackWebResponse,
+        attempt: u32,
+    ) -> Option<Duration> {
+        if !response.is_rate_limited() {
+            return None;
+        }
+        let wait = response
+            .retry_after()
+            .unwrap_or(self.policy.default_retry_after);
+        if let Some(budget) = &self.budget {
+            budget.lock().unwrap_or_else(|e| e.into_inner()).block(
+                method_name(method_url),
+                Instant::now(),
+                wait,
+            );
+        }
+        if attempt < self.policy.max_retries {
+            Some(wait)
+        } else {
+            None
+        }
+    }
+}
+
+/// Extracts `conversations.history` from `https://slack.com/api/conversations.history`.
+fn method_name(method_url: &str) -> &str {
+    method_url.rsplit('/').next().unwrap_or(method_url)
+}
+
+#[derive(Debug, Default)]
+struct MethodBudget {
+    buckets: HashMap<String, Bucket>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+    blocked_until: Option<Instant>,
+}
+
+impl MethodBudget {
+    /// Takes a token from the method's bucket, which holds a minute worth of requests and refills
+    /// at the tier's rate. Tokens may go negative; the caller then waits until they are repaid.
+    fn reserve(&mut self, method: &str, now: Instant) -> Duration {
+        let per_minute = f64::from(RateLimitTier::for_method(method).requests_per_minute());
+        let per_second = per_minute / 60.0;
+        let bucket = self
+            .buckets
+            .entry(method.to_owned())
+            .or_insert_with(|| Bucket {
+                tokens: per_minute,
+                updated: now,
+                blocked_until: None,
+            });
+
+        let elapsed = now.saturating_duration_since(bucket.updated).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * per_second).min(per_minute);
+        bucket.updated = now;
+        bucket.tokens -= 1.0;
+
+        let mut wait = if bucket.tokens < 0.0 {
+            Duration::from_secs_f64(-bucket.tokens / per_second)
+        } else {
+            Duration::from_secs(0)
+        };
+        if let Some(until) = bucket.blocked_until {
+            wait = wait.max(until.saturating_duration_since(now));
+        }
+        wait
+    }
+
+    /// Records a `429` so that every caller of the method waits out the `Retry-After`.
+    fn block(&mut self, method: &str, now: Instant, retry_after: Duration) {
+        if let Some(bucket) = self.buckets.get_mut(method) {
+            let until = now + retry_after;
+            bucket.blocked_until = Some(bucket.blocked_until.map_or(until, |b| b.max(until)));
+            bucket.tokens = bucket.tokens.min(0.0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tiers_for_methods() {
+        assert_eq!(
+            RateLimitTier::for_method("conversations.history"),
+            RateLimitTier::Tier3
+        );
+        assert_eq!(
+            RateLimitTier::for_method("users.list"),
+            RateLimitTier::Tier2
+        );
+        assert_eq!(
+            RateLimitTier::for_method("admin.users.session.reset"),
+            RateLimitTier::Tier2
+        );
+        assert_eq!(
+            RateLimitTier::for_method("chat.postMessage"),
+            RateLimitTier::Special
+        );
+    }
+
+    #[test]
+    fn method_name_from_url() {
+        assert_eq!(
+            method_name("https://slack.com/api/conversations.history"),
+            "conversations.history"
+        );
+    }
+
+    #[test]
+    fn budget_allows_a_burst_then_paces() {
+        let mut budget = MethodBudget::default();
+        let now = Instant::now();
+        for _ in 0..20 {
+            assert_eq!(budget.reserve("users.list", now), Duration::from_secs(0));
+        }
+        assert_eq!(budget.reserve("users.list", now), Duration::from_secs(3));
+        assert_eq!(budget.reserve("users.list", now), Duration::from_secs(6));
+    }
+
+    #[test]
+    fn rate_limited_response_blocks_the_method() {
+        let limiter = RateLimiter::new();
+        let url = "https://slack.com/api/users.list";
+        assert_eq!(limiter.before_request(url), Duration::from_secs(0));
+
+        let response = SlackWebResponse {
+            status: 429,
+            headers: vec![("Retry-After".into(), "10".into())],
+            body: String::new(),
+        };
+        assert_eq!(
+            limiter.after_response(url, &response, 0),
+            Some(Duration::from_secs(10))
+        );
+        assert!(limiter.before_request(url) > Duration::from_secs(9));
+        assert_eq!(limiter.after_response(url, &response, 3), None);
+    }
+}
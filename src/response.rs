@@ -0,0 +1,227 @@
+//! The raw HTTP response a request sender got back from Slack, and the parts of it that are kept
+//! on every generated `*Response`.
+
+use std::time::Duration;
+
+/// The error of file uploads through request senders that do not override `post_multipart`.
+pub const MULTIPART_UNSUPPORTED: &str = "multipart_unsupported";
+
+/// Status, headers and body of a response to a Slack Web API call.
+///
+/// Request senders that cannot see the HTTP envelope report a `200` status and no headers.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SlackWebResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl SlackWebResponse {
+    /// A `200 OK` response without headers, used for senders that only return the body.
+    pub fn from_body(body: String) -> Self {
+        SlackWebResponse {
+            status: 200,
+            headers: Vec::new(),
+            body,
+        }
+    }
+
+    /// The answer of request senders that cannot send `multipart/form-data`.
+    pub(crate) fn multipart_unsupported() -> Self {
+        Self::from_body(format!(
+            r#"{{"ok": false, "error": "{}"}}"#,
+            MULTIPART_UNSUPPORTED
+        ))
+    }
+
+    /// Whether this is the answer of a request sender that cannot send `multipart/form-data`.
+    pub(crate) fn is_multipart_unsupported(&self) -> bool {
+        serde_json::from_str::<serde_json::Value>(&self.body)
+            .map(|body| body["error"] == MULTIPART_UNSUPPORTED)
+            .unwrap_or(false)
+    }
+
+    /// Splits the response into its status and headers, and its body.
+    pub fn into_parts(self) -> (HttpInfo, String) {
+        (
+            HttpInfo {
+                status: self.status,
+                headers: self.headers,
+            },
+            self.body,
+        )
+    }
+
+    /// Returns the first header with the given name, compared case-insensitively.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+
+    /// Whether Slack rejected the call with `429 Too Many Requests`.
+    pub fn is_rate_limited(&self) -> bool {
+        self.status == 429
+    }
+
+    /// How long Slack asked us to wait before retrying, from the `Retry-After` header.
+    pub fn retry_after(&self) -> Option<Duration> {
+        parse_retry_after(self.header("retry-after"))
+    }
+}
+
+/// HTTP status and headers of a Slack Web API response.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HttpInfo {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+}
+
+impl HttpInfo {
+    /// Returns the first header with the given name, compared case-insensitively.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+
+    /// The scopes granted to the token that made the call, from `x-oauth-scopes`.
+    pub fn oauth_scopes(&self) -> Vec<&str> {
+        split_scopes(self.header("x-oauth-scopes"))
+    }
+
+    /// The scopes the called method accepts, from `x-accepted-oauth-scopes`.
+    pub fn accepted_oauth_scopes(&self) -> Vec<&str> {
+        split_scopes(self.header("x-accepted-oauth-scopes"))
+    }
+
+    /// Slack's identifier for the request, from `x-slack-req-id`. Include it when contacting
+    /// Slack support about a call.
+    pub fn request_id(&self) -> Option<&str> {
+        self.header("x-slack-req-id")
+    }
+
+    /// How long Slack asked us to wait before retrying, from the `Retry-After` header.
+    pub fn retry_after(&self) -> Option<Duration> {
+        parse_retry_after(self.header("retry-after"))
+    }
+}
+
+/// The `response_metadata` of responses that do not declare their own.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ResponseMetadata {
+    pub next_cursor: Option<String>,
+    /// Explanations of errors and warnings, such as `[ERROR] missing required field: channel`.
+    pub messages: Option<Vec<String>>,
+    pub warnings: Option<Vec<String>>,
+}
+
+/// Implemented by every generated `*Response` type.
+pub trait SlackResponse {
+    /// HTTP status and headers Slack sent along with the response.
+    ///
+    /// Empty if the request sender does not implement `get_response` and `post_response`.
+    fn http(&self) -> &HttpInfo;
+
+    /// The warnings Slack sent along with the response, such as `message_truncated` or
+    /// `missing_charset`, from `warning` and `response_metadata.warnings`.
+    fn warnings(&self) -> Vec<&str>;
+}
+
+fn find_header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(k, _)| k.eq_ignore_ascii_case(name))
+        .map(|(_, v)| v.as_str())
+}
+
+fn parse_retry_after(value: Option<&str>) -> Option<Duration> {
+    value
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
+
+pub(crate) fn split_scopes(value: Option<&str>) -> Vec<&str> {
+    value
+        .map(|v| {
+            v.split(',')
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// The warnings of a comma-separated `warning` member and of `response_metadata.warnings`,
+/// without duplicates.
+pub(crate) fn collect_warnings<'a>(
+    warning: Option<&'a str>,
+    warnings: Option<&'a [String]>,
+) -> Vec<&'a str> {
+    let mut collected: Vec<&str> = Vec::new();
+    let all = warning
+        .into_iter()
+        .flat_map(|w| w.split(','))
+        .map(str::trim)
+        .chain(warnings.into_iter().flatten().map(String::as_str));
+    for w in all {
+        if !w.is_empty() && !collected.contains(&w) {
+            collected.push(w);
+        }
+    }
+    collected
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retry_after_is_read_case_insensitively() {
+        let response = SlackWebResponse {
+            status: 429,
+            headers: vec![("Retry-After".into(), "30".into())],
+            body: String::new(),
+        };
+        assert!(response.is_rate_limited());
+        assert_eq!(response.retry_after(), Some(Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn scopes_and_request_id() {
+        let (http, body) = SlackWebResponse {
+            status: 200,
+            headers: vec![
+                ("x-oauth-scopes".into(), "chat:write, channels:read".into()),
+                ("x-accepted-oauth-scopes".into(), "".into()),
+                ("x-slack-req-id".into(), "c0ffee".into()),
+            ],
+            body: "{}".into(),
+        }
+        .into_parts();
+        assert_eq!(body, "{}");
+        assert_eq!(http.oauth_scopes(), vec!["chat:write", "channels:read"]);
+        assert!(http.accepted_oauth_scopes().is_empty());
+        assert_eq!(http.request_id(), Some("c0ffee"));
+    }
+
+    #[test]
+    fn missing_retry_after() {
+        let response = SlackWebResponse::from_body("{}".into());
+        assert!(!response.is_rate_limited());
+        assert_eq!(response.retry_after(), None);
+    }
+
+    #[test]
+    fn warnings_are_collected_once() {
+        let metadata = vec![
+            "superfluous_charset".to_owned(),
+            "missing_charset".to_owned(),
+        ];
+        assert_eq!(
+            collect_warnings(Some("missing_charset,message_truncated"), Some(&metadata)),
+            vec![
+                "missing_charset",
+                "message_truncated",
+                "superfluous_charset"
+            ]
+        );
+        assert!(collect_warnings(None, None).is_empty());
+    }
+}
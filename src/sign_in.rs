@@ -0,0 +1,547 @@
+//! Sign in with Slack: the OpenID Connect link that sends users to Slack to sign in, the claims
+//! of the ID token `openid.connect.token` returns, and, with the `"openid"` feature, checking
+//! that token against Slack's signing keys.
+
+use std::borrow::Cow;
+use std::time::Duration;
+
+use crate::install::InstallFlow;
+use crate::mod_types::openid::connect_types::TokenRequest;
+use crate::{EnterpriseId, TeamId, UserId};
+
+/// Where users are sent to sign in with Slack.
+pub const OPENID_AUTHORIZE_URL: &str = "https://slack.com/openid/connect/authorize";
+
+/// Where Slack publishes the keys that sign its ID tokens, as a [`Jwks`].
+pub const OPENID_KEYS_URL: &str = "https://slack.com/openid/connect/keys";
+
+/// The issuer of Slack's ID tokens.
+pub const OPENID_ISSUER: &str = "https://slack.com";
+
+/// The settings of an app's Sign in with Slack flow.
+///
+/// The flow goes:
+/// 1. [`new_state`](Self::new_state) and [`new_nonce`](Self::new_nonce) create a `state` and a
+///    `nonce`, and [`authorize_url`](Self::authorize_url) the link to Slack that carries them.
+///    Both are kept, e.g. in a cookie, until the user comes back.
+/// 2. Slack redirects the user back to the app with a `code` and the same `state`.
+/// 3. `openid::connect::sign_in` checks the `state` against the one kept for the user, exchanges
+///    the `code` with `openid.connect.token` and checks that the ID token carries the `nonce`.
+///
+/// The `state` is signed, expires and is bound to the user's browser like the one of an
+/// [`InstallFlow`].
+///
+/// # Examples
+///
+/// ```
+/// let flow = slack_api::SignInFlow::new("123.456", "client secret")
+///     .with_redirect_uri("https://example.com/slack/sign_in");
+/// let state = flow.new_state();
+/// let nonce = flow.new_nonce();
+/// let url = flow.authorize_url(&state, &nonce);
+/// assert!(url.starts_with("https://slack.com/openid/connect/authorize?response_type=code"));
+/// assert!(url.contains("scope=openid+email+profile"));
+/// assert!(flow.check_state(&state, &state).is_ok());
+/// ```
+#[derive(Clone, Debug)]
+pub struct SignInFlow {
+    install: InstallFlow,
+    scopes: Vec<String>,
+    team: Option<TeamId>,
+    authorize_url: String,
+}
+
+impl SignInFlow {
+    /// The flow of the app with the given credentials, asking for the `openid`, `email` and
+    /// `profile` scopes.
+    pub fn new<I: Into<String>, S: Into<String>>(client_id: I, client_secret: S) -> Self {
+        SignInFlow {
+            install: InstallFlow::new(client_id, client_secret),
+            scopes: vec!["openid".into(), "email".into(), "profile".into()],
+            team: None,
+            authorize_url: OPENID_AUTHORIZE_URL.into(),
+        }
+    }
+
+    /// Asks for `scopes` instead, which should include `openid`.
+    pub fn with_scopes<I, S>(mut self, scopes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.scopes = scopes.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Has Slack redirect users back to `redirect_uri`.
+    pub fn with_redirect_uri<S: Into<String>>(mut self, redirect_uri: S) -> Self {
+        self.install = self.install.with_redirect_uri(redirect_uri);
+        self
+    }
+
+    /// Has users sign in to the workspace `team` if they are already signed in to it.
+    pub fn with_team<T: Into<TeamId>>(mut self, team: T) -> Self {
+        self.team = Some(team.into());
+        self
+    }
+
+    /// Signs states with `secret` instead of the client secret.
+    pub fn with_state_secret<B: Into<Vec<u8>>>(mut self, secret: B) -> Self {
+        self.install = self.install.with_state_secret(secret);
+        self
+    }
+
+    /// Rejects states older than `max_age` instead of 10 minutes.
+    pub fn with_state_max_age(mut self, max_age: Duration) -> Self {
+        self.install = self.install.with_state_max_age(max_age);
+        self
+    }
+
+    /// Sends users to `authorize_url` instead of [`OPENID_AUTHORIZE_URL`].
+    pub fn with_authorize_url<S: Into<String>>(mut self, authorize_url: S) -> Self {
+        self.authorize_url = authorize_url.into();
+        self
+    }
+
+    pub fn client_id(&self) -> &str {
+        self.install.client_id()
+    }
+
+    /// A new signed `state`, for one user's sign in; keep it in a cookie or the user's session
+    /// to check the one Slack redirects back with.
+    pub fn new_state(&self) -> String {
+        self.install.new_state()
+    }
+
+    /// A new random `nonce`, for one user's sign in.
+    pub fn new_nonce(&self) -> String {
+        let mut nonce = [0; 16];
+        getrandom::getrandom(&mut nonce).expect("the system's random number generator failed");
+        hex::encode(nonce)
+    }
+
+    /// The link that sends users to Slack to sign in, carrying `state` and `nonce`.
+    pub fn authorize_url(&self, state: &str, nonce: &str) -> String {
+        let mut query = form_urlencoded::Serializer::new(String::new());
+        query.append_pair("response_type", "code");
+        query.append_pair("scope", &self.scopes.join(" "));
+        query.append_pair("client_id", self.client_id());
+        query.append_pair("state", state);
+        query.append_pair("nonce", nonce);
+        if let Some(redirect_uri) = self.install.redirect_uri() {
+            query.append_pair("redirect_uri", redirect_uri);
+        }
+        if let Some(ref team) = self.team {
+            query.append_pair("team", team.as_ref());
+        }
+        format!("{}?{}", self.authorize_url, query.finish())
+    }
+
+    /// Checks that the `state` Slack redirected back with is the `expected` one kept for the user,
+    /// was created by this flow and has not expired.
+    pub fn check_state(&self, state: &str, expected: &str) -> Result<(), crate::StateError> {
+        self.install.check_state(state, expected)
+    }
+
+    /// The `openid.connect.token` request exchanging `code` for tokens, without checking the
+    /// state.
+    pub fn token_request<'a>(&'a self, code: &'a str) -> TokenRequest<'a> {
+        TokenRequest {
+            client_id: Cow::Borrowed(self.client_id()),
+            client_secret: Cow::Borrowed(self.install.client_secret()),
+            code: Some(Cow::Borrowed(code)),
+            redirect_uri: self.install.redirect_uri().map(Cow::Borrowed),
+            ..Default::default()
+        }
+    }
+}
+
+/// The claims of a Sign in with Slack ID token.
+///
+/// Claims Slack adds under `https://slack.com/`, such as `https://slack.com/team_id`, are named
+/// after their last segment. Those without a field here, such as the team and user images, are
+/// kept in `other`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct IdTokenClaims {
+    /// The issuer, [`OPENID_ISSUER`]
+    pub iss: String,
+    /// The user's ID
+    pub sub: String,
+    /// The client ID of the app the token was issued to
+    pub aud: String,
+    /// When the token expires, in seconds since the Unix epoch
+    pub exp: u64,
+    /// When the token was issued, in seconds since the Unix epoch
+    pub iat: u64,
+    pub auth_time: Option<u64>,
+    /// The `nonce` of the authorize link
+    pub nonce: Option<String>,
+    pub at_hash: Option<String>,
+    #[serde(rename = "https://slack.com/team_id")]
+    pub team_id: Option<TeamId>,
+    #[serde(rename = "https://slack.com/user_id")]
+    pub user_id: Option<UserId>,
+    #[serde(rename = "https://slack.com/enterprise_id")]
+    pub enterprise_id: Option<EnterpriseId>,
+    #[serde(rename = "https://slack.com/team_name")]
+    pub team_name: Option<String>,
+    #[serde(rename = "https://slack.com/team_domain")]
+    pub team_domain: Option<String>,
+    pub email: Option<String>,
+    pub email_verified: Option<bool>,
+    pub date_email_verified: Option<u64>,
+    pub name: Option<String>,
+    pub picture: Option<String>,
+    pub given_name: Option<String>,
+    pub family_name: Option<String>,
+    pub locale: Option<String>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+/// A JSON Web Key Set, such as the one Slack publishes at [`OPENID_KEYS_URL`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Jwks {
+    pub keys: Vec<Jwk>,
+}
+
+/// An RSA public key of a [`Jwks`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Jwk {
+    pub kty: String,
+    pub kid: Option<String>,
+    pub alg: Option<String>,
+    #[serde(rename = "use")]
+    pub r#use: Option<String>,
+    /// The modulus, base64url encoded
+    pub n: String,
+    /// The exponent, base64url encoded
+    pub e: String,
+}
+
+#[cfg(feature = "openid")]
+pub use self::verify::{IdTokenError, IdTokenVerifier, SignInError};
+
+#[cfg(feature = "openid")]
+mod verify {
+    use std::error::Error;
+    use std::fmt;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use ring::signature::{RsaPublicKeyComponents, RSA_PKCS1_2048_8192_SHA256};
+
+    use super::{IdTokenClaims, Jwk, Jwks, OPENID_ISSUER};
+    use crate::mod_types::openid::connect_types::TokenError;
+    use crate::StateError;
+
+    /// Checks ID tokens against a key set, such as Slack's at
+    /// [`OPENID_KEYS_URL`](super::OPENID_KEYS_URL), fetched and cached by the app.
+    ///
+    /// A token passes if it is signed with `RS256` by the key its header names, was issued by
+    /// [`OPENID_ISSUER`] to the app, has not expired and carries the expected nonce. A minute of
+    /// clock skew is allowed.
+    #[derive(Clone, Debug)]
+    pub struct IdTokenVerifier {
+        client_id: String,
+        issuer: String,
+        leeway: Duration,
+        keys: Jwks,
+    }
+
+    impl IdTokenVerifier {
+        /// The verifier of ID tokens issued to the app `client_id`, signed by one of `keys`.
+        pub fn new<I: Into<String>>(client_id: I, keys: Jwks) -> Self {
+            IdTokenVerifier {
+                client_id: client_id.into(),
+                issuer: OPENID_ISSUER.into(),
+                leeway: Duration::from_secs(60),
+                keys,
+            }
+        }
+
+        /// Expects tokens issued by `issuer` instead of [`OPENID_ISSUER`].
+        pub fn with_issuer<S: Into<String>>(mut self, issuer: S) -> Self {
+            self.issuer = issuer.into();
+            self
+        }
+
+        /// Allows for `leeway` of clock skew instead of a minute.
+        pub fn with_leeway(mut self, leeway: Duration) -> Self {
+            self.leeway = leeway;
+            self
+        }
+
+        /// Replaces the key set, e.g. after Slack rotated its keys.
+        pub fn set_keys(&mut self, keys: Jwks) {
+            self.keys = keys;
+        }
+
+        /// Checks `id_token` and returns its claims.
+        ///
+        /// `nonce` is the one of the authorize link the user signed in with, if any.
+        pub fn verify(
+            &self,
+            id_token: &str,
+            nonce: Option<&str>,
+        ) -> Result<IdTokenClaims, IdTokenError> {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |since| since.as_secs());
+            self.verify_at(id_token, nonce, now)
+        }
+
+        fn verify_at(
+            &self,
+            id_token: &str,
+            nonce: Option<&str>,
+            now: u64,
+        ) -> Result<IdTokenClaims, IdTokenError> {
+            let mut parts = id_token.split('.');
+            let (header, payload, signature) = match (parts.next(), parts.next(), parts.next()) {
+                (Some(header), Some(payload), Some(signature)) if parts.next().is_none() => {
+                    (header, payload, signature)
+                }
+                _ => return Err(IdTokenError::Malformed),
+            };
+            let signed = &id_token[..header.len() + 1 + payload.len()];
+            let header: Header = decode_json(header)?;
+            if header.alg != "RS256" {
+                return Err(IdTokenError::UnsupportedAlgorithm(header.alg));
+            }
+            let key = self
+                .key(header.kid.as_deref())
+                .ok_or(IdTokenError::UnknownKey(header.kid))?;
+            let n = decode(&key.n)?;
+            let e = decode(&key.e)?;
+            RsaPublicKeyComponents { n: &n, e: &e }
+                .verify(
+                    &RSA_PKCS1_2048_8192_SHA256,
+                    signed.as_bytes(),
+                    &decode(signature)?,
+                )
+                .map_err(|_| IdTokenError::InvalidSignature)?;
+
+            let claims: IdTokenClaims = decode_json(payload)?;
+            if claims.iss != self.issuer {
+                return Err(IdTokenError::InvalidIssuer(claims.iss));
+            }
+            if claims.aud != self.client_id {
+                return Err(IdTokenError::InvalidAudience(claims.aud));
+            }
+            let leeway = self.leeway.as_secs();
+            if claims.exp.saturating_add(leeway) <= now {
+                return Err(IdTokenError::Expired);
+            }
+            if claims.iat > now.saturating_add(leeway) {
+                return Err(IdTokenError::IssuedInFuture);
+            }
+            if let Some(nonce) = nonce {
+                if claims.nonce.as_deref() != Some(nonce) {
+                    return Err(IdTokenError::InvalidNonce);
+                }
+            }
+            Ok(claims)
+        }
+
+        /// The RSA key `kid` names, or the only one if the token names none.
+        fn key(&self, kid: Option<&str>) -> Option<&Jwk> {
+            let mut keys = self.keys.keys.iter().filter(|key| {
+                key.kty == "RSA"
+                    && matches!(key.r#use.as_deref(), None | Some("sig"))
+                    && matches!(key.alg.as_deref(), None | Some("RS256"))
+            });
+            match kid {
+                Some(kid) => keys.find(|key| key.kid.as_deref() == Some(kid)),
+                None => keys.next().filter(|_| keys.next().is_none()),
+            }
+        }
+    }
+
+    #[derive(Deserialize)]
+    struct Header {
+        alg: String,
+        kid: Option<String>,
+    }
+
+    fn decode(part: &str) -> Result<Vec<u8>, IdTokenError> {
+        base64::decode_config(part, base64::URL_SAFE_NO_PAD).map_err(|_| IdTokenError::Malformed)
+    }
+
+    fn decode_json<T: serde::de::DeserializeOwned>(part: &str) -> Result<T, IdTokenError> {
+        serde_json::from_slice(&decode(part)?).map_err(|_| IdTokenError::Malformed)
+    }
+
+    /// Why an ID token was rejected.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum IdTokenError {
+        /// The token is not a JWT with a JSON header and claims
+        Malformed,
+        /// The token is signed with an algorithm other than `RS256`
+        UnsupportedAlgorithm(String),
+        /// The key set has no key with the `kid` of the token's header
+        UnknownKey(Option<String>),
+        /// The signature does not match the token
+        InvalidSignature,
+        /// The token was issued by someone else
+        InvalidIssuer(String),
+        /// The token was issued to another app
+        InvalidAudience(String),
+        /// The token has expired
+        Expired,
+        /// The token was issued after now
+        IssuedInFuture,
+        /// The token does not carry the nonce of the sign in
+        InvalidNonce,
+    }
+
+    impl fmt::Display for IdTokenError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match *self {
+                IdTokenError::Malformed => write!(f, "Malformed ID token"),
+                IdTokenError::UnsupportedAlgorithm(ref alg) => {
+                    write!(f, "Unsupported ID token algorithm {}", alg)
+                }
+                IdTokenError::UnknownKey(Some(ref kid)) => {
+                    write!(f, "Unknown ID token key {}", kid)
+                }
+                IdTokenError::UnknownKey(None) => write!(f, "ID token key not found"),
+                IdTokenError::InvalidSignature => write!(f, "Invalid ID token signature"),
+                IdTokenError::InvalidIssuer(ref iss) => write!(f, "ID token issued by {}", iss),
+                IdTokenError::InvalidAudience(ref aud) => write!(f, "ID token issued to {}", aud),
+                IdTokenError::Expired => write!(f, "Expired ID token"),
+                IdTokenError::IssuedInFuture => write!(f, "ID token issued in the future"),
+                IdTokenError::InvalidNonce => write!(f, "Invalid ID token nonce"),
+            }
+        }
+    }
+
+    impl Error for IdTokenError {}
+
+    #[derive(Debug)]
+    pub enum SignInError<E: Error> {
+        /// The `state` Slack redirected back with was rejected
+        State(StateError),
+        /// `openid.connect.token` failed
+        Token(TokenError<E>),
+        /// `openid.connect.token` returned no ID token
+        MissingIdToken,
+        /// The ID token was rejected
+        IdToken(IdTokenError),
+    }
+
+    impl<E: Error> fmt::Display for SignInError<E> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match *self {
+                SignInError::State(ref e) => write!(f, "{}", e),
+                SignInError::Token(ref e) => write!(f, "{}", e),
+                SignInError::MissingIdToken => write!(f, "No ID token in the response"),
+                SignInError::IdToken(ref e) => write!(f, "{}", e),
+            }
+        }
+    }
+
+    impl<E: Error + 'static> Error for SignInError<E> {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            match *self {
+                SignInError::State(ref e) => Some(e),
+                SignInError::Token(ref e) => Some(e),
+                SignInError::MissingIdToken => None,
+                SignInError::IdToken(ref e) => Some(e),
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        const TOKEN: &str = "eyJhbGciOiJSUzI1NiIsImtpZCI6InRlc3Qta2V5IiwidHlwIjoiSldUIn0.eyJpc3MiOiJodHRwczovL3NsYWNrLmNvbSIsInN1YiI6IlUxIiwiYXVkIjoiMTIzLjQ1NiIsImV4cCI6MTcwMDAwMzYwMCwiaWF0IjoxNzAwMDAwMDAwLCJhdXRoX3RpbWUiOjE3MDAwMDAwMDAsIm5vbmNlIjoibjBuY2UiLCJhdF9oYXNoIjoiYWJjIiwiaHR0cHM6Ly9zbGFjay5jb20vdGVhbV9pZCI6IlQxIiwiaHR0cHM6Ly9zbGFjay5jb20vdXNlcl9pZCI6IlUxIiwiZW1haWwiOiJhZGFAZXhhbXBsZS5jb20iLCJlbWFpbF92ZXJpZmllZCI6dHJ1ZSwiZGF0ZV9lbWFpbF92ZXJpZmllZCI6MTY5MDAwMDAwMCwibG9jYWxlIjoiZW4tVVMiLCJuYW1lIjoiQWRhIExvdmVsYWNlIiwiZ2l2ZW5fbmFtZSI6IkFkYSIsImZhbWlseV9uYW1lIjoiTG92ZWxhY2UiLCJwaWN0dXJlIjoiaHR0cHM6Ly9leGFtcGxlLmNvbS9hZGEucG5nIiwiaHR0cHM6Ly9zbGFjay5jb20vdGVhbV9uYW1lIjoiQW5hbHl0aWNhbCIsImh0dHBzOi8vc2xhY2suY29tL3RlYW1fZG9tYWluIjoiYW5hbHl0aWNhbCIsImh0dHBzOi8vc2xhY2suY29tL3VzZXJfaW1hZ2VfMjQiOiJodHRwczovL2V4YW1wbGUuY29tL2FkYV8yNC5wbmcifQ.No6EMTgHFIEww7rH7rbl6ZNMuxjXcc2fVWI7SI4PUnShG5IArmJRyGhexCVIIOaVNWJFmlXfgvfQTBnOtRHfZGmGl_SIwffYl4Cj2eTan98qIY4xA7dA2amB33FE4wToiCB79zP9IcI_RfAdH0HtJchdYpDjyMCdqsivA8fXy3lrnFJL1FXexAKBDwBuBRTiotLgOREpFTL_59nt7cwZgCDSeN3lX1EaFU3SKv3KqluTT1EEtD22yoA_Wkx8WEOFqmnqp9lXqVF0vez3Xu4N-Jw6z9ckm8GgdUBM7w1FOmgbuE7jXi5j0CgWx52MFW53yvEBBjHdC5GIRT1yDSQWmg";
+        const ISSUED_AT: u64 = 1_700_000_000;
+
+        fn keys() -> Jwks {
+            serde_json::from_str(
+                r#"{"keys": [{"kty": "RSA", "kid": "test-key", "alg": "RS256", "use": "sig", "e": "AQAB", "n": "nv0DkqmMWh1wScHSpuv9lqEUYqm0AAoYPOnCgH2pW44qQom8rDhIAguVPrZhfMaoUH7RQ84Tj5N5g1OrtU-0NvWl5fHKHAicA-Y5YS5J-KdsBYo-iYb64jsTj1lHG5Jfkfch9kB0R87wr2aRjCxb7nP617NFm2PqhtRYmIu_NLm-Jo22DWKT4ZgrCO8kJudd7IK_JATHE3sTSvb1KKMWUgwqErsI513YmvDpXl7X5TZY_G9cPOxhpMhf7r1LcTdpjlmKAAFoN5b-GYxOi6N2mxCq8JQ9GOEL6wSMawZevGIf919DCCRHxbEPyAn_EpikRRslAMncoVgT5VROpT-DzQ"}]}"#,
+            )
+            .unwrap()
+        }
+
+        #[test]
+        fn verifies_claims() {
+            let verifier = IdTokenVerifier::new("123.456", keys());
+            let claims = verifier
+                .verify_at(TOKEN, Some("n0nce"), ISSUED_AT + 10)
+                .unwrap();
+            assert_eq!(claims.sub, "U1");
+            assert_eq!(claims.team_id.unwrap(), "T1");
+            assert_eq!(claims.user_id.unwrap(), "U1");
+            assert_eq!(claims.email.as_deref(), Some("ada@example.com"));
+            assert_eq!(claims.email_verified, Some(true));
+            assert_eq!(claims.team_domain.as_deref(), Some("analytical"));
+            assert_eq!(
+                claims.other["https://slack.com/user_image_24"],
+                "https://example.com/ada_24.png"
+            );
+            assert!(verifier.verify_at(TOKEN, None, ISSUED_AT).is_ok());
+        }
+
+        #[test]
+        fn rejects_invalid_tokens() {
+            let verifier = IdTokenVerifier::new("123.456", keys());
+            let at = |token: &str, nonce, now| verifier.verify_at(token, Some(nonce), now);
+            assert_eq!(
+                at(TOKEN, "other", ISSUED_AT),
+                Err(IdTokenError::InvalidNonce)
+            );
+            assert_eq!(
+                at(TOKEN, "n0nce", ISSUED_AT + 3600 + 60),
+                Err(IdTokenError::Expired)
+            );
+            assert_eq!(
+                at(TOKEN, "n0nce", ISSUED_AT - 61),
+                Err(IdTokenError::IssuedInFuture)
+            );
+            assert_eq!(at("abc", "n0nce", ISSUED_AT), Err(IdTokenError::Malformed));
+
+            let (signed, signature) = TOKEN.rsplit_once('.').unwrap();
+            let tampered = format!("{}x.{}", signed, signature);
+            assert_eq!(
+                at(&tampered, "n0nce", ISSUED_AT),
+                Err(IdTokenError::InvalidSignature)
+            );
+
+            let other_app = IdTokenVerifier::new("789.012", keys());
+            assert_eq!(
+                other_app.verify_at(TOKEN, None, ISSUED_AT),
+                Err(IdTokenError::InvalidAudience("123.456".into()))
+            );
+            let no_keys = IdTokenVerifier::new("123.456", Jwks::default());
+            assert_eq!(
+                no_keys.verify_at(TOKEN, None, ISSUED_AT),
+                Err(IdTokenError::UnknownKey(Some("test-key".into())))
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn authorize_url_carries_nonce() {
+        let flow = SignInFlow::new("123.456", "secret")
+            .with_redirect_uri("https://example.com/cb")
+            .with_team(TeamId::new("T1").unwrap());
+        assert_eq!(
+            flow.authorize_url("s", "n"),
+            "https://slack.com/openid/connect/authorize?response_type=code&scope=openid+email+profile&client_id=123.456&state=s&nonce=n&redirect_uri=https%3A%2F%2Fexample.com%2Fcb&team=T1"
+        );
+        let request = flow.token_request("c");
+        assert_eq!(request.client_secret, "secret");
+        assert_eq!(request.code.as_deref(), Some("c"));
+        assert_eq!(
+            request.redirect_uri.as_deref(),
+            Some("https://example.com/cb")
+        );
+    }
+}
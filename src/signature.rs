@@ -0,0 +1,322 @@
+//! Checks that requests to an app's endpoints come from Slack.
+//!
+//! Slack [signs](https://api.slack.com/authentication/verifying-requests-from-slack) every
+//! Events API, interactivity and slash command request with the app's signing secret: the
+//! `X-Slack-Signature` header is `v0=` followed by the hex HMAC-SHA256 of
+//! `v0:{timestamp}:{body}`, where the timestamp is the `X-Slack-Request-Timestamp` header. A
+//! [`SignatureVerifier`] checks both headers against the raw body, before it is parsed.
+//!
+//! # Examples
+//!
+//! ```
+//! use slack_api::signature::{self, SignatureVerifier};
+//!
+//! let verifier = SignatureVerifier::new("signing secret");
+//! let body = br#"{"type": "url_verification", "challenge": "abc"}"#;
+//! // What Slack would send along with `body`.
+//! let (timestamp, signature) = signature::sign_now(b"signing secret", body);
+//! assert!(verifier.verify(&timestamp, &signature, body).is_ok());
+//! assert!(verifier.verify(&timestamp, &signature, b"{}").is_err());
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/// The header carrying the signature of a request from Slack.
+pub const SIGNATURE_HEADER: &str = "X-Slack-Signature";
+
+/// The header carrying the time a request from Slack was signed, in seconds since the Unix
+/// epoch.
+pub const TIMESTAMP_HEADER: &str = "X-Slack-Request-Timestamp";
+
+/// The version of the signatures Slack sends.
+const VERSION: &str = "v0";
+
+/// Checks the signatures of requests from Slack.
+///
+/// Requests signed more than 5 minutes before or after now are rejected, so that a recorded
+/// request cannot be replayed later.
+///
+/// While the signing secret is being rotated, [`with_secret`](Self::with_secret) adds the new
+/// one, and requests signed with either are accepted.
+#[derive(Clone)]
+pub struct SignatureVerifier {
+    secrets: Vec<Vec<u8>>,
+    max_skew: Duration,
+}
+
+impl SignatureVerifier {
+    /// The verifier of requests signed with `signing_secret`, from the app's Basic Information.
+    pub fn new<S: Into<Vec<u8>>>(signing_secret: S) -> Self {
+        SignatureVerifier {
+            secrets: vec![signing_secret.into()],
+            max_skew: Duration::from_secs(5 * 60),
+        }
+    }
+
+    /// Also accepts requests signed with `signing_secret`.
+    pub fn with_secret<S: Into<Vec<u8>>>(mut self, signing_secret: S) -> Self {
+        self.secrets.push(signing_secret.into());
+        self
+    }
+
+    /// Rejects requests signed more than `max_skew` before or after now instead of 5 minutes.
+    pub fn with_max_skew(mut self, max_skew: Duration) -> Self {
+        self.max_skew = max_skew;
+        self
+    }
+
+    /// Checks the `X-Slack-Request-Timestamp` and `X-Slack-Signature` headers of a request
+    /// against its raw `body`.
+    pub fn verify(
+        &self,
+        timestamp: &str,
+        signature: &str,
+        body: &[u8],
+    ) -> Result<(), SignatureError> {
+        self.verify_at(timestamp, signature, body, unix_time(SystemTime::now()))
+    }
+
+    /// [`verify`](Self::verify) with the headers looked up by name with `header`, e.g.
+    /// `|name| headers.get(name).and_then(|value| value.to_str().ok())`.
+    pub fn verify_headers<'a, F>(&self, header: F, body: &[u8]) -> Result<(), SignatureError>
+    where
+        F: Fn(&str) -> Option<&'a str>,
+    {
+        let timestamp = header(TIMESTAMP_HEADER).ok_or(SignatureError::MissingTimestamp)?;
+        let signature = header(SIGNATURE_HEADER).ok_or(SignatureError::MissingSignature)?;
+        self.verify(timestamp, signature, body)
+    }
+
+    fn verify_at(
+        &self,
+        timestamp: &str,
+        signature: &str,
+        body: &[u8],
+        now: u64,
+    ) -> Result<(), SignatureError> {
+        let signed_at = timestamp
+            .trim()
+            .parse::<u64>()
+            .map_err(|_| SignatureError::InvalidTimestamp)?;
+        if signed_at.abs_diff(now) > self.max_skew.as_secs() {
+            return Err(SignatureError::StaleTimestamp);
+        }
+        let (version, hash) = signature
+            .trim()
+            .split_once('=')
+            .ok_or(SignatureError::MalformedSignature)?;
+        if version != VERSION {
+            return Err(SignatureError::UnsupportedVersion(version.to_owned()));
+        }
+        let hash = hex::decode(hash).map_err(|_| SignatureError::MalformedSignature)?;
+        // Every secret is tried, so that the time taken does not tell which one matched.
+        let matched = self.secrets.iter().fold(false, |matched, secret| {
+            mac(secret, signed_at, body).verify_slice(&hash).is_ok() | matched
+        });
+        if matched {
+            Ok(())
+        } else {
+            Err(SignatureError::Mismatch)
+        }
+    }
+}
+
+impl fmt::Debug for SignatureVerifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SignatureVerifier")
+            .field("max_skew", &self.max_skew)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The `X-Slack-Signature` of `body` signed with `signing_secret` at `timestamp`, in seconds
+/// since the Unix epoch, as Slack signs requests.
+///
+/// This is meant for testing endpoints that check signatures.
+pub fn sign(signing_secret: &[u8], timestamp: u64, body: &[u8]) -> String {
+    let hash = mac(signing_secret, timestamp, body).finalize().into_bytes();
+    format!("{}={}", VERSION, hex::encode(hash))
+}
+
+/// The `X-Slack-Request-Timestamp` and `X-Slack-Signature` of `body` signed with
+/// `signing_secret` now.
+///
+/// This is meant for testing endpoints that check signatures.
+pub fn sign_now(signing_secret: &[u8], body: &[u8]) -> (String, String) {
+    let timestamp = unix_time(SystemTime::now());
+    (timestamp.to_string(), sign(signing_secret, timestamp, body))
+}
+
+fn mac(signing_secret: &[u8], timestamp: u64, body: &[u8]) -> Hmac<Sha256> {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(signing_secret).expect("HMAC takes keys of any size");
+    mac.update(format!("{}:{}:", VERSION, timestamp).as_bytes());
+    mac.update(body);
+    mac
+}
+
+fn unix_time(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs())
+}
+
+/// Why the signature of a request was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignatureError {
+    /// The request has no `X-Slack-Request-Timestamp` header
+    MissingTimestamp,
+    /// The request has no `X-Slack-Signature` header
+    MissingSignature,
+    /// The timestamp is not a number of seconds
+    InvalidTimestamp,
+    /// The request was signed too long before or after now, as when it is replayed
+    StaleTimestamp,
+    /// The signature is not `v0=` followed by a hex hash
+    MalformedSignature,
+    /// The signature has a version other than `v0`
+    UnsupportedVersion(String),
+    /// The signature does not match the body with any of the signing secrets
+    Mismatch,
+}
+
+impl fmt::Display for SignatureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            SignatureError::MissingTimestamp => write!(f, "Missing {} header", TIMESTAMP_HEADER),
+            SignatureError::MissingSignature => write!(f, "Missing {} header", SIGNATURE_HEADER),
+            SignatureError::InvalidTimestamp => write!(f, "Invalid request timestamp"),
+            SignatureError::StaleTimestamp => write!(f, "Stale request timestamp"),
+            SignatureError::MalformedSignature => write!(f, "Malformed request signature"),
+            SignatureError::UnsupportedVersion(ref version) => {
+                write!(f, "Unsupported request signature version {}", version)
+            }
+            SignatureError::Mismatch => write!(f, "Request signature mismatch"),
+        }
+    }
+}
+
+impl Error for SignatureError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The example of Slack's documentation.
+    const SECRET: &str = "8f742231b10e8888abcd99yyyzzz85a5";
+    const TIMESTAMP: u64 = 1531420618;
+    const BODY: &str = "token=xyzz0WbapA4vBCDEFasx0q6G&team_id=T1DC2JH3J&team_domain=testteamnow&channel_id=G8PSS9T3V&channel_name=foobar&user_id=U2CERLKJA&user_name=roadrunner&command=%2Fwebhook-collect&text=&response_url=https%3A%2F%2Fhooks.slack.com%2Fcommands%2FT1DC2JH3J%2F397700885554%2F96rGlfmibIGlgcZRskXaIFfN&trigger_id=398738663015.47445629121.803a0bc887a14d10d2c447fce8b6703c";
+    const SIGNATURE: &str = "v0=a2114d57b48eac39b9ad189dd8316235a7b4a8d21a10bd27519666489c69b503";
+
+    #[test]
+    fn signs_like_slack() {
+        assert_eq!(
+            sign(SECRET.as_bytes(), TIMESTAMP, BODY.as_bytes()),
+            SIGNATURE
+        );
+    }
+
+    #[test]
+    fn verifies_signatures() {
+        let verifier = SignatureVerifier::new(SECRET);
+        let verify = |timestamp: &str, signature: &str, body: &str, now| {
+            verifier.verify_at(timestamp, signature, body.as_bytes(), now)
+        };
+        assert_eq!(
+            verify("1531420618", SIGNATURE, BODY, TIMESTAMP + 300),
+            Ok(())
+        );
+        assert_eq!(
+            verify("1531420618", SIGNATURE, BODY, TIMESTAMP - 300),
+            Ok(())
+        );
+        assert_eq!(
+            verify("1531420618", SIGNATURE, BODY, TIMESTAMP + 301),
+            Err(SignatureError::StaleTimestamp)
+        );
+        assert_eq!(
+            verify("1531420618", SIGNATURE, "token=other", TIMESTAMP),
+            Err(SignatureError::Mismatch)
+        );
+        assert_eq!(
+            verify("1531420619", SIGNATURE, BODY, TIMESTAMP),
+            Err(SignatureError::Mismatch)
+        );
+        assert_eq!(
+            verify("yesterday", SIGNATURE, BODY, TIMESTAMP),
+            Err(SignatureError::InvalidTimestamp)
+        );
+        assert_eq!(
+            verify("1531420618", "v0=xyz", BODY, TIMESTAMP),
+            Err(SignatureError::MalformedSignature)
+        );
+        assert_eq!(
+            verify("1531420618", "a2114d57", BODY, TIMESTAMP),
+            Err(SignatureError::MalformedSignature)
+        );
+        assert_eq!(
+            verify(
+                "1531420618",
+                &SIGNATURE.replacen("v0", "v1", 1),
+                BODY,
+                TIMESTAMP
+            ),
+            Err(SignatureError::UnsupportedVersion("v1".into()))
+        );
+
+        let strict = SignatureVerifier::new(SECRET).with_max_skew(Duration::from_secs(10));
+        assert_eq!(
+            strict.verify_at("1531420618", SIGNATURE, BODY.as_bytes(), TIMESTAMP + 11),
+            Err(SignatureError::StaleTimestamp)
+        );
+    }
+
+    #[test]
+    fn accepts_every_secret() {
+        let verifier = SignatureVerifier::new("old secret").with_secret(SECRET);
+        let old = sign(b"old secret", TIMESTAMP, BODY.as_bytes());
+        assert!(verifier
+            .verify_at("1531420618", &old, BODY.as_bytes(), TIMESTAMP)
+            .is_ok());
+        assert!(verifier
+            .verify_at("1531420618", SIGNATURE, BODY.as_bytes(), TIMESTAMP)
+            .is_ok());
+        let other = sign(b"other secret", TIMESTAMP, BODY.as_bytes());
+        assert_eq!(
+            verifier.verify_at("1531420618", &other, BODY.as_bytes(), TIMESTAMP),
+            Err(SignatureError::Mismatch)
+        );
+    }
+
+    #[test]
+    fn verifies_headers() {
+        let verifier = SignatureVerifier::new(SECRET);
+        let (timestamp, signature) = sign_now(SECRET.as_bytes(), BODY.as_bytes());
+        let headers = [
+            (TIMESTAMP_HEADER, timestamp.as_str()),
+            (SIGNATURE_HEADER, signature.as_str()),
+        ];
+        let header = |name: &str| {
+            headers
+                .iter()
+                .find(|(header, _)| header.eq_ignore_ascii_case(name))
+                .map(|&(_, value)| value)
+        };
+        assert_eq!(verifier.verify_headers(header, BODY.as_bytes()), Ok(()));
+        assert_eq!(
+            verifier.verify_headers(|_| None, BODY.as_bytes()),
+            Err(SignatureError::MissingTimestamp)
+        );
+        assert_eq!(
+            verifier.verify_headers(
+                |name| header(name).filter(|_| name == TIMESTAMP_HEADER),
+                BODY.as_bytes()
+            ),
+            Err(SignatureError::MissingSignature)
+        );
+    }
+}
@@ -0,0 +1,187 @@
+//! The external upload flow as a single call.
+
+use crate::mod_types::files::{
+    CompleteUploadExternalRequest, CompleteUploadExternalResponse, GetUploadURLExternalRequest,
+};
+use crate::sync::mods::files::{complete_upload_external, get_upload_url_external};
+use crate::sync::SlackWebRequestSender;
+use crate::upload::complete_upload_files;
+pub use crate::upload::{UploadV2Error, UploadV2File, UploadV2Request};
+
+/// Uploads files and shares them, optionally in a thread, in one message.
+///
+/// Runs Slack's upload flow: `files.getUploadURLExternal` for every file, a `POST` of its contents
+/// to the returned URL, then one `files.completeUploadExternal` for all of them. Slack needs the
+/// length of every file up front, so readers are read into memory first unless they were created
+/// with [`FileUpload::reader_with_length`](crate::FileUpload::reader_with_length).
+///
+pub fn upload_v2<R>(
+    client: &R,
+    token: &str,
+    request: &UploadV2Request<'_>,
+) -> Result<CompleteUploadExternalResponse, UploadV2Error<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let mut uploaded = Vec::with_capacity(request.files.len());
+    for file in &request.files {
+        let (content, length) = file.content.with_length().map_err(UploadV2Error::Read)?;
+        let external = get_upload_url_external(
+            client,
+            token,
+            &GetUploadURLExternalRequest {
+                alt_txt: file.alt_txt.clone(),
+                filename: file.filename.clone(),
+                length,
+                snippet_type: file.snippet_type.clone(),
+            },
+        )
+        .map_err(UploadV2Error::GetUploadUrl)?;
+        let (upload_url, file_id) = match (external.upload_url, external.file_id) {
+            (Some(upload_url), Some(file_id)) => (upload_url, file_id),
+            _ => return Err(UploadV2Error::MissingUploadUrl),
+        };
+
+        let response = client
+            .post_multipart(&upload_url, None, &[], &[("filename", &content)])
+            .map_err(UploadV2Error::Client)?;
+        if response.is_multipart_unsupported() {
+            return Err(UploadV2Error::MultipartUnsupported);
+        }
+        if response.status != 200 {
+            return Err(UploadV2Error::Upload {
+                status: response.status,
+                body: response.body,
+            });
+        }
+        uploaded.push((file_id, file.title.as_deref()));
+    }
+
+    complete_upload_external(
+        client,
+        token,
+        &CompleteUploadExternalRequest {
+            channel_id: request.channel_id.clone(),
+            files: complete_upload_files(&uploaded).into(),
+            initial_comment: request.initial_comment.clone(),
+            thread_ts: request.thread_ts,
+        },
+    )
+    .map_err(UploadV2Error::Complete)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{Call, FormOnly, Scripted, Via};
+    use crate::{FileUpload, SlackWebResponse};
+    use std::io;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Plays Slack's side of the upload flow, answering uploads with `upload`.
+    fn flow(upload: fn(&Call) -> SlackWebResponse) -> Scripted {
+        let count = AtomicUsize::new(0);
+        Scripted::new(move |call| {
+            let n = count.fetch_add(1, Ordering::SeqCst) + 1;
+            match call.method() {
+                "files.getUploadURLExternal" => SlackWebResponse::from_body(format!(
+                    r#"{{"ok": true, "upload_url": "https://files.slack.com/upload/v1/{n}", "file_id": "F{n}"}}"#,
+                    n = n
+                )),
+                "files.completeUploadExternal" => SlackWebResponse::from_body(
+                    r#"{"ok": true, "files": [{"id": "F1"}, {"id": "F3", "title": "Notes"}]}"#
+                        .into(),
+                ),
+                _ => upload(call),
+            }
+        })
+    }
+
+    #[test]
+    fn uploads_every_file_then_completes_once() {
+        let mut notes = UploadV2File::new(
+            "notes.txt",
+            FileUpload::reader(io::Cursor::new(b"notes".to_vec())),
+        );
+        notes.title = Some("Notes".into());
+        let request = UploadV2Request {
+            files: vec![
+                UploadV2File::new("a.log", FileUpload::bytes(&b"a-log"[..])),
+                notes,
+            ],
+            channel_id: Some("C1".parse().unwrap()),
+            initial_comment: None,
+            thread_ts: serde_json::from_str("\"1588861564.009805\"").ok(),
+        };
+        let sender = flow(|_| SlackWebResponse::from_body("OK - 5".into()));
+        let response = upload_v2(&sender, "xoxb", &request).unwrap();
+        assert_eq!(response.files.unwrap().len(), 2);
+        let calls: Vec<_> = sender
+            .calls()
+            .into_iter()
+            .map(|call| {
+                assert_eq!(
+                    call.via,
+                    if call.token.is_some() {
+                        Via::Post
+                    } else {
+                        Via::Multipart
+                    }
+                );
+                match call.files.first() {
+                    Some((name, _, contents)) => format!(
+                        "{} {} {}",
+                        call.url,
+                        name,
+                        String::from_utf8_lossy(contents)
+                    ),
+                    None => format!("{} {:?}", call.url, call.params),
+                }
+            })
+            .collect();
+        assert_eq!(
+            calls,
+            vec![
+                r#"https://slack.com/api/files.getUploadURLExternal [("filename", "a.log"), ("length", "5")]"#,
+                "https://files.slack.com/upload/v1/1 filename a-log",
+                r#"https://slack.com/api/files.getUploadURLExternal [("filename", "notes.txt"), ("length", "5")]"#,
+                "https://files.slack.com/upload/v1/3 filename notes",
+                r#"https://slack.com/api/files.completeUploadExternal [("channel_id", "C1"), ("files", "[{\"id\":\"F1\"},{\"id\":\"F3\",\"title\":\"Notes\"}]"), ("thread_ts", "1588861564.009805")]"#,
+            ]
+        );
+    }
+
+    #[test]
+    fn rejected_upload_stops_the_flow() {
+        let request = UploadV2Request {
+            files: vec![UploadV2File::new("a.log", FileUpload::bytes(&b"a-log"[..]))],
+            ..Default::default()
+        };
+        let sender = flow(|_| SlackWebResponse {
+            status: 500,
+            headers: Vec::new(),
+            body: "upload_failed".into(),
+        });
+        match upload_v2(&sender, "xoxb", &request) {
+            Err(UploadV2Error::Upload { status: 500, body }) => assert_eq!(body, "upload_failed"),
+            other => panic!("unexpected {:?}", other),
+        }
+        assert_eq!(sender.calls().len(), 2);
+    }
+
+    #[test]
+    fn senders_without_multipart_fail_before_completing() {
+        let request = UploadV2Request {
+            files: vec![UploadV2File::new("a.log", FileUpload::bytes(&b"a-log"[..]))],
+            ..Default::default()
+        };
+        let sender = FormOnly(flow(|_| unreachable!()));
+        match upload_v2(&sender, "xoxb", &request) {
+            Err(UploadV2Error::MultipartUnsupported) => {}
+            other => panic!("unexpected {:?}", other),
+        }
+        let calls = sender.0.calls();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].method(), "files.getUploadURLExternal");
+    }
+}
@@ -1,9 +1,29 @@
+pub use crate::pagination::{CursorPage, CursorRequest};
+pub use crate::rate_limit::*;
+pub use crate::response::*;
 pub use crate::timestamp::*;
+pub use crate::token_rotation::*;
 pub use crate::types::*;
+pub use crate::upload::{FileUpload, SharedReader, UploadSource};
 
+mod ext;
 mod mods;
 pub use self::mods::*;
 
+mod pagination;
+
+mod rate_limited;
+pub use self::rate_limited::RateLimitedSender;
+
+mod with_base_url;
+pub use self::with_base_url::WithBaseUrl;
+
+mod with_warning_hook;
+pub use self::with_warning_hook::WithWarningHook;
+
+mod with_token_rotation;
+pub use self::with_token_rotation::WithTokenRotation;
+
 pub mod requests;
 
 #[cfg(feature = "reqwest_blocking")]
@@ -18,20 +38,144 @@ use std::error;
 pub trait SlackWebRequestSender {
     type Error: error::Error;
 
+    /// The base URL that method names are appended to.
+    ///
+    /// Defaults to [`SLACK_API_URL`](crate::SLACK_API_URL). Wrap a sender in [`WithBaseUrl`] to
+    /// send requests to GovSlack, an egress proxy or a local fake server instead.
+    fn base_url(&self) -> &str {
+        crate::SLACK_API_URL
+    }
+
+    /// Called after a successful call whose response carries warnings, such as
+    /// `message_truncated` for `chat.postMessage`, with the method's name, such as
+    /// `chat.postMessage`.
+    ///
+    /// Does nothing by default. Wrap a sender in [`WithWarningHook`] to log the warnings.
+    fn on_warnings(&self, _method: &str, _warnings: &[&str]) {}
+
     /// Make an get API call to Slack. Takes a map of parameters that get appended to the request as query
     /// params.
-    fn get<S>(&self, method_url: S, params: &[(&str, &str)]) -> Result<String, Self::Error>
+    ///
+    /// The token, if any, must be sent in an `Authorization: Bearer` header and never as a parameter.
+    fn get<S>(
+        &self,
+        method_url: S,
+        token: Option<&str>,
+        params: &[(&str, &str)],
+    ) -> Result<String, Self::Error>
     where
         S: AsRef<str> + Send;
 
-    /// Make an post API call to Slack. Takes a map of parameters that get appended to the request as body
-    /// and a map of headers to set.
+    /// Make an post API call to Slack. Takes a map of parameters that get appended to the request as body.
+    ///
+    /// The token, if any, must be sent in an `Authorization: Bearer` header and never as a parameter.
     fn post<S>(
         &self,
         method_url: S,
+        token: Option<&str>,
         form: &[(&str, &str)],
-        headers: &[(&str, &str)],
     ) -> Result<String, Self::Error>
     where
         S: AsRef<str> + Send;
+
+    /// Make an get API call to Slack and return the HTTP status and headers along with the body.
+    ///
+    /// The default implementation calls [`get`](Self::get) and reports a `200` status without
+    /// headers. Override it to let wrappers such as `RateLimitedSender` see `429` responses.
+    fn get_response<S>(
+        &self,
+        method_url: S,
+        token: Option<&str>,
+        params: &[(&str, &str)],
+    ) -> Result<SlackWebResponse, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        self.get(method_url, token, params)
+            .map(SlackWebResponse::from_body)
+    }
+
+    /// Make an post API call to Slack and return the HTTP status and headers along with the body.
+    ///
+    /// The default implementation calls [`post`](Self::post) and reports a `200` status without
+    /// headers. Override it to let wrappers such as `RateLimitedSender` see `429` responses.
+    fn post_response<S>(
+        &self,
+        method_url: S,
+        token: Option<&str>,
+        form: &[(&str, &str)],
+    ) -> Result<SlackWebResponse, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        self.post(method_url, token, form)
+            .map(SlackWebResponse::from_body)
+    }
+
+    /// Make a `multipart/form-data` post API call to Slack, for methods that take file contents.
+    ///
+    /// `form` holds the plain parameters and `files` the file parameters by name, such as
+    /// `("file", upload)`. The token, if any, must be sent in an `Authorization: Bearer` header.
+    ///
+    /// The default implementation sends nothing and answers as if Slack had returned the error
+    /// [`MULTIPART_UNSUPPORTED`](crate::MULTIPART_UNSUPPORTED), which methods report as an
+    /// `Unknown` error. Override it to upload files; the `reqwest` clients do.
+    fn post_multipart<S>(
+        &self,
+        _method_url: S,
+        _token: Option<&str>,
+        _form: &[(&str, &str)],
+        _files: &[(&str, &FileUpload<'_>)],
+    ) -> Result<SlackWebResponse, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        Ok(SlackWebResponse::multipart_unsupported())
+    }
+
+    /// Make a post API call to Slack with an `application/json` body, for methods that take
+    /// structured arguments such as `blocks` or `view`.
+    ///
+    /// The default implementation sends `body` as a form through
+    /// [`post_response`](Self::post_response), with every non-string member JSON-encoded. Override
+    /// it to send the JSON as is. The token, if any, must be sent in an `Authorization: Bearer`
+    /// header.
+    fn post_json<S>(
+        &self,
+        method_url: S,
+        token: Option<&str>,
+        body: &serde_json::Value,
+    ) -> Result<SlackWebResponse, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        let form = crate::json_body_to_form(body);
+        let form: Vec<(&str, &str)> = form
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+            .collect();
+        self.post_response(method_url, token, &form)
+    }
+
+    /// Make a post API call to Slack authenticated as an app, for the `oauth.*` methods that
+    /// exchange a code or refresh token for an access token.
+    ///
+    /// The default implementation sends `client_id` and `client_secret` as form parameters
+    /// through [`post_response`](Self::post_response). Override it to send them in an
+    /// `Authorization: Basic` header instead, as Slack prefers; the `reqwest` clients do.
+    fn post_client_auth<S>(
+        &self,
+        method_url: S,
+        client_id: &str,
+        client_secret: &str,
+        form: &[(&str, &str)],
+    ) -> Result<SlackWebResponse, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        let mut form = form.to_vec();
+        form.push(("client_id", client_id));
+        form.push(("client_secret", client_secret));
+        self.post_response(method_url, None, &form)
+    }
 }
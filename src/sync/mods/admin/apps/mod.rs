@@ -55,15 +55,29 @@ where
             .map(|team_id| ("team_id", team_id.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.apps.approve");
+    let url = crate::get_slack_url_for_method(client.base_url(), "admin.apps.approve");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .map_err(ApproveError::Client)
         .and_then(|result| {
-            serde_json::from_str::<ApproveResponse>(&result)
-                .map_err(|e| ApproveError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<ApproveResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => ApproveError::new(&code, context),
+                    None => ApproveError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.apps.approve", &warnings);
+            }
+        })
 }
 /// Restrict an app for installation on a workspace.
 ///
@@ -92,13 +106,27 @@ where
             .map(|team_id| ("team_id", team_id.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.apps.restrict");
+    let url = crate::get_slack_url_for_method(client.base_url(), "admin.apps.restrict");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .map_err(RestrictError::Client)
         .and_then(|result| {
-            serde_json::from_str::<RestrictResponse>(&result)
-                .map_err(|e| RestrictError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<RestrictResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => RestrictError::new(&code, context),
+                    None => RestrictError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.apps.restrict", &warnings);
+            }
+        })
 }
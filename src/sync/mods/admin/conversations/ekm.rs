@@ -34,7 +34,6 @@ where
 {
     let limit: Option<Cow<'_, str>> = request.limit.as_ref().map(|limit| limit.to_string().into());
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
         request
             .channel_ids
             .as_ref()
@@ -51,14 +50,51 @@ where
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method(
-        "/admin.conversations.ekm.listOriginalConnectedChannelInfo",
+        client.base_url(),
+        "admin.conversations.ekm.listOriginalConnectedChannelInfo",
     );
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .map_err(ListOriginalConnectedChannelInfoError::Client)
         .and_then(|result| {
-            serde_json::from_str::<ListOriginalConnectedChannelInfoResponse>(&result)
-                .map_err(|e| ListOriginalConnectedChannelInfoError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<ListOriginalConnectedChannelInfoResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => {
+                        ListOriginalConnectedChannelInfoError::new(&code, context)
+                    }
+                    None => ListOriginalConnectedChannelInfoError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings(
+                    "admin.conversations.ekm.listOriginalConnectedChannelInfo",
+                    &warnings,
+                );
+            }
+        })
+}
+/// Calls [`list_original_connected_channel_info`] page by page and yields the `channels` of every page.
+///
+/// Follows `response_metadata.next_cursor` until it is empty, and stops early once
+/// `max_items` items were yielded. An error is yielded once and ends the iteration.
+pub fn list_original_connected_channel_info_iter<'a, R>(
+    client: &'a R,
+    token: &'a str,
+    request: &ListOriginalConnectedChannelInfoRequest<'a>,
+    max_items: Option<usize>,
+) -> impl Iterator<Item = Result<serde_json::Value, ListOriginalConnectedChannelInfoError<R::Error>>> + 'a
+where
+    R: SlackWebRequestSender,
+{
+    crate::sync::pagination::cursor_iter(request.clone(), max_items, move |request| {
+        list_original_connected_channel_info(client, token, request)
+    })
 }
@@ -27,25 +27,39 @@ use std::borrow::Cow;
 pub fn info<R>(
     client: &R,
     token: &str,
-    request: &InfoRequest<'_>,
+    request: &InfoRequest,
 ) -> Result<InfoResponse, InfoError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
-    let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
-        Some(("team_id", request.team_id.as_ref())),
-    ];
+    let params: Vec<Option<(&str, &str)>> = vec![request
+        .team_id
+        .as_ref()
+        .map(|team_id| ("team_id", team_id.as_ref()))];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.teams.settings.info");
+    let url = crate::get_slack_url_for_method(client.base_url(), "admin.teams.settings.info");
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .map_err(InfoError::Client)
         .and_then(|result| {
-            serde_json::from_str::<InfoResponse>(&result)
-                .map_err(|e| InfoError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<InfoResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => InfoError::new(&code, context),
+                    None => InfoError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.teams.settings.info", &warnings);
+            }
+        })
 }
 /// Set the default channels of a workspace.
 ///
@@ -61,18 +75,38 @@ where
 {
     let params: Vec<Option<(&str, &str)>> = vec![
         Some(("channel_ids", request.channel_ids.as_ref())),
-        Some(("team_id", request.team_id.as_ref())),
+        request
+            .team_id
+            .as_ref()
+            .map(|team_id| ("team_id", team_id.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.teams.settings.setDefaultChannels");
+    let url = crate::get_slack_url_for_method(
+        client.base_url(),
+        "admin.teams.settings.setDefaultChannels",
+    );
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .map_err(SetDefaultChannelsError::Client)
         .and_then(|result| {
-            serde_json::from_str::<SetDefaultChannelsResponse>(&result)
-                .map_err(|e| SetDefaultChannelsError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<SetDefaultChannelsResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => SetDefaultChannelsError::new(&code, context),
+                    None => SetDefaultChannelsError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.teams.settings.setDefaultChannels", &warnings);
+            }
+        })
 }
 /// Set the description of a given workspace.
 ///
@@ -88,18 +122,36 @@ where
 {
     let params: Vec<Option<(&str, &str)>> = vec![
         Some(("description", request.description.as_ref())),
-        Some(("team_id", request.team_id.as_ref())),
+        request
+            .team_id
+            .as_ref()
+            .map(|team_id| ("team_id", team_id.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.teams.settings.setDescription");
+    let url =
+        crate::get_slack_url_for_method(client.base_url(), "admin.teams.settings.setDescription");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .map_err(SetDescriptionError::Client)
         .and_then(|result| {
-            serde_json::from_str::<SetDescriptionResponse>(&result)
-                .map_err(|e| SetDescriptionError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<SetDescriptionResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => SetDescriptionError::new(&code, context),
+                    None => SetDescriptionError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.teams.settings.setDescription", &warnings);
+            }
+        })
 }
 /// An API method that allows admins to set the discoverability of a given workspace
 ///
@@ -115,18 +167,38 @@ where
 {
     let params: Vec<Option<(&str, &str)>> = vec![
         Some(("discoverability", request.discoverability.as_ref())),
-        Some(("team_id", request.team_id.as_ref())),
+        request
+            .team_id
+            .as_ref()
+            .map(|team_id| ("team_id", team_id.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.teams.settings.setDiscoverability");
+    let url = crate::get_slack_url_for_method(
+        client.base_url(),
+        "admin.teams.settings.setDiscoverability",
+    );
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .map_err(SetDiscoverabilityError::Client)
         .and_then(|result| {
-            serde_json::from_str::<SetDiscoverabilityResponse>(&result)
-                .map_err(|e| SetDiscoverabilityError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<SetDiscoverabilityResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => SetDiscoverabilityError::new(&code, context),
+                    None => SetDiscoverabilityError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.teams.settings.setDiscoverability", &warnings);
+            }
+        })
 }
 /// Sets the icon of a workspace.
 ///
@@ -142,18 +214,35 @@ where
 {
     let params: Vec<Option<(&str, &str)>> = vec![
         Some(("image_url", request.image_url.as_ref())),
-        Some(("team_id", request.team_id.as_ref())),
+        request
+            .team_id
+            .as_ref()
+            .map(|team_id| ("team_id", team_id.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.teams.settings.setIcon");
+    let url = crate::get_slack_url_for_method(client.base_url(), "admin.teams.settings.setIcon");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .map_err(SetIconError::Client)
         .and_then(|result| {
-            serde_json::from_str::<SetIconResponse>(&result)
-                .map_err(|e| SetIconError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<SetIconResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => SetIconError::new(&code, context),
+                    None => SetIconError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.teams.settings.setIcon", &warnings);
+            }
+        })
 }
 /// Set the name of a given workspace.
 ///
@@ -169,16 +258,33 @@ where
 {
     let params: Vec<Option<(&str, &str)>> = vec![
         Some(("name", request.name.as_ref())),
-        Some(("team_id", request.team_id.as_ref())),
+        request
+            .team_id
+            .as_ref()
+            .map(|team_id| ("team_id", team_id.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.teams.settings.setName");
+    let url = crate::get_slack_url_for_method(client.base_url(), "admin.teams.settings.setName");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .map_err(SetNameError::Client)
         .and_then(|result| {
-            serde_json::from_str::<SetNameResponse>(&result)
-                .map_err(|e| SetNameError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<SetNameResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => SetNameError::new(&code, context),
+                    None => SetNameError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.teams.settings.setName", &warnings);
+            }
+        })
 }
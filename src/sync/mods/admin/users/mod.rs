@@ -53,19 +53,39 @@ where
         is_ultra_restricted
             .as_ref()
             .map(|is_ultra_restricted| ("is_ultra_restricted", is_ultra_restricted.as_ref())),
-        Some(("team_id", request.team_id.as_ref())),
-        Some(("user_id", request.user_id.as_ref())),
+        request
+            .team_id
+            .as_ref()
+            .map(|team_id| ("team_id", team_id.as_ref())),
+        request
+            .user_id
+            .as_ref()
+            .map(|user_id| ("user_id", user_id.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.users.assign");
+    let url = crate::get_slack_url_for_method(client.base_url(), "admin.users.assign");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .map_err(AssignError::Client)
         .and_then(|result| {
-            serde_json::from_str::<AssignResponse>(&result)
-                .map_err(|e| AssignError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<AssignResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => AssignError::new(&code, context),
+                    None => AssignError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.users.assign", &warnings);
+            }
+        })
 }
 /// Invite a user to a workspace.
 ///
@@ -113,18 +133,35 @@ where
             .as_ref()
             .map(|real_name| ("real_name", real_name.as_ref())),
         resend.as_ref().map(|resend| ("resend", resend.as_ref())),
-        Some(("team_id", request.team_id.as_ref())),
+        request
+            .team_id
+            .as_ref()
+            .map(|team_id| ("team_id", team_id.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.users.invite");
+    let url = crate::get_slack_url_for_method(client.base_url(), "admin.users.invite");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .map_err(InviteError::Client)
         .and_then(|result| {
-            serde_json::from_str::<InviteResponse>(&result)
-                .map_err(|e| InviteError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<InviteResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => InviteError::new(&code, context),
+                    None => InviteError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.users.invite", &warnings);
+            }
+        })
 }
 /// List users on a workspace
 ///
@@ -140,24 +177,57 @@ where
 {
     let limit: Option<Cow<'_, str>> = request.limit.as_ref().map(|limit| limit.to_string().into());
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
         request
             .cursor
             .as_ref()
             .map(|cursor| ("cursor", cursor.as_ref())),
         limit.as_ref().map(|limit| ("limit", limit.as_ref())),
-        Some(("team_id", request.team_id.as_ref())),
+        request
+            .team_id
+            .as_ref()
+            .map(|team_id| ("team_id", team_id.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.users.list");
+    let url = crate::get_slack_url_for_method(client.base_url(), "admin.users.list");
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .map_err(ListError::Client)
         .and_then(|result| {
-            serde_json::from_str::<ListResponse>(&result)
-                .map_err(|e| ListError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<ListResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => ListError::new(&code, context),
+                    None => ListError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.users.list", &warnings);
+            }
+        })
+}
+/// Calls [`list`] page by page and yields the `users` of every page.
+///
+/// Follows `response_metadata.next_cursor` until it is empty, and stops early once
+/// `max_items` items were yielded. An error is yielded once and ends the iteration.
+pub fn list_iter<'a, R>(
+    client: &'a R,
+    token: &'a str,
+    request: &ListRequest<'a>,
+    max_items: Option<usize>,
+) -> impl Iterator<Item = Result<serde_json::Value, ListError<R::Error>>> + 'a
+where
+    R: SlackWebRequestSender,
+{
+    crate::sync::pagination::cursor_iter(request.clone(), max_items, move |request| {
+        list(client, token, request)
+    })
 }
 /// Remove a user from a workspace.
 ///
@@ -166,25 +236,45 @@ where
 pub fn remove<R>(
     client: &R,
     token: &str,
-    request: &RemoveRequest<'_>,
+    request: &RemoveRequest,
 ) -> Result<RemoveResponse, RemoveError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("team_id", request.team_id.as_ref())),
-        Some(("user_id", request.user_id.as_ref())),
+        request
+            .team_id
+            .as_ref()
+            .map(|team_id| ("team_id", team_id.as_ref())),
+        request
+            .user_id
+            .as_ref()
+            .map(|user_id| ("user_id", user_id.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.users.remove");
+    let url = crate::get_slack_url_for_method(client.base_url(), "admin.users.remove");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .map_err(RemoveError::Client)
         .and_then(|result| {
-            serde_json::from_str::<RemoveResponse>(&result)
-                .map_err(|e| RemoveError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<RemoveResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => RemoveError::new(&code, context),
+                    None => RemoveError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.users.remove", &warnings);
+            }
+        })
 }
 /// Set an existing guest, regular user, or owner to be an admin user.
 ///
@@ -193,25 +283,45 @@ where
 pub fn set_admin<R>(
     client: &R,
     token: &str,
-    request: &SetAdminRequest<'_>,
+    request: &SetAdminRequest,
 ) -> Result<SetAdminResponse, SetAdminError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("team_id", request.team_id.as_ref())),
-        Some(("user_id", request.user_id.as_ref())),
+        request
+            .team_id
+            .as_ref()
+            .map(|team_id| ("team_id", team_id.as_ref())),
+        request
+            .user_id
+            .as_ref()
+            .map(|user_id| ("user_id", user_id.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.users.setAdmin");
+    let url = crate::get_slack_url_for_method(client.base_url(), "admin.users.setAdmin");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .map_err(SetAdminError::Client)
         .and_then(|result| {
-            serde_json::from_str::<SetAdminResponse>(&result)
-                .map_err(|e| SetAdminError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<SetAdminResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => SetAdminError::new(&code, context),
+                    None => SetAdminError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.users.setAdmin", &warnings);
+            }
+        })
 }
 /// Set an expiration for a guest user
 ///
@@ -220,7 +330,7 @@ where
 pub fn set_expiration<R>(
     client: &R,
     token: &str,
-    request: &SetExpirationRequest<'_>,
+    request: &SetExpirationRequest,
 ) -> Result<SetExpirationResponse, SetExpirationError<R::Error>>
 where
     R: SlackWebRequestSender,
@@ -230,19 +340,39 @@ where
         expiration_ts
             .as_ref()
             .map(|expiration_ts| ("expiration_ts", expiration_ts.as_ref())),
-        Some(("team_id", request.team_id.as_ref())),
-        Some(("user_id", request.user_id.as_ref())),
+        request
+            .team_id
+            .as_ref()
+            .map(|team_id| ("team_id", team_id.as_ref())),
+        request
+            .user_id
+            .as_ref()
+            .map(|user_id| ("user_id", user_id.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.users.setExpiration");
+    let url = crate::get_slack_url_for_method(client.base_url(), "admin.users.setExpiration");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .map_err(SetExpirationError::Client)
         .and_then(|result| {
-            serde_json::from_str::<SetExpirationResponse>(&result)
-                .map_err(|e| SetExpirationError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<SetExpirationResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => SetExpirationError::new(&code, context),
+                    None => SetExpirationError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.users.setExpiration", &warnings);
+            }
+        })
 }
 /// Set an existing guest, regular user, or admin user to be a workspace owner.
 ///
@@ -251,25 +381,45 @@ where
 pub fn set_owner<R>(
     client: &R,
     token: &str,
-    request: &SetOwnerRequest<'_>,
+    request: &SetOwnerRequest,
 ) -> Result<SetOwnerResponse, SetOwnerError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("team_id", request.team_id.as_ref())),
-        Some(("user_id", request.user_id.as_ref())),
+        request
+            .team_id
+            .as_ref()
+            .map(|team_id| ("team_id", team_id.as_ref())),
+        request
+            .user_id
+            .as_ref()
+            .map(|user_id| ("user_id", user_id.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.users.setOwner");
+    let url = crate::get_slack_url_for_method(client.base_url(), "admin.users.setOwner");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .map_err(SetOwnerError::Client)
         .and_then(|result| {
-            serde_json::from_str::<SetOwnerResponse>(&result)
-                .map_err(|e| SetOwnerError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<SetOwnerResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => SetOwnerError::new(&code, context),
+                    None => SetOwnerError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.users.setOwner", &warnings);
+            }
+        })
 }
 /// Set an existing guest user, admin user, or owner to be a regular user.
 ///
@@ -278,23 +428,43 @@ where
 pub fn set_regular<R>(
     client: &R,
     token: &str,
-    request: &SetRegularRequest<'_>,
+    request: &SetRegularRequest,
 ) -> Result<SetRegularResponse, SetRegularError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("team_id", request.team_id.as_ref())),
-        Some(("user_id", request.user_id.as_ref())),
+        request
+            .team_id
+            .as_ref()
+            .map(|team_id| ("team_id", team_id.as_ref())),
+        request
+            .user_id
+            .as_ref()
+            .map(|user_id| ("user_id", user_id.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.users.setRegular");
+    let url = crate::get_slack_url_for_method(client.base_url(), "admin.users.setRegular");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .map_err(SetRegularError::Client)
         .and_then(|result| {
-            serde_json::from_str::<SetRegularResponse>(&result)
-                .map_err(|e| SetRegularError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<SetRegularResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => SetRegularError::new(&code, context),
+                    None => SetRegularError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.users.setRegular", &warnings);
+            }
+        })
 }
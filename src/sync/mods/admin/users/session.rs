@@ -27,7 +27,7 @@ use std::borrow::Cow;
 pub fn invalidate<R>(
     client: &R,
     token: &str,
-    request: &InvalidateRequest<'_>,
+    request: &InvalidateRequest,
 ) -> Result<InvalidateResponse, InvalidateError<R::Error>>
 where
     R: SlackWebRequestSender,
@@ -37,18 +37,35 @@ where
         session_id
             .as_ref()
             .map(|session_id| ("session_id", session_id.as_ref())),
-        Some(("team_id", request.team_id.as_ref())),
+        request
+            .team_id
+            .as_ref()
+            .map(|team_id| ("team_id", team_id.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.users.session.invalidate");
+    let url = crate::get_slack_url_for_method(client.base_url(), "admin.users.session.invalidate");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .map_err(InvalidateError::Client)
         .and_then(|result| {
-            serde_json::from_str::<InvalidateResponse>(&result)
-                .map_err(|e| InvalidateError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<InvalidateResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => InvalidateError::new(&code, context),
+                    None => InvalidateError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.users.session.invalidate", &warnings);
+            }
+        })
 }
 /// Wipes all valid sessions on all devices for a given user
 ///
@@ -57,7 +74,7 @@ where
 pub fn reset<R>(
     client: &R,
     token: &str,
-    request: &ResetRequest<'_>,
+    request: &ResetRequest,
 ) -> Result<ResetResponse, ResetError<R::Error>>
 where
     R: SlackWebRequestSender,
@@ -74,19 +91,36 @@ where
         mobile_only
             .as_ref()
             .map(|mobile_only| ("mobile_only", mobile_only.as_ref())),
-        Some(("user_id", request.user_id.as_ref())),
+        request
+            .user_id
+            .as_ref()
+            .map(|user_id| ("user_id", user_id.as_ref())),
         web_only
             .as_ref()
             .map(|web_only| ("web_only", web_only.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/admin.users.session.reset");
+    let url = crate::get_slack_url_for_method(client.base_url(), "admin.users.session.reset");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .map_err(ResetError::Client)
         .and_then(|result| {
-            serde_json::from_str::<ResetResponse>(&result)
-                .map_err(|e| ResetError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<ResetResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => ResetError::new(&code, context),
+                    None => ResetError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("admin.users.session.reset", &warnings);
+            }
+        })
 }
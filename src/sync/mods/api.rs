@@ -36,13 +36,27 @@ where
         request.foo.as_ref().map(|foo| ("foo", foo.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/api.test");
+    let url = crate::get_slack_url_for_method(client.base_url(), "api.test");
     client
-        .get(&url, &params[..])
+        .get_response(&url, None, &params[..])
         .map_err(TestError::Client)
         .and_then(|result| {
-            serde_json::from_str::<TestResponse>(&result)
-                .map_err(|e| TestError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<TestResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => TestError::new(&code, context),
+                    None => TestError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("api.test", &warnings);
+            }
+        })
 }
@@ -36,7 +36,6 @@ where
     R: SlackWebRequestSender,
 {
     let params: Vec<Option<(&str, &str)>> = vec![
-        token.map(|token| ("token", token)),
         request
             .client_id
             .as_ref()
@@ -47,13 +46,27 @@ where
             .map(|client_secret| ("client_secret", client_secret.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/apps.uninstall");
+    let url = crate::get_slack_url_for_method(client.base_url(), "apps.uninstall");
     client
-        .get(&url, &params[..])
+        .get_response(&url, token, &params[..])
         .map_err(UninstallError::Client)
         .and_then(|result| {
-            serde_json::from_str::<UninstallResponse>(&result)
-                .map_err(|e| UninstallError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<UninstallResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => UninstallError::new(&code, context),
+                    None => UninstallError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("apps.uninstall", &warnings);
+            }
+        })
 }
@@ -29,33 +29,47 @@ use std::borrow::Cow;
 pub fn delete<R>(
     client: &R,
     token: &str,
-    request: &DeleteRequest<'_>,
+    request: &DeleteRequest,
 ) -> Result<DeleteResponse, DeleteError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
-    let as_user: Option<Cow<'_, str>> = request
-        .as_user
-        .as_ref()
-        .map(|as_user| as_user.to_string().into());
-    let ts: Option<Cow<'_, str>> = Some(request.ts.to_string().into());
-    let params: Vec<Option<(&str, &str)>> = vec![
-        as_user
+    let body: Vec<Option<(&str, serde_json::Value)>> = vec![
+        request.as_user.map(|as_user| ("as_user", as_user.into())),
+        request
+            .channel
             .as_ref()
-            .map(|as_user| ("as_user", as_user.as_ref())),
-        Some(("channel", request.channel.as_ref())),
-        ts.as_ref().map(|ts| ("ts", ts.as_ref())),
+            .map(|channel| ("channel", channel.as_ref().into())),
+        Some(("ts", request.ts.to_param_value().into())),
     ];
-    let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/chat.delete");
+    let body: serde_json::Map<String, serde_json::Value> = body
+        .into_iter()
+        .filter_map(|x| x)
+        .map(|(name, value)| (name.to_owned(), value))
+        .collect();
+    let url = crate::get_slack_url_for_method(client.base_url(), "chat.delete");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_json(&url, Some(token), &serde_json::Value::Object(body))
         .map_err(DeleteError::Client)
         .and_then(|result| {
-            serde_json::from_str::<DeleteResponse>(&result)
-                .map_err(|e| DeleteError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<DeleteResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => DeleteError::new(&code, context),
+                    None => DeleteError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("chat.delete", &warnings);
+            }
+        })
 }
 /// Deletes a pending scheduled message from the queue.
 ///
@@ -69,30 +83,45 @@ pub fn delete_scheduled_message<R>(
 where
     R: SlackWebRequestSender,
 {
-    let as_user: Option<Cow<'_, str>> = request
-        .as_user
-        .as_ref()
-        .map(|as_user| as_user.to_string().into());
-    let params: Vec<Option<(&str, &str)>> = vec![
-        as_user
+    let body: Vec<Option<(&str, serde_json::Value)>> = vec![
+        request.as_user.map(|as_user| ("as_user", as_user.into())),
+        request
+            .channel
             .as_ref()
-            .map(|as_user| ("as_user", as_user.as_ref())),
-        Some(("channel", request.channel.as_ref())),
+            .map(|channel| ("channel", channel.as_ref().into())),
         Some((
             "scheduled_message_id",
-            request.scheduled_message_id.as_ref(),
+            request.scheduled_message_id.as_ref().into(),
         )),
     ];
-    let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/chat.deleteScheduledMessage");
+    let body: serde_json::Map<String, serde_json::Value> = body
+        .into_iter()
+        .filter_map(|x| x)
+        .map(|(name, value)| (name.to_owned(), value))
+        .collect();
+    let url = crate::get_slack_url_for_method(client.base_url(), "chat.deleteScheduledMessage");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_json(&url, Some(token), &serde_json::Value::Object(body))
         .map_err(DeleteScheduledMessageError::Client)
         .and_then(|result| {
-            serde_json::from_str::<DeleteScheduledMessageResponse>(&result)
-                .map_err(|e| DeleteScheduledMessageError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<DeleteScheduledMessageResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => DeleteScheduledMessageError::new(&code, context),
+                    None => DeleteScheduledMessageError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("chat.deleteScheduledMessage", &warnings);
+            }
+        })
 }
 /// Retrieve a permalink URL for a specific extant message
 ///
@@ -101,26 +130,45 @@ where
 pub fn get_permalink<R>(
     client: &R,
     token: &str,
-    request: &GetPermalinkRequest<'_>,
+    request: &GetPermalinkRequest,
 ) -> Result<GetPermalinkResponse, GetPermalinkError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
+    let message_ts: Option<Cow<'_, str>> = Some(request.message_ts.to_param_value().into());
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
-        Some(("channel", request.channel.as_ref())),
-        Some(("message_ts", request.message_ts.as_ref())),
+        request
+            .channel
+            .as_ref()
+            .map(|channel| ("channel", channel.as_ref())),
+        message_ts
+            .as_ref()
+            .map(|message_ts| ("message_ts", message_ts.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/chat.getPermalink");
+    let url = crate::get_slack_url_for_method(client.base_url(), "chat.getPermalink");
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .map_err(GetPermalinkError::Client)
         .and_then(|result| {
-            serde_json::from_str::<GetPermalinkResponse>(&result)
-                .map_err(|e| GetPermalinkError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<GetPermalinkResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => GetPermalinkError::new(&code, context),
+                    None => GetPermalinkError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("chat.getPermalink", &warnings);
+            }
+        })
 }
 /// Share a me message into a channel.
 ///
@@ -134,20 +182,41 @@ pub fn me_message<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("channel", request.channel.as_ref())),
-        Some(("text", request.text.as_ref())),
+    let body: Vec<Option<(&str, serde_json::Value)>> = vec![
+        request
+            .channel
+            .as_ref()
+            .map(|channel| ("channel", channel.as_ref().into())),
+        Some(("text", request.text.as_ref().into())),
     ];
-    let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/chat.meMessage");
+    let body: serde_json::Map<String, serde_json::Value> = body
+        .into_iter()
+        .filter_map(|x| x)
+        .map(|(name, value)| (name.to_owned(), value))
+        .collect();
+    let url = crate::get_slack_url_for_method(client.base_url(), "chat.meMessage");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_json(&url, Some(token), &serde_json::Value::Object(body))
         .map_err(MeMessageError::Client)
         .and_then(|result| {
-            serde_json::from_str::<MeMessageResponse>(&result)
-                .map_err(|e| MeMessageError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<MeMessageResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => MeMessageError::new(&code, context),
+                    None => MeMessageError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("chat.meMessage", &warnings);
+            }
+        })
 }
 /// Sends an ephemeral message to a user in a channel.
 ///
@@ -161,63 +230,79 @@ pub fn post_ephemeral<R>(
 where
     R: SlackWebRequestSender,
 {
-    let as_user: Option<Cow<'_, str>> = request
-        .as_user
-        .as_ref()
-        .map(|as_user| as_user.to_string().into());
-    let link_names: Option<Cow<'_, str>> = request
-        .link_names
-        .as_ref()
-        .map(|link_names| link_names.to_string().into());
-    let params: Vec<Option<(&str, &str)>> = vec![
-        as_user
-            .as_ref()
-            .map(|as_user| ("as_user", as_user.as_ref())),
+    let body: Vec<Option<(&str, serde_json::Value)>> = vec![
+        request.as_user.map(|as_user| ("as_user", as_user.into())),
         request
             .attachments
-            .as_ref()
-            .map(|attachments| ("attachments", attachments.as_ref())),
+            .clone()
+            .map(|attachments| ("attachments", attachments)),
         request
             .blocks
             .as_ref()
-            .map(|blocks| ("blocks", blocks.as_ref())),
-        Some(("channel", request.channel.as_ref())),
+            .map(|blocks| ("blocks", crate::blocks::to_json(blocks))),
+        request
+            .channel
+            .as_ref()
+            .map(|channel| ("channel", channel.as_ref().into())),
         request
             .icon_emoji
             .as_ref()
-            .map(|icon_emoji| ("icon_emoji", icon_emoji.as_ref())),
+            .map(|icon_emoji| ("icon_emoji", icon_emoji.as_ref().into())),
         request
             .icon_url
             .as_ref()
-            .map(|icon_url| ("icon_url", icon_url.as_ref())),
-        link_names
-            .as_ref()
-            .map(|link_names| ("link_names", link_names.as_ref())),
+            .map(|icon_url| ("icon_url", icon_url.as_ref().into())),
+        request
+            .link_names
+            .map(|link_names| ("link_names", link_names.into())),
         request
             .parse
             .as_ref()
-            .map(|parse| ("parse", parse.as_ref())),
-        request.text.as_ref().map(|text| ("text", text.as_ref())),
+            .map(|parse| ("parse", parse.as_ref().into())),
+        request
+            .text
+            .as_ref()
+            .map(|text| ("text", text.as_ref().into())),
         request
             .thread_ts
+            .map(|thread_ts| ("thread_ts", thread_ts.to_param_value().into())),
+        request
+            .user
             .as_ref()
-            .map(|thread_ts| ("thread_ts", thread_ts.as_ref())),
-        Some(("user", request.user.as_ref())),
+            .map(|user| ("user", user.as_ref().into())),
         request
             .username
             .as_ref()
-            .map(|username| ("username", username.as_ref())),
+            .map(|username| ("username", username.as_ref().into())),
     ];
-    let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/chat.postEphemeral");
+    let body: serde_json::Map<String, serde_json::Value> = body
+        .into_iter()
+        .filter_map(|x| x)
+        .map(|(name, value)| (name.to_owned(), value))
+        .collect();
+    let url = crate::get_slack_url_for_method(client.base_url(), "chat.postEphemeral");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_json(&url, Some(token), &serde_json::Value::Object(body))
         .map_err(PostEphemeralError::Client)
         .and_then(|result| {
-            serde_json::from_str::<PostEphemeralResponse>(&result)
-                .map_err(|e| PostEphemeralError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<PostEphemeralResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => PostEphemeralError::new(&code, context),
+                    None => PostEphemeralError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("chat.postEphemeral", &warnings);
+            }
+        })
 }
 /// Sends a message to a channel.
 ///
@@ -231,85 +316,85 @@ pub fn post_message<R>(
 where
     R: SlackWebRequestSender,
 {
-    let link_names: Option<Cow<'_, str>> = request
-        .link_names
-        .as_ref()
-        .map(|link_names| link_names.to_string().into());
-    let mrkdwn: Option<Cow<'_, str>> = request
-        .mrkdwn
-        .as_ref()
-        .map(|mrkdwn| mrkdwn.to_string().into());
-    let reply_broadcast: Option<Cow<'_, str>> = request
-        .reply_broadcast
-        .as_ref()
-        .map(|reply_broadcast| reply_broadcast.to_string().into());
-    let unfurl_links: Option<Cow<'_, str>> = request
-        .unfurl_links
-        .as_ref()
-        .map(|unfurl_links| unfurl_links.to_string().into());
-    let unfurl_media: Option<Cow<'_, str>> = request
-        .unfurl_media
-        .as_ref()
-        .map(|unfurl_media| unfurl_media.to_string().into());
-    let params: Vec<Option<(&str, &str)>> = vec![
+    let body: Vec<Option<(&str, serde_json::Value)>> = vec![
         request
             .as_user
             .as_ref()
-            .map(|as_user| ("as_user", as_user.as_ref())),
+            .map(|as_user| ("as_user", as_user.as_ref().into())),
         request
             .attachments
-            .as_ref()
-            .map(|attachments| ("attachments", attachments.as_ref())),
+            .clone()
+            .map(|attachments| ("attachments", attachments)),
         request
             .blocks
             .as_ref()
-            .map(|blocks| ("blocks", blocks.as_ref())),
-        Some(("channel", request.channel.as_ref())),
+            .map(|blocks| ("blocks", crate::blocks::to_json(blocks))),
+        request
+            .channel
+            .as_ref()
+            .map(|channel| ("channel", channel.as_ref().into())),
         request
             .icon_emoji
             .as_ref()
-            .map(|icon_emoji| ("icon_emoji", icon_emoji.as_ref())),
+            .map(|icon_emoji| ("icon_emoji", icon_emoji.as_ref().into())),
         request
             .icon_url
             .as_ref()
-            .map(|icon_url| ("icon_url", icon_url.as_ref())),
-        link_names
-            .as_ref()
-            .map(|link_names| ("link_names", link_names.as_ref())),
-        mrkdwn.as_ref().map(|mrkdwn| ("mrkdwn", mrkdwn.as_ref())),
+            .map(|icon_url| ("icon_url", icon_url.as_ref().into())),
+        request
+            .link_names
+            .map(|link_names| ("link_names", link_names.into())),
+        request.mrkdwn.map(|mrkdwn| ("mrkdwn", mrkdwn.into())),
         request
             .parse
             .as_ref()
-            .map(|parse| ("parse", parse.as_ref())),
-        reply_broadcast
-            .as_ref()
-            .map(|reply_broadcast| ("reply_broadcast", reply_broadcast.as_ref())),
-        Some(("text", request.text.as_ref())),
+            .map(|parse| ("parse", parse.as_ref().into())),
+        request
+            .reply_broadcast
+            .map(|reply_broadcast| ("reply_broadcast", reply_broadcast.into())),
+        Some(("text", request.text.as_ref().into())),
         request
             .thread_ts
-            .as_ref()
-            .map(|thread_ts| ("thread_ts", thread_ts.as_ref())),
-        unfurl_links
-            .as_ref()
-            .map(|unfurl_links| ("unfurl_links", unfurl_links.as_ref())),
-        unfurl_media
-            .as_ref()
-            .map(|unfurl_media| ("unfurl_media", unfurl_media.as_ref())),
+            .map(|thread_ts| ("thread_ts", thread_ts.to_param_value().into())),
+        request
+            .unfurl_links
+            .map(|unfurl_links| ("unfurl_links", unfurl_links.into())),
+        request
+            .unfurl_media
+            .map(|unfurl_media| ("unfurl_media", unfurl_media.into())),
         request
             .username
             .as_ref()
-            .map(|username| ("username", username.as_ref())),
+            .map(|username| ("username", username.as_ref().into())),
     ];
-    let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/chat.postMessage");
+    let body: serde_json::Map<String, serde_json::Value> = body
+        .into_iter()
+        .filter_map(|x| x)
+        .map(|(name, value)| (name.to_owned(), value))
+        .collect();
+    let url = crate::get_slack_url_for_method(client.base_url(), "chat.postMessage");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_json(&url, Some(token), &serde_json::Value::Object(body))
         .map_err(PostMessageError::Client)
         .and_then(|result| {
-            serde_json::from_str::<PostMessageResponse>(&result)
-                .map_err(|e| PostMessageError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<PostMessageResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => PostMessageError::new(&code, context),
+                    None => PostMessageError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("chat.postMessage", &warnings);
+            }
+        })
 }
 /// Schedules a message to be sent to a channel.
 ///
@@ -323,85 +408,76 @@ pub fn schedule_message<R>(
 where
     R: SlackWebRequestSender,
 {
-    let as_user: Option<Cow<'_, str>> = request
-        .as_user
-        .as_ref()
-        .map(|as_user| as_user.to_string().into());
-    let link_names: Option<Cow<'_, str>> = request
-        .link_names
-        .as_ref()
-        .map(|link_names| link_names.to_string().into());
-    let reply_broadcast: Option<Cow<'_, str>> = request
-        .reply_broadcast
-        .as_ref()
-        .map(|reply_broadcast| reply_broadcast.to_string().into());
-    let thread_ts: Option<Cow<'_, str>> = request
-        .thread_ts
-        .as_ref()
-        .map(|thread_ts| thread_ts.to_string().into());
-    let unfurl_links: Option<Cow<'_, str>> = request
-        .unfurl_links
-        .as_ref()
-        .map(|unfurl_links| unfurl_links.to_string().into());
-    let unfurl_media: Option<Cow<'_, str>> = request
-        .unfurl_media
-        .as_ref()
-        .map(|unfurl_media| unfurl_media.to_string().into());
-    let params: Vec<Option<(&str, &str)>> = vec![
-        as_user
-            .as_ref()
-            .map(|as_user| ("as_user", as_user.as_ref())),
+    let body: Vec<Option<(&str, serde_json::Value)>> = vec![
+        request.as_user.map(|as_user| ("as_user", as_user.into())),
         request
             .attachments
-            .as_ref()
-            .map(|attachments| ("attachments", attachments.as_ref())),
+            .clone()
+            .map(|attachments| ("attachments", attachments)),
         request
             .blocks
             .as_ref()
-            .map(|blocks| ("blocks", blocks.as_ref())),
+            .map(|blocks| ("blocks", crate::blocks::to_json(blocks))),
         request
             .channel
             .as_ref()
-            .map(|channel| ("channel", channel.as_ref())),
-        link_names
-            .as_ref()
-            .map(|link_names| ("link_names", link_names.as_ref())),
+            .map(|channel| ("channel", channel.as_ref().into())),
+        request
+            .link_names
+            .map(|link_names| ("link_names", link_names.into())),
         request
             .parse
             .as_ref()
-            .map(|parse| ("parse", parse.as_ref())),
+            .map(|parse| ("parse", parse.as_ref().into())),
         request
             .post_at
             .as_ref()
-            .map(|post_at| ("post_at", post_at.as_ref())),
-        reply_broadcast
-            .as_ref()
-            .map(|reply_broadcast| ("reply_broadcast", reply_broadcast.as_ref())),
-        request.text.as_ref().map(|text| ("text", text.as_ref())),
-        thread_ts
-            .as_ref()
-            .map(|thread_ts| ("thread_ts", thread_ts.as_ref())),
-        unfurl_links
-            .as_ref()
-            .map(|unfurl_links| ("unfurl_links", unfurl_links.as_ref())),
-        unfurl_media
+            .map(|post_at| ("post_at", post_at.as_ref().into())),
+        request
+            .reply_broadcast
+            .map(|reply_broadcast| ("reply_broadcast", reply_broadcast.into())),
+        request
+            .text
             .as_ref()
-            .map(|unfurl_media| ("unfurl_media", unfurl_media.as_ref())),
+            .map(|text| ("text", text.as_ref().into())),
+        request
+            .thread_ts
+            .map(|thread_ts| ("thread_ts", thread_ts.to_param_value().into())),
+        request
+            .unfurl_links
+            .map(|unfurl_links| ("unfurl_links", unfurl_links.into())),
+        request
+            .unfurl_media
+            .map(|unfurl_media| ("unfurl_media", unfurl_media.into())),
     ];
-    let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/chat.scheduleMessage");
+    let body: serde_json::Map<String, serde_json::Value> = body
+        .into_iter()
+        .filter_map(|x| x)
+        .map(|(name, value)| (name.to_owned(), value))
+        .collect();
+    let url = crate::get_slack_url_for_method(client.base_url(), "chat.scheduleMessage");
     client
-        .post(
-            &url,
-            &params[..],
-            &token.map_or(vec![], |t| vec![("token", t)]),
-        )
+        .post_json(&url, token, &serde_json::Value::Object(body))
         .map_err(ScheduleMessageError::Client)
         .and_then(|result| {
-            serde_json::from_str::<ScheduleMessageResponse>(&result)
-                .map_err(|e| ScheduleMessageError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<ScheduleMessageResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => ScheduleMessageError::new(&code, context),
+                    None => ScheduleMessageError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("chat.scheduleMessage", &warnings);
+            }
+        })
 }
 /// Provide custom unfurl behavior for user-posted URLs
 ///
@@ -415,36 +491,53 @@ pub fn unfurl<R>(
 where
     R: SlackWebRequestSender,
 {
-    let user_auth_required: Option<Cow<'_, str>> = request
-        .user_auth_required
-        .as_ref()
-        .map(|user_auth_required| user_auth_required.to_string().into());
-    let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("channel", request.channel.as_ref())),
-        Some(("ts", request.ts.as_ref())),
-        Some(("unfurls", request.unfurls.as_ref())),
+    let body: Vec<Option<(&str, serde_json::Value)>> = vec![
         request
-            .user_auth_message
+            .channel
             .as_ref()
-            .map(|user_auth_message| ("user_auth_message", user_auth_message.as_ref())),
-        user_auth_required
+            .map(|channel| ("channel", channel.as_ref().into())),
+        Some(("ts", request.ts.to_param_value().into())),
+        Some(("unfurls", request.unfurls.clone())),
+        request
+            .user_auth_message
             .as_ref()
-            .map(|user_auth_required| ("user_auth_required", user_auth_required.as_ref())),
+            .map(|user_auth_message| ("user_auth_message", user_auth_message.as_ref().into())),
+        request
+            .user_auth_required
+            .map(|user_auth_required| ("user_auth_required", user_auth_required.into())),
         request
             .user_auth_url
             .as_ref()
-            .map(|user_auth_url| ("user_auth_url", user_auth_url.as_ref())),
+            .map(|user_auth_url| ("user_auth_url", user_auth_url.as_ref().into())),
     ];
-    let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/chat.unfurl");
+    let body: serde_json::Map<String, serde_json::Value> = body
+        .into_iter()
+        .filter_map(|x| x)
+        .map(|(name, value)| (name.to_owned(), value))
+        .collect();
+    let url = crate::get_slack_url_for_method(client.base_url(), "chat.unfurl");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_json(&url, Some(token), &serde_json::Value::Object(body))
         .map_err(UnfurlError::Client)
         .and_then(|result| {
-            serde_json::from_str::<UnfurlResponse>(&result)
-                .map_err(|e| UnfurlError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<UnfurlResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => UnfurlError::new(&code, context),
+                    None => UnfurlError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("chat.unfurl", &warnings);
+            }
+        })
 }
 /// Updates a message.
 ///
@@ -458,39 +551,63 @@ pub fn update<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params: Vec<Option<(&str, &str)>> = vec![
+    let body: Vec<Option<(&str, serde_json::Value)>> = vec![
         request
             .as_user
             .as_ref()
-            .map(|as_user| ("as_user", as_user.as_ref())),
+            .map(|as_user| ("as_user", as_user.as_ref().into())),
         request
             .attachments
-            .as_ref()
-            .map(|attachments| ("attachments", attachments.as_ref())),
+            .clone()
+            .map(|attachments| ("attachments", attachments)),
         request
             .blocks
             .as_ref()
-            .map(|blocks| ("blocks", blocks.as_ref())),
-        Some(("channel", request.channel.as_ref())),
+            .map(|blocks| ("blocks", crate::blocks::to_json(blocks))),
+        request
+            .channel
+            .as_ref()
+            .map(|channel| ("channel", channel.as_ref().into())),
         request
             .link_names
             .as_ref()
-            .map(|link_names| ("link_names", link_names.as_ref())),
+            .map(|link_names| ("link_names", link_names.as_ref().into())),
         request
             .parse
             .as_ref()
-            .map(|parse| ("parse", parse.as_ref())),
-        request.text.as_ref().map(|text| ("text", text.as_ref())),
-        Some(("ts", request.ts.as_ref())),
+            .map(|parse| ("parse", parse.as_ref().into())),
+        request
+            .text
+            .as_ref()
+            .map(|text| ("text", text.as_ref().into())),
+        Some(("ts", request.ts.to_param_value().into())),
     ];
-    let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/chat.update");
+    let body: serde_json::Map<String, serde_json::Value> = body
+        .into_iter()
+        .filter_map(|x| x)
+        .map(|(name, value)| (name.to_owned(), value))
+        .collect();
+    let url = crate::get_slack_url_for_method(client.base_url(), "chat.update");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_json(&url, Some(token), &serde_json::Value::Object(body))
         .map_err(UpdateError::Client)
         .and_then(|result| {
-            serde_json::from_str::<UpdateResponse>(&result)
-                .map_err(|e| UpdateError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<UpdateResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => UpdateError::new(&code, context),
+                    None => UpdateError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("chat.update", &warnings);
+            }
+        })
 }
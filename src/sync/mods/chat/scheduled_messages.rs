@@ -32,37 +32,66 @@ pub fn list<R>(
 where
     R: SlackWebRequestSender,
 {
-    let latest: Option<Cow<'_, str>> = request
-        .latest
-        .as_ref()
-        .map(|latest| latest.to_string().into());
-    let limit: Option<Cow<'_, str>> = request.limit.as_ref().map(|limit| limit.to_string().into());
-    let oldest: Option<Cow<'_, str>> = request
-        .oldest
-        .as_ref()
-        .map(|oldest| oldest.to_string().into());
-    let params: Vec<Option<(&str, &str)>> = vec![
-        token.map(|token| ("token", token)),
+    let body: Vec<Option<(&str, serde_json::Value)>> = vec![
         request
             .channel
             .as_ref()
-            .map(|channel| ("channel", channel.as_ref())),
+            .map(|channel| ("channel", channel.as_ref().into())),
         request
             .cursor
             .as_ref()
-            .map(|cursor| ("cursor", cursor.as_ref())),
-        latest.as_ref().map(|latest| ("latest", latest.as_ref())),
-        limit.as_ref().map(|limit| ("limit", limit.as_ref())),
-        oldest.as_ref().map(|oldest| ("oldest", oldest.as_ref())),
+            .map(|cursor| ("cursor", cursor.as_ref().into())),
+        request
+            .latest
+            .map(|latest| ("latest", latest.to_param_value().into())),
+        request.limit.map(|limit| ("limit", limit.into())),
+        request
+            .oldest
+            .map(|oldest| ("oldest", oldest.to_param_value().into())),
     ];
-    let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/chat.scheduledMessages.list");
+    let body: serde_json::Map<String, serde_json::Value> = body
+        .into_iter()
+        .filter_map(|x| x)
+        .map(|(name, value)| (name.to_owned(), value))
+        .collect();
+    let url = crate::get_slack_url_for_method(client.base_url(), "chat.scheduledMessages.list");
     client
-        .get(&url, &params[..])
+        .post_json(&url, token, &serde_json::Value::Object(body))
         .map_err(ListError::Client)
         .and_then(|result| {
-            serde_json::from_str::<ListResponse>(&result)
-                .map_err(|e| ListError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<ListResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => ListError::new(&code, context),
+                    None => ListError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("chat.scheduledMessages.list", &warnings);
+            }
+        })
+}
+/// Calls [`list`] page by page and yields the `scheduled_messages` of every page.
+///
+/// Follows `response_metadata.next_cursor` until it is empty, and stops early once
+/// `max_items` items were yielded. An error is yielded once and ends the iteration.
+pub fn list_iter<'a, R>(
+    client: &'a R,
+    token: Option<&'a str>,
+    request: &ListRequest<'a>,
+    max_items: Option<usize>,
+) -> impl Iterator<Item = Result<ListScheduledMessagesInner, ListError<R::Error>>> + 'a
+where
+    R: SlackWebRequestSender,
+{
+    crate::sync::pagination::cursor_iter(request.clone(), max_items, move |request| {
+        list(client, token, request)
+    })
 }
@@ -27,7 +27,7 @@ use std::borrow::Cow;
 pub fn archive<R>(
     client: &R,
     token: Option<&str>,
-    request: &ArchiveRequest<'_>,
+    request: &ArchiveRequest,
 ) -> Result<ArchiveResponse, ArchiveError<R::Error>>
 where
     R: SlackWebRequestSender,
@@ -37,19 +37,29 @@ where
         .as_ref()
         .map(|channel| ("channel", channel.as_ref()))];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/conversations.archive");
+    let url = crate::get_slack_url_for_method(client.base_url(), "conversations.archive");
     client
-        .post(
-            &url,
-            &params[..],
-            &token.map_or(vec![], |t| vec![("token", t)]),
-        )
+        .post_response(&url, token, &params[..])
         .map_err(ArchiveError::Client)
         .and_then(|result| {
-            serde_json::from_str::<ArchiveResponse>(&result)
-                .map_err(|e| ArchiveError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<ArchiveResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => ArchiveError::new(&code, context),
+                    None => ArchiveError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("conversations.archive", &warnings);
+            }
+        })
 }
 /// Closes a direct message or multi-person direct message.
 ///
@@ -58,7 +68,7 @@ where
 pub fn close<R>(
     client: &R,
     token: Option<&str>,
-    request: &CloseRequest<'_>,
+    request: &CloseRequest,
 ) -> Result<CloseResponse, CloseError<R::Error>>
 where
     R: SlackWebRequestSender,
@@ -68,19 +78,29 @@ where
         .as_ref()
         .map(|channel| ("channel", channel.as_ref()))];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/conversations.close");
+    let url = crate::get_slack_url_for_method(client.base_url(), "conversations.close");
     client
-        .post(
-            &url,
-            &params[..],
-            &token.map_or(vec![], |t| vec![("token", t)]),
-        )
+        .post_response(&url, token, &params[..])
         .map_err(CloseError::Client)
         .and_then(|result| {
-            serde_json::from_str::<CloseResponse>(&result)
-                .map_err(|e| CloseError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<CloseResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => CloseError::new(&code, context),
+                    None => CloseError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("conversations.close", &warnings);
+            }
+        })
 }
 /// Initiates a public or private channel-based conversation
 ///
@@ -105,19 +125,29 @@ where
         request.name.as_ref().map(|name| ("name", name.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/conversations.create");
+    let url = crate::get_slack_url_for_method(client.base_url(), "conversations.create");
     client
-        .post(
-            &url,
-            &params[..],
-            &token.map_or(vec![], |t| vec![("token", t)]),
-        )
+        .post_response(&url, token, &params[..])
         .map_err(CreateError::Client)
         .and_then(|result| {
-            serde_json::from_str::<CreateResponse>(&result)
-                .map_err(|e| CreateError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<CreateResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => CreateError::new(&code, context),
+                    None => CreateError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("conversations.create", &warnings);
+            }
+        })
 }
 /// Fetches a conversation's history of messages and events.
 ///
@@ -138,15 +168,17 @@ where
     let latest: Option<Cow<'_, str>> = request
         .latest
         .as_ref()
-        .map(|latest| latest.to_string().into());
+        .map(|latest| latest.to_param_value().into());
     let limit: Option<Cow<'_, str>> = request.limit.as_ref().map(|limit| limit.to_string().into());
     let oldest: Option<Cow<'_, str>> = request
         .oldest
         .as_ref()
-        .map(|oldest| oldest.to_string().into());
+        .map(|oldest| oldest.to_param_value().into());
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
-        Some(("channel", request.channel.as_ref())),
+        request
+            .channel
+            .as_ref()
+            .map(|channel| ("channel", channel.as_ref())),
         request
             .cursor
             .as_ref()
@@ -159,15 +191,46 @@ where
         oldest.as_ref().map(|oldest| ("oldest", oldest.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/conversations.history");
+    let url = crate::get_slack_url_for_method(client.base_url(), "conversations.history");
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .map_err(HistoryError::Client)
         .and_then(|result| {
-            serde_json::from_str::<HistoryResponse>(&result)
-                .map_err(|e| HistoryError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<HistoryResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => HistoryError::new(&code, context),
+                    None => HistoryError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("conversations.history", &warnings);
+            }
+        })
+}
+/// Calls [`history`] page by page and yields the `messages` of every page.
+///
+/// Follows `response_metadata.next_cursor` until it is empty, and stops early once
+/// `max_items` items were yielded. An error is yielded once and ends the iteration.
+pub fn history_iter<'a, R>(
+    client: &'a R,
+    token: &'a str,
+    request: &HistoryRequest<'a>,
+    max_items: Option<usize>,
+) -> impl Iterator<Item = Result<HistoryMessagesInner, HistoryError<R::Error>>> + 'a
+where
+    R: SlackWebRequestSender,
+{
+    crate::sync::pagination::cursor_iter(request.clone(), max_items, move |request| {
+        history(client, token, request)
+    })
 }
 /// Retrieve information about a conversation.
 ///
@@ -176,7 +239,7 @@ where
 pub fn info<R>(
     client: &R,
     token: &str,
-    request: &InfoRequest<'_>,
+    request: &InfoRequest,
 ) -> Result<InfoResponse, InfoError<R::Error>>
 where
     R: SlackWebRequestSender,
@@ -190,8 +253,10 @@ where
         .as_ref()
         .map(|include_num_members| include_num_members.to_string().into());
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
-        Some(("channel", request.channel.as_ref())),
+        request
+            .channel
+            .as_ref()
+            .map(|channel| ("channel", channel.as_ref())),
         include_locale
             .as_ref()
             .map(|include_locale| ("include_locale", include_locale.as_ref())),
@@ -200,15 +265,29 @@ where
             .map(|include_num_members| ("include_num_members", include_num_members.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/conversations.info");
+    let url = crate::get_slack_url_for_method(client.base_url(), "conversations.info");
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .map_err(InfoError::Client)
         .and_then(|result| {
-            serde_json::from_str::<InfoResponse>(&result)
-                .map_err(|e| InfoError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<InfoResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => InfoError::new(&code, context),
+                    None => InfoError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("conversations.info", &warnings);
+            }
+        })
 }
 /// Invites users to a channel.
 ///
@@ -233,19 +312,29 @@ where
             .map(|users| ("users", users.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/conversations.invite");
+    let url = crate::get_slack_url_for_method(client.base_url(), "conversations.invite");
     client
-        .post(
-            &url,
-            &params[..],
-            &token.map_or(vec![], |t| vec![("token", t)]),
-        )
+        .post_response(&url, token, &params[..])
         .map_err(InviteError::Client)
         .and_then(|result| {
-            serde_json::from_str::<InviteResponse>(&result)
-                .map_err(|e| InviteError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<InviteResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => InviteError::new(&code, context),
+                    None => InviteError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("conversations.invite", &warnings);
+            }
+        })
 }
 /// Joins an existing conversation.
 ///
@@ -254,7 +343,7 @@ where
 pub fn join<R>(
     client: &R,
     token: Option<&str>,
-    request: &JoinRequest<'_>,
+    request: &JoinRequest,
 ) -> Result<JoinResponse, JoinError<R::Error>>
 where
     R: SlackWebRequestSender,
@@ -264,19 +353,29 @@ where
         .as_ref()
         .map(|channel| ("channel", channel.as_ref()))];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/conversations.join");
+    let url = crate::get_slack_url_for_method(client.base_url(), "conversations.join");
     client
-        .post(
-            &url,
-            &params[..],
-            &token.map_or(vec![], |t| vec![("token", t)]),
-        )
+        .post_response(&url, token, &params[..])
         .map_err(JoinError::Client)
         .and_then(|result| {
-            serde_json::from_str::<JoinResponse>(&result)
-                .map_err(|e| JoinError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<JoinResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => JoinError::new(&code, context),
+                    None => JoinError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("conversations.join", &warnings);
+            }
+        })
 }
 /// Removes a user from a conversation.
 ///
@@ -285,7 +384,7 @@ where
 pub fn kick<R>(
     client: &R,
     token: Option<&str>,
-    request: &KickRequest<'_>,
+    request: &KickRequest,
 ) -> Result<KickResponse, KickError<R::Error>>
 where
     R: SlackWebRequestSender,
@@ -298,19 +397,29 @@ where
         request.user.as_ref().map(|user| ("user", user.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/conversations.kick");
+    let url = crate::get_slack_url_for_method(client.base_url(), "conversations.kick");
     client
-        .post(
-            &url,
-            &params[..],
-            &token.map_or(vec![], |t| vec![("token", t)]),
-        )
+        .post_response(&url, token, &params[..])
         .map_err(KickError::Client)
         .and_then(|result| {
-            serde_json::from_str::<KickResponse>(&result)
-                .map_err(|e| KickError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<KickResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => KickError::new(&code, context),
+                    None => KickError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("conversations.kick", &warnings);
+            }
+        })
 }
 /// Leaves a conversation.
 ///
@@ -319,7 +428,7 @@ where
 pub fn leave<R>(
     client: &R,
     token: Option<&str>,
-    request: &LeaveRequest<'_>,
+    request: &LeaveRequest,
 ) -> Result<LeaveResponse, LeaveError<R::Error>>
 where
     R: SlackWebRequestSender,
@@ -329,19 +438,29 @@ where
         .as_ref()
         .map(|channel| ("channel", channel.as_ref()))];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/conversations.leave");
+    let url = crate::get_slack_url_for_method(client.base_url(), "conversations.leave");
     client
-        .post(
-            &url,
-            &params[..],
-            &token.map_or(vec![], |t| vec![("token", t)]),
-        )
+        .post_response(&url, token, &params[..])
         .map_err(LeaveError::Client)
         .and_then(|result| {
-            serde_json::from_str::<LeaveResponse>(&result)
-                .map_err(|e| LeaveError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<LeaveResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => LeaveError::new(&code, context),
+                    None => LeaveError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("conversations.leave", &warnings);
+            }
+        })
 }
 /// Lists all channels in a Slack team.
 ///
@@ -361,7 +480,6 @@ where
         .map(|exclude_archived| exclude_archived.to_string().into());
     let limit: Option<Cow<'_, str>> = request.limit.as_ref().map(|limit| limit.to_string().into());
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
         request
             .cursor
             .as_ref()
@@ -376,15 +494,46 @@ where
             .map(|types| ("types", types.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/conversations.list");
+    let url = crate::get_slack_url_for_method(client.base_url(), "conversations.list");
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .map_err(ListError::Client)
         .and_then(|result| {
-            serde_json::from_str::<ListResponse>(&result)
-                .map_err(|e| ListError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<ListResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => ListError::new(&code, context),
+                    None => ListError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("conversations.list", &warnings);
+            }
+        })
+}
+/// Calls [`list`] page by page and yields the `channels` of every page.
+///
+/// Follows `response_metadata.next_cursor` until it is empty, and stops early once
+/// `max_items` items were yielded. An error is yielded once and ends the iteration.
+pub fn list_iter<'a, R>(
+    client: &'a R,
+    token: &'a str,
+    request: &ListRequest<'a>,
+    max_items: Option<usize>,
+) -> impl Iterator<Item = Result<ListChannelsInner, ListError<R::Error>>> + 'a
+where
+    R: SlackWebRequestSender,
+{
+    crate::sync::pagination::cursor_iter(request.clone(), max_items, move |request| {
+        list(client, token, request)
+    })
 }
 /// Sets the read cursor in a channel.
 ///
@@ -393,12 +542,12 @@ where
 pub fn mark<R>(
     client: &R,
     token: Option<&str>,
-    request: &MarkRequest<'_>,
+    request: &MarkRequest,
 ) -> Result<MarkResponse, MarkError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
-    let ts: Option<Cow<'_, str>> = request.ts.as_ref().map(|ts| ts.to_string().into());
+    let ts: Option<Cow<'_, str>> = request.ts.as_ref().map(|ts| ts.to_param_value().into());
     let params: Vec<Option<(&str, &str)>> = vec![
         request
             .channel
@@ -407,19 +556,29 @@ where
         ts.as_ref().map(|ts| ("ts", ts.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/conversations.mark");
+    let url = crate::get_slack_url_for_method(client.base_url(), "conversations.mark");
     client
-        .post(
-            &url,
-            &params[..],
-            &token.map_or(vec![], |t| vec![("token", t)]),
-        )
+        .post_response(&url, token, &params[..])
         .map_err(MarkError::Client)
         .and_then(|result| {
-            serde_json::from_str::<MarkResponse>(&result)
-                .map_err(|e| MarkError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<MarkResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => MarkError::new(&code, context),
+                    None => MarkError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("conversations.mark", &warnings);
+            }
+        })
 }
 /// Retrieve members of a conversation.
 ///
@@ -435,7 +594,6 @@ where
 {
     let limit: Option<Cow<'_, str>> = request.limit.as_ref().map(|limit| limit.to_string().into());
     let params: Vec<Option<(&str, &str)>> = vec![
-        token.map(|token| ("token", token)),
         request
             .channel
             .as_ref()
@@ -447,15 +605,46 @@ where
         limit.as_ref().map(|limit| ("limit", limit.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/conversations.members");
+    let url = crate::get_slack_url_for_method(client.base_url(), "conversations.members");
     client
-        .get(&url, &params[..])
+        .get_response(&url, token, &params[..])
         .map_err(MembersError::Client)
         .and_then(|result| {
-            serde_json::from_str::<MembersResponse>(&result)
-                .map_err(|e| MembersError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<MembersResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => MembersError::new(&code, context),
+                    None => MembersError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("conversations.members", &warnings);
+            }
+        })
+}
+/// Calls [`members`] page by page and yields the `members` of every page.
+///
+/// Follows `response_metadata.next_cursor` until it is empty, and stops early once
+/// `max_items` items were yielded. An error is yielded once and ends the iteration.
+pub fn members_iter<'a, R>(
+    client: &'a R,
+    token: Option<&'a str>,
+    request: &MembersRequest<'a>,
+    max_items: Option<usize>,
+) -> impl Iterator<Item = Result<String, MembersError<R::Error>>> + 'a
+where
+    R: SlackWebRequestSender,
+{
+    crate::sync::pagination::cursor_iter(request.clone(), max_items, move |request| {
+        members(client, token, request)
+    })
 }
 /// Opens or resumes a direct message or multi-person direct message.
 ///
@@ -487,19 +676,29 @@ where
             .map(|users| ("users", users.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/conversations.open");
+    let url = crate::get_slack_url_for_method(client.base_url(), "conversations.open");
     client
-        .post(
-            &url,
-            &params[..],
-            &token.map_or(vec![], |t| vec![("token", t)]),
-        )
+        .post_response(&url, token, &params[..])
         .map_err(OpenError::Client)
         .and_then(|result| {
-            serde_json::from_str::<OpenResponse>(&result)
-                .map_err(|e| OpenError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<OpenResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => OpenError::new(&code, context),
+                    None => OpenError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("conversations.open", &warnings);
+            }
+        })
 }
 /// Renames a conversation.
 ///
@@ -521,19 +720,29 @@ where
         request.name.as_ref().map(|name| ("name", name.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/conversations.rename");
+    let url = crate::get_slack_url_for_method(client.base_url(), "conversations.rename");
     client
-        .post(
-            &url,
-            &params[..],
-            &token.map_or(vec![], |t| vec![("token", t)]),
-        )
+        .post_response(&url, token, &params[..])
         .map_err(RenameError::Client)
         .and_then(|result| {
-            serde_json::from_str::<RenameResponse>(&result)
-                .map_err(|e| RenameError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<RenameResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => RenameError::new(&code, context),
+                    None => RenameError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("conversations.rename", &warnings);
+            }
+        })
 }
 /// Retrieve a thread of messages posted to a conversation
 ///
@@ -554,15 +763,14 @@ where
     let latest: Option<Cow<'_, str>> = request
         .latest
         .as_ref()
-        .map(|latest| latest.to_string().into());
+        .map(|latest| latest.to_param_value().into());
     let limit: Option<Cow<'_, str>> = request.limit.as_ref().map(|limit| limit.to_string().into());
     let oldest: Option<Cow<'_, str>> = request
         .oldest
         .as_ref()
-        .map(|oldest| oldest.to_string().into());
-    let ts: Option<Cow<'_, str>> = request.ts.as_ref().map(|ts| ts.to_string().into());
+        .map(|oldest| oldest.to_param_value().into());
+    let ts: Option<Cow<'_, str>> = request.ts.as_ref().map(|ts| ts.to_param_value().into());
     let params: Vec<Option<(&str, &str)>> = vec![
-        token.map(|token| ("token", token)),
         request
             .channel
             .as_ref()
@@ -580,15 +788,46 @@ where
         ts.as_ref().map(|ts| ("ts", ts.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/conversations.replies");
+    let url = crate::get_slack_url_for_method(client.base_url(), "conversations.replies");
     client
-        .get(&url, &params[..])
+        .get_response(&url, token, &params[..])
         .map_err(RepliesError::Client)
         .and_then(|result| {
-            serde_json::from_str::<RepliesResponse>(&result)
-                .map_err(|e| RepliesError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<RepliesResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => RepliesError::new(&code, context),
+                    None => RepliesError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("conversations.replies", &warnings);
+            }
+        })
+}
+/// Calls [`replies`] page by page and yields the `messages` of every page.
+///
+/// Follows `response_metadata.next_cursor` until it is empty, and stops early once
+/// `max_items` items were yielded. An error is yielded once and ends the iteration.
+pub fn replies_iter<'a, R>(
+    client: &'a R,
+    token: Option<&'a str>,
+    request: &RepliesRequest<'a>,
+    max_items: Option<usize>,
+) -> impl Iterator<Item = Result<RepliesMessagesInner, RepliesError<R::Error>>> + 'a
+where
+    R: SlackWebRequestSender,
+{
+    crate::sync::pagination::cursor_iter(request.clone(), max_items, move |request| {
+        replies(client, token, request)
+    })
 }
 /// Sets the purpose for a conversation.
 ///
@@ -613,19 +852,29 @@ where
             .map(|purpose| ("purpose", purpose.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/conversations.setPurpose");
+    let url = crate::get_slack_url_for_method(client.base_url(), "conversations.setPurpose");
     client
-        .post(
-            &url,
-            &params[..],
-            &token.map_or(vec![], |t| vec![("token", t)]),
-        )
+        .post_response(&url, token, &params[..])
         .map_err(SetPurposeError::Client)
         .and_then(|result| {
-            serde_json::from_str::<SetPurposeResponse>(&result)
-                .map_err(|e| SetPurposeError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<SetPurposeResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => SetPurposeError::new(&code, context),
+                    None => SetPurposeError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("conversations.setPurpose", &warnings);
+            }
+        })
 }
 /// Sets the topic for a conversation.
 ///
@@ -650,19 +899,29 @@ where
             .map(|topic| ("topic", topic.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/conversations.setTopic");
+    let url = crate::get_slack_url_for_method(client.base_url(), "conversations.setTopic");
     client
-        .post(
-            &url,
-            &params[..],
-            &token.map_or(vec![], |t| vec![("token", t)]),
-        )
+        .post_response(&url, token, &params[..])
         .map_err(SetTopicError::Client)
         .and_then(|result| {
-            serde_json::from_str::<SetTopicResponse>(&result)
-                .map_err(|e| SetTopicError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<SetTopicResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => SetTopicError::new(&code, context),
+                    None => SetTopicError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("conversations.setTopic", &warnings);
+            }
+        })
 }
 /// Reverses conversation archival.
 ///
@@ -671,7 +930,7 @@ where
 pub fn unarchive<R>(
     client: &R,
     token: Option<&str>,
-    request: &UnarchiveRequest<'_>,
+    request: &UnarchiveRequest,
 ) -> Result<UnarchiveResponse, UnarchiveError<R::Error>>
 where
     R: SlackWebRequestSender,
@@ -681,17 +940,27 @@ where
         .as_ref()
         .map(|channel| ("channel", channel.as_ref()))];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/conversations.unarchive");
+    let url = crate::get_slack_url_for_method(client.base_url(), "conversations.unarchive");
     client
-        .post(
-            &url,
-            &params[..],
-            &token.map_or(vec![], |t| vec![("token", t)]),
-        )
+        .post_response(&url, token, &params[..])
         .map_err(UnarchiveError::Client)
         .and_then(|result| {
-            serde_json::from_str::<UnarchiveResponse>(&result)
-                .map_err(|e| UnarchiveError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<UnarchiveResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => UnarchiveError::new(&code, context),
+                    None => UnarchiveError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("conversations.unarchive", &warnings);
+            }
+        })
 }
@@ -20,9 +20,65 @@ pub mod comments;
 pub mod remote;
 
 pub use crate::mod_types::files::*;
+pub use crate::sync::ext::files::*;
 use crate::sync::SlackWebRequestSender;
 use std::borrow::Cow;
 
+/// Finishes an upload started with files.getUploadURLExternal
+///
+/// Wraps https://api.slack.com/methods/files.completeUploadExternal
+
+pub fn complete_upload_external<R>(
+    client: &R,
+    token: &str,
+    request: &CompleteUploadExternalRequest<'_>,
+) -> Result<CompleteUploadExternalResponse, CompleteUploadExternalError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let thread_ts: Option<Cow<'_, str>> = request
+        .thread_ts
+        .as_ref()
+        .map(|thread_ts| thread_ts.to_param_value().into());
+    let params: Vec<Option<(&str, &str)>> = vec![
+        request
+            .channel_id
+            .as_ref()
+            .map(|channel_id| ("channel_id", channel_id.as_ref())),
+        Some(("files", request.files.as_ref())),
+        request
+            .initial_comment
+            .as_ref()
+            .map(|initial_comment| ("initial_comment", initial_comment.as_ref())),
+        thread_ts
+            .as_ref()
+            .map(|thread_ts| ("thread_ts", thread_ts.as_ref())),
+    ];
+    let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "files.completeUploadExternal");
+    client
+        .post_response(&url, Some(token), &params[..])
+        .map_err(CompleteUploadExternalError::Client)
+        .and_then(|result| {
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<CompleteUploadExternalResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => CompleteUploadExternalError::new(&code, context),
+                    None => CompleteUploadExternalError::MalformedResponse(body, e),
+                })
+        })
+        .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("files.completeUploadExternal", &warnings);
+            }
+        })
+}
 /// Deletes a file.
 ///
 /// Wraps https://api.slack.com/methods/files.delete
@@ -30,7 +86,7 @@ use std::borrow::Cow;
 pub fn delete<R>(
     client: &R,
     token: Option<&str>,
-    request: &DeleteRequest<'_>,
+    request: &DeleteRequest,
 ) -> Result<DeleteResponse, DeleteError<R::Error>>
 where
     R: SlackWebRequestSender,
@@ -38,19 +94,79 @@ where
     let params: Vec<Option<(&str, &str)>> =
         vec![request.file.as_ref().map(|file| ("file", file.as_ref()))];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/files.delete");
+    let url = crate::get_slack_url_for_method(client.base_url(), "files.delete");
     client
-        .post(
-            &url,
-            &params[..],
-            &token.map_or(vec![], |t| vec![("token", t)]),
-        )
+        .post_response(&url, token, &params[..])
         .map_err(DeleteError::Client)
         .and_then(|result| {
-            serde_json::from_str::<DeleteResponse>(&result)
-                .map_err(|e| DeleteError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<DeleteResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => DeleteError::new(&code, context),
+                    None => DeleteError::MalformedResponse(body, e),
+                })
+        })
+        .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("files.delete", &warnings);
+            }
+        })
+}
+/// Gets a URL for an edge external file upload
+///
+/// Wraps https://api.slack.com/methods/files.getUploadURLExternal
+
+pub fn get_upload_url_external<R>(
+    client: &R,
+    token: &str,
+    request: &GetUploadURLExternalRequest<'_>,
+) -> Result<GetUploadURLExternalResponse, GetUploadURLExternalError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let length: Option<Cow<'_, str>> = Some(request.length.to_string().into());
+    let params: Vec<Option<(&str, &str)>> = vec![
+        request
+            .alt_txt
+            .as_ref()
+            .map(|alt_txt| ("alt_txt", alt_txt.as_ref())),
+        Some(("filename", request.filename.as_ref())),
+        length.as_ref().map(|length| ("length", length.as_ref())),
+        request
+            .snippet_type
+            .as_ref()
+            .map(|snippet_type| ("snippet_type", snippet_type.as_ref())),
+    ];
+    let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "files.getUploadURLExternal");
+    client
+        .post_response(&url, Some(token), &params[..])
+        .map_err(GetUploadURLExternalError::Client)
+        .and_then(|result| {
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<GetUploadURLExternalResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => GetUploadURLExternalError::new(&code, context),
+                    None => GetUploadURLExternalError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("files.getUploadURLExternal", &warnings);
+            }
+        })
 }
 /// Gets information about a file.
 ///
@@ -66,7 +182,6 @@ where
 {
     let limit: Option<Cow<'_, str>> = request.limit.as_ref().map(|limit| limit.to_string().into());
     let params: Vec<Option<(&str, &str)>> = vec![
-        token.map(|token| ("token", token)),
         request
             .count
             .as_ref()
@@ -80,15 +195,29 @@ where
         request.page.as_ref().map(|page| ("page", page.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/files.info");
+    let url = crate::get_slack_url_for_method(client.base_url(), "files.info");
     client
-        .get(&url, &params[..])
+        .get_response(&url, token, &params[..])
         .map_err(InfoError::Client)
         .and_then(|result| {
-            serde_json::from_str::<InfoResponse>(&result)
-                .map_err(|e| InfoError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<InfoResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => InfoError::new(&code, context),
+                    None => InfoError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("files.info", &warnings);
+            }
+        })
 }
 /// List for a team, in a channel, or from a user with applied filters.
 ///
@@ -109,10 +238,12 @@ where
     let ts_from: Option<Cow<'_, str>> = request
         .ts_from
         .as_ref()
-        .map(|ts_from| ts_from.to_string().into());
-    let ts_to: Option<Cow<'_, str>> = request.ts_to.as_ref().map(|ts_to| ts_to.to_string().into());
+        .map(|ts_from| ts_from.to_param_value().into());
+    let ts_to: Option<Cow<'_, str>> = request
+        .ts_to
+        .as_ref()
+        .map(|ts_to| ts_to.to_param_value().into());
     let params: Vec<Option<(&str, &str)>> = vec![
-        token.map(|token| ("token", token)),
         request
             .channel
             .as_ref()
@@ -141,15 +272,46 @@ where
         request.user.as_ref().map(|user| ("user", user.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/files.list");
+    let url = crate::get_slack_url_for_method(client.base_url(), "files.list");
     client
-        .get(&url, &params[..])
+        .get_response(&url, token, &params[..])
         .map_err(ListError::Client)
         .and_then(|result| {
-            serde_json::from_str::<ListResponse>(&result)
-                .map_err(|e| ListError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<ListResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => ListError::new(&code, context),
+                    None => ListError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("files.list", &warnings);
+            }
+        })
+}
+/// Calls [`list`] page by page and yields the `files` of every page.
+///
+/// Follows `paging.page` up to `paging.pages`, and stops early once
+/// `max_items` items were yielded. An error is yielded once and ends the iteration.
+pub fn list_iter<'a, R>(
+    client: &'a R,
+    token: Option<&'a str>,
+    request: &ListRequest<'a>,
+    max_items: Option<usize>,
+) -> impl Iterator<Item = Result<ListFilesInner, ListError<R::Error>>> + 'a
+where
+    R: SlackWebRequestSender,
+{
+    crate::sync::pagination::page_iter(request.clone(), max_items, move |request| {
+        list(client, token, request)
+    })
 }
 /// Revokes public/external sharing access for a file
 ///
@@ -158,7 +320,7 @@ where
 pub fn revoke_public_url<R>(
     client: &R,
     token: Option<&str>,
-    request: &RevokePublicURLRequest<'_>,
+    request: &RevokePublicURLRequest,
 ) -> Result<RevokePublicURLResponse, RevokePublicURLError<R::Error>>
 where
     R: SlackWebRequestSender,
@@ -166,19 +328,29 @@ where
     let params: Vec<Option<(&str, &str)>> =
         vec![request.file.as_ref().map(|file| ("file", file.as_ref()))];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/files.revokePublicURL");
+    let url = crate::get_slack_url_for_method(client.base_url(), "files.revokePublicURL");
     client
-        .post(
-            &url,
-            &params[..],
-            &token.map_or(vec![], |t| vec![("token", t)]),
-        )
+        .post_response(&url, token, &params[..])
         .map_err(RevokePublicURLError::Client)
         .and_then(|result| {
-            serde_json::from_str::<RevokePublicURLResponse>(&result)
-                .map_err(|e| RevokePublicURLError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<RevokePublicURLResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => RevokePublicURLError::new(&code, context),
+                    None => RevokePublicURLError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("files.revokePublicURL", &warnings);
+            }
+        })
 }
 /// Enables a file for public/external sharing.
 ///
@@ -187,7 +359,7 @@ where
 pub fn shared_public_url<R>(
     client: &R,
     token: Option<&str>,
-    request: &SharedPublicURLRequest<'_>,
+    request: &SharedPublicURLRequest,
 ) -> Result<SharedPublicURLResponse, SharedPublicURLError<R::Error>>
 where
     R: SlackWebRequestSender,
@@ -195,19 +367,29 @@ where
     let params: Vec<Option<(&str, &str)>> =
         vec![request.file.as_ref().map(|file| ("file", file.as_ref()))];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/files.sharedPublicURL");
+    let url = crate::get_slack_url_for_method(client.base_url(), "files.sharedPublicURL");
     client
-        .post(
-            &url,
-            &params[..],
-            &token.map_or(vec![], |t| vec![("token", t)]),
-        )
+        .post_response(&url, token, &params[..])
         .map_err(SharedPublicURLError::Client)
         .and_then(|result| {
-            serde_json::from_str::<SharedPublicURLResponse>(&result)
-                .map_err(|e| SharedPublicURLError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<SharedPublicURLResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => SharedPublicURLError::new(&code, context),
+                    None => SharedPublicURLError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("files.sharedPublicURL", &warnings);
+            }
+        })
 }
 /// Uploads or creates a file.
 ///
@@ -224,7 +406,7 @@ where
     let thread_ts: Option<Cow<'_, str>> = request
         .thread_ts
         .as_ref()
-        .map(|thread_ts| thread_ts.to_string().into());
+        .map(|thread_ts| thread_ts.to_param_value().into());
     let params: Vec<Option<(&str, &str)>> = vec![
         request
             .channels
@@ -234,7 +416,6 @@ where
             .content
             .as_ref()
             .map(|content| ("content", content.as_ref())),
-        request.file.as_ref().map(|file| ("file", file.as_ref())),
         request
             .filename
             .as_ref()
@@ -256,17 +437,35 @@ where
             .map(|title| ("title", title.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/files.upload");
-    client
-        .post(
-            &url,
-            &params[..],
-            &token.map_or(vec![], |t| vec![("token", t)]),
-        )
+    let files: Vec<Option<(&str, &crate::FileUpload<'_>)>> =
+        vec![request.file.as_ref().map(|file| ("file", file))];
+    let files: Vec<(&str, &crate::FileUpload<'_>)> =
+        files.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "files.upload");
+    let response = if files.is_empty() {
+        client.post_response(&url, token, &params[..])
+    } else {
+        client.post_multipart(&url, token, &params[..], &files[..])
+    };
+    response
         .map_err(UploadError::Client)
         .and_then(|result| {
-            serde_json::from_str::<UploadResponse>(&result)
-                .map_err(|e| UploadError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<UploadResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => UploadError::new(&code, context),
+                    None => UploadError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("files.upload", &warnings);
+            }
+        })
 }
@@ -46,34 +46,50 @@ where
             .as_ref()
             .map(|filetype| ("filetype", filetype.as_ref())),
         request
-            .indexable_file_contents
+            .title
             .as_ref()
-            .map(|indexable_file_contents| {
-                ("indexable_file_contents", indexable_file_contents.as_ref())
-            }),
+            .map(|title| ("title", title.as_ref())),
+    ];
+    let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let files: Vec<Option<(&str, &crate::FileUpload<'_>)>> = vec![
         request
-            .preview_image
+            .indexable_file_contents
             .as_ref()
-            .map(|preview_image| ("preview_image", preview_image.as_ref())),
+            .map(|indexable_file_contents| ("indexable_file_contents", indexable_file_contents)),
         request
-            .title
+            .preview_image
             .as_ref()
-            .map(|title| ("title", title.as_ref())),
+            .map(|preview_image| ("preview_image", preview_image)),
     ];
-    let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/files.remote.add");
-    client
-        .post(
-            &url,
-            &params[..],
-            &token.map_or(vec![], |t| vec![("token", t)]),
-        )
+    let files: Vec<(&str, &crate::FileUpload<'_>)> =
+        files.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "files.remote.add");
+    let response = if files.is_empty() {
+        client.post_response(&url, token, &params[..])
+    } else {
+        client.post_multipart(&url, token, &params[..], &files[..])
+    };
+    response
         .map_err(AddError::Client)
         .and_then(|result| {
-            serde_json::from_str::<AddResponse>(&result)
-                .map_err(|e| AddError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<AddResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => AddError::new(&code, context),
+                    None => AddError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("files.remote.add", &warnings);
+            }
+        })
 }
 /// Retrieve information about a remote file added to Slack
 ///
@@ -88,7 +104,6 @@ where
     R: SlackWebRequestSender,
 {
     let params: Vec<Option<(&str, &str)>> = vec![
-        token.map(|token| ("token", token)),
         request
             .external_id
             .as_ref()
@@ -96,15 +111,29 @@ where
         request.file.as_ref().map(|file| ("file", file.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/files.remote.info");
+    let url = crate::get_slack_url_for_method(client.base_url(), "files.remote.info");
     client
-        .get(&url, &params[..])
+        .get_response(&url, token, &params[..])
         .map_err(InfoError::Client)
         .and_then(|result| {
-            serde_json::from_str::<InfoResponse>(&result)
-                .map_err(|e| InfoError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<InfoResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => InfoError::new(&code, context),
+                    None => InfoError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("files.remote.info", &warnings);
+            }
+        })
 }
 /// Retrieve information about a remote file added to Slack
 ///
@@ -122,10 +151,12 @@ where
     let ts_from: Option<Cow<'_, str>> = request
         .ts_from
         .as_ref()
-        .map(|ts_from| ts_from.to_string().into());
-    let ts_to: Option<Cow<'_, str>> = request.ts_to.as_ref().map(|ts_to| ts_to.to_string().into());
+        .map(|ts_from| ts_from.to_param_value().into());
+    let ts_to: Option<Cow<'_, str>> = request
+        .ts_to
+        .as_ref()
+        .map(|ts_to| ts_to.to_param_value().into());
     let params: Vec<Option<(&str, &str)>> = vec![
-        token.map(|token| ("token", token)),
         request
             .channel
             .as_ref()
@@ -141,15 +172,46 @@ where
         ts_to.as_ref().map(|ts_to| ("ts_to", ts_to.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/files.remote.list");
+    let url = crate::get_slack_url_for_method(client.base_url(), "files.remote.list");
     client
-        .get(&url, &params[..])
+        .get_response(&url, token, &params[..])
         .map_err(ListError::Client)
         .and_then(|result| {
-            serde_json::from_str::<ListResponse>(&result)
-                .map_err(|e| ListError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<ListResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => ListError::new(&code, context),
+                    None => ListError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("files.remote.list", &warnings);
+            }
+        })
+}
+/// Calls [`list`] page by page and yields the `files` of every page.
+///
+/// Follows `response_metadata.next_cursor` until it is empty, and stops early once
+/// `max_items` items were yielded. An error is yielded once and ends the iteration.
+pub fn list_iter<'a, R>(
+    client: &'a R,
+    token: Option<&'a str>,
+    request: &ListRequest<'a>,
+    max_items: Option<usize>,
+) -> impl Iterator<Item = Result<serde_json::Value, ListError<R::Error>>> + 'a
+where
+    R: SlackWebRequestSender,
+{
+    crate::sync::pagination::cursor_iter(request.clone(), max_items, move |request| {
+        list(client, token, request)
+    })
 }
 /// Remove a remote file.
 ///
@@ -171,19 +233,29 @@ where
         request.file.as_ref().map(|file| ("file", file.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/files.remote.remove");
+    let url = crate::get_slack_url_for_method(client.base_url(), "files.remote.remove");
     client
-        .post(
-            &url,
-            &params[..],
-            &token.map_or(vec![], |t| vec![("token", t)]),
-        )
+        .post_response(&url, token, &params[..])
         .map_err(RemoveError::Client)
         .and_then(|result| {
-            serde_json::from_str::<RemoveResponse>(&result)
-                .map_err(|e| RemoveError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<RemoveResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => RemoveError::new(&code, context),
+                    None => RemoveError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("files.remote.remove", &warnings);
+            }
+        })
 }
 /// Share a remote file into a channel.
 ///
@@ -198,7 +270,6 @@ where
     R: SlackWebRequestSender,
 {
     let params: Vec<Option<(&str, &str)>> = vec![
-        token.map(|token| ("token", token)),
         request
             .channels
             .as_ref()
@@ -210,15 +281,29 @@ where
         request.file.as_ref().map(|file| ("file", file.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/files.remote.share");
+    let url = crate::get_slack_url_for_method(client.base_url(), "files.remote.share");
     client
-        .get(&url, &params[..])
+        .get_response(&url, token, &params[..])
         .map_err(ShareError::Client)
         .and_then(|result| {
-            serde_json::from_str::<ShareResponse>(&result)
-                .map_err(|e| ShareError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<ShareResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => ShareError::new(&code, context),
+                    None => ShareError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("files.remote.share", &warnings);
+            }
+        })
 }
 /// Updates an existing remote file.
 ///
@@ -247,32 +332,48 @@ where
             .as_ref()
             .map(|filetype| ("filetype", filetype.as_ref())),
         request
-            .indexable_file_contents
+            .title
             .as_ref()
-            .map(|indexable_file_contents| {
-                ("indexable_file_contents", indexable_file_contents.as_ref())
-            }),
+            .map(|title| ("title", title.as_ref())),
+    ];
+    let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let files: Vec<Option<(&str, &crate::FileUpload<'_>)>> = vec![
         request
-            .preview_image
+            .indexable_file_contents
             .as_ref()
-            .map(|preview_image| ("preview_image", preview_image.as_ref())),
+            .map(|indexable_file_contents| ("indexable_file_contents", indexable_file_contents)),
         request
-            .title
+            .preview_image
             .as_ref()
-            .map(|title| ("title", title.as_ref())),
+            .map(|preview_image| ("preview_image", preview_image)),
     ];
-    let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/files.remote.update");
-    client
-        .post(
-            &url,
-            &params[..],
-            &token.map_or(vec![], |t| vec![("token", t)]),
-        )
+    let files: Vec<(&str, &crate::FileUpload<'_>)> =
+        files.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "files.remote.update");
+    let response = if files.is_empty() {
+        client.post_response(&url, token, &params[..])
+    } else {
+        client.post_multipart(&url, token, &params[..], &files[..])
+    };
+    response
         .map_err(UpdateError::Client)
         .and_then(|result| {
-            serde_json::from_str::<UpdateResponse>(&result)
-                .map_err(|e| UpdateError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<UpdateResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => UpdateError::new(&code, context),
+                    None => UpdateError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("files.remote.update", &warnings);
+            }
+        })
 }
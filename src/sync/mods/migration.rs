@@ -37,7 +37,6 @@ where
         .as_ref()
         .map(|to_old| to_old.to_string().into());
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
         request
             .team_id
             .as_ref()
@@ -46,13 +45,27 @@ where
         Some(("users", request.users.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/migration.exchange");
+    let url = crate::get_slack_url_for_method(client.base_url(), "migration.exchange");
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .map_err(ExchangeError::Client)
         .and_then(|result| {
-            serde_json::from_str::<ExchangeResponse>(&result)
-                .map_err(|e| ExchangeError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<ExchangeResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => ExchangeError::new(&code, context),
+                    None => ExchangeError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("migration.exchange", &warnings);
+            }
+        })
 }
@@ -38,14 +38,6 @@ where
         .as_ref()
         .map(|single_channel| single_channel.to_string().into());
     let params: Vec<Option<(&str, &str)>> = vec![
-        request
-            .client_id
-            .as_ref()
-            .map(|client_id| ("client_id", client_id.as_ref())),
-        request
-            .client_secret
-            .as_ref()
-            .map(|client_secret| ("client_secret", client_secret.as_ref())),
         request.code.as_ref().map(|code| ("code", code.as_ref())),
         request
             .redirect_uri
@@ -56,15 +48,34 @@ where
             .map(|single_channel| ("single_channel", single_channel.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/oauth.access");
+    let url = crate::get_slack_url_for_method(client.base_url(), "oauth.access");
     client
-        .get(&url, &params[..])
+        .post_client_auth(
+            &url,
+            request.client_id.as_ref(),
+            request.client_secret.as_ref(),
+            &params[..],
+        )
         .map_err(AccessError::Client)
         .and_then(|result| {
-            serde_json::from_str::<AccessResponse>(&result)
-                .map_err(|e| AccessError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<AccessResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => AccessError::new(&code, context),
+                    None => AccessError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("oauth.access", &warnings);
+            }
+        })
 }
 /// Exchanges a temporary OAuth verifier code for a workspace token.
 ///
@@ -100,13 +111,27 @@ where
             .map(|single_channel| ("single_channel", single_channel.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/oauth.token");
+    let url = crate::get_slack_url_for_method(client.base_url(), "oauth.token");
     client
-        .get(&url, &params[..])
+        .get_response(&url, None, &params[..])
         .map_err(TokenError::Client)
         .and_then(|result| {
-            serde_json::from_str::<TokenResponse>(&result)
-                .map_err(|e| TokenError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<TokenResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => TokenError::new(&code, context),
+                    None => TokenError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("oauth.token", &warnings);
+            }
+        })
 }
@@ -0,0 +1,117 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+#![allow(unused_imports)]
+#![allow(clippy::match_single_binding)]
+#![allow(clippy::blacklisted_name)]
+
+pub use crate::mod_types::openid::connect_types::*;
+pub use crate::sync::ext::openid::connect::*;
+use crate::sync::SlackWebRequestSender;
+use std::borrow::Cow;
+
+/// Exchanges a temporary OAuth verifier code for an access token for Sign in with Slack.
+///
+/// Wraps https://api.slack.com/methods/openid.connect.token
+
+pub fn token<R>(
+    client: &R,
+    request: &TokenRequest<'_>,
+) -> Result<TokenResponse, TokenError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let params: Vec<Option<(&str, &str)>> = vec![
+        request.code.as_ref().map(|code| ("code", code.as_ref())),
+        request
+            .grant_type
+            .as_ref()
+            .map(|grant_type| ("grant_type", grant_type.as_ref())),
+        request
+            .redirect_uri
+            .as_ref()
+            .map(|redirect_uri| ("redirect_uri", redirect_uri.as_ref())),
+        request
+            .refresh_token
+            .as_ref()
+            .map(|refresh_token| ("refresh_token", refresh_token.as_ref())),
+    ];
+    let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "openid.connect.token");
+    client
+        .post_client_auth(
+            &url,
+            request.client_id.as_ref(),
+            request.client_secret.as_ref(),
+            &params[..],
+        )
+        .map_err(TokenError::Client)
+        .and_then(|result| {
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<TokenResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => TokenError::new(&code, context),
+                    None => TokenError::MalformedResponse(body, e),
+                })
+        })
+        .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("openid.connect.token", &warnings);
+            }
+        })
+}
+/// Get the identity of a user who has authorized Sign in with Slack.
+///
+/// Wraps https://api.slack.com/methods/openid.connect.userInfo
+
+pub fn user_info<R>(
+    client: &R,
+    token: &str,
+    _request: &UserInfoRequest,
+) -> Result<UserInfoResponse, UserInfoError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let params: Vec<Option<(&str, &str)>> = vec![];
+    let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "openid.connect.userInfo");
+    client
+        .post_response(&url, Some(token), &params[..])
+        .map_err(UserInfoError::Client)
+        .and_then(|result| {
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<UserInfoResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => UserInfoError::new(&code, context),
+                    None => UserInfoError::MalformedResponse(body, e),
+                })
+        })
+        .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("openid.connect.userInfo", &warnings);
+            }
+        })
+}
@@ -27,28 +27,48 @@ use std::borrow::Cow;
 pub fn add<R>(
     client: &R,
     token: &str,
-    request: &AddRequest<'_>,
+    request: &AddRequest,
 ) -> Result<AddResponse, AddError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
+    let timestamp: Option<Cow<'_, str>> = request
+        .timestamp
+        .as_ref()
+        .map(|timestamp| timestamp.to_param_value().into());
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("channel", request.channel.as_ref())),
         request
-            .timestamp
+            .channel
+            .as_ref()
+            .map(|channel| ("channel", channel.as_ref())),
+        timestamp
             .as_ref()
             .map(|timestamp| ("timestamp", timestamp.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/pins.add");
+    let url = crate::get_slack_url_for_method(client.base_url(), "pins.add");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .map_err(AddError::Client)
         .and_then(|result| {
-            serde_json::from_str::<AddResponse>(&result)
-                .map_err(|e| AddError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<AddResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => AddError::new(&code, context),
+                    None => AddError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("pins.add", &warnings);
+            }
+        })
 }
 /// Lists items pinned to a channel.
 ///
@@ -57,25 +77,39 @@ where
 pub fn list<R>(
     client: &R,
     token: &str,
-    request: &ListRequest<'_>,
+    request: &ListRequest,
 ) -> Result<ListResponse, ListError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
-    let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
-        Some(("channel", request.channel.as_ref())),
-    ];
+    let params: Vec<Option<(&str, &str)>> = vec![request
+        .channel
+        .as_ref()
+        .map(|channel| ("channel", channel.as_ref()))];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/pins.list");
+    let url = crate::get_slack_url_for_method(client.base_url(), "pins.list");
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .map_err(ListError::Client)
         .and_then(|result| {
-            serde_json::from_str::<ListResponse>(&result)
-                .map_err(|e| ListError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<ListResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => ListError::new(&code, context),
+                    None => ListError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("pins.list", &warnings);
+            }
+        })
 }
 /// Un-pins an item from a channel.
 ///
@@ -84,26 +118,46 @@ where
 pub fn remove<R>(
     client: &R,
     token: &str,
-    request: &RemoveRequest<'_>,
+    request: &RemoveRequest,
 ) -> Result<RemoveResponse, RemoveError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
+    let timestamp: Option<Cow<'_, str>> = request
+        .timestamp
+        .as_ref()
+        .map(|timestamp| timestamp.to_param_value().into());
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("channel", request.channel.as_ref())),
         request
-            .timestamp
+            .channel
+            .as_ref()
+            .map(|channel| ("channel", channel.as_ref())),
+        timestamp
             .as_ref()
             .map(|timestamp| ("timestamp", timestamp.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/pins.remove");
+    let url = crate::get_slack_url_for_method(client.base_url(), "pins.remove");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .map_err(RemoveError::Client)
         .and_then(|result| {
-            serde_json::from_str::<RemoveResponse>(&result)
-                .map_err(|e| RemoveError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<RemoveResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => RemoveError::new(&code, context),
+                    None => RemoveError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("pins.remove", &warnings);
+            }
+        })
 }
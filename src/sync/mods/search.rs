@@ -39,7 +39,6 @@ where
         .map(|highlight| highlight.to_string().into());
     let page: Option<Cow<'_, str>> = request.page.as_ref().map(|page| page.to_string().into());
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
         count.as_ref().map(|count| ("count", count.as_ref())),
         highlight
             .as_ref()
@@ -53,13 +52,44 @@ where
             .map(|sort_dir| ("sort_dir", sort_dir.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/search.messages");
+    let url = crate::get_slack_url_for_method(client.base_url(), "search.messages");
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .map_err(MessagesError::Client)
         .and_then(|result| {
-            serde_json::from_str::<MessagesResponse>(&result)
-                .map_err(|e| MessagesError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<MessagesResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => MessagesError::new(&code, context),
+                    None => MessagesError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("search.messages", &warnings);
+            }
+        })
+}
+/// Calls [`messages`] page by page and yields the `messages.matches` of every page.
+///
+/// Follows `messages.paging.page` up to `messages.paging.pages`, and stops early once
+/// `max_items` items were yielded. An error is yielded once and ends the iteration.
+pub fn messages_iter<'a, R>(
+    client: &'a R,
+    token: &'a str,
+    request: &MessagesRequest<'a>,
+    max_items: Option<usize>,
+) -> impl Iterator<Item = Result<serde_json::Value, MessagesError<R::Error>>> + 'a
+where
+    R: SlackWebRequestSender,
+{
+    crate::sync::pagination::page_iter(request.clone(), max_items, move |request| {
+        messages(client, token, request)
+    })
 }
@@ -34,12 +34,12 @@ pub fn access_logs<R>(
 where
     R: SlackWebRequestSender,
 {
+    let before: Option<Cow<'_, str>> = request
+        .before
+        .as_ref()
+        .map(|before| before.to_param_value().into());
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
-        request
-            .before
-            .as_ref()
-            .map(|before| ("before", before.as_ref())),
+        before.as_ref().map(|before| ("before", before.as_ref())),
         request
             .count
             .as_ref()
@@ -47,15 +47,47 @@ where
         request.page.as_ref().map(|page| ("page", page.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/team.accessLogs");
+    let url = crate::get_slack_url_for_method(client.base_url(), "team.accessLogs");
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .map_err(AccessLogsError::Client)
         .and_then(|result| {
-            serde_json::from_str::<AccessLogsResponse>(&result)
-                .map_err(|e| AccessLogsError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<AccessLogsResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => AccessLogsError::new(&code, context),
+                    None => AccessLogsError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("team.accessLogs", &warnings);
+            }
+        })
+}
+/// Calls [`access_logs`] page by page and yields the `logins` of every page.
+///
+/// Follows `paging.page` up to `paging.pages`, continuing past page 100 with
+/// `before` set to the oldest `date_first` seen, and stops early once
+/// `max_items` items were yielded. An error is yielded once and ends the iteration.
+pub fn access_logs_iter<'a, R>(
+    client: &'a R,
+    token: &'a str,
+    request: &AccessLogsRequest<'a>,
+    max_items: Option<usize>,
+) -> impl Iterator<Item = Result<AccessLogsLoginsInner, AccessLogsError<R::Error>>> + 'a
+where
+    R: SlackWebRequestSender,
+{
+    crate::sync::pagination::page_iter(request.clone(), max_items, move |request| {
+        access_logs(client, token, request)
+    })
 }
 /// Gets billable users information for the current team.
 ///
@@ -64,25 +96,37 @@ where
 pub fn billable_info<R>(
     client: &R,
     token: &str,
-    request: &BillableInfoRequest<'_>,
+    request: &BillableInfoRequest,
 ) -> Result<BillableInfoResponse, BillableInfoError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
-    let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
-        request.user.as_ref().map(|user| ("user", user.as_ref())),
-    ];
+    let params: Vec<Option<(&str, &str)>> =
+        vec![request.user.as_ref().map(|user| ("user", user.as_ref()))];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/team.billableInfo");
+    let url = crate::get_slack_url_for_method(client.base_url(), "team.billableInfo");
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .map_err(BillableInfoError::Client)
         .and_then(|result| {
-            serde_json::from_str::<BillableInfoResponse>(&result)
-                .map_err(|e| BillableInfoError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<BillableInfoResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => BillableInfoError::new(&code, context),
+                    None => BillableInfoError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("team.billableInfo", &warnings);
+            }
+        })
 }
 /// Gets information about the current team.
 ///
@@ -91,25 +135,37 @@ where
 pub fn info<R>(
     client: &R,
     token: &str,
-    request: &InfoRequest<'_>,
+    request: &InfoRequest,
 ) -> Result<InfoResponse, InfoError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
-    let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
-        request.team.as_ref().map(|team| ("team", team.as_ref())),
-    ];
+    let params: Vec<Option<(&str, &str)>> =
+        vec![request.team.as_ref().map(|team| ("team", team.as_ref()))];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/team.info");
+    let url = crate::get_slack_url_for_method(client.base_url(), "team.info");
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .map_err(InfoError::Client)
         .and_then(|result| {
-            serde_json::from_str::<InfoResponse>(&result)
-                .map_err(|e| InfoError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<InfoResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => InfoError::new(&code, context),
+                    None => InfoError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("team.info", &warnings);
+            }
+        })
 }
 /// Gets the integration logs for the current team.
 ///
@@ -124,7 +180,6 @@ where
     R: SlackWebRequestSender,
 {
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
         request
             .app_id
             .as_ref()
@@ -145,13 +200,44 @@ where
         request.user.as_ref().map(|user| ("user", user.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/team.integrationLogs");
+    let url = crate::get_slack_url_for_method(client.base_url(), "team.integrationLogs");
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .map_err(IntegrationLogsError::Client)
         .and_then(|result| {
-            serde_json::from_str::<IntegrationLogsResponse>(&result)
-                .map_err(|e| IntegrationLogsError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<IntegrationLogsResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => IntegrationLogsError::new(&code, context),
+                    None => IntegrationLogsError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("team.integrationLogs", &warnings);
+            }
+        })
+}
+/// Calls [`integration_logs`] page by page and yields the `logs` of every page.
+///
+/// Follows `paging.page` up to `paging.pages`, and stops early once
+/// `max_items` items were yielded. An error is yielded once and ends the iteration.
+pub fn integration_logs_iter<'a, R>(
+    client: &'a R,
+    token: &'a str,
+    request: &IntegrationLogsRequest<'a>,
+    max_items: Option<usize>,
+) -> impl Iterator<Item = Result<IntegrationLogsLogsInner, IntegrationLogsError<R::Error>>> + 'a
+where
+    R: SlackWebRequestSender,
+{
+    crate::sync::pagination::page_iter(request.clone(), max_items, move |request| {
+        integration_logs(client, token, request)
+    })
 }
@@ -32,21 +32,32 @@ pub fn get<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
-        request
-            .visibility
-            .as_ref()
-            .map(|visibility| ("visibility", visibility.as_ref())),
-    ];
+    let params: Vec<Option<(&str, &str)>> = vec![request
+        .visibility
+        .as_ref()
+        .map(|visibility| ("visibility", visibility.as_ref()))];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/team.profile.get");
+    let url = crate::get_slack_url_for_method(client.base_url(), "team.profile.get");
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .map_err(GetError::Client)
         .and_then(|result| {
-            serde_json::from_str::<GetResponse>(&result)
-                .map_err(|e| GetError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<GetResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => GetError::new(&code, context),
+                    None => GetError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("team.profile.get", &warnings);
+            }
+        })
 }
@@ -27,7 +27,7 @@ use std::borrow::Cow;
 pub fn list<R>(
     client: &R,
     token: &str,
-    request: &ListRequest<'_>,
+    request: &ListRequest,
 ) -> Result<ListResponse, ListError<R::Error>>
 where
     R: SlackWebRequestSender,
@@ -37,22 +37,38 @@ where
         .as_ref()
         .map(|include_disabled| include_disabled.to_string().into());
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
         include_disabled
             .as_ref()
             .map(|include_disabled| ("include_disabled", include_disabled.as_ref())),
-        Some(("usergroup", request.usergroup.as_ref())),
+        request
+            .usergroup
+            .as_ref()
+            .map(|usergroup| ("usergroup", usergroup.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/usergroups.users.list");
+    let url = crate::get_slack_url_for_method(client.base_url(), "usergroups.users.list");
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .map_err(ListError::Client)
         .and_then(|result| {
-            serde_json::from_str::<ListResponse>(&result)
-                .map_err(|e| ListError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<ListResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => ListError::new(&code, context),
+                    None => ListError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("usergroups.users.list", &warnings);
+            }
+        })
 }
 /// Update the list of users for a User Group
 ///
@@ -74,17 +90,34 @@ where
         include_count
             .as_ref()
             .map(|include_count| ("include_count", include_count.as_ref())),
-        Some(("usergroup", request.usergroup.as_ref())),
+        request
+            .usergroup
+            .as_ref()
+            .map(|usergroup| ("usergroup", usergroup.as_ref())),
         Some(("users", request.users.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/usergroups.users.update");
+    let url = crate::get_slack_url_for_method(client.base_url(), "usergroups.users.update");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_response(&url, Some(token), &params[..])
         .map_err(UpdateError::Client)
         .and_then(|result| {
-            serde_json::from_str::<UpdateResponse>(&result)
-                .map_err(|e| UpdateError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<UpdateResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => UpdateError::new(&code, context),
+                    None => UpdateError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("usergroups.users.update", &warnings);
+            }
+        })
 }
@@ -27,7 +27,7 @@ use std::borrow::Cow;
 pub fn get<R>(
     client: &R,
     token: &str,
-    request: &GetRequest<'_>,
+    request: &GetRequest,
 ) -> Result<GetResponse, GetError<R::Error>>
 where
     R: SlackWebRequestSender,
@@ -37,22 +37,35 @@ where
         .as_ref()
         .map(|include_labels| include_labels.to_string().into());
     let params: Vec<Option<(&str, &str)>> = vec![
-        Some(("token", token)),
         include_labels
             .as_ref()
             .map(|include_labels| ("include_labels", include_labels.as_ref())),
         request.user.as_ref().map(|user| ("user", user.as_ref())),
     ];
     let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/users.profile.get");
+    let url = crate::get_slack_url_for_method(client.base_url(), "users.profile.get");
     client
-        .get(&url, &params[..])
+        .get_response(&url, Some(token), &params[..])
         .map_err(GetError::Client)
         .and_then(|result| {
-            serde_json::from_str::<GetResponse>(&result)
-                .map_err(|e| GetError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<GetResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => GetError::new(&code, context),
+                    None => GetError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("users.profile.get", &warnings);
+            }
+        })
 }
 /// Set the profile information for a user.
 ///
@@ -66,26 +79,47 @@ pub fn set<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params: Vec<Option<(&str, &str)>> = vec![
-        request.name.as_ref().map(|name| ("name", name.as_ref())),
+    let body: Vec<Option<(&str, serde_json::Value)>> = vec![
         request
-            .profile
+            .name
             .as_ref()
-            .map(|profile| ("profile", profile.as_ref())),
-        request.user.as_ref().map(|user| ("user", user.as_ref())),
+            .map(|name| ("name", name.as_ref().into())),
+        request.profile.clone().map(|profile| ("profile", profile)),
+        request
+            .user
+            .as_ref()
+            .map(|user| ("user", user.as_ref().into())),
         request
             .value
             .as_ref()
-            .map(|value| ("value", value.as_ref())),
+            .map(|value| ("value", value.as_ref().into())),
     ];
-    let params: Vec<(&str, &str)> = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = crate::get_slack_url_for_method("/users.profile.set");
+    let body: serde_json::Map<String, serde_json::Value> = body
+        .into_iter()
+        .filter_map(|x| x)
+        .map(|(name, value)| (name.to_owned(), value))
+        .collect();
+    let url = crate::get_slack_url_for_method(client.base_url(), "users.profile.set");
     client
-        .post(&url, &params[..], &[("token", token)])
+        .post_json(&url, Some(token), &serde_json::Value::Object(body))
         .map_err(SetError::Client)
         .and_then(|result| {
-            serde_json::from_str::<SetResponse>(&result)
-                .map_err(|e| SetError::MalformedResponse(result, e))
+            let (http, body) = result.into_parts();
+            serde_json::from_str::<SetResponse>(&body)
+                .map(|mut response| {
+                    response.http = http;
+                    response
+                })
+                .map_err(|e| match crate::error::read_error(&body) {
+                    Some((code, context)) => SetError::new(&code, context),
+                    None => SetError::MalformedResponse(body, e),
+                })
         })
         .and_then(|o| o.into())
+        .inspect(|response| {
+            let warnings = crate::SlackResponse::warnings(response);
+            if !warnings.is_empty() {
+                client.on_warnings("users.profile.set", &warnings);
+            }
+        })
 }
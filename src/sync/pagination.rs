@@ -0,0 +1,235 @@
+//! The iterators behind the generated `*_iter` functions.
+
+use crate::pagination::{
+    Cursor, CursorPage, CursorRequest, Numbered, NumberedPage, Page, PageRequest, PageState,
+};
+
+/// Calls a cursor-paginated method page by page and yields the items of every page.
+///
+/// An error is yielded once and ends the iteration.
+pub(crate) fn cursor_iter<Req, P, E, F>(
+    request: Req,
+    max_items: Option<usize>,
+    mut call: F,
+) -> impl Iterator<Item = Result<P::Item, E>>
+where
+    Req: CursorRequest,
+    P: CursorPage,
+    F: FnMut(&Req) -> Result<P, E>,
+{
+    Pages::new(request, max_items, move |request: &Req| {
+        call(request).map(Cursor)
+    })
+}
+
+/// Calls a method paginated by page number page by page and yields the items of every page.
+///
+/// An error is yielded once and ends the iteration.
+pub(crate) fn page_iter<Req, P, E, F>(
+    request: Req,
+    max_items: Option<usize>,
+    mut call: F,
+) -> impl Iterator<Item = Result<P::Item, E>>
+where
+    Req: PageRequest,
+    P: NumberedPage,
+    F: FnMut(&Req) -> Result<P, E>,
+{
+    Pages::new(request, max_items, move |request: &Req| {
+        call(request).map(Numbered)
+    })
+}
+
+struct Pages<Req, P: Page<Req>, F> {
+    state: PageState<Req, P::Item>,
+    call: F,
+}
+
+impl<Req, P, E, F> Pages<Req, P, F>
+where
+    P: Page<Req>,
+    F: FnMut(&Req) -> Result<P, E>,
+{
+    fn new(request: Req, max_items: Option<usize>, call: F) -> Self {
+        Pages {
+            state: PageState::new(request, max_items),
+            call,
+        }
+    }
+}
+
+impl<Req, P, E, F> Iterator for Pages<Req, P, F>
+where
+    P: Page<Req>,
+    F: FnMut(&Req) -> Result<P, E>,
+{
+    type Item = Result<P::Item, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.state.next_item() {
+                return Some(Ok(item));
+            }
+            let request = self.state.next_request()?;
+            match (self.call)(&request) {
+                Ok(page) => self.state.add_page(request, page),
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sync::{admin, conversations, search};
+    use crate::testing::Scripted;
+    use crate::SlackWebResponse;
+    use std::cell::RefCell;
+
+    #[derive(Clone, Default)]
+    struct Request(Option<String>);
+
+    impl CursorRequest for Request {
+        fn set_cursor(&mut self, cursor: String) {
+            self.0 = Some(cursor);
+        }
+    }
+
+    struct Chunk(Vec<u32>, &'static str);
+
+    impl CursorPage for Chunk {
+        type Item = u32;
+
+        fn next_cursor(&self) -> Option<&str> {
+            Some(self.1)
+        }
+
+        fn into_items(self) -> Vec<u32> {
+            self.0
+        }
+    }
+
+    fn page(request: &Request) -> Result<Chunk, &'static str> {
+        match request.0.as_deref() {
+            None => Ok(Chunk(vec![1, 2], "b")),
+            Some("b") => Ok(Chunk(vec![], "c")),
+            Some("c") => Ok(Chunk(vec![3], "")),
+            Some(_) => Err("unknown cursor"),
+        }
+    }
+
+    #[test]
+    fn follows_cursor_until_empty() {
+        let items: Result<Vec<_>, _> = cursor_iter(Request::default(), None, page).collect();
+        assert_eq!(items, Ok(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn stops_at_max_items_without_fetching_more() {
+        let calls = RefCell::new(0);
+        let iter = cursor_iter(Request::default(), Some(2), |request: &Request| {
+            *calls.borrow_mut() += 1;
+            page(request)
+        });
+        assert_eq!(iter.collect::<Vec<_>>(), vec![Ok(1), Ok(2)]);
+        assert_eq!(*calls.borrow(), 1);
+    }
+
+    #[test]
+    fn error_ends_iteration() {
+        let request = Request(Some("x".into()));
+        let items: Vec<_> = cursor_iter(request, None, page).collect();
+        assert_eq!(items, vec![Err("unknown cursor")]);
+    }
+
+    #[test]
+    fn generated_iter_yields_items_of_every_page() {
+        let members = Scripted::new(|call| {
+            let body = match call.param("cursor") {
+                None => {
+                    r#"{"ok": true, "members": ["U1", "U2"], "response_metadata": {"next_cursor": "dXNlcjpVMg=="}}"#
+                }
+                Some("dXNlcjpVMg==") => {
+                    r#"{"ok": true, "members": ["U3"], "response_metadata": {"next_cursor": ""}}"#
+                }
+                Some(_) => unreachable!(),
+            };
+            SlackWebResponse::from_body(body.into())
+        });
+        let request = conversations::MembersRequest {
+            channel: Some("C1".parse().unwrap()),
+            ..Default::default()
+        };
+        let members: Vec<String> =
+            conversations::members_iter(&members, Some("xoxb"), &request, None)
+                .collect::<Result<_, _>>()
+                .unwrap();
+        assert_eq!(members, vec!["U1", "U2", "U3"]);
+    }
+
+    #[test]
+    fn generated_iter_follows_page_numbers() {
+        let matches = Scripted::new(|call| {
+            let body = match call.param("page") {
+                None => {
+                    r#"{"ok": true, "messages": {"matches": [{"ts": "1"}, {"ts": "2"}], "paging": {"page": 1, "pages": 2, "total": 3}, "total": 3}}"#
+                }
+                Some("2") => {
+                    r#"{"ok": true, "messages": {"matches": [{"ts": "3"}], "paging": {"page": 2, "pages": 2, "total": 3}, "total": 3}}"#
+                }
+                Some(_) => unreachable!(),
+            };
+            SlackWebResponse::from_body(body.into())
+        });
+        let request = search::MessagesRequest {
+            query: "deploy".into(),
+            ..Default::default()
+        };
+        let ts: Vec<serde_json::Value> = search::messages_iter(&matches, "xoxb", &request, None)
+            .map(|message| message.map(|message| message["ts"].clone()))
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(ts, vec!["1", "2", "3"]);
+    }
+
+    #[test]
+    fn generated_iter_ends_without_next_cursor() {
+        let history = Scripted::body(
+            r#"{"ok": true, "channel_actions_count": 0, "has_more": false, "pin_count": 0, "messages": [{"text": "hi", "ts": "1.2", "type": "message"}], "response_metadata": {"warnings": ["superfluous_charset"]}}"#,
+        );
+        let request = conversations::HistoryRequest {
+            channel: Some("C1".parse().unwrap()),
+            ..Default::default()
+        };
+        let ts: Vec<String> = conversations::history_iter(&history, "xoxb", &request, None)
+            .map(|message| message.map(|message| message.ts.to_param_value()))
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(ts, vec!["1.200000"]);
+        assert_eq!(history.calls().len(), 1);
+    }
+
+    #[test]
+    fn generated_iter_follows_shared_response_metadata() {
+        let users = Scripted::new(|call| {
+            let body = match call.param("cursor") {
+                None => {
+                    r#"{"ok": true, "users": [{"id": "W1"}], "response_metadata": {"next_cursor": "Vz0x"}}"#
+                }
+                Some("Vz0x") => r#"{"ok": true, "users": [{"id": "W2"}]}"#,
+                Some(_) => unreachable!(),
+            };
+            SlackWebResponse::from_body(body.into())
+        });
+        let request = admin::users::ListRequest {
+            team_id: Some("T1".parse().unwrap()),
+            ..Default::default()
+        };
+        let ids: Vec<serde_json::Value> = admin::users::list_iter(&users, "xoxp", &request, None)
+            .map(|user| user.map(|user| user["id"].clone()))
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(ids, vec!["W1", "W2"]);
+    }
+}
@@ -0,0 +1,258 @@
+//! A request sender that waits out Slack's rate limits instead of failing.
+
+use std::thread;
+
+use crate::rate_limit::{RateLimiter, RetryPolicy};
+use crate::sync::SlackWebRequestSender;
+use crate::{FileUpload, SlackWebResponse};
+
+/// Wraps a [`SlackWebRequestSender`] and retries calls that Slack answers with
+/// `429 Too Many Requests`, sleeping for as long as the `Retry-After` header asks.
+///
+/// Calls are also paced by a per-method budget based on Slack's rate limit tiers, so that paging
+/// through e.g. `conversations.history` slows down before it gets rate limited.
+///
+/// The inner sender must override [`SlackWebRequestSender::get_response`] and
+/// [`SlackWebRequestSender::post_response`] for `429` responses to be noticed; the `reqwest`
+/// blocking client does.
+#[derive(Debug)]
+pub struct RateLimitedSender<S> {
+    inner: S,
+    limiter: RateLimiter,
+}
+
+impl<S> RateLimitedSender<S> {
+    /// Wraps `inner` with the default [`RetryPolicy`] and tier-based pacing.
+    pub fn new(inner: S) -> Self {
+        Self::with_limiter(inner, RateLimiter::new())
+    }
+
+    /// Wraps `inner` with the given retry policy and tier-based pacing.
+    pub fn with_policy(inner: S, policy: RetryPolicy) -> Self {
+        Self::with_limiter(inner, RateLimiter::with_policy(policy))
+    }
+
+    pub fn with_limiter(inner: S, limiter: RateLimiter) -> Self {
+        RateLimitedSender { inner, limiter }
+    }
+
+    pub fn get_ref(&self) -> &S {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<R> SlackWebRequestSender for RateLimitedSender<R>
+where
+    R: SlackWebRequestSender,
+{
+    type Error = R::Error;
+
+    fn base_url(&self) -> &str {
+        self.inner.base_url()
+    }
+
+    fn on_warnings(&self, method: &str, warnings: &[&str]) {
+        self.inner.on_warnings(method, warnings)
+    }
+
+    fn get<S>(
+        &self,
+        method_url: S,
+        token: Option<&str>,
+        params: &[(&str, &str)],
+    ) -> Result<String, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        self.get_response(method_url, token, params)
+            .map(|response| response.body)
+    }
+
+    fn post<S>(
+        &self,
+        method_url: S,
+        token: Option<&str>,
+        form: &[(&str, &str)],
+    ) -> Result<String, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        self.post_response(method_url, token, form)
+            .map(|response| response.body)
+    }
+
+    fn get_response<S>(
+        &self,
+        method_url: S,
+        token: Option<&str>,
+        params: &[(&str, &str)],
+    ) -> Result<SlackWebResponse, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        let method_url = method_url.as_ref();
+        let mut attempt = 0;
+        loop {
+            thread::sleep(self.limiter.before_request(method_url));
+            let response = self.inner.get_response(method_url, token, params)?;
+            match self.limiter.after_response(method_url, &response, attempt) {
+                Some(wait) => thread::sleep(wait),
+                None => return Ok(response),
+            }
+            attempt += 1;
+        }
+    }
+
+    fn post_response<S>(
+        &self,
+        method_url: S,
+        token: Option<&str>,
+        form: &[(&str, &str)],
+    ) -> Result<SlackWebResponse, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        let method_url = method_url.as_ref();
+        let mut attempt = 0;
+        loop {
+            thread::sleep(self.limiter.before_request(method_url));
+            let response = self.inner.post_response(method_url, token, form)?;
+            match self.limiter.after_response(method_url, &response, attempt) {
+                Some(wait) => thread::sleep(wait),
+                None => return Ok(response),
+            }
+            attempt += 1;
+        }
+    }
+
+    fn post_multipart<S>(
+        &self,
+        method_url: S,
+        token: Option<&str>,
+        form: &[(&str, &str)],
+        files: &[(&str, &FileUpload<'_>)],
+    ) -> Result<SlackWebResponse, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        let method_url = method_url.as_ref();
+        // Readers cannot be sent again, so their uploads are not retried.
+        let replayable = files.iter().all(|(_, file)| file.is_replayable());
+        let mut attempt = 0;
+        loop {
+            thread::sleep(self.limiter.before_request(method_url));
+            let response = self.inner.post_multipart(method_url, token, form, files)?;
+            match self.limiter.after_response(method_url, &response, attempt) {
+                Some(wait) if replayable => thread::sleep(wait),
+                _ => return Ok(response),
+            }
+            attempt += 1;
+        }
+    }
+
+    fn post_json<S>(
+        &self,
+        method_url: S,
+        token: Option<&str>,
+        body: &serde_json::Value,
+    ) -> Result<SlackWebResponse, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        let method_url = method_url.as_ref();
+        let mut attempt = 0;
+        loop {
+            thread::sleep(self.limiter.before_request(method_url));
+            let response = self.inner.post_json(method_url, token, body)?;
+            match self.limiter.after_response(method_url, &response, attempt) {
+                Some(wait) => thread::sleep(wait),
+                None => return Ok(response),
+            }
+            attempt += 1;
+        }
+    }
+
+    fn post_client_auth<S>(
+        &self,
+        method_url: S,
+        client_id: &str,
+        client_secret: &str,
+        form: &[(&str, &str)],
+    ) -> Result<SlackWebResponse, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        let method_url = method_url.as_ref();
+        let mut attempt = 0;
+        loop {
+            thread::sleep(self.limiter.before_request(method_url));
+            let response =
+                self.inner
+                    .post_client_auth(method_url, client_id, client_secret, form)?;
+            match self.limiter.after_response(method_url, &response, attempt) {
+                Some(wait) => thread::sleep(wait),
+                None => return Ok(response),
+            }
+            attempt += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Scripted;
+    use std::time::Duration;
+
+    fn policy(max_retries: u32) -> RateLimiter {
+        RateLimiter::with_policy(RetryPolicy {
+            max_retries,
+            default_retry_after: Duration::from_millis(1),
+        })
+        .without_budget()
+    }
+
+    #[test]
+    fn retries_until_success() {
+        let sender = RateLimitedSender::with_limiter(Scripted::throttled(2), policy(3));
+        assert_eq!(
+            sender
+                .get("https://slack.com/api/users.list", None, &[])
+                .unwrap(),
+            "200"
+        );
+        assert_eq!(sender.into_inner().calls().len(), 3);
+    }
+
+    #[test]
+    fn gives_up_after_max_retries() {
+        let sender = RateLimitedSender::with_limiter(Scripted::throttled(3), policy(1));
+        let response = sender
+            .get_response("https://slack.com/api/users.list", None, &[])
+            .unwrap();
+        assert_eq!(response.status, 429);
+        assert_eq!(sender.into_inner().calls().len(), 2);
+    }
+
+    #[test]
+    fn retries_uploads_only_if_replayable() {
+        let upload = |file: &FileUpload<'_>| {
+            let sender = RateLimitedSender::with_limiter(Scripted::throttled(1), policy(3));
+            sender
+                .post_multipart(
+                    "https://slack.com/api/users.setPhoto",
+                    None,
+                    &[],
+                    &[("image", file)],
+                )
+                .unwrap()
+                .status
+        };
+        assert_eq!(upload(&FileUpload::bytes(&b"abc"[..])), 200);
+        assert_eq!(upload(&FileUpload::reader(&b"abc"[..])), 429);
+    }
+}
@@ -5,56 +5,192 @@ mod reqwest_support {
     pub use self::reqwest::Error;
     use reqwest_ as reqwest;
 
+    use reqwest::blocking::multipart::{Form, Part};
+    use reqwest::header::CONTENT_TYPE;
+
     use crate::sync::SlackWebRequestSender;
+    use crate::{FileUpload, SlackWebResponse, UploadSource};
 
     type Client = reqwest::blocking::Client;
 
     impl SlackWebRequestSender for Client {
         type Error = reqwest::Error;
 
-        fn get<S>(&self, method_url: S, params: &[(&str, &str)]) -> Result<String, Self::Error>
+        fn get<S>(
+            &self,
+            method_url: S,
+            token: Option<&str>,
+            params: &[(&str, &str)],
+        ) -> Result<String, Self::Error>
         where
             S: AsRef<str> + Send,
         {
-            let mut url = reqwest::Url::parse(method_url.as_ref()).expect("Unable to parse url");
-
-            let mut token = None;
-            for (name, value) in params {
-                if *name == "token" {
-                    token = Some(*value);
-                } else {
-                    url.query_pairs_mut().append_pair(name, value);
-                }
+            self.get_response(method_url, token, params)
+                .map(|response| response.body)
+        }
+
+        fn post<S>(
+            &self,
+            method_url: S,
+            token: Option<&str>,
+            form: &[(&str, &str)],
+        ) -> Result<String, Self::Error>
+        where
+            S: AsRef<str> + Send,
+        {
+            self.post_response(method_url, token, form)
+                .map(|response| response.body)
+        }
+
+        fn get_response<S>(
+            &self,
+            method_url: S,
+            token: Option<&str>,
+            params: &[(&str, &str)],
+        ) -> Result<SlackWebResponse, Self::Error>
+        where
+            S: AsRef<str> + Send,
+        {
+            let mut request = self.get(method_url.as_ref()).query(params);
+
+            if let Some(token) = token {
+                request = request.bearer_auth(token);
             }
 
-            let mut request = self.get(url);
+            into_slack_web_response(request.send()?)
+        }
+
+        fn post_response<S>(
+            &self,
+            method_url: S,
+            token: Option<&str>,
+            form: &[(&str, &str)],
+        ) -> Result<SlackWebResponse, Self::Error>
+        where
+            S: AsRef<str> + Send,
+        {
+            let mut request = self.post(method_url.as_ref()).form(form);
 
             if let Some(token) = token {
                 request = request.bearer_auth(token);
             }
 
-            Ok(request.send()?.text()?)
+            into_slack_web_response(request.send()?)
         }
 
-        fn post<S>(
+        fn post_multipart<S>(
             &self,
             method_url: S,
+            token: Option<&str>,
             form: &[(&str, &str)],
-            headers: &[(&str, &str)],
-        ) -> Result<String, Self::Error>
+            files: &[(&str, &FileUpload<'_>)],
+        ) -> Result<SlackWebResponse, Self::Error>
         where
             S: AsRef<str> + Send,
         {
-            let url = reqwest::Url::parse(method_url.as_ref()).expect("Unable to parse url");
-            let mut req = self.post(url).form(form);
-            for (k, v) in headers {
-                if *k == "token" {
-                    req = req.bearer_auth(*v);
-                } else {
-                    req = req.header(*k, *v);
-                }
+            let mut multipart = Form::new();
+            for (name, value) in form {
+                multipart = multipart.text(name.to_string(), value.to_string());
+            }
+            for (name, upload) in files {
+                multipart = multipart.part(name.to_string(), into_part(upload)?);
             }
-            Ok(req.send()?.text()?)
+
+            let mut request = self.post(method_url.as_ref()).multipart(multipart);
+
+            if let Some(token) = token {
+                request = request.bearer_auth(token);
+            }
+
+            into_slack_web_response(request.send()?)
+        }
+
+        fn post_json<S>(
+            &self,
+            method_url: S,
+            token: Option<&str>,
+            body: &serde_json::Value,
+        ) -> Result<SlackWebResponse, Self::Error>
+        where
+            S: AsRef<str> + Send,
+        {
+            let mut request = self
+                .post(method_url.as_ref())
+                .header(CONTENT_TYPE, "application/json; charset=utf-8")
+                .body(body.to_string());
+
+            if let Some(token) = token {
+                request = request.bearer_auth(token);
+            }
+
+            into_slack_web_response(request.send()?)
+        }
+
+        fn post_client_auth<S>(
+            &self,
+            method_url: S,
+            client_id: &str,
+            client_secret: &str,
+            form: &[(&str, &str)],
+        ) -> Result<SlackWebResponse, Self::Error>
+        where
+            S: AsRef<str> + Send,
+        {
+            let request = self
+                .post(method_url.as_ref())
+                .basic_auth(client_id, Some(client_secret))
+                .form(form);
+
+            into_slack_web_response(request.send()?)
+        }
+    }
+
+    /// Streams paths and readers instead of reading them into memory first.
+    fn into_part(upload: &FileUpload<'_>) -> Result<Part, reqwest::Error> {
+        let mut part = match upload.source() {
+            UploadSource::Bytes(bytes) => Part::bytes(bytes.to_vec()),
+            _ => match upload.open_or_failing() {
+                (reader, Some(len)) => Part::reader_with_length(reader, len),
+                (reader, None) => Part::reader(reader),
+            },
+        };
+        if let Some(filename) = upload.filename() {
+            part = part.file_name(filename.to_owned());
+        }
+        if let Some(mime_type) = upload.mime_type() {
+            part = part.mime_str(mime_type)?;
+        }
+        Ok(part)
+    }
+
+    fn into_slack_web_response(
+        response: reqwest::blocking::Response,
+    ) -> Result<SlackWebResponse, reqwest::Error> {
+        let status = response.status().as_u16();
+        let headers = response
+            .headers()
+            .iter()
+            .filter_map(|(k, v)| Some((k.as_str().to_owned(), v.to_str().ok()?.to_owned())))
+            .collect();
+        let body = response.text()?;
+        Ok(SlackWebResponse {
+            status,
+            headers,
+            body,
+        })
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn invalid_base_url_fails_the_request() {
+            use crate::sync::{auth, WithBaseUrl};
+
+            let client = WithBaseUrl::new(Client::new(), "not a url");
+            let error = auth::test(&client, "xoxb", &auth::TestRequest {}).unwrap_err();
+            assert!(matches!(error, auth::TestError::Client(ref e) if e.is_builder()));
         }
     }
 
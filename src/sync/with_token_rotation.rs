@@ -0,0 +1,346 @@
+//! A request sender that keeps rotating tokens fresh.
+
+use std::sync::{Mutex, PoisonError};
+
+use crate::sync::oauth::v_2::access;
+use crate::sync::SlackWebRequestSender;
+use crate::token_rotation::{is_token_expired, RotatingToken, RotationError};
+use crate::{FileUpload, SlackWebResponse};
+
+/// Wraps a [`SlackWebRequestSender`] and sends the current access token of a [`RotatingToken`]
+/// in place of any token it issued.
+///
+/// The token is refreshed with `oauth.v2.access` before it expires, and once more if Slack
+/// answers a call with `token_expired`, which is then retried. Uploads of readers cannot be sent
+/// twice, so they return the `token_expired` response instead. Calls with other tokens are sent
+/// as they are.
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn f<R: slack_api::sync::SlackWebRequestSender>(client: R, token: slack_api::token_rotation::RotatingToken)
+/// # where R::Error: 'static {
+/// use slack_api::sync::{auth, WithTokenRotation};
+///
+/// let initial = token.access_token();
+/// let client = WithTokenRotation::new(client, token);
+/// // Still works after the initial token expired.
+/// auth::test(&client, &initial, &auth::TestRequest {}).unwrap();
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct WithTokenRotation<S> {
+    inner: S,
+    token: RotatingToken,
+    refreshing: Mutex<()>,
+}
+
+impl<S> WithTokenRotation<S> {
+    pub fn new(inner: S, token: RotatingToken) -> Self {
+        WithTokenRotation {
+            inner,
+            token,
+            refreshing: Mutex::new(()),
+        }
+    }
+
+    pub fn token(&self) -> &RotatingToken {
+        &self.token
+    }
+
+    pub fn get_ref(&self) -> &S {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<R> WithTokenRotation<R>
+where
+    R: SlackWebRequestSender,
+    R::Error: 'static,
+{
+    /// Refreshes the token if `expired` is still current, or without it, if it expires soon;
+    /// either may have changed while another call was refreshing.
+    fn refresh(&self, expired: Option<&str>) -> Result<(), RotationError<R::Error>> {
+        let _refreshing = self
+            .refreshing
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let stale = match expired {
+            Some(expired) => self.token.access_token() == expired,
+            None => self.token.needs_refresh(),
+        };
+        if stale {
+            let response = access(&self.inner, &self.token.refresh_request())
+                .map_err(RotationError::Refresh)?;
+            self.token
+                .update(&response)
+                .ok_or(RotationError::NotRotated)?;
+        }
+        Ok(())
+    }
+
+    /// Sends `call` with the current token, and again after a refresh if it expired and the call
+    /// is `replayable`.
+    fn send<F>(
+        &self,
+        token: Option<&str>,
+        replayable: bool,
+        call: F,
+    ) -> Result<SlackWebResponse, RotationError<R::Error>>
+    where
+        F: Fn(&R, Option<&str>) -> Result<SlackWebResponse, R::Error>,
+    {
+        match token {
+            Some(token) if self.token.issued(token) => {}
+            token => return call(&self.inner, token).map_err(RotationError::Client),
+        }
+        if self.token.needs_refresh() {
+            self.refresh(None)?;
+        }
+        let current = self.token.access_token();
+        let response = call(&self.inner, Some(&current)).map_err(RotationError::Client)?;
+        if !is_token_expired(&response) {
+            return Ok(response);
+        }
+        self.refresh(Some(&current))?;
+        if !replayable {
+            return Ok(response);
+        }
+        call(&self.inner, Some(&self.token.access_token())).map_err(RotationError::Client)
+    }
+}
+
+impl<R> SlackWebRequestSender for WithTokenRotation<R>
+where
+    R: SlackWebRequestSender,
+    R::Error: 'static,
+{
+    type Error = RotationError<R::Error>;
+
+    fn base_url(&self) -> &str {
+        self.inner.base_url()
+    }
+
+    fn on_warnings(&self, method: &str, warnings: &[&str]) {
+        self.inner.on_warnings(method, warnings)
+    }
+
+    fn get<S>(
+        &self,
+        method_url: S,
+        token: Option<&str>,
+        params: &[(&str, &str)],
+    ) -> Result<String, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        self.get_response(method_url, token, params)
+            .map(|response| response.body)
+    }
+
+    fn post<S>(
+        &self,
+        method_url: S,
+        token: Option<&str>,
+        form: &[(&str, &str)],
+    ) -> Result<String, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        self.post_response(method_url, token, form)
+            .map(|response| response.body)
+    }
+
+    fn get_response<S>(
+        &self,
+        method_url: S,
+        token: Option<&str>,
+        params: &[(&str, &str)],
+    ) -> Result<SlackWebResponse, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        let method_url = method_url.as_ref();
+        self.send(token, true, |inner, token| {
+            inner.get_response(method_url, token, params)
+        })
+    }
+
+    fn post_response<S>(
+        &self,
+        method_url: S,
+        token: Option<&str>,
+        form: &[(&str, &str)],
+    ) -> Result<SlackWebResponse, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        let method_url = method_url.as_ref();
+        self.send(token, true, |inner, token| {
+            inner.post_response(method_url, token, form)
+        })
+    }
+
+    fn post_multipart<S>(
+        &self,
+        method_url: S,
+        token: Option<&str>,
+        form: &[(&str, &str)],
+        files: &[(&str, &FileUpload<'_>)],
+    ) -> Result<SlackWebResponse, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        let method_url = method_url.as_ref();
+        let replayable = files.iter().all(|(_, file)| file.is_replayable());
+        self.send(token, replayable, |inner, token| {
+            inner.post_multipart(method_url, token, form, files)
+        })
+    }
+
+    fn post_json<S>(
+        &self,
+        method_url: S,
+        token: Option<&str>,
+        body: &serde_json::Value,
+    ) -> Result<SlackWebResponse, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        let method_url = method_url.as_ref();
+        self.send(token, true, |inner, token| {
+            inner.post_json(method_url, token, body)
+        })
+    }
+
+    fn post_client_auth<S>(
+        &self,
+        method_url: S,
+        client_id: &str,
+        client_secret: &str,
+        form: &[(&str, &str)],
+    ) -> Result<SlackWebResponse, Self::Error>
+    where
+        S: AsRef<str> + Send,
+    {
+        self.inner
+            .post_client_auth(method_url, client_id, client_secret, form)
+            .map_err(RotationError::Client)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{Scripted, Via};
+    use crate::token_rotation::TokenSet;
+    use std::time::{Duration, SystemTime};
+
+    fn client(expires_in: Duration) -> WithTokenRotation<Scripted> {
+        let token = RotatingToken::new(
+            "123.456",
+            "secret",
+            TokenSet {
+                access_token: "xoxe.xoxb-1".into(),
+                refresh_token: "xoxe-1".into(),
+                expires_at: SystemTime::now() + expires_in,
+            },
+        );
+        WithTokenRotation::new(Scripted::rotating(), token)
+    }
+
+    /// The tokens of every call but refreshes.
+    fn sent(client: &WithTokenRotation<Scripted>) -> Vec<String> {
+        client
+            .get_ref()
+            .calls()
+            .into_iter()
+            .filter(|call| call.via != Via::ClientAuth)
+            .map(|call| call.token.unwrap_or_default())
+            .collect()
+    }
+
+    fn refreshes(client: &WithTokenRotation<Scripted>) -> usize {
+        client
+            .get_ref()
+            .calls()
+            .iter()
+            .filter(|call| call.via == Via::ClientAuth)
+            .count()
+    }
+
+    #[test]
+    fn refreshes_before_expiry() {
+        let client = client(Duration::from_secs(60));
+        let response = client.get_response("auth.test", Some("xoxe.xoxb-1"), &[]);
+        assert_eq!(response.unwrap().body, r#"{"ok": true}"#);
+        assert_eq!(sent(&client), vec!["xoxe.xoxb-2"]);
+        assert_eq!(refreshes(&client), 1);
+    }
+
+    #[test]
+    fn refreshes_on_token_expired() {
+        let client = client(Duration::from_secs(3600));
+        for _ in 0..2 {
+            let response = client.get_response("auth.test", Some("xoxe.xoxb-1"), &[]);
+            assert_eq!(response.unwrap().body, r#"{"ok": true}"#);
+        }
+        assert_eq!(
+            sent(&client),
+            vec!["xoxe.xoxb-1", "xoxe.xoxb-2", "xoxe.xoxb-2"]
+        );
+        assert_eq!(refreshes(&client), 1);
+        assert_eq!(client.token().tokens().refresh_token, "xoxe-2");
+    }
+
+    #[test]
+    fn sends_other_tokens_as_is() {
+        let client = client(Duration::from_secs(60));
+        let response = client.get_response("auth.test", Some("xoxb-other"), &[]);
+        assert!(response.unwrap().body.contains("token_expired"));
+        assert_eq!(sent(&client), vec!["xoxb-other"]);
+        assert_eq!(refreshes(&client), 0);
+    }
+
+    #[test]
+    fn returns_token_expired_for_reader_uploads() {
+        let client = client(Duration::from_secs(3600));
+        let upload = |file: &FileUpload<'_>| {
+            client
+                .post_multipart("files.upload", Some("xoxe.xoxb-1"), &[], &[("file", file)])
+                .unwrap()
+                .body
+        };
+        assert!(upload(&FileUpload::reader(&b"abc"[..])).contains("token_expired"));
+        assert_eq!(sent(&client), vec!["xoxe.xoxb-1"]);
+        assert_eq!(refreshes(&client), 1);
+
+        // The token was refreshed, so the upload can be sent again with a new reader.
+        assert_eq!(upload(&FileUpload::reader(&b"abc"[..])), r#"{"ok": true}"#);
+        assert_eq!(sent(&client), vec!["xoxe.xoxb-1", "xoxe.xoxb-2"]);
+    }
+
+    #[test]
+    fn retries_replayable_uploads() {
+        let client = client(Duration::from_secs(3600));
+        let response = client.post_multipart(
+            "files.upload",
+            Some("xoxe.xoxb-1"),
+            &[],
+            &[("file", &FileUpload::bytes(&b"abc"[..]))],
+        );
+        assert_eq!(response.unwrap().body, r#"{"ok": true}"#);
+        assert_eq!(sent(&client), vec!["xoxe.xoxb-1", "xoxe.xoxb-2"]);
+        let uploaded: Vec<_> = client
+            .get_ref()
+            .calls()
+            .into_iter()
+            .map(|call| call.files)
+            .collect();
+        assert_eq!(uploaded[0], uploaded[2]);
+    }
+}
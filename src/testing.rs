@@ -0,0 +1,436 @@
+//! A request sender for tests, which answers calls as a closure scripts and records them.
+
+// Not every feature set uses every helper.
+#![allow(dead_code)]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, PoisonError};
+
+use crate::{FileUpload, SlackWebResponse};
+
+/// How a call was sent.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Via {
+    Get,
+    Post,
+    Multipart,
+    Json,
+    ClientAuth,
+}
+
+/// A call received by a [`Scripted`] sender.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Call {
+    pub via: Via,
+    pub url: String,
+    pub token: Option<String>,
+    pub params: Vec<(String, String)>,
+    /// The name, filename and contents of every file part.
+    pub files: Vec<(String, Option<String>, Vec<u8>)>,
+    pub json: Option<serde_json::Value>,
+    pub client_auth: Option<(String, String)>,
+}
+
+impl Call {
+    fn new(via: Via, url: &str, token: Option<&str>, params: &[(&str, &str)]) -> Self {
+        Call {
+            via,
+            url: url.to_owned(),
+            token: token.map(str::to_owned),
+            params: params
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+            files: Vec::new(),
+            json: None,
+            client_auth: None,
+        }
+    }
+
+    /// The method name, e.g. `chat.postMessage`.
+    pub fn method(&self) -> &str {
+        self.url.rsplit('/').next().unwrap_or_default()
+    }
+
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+type Respond = Box<dyn Fn(&Call) -> SlackWebResponse + Send + Sync>;
+
+/// Answers every call with what `respond` returns for it, and records the calls in order.
+pub(crate) struct Scripted {
+    respond: Respond,
+    calls: Mutex<Vec<Call>>,
+}
+
+impl Scripted {
+    pub fn new<F>(respond: F) -> Self
+    where
+        F: Fn(&Call) -> SlackWebResponse + Send + Sync + 'static,
+    {
+        Scripted {
+            respond: Box::new(respond),
+            calls: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Answers every call with `body` and a `200` status.
+    pub fn body(body: &'static str) -> Self {
+        Self::new(move |_| SlackWebResponse::from_body(body.into()))
+    }
+
+    /// Answers the first `times` calls with a `429` and a `Retry-After: 0` header, then with a
+    /// `200`. Every body is the status.
+    pub fn throttled(times: usize) -> Self {
+        let count = AtomicUsize::new(0);
+        Self::new(move |_| {
+            let status = if count.fetch_add(1, Ordering::SeqCst) < times {
+                429
+            } else {
+                200
+            };
+            SlackWebResponse {
+                status,
+                headers: vec![("Retry-After".into(), "0".into())],
+                body: status.to_string(),
+            }
+        })
+    }
+
+    /// Accepts only the token `xoxe.xoxb-2`, answering others with `token_expired`, and refreshes
+    /// `xoxe-1` to it.
+    pub fn rotating() -> Self {
+        Self::new(|call| {
+            if call.via == Via::ClientAuth {
+                assert_eq!(call.url, "https://slack.com/api/oauth.v2.access");
+                assert_eq!(call.param("grant_type"), Some("refresh_token"));
+                assert_eq!(call.param("refresh_token"), Some("xoxe-1"));
+                SlackWebResponse::from_body(
+                    r#"{"ok": true, "app_id": "A1", "authed_user": {"id": "U1"}, "access_token": "xoxe.xoxb-2", "refresh_token": "xoxe-2", "expires_in": 43200}"#.into(),
+                )
+            } else if call.token.as_deref() == Some("xoxe.xoxb-2") {
+                SlackWebResponse::from_body(r#"{"ok": true}"#.into())
+            } else {
+                SlackWebResponse::from_body(r#"{"ok": false, "error": "token_expired"}"#.into())
+            }
+        })
+    }
+
+    pub fn calls(&self) -> Vec<Call> {
+        self.calls
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    fn send(&self, call: Call) -> SlackWebResponse {
+        let response = (self.respond)(&call);
+        self.calls
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(call);
+        response
+    }
+
+    fn multipart(
+        &self,
+        url: &str,
+        token: Option<&str>,
+        form: &[(&str, &str)],
+        files: &[(&str, &FileUpload<'_>)],
+    ) -> SlackWebResponse {
+        let mut call = Call::new(Via::Multipart, url, token, form);
+        call.files = files
+            .iter()
+            .map(|(name, upload)| {
+                let filename = upload.filename().map(str::to_owned);
+                (name.to_string(), filename, upload.read_to_vec().unwrap())
+            })
+            .collect();
+        self.send(call)
+    }
+
+    fn json(&self, url: &str, token: Option<&str>, body: &serde_json::Value) -> SlackWebResponse {
+        let mut call = Call::new(Via::Json, url, token, &[]);
+        call.json = Some(body.clone());
+        self.send(call)
+    }
+
+    fn client_auth(
+        &self,
+        url: &str,
+        client_id: &str,
+        client_secret: &str,
+        form: &[(&str, &str)],
+    ) -> SlackWebResponse {
+        let mut call = Call::new(Via::ClientAuth, url, None, form);
+        call.client_auth = Some((client_id.to_owned(), client_secret.to_owned()));
+        self.send(call)
+    }
+}
+
+/// Sends only forms through a [`Scripted`] sender, leaving every other method of the request
+/// sender traits to its default.
+pub(crate) struct FormOnly(pub Scripted);
+
+#[cfg(feature = "sync")]
+mod sync_impl {
+    use super::*;
+    use crate::sync::SlackWebRequestSender;
+
+    impl SlackWebRequestSender for Scripted {
+        type Error = std::fmt::Error;
+
+        fn get<S>(
+            &self,
+            method_url: S,
+            token: Option<&str>,
+            params: &[(&str, &str)],
+        ) -> Result<String, Self::Error>
+        where
+            S: AsRef<str> + Send,
+        {
+            self.get_response(method_url, token, params)
+                .map(|response| response.body)
+        }
+
+        fn post<S>(
+            &self,
+            method_url: S,
+            token: Option<&str>,
+            form: &[(&str, &str)],
+        ) -> Result<String, Self::Error>
+        where
+            S: AsRef<str> + Send,
+        {
+            self.post_response(method_url, token, form)
+                .map(|response| response.body)
+        }
+
+        fn get_response<S>(
+            &self,
+            method_url: S,
+            token: Option<&str>,
+            params: &[(&str, &str)],
+        ) -> Result<SlackWebResponse, Self::Error>
+        where
+            S: AsRef<str> + Send,
+        {
+            Ok(self.send(Call::new(Via::Get, method_url.as_ref(), token, params)))
+        }
+
+        fn post_response<S>(
+            &self,
+            method_url: S,
+            token: Option<&str>,
+            form: &[(&str, &str)],
+        ) -> Result<SlackWebResponse, Self::Error>
+        where
+            S: AsRef<str> + Send,
+        {
+            Ok(self.send(Call::new(Via::Post, method_url.as_ref(), token, form)))
+        }
+
+        fn post_multipart<S>(
+            &self,
+            method_url: S,
+            token: Option<&str>,
+            form: &[(&str, &str)],
+            files: &[(&str, &FileUpload<'_>)],
+        ) -> Result<SlackWebResponse, Self::Error>
+        where
+            S: AsRef<str> + Send,
+        {
+            Ok(self.multipart(method_url.as_ref(), token, form, files))
+        }
+
+        fn post_json<S>(
+            &self,
+            method_url: S,
+            token: Option<&str>,
+            body: &serde_json::Value,
+        ) -> Result<SlackWebResponse, Self::Error>
+        where
+            S: AsRef<str> + Send,
+        {
+            Ok(self.json(method_url.as_ref(), token, body))
+        }
+
+        fn post_client_auth<S>(
+            &self,
+            method_url: S,
+            client_id: &str,
+            client_secret: &str,
+            form: &[(&str, &str)],
+        ) -> Result<SlackWebResponse, Self::Error>
+        where
+            S: AsRef<str> + Send,
+        {
+            Ok(self.client_auth(method_url.as_ref(), client_id, client_secret, form))
+        }
+    }
+
+    impl SlackWebRequestSender for FormOnly {
+        type Error = std::fmt::Error;
+
+        fn get<S>(
+            &self,
+            method_url: S,
+            token: Option<&str>,
+            params: &[(&str, &str)],
+        ) -> Result<String, Self::Error>
+        where
+            S: AsRef<str> + Send,
+        {
+            self.0.get(method_url, token, params)
+        }
+
+        fn post<S>(
+            &self,
+            method_url: S,
+            token: Option<&str>,
+            form: &[(&str, &str)],
+        ) -> Result<String, Self::Error>
+        where
+            S: AsRef<str> + Send,
+        {
+            self.0.post(method_url, token, form)
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+mod async_impl {
+    use super::*;
+    use crate::async_impl::SlackWebRequestSender;
+    use async_trait::async_trait;
+
+    #[async_trait]
+    impl SlackWebRequestSender for Scripted {
+        type Error = std::fmt::Error;
+
+        async fn get<S>(
+            &self,
+            method_url: S,
+            token: Option<&str>,
+            params: &[(&str, &str)],
+        ) -> Result<String, Self::Error>
+        where
+            S: AsRef<str> + Send,
+        {
+            self.get_response(method_url, token, params)
+                .await
+                .map(|response| response.body)
+        }
+
+        async fn post<S>(
+            &self,
+            method_url: S,
+            token: Option<&str>,
+            form: &[(&str, &str)],
+        ) -> Result<String, Self::Error>
+        where
+            S: AsRef<str> + Send,
+        {
+            self.post_response(method_url, token, form)
+                .await
+                .map(|response| response.body)
+        }
+
+        async fn get_response<S>(
+            &self,
+            method_url: S,
+            token: Option<&str>,
+            params: &[(&str, &str)],
+        ) -> Result<SlackWebResponse, Self::Error>
+        where
+            S: AsRef<str> + Send,
+        {
+            Ok(self.send(Call::new(Via::Get, method_url.as_ref(), token, params)))
+        }
+
+        async fn post_response<S>(
+            &self,
+            method_url: S,
+            token: Option<&str>,
+            form: &[(&str, &str)],
+        ) -> Result<SlackWebResponse, Self::Error>
+        where
+            S: AsRef<str> + Send,
+        {
+            Ok(self.send(Call::new(Via::Post, method_url.as_ref(), token, form)))
+        }
+
+        async fn post_multipart<S>(
+            &self,
+            method_url: S,
+            token: Option<&str>,
+            form: &[(&str, &str)],
+            files: &[(&str, &FileUpload<'_>)],
+        ) -> Result<SlackWebResponse, Self::Error>
+        where
+            S: AsRef<str> + Send,
+        {
+            Ok(self.multipart(method_url.as_ref(), token, form, files))
+        }
+
+        async fn post_json<S>(
+            &self,
+            method_url: S,
+            token: Option<&str>,
+            body: &serde_json::Value,
+        ) -> Result<SlackWebResponse, Self::Error>
+        where
+            S: AsRef<str> + Send,
+        {
+            Ok(self.json(method_url.as_ref(), token, body))
+        }
+
+        async fn post_client_auth<S>(
+            &self,
+            method_url: S,
+            client_id: &str,
+            client_secret: &str,
+            form: &[(&str, &str)],
+        ) -> Result<SlackWebResponse, Self::Error>
+        where
+            S: AsRef<str> + Send,
+        {
+            Ok(self.client_auth(method_url.as_ref(), client_id, client_secret, form))
+        }
+    }
+
+    #[async_trait]
+    impl SlackWebRequestSender for FormOnly {
+        type Error = std::fmt::Error;
+
+        async fn get<S>(
+            &self,
+            method_url: S,
+            token: Option<&str>,
+            params: &[(&str, &str)],
+        ) -> Result<String, Self::Error>
+        where
+            S: AsRef<str> + Send,
+        {
+            self.0.get(method_url, token, params).await
+        }
+
+        async fn post<S>(
+            &self,
+            method_url: S,
+            token: Option<&str>,
+            form: &[(&str, &str)],
+        ) -> Result<String, Self::Error>
+        where
+            S: AsRef<str> + Send,
+        {
+            self.0.post(method_url, token, form).await
+        }
+    }
+}
@@ -1,3 +1,14 @@
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// A Slack timestamp such as a message `ts`, kept to the microsecond.
+///
+/// It parses from and formats to Slack's `seconds.micros` form, and to the `p` id that message
+/// permalinks end with.
 #[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct Timestamp(u64);
 
@@ -11,7 +22,7 @@ impl std::fmt::Display for Timestamp {
 
 impl From<f64> for Timestamp {
     fn from(t: f64) -> Self {
-        let micro_seconds = t * 1_000_000.0;
+        let micro_seconds = (t * 1_000_000.0).round();
         Timestamp(micro_seconds as u64)
     }
 }
@@ -26,11 +37,158 @@ impl From<u64> for Timestamp {
 impl From<(u64, f64)> for Timestamp {
     fn from(ts: (u64, f64)) -> Self {
         let (ti, td) = ts;
-        let micro_seconds = ti * 1_000_000 + (td * 1_000_000.0) as u64;
+        let micro_seconds = ti * 1_000_000 + (td * 1_000_000.0).round() as u64;
         Timestamp(micro_seconds)
     }
 }
 
+impl FromStr for Timestamp {
+    type Err = ParseTimestampError;
+
+    /// Parses the exact `seconds.micros` form, e.g. `1588859442.008705`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Timestamp::parse(s).ok_or(ParseTimestampError(()))
+    }
+}
+
+impl ::serde::Serialize for Timestamp {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl From<Timestamp> for SystemTime {
+    fn from(ts: Timestamp) -> Self {
+        UNIX_EPOCH + Duration::from_micros(ts.0)
+    }
+}
+
+impl TryFrom<SystemTime> for Timestamp {
+    type Error = TimestampRangeError;
+
+    /// Fails for times before the Unix epoch.
+    fn try_from(time: SystemTime) -> Result<Self, Self::Error> {
+        let since_epoch = time
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| TimestampRangeError(()))?;
+        u64::try_from(since_epoch.as_micros())
+            .map(Timestamp)
+            .map_err(|_| TimestampRangeError(()))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<Timestamp> for chrono::DateTime<chrono::Utc> {
+    type Error = TimestampRangeError;
+
+    fn try_from(ts: Timestamp) -> Result<Self, Self::Error> {
+        use chrono::TimeZone;
+
+        let seconds = i64::try_from(ts.0 / 1_000_000).map_err(|_| TimestampRangeError(()))?;
+        let nanos = (ts.0 % 1_000_000) as u32 * 1_000;
+        chrono::Utc
+            .timestamp_opt(seconds, nanos)
+            .single()
+            .ok_or(TimestampRangeError(()))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::DateTime<chrono::Utc>> for Timestamp {
+    type Error = TimestampRangeError;
+
+    /// Fails for times before the Unix epoch; nanoseconds are truncated to microseconds.
+    fn try_from(time: chrono::DateTime<chrono::Utc>) -> Result<Self, Self::Error> {
+        let seconds = u64::try_from(time.timestamp()).map_err(|_| TimestampRangeError(()))?;
+        let micros = u64::from(time.timestamp_subsec_micros());
+        seconds
+            .checked_mul(1_000_000)
+            .and_then(|micro_seconds| micro_seconds.checked_add(micros))
+            .map(Timestamp)
+            .ok_or(TimestampRangeError(()))
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<Timestamp> for time::OffsetDateTime {
+    type Error = TimestampRangeError;
+
+    fn try_from(ts: Timestamp) -> Result<Self, Self::Error> {
+        time::OffsetDateTime::from_unix_timestamp_nanos(i128::from(ts.0) * 1_000)
+            .map_err(|_| TimestampRangeError(()))
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<time::OffsetDateTime> for Timestamp {
+    type Error = TimestampRangeError;
+
+    /// Fails for times before the Unix epoch; nanoseconds are truncated to microseconds.
+    fn try_from(time: time::OffsetDateTime) -> Result<Self, Self::Error> {
+        u64::try_from(time.unix_timestamp_nanos() / 1_000)
+            .map(Timestamp)
+            .map_err(|_| TimestampRangeError(()))
+    }
+}
+
+/// Adds a duration, truncated to microseconds, saturating at the latest `Timestamp`.
+impl Add<Duration> for Timestamp {
+    type Output = Timestamp;
+
+    fn add(self, duration: Duration) -> Self::Output {
+        self.checked_add(duration).unwrap_or(Timestamp(u64::MAX))
+    }
+}
+
+impl AddAssign<Duration> for Timestamp {
+    fn add_assign(&mut self, duration: Duration) {
+        *self = *self + duration;
+    }
+}
+
+/// Subtracts a duration, truncated to microseconds, saturating at the Unix epoch.
+impl Sub<Duration> for Timestamp {
+    type Output = Timestamp;
+
+    fn sub(self, duration: Duration) -> Self::Output {
+        self.checked_sub(duration).unwrap_or_default()
+    }
+}
+
+impl SubAssign<Duration> for Timestamp {
+    fn sub_assign(&mut self, duration: Duration) {
+        *self = *self - duration;
+    }
+}
+
+/// The error returned when parsing a `Timestamp` fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseTimestampError(());
+
+impl fmt::Display for ParseTimestampError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid Slack timestamp")
+    }
+}
+
+impl Error for ParseTimestampError {}
+
+/// The error returned when a time is out of the range a `Timestamp`, or the type it is
+/// converted to, can hold.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimestampRangeError(());
+
+impl fmt::Display for TimestampRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("timestamp out of range")
+    }
+}
+
+impl Error for TimestampRangeError {}
+
 impl<'de> ::serde::Deserialize<'de> for Timestamp {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -44,20 +202,8 @@ impl<'de> ::serde::Deserialize<'de> for Timestamp {
 
         let value = ::serde_json::Value::deserialize(deserializer)?;
 
-        if let Some(s) = value.as_str() {
-            if let Some(dot_index) = s.find('.') {
-                // must be f128 in string
-                let i = s[..dot_index].parse::<u64>();
-                let d = s[dot_index..].parse::<f64>();
-                if let (Ok(i), Ok(d)) = (i, d) {
-                    return Ok((i, d).into());
-                }
-            } else {
-                // must be u64 in a string
-                if let Ok(u) = s.parse::<u64>() {
-                    return Ok(u.into());
-                }
-            }
+        if let Some(ts) = value.as_str().and_then(Timestamp::parse) {
+            return Ok(ts);
         }
 
         if let Some(f) = value.as_f64() {
@@ -67,7 +213,7 @@ impl<'de> ::serde::Deserialize<'de> for Timestamp {
         } else {
             Err(D::Error::custom(format!(
                 "expected a timestamp but got: {}",
-                value.to_string()
+                value
             )))
         }
     }
@@ -77,6 +223,54 @@ impl Timestamp {
     pub fn to_param_value(self) -> String {
         format!("{}", self)
     }
+
+    /// Adds a duration, truncated to microseconds, or returns `None` past the latest
+    /// `Timestamp`.
+    pub fn checked_add(self, duration: Duration) -> Option<Self> {
+        let micros = u64::try_from(duration.as_micros()).ok()?;
+        self.0.checked_add(micros).map(Timestamp)
+    }
+
+    /// Subtracts a duration, truncated to microseconds, or returns `None` before the Unix epoch.
+    pub fn checked_sub(self, duration: Duration) -> Option<Self> {
+        let micros = u64::try_from(duration.as_micros()).ok()?;
+        self.0.checked_sub(micros).map(Timestamp)
+    }
+
+    /// The id message permalinks end with, e.g. `p1588859442008705` for `1588859442.008705`.
+    pub fn to_permalink_id(self) -> String {
+        format!("p{}{:06}", self.0 / 1_000_000, self.0 % 1_000_000)
+    }
+
+    /// Parses the id message permalinks end with, e.g. `p1588859442008705`.
+    pub fn from_permalink_id(id: &str) -> Result<Self, ParseTimestampError> {
+        let digits = id
+            .strip_prefix('p')
+            .filter(|digits| digits.len() > 6 && digits.bytes().all(|b| b.is_ascii_digit()))
+            .ok_or(ParseTimestampError(()))?;
+        let (seconds, micros) = digits.split_at(digits.len() - 6);
+        Timestamp::parse(&format!("{}.{}", seconds, micros)).ok_or(ParseTimestampError(()))
+    }
+
+    /// Parses `seconds[.micros]` digit by digit, so no precision is lost on the way.
+    fn parse(s: &str) -> Option<Self> {
+        let (seconds, fraction) = match s.find('.') {
+            Some(dot_index) => (&s[..dot_index], &s[dot_index + 1..]),
+            None => (s, ""),
+        };
+        if fraction.len() > 6 || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        if seconds.is_empty() || !seconds.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let seconds = seconds.parse::<u64>().ok()?;
+        let micros = format!("{:0<6}", fraction).parse::<u64>().ok()?;
+        seconds
+            .checked_mul(1_000_000)?
+            .checked_add(micros)
+            .map(Timestamp)
+    }
 }
 
 #[cfg(test)]
@@ -107,6 +301,14 @@ mod tests {
         assert_eq!(ts.to_param_value(), "1588859442.000000");
     }
 
+    #[test]
+    fn preserve_precision_str_16_digits() {
+        let ts: Timestamp = serde_json::from_str("\"1588859442.999999\"").unwrap();
+        assert_eq!(ts, Timestamp(1588859442999999));
+        let ts: Timestamp = serde_json::from_str("\"1588859442.000001\"").unwrap();
+        assert_eq!(ts.to_param_value(), "1588859442.000001");
+    }
+
     #[test]
     fn preserve_precision_str_1_dp() {
         let ts_str = "\"1588859442.1\"";
@@ -114,4 +316,97 @@ mod tests {
         assert_eq!(ts, Timestamp(1588859442100000));
         assert_eq!(ts.to_param_value(), "1588859442.100000");
     }
+
+    #[test]
+    fn from_str_is_exact() {
+        let ts: Timestamp = "1588859442.008705".parse().unwrap();
+        assert_eq!(ts, Timestamp(1588859442008705));
+        assert_eq!("1588859442".parse(), Ok(Timestamp(1588859442000000)));
+        assert!("1588859442.0087051".parse::<Timestamp>().is_err());
+        assert!("-1.5".parse::<Timestamp>().is_err());
+        assert!("".parse::<Timestamp>().is_err());
+    }
+
+    #[test]
+    fn serializes_as_string() {
+        let ts = Timestamp(1588859442008705);
+        assert_eq!(serde_json::to_string(&ts).unwrap(), "\"1588859442.008705\"");
+        assert_eq!(
+            serde_json::from_value::<Timestamp>(serde_json::to_value(ts).unwrap()).unwrap(),
+            ts
+        );
+    }
+
+    #[test]
+    fn converts_to_and_from_system_time() {
+        let ts = Timestamp(1588859442008705);
+        let time = SystemTime::from(ts);
+        assert_eq!(
+            time.duration_since(UNIX_EPOCH).unwrap(),
+            Duration::from_micros(1588859442008705)
+        );
+        assert_eq!(Timestamp::try_from(time), Ok(ts));
+        assert!(Timestamp::try_from(UNIX_EPOCH - Duration::from_secs(1)).is_err());
+    }
+
+    #[test]
+    fn adds_and_subtracts_durations() {
+        let mut ts = Timestamp(1588859442008705);
+        assert_eq!(ts + Duration::from_secs(60), Timestamp(1588859502008705));
+        assert_eq!(
+            ts - Duration::from_micros(8705),
+            Timestamp(1588859442000000)
+        );
+        ts += Duration::from_nanos(1_500);
+        assert_eq!(ts, Timestamp(1588859442008706));
+        ts -= Duration::from_millis(1);
+        assert_eq!(ts, Timestamp(1588859442007706));
+    }
+
+    #[test]
+    fn arithmetic_saturates_at_the_bounds() {
+        let latest = Timestamp(u64::MAX);
+        assert_eq!(latest.checked_add(Duration::from_micros(1)), None);
+        assert_eq!(latest + Duration::from_micros(1), latest);
+        assert_eq!(Timestamp(1).checked_add(Duration::MAX), None);
+        assert_eq!(Timestamp(1) + Duration::MAX, latest);
+
+        let epoch = Timestamp(0);
+        assert_eq!(epoch.checked_sub(Duration::from_micros(1)), None);
+        assert_eq!(epoch - Duration::from_micros(1), epoch);
+        assert_eq!(latest.checked_sub(Duration::MAX), None);
+        assert_eq!(latest - Duration::MAX, epoch);
+        assert_eq!(
+            Timestamp(5).checked_sub(Duration::from_micros(5)),
+            Some(epoch)
+        );
+    }
+
+    #[test]
+    fn permalink_id_round_trip() {
+        let ts = Timestamp(1588859442008705);
+        assert_eq!(ts.to_permalink_id(), "p1588859442008705");
+        assert_eq!(Timestamp::from_permalink_id("p1588859442008705"), Ok(ts));
+        assert!(Timestamp::from_permalink_id("1588859442008705").is_err());
+        assert!(Timestamp::from_permalink_id("p123").is_err());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn converts_to_and_from_chrono() {
+        let ts = Timestamp(1588859442008705);
+        let time = chrono::DateTime::<chrono::Utc>::try_from(ts).unwrap();
+        assert_eq!(time.to_rfc3339(), "2020-05-07T13:50:42.008705+00:00");
+        assert_eq!(Timestamp::try_from(time), Ok(ts));
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn converts_to_and_from_time() {
+        let ts = Timestamp(1588859442008705);
+        let time = time::OffsetDateTime::try_from(ts).unwrap();
+        assert_eq!(time.unix_timestamp(), 1588859442);
+        assert_eq!(time.microsecond(), 8705);
+        assert_eq!(Timestamp::try_from(time), Ok(ts));
+    }
 }
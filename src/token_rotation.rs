@@ -0,0 +1,311 @@
+//! Token bookkeeping shared by the async and sync `WithTokenRotation` wrappers.
+//!
+//! Apps with [token rotation](https://api.slack.com/authentication/rotation) enabled get access
+//! tokens that expire after 12 hours, along with an `xoxe-` refresh token that `oauth.v2.access`
+//! exchanges for a new pair. A [`RotatingToken`] keeps the current pair and when it expires, so
+//! that the wrappers can refresh it before it does, or when Slack answers `token_expired` anyway.
+
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, SystemTime};
+
+use crate::mod_types::oauth::v_2_types::{AccessError, AccessRequest, AccessResponse};
+use crate::SlackWebResponse;
+
+/// An access token, the refresh token issued along with it and when the access token expires.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenSet {
+    pub access_token: String,
+    pub refresh_token: String,
+    pub expires_at: SystemTime,
+}
+
+impl TokenSet {
+    /// The top-level token of an `oauth.v2.access` response: the bot token of an install, or the
+    /// new token of a refresh.
+    ///
+    /// Returns `None` unless the response has a refresh token and an expiry, as it only does for
+    /// apps with token rotation enabled.
+    pub fn from_response(response: &AccessResponse) -> Option<Self> {
+        Self::from_parts(
+            response.access_token.as_deref(),
+            response.refresh_token.as_deref(),
+            response.expires_in,
+            SystemTime::now(),
+        )
+    }
+
+    /// The installing user's token of an `oauth.v2.access` response, if it rotates.
+    pub fn from_authed_user(response: &AccessResponse) -> Option<Self> {
+        let user = &response.authed_user;
+        Self::from_parts(
+            user.access_token.as_deref(),
+            user.refresh_token.as_deref(),
+            user.expires_in,
+            SystemTime::now(),
+        )
+    }
+
+    fn from_parts(
+        access_token: Option<&str>,
+        refresh_token: Option<&str>,
+        expires_in: Option<u64>,
+        now: SystemTime,
+    ) -> Option<Self> {
+        Some(TokenSet {
+            access_token: access_token?.to_owned(),
+            refresh_token: refresh_token?.to_owned(),
+            expires_at: now + Duration::from_secs(expires_in?),
+        })
+    }
+}
+
+/// A rotating access token, shared by its clones.
+///
+/// Wrap a request sender in `WithTokenRotation` with it, then pass any access token it issued to
+/// the generated calls in place of a fixed token, such as the one the app was installed with. The
+/// wrapper swaps it for the current access token, refreshing that first if it expires within the
+/// refresh leeway of 5 minutes.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::{Duration, SystemTime};
+/// use slack_api::token_rotation::{RotatingToken, TokenSet};
+///
+/// let token = RotatingToken::new(
+///     "123.456",
+///     "client secret",
+///     TokenSet {
+///         access_token: "xoxe.xoxb-1-abc".into(),
+///         refresh_token: "xoxe-1-def".into(),
+///         expires_at: SystemTime::now() + Duration::from_secs(12 * 60 * 60),
+///     },
+/// )
+/// .with_on_rotate(|tokens| println!("store {} somewhere safe", tokens.refresh_token));
+/// assert!(!token.needs_refresh());
+/// assert!(token.issued("xoxe.xoxb-1-abc"));
+/// ```
+#[derive(Clone)]
+pub struct RotatingToken {
+    client_id: Arc<str>,
+    client_secret: Arc<str>,
+    leeway: Duration,
+    state: Arc<Mutex<State>>,
+    on_rotate: Option<OnRotate>,
+}
+
+type OnRotate = Arc<dyn Fn(&TokenSet) + Send + Sync>;
+
+struct State {
+    tokens: TokenSet,
+    /// Every access token handed out so far, which callers may still hold.
+    issued: HashSet<String>,
+}
+
+impl RotatingToken {
+    /// The rotating token of the app with the given credentials, starting from `tokens`.
+    pub fn new<I: Into<String>, S: Into<String>>(
+        client_id: I,
+        client_secret: S,
+        tokens: TokenSet,
+    ) -> Self {
+        let mut issued = HashSet::new();
+        issued.insert(tokens.access_token.clone());
+        RotatingToken {
+            client_id: client_id.into().into(),
+            client_secret: client_secret.into().into(),
+            leeway: Duration::from_secs(5 * 60),
+            state: Arc::new(Mutex::new(State { tokens, issued })),
+            on_rotate: None,
+        }
+    }
+
+    /// Refreshes the token once it expires within `leeway` instead of 5 minutes.
+    pub fn with_refresh_leeway(mut self, leeway: Duration) -> Self {
+        self.leeway = leeway;
+        self
+    }
+
+    /// Calls `on_rotate` with every new pair of tokens, e.g. to store the refresh token, which
+    /// the old one cannot be used again after.
+    pub fn with_on_rotate<F>(mut self, on_rotate: F) -> Self
+    where
+        F: Fn(&TokenSet) + Send + Sync + 'static,
+    {
+        self.on_rotate = Some(Arc::new(on_rotate));
+        self
+    }
+
+    /// The current tokens.
+    pub fn tokens(&self) -> TokenSet {
+        self.state().tokens.clone()
+    }
+
+    /// The current access token.
+    pub fn access_token(&self) -> String {
+        self.state().tokens.access_token.clone()
+    }
+
+    /// Whether `token` is an access token this rotating token has issued, current or not.
+    pub fn issued(&self, token: &str) -> bool {
+        self.state().issued.contains(token)
+    }
+
+    /// Whether the access token expires within the refresh leeway.
+    pub fn needs_refresh(&self) -> bool {
+        self.needs_refresh_at(SystemTime::now())
+    }
+
+    /// The `oauth.v2.access` request exchanging the current refresh token for new tokens.
+    pub fn refresh_request(&self) -> AccessRequest<'static> {
+        AccessRequest {
+            client_id: Cow::Owned(self.client_id.to_string()),
+            client_secret: Cow::Owned(self.client_secret.to_string()),
+            grant_type: Some(Cow::Borrowed("refresh_token")),
+            refresh_token: Some(Cow::Owned(self.state().tokens.refresh_token.clone())),
+            ..Default::default()
+        }
+    }
+
+    /// Replaces the tokens with those of the response to a [`refresh_request`](Self::refresh_request).
+    ///
+    /// Returns `None`, and keeps the current tokens, if the response has none.
+    pub fn update(&self, response: &AccessResponse) -> Option<TokenSet> {
+        let tokens = TokenSet::from_response(response)?;
+        self.rotate(tokens.clone());
+        Some(tokens)
+    }
+
+    /// Replaces the tokens, e.g. with those another process refreshed.
+    pub fn rotate(&self, tokens: TokenSet) {
+        {
+            let mut state = self.state();
+            state.issued.insert(tokens.access_token.clone());
+            state.tokens = tokens.clone();
+        }
+        if let Some(ref on_rotate) = self.on_rotate {
+            on_rotate(&tokens);
+        }
+    }
+
+    fn needs_refresh_at(&self, now: SystemTime) -> bool {
+        self.state().tokens.expires_at <= now + self.leeway
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl fmt::Debug for RotatingToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RotatingToken")
+            .field("client_id", &self.client_id)
+            .field("leeway", &self.leeway)
+            .field("expires_at", &self.state().tokens.expires_at)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Whether Slack rejected the call because its access token expired.
+pub(crate) fn is_token_expired(response: &SlackWebResponse) -> bool {
+    matches!(crate::error::read_error(&response.body), Some((code, _)) if code == "token_expired")
+}
+
+/// The error of a request sent through `WithTokenRotation`.
+#[derive(Debug)]
+pub enum RotationError<E: Error> {
+    /// The inner sender had an error sending the request to Slack
+    Client(E),
+    /// `oauth.v2.access` failed to refresh the token
+    Refresh(AccessError<E>),
+    /// `oauth.v2.access` answered the refresh without a new refresh token and expiry
+    NotRotated,
+}
+
+impl<E: Error> fmt::Display for RotationError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            RotationError::Client(ref inner) => write!(f, "{}", inner),
+            RotationError::Refresh(ref e) => write!(f, "Failed to refresh the token: {}", e),
+            RotationError::NotRotated => write!(f, "The refreshed token does not rotate"),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for RotationError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            RotationError::Client(ref inner) => Some(inner),
+            RotationError::Refresh(ref e) => Some(e),
+            RotationError::NotRotated => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token(expires_at: SystemTime) -> RotatingToken {
+        RotatingToken::new(
+            "123.456",
+            "secret",
+            TokenSet {
+                access_token: "xoxe.xoxb-1".into(),
+                refresh_token: "xoxe-1".into(),
+                expires_at,
+            },
+        )
+    }
+
+    #[test]
+    fn refreshes_within_leeway() {
+        let now = SystemTime::now();
+        let token = token(now + Duration::from_secs(600));
+        assert!(!token.needs_refresh_at(now));
+        assert!(token.needs_refresh_at(now + Duration::from_secs(300)));
+        let token = token.with_refresh_leeway(Duration::from_secs(0));
+        assert!(!token.needs_refresh_at(now + Duration::from_secs(599)));
+        assert!(token.needs_refresh_at(now + Duration::from_secs(600)));
+    }
+
+    #[test]
+    fn refresh_request_uses_refresh_token() {
+        let request = token(SystemTime::now()).refresh_request();
+        assert_eq!(request.client_id, "123.456");
+        assert_eq!(request.grant_type.as_deref(), Some("refresh_token"));
+        assert_eq!(request.refresh_token.as_deref(), Some("xoxe-1"));
+        assert!(request.code.is_none());
+    }
+
+    #[test]
+    fn update_keeps_issued_tokens() {
+        let rotated = Arc::new(Mutex::new(Vec::new()));
+        let seen = rotated.clone();
+        let token = token(SystemTime::now())
+            .with_on_rotate(move |tokens| seen.lock().unwrap().push(tokens.refresh_token.clone()));
+        let response: AccessResponse = serde_json::from_str(
+            r#"{"ok": true, "app_id": "A1", "authed_user": {"id": "U1"}, "access_token": "xoxe.xoxb-2", "refresh_token": "xoxe-2", "expires_in": 43200, "token_type": "bot"}"#,
+        )
+        .unwrap();
+        let now = SystemTime::now();
+        let tokens = token.update(&response).unwrap();
+        assert_eq!(token.access_token(), "xoxe.xoxb-2");
+        assert!(tokens.expires_at >= now + Duration::from_secs(43200));
+        assert!(token.issued("xoxe.xoxb-1") && token.issued("xoxe.xoxb-2"));
+        assert!(!token.issued("xoxb-other"));
+        assert_eq!(*rotated.lock().unwrap(), vec!["xoxe-2"]);
+
+        let not_rotating: AccessResponse = serde_json::from_str(
+            r#"{"ok": true, "app_id": "A1", "authed_user": {"id": "U1"}, "access_token": "xoxb-3"}"#,
+        )
+        .unwrap();
+        assert!(token.update(&not_rotating).is_none());
+        assert_eq!(token.access_token(), "xoxe.xoxb-2");
+    }
+}
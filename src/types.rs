@@ -16,7 +16,7 @@
 
 use std::collections::HashMap;
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Bot {
     pub app_id: Option<String>,
     pub deleted: Option<bool>,
@@ -25,14 +25,14 @@ pub struct Bot {
     pub name: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct BotIcons {
     pub image_36: Option<String>,
     pub image_48: Option<String>,
     pub image_72: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Channel {
     pub accepted_user: Option<String>,
     pub created: Option<i32>,
@@ -49,7 +49,7 @@ pub struct Channel {
     pub is_private: Option<bool>,
     pub is_read_only: Option<bool>,
     pub is_shared: Option<bool>,
-    pub last_read: Option<String>,
+    pub last_read: Option<crate::Timestamp>,
     pub latest: Option<crate::Message>,
     pub members: Option<Vec<String>>,
     pub name: Option<String>,
@@ -64,21 +64,21 @@ pub struct Channel {
     pub unread_count_display: Option<i32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ChannelPurpose {
     pub creator: Option<String>,
     pub last_set: Option<i32>,
     pub value: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ChannelTopic {
     pub creator: Option<String>,
     pub last_set: Option<i32>,
     pub value: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct File {
     pub channels: Option<Vec<String>>,
     pub comments_count: Option<i32>,
@@ -128,7 +128,7 @@ pub struct File {
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct FileComment {
     pub comment: Option<String>,
     pub id: Option<String>,
@@ -137,7 +137,7 @@ pub struct FileComment {
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Group {
     pub created: Option<i32>,
     pub creator: Option<String>,
@@ -145,7 +145,7 @@ pub struct Group {
     pub is_archived: Option<bool>,
     pub is_group: Option<bool>,
     pub is_mpim: Option<bool>,
-    pub last_read: Option<String>,
+    pub last_read: Option<crate::Timestamp>,
     pub latest: Option<crate::Message>,
     pub members: Option<Vec<String>>,
     pub name: Option<String>,
@@ -155,21 +155,21 @@ pub struct Group {
     pub unread_count_display: Option<i32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GroupPurpose {
     pub creator: Option<String>,
     pub last_set: Option<i32>,
     pub value: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GroupTopic {
     pub creator: Option<String>,
     pub last_set: Option<i32>,
     pub value: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Im {
     pub created: Option<i32>,
     pub id: Option<String>,
@@ -414,7 +414,108 @@ impl<'de> ::serde::Deserialize<'de> for Message {
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl ::serde::Serialize for Message {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        use ::serde::ser::Error as SerdeError;
+
+        let (subtype, value) = match self {
+            Message::Standard(message) => (None, ::serde_json::to_value(message)),
+            Message::BotAdd(message) => (Some("bot_add"), ::serde_json::to_value(message)),
+            Message::BotDisable(message) => (Some("bot_disable"), ::serde_json::to_value(message)),
+            Message::BotEnable(message) => (Some("bot_enable"), ::serde_json::to_value(message)),
+            Message::BotRemove(message) => (Some("bot_remove"), ::serde_json::to_value(message)),
+            Message::BotMessage(message) => (Some("bot_message"), ::serde_json::to_value(message)),
+            Message::ChannelArchive(message) => {
+                (Some("channel_archive"), ::serde_json::to_value(message))
+            }
+            Message::ChannelJoin(message) => {
+                (Some("channel_join"), ::serde_json::to_value(message))
+            }
+            Message::ChannelLeave(message) => {
+                (Some("channel_leave"), ::serde_json::to_value(message))
+            }
+            Message::ChannelName(message) => {
+                (Some("channel_name"), ::serde_json::to_value(message))
+            }
+            Message::ChannelPurpose(message) => {
+                (Some("channel_purpose"), ::serde_json::to_value(message))
+            }
+            Message::ChannelTopic(message) => {
+                (Some("channel_topic"), ::serde_json::to_value(message))
+            }
+            Message::ChannelUnarchive(message) => {
+                (Some("channel_unarchive"), ::serde_json::to_value(message))
+            }
+            Message::FileComment(message) => {
+                (Some("file_comment"), ::serde_json::to_value(message))
+            }
+            Message::FileMention(message) => {
+                (Some("file_mention"), ::serde_json::to_value(message))
+            }
+            Message::FileShare(message) => (Some("file_share"), ::serde_json::to_value(message)),
+            Message::GroupArchive(message) => {
+                (Some("group_archive"), ::serde_json::to_value(message))
+            }
+            Message::GroupJoin(message) => (Some("group_join"), ::serde_json::to_value(message)),
+            Message::GroupLeave(message) => (Some("group_leave"), ::serde_json::to_value(message)),
+            Message::GroupName(message) => (Some("group_name"), ::serde_json::to_value(message)),
+            Message::GroupPurpose(message) => {
+                (Some("group_purpose"), ::serde_json::to_value(message))
+            }
+            Message::GroupTopic(message) => (Some("group_topic"), ::serde_json::to_value(message)),
+            Message::GroupUnarchive(message) => {
+                (Some("group_unarchive"), ::serde_json::to_value(message))
+            }
+            Message::MeMessage(message) => (Some("me_message"), ::serde_json::to_value(message)),
+            Message::MessageChanged(message) => {
+                (Some("message_changed"), ::serde_json::to_value(message))
+            }
+            Message::MessageDeleted(message) => {
+                (Some("message_deleted"), ::serde_json::to_value(message))
+            }
+            Message::MessageReplied(message) => {
+                (Some("message_replied"), ::serde_json::to_value(message))
+            }
+            Message::PinnedItem(message) => (Some("pinned_item"), ::serde_json::to_value(message)),
+            Message::ReminderAdd(message) => {
+                (Some("reminder_add"), ::serde_json::to_value(message))
+            }
+            Message::ReplyBroadcast(message) => {
+                (Some("reply_broadcast"), ::serde_json::to_value(message))
+            }
+            Message::ThreadBroadcast(message) => {
+                (Some("thread_broadcast"), ::serde_json::to_value(message))
+            }
+            Message::UnpinnedItem(message) => {
+                (Some("unpinned_item"), ::serde_json::to_value(message))
+            }
+            Message::ShRoomCreated(message) => {
+                (Some("sh_room_created"), ::serde_json::to_value(message))
+            }
+            Message::SlackbotResponse(message) => {
+                (Some("slackbot_response"), ::serde_json::to_value(message))
+            }
+        };
+        let mut value = value.map_err(S::Error::custom)?;
+        // The subtype is what `Deserialize` picks the variant by, so it has to match the variant
+        if let Some(object) = value.as_object_mut() {
+            match subtype {
+                Some(subtype) => {
+                    object.insert("subtype".into(), subtype.into());
+                }
+                None => {
+                    object.remove("subtype");
+                }
+            }
+        }
+        value.serialize(serializer)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageBotAdd {
     pub bot_id: Option<String>,
     pub bot_link: Option<String>,
@@ -428,7 +529,7 @@ pub struct MessageBotAdd {
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageBotDisable {
     pub bot_id: Option<String>,
     pub bot_link: Option<String>,
@@ -442,7 +543,7 @@ pub struct MessageBotDisable {
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageBotEnable {
     pub bot_id: Option<String>,
     pub bot_link: Option<String>,
@@ -456,7 +557,7 @@ pub struct MessageBotEnable {
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageBotMessage {
     pub attachments: Option<Vec<MessageBotMessageAttachment>>,
     pub bot_id: Option<String>,
@@ -474,7 +575,7 @@ pub struct MessageBotMessage {
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageBotMessageAttachment {
     pub author_icon: Option<String>,
     pub author_link: Option<String>,
@@ -493,14 +594,14 @@ pub struct MessageBotMessageAttachment {
     pub ts: Option<crate::Timestamp>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageBotMessageAttachmentField {
     pub short: Option<bool>,
     pub title: Option<String>,
     pub value: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageBotMessageIcons {
     pub emoji: Option<String>,
     pub image_36: Option<String>,
@@ -509,7 +610,7 @@ pub struct MessageBotMessageIcons {
     pub image_72: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageBotRemove {
     pub bot_id: Option<String>,
     pub bot_link: Option<String>,
@@ -523,7 +624,7 @@ pub struct MessageBotRemove {
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageChannelArchive {
     pub members: Option<Vec<String>>,
     pub subtype: Option<String>,
@@ -534,7 +635,7 @@ pub struct MessageChannelArchive {
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageChannelJoin {
     pub inviter: Option<String>,
     pub subtype: Option<String>,
@@ -545,7 +646,7 @@ pub struct MessageChannelJoin {
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageChannelLeave {
     pub subtype: Option<String>,
     pub text: Option<String>,
@@ -555,7 +656,7 @@ pub struct MessageChannelLeave {
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageChannelName {
     pub name: Option<String>,
     pub old_name: Option<String>,
@@ -567,7 +668,7 @@ pub struct MessageChannelName {
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageChannelPurpose {
     pub purpose: Option<String>,
     pub subtype: Option<String>,
@@ -578,7 +679,7 @@ pub struct MessageChannelPurpose {
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageChannelTopic {
     pub subtype: Option<String>,
     pub text: Option<String>,
@@ -589,7 +690,7 @@ pub struct MessageChannelTopic {
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageChannelUnarchive {
     pub subtype: Option<String>,
     pub text: Option<String>,
@@ -599,7 +700,7 @@ pub struct MessageChannelUnarchive {
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageFileComment {
     pub comment: Option<crate::FileComment>,
     pub file: Option<crate::File>,
@@ -610,7 +711,7 @@ pub struct MessageFileComment {
     pub ty: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageFileMention {
     pub file: Option<crate::File>,
     pub subtype: Option<String>,
@@ -621,7 +722,7 @@ pub struct MessageFileMention {
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageFileShare {
     pub file: Option<crate::File>,
     pub subtype: Option<String>,
@@ -633,7 +734,7 @@ pub struct MessageFileShare {
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageGroupArchive {
     pub members: Option<Vec<String>>,
     pub subtype: Option<String>,
@@ -644,7 +745,7 @@ pub struct MessageGroupArchive {
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageGroupJoin {
     pub subtype: Option<String>,
     pub text: Option<String>,
@@ -654,7 +755,7 @@ pub struct MessageGroupJoin {
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageGroupLeave {
     pub subtype: Option<String>,
     pub text: Option<String>,
@@ -664,7 +765,7 @@ pub struct MessageGroupLeave {
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageGroupName {
     pub name: Option<String>,
     pub old_name: Option<String>,
@@ -676,7 +777,7 @@ pub struct MessageGroupName {
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageGroupPurpose {
     pub purpose: Option<String>,
     pub subtype: Option<String>,
@@ -687,7 +788,7 @@ pub struct MessageGroupPurpose {
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageGroupTopic {
     pub subtype: Option<String>,
     pub text: Option<String>,
@@ -698,7 +799,7 @@ pub struct MessageGroupTopic {
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageGroupUnarchive {
     pub subtype: Option<String>,
     pub text: Option<String>,
@@ -708,7 +809,7 @@ pub struct MessageGroupUnarchive {
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMeMessage {
     pub channel: Option<String>,
     pub subtype: Option<String>,
@@ -719,7 +820,7 @@ pub struct MessageMeMessage {
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageChanged {
     pub channel: Option<String>,
     pub event_ts: Option<crate::Timestamp>,
@@ -732,11 +833,11 @@ pub struct MessageMessageChanged {
     pub ty: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageChangedMessage {
     pub bot_id: Option<String>,
     pub edited: Option<MessageMessageChangedMessageEdited>,
-    pub last_read: Option<String>,
+    pub last_read: Option<crate::Timestamp>,
     pub parent_user_id: Option<String>,
     pub replies: Option<Vec<MessageMessageChangedMessageReply>>,
     pub reply_count: Option<i32>,
@@ -750,23 +851,23 @@ pub struct MessageMessageChangedMessage {
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageChangedMessageEdited {
     pub ts: Option<crate::Timestamp>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageChangedMessageReply {
     pub ts: Option<crate::Timestamp>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageChangedPreviousMessage {
     pub bot_id: Option<String>,
     pub edited: Option<MessageMessageChangedPreviousMessageEdited>,
-    pub last_read: Option<String>,
+    pub last_read: Option<crate::Timestamp>,
     pub parent_user_id: Option<String>,
     pub replies: Option<Vec<MessageMessageChangedPreviousMessageReply>>,
     pub reply_count: Option<i32>,
@@ -780,19 +881,19 @@ pub struct MessageMessageChangedPreviousMessage {
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageChangedPreviousMessageEdited {
     pub ts: Option<crate::Timestamp>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageChangedPreviousMessageReply {
     pub ts: Option<crate::Timestamp>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageDeleted {
     pub channel: Option<String>,
     pub deleted_ts: Option<crate::Timestamp>,
@@ -805,11 +906,11 @@ pub struct MessageMessageDeleted {
     pub ty: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageDeletedPreviousMessage {
     pub bot_id: Option<String>,
     pub edited: Option<MessageMessageDeletedPreviousMessageEdited>,
-    pub last_read: Option<String>,
+    pub last_read: Option<crate::Timestamp>,
     pub parent_user_id: Option<String>,
     pub replies: Option<Vec<MessageMessageDeletedPreviousMessageReply>>,
     pub reply_count: Option<i32>,
@@ -823,19 +924,19 @@ pub struct MessageMessageDeletedPreviousMessage {
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageDeletedPreviousMessageEdited {
     pub ts: Option<crate::Timestamp>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageDeletedPreviousMessageReply {
     pub ts: Option<crate::Timestamp>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageReplied {
     pub channel: Option<String>,
     pub event_ts: Option<crate::Timestamp>,
@@ -848,11 +949,11 @@ pub struct MessageMessageReplied {
     pub ty: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageRepliedMessage {
     pub bot_id: Option<String>,
     pub edited: Option<MessageMessageRepliedMessageEdited>,
-    pub last_read: Option<String>,
+    pub last_read: Option<crate::Timestamp>,
     pub parent_user_id: Option<String>,
     pub replies: Option<Vec<MessageMessageRepliedMessageReply>>,
     pub reply_count: Option<i32>,
@@ -866,19 +967,19 @@ pub struct MessageMessageRepliedMessage {
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageRepliedMessageEdited {
     pub ts: Option<crate::Timestamp>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageRepliedMessageReply {
     pub ts: Option<crate::Timestamp>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessagePinnedItem {
     pub channel: Option<String>,
     pub item: Option<MessagePinnedItemItem>,
@@ -891,10 +992,10 @@ pub struct MessagePinnedItem {
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessagePinnedItemItem {}
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageReminderAdd {
     pub channel: Option<String>,
     pub subtype: Option<String>,
@@ -906,7 +1007,7 @@ pub struct MessageReminderAdd {
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageReplyBroadcast {
     pub attachments: Option<Vec<MessageReplyBroadcastAttachment>>,
     pub channel: Option<String>,
@@ -918,7 +1019,7 @@ pub struct MessageReplyBroadcast {
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageReplyBroadcastAttachment {
     pub author_icon: Option<String>,
     pub author_link: Option<String>,
@@ -934,7 +1035,7 @@ pub struct MessageReplyBroadcastAttachment {
     pub ts: Option<crate::Timestamp>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageShRoomCreated {
     pub channel: Option<String>,
     pub no_notifications: Option<bool>,
@@ -948,7 +1049,7 @@ pub struct MessageShRoomCreated {
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageShRoomCreatedRoot {
     pub app_id: Option<String>,
     pub channels: Option<Vec<String>>,
@@ -973,7 +1074,7 @@ pub struct MessageShRoomCreatedRoot {
     pub was_rejected: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageSlackbotResponse {
     pub channel: Option<String>,
     pub subtype: Option<String>,
@@ -985,7 +1086,7 @@ pub struct MessageSlackbotResponse {
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageStandard {
     pub attachments: Option<Vec<MessageStandardAttachment>>,
     pub bot_id: Option<String>,
@@ -1003,7 +1104,7 @@ pub struct MessageStandard {
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageStandardAttachment {
     pub author_icon: Option<String>,
     pub author_link: Option<String>,
@@ -1022,20 +1123,20 @@ pub struct MessageStandardAttachment {
     pub ts: Option<crate::Timestamp>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageStandardAttachmentField {
     pub short: Option<bool>,
     pub title: Option<String>,
     pub value: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageStandardEdited {
     pub ts: Option<crate::Timestamp>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageThreadBroadcast {
     pub root: Option<MessageThreadBroadcastRoot>,
     pub subtype: Option<String>,
@@ -1046,7 +1147,7 @@ pub struct MessageThreadBroadcast {
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageThreadBroadcastRoot {
     pub latest_reply: Option<crate::Timestamp>,
     pub replies: Option<Vec<MessageThreadBroadcastRootReply>>,
@@ -1061,13 +1162,13 @@ pub struct MessageThreadBroadcastRoot {
     pub ty: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageThreadBroadcastRootReply {
     pub ts: Option<crate::Timestamp>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageUnpinnedItem {
     pub channel: Option<String>,
     pub item: Option<MessageUnpinnedItemItem>,
@@ -1080,17 +1181,17 @@ pub struct MessageUnpinnedItem {
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageUnpinnedItemItem {}
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Mpim {
     pub created: Option<i32>,
     pub creator: Option<String>,
     pub id: Option<String>,
     pub is_group: Option<bool>,
     pub is_mpim: Option<bool>,
-    pub last_read: Option<String>,
+    pub last_read: Option<crate::Timestamp>,
     pub latest: Option<crate::Message>,
     pub members: Option<Vec<String>>,
     pub name: Option<String>,
@@ -1098,7 +1199,7 @@ pub struct Mpim {
     pub unread_count_display: Option<i32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Paging {
     pub count: Option<i32>,
     pub page: Option<i32>,
@@ -1106,14 +1207,14 @@ pub struct Paging {
     pub total: Option<i32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Reaction {
     pub count: Option<i32>,
     pub name: Option<String>,
     pub users: Option<Vec<String>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Reminder {
     pub complete_ts: Option<crate::Timestamp>,
     pub creator: Option<String>,
@@ -1124,7 +1225,7 @@ pub struct Reminder {
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Team {
     pub domain: Option<String>,
     pub email_domain: Option<String>,
@@ -1133,7 +1234,7 @@ pub struct Team {
     pub name: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct TeamIcon {
     pub image_102: Option<String>,
     pub image_132: Option<String>,
@@ -1144,13 +1245,13 @@ pub struct TeamIcon {
     pub image_default: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ThreadInfo {
     pub complete: Option<bool>,
     pub count: Option<i32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct User {
     pub color: Option<String>,
     pub deleted: Option<bool>,
@@ -1175,7 +1276,7 @@ pub struct User {
     pub updated: Option<f32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Usergroup {
     pub auto_type: Option<String>,
     pub created_by: Option<String>,
@@ -1195,13 +1296,13 @@ pub struct Usergroup {
     pub user_count: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UsergroupPrefs {
     pub channels: Option<Vec<String>>,
     pub groups: Option<Vec<String>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UserProfile {
     pub avatar_hash: Option<String>,
     pub display_name: Option<String>,
@@ -1230,7 +1331,7 @@ pub struct UserProfile {
     pub title: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UserProfileFields {
     pub alt: Option<String>,
     pub label: Option<String>,
@@ -0,0 +1,372 @@
+//! File contents for the parameters Slack expects as `multipart/form-data`, such as
+//! `files.upload`'s `file` or `users.setPhoto`'s `image`, and the request and error types of the
+//! `files::upload_v2` helper.
+
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use crate::mod_types::files::{CompleteUploadExternalError, GetUploadURLExternalError};
+
+/// A file to upload, read from memory, from disk or from any reader.
+///
+/// # Examples
+///
+/// ```
+/// let upload = slack_api::FileUpload::bytes(&b"hello"[..]).with_filename("hello.txt");
+/// assert_eq!(upload.filename(), Some("hello.txt"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct FileUpload<'a> {
+    source: UploadSource<'a>,
+    length: Option<u64>,
+    filename: Option<Cow<'a, str>>,
+    mime_type: Option<Cow<'a, str>>,
+}
+
+/// Where the contents of a [`FileUpload`] come from.
+#[derive(Clone, Debug)]
+pub enum UploadSource<'a> {
+    Bytes(Cow<'a, [u8]>),
+    Path(Cow<'a, Path>),
+    Reader(SharedReader),
+}
+
+impl<'a> FileUpload<'a> {
+    /// Uploads the given bytes.
+    pub fn bytes<B: Into<Cow<'a, [u8]>>>(bytes: B) -> Self {
+        Self::from_source(UploadSource::Bytes(bytes.into()))
+    }
+
+    /// Uploads the file at `path`, which is opened when the request is sent. The filename
+    /// defaults to the last component of the path.
+    pub fn path<P: Into<Cow<'a, Path>>>(path: P) -> Self {
+        let path = path.into();
+        let filename = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned().into());
+        FileUpload {
+            filename,
+            ..Self::from_source(UploadSource::Path(path))
+        }
+    }
+
+    /// Uploads whatever `reader` yields.
+    ///
+    /// A reader can only be read once, so a `RateLimitedSender` does not retry uploads of readers
+    /// and returns the `429` instead.
+    pub fn reader<R: Read + Send + 'static>(reader: R) -> Self {
+        Self::from_source(UploadSource::Reader(SharedReader::new(reader)))
+    }
+
+    /// Uploads the `length` bytes that `reader` yields.
+    ///
+    /// Knowing the length up front lets `files::upload_v2` stream the reader instead of reading
+    /// it into memory to measure it.
+    pub fn reader_with_length<R: Read + Send + 'static>(reader: R, length: u64) -> Self {
+        FileUpload {
+            length: Some(length),
+            ..Self::reader(reader)
+        }
+    }
+
+    fn from_source(source: UploadSource<'a>) -> Self {
+        FileUpload {
+            source,
+            length: None,
+            filename: None,
+            mime_type: None,
+        }
+    }
+
+    /// Sets the filename sent along with the contents.
+    pub fn with_filename<S: Into<Cow<'a, str>>>(mut self, filename: S) -> Self {
+        self.filename = Some(filename.into());
+        self
+    }
+
+    /// Sets the `Content-Type` of the part, e.g. `image/png`.
+    pub fn with_mime_type<S: Into<Cow<'a, str>>>(mut self, mime_type: S) -> Self {
+        self.mime_type = Some(mime_type.into());
+        self
+    }
+
+    pub fn source(&self) -> &UploadSource<'a> {
+        &self.source
+    }
+
+    pub fn filename(&self) -> Option<&str> {
+        self.filename.as_deref()
+    }
+
+    pub fn mime_type(&self) -> Option<&str> {
+        self.mime_type.as_deref()
+    }
+
+    /// Opens the contents for reading; the length is known for bytes, files and readers given
+    /// one.
+    pub fn open(&self) -> io::Result<(Box<dyn Read + Send + 'static>, Option<u64>)> {
+        match &self.source {
+            UploadSource::Bytes(bytes) => {
+                let len = bytes.len() as u64;
+                Ok((Box::new(io::Cursor::new(bytes.to_vec())), Some(len)))
+            }
+            UploadSource::Path(path) => {
+                let file = File::open(path)?;
+                let len = file.metadata().ok().map(|m| m.len());
+                Ok((Box::new(file), len))
+            }
+            UploadSource::Reader(reader) => Ok((Box::new(reader.clone()), self.length)),
+        }
+    }
+
+    /// Like [`open`](Self::open), but a file that cannot be opened yields a reader that fails
+    /// with the error, so that it surfaces when the request body is sent.
+    #[cfg(feature = "reqwest_blocking")]
+    pub(crate) fn open_or_failing(&self) -> (Box<dyn Read + Send + 'static>, Option<u64>) {
+        self.open()
+            .unwrap_or_else(|e| (Box::new(FailingReader(Some(e))), None))
+    }
+
+    /// Whether the contents can be read again to send the request once more.
+    pub(crate) fn is_replayable(&self) -> bool {
+        !matches!(self.source, UploadSource::Reader(_))
+    }
+
+    /// Copies borrowed bytes, paths and names so that the upload can outlive them.
+    #[cfg(feature = "reqwest")]
+    pub(crate) fn to_static(&self) -> FileUpload<'static> {
+        let source = match &self.source {
+            UploadSource::Bytes(bytes) => UploadSource::Bytes(Cow::Owned(bytes.to_vec())),
+            UploadSource::Path(path) => UploadSource::Path(Cow::Owned(path.to_path_buf())),
+            UploadSource::Reader(reader) => UploadSource::Reader(reader.clone()),
+        };
+        FileUpload {
+            source,
+            length: self.length,
+            filename: self.filename.as_deref().map(|s| Cow::Owned(s.to_owned())),
+            mime_type: self.mime_type.as_deref().map(|s| Cow::Owned(s.to_owned())),
+        }
+    }
+
+    /// Returns the upload along with its length in bytes, reading a reader of unknown length into
+    /// memory first since its length cannot be known otherwise.
+    pub(crate) fn with_length(&self) -> io::Result<(FileUpload<'a>, u64)> {
+        match (&self.source, self.length) {
+            (UploadSource::Bytes(bytes), _) => Ok((self.clone(), bytes.len() as u64)),
+            (UploadSource::Path(path), _) => Ok((self.clone(), fs::metadata(path)?.len())),
+            (UploadSource::Reader(_), Some(len)) => Ok((self.clone(), len)),
+            (UploadSource::Reader(_), None) => {
+                let bytes = self.read_to_vec()?;
+                let len = bytes.len() as u64;
+                let upload = FileUpload {
+                    source: UploadSource::Bytes(bytes.into()),
+                    length: None,
+                    ..self.clone()
+                };
+                Ok((upload, len))
+            }
+        }
+    }
+
+    /// Reads the whole contents into memory.
+    pub fn read_to_vec(&self) -> io::Result<Vec<u8>> {
+        if let UploadSource::Bytes(bytes) = &self.source {
+            return Ok(bytes.to_vec());
+        }
+        let (mut reader, len) = self.open()?;
+        let mut buf = Vec::with_capacity(len.unwrap_or(0) as usize);
+        reader.read_to_end(&mut buf)?;
+        Ok(buf)
+    }
+}
+
+/// A reader that can be cloned into a request body; all clones read from the same source.
+#[derive(Clone)]
+pub struct SharedReader(Arc<Mutex<Box<dyn Read + Send>>>);
+
+impl SharedReader {
+    pub fn new<R: Read + Send + 'static>(reader: R) -> Self {
+        SharedReader(Arc::new(Mutex::new(Box::new(reader))))
+    }
+}
+
+impl Read for SharedReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner()).read(buf)
+    }
+}
+
+#[cfg(feature = "reqwest_blocking")]
+struct FailingReader(Option<io::Error>);
+
+#[cfg(feature = "reqwest_blocking")]
+impl Read for FailingReader {
+    fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+        match self.0.take() {
+            Some(e) => Err(e),
+            None => Ok(0),
+        }
+    }
+}
+
+impl fmt::Debug for SharedReader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SharedReader")
+    }
+}
+
+/// A request for `files::upload_v2`, which uploads files through `files.getUploadURLExternal`
+/// and shares them with `files.completeUploadExternal`.
+#[derive(Clone, Default, Debug)]
+pub struct UploadV2Request<'a> {
+    /// The files to upload; they are shared together in one message.
+    pub files: Vec<UploadV2File<'a>>,
+    /// Channel ID where the files will be shared. If not specified the files will be private.
+    pub channel_id: Option<crate::ChannelId>,
+    /// The message text introducing the files in the channel.
+    pub initial_comment: Option<Cow<'a, str>>,
+    /// Provide another message's `ts` value to upload the files as a reply. Never use a reply's
+    /// `ts` value; use its parent instead.
+    pub thread_ts: Option<crate::Timestamp>,
+}
+
+/// One file of an [`UploadV2Request`].
+#[derive(Clone, Debug)]
+pub struct UploadV2File<'a> {
+    pub content: FileUpload<'a>,
+    /// Name of the file being uploaded.
+    pub filename: Cow<'a, str>,
+    /// Title of the file; Slack uses the filename if not set.
+    pub title: Option<Cow<'a, str>>,
+    /// Description of image for screen-reader.
+    pub alt_txt: Option<Cow<'a, str>>,
+    /// Syntax type of the snippet being uploaded, e.g. `python`.
+    pub snippet_type: Option<Cow<'a, str>>,
+}
+
+impl<'a> UploadV2File<'a> {
+    pub fn new<S: Into<Cow<'a, str>>>(filename: S, content: FileUpload<'a>) -> Self {
+        UploadV2File {
+            content,
+            filename: filename.into(),
+            title: None,
+            alt_txt: None,
+            snippet_type: None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum UploadV2Error<E: Error> {
+    /// The contents of a file could not be read
+    Read(io::Error),
+    /// `files.getUploadURLExternal` failed
+    GetUploadUrl(GetUploadURLExternalError<E>),
+    /// `files.getUploadURLExternal` succeeded without returning an upload URL and file ID
+    MissingUploadUrl,
+    /// Slack did not accept the file contents sent to the upload URL
+    Upload { status: u16, body: String },
+    /// The client cannot send `multipart/form-data`, which uploads need
+    MultipartUnsupported,
+    /// `files.completeUploadExternal` failed
+    Complete(CompleteUploadExternalError<E>),
+    /// The client had an error sending the file contents to the upload URL
+    Client(E),
+}
+
+impl<E: Error> fmt::Display for UploadV2Error<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            UploadV2Error::Read(ref e) => write!(f, "{}", e),
+            UploadV2Error::GetUploadUrl(ref e) => write!(f, "{}", e),
+            UploadV2Error::MissingUploadUrl => {
+                write!(f, "Server did not return an upload URL and file ID")
+            }
+            UploadV2Error::Upload { status, ref body } => {
+                write!(f, "Upload failed with status {}: {}", status, body)
+            }
+            UploadV2Error::MultipartUnsupported => {
+                write!(f, "Client cannot send multipart/form-data uploads")
+            }
+            UploadV2Error::Complete(ref e) => write!(f, "{}", e),
+            UploadV2Error::Client(ref inner) => write!(f, "{}", inner),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for UploadV2Error<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            UploadV2Error::Read(ref e) => Some(e),
+            UploadV2Error::GetUploadUrl(ref e) => Some(e),
+            UploadV2Error::Complete(ref e) => Some(e),
+            UploadV2Error::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// The `files` argument of `files.completeUploadExternal`.
+pub(crate) fn complete_upload_files(uploaded: &[(crate::FileId, Option<&str>)]) -> String {
+    let files: Vec<serde_json::Value> = uploaded
+        .iter()
+        .map(|(id, title)| match title {
+            Some(title) => serde_json::json!({ "id": id, "title": title }),
+            None => serde_json::json!({ "id": id }),
+        })
+        .collect();
+    serde_json::Value::from(files).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn path_sets_filename() {
+        let upload = FileUpload::path(Path::new("/tmp/report.csv"));
+        assert_eq!(upload.filename(), Some("report.csv"));
+        let upload = upload.with_filename("renamed.csv");
+        assert_eq!(upload.filename(), Some("renamed.csv"));
+    }
+
+    #[test]
+    fn reader_is_read_once() {
+        let upload = FileUpload::reader(io::Cursor::new(b"abc".to_vec()));
+        let clone = upload.clone();
+        assert_eq!(upload.read_to_vec().unwrap(), b"abc");
+        assert!(clone.read_to_vec().unwrap().is_empty());
+    }
+
+    #[test]
+    fn reader_length_is_known_after_buffering() {
+        let upload = FileUpload::reader(io::Cursor::new(b"abcd".to_vec())).with_filename("a.txt");
+        let (buffered, len) = upload.with_length().unwrap();
+        assert_eq!(len, 4);
+        assert_eq!(buffered.filename(), Some("a.txt"));
+        assert_eq!(buffered.read_to_vec().unwrap(), b"abcd");
+    }
+
+    #[test]
+    fn reader_with_length_is_not_buffered() {
+        let upload = FileUpload::reader_with_length(io::Cursor::new(b"abcd".to_vec()), 4);
+        let (streamed, len) = upload.with_length().unwrap();
+        assert_eq!(len, 4);
+        assert!(matches!(streamed.source(), UploadSource::Reader(_)));
+        assert_eq!(streamed.open().unwrap().1, Some(4));
+    }
+
+    #[test]
+    fn missing_file_fails_on_open() {
+        let upload = FileUpload::path(Path::new("/nonexistent/slack-api-upload"));
+        assert_eq!(
+            upload.open().err().map(|e| e.kind()),
+            Some(io::ErrorKind::NotFound)
+        );
+    }
+}
@@ -56,7 +56,7 @@ async fn smoke_channels() -> Result<(), Box<dyn std::error::Error>> {
             &client,
             &token,
             &slack::conversations::InfoRequest {
-                channel: channel_id.into(),
+                channel: Some(channel_id.clone()),
                 ..Default::default()
             },
         )
@@ -69,8 +69,8 @@ async fn smoke_channels() -> Result<(), Box<dyn std::error::Error>> {
             &client,
             &token,
             &slack::conversations::HistoryRequest {
-                channel: channel_id.into(),
-                oldest: Some(1234567890.1234),
+                channel: Some(channel_id.clone()),
+                oldest: Some(1234567890.1234.into()),
                 ..Default::default()
             },
         )